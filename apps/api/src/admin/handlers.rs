@@ -0,0 +1,134 @@
+//! Axum handlers for the admin routes. `require_admin` guards every one.
+
+use std::collections::HashMap;
+
+use axum::{
+    extract::{Query, State},
+    Json,
+};
+use chrono::Utc;
+use uuid::Uuid;
+
+use crate::admin::{
+    flag_counts, generations_per_day, rate, render_counts, window_days, FlagStats, GenerationStats,
+    RenderStats, TokenSpendStats, TopUsersQuery, UserTokenSpend, WindowQuery, DEFAULT_TOP_USERS,
+};
+use crate::context::compaction::CompactContext;
+use crate::context::worker::IngestItemJob;
+use crate::errors::AppError;
+use crate::export::ExportJob;
+use crate::jobs::{self, Job, QueueDepth};
+use crate::quota::{self, Meter};
+use crate::render::RenderJob;
+use crate::retention::RetentionSweep;
+use crate::seed::{self, SeedReport};
+use crate::state::AppState;
+use crate::users::deletion::PurgeObjectsJob;
+
+/// Every queue a worker is registered for in main.rs.
+const QUEUES: &[&str] = &[
+    RenderJob::QUEUE,
+    IngestItemJob::QUEUE,
+    ExportJob::QUEUE,
+    PurgeObjectsJob::QUEUE,
+    RetentionSweep::QUEUE,
+    CompactContext::QUEUE,
+];
+
+/// GET /api/v1/admin/stats/generations
+pub async fn handle_generation_stats(
+    State(state): State<AppState>,
+    Query(query): Query<WindowQuery>,
+) -> Result<Json<GenerationStats>, AppError> {
+    let days = window_days(query.days);
+    let per_day = generations_per_day(&state.db, days).await?;
+    Ok(Json(GenerationStats { days, per_day }))
+}
+
+/// GET /api/v1/admin/stats/llm-tokens
+///
+/// Read from the quota counters, so it covers the current calendar month (UTC).
+pub async fn handle_token_spend(
+    State(state): State<AppState>,
+    Query(query): Query<TopUsersQuery>,
+) -> Result<Json<TokenSpendStats>, AppError> {
+    let usage = quota::usage_by_user(&state.redis, Meter::LlmTokens).await?;
+    let total_tokens = usage.iter().map(|(_, tokens)| tokens).sum();
+    let users_with_spend = usage.len();
+    let top: Vec<(Uuid, i64)> = usage
+        .into_iter()
+        .take(query.limit.unwrap_or(DEFAULT_TOP_USERS))
+        .collect();
+
+    let ids: Vec<Uuid> = top.iter().map(|(id, _)| *id).collect();
+    let emails: HashMap<Uuid, String> =
+        sqlx::query!("SELECT id, email FROM users WHERE id = ANY($1)", &ids)
+            .fetch_all(&state.db)
+            .await?
+            .into_iter()
+            .map(|row| (row.id, row.email))
+            .collect();
+
+    Ok(Json(TokenSpendStats {
+        period: quota::current_period(Utc::now()).key,
+        total_tokens,
+        users_with_spend,
+        users: top
+            .into_iter()
+            .map(|(user_id, tokens)| UserTokenSpend {
+                user_id,
+                email: emails.get(&user_id).cloned(),
+                tokens,
+            })
+            .collect(),
+    }))
+}
+
+/// GET /api/v1/admin/stats/renders
+pub async fn handle_render_stats(
+    State(state): State<AppState>,
+    Query(query): Query<WindowQuery>,
+) -> Result<Json<RenderStats>, AppError> {
+    let days = window_days(query.days);
+    let counts = render_counts(&state.db, days).await?;
+    let failure_rate = rate(counts.failed, counts.done + counts.failed);
+    Ok(Json(RenderStats {
+        days,
+        counts,
+        failure_rate,
+    }))
+}
+
+/// GET /api/v1/admin/stats/flagged-bullets
+pub async fn handle_flag_stats(
+    State(state): State<AppState>,
+    Query(query): Query<WindowQuery>,
+) -> Result<Json<FlagStats>, AppError> {
+    let days = window_days(query.days);
+    let counts = flag_counts(&state.db, days).await?;
+    let flagged_rate = rate(counts.flagged_bullets, counts.bullets);
+    Ok(Json(FlagStats {
+        days,
+        counts,
+        flagged_rate,
+    }))
+}
+
+/// GET /api/v1/admin/queues
+pub async fn handle_queue_depths(
+    State(state): State<AppState>,
+) -> Result<Json<Vec<QueueDepth>>, AppError> {
+    let mut depths = Vec::with_capacity(QUEUES.len());
+    for queue in QUEUES {
+        let depth = jobs::depth(&state.redis, queue).await.map_err(|e| {
+            AppError::Internal(anyhow::anyhow!("Reading queue {queue} failed: {e}"))
+        })?;
+        depths.push(depth);
+    }
+    Ok(Json(depths))
+}
+
+/// POST /api/v1/admin/seed
+pub async fn handle_seed(State(state): State<AppState>) -> Result<Json<SeedReport>, AppError> {
+    Ok(Json(seed::seed_demo_user(&state).await?))
+}
@@ -0,0 +1,286 @@
+//! Admin stats — aggregate numbers for operating the service, across all users.
+//!
+//! GET /api/v1/admin/stats/generations     — resumes generated per day (`days`, default 30)
+//! GET /api/v1/admin/stats/llm-tokens      — this month's LLM token spend per user (`limit`)
+//! GET /api/v1/admin/stats/renders         — render job outcomes and failure rate (`days`)
+//! GET /api/v1/admin/stats/flagged-bullets — share of bullets flagged for review (`days`)
+//! GET /api/v1/admin/queues                — depth of every background job queue
+//! POST /api/v1/admin/seed                  — (re)create the demo account (see `crate::seed`)
+//!
+//! Every route is behind `require_admin`: the caller must be signed in and have
+//! `users.is_admin` set (403 otherwise). Nothing here returns résumé content.
+//!
+//! Types, queries and the guard live here; handlers in handlers.rs.
+
+pub mod handlers;
+
+use axum::{
+    extract::{Request, State},
+    middleware::Next,
+    response::Response,
+};
+use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
+use sqlx::{FromRow, PgPool};
+use uuid::Uuid;
+
+use crate::auth::AuthUser;
+use crate::errors::AppError;
+use crate::state::AppState;
+
+/// Window used when `days` is not given.
+pub const DEFAULT_DAYS: i32 = 30;
+
+/// Longest window a stats query may cover.
+pub const MAX_DAYS: i32 = 365;
+
+/// Users listed by the token spend endpoint when `limit` is not given.
+pub const DEFAULT_TOP_USERS: usize = 50;
+
+// ────────────────────────────────────────────────────────────────────────────
+// Guard
+// ────────────────────────────────────────────────────────────────────────────
+
+/// `route_layer` middleware for the admin router.
+pub async fn require_admin(
+    State(state): State<AppState>,
+    auth: AuthUser,
+    req: Request,
+    next: Next,
+) -> Result<Response, AppError> {
+    let is_admin: Option<bool> =
+        sqlx::query_scalar!("SELECT is_admin FROM users WHERE id = $1", auth.user_id)
+            .fetch_optional(&state.db)
+            .await?;
+    if is_admin != Some(true) {
+        tracing::warn!(user_id = %auth.user_id, path = %req.uri().path(), "non-admin denied");
+        return Err(AppError::Forbidden);
+    }
+    Ok(next.run(req).await)
+}
+
+// ────────────────────────────────────────────────────────────────────────────
+// Request / response types
+// ────────────────────────────────────────────────────────────────────────────
+
+#[derive(Debug, Deserialize)]
+pub struct WindowQuery {
+    /// Days back from today (UTC), today included.
+    pub days: Option<i32>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct TopUsersQuery {
+    pub limit: Option<usize>,
+}
+
+/// One UTC day of generation volume.
+#[derive(Debug, Clone, Serialize, FromRow)]
+pub struct GenerationDay {
+    pub day: NaiveDate,
+    /// New resumes generated from a JD.
+    pub generated: i64,
+    /// Resumes created by regenerating an earlier one.
+    pub regenerated: i64,
+    /// Batch generation jobs that failed.
+    pub failed: i64,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct GenerationStats {
+    pub days: i32,
+    /// Oldest first; days without activity are present with zero counts.
+    pub per_day: Vec<GenerationDay>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct UserTokenSpend {
+    pub user_id: Uuid,
+    /// Absent if the account was deleted this month.
+    pub email: Option<String>,
+    pub tokens: i64,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct TokenSpendStats {
+    /// `YYYY-MM`
+    pub period: String,
+    /// Across every user, not only those listed.
+    pub total_tokens: i64,
+    pub users_with_spend: usize,
+    /// Highest spend first.
+    pub users: Vec<UserTokenSpend>,
+}
+
+/// Render jobs created in the window, by status.
+#[derive(Debug, Clone, Serialize, FromRow)]
+pub struct RenderCounts {
+    pub queued: i64,
+    pub processing: i64,
+    pub done: i64,
+    pub failed: i64,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct RenderStats {
+    pub days: i32,
+    #[serde(flatten)]
+    pub counts: RenderCounts,
+    /// `failed / (done + failed)`; absent when no job has finished.
+    pub failure_rate: Option<f64>,
+}
+
+/// Flag totals over resumes generated in the window that carry a layout report.
+#[derive(Debug, Clone, Serialize, FromRow)]
+pub struct FlagCounts {
+    pub resumes: i64,
+    pub resumes_with_flags: i64,
+    pub bullets: i64,
+    pub flagged_bullets: i64,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct FlagStats {
+    pub days: i32,
+    #[serde(flatten)]
+    pub counts: FlagCounts,
+    /// `flagged_bullets / bullets`
+    pub flagged_rate: Option<f64>,
+}
+
+// ────────────────────────────────────────────────────────────────────────────
+// Helpers
+// ────────────────────────────────────────────────────────────────────────────
+
+/// `days` clamped to `1..=MAX_DAYS`.
+pub fn window_days(days: Option<i32>) -> i32 {
+    days.unwrap_or(DEFAULT_DAYS).clamp(1, MAX_DAYS)
+}
+
+/// `part / whole`, or `None` when `whole` is zero.
+pub fn rate(part: i64, whole: i64) -> Option<f64> {
+    (whole > 0).then(|| part as f64 / whole as f64)
+}
+
+// ────────────────────────────────────────────────────────────────────────────
+// Queries
+// ────────────────────────────────────────────────────────────────────────────
+
+/// Volume stats count soft-deleted resumes too: they were still generated.
+pub async fn generations_per_day(db: &PgPool, days: i32) -> Result<Vec<GenerationDay>, AppError> {
+    let rows = sqlx::query_as!(
+        GenerationDay,
+        r#"WITH days AS (
+               SELECT generate_series(
+                   (NOW() AT TIME ZONE 'UTC')::date - ($1 - 1),
+                   (NOW() AT TIME ZONE 'UTC')::date,
+                   INTERVAL '1 day'
+               )::date AS day
+           ),
+           resumes_by_day AS (
+               SELECT (created_at AT TIME ZONE 'UTC')::date AS day,
+                      COUNT(*) FILTER (WHERE parent_resume_id IS NULL) AS generated,
+                      COUNT(*) FILTER (WHERE parent_resume_id IS NOT NULL) AS regenerated
+               FROM resumes
+               WHERE created_at >= NOW() - make_interval(days => $1)
+               GROUP BY 1
+           ),
+           failures_by_day AS (
+               SELECT (updated_at AT TIME ZONE 'UTC')::date AS day, COUNT(*) AS failed
+               FROM generation_jobs
+               WHERE status = 'failed' AND updated_at >= NOW() - make_interval(days => $1)
+               GROUP BY 1
+           )
+           SELECT d.day AS "day!",
+                  COALESCE(r.generated, 0) AS "generated!",
+                  COALESCE(r.regenerated, 0) AS "regenerated!",
+                  COALESCE(f.failed, 0) AS "failed!"
+           FROM days d
+           LEFT JOIN resumes_by_day r ON r.day = d.day
+           LEFT JOIN failures_by_day f ON f.day = d.day
+           ORDER BY d.day"#,
+        days
+    )
+    .fetch_all(db)
+    .await?;
+    Ok(rows)
+}
+
+pub async fn render_counts(db: &PgPool, days: i32) -> Result<RenderCounts, AppError> {
+    let counts = sqlx::query_as!(
+        RenderCounts,
+        r#"SELECT COUNT(*) FILTER (WHERE status = 'queued') AS "queued!",
+                  COUNT(*) FILTER (WHERE status = 'processing') AS "processing!",
+                  COUNT(*) FILTER (WHERE status = 'done') AS "done!",
+                  COUNT(*) FILTER (WHERE status = 'failed') AS "failed!"
+           FROM render_jobs
+           WHERE created_at >= NOW() - make_interval(days => $1)"#,
+        days
+    )
+    .fetch_one(db)
+    .await?;
+    Ok(counts)
+}
+
+pub async fn flag_counts(db: &PgPool, days: i32) -> Result<FlagCounts, AppError> {
+    let counts = sqlx::query_as!(
+        FlagCounts,
+        r#"SELECT COUNT(*) AS "resumes!",
+                  COUNT(*) FILTER (WHERE (r.layout_report->>'flagged_count')::int > 0)
+                      AS "resumes_with_flags!",
+                  COALESCE(SUM(b.bullets), 0)::BIGINT AS "bullets!",
+                  COALESCE(SUM((r.layout_report->>'flagged_count')::bigint), 0)::BIGINT
+                      AS "flagged_bullets!"
+           FROM resumes r
+           LEFT JOIN LATERAL (
+               SELECT COUNT(*) AS bullets FROM resume_bullets WHERE resume_id = r.id
+           ) b ON TRUE
+           WHERE r.layout_report IS NOT NULL
+             AND r.created_at >= NOW() - make_interval(days => $1)"#,
+        days
+    )
+    .fetch_one(db)
+    .await?;
+    Ok(counts)
+}
+
+// ────────────────────────────────────────────────────────────────────────────
+// Tests
+// ────────────────────────────────────────────────────────────────────────────
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_window_days_is_clamped() {
+        assert_eq!(window_days(None), DEFAULT_DAYS);
+        assert_eq!(window_days(Some(7)), 7);
+        assert_eq!(window_days(Some(0)), 1);
+        assert_eq!(window_days(Some(-5)), 1);
+        assert_eq!(window_days(Some(10_000)), MAX_DAYS);
+    }
+
+    #[test]
+    fn test_rate_is_absent_without_a_denominator() {
+        assert_eq!(rate(0, 0), None);
+        assert_eq!(rate(1, 4), Some(0.25));
+    }
+
+    #[test]
+    fn test_render_stats_serialize_flat() {
+        let stats = RenderStats {
+            days: 7,
+            counts: RenderCounts {
+                queued: 1,
+                processing: 0,
+                done: 9,
+                failed: 1,
+            },
+            failure_rate: rate(1, 10),
+        };
+        let json = serde_json::to_value(&stats).unwrap();
+        assert_eq!(json["done"], 9);
+        assert_eq!(json["failure_rate"], 0.1);
+    }
+}
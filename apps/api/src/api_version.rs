@@ -0,0 +1,172 @@
+//! API versioning — `/api/v1` and `/api/v2` served from one route table.
+//!
+//! `routes::api_routes` is nested under both prefixes, each with its own middleware:
+//! - v1 responses are unchanged but carry `Deprecation: true`, a
+//!   `Link: </api/v2/...>; rel="successor-version"` header naming the same route on
+//!   v2, and `Sunset` once `API_V1_SUNSET` is configured.
+//! - v2 wraps every JSON body in a version-tagged envelope:
+//!   `{ "api_version": "v2", "data": ... }` on success and
+//!   `{ "api_version": "v2", "error": { "code", "message", ... } }` on failure.
+//!   Non-JSON responses (PDFs, SSE streams, 204s) pass through untouched.
+//!
+//! Both set `API-Version`. Breaking changes land on v2 only: register the new handler
+//! on the v2 tree in `routes` and leave v1 on the old one until it is retired.
+
+use axum::{
+    body::{to_bytes, Body},
+    extract::{Request, State},
+    http::{
+        header::{CONTENT_LENGTH, CONTENT_TYPE, LINK},
+        HeaderName, HeaderValue, StatusCode,
+    },
+    middleware::Next,
+    response::Response,
+};
+use chrono::NaiveDate;
+use serde_json::{json, Value};
+
+use crate::state::AppState;
+
+pub const API_VERSION_HEADER: HeaderName = HeaderName::from_static("api-version");
+pub const DEPRECATION_HEADER: HeaderName = HeaderName::from_static("deprecation");
+pub const SUNSET_HEADER: HeaderName = HeaderName::from_static("sunset");
+
+pub const V1_PREFIX: &str = "/api/v1";
+pub const V2_PREFIX: &str = "/api/v2";
+
+/// Largest response body the v2 envelope will buffer; bigger JSON passes through
+/// unwrapped rather than being held in memory twice.
+const MAX_ENVELOPE_BYTES: usize = 16 * 1024 * 1024;
+
+// ────────────────────────────────────────────────────────────────────────────
+// v1: deprecation headers
+// ────────────────────────────────────────────────────────────────────────────
+
+/// Middleware for the v1 tree.
+pub async fn deprecate_v1(State(state): State<AppState>, req: Request, next: Next) -> Response {
+    let successor = successor_link(req.uri().path());
+    let mut response = next.run(req).await;
+    let headers = response.headers_mut();
+    headers.insert(API_VERSION_HEADER, HeaderValue::from_static("v1"));
+    headers.insert(DEPRECATION_HEADER, HeaderValue::from_static("true"));
+    if let Some(link) = successor.and_then(|l| HeaderValue::from_str(&l).ok()) {
+        headers.insert(LINK, link);
+    }
+    if let Some(sunset) = state.config.server.v1_sunset {
+        let value = HeaderValue::from_str(&http_date(sunset)).expect("formatted date is ASCII");
+        headers.insert(SUNSET_HEADER, value);
+    }
+    response
+}
+
+/// `Link` value pointing a v1 path at its v2 equivalent.
+fn successor_link(path: &str) -> Option<String> {
+    let rest = path.strip_prefix(V1_PREFIX)?;
+    Some(format!("<{V2_PREFIX}{rest}>; rel=\"successor-version\""))
+}
+
+/// Midnight UTC on `date` as an HTTP-date (RFC 9110), e.g. `Wed, 30 Jun 2027 00:00:00 GMT`.
+fn http_date(date: NaiveDate) -> String {
+    date.format("%a, %d %b %Y 00:00:00 GMT").to_string()
+}
+
+// ────────────────────────────────────────────────────────────────────────────
+// v2: response envelope
+// ────────────────────────────────────────────────────────────────────────────
+
+/// Middleware for the v2 tree.
+pub async fn envelope_v2(req: Request, next: Next) -> Response {
+    let response = next.run(req).await;
+    let mut response = if is_json(&response) {
+        wrap(response).await
+    } else {
+        response
+    };
+    response
+        .headers_mut()
+        .insert(API_VERSION_HEADER, HeaderValue::from_static("v2"));
+    response
+}
+
+fn is_json(response: &Response) -> bool {
+    response
+        .headers()
+        .get(CONTENT_TYPE)
+        .and_then(|v| v.to_str().ok())
+        .is_some_and(|v| v.starts_with("application/json"))
+}
+
+async fn wrap(response: Response) -> Response {
+    let (mut parts, body) = response.into_parts();
+    let bytes = match to_bytes(body, MAX_ENVELOPE_BYTES).await {
+        Ok(bytes) => bytes,
+        Err(e) => {
+            tracing::error!(error = %e, "could not buffer response for the v2 envelope");
+            parts.status = StatusCode::INTERNAL_SERVER_ERROR;
+            parts.headers.remove(CONTENT_LENGTH);
+            return Response::from_parts(parts, Body::empty());
+        }
+    };
+    let Ok(value) = serde_json::from_slice::<Value>(&bytes) else {
+        return Response::from_parts(parts, Body::from(bytes));
+    };
+    let wrapped = envelope(parts.status, value);
+    parts.headers.remove(CONTENT_LENGTH);
+    Response::from_parts(parts, Body::from(wrapped.to_string()))
+}
+
+/// The v2 body for a response with `status` and JSON `body`. Errors are already
+/// `{ "error": {...} }` (see `AppError`); the inner object is kept as-is.
+pub fn envelope(status: StatusCode, body: Value) -> Value {
+    if status.is_client_error() || status.is_server_error() {
+        let error = match body {
+            Value::Object(mut map) if map.contains_key("error") => map.remove("error"),
+            other => Some(other),
+        };
+        json!({ "api_version": "v2", "error": error })
+    } else {
+        json!({ "api_version": "v2", "data": body })
+    }
+}
+
+// ────────────────────────────────────────────────────────────────────────────
+// Tests
+// ────────────────────────────────────────────────────────────────────────────
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_successor_link_swaps_the_prefix() {
+        assert_eq!(
+            successor_link("/api/v1/resumes/42/audit").unwrap(),
+            "</api/v2/resumes/42/audit>; rel=\"successor-version\""
+        );
+        assert_eq!(successor_link("/health"), None);
+    }
+
+    #[test]
+    fn test_sunset_is_an_http_date() {
+        let date = NaiveDate::from_ymd_opt(2027, 6, 30).unwrap();
+        assert_eq!(http_date(date), "Wed, 30 Jun 2027 00:00:00 GMT");
+    }
+
+    #[test]
+    fn test_envelope_tags_success_and_error_bodies() {
+        let ok = envelope(StatusCode::OK, json!({ "resume_id": "r1" }));
+        assert_eq!(ok["api_version"], "v2");
+        assert_eq!(ok["data"]["resume_id"], "r1");
+        assert!(ok.get("error").is_none());
+
+        let err = envelope(
+            StatusCode::NOT_FOUND,
+            json!({ "error": { "code": "NOT_FOUND", "message": "gone" } }),
+        );
+        assert_eq!(err["error"]["code"], "NOT_FOUND");
+        assert!(err.get("data").is_none());
+
+        let list = envelope(StatusCode::OK, json!([1, 2]));
+        assert_eq!(list["data"], json!([1, 2]));
+    }
+}
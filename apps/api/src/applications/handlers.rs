@@ -0,0 +1,221 @@
+//! Axum handlers for the application tracker.
+//!
+//! GET    /api/v1/applications      — list the caller's applications (?status=, ?resume_id=)
+//! POST   /api/v1/applications      — record a new application
+//! GET    /api/v1/applications/:id  — fetch one application
+//! PATCH  /api/v1/applications/:id  — partial update, including status changes
+//! DELETE /api/v1/applications/:id  — hard delete
+
+use axum::{
+    extract::{Path, Query, State},
+    http::StatusCode,
+    response::IntoResponse,
+    Json,
+};
+use uuid::Uuid;
+
+use crate::applications::{
+    ApplicationListQuery, ApplicationRow, ApplicationStatus, CreateApplicationRequest,
+    UpdateApplicationRequest,
+};
+use crate::audit::{self, Action, AuditEvent};
+use crate::auth::{AuthUser, Resource};
+use crate::errors::AppError;
+use crate::state::AppState;
+use crate::validation::ValidJson;
+
+// ────────────────────────────────────────────────────────────────────────────
+// GET /api/v1/applications
+// ────────────────────────────────────────────────────────────────────────────
+
+/// Returns the caller's applications, most recently updated first, optionally
+/// filtered by status and/or resume.
+pub async fn handle_list_applications(
+    State(state): State<AppState>,
+    auth: AuthUser,
+    Query(query): Query<ApplicationListQuery>,
+) -> Result<impl IntoResponse, AppError> {
+    let applications = sqlx::query_as!(
+        ApplicationRow,
+        r#"SELECT * FROM applications
+           WHERE user_id = $1
+             AND ($2::text IS NULL OR status = $2)
+             AND ($3::uuid IS NULL OR resume_id = $3)
+           ORDER BY updated_at DESC"#,
+        auth.user_id,
+        query.status.map(ApplicationStatus::as_str),
+        query.resume_id
+    )
+    .fetch_all(&state.db)
+    .await?;
+
+    Ok(Json(serde_json::json!({ "applications": applications })))
+}
+
+// ────────────────────────────────────────────────────────────────────────────
+// POST /api/v1/applications
+// ────────────────────────────────────────────────────────────────────────────
+
+/// Records an application. A `resume_id` must be one of the caller's resumes.
+pub async fn handle_create_application(
+    State(state): State<AppState>,
+    auth: AuthUser,
+    ValidJson(body): ValidJson<CreateApplicationRequest>,
+) -> Result<impl IntoResponse, AppError> {
+    if let Some(resume_id) = body.resume_id {
+        auth.authorize(&state.db, Resource::Resume, resume_id)
+            .await?;
+    }
+
+    let application = sqlx::query_as!(
+        ApplicationRow,
+        r#"INSERT INTO applications
+               (user_id, company, role, jd_reference, resume_id, status, applied_on, notes)
+           VALUES ($1, $2, $3, $4, $5, $6, COALESCE($7, CURRENT_DATE), $8)
+           RETURNING *"#,
+        auth.user_id,
+        body.company.trim(),
+        body.role.trim(),
+        body.jd_reference.as_deref(),
+        body.resume_id,
+        body.status.unwrap_or(ApplicationStatus::Applied).as_str(),
+        body.applied_on,
+        body.notes.as_deref()
+    )
+    .fetch_one(&state.db)
+    .await?;
+    audit::record(
+        &state.db,
+        auth.user_id,
+        AuditEvent::new(Action::ApplicationCreate, application.id)
+            .after(application_ids(&application)),
+    )
+    .await;
+
+    Ok((StatusCode::CREATED, Json(application)))
+}
+
+// ────────────────────────────────────────────────────────────────────────────
+// GET /api/v1/applications/:id
+// ────────────────────────────────────────────────────────────────────────────
+
+/// Returns a single application by ID.
+pub async fn handle_get_application(
+    Path(id): Path<Uuid>,
+    State(state): State<AppState>,
+    auth: AuthUser,
+) -> Result<impl IntoResponse, AppError> {
+    auth.authorize(&state.db, Resource::Application, id).await?;
+    Ok(Json(load_application(&state, id).await?))
+}
+
+// ────────────────────────────────────────────────────────────────────────────
+// PATCH /api/v1/applications/:id
+// ────────────────────────────────────────────────────────────────────────────
+
+/// Partially updates an application. `status_changed_at` moves only when the status
+/// actually changes, so it dates the latest stage.
+pub async fn handle_update_application(
+    Path(id): Path<Uuid>,
+    State(state): State<AppState>,
+    auth: AuthUser,
+    ValidJson(body): ValidJson<UpdateApplicationRequest>,
+) -> Result<impl IntoResponse, AppError> {
+    auth.authorize(&state.db, Resource::Application, id).await?;
+    let before = load_application(&state, id).await?;
+    if let Some(resume_id) = body.resume_id {
+        auth.authorize(&state.db, Resource::Resume, resume_id)
+            .await?;
+    }
+
+    let application = sqlx::query_as!(
+        ApplicationRow,
+        r#"UPDATE applications
+           SET company           = COALESCE($2, company),
+               role              = COALESCE($3, role),
+               jd_reference      = COALESCE($4, jd_reference),
+               resume_id         = COALESCE($5, resume_id),
+               status_changed_at = CASE WHEN $6::text IS NOT NULL AND $6 <> status
+                                        THEN NOW() ELSE status_changed_at END,
+               status            = COALESCE($6, status),
+               applied_on        = COALESCE($7, applied_on),
+               notes             = COALESCE($8, notes),
+               updated_at        = NOW()
+           WHERE id = $1
+           RETURNING *"#,
+        id,
+        body.company.as_deref().map(str::trim),
+        body.role.as_deref().map(str::trim),
+        body.jd_reference.as_deref(),
+        body.resume_id,
+        body.status.map(ApplicationStatus::as_str),
+        body.applied_on,
+        body.notes.as_deref()
+    )
+    .fetch_optional(&state.db)
+    .await?
+    .ok_or(AppError::NotFound(format!("Application {id} not found")))?;
+    audit::record(
+        &state.db,
+        auth.user_id,
+        AuditEvent::new(Action::ApplicationUpdate, id)
+            .before(application_ids(&before))
+            .after(application_ids(&application)),
+    )
+    .await;
+
+    Ok(Json(application))
+}
+
+// ────────────────────────────────────────────────────────────────────────────
+// DELETE /api/v1/applications/:id
+// ────────────────────────────────────────────────────────────────────────────
+
+/// Hard-deletes an application. The linked resume is untouched.
+pub async fn handle_delete_application(
+    Path(id): Path<Uuid>,
+    State(state): State<AppState>,
+    auth: AuthUser,
+) -> Result<impl IntoResponse, AppError> {
+    auth.authorize(&state.db, Resource::Application, id).await?;
+
+    let rows = sqlx::query!("DELETE FROM applications WHERE id = $1", id)
+        .execute(&state.db)
+        .await?
+        .rows_affected();
+    if rows == 0 {
+        return Err(AppError::NotFound(format!("Application {id} not found")));
+    }
+    audit::record(
+        &state.db,
+        auth.user_id,
+        AuditEvent::new(Action::ApplicationDelete, id),
+    )
+    .await;
+
+    Ok(StatusCode::NO_CONTENT)
+}
+
+// ────────────────────────────────────────────────────────────────────────────
+// Helpers
+// ────────────────────────────────────────────────────────────────────────────
+
+async fn load_application(state: &AppState, id: Uuid) -> Result<ApplicationRow, AppError> {
+    sqlx::query_as!(
+        ApplicationRow,
+        "SELECT * FROM applications WHERE id = $1",
+        id
+    )
+    .fetch_optional(&state.db)
+    .await?
+    .ok_or(AppError::NotFound(format!("Application {id} not found")))
+}
+
+/// The resume link and stage, for the audit log — company, role and notes stay out
+/// of it.
+fn application_ids(application: &ApplicationRow) -> serde_json::Value {
+    serde_json::json!({
+        "resume_id": application.resume_id,
+        "status": application.status,
+    })
+}
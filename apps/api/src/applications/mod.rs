@@ -0,0 +1,158 @@
+//! Applications module — the job application tracker.
+//!
+//! An application records where the user applied (company, role, a reference to the
+//! posting), which generated resume they sent, and how far it got. Linking resumes to
+//! outcomes is what lets later analytics learn which resumes convert.
+//!
+//! Deleting (or purging) the resume leaves the application in place with
+//! `resume_id = NULL`.
+//!
+//! Types live here; handlers in handlers.rs.
+
+pub mod handlers;
+
+use chrono::{DateTime, NaiveDate, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+use uuid::Uuid;
+use validator::Validate;
+
+use crate::validation::{not_blank, MAX_NAME_CHARS};
+
+/// Longest accepted `jd_reference` (a URL or requisition id).
+pub const MAX_JD_REFERENCE_CHARS: u64 = 2_000;
+/// Longest accepted `notes`.
+pub const MAX_NOTES_CHARS: u64 = 10_000;
+
+// ────────────────────────────────────────────────────────────────────────────
+// Status
+// ────────────────────────────────────────────────────────────────────────────
+
+/// How far an application got. Any status may follow any other — a rejection can
+/// come at every stage, and users correct mistakes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ApplicationStatus {
+    Applied,
+    Screen,
+    Interview,
+    Offer,
+    Rejected,
+}
+
+impl ApplicationStatus {
+    /// The value stored in `applications.status`.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            ApplicationStatus::Applied => "applied",
+            ApplicationStatus::Screen => "screen",
+            ApplicationStatus::Interview => "interview",
+            ApplicationStatus::Offer => "offer",
+            ApplicationStatus::Rejected => "rejected",
+        }
+    }
+}
+
+// ────────────────────────────────────────────────────────────────────────────
+// DB row type
+// ────────────────────────────────────────────────────────────────────────────
+
+/// A row from the `applications` table.
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
+pub struct ApplicationRow {
+    pub id: Uuid,
+    pub user_id: Uuid,
+    pub company: String,
+    pub role: String,
+    pub jd_reference: Option<String>,
+    pub resume_id: Option<Uuid>,
+    /// One of `ApplicationStatus::as_str`.
+    pub status: String,
+    pub applied_on: NaiveDate,
+    pub status_changed_at: DateTime<Utc>,
+    pub notes: Option<String>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+// ────────────────────────────────────────────────────────────────────────────
+// Request / response types
+// ────────────────────────────────────────────────────────────────────────────
+
+/// Body for `POST /api/v1/applications`.
+#[derive(Debug, Deserialize, Validate)]
+pub struct CreateApplicationRequest {
+    #[validate(custom(function = "not_blank"), length(max = MAX_NAME_CHARS))]
+    pub company: String,
+    #[validate(custom(function = "not_blank"), length(max = MAX_NAME_CHARS))]
+    pub role: String,
+    #[validate(length(max = MAX_JD_REFERENCE_CHARS))]
+    pub jd_reference: Option<String>,
+    /// Must be one of the caller's resumes.
+    pub resume_id: Option<Uuid>,
+    /// Defaults to `applied`.
+    pub status: Option<ApplicationStatus>,
+    /// Defaults to today (UTC).
+    pub applied_on: Option<NaiveDate>,
+    #[validate(length(max = MAX_NOTES_CHARS))]
+    pub notes: Option<String>,
+}
+
+/// Body for `PATCH /api/v1/applications/:id` — all fields are optional; absent
+/// fields are left unchanged.
+#[derive(Debug, Deserialize, Validate)]
+pub struct UpdateApplicationRequest {
+    #[validate(custom(function = "not_blank"), length(max = MAX_NAME_CHARS))]
+    pub company: Option<String>,
+    #[validate(custom(function = "not_blank"), length(max = MAX_NAME_CHARS))]
+    pub role: Option<String>,
+    #[validate(length(max = MAX_JD_REFERENCE_CHARS))]
+    pub jd_reference: Option<String>,
+    pub resume_id: Option<Uuid>,
+    pub status: Option<ApplicationStatus>,
+    pub applied_on: Option<NaiveDate>,
+    #[validate(length(max = MAX_NOTES_CHARS))]
+    pub notes: Option<String>,
+}
+
+/// Query for `GET /api/v1/applications`.
+#[derive(Debug, Default, Deserialize)]
+pub struct ApplicationListQuery {
+    pub status: Option<ApplicationStatus>,
+    /// Only applications that sent this resume.
+    pub resume_id: Option<Uuid>,
+}
+
+// ────────────────────────────────────────────────────────────────────────────
+// Tests
+// ────────────────────────────────────────────────────────────────────────────
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_status_json_matches_stored_value() {
+        for status in [
+            ApplicationStatus::Applied,
+            ApplicationStatus::Screen,
+            ApplicationStatus::Interview,
+            ApplicationStatus::Offer,
+            ApplicationStatus::Rejected,
+        ] {
+            let json = serde_json::to_value(status).unwrap();
+            assert_eq!(json, status.as_str());
+        }
+        assert!(serde_json::from_str::<ApplicationStatus>(r#""ghosted""#).is_err());
+    }
+
+    #[test]
+    fn test_create_request_requires_company_and_role() {
+        let body: CreateApplicationRequest =
+            serde_json::from_str(r#"{"company": "Acme", "role": "  "}"#).unwrap();
+        let errors = body.validate().unwrap_err();
+        assert!(errors.field_errors().contains_key("role"));
+        assert!(!errors.field_errors().contains_key("company"));
+        assert!(body.status.is_none() && body.applied_on.is_none());
+    }
+}
@@ -0,0 +1,67 @@
+//! Axum handler for reading the caller's audit log.
+
+use axum::{
+    extract::{Query, State},
+    Json,
+};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::audit::{list_for_user, Action, AuditFilter, AuditLogRow};
+use crate::auth::AuthUser;
+use crate::errors::AppError;
+use crate::state::AppState;
+
+const DEFAULT_PAGE_SIZE: i64 = 50;
+
+#[derive(Debug, Deserialize)]
+pub struct AuditQuery {
+    /// e.g. `context_entry.edit`
+    pub action: Option<String>,
+    pub resource_id: Option<Uuid>,
+    /// Cursor: `next_before` from the previous page.
+    pub before: Option<DateTime<Utc>>,
+    pub limit: Option<i64>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct AuditLogResponse {
+    pub entries: Vec<AuditLogRow>,
+    /// Pass as `before` to fetch the next page; absent on the last page.
+    pub next_before: Option<DateTime<Utc>>,
+}
+
+/// GET /api/v1/users/me/audit
+///
+/// The caller's audit entries, newest first. Filter with `action` and `resource_id`
+/// (e.g. every change to one context entry); page with `limit` (≤ 200) and `before`.
+pub async fn handle_get_audit_log(
+    State(state): State<AppState>,
+    auth: AuthUser,
+    Query(query): Query<AuditQuery>,
+) -> Result<Json<AuditLogResponse>, AppError> {
+    let action = query
+        .action
+        .as_deref()
+        .map(|a| {
+            Action::parse(a).ok_or_else(|| AppError::Validation(format!("Unknown action '{a}'")))
+        })
+        .transpose()?;
+    let filter = AuditFilter {
+        action,
+        resource_id: query.resource_id,
+        before: query.before,
+        limit: query.limit.unwrap_or(DEFAULT_PAGE_SIZE),
+    };
+
+    let entries = list_for_user(&state.db, auth.user_id, &filter).await?;
+    let full_page = entries.len() as i64 == filter.limit.clamp(1, super::MAX_PAGE_SIZE);
+    let next_before = full_page
+        .then(|| entries.last().map(|e| e.created_at))
+        .flatten();
+    Ok(Json(AuditLogResponse {
+        entries,
+        next_before,
+    }))
+}
@@ -0,0 +1,371 @@
+//! Audit log — who did what to which resource, queryable per user.
+//!
+//! GET /api/v1/users/me/audit — the caller's audit entries, newest first
+//!
+//! Handlers call `record` after a mutation succeeds. Entries carry identifiers, not
+//! content: `before`/`after` hold ids and versions (e.g. `{"version": 3}` →
+//! `{"version": 4}` for an entry edit) so an entry can be traced back to the rows it
+//! touched without copying résumé text into a second table.
+//!
+//! Recording is best-effort: the mutation has already committed, so a failed insert
+//! is logged rather than turned into an error response.
+
+pub mod handlers;
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::{FromRow, PgPool};
+use uuid::Uuid;
+
+use crate::errors::AppError;
+
+/// Largest page `list_for_user` returns.
+pub const MAX_PAGE_SIZE: i64 = 200;
+
+// ────────────────────────────────────────────────────────────────────────────
+// Actions
+// ────────────────────────────────────────────────────────────────────────────
+
+/// A recorded operation. Serialized (and stored) as `resource.verb`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Action {
+    #[serde(rename = "context_entry.ingest")]
+    ContextIngest,
+    #[serde(rename = "context_entry.edit")]
+    ContextEntryEdit,
+    #[serde(rename = "context_entry.evergreen")]
+    ContextEvergreenToggle,
+    #[serde(rename = "context_entry.retag")]
+    ContextRetag,
+    #[serde(rename = "ingest_batch.create")]
+    IngestBatchCreate,
+    #[serde(rename = "resume.generate")]
+    ResumeGenerate,
+    #[serde(rename = "resume.regenerate")]
+    ResumeRegenerate,
+    #[serde(rename = "resume.reorder_bullets")]
+    ResumeReorderBullets,
+    #[serde(rename = "generation_batch.create")]
+    GenerationBatchCreate,
+    #[serde(rename = "render_job.create")]
+    RenderJobCreate,
+    #[serde(rename = "project.create")]
+    ProjectCreate,
+    #[serde(rename = "project.update")]
+    ProjectUpdate,
+    #[serde(rename = "project.delete")]
+    ProjectDelete,
+    #[serde(rename = "user.signup")]
+    UserSignup,
+    #[serde(rename = "user.update")]
+    UserUpdate,
+    #[serde(rename = "user.preferences_update")]
+    UserPreferencesUpdate,
+    #[serde(rename = "org.create")]
+    OrgCreate,
+    /// A member added, or their role changed.
+    #[serde(rename = "org.member_update")]
+    OrgMemberUpdate,
+    #[serde(rename = "org.member_remove")]
+    OrgMemberRemove,
+    #[serde(rename = "resume.annotate")]
+    ResumeAnnotate,
+    /// Soft delete; the row is purged later by `retention`.
+    #[serde(rename = "resume.delete")]
+    ResumeDelete,
+    /// Soft delete of one context snapshot; `resource_id` is the snapshot id.
+    #[serde(rename = "context_snapshot.delete")]
+    ContextSnapshotDelete,
+    #[serde(rename = "application.create")]
+    ApplicationCreate,
+    /// Any field change; before/after carry the resume link and status.
+    #[serde(rename = "application.update")]
+    ApplicationUpdate,
+    #[serde(rename = "application.delete")]
+    ApplicationDelete,
+    #[serde(rename = "persona.create")]
+    PersonaCreate,
+    #[serde(rename = "persona.update")]
+    PersonaUpdate,
+    #[serde(rename = "persona.delete")]
+    PersonaDelete,
+    #[serde(rename = "webhook.create")]
+    WebhookCreate,
+    /// Events or URL changed; the URL itself is never logged.
+    #[serde(rename = "webhook.update")]
+    WebhookUpdate,
+    #[serde(rename = "webhook.delete")]
+    WebhookDelete,
+    /// A public read-only link to a resume; `resource_id` is the share id.
+    #[serde(rename = "resume_share.create")]
+    ResumeShareCreate,
+    #[serde(rename = "resume_share.revoke")]
+    ResumeShareRevoke,
+    /// A review comment on a bullet; `resource_id` is the comment id.
+    #[serde(rename = "resume_comment.create")]
+    ResumeCommentCreate,
+    /// A thread resolved or reopened; `resource_id` is its first comment.
+    #[serde(rename = "resume_comment.resolve")]
+    ResumeCommentResolve,
+    /// A saved job description; autosaves are not logged.
+    #[serde(rename = "target_role.create")]
+    TargetRoleCreate,
+    #[serde(rename = "target_role.delete")]
+    TargetRoleDelete,
+    /// An earlier revision made current again; `resource_id` is the bullet id.
+    #[serde(rename = "resume_bullet.restore")]
+    ResumeBulletRestore,
+}
+
+impl Action {
+    pub const ALL: [Action; 38] = [
+        Action::ContextIngest,
+        Action::ContextEntryEdit,
+        Action::ContextEvergreenToggle,
+        Action::ContextRetag,
+        Action::IngestBatchCreate,
+        Action::ResumeGenerate,
+        Action::ResumeRegenerate,
+        Action::ResumeReorderBullets,
+        Action::GenerationBatchCreate,
+        Action::RenderJobCreate,
+        Action::ProjectCreate,
+        Action::ProjectUpdate,
+        Action::ProjectDelete,
+        Action::UserSignup,
+        Action::UserUpdate,
+        Action::UserPreferencesUpdate,
+        Action::OrgCreate,
+        Action::OrgMemberUpdate,
+        Action::OrgMemberRemove,
+        Action::ResumeAnnotate,
+        Action::ResumeDelete,
+        Action::ContextSnapshotDelete,
+        Action::ApplicationCreate,
+        Action::ApplicationUpdate,
+        Action::ApplicationDelete,
+        Action::PersonaCreate,
+        Action::PersonaUpdate,
+        Action::PersonaDelete,
+        Action::WebhookCreate,
+        Action::WebhookUpdate,
+        Action::WebhookDelete,
+        Action::ResumeShareCreate,
+        Action::ResumeShareRevoke,
+        Action::ResumeCommentCreate,
+        Action::ResumeCommentResolve,
+        Action::TargetRoleCreate,
+        Action::TargetRoleDelete,
+        Action::ResumeBulletRestore,
+    ];
+
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Action::ContextIngest => "context_entry.ingest",
+            Action::ContextEntryEdit => "context_entry.edit",
+            Action::ContextEvergreenToggle => "context_entry.evergreen",
+            Action::ContextRetag => "context_entry.retag",
+            Action::IngestBatchCreate => "ingest_batch.create",
+            Action::ResumeGenerate => "resume.generate",
+            Action::ResumeRegenerate => "resume.regenerate",
+            Action::ResumeReorderBullets => "resume.reorder_bullets",
+            Action::GenerationBatchCreate => "generation_batch.create",
+            Action::RenderJobCreate => "render_job.create",
+            Action::ProjectCreate => "project.create",
+            Action::ProjectUpdate => "project.update",
+            Action::ProjectDelete => "project.delete",
+            Action::UserSignup => "user.signup",
+            Action::UserUpdate => "user.update",
+            Action::UserPreferencesUpdate => "user.preferences_update",
+            Action::OrgCreate => "org.create",
+            Action::OrgMemberUpdate => "org.member_update",
+            Action::OrgMemberRemove => "org.member_remove",
+            Action::ResumeAnnotate => "resume.annotate",
+            Action::ResumeDelete => "resume.delete",
+            Action::ContextSnapshotDelete => "context_snapshot.delete",
+            Action::ApplicationCreate => "application.create",
+            Action::ApplicationUpdate => "application.update",
+            Action::ApplicationDelete => "application.delete",
+            Action::PersonaCreate => "persona.create",
+            Action::PersonaUpdate => "persona.update",
+            Action::PersonaDelete => "persona.delete",
+            Action::WebhookCreate => "webhook.create",
+            Action::WebhookUpdate => "webhook.update",
+            Action::WebhookDelete => "webhook.delete",
+            Action::ResumeShareCreate => "resume_share.create",
+            Action::ResumeShareRevoke => "resume_share.revoke",
+            Action::ResumeCommentCreate => "resume_comment.create",
+            Action::ResumeCommentResolve => "resume_comment.resolve",
+            Action::TargetRoleCreate => "target_role.create",
+            Action::TargetRoleDelete => "target_role.delete",
+            Action::ResumeBulletRestore => "resume_bullet.restore",
+        }
+    }
+
+    /// The kind of row `resource_id` refers to — the part before the dot.
+    pub fn resource_type(self) -> &'static str {
+        self.as_str()
+            .split_once('.')
+            .map(|(resource, _)| resource)
+            .unwrap_or_default()
+    }
+
+    pub fn parse(s: &str) -> Option<Action> {
+        Action::ALL.into_iter().find(|a| a.as_str() == s)
+    }
+}
+
+// ────────────────────────────────────────────────────────────────────────────
+// Events and rows
+// ────────────────────────────────────────────────────────────────────────────
+
+/// One operation to record. Build with `AuditEvent::new(..).before(..).after(..)`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct AuditEvent {
+    pub action: Action,
+    pub resource_id: Option<Uuid>,
+    pub before: Option<serde_json::Value>,
+    pub after: Option<serde_json::Value>,
+}
+
+impl AuditEvent {
+    pub fn new(action: Action, resource_id: impl Into<Option<Uuid>>) -> Self {
+        AuditEvent {
+            action,
+            resource_id: resource_id.into(),
+            before: None,
+            after: None,
+        }
+    }
+
+    pub fn before(mut self, ids: serde_json::Value) -> Self {
+        self.before = Some(ids);
+        self
+    }
+
+    pub fn after(mut self, ids: serde_json::Value) -> Self {
+        self.after = Some(ids);
+        self
+    }
+}
+
+/// A row from the `audit_log` table.
+#[derive(Debug, Clone, Serialize, FromRow)]
+pub struct AuditLogRow {
+    pub id: Uuid,
+    pub user_id: Uuid,
+    pub action: String,
+    pub resource_type: String,
+    pub resource_id: Option<Uuid>,
+    pub before: Option<serde_json::Value>,
+    pub after: Option<serde_json::Value>,
+    /// The `X-Request-Id` of the request that made the change.
+    pub request_id: Option<String>,
+    pub created_at: DateTime<Utc>,
+}
+
+// ────────────────────────────────────────────────────────────────────────────
+// Recording and querying
+// ────────────────────────────────────────────────────────────────────────────
+
+/// Records `event` as done by `user_id`, tagged with the current request id.
+pub async fn record(db: &PgPool, user_id: Uuid, event: AuditEvent) {
+    let result = sqlx::query!(
+        r#"INSERT INTO audit_log
+               (user_id, action, resource_type, resource_id, before, after, request_id)
+           VALUES ($1, $2, $3, $4, $5, $6, $7)"#,
+        user_id,
+        event.action.as_str(),
+        event.action.resource_type(),
+        event.resource_id,
+        event.before.as_ref(),
+        event.after.as_ref(),
+        crate::request_id::current()
+    )
+    .execute(db)
+    .await;
+
+    if let Err(e) = result {
+        tracing::warn!(
+            %user_id,
+            action = event.action.as_str(),
+            resource_id = ?event.resource_id,
+            error = %e,
+            "failed to write audit log entry"
+        );
+    }
+}
+
+/// Filters for `list_for_user`.
+#[derive(Debug, Default, Clone)]
+pub struct AuditFilter {
+    pub action: Option<Action>,
+    pub resource_id: Option<Uuid>,
+    /// Only entries strictly older than this (keyset pagination).
+    pub before: Option<DateTime<Utc>>,
+    pub limit: i64,
+}
+
+/// `user_id`'s entries matching `filter`, newest first.
+pub async fn list_for_user(
+    db: &PgPool,
+    user_id: Uuid,
+    filter: &AuditFilter,
+) -> Result<Vec<AuditLogRow>, AppError> {
+    let rows = sqlx::query_as!(
+        AuditLogRow,
+        r#"SELECT * FROM audit_log
+           WHERE user_id = $1
+             AND ($2::text IS NULL OR action = $2)
+             AND ($3::uuid IS NULL OR resource_id = $3)
+             AND ($4::timestamptz IS NULL OR created_at < $4)
+           ORDER BY created_at DESC
+           LIMIT $5"#,
+        user_id,
+        filter.action.map(Action::as_str),
+        filter.resource_id,
+        filter.before,
+        filter.limit.clamp(1, MAX_PAGE_SIZE)
+    )
+    .fetch_all(db)
+    .await?;
+    Ok(rows)
+}
+
+// ────────────────────────────────────────────────────────────────────────────
+// Tests
+// ────────────────────────────────────────────────────────────────────────────
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_action_names_match_serde_and_parse_back() {
+        for action in Action::ALL {
+            let json = serde_json::to_value(action).unwrap();
+            assert_eq!(json, action.as_str());
+            assert_eq!(Action::parse(action.as_str()), Some(action));
+        }
+        assert_eq!(Action::parse("resume.delete_everything"), None);
+    }
+
+    #[test]
+    fn test_resource_type_is_the_action_prefix() {
+        assert_eq!(Action::ContextEntryEdit.resource_type(), "context_entry");
+        assert_eq!(Action::RenderJobCreate.resource_type(), "render_job");
+        assert_eq!(Action::UserSignup.resource_type(), "user");
+    }
+
+    #[test]
+    fn test_event_builder() {
+        let id = Uuid::new_v4();
+        let event = AuditEvent::new(Action::ContextEntryEdit, id)
+            .before(serde_json::json!({ "version": 3 }))
+            .after(serde_json::json!({ "version": 4 }));
+        assert_eq!(event.resource_id, Some(id));
+        assert_eq!(event.before.unwrap()["version"], 3);
+        assert_eq!(event.after.unwrap()["version"], 4);
+        assert_eq!(AuditEvent::new(Action::UserUpdate, None).resource_id, None);
+    }
+}
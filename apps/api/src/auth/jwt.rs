@@ -0,0 +1,224 @@
+//! HS256 JSON Web Tokens: issuing and validation.
+//!
+//! Only what the API needs: a compact `header.payload.signature` token signed with
+//! HMAC-SHA256, carrying the user id in `sub` and `iat`/`exp` timestamps. Tokens with
+//! any other `alg` (including `none`) are rejected.
+
+use std::sync::Arc;
+
+use base64::engine::general_purpose::URL_SAFE_NO_PAD;
+use base64::Engine;
+use chrono::{DateTime, Duration, Utc};
+use hmac::{Hmac, Mac};
+use serde::{Deserialize, Serialize};
+use sha2::Sha256;
+use uuid::Uuid;
+
+use crate::errors::AppError;
+
+type HmacSha256 = Hmac<Sha256>;
+
+/// Shortest accepted signing secret, in bytes.
+pub const MIN_SECRET_LEN: usize = 32;
+
+/// Default token lifetime.
+pub const DEFAULT_TTL_SECS: i64 = 24 * 60 * 60;
+
+/// Leeway for clock skew between issuer and validator.
+const LEEWAY_SECS: i64 = 30;
+
+// ────────────────────────────────────────────────────────────────────────────
+// Data models
+// ────────────────────────────────────────────────────────────────────────────
+
+/// Registered claims carried by every token.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Claims {
+    /// Authenticated user (`users.id`).
+    pub sub: Uuid,
+    /// Issued at, seconds since the Unix epoch.
+    pub iat: i64,
+    /// Expiry, seconds since the Unix epoch.
+    pub exp: i64,
+}
+
+#[derive(Serialize, Deserialize)]
+struct Header {
+    alg: String,
+    typ: String,
+}
+
+/// Signing key and token lifetime. Cheap to clone.
+#[derive(Clone)]
+pub struct JwtKeys {
+    secret: Arc<[u8]>,
+    ttl: Duration,
+}
+
+impl std::fmt::Debug for JwtKeys {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("JwtKeys")
+            .field("ttl", &self.ttl)
+            .finish_non_exhaustive()
+    }
+}
+
+// ────────────────────────────────────────────────────────────────────────────
+// Issuing and validation
+// ────────────────────────────────────────────────────────────────────────────
+
+impl JwtKeys {
+    pub fn new(secret: &[u8], ttl_secs: i64) -> Self {
+        JwtKeys {
+            secret: Arc::from(secret),
+            ttl: Duration::seconds(ttl_secs),
+        }
+    }
+
+    /// Token lifetime in seconds.
+    pub fn ttl_secs(&self) -> i64 {
+        self.ttl.num_seconds()
+    }
+
+    /// Issues a token for `user_id`, valid from now for the configured lifetime.
+    pub fn issue(&self, user_id: Uuid) -> String {
+        self.issue_at(user_id, Utc::now())
+    }
+
+    fn issue_at(&self, user_id: Uuid, now: DateTime<Utc>) -> String {
+        let header = Header {
+            alg: "HS256".to_string(),
+            typ: "JWT".to_string(),
+        };
+        let claims = Claims {
+            sub: user_id,
+            iat: now.timestamp(),
+            exp: (now + self.ttl).timestamp(),
+        };
+        let signing_input = format!("{}.{}", encode_json(&header), encode_json(&claims));
+        let signature = URL_SAFE_NO_PAD.encode(self.mac(&signing_input).finalize().into_bytes());
+        format!("{signing_input}.{signature}")
+    }
+
+    /// Validates signature, algorithm and expiry. Every failure is `Unauthorized`;
+    /// the reason is only logged.
+    pub fn verify(&self, token: &str) -> Result<Claims, AppError> {
+        self.verify_at(token, Utc::now())
+    }
+
+    fn verify_at(&self, token: &str, now: DateTime<Utc>) -> Result<Claims, AppError> {
+        let reject = |reason: &str| {
+            tracing::debug!(reason, "rejected bearer token");
+            AppError::Unauthorized
+        };
+
+        let Some((signing_input, signature)) = token.rsplit_once('.') else {
+            return Err(reject("malformed token"));
+        };
+        let Some((header, payload)) = signing_input.split_once('.') else {
+            return Err(reject("malformed token"));
+        };
+
+        let header: Header = decode_json(header).ok_or_else(|| reject("bad header"))?;
+        if header.alg != "HS256" {
+            return Err(reject("unsupported alg"));
+        }
+
+        let signature = URL_SAFE_NO_PAD
+            .decode(signature)
+            .map_err(|_| reject("bad signature encoding"))?;
+        self.mac(signing_input)
+            .verify_slice(&signature)
+            .map_err(|_| reject("bad signature"))?;
+
+        let claims: Claims = decode_json(payload).ok_or_else(|| reject("bad claims"))?;
+        if claims.exp + LEEWAY_SECS < now.timestamp() {
+            return Err(reject("expired"));
+        }
+        Ok(claims)
+    }
+
+    fn mac(&self, signing_input: &str) -> HmacSha256 {
+        let mut mac =
+            HmacSha256::new_from_slice(&self.secret).expect("HMAC accepts keys of any length");
+        mac.update(signing_input.as_bytes());
+        mac
+    }
+}
+
+// ────────────────────────────────────────────────────────────────────────────
+// Helpers
+// ────────────────────────────────────────────────────────────────────────────
+
+fn encode_json<T: Serialize>(value: &T) -> String {
+    URL_SAFE_NO_PAD.encode(serde_json::to_vec(value).expect("claims serialize"))
+}
+
+fn decode_json<T: for<'de> Deserialize<'de>>(segment: &str) -> Option<T> {
+    let bytes = URL_SAFE_NO_PAD.decode(segment).ok()?;
+    serde_json::from_slice(&bytes).ok()
+}
+
+// ────────────────────────────────────────────────────────────────────────────
+// Tests
+// ────────────────────────────────────────────────────────────────────────────
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn keys() -> JwtKeys {
+        JwtKeys::new(b"0123456789abcdef0123456789abcdef", 3600)
+    }
+
+    #[test]
+    fn test_issued_token_round_trips() {
+        let user_id = Uuid::new_v4();
+        let token = keys().issue(user_id);
+        let claims = keys().verify(&token).unwrap();
+        assert_eq!(claims.sub, user_id);
+        assert_eq!(claims.exp - claims.iat, 3600);
+    }
+
+    #[test]
+    fn test_expired_token_is_rejected() {
+        let issued = Utc::now() - Duration::hours(2);
+        let token = keys().issue_at(Uuid::new_v4(), issued);
+        assert!(matches!(keys().verify(&token), Err(AppError::Unauthorized)));
+    }
+
+    #[test]
+    fn test_wrong_secret_is_rejected() {
+        let token = keys().issue(Uuid::new_v4());
+        let other = JwtKeys::new(b"another-secret-another-secret-xx", 3600);
+        assert!(other.verify(&token).is_err());
+    }
+
+    #[test]
+    fn test_tampered_claims_are_rejected() {
+        let token = keys().issue(Uuid::new_v4());
+        let parts: Vec<&str> = token.split('.').collect();
+        let forged = encode_json(&Claims {
+            sub: Uuid::new_v4(),
+            iat: 0,
+            exp: i64::MAX / 2,
+        });
+        let tampered = format!("{}.{}.{}", parts[0], forged, parts[2]);
+        assert!(keys().verify(&tampered).is_err());
+    }
+
+    #[test]
+    fn test_alg_none_is_rejected() {
+        let header = encode_json(&Header {
+            alg: "none".to_string(),
+            typ: "JWT".to_string(),
+        });
+        let claims = encode_json(&Claims {
+            sub: Uuid::new_v4(),
+            iat: 0,
+            exp: i64::MAX / 2,
+        });
+        assert!(keys().verify(&format!("{header}.{claims}.")).is_err());
+        assert!(keys().verify("not-a-token").is_err());
+    }
+}
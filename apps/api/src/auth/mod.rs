@@ -0,0 +1,19 @@
+//! Authentication — HS256 JWTs, password hashing and the `AuthUser` extractor.
+//!
+//! Handlers never take a `user_id` from the request body or query string. A handler
+//! that acts on behalf of a user takes `AuthUser` as an extractor argument; the user
+//! id comes from the `sub` claim of the `Authorization: Bearer <jwt>` header, signed
+//! with `JWT_SECRET`. Tokens are issued by the signup and login endpoints in `users`.
+//!
+//! Routes that address a row by id additionally check that the caller owns it
+//! (`AuthUser::authorize`, see `ownership`), or for read-only routes that the caller
+//! owns or coaches its owner (`AuthUser::authorize_view`).
+
+pub mod extractor;
+pub mod jwt;
+pub mod ownership;
+pub mod password;
+
+pub use extractor::{bearer_token, AuthUser};
+pub use jwt::JwtKeys;
+pub use ownership::{Access, Resource};
@@ -0,0 +1,231 @@
+//! Resource ownership checks.
+//!
+//! Every route that addresses a row by id (`/resumes/:id`, `/render/:job_id`, ...)
+//! calls `AuthUser::authorize` before reading or mutating it. A row owned by someone
+//! else is reported exactly like a missing one (404), so ids cannot be probed for
+//! existence.
+//!
+//! Read-only routes use `AuthUser::authorize_view` instead, which also admits a coach
+//! or owner of an organization the row's owner is a candidate in (see `orgs`).
+
+use sqlx::PgPool;
+use uuid::Uuid;
+
+use crate::auth::AuthUser;
+use crate::errors::AppError;
+
+/// A user-owned row type, addressed by id.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Resource {
+    Resume,
+    /// Owned through its resume.
+    RenderJob,
+    /// Addressed by the stable `entry_id`, not the per-version row id.
+    ContextEntry,
+    IngestBatch,
+    GenerationBatch,
+    /// One job of a generation batch.
+    GenerationJob,
+    Project,
+    /// A data export (`user_exports`).
+    Export,
+    /// A tracked job application.
+    Application,
+    Persona,
+    /// A Slack or Discord webhook (`user_webhooks`).
+    Webhook,
+    /// A saved job description (`target_roles`).
+    TargetRole,
+}
+
+impl Resource {
+    fn label(self) -> &'static str {
+        match self {
+            Resource::Resume => "Resume",
+            Resource::RenderJob => "Render job",
+            Resource::ContextEntry => "Context entry",
+            Resource::IngestBatch => "Batch",
+            Resource::GenerationBatch => "Batch",
+            Resource::GenerationJob => "Job",
+            Resource::Project => "Project",
+            Resource::Export => "Export",
+            Resource::Application => "Application",
+            Resource::Persona => "Persona",
+            Resource::Webhook => "Webhook",
+            Resource::TargetRole => "Target role",
+        }
+    }
+
+    /// The owning `user_id` of the row with `id`, or `None` if there is no such row.
+    async fn owner(self, db: &PgPool, id: Uuid) -> Result<Option<Uuid>, sqlx::Error> {
+        match self {
+            Resource::Resume => {
+                sqlx::query_scalar!(
+                    "SELECT user_id FROM resumes WHERE id = $1 AND deleted_at IS NULL",
+                    id
+                )
+                .fetch_optional(db)
+                .await
+            }
+            Resource::RenderJob => {
+                sqlx::query_scalar!(
+                    "SELECT r.user_id FROM render_jobs j JOIN resumes r ON r.id = j.resume_id \
+                     WHERE j.id = $1 AND r.deleted_at IS NULL",
+                    id
+                )
+                .fetch_optional(db)
+                .await
+            }
+            Resource::ContextEntry => {
+                sqlx::query_scalar!(
+                    "SELECT user_id FROM context_current_entries WHERE entry_id = $1 LIMIT 1",
+                    id
+                )
+                .fetch_optional(db)
+                .await
+            }
+            Resource::IngestBatch => {
+                sqlx::query_scalar!(
+                    "SELECT user_id FROM context_ingest_batches WHERE id = $1",
+                    id
+                )
+                .fetch_optional(db)
+                .await
+            }
+            Resource::GenerationBatch => {
+                sqlx::query_scalar!("SELECT user_id FROM generation_batches WHERE id = $1", id)
+                    .fetch_optional(db)
+                    .await
+            }
+            Resource::GenerationJob => {
+                sqlx::query_scalar!("SELECT user_id FROM generation_jobs WHERE id = $1", id)
+                    .fetch_optional(db)
+                    .await
+            }
+            Resource::Project => {
+                sqlx::query_scalar!("SELECT user_id FROM cv_projects WHERE id = $1", id)
+                    .fetch_optional(db)
+                    .await
+            }
+            Resource::Export => {
+                sqlx::query_scalar!("SELECT user_id FROM user_exports WHERE id = $1", id)
+                    .fetch_optional(db)
+                    .await
+            }
+            Resource::Application => {
+                sqlx::query_scalar!("SELECT user_id FROM applications WHERE id = $1", id)
+                    .fetch_optional(db)
+                    .await
+            }
+            Resource::Persona => {
+                sqlx::query_scalar!("SELECT user_id FROM personas WHERE id = $1", id)
+                    .fetch_optional(db)
+                    .await
+            }
+            Resource::Webhook => {
+                sqlx::query_scalar!("SELECT user_id FROM user_webhooks WHERE id = $1", id)
+                    .fetch_optional(db)
+                    .await
+            }
+            Resource::TargetRole => {
+                sqlx::query_scalar!("SELECT user_id FROM target_roles WHERE id = $1", id)
+                    .fetch_optional(db)
+                    .await
+            }
+        }
+    }
+}
+
+impl AuthUser {
+    /// Succeeds only if `resource` `id` exists and belongs to this user.
+    pub async fn authorize(
+        &self,
+        db: &PgPool,
+        resource: Resource,
+        id: Uuid,
+    ) -> Result<(), AppError> {
+        let owner = resource.owner(db, id).await?;
+        check_owner(self, resource, id, owner)
+    }
+}
+
+/// How `authorize_view` admitted the caller.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Access {
+    Owner,
+    /// Coach or owner of an org the row's owner is a candidate in.
+    Coach,
+}
+
+impl AuthUser {
+    /// Like `authorize`, but a coach of the row's owner may also read it. Only for
+    /// routes that do not change the row.
+    pub async fn authorize_view(
+        &self,
+        db: &PgPool,
+        resource: Resource,
+        id: Uuid,
+    ) -> Result<Access, AppError> {
+        let owner = resource.owner(db, id).await?;
+        if let Some(owner) = owner.filter(|o| *o != self.user_id) {
+            if crate::orgs::coaches(db, self.user_id, owner).await? {
+                return Ok(Access::Coach);
+            }
+        }
+        check_owner(self, resource, id, owner).map(|()| Access::Owner)
+    }
+}
+
+fn check_owner(
+    auth: &AuthUser,
+    resource: Resource,
+    id: Uuid,
+    owner: Option<Uuid>,
+) -> Result<(), AppError> {
+    match owner {
+        Some(owner) if owner == auth.user_id => Ok(()),
+        Some(_) => {
+            tracing::warn!(user_id = %auth.user_id, ?resource, %id, "access to another user's resource denied");
+            Err(not_found(resource, id))
+        }
+        None => Err(not_found(resource, id)),
+    }
+}
+
+fn not_found(resource: Resource, id: Uuid) -> AppError {
+    AppError::NotFound(format!("{} {id} not found", resource.label()))
+}
+
+// ────────────────────────────────────────────────────────────────────────────
+// Tests
+// ────────────────────────────────────────────────────────────────────────────
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_owner_passes() {
+        let auth = AuthUser {
+            user_id: Uuid::new_v4(),
+        };
+        assert!(check_owner(&auth, Resource::Resume, Uuid::new_v4(), Some(auth.user_id)).is_ok());
+    }
+
+    #[test]
+    fn test_other_owner_looks_like_missing_row() {
+        let auth = AuthUser {
+            user_id: Uuid::new_v4(),
+        };
+        let id = Uuid::new_v4();
+        let foreign = check_owner(&auth, Resource::Project, id, Some(Uuid::new_v4()));
+        let missing = check_owner(&auth, Resource::Project, id, None);
+        match (foreign, missing) {
+            (Err(AppError::NotFound(a)), Err(AppError::NotFound(b))) => {
+                assert_eq!(a, b);
+                assert_eq!(a, format!("Project {id} not found"));
+            }
+            other => panic!("expected two NotFound errors, got {other:?}"),
+        }
+    }
+}
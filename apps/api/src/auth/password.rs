@@ -0,0 +1,131 @@
+//! Password hashing — PBKDF2-HMAC-SHA256 with a random per-user salt.
+//!
+//! Stored as `pbkdf2-sha256$<iterations>$<salt>$<hash>` (base64, no padding), so the
+//! iteration count can be raised later without invalidating existing hashes.
+//! Hashing is deliberately slow; call it from `spawn_blocking`.
+
+use std::num::NonZeroU32;
+
+use base64::engine::general_purpose::STANDARD_NO_PAD;
+use base64::Engine;
+use ring::pbkdf2::{self, PBKDF2_HMAC_SHA256};
+use ring::rand::{SecureRandom, SystemRandom};
+
+use crate::errors::AppError;
+
+const SCHEME: &str = "pbkdf2-sha256";
+/// Iterations for new hashes (OWASP 2023 guidance for PBKDF2-HMAC-SHA256).
+pub const ITERATIONS: u32 = 600_000;
+const SALT_LEN: usize = 16;
+const HASH_LEN: usize = 32;
+
+pub const MIN_PASSWORD_LEN: usize = 8;
+pub const MAX_PASSWORD_LEN: usize = 128;
+
+/// Rejects passwords outside `MIN_PASSWORD_LEN..=MAX_PASSWORD_LEN` characters.
+pub fn validate_password(password: &str) -> Result<(), AppError> {
+    let len = password.chars().count();
+    if !(MIN_PASSWORD_LEN..=MAX_PASSWORD_LEN).contains(&len) {
+        return Err(AppError::Validation(format!(
+            "password must be {MIN_PASSWORD_LEN}–{MAX_PASSWORD_LEN} characters"
+        )));
+    }
+    Ok(())
+}
+
+/// Hashes `password` with a fresh salt at `ITERATIONS`.
+pub fn hash_password(password: &str) -> Result<String, AppError> {
+    hash_with_iterations(password, ITERATIONS)
+}
+
+fn hash_with_iterations(password: &str, iterations: u32) -> Result<String, AppError> {
+    let mut salt = [0u8; SALT_LEN];
+    SystemRandom::new()
+        .fill(&mut salt)
+        .map_err(|_| AppError::Internal(anyhow::anyhow!("system RNG unavailable")))?;
+
+    let rounds = NonZeroU32::new(iterations).expect("iterations are non-zero");
+    let mut hash = [0u8; HASH_LEN];
+    pbkdf2::derive(
+        PBKDF2_HMAC_SHA256,
+        rounds,
+        &salt,
+        password.as_bytes(),
+        &mut hash,
+    );
+    Ok(format!(
+        "{SCHEME}${iterations}${}${}",
+        STANDARD_NO_PAD.encode(salt),
+        STANDARD_NO_PAD.encode(hash)
+    ))
+}
+
+/// Constant-time check of `password` against a stored hash. A malformed stored hash
+/// never matches.
+pub fn verify_password(password: &str, stored: &str) -> bool {
+    let mut parts = stored.split('$');
+    let (Some(SCHEME), Some(iterations), Some(salt), Some(hash), None) = (
+        parts.next(),
+        parts.next(),
+        parts.next(),
+        parts.next(),
+        parts.next(),
+    ) else {
+        return false;
+    };
+    let Some(rounds) = iterations.parse().ok().and_then(NonZeroU32::new) else {
+        return false;
+    };
+    let (Ok(salt), Ok(hash)) = (STANDARD_NO_PAD.decode(salt), STANDARD_NO_PAD.decode(hash)) else {
+        return false;
+    };
+    pbkdf2::verify(
+        PBKDF2_HMAC_SHA256,
+        rounds,
+        &salt,
+        password.as_bytes(),
+        &hash,
+    )
+    .is_ok()
+}
+
+// ────────────────────────────────────────────────────────────────────────────
+// Tests
+// ────────────────────────────────────────────────────────────────────────────
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Few iterations keep debug-build tests fast; the format is the same.
+    const TEST_ITERATIONS: u32 = 1_000;
+
+    #[test]
+    fn test_hash_verifies_only_the_same_password() {
+        let stored = hash_with_iterations("correct horse", TEST_ITERATIONS).unwrap();
+        assert!(stored.starts_with("pbkdf2-sha256$1000$"));
+        assert!(verify_password("correct horse", &stored));
+        assert!(!verify_password("correct horse!", &stored));
+    }
+
+    #[test]
+    fn test_salts_differ_between_hashes() {
+        let a = hash_with_iterations("same password", TEST_ITERATIONS).unwrap();
+        let b = hash_with_iterations("same password", TEST_ITERATIONS).unwrap();
+        assert_ne!(a, b);
+    }
+
+    #[test]
+    fn test_malformed_hash_never_matches() {
+        assert!(!verify_password("x", ""));
+        assert!(!verify_password("x", "pbkdf2-sha256$0$AAAA$AAAA"));
+        assert!(!verify_password("x", "bcrypt$10$abc$def"));
+    }
+
+    #[test]
+    fn test_password_length_bounds() {
+        assert!(validate_password("short").is_err());
+        assert!(validate_password("long enough").is_ok());
+        assert!(validate_password(&"x".repeat(MAX_PASSWORD_LEN + 1)).is_err());
+    }
+}
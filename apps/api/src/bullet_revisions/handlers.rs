@@ -0,0 +1,59 @@
+//! Axum handlers for bullet revisions.
+//!
+//! GET  /api/v1/resumes/:id/bullets/:bullet_id/revisions                    — history, oldest first
+//! POST /api/v1/resumes/:id/bullets/:bullet_id/revisions/:revision/restore  — bring one back
+
+use axum::{
+    extract::{Path, State},
+    Json,
+};
+use uuid::Uuid;
+
+use crate::audit::{self, Action, AuditEvent};
+use crate::auth::{AuthUser, Resource};
+use crate::bullet_revisions::{list, restore, BulletRevisionRow, RestoreRevisionResponse};
+use crate::errors::AppError;
+use crate::state::AppState;
+
+// ────────────────────────────────────────────────────────────────────────────
+// GET /api/v1/resumes/:id/bullets/:bullet_id/revisions
+// ────────────────────────────────────────────────────────────────────────────
+
+/// Every text the bullet has had, oldest first. Readable by the owner and their
+/// coaches.
+pub async fn handle_list_revisions(
+    State(state): State<AppState>,
+    auth: AuthUser,
+    Path((resume_id, bullet_id)): Path<(Uuid, Uuid)>,
+) -> Result<Json<Vec<BulletRevisionRow>>, AppError> {
+    auth.authorize_view(&state.db, Resource::Resume, resume_id)
+        .await?;
+    Ok(Json(list(&state.db, resume_id, bullet_id).await?))
+}
+
+// ────────────────────────────────────────────────────────────────────────────
+// POST /api/v1/resumes/:id/bullets/:bullet_id/revisions/:revision/restore
+// ────────────────────────────────────────────────────────────────────────────
+
+/// Restores an earlier revision of the bullet. The restore is itself a new revision,
+/// so it can be undone the same way. The PDF is not re-rendered.
+pub async fn handle_restore_revision(
+    State(state): State<AppState>,
+    auth: AuthUser,
+    Path((resume_id, bullet_id, revision)): Path<(Uuid, Uuid, i32)>,
+) -> Result<Json<RestoreRevisionResponse>, AppError> {
+    auth.authorize(&state.db, Resource::Resume, resume_id)
+        .await?;
+    let restored = restore(&state.db, resume_id, bullet_id, revision).await?;
+    audit::record(
+        &state.db,
+        auth.user_id,
+        AuditEvent::new(Action::ResumeBulletRestore, bullet_id).after(serde_json::json!({
+            "revision": restored.revision.revision,
+            "restored_from": revision,
+        })),
+    )
+    .await;
+
+    Ok(Json(restored))
+}
@@ -0,0 +1,301 @@
+//! Bullet revisions module — the edit history of each resume bullet.
+//!
+//! The pipeline rewrites a bullet several times before it is saved: the simulator
+//! expands or compresses it, page fill promotes or shrinks it, grounding and the
+//! keyword-stuffing check regenerate it. `SimulatedBullet::revise` keeps each text it
+//! replaces, and generation stores the whole chain next to the bullet, so nothing the
+//! pipeline wrote is lost. Restoring an old revision makes it the bullet's text again
+//! and appends it to the history as a `user` revision.
+//!
+//! Types and storage live here; handlers in handlers.rs.
+
+pub mod handlers;
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::{FromRow, PgConnection, PgPool};
+use uuid::Uuid;
+
+use crate::errors::AppError;
+use crate::generation::jd_parser::ParsedJD;
+use crate::generation::keyword_coverage::keyword_spans;
+use crate::layout::contract::simulate_lines;
+use crate::layout::font_metrics::get_metrics;
+use crate::layout::{
+    page_config_for_paper, FontFamily, MarginPreset, PaperSize, RevisionCause, SimulatedBullet,
+};
+use crate::models::resume::ResumeBulletRow;
+
+// ────────────────────────────────────────────────────────────────────────────
+// DB row type
+// ────────────────────────────────────────────────────────────────────────────
+
+/// A row from the `bullet_revisions` table.
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
+pub struct BulletRevisionRow {
+    pub id: Uuid,
+    pub bullet_id: Uuid,
+    /// 1-based; the highest is the bullet's current text.
+    pub revision: i32,
+    pub bullet_text: String,
+    /// A `RevisionCause`: generate | expand | compress | regenerate | user
+    pub cause: String,
+    /// The revision a `user` restore copied.
+    pub restored_from: Option<i32>,
+    pub created_at: DateTime<Utc>,
+}
+
+// ────────────────────────────────────────────────────────────────────────────
+// Response types
+// ────────────────────────────────────────────────────────────────────────────
+
+/// Response body for `POST /api/v1/resumes/:id/bullets/:bullet_id/revisions/:revision/restore`.
+#[derive(Debug, Serialize)]
+pub struct RestoreRevisionResponse {
+    pub bullet: ResumeBulletRow,
+    /// The `user` revision the restore added.
+    pub revision: BulletRevisionRow,
+}
+
+// ────────────────────────────────────────────────────────────────────────────
+// Storage
+// ────────────────────────────────────────────────────────────────────────────
+
+/// A bullet's texts in order, each with what produced it: the first draft, then the
+/// text after each of its revisions.
+pub fn revision_chain(bullet: &SimulatedBullet) -> Vec<(&str, RevisionCause)> {
+    let mut chain = Vec::with_capacity(bullet.revisions.len() + 1);
+    let mut cause = RevisionCause::Generate;
+    for revision in &bullet.revisions {
+        chain.push((revision.previous_text.as_str(), cause));
+        cause = revision.cause;
+    }
+    chain.push((bullet.text.as_str(), cause));
+    chain
+}
+
+/// Stores the revision chain of every freshly generated bullet, in one INSERT.
+/// `bullet_ids[i]` is the saved row of `bullets[i]`.
+pub async fn insert_generated(
+    conn: &mut PgConnection,
+    bullet_ids: &[Uuid],
+    bullets: &[SimulatedBullet],
+) -> Result<(), AppError> {
+    let mut ids = Vec::new();
+    let mut revisions = Vec::new();
+    let mut texts = Vec::new();
+    let mut causes = Vec::new();
+    for (&bullet_id, bullet) in bullet_ids.iter().zip(bullets) {
+        for (n, (text, cause)) in revision_chain(bullet).into_iter().enumerate() {
+            ids.push(bullet_id);
+            revisions.push(n as i32 + 1);
+            texts.push(text.to_string());
+            causes.push(cause.as_str().to_string());
+        }
+    }
+
+    sqlx::query!(
+        r#"
+        INSERT INTO bullet_revisions (bullet_id, revision, bullet_text, cause)
+        SELECT * FROM UNNEST($1::uuid[], $2::int4[], $3::text[], $4::text[])
+        "#,
+        &ids,
+        &revisions,
+        &texts,
+        &causes
+    )
+    .execute(conn)
+    .await?;
+    Ok(())
+}
+
+/// A bullet's revisions, oldest first. 404 unless the bullet is on the resume.
+pub async fn list(
+    db: &PgPool,
+    resume_id: Uuid,
+    bullet_id: Uuid,
+) -> Result<Vec<BulletRevisionRow>, AppError> {
+    let on_resume: bool = sqlx::query_scalar!(
+        r#"SELECT EXISTS (
+               SELECT 1 FROM resume_bullets WHERE id = $1 AND resume_id = $2
+           ) AS "exists!""#,
+        bullet_id,
+        resume_id
+    )
+    .fetch_one(db)
+    .await?;
+    if !on_resume {
+        return Err(AppError::NotFound(format!("Bullet {bullet_id} not found")));
+    }
+
+    Ok(sqlx::query_as!(
+        BulletRevisionRow,
+        "SELECT * FROM bullet_revisions WHERE bullet_id = $1 ORDER BY revision",
+        bullet_id
+    )
+    .fetch_all(db)
+    .await?)
+}
+
+/// Makes `revision`'s text the bullet's text again, re-measuring its line count on
+/// the resume's page and re-locating the JD keywords in it, and records that as a new
+/// `user` revision.
+pub async fn restore(
+    db: &PgPool,
+    resume_id: Uuid,
+    bullet_id: Uuid,
+    revision: i32,
+) -> Result<RestoreRevisionResponse, AppError> {
+    let mut tx = db.begin().await?;
+    // Row lock: concurrent restores of one bullet number their revisions in turn.
+    let current = sqlx::query!(
+        r#"SELECT b.section, r.paper_size, r.margin_preset, r.font_family, r.font_size_pt,
+                  r.jd_parsed
+           FROM resume_bullets b JOIN resumes r ON r.id = b.resume_id
+           WHERE b.id = $1 AND b.resume_id = $2
+           FOR UPDATE OF b"#,
+        bullet_id,
+        resume_id
+    )
+    .fetch_optional(&mut *tx)
+    .await?
+    .ok_or_else(|| AppError::NotFound(format!("Bullet {bullet_id} not found")))?;
+
+    let text = sqlx::query_scalar!(
+        "SELECT bullet_text FROM bullet_revisions WHERE bullet_id = $1 AND revision = $2",
+        bullet_id,
+        revision
+    )
+    .fetch_optional(&mut *tx)
+    .await?
+    .ok_or_else(|| {
+        AppError::NotFound(format!(
+            "Revision {revision} of bullet {bullet_id} not found"
+        ))
+    })?;
+
+    let config = page_config_for_paper(
+        FontFamily::parse(&current.font_family).unwrap_or(FontFamily::Inter),
+        PaperSize::parse(&current.paper_size).unwrap_or(PaperSize::Letter),
+    )
+    .with_margin_preset(MarginPreset::parse(&current.margin_preset).unwrap_or_default())
+    .with_font_size(current.font_size_pt);
+    let (line_count, _) = simulate_lines(
+        &text,
+        get_metrics(&config.font),
+        &config.for_section(&current.section),
+    );
+
+    // Keyword spans follow the text; a resume without a stored parse has none.
+    let spans = current
+        .jd_parsed
+        .and_then(|v| serde_json::from_value::<ParsedJD>(v).ok())
+        .map(|parsed_jd| keyword_spans(&parsed_jd, &text))
+        .unwrap_or_default();
+    let spans = serde_json::to_value(&spans).map_err(|e| {
+        AppError::Internal(anyhow::anyhow!("Failed to serialize keyword spans: {e}"))
+    })?;
+
+    let bullet = sqlx::query_as!(
+        ResumeBulletRow,
+        r#"UPDATE resume_bullets
+           SET bullet_text = $2, line_count = $3, keyword_spans = $4, is_user_edited = true
+           WHERE id = $1
+           RETURNING *"#,
+        bullet_id,
+        &text,
+        line_count.max(1) as i16,
+        spans
+    )
+    .fetch_one(&mut *tx)
+    .await?;
+    let revision = sqlx::query_as!(
+        BulletRevisionRow,
+        r#"INSERT INTO bullet_revisions (bullet_id, revision, bullet_text, cause, restored_from)
+           SELECT $1, COALESCE(MAX(revision), 0) + 1, $2, $3, $4
+           FROM bullet_revisions WHERE bullet_id = $1
+           RETURNING *"#,
+        bullet_id,
+        &text,
+        RevisionCause::User.as_str(),
+        revision
+    )
+    .fetch_one(&mut *tx)
+    .await?;
+    // Bullets are part of GET /resumes/:id, whose ETag follows updated_at.
+    sqlx::query!(
+        "UPDATE resumes SET updated_at = NOW() WHERE id = $1",
+        resume_id
+    )
+    .execute(&mut *tx)
+    .await?;
+    tx.commit().await?;
+
+    Ok(RestoreRevisionResponse { bullet, revision })
+}
+
+// ────────────────────────────────────────────────────────────────────────────
+// Tests
+// ────────────────────────────────────────────────────────────────────────────
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::layout::LineType;
+
+    fn bullet(text: &str) -> SimulatedBullet {
+        SimulatedBullet {
+            text: text.to_string(),
+            source_entry_id: Uuid::new_v4(),
+            section: "experience".to_string(),
+            line_type: LineType::Bullet,
+            verified_line_count: 1,
+            jd_keywords_used: vec![],
+            was_adjusted: false,
+            flagged_for_review: false,
+            revisions: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn test_unrevised_bullet_is_one_generate_revision() {
+        let b = bullet("Built a queue");
+        assert_eq!(
+            revision_chain(&b),
+            vec![("Built a queue", RevisionCause::Generate)]
+        );
+    }
+
+    #[test]
+    fn test_chain_pairs_each_text_with_what_produced_it() {
+        let mut b = bullet("Built a queue");
+        b.revise(
+            "Built a Rust job queue serving 2M jobs/day".to_string(),
+            RevisionCause::Expand,
+        );
+        b.revise(
+            "Built a Rust job queue".to_string(),
+            RevisionCause::Compress,
+        );
+        b.revise(
+            "Built a Rust job queue for billing".to_string(),
+            RevisionCause::Regenerate,
+        );
+
+        assert_eq!(
+            revision_chain(&b),
+            vec![
+                ("Built a queue", RevisionCause::Generate),
+                (
+                    "Built a Rust job queue serving 2M jobs/day",
+                    RevisionCause::Expand
+                ),
+                ("Built a Rust job queue", RevisionCause::Compress),
+                (
+                    "Built a Rust job queue for billing",
+                    RevisionCause::Regenerate
+                ),
+            ]
+        );
+    }
+}
@@ -0,0 +1,207 @@
+//! Axum handlers for review comments.
+
+use axum::{
+    extract::{Path, Query, State},
+    http::StatusCode,
+    Json,
+};
+use uuid::Uuid;
+
+use crate::audit::{self, Action, AuditEvent};
+use crate::auth::{Access, AuthUser, Resource};
+use crate::comments::{
+    insert, list_threads, Author, CommentQuery, CommentRow, CommentThread, CreateCommentRequest,
+    NewComment, ResolveCommentRequest, ReviewBullet, SharedCommentRequest, SharedReviewResponse,
+};
+use crate::errors::AppError;
+use crate::shares::handlers::resolve_share;
+use crate::shares::OpenShare;
+use crate::state::AppState;
+use crate::validation::ValidJson;
+
+// ────────────────────────────────────────────────────────────────────────────
+// Account reviewers
+// ────────────────────────────────────────────────────────────────────────────
+
+/// GET /api/v1/resumes/:id/comments
+///
+/// The resume's threads, oldest first. Readable by the owner and their coaches.
+pub async fn handle_list_comments(
+    State(state): State<AppState>,
+    auth: AuthUser,
+    Path(resume_id): Path<Uuid>,
+    Query(query): Query<CommentQuery>,
+) -> Result<Json<Vec<CommentThread>>, AppError> {
+    auth.authorize_view(&state.db, Resource::Resume, resume_id)
+        .await?;
+    Ok(Json(
+        list_threads(&state.db, resume_id, query.resolved).await?,
+    ))
+}
+
+/// POST /api/v1/resumes/:id/comments
+///
+/// Starts a thread on a bullet, or replies to one. The owner and their coaches can
+/// comment; the resume itself is never changed.
+pub async fn handle_create_comment(
+    State(state): State<AppState>,
+    auth: AuthUser,
+    Path(resume_id): Path<Uuid>,
+    ValidJson(body): ValidJson<CreateCommentRequest>,
+) -> Result<(StatusCode, Json<CommentRow>), AppError> {
+    auth.authorize_view(&state.db, Resource::Resume, resume_id)
+        .await?;
+    let comment = insert(
+        &state.db,
+        resume_id,
+        NewComment {
+            author: Author::Account(auth.user_id),
+            bullet_id: body.bullet_id,
+            parent_id: body.parent_id,
+            body: &body.body,
+        },
+    )
+    .await?;
+    audit::record(
+        &state.db,
+        auth.user_id,
+        AuditEvent::new(Action::ResumeCommentCreate, comment.id).after(serde_json::json!({
+            "resume_id": resume_id,
+            "bullet_id": comment.bullet_id,
+            "parent_id": comment.parent_id,
+        })),
+    )
+    .await;
+    Ok((StatusCode::CREATED, Json(comment)))
+}
+
+/// PATCH /api/v1/resumes/:id/comments/:comment_id
+///
+/// Resolves or reopens the thread `comment_id` belongs to; returns the thread's first
+/// comment. The owner and their coaches can.
+pub async fn handle_resolve_comment(
+    State(state): State<AppState>,
+    auth: AuthUser,
+    Path((resume_id, comment_id)): Path<(Uuid, Uuid)>,
+    Json(body): Json<ResolveCommentRequest>,
+) -> Result<Json<CommentRow>, AppError> {
+    auth.authorize_view(&state.db, Resource::Resume, resume_id)
+        .await?;
+    let thread = sqlx::query_as!(
+        CommentRow,
+        r#"UPDATE resume_comments
+           SET resolved_at = CASE WHEN $3 THEN COALESCE(resolved_at, NOW()) END,
+               resolved_by = CASE WHEN $3 THEN COALESCE(resolved_by, $4) END
+           WHERE id = (SELECT COALESCE(parent_id, id) FROM resume_comments
+                       WHERE id = $1 AND resume_id = $2)
+           RETURNING *"#,
+        comment_id,
+        resume_id,
+        body.resolved,
+        auth.user_id
+    )
+    .fetch_optional(&state.db)
+    .await?
+    .ok_or_else(|| AppError::NotFound(format!("Comment {comment_id} not found")))?;
+    audit::record(
+        &state.db,
+        auth.user_id,
+        AuditEvent::new(Action::ResumeCommentResolve, thread.id).after(serde_json::json!({
+            "resume_id": resume_id,
+            "resolved": body.resolved,
+        })),
+    )
+    .await;
+    Ok(Json(thread))
+}
+
+/// DELETE /api/v1/resumes/:id/comments/:comment_id
+///
+/// Removes a comment, and its replies if it starts a thread. The resume's owner can
+/// remove any comment, link reviewers' included; a coach only their own.
+pub async fn handle_delete_comment(
+    State(state): State<AppState>,
+    auth: AuthUser,
+    Path((resume_id, comment_id)): Path<(Uuid, Uuid)>,
+) -> Result<StatusCode, AppError> {
+    let access = auth
+        .authorize_view(&state.db, Resource::Resume, resume_id)
+        .await?;
+    let deleted = sqlx::query!(
+        r#"DELETE FROM resume_comments
+           WHERE id = $1 AND resume_id = $2 AND ($3 OR author_id = $4)"#,
+        comment_id,
+        resume_id,
+        access == Access::Owner,
+        auth.user_id
+    )
+    .execute(&state.db)
+    .await?
+    .rows_affected();
+    if deleted == 0 {
+        return Err(AppError::NotFound(format!(
+            "Comment {comment_id} not found"
+        )));
+    }
+    Ok(StatusCode::NO_CONTENT)
+}
+
+// ────────────────────────────────────────────────────────────────────────────
+// Link reviewers
+// ────────────────────────────────────────────────────────────────────────────
+
+/// GET /api/v1/shared/:token/comments
+///
+/// The resume's bullets and every thread, for a reviewer holding a link that allows
+/// comments. 403 for a view-only link.
+pub async fn handle_shared_review(
+    State(state): State<AppState>,
+    Path(token): Path<String>,
+) -> Result<Json<SharedReviewResponse>, AppError> {
+    let share = commenting_share(&state, &token).await?;
+    let bullets = sqlx::query_as!(
+        ReviewBullet,
+        "SELECT id, section, bullet_text FROM resume_bullets WHERE resume_id = $1 ORDER BY position, id",
+        share.resume_id
+    )
+    .fetch_all(&state.db)
+    .await?;
+    let threads = list_threads(&state.db, share.resume_id, None).await?;
+    Ok(Json(SharedReviewResponse { bullets, threads }))
+}
+
+/// POST /api/v1/shared/:token/comments
+///
+/// Starts a thread or replies, signed with the name the reviewer gives. 403 for a
+/// view-only link.
+pub async fn handle_create_shared_comment(
+    State(state): State<AppState>,
+    Path(token): Path<String>,
+    ValidJson(body): ValidJson<SharedCommentRequest>,
+) -> Result<(StatusCode, Json<CommentRow>), AppError> {
+    let share = commenting_share(&state, &token).await?;
+    let comment = insert(
+        &state.db,
+        share.resume_id,
+        NewComment {
+            author: Author::Link {
+                share_id: share.share_id,
+                name: &body.author_name,
+            },
+            bullet_id: body.bullet_id,
+            parent_id: body.parent_id,
+            body: &body.body,
+        },
+    )
+    .await?;
+    Ok((StatusCode::CREATED, Json(comment)))
+}
+
+/// The share `token` opens, if it was minted to allow comments.
+async fn commenting_share(state: &AppState, token: &str) -> Result<OpenShare, AppError> {
+    let share = resolve_share(state, token).await?;
+    if !share.can_comment {
+        return Err(AppError::Forbidden);
+    }
+    Ok(share)
+}
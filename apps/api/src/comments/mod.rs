@@ -0,0 +1,343 @@
+//! Comments module — threaded review comments on a resume's bullets, for coaches and
+//! mentors.
+//!
+//! GET    /api/v1/resumes/:id/comments              — threads, oldest first (`?resolved=`)
+//! POST   /api/v1/resumes/:id/comments              — comment or reply (owner, their coaches)
+//! PATCH  /api/v1/resumes/:id/comments/:comment_id  — resolve or reopen a thread
+//! DELETE /api/v1/resumes/:id/comments/:comment_id  — remove a comment (its author, or the owner)
+//! GET    /api/v1/shared/:token/comments            — bullets and threads, for a link reviewer
+//! POST   /api/v1/shared/:token/comments            — comment or reply as a link reviewer
+//!
+//! Reviewers with an account are the resume's owner and their org coaches
+//! (`AuthUser::authorize_view`). Reviewers without one hold a share link minted with
+//! `allow_comments`; they sign their comments with a name of their choosing.
+//!
+//! A comment without `parent_id` starts a thread on one bullet; replies attach to the
+//! thread's first comment, so threads are one level deep. Resolution belongs to the
+//! thread, and a new reply reopens it. `GET /resumes/:id` reports the open threads as
+//! `unresolved_comments`.
+//!
+//! Types and storage live here; handlers in handlers.rs.
+
+pub mod handlers;
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::{FromRow, PgPool};
+use uuid::Uuid;
+use validator::Validate;
+
+use crate::errors::AppError;
+use crate::validation::{not_blank, MAX_NAME_CHARS};
+
+/// Longest accepted comment, in characters.
+pub const MAX_COMMENT_CHARS: u64 = 2_000;
+
+// ────────────────────────────────────────────────────────────────────────────
+// DB row type
+// ────────────────────────────────────────────────────────────────────────────
+
+/// A row from `resume_comments`. Exactly one of `author_id` (an account) and
+/// `share_id` (a link reviewer) is set.
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
+pub struct CommentRow {
+    pub id: Uuid,
+    pub resume_id: Uuid,
+    pub bullet_id: Uuid,
+    /// The thread's first comment; absent on that first comment.
+    pub parent_id: Option<Uuid>,
+    pub author_id: Option<Uuid>,
+    pub share_id: Option<Uuid>,
+    pub author_name: String,
+    pub body: String,
+    /// Set on a thread's first comment once the thread is resolved.
+    pub resolved_at: Option<DateTime<Utc>>,
+    pub resolved_by: Option<Uuid>,
+    pub created_at: DateTime<Utc>,
+}
+
+// ────────────────────────────────────────────────────────────────────────────
+// Request / response types
+// ────────────────────────────────────────────────────────────────────────────
+
+/// Query for `GET /api/v1/resumes/:id/comments`.
+#[derive(Debug, Default, Deserialize)]
+pub struct CommentQuery {
+    /// Only resolved (`true`) or open (`false`) threads; every thread when absent.
+    pub resolved: Option<bool>,
+}
+
+/// Body for `POST /api/v1/resumes/:id/comments`.
+#[derive(Debug, Deserialize, Validate)]
+pub struct CreateCommentRequest {
+    pub bullet_id: Uuid,
+    /// Reply to this comment's thread; absent to start a thread.
+    #[serde(default)]
+    pub parent_id: Option<Uuid>,
+    #[validate(custom(function = "not_blank"), length(max = MAX_COMMENT_CHARS))]
+    pub body: String,
+}
+
+/// Body for `POST /api/v1/shared/:token/comments`.
+#[derive(Debug, Deserialize, Validate)]
+pub struct SharedCommentRequest {
+    pub bullet_id: Uuid,
+    #[serde(default)]
+    pub parent_id: Option<Uuid>,
+    /// Shown with the comment; link reviewers have no account to take it from.
+    #[validate(custom(function = "not_blank"), length(max = MAX_NAME_CHARS))]
+    pub author_name: String,
+    #[validate(custom(function = "not_blank"), length(max = MAX_COMMENT_CHARS))]
+    pub body: String,
+}
+
+/// Body for `PATCH /api/v1/resumes/:id/comments/:comment_id`.
+#[derive(Debug, Deserialize)]
+pub struct ResolveCommentRequest {
+    pub resolved: bool,
+}
+
+/// A thread: its first comment, which carries the resolution, and the replies in order.
+#[derive(Debug, Clone, Serialize)]
+pub struct CommentThread {
+    #[serde(flatten)]
+    pub comment: CommentRow,
+    pub replies: Vec<CommentRow>,
+}
+
+/// A bullet as a link reviewer sees it, so comments can name it.
+#[derive(Debug, Clone, Serialize, FromRow)]
+pub struct ReviewBullet {
+    pub id: Uuid,
+    pub section: String,
+    pub bullet_text: String,
+}
+
+/// Response body for `GET /api/v1/shared/:token/comments`.
+#[derive(Debug, Clone, Serialize)]
+pub struct SharedReviewResponse {
+    /// In display order.
+    pub bullets: Vec<ReviewBullet>,
+    pub threads: Vec<CommentThread>,
+}
+
+// ────────────────────────────────────────────────────────────────────────────
+// Storage
+// ────────────────────────────────────────────────────────────────────────────
+
+/// Who is writing a comment.
+#[derive(Debug, Clone, Copy)]
+pub enum Author<'a> {
+    Account(Uuid),
+    Link { share_id: Uuid, name: &'a str },
+}
+
+/// A new comment on `resume_id`, before it is stored.
+#[derive(Debug, Clone, Copy)]
+pub struct NewComment<'a> {
+    pub author: Author<'a>,
+    pub bullet_id: Uuid,
+    pub parent_id: Option<Uuid>,
+    pub body: &'a str,
+}
+
+/// Stores a comment. The bullet must be on the resume, and a reply's parent on the
+/// same bullet; replying to a reply joins the same thread. A reply reopens a resolved
+/// thread.
+pub async fn insert(
+    db: &PgPool,
+    resume_id: Uuid,
+    comment: NewComment<'_>,
+) -> Result<CommentRow, AppError> {
+    let on_resume: bool = sqlx::query_scalar!(
+        r#"SELECT EXISTS (
+               SELECT 1 FROM resume_bullets WHERE id = $1 AND resume_id = $2
+           ) AS "exists!""#,
+        comment.bullet_id,
+        resume_id
+    )
+    .fetch_one(db)
+    .await?;
+    if !on_resume {
+        return Err(AppError::Validation(format!(
+            "Bullet {} is not part of resume {resume_id}",
+            comment.bullet_id
+        )));
+    }
+
+    let mut tx = db.begin().await?;
+    let thread = match comment.parent_id {
+        None => None,
+        Some(parent_id) => {
+            let parent = sqlx::query!(
+                "SELECT bullet_id, parent_id FROM resume_comments WHERE id = $1 AND resume_id = $2",
+                parent_id,
+                resume_id
+            )
+            .fetch_optional(&mut *tx)
+            .await?
+            .ok_or_else(|| AppError::NotFound(format!("Comment {parent_id} not found")))?;
+            if parent.bullet_id != comment.bullet_id {
+                return Err(AppError::Validation(format!(
+                    "Comment {parent_id} is on a different bullet"
+                )));
+            }
+            let thread = parent.parent_id.unwrap_or(parent_id);
+            sqlx::query!(
+                "UPDATE resume_comments SET resolved_at = NULL, resolved_by = NULL WHERE id = $1",
+                thread
+            )
+            .execute(&mut *tx)
+            .await?;
+            Some(thread)
+        }
+    };
+
+    let (author_id, share_id, link_name) = match comment.author {
+        Author::Account(user_id) => (Some(user_id), None, None),
+        Author::Link { share_id, name } => (None, Some(share_id), Some(name.trim())),
+    };
+    // Account authors go by their display name, else their email's local part, so
+    // link reviewers never see a coach's full address.
+    let row = sqlx::query_as!(
+        CommentRow,
+        r#"INSERT INTO resume_comments
+               (resume_id, bullet_id, parent_id, author_id, share_id, author_name, body)
+           VALUES ($1, $2, $3, $4, $5,
+                   COALESCE($6, (SELECT COALESCE(display_name, split_part(email, '@', 1))
+                                 FROM users WHERE id = $4)),
+                   $7)
+           RETURNING *"#,
+        resume_id,
+        comment.bullet_id,
+        thread,
+        author_id,
+        share_id,
+        link_name,
+        comment.body.trim()
+    )
+    .fetch_one(&mut *tx)
+    .await?;
+    tx.commit().await?;
+    Ok(row)
+}
+
+/// The resume's threads, oldest first, optionally only resolved or only open ones.
+pub async fn list_threads(
+    db: &PgPool,
+    resume_id: Uuid,
+    resolved: Option<bool>,
+) -> Result<Vec<CommentThread>, AppError> {
+    let rows = sqlx::query_as!(
+        CommentRow,
+        "SELECT * FROM resume_comments WHERE resume_id = $1 ORDER BY created_at, id",
+        resume_id
+    )
+    .fetch_all(db)
+    .await?;
+    Ok(threads(rows)
+        .into_iter()
+        .filter(|t| resolved.is_none_or(|r| t.comment.resolved_at.is_some() == r))
+        .collect())
+}
+
+/// Groups comments (oldest first) into threads, ordered by their first comment.
+pub fn threads(rows: Vec<CommentRow>) -> Vec<CommentThread> {
+    let (firsts, replies): (Vec<CommentRow>, Vec<CommentRow>) =
+        rows.into_iter().partition(|c| c.parent_id.is_none());
+    let mut threads: Vec<CommentThread> = firsts
+        .into_iter()
+        .map(|comment| CommentThread {
+            comment,
+            replies: Vec::new(),
+        })
+        .collect();
+    for reply in replies {
+        if let Some(thread) = threads
+            .iter_mut()
+            .find(|t| Some(t.comment.id) == reply.parent_id)
+        {
+            thread.replies.push(reply);
+        }
+    }
+    threads
+}
+
+// ────────────────────────────────────────────────────────────────────────────
+// Tests
+// ────────────────────────────────────────────────────────────────────────────
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Duration;
+
+    fn comment(parent_id: Option<Uuid>, minutes: i64) -> CommentRow {
+        CommentRow {
+            id: Uuid::new_v4(),
+            resume_id: Uuid::nil(),
+            bullet_id: Uuid::nil(),
+            parent_id,
+            author_id: Some(Uuid::nil()),
+            share_id: None,
+            author_name: "Coach".to_string(),
+            body: "Quantify this".to_string(),
+            resolved_at: None,
+            resolved_by: None,
+            created_at: DateTime::UNIX_EPOCH + Duration::minutes(minutes),
+        }
+    }
+
+    #[test]
+    fn test_replies_are_grouped_under_their_thread() {
+        let first = comment(None, 0);
+        let second = comment(None, 1);
+        let reply_a = comment(Some(first.id), 2);
+        let reply_b = comment(Some(second.id), 3);
+        let reply_c = comment(Some(first.id), 4);
+
+        let grouped = threads(vec![
+            first.clone(),
+            second.clone(),
+            reply_a.clone(),
+            reply_b.clone(),
+            reply_c.clone(),
+        ]);
+
+        assert_eq!(grouped.len(), 2);
+        assert_eq!(grouped[0].comment.id, first.id);
+        assert_eq!(
+            grouped[0].replies.iter().map(|r| r.id).collect::<Vec<_>>(),
+            vec![reply_a.id, reply_c.id]
+        );
+        assert_eq!(grouped[1].replies[0].id, reply_b.id);
+    }
+
+    #[test]
+    fn test_thread_serializes_flat_with_replies() {
+        let first = comment(None, 0);
+        let json = serde_json::to_value(CommentThread {
+            comment: first.clone(),
+            replies: vec![comment(Some(first.id), 1)],
+        })
+        .unwrap();
+
+        assert_eq!(json["id"], first.id.to_string());
+        assert_eq!(json["replies"].as_array().unwrap().len(), 1);
+    }
+
+    #[test]
+    fn test_shared_comment_needs_a_name() {
+        let request = |name: &str| SharedCommentRequest {
+            bullet_id: Uuid::nil(),
+            parent_id: None,
+            author_name: name.to_string(),
+            body: "Lead with the result".to_string(),
+        };
+        assert!(request("Sam").validate().is_ok());
+        assert!(request("  ").validate().is_err());
+        assert!(request(&"x".repeat(MAX_NAME_CHARS as usize + 1))
+            .validate()
+            .is_err());
+    }
+}
@@ -7,25 +7,16 @@
 //! under concurrent workers.
 
 use anyhow::Result;
-use redis::AsyncCommands;
-use serde::Serialize;
-use sqlx::{FromRow, PgPool};
+use sqlx::PgPool;
 use uuid::Uuid;
 
-/// Per-item status as returned by the polling endpoint.
-#[derive(Debug, Serialize, Clone)]
-pub struct BatchItemStatus {
-    pub id: Uuid,
-    pub entry_index: i32,
-    pub status: String,
-    pub error_msg: Option<String>,
-    pub entry_id: Option<Uuid>,
-    /// Phase 5.5.4: non-null when this item was merged into an existing entry.
-    pub merged_with_entry_id: Option<Uuid>,
-}
+use crate::context::worker::IngestItemJob;
+use crate::jobs;
+
+pub use templar_types::context::{BatchItemStatus, BatchStatusResponse};
 
-/// Intermediate DB row for context_ingest_items (non-macro query, supports merged_with).
-#[derive(Debug, FromRow)]
+/// Intermediate DB row for context_ingest_items.
+#[derive(Debug)]
 struct BatchItemDbRow {
     id: Uuid,
     entry_index: i32,
@@ -35,20 +26,6 @@ struct BatchItemDbRow {
     merged_with: Option<Uuid>,
 }
 
-/// Full batch status response for the polling endpoint.
-#[derive(Debug, Serialize)]
-pub struct BatchStatusResponse {
-    pub batch_id: Uuid,
-    pub source_type: String,
-    pub filename: Option<String>,
-    pub total: i32,
-    pub pending: i32,
-    pub succeeded: i32,
-    pub failed: i32,
-    pub status: String,
-    pub items: Vec<BatchItemStatus>,
-}
-
 // ────────────────────────────────────────────────────────────────────────────
 // Create
 // ────────────────────────────────────────────────────────────────────────────
@@ -144,14 +121,14 @@ pub async fn get_batch_status(
         return Ok(None);
     };
 
-    // Non-macro query so we can SELECT `merged_with` without touching .sqlx cache
-    let items = sqlx::query_as::<_, BatchItemDbRow>(
+    let items = sqlx::query_as!(
+        BatchItemDbRow,
         r#"SELECT id, entry_index, status, error_msg, entry_id, merged_with
            FROM context_ingest_items
            WHERE batch_id = $1
            ORDER BY entry_index"#,
+        batch_id
     )
-    .bind(batch_id)
     .fetch_all(pool)
     .await?;
 
@@ -224,13 +201,13 @@ pub async fn mark_item_succeeded(pool: &PgPool, item_id: Uuid, entry_id: Uuid) -
 /// Phase 5.5.4: used when new context text was merged into an existing entry.
 /// Counts as succeeded for batch counter purposes.
 pub async fn mark_item_merged(pool: &PgPool, item_id: Uuid, existing_entry_id: Uuid) -> Result<()> {
-    sqlx::query(
+    sqlx::query!(
         r#"UPDATE context_ingest_items
            SET status = 'succeeded', entry_id = $2, merged_with = $2, updated_at = NOW()
            WHERE id = $1"#,
+        item_id,
+        existing_entry_id
     )
-    .bind(item_id)
-    .bind(existing_entry_id)
     .execute(pool)
     .await?;
     update_batch_counters(pool, item_id, true).await
@@ -293,24 +270,13 @@ async fn update_batch_counters(pool: &PgPool, item_id: Uuid, succeeded: bool) ->
 // Redis enqueue
 // ────────────────────────────────────────────────────────────────────────────
 
-/// Enqueue all item IDs to the Redis ingest queue in a single pipelined call.
-///
-/// Uses LPUSH (left-push) so workers BRPOP from the right — FIFO ordering.
-/// This is an async function using an async Redis connection.
+/// Enqueue an `IngestItemJob` per item ID in a single round trip, in order.
 pub async fn enqueue_batch_items(redis: &redis::Client, item_ids: Vec<Uuid>) -> Result<()> {
-    if item_ids.is_empty() {
-        return Ok(());
-    }
-
-    let mut conn = redis.get_multiplexed_async_connection().await?;
-
-    // LPUSH each item_id individually to the ingest queue
-    // Using AsyncCommands which is straightforward and compatible with redis 0.25
-    for id in &item_ids {
-        conn.lpush::<_, _, ()>("context:ingest:queue", id.to_string())
-            .await?;
-    }
-
+    let jobs: Vec<IngestItemJob> = item_ids
+        .into_iter()
+        .map(|item_id| IngestItemJob { item_id })
+        .collect();
+    jobs::enqueue_all(redis, &jobs).await?;
     Ok(())
 }
 
@@ -0,0 +1,94 @@
+//! Context compaction — moves old entry versions out of `context_entries`.
+//!
+//! Versioning is append-only, so every edit, evergreen toggle and re-ingest leaves a
+//! row behind. `CompactContext` (queued every `COMPACTION_INTERVAL_SECS`) keeps the
+//! newest `COMPACTION_KEEP_VERSIONS` versions of each entry in place and moves the
+//! rest to `context_entries_archive`. The current version is never moved, so
+//! `context_current_entries` never points into the archive.
+//!
+//! Nothing is lost: `get_entries_at_version` and data exports read both tables.
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::PgPool;
+use tracing::info;
+
+use crate::jobs::{Handler, Job, JobError};
+
+/// Rows moved per statement.
+const BATCH_SIZE: i64 = 1000;
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct CompactContext {
+    pub scheduled_at: DateTime<Utc>,
+}
+
+impl Job for CompactContext {
+    const QUEUE: &'static str = "context_compaction";
+}
+
+pub struct ContextCompactor {
+    pub db: PgPool,
+    /// `COMPACTION_KEEP_VERSIONS`; at least 1.
+    pub keep_versions: i32,
+}
+
+#[async_trait]
+impl Handler for ContextCompactor {
+    type Job = CompactContext;
+
+    async fn run(&self, job: &CompactContext) -> Result<(), JobError> {
+        let mut archived = 0;
+        loop {
+            let moved = archive_batch(&self.db, self.keep_versions)
+                .await
+                .map_err(|e| JobError::Retry(e.into()))?;
+            archived += moved;
+            if moved < BATCH_SIZE as u64 {
+                break;
+            }
+        }
+        if archived > 0 {
+            info!(
+                scheduled_at = %job.scheduled_at,
+                archived,
+                keep_versions = self.keep_versions,
+                "context compaction archived old entry versions"
+            );
+        }
+        Ok(())
+    }
+}
+
+/// Moves up to `BATCH_SIZE` versions ranked below the newest `keep_versions` of their
+/// entry into the archive, in one statement. Returns how many rows moved.
+async fn archive_batch(db: &PgPool, keep_versions: i32) -> Result<u64, sqlx::Error> {
+    let moved = sqlx::query!(
+        r#"WITH ranked AS (
+               SELECT id,
+                      ROW_NUMBER() OVER (PARTITION BY user_id, entry_id ORDER BY version DESC)
+                          AS rank
+               FROM context_entries
+           ),
+           moved AS (
+               DELETE FROM context_entries e
+               WHERE e.id IN (SELECT id FROM ranked WHERE rank > $1 LIMIT $2)
+               RETURNING e.*
+           )
+           INSERT INTO context_entries_archive
+               (id, user_id, entry_id, version, entry_type, data, raw_text,
+                recency_score, impact_score, tags, flagged_evergreen, contribution_type,
+                quality_score, quality_flags, created_at, recency_policy)
+           SELECT id, user_id, entry_id, version, entry_type, data, raw_text,
+                  recency_score, impact_score, tags, flagged_evergreen, contribution_type,
+                  quality_score, quality_flags, created_at, recency_policy
+           FROM moved"#,
+        keep_versions as i64,
+        BATCH_SIZE
+    )
+    .execute(db)
+    .await?
+    .rows_affected();
+    Ok(moved)
+}
@@ -287,6 +287,7 @@ mod tests {
             quality_score: 1.0,
             quality_flags: vec![],
             created_at: Utc::now(),
+            recency_policy: None,
         }];
 
         let new_entry = json!({
@@ -319,6 +320,7 @@ mod tests {
             quality_score: 1.0,
             quality_flags: vec![],
             created_at: Utc::now(),
+            recency_policy: None,
         }];
 
         let new_entry = json!({
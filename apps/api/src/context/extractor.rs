@@ -9,8 +9,7 @@
 use std::path::Path;
 
 use crate::errors::AppError;
-
-const MAX_FILE_SIZE: usize = 10 * 1024 * 1024; // 10 MB
+use crate::validation::MAX_UPLOAD_BYTES;
 
 /// Extract plain text from an uploaded file.
 ///
@@ -19,10 +18,10 @@ const MAX_FILE_SIZE: usize = 10 * 1024 * 1024; // 10 MB
 /// - `.pdf`         — `pdf_extract::extract_text_from_mem`
 /// - anything else  — `AppError::Validation` with an informative message
 ///
-/// Returns `AppError::Validation` if the file exceeds MAX_FILE_SIZE or the
+/// Returns `AppError::Validation` if the file exceeds MAX_UPLOAD_BYTES or the
 /// file type is unsupported.
 pub fn extract_text(filename: &str, bytes: &[u8]) -> Result<String, AppError> {
-    if bytes.len() > MAX_FILE_SIZE {
+    if bytes.len() > MAX_UPLOAD_BYTES {
         return Err(AppError::Validation(format!(
             "File too large: {:.1} MB. Maximum allowed size is 10 MB.",
             bytes.len() as f64 / (1024.0 * 1024.0)
@@ -130,7 +129,7 @@ mod tests {
     #[test]
     fn test_exactly_max_size_is_allowed() {
         // Exactly 10 MB of valid ASCII
-        let exactly_max = vec![b'a'; MAX_FILE_SIZE];
+        let exactly_max = vec![b'a'; MAX_UPLOAD_BYTES];
         let result = extract_text("exact.txt", &exactly_max);
         assert!(result.is_ok());
     }
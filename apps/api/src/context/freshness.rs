@@ -0,0 +1,354 @@
+//! Context freshness — nudges to update context that has gone stale.
+//!
+//! Every edit writes a new entry version, so the current version's `created_at` is
+//! when an entry was last touched. Two things go stale:
+//! - a current job (an experience entry without `date_end`) not updated in
+//!   `STALE_CURRENT_ROLE_MONTHS` months: its bullets miss everything done since;
+//! - the skills section, when no skill entry has been touched in
+//!   `STALE_SKILLS_MONTHS` months.
+//!
+//! `GET /api/v1/context/nudges` lists the caller's nudges. `CheckContextFreshness`
+//! (queued every `CONTEXT_NUDGE_INTERVAL_SECS`) sends each new nudge once to the
+//! user's `context_stale` webhooks and, if `context_nudges` is on in their
+//! notification preferences, by email. `context_nudge_deliveries` remembers what was
+//! sent; updating the entry changes the nudge, so it is sent again once that goes
+//! stale too.
+
+use async_trait::async_trait;
+use chrono::{DateTime, Months, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::PgPool;
+use tracing::{info, warn};
+use uuid::Uuid;
+
+use crate::context::versioning::get_current_entries;
+use crate::jobs::{Handler, Job, JobError};
+use crate::models::context::ContextEntryRow;
+use crate::notifications::Notifier;
+
+/// Months after which a current job entry is stale.
+pub const STALE_CURRENT_ROLE_MONTHS: u32 = 9;
+/// Months after which the skills section is stale.
+pub const STALE_SKILLS_MONTHS: u32 = 12;
+
+// ────────────────────────────────────────────────────────────────────────────
+// Nudges
+// ────────────────────────────────────────────────────────────────────────────
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum NudgeKind {
+    /// A current job entry not updated in `STALE_CURRENT_ROLE_MONTHS` months.
+    CurrentRoleStale,
+    /// No skill entry touched in `STALE_SKILLS_MONTHS` months.
+    SkillsStale,
+}
+
+impl NudgeKind {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            NudgeKind::CurrentRoleStale => "current_role_stale",
+            NudgeKind::SkillsStale => "skills_stale",
+        }
+    }
+}
+
+/// One piece of stale context. Returned (as an array) by `GET /api/v1/context/nudges`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ContextNudge {
+    pub kind: NudgeKind,
+    /// The stale entry; None for `skills_stale`, which covers the whole section.
+    pub entry_id: Option<Uuid>,
+    /// Company or title of the stale job.
+    pub label: Option<String>,
+    pub last_updated_at: DateTime<Utc>,
+    pub message: String,
+}
+
+impl ContextNudge {
+    /// Identifies the nudge in `context_nudge_deliveries`. Includes `last_updated_at`,
+    /// so an entry updated and gone stale again is a new nudge.
+    pub fn delivery_key(&self) -> String {
+        let entry = self
+            .entry_id
+            .map(|id| id.to_string())
+            .unwrap_or_else(|| "-".to_string());
+        format!(
+            "{}:{entry}:{}",
+            self.kind.as_str(),
+            self.last_updated_at.timestamp()
+        )
+    }
+}
+
+/// The nudges for a user's current entries as of `now`: stale current jobs, most
+/// stale first, then stale skills.
+pub fn compute_nudges(entries: &[ContextEntryRow], now: DateTime<Utc>) -> Vec<ContextNudge> {
+    let role_cutoff = months_before(now, STALE_CURRENT_ROLE_MONTHS);
+    let skills_cutoff = months_before(now, STALE_SKILLS_MONTHS);
+
+    let mut stale_roles: Vec<&ContextEntryRow> = entries
+        .iter()
+        .filter(|e| e.entry_type == "experience" && is_current(e) && e.created_at <= role_cutoff)
+        .collect();
+    stale_roles.sort_by_key(|e| e.created_at);
+    let mut nudges: Vec<ContextNudge> = stale_roles
+        .into_iter()
+        .map(|e| {
+            let label = ["company", "title"]
+                .iter()
+                .find_map(|field| e.data.get(*field).and_then(|v| v.as_str()))
+                .map(str::to_string);
+            let role = label.as_deref().unwrap_or("Your current job");
+            ContextNudge {
+                kind: NudgeKind::CurrentRoleStale,
+                entry_id: Some(e.entry_id),
+                label: label.clone(),
+                last_updated_at: e.created_at,
+                message: format!(
+                    "{role} was last updated {} months ago. Add what you have worked on since.",
+                    months_between(e.created_at, now)
+                ),
+            }
+        })
+        .collect();
+
+    let newest_skill = entries
+        .iter()
+        .filter(|e| e.entry_type == "skill")
+        .map(|e| e.created_at)
+        .max();
+    if let Some(touched) = newest_skill.filter(|t| *t <= skills_cutoff) {
+        nudges.push(ContextNudge {
+            kind: NudgeKind::SkillsStale,
+            entry_id: None,
+            label: None,
+            last_updated_at: touched,
+            message: format!(
+                "Your skills have not changed in {} months. Add the tools and languages you use now.",
+                months_between(touched, now)
+            ),
+        });
+    }
+    nudges
+}
+
+/// An experience entry with no end date (null, missing or blank) is the current job.
+fn is_current(entry: &ContextEntryRow) -> bool {
+    entry
+        .data
+        .get("date_end")
+        .and_then(|v| v.as_str())
+        .is_none_or(|end| end.trim().is_empty())
+}
+
+fn months_before(now: DateTime<Utc>, months: u32) -> DateTime<Utc> {
+    now.checked_sub_months(Months::new(months)).unwrap_or(now)
+}
+
+/// Whole months from `from` to `to`.
+fn months_between(from: DateTime<Utc>, to: DateTime<Utc>) -> u32 {
+    let mut months = 0;
+    while from
+        .checked_add_months(Months::new(months + 1))
+        .is_some_and(|t| t <= to)
+    {
+        months += 1;
+    }
+    months
+}
+
+// ────────────────────────────────────────────────────────────────────────────
+// Background check
+// ────────────────────────────────────────────────────────────────────────────
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct CheckContextFreshness {
+    pub scheduled_at: DateTime<Utc>,
+}
+
+impl Job for CheckContextFreshness {
+    const QUEUE: &'static str = "context_freshness";
+}
+
+pub struct FreshnessChecker {
+    pub db: PgPool,
+    pub notifier: Notifier,
+}
+
+#[async_trait]
+impl Handler for FreshnessChecker {
+    type Job = CheckContextFreshness;
+
+    async fn run(&self, job: &CheckContextFreshness) -> Result<(), JobError> {
+        let now = Utc::now();
+        let users = candidate_users(&self.db, now)
+            .await
+            .map_err(|e| JobError::Retry(e.into()))?;
+        let mut notified = 0;
+        for user_id in users {
+            match self.notify_user(user_id, now).await {
+                Ok(true) => notified += 1,
+                Ok(false) => {}
+                // One user's failure does not hold up the rest; the next run retries.
+                Err(e) => warn!(%user_id, "context nudges not sent: {e:#}"),
+            }
+        }
+        if notified > 0 {
+            info!(
+                scheduled_at = %job.scheduled_at,
+                notified,
+                "context freshness check sent nudges"
+            );
+        }
+        Ok(())
+    }
+}
+
+impl FreshnessChecker {
+    /// Sends the user's nudges not sent before. Returns whether there were any.
+    async fn notify_user(&self, user_id: Uuid, now: DateTime<Utc>) -> anyhow::Result<bool> {
+        let entries = get_current_entries(&self.db, user_id).await?;
+        let nudges = compute_nudges(&entries, now);
+        if nudges.is_empty() {
+            return Ok(false);
+        }
+        let keys: Vec<String> = nudges.iter().map(ContextNudge::delivery_key).collect();
+        // Claimed before sending: a crash in between drops the nudge rather than
+        // sending it twice.
+        let claimed: Vec<String> = sqlx::query_scalar!(
+            r#"INSERT INTO context_nudge_deliveries (user_id, nudge_key)
+               SELECT $1, k FROM UNNEST($2::text[]) AS k
+               ON CONFLICT (user_id, nudge_key) DO NOTHING
+               RETURNING nudge_key"#,
+            user_id,
+            &keys
+        )
+        .fetch_all(&self.db)
+        .await?;
+        let fresh: Vec<ContextNudge> = nudges
+            .into_iter()
+            .filter(|n| claimed.contains(&n.delivery_key()))
+            .collect();
+        if fresh.is_empty() {
+            return Ok(false);
+        }
+        self.notifier.context_stale(&self.db, user_id, &fresh).await;
+        Ok(true)
+    }
+}
+
+/// Users with a current job or a skill entry older than its staleness window: a
+/// superset of the users with nudges, narrowed by `compute_nudges`.
+async fn candidate_users(db: &PgPool, now: DateTime<Utc>) -> Result<Vec<Uuid>, sqlx::Error> {
+    sqlx::query_scalar!(
+        r#"SELECT DISTINCT c.user_id AS "user_id!"
+           FROM context_current_entries c
+           JOIN context_entries e ON e.id = c.entry_row_id
+           WHERE (e.entry_type = 'experience'
+                  AND COALESCE(TRIM(e.data->>'date_end'), '') = ''
+                  AND e.created_at <= $1)
+              OR (e.entry_type = 'skill' AND e.created_at <= $2)"#,
+        months_before(now, STALE_CURRENT_ROLE_MONTHS),
+        months_before(now, STALE_SKILLS_MONTHS)
+    )
+    .fetch_all(db)
+    .await
+}
+
+// ────────────────────────────────────────────────────────────────────────────
+// Tests
+// ────────────────────────────────────────────────────────────────────────────
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+    use serde_json::{json, Value};
+
+    fn now() -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(2026, 10, 1, 12, 0, 0).unwrap()
+    }
+
+    fn months_ago(months: u32) -> DateTime<Utc> {
+        now().checked_sub_months(Months::new(months)).unwrap()
+    }
+
+    fn make_entry(entry_type: &str, data: Value, created_at: DateTime<Utc>) -> ContextEntryRow {
+        ContextEntryRow {
+            id: Uuid::new_v4(),
+            user_id: Uuid::new_v4(),
+            entry_id: Uuid::new_v4(),
+            version: 1,
+            entry_type: entry_type.to_string(),
+            data,
+            raw_text: None,
+            recency_score: 0.5,
+            impact_score: 0.5,
+            tags: vec![],
+            flagged_evergreen: false,
+            contribution_type: "sole_author".to_string(),
+            quality_score: 1.0,
+            quality_flags: vec![],
+            created_at,
+            recency_policy: None,
+        }
+    }
+
+    #[test]
+    fn test_only_current_jobs_past_the_window_are_stale() {
+        let stale = make_entry(
+            "experience",
+            json!({ "company": "Acme", "date_end": null }),
+            months_ago(10),
+        );
+        let recent = make_entry("experience", json!({ "company": "Beta" }), months_ago(3));
+        let ended = make_entry(
+            "experience",
+            json!({ "company": "Gamma", "date_end": "2020-01-31" }),
+            months_ago(30),
+        );
+
+        let nudges = compute_nudges(&[stale.clone(), recent, ended], now());
+
+        assert_eq!(nudges.len(), 1);
+        assert_eq!(nudges[0].kind, NudgeKind::CurrentRoleStale);
+        assert_eq!(nudges[0].entry_id, Some(stale.entry_id));
+        assert_eq!(nudges[0].label.as_deref(), Some("Acme"));
+        assert!(nudges[0]
+            .message
+            .starts_with("Acme was last updated 10 months ago"));
+    }
+
+    #[test]
+    fn test_skills_are_stale_only_when_every_skill_entry_is() {
+        let old = make_entry("skill", json!({ "name": "Go" }), months_ago(14));
+        let older = make_entry("skill", json!({ "name": "Perl" }), months_ago(40));
+
+        let nudges = compute_nudges(&[old.clone(), older.clone()], now());
+        assert_eq!(nudges.len(), 1);
+        assert_eq!(nudges[0].kind, NudgeKind::SkillsStale);
+        assert_eq!(nudges[0].last_updated_at, old.created_at);
+        assert!(nudges[0].message.contains("in 14 months"));
+
+        let fresh = make_entry("skill", json!({ "name": "Rust" }), months_ago(2));
+        assert!(compute_nudges(&[old, older, fresh], now()).is_empty());
+        assert!(compute_nudges(&[], now()).is_empty());
+    }
+
+    #[test]
+    fn test_delivery_key_changes_when_the_entry_is_updated() {
+        let entry = make_entry("experience", json!({}), months_ago(12));
+        let before = compute_nudges(std::slice::from_ref(&entry), now());
+        let updated = ContextEntryRow {
+            created_at: months_ago(9),
+            version: 2,
+            ..entry
+        };
+        let after = compute_nudges(&[updated], now());
+
+        assert_eq!(before[0].label, None);
+        assert!(before[0].message.starts_with("Your current job"));
+        assert_ne!(before[0].delivery_key(), after[0].delivery_key());
+    }
+}
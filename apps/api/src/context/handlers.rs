@@ -1,30 +1,35 @@
 use axum::{
-    extract::{Multipart, Path, Query, State},
-    http::StatusCode,
+    extract::{Multipart, Path, State},
+    http::{HeaderMap, StatusCode},
     Json,
 };
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
+use crate::audit::{self, Action, AuditEvent};
+use crate::auth::{AuthUser, Resource};
 use crate::context::batch;
 use crate::context::completeness::compute_completeness_report;
 use crate::context::extractor;
+use crate::context::freshness::{compute_nudges, ContextNudge};
 use crate::context::ingest::{
     confirm_ingest, parse_and_validate, IngestConfirmRequest, IngestConfirmResponse,
     IngestPreviewResponse, IngestRequest,
 };
+use crate::context::quality::{assess_entry, EntryQualityReport};
+use crate::context::retag::{self, RetagApplyRequest, RetagApplyResponse, RetagPreview};
 use crate::context::splitter::smart_split;
 use crate::context::versioning::{
-    get_current_entries, get_entries_at_version, get_version_history,
+    get_context_revision, get_current_entries, get_entries_at_version, get_version_history,
+    is_snapshot_deleted, soft_delete_snapshot,
 };
 use crate::errors::AppError;
+use crate::etag::{Conditional, ETag};
 use crate::models::context::{ContextEntryRow, ContextSnapshotRow};
+use crate::quota;
 use crate::state::AppState;
-
-#[derive(Deserialize)]
-pub struct UserIdQuery {
-    pub user_id: Uuid,
-}
+use crate::users::preferences;
+use crate::validation::{self, ValidJson};
 
 #[derive(Serialize)]
 pub struct ContextListResponse {
@@ -38,108 +43,284 @@ pub struct ContextListResponse {
 /// The old 422 rejection for missing metrics has been removed.
 pub async fn handle_ingest(
     State(state): State<AppState>,
-    Json(req): Json<IngestRequest>,
+    auth: AuthUser,
+    ValidJson(req): ValidJson<IngestRequest>,
 ) -> Result<Json<IngestPreviewResponse>, AppError> {
-    let preview = parse_and_validate(&req.raw_text, &state.llm, &state.db, req.user_id).await?;
+    let preview = parse_and_validate(&req.raw_text, &state.llm, &state.db, auth.user_id).await?;
     Ok(Json(preview))
 }
 
 /// POST /api/v1/context/ingest/confirm
 pub async fn handle_ingest_confirm(
     State(state): State<AppState>,
-    Json(req): Json<IngestConfirmRequest>,
+    auth: AuthUser,
+    Json(mut req): Json<IngestConfirmRequest>,
 ) -> Result<Json<IngestConfirmResponse>, AppError> {
-    let response = confirm_ingest(&state.db, &state.s3, &state.config.s3_bucket, &req).await?;
+    req.user_id = auth.user_id;
+    let response = confirm_ingest(&state.db, &*state.blobs, &req).await?;
+    audit::record(
+        &state.db,
+        auth.user_id,
+        AuditEvent::new(Action::ContextIngest, response.entry_id)
+            .after(serde_json::json!({ "version": response.version })),
+    )
+    .await;
     Ok(Json(response))
 }
 
 /// GET /api/v1/context
+///
+/// Conditional: the ETag follows the context revision, so a poll with a matching
+/// `If-None-Match` gets a 304 without the entries being loaded. Served from the read
+/// replica, if any; the revision and entries come from the same pool, so a lagging
+/// replica yields an older tag with older entries, never a mismatch.
 pub async fn handle_get_context(
     State(state): State<AppState>,
-    Query(params): Query<UserIdQuery>,
-) -> Result<Json<ContextListResponse>, AppError> {
-    let entries = get_current_entries(&state.db, params.user_id).await?;
-    let completeness = compute_completeness_report(&entries);
-    Ok(Json(ContextListResponse {
-        entries,
-        completeness,
-    }))
+    auth: AuthUser,
+    headers: HeaderMap,
+) -> Result<Conditional<Json<ContextListResponse>>, AppError> {
+    // Read before the entries: a write landing in between yields newer entries
+    // under the older tag, which only costs the client one extra full response.
+    let db = state.reads.pool();
+    let revision = get_context_revision(db, auth.user_id).await?;
+    let etag = ETag::weak(&format!(
+        "ctx-{}-{}",
+        revision.version, revision.version_sum
+    ));
+    Conditional::respond(&headers, etag, || async {
+        let entries = get_current_entries(db, auth.user_id).await?;
+        let completeness = compute_completeness_report(&entries);
+        Ok(Json(ContextListResponse {
+            entries,
+            completeness,
+        }))
+    })
+    .await
 }
 
 /// GET /api/v1/context/health
 pub async fn handle_context_health(
     State(state): State<AppState>,
-    Query(params): Query<UserIdQuery>,
+    auth: AuthUser,
 ) -> Result<Json<crate::context::completeness::CompletenessReport>, AppError> {
-    let entries = get_current_entries(&state.db, params.user_id).await?;
+    let entries = get_current_entries(state.reads.pool(), auth.user_id).await?;
     Ok(Json(compute_completeness_report(&entries)))
 }
 
+/// GET /api/v1/context/nudges
+///
+/// Stale parts of the caller's context: current jobs not updated in nine months, and
+/// skills untouched for a year. Empty when everything is fresh.
+pub async fn handle_context_nudges(
+    State(state): State<AppState>,
+    auth: AuthUser,
+) -> Result<Json<Vec<ContextNudge>>, AppError> {
+    let entries = get_current_entries(state.reads.pool(), auth.user_id).await?;
+    Ok(Json(compute_nudges(&entries, chrono::Utc::now())))
+}
+
+/// POST /api/v1/context/retag/preview
+///
+/// LLM-proposed tags for every current entry, as a diff against the stored ones.
+/// Stores nothing; see `retag`.
+pub async fn handle_retag_preview(
+    State(state): State<AppState>,
+    auth: AuthUser,
+) -> Result<Json<RetagPreview>, AppError> {
+    let preview = quota::metered_llm(
+        &state.db,
+        &state.redis,
+        auth.user_id,
+        retag::preview(&state.db, &state.llm, auth.user_id),
+    )
+    .await?;
+    Ok(Json(preview))
+}
+
+/// POST /api/v1/context/retag/apply
+///
+/// Stores the accepted tags as a new version of each entry. Entries edited since the
+/// preview are returned as stale and left alone.
+pub async fn handle_retag_apply(
+    State(state): State<AppState>,
+    auth: AuthUser,
+    ValidJson(req): ValidJson<RetagApplyRequest>,
+) -> Result<Json<RetagApplyResponse>, AppError> {
+    let response = retag::apply(&state.db, auth.user_id, &req).await?;
+    for applied in &response.applied {
+        audit::record(
+            &state.db,
+            auth.user_id,
+            AuditEvent::new(Action::ContextRetag, applied.entry_id)
+                .before(serde_json::json!({
+                    "version": applied.version - 1,
+                    "tags": applied.previous_tags,
+                }))
+                .after(serde_json::json!({
+                    "version": applied.version,
+                    "tags": applied.tags,
+                })),
+        )
+        .await;
+    }
+    Ok(Json(response))
+}
+
 /// GET /api/v1/context/history
 pub async fn handle_context_history(
     State(state): State<AppState>,
-    Query(params): Query<UserIdQuery>,
+    auth: AuthUser,
 ) -> Result<Json<Vec<ContextSnapshotRow>>, AppError> {
-    let history = get_version_history(&state.db, params.user_id).await?;
+    let history = get_version_history(state.reads.pool(), auth.user_id).await?;
     Ok(Json(history))
 }
 
 /// GET /api/v1/context/version/:v
+///
+/// 404 once the version's snapshot has been deleted.
 pub async fn handle_get_version(
     State(state): State<AppState>,
     Path(v): Path<i32>,
-    Query(params): Query<UserIdQuery>,
+    auth: AuthUser,
 ) -> Result<Json<Vec<ContextEntryRow>>, AppError> {
-    let entries = get_entries_at_version(&state.db, params.user_id, v).await?;
+    let db = state.reads.pool();
+    if is_snapshot_deleted(db, auth.user_id, v).await? {
+        return Err(version_not_found(v));
+    }
+    let entries = get_entries_at_version(db, auth.user_id, v).await?;
     Ok(Json(entries))
 }
 
+/// DELETE /api/v1/context/version/:v
+///
+/// Soft-deletes the snapshot: it disappears from history and exports at once and is
+/// purged, with its S3 object, after the retention window. The entries themselves
+/// are untouched.
+pub async fn handle_delete_version(
+    State(state): State<AppState>,
+    Path(v): Path<i32>,
+    auth: AuthUser,
+) -> Result<StatusCode, AppError> {
+    let snapshot_id = soft_delete_snapshot(&state.db, auth.user_id, v)
+        .await?
+        .ok_or_else(|| version_not_found(v))?;
+    audit::record(
+        &state.db,
+        auth.user_id,
+        AuditEvent::new(Action::ContextSnapshotDelete, snapshot_id)
+            .before(serde_json::json!({ "version": v })),
+    )
+    .await;
+    Ok(StatusCode::NO_CONTENT)
+}
+
+fn version_not_found(v: i32) -> AppError {
+    AppError::NotFound(format!("Context version {v} not found"))
+}
+
 #[derive(Deserialize)]
 pub struct EvergreenToggle {
     pub flagged_evergreen: bool,
-    pub user_id: Uuid,
 }
 
 /// PATCH /api/v1/context/entries/:id/evergreen
 pub async fn handle_toggle_evergreen(
     State(state): State<AppState>,
     Path(id): Path<Uuid>,
+    auth: AuthUser,
     Json(req): Json<EvergreenToggle>,
 ) -> Result<StatusCode, AppError> {
-    let existing: Option<ContextEntryRow> = sqlx::query_as(
+    auth.authorize(&state.db, Resource::ContextEntry, id)
+        .await?;
+
+    let existing = sqlx::query_as!(
+        ContextEntryRow,
         "SELECT * FROM context_entries WHERE entry_id = $1 AND user_id = $2 ORDER BY version DESC LIMIT 1",
+        id,
+        auth.user_id
     )
-    .bind(id)
-    .bind(req.user_id)
     .fetch_optional(&state.db)
     .await?;
 
     let existing = existing.ok_or_else(|| AppError::NotFound(format!("Entry {id} not found")))?;
 
     // Append-only: INSERT a new version with updated evergreen flag, never UPDATE
-    sqlx::query(
+    sqlx::query!(
         r#"
         INSERT INTO context_entries
             (user_id, entry_id, version, entry_type, data, raw_text,
-             recency_score, impact_score, tags, flagged_evergreen, contribution_type)
+             recency_score, impact_score, tags, flagged_evergreen, contribution_type,
+             recency_policy)
         SELECT user_id, entry_id, $1, entry_type, data, raw_text,
-               recency_score, impact_score, tags, $2, contribution_type
+               recency_score, impact_score, tags, $2, contribution_type, recency_policy
         FROM context_entries
         WHERE entry_id = $3 AND user_id = $4
         ORDER BY version DESC
         LIMIT 1
         "#,
+        existing.version + 1,
+        req.flagged_evergreen,
+        id,
+        auth.user_id
     )
-    .bind(existing.version + 1)
-    .bind(req.flagged_evergreen)
-    .bind(id)
-    .bind(req.user_id)
     .execute(&state.db)
     .await?;
 
+    audit::record(
+        &state.db,
+        auth.user_id,
+        AuditEvent::new(Action::ContextEvergreenToggle, id)
+            .before(serde_json::json!({
+                "version": existing.version,
+                "flagged_evergreen": existing.flagged_evergreen,
+            }))
+            .after(serde_json::json!({
+                "version": existing.version + 1,
+                "flagged_evergreen": req.flagged_evergreen,
+            })),
+    )
+    .await;
+
     Ok(StatusCode::NO_CONTENT)
 }
 
+/// GET /api/v1/context/entries/:id/quality
+///
+/// Scores the entry's current version on impact, recency, tag richness and grounding
+/// readiness, under the user's domain pack and recency policy, with concrete fixes.
+pub async fn handle_entry_quality(
+    State(state): State<AppState>,
+    Path(id): Path<Uuid>,
+    auth: AuthUser,
+) -> Result<Json<EntryQualityReport>, AppError> {
+    auth.authorize(&state.db, Resource::ContextEntry, id)
+        .await?;
+
+    let db = state.reads.pool();
+    let entry = sqlx::query_as!(
+        ContextEntryRow,
+        "SELECT * FROM context_entries WHERE entry_id = $1 AND user_id = $2 ORDER BY version DESC LIMIT 1",
+        id,
+        auth.user_id
+    )
+    .fetch_optional(db)
+    .await?
+    .ok_or_else(|| AppError::NotFound(format!("Entry {id} not found")))?;
+
+    let prefs = preferences::load(db, auth.user_id).await?;
+    let curve = prefs
+        .recency_policy
+        .unwrap_or_default()
+        .curve_for(&entry.entry_type, entry.flagged_evergreen);
+    let vague_words = prefs.domain_pack.unwrap_or_default().vague_words();
+    Ok(Json(assess_entry(
+        &entry,
+        &curve,
+        &vague_words,
+        chrono::Utc::now().date_naive(),
+    )))
+}
+
 // ────────────────────────────────────────────────────────────────────────────
 // Patch entry handler
 // ────────────────────────────────────────────────────────────────────────────
@@ -162,7 +343,6 @@ const EDITABLE_FIELDS: &[&str] = &[
 
 #[derive(Deserialize)]
 pub struct PatchEntryRequest {
-    pub user_id: Uuid,
     pub patch: serde_json::Value,
 }
 
@@ -174,14 +354,19 @@ pub struct PatchEntryRequest {
 pub async fn handle_patch_entry(
     State(state): State<AppState>,
     Path(id): Path<Uuid>,
+    auth: AuthUser,
     Json(req): Json<PatchEntryRequest>,
 ) -> Result<StatusCode, AppError> {
+    auth.authorize(&state.db, Resource::ContextEntry, id)
+        .await?;
+
     // Fetch the latest version for this entry + user.
-    let existing: Option<ContextEntryRow> = sqlx::query_as(
+    let existing = sqlx::query_as!(
+        ContextEntryRow,
         "SELECT * FROM context_entries WHERE entry_id = $1 AND user_id = $2 ORDER BY version DESC LIMIT 1",
+        id,
+        auth.user_id
     )
-    .bind(id)
-    .bind(req.user_id)
     .fetch_optional(&state.db)
     .await?;
 
@@ -195,37 +380,51 @@ pub async fn handle_patch_entry(
         serde_json::json!({})
     };
 
+    let mut changed_fields = vec![];
     if let Some(patch_obj) = req.patch.as_object() {
         for field in EDITABLE_FIELDS {
             if let Some(val) = patch_obj.get(*field) {
                 merged[field] = val.clone();
+                changed_fields.push(*field);
             }
         }
     }
 
     // INSERT new version with merged data (append-only versioning).
-    sqlx::query(
+    sqlx::query!(
         r#"
         INSERT INTO context_entries
             (user_id, entry_id, version, entry_type, data, raw_text,
              recency_score, impact_score, tags, flagged_evergreen, contribution_type,
-             quality_score, quality_flags)
+             quality_score, quality_flags, recency_policy)
         SELECT user_id, entry_id, $1, entry_type, $2, raw_text,
                recency_score, impact_score, tags, flagged_evergreen, contribution_type,
-               quality_score, quality_flags
+               quality_score, quality_flags, recency_policy
         FROM context_entries
         WHERE entry_id = $3 AND user_id = $4
         ORDER BY version DESC
         LIMIT 1
         "#,
+        existing.version + 1,
+        &merged,
+        id,
+        auth.user_id
     )
-    .bind(existing.version + 1)
-    .bind(&merged)
-    .bind(id)
-    .bind(req.user_id)
     .execute(&state.db)
     .await?;
 
+    audit::record(
+        &state.db,
+        auth.user_id,
+        AuditEvent::new(Action::ContextEntryEdit, id)
+            .before(serde_json::json!({ "version": existing.version }))
+            .after(serde_json::json!({
+                "version": existing.version + 1,
+                "fields": changed_fields,
+            })),
+    )
+    .await;
+
     Ok(StatusCode::NO_CONTENT)
 }
 
@@ -233,18 +432,7 @@ pub async fn handle_patch_entry(
 // Batch ingestion handlers (Phase: async batch pipeline)
 // ────────────────────────────────────────────────────────────────────────────
 
-#[derive(Debug, Deserialize)]
-pub struct IngestBatchRequest {
-    pub user_id: Uuid,
-    pub raw_text: String,
-}
-
-#[derive(Debug, Serialize)]
-pub struct BatchStartResponse {
-    pub batch_id: Uuid,
-    pub entry_count: usize,
-    pub status: &'static str,
-}
+pub use templar_types::context::{BatchStartResponse, IngestBatchRequest};
 
 /// POST /api/v1/context/ingest/batch
 ///
@@ -253,10 +441,11 @@ pub struct BatchStartResponse {
 /// item IDs to the Redis ingest queue. Returns immediately with a `batch_id`.
 ///
 /// The client should poll `GET /api/v1/context/ingest/batch/:id` for progress.
-#[tracing::instrument(skip(state, req), fields(user_id = %req.user_id, text_len = req.raw_text.len()))]
+#[tracing::instrument(skip(state, auth, req), fields(user_id = %auth.user_id, text_len = req.raw_text.len()))]
 pub async fn handle_ingest_batch(
     State(state): State<AppState>,
-    Json(req): Json<IngestBatchRequest>,
+    auth: AuthUser,
+    ValidJson(req): ValidJson<IngestBatchRequest>,
 ) -> Result<Json<BatchStartResponse>, AppError> {
     tracing::info!("batch text ingest requested");
 
@@ -264,7 +453,7 @@ pub async fn handle_ingest_batch(
     let entry_count = entries.len();
     tracing::info!(entry_count, "split entries from raw text");
 
-    let batch_id = batch::create_batch(&state.db, req.user_id, "text", None, &entries)
+    let batch_id = batch::create_batch(&state.db, auth.user_id, "text", None, &entries)
         .await
         .map_err(AppError::Internal)?;
 
@@ -277,30 +466,36 @@ pub async fn handle_ingest_batch(
         .map_err(AppError::Internal)?;
 
     tracing::info!(%batch_id, entry_count, "text batch enqueued");
+    audit::record(
+        &state.db,
+        auth.user_id,
+        AuditEvent::new(Action::IngestBatchCreate, batch_id)
+            .after(serde_json::json!({ "source": "text", "entry_count": entry_count })),
+    )
+    .await;
 
     Ok(Json(BatchStartResponse {
         batch_id,
         entry_count,
-        status: "queued",
+        status: "queued".to_string(),
     }))
 }
 
 /// POST /api/v1/context/ingest/upload
 ///
-/// Accepts a multipart form with fields:
-/// - `user_id` (UUID string)
-/// - `file` (binary — .md, .txt, or .pdf, max 10 MB)
+/// Accepts a multipart form with one field:
+/// - `file` (binary — .md, .txt, or .pdf, max 10 MB; at most `MAX_RAW_TEXT_CHARS` of text)
 ///
 /// Extracts text, splits into entries, stores in DB, enqueues to Redis.
 /// Returns immediately with a `batch_id`.
-#[tracing::instrument(skip(state, multipart))]
+#[tracing::instrument(skip(state, auth, multipart), fields(user_id = %auth.user_id))]
 pub async fn handle_ingest_upload(
     State(state): State<AppState>,
+    auth: AuthUser,
     mut multipart: Multipart,
 ) -> Result<Json<serde_json::Value>, AppError> {
     tracing::info!("file upload ingest requested");
 
-    let mut user_id: Option<Uuid> = None;
     let mut file_data: Option<(String, Vec<u8>)> = None;
 
     while let Some(field) = multipart
@@ -309,15 +504,6 @@ pub async fn handle_ingest_upload(
         .map_err(|e| AppError::Validation(format!("Multipart error: {e}")))?
     {
         match field.name() {
-            Some("user_id") => {
-                let text = field
-                    .text()
-                    .await
-                    .map_err(|e| AppError::Validation(format!("user_id field error: {e}")))?;
-                user_id = Some(text.trim().parse::<Uuid>().map_err(|_| {
-                    AppError::Validation("Invalid user_id: must be a valid UUID".into())
-                })?);
-            }
             Some("file") => {
                 let filename = field.file_name().unwrap_or("upload.md").to_string();
                 let bytes = field
@@ -332,23 +518,21 @@ pub async fn handle_ingest_upload(
         }
     }
 
-    let user_id =
-        user_id.ok_or_else(|| AppError::Validation("Missing required field: user_id".into()))?;
     let (filename, bytes) =
         file_data.ok_or_else(|| AppError::Validation("Missing required field: file".into()))?;
 
     tracing::info!(
-        %user_id,
         filename = %filename,
         file_size = bytes.len(),
         "extracting text from uploaded file"
     );
 
     let raw_text = extractor::extract_text(&filename, &bytes)?;
+    validation::check_raw_text("file", &raw_text)?;
     let entries = smart_split(&raw_text, &state.llm).await?;
     let entry_count = entries.len();
 
-    let batch_id = batch::create_batch(&state.db, user_id, "file", Some(&filename), &entries)
+    let batch_id = batch::create_batch(&state.db, auth.user_id, "file", Some(&filename), &entries)
         .await
         .map_err(AppError::Internal)?;
 
@@ -361,6 +545,13 @@ pub async fn handle_ingest_upload(
         .map_err(AppError::Internal)?;
 
     tracing::info!(%batch_id, entry_count, filename = %filename, "file batch enqueued");
+    audit::record(
+        &state.db,
+        auth.user_id,
+        AuditEvent::new(Action::IngestBatchCreate, batch_id)
+            .after(serde_json::json!({ "source": "file", "entry_count": entry_count })),
+    )
+    .await;
 
     Ok(Json(serde_json::json!({
         "batch_id": batch_id,
@@ -374,11 +565,14 @@ pub async fn handle_ingest_upload(
 ///
 /// Returns the current status of a batch including per-item progress.
 /// Poll this endpoint at 2-second intervals until `status == "done"`.
-#[tracing::instrument(skip(state))]
+#[tracing::instrument(skip(state, auth))]
 pub async fn handle_batch_status(
     State(state): State<AppState>,
+    auth: AuthUser,
     Path(batch_id): Path<Uuid>,
 ) -> Result<Json<batch::BatchStatusResponse>, AppError> {
+    auth.authorize(&state.db, Resource::IngestBatch, batch_id)
+        .await?;
     let status = batch::get_batch_status(&state.db, batch_id)
         .await
         .map_err(AppError::Internal)?
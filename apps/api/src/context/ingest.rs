@@ -6,16 +6,17 @@ use crate::context::completeness::compute_completeness_report;
 use crate::context::dedup::{check_for_conflicts, ConflictWarning};
 use crate::context::prompts::{CONTEXT_PARSE_PROMPT, CONTEXT_PARSE_SYSTEM};
 use crate::context::scoring::compute_recency_score;
-use crate::context::validation::{validate_bullets, validate_impact, ImpactQuality};
+use crate::context::validation::{
+    validate_bullets_with, validate_impact_with, ImpactQuality, VagueWords,
+};
 use crate::context::versioning::{commit_context_update, get_current_entries, CommitParams};
 use crate::errors::AppError;
+use crate::generation::domain_pack::user_pack;
 use crate::llm_client::LlmClient;
+use crate::storage::BlobStore;
+use crate::users::preferences;
 
-#[derive(Debug, Deserialize)]
-pub struct IngestRequest {
-    pub raw_text: String,
-    pub user_id: Uuid,
-}
+pub use templar_types::context::IngestRequest;
 
 #[derive(Debug, Serialize)]
 pub struct IngestPreviewResponse {
@@ -28,6 +29,9 @@ pub struct IngestPreviewResponse {
 #[derive(Debug, Deserialize)]
 pub struct IngestConfirmRequest {
     pub entry: serde_json::Value,
+    /// Set from the authenticated caller (or the batch owner in the ingest worker) —
+    /// never accepted from the request body.
+    #[serde(skip)]
     pub user_id: Uuid,
     // Acknowledged gaps are accepted from the client but not yet processed server-side.
     // They are preserved for future audit logging. See Phase 5 grounding system.
@@ -69,12 +73,17 @@ pub async fn parse_and_validate(
         .map_err(|e| AppError::Llm(format!("Failed to parse context entry: {e}")))?;
     tracing::debug!("LLM parse complete, computing quality");
 
-    // Phase 5.5: quality assessment is non-blocking — we always proceed
+    // Phase 5.5: quality assessment is non-blocking — we always proceed. Vague words
+    // follow the user's domain pack.
+    let vague_words = user_pack(pool, user_id).await?.vague_words();
     let bullets = extract_bullets(&parsed);
     let quality = if bullets.is_empty() {
-        validate_impact(raw_text)
+        validate_impact_with(raw_text, &vague_words)
     } else {
-        let per_bullet: Vec<_> = bullets.iter().map(|b| validate_impact(b)).collect();
+        let per_bullet: Vec<_> = bullets
+            .iter()
+            .map(|b| validate_impact_with(b, &vague_words))
+            .collect();
         ImpactQuality::aggregate(&per_bullet)
     };
 
@@ -107,11 +116,10 @@ pub async fn parse_and_validate(
     })
 }
 
-#[tracing::instrument(skip(pool, s3), fields(user_id = %request.user_id))]
+#[tracing::instrument(skip(pool, blobs), fields(user_id = %request.user_id))]
 pub async fn confirm_ingest(
     pool: &sqlx::PgPool,
-    s3: &aws_sdk_s3::Client,
-    s3_bucket: &str,
+    blobs: &dyn BlobStore,
     request: &IngestConfirmRequest,
 ) -> Result<IngestConfirmResponse, AppError> {
     tracing::info!("starting context commit to DB and S3");
@@ -137,17 +145,24 @@ pub async fn confirm_ingest(
         .and_then(|v| v.as_str())
         .and_then(|s| chrono::NaiveDate::parse_from_str(s, "%Y-%m-%d").ok());
     let flagged_evergreen = matches!(entry_type.as_str(), "skill" | "certification");
-    let recency_score = compute_recency_score(end_date, flagged_evergreen, 18.0);
+    let prefs = preferences::load(pool, user_id).await?;
+    let recency_policy = prefs
+        .recency_policy
+        .unwrap_or_default()
+        .curve_for(&entry_type, flagged_evergreen);
+    let recency_score = compute_recency_score(end_date, &recency_policy);
 
+    let vague_words = prefs.domain_pack.unwrap_or_default().vague_words();
     let bullets = extract_bullets_from_data(&data);
-    let impact_score = compute_impact_score(&bullets);
+    let impact_score = compute_impact_score(&bullets, &vague_words);
     let tags = extract_tags(&data, &entry_type);
 
     // Phase 5.5: compute quality for storage
-    let quality = validate_bullets(&bullets);
+    let quality = validate_bullets_with(&bullets, &vague_words);
     let quality_flags = quality.flags.clone();
 
-    // Completeness before insert
+    // Completeness before insert; the same entries seed the snapshot and the
+    // post-insert state, so they are loaded once.
     let entries_before = get_current_entries(pool, user_id)
         .await
         .map_err(AppError::Internal)?;
@@ -156,8 +171,7 @@ pub async fn confirm_ingest(
     tracing::debug!(%entry_id, %entry_type, "committing context entry to DB and S3");
     let version = commit_context_update(
         pool,
-        s3,
-        s3_bucket,
+        blobs,
         CommitParams {
             user_id,
             entry_id,
@@ -165,22 +179,20 @@ pub async fn confirm_ingest(
             data: &data,
             raw_text: None,
             recency_score,
+            recency_policy: &recency_policy,
             impact_score,
             tags: &tags,
             flagged_evergreen,
             contribution_type: &contribution_type,
             quality_score: quality.quality_score as f64,
             quality_flags: &quality_flags,
+            current_entries: Some(&entries_before),
         },
     )
     .await
     .map_err(AppError::Internal)?;
 
-    // Completeness after insert
-    let entries_after = get_current_entries(pool, user_id)
-        .await
-        .map_err(AppError::Internal)?;
-    let score_after = compute_completeness_report(&entries_after).overall_score;
+    let score_after = compute_completeness_report(&version.entries).overall_score;
 
     let completeness_delta = score_after - score_before;
     tracing::info!(
@@ -217,13 +229,13 @@ fn extract_bullets_from_data(data: &serde_json::Value) -> Vec<String> {
         .unwrap_or_default()
 }
 
-fn compute_impact_score(bullets: &[String]) -> f64 {
+fn compute_impact_score(bullets: &[String], vague_words: &[VagueWords]) -> f64 {
     if bullets.is_empty() {
         return 0.5;
     }
     let total_quality: f32 = bullets
         .iter()
-        .map(|b| validate_impact(b).quality_score)
+        .map(|b| validate_impact_with(b, vague_words).quality_score)
         .sum();
     (total_quality as f64 / bullets.len() as f64).clamp(0.0, 1.0)
 }
@@ -7,16 +7,17 @@
 //! unique details from each while eliminating redundancy.
 
 use anyhow::Result;
-use aws_sdk_s3::Client as S3Client;
 use sqlx::PgPool;
 use tracing::{info, warn};
 use uuid::Uuid;
 
 use crate::context::prompts::{MERGE_ENTRIES_PROMPT, MERGE_ENTRIES_SYSTEM};
 use crate::context::scoring::compute_recency_score;
-use crate::context::validation::validate_bullets;
+use crate::context::validation::{validate_bullets_with, validate_impact_with, VagueWords};
 use crate::context::versioning::{commit_context_update, get_current_entries, CommitParams};
 use crate::llm_client::LlmClient;
+use crate::storage::BlobStore;
+use crate::users::preferences;
 
 /// Merge `new_entry` into the existing entry identified by `existing_entry_id`.
 ///
@@ -28,8 +29,7 @@ use crate::llm_client::LlmClient;
 /// The existing `entry_id` is preserved — only the version increments.
 pub async fn merge_and_commit(
     db: &PgPool,
-    s3: &S3Client,
-    s3_bucket: &str,
+    blobs: &dyn BlobStore,
     llm: &LlmClient,
     user_id: Uuid,
     existing_entry_id: Uuid,
@@ -85,18 +85,23 @@ pub async fn merge_and_commit(
         .and_then(|v| v.as_str())
         .and_then(|s| chrono::NaiveDate::parse_from_str(s, "%Y-%m-%d").ok());
     let flagged_evergreen = matches!(entry_type.as_str(), "skill" | "certification");
-    let recency_score = compute_recency_score(end_date, flagged_evergreen, 18.0);
+    let prefs = preferences::load(db, user_id).await?;
+    let recency_policy = prefs
+        .recency_policy
+        .unwrap_or_default()
+        .curve_for(&entry_type, flagged_evergreen);
+    let recency_score = compute_recency_score(end_date, &recency_policy);
 
     let bullets = extract_bullets_from_data(&data);
-    let impact_score = compute_impact_score(&bullets);
-    let quality = validate_bullets(&bullets);
+    let vague_words = prefs.domain_pack.unwrap_or_default().vague_words();
+    let impact_score = compute_impact_score(&bullets, &vague_words);
+    let quality = validate_bullets_with(&bullets, &vague_words);
     let quality_flags = quality.flags.clone();
     let tags = extract_tags(&data, &entry_type);
 
     commit_context_update(
         db,
-        s3,
-        s3_bucket,
+        blobs,
         CommitParams {
             user_id,
             entry_id: existing_entry_id, // preserve the stable entry_id
@@ -104,12 +109,14 @@ pub async fn merge_and_commit(
             data: &data,
             raw_text: None,
             recency_score,
+            recency_policy: &recency_policy,
             impact_score,
             tags: &tags,
             flagged_evergreen,
             contribution_type: &contribution_type,
             quality_score: quality.quality_score as f64,
             quality_flags: &quality_flags,
+            current_entries: Some(&existing_entries),
         },
     )
     .await?;
@@ -118,14 +125,13 @@ pub async fn merge_and_commit(
     Ok(())
 }
 
-fn compute_impact_score(bullets: &[String]) -> f64 {
-    use crate::context::validation::validate_impact;
+fn compute_impact_score(bullets: &[String], vague_words: &[VagueWords]) -> f64 {
     if bullets.is_empty() {
         return 0.5;
     }
     let total: f32 = bullets
         .iter()
-        .map(|b| validate_impact(b).quality_score)
+        .map(|b| validate_impact_with(b, vague_words).quality_score)
         .sum();
     (total as f64 / bullets.len() as f64).clamp(0.0, 1.0)
 }
@@ -1,12 +1,17 @@
 pub mod batch;
+pub mod compaction;
 pub mod completeness;
 pub mod dedup;
 pub mod extractor;
+pub mod freshness;
 pub mod handlers;
 pub mod ingest;
 pub mod merger;
 pub mod models;
+pub mod outcomes;
 pub mod prompts;
+pub mod quality;
+pub mod retag;
 pub mod scoring;
 pub mod splitter;
 pub mod validation;
@@ -0,0 +1,300 @@
+//! Quantified-outcome detection, shared by ingest validation (`validation.rs`) and
+//! 2-line promotion scoring (`layout::contract`), so a bullet one calls quantified the
+//! other does too.
+//!
+//! The text is split into numbers, words and symbols, then matched against the shapes
+//! outcomes take in resume bullets:
+//! - a number with a unit: "40%", "3.47x", "$2.5M", "500k users", "sub-100ms", "8 minutes"
+//! - a before/after pair or range: "from 45 to 8 minutes", "12s → 3s", "10-20%"
+//! - a ratio in words: "cut costs in half", "doubled", "threefold", "by a third"
+//!
+//! A bare count ("a team of 5", "in 2021") is not an outcome.
+
+/// Which shape of outcome a bullet contains; the most specific one found wins.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Outcome {
+    /// A before/after pair or a range of values.
+    Change,
+    Percent,
+    Currency,
+    Multiplier,
+    /// A time, size, rate or magnitude: "100ms", "8 minutes", "2 GB", "500k".
+    Measure,
+    /// A ratio said in words: "in half", "doubled", "threefold".
+    Ratio,
+}
+
+/// Whether `text` states a quantified outcome.
+pub fn has_quantified_outcome(text: &str) -> bool {
+    detect_outcome(text).is_some()
+}
+
+/// The most specific outcome in `text`, if any.
+pub fn detect_outcome(text: &str) -> Option<Outcome> {
+    let lower = text.to_lowercase();
+    let tokens = tokenize(&lower);
+    let quantities: Vec<(usize, Option<Outcome>)> = tokens
+        .iter()
+        .enumerate()
+        .filter(|(_, t)| matches!(t, Token::Number { .. }))
+        .map(|(i, _)| (i, classify_number(&tokens, i)))
+        .collect();
+
+    if quantities
+        .iter()
+        .any(|&(i, kind)| is_change(&tokens, i, kind))
+    {
+        return Some(Outcome::Change);
+    }
+    if let Some(kind) = quantities.iter().find_map(|&(_, kind)| kind) {
+        return Some(kind);
+    }
+    has_ratio_words(&tokens).then_some(Outcome::Ratio)
+}
+
+// ────────────────────────────────────────────────────────────────────────────
+// Tokens
+// ────────────────────────────────────────────────────────────────────────────
+
+#[derive(Debug, Clone, PartialEq)]
+enum Token<'a> {
+    /// Digits (with `.` or `,` between them) and any letters or `%` glued to the end.
+    Number {
+        digits: &'a str,
+        suffix: &'a str,
+    },
+    /// Letters, and digits after them: "k8s", "p99" and "ec2" are names, not numbers.
+    Word(&'a str),
+    Symbol(char),
+}
+
+/// Units that make a number a percentage.
+const PERCENT_UNITS: &[&str] = &["%", "percent", "pct", "pp", "bps"];
+/// Units that make a number a multiplier.
+const MULTIPLIER_UNITS: &[&str] = &["x", "fold"];
+/// ISO codes accepted before or after an amount; symbols are `CURRENCY_SYMBOLS`.
+const CURRENCY_CODES: &[&str] = &["usd", "eur", "gbp", "inr", "jpy", "cad", "aud", "chf"];
+const CURRENCY_SYMBOLS: &[char] = &[
+    '$', '€', '£', '¥', '₹', '₩', '₽', '₺', '₪', '₫', '₱', '₦', '₴', '฿', '¢', '元', '円',
+];
+/// Time, size, rate and magnitude units. Years are left out: "5 years" is tenure.
+const MEASURE_UNITS: &[&str] = &[
+    "ms", "s", "sec", "secs", "second", "seconds", "min", "mins", "minute", "minutes", "h", "hr",
+    "hrs", "hour", "hours", "day", "days", "week", "weeks", "month", "months", "k", "m", "mm", "b",
+    "bn", "million", "billion", "kb", "mb", "gb", "tb", "pb", "rps", "qps", "tps",
+];
+/// Words that join the two sides of a before/after pair or range.
+const CHANGE_JOINERS: &[&str] = &["to", "through"];
+
+fn tokenize(text: &str) -> Vec<Token<'_>> {
+    let mut tokens = vec![];
+    let mut chars = text.char_indices().peekable();
+    while let Some((start, c)) = chars.next() {
+        if c.is_numeric() {
+            let mut end = start + c.len_utf8();
+            // Digits, and `.`/`,` only when another digit follows ("1,000.5", not "5.").
+            while let Some(&(i, next)) = chars.peek() {
+                let continues = next.is_numeric()
+                    || (matches!(next, '.' | ',')
+                        && text[i + 1..].chars().next().is_some_and(char::is_numeric));
+                if !continues {
+                    break;
+                }
+                end = i + next.len_utf8();
+                chars.next();
+            }
+            let digits = &text[start..end];
+            let suffix_start = end;
+            while let Some(&(i, next)) = chars.peek() {
+                if !(next.is_alphabetic() || next == '%') {
+                    break;
+                }
+                end = i + next.len_utf8();
+                chars.next();
+            }
+            tokens.push(Token::Number {
+                digits,
+                suffix: &text[suffix_start..end],
+            });
+        } else if c.is_alphabetic() {
+            let mut end = start + c.len_utf8();
+            while let Some(&(i, next)) = chars.peek() {
+                if !next.is_alphanumeric() {
+                    break;
+                }
+                end = i + next.len_utf8();
+                chars.next();
+            }
+            tokens.push(Token::Word(&text[start..end]));
+        } else if !c.is_whitespace() {
+            tokens.push(Token::Symbol(c));
+        }
+    }
+    tokens
+}
+
+// ────────────────────────────────────────────────────────────────────────────
+// Matching
+// ────────────────────────────────────────────────────────────────────────────
+
+/// What the number at `i` measures, from its glued suffix or the token after it.
+fn classify_number(tokens: &[Token<'_>], i: usize) -> Option<Outcome> {
+    let Token::Number { suffix, .. } = tokens[i] else {
+        return None;
+    };
+    let unit = match (suffix, tokens.get(i + 1)) {
+        ("", Some(Token::Word(w))) => *w,
+        ("", Some(Token::Symbol('%'))) => "%",
+        // "2-fold"
+        ("", Some(Token::Symbol('-'))) if tokens.get(i + 2) == Some(&Token::Word("fold")) => "fold",
+        (suffix, _) => suffix,
+    };
+    let currency_before = match i.checked_sub(1).map(|j| &tokens[j]) {
+        Some(Token::Symbol(c)) => CURRENCY_SYMBOLS.contains(c),
+        Some(Token::Word(w)) => CURRENCY_CODES.contains(w),
+        _ => false,
+    };
+    let currency_after = match tokens.get(i + 1) {
+        Some(Token::Symbol(c)) => CURRENCY_SYMBOLS.contains(c),
+        _ => CURRENCY_CODES.contains(&unit),
+    };
+
+    if currency_before || currency_after {
+        Some(Outcome::Currency)
+    } else if PERCENT_UNITS.contains(&unit) {
+        Some(Outcome::Percent)
+    } else if MULTIPLIER_UNITS.contains(&unit) {
+        Some(Outcome::Multiplier)
+    } else if MEASURE_UNITS.contains(&unit) {
+        Some(Outcome::Measure)
+    } else {
+        None
+    }
+}
+
+/// Whether the number at `i` starts a before/after pair or range: a joiner ("to",
+/// "→", "->", "-", "–") and a second number follow, and either side has a unit or
+/// "from" comes first. "2019-2021" and "3 to 5 engineers" are not changes, and
+/// neither is "from 2019 to 2021".
+fn is_change(tokens: &[Token<'_>], i: usize, kind: Option<Outcome>) -> bool {
+    // Skip a unit word after the first number: "45 minutes to 8 minutes".
+    let spaced_unit = matches!(tokens[i], Token::Number { suffix: "", .. })
+        && matches!(tokens.get(i + 1), Some(Token::Word(w)) if !CHANGE_JOINERS.contains(w));
+    let j = if kind.is_some() && spaced_unit {
+        i + 2
+    } else {
+        i + 1
+    };
+    let mut joined = match tokens.get(j) {
+        Some(Token::Word(w)) if CHANGE_JOINERS.contains(w) => j + 1,
+        Some(Token::Symbol('→' | '–' | '—')) => j + 1,
+        Some(Token::Symbol('-')) if tokens.get(j + 1) == Some(&Token::Symbol('>')) => j + 2,
+        Some(Token::Symbol('-')) => j + 1,
+        _ => return false,
+    };
+    // "$5M to $8M"
+    if matches!(tokens.get(joined), Some(Token::Symbol(c)) if CURRENCY_SYMBOLS.contains(c)) {
+        joined += 1;
+    }
+    if !matches!(tokens.get(joined), Some(Token::Number { .. })) {
+        return false;
+    }
+    if is_year(&tokens[i]) && is_year(&tokens[joined]) {
+        return false;
+    }
+    let from = i
+        .checked_sub(1)
+        .is_some_and(|p| tokens[p] == Token::Word("from"));
+    from || kind.is_some() || classify_number(tokens, joined).is_some()
+}
+
+/// A bare four-digit number from 1900 to 2099.
+fn is_year(token: &Token<'_>) -> bool {
+    matches!(token, Token::Number { digits, suffix: "" }
+        if digits.len() == 4 && (digits.starts_with("19") || digits.starts_with("20")))
+}
+
+/// "in half", "by half", "by a third", "halved", "doubled", "threefold", …
+fn has_ratio_words(tokens: &[Token<'_>]) -> bool {
+    const RATIO_VERBS: &[&str] = &["halved", "doubled", "tripled", "quadrupled"];
+    const FOLD_WORDS: &[&str] = &["twofold", "threefold", "fourfold", "fivefold", "tenfold"];
+    const FRACTIONS: &[&str] = &["half", "third", "thirds", "quarter", "quarters"];
+
+    tokens.iter().enumerate().any(|(i, t)| match t {
+        Token::Word(w) if RATIO_VERBS.contains(w) || FOLD_WORDS.contains(w) => true,
+        Token::Word(w) if FRACTIONS.contains(w) => {
+            let before = |n: usize| i.checked_sub(n).map(|j| &tokens[j]);
+            matches!(before(1), Some(Token::Word("in" | "by")))
+                || (matches!(before(1), Some(Token::Word("a" | "one" | "two")))
+                    && matches!(before(2), Some(Token::Word("by"))))
+        }
+        _ => false,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_units_glued_or_spaced() {
+        for (text, kind) in [
+            ("Reduced latency by 40%", Outcome::Percent),
+            ("Cut error rate 12 percent", Outcome::Percent),
+            ("Improved throughput by 3.47x", Outcome::Multiplier),
+            ("Sped up builds 2-fold", Outcome::Multiplier),
+            ("Saved $2.5M annually", Outcome::Currency),
+            ("Closed a 40 000 € contract", Outcome::Currency),
+            ("Raised USD 5m in seed funding", Outcome::Currency),
+            ("Served 500k users", Outcome::Measure),
+            ("Kept p99 at sub-100ms latency", Outcome::Measure),
+            ("Shrank the image by 2 GB", Outcome::Measure),
+            ("Onboarded new hires in 3 days", Outcome::Measure),
+        ] {
+            assert_eq!(detect_outcome(text), Some(kind), "{text}");
+        }
+    }
+
+    #[test]
+    fn test_before_after_pairs_and_ranges() {
+        for text in [
+            "Cut deploy time from 45 minutes to 8 minutes",
+            "Cut deploy time from 45 to 8 minutes",
+            "Reduced cold starts from 12 to 3",
+            "Grew ARR from $5M to $8M",
+            "Brought page load 4.2s → 1.1s",
+            "Brought page load 4.2s -> 1.1s",
+            "Lifted conversion 10-20% across cohorts",
+        ] {
+            assert_eq!(detect_outcome(text), Some(Outcome::Change), "{text}");
+        }
+    }
+
+    #[test]
+    fn test_ratios_in_words() {
+        for text in [
+            "Cut infrastructure costs in half",
+            "Doubled weekly active users",
+            "Grew signups threefold",
+            "Reduced churn by a third",
+        ] {
+            assert_eq!(detect_outcome(text), Some(Outcome::Ratio), "{text}");
+        }
+    }
+
+    #[test]
+    fn test_bare_counts_and_years_are_not_outcomes() {
+        for text in [
+            "Led a team of 5 engineers",
+            "Maintained the service from 2019-2021",
+            "Ran the on-call rotation from 2019 to 2021",
+            "Moved 40 services to k8s and tuned p99 alerts",
+            "Hired 3 to 5 contractors per quarter",
+            "Worked with 5 years of historical data",
+            "Improved performance significantly",
+            "Split the monolith into halves",
+        ] {
+            assert_eq!(detect_outcome(text), None, "{text}");
+        }
+    }
+}
@@ -155,3 +155,40 @@ RULES:
 3. If a bullet has no metrics, set confidence_marker to "[LOW_METRICS]"
 4. Dates must be "YYYY-MM-DD". Use "YYYY-01-01" if only year is known.
 5. Return ONLY the JSON object — nothing else, no code fences."#;
+
+// ────────────────────────────────────────────────────────────────────────────
+// Bulk re-tagging
+// ────────────────────────────────────────────────────────────────────────────
+
+pub const RETAG_SYSTEM: &str = "\
+You are curating the tags on a candidate's resume context entries. \
+Tags are matched against job description keywords, so they must use the names a job \
+description would use. \
+You MUST respond with valid JSON only. \
+Do NOT include any text outside the JSON object. \
+Do NOT use markdown code fences.";
+
+/// Replace `{entries_json}` before sending.
+pub const RETAG_PROMPT: &str = r#"Propose an improved tag list for each context entry below.
+
+Each entry has its entry_id, entry_type, data and current tags.
+
+Good tags:
+1. Technologies under their canonical names, lowercase: "kubernetes" not "k8s", "postgresql" not "postgres", "javascript" not "js"
+2. One seniority marker when the data supports it: "junior", "mid", "senior", "staff", "lead" or "manager"
+3. Business or technical domains the work belongs to: "payments", "distributed systems", "machine learning"
+4. The employer, school, or project name as already tagged
+5. Only what the entry's data supports — do NOT invent skills or domains
+
+Keep the entry_type as a tag. Drop duplicates, misspellings and tags too vague to match a job description ("stuff", "misc").
+Use at most 20 tags per entry.
+
+Return a JSON object with this EXACT schema (no extra fields), with one element per entry:
+{
+  "entries": [
+    {"entry_id": "uuid", "tags": ["experience", "rust", "payments", "senior"]}
+  ]
+}
+
+ENTRIES:
+{entries_json}"#;
@@ -0,0 +1,529 @@
+//! Per-entry quality assessment for `GET /api/v1/context/entries/:id/quality`.
+//!
+//! The completeness report (completeness.rs) grades whole sections; this grades one
+//! entry on four components and says what to change:
+//! - impact: each bullet through `validate_impact_with`, with the user's domain pack
+//! - recency: the entry's recency score as of today, under the user's recency policy
+//! - tags: how much the entry gives JD matching to work with (tech stack, skill items,
+//!   organization)
+//! - grounding: whether generated bullets can be checked against the entry — a known
+//!   contribution type, bullets whose verbs fit it, and the identifying fields
+//!
+//! The composite is a weighted mean of the components that apply: entry types without
+//! bullets (skills, education, certifications, awards) have no impact component.
+
+use chrono::NaiveDate;
+use serde::Serialize;
+use uuid::Uuid;
+
+use crate::context::scoring::{recency_score_at, DecayCurve};
+use crate::context::validation::{validate_impact_with, VagueWords};
+use crate::generation::static_sections::parse_date;
+use crate::grounding::scope_check::check_scope_inflation;
+use crate::models::context::ContextEntryRow;
+
+/// Entry types whose achievements are written as bullets.
+const BULLET_TYPES: &[&str] = &["experience", "project", "open_source", "extracurricular"];
+
+/// Entry types that record how much of the work was the user's.
+const CONTRIBUTION_TYPES: &[&str] = &["experience", "project", "open_source", "publication"];
+
+const KNOWN_CONTRIBUTIONS: &[&str] = &[
+    "sole_author",
+    "primary_contributor",
+    "team_member",
+    "reviewer",
+];
+
+/// Tags beyond the entry type at which the tag component scores 1.0.
+const FULL_TAGS: usize = 5;
+
+/// Recency below this is called out as stale.
+const STALE_RECENCY: f64 = 0.3;
+
+const IMPACT_WEIGHT: f64 = 0.4;
+const GROUNDING_WEIGHT: f64 = 0.3;
+const RECENCY_WEIGHT: f64 = 0.15;
+const TAGS_WEIGHT: f64 = 0.15;
+
+// ────────────────────────────────────────────────────────────────────────────
+// Response types
+// ────────────────────────────────────────────────────────────────────────────
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum QualityComponent {
+    Impact,
+    Recency,
+    Tags,
+    Grounding,
+}
+
+/// One change that would raise the entry's score.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct QualitySuggestion {
+    pub component: QualityComponent,
+    /// 0-based index into the entry's bullets, when the fix is to one bullet.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub bullet_index: Option<usize>,
+    pub message: String,
+}
+
+/// Impact validation of one bullet.
+#[derive(Debug, Clone, Serialize)]
+pub struct BulletQuality {
+    pub text: String,
+    pub quality_score: f32,
+    pub flags: Vec<String>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ImpactComponent {
+    /// Mean of the bullets' scores; 0.0 when the entry has none.
+    pub score: f64,
+    pub bullets: Vec<BulletQuality>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct RecencyComponent {
+    /// As of today; the stored `recency_score` is as of the entry's last version.
+    pub score: f64,
+    /// Absent for ongoing entries and those without an end date.
+    pub date_end: Option<NaiveDate>,
+    pub evergreen: bool,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct TagsComponent {
+    pub score: f64,
+    /// The entry's tags other than its type.
+    pub tags: Vec<String>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct GroundingComponent {
+    pub score: f64,
+    pub contribution_type: Option<String>,
+    /// What would make generated bullets fail or be flagged by the grounding check.
+    pub issues: Vec<String>,
+}
+
+/// Response body for `GET /api/v1/context/entries/:id/quality`.
+#[derive(Debug, Clone, Serialize)]
+pub struct EntryQualityReport {
+    pub entry_id: Uuid,
+    pub version: i32,
+    pub entry_type: String,
+    /// 0.0–1.0, the weighted mean of the components that apply.
+    pub score: f64,
+    /// Absent for entry types that have no bullets.
+    pub impact: Option<ImpactComponent>,
+    pub recency: RecencyComponent,
+    pub tags: TagsComponent,
+    pub grounding: GroundingComponent,
+    /// Grounding fixes first, then impact, tags and recency.
+    pub suggestions: Vec<QualitySuggestion>,
+}
+
+// ────────────────────────────────────────────────────────────────────────────
+// Assessment
+// ────────────────────────────────────────────────────────────────────────────
+
+/// Assesses `entry` as of `today`. `curve` is the decay curve the user's recency
+/// policy gives the entry; `vague_words` come from their domain pack.
+pub fn assess_entry(
+    entry: &ContextEntryRow,
+    curve: &DecayCurve,
+    vague_words: &[VagueWords],
+    today: NaiveDate,
+) -> EntryQualityReport {
+    let bullets = bullet_texts(&entry.data);
+    let mut suggestions = Vec::new();
+
+    let grounding = assess_grounding(entry, &bullets, &mut suggestions);
+    let impact = BULLET_TYPES
+        .contains(&entry.entry_type.as_str())
+        .then(|| assess_impact(&bullets, vague_words, &mut suggestions));
+    let tags = assess_tags(entry, &mut suggestions);
+    let recency = assess_recency(entry, curve, today, &mut suggestions);
+
+    let mut weighted = vec![
+        (grounding.score, GROUNDING_WEIGHT),
+        (tags.score, TAGS_WEIGHT),
+        (recency.score, RECENCY_WEIGHT),
+    ];
+    if let Some(impact) = &impact {
+        weighted.push((impact.score, IMPACT_WEIGHT));
+    }
+    let total_weight: f64 = weighted.iter().map(|(_, w)| w).sum();
+    let score = weighted.iter().map(|(s, w)| s * w).sum::<f64>() / total_weight;
+
+    EntryQualityReport {
+        entry_id: entry.entry_id,
+        version: entry.version,
+        entry_type: entry.entry_type.clone(),
+        score: score.clamp(0.0, 1.0),
+        impact,
+        recency,
+        tags,
+        grounding,
+        suggestions,
+    }
+}
+
+fn assess_impact(
+    bullets: &[String],
+    vague_words: &[VagueWords],
+    suggestions: &mut Vec<QualitySuggestion>,
+) -> ImpactComponent {
+    let bullets: Vec<BulletQuality> =
+        bullets
+            .iter()
+            .enumerate()
+            .map(|(i, text)| {
+                let quality = validate_impact_with(text, vague_words);
+                suggestions.extend(quality.suggestions.into_iter().map(|message| {
+                    QualitySuggestion {
+                        component: QualityComponent::Impact,
+                        bullet_index: Some(i),
+                        message,
+                    }
+                }));
+                BulletQuality {
+                    text: text.clone(),
+                    quality_score: quality.quality_score,
+                    flags: quality.flags,
+                }
+            })
+            .collect();
+    let score = if bullets.is_empty() {
+        0.0
+    } else {
+        bullets.iter().map(|b| b.quality_score as f64).sum::<f64>() / bullets.len() as f64
+    };
+    ImpactComponent { score, bullets }
+}
+
+fn assess_recency(
+    entry: &ContextEntryRow,
+    curve: &DecayCurve,
+    today: NaiveDate,
+    suggestions: &mut Vec<QualitySuggestion>,
+) -> RecencyComponent {
+    let date_end = parse_date(entry.data.get("date_end"));
+    let score = recency_score_at(date_end, curve, today);
+    if let Some(end) = date_end.filter(|_| score < STALE_RECENCY && !entry.flagged_evergreen) {
+        suggestions.push(QualitySuggestion {
+            component: QualityComponent::Recency,
+            bullet_index: None,
+            message: format!(
+                "Ended {}, so it ranks low against recent work. If it is still relevant, flag it evergreen.",
+                end.format("%b %Y")
+            ),
+        });
+    }
+    RecencyComponent {
+        score,
+        date_end,
+        evergreen: entry.flagged_evergreen,
+    }
+}
+
+fn assess_tags(entry: &ContextEntryRow, suggestions: &mut Vec<QualitySuggestion>) -> TagsComponent {
+    let tags: Vec<String> = entry
+        .tags
+        .iter()
+        .filter(|t| **t != entry.entry_type)
+        .cloned()
+        .collect();
+    if tags.len() < FULL_TAGS {
+        let field = match entry.entry_type.as_str() {
+            "skill" => Some("items"),
+            "experience" | "project" | "open_source" => Some("tech_stack"),
+            _ => None,
+        };
+        if let Some(field) = field {
+            suggestions.push(QualitySuggestion {
+                component: QualityComponent::Tags,
+                bullet_index: None,
+                message: format!(
+                    "Only {} tag{}: list the tools and skills involved in `{field}` so job descriptions that ask for them match this entry.",
+                    tags.len(),
+                    if tags.len() == 1 { "" } else { "s" }
+                ),
+            });
+        }
+    }
+    TagsComponent {
+        score: (tags.len() as f64 / FULL_TAGS as f64).min(1.0),
+        tags,
+    }
+}
+
+fn assess_grounding(
+    entry: &ContextEntryRow,
+    bullets: &[String],
+    suggestions: &mut Vec<QualitySuggestion>,
+) -> GroundingComponent {
+    let entry_type = entry.entry_type.as_str();
+    let mut issues = Vec::new();
+    let mut suggest = |issue: String, bullet_index: Option<usize>, fix: String| {
+        issues.push(issue);
+        suggestions.push(QualitySuggestion {
+            component: QualityComponent::Grounding,
+            bullet_index,
+            message: fix,
+        });
+    };
+
+    let missing: Vec<&str> = identifying_fields(entry_type)
+        .iter()
+        .copied()
+        .filter(|field| {
+            entry
+                .data
+                .get(*field)
+                .and_then(|v| v.as_str())
+                .is_none_or(|s| s.trim().is_empty())
+        })
+        .collect();
+    if !missing.is_empty() {
+        suggest(
+            format!("missing {}", missing.join(", ")),
+            None,
+            format!(
+                "Add the {}: generated bullets are checked against what the entry records.",
+                missing.join(" and ")
+            ),
+        );
+    }
+
+    let contribution = entry
+        .data
+        .get("contribution_type")
+        .and_then(|v| v.as_str())
+        .filter(|c| KNOWN_CONTRIBUTIONS.contains(c));
+    if CONTRIBUTION_TYPES.contains(&entry_type) {
+        match contribution {
+            None => suggest(
+                "unknown contribution type".to_string(),
+                None,
+                "Set contribution_type (sole_author, primary_contributor, team_member or reviewer). \
+                 Until then it is treated as team_member, and bullets claiming ownership fail grounding."
+                    .to_string(),
+            ),
+            Some(contribution) => {
+                for (i, bullet) in bullets.iter().enumerate() {
+                    if let Some(reason) = check_scope_inflation(bullet, contribution) {
+                        suggest(
+                            format!("bullet {} overstates a {contribution} role", i + 1),
+                            Some(i),
+                            format!(
+                                "{reason} Reword it to what you did, or correct contribution_type if it is wrong."
+                            ),
+                        );
+                    }
+                }
+            }
+        }
+    }
+
+    if BULLET_TYPES.contains(&entry_type) && bullets.is_empty() {
+        suggest(
+            "no bullets".to_string(),
+            None,
+            "Add bullets describing what you did: generation can only claim what the entry says."
+                .to_string(),
+        );
+    }
+
+    GroundingComponent {
+        score: (1.0 - 0.25 * issues.len() as f64).max(0.0),
+        contribution_type: contribution.map(str::to_string),
+        issues,
+    }
+}
+
+/// Fields a reader needs to place the entry, by type.
+fn identifying_fields(entry_type: &str) -> &'static [&'static str] {
+    match entry_type {
+        "experience" => &["company", "role", "date_start"],
+        "education" => &["institution", "degree"],
+        "project" => &["name"],
+        "open_source" => &["project_name"],
+        "certification" => &["name", "issuer"],
+        "award" | "publication" => &["title"],
+        "extracurricular" => &["organization", "role"],
+        _ => &[],
+    }
+}
+
+/// Bullet texts, whether stored as `{"text": ..}` objects or plain strings.
+fn bullet_texts(data: &serde_json::Value) -> Vec<String> {
+    data.get("bullets")
+        .and_then(|v| v.as_array())
+        .map(|bullets| {
+            bullets
+                .iter()
+                .filter_map(|b| b.get("text").and_then(|t| t.as_str()).or(b.as_str()))
+                .map(str::to_string)
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+// ────────────────────────────────────────────────────────────────────────────
+// Tests
+// ────────────────────────────────────────────────────────────────────────────
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::context::validation::ALL_VAGUE_WORDS;
+    use serde_json::json;
+
+    fn today() -> NaiveDate {
+        NaiveDate::from_ymd_opt(2026, 6, 1).unwrap()
+    }
+
+    fn entry(entry_type: &str, data: serde_json::Value, tags: &[&str]) -> ContextEntryRow {
+        ContextEntryRow {
+            id: Uuid::new_v4(),
+            user_id: Uuid::nil(),
+            entry_id: Uuid::new_v4(),
+            version: 3,
+            entry_type: entry_type.to_string(),
+            data,
+            raw_text: None,
+            recency_score: 1.0,
+            impact_score: 1.0,
+            tags: tags.iter().map(|t| t.to_string()).collect(),
+            flagged_evergreen: false,
+            contribution_type: "team_member".to_string(),
+            quality_score: 1.0,
+            quality_flags: vec![],
+            created_at: chrono::Utc::now(),
+            recency_policy: None,
+        }
+    }
+
+    fn assess(entry: &ContextEntryRow) -> EntryQualityReport {
+        assess_entry(entry, &DecayCurve::default(), ALL_VAGUE_WORDS, today())
+    }
+
+    #[test]
+    fn test_strong_entry_scores_high_without_suggestions() {
+        let strong = entry(
+            "experience",
+            json!({
+                "company": "Acme", "role": "Engineer", "date_start": "2023-01-01",
+                "contribution_type": "primary_contributor",
+                "bullets": [{"text": "Cut p99 latency by 40% across 12 services"}],
+            }),
+            &["experience", "acme", "rust", "postgres", "kafka", "redis"],
+        );
+
+        let report = assess(&strong);
+
+        assert!(report.score > 0.95, "{}", report.score);
+        assert!(report.suggestions.is_empty(), "{:?}", report.suggestions);
+        assert_eq!(report.impact.unwrap().bullets[0].quality_score, 1.0);
+    }
+
+    #[test]
+    fn test_weak_bullets_get_per_bullet_fixes() {
+        let weak = entry(
+            "project",
+            json!({
+                "name": "Tracker", "contribution_type": "sole_author",
+                "bullets": [{"text": "Shipped v2 in 3 weeks"}, {"text": "Improved many flows"}],
+            }),
+            &["project"],
+        );
+
+        let report = assess(&weak);
+        let impact = report.impact.as_ref().unwrap();
+
+        assert!(impact.score < 1.0);
+        assert!(report
+            .suggestions
+            .iter()
+            .any(|s| s.component == QualityComponent::Impact && s.bullet_index == Some(1)));
+        assert!(report.suggestions.iter().all(|s| s.bullet_index != Some(0)));
+        assert!(report
+            .suggestions
+            .iter()
+            .any(|s| s.component == QualityComponent::Tags && s.message.contains("tech_stack")));
+    }
+
+    #[test]
+    fn test_grounding_flags_missing_fields_and_overstated_verbs() {
+        let overstated = entry(
+            "experience",
+            json!({
+                "company": "Acme", "contribution_type": "team_member",
+                "bullets": [{"text": "Led the migration to Rust, saving $40k"}],
+            }),
+            &["experience"],
+        );
+
+        let grounding = assess(&overstated).grounding;
+
+        assert_eq!(grounding.contribution_type.as_deref(), Some("team_member"));
+        assert_eq!(grounding.issues.len(), 2, "{:?}", grounding.issues);
+        assert!(grounding.issues[0].contains("role"));
+        assert!(grounding.issues[1].contains("overstates"));
+        assert_eq!(grounding.score, 0.5);
+    }
+
+    #[test]
+    fn test_unknown_contribution_type_is_an_issue() {
+        let unknown = entry(
+            "open_source",
+            json!({"project_name": "templar", "bullets": ["Fixed 30 parser bugs"]}),
+            &["open_source"],
+        );
+
+        let report = assess(&unknown);
+
+        assert_eq!(report.grounding.issues, vec!["unknown contribution type"]);
+        assert_eq!(report.impact.unwrap().bullets.len(), 1);
+    }
+
+    #[test]
+    fn test_skills_have_no_impact_component() {
+        let skills = entry(
+            "skill",
+            json!({"category": "Languages", "items": ["Rust", "Go"]}),
+            &["skill", "rust", "go"],
+        );
+
+        let report = assess(&skills);
+
+        assert!(report.impact.is_none());
+        assert_eq!(report.tags.tags, vec!["rust", "go"]);
+        assert!(report.suggestions[0].message.contains("`items`"));
+    }
+
+    #[test]
+    fn test_stale_entries_suggest_evergreen_unless_flagged() {
+        let mut old = entry(
+            "extracurricular",
+            json!({"organization": "Chess club", "role": "Captain", "date_end": "2012-05-01"}),
+            &["extracurricular", "chess club"],
+        );
+
+        let report = assess(&old);
+        assert!(report.recency.score < STALE_RECENCY);
+        assert!(report
+            .suggestions
+            .iter()
+            .any(|s| s.component == QualityComponent::Recency && s.message.contains("May 2012")));
+
+        old.flagged_evergreen = true;
+        assert!(assess(&old)
+            .suggestions
+            .iter()
+            .all(|s| s.component != QualityComponent::Recency));
+    }
+}
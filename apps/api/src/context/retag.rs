@@ -0,0 +1,399 @@
+//! Bulk re-tagging — LLM-proposed tags for every current entry, reviewed before they
+//! are stored.
+//!
+//! POST /api/v1/context/retag/preview — proposed tags for the caller's current entries
+//! POST /api/v1/context/retag/apply   — store the accepted ones
+//!
+//! Tags drive JD relevance in selection and fit scoring, yet ingest only derives them
+//! from tech stacks and names (see `ingest::extract_tags`). The preview sends current
+//! entries to the LLM `RETAG_BATCH_SIZE` at a time and returns, for each entry whose
+//! tags would change, the current and proposed lists with what was added and removed.
+//! Nothing is stored until apply, which writes each accepted entry as a new version
+//! carrying the accepted tags (the client may edit them first), append-only like every
+//! other edit. An entry edited since its preview is skipped as stale.
+
+use futures::stream::{self, StreamExt};
+use serde::{Deserialize, Serialize};
+use sqlx::PgPool;
+use tracing::warn;
+use uuid::Uuid;
+use validator::Validate;
+
+use crate::context::prompts::{RETAG_PROMPT, RETAG_SYSTEM};
+use crate::context::versioning::get_current_entries;
+use crate::errors::AppError;
+use crate::llm_client::{LlmClient, LlmError};
+use crate::models::context::ContextEntryRow;
+use crate::personas::normalize_tags;
+
+/// Entries per LLM call.
+pub const RETAG_BATCH_SIZE: usize = 20;
+/// LLM calls in flight at once.
+const RETAG_CONCURRENCY: usize = 4;
+/// Most tags stored on one entry, its entry type included.
+pub const MAX_ENTRY_TAGS: u64 = 20;
+/// Longest tag kept; anything longer is a sentence, not a keyword.
+const MAX_TAG_CHARS: usize = 60;
+/// Most entries one apply may write.
+pub const MAX_RETAG_ENTRIES: u64 = 500;
+
+// ────────────────────────────────────────────────────────────────────────────
+// Request / response types
+// ────────────────────────────────────────────────────────────────────────────
+
+/// One entry whose tags the LLM would change.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct TagProposal {
+    pub entry_id: Uuid,
+    /// The version the proposal was made from; apply skips the entry if it moved on.
+    pub version: i32,
+    pub entry_type: String,
+    /// Company, project or institution name, when the entry has one.
+    pub label: Option<String>,
+    pub current_tags: Vec<String>,
+    pub proposed_tags: Vec<String>,
+    pub added: Vec<String>,
+    pub removed: Vec<String>,
+}
+
+/// Response of `POST /api/v1/context/retag/preview`.
+#[derive(Debug, Clone, Serialize)]
+pub struct RetagPreview {
+    pub proposals: Vec<TagProposal>,
+    /// Entries whose proposed tags match their current ones.
+    pub unchanged: usize,
+    /// Entries in batches the LLM failed on; preview again to retry them.
+    pub failed: usize,
+}
+
+/// Tags accepted for one entry, as proposed or edited.
+#[derive(Debug, Clone, Serialize, Deserialize, Validate)]
+pub struct AcceptedTags {
+    pub entry_id: Uuid,
+    /// `TagProposal::version`.
+    pub version: i32,
+    #[validate(length(min = 1, max = MAX_ENTRY_TAGS))]
+    pub tags: Vec<String>,
+}
+
+/// Body for `POST /api/v1/context/retag/apply`.
+#[derive(Debug, Clone, Deserialize, Validate)]
+pub struct RetagApplyRequest {
+    #[validate(length(min = 1, max = MAX_RETAG_ENTRIES), nested)]
+    pub accepted: Vec<AcceptedTags>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct AppliedTags {
+    pub entry_id: Uuid,
+    /// The new version carrying the tags.
+    pub version: i32,
+    pub previous_tags: Vec<String>,
+    pub tags: Vec<String>,
+}
+
+/// Response of `POST /api/v1/context/retag/apply`.
+#[derive(Debug, Clone, Serialize)]
+pub struct RetagApplyResponse {
+    pub applied: Vec<AppliedTags>,
+    /// Entries edited (or deleted) since the preview; nothing was written for them.
+    pub stale: Vec<Uuid>,
+}
+
+// ────────────────────────────────────────────────────────────────────────────
+// Preview
+// ────────────────────────────────────────────────────────────────────────────
+
+#[derive(Debug, Serialize)]
+struct BatchEntry<'a> {
+    entry_id: Uuid,
+    entry_type: &'a str,
+    data: &'a serde_json::Value,
+    tags: &'a [String],
+}
+
+#[derive(Debug, Deserialize)]
+struct RetagResponse {
+    #[serde(default)]
+    entries: Vec<ProposedTags>,
+}
+
+#[derive(Debug, Deserialize)]
+struct ProposedTags {
+    entry_id: Uuid,
+    #[serde(default)]
+    tags: Vec<String>,
+}
+
+/// Proposed tags for every current entry of `user_id`. Stores nothing.
+pub async fn preview(
+    db: &PgPool,
+    llm: &LlmClient,
+    user_id: Uuid,
+) -> Result<RetagPreview, AppError> {
+    let entries = get_current_entries(db, user_id).await?;
+    if entries.is_empty() {
+        return Err(AppError::Validation(
+            "No context entries to re-tag".to_string(),
+        ));
+    }
+
+    // Owned batches: a future borrowing each chunk makes the handler future non-Send.
+    let batches: Vec<Result<Vec<ProposedTags>, (usize, LlmError)>> =
+        stream::iter(entries.chunks(RETAG_BATCH_SIZE).map(<[_]>::to_vec))
+            .map(|batch| propose_batch(llm, batch))
+            .buffered(RETAG_CONCURRENCY)
+            .collect()
+            .await;
+
+    let mut proposed = Vec::new();
+    let mut failed = 0;
+    for batch in batches {
+        match batch {
+            Ok(tags) => proposed.extend(tags),
+            Err((len, e)) => {
+                warn!(%user_id, entries = len, error = %e, "Retag: LLM batch failed");
+                failed += len;
+            }
+        }
+    }
+
+    let mut proposals = Vec::new();
+    let mut unchanged = 0;
+    for entry in &entries {
+        // Unknown ids (hallucinated or from another batch's echo) match nothing here.
+        let Some(tags) = proposed.iter().find(|p| p.entry_id == entry.entry_id) else {
+            continue;
+        };
+        match diff(entry, &tags.tags) {
+            Some(proposal) => proposals.push(proposal),
+            None => unchanged += 1,
+        }
+    }
+    Ok(RetagPreview {
+        proposals,
+        unchanged,
+        failed,
+    })
+}
+
+/// Errors carry the batch size, for `RetagPreview::failed`.
+async fn propose_batch(
+    llm: &LlmClient,
+    batch: Vec<ContextEntryRow>,
+) -> Result<Vec<ProposedTags>, (usize, LlmError)> {
+    let payload: Vec<BatchEntry> = batch
+        .iter()
+        .map(|e| BatchEntry {
+            entry_id: e.entry_id,
+            entry_type: &e.entry_type,
+            data: &e.data,
+            tags: &e.tags,
+        })
+        .collect();
+    let entries_json = serde_json::to_string_pretty(&payload).unwrap_or_default();
+    let prompt = RETAG_PROMPT.replace("{entries_json}", &entries_json);
+    let response: RetagResponse = llm
+        .call_json(&prompt, RETAG_SYSTEM)
+        .await
+        .map_err(|e| (batch.len(), e))?;
+    Ok(response.entries)
+}
+
+/// The proposal for `entry`, or None when the tags would not change.
+fn diff(entry: &ContextEntryRow, proposed: &[String]) -> Option<TagProposal> {
+    let current = normalize_tags(&entry.tags);
+    let proposed = tags_for_entry(proposed, &entry.entry_type);
+    let added: Vec<String> = proposed
+        .iter()
+        .filter(|t| !current.contains(t))
+        .cloned()
+        .collect();
+    let removed: Vec<String> = current
+        .iter()
+        .filter(|t| !proposed.contains(t))
+        .cloned()
+        .collect();
+    if added.is_empty() && removed.is_empty() {
+        return None;
+    }
+    Some(TagProposal {
+        entry_id: entry.entry_id,
+        version: entry.version,
+        entry_type: entry.entry_type.clone(),
+        label: [
+            "company",
+            "institution",
+            "project_name",
+            "organization",
+            "name",
+        ]
+        .iter()
+        .find_map(|field| entry.data.get(*field).and_then(|v| v.as_str()))
+        .map(str::to_string),
+        current_tags: entry.tags.clone(),
+        proposed_tags: proposed,
+        added,
+        removed,
+    })
+}
+
+/// Tags as stored on an entry: normalized like every other tag list, overlong ones
+/// dropped, the entry type always present, at most `MAX_ENTRY_TAGS`. The first tags
+/// win the cut, since the LLM lists the most relevant first.
+pub fn tags_for_entry(tags: &[String], entry_type: &str) -> Vec<String> {
+    let mut kept: Vec<String> = Vec::new();
+    for tag in tags {
+        let tag = tag.split_whitespace().collect::<Vec<_>>().join(" ");
+        let tag = normalize_tags(&[tag]).pop();
+        if let Some(tag) = tag.filter(|t| t.chars().count() <= MAX_TAG_CHARS) {
+            if tag != entry_type && !kept.contains(&tag) {
+                kept.push(tag);
+            }
+        }
+    }
+    kept.truncate(MAX_ENTRY_TAGS as usize - 1);
+    kept.push(entry_type.to_string());
+    normalize_tags(&kept)
+}
+
+// ────────────────────────────────────────────────────────────────────────────
+// Apply
+// ────────────────────────────────────────────────────────────────────────────
+
+/// Writes each accepted entry whose current version is still the previewed one as a
+/// new version with the accepted tags, in one transaction.
+pub async fn apply(
+    db: &PgPool,
+    user_id: Uuid,
+    request: &RetagApplyRequest,
+) -> Result<RetagApplyResponse, AppError> {
+    let mut tx = db.begin().await?;
+    let mut response = RetagApplyResponse {
+        applied: vec![],
+        stale: vec![],
+    };
+    for accepted in &request.accepted {
+        let current = sqlx::query!(
+            r#"SELECT e.entry_type, e.tags
+               FROM context_current_entries c
+               JOIN context_entries e ON e.id = c.entry_row_id
+               WHERE c.user_id = $1 AND c.entry_id = $2 AND c.version = $3
+               FOR UPDATE OF c"#,
+            user_id,
+            accepted.entry_id,
+            accepted.version
+        )
+        .fetch_optional(&mut *tx)
+        .await?;
+        let Some(current) = current else {
+            response.stale.push(accepted.entry_id);
+            continue;
+        };
+        let tags = tags_for_entry(&accepted.tags, &current.entry_type);
+
+        // Append-only: INSERT a new version with the new tags, never UPDATE.
+        let version = sqlx::query_scalar!(
+            r#"
+            INSERT INTO context_entries
+                (user_id, entry_id, version, entry_type, data, raw_text,
+                 recency_score, impact_score, tags, flagged_evergreen, contribution_type,
+                 quality_score, quality_flags, recency_policy)
+            SELECT user_id, entry_id, version + 1, entry_type, data, raw_text,
+                   recency_score, impact_score, $4, flagged_evergreen, contribution_type,
+                   quality_score, quality_flags, recency_policy
+            FROM context_entries
+            WHERE user_id = $1 AND entry_id = $2 AND version = $3
+            RETURNING version
+            "#,
+            user_id,
+            accepted.entry_id,
+            accepted.version,
+            &tags
+        )
+        .fetch_one(&mut *tx)
+        .await?;
+        response.applied.push(AppliedTags {
+            entry_id: accepted.entry_id,
+            version,
+            previous_tags: current.tags,
+            tags,
+        });
+    }
+    tx.commit().await?;
+    Ok(response)
+}
+
+// ────────────────────────────────────────────────────────────────────────────
+// Tests
+// ────────────────────────────────────────────────────────────────────────────
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Utc;
+    use serde_json::json;
+
+    fn make_entry(tags: &[&str]) -> ContextEntryRow {
+        ContextEntryRow {
+            id: Uuid::new_v4(),
+            user_id: Uuid::new_v4(),
+            entry_id: Uuid::new_v4(),
+            version: 3,
+            entry_type: "experience".to_string(),
+            data: json!({ "company": "Acme" }),
+            raw_text: None,
+            recency_score: 0.5,
+            impact_score: 0.5,
+            tags: tags.iter().map(|t| t.to_string()).collect(),
+            flagged_evergreen: false,
+            contribution_type: "sole_author".to_string(),
+            quality_score: 1.0,
+            quality_flags: vec![],
+            created_at: Utc::now(),
+            recency_policy: None,
+        }
+    }
+
+    fn strings(tags: &[&str]) -> Vec<String> {
+        tags.iter().map(|t| t.to_string()).collect()
+    }
+
+    #[test]
+    fn test_tags_are_normalized_capped_and_keep_the_entry_type() {
+        let mut proposed = strings(&["Kubernetes", " kubernetes ", "Distributed   Systems", ""]);
+        proposed.push("x".repeat(MAX_TAG_CHARS + 1));
+        assert_eq!(
+            tags_for_entry(&proposed, "experience"),
+            vec!["distributed systems", "experience", "kubernetes"]
+        );
+
+        let many: Vec<String> = (0..30).map(|i| format!("tag{i:02}")).collect();
+        let capped = tags_for_entry(&many, "project");
+        assert_eq!(capped.len(), MAX_ENTRY_TAGS as usize);
+        assert!(capped.contains(&"project".to_string()));
+        assert!(capped.contains(&"tag00".to_string()));
+        assert!(!capped.contains(&"tag29".to_string()));
+    }
+
+    #[test]
+    fn test_diff_lists_added_and_removed_tags() {
+        let entry = make_entry(&["acme", "experience", "k8s"]);
+        let proposal = diff(&entry, &strings(&["acme", "kubernetes", "senior"])).unwrap();
+
+        assert_eq!(proposal.version, 3);
+        assert_eq!(proposal.label.as_deref(), Some("Acme"));
+        assert_eq!(
+            proposal.proposed_tags,
+            vec!["acme", "experience", "kubernetes", "senior"]
+        );
+        assert_eq!(proposal.added, vec!["kubernetes", "senior"]);
+        assert_eq!(proposal.removed, vec!["k8s"]);
+    }
+
+    #[test]
+    fn test_no_proposal_when_tags_would_not_change() {
+        let entry = make_entry(&["acme", "experience", "rust"]);
+        assert!(diff(&entry, &strings(&["Rust", "ACME"])).is_none());
+    }
+}
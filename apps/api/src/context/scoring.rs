@@ -1,9 +1,11 @@
 #![allow(dead_code)]
 
+use std::collections::BTreeMap;
+
 use chrono::{Datelike, NaiveDate, Utc};
 use serde::{Deserialize, Serialize};
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct ScoringWeights {
     pub recency: f64,
     pub impact: f64,
@@ -20,28 +22,150 @@ impl Default for ScoringWeights {
     }
 }
 
-/// Computes recency score with 18-month half-life exponential decay.
-/// Returns 1.0 for current positions (end_date = None) and evergreen entries.
-pub fn compute_recency_score(
-    end_date: Option<NaiveDate>,
-    flagged_evergreen: bool,
-    half_life_months: f64,
-) -> f64 {
-    if flagged_evergreen {
-        return 1.0;
-    }
-    let end_date = match end_date {
-        Some(d) => d,
-        None => return 1.0, // current position
-    };
-    let now = Utc::now().naive_utc().date();
-    let months_since = months_between(end_date, now);
-    if months_since <= 0.0 {
-        return 1.0;
-    }
-    (0.5_f64)
-        .powf(months_since / half_life_months)
-        .clamp(0.0, 1.0)
+/// Half-life of the default curve, and of every entry scored before decay policies.
+pub const DEFAULT_HALF_LIFE_MONTHS: f64 = 18.0;
+
+/// Longest half-life or step offset a policy may set (50 years).
+const MAX_DECAY_MONTHS: f64 = 600.0;
+
+/// Most steps a step curve may have.
+const MAX_DECAY_STEPS: usize = 12;
+
+/// How an entry's recency score falls after it ends. Each entry version stores the
+/// curve its score was computed with (`context_entries.recency_policy`), so a
+/// recomputation can reproduce the score with `recency_score_at`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "curve", rename_all = "snake_case")]
+pub enum DecayCurve {
+    /// Halves every `half_life_months`.
+    Exponential { half_life_months: f64 },
+    /// 1.0 until the first step, then each step's score once `after_months` have
+    /// passed. Steps ascend in `after_months` and never raise the score.
+    Step { steps: Vec<DecayStep> },
+    /// Always 1.0 — evergreen entries (skills, certifications) by default.
+    Never,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DecayStep {
+    pub after_months: f64,
+    pub score: f64,
+}
+
+impl Default for DecayCurve {
+    fn default() -> Self {
+        DecayCurve::Exponential {
+            half_life_months: DEFAULT_HALF_LIFE_MONTHS,
+        }
+    }
+}
+
+impl DecayCurve {
+    /// Score `months_since` months after the entry ended.
+    pub fn score(&self, months_since: f64) -> f64 {
+        if months_since <= 0.0 {
+            return 1.0;
+        }
+        match self {
+            DecayCurve::Exponential { half_life_months } => (0.5_f64)
+                .powf(months_since / half_life_months)
+                .clamp(0.0, 1.0),
+            DecayCurve::Step { steps } => steps
+                .iter()
+                .take_while(|s| s.after_months <= months_since)
+                .last()
+                .map_or(1.0, |s| s.score),
+            DecayCurve::Never => 1.0,
+        }
+    }
+
+    fn validate(&self) -> Result<(), String> {
+        match self {
+            DecayCurve::Exponential { half_life_months } => {
+                if !(*half_life_months > 0.0 && *half_life_months <= MAX_DECAY_MONTHS) {
+                    return Err(format!(
+                        "half_life_months must be above 0 and at most {MAX_DECAY_MONTHS}"
+                    ));
+                }
+            }
+            DecayCurve::Step { steps } => {
+                if steps.is_empty() || steps.len() > MAX_DECAY_STEPS {
+                    return Err(format!("a step curve needs 1 to {MAX_DECAY_STEPS} steps"));
+                }
+                if steps
+                    .iter()
+                    .any(|s| !(0.0..=MAX_DECAY_MONTHS).contains(&s.after_months))
+                {
+                    return Err(format!(
+                        "step after_months must be between 0 and {MAX_DECAY_MONTHS}"
+                    ));
+                }
+                if steps.iter().any(|s| !(0.0..=1.0).contains(&s.score)) {
+                    return Err("step scores must be between 0 and 1".to_string());
+                }
+                if steps
+                    .windows(2)
+                    .any(|w| w[1].after_months <= w[0].after_months || w[1].score > w[0].score)
+                {
+                    return Err(
+                        "steps must ascend in after_months and never raise the score".to_string(),
+                    );
+                }
+            }
+            DecayCurve::Never => {}
+        }
+        Ok(())
+    }
+}
+
+/// A user's recency settings (`user_preferences.recency_policy`): a curve per entry
+/// type — skills can decay slower than jobs — and one for every other type.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct RecencyPolicy {
+    #[serde(default)]
+    pub default: DecayCurve,
+    /// Keyed by `entry_type`. A curve here applies even to evergreen entries.
+    #[serde(default)]
+    pub by_entry_type: BTreeMap<String, DecayCurve>,
+}
+
+impl RecencyPolicy {
+    /// The curve an entry is scored with: its type's, else `Never` when evergreen,
+    /// else the default.
+    pub fn curve_for(&self, entry_type: &str, flagged_evergreen: bool) -> DecayCurve {
+        match self.by_entry_type.get(entry_type) {
+            Some(curve) => curve.clone(),
+            None if flagged_evergreen => DecayCurve::Never,
+            None => self.default.clone(),
+        }
+    }
+
+    pub fn validate(&self) -> Result<(), String> {
+        self.default
+            .validate()
+            .map_err(|e| format!("recency_policy.default: {e}"))?;
+        for (entry_type, curve) in &self.by_entry_type {
+            curve
+                .validate()
+                .map_err(|e| format!("recency_policy.by_entry_type.{entry_type}: {e}"))?;
+        }
+        Ok(())
+    }
+}
+
+/// Recency score of an entry that ended on `end_date`, as of today.
+/// Returns 1.0 for current positions (end_date = None).
+pub fn compute_recency_score(end_date: Option<NaiveDate>, curve: &DecayCurve) -> f64 {
+    recency_score_at(end_date, curve, Utc::now().naive_utc().date())
+}
+
+/// Recency score as of `today` — pass an entry's `created_at` date to reproduce the
+/// score stored with it.
+pub fn recency_score_at(end_date: Option<NaiveDate>, curve: &DecayCurve, today: NaiveDate) -> f64 {
+    match end_date {
+        Some(end_date) => curve.score(months_between(end_date, today)),
+        None => 1.0, // current position
+    }
 }
 
 /// Combined relevance score: 0.5*recency + 0.3*impact + 0.2*jd_relevance
@@ -67,24 +191,106 @@ fn months_between(start: NaiveDate, end: NaiveDate) -> f64 {
 mod tests {
     use super::*;
 
+    fn date(y: i32, m: u32, d: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(y, m, d).unwrap()
+    }
+
     #[test]
     fn test_evergreen_always_one() {
-        let old = NaiveDate::from_ymd_opt(2010, 1, 1);
-        assert_eq!(compute_recency_score(old, true, 18.0), 1.0);
+        let curve = RecencyPolicy::default().curve_for("skill", true);
+        assert_eq!(curve, DecayCurve::Never);
+        assert_eq!(compute_recency_score(Some(date(2010, 1, 1)), &curve), 1.0);
     }
 
     #[test]
     fn test_current_job_is_one() {
-        assert_eq!(compute_recency_score(None, false, 18.0), 1.0);
+        assert_eq!(compute_recency_score(None, &DecayCurve::default()), 1.0);
     }
 
     #[test]
     fn test_very_old_entry_low_score() {
-        let old = NaiveDate::from_ymd_opt(2010, 1, 1);
-        let score = compute_recency_score(old, false, 18.0);
+        let score = compute_recency_score(Some(date(2010, 1, 1)), &DecayCurve::default());
         assert!(score < 0.01, "Score was {score}");
     }
 
+    #[test]
+    fn test_default_curve_halves_every_eighteen_months() {
+        let curve = DecayCurve::default();
+        let score = recency_score_at(Some(date(2022, 1, 1)), &curve, date(2023, 7, 1));
+        assert!((score - 0.5).abs() < 1e-9, "Score was {score}");
+    }
+
+    #[test]
+    fn test_step_curve_holds_then_drops() {
+        let curve = DecayCurve::Step {
+            steps: vec![
+                DecayStep {
+                    after_months: 24.0,
+                    score: 0.6,
+                },
+                DecayStep {
+                    after_months: 60.0,
+                    score: 0.2,
+                },
+            ],
+        };
+        let end = Some(date(2020, 1, 1));
+        assert_eq!(recency_score_at(end, &curve, date(2021, 12, 1)), 1.0);
+        assert_eq!(recency_score_at(end, &curve, date(2022, 1, 1)), 0.6);
+        assert_eq!(recency_score_at(end, &curve, date(2030, 1, 1)), 0.2);
+        assert!(curve.validate().is_ok());
+    }
+
+    #[test]
+    fn test_per_type_curve_wins_over_default_and_evergreen() {
+        let slow = DecayCurve::Exponential {
+            half_life_months: 60.0,
+        };
+        let policy = RecencyPolicy {
+            default: DecayCurve::default(),
+            by_entry_type: BTreeMap::from([("skill".to_string(), slow.clone())]),
+        };
+        assert_eq!(policy.curve_for("skill", true), slow);
+        assert_eq!(policy.curve_for("certification", true), DecayCurve::Never);
+        assert_eq!(policy.curve_for("experience", false), DecayCurve::default());
+    }
+
+    #[test]
+    fn test_policy_round_trips_and_rejects_bad_curves() {
+        let policy: RecencyPolicy = serde_json::from_str(
+            r#"{"by_entry_type": {"project": {"curve": "step",
+                "steps": [{"after_months": 12, "score": 0.5}]}}}"#,
+        )
+        .unwrap();
+        assert_eq!(policy.default, DecayCurve::default());
+        assert!(policy.validate().is_ok());
+
+        for bad in [
+            DecayCurve::Exponential {
+                half_life_months: 0.0,
+            },
+            DecayCurve::Step { steps: vec![] },
+            DecayCurve::Step {
+                steps: vec![
+                    DecayStep {
+                        after_months: 12.0,
+                        score: 0.4,
+                    },
+                    DecayStep {
+                        after_months: 24.0,
+                        score: 0.7,
+                    },
+                ],
+            },
+        ] {
+            let policy = RecencyPolicy {
+                default: bad,
+                ..Default::default()
+            };
+            assert!(policy.validate().is_err(), "{policy:?}");
+        }
+    }
+
     #[test]
     fn test_combined_score_full() {
         let w = ScoringWeights::default();
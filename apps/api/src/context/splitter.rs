@@ -167,6 +167,7 @@ mod tests {
     }
 
     #[test]
+    #[allow(clippy::len_zero)]
     fn test_leading_separator_is_ignored() {
         // Document starting with --- should produce clean entries
         let raw = "---\nFirst real entry about distributed systems work.\n---\nSecond entry about machine learning project.";
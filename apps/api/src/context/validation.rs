@@ -2,6 +2,8 @@
 
 use serde::{Deserialize, Serialize};
 
+use crate::context::outcomes::has_quantified_outcome;
+
 /// Non-blocking quality assessment for a context entry bullet.
 ///
 /// Replaces the old pass/fail `ImpactValidationResult`. Ingest always proceeds;
@@ -52,56 +54,152 @@ impl ImpactQuality {
     }
 }
 
-const VAGUE_VERBS: &[&str] = &[
-    "improved",
-    "enhanced",
-    "helped",
-    "worked on",
-    "assisted",
-    "supported",
-    "participated",
-    "involved",
-];
+/// Vague verbs and scale words in one language, matched as whole words (so "many" does
+/// not match "Germany"). Lists must not contain ordinary words of the other built-in
+/// languages, since `validate_impact` checks a bullet against all of them.
+#[derive(Debug, Clone, Copy)]
+pub struct VagueWords {
+    /// ISO 639-1 code.
+    pub language: &'static str,
+    pub verbs: &'static [&'static str],
+    pub scale_words: &'static [&'static str],
+}
 
-const VAGUE_SCALE_WORDS: &[&str] = &[
-    "significant",
-    "major",
-    "large",
-    "huge",
-    "massive",
-    "substantial",
-    "considerable",
-    "great",
-    "many",
-    "numerous",
-    "various",
-    "several",
+pub const ENGLISH: VagueWords = VagueWords {
+    language: "en",
+    verbs: &[
+        "improved",
+        "enhanced",
+        "helped",
+        "worked on",
+        "assisted",
+        "supported",
+        "participated",
+        "involved",
+    ],
+    scale_words: &[
+        "significant",
+        "major",
+        "large",
+        "huge",
+        "massive",
+        "substantial",
+        "considerable",
+        "great",
+        "many",
+        "numerous",
+        "various",
+        "several",
+    ],
+};
+
+pub const GERMAN: VagueWords = VagueWords {
+    language: "de",
+    verbs: &[
+        "verbessert",
+        "unterstützt",
+        "geholfen",
+        "mitgewirkt",
+        "beteiligt",
+        "gearbeitet an",
+    ],
+    scale_words: &[
+        "erheblich",
+        "erhebliche",
+        "deutlich",
+        "deutliche",
+        "signifikant",
+        "viele",
+        "zahlreiche",
+        "verschiedene",
+        "mehrere",
+    ],
+};
+
+pub const FRENCH: VagueWords = VagueWords {
+    language: "fr",
+    verbs: &[
+        "amélioré",
+        "aidé",
+        "participé",
+        "contribué",
+        "soutenu",
+        "travaillé sur",
+    ],
+    scale_words: &[
+        "significatif",
+        "significative",
+        "considérable",
+        "nombreux",
+        "nombreuses",
+        "plusieurs",
+        "diverses",
+    ],
+};
+
+pub const SPANISH: VagueWords = VagueWords {
+    language: "es",
+    verbs: &[
+        "mejoré",
+        "mejorado",
+        "ayudé",
+        "participé",
+        "apoyé",
+        "trabajé en",
+    ],
+    scale_words: &[
+        "significativo",
+        "significativa",
+        "gran",
+        "grandes",
+        "muchos",
+        "muchas",
+        "varios",
+        "varias",
+        "diversos",
+    ],
+};
+
+/// Every built-in list; what `validate_impact` checks.
+pub const ALL_VAGUE_WORDS: &[VagueWords] = &[ENGLISH, GERMAN, FRENCH, SPANISH];
+
+/// Currency symbols that mark a bullet as quantified, like a digit does.
+const CURRENCY_SYMBOLS: &[char] = &[
+    '$', '€', '£', '¥', '₹', '₩', '₽', '₺', '₪', '₫', '₱', '₦', '₴', '฿', '¢', '元', '円',
 ];
 
-/// Assesses the impact quality of a single bullet string.
+/// ASCII, Arabic and fullwidth percent signs, and per mille.
+const PERCENT_SIGNS: &[char] = &['%', '٪', '％', '‰'];
+
+/// Assesses the impact quality of a single bullet string against every built-in
+/// vague-word list.
 ///
 /// Always returns an `ImpactQuality` — never blocks ingest.
 /// A score of 1.0 means fully quantified; 0.3 means no metrics at all.
 ///
-/// HIGH quality (score 1.0): contains digit, %, $, [LOW_METRICS], ~N, or Nx multiplier
+/// HIGH quality (score 1.0): contains a digit in any script, a percent sign, a currency
+/// symbol, [LOW_METRICS], or a quantified outcome without digits ("cut costs in half",
+/// "doubled"; see `crate::context::outcomes`, which promotion scoring also uses).
+/// Localized number formats ("40 %", "1.000,5", "3,5 Mio. €") and metric units
+/// ("120 km", "2 GB") carry digits.
 /// MEDIUM quality (score 0.5): no metrics but no vague language
 /// LOW quality (score 0.3–0.4): vague verbs or vague scale words
 pub fn validate_impact(text: &str) -> ImpactQuality {
+    validate_impact_with(text, ALL_VAGUE_WORDS)
+}
+
+/// `validate_impact` with the vague words of `languages` only.
+pub fn validate_impact_with(text: &str, languages: &[VagueWords]) -> ImpactQuality {
     let text_lower = text.to_lowercase();
 
-    let has_digit = text.chars().any(|c| c.is_ascii_digit());
+    // `is_numeric` also accepts Devanagari, Arabic-Indic, fullwidth and other digits.
+    let has_digit = text.chars().any(char::is_numeric);
     let has_low_metrics = text.contains("[LOW_METRICS]");
-    let has_tilde = text.contains('~') && text.chars().any(|c| c.is_ascii_digit());
-    let has_percent = text.contains('%');
-    let has_currency = text.contains('$') || text.contains('€') || text.contains('£');
-    let has_multiplier = has_digit
-        && (text_lower.contains("x faster")
-            || text_lower.contains("x improvement")
-            || text_lower.contains("x reduction")
-            || text_lower.contains("x more"));
+    let has_percent = text.contains(PERCENT_SIGNS);
+    let has_currency = text.contains(CURRENCY_SYMBOLS);
 
     let is_quantified =
-        has_digit || has_low_metrics || has_tilde || has_percent || has_currency || has_multiplier;
+        has_digit || has_low_metrics || has_percent || has_currency || has_quantified_outcome(text);
 
     if is_quantified {
         return ImpactQuality {
@@ -111,34 +209,40 @@ pub fn validate_impact(text: &str) -> ImpactQuality {
         };
     }
 
+    let words: Vec<&str> = text_lower
+        .split(|c: char| !c.is_alphanumeric())
+        .filter(|w| !w.is_empty())
+        .collect();
     let mut flags = Vec::new();
     let mut suggestions = Vec::new();
     let mut quality_score: f32 = 0.5; // default medium quality for no-metric bullets
 
     // Check for vague verbs
-    for &vague in VAGUE_VERBS {
-        if text_lower.contains(vague) {
-            flags.push(format!("vague_verb:{}", vague.replace(' ', "_")));
-            suggestions.push(format!(
-                "Quantify '{}': Add a number, percentage, or time metric. If data unavailable, append [LOW_METRICS].",
-                vague
-            ));
-            quality_score = 0.4;
-            break;
-        }
+    let vague_verb = languages
+        .iter()
+        .flat_map(|l| l.verbs)
+        .find(|verb| contains_phrase(&words, verb));
+    if let Some(vague) = vague_verb {
+        flags.push(format!("vague_verb:{}", vague.replace(' ', "_")));
+        suggestions.push(format!(
+            "Quantify '{}': Add a number, percentage, or time metric. If data unavailable, append [LOW_METRICS].",
+            vague
+        ));
+        quality_score = 0.4;
     }
 
     // Check for vague scale words
-    for &vague_scale in VAGUE_SCALE_WORDS {
-        if text_lower.contains(vague_scale) {
-            flags.push(format!("vague_scale:{}", vague_scale));
-            suggestions.push(format!(
-                "Replace '{}' with a specific number or percentage (e.g. '5x', '40%', '3 weeks').",
-                vague_scale
-            ));
-            quality_score = quality_score.min(0.4);
-            break;
-        }
+    let vague_scale = languages
+        .iter()
+        .flat_map(|l| l.scale_words)
+        .find(|word| contains_phrase(&words, word));
+    if let Some(vague_scale) = vague_scale {
+        flags.push(format!("vague_scale:{}", vague_scale));
+        suggestions.push(format!(
+            "Replace '{}' with a specific number or percentage (e.g. '5x', '40%', '3 weeks').",
+            vague_scale
+        ));
+        quality_score = quality_score.min(0.4);
     }
 
     // No metrics at all in an otherwise clean bullet
@@ -157,9 +261,24 @@ pub fn validate_impact(text: &str) -> ImpactQuality {
     }
 }
 
+/// Whether `phrase` (lowercase, words separated by single spaces) appears in `words`
+/// as consecutive whole words.
+fn contains_phrase(words: &[&str], phrase: &str) -> bool {
+    let phrase: Vec<&str> = phrase.split(' ').collect();
+    words.windows(phrase.len()).any(|window| window == phrase)
+}
+
 /// Assesses quality across a batch of bullets, returning an aggregate.
 pub fn validate_bullets(bullets: &[String]) -> ImpactQuality {
-    let qualities: Vec<_> = bullets.iter().map(|b| validate_impact(b)).collect();
+    validate_bullets_with(bullets, ALL_VAGUE_WORDS)
+}
+
+/// `validate_bullets` with the vague words of `languages` only.
+pub fn validate_bullets_with(bullets: &[String], languages: &[VagueWords]) -> ImpactQuality {
+    let qualities: Vec<_> = bullets
+        .iter()
+        .map(|b| validate_impact_with(b, languages))
+        .collect();
     ImpactQuality::aggregate(&qualities)
 }
 
@@ -280,6 +399,75 @@ mod tests {
         assert!(q.quality_score >= 0.4);
     }
 
+    // --- Non-English and non-US bullets ---
+
+    #[test]
+    fn test_high_quality_with_rupee_yen_and_localized_numbers() {
+        for text in [
+            "Saved ₹ crores in annual cloud spend",
+            "Cut infrastructure cost to ¥ per user",
+            "Reduced churn by 40 %",
+            "Generated 1.000,5 k€ in new revenue",
+            "Shortened delivery routes by 120 km",
+            "Raised conversion by ٤٠٪",
+            "Raised conversion by ４０％",
+            "बिक्री में ४० गुना वृद्धि",
+        ] {
+            assert_eq!(validate_impact(text).quality_score, 1.0, "{text}");
+        }
+    }
+
+    #[test]
+    fn test_vague_words_match_whole_words_only() {
+        // "many" in Germany, "major" in majority, "large" in enlarged
+        for text in [
+            "Launched the product in Germany",
+            "Won the majority of enterprise deals",
+            "Enlarged the on-call rotation",
+        ] {
+            let q = validate_impact(text);
+            assert_eq!(q.flags, vec!["missing_metric".to_string()], "{text}");
+        }
+    }
+
+    #[test]
+    fn test_vague_words_in_other_languages() {
+        let q = validate_impact("Die Datenbankleistung deutlich verbessert");
+        assert!(q.flags.contains(&"vague_verb:verbessert".to_string()));
+        assert!(q.flags.contains(&"vague_scale:deutlich".to_string()));
+
+        let q = validate_impact("Travaillé sur plusieurs projets internes");
+        assert!(q.flags.contains(&"vague_verb:travaillé_sur".to_string()));
+        assert!(q.flags.contains(&"vague_scale:plusieurs".to_string()));
+
+        let q = validate_impact("Mejoré la experiencia de usuario");
+        assert!(q.flags.contains(&"vague_verb:mejoré".to_string()));
+    }
+
+    #[test]
+    fn test_vague_word_lists_are_pluggable() {
+        let text = "Die Datenbankleistung verbessert";
+        assert!(validate_impact_with(text, &[ENGLISH])
+            .flags
+            .contains(&"missing_metric".to_string()));
+        assert!(validate_impact_with(text, &[GERMAN]).quality_score < 0.5);
+    }
+
+    #[test]
+    fn test_outcomes_without_digits_are_high_quality() {
+        // Promotion scoring and validation share the outcome parser: whatever one
+        // calls quantified, the other must too.
+        for text in [
+            "Cut infrastructure costs in half",
+            "Doubled weekly active users after the redesign",
+            "Cut deploy time from 45 minutes to 8 minutes",
+            "Held sub-100ms latency through peak season",
+        ] {
+            assert!(has_quantified_outcome(text), "{text}");
+            assert_eq!(validate_impact(text).quality_score, 1.0, "{text}");
+        }
+    }
+
     #[test]
     fn test_validate_bullets_aggregate() {
         let bullets = vec![
@@ -1,17 +1,22 @@
 #![allow(dead_code)]
 
+use std::collections::BTreeMap;
+
 use anyhow::Result;
-use aws_sdk_s3::primitives::ByteStream;
 use sqlx::PgPool;
 use tracing::info;
 use uuid::Uuid;
 
+use crate::context::scoring::DecayCurve;
 use crate::models::context::{ContextEntryRow, ContextSnapshotRow};
+use crate::storage::BlobStore;
 
 pub struct ContextVersion {
     pub version: i32,
     pub s3_key: String,
     pub snapshot_id: Uuid,
+    /// The user's current entries after the insert, ordered by `entry_id`.
+    pub entries: Vec<ContextEntryRow>,
 }
 
 /// Parameters for committing a new context entry version.
@@ -22,6 +27,8 @@ pub struct CommitParams<'a> {
     pub data: &'a serde_json::Value,
     pub raw_text: Option<&'a str>,
     pub recency_score: f64,
+    /// The curve `recency_score` was computed with.
+    pub recency_policy: &'a DecayCurve,
     pub impact_score: f64,
     pub tags: &'a [String],
     pub flagged_evergreen: bool,
@@ -30,14 +37,16 @@ pub struct CommitParams<'a> {
     pub quality_score: f64,
     /// Phase 5.5: machine-readable quality flags.
     pub quality_flags: &'a [String],
+    /// The user's current entries, when the caller has already loaded them. The
+    /// post-insert state is then derived from these instead of read back.
+    pub current_entries: Option<&'a [ContextEntryRow]>,
 }
 
 /// Commits a new context entry as a versioned INSERT.
 /// CRITICAL: This is append-only. Never UPDATE existing rows.
 pub async fn commit_context_update(
     pool: &PgPool,
-    s3: &aws_sdk_s3::Client,
-    s3_bucket: &str,
+    blobs: &dyn BlobStore,
     params: CommitParams<'_>,
 ) -> Result<ContextVersion> {
     let CommitParams {
@@ -47,75 +56,84 @@ pub async fn commit_context_update(
         data,
         raw_text,
         recency_score,
+        recency_policy,
         impact_score,
         tags,
         flagged_evergreen,
         contribution_type,
         quality_score,
         quality_flags,
+        current_entries,
     } = params;
+    let recency_policy = serde_json::to_value(recency_policy)?;
     // 1. Determine next version
-    let current_max: Option<i32> =
-        sqlx::query_scalar("SELECT MAX(version) FROM context_entries WHERE user_id = $1")
-            .bind(user_id)
-            .fetch_one(pool)
-            .await?;
+    let current_max: Option<i32> = sqlx::query_scalar!(
+        "SELECT MAX(version) FROM context_current_entries WHERE user_id = $1",
+        user_id
+    )
+    .fetch_one(pool)
+    .await?;
     let new_version = current_max.unwrap_or(0) + 1;
 
-    // 2. Append-only INSERT
-    sqlx::query(
+    // 2. Append-only INSERT (a trigger moves the context_current_entries pointer)
+    let inserted = sqlx::query_as!(
+        ContextEntryRow,
         r#"
         INSERT INTO context_entries
             (user_id, entry_id, version, entry_type, data, raw_text,
              recency_score, impact_score, tags, flagged_evergreen, contribution_type,
-             quality_score, quality_flags)
-        VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13)
+             quality_score, quality_flags, recency_policy)
+        VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13, $14)
+        RETURNING *
         "#,
+        user_id,
+        entry_id,
+        new_version,
+        entry_type,
+        data,
+        raw_text,
+        recency_score,
+        impact_score,
+        tags,
+        flagged_evergreen,
+        contribution_type,
+        quality_score,
+        quality_flags,
+        recency_policy,
     )
-    .bind(user_id)
-    .bind(entry_id)
-    .bind(new_version)
-    .bind(entry_type)
-    .bind(data)
-    .bind(raw_text)
-    .bind(recency_score)
-    .bind(impact_score)
-    .bind(tags)
-    .bind(flagged_evergreen)
-    .bind(contribution_type)
-    .bind(quality_score)
-    .bind(quality_flags)
-    .execute(pool)
+    .fetch_one(pool)
     .await?;
 
     info!("Inserted context entry {entry_id} version {new_version} for user {user_id}");
 
     // 3. Render all current entries to markdown
-    let all_entries = get_current_entries(pool, user_id).await?;
+    let all_entries = match current_entries {
+        Some(before) => latest_per_entry(before.iter().cloned().chain([inserted])),
+        None => get_current_entries(pool, user_id).await?,
+    };
     let md_content = render_context_to_md(user_id, &all_entries);
 
-    // 4. Upload markdown snapshot to S3
+    // 4. Upload markdown snapshot to blob storage
     let s3_key = format!("contexts/{}/v{}.md", user_id, new_version);
-    s3.put_object()
-        .bucket(s3_bucket)
-        .key(&s3_key)
-        .body(ByteStream::from(md_content.into_bytes()))
-        .content_type("text/markdown")
-        .send()
-        .await
-        .map_err(|e| anyhow::anyhow!("S3 upload failed: {e}"))?;
+    blobs
+        .put(&s3_key, md_content.into_bytes(), "text/markdown")
+        .await?;
 
-    info!("Uploaded context snapshot to s3://{}/{}", s3_bucket, s3_key);
+    info!(
+        "Uploaded context snapshot to {}/{}",
+        blobs.describe(),
+        s3_key
+    );
 
     // 5. Record snapshot
     let snapshot_id = Uuid::new_v4();
-    sqlx::query(
+    sqlx::query!(
         "INSERT INTO context_snapshots (id, user_id, version, s3_key) VALUES ($1, $2, $3, $4)",
+        snapshot_id,
+        user_id,
+        new_version,
+        s3_key,
     )
-    .bind(snapshot_id)
-    .bind(user_id)
-    .bind(new_version)
-    .bind(&s3_key)
     .execute(pool)
     .await?;
 
@@ -123,54 +141,179 @@ pub async fn commit_context_update(
         version: new_version,
         s3_key,
         snapshot_id,
+        entries: all_entries,
     })
 }
 
-/// Returns the most recent version of each entry for a user.
+/// Returns the most recent version of each entry for a user, through the
+/// `context_current_entries` pointers rather than a scan of every version.
 pub async fn get_current_entries(pool: &PgPool, user_id: Uuid) -> Result<Vec<ContextEntryRow>> {
-    Ok(sqlx::query_as::<_, ContextEntryRow>(
+    Ok(sqlx::query_as!(
+        ContextEntryRow,
         r#"
-        SELECT DISTINCT ON (entry_id) *
-        FROM context_entries
-        WHERE user_id = $1
-        ORDER BY entry_id, version DESC
+        SELECT e.*
+        FROM context_current_entries c
+        JOIN context_entries e ON e.id = c.entry_row_id
+        WHERE c.user_id = $1
+        ORDER BY c.entry_id
         "#,
+        user_id
     )
-    .bind(user_id)
     .fetch_all(pool)
     .await?)
 }
 
-/// Returns all entries as of a specific version number.
+/// Identifies the user's current context: changes whenever an entry version is
+/// appended. `version` alone is not enough — an evergreen toggle or patch appends
+/// the entry's own next version, which can sit below the user's maximum — so the
+/// sum of current versions is part of it. Every append raises one entry's current
+/// version, so the sum only grows, and compaction leaves it alone.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ContextRevision {
+    pub version: i32,
+    pub version_sum: i64,
+}
+
+/// Returns the user's current `ContextRevision` without loading any entry.
+pub async fn get_context_revision(pool: &PgPool, user_id: Uuid) -> Result<ContextRevision> {
+    Ok(sqlx::query_as!(
+        ContextRevision,
+        r#"SELECT COALESCE(MAX(version), 0) AS "version!",
+                  COALESCE(SUM(version), 0)::BIGINT AS "version_sum!"
+           FROM context_current_entries WHERE user_id = $1"#,
+        user_id
+    )
+    .fetch_one(pool)
+    .await?)
+}
+
+/// Returns all entries as of a specific version number. Old versions may have been
+/// moved to `context_entries_archive`, so both tables are read.
 pub async fn get_entries_at_version(
     pool: &PgPool,
     user_id: Uuid,
     version: i32,
 ) -> Result<Vec<ContextEntryRow>> {
-    Ok(sqlx::query_as::<_, ContextEntryRow>(
+    let live = sqlx::query_as!(
+        ContextEntryRow,
         r#"
         SELECT DISTINCT ON (entry_id) *
         FROM context_entries
         WHERE user_id = $1 AND version <= $2
         ORDER BY entry_id, version DESC
         "#,
+        user_id,
+        version
     )
-    .bind(user_id)
-    .bind(version)
     .fetch_all(pool)
-    .await?)
+    .await?;
+    let archived = sqlx::query_as!(
+        ContextEntryRow,
+        r#"
+        SELECT DISTINCT ON (entry_id)
+               id, user_id, entry_id, version, entry_type, data, raw_text,
+               recency_score, impact_score, tags, flagged_evergreen, contribution_type,
+               quality_score, quality_flags, created_at, recency_policy
+        FROM context_entries_archive
+        WHERE user_id = $1 AND version <= $2
+        ORDER BY entry_id, version DESC
+        "#,
+        user_id,
+        version
+    )
+    .fetch_all(pool)
+    .await?;
+    Ok(latest_per_entry(live.into_iter().chain(archived)))
 }
 
-/// Returns all context snapshot versions for a user.
+/// Every version of every entry the user has, archived ones included, ordered by
+/// entry and version.
+pub async fn get_all_versions(pool: &PgPool, user_id: Uuid) -> Result<Vec<ContextEntryRow>> {
+    let mut rows = sqlx::query_as!(
+        ContextEntryRow,
+        "SELECT * FROM context_entries WHERE user_id = $1",
+        user_id
+    )
+    .fetch_all(pool)
+    .await?;
+    rows.extend(
+        sqlx::query_as!(
+            ContextEntryRow,
+            r#"
+            SELECT id, user_id, entry_id, version, entry_type, data, raw_text,
+                   recency_score, impact_score, tags, flagged_evergreen, contribution_type,
+                   quality_score, quality_flags, created_at, recency_policy
+            FROM context_entries_archive
+            WHERE user_id = $1
+            "#,
+            user_id
+        )
+        .fetch_all(pool)
+        .await?,
+    );
+    rows.sort_by_key(|row| (row.entry_id, row.version));
+    Ok(rows)
+}
+
+/// The highest version of each entry in `rows`, ordered by `entry_id`.
+fn latest_per_entry(rows: impl IntoIterator<Item = ContextEntryRow>) -> Vec<ContextEntryRow> {
+    let mut latest: BTreeMap<Uuid, ContextEntryRow> = BTreeMap::new();
+    for row in rows {
+        match latest.get(&row.entry_id) {
+            Some(kept) if kept.version >= row.version => {}
+            _ => {
+                latest.insert(row.entry_id, row);
+            }
+        }
+    }
+    latest.into_values().collect()
+}
+
+/// Returns all context snapshot versions for a user, minus soft-deleted ones.
 pub async fn get_version_history(pool: &PgPool, user_id: Uuid) -> Result<Vec<ContextSnapshotRow>> {
-    Ok(sqlx::query_as::<_, ContextSnapshotRow>(
-        "SELECT * FROM context_snapshots WHERE user_id = $1 ORDER BY version ASC",
+    Ok(sqlx::query_as!(
+        ContextSnapshotRow,
+        "SELECT * FROM context_snapshots WHERE user_id = $1 AND deleted_at IS NULL \
+         ORDER BY version ASC",
+        user_id
     )
-    .bind(user_id)
     .fetch_all(pool)
     .await?)
 }
 
+/// Whether the user's snapshot for `version` has been soft-deleted.
+pub async fn is_snapshot_deleted(pool: &PgPool, user_id: Uuid, version: i32) -> Result<bool> {
+    Ok(sqlx::query_scalar!(
+        r#"SELECT EXISTS (
+               SELECT 1 FROM context_snapshots
+               WHERE user_id = $1 AND version = $2 AND deleted_at IS NOT NULL
+           ) AS "deleted!""#,
+        user_id,
+        version
+    )
+    .fetch_one(pool)
+    .await?)
+}
+
+/// Soft-deletes the user's snapshot for `version`. Returns its id, or `None` if there
+/// is no live snapshot with that version. The S3 object stays until `retention`
+/// purges the row.
+pub async fn soft_delete_snapshot(
+    pool: &PgPool,
+    user_id: Uuid,
+    version: i32,
+) -> Result<Option<Uuid>> {
+    Ok(sqlx::query_scalar!(
+        "UPDATE context_snapshots SET deleted_at = NOW() \
+         WHERE user_id = $1 AND version = $2 AND deleted_at IS NULL \
+         RETURNING id",
+        user_id,
+        version
+    )
+    .fetch_optional(pool)
+    .await?)
+}
+
 /// Renders all context entries as a structured markdown document.
 pub fn render_context_to_md(user_id: Uuid, entries: &[ContextEntryRow]) -> String {
     let mut md = format!("# Context Snapshot — User {}\n\n", user_id);
@@ -225,3 +368,134 @@ pub fn render_context_to_md(user_id: Uuid, entries: &[ContextEntryRow]) -> Strin
     }
     md
 }
+
+// ────────────────────────────────────────────────────────────────────────────
+// Tests
+// ────────────────────────────────────────────────────────────────────────────
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn row(entry_id: Uuid, version: i32) -> ContextEntryRow {
+        ContextEntryRow {
+            id: Uuid::new_v4(),
+            user_id: Uuid::nil(),
+            entry_id,
+            version,
+            entry_type: "experience".to_string(),
+            data: serde_json::json!({}),
+            raw_text: None,
+            recency_score: 1.0,
+            impact_score: 0.5,
+            tags: vec![],
+            flagged_evergreen: false,
+            contribution_type: "team_member".to_string(),
+            quality_score: 1.0,
+            quality_flags: vec![],
+            created_at: chrono::Utc::now(),
+            recency_policy: None,
+        }
+    }
+
+    #[test]
+    fn test_latest_per_entry_merges_live_and_archived() {
+        let (a, b) = (Uuid::from_u128(1), Uuid::from_u128(2));
+        // `a` was edited after its old versions were archived; `b` only has an
+        // archived version at or below the requested one.
+        let live = vec![row(a, 7)];
+        let archived = vec![row(a, 3), row(b, 2)];
+        let merged = latest_per_entry(live.into_iter().chain(archived));
+        let got: Vec<(Uuid, i32)> = merged.iter().map(|r| (r.entry_id, r.version)).collect();
+        assert_eq!(got, vec![(a, 7), (b, 2)]);
+    }
+
+    #[test]
+    fn test_committed_row_replaces_or_joins_current_entries() {
+        let (a, b, c) = (Uuid::from_u128(1), Uuid::from_u128(2), Uuid::from_u128(3));
+        let before = [row(a, 4), row(c, 2)];
+        // A new version of `c` replaces it; a new entry `b` lands in entry order.
+        let after = latest_per_entry(before.iter().cloned().chain([row(c, 5), row(b, 6)]));
+        let got: Vec<(Uuid, i32)> = after.iter().map(|r| (r.entry_id, r.version)).collect();
+        assert_eq!(got, vec![(a, 4), (b, 6), (c, 5)]);
+    }
+
+    /// `EXPLAIN` output for `sql` with `$1` bound to a random user. Sequential and
+    /// bitmap scans are disabled so the planner reports the index it would use on a
+    /// large table rather than the cheapest path through the near-empty test one.
+    async fn explain(sql: &str) -> String {
+        let db_url = std::env::var("DATABASE_URL").expect("DATABASE_URL required");
+        let pool = sqlx::postgres::PgPoolOptions::new()
+            .connect(&db_url)
+            .await
+            .expect("DB pool");
+        let mut tx = pool.begin().await.expect("transaction");
+        for setting in ["enable_seqscan", "enable_bitmapscan"] {
+            sqlx::query(&format!("SET LOCAL {setting} = off"))
+                .execute(&mut *tx)
+                .await
+                .unwrap();
+        }
+        let lines: Vec<String> = sqlx::query_scalar(&format!("EXPLAIN {sql}"))
+            .bind(Uuid::new_v4())
+            .fetch_all(&mut *tx)
+            .await
+            .expect("EXPLAIN");
+        lines.join("\n")
+    }
+
+    /// Integration test — requires live PostgreSQL with migration 026 applied.
+    /// Expected: `Unique -> Index Scan using idx_context_entries_user_entry_version`,
+    /// no Sort node.
+    #[tokio::test]
+    #[ignore]
+    async fn test_versioned_read_indexes_serve_distinct_on() {
+        for table in ["context_entries", "context_entries_archive"] {
+            let plan = explain(&format!(
+                "SELECT DISTINCT ON (entry_id) * FROM {table} \
+                 WHERE user_id = $1 AND version <= 10 ORDER BY entry_id, version DESC"
+            ))
+            .await;
+            assert!(
+                plan.contains(&format!("idx_{table}_user_entry_version")),
+                "{plan}"
+            );
+            assert!(!plan.contains("Sort"), "{plan}");
+        }
+    }
+
+    /// Integration test — requires live PostgreSQL with migration 026 applied.
+    /// Expected: `Result -> Limit -> Index Only Scan Backward using
+    /// idx_context_current_entries_user_version`.
+    #[tokio::test]
+    #[ignore]
+    async fn test_versioned_read_indexes_serve_max_version() {
+        let plan =
+            explain("SELECT MAX(version) FROM context_current_entries WHERE user_id = $1").await;
+        assert!(
+            plan.contains(
+                "Index Only Scan Backward using idx_context_current_entries_user_version"
+            ),
+            "{plan}"
+        );
+    }
+
+    /// Integration test — requires live PostgreSQL with migration 026 applied.
+    /// Expected: the pointer side of `get_current_entries` is `Index Only Scan using
+    /// idx_context_current_entries_latest_versions`, joined to context_entries by pkey.
+    #[tokio::test]
+    #[ignore]
+    async fn test_versioned_read_indexes_cover_current_entries() {
+        let plan = explain(
+            "SELECT e.* FROM context_current_entries c \
+             JOIN context_entries e ON e.id = c.entry_row_id \
+             WHERE c.user_id = $1 ORDER BY c.entry_id",
+        )
+        .await;
+        assert!(
+            plan.contains("Index Only Scan using idx_context_current_entries_latest_versions"),
+            "{plan}"
+        );
+        assert!(plan.contains("context_entries_pkey"), "{plan}");
+    }
+}
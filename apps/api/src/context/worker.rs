@@ -1,136 +1,98 @@
-//! Background context ingest worker: Redis BRPOP → DB fetch → LLM parse → confirm → DB update.
+//! Background context ingest worker: `IngestItemJob` → DB fetch → LLM parse → confirm → DB update.
 //!
-//! Queue pattern: LPUSH on enqueue, BRPOP with 5-second timeout on dequeue.
-//! Redis key stores `item_id` as a UUID string; worker fetches all data from DB.
+//! Runs on the shared job queue (`crate::jobs`, queue `context_ingest`). The payload
+//! is the item id; the worker fetches all data from DB.
 //!
-//! The worker NEVER panics on individual item failure — it logs the error and
-//! continues processing the next item.
+//! The worker NEVER panics on individual item failure — the failure is recorded on
+//! the item and the worker continues processing the next one.
 //!
-//! Multiple worker instances can be spawned (INGEST_WORKER_COUNT env var, default 4).
-//! Each worker independently drains the same Redis queue — zero coordination needed.
+//! Concurrency is INGEST_WORKER_COUNT (default 4) items at a time.
 
 use std::sync::atomic::{AtomicU64, Ordering};
 use std::sync::Arc;
-use std::time::Duration;
 
-use aws_sdk_s3::Client as S3Client;
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
 use sqlx::PgPool;
 use tracing::{error, info, warn};
 use uuid::Uuid;
 
 use crate::context::batch;
 use crate::context::ingest::{confirm_ingest, parse_and_validate, IngestConfirmRequest};
+use crate::jobs::{Handler, Job, JobError};
 use crate::llm_client::LlmClient;
-
-/// Redis list key for the context ingest job queue.
-pub const INGEST_QUEUE_KEY: &str = "context:ingest:queue";
+use crate::storage::BlobStore;
 
 /// Run cleanup every N completed jobs to delete expired batches.
 const CLEANUP_EVERY_N_JOBS: u64 = 100;
 
 // ────────────────────────────────────────────────────────────────────────────
-// Worker spawn
+// Job
 // ────────────────────────────────────────────────────────────────────────────
 
-/// Spawns a context ingest background worker as a detached tokio task.
-///
-/// The worker processes ingest items from the Redis queue indefinitely.
-/// Call this N times in main.rs to create N parallel workers.
-pub fn spawn_context_ingest_worker(
-    redis: redis::Client,
-    db: PgPool,
-    llm: LlmClient,
-    s3: S3Client,
-    s3_bucket: String,
-) {
-    tokio::spawn(async move {
-        worker_loop(redis, db, llm, s3, s3_bucket).await;
-    });
+/// Queue payload: one `context_ingest_items` row.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct IngestItemJob {
+    pub item_id: Uuid,
 }
 
-// ────────────────────────────────────────────────────────────────────────────
-// Worker loop
-// ────────────────────────────────────────────────────────────────────────────
+impl Job for IngestItemJob {
+    const QUEUE: &'static str = "context_ingest";
+}
 
-/// Main worker loop — runs indefinitely, BRPOP-ing item IDs from Redis.
-///
-/// Uses a 5-second BRPOP timeout (not 0) so the loop can detect a lost
-/// connection and recover gracefully without blocking forever.
-async fn worker_loop(
-    redis: redis::Client,
-    db: PgPool,
-    llm: LlmClient,
-    s3: S3Client,
-    s3_bucket: String,
-) {
-    info!("Context ingest worker loop started");
+/// Runs ingest items. Parse and commit failures are recorded on the item by
+/// `process_ingest_item`; errors it returns (DB or storage trouble) are retried, and the
+/// item is marked failed once its attempts run out.
+pub struct IngestWorker {
+    pub db: PgPool,
+    pub llm: LlmClient,
+    pub blobs: Arc<dyn BlobStore>,
+    jobs_completed: AtomicU64,
+}
 
-    let jobs_completed = Arc::new(AtomicU64::new(0));
+impl IngestWorker {
+    pub fn new(db: PgPool, llm: LlmClient, blobs: Arc<dyn BlobStore>) -> Self {
+        IngestWorker {
+            db,
+            llm,
+            blobs,
+            jobs_completed: AtomicU64::new(0),
+        }
+    }
 
-    loop {
-        let mut conn = match redis.get_multiplexed_async_connection().await {
-            Ok(c) => c,
-            Err(e) => {
-                error!("Ingest worker: Redis connection failed: {e} — retrying in 5s");
-                tokio::time::sleep(Duration::from_secs(5)).await;
-                continue;
-            }
-        };
+    /// Periodic cleanup of expired batches.
+    async fn maybe_cleanup(&self) {
+        let count = self.jobs_completed.fetch_add(1, Ordering::Relaxed) + 1;
+        if !count.is_multiple_of(CLEANUP_EVERY_N_JOBS) {
+            return;
+        }
+        match batch::cleanup_expired_batches(&self.db).await {
+            Ok(n) if n > 0 => info!("Ingest worker: cleaned up {n} expired batches"),
+            Ok(_) => {}
+            Err(e) => warn!("Ingest worker: batch cleanup error: {e}"),
+        }
+    }
+}
 
-        // BRPOP with 5.0-second timeout — returns None on timeout, Some on item
-        let result: Result<Option<(String, String)>, redis::RedisError> = redis::cmd("BRPOP")
-            .arg(INGEST_QUEUE_KEY)
-            .arg(5.0_f64)
-            .query_async(&mut conn)
-            .await;
+#[async_trait]
+impl Handler for IngestWorker {
+    type Job = IngestItemJob;
 
-        match result {
-            Ok(None) => {
-                // Timeout — no item in queue; loop continues
-            }
-            Ok(Some((_key, item_id_str))) => {
-                let item_id_str = item_id_str.trim().to_string();
-                match Uuid::parse_str(&item_id_str) {
-                    Ok(item_id) => {
-                        info!(%item_id, "Ingest worker: dequeued item");
-                        if let Err(e) =
-                            process_ingest_item(item_id, &db, &llm, &s3, &s3_bucket).await
-                        {
-                            error!(%item_id, error = %e, "Ingest worker: item processing failed");
-                            // Best-effort mark failed — if this also errors, just log it
-                            if let Err(db_err) =
-                                batch::mark_item_failed(&db, item_id, &e.to_string()).await
-                            {
-                                error!(%item_id, db_error = %db_err, "Ingest worker: failed to update item status after error");
-                            }
-                        }
+    async fn run(&self, job: &IngestItemJob) -> Result<(), JobError> {
+        let item_id = job.item_id;
+        info!(%item_id, "Ingest worker: dequeued item");
+        let result = process_ingest_item(item_id, &self.db, &self.llm, &*self.blobs).await;
+        self.maybe_cleanup().await;
+        result.map_err(|e| {
+            error!(%item_id, error = %e, "Ingest worker: item processing failed");
+            JobError::Retry(e)
+        })
+    }
 
-                        // Periodic cleanup of expired batches
-                        let count = jobs_completed.fetch_add(1, Ordering::Relaxed) + 1;
-                        if count.is_multiple_of(CLEANUP_EVERY_N_JOBS) {
-                            match batch::cleanup_expired_batches(&db).await {
-                                Ok(n) if n > 0 => {
-                                    info!("Ingest worker: cleaned up {n} expired batches")
-                                }
-                                Ok(_) => {}
-                                Err(e) => {
-                                    warn!("Ingest worker: batch cleanup error: {e}")
-                                }
-                            }
-                        }
-                    }
-                    Err(e) => {
-                        error!(
-                            "Ingest worker: invalid UUID in queue '{}': {e}",
-                            item_id_str
-                        );
-                    }
-                }
-            }
-            Err(e) => {
-                error!("Ingest worker: BRPOP error: {e} — reconnecting");
-                tokio::time::sleep(Duration::from_secs(1)).await;
-            }
+    async fn on_dead(&self, job: &IngestItemJob, error: &str) {
+        // Best-effort mark failed — if this also errors, just log it
+        if let Err(db_err) = batch::mark_item_failed(&self.db, job.item_id, error).await {
+            error!(item_id = %job.item_id, db_error = %db_err, "Ingest worker: failed to update item status after error");
         }
     }
 }
@@ -154,8 +116,7 @@ async fn process_ingest_item(
     item_id: Uuid,
     db: &PgPool,
     llm: &LlmClient,
-    s3: &S3Client,
-    s3_bucket: &str,
+    blobs: &dyn BlobStore,
 ) -> anyhow::Result<()> {
     use crate::context::dedup::DedupResult;
     use crate::context::merger;
@@ -209,8 +170,7 @@ async fn process_ingest_item(
             info!(%item_id, %user_id, %existing_entry_id, "Ingest worker: merging with existing entry");
             match merger::merge_and_commit(
                 db,
-                s3,
-                s3_bucket,
+                blobs,
                 llm,
                 user_id,
                 existing_entry_id,
@@ -230,7 +190,7 @@ async fn process_ingest_item(
                         entry: preview.entry,
                         acknowledged_gaps: vec![],
                     };
-                    commit_and_mark(db, s3, s3_bucket, item_id, user_id, confirm_req).await?;
+                    commit_and_mark(db, blobs, item_id, user_id, confirm_req).await?;
                 }
             }
         }
@@ -244,7 +204,7 @@ async fn process_ingest_item(
                 entry: preview.entry,
                 acknowledged_gaps: vec![],
             };
-            commit_and_mark(db, s3, s3_bucket, item_id, user_id, confirm_req).await?;
+            commit_and_mark(db, blobs, item_id, user_id, confirm_req).await?;
         }
     }
 
@@ -254,13 +214,12 @@ async fn process_ingest_item(
 /// Helper: confirm_ingest + mark_item_succeeded.
 async fn commit_and_mark(
     db: &PgPool,
-    s3: &S3Client,
-    s3_bucket: &str,
+    blobs: &dyn BlobStore,
     item_id: Uuid,
     user_id: Uuid,
     confirm_req: IngestConfirmRequest,
 ) -> anyhow::Result<()> {
-    match confirm_ingest(db, s3, s3_bucket, &confirm_req).await {
+    match confirm_ingest(db, blobs, &confirm_req).await {
         Ok(r) => {
             batch::mark_item_succeeded(db, item_id, r.entry_id).await?;
             info!(
@@ -286,6 +245,7 @@ async fn commit_and_mark(
 // ────────────────────────────────────────────────────────────────────────────
 
 #[cfg(test)]
+#[allow(clippy::empty_line_after_outer_attr)]
 mod tests {
     /// Integration tests require live Redis + PostgreSQL.
     /// Unit-testable logic is covered by splitter.rs and batch.rs tests.
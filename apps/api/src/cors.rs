@@ -0,0 +1,119 @@
+//! CORS policy built from `Config`.
+//!
+//! Production allows only the configured origins, methods and headers. The
+//! permissive profile (`CORS_PERMISSIVE=true`) accepts anything and is meant for
+//! local development against ad-hoc frontends.
+
+use anyhow::{bail, Context, Result};
+use axum::http::{HeaderName, HeaderValue, Method};
+use tower_http::cors::{AllowOrigin, CorsLayer};
+
+use crate::api_version::{API_VERSION_HEADER, DEPRECATION_HEADER, SUNSET_HEADER};
+use crate::config::Config;
+use crate::rate_limit::{LIMIT_HEADER, REMAINING_HEADER};
+use crate::request_id::REQUEST_ID_HEADER;
+
+/// Response headers browser code may read.
+const EXPOSED_HEADERS: [HeaderName; 9] = [
+    REQUEST_ID_HEADER,
+    axum::http::header::RETRY_AFTER,
+    LIMIT_HEADER,
+    REMAINING_HEADER,
+    API_VERSION_HEADER,
+    DEPRECATION_HEADER,
+    SUNSET_HEADER,
+    axum::http::header::LINK,
+    axum::http::header::ETAG,
+];
+
+/// Builds the layer, failing on malformed origins, methods or headers so a typo in
+/// the environment stops startup instead of silently blocking the web app.
+pub fn build_cors_layer(config: &Config) -> Result<CorsLayer> {
+    if config.cors.permissive {
+        tracing::warn!("CORS_PERMISSIVE is set — any origin may call the API");
+        return Ok(CorsLayer::permissive());
+    }
+    restricted_layer(
+        &config.cors.allowed_origins,
+        &config.cors.allowed_methods,
+        &config.cors.allowed_headers,
+    )
+}
+
+fn restricted_layer(
+    allowed_origins: &[String],
+    allowed_methods: &[String],
+    allowed_headers: &[String],
+) -> Result<CorsLayer> {
+    if allowed_origins.is_empty() {
+        bail!("CORS_ALLOWED_ORIGINS is empty; set it or enable CORS_PERMISSIVE for development");
+    }
+    if allowed_origins.iter().any(|o| o == "*") {
+        bail!("CORS_ALLOWED_ORIGINS may not contain '*'; use CORS_PERMISSIVE for development");
+    }
+
+    let origins = allowed_origins
+        .iter()
+        .map(|o| {
+            HeaderValue::from_str(o.trim_end_matches('/'))
+                .with_context(|| format!("invalid CORS origin '{o}'"))
+        })
+        .collect::<Result<Vec<_>>>()?;
+    let methods = allowed_methods
+        .iter()
+        .map(|m| {
+            Method::from_bytes(m.to_ascii_uppercase().as_bytes())
+                .with_context(|| format!("invalid CORS method '{m}'"))
+        })
+        .collect::<Result<Vec<_>>>()?;
+    let headers = allowed_headers
+        .iter()
+        .map(|h| {
+            HeaderName::try_from(h.as_str()).with_context(|| format!("invalid CORS header '{h}'"))
+        })
+        .collect::<Result<Vec<_>>>()?;
+
+    Ok(CorsLayer::new()
+        .allow_origin(AllowOrigin::list(origins))
+        .allow_methods(methods)
+        .allow_headers(headers)
+        .expose_headers(EXPOSED_HEADERS))
+}
+
+// ────────────────────────────────────────────────────────────────────────────
+// Tests
+// ────────────────────────────────────────────────────────────────────────────
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn list(items: &[&str]) -> Vec<String> {
+        items.iter().map(|s| s.to_string()).collect()
+    }
+
+    #[test]
+    fn test_valid_policy_builds() {
+        let layer = restricted_layer(
+            &list(&["https://app.templar.dev", "http://localhost:3000/"]),
+            &list(&["get", "POST"]),
+            &list(&["authorization", "content-type"]),
+        );
+        assert!(layer.is_ok());
+    }
+
+    #[test]
+    fn test_wildcard_and_empty_origins_are_rejected() {
+        let methods = list(&["GET"]);
+        let headers = list(&["authorization"]);
+        assert!(restricted_layer(&list(&["*"]), &methods, &headers).is_err());
+        assert!(restricted_layer(&[], &methods, &headers).is_err());
+    }
+
+    #[test]
+    fn test_malformed_entries_are_rejected() {
+        let origins = list(&["https://app.templar.dev"]);
+        assert!(restricted_layer(&origins, &list(&["GE T"]), &list(&["authorization"])).is_err());
+        assert!(restricted_layer(&origins, &list(&["GET"]), &list(&["bad header"])).is_err());
+    }
+}
@@ -1,17 +1,145 @@
+use std::future::Future;
+use std::str::FromStr;
+use std::time::Duration;
+
 use anyhow::Result;
-use sqlx::postgres::PgPoolOptions;
+use serde::Serialize;
+use sqlx::postgres::{PgConnectOptions, PgPoolOptions};
 use sqlx::PgPool;
 use tracing::info;
 
-/// Creates and returns a PostgreSQL connection pool.
-pub async fn create_pool(database_url: &str) -> Result<PgPool> {
-    info!("Connecting to PostgreSQL...");
+use crate::config::PoolConfig;
+
+/// Creates and returns a PostgreSQL connection pool sized and timed out per `config`.
+/// `role` ("primary", "replica") only labels the log lines.
+pub async fn create_pool(database_url: &str, role: &str, config: &PoolConfig) -> Result<PgPool> {
+    info!("Connecting to PostgreSQL ({role})...");
 
+    let mut connect = PgConnectOptions::from_str(database_url)?;
+    if config.statement_timeout_ms > 0 {
+        // Sent as a startup parameter, so it costs no extra round trip per connection.
+        connect = connect.options([("statement_timeout", config.statement_timeout_ms.to_string())]);
+    }
     let pool = PgPoolOptions::new()
-        .max_connections(10)
-        .connect(database_url)
+        .max_connections(config.max_connections)
+        .min_connections(config.min_connections)
+        .acquire_timeout(Duration::from_secs(config.acquire_timeout_secs))
+        .idle_timeout(
+            (config.idle_timeout_secs > 0).then(|| Duration::from_secs(config.idle_timeout_secs)),
+        )
+        .connect_with(connect)
         .await?;
 
-    info!("PostgreSQL connection pool established");
+    info!(
+        "PostgreSQL connection pool established ({role}, max {} connections)",
+        config.max_connections
+    );
     Ok(pool)
 }
+
+/// A pool's occupancy at one instant, for `/metrics` and `/ready`.
+#[derive(Debug, Clone, Copy, Serialize)]
+pub struct PoolStatus {
+    pub role: &'static str,
+    /// Open connections, idle or checked out.
+    pub size: u32,
+    pub idle: u32,
+    pub max: u32,
+}
+
+impl PoolStatus {
+    pub fn of(pool: &PgPool, role: &'static str) -> Self {
+        PoolStatus {
+            role,
+            size: pool.size(),
+            idle: pool.num_idle() as u32,
+            max: pool.options().get_max_connections(),
+        }
+    }
+
+    /// Every connection the pool may open is checked out: the next query waits for
+    /// one, up to the acquire timeout.
+    pub fn is_saturated(&self) -> bool {
+        self.size >= self.max && self.idle == 0
+    }
+}
+
+/// Routes read-only queries to the replica when `DATABASE_REPLICA_URL` is set.
+///
+/// Only for endpoints that display data and can tolerate replication lag (context
+/// listing, history, resume fetch). Writes, ownership checks and read-modify-write
+/// sequences stay on `AppState::db`.
+#[derive(Clone)]
+pub struct ReadRouter {
+    primary: PgPool,
+    replica: Option<PgPool>,
+}
+
+impl ReadRouter {
+    pub fn new(primary: PgPool, replica: Option<PgPool>) -> Self {
+        ReadRouter { primary, replica }
+    }
+
+    /// The primary and, if configured, the replica, with their roles.
+    pub fn pools(&self) -> Vec<(&'static str, &PgPool)> {
+        let mut pools = vec![("primary", &self.primary)];
+        pools.extend(self.replica.as_ref().map(|r| ("replica", r)));
+        pools
+    }
+
+    /// Status of every pool in `pools`.
+    pub fn statuses(&self) -> Vec<PoolStatus> {
+        self.pools()
+            .into_iter()
+            .map(|(role, pool)| PoolStatus::of(pool, role))
+            .collect()
+    }
+
+    /// The pool for a lag-tolerant read: the replica, or the primary without one.
+    pub fn pool(&self) -> &PgPool {
+        self.replica.as_ref().unwrap_or(&self.primary)
+    }
+
+    /// Runs `query` on the replica and, if it finds nothing, once more on the primary:
+    /// a row created moments ago (a resume the client was just redirected to) may not
+    /// have replicated yet. Returns the row with the pool that had it, so follow-up
+    /// reads of the same resource see the same state.
+    pub async fn fetch_optional<'a, T, F, Fut>(
+        &'a self,
+        query: F,
+    ) -> Result<Option<(T, &'a PgPool)>, sqlx::Error>
+    where
+        F: Fn(&'a PgPool) -> Fut,
+        Fut: Future<Output = Result<Option<T>, sqlx::Error>>,
+    {
+        let Some(replica) = &self.replica else {
+            return Ok(query(&self.primary).await?.map(|row| (row, &self.primary)));
+        };
+        if let Some(row) = query(replica).await? {
+            return Ok(Some((row, replica)));
+        }
+        Ok(query(&self.primary).await?.map(|row| (row, &self.primary)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn status(size: u32, idle: u32) -> PoolStatus {
+        PoolStatus {
+            role: "primary",
+            size,
+            idle,
+            max: 10,
+        }
+    }
+
+    #[test]
+    fn test_pool_saturated_only_when_full_and_busy() {
+        assert!(status(10, 0).is_saturated());
+        // Room to open another connection, or one sitting idle: queries do not wait.
+        assert!(!status(9, 0).is_saturated());
+        assert!(!status(10, 1).is_saturated());
+    }
+}
@@ -1,13 +1,16 @@
 #![allow(dead_code)]
 
 use axum::{
-    http::StatusCode,
+    http::{header::RETRY_AFTER, StatusCode},
     response::{IntoResponse, Response},
     Json,
 };
+use chrono::{DateTime, Utc};
 use serde_json::json;
 use thiserror::Error;
 
+use crate::validation::FieldError;
+
 /// Application-level error type.
 /// Implements `IntoResponse` so Axum handlers can return `Result<T, AppError>`.
 #[derive(Debug, Error)]
@@ -18,6 +21,14 @@ pub enum AppError {
     #[error("Validation error: {0}")]
     Validation(String),
 
+    /// Request body fields that failed `validator` checks; see `crate::validation`.
+    /// Listed under `fields` in the body.
+    #[error("Validation error: {}", describe_fields(.0))]
+    InvalidFields(Vec<FieldError>),
+
+    #[error("Payload too large: {0}")]
+    PayloadTooLarge(String),
+
     #[error("Unprocessable entity: {0}")]
     UnprocessableEntity(String),
 
@@ -27,6 +38,21 @@ pub enum AppError {
     #[error("Forbidden")]
     Forbidden,
 
+    #[error("Conflict: {0}")]
+    Conflict(String),
+
+    /// `resets_at` is when the exhausted allowance refills, if it refills at a fixed
+    /// time (monthly tier quotas). Surfaced in the body and as `Retry-After`.
+    #[error("Quota exceeded: {message}")]
+    QuotaExceeded {
+        message: String,
+        resets_at: Option<DateTime<Utc>>,
+    },
+
+    /// Request rate limit hit; the client may retry after `retry_after_secs`.
+    #[error("Rate limited: retry after {retry_after_secs}s")]
+    RateLimited { retry_after_secs: u64 },
+
     #[error("Database error: {0}")]
     Database(#[from] sqlx::Error),
 
@@ -39,6 +65,10 @@ pub enum AppError {
     #[error("Internal server error: {0}")]
     Internal(#[from] anyhow::Error),
 
+    /// The request ran past its route's budget; see `crate::timeout`.
+    #[error("Timed out after {budget_secs}s")]
+    Timeout { budget_secs: u64 },
+
     #[error("Not implemented")]
     NotImplemented,
 }
@@ -48,6 +78,16 @@ impl IntoResponse for AppError {
         let (status, code, message) = match &self {
             AppError::NotFound(msg) => (StatusCode::NOT_FOUND, "NOT_FOUND", msg.clone()),
             AppError::Validation(msg) => (StatusCode::BAD_REQUEST, "VALIDATION_ERROR", msg.clone()),
+            AppError::InvalidFields(fields) => (
+                StatusCode::BAD_REQUEST,
+                "VALIDATION_ERROR",
+                describe_fields(fields),
+            ),
+            AppError::PayloadTooLarge(msg) => (
+                StatusCode::PAYLOAD_TOO_LARGE,
+                "PAYLOAD_TOO_LARGE",
+                msg.clone(),
+            ),
             AppError::UnprocessableEntity(msg) => (
                 StatusCode::UNPROCESSABLE_ENTITY,
                 "UNPROCESSABLE_ENTITY",
@@ -63,14 +103,30 @@ impl IntoResponse for AppError {
                 "FORBIDDEN",
                 "Access denied".to_string(),
             ),
+            AppError::Conflict(msg) => (StatusCode::CONFLICT, "CONFLICT", msg.clone()),
+            AppError::QuotaExceeded { message, .. } => (
+                StatusCode::TOO_MANY_REQUESTS,
+                "QUOTA_EXCEEDED",
+                message.clone(),
+            ),
+            AppError::RateLimited { retry_after_secs } => (
+                StatusCode::TOO_MANY_REQUESTS,
+                "RATE_LIMITED",
+                format!("Too many requests — retry in {retry_after_secs}s"),
+            ),
             AppError::Database(e) => {
+                if matches!(e, sqlx::Error::PoolTimedOut) {
+                    crate::metrics::DB_POOL_ACQUIRE_TIMEOUTS.inc();
+                }
                 tracing::error!(db_error = %e, "Database error");
+                crate::reporting::capture_error("Database", &e.to_string());
                 (
                     StatusCode::INTERNAL_SERVER_ERROR,
                     "DATABASE_ERROR",
                     "A database error occurred".to_string(),
                 )
             }
+            // Already reported by `LlmClient` where the call failed.
             AppError::Llm(msg) => {
                 tracing::error!(error_msg = %msg, "LLM error");
                 (
@@ -81,6 +137,7 @@ impl IntoResponse for AppError {
             }
             AppError::S3(msg) => {
                 tracing::error!(error_msg = %msg, "S3 error");
+                crate::reporting::capture_error("Storage", msg);
                 (
                     StatusCode::INTERNAL_SERVER_ERROR,
                     "S3_ERROR",
@@ -89,12 +146,24 @@ impl IntoResponse for AppError {
             }
             AppError::Internal(e) => {
                 tracing::error!(error = %e, chain = ?e, "Internal error");
+                crate::reporting::capture_error("Internal", &format!("{e:#}"));
                 (
                     StatusCode::INTERNAL_SERVER_ERROR,
                     "INTERNAL_ERROR",
                     "An internal server error occurred".to_string(),
                 )
             }
+            AppError::Timeout { budget_secs } => {
+                crate::reporting::capture_error(
+                    "Timeout",
+                    &format!("request exceeded its {budget_secs}s budget"),
+                );
+                (
+                    StatusCode::GATEWAY_TIMEOUT,
+                    "TIMEOUT",
+                    format!("The request did not complete within {budget_secs}s"),
+                )
+            }
             AppError::NotImplemented => (
                 StatusCode::NOT_IMPLEMENTED,
                 "NOT_IMPLEMENTED",
@@ -102,13 +171,54 @@ impl IntoResponse for AppError {
             ),
         };
 
-        let body = Json(json!({
-            "error": {
-                "code": code,
-                "message": message
-            }
-        }));
+        let mut error = json!({
+            "code": code,
+            "message": message
+        });
+        if let Some(request_id) = crate::request_id::current() {
+            error["request_id"] = json!(request_id);
+        }
+        if let AppError::QuotaExceeded {
+            resets_at: Some(resets_at),
+            ..
+        } = &self
+        {
+            error["resets_at"] = json!(resets_at);
+        }
+        if let AppError::InvalidFields(fields) = &self {
+            error["fields"] = json!(fields);
+        }
 
-        (status, body).into_response()
+        let mut response = (status, Json(json!({ "error": error }))).into_response();
+        if let Some(secs) = self.retry_after_secs() {
+            response.headers_mut().insert(
+                RETRY_AFTER,
+                secs.to_string().parse().expect("digits are a valid header"),
+            );
+        }
+        response
     }
 }
+
+impl AppError {
+    /// Seconds for the `Retry-After` header, for errors that clear on their own.
+    fn retry_after_secs(&self) -> Option<u64> {
+        match self {
+            AppError::RateLimited { retry_after_secs } => Some(*retry_after_secs),
+            AppError::QuotaExceeded {
+                resets_at: Some(resets_at),
+                ..
+            } => Some((*resets_at - Utc::now()).num_seconds().max(1) as u64),
+            _ => None,
+        }
+    }
+}
+
+/// `field: message` for each failing field, `; `-separated.
+fn describe_fields(fields: &[FieldError]) -> String {
+    fields
+        .iter()
+        .map(|f| format!("{}: {}", f.field, f.message))
+        .collect::<Vec<_>>()
+        .join("; ")
+}
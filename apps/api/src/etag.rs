@@ -0,0 +1,158 @@
+//! Conditional GETs for read endpoints the web app polls.
+//!
+//! A handler derives an `ETag` from a cheap revision query (the latest context
+//! version, a resume's `updated_at`) before loading the payload. If the request's
+//! `If-None-Match` already names that tag it returns `Conditional::NotModified`
+//! without reading anything else; otherwise it builds the body and returns
+//! `Conditional::Fresh`.
+//!
+//! Tags are weak (`W/"..."`): they identify the state the JSON was built from, not
+//! its bytes. Every tag embeds the crate version, so a deploy that changes the shape
+//! of a response invalidates what clients hold. Responses carry
+//! `Cache-Control: private, no-cache` and `Vary: Authorization` — browsers may keep
+//! a copy but must revalidate it on every poll, and never share it across accounts.
+
+use axum::{
+    http::{
+        header::{CACHE_CONTROL, ETAG, IF_NONE_MATCH, VARY},
+        HeaderMap, HeaderValue, StatusCode,
+    },
+    response::{IntoResponse, Response},
+};
+
+/// `Cache-Control` on every conditional response.
+pub const CACHE_CONTROL_VALUE: &str = "private, no-cache";
+
+/// A weak entity tag.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ETag(String);
+
+impl ETag {
+    /// Weak tag for `revision`, which must identify the state the response is
+    /// built from (e.g. `resume-1718000000123456`). Characters not allowed in an
+    /// entity tag are replaced with `-`.
+    pub fn weak(revision: &str) -> Self {
+        let revision: String = revision
+            .chars()
+            .map(|c| match c {
+                '!' | '#'..='~' => c,
+                _ => '-',
+            })
+            .collect();
+        ETag(format!("W/\"{}-{revision}\"", env!("CARGO_PKG_VERSION")))
+    }
+
+    /// True when `If-None-Match` lists this tag or `*`. Comparison is weak
+    /// (RFC 9110 §13.1.2), so `W/` prefixes on either side are ignored.
+    pub fn matches(&self, headers: &HeaderMap) -> bool {
+        let ours = opaque(&self.0);
+        headers
+            .get_all(IF_NONE_MATCH)
+            .iter()
+            .filter_map(|v| v.to_str().ok())
+            .flat_map(|v| v.split(','))
+            .map(str::trim)
+            .any(|tag| tag == "*" || opaque(tag) == ours)
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+/// The quoted part of a tag, without the weakness prefix.
+fn opaque(tag: &str) -> &str {
+    tag.strip_prefix("W/").unwrap_or(tag)
+}
+
+/// Handler result for a conditional GET.
+#[derive(Debug)]
+pub enum Conditional<T> {
+    /// 304 with no body.
+    NotModified(ETag),
+    /// The full response for `ETag`.
+    Fresh(ETag, T),
+}
+
+impl<T> Conditional<T> {
+    /// `NotModified` if the request already holds `etag`, otherwise `Fresh` with
+    /// the body from `build`.
+    pub async fn respond<F, Fut, E>(headers: &HeaderMap, etag: ETag, build: F) -> Result<Self, E>
+    where
+        F: FnOnce() -> Fut,
+        Fut: std::future::Future<Output = Result<T, E>>,
+    {
+        if etag.matches(headers) {
+            return Ok(Conditional::NotModified(etag));
+        }
+        Ok(Conditional::Fresh(etag, build().await?))
+    }
+}
+
+impl<T: IntoResponse> IntoResponse for Conditional<T> {
+    fn into_response(self) -> Response {
+        let (etag, mut response) = match self {
+            Conditional::NotModified(etag) => (etag, StatusCode::NOT_MODIFIED.into_response()),
+            Conditional::Fresh(etag, body) => (etag, body.into_response()),
+        };
+        let headers = response.headers_mut();
+        if let Ok(value) = HeaderValue::from_str(etag.as_str()) {
+            headers.insert(ETAG, value);
+        }
+        headers.insert(CACHE_CONTROL, HeaderValue::from_static(CACHE_CONTROL_VALUE));
+        headers.insert(VARY, HeaderValue::from_static("authorization"));
+        response
+    }
+}
+
+// ────────────────────────────────────────────────────────────────────────────
+// Tests
+// ────────────────────────────────────────────────────────────────────────────
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn if_none_match(values: &[&str]) -> HeaderMap {
+        let mut headers = HeaderMap::new();
+        for v in values {
+            headers.append(IF_NONE_MATCH, HeaderValue::from_str(v).unwrap());
+        }
+        headers
+    }
+
+    #[test]
+    fn test_weak_tag_embeds_the_build_and_sanitizes() {
+        let tag = ETag::weak("ctx-12 \"x\"");
+        assert!(tag.as_str().starts_with("W/\""));
+        assert!(tag.as_str().contains(env!("CARGO_PKG_VERSION")));
+        assert!(tag.as_str().ends_with("-ctx-12--x-\""));
+        assert!(HeaderValue::from_str(tag.as_str()).is_ok());
+    }
+
+    #[test]
+    fn test_matches_lists_wildcards_and_strong_forms() {
+        let tag = ETag::weak("resume-1");
+        let strong = tag.as_str().trim_start_matches("W/").to_string();
+
+        assert!(!tag.matches(&HeaderMap::new()));
+        assert!(tag.matches(&if_none_match(&[tag.as_str()])));
+        assert!(tag.matches(&if_none_match(&[&format!("W/\"other\", {}", tag.as_str())])));
+        assert!(tag.matches(&if_none_match(&["W/\"other\"", &strong])));
+        assert!(tag.matches(&if_none_match(&["*"])));
+        assert!(!tag.matches(&if_none_match(&["W/\"0.0.0-resume-2\""])));
+    }
+
+    #[test]
+    fn test_not_modified_response_keeps_validators() {
+        let response = Conditional::<&str>::NotModified(ETag::weak("ctx-3")).into_response();
+        assert_eq!(response.status(), StatusCode::NOT_MODIFIED);
+        assert_eq!(response.headers()[CACHE_CONTROL], CACHE_CONTROL_VALUE);
+        assert_eq!(response.headers()[VARY], "authorization");
+        assert!(response.headers().contains_key(ETAG));
+
+        let fresh = Conditional::Fresh(ETag::weak("ctx-3"), "body").into_response();
+        assert_eq!(fresh.status(), StatusCode::OK);
+        assert_eq!(fresh.headers()[ETAG], response.headers()[ETAG]);
+    }
+}
@@ -0,0 +1,117 @@
+//! Axum handlers for starting an export and fetching its download link.
+
+use std::time::Duration;
+
+use axum::{
+    extract::{Path, State},
+    http::StatusCode,
+    Json,
+};
+use uuid::Uuid;
+
+use crate::auth::{AuthUser, Resource};
+use crate::errors::AppError;
+use crate::export::{
+    ExportJob, ExportStatusResponse, StartExportResponse, UserExportRow, DOWNLOAD_URL_TTL_SECS,
+};
+use crate::jobs;
+use crate::state::AppState;
+
+/// POST /api/v1/users/me/export
+///
+/// Queues an export of all the caller's data. Idempotent while one is in flight: a
+/// pending or running export is returned instead of starting another. Follow progress
+/// at `events_url`, then fetch the download link from the status endpoint.
+pub async fn handle_start_export(
+    State(state): State<AppState>,
+    auth: AuthUser,
+) -> Result<(StatusCode, Json<StartExportResponse>), AppError> {
+    let existing = sqlx::query_as!(
+        UserExportRow,
+        "SELECT * FROM user_exports \
+         WHERE user_id = $1 AND status IN ('pending', 'running') \
+         AND created_at > NOW() - INTERVAL '1 hour' \
+         ORDER BY created_at DESC LIMIT 1",
+        auth.user_id
+    )
+    .fetch_optional(&state.db)
+    .await?;
+    if let Some(export) = existing {
+        return Ok((StatusCode::ACCEPTED, Json(start_response(&export))));
+    }
+
+    let export = sqlx::query_as!(
+        UserExportRow,
+        "INSERT INTO user_exports (id, user_id) VALUES ($1, $2) RETURNING *",
+        Uuid::new_v4(),
+        auth.user_id
+    )
+    .fetch_one(&state.db)
+    .await?;
+
+    let job = ExportJob {
+        export_id: export.id,
+    };
+    if let Err(e) = jobs::enqueue(&state.redis, &job).await {
+        sqlx::query!(
+            "UPDATE user_exports SET status = 'failed', error_message = $2 WHERE id = $1",
+            export.id,
+            "could not be queued"
+        )
+        .execute(&state.db)
+        .await?;
+        return Err(AppError::Internal(anyhow::anyhow!(
+            "Failed to queue export: {e}"
+        )));
+    }
+
+    tracing::info!(user_id = %auth.user_id, export_id = %export.id, "export queued");
+    Ok((StatusCode::ACCEPTED, Json(start_response(&export))))
+}
+
+/// GET /api/v1/users/me/export/:id
+///
+/// The export's status. Once `completed`, includes a presigned download URL valid for
+/// `DOWNLOAD_URL_TTL_SECS`; call again for a fresh one.
+pub async fn handle_export_status(
+    State(state): State<AppState>,
+    auth: AuthUser,
+    Path(export_id): Path<Uuid>,
+) -> Result<Json<ExportStatusResponse>, AppError> {
+    auth.authorize(&state.db, Resource::Export, export_id)
+        .await?;
+    let export = sqlx::query_as!(
+        UserExportRow,
+        "SELECT * FROM user_exports WHERE id = $1",
+        export_id
+    )
+    .fetch_one(&state.db)
+    .await?;
+
+    let (download_url, download_url_expires_at) = match export.s3_key.as_deref() {
+        Some(key) if export.status == "completed" => {
+            let ttl = Duration::from_secs(DOWNLOAD_URL_TTL_SECS);
+            let url = state
+                .blobs
+                .presigned_get(key, ttl, &format!("templar-export-{export_id}.zip"))
+                .await?;
+            let expires_at = chrono::Utc::now() + chrono::Duration::seconds(ttl.as_secs() as i64);
+            (Some(url), Some(expires_at))
+        }
+        _ => (None, None),
+    };
+
+    Ok(Json(ExportStatusResponse {
+        export,
+        download_url,
+        download_url_expires_at,
+    }))
+}
+
+fn start_response(export: &UserExportRow) -> StartExportResponse {
+    StartExportResponse {
+        export_id: export.id,
+        status: export.status.clone(),
+        events_url: format!("/api/v1/events/{}", export.id),
+    }
+}
@@ -0,0 +1,459 @@
+//! Full data export ("takeout") — everything a user has stored, as one ZIP.
+//!
+//! POST /api/v1/users/me/export      — start an export, returns its id (202)
+//! GET  /api/v1/users/me/export/:id  — status; a presigned download URL once completed
+//!
+//! The archive is built by `ExportWorker` on the `export` job queue and stored at
+//! `exports/{user_id}/{export_id}.zip`. Progress is reported on `/api/v1/events/:id`
+//! like generation and render jobs. Layout:
+//!
+//! ```text
+//! manifest.json                 exported_at, file counts
+//! profile.json                  the user row
+//! preferences.json              saved generation defaults
+//! context/entries.json          every version of every context entry
+//! context/snapshots/v{n}.md     snapshot markdown, one file per context version
+//! projects.json
+//! applications.json             the application tracker
+//! personas.json
+//! target_roles.json             saved job descriptions and notes
+//! webhooks.json                 Slack/Discord webhooks, URLs reduced to a hint
+//! resumes/{id}/resume.json      the resume row (JD, status, layout report, ...)
+//! resumes/{id}/bullets.json
+//! resumes/{id}/bullet_revisions.json  each bullet's text history, by bullet id
+//! resumes/{id}/fit_report.json  fit score and keyword coverage
+//! resumes/{id}/resume.tex       LaTeX source, when rendered
+//! resumes/{id}/annotations.json coach notes, when there are any
+//! resumes/{id}/comments.json    review comment threads, when there are any
+//! resumes/{id}/shares.json      share links: expiry and revocation, never the token
+//! ```
+//!
+//! Types and archive assembly live here; the job in worker.rs; handlers in handlers.rs.
+
+pub mod handlers;
+pub mod worker;
+
+use std::collections::{BTreeMap, HashMap};
+use std::io::Write;
+
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use sqlx::FromRow;
+use uuid::Uuid;
+use zip::write::SimpleFileOptions;
+use zip::ZipWriter;
+
+use crate::applications::ApplicationRow;
+use crate::bullet_revisions::BulletRevisionRow;
+use crate::comments::CommentRow;
+use crate::models::context::ContextEntryRow;
+use crate::models::resume::{PersonaRow, ResumeBulletRow, ResumeRow};
+use crate::models::user::User;
+use crate::notifications::webhooks::WebhookView;
+use crate::orgs::AnnotationRow;
+use crate::projects::CvProjectRow;
+use crate::shares::ShareRow;
+use crate::target_roles::TargetRoleRow;
+use crate::users::preferences::Preferences;
+
+pub use worker::ExportJob;
+
+/// How long a download URL from the status endpoint stays valid.
+pub const DOWNLOAD_URL_TTL_SECS: u64 = 15 * 60;
+
+// ────────────────────────────────────────────────────────────────────────────
+// Rows and responses
+// ────────────────────────────────────────────────────────────────────────────
+
+/// A row from `user_exports`. `status` is `pending`, `running`, `completed` or `failed`.
+#[derive(Debug, Clone, Serialize, FromRow)]
+pub struct UserExportRow {
+    pub id: Uuid,
+    pub user_id: Uuid,
+    pub status: String,
+    /// Set once completed.
+    pub s3_key: Option<String>,
+    pub size_bytes: Option<i64>,
+    pub error_message: Option<String>,
+    pub created_at: DateTime<Utc>,
+    pub completed_at: Option<DateTime<Utc>>,
+}
+
+/// Returned by `POST /api/v1/users/me/export`.
+#[derive(Debug, Serialize)]
+pub struct StartExportResponse {
+    pub export_id: Uuid,
+    pub status: String,
+    /// SSE stream of the export's progress.
+    pub events_url: String,
+}
+
+/// Returned by `GET /api/v1/users/me/export/:id`.
+#[derive(Debug, Serialize)]
+pub struct ExportStatusResponse {
+    #[serde(flatten)]
+    pub export: UserExportRow,
+    /// Presigned GET for the ZIP; present once `status` is `completed`.
+    pub download_url: Option<String>,
+    pub download_url_expires_at: Option<DateTime<Utc>>,
+}
+
+/// Where all of `user_id`'s archives are stored.
+pub fn user_prefix(user_id: Uuid) -> String {
+    format!("exports/{user_id}/")
+}
+
+pub fn s3_key(user_id: Uuid, export_id: Uuid) -> String {
+    format!("{}{export_id}.zip", user_prefix(user_id))
+}
+
+// ────────────────────────────────────────────────────────────────────────────
+// Archive
+// ────────────────────────────────────────────────────────────────────────────
+
+/// Everything that goes into one archive, loaded by the worker.
+#[derive(Debug, Default)]
+pub struct ExportData {
+    pub user: Option<User>,
+    pub entries: Vec<ContextEntryRow>,
+    /// `(version, markdown)`; versions whose snapshot could not be read are absent.
+    pub snapshots: Vec<(i32, String)>,
+    pub projects: Vec<CvProjectRow>,
+    pub resumes: Vec<(ResumeRow, Vec<ResumeBulletRow>)>,
+    /// Revision history per bullet id, oldest first.
+    pub bullet_revisions: HashMap<Uuid, Vec<BulletRevisionRow>>,
+    /// Notes on the user's resumes, by anyone.
+    pub annotations: Vec<AnnotationRow>,
+    /// Review comments on the user's resumes, including link reviewers'.
+    pub comments: Vec<CommentRow>,
+    /// Share links minted for the user's resumes. Rows only: the link token is signed
+    /// on demand and never stored, so it cannot leak through an export.
+    pub shares: Vec<ShareRow>,
+    pub preferences: Preferences,
+    pub applications: Vec<ApplicationRow>,
+    pub personas: Vec<PersonaRow>,
+    pub target_roles: Vec<TargetRoleRow>,
+    /// As the API lists them: the URL is a credential, so only `url_hint` is kept.
+    pub webhooks: Vec<WebhookView>,
+}
+
+#[derive(Debug, Serialize)]
+struct Manifest {
+    user_id: Option<Uuid>,
+    exported_at: DateTime<Utc>,
+    context_entry_versions: usize,
+    snapshots: usize,
+    projects: usize,
+    resumes: usize,
+    bullets: usize,
+}
+
+/// The archive's file paths and contents, in write order.
+pub fn archive_files(
+    data: &ExportData,
+    exported_at: DateTime<Utc>,
+) -> serde_json::Result<Vec<(String, Vec<u8>)>> {
+    let manifest = Manifest {
+        user_id: data.user.as_ref().map(|u| u.id),
+        exported_at,
+        context_entry_versions: data.entries.len(),
+        snapshots: data.snapshots.len(),
+        projects: data.projects.len(),
+        resumes: data.resumes.len(),
+        bullets: data.resumes.iter().map(|(_, b)| b.len()).sum(),
+    };
+
+    let mut files = vec![
+        ("manifest.json".to_string(), json(&manifest)?),
+        ("profile.json".to_string(), json(&data.user)?),
+        ("preferences.json".to_string(), json(&data.preferences)?),
+        ("context/entries.json".to_string(), json(&data.entries)?),
+    ];
+    for (version, markdown) in &data.snapshots {
+        files.push((
+            format!("context/snapshots/v{version}.md"),
+            markdown.clone().into_bytes(),
+        ));
+    }
+    files.push(("projects.json".to_string(), json(&data.projects)?));
+    files.push(("applications.json".to_string(), json(&data.applications)?));
+    files.push(("personas.json".to_string(), json(&data.personas)?));
+    files.push(("target_roles.json".to_string(), json(&data.target_roles)?));
+    files.push(("webhooks.json".to_string(), json(&data.webhooks)?));
+    for (resume, bullets) in &data.resumes {
+        let dir = format!("resumes/{}", resume.id);
+        let fit_report = serde_json::json!({
+            "fit_score": resume.fit_score,
+            "keyword_coverage": resume.keyword_coverage,
+        });
+        files.push((format!("{dir}/resume.json"), json(resume)?));
+        files.push((format!("{dir}/bullets.json"), json(bullets)?));
+        let revisions: BTreeMap<Uuid, &Vec<BulletRevisionRow>> = bullets
+            .iter()
+            .filter_map(|b| Some((b.id, data.bullet_revisions.get(&b.id)?)))
+            .collect();
+        if !revisions.is_empty() {
+            files.push((format!("{dir}/bullet_revisions.json"), json(&revisions)?));
+        }
+        files.push((format!("{dir}/fit_report.json"), json(&fit_report)?));
+        if let Some(latex) = &resume.latex_source {
+            files.push((format!("{dir}/resume.tex"), latex.clone().into_bytes()));
+        }
+        let notes: Vec<&AnnotationRow> = data
+            .annotations
+            .iter()
+            .filter(|a| a.resume_id == resume.id)
+            .collect();
+        if !notes.is_empty() {
+            files.push((format!("{dir}/annotations.json"), json(&notes)?));
+        }
+        let comments: Vec<&CommentRow> = data
+            .comments
+            .iter()
+            .filter(|c| c.resume_id == resume.id)
+            .collect();
+        if !comments.is_empty() {
+            files.push((format!("{dir}/comments.json"), json(&comments)?));
+        }
+        let shares: Vec<&ShareRow> = data
+            .shares
+            .iter()
+            .filter(|s| s.resume_id == resume.id)
+            .collect();
+        if !shares.is_empty() {
+            files.push((format!("{dir}/shares.json"), json(&shares)?));
+        }
+    }
+    Ok(files)
+}
+
+fn json<T: Serialize>(value: &T) -> serde_json::Result<Vec<u8>> {
+    serde_json::to_vec_pretty(value)
+}
+
+/// Deflates `files` into a ZIP held in memory. CPU-bound — call from a blocking task.
+pub fn write_zip(files: &[(String, Vec<u8>)]) -> anyhow::Result<Vec<u8>> {
+    let mut zip = ZipWriter::new(std::io::Cursor::new(Vec::new()));
+    let options = SimpleFileOptions::default().compression_method(zip::CompressionMethod::Deflated);
+    for (path, contents) in files {
+        zip.start_file(path.as_str(), options)?;
+        zip.write_all(contents)?;
+    }
+    Ok(zip.finish()?.into_inner())
+}
+
+// ────────────────────────────────────────────────────────────────────────────
+// Tests
+// ────────────────────────────────────────────────────────────────────────────
+
+#[cfg(test)]
+mod tests {
+    use std::io::Read;
+
+    use super::*;
+    use crate::notifications::webhooks::WebhookRow;
+
+    fn resume(latex: Option<&str>) -> ResumeRow {
+        ResumeRow {
+            id: Uuid::new_v4(),
+            user_id: Uuid::new_v4(),
+            jd_text: "Senior Rust engineer".to_string(),
+            jd_parsed: None,
+            fit_score: Some(0.82),
+            latex_source: latex.map(str::to_string),
+            s3_pdf_key: None,
+            status: "rendered".to_string(),
+            template_id: None,
+            keyword_coverage: Some(serde_json::json!({ "matched": ["rust"] })),
+            parent_resume_id: None,
+            paper_size: "letter".to_string(),
+            font_size_pt: 11.0,
+            layout_report: None,
+            margin_preset: "standard".to_string(),
+            created_at: Utc::now(),
+            updated_at: Utc::now(),
+            deleted_at: None,
+            font_family: "inter".to_string(),
+            margin_left_in: None,
+            margin_right_in: None,
+            margin_top_in: None,
+            margin_bottom_in: None,
+            prompt_versions: None,
+            target_role_id: None,
+            explanation: None,
+        }
+    }
+
+    #[test]
+    fn test_archive_layout() {
+        let (rendered, draft) = (resume(Some("\\documentclass{article}")), resume(None));
+        let data = ExportData {
+            snapshots: vec![(1, "# v1".to_string()), (2, "# v2".to_string())],
+            resumes: vec![(rendered.clone(), vec![]), (draft.clone(), vec![])],
+            annotations: vec![AnnotationRow {
+                id: Uuid::new_v4(),
+                resume_id: draft.id,
+                bullet_id: None,
+                author_id: Uuid::new_v4(),
+                body: "Lead with the migration".to_string(),
+                created_at: Utc::now(),
+            }],
+            comments: vec![CommentRow {
+                id: Uuid::new_v4(),
+                resume_id: rendered.id,
+                bullet_id: Uuid::new_v4(),
+                parent_id: None,
+                author_id: None,
+                share_id: Some(Uuid::new_v4()),
+                author_name: "Recruiter".to_string(),
+                body: "Quantify this one".to_string(),
+                resolved_at: None,
+                resolved_by: None,
+                created_at: Utc::now(),
+            }],
+            shares: vec![ShareRow {
+                id: Uuid::new_v4(),
+                resume_id: draft.id,
+                user_id: draft.user_id,
+                expires_at: Utc::now(),
+                revoked_at: Some(Utc::now()),
+                created_at: Utc::now(),
+                can_comment: false,
+            }],
+            ..Default::default()
+        };
+        let files = archive_files(&data, Utc::now()).unwrap();
+        let paths: Vec<&str> = files.iter().map(|(p, _)| p.as_str()).collect();
+
+        assert_eq!(paths[0], "manifest.json");
+        assert!(paths.contains(&"context/snapshots/v2.md"));
+        assert!(paths.contains(&format!("resumes/{}/resume.tex", rendered.id).as_str()));
+        assert!(!paths.contains(&format!("resumes/{}/resume.tex", draft.id).as_str()));
+        assert!(paths.contains(&format!("resumes/{}/fit_report.json", draft.id).as_str()));
+        assert!(paths.contains(&format!("resumes/{}/annotations.json", draft.id).as_str()));
+        assert!(!paths.contains(&format!("resumes/{}/annotations.json", rendered.id).as_str()));
+        assert!(paths.contains(&format!("resumes/{}/comments.json", rendered.id).as_str()));
+        assert!(!paths.contains(&format!("resumes/{}/comments.json", draft.id).as_str()));
+        assert!(paths.contains(&format!("resumes/{}/shares.json", draft.id).as_str()));
+        assert!(!paths.contains(&format!("resumes/{}/shares.json", rendered.id).as_str()));
+    }
+
+    fn bullet(resume_id: Uuid) -> ResumeBulletRow {
+        ResumeBulletRow {
+            id: Uuid::new_v4(),
+            resume_id,
+            section: "experience".to_string(),
+            bullet_text: "Cut p99 latency 40% by batching writes".to_string(),
+            source_entry_id: Uuid::new_v4(),
+            grounding_score: 0.9,
+            is_user_edited: false,
+            line_count: 1,
+            position: 0,
+            created_at: Utc::now(),
+            keyword_spans: serde_json::json!([]),
+        }
+    }
+
+    fn revision(bullet_id: Uuid, revision: i32, cause: &str) -> BulletRevisionRow {
+        BulletRevisionRow {
+            id: Uuid::new_v4(),
+            bullet_id,
+            revision,
+            bullet_text: format!("text {revision}"),
+            cause: cause.to_string(),
+            restored_from: None,
+            created_at: Utc::now(),
+        }
+    }
+
+    #[test]
+    fn test_bullet_revisions_are_filed_under_their_resume_by_bullet() {
+        let (revised, untouched) = (resume(None), resume(None));
+        let (b1, b2) = (bullet(revised.id), bullet(untouched.id));
+        let data = ExportData {
+            bullet_revisions: HashMap::from([(
+                b1.id,
+                vec![revision(b1.id, 1, "generate"), revision(b1.id, 2, "user")],
+            )]),
+            resumes: vec![
+                (revised.clone(), vec![b1.clone()]),
+                (untouched.clone(), vec![b2]),
+            ],
+            ..Default::default()
+        };
+        let files = archive_files(&data, Utc::now()).unwrap();
+        let find = |path: String| files.iter().find(|(p, _)| *p == path).map(|(_, c)| c);
+
+        let revisions: serde_json::Value = serde_json::from_slice(
+            find(format!("resumes/{}/bullet_revisions.json", revised.id)).unwrap(),
+        )
+        .unwrap();
+        let history = revisions[b1.id.to_string()].as_array().unwrap();
+        assert_eq!(history.len(), 2);
+        assert_eq!(history[1]["cause"], "user");
+        assert!(find(format!("resumes/{}/bullet_revisions.json", untouched.id)).is_none());
+    }
+
+    #[test]
+    fn test_webhook_urls_are_redacted() {
+        let secret = "https://hooks.slack.com/services/T000/B000/abcdXy7Q";
+        let data = ExportData {
+            webhooks: vec![WebhookView::from(WebhookRow {
+                id: Uuid::new_v4(),
+                kind: "slack".to_string(),
+                url: secret.to_string(),
+                events: vec!["generation_complete".to_string()],
+                created_at: Utc::now(),
+                last_delivery_at: None,
+                last_error: None,
+                disabled_at: None,
+            })],
+            ..Default::default()
+        };
+        let files = archive_files(&data, Utc::now()).unwrap();
+        let (_, contents) = files.iter().find(|(p, _)| p == "webhooks.json").unwrap();
+        let contents = String::from_utf8_lossy(contents);
+        assert!(contents.contains("Xy7Q"));
+        assert!(!contents.contains(secret));
+        assert!(!contents.contains("T000/B000"));
+    }
+
+    #[test]
+    fn test_manifest_counts() {
+        let data = ExportData {
+            snapshots: vec![(1, String::new())],
+            resumes: vec![(resume(None), vec![])],
+            ..Default::default()
+        };
+        let files = archive_files(&data, Utc::now()).unwrap();
+        let manifest: serde_json::Value = serde_json::from_slice(&files[0].1).unwrap();
+        assert_eq!(manifest["snapshots"], 1);
+        assert_eq!(manifest["resumes"], 1);
+        assert_eq!(manifest["bullets"], 0);
+    }
+
+    #[test]
+    fn test_zip_round_trip() {
+        let files = vec![
+            ("a.json".to_string(), b"{}".to_vec()),
+            ("dir/b.md".to_string(), "# héllo".as_bytes().to_vec()),
+        ];
+        let bytes = write_zip(&files).unwrap();
+
+        let mut archive = zip::ZipArchive::new(std::io::Cursor::new(bytes)).unwrap();
+        assert_eq!(archive.len(), 2);
+        let mut contents = String::new();
+        archive
+            .by_name("dir/b.md")
+            .unwrap()
+            .read_to_string(&mut contents)
+            .unwrap();
+        assert_eq!(contents, "# héllo");
+    }
+
+    #[test]
+    fn test_s3_key_is_per_user() {
+        let (user, export) = (Uuid::new_v4(), Uuid::new_v4());
+        assert_eq!(s3_key(user, export), format!("exports/{user}/{export}.zip"));
+    }
+}
@@ -0,0 +1,284 @@
+//! `ExportJob` — loads a user's data, writes the ZIP and uploads it.
+//!
+//! The `user_exports` row is authoritative: `running` while an attempt is in progress,
+//! then `completed` with the S3 key, or `failed` once the job is dead-lettered.
+//! Snapshots that cannot be read from S3 are skipped with a warning rather than
+//! failing the whole export; the manifest counts what was actually included.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use chrono::Utc;
+use serde::{Deserialize, Serialize};
+use sqlx::PgPool;
+use tracing::{info, warn};
+use uuid::Uuid;
+
+use crate::applications::ApplicationRow;
+use crate::bullet_revisions::BulletRevisionRow;
+use crate::comments::CommentRow;
+use crate::context::versioning::get_all_versions;
+use crate::export::{archive_files, s3_key, write_zip, ExportData};
+use crate::jobs::{Handler, Job, JobError};
+use crate::models::context::ContextSnapshotRow;
+use crate::models::resume::{PersonaRow, ResumeBulletRow, ResumeRow};
+use crate::models::user::User;
+use crate::notifications::webhooks::{WebhookRow, WebhookView};
+use crate::orgs::AnnotationRow;
+use crate::progress::{self, Stage};
+use crate::projects::CvProjectRow;
+use crate::shares::ShareRow;
+use crate::storage::BlobStore;
+use crate::target_roles::TargetRoleRow;
+use crate::users::preferences;
+
+/// Exports built at once. Kept low: each holds its whole archive in memory.
+pub const WORKER_CONCURRENCY: usize = 2;
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ExportJob {
+    pub export_id: Uuid,
+}
+
+impl Job for ExportJob {
+    const QUEUE: &'static str = "export";
+}
+
+pub struct ExportWorker {
+    pub redis: redis::Client,
+    pub db: PgPool,
+    pub blobs: Arc<dyn BlobStore>,
+}
+
+#[async_trait]
+impl Handler for ExportWorker {
+    type Job = ExportJob;
+
+    async fn run(&self, job: &ExportJob) -> Result<(), JobError> {
+        progress::scoped(&self.redis, job.export_id, async {
+            self.export(job.export_id).await?;
+            progress::emit(Stage::Completed).await;
+            Ok::<_, JobError>(())
+        })
+        .await
+    }
+
+    async fn on_dead(&self, job: &ExportJob, error: &str) {
+        let result = sqlx::query!(
+            "UPDATE user_exports SET status = 'failed', error_message = $2 WHERE id = $1",
+            job.export_id,
+            error
+        )
+        .execute(&self.db)
+        .await;
+        if let Err(e) = result {
+            warn!(export_id = %job.export_id, "failed to mark export failed: {e}");
+        }
+        progress::scoped(&self.redis, job.export_id, async {
+            progress::emit(Stage::Failed {
+                message: "The export failed".to_string(),
+            })
+            .await
+        })
+        .await;
+    }
+}
+
+impl ExportWorker {
+    async fn export(&self, export_id: Uuid) -> anyhow::Result<()> {
+        let user_id: Option<Uuid> = sqlx::query_scalar!(
+            "UPDATE user_exports SET status = 'running' WHERE id = $1 RETURNING user_id",
+            export_id
+        )
+        .fetch_optional(&self.db)
+        .await?;
+        let Some(user_id) = user_id else {
+            // Deleted along with its account; nothing to do.
+            info!(%export_id, "export row gone — skipping");
+            return Ok(());
+        };
+
+        let data = self.load(user_id).await?;
+        let files = archive_files(&data, Utc::now())?;
+        progress::emit(Stage::ExportCollected { files: files.len() }).await;
+
+        let zip = tokio::task::spawn_blocking(move || write_zip(&files)).await??;
+        let size = zip.len();
+        let key = s3_key(user_id, export_id);
+        self.blobs.put(&key, zip, "application/zip").await?;
+        progress::emit(Stage::ExportUploaded { bytes: size as u64 }).await;
+
+        sqlx::query!(
+            r#"UPDATE user_exports
+               SET status = 'completed', s3_key = $2, size_bytes = $3,
+                   error_message = NULL, completed_at = NOW()
+               WHERE id = $1"#,
+            export_id,
+            &key,
+            size as i64
+        )
+        .execute(&self.db)
+        .await?;
+        info!(%export_id, %user_id, bytes = size, "export completed");
+        Ok(())
+    }
+
+    async fn load(&self, user_id: Uuid) -> anyhow::Result<ExportData> {
+        let user = sqlx::query_as!(User, "SELECT * FROM users WHERE id = $1", user_id)
+            .fetch_optional(&self.db)
+            .await?;
+        // Archived versions are still the user's data.
+        let entries = get_all_versions(&self.db, user_id).await?;
+        let snapshot_rows = sqlx::query_as!(
+            ContextSnapshotRow,
+            "SELECT * FROM context_snapshots WHERE user_id = $1 AND deleted_at IS NULL ORDER BY version",
+            user_id
+        )
+        .fetch_all(&self.db)
+        .await?;
+        let projects = sqlx::query_as!(
+            CvProjectRow,
+            "SELECT * FROM cv_projects WHERE user_id = $1 ORDER BY created_at",
+            user_id
+        )
+        .fetch_all(&self.db)
+        .await?;
+        let resumes = sqlx::query_as!(
+            ResumeRow,
+            "SELECT * FROM resumes WHERE user_id = $1 AND deleted_at IS NULL ORDER BY created_at",
+            user_id
+        )
+        .fetch_all(&self.db)
+        .await?;
+        let bullets = sqlx::query_as!(
+            ResumeBulletRow,
+            r#"SELECT b.* FROM resume_bullets b JOIN resumes r ON r.id = b.resume_id
+               WHERE r.user_id = $1 AND r.deleted_at IS NULL
+               ORDER BY b.resume_id, b.section, b.position"#,
+            user_id
+        )
+        .fetch_all(&self.db)
+        .await?;
+        let revisions = sqlx::query_as!(
+            BulletRevisionRow,
+            r#"SELECT v.* FROM bullet_revisions v
+               JOIN resume_bullets b ON b.id = v.bullet_id
+               JOIN resumes r ON r.id = b.resume_id
+               WHERE r.user_id = $1 AND r.deleted_at IS NULL
+               ORDER BY v.bullet_id, v.revision"#,
+            user_id
+        )
+        .fetch_all(&self.db)
+        .await?;
+
+        let annotations = sqlx::query_as!(
+            AnnotationRow,
+            r#"SELECT a.* FROM resume_annotations a JOIN resumes r ON r.id = a.resume_id
+               WHERE r.user_id = $1 AND r.deleted_at IS NULL
+               ORDER BY a.created_at"#,
+            user_id
+        )
+        .fetch_all(&self.db)
+        .await?;
+        let comments = sqlx::query_as!(
+            CommentRow,
+            r#"SELECT c.* FROM resume_comments c JOIN resumes r ON r.id = c.resume_id
+               WHERE r.user_id = $1 AND r.deleted_at IS NULL
+               ORDER BY c.created_at, c.id"#,
+            user_id
+        )
+        .fetch_all(&self.db)
+        .await?;
+        let shares = sqlx::query_as!(
+            ShareRow,
+            r#"SELECT s.* FROM resume_shares s JOIN resumes r ON r.id = s.resume_id
+               WHERE s.user_id = $1 AND r.deleted_at IS NULL
+               ORDER BY s.created_at"#,
+            user_id
+        )
+        .fetch_all(&self.db)
+        .await?;
+
+        let applications = sqlx::query_as!(
+            ApplicationRow,
+            "SELECT * FROM applications WHERE user_id = $1 ORDER BY applied_on, created_at",
+            user_id
+        )
+        .fetch_all(&self.db)
+        .await?;
+        let personas = sqlx::query_as!(
+            PersonaRow,
+            "SELECT * FROM personas WHERE user_id = $1 ORDER BY created_at",
+            user_id
+        )
+        .fetch_all(&self.db)
+        .await?;
+        let target_roles = sqlx::query_as!(
+            TargetRoleRow,
+            "SELECT * FROM target_roles WHERE user_id = $1 ORDER BY created_at",
+            user_id
+        )
+        .fetch_all(&self.db)
+        .await?;
+        let webhooks = sqlx::query_as!(
+            WebhookRow,
+            r#"SELECT id, kind, url, events, created_at, last_delivery_at, last_error, disabled_at
+               FROM user_webhooks WHERE user_id = $1
+               ORDER BY created_at, id"#,
+            user_id
+        )
+        .fetch_all(&self.db)
+        .await?;
+        let preferences = preferences::load(&self.db, user_id).await?;
+
+        let mut snapshots = Vec::with_capacity(snapshot_rows.len());
+        for row in snapshot_rows {
+            match self.read_snapshot(&row.s3_key).await {
+                Ok(markdown) => snapshots.push((row.version, markdown)),
+                Err(e) => warn!(%user_id, key = %row.s3_key, "skipping unreadable snapshot: {e}"),
+            }
+        }
+
+        let mut by_resume: HashMap<Uuid, Vec<ResumeBulletRow>> = HashMap::new();
+        for bullet in bullets {
+            by_resume.entry(bullet.resume_id).or_default().push(bullet);
+        }
+        let resumes = resumes
+            .into_iter()
+            .map(|resume| {
+                let bullets = by_resume.remove(&resume.id).unwrap_or_default();
+                (resume, bullets)
+            })
+            .collect();
+        let mut bullet_revisions: HashMap<Uuid, Vec<BulletRevisionRow>> = HashMap::new();
+        for revision in revisions {
+            bullet_revisions
+                .entry(revision.bullet_id)
+                .or_default()
+                .push(revision);
+        }
+
+        Ok(ExportData {
+            user,
+            entries,
+            snapshots,
+            projects,
+            resumes,
+            bullet_revisions,
+            annotations,
+            comments,
+            shares,
+            preferences,
+            applications,
+            personas,
+            target_roles,
+            webhooks: webhooks.into_iter().map(WebhookView::from).collect(),
+        })
+    }
+
+    async fn read_snapshot(&self, key: &str) -> anyhow::Result<String> {
+        let bytes = self.blobs.get(key).await?;
+        Ok(String::from_utf8_lossy(&bytes).into_owned())
+    }
+}
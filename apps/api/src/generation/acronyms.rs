@@ -0,0 +1,570 @@
+//! Acronym pass — makes sure the JD's acronyms appear in the resume in both forms.
+//!
+//! ATS keyword matching is literal: a JD asking for "continuous integration" does not
+//! match a resume that only says "CI/CD", and the other way round. After drafting, and
+//! before layout so the simulation measures the longer text, every acronym the JD uses
+//! in either form is looked up in the bullets. When the bullets have only the other
+//! form, its first occurrence is written out as "long form (ACRONYM)".
+//!
+//! Expansions come from a built-in table plus definitions written in the JD or the
+//! bullets ("Site Reliability Engineering (SRE)"); the JD's wording wins. What adding
+//! text cannot fix — one acronym spelled two ways, or defined differently by the resume
+//! and the JD — is flagged and left as written.
+
+use crate::generation::generator::DraftBullet;
+
+pub use templar_types::generation::{AcronymFlag, AcronymIssue, AcronymReport, AcronymRewrite};
+
+// ────────────────────────────────────────────────────────────────────────────
+// Known acronyms
+// ────────────────────────────────────────────────────────────────────────────
+
+struct KnownAcronym {
+    short: &'static str,
+    long: &'static str,
+    /// Other long forms that count as the acronym being spelled out.
+    long_variants: &'static [&'static str],
+    /// Other spellings of the acronym itself.
+    short_variants: &'static [&'static str],
+}
+
+const fn known(short: &'static str, long: &'static str) -> KnownAcronym {
+    KnownAcronym {
+        short,
+        long,
+        long_variants: &[],
+        short_variants: &[],
+    }
+}
+
+/// Acronyms that JDs and ATS keyword lists commonly use in only one of their forms.
+/// Ones that are never spelled out ("API", "SQL") are left off.
+const KNOWN_ACRONYMS: &[KnownAcronym] = &[
+    KnownAcronym {
+        short: "CI/CD",
+        long: "continuous integration/continuous delivery",
+        long_variants: &[
+            "continuous integration",
+            "continuous delivery",
+            "continuous deployment",
+        ],
+        short_variants: &["CICD", "CI-CD"],
+    },
+    KnownAcronym {
+        short: "ETL",
+        long: "extract, transform, load",
+        long_variants: &["extract, transform, and load", "extract transform load"],
+        short_variants: &[],
+    },
+    KnownAcronym {
+        short: "SEO",
+        long: "search engine optimization",
+        long_variants: &["search engine optimisation"],
+        short_variants: &[],
+    },
+    known("ML", "machine learning"),
+    known("AI", "artificial intelligence"),
+    known("NLP", "natural language processing"),
+    known("LLM", "large language model"),
+    known("SRE", "site reliability engineering"),
+    known("SLA", "service level agreement"),
+    known("SLO", "service level objective"),
+    known("KPI", "key performance indicator"),
+    known("OKR", "objectives and key results"),
+    known("CRM", "customer relationship management"),
+    known("ERP", "enterprise resource planning"),
+    known("SaaS", "software as a service"),
+    known("IaC", "infrastructure as code"),
+    known("TDD", "test-driven development"),
+    known("QA", "quality assurance"),
+    known("UX", "user experience"),
+    known("RBAC", "role-based access control"),
+    known("SSO", "single sign-on"),
+    known("P&L", "profit and loss"),
+    known("B2B", "business-to-business"),
+    known("AWS", "Amazon Web Services"),
+    known("GCP", "Google Cloud Platform"),
+    known("GDPR", "General Data Protection Regulation"),
+];
+
+/// Words an expansion may skip when matching initials: "objectives and key results".
+const STOP_WORDS: &[&str] = &["a", "an", "and", "as", "for", "of", "on", "the", "to"];
+
+/// One acronym for this run: a built-in entry, possibly reworded by the JD.
+struct Acronym {
+    short: String,
+    long: String,
+    long_variants: Vec<String>,
+    short_variants: Vec<String>,
+    /// `long` is the JD's own definition.
+    defined_by_jd: bool,
+}
+
+impl Acronym {
+    fn spellings(&self) -> impl Iterator<Item = &str> {
+        std::iter::once(self.short.as_str()).chain(self.short_variants.iter().map(String::as_str))
+    }
+
+    fn long_forms(&self) -> impl Iterator<Item = &str> {
+        std::iter::once(self.long.as_str()).chain(self.long_variants.iter().map(String::as_str))
+    }
+
+    fn expands_to(&self, long: &str) -> bool {
+        let long = normalize(long);
+        self.long_forms().any(|l| normalize(l) == long)
+    }
+}
+
+// ────────────────────────────────────────────────────────────────────────────
+// Pass
+// ────────────────────────────────────────────────────────────────────────────
+
+/// Writes out JD acronyms the bullets only use in one form and reports what it changed
+/// and what it flagged. Each acronym is rewritten at most once, at its first occurrence.
+pub fn check_acronyms(bullets: &mut [DraftBullet], jd_text: &str) -> AcronymReport {
+    let mut table = acronym_table(jd_text);
+    let mut report = AcronymReport::default();
+
+    // Definitions the resume writes itself; a JD definition it contradicts is flagged.
+    let mut conflicted = vec![];
+    for (short, long) in bullets.iter().flat_map(|b| definitions(&b.text)) {
+        match table.iter_mut().find(|a| a.spellings().any(|s| s == short)) {
+            Some(acronym) if acronym.expands_to(&long) => {}
+            Some(acronym) if acronym.defined_by_jd => {
+                report.flags.push(AcronymFlag {
+                    acronym: short.clone(),
+                    issue: AcronymIssue::ConflictingExpansion,
+                    detail: format!("resume: \"{long}\", JD: \"{}\"", acronym.long),
+                });
+                conflicted.push(acronym.short.clone());
+            }
+            Some(acronym) => acronym.long_variants.push(long),
+            None => table.push(Acronym {
+                short,
+                long,
+                long_variants: vec![],
+                short_variants: vec![],
+                defined_by_jd: false,
+            }),
+        }
+    }
+
+    for acronym in &table {
+        let used: Vec<&str> = acronym
+            .spellings()
+            .filter(|s| bullets.iter().any(|b| find_short(&b.text, s).is_some()))
+            .collect();
+        if used.len() > 1 {
+            report.flags.push(AcronymFlag {
+                acronym: acronym.short.clone(),
+                issue: AcronymIssue::MixedSpellings,
+                detail: format!("spelled {}", used.join(", ")),
+            });
+        }
+
+        if conflicted.contains(&acronym.short) {
+            continue;
+        }
+        let jd_spelling = acronym
+            .spellings()
+            .find(|s| find_short(jd_text, s).is_some());
+        let jd_has_long = acronym
+            .long_forms()
+            .any(|l| find_long(jd_text, l).is_some());
+        if jd_spelling.is_none() && !jd_has_long {
+            continue;
+        }
+        let resume_has_long = acronym
+            .long_forms()
+            .any(|l| bullets.iter().any(|b| find_long(&b.text, l).is_some()));
+        if !used.is_empty() && resume_has_long {
+            continue;
+        }
+
+        let rewrite = if let Some(spelling) = used.first() {
+            // Short form only: "CI/CD pipelines" → "continuous integration/… (CI/CD) pipelines".
+            bullets.iter_mut().find_map(|b| {
+                let m = find_short(&b.text, spelling)?;
+                let s = if m.plural { "s" } else { "" };
+                let long = if m.start == 0 {
+                    capitalize(&acronym.long)
+                } else {
+                    acronym.long.clone()
+                };
+                b.text.replace_range(
+                    m.start..m.end + s.len(),
+                    &format!("{long}{s} ({spelling}{s})"),
+                );
+                Some(b)
+            })
+        } else if resume_has_long {
+            // Long form only: "site reliability engineering" → "… engineering (SRE)".
+            let spelling = jd_spelling.unwrap_or(acronym.short.as_str());
+            bullets.iter_mut().find_map(|b| {
+                let m = acronym.long_forms().find_map(|l| find_long(&b.text, l))?;
+                let s = if m.plural { "s" } else { "" };
+                b.text
+                    .insert_str(m.end + s.len(), &format!(" ({spelling}{s})"));
+                Some(b)
+            })
+        } else {
+            None
+        };
+        if let Some(bullet) = rewrite {
+            report.rewrites.push(AcronymRewrite {
+                acronym: acronym.short.clone(),
+                expansion: acronym.long.clone(),
+                source_entry_id: bullet.source_entry_id,
+                text: bullet.text.clone(),
+            });
+        }
+    }
+    report
+}
+
+/// The built-in acronyms, with the JD's definitions replacing or extending them.
+fn acronym_table(jd_text: &str) -> Vec<Acronym> {
+    let mut table: Vec<Acronym> = KNOWN_ACRONYMS
+        .iter()
+        .map(|k| Acronym {
+            short: k.short.to_string(),
+            long: k.long.to_string(),
+            long_variants: k.long_variants.iter().map(|s| s.to_string()).collect(),
+            short_variants: k.short_variants.iter().map(|s| s.to_string()).collect(),
+            defined_by_jd: false,
+        })
+        .collect();
+
+    for (short, long) in definitions(jd_text) {
+        match table.iter_mut().find(|a| a.spellings().any(|s| s == short)) {
+            Some(acronym) if acronym.defined_by_jd => {}
+            Some(acronym) => {
+                if !acronym.expands_to(&long) {
+                    let previous = std::mem::replace(&mut acronym.long, long);
+                    acronym.long_variants.push(previous);
+                } else {
+                    acronym.long = long;
+                }
+                acronym.defined_by_jd = true;
+            }
+            None => table.push(Acronym {
+                short,
+                long,
+                long_variants: vec![],
+                short_variants: vec![],
+                defined_by_jd: true,
+            }),
+        }
+    }
+    table
+}
+
+// ────────────────────────────────────────────────────────────────────────────
+// Definitions
+// ────────────────────────────────────────────────────────────────────────────
+
+/// `(acronym, long form)` pairs defined in `text` as "Long Form (LF)" or
+/// "LF (Long Form)", where the long form's initials spell the acronym.
+fn definitions(text: &str) -> Vec<(String, String)> {
+    let mut found = vec![];
+    for (open, _) in text.match_indices('(') {
+        let Some(close) = text[open..].find(')').map(|i| open + i) else {
+            continue;
+        };
+        let inner = text[open + 1..close].trim();
+        if inner.contains('(') {
+            continue;
+        }
+        let before = text[..open].trim_end();
+        if is_acronym(inner) {
+            let short = singular(inner);
+            if let Some(long) = expansion_ending(before, short) {
+                found.push((short.to_string(), long.to_string()));
+            }
+        } else if let Some(token) = before.split_whitespace().next_back() {
+            let short = singular(token);
+            if is_acronym(token) && expansion_ending(inner, short) == Some(inner) {
+                found.push((short.to_string(), inner.to_string()));
+            }
+        }
+    }
+    found
+}
+
+/// The words at the end of `text` whose initials spell the capitals of `short`,
+/// allowing stop words between them.
+fn expansion_ending<'a>(text: &'a str, short: &str) -> Option<&'a str> {
+    let letters: Vec<char> = short.chars().filter(char::is_ascii_uppercase).collect();
+    let mut needed = letters.len();
+    let mut start = None;
+    for (pos, word) in word_spans(text).into_iter().rev() {
+        if needed == 0 {
+            break;
+        }
+        let initial = word.chars().next()?.to_ascii_uppercase();
+        if initial == letters[needed - 1] {
+            needed -= 1;
+            start = Some(pos);
+        } else if !(start.is_some() && STOP_WORDS.contains(&word.to_lowercase().as_str())) {
+            return None;
+        }
+    }
+    if needed > 0 {
+        return None;
+    }
+    let long = text[start?..].trim_end_matches(|c: char| !c.is_alphanumeric());
+    Some(long)
+}
+
+/// Words split on whitespace, `/` and `-`, with leading punctuation trimmed, and the
+/// byte offset each starts at.
+fn word_spans(text: &str) -> Vec<(usize, &str)> {
+    let mut spans = vec![];
+    let mut offset = 0;
+    for piece in text.split(|c: char| c.is_whitespace() || c == '/' || c == '-') {
+        let word = piece.trim_start_matches(|c: char| !c.is_alphanumeric());
+        if !word.is_empty() {
+            spans.push((offset + piece.len() - word.len(), word));
+        }
+        offset += piece.len() + 1;
+    }
+    spans
+}
+
+/// Two or more capitals, starting with one, and no spaces: "SRE", "CI/CD", "SaaS", "P&L".
+fn is_acronym(token: &str) -> bool {
+    let token = singular(token);
+    (2..=8).contains(&token.len())
+        && token.starts_with(|c: char| c.is_ascii_uppercase())
+        && token.chars().filter(char::is_ascii_uppercase).count() >= 2
+        && token
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || matches!(c, '/' | '&' | '-'))
+}
+
+/// "KPIs" → "KPI".
+fn singular(token: &str) -> &str {
+    match token.strip_suffix('s') {
+        Some(rest) if rest.ends_with(|c: char| c.is_ascii_uppercase()) => rest,
+        _ => token,
+    }
+}
+
+// ────────────────────────────────────────────────────────────────────────────
+// Matching
+// ────────────────────────────────────────────────────────────────────────────
+
+struct TermMatch {
+    start: usize,
+    /// End of the term, before any plural "s".
+    end: usize,
+    plural: bool,
+}
+
+/// Case-sensitive whole-term match of an acronym spelling, plural allowed.
+fn find_short(text: &str, spelling: &str) -> Option<TermMatch> {
+    find_term(text, spelling)
+}
+
+/// Whole-term match of a long form, ignoring ASCII case and hyphens vs spaces.
+fn find_long(text: &str, long: &str) -> Option<TermMatch> {
+    // Byte-for-byte replacements, so offsets into the normalized text hold in `text`.
+    find_term(&normalize(text), &normalize(long))
+}
+
+fn normalize(text: &str) -> String {
+    text.to_ascii_lowercase().replace('-', " ")
+}
+
+/// First occurrence of `needle` in `haystack` with non-alphanumeric characters (or a
+/// single plural "s") on both sides.
+fn find_term(haystack: &str, needle: &str) -> Option<TermMatch> {
+    if needle.is_empty() {
+        return None;
+    }
+    for (begin, _) in haystack.match_indices(needle) {
+        let end = begin + needle.len();
+        let before_ok = !haystack[..begin]
+            .chars()
+            .next_back()
+            .is_some_and(char::is_alphanumeric);
+        let mut rest = haystack[end..].chars();
+        let (after_ok, plural) = match rest.next() {
+            Some('s') => (!rest.next().is_some_and(char::is_alphanumeric), true),
+            Some(c) => (!c.is_alphanumeric(), false),
+            None => (true, false),
+        };
+        if before_ok && after_ok {
+            return Some(TermMatch {
+                start: begin,
+                end,
+                plural,
+            });
+        }
+    }
+    None
+}
+
+fn capitalize(text: &str) -> String {
+    let mut chars = text.chars();
+    match chars.next() {
+        Some(first) => first.to_uppercase().chain(chars).collect(),
+        None => String::new(),
+    }
+}
+
+// ────────────────────────────────────────────────────────────────────────────
+// Tests
+// ────────────────────────────────────────────────────────────────────────────
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use uuid::Uuid;
+
+    fn make_bullet(text: &str) -> DraftBullet {
+        DraftBullet {
+            text: text.to_string(),
+            source_entry_id: Uuid::new_v4(),
+            section: "experience".to_string(),
+            line_estimate: 1,
+            jd_keywords_used: vec![],
+        }
+    }
+
+    #[test]
+    fn test_short_form_is_expanded_when_jd_uses_long_form() {
+        let mut bullets = vec![make_bullet("Built CI/CD pipelines for 40 services")];
+        let report = check_acronyms(
+            &mut bullets,
+            "Experience with continuous integration is a must.",
+        );
+
+        assert_eq!(
+            bullets[0].text,
+            "Built continuous integration/continuous delivery (CI/CD) pipelines for 40 services"
+        );
+        assert_eq!(report.rewrites.len(), 1);
+        assert_eq!(report.rewrites[0].acronym, "CI/CD");
+        assert_eq!(
+            report.rewrites[0].source_entry_id,
+            bullets[0].source_entry_id
+        );
+    }
+
+    #[test]
+    fn test_long_form_gets_acronym_when_jd_uses_acronym() {
+        let mut bullets = vec![
+            make_bullet("Cut inference cost 40% on large language models"),
+            make_bullet("Ran site reliability engineering for payments"),
+        ];
+        let report = check_acronyms(&mut bullets, "You will fine-tune LLMs and join SRE.");
+
+        assert_eq!(
+            bullets[0].text,
+            "Cut inference cost 40% on large language models (LLMs)"
+        );
+        assert_eq!(
+            bullets[1].text,
+            "Ran site reliability engineering (SRE) for payments"
+        );
+        assert_eq!(report.rewrites.len(), 2);
+    }
+
+    #[test]
+    fn test_sentence_start_is_capitalized_and_plural_kept() {
+        let mut bullets = vec![make_bullet("KPIs for onboarding rose 12%")];
+        check_acronyms(&mut bullets, "Owns key performance indicators for growth.");
+        assert_eq!(
+            bullets[0].text,
+            "Key performance indicators (KPIs) for onboarding rose 12%"
+        );
+    }
+
+    #[test]
+    fn test_jd_definition_supplies_unknown_expansion() {
+        let mut bullets = vec![make_bullet("Shipped the PLG funnel redesign")];
+        let report = check_acronyms(
+            &mut bullets,
+            "We are a Product-Led Growth (PLG) company hiring a PLG lead.",
+        );
+        assert_eq!(
+            bullets[0].text,
+            "Shipped the Product-Led Growth (PLG) funnel redesign"
+        );
+        assert_eq!(report.rewrites[0].expansion, "Product-Led Growth");
+    }
+
+    #[test]
+    fn test_no_rewrite_when_both_forms_present_or_jd_silent() {
+        let mut bullets = vec![
+            make_bullet("Introduced machine learning ranking"),
+            make_bullet("Moved ML training to spot instances"),
+            make_bullet("Set SLOs for every tier-1 service"),
+        ];
+        let before: Vec<String> = bullets.iter().map(|b| b.text.clone()).collect();
+        let report = check_acronyms(&mut bullets, "Strong ML background required.");
+
+        let after: Vec<String> = bullets.iter().map(|b| b.text.clone()).collect();
+        assert_eq!(before, after);
+        assert!(report.rewrites.is_empty());
+        assert!(report.flags.is_empty());
+    }
+
+    #[test]
+    fn test_acronyms_inside_words_do_not_match() {
+        let mut bullets = vec![make_bullet("Rewrote the HTML email templates")];
+        let report = check_acronyms(&mut bullets, "Machine learning experience preferred.");
+        assert_eq!(bullets[0].text, "Rewrote the HTML email templates");
+        assert!(report.rewrites.is_empty());
+    }
+
+    #[test]
+    fn test_mixed_spellings_are_flagged() {
+        let mut bullets = vec![
+            make_bullet("Owned CI/CD for the mobile apps"),
+            make_bullet("Cut CICD run time from 30 to 9 minutes"),
+        ];
+        let report = check_acronyms(&mut bullets, "Rust backend role.");
+
+        assert_eq!(report.flags.len(), 1);
+        assert_eq!(report.flags[0].issue, AcronymIssue::MixedSpellings);
+        assert_eq!(report.flags[0].detail, "spelled CI/CD, CICD");
+    }
+
+    #[test]
+    fn test_conflicting_definition_is_flagged_not_rewritten() {
+        let mut bullets = vec![make_bullet(
+            "Ran the Partner Review Board (PRB) for API changes",
+        )];
+        let report = check_acronyms(
+            &mut bullets,
+            "You will chair the Production Readiness Board (PRB).",
+        );
+
+        assert_eq!(
+            bullets[0].text,
+            "Ran the Partner Review Board (PRB) for API changes"
+        );
+        assert_eq!(report.flags.len(), 1);
+        assert_eq!(report.flags[0].issue, AcronymIssue::ConflictingExpansion);
+        assert!(report.rewrites.is_empty());
+    }
+
+    #[test]
+    fn test_definitions_in_either_order() {
+        assert_eq!(
+            definitions(
+                "Drive objectives and key results (OKRs) and SRE (site reliability engineering)."
+            ),
+            vec![
+                ("OKR".to_string(), "objectives and key results".to_string()),
+                (
+                    "SRE".to_string(),
+                    "site reliability engineering".to_string()
+                ),
+            ]
+        );
+        assert!(definitions("Worked in Go (mostly) on the API layer (REST)").is_empty());
+    }
+}
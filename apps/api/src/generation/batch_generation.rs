@@ -0,0 +1,551 @@
+#![allow(dead_code)]
+
+//! Batch resume generation — one request, several JDs, one job per JD.
+//!
+//! The request path only validates, dedups, checks quota and writes job rows; the
+//! pipeline itself runs in a detached tokio task. That task loads the user's context
+//! ONCE and runs each JD through `generate_resume_with_entries` sequentially, so a
+//! 10-JD batch costs one context query and never bursts 10 pipelines at the LLM.
+//!
+//! Quota: a per-tier rolling 24h generation limit. Resumes created in the window and
+//! jobs still queued/running both count, so a user cannot stack batches to overshoot.
+//! The monthly tier quota (`crate::quota`) is charged for the whole batch on accept;
+//! the worker refunds failed jobs and charges the LLM tokens each job used.
+
+use std::collections::HashSet;
+
+use serde::{Deserialize, Serialize};
+use sqlx::{PgConnection, PgPool};
+use tracing::{error, info};
+use uuid::Uuid;
+use validator::Validate;
+
+use crate::context::versioning::get_current_entries;
+use crate::errors::AppError;
+use crate::generation::generator::{generate_resume_with_entries, GenerateRequest};
+use crate::layout::{MarginPreset, PaperSize};
+use crate::llm_client;
+use crate::models::resume::GenerationJobRow;
+use crate::progress;
+use crate::quota::{self, Reservation};
+use crate::state::AppState;
+use crate::validation::jd_texts;
+
+/// Hard cap on JDs per batch request (after dedup).
+pub const MAX_BATCH_JDS: usize = 10;
+
+// ────────────────────────────────────────────────────────────────────────────
+// Data models
+// ────────────────────────────────────────────────────────────────────────────
+
+/// Request body for `POST /api/v1/resumes/generate-batch`.
+#[derive(Debug, Clone, Deserialize, Validate)]
+pub struct BatchGenerateRequest {
+    /// Each is checked like `GenerateRequest::jd_text`. The cap applies after dedup.
+    #[validate(length(min = 1), custom(function = "jd_texts"))]
+    pub jd_texts: Vec<String>,
+    /// Applied to every resume in the batch.
+    #[serde(default)]
+    pub paper_size: Option<PaperSize>,
+    /// Applied to every resume in the batch.
+    #[serde(default)]
+    pub margin_preset: Option<MarginPreset>,
+}
+
+/// Where the user stands against their rolling 24h generation limit.
+#[derive(Debug, Clone, Serialize)]
+pub struct QuotaStatus {
+    pub tier: String,
+    pub daily_limit: i64,
+    /// Resumes generated in the last 24h plus jobs still queued or running.
+    pub used: i64,
+    pub remaining: i64,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct BatchGenerateResponse {
+    pub batch_id: Uuid,
+    /// One job per unique JD, in submission order.
+    pub job_ids: Vec<Uuid>,
+    /// JDs dropped because they duplicated an earlier JD in the same request.
+    pub duplicates_dropped: usize,
+    /// Quota AFTER this batch was accepted.
+    pub quota: QuotaStatus,
+}
+
+/// Polling response for `GET /api/v1/resumes/batches/:id`.
+#[derive(Debug, Clone, Serialize)]
+pub struct BatchGenerationStatus {
+    pub batch_id: Uuid,
+    pub total: usize,
+    pub queued: usize,
+    pub running: usize,
+    pub succeeded: usize,
+    pub failed: usize,
+    /// "processing" until every job is terminal, then "done".
+    pub status: String,
+    pub jobs: Vec<GenerationJobRow>,
+}
+
+// ────────────────────────────────────────────────────────────────────────────
+// Validation + dedup
+// ────────────────────────────────────────────────────────────────────────────
+
+/// Case- and whitespace-insensitive key for JD dedup. Re-pasting the same posting
+/// with different line wrapping must not cost the user two generations.
+pub fn normalize_jd(text: &str) -> String {
+    text.split_whitespace()
+        .map(|w| w.to_lowercase())
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// Drops later duplicates, preserving submission order.
+///
+/// Returns `(unique_jds, duplicates_dropped)`. Blank and oversized JDs were already
+/// rejected by `BatchGenerateRequest`'s validation — an empty string in the array is
+/// almost certainly a client bug, so it is not silently dropped.
+pub fn dedup_jd_texts(jd_texts: Vec<String>) -> Result<(Vec<String>, usize), AppError> {
+    let mut seen = HashSet::new();
+    let mut unique = Vec::with_capacity(jd_texts.len());
+    let mut dropped = 0;
+    for text in jd_texts {
+        if seen.insert(normalize_jd(&text)) {
+            unique.push(text);
+        } else {
+            dropped += 1;
+        }
+    }
+
+    if unique.len() > MAX_BATCH_JDS {
+        return Err(AppError::Validation(format!(
+            "A batch may contain at most {MAX_BATCH_JDS} unique job descriptions (got {})",
+            unique.len()
+        )));
+    }
+
+    Ok((unique, dropped))
+}
+
+// ────────────────────────────────────────────────────────────────────────────
+// Quota
+// ────────────────────────────────────────────────────────────────────────────
+
+/// Resume generations allowed per rolling 24h, by `users.tier`.
+/// Unknown tiers get the free limit rather than an error.
+pub fn daily_generation_limit(tier: &str) -> i64 {
+    match tier {
+        "pro" => 100,
+        "team" => 300,
+        "api" => 1_000,
+        _ => 20,
+    }
+}
+
+/// Pure quota arithmetic — errors if `requested` more generations would exceed the limit.
+pub fn evaluate_quota(tier: &str, used: i64, requested: usize) -> Result<QuotaStatus, AppError> {
+    let daily_limit = daily_generation_limit(tier);
+    let remaining = (daily_limit - used).max(0);
+    if requested as i64 > remaining {
+        return Err(AppError::QuotaExceeded {
+            message: format!(
+                "Batch of {requested} exceeds remaining daily generation quota \
+                 ({remaining} of {daily_limit} left on the '{tier}' tier)"
+            ),
+            // Rolling window — there is no single reset instant.
+            resets_at: None,
+        });
+    }
+    Ok(QuotaStatus {
+        tier: tier.to_string(),
+        daily_limit,
+        used: used + requested as i64,
+        remaining: remaining - requested as i64,
+    })
+}
+
+/// Loads the user's tier and current usage, then checks `requested` against it. Locks
+/// the user row, so concurrent batches for the same user count one after the other;
+/// the caller inserts the batch in the same transaction.
+pub async fn check_generation_quota(
+    conn: &mut PgConnection,
+    user_id: Uuid,
+    requested: usize,
+) -> Result<QuotaStatus, AppError> {
+    let tier: String =
+        sqlx::query_scalar!("SELECT tier FROM users WHERE id = $1 FOR UPDATE", user_id)
+            .fetch_optional(&mut *conn)
+            .await?
+            .ok_or_else(|| AppError::NotFound(format!("User {user_id} not found")))?;
+
+    // Soft-deleted resumes still count: deleting one must not hand the quota back.
+    let used: i64 = sqlx::query_scalar!(
+        r#"SELECT
+               (SELECT COUNT(*) FROM resumes
+                 WHERE user_id = $1 AND created_at > NOW() - INTERVAL '24 hours')
+             + (SELECT COUNT(*) FROM generation_jobs
+                 WHERE user_id = $1 AND status IN ('queued', 'running')) AS "used!""#,
+        user_id
+    )
+    .fetch_one(&mut *conn)
+    .await?;
+
+    evaluate_quota(&tier, used, requested)
+}
+
+// ────────────────────────────────────────────────────────────────────────────
+// Persistence
+// ────────────────────────────────────────────────────────────────────────────
+
+/// Checks the daily quota, then inserts the batch row and one queued job per JD, all
+/// in one transaction. Returns `(batch_id, job_ids, quota)`.
+pub async fn create_generation_batch(
+    pool: &PgPool,
+    user_id: Uuid,
+    jd_texts: &[String],
+) -> Result<(Uuid, Vec<Uuid>, QuotaStatus), AppError> {
+    let mut tx = pool.begin().await?;
+
+    let quota = check_generation_quota(&mut tx, user_id, jd_texts.len()).await?;
+
+    let batch_id: Uuid = sqlx::query_scalar!(
+        "INSERT INTO generation_batches (user_id, total) VALUES ($1, $2) RETURNING id",
+        user_id,
+        jd_texts.len() as i32
+    )
+    .fetch_one(&mut *tx)
+    .await?;
+
+    let mut job_ids = Vec::with_capacity(jd_texts.len());
+    for (idx, text) in jd_texts.iter().enumerate() {
+        let job_id: Uuid = sqlx::query_scalar!(
+            r#"INSERT INTO generation_jobs (batch_id, user_id, jd_index, jd_text)
+               VALUES ($1, $2, $3, $4)
+               RETURNING id"#,
+            batch_id,
+            user_id,
+            idx as i32,
+            text
+        )
+        .fetch_one(&mut *tx)
+        .await?;
+        job_ids.push(job_id);
+    }
+
+    tx.commit().await?;
+    Ok((batch_id, job_ids, quota))
+}
+
+async fn set_job_status(
+    pool: &PgPool,
+    job_id: Uuid,
+    status: &str,
+    resume_id: Option<Uuid>,
+    error_msg: Option<&str>,
+) -> Result<(), sqlx::Error> {
+    sqlx::query!(
+        r#"UPDATE generation_jobs
+           SET status = $2, resume_id = $3, error_msg = $4, updated_at = NOW()
+           WHERE id = $1"#,
+        job_id,
+        status,
+        resume_id,
+        error_msg
+    )
+    .execute(pool)
+    .await?;
+    Ok(())
+}
+
+/// Returns batch progress plus every job row, or `None` if the batch does not exist.
+pub async fn get_generation_batch_status(
+    pool: &PgPool,
+    batch_id: Uuid,
+) -> Result<Option<BatchGenerationStatus>, AppError> {
+    let exists: Option<Uuid> =
+        sqlx::query_scalar!("SELECT id FROM generation_batches WHERE id = $1", batch_id)
+            .fetch_optional(pool)
+            .await?;
+    if exists.is_none() {
+        return Ok(None);
+    }
+
+    let jobs = sqlx::query_as!(
+        GenerationJobRow,
+        "SELECT * FROM generation_jobs WHERE batch_id = $1 ORDER BY jd_index",
+        batch_id
+    )
+    .fetch_all(pool)
+    .await?;
+
+    Ok(Some(summarize_batch(batch_id, jobs)))
+}
+
+fn summarize_batch(batch_id: Uuid, jobs: Vec<GenerationJobRow>) -> BatchGenerationStatus {
+    let count = |s: &str| jobs.iter().filter(|j| j.status == s).count();
+    let queued = count("queued");
+    let running = count("running");
+    let status = if queued + running == 0 {
+        "done"
+    } else {
+        "processing"
+    };
+    BatchGenerationStatus {
+        batch_id,
+        total: jobs.len(),
+        queued,
+        running,
+        succeeded: count("succeeded"),
+        failed: count("failed"),
+        status: status.to_string(),
+        jobs,
+    }
+}
+
+// ────────────────────────────────────────────────────────────────────────────
+// Background runner
+// ────────────────────────────────────────────────────────────────────────────
+
+/// Spawns the detached task that works through a batch's jobs.
+///
+/// Failures are recorded per job and never abort the rest of the batch. Once every
+/// job has finished, the user is emailed a summary (see `crate::notifications`).
+pub fn spawn_batch_generation(
+    state: AppState,
+    user_id: Uuid,
+    paper_size: Option<PaperSize>,
+    margin_preset: Option<MarginPreset>,
+    jobs: Vec<(Uuid, String)>,
+    reservation: Reservation,
+) {
+    tokio::spawn(crate::reporting::inherit(async move {
+        run_batch(
+            &state,
+            user_id,
+            paper_size,
+            margin_preset,
+            jobs,
+            reservation,
+        )
+        .await;
+    }));
+}
+
+async fn run_batch(
+    state: &AppState,
+    user_id: Uuid,
+    paper_size: Option<PaperSize>,
+    margin_preset: Option<MarginPreset>,
+    jobs: Vec<(Uuid, String)>,
+    reservation: Reservation,
+) {
+    // Shared context load — every JD in the batch sees the same snapshot.
+    let entries = match get_current_entries(&state.db, user_id).await {
+        Ok(entries) => entries,
+        Err(e) => {
+            error!(%user_id, error = %e, "Batch generation: context load failed");
+            let msg = format!("Failed to load context: {e}");
+            for (job_id, _) in &jobs {
+                if let Err(db_err) =
+                    set_job_status(&state.db, *job_id, "failed", None, Some(&msg)).await
+                {
+                    error!(%job_id, db_error = %db_err, "Batch generation: failed to mark job failed");
+                }
+            }
+            quota::release(&state.redis, &reservation).await;
+            let outcomes = vec![Err(msg); jobs.len()];
+            state
+                .notifier
+                .batch_finished(&state.db, user_id, &outcomes)
+                .await;
+            return;
+        }
+    };
+
+    let mut outcomes = Vec::with_capacity(jobs.len());
+    for (job_id, jd_text) in jobs {
+        if let Err(e) = set_job_status(&state.db, job_id, "running", None, None).await {
+            error!(%job_id, db_error = %e, "Batch generation: failed to mark job running");
+        }
+
+        let request = GenerateRequest {
+            user_id,
+            jd_text,
+            target_role_id: None,
+            persona_id: None,
+            tone_override: None,
+            parent_resume_id: None,
+            paper_size,
+            margin_preset,
+            progress_id: None,
+            rewrite_stuffed_bullets: false,
+            entry_ids: None,
+        };
+        let (result, tokens) = llm_client::metered(progress::track(
+            &state.redis,
+            Some(job_id),
+            generate_resume_with_entries(
+                &state.db,
+                &state.llm,
+                state.fit_scorer.as_ref(),
+                &state.page_config,
+                state.config.layout.adjust_concurrency,
+                Some(&state.redis),
+                true,
+                request,
+                entries.clone(),
+            ),
+        ))
+        .await;
+        quota::record_tokens(&state.db, &state.redis, user_id, tokens).await;
+
+        let update = match result {
+            Ok(response) => {
+                info!(%job_id, resume_id = %response.resume_id, "Batch generation: job succeeded");
+                outcomes.push(Ok(response.resume_id));
+                set_job_status(
+                    &state.db,
+                    job_id,
+                    "succeeded",
+                    Some(response.resume_id),
+                    None,
+                )
+                .await
+            }
+            Err(e) => {
+                error!(%job_id, error = %e, "Batch generation: job failed");
+                quota::release(&state.redis, &reservation.unit()).await;
+                outcomes.push(Err(e.to_string()));
+                set_job_status(&state.db, job_id, "failed", None, Some(&e.to_string())).await
+            }
+        };
+        if let Err(e) = update {
+            error!(%job_id, db_error = %e, "Batch generation: failed to record job result");
+        }
+    }
+    state
+        .notifier
+        .batch_finished(&state.db, user_id, &outcomes)
+        .await;
+}
+
+// ────────────────────────────────────────────────────────────────────────────
+// Tests
+// ────────────────────────────────────────────────────────────────────────────
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Utc;
+
+    fn make_job(status: &str, jd_index: i32) -> GenerationJobRow {
+        GenerationJobRow {
+            id: Uuid::new_v4(),
+            batch_id: Uuid::nil(),
+            user_id: Uuid::nil(),
+            jd_index,
+            jd_text: "jd".to_string(),
+            status: status.to_string(),
+            resume_id: None,
+            error_msg: None,
+            created_at: Utc::now(),
+            updated_at: Utc::now(),
+        }
+    }
+
+    #[test]
+    fn test_dedup_ignores_case_and_whitespace() {
+        let jds = vec![
+            "Senior Rust Engineer\n\nBuild things".to_string(),
+            "senior rust engineer build   things".to_string(),
+            "Staff Go Engineer".to_string(),
+        ];
+        let (unique, dropped) = dedup_jd_texts(jds).unwrap();
+        assert_eq!(unique.len(), 2);
+        assert_eq!(dropped, 1);
+        assert!(
+            unique[0].starts_with("Senior Rust"),
+            "first occurrence is kept"
+        );
+    }
+
+    fn batch(jd_texts: &[&str]) -> BatchGenerateRequest {
+        BatchGenerateRequest {
+            jd_texts: jd_texts.iter().map(|t| t.to_string()).collect(),
+            paper_size: None,
+            margin_preset: None,
+        }
+    }
+
+    #[test]
+    fn test_batch_rejects_blank_jd() {
+        let errors = batch(&["ok", "   "]).validate().unwrap_err();
+        let fields = crate::validation::field_errors(&errors);
+        assert_eq!(fields[0].field, "jd_texts");
+        assert!(fields[0].message.contains("jd_texts[1]"));
+    }
+
+    #[test]
+    fn test_batch_rejects_empty_batch() {
+        assert!(batch(&[]).validate().is_err());
+        assert!(batch(&["Senior Rust Engineer"]).validate().is_ok());
+    }
+
+    #[test]
+    fn test_cap_applies_after_dedup() {
+        // 12 submitted, but only MAX_BATCH_JDS unique — accepted
+        let mut jds: Vec<String> = (0..MAX_BATCH_JDS).map(|i| format!("JD {i}")).collect();
+        jds.push("JD 0".to_string());
+        jds.push("jd 1".to_string());
+        let (unique, dropped) = dedup_jd_texts(jds).unwrap();
+        assert_eq!(unique.len(), MAX_BATCH_JDS);
+        assert_eq!(dropped, 2);
+
+        let too_many: Vec<String> = (0..=MAX_BATCH_JDS).map(|i| format!("JD {i}")).collect();
+        assert!(dedup_jd_texts(too_many).is_err());
+    }
+
+    #[test]
+    fn test_quota_allows_batch_within_limit() {
+        let q = evaluate_quota("free", 15, 5).unwrap();
+        assert_eq!(q.daily_limit, 20);
+        assert_eq!(q.used, 20);
+        assert_eq!(q.remaining, 0);
+    }
+
+    #[test]
+    fn test_quota_rejects_batch_over_limit() {
+        let err = evaluate_quota("free", 18, 3).unwrap_err();
+        assert!(
+            matches!(err, AppError::QuotaExceeded { ref message, .. } if message.contains("2 of 20"))
+        );
+    }
+
+    #[test]
+    fn test_unknown_tier_gets_free_limit() {
+        assert_eq!(daily_generation_limit("enterprise-trial"), 20);
+        assert!(daily_generation_limit("pro") > daily_generation_limit("free"));
+    }
+
+    #[test]
+    fn test_summarize_batch_counts_and_done_state() {
+        let batch_id = Uuid::new_v4();
+        let s = summarize_batch(
+            batch_id,
+            vec![
+                make_job("succeeded", 0),
+                make_job("running", 1),
+                make_job("queued", 2),
+            ],
+        );
+        assert_eq!((s.total, s.queued, s.running, s.succeeded), (3, 1, 1, 1));
+        assert_eq!(s.status, "processing");
+
+        let s = summarize_batch(
+            batch_id,
+            vec![make_job("succeeded", 0), make_job("failed", 1)],
+        );
+        assert_eq!(s.status, "done");
+        assert_eq!(s.failed, 1);
+    }
+}
@@ -0,0 +1,318 @@
+#![allow(dead_code)]
+
+//! Near-duplicate bullet dedup — runs on LLM draft bullets before layout simulation.
+//!
+//! Two context entries can describe the same work (an `experience` entry and the
+//! `open_source` entry for the project shipped there), and the LLM will happily write
+//! a bullet for each. This pass compares every pair of draft bullets by Jaccard
+//! similarity over content tokens and collapses near-duplicates into one.
+//!
+//! Merge rule: the bullet whose source entry ranked higher in content selection wins;
+//! the loser's `jd_keywords_used` are folded into the winner. Every decision is logged.
+
+use std::collections::{HashMap, HashSet};
+
+use serde::Serialize;
+use tracing::info;
+use uuid::Uuid;
+
+use crate::generation::content_selector::SelectionResult;
+use crate::generation::generator::DraftBullet;
+
+/// Jaccard similarity at or above which two bullets are treated as duplicates.
+pub const NEAR_DUPLICATE_THRESHOLD: f32 = 0.6;
+
+/// Tokens ignored when comparing bullets — they carry no content signal.
+const STOPWORDS: &[&str] = &[
+    "a", "an", "and", "as", "at", "by", "for", "from", "in", "into", "of", "on", "or", "the", "to",
+    "via", "with", "across", "over", "using",
+];
+
+// ────────────────────────────────────────────────────────────────────────────
+// Data models
+// ────────────────────────────────────────────────────────────────────────────
+
+/// Record of one merge — which bullet survived and which was dropped.
+#[derive(Debug, Clone, Serialize)]
+pub struct DedupDecision {
+    pub kept_text: String,
+    pub kept_entry_id: Uuid,
+    pub dropped_text: String,
+    pub dropped_entry_id: Uuid,
+    pub similarity: f32,
+}
+
+// ────────────────────────────────────────────────────────────────────────────
+// Dedup pass
+// ────────────────────────────────────────────────────────────────────────────
+
+/// Removes near-duplicate draft bullets, preserving the original order of survivors.
+///
+/// Returns `(surviving_bullets, decisions)`. O(n²) in bullet count, which is fine —
+/// a resume never has more than a few dozen bullets.
+pub fn dedup_draft_bullets(
+    bullets: Vec<DraftBullet>,
+    selection: &SelectionResult,
+) -> (Vec<DraftBullet>, Vec<DedupDecision>) {
+    let entry_scores: HashMap<Uuid, f64> = selection
+        .selected_entries
+        .iter()
+        .map(|re| (re.entry.entry_id, re.combined_score))
+        .collect();
+    let score_of = |id: &Uuid| entry_scores.get(id).copied().unwrap_or(0.0);
+
+    let mut kept: Vec<(DraftBullet, HashSet<String>)> = Vec::with_capacity(bullets.len());
+    let mut decisions = Vec::new();
+
+    for bullet in bullets {
+        let tokens = content_tokens(&bullet.text);
+        kept.push((bullet, tokens));
+
+        // A merge can leave a different bullet in the surviving slot, one that may
+        // duplicate kept bullets the old one didn't — so recheck until it's clean.
+        let mut idx = kept.len() - 1;
+        while let Some((other, similarity)) = closest_duplicate(&kept, idx) {
+            let (first, second) = (idx.min(other), idx.max(other));
+            let (loser, loser_tokens) = kept.remove(second);
+            let (existing, existing_tokens) = &mut kept[first];
+
+            let (winner, loser) =
+                if score_of(&loser.source_entry_id) > score_of(&existing.source_entry_id) {
+                    let loser = std::mem::replace(existing, loser);
+                    *existing_tokens = loser_tokens;
+                    (existing.clone(), loser)
+                } else {
+                    (existing.clone(), loser)
+                };
+
+            info!(
+                kept_entry = %winner.source_entry_id,
+                dropped_entry = %loser.source_entry_id,
+                similarity,
+                dropped = %loser.text.chars().take(60).collect::<String>(),
+                "Dropping near-duplicate bullet"
+            );
+
+            for kw in &loser.jd_keywords_used {
+                if !existing
+                    .jd_keywords_used
+                    .iter()
+                    .any(|k| k.eq_ignore_ascii_case(kw))
+                {
+                    existing.jd_keywords_used.push(kw.clone());
+                }
+            }
+
+            decisions.push(DedupDecision {
+                kept_text: winner.text,
+                kept_entry_id: winner.source_entry_id,
+                dropped_text: loser.text,
+                dropped_entry_id: loser.source_entry_id,
+                similarity,
+            });
+            idx = first;
+        }
+    }
+
+    (kept.into_iter().map(|(b, _)| b).collect(), decisions)
+}
+
+/// The kept bullet most similar to `kept[idx]`, if any is a near-duplicate of it.
+fn closest_duplicate(kept: &[(DraftBullet, HashSet<String>)], idx: usize) -> Option<(usize, f32)> {
+    let tokens = &kept[idx].1;
+    kept.iter()
+        .enumerate()
+        .filter(|(i, _)| *i != idx)
+        .map(|(i, (_, other))| (i, jaccard(tokens, other)))
+        .filter(|(_, sim)| *sim >= NEAR_DUPLICATE_THRESHOLD)
+        .max_by(|a, b| a.1.partial_cmp(&b.1).unwrap_or(std::cmp::Ordering::Equal))
+}
+
+/// Jaccard similarity between two bullet texts over content tokens (0.0 – 1.0).
+pub fn bullet_similarity(a: &str, b: &str) -> f32 {
+    jaccard(&content_tokens(a), &content_tokens(b))
+}
+
+fn jaccard(a: &HashSet<String>, b: &HashSet<String>) -> f32 {
+    if a.is_empty() && b.is_empty() {
+        return 0.0;
+    }
+    let intersection = a.intersection(b).count();
+    let union = a.union(b).count();
+    intersection as f32 / union as f32
+}
+
+/// Lowercased alphanumeric tokens with stopwords removed. Punctuation is dropped,
+/// so "40%" and "40" compare equal.
+fn content_tokens(text: &str) -> HashSet<String> {
+    text.split(|c: char| !c.is_alphanumeric())
+        .filter(|t| !t.is_empty())
+        .map(|t| t.to_lowercase())
+        .filter(|t| !STOPWORDS.contains(&t.as_str()))
+        .collect()
+}
+
+// ────────────────────────────────────────────────────────────────────────────
+// Tests
+// ────────────────────────────────────────────────────────────────────────────
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::generation::content_selector::RankedEntry;
+    use crate::models::context::ContextEntryRow;
+    use chrono::Utc;
+    use serde_json::json;
+
+    fn make_bullet(text: &str, entry_id: Uuid, keywords: Vec<&str>) -> DraftBullet {
+        DraftBullet {
+            text: text.to_string(),
+            source_entry_id: entry_id,
+            section: "experience".to_string(),
+            line_estimate: 1,
+            jd_keywords_used: keywords.into_iter().map(String::from).collect(),
+        }
+    }
+
+    fn make_selection(scores: Vec<(Uuid, f64)>) -> SelectionResult {
+        SelectionResult {
+            selected_entries: scores
+                .into_iter()
+                .map(|(entry_id, combined_score)| RankedEntry {
+                    entry: ContextEntryRow {
+                        id: Uuid::new_v4(),
+                        user_id: Uuid::new_v4(),
+                        entry_id,
+                        version: 1,
+                        entry_type: "experience".to_string(),
+                        data: json!({}),
+                        raw_text: None,
+                        recency_score: 1.0,
+                        impact_score: 0.5,
+                        tags: vec![],
+                        flagged_evergreen: false,
+                        contribution_type: "primary_contributor".to_string(),
+                        quality_score: 1.0,
+                        quality_flags: vec![],
+                        created_at: Utc::now(),
+                        recency_policy: None,
+                    },
+                    combined_score,
+                    jd_relevance: 0.5,
+                })
+                .collect(),
+            excluded_entries: vec![],
+            section_weights: HashMap::new(),
+            reframe_hints: vec![],
+        }
+    }
+
+    #[test]
+    fn test_similarity_identical_is_one() {
+        let s = bullet_similarity("Built a Rust cache", "built a rust cache");
+        assert!((s - 1.0).abs() < 1e-5);
+    }
+
+    #[test]
+    fn test_similarity_unrelated_is_low() {
+        let s = bullet_similarity(
+            "Built a Rust caching layer for the payments API",
+            "Mentored four junior engineers through onboarding",
+        );
+        assert!(s < 0.1, "similarity={s}");
+    }
+
+    #[test]
+    fn test_near_duplicates_collapse_to_higher_ranked_entry() {
+        let exp = Uuid::new_v4();
+        let oss = Uuid::new_v4();
+        let selection = make_selection(vec![(exp, 0.9), (oss, 0.4)]);
+
+        let bullets = vec![
+            make_bullet(
+                "Built open-source Rust tracing library adopted by 40 teams",
+                oss,
+                vec!["tracing"],
+            ),
+            make_bullet(
+                "Built the open-source Rust tracing library, adopted by 40 teams",
+                exp,
+                vec!["Rust"],
+            ),
+        ];
+
+        let (kept, decisions) = dedup_draft_bullets(bullets, &selection);
+        assert_eq!(kept.len(), 1, "near-duplicates must collapse");
+        assert_eq!(
+            kept[0].source_entry_id, exp,
+            "higher combined_score entry wins"
+        );
+        assert!(kept[0].jd_keywords_used.contains(&"tracing".to_string()));
+        assert!(kept[0].jd_keywords_used.contains(&"Rust".to_string()));
+        assert_eq!(decisions.len(), 1);
+        assert_eq!(decisions[0].dropped_entry_id, oss);
+    }
+
+    #[test]
+    fn test_replacing_winner_is_rechecked_against_all_kept_bullets() {
+        let low = Uuid::new_v4();
+        let mid = Uuid::new_v4();
+        let high = Uuid::new_v4();
+        let selection = make_selection(vec![(low, 0.2), (mid, 0.5), (high, 0.9)]);
+
+        // The first two are distinct from each other; the third duplicates both.
+        let bullets = vec![
+            make_bullet(
+                "Built Rust tracing library for payments, adopted by 40 teams",
+                low,
+                vec!["payments"],
+            ),
+            make_bullet(
+                "Maintained open-source Rust tracing library for 40 teams",
+                mid,
+                vec!["tracing"],
+            ),
+            make_bullet(
+                "Built open-source Rust tracing library adopted by 40 teams",
+                high,
+                vec!["Rust"],
+            ),
+        ];
+
+        let (kept, decisions) = dedup_draft_bullets(bullets, &selection);
+        assert_eq!(kept.len(), 1, "the new winner must absorb every duplicate");
+        assert_eq!(kept[0].source_entry_id, high);
+        for kw in ["payments", "tracing", "Rust"] {
+            assert!(kept[0].jd_keywords_used.contains(&kw.to_string()));
+        }
+        assert_eq!(decisions.len(), 2);
+    }
+
+    #[test]
+    fn test_distinct_bullets_are_untouched() {
+        let a = Uuid::new_v4();
+        let selection = make_selection(vec![(a, 0.5)]);
+        let bullets = vec![
+            make_bullet("Reduced p99 latency by 40% with a Redis cache", a, vec![]),
+            make_bullet("Led migration of 12 services to Kubernetes", a, vec![]),
+        ];
+        let (kept, decisions) = dedup_draft_bullets(bullets, &selection);
+        assert_eq!(kept.len(), 2);
+        assert!(decisions.is_empty());
+        assert!(kept[0].text.starts_with("Reduced"), "order preserved");
+    }
+
+    #[test]
+    fn test_tie_keeps_first_bullet() {
+        let a = Uuid::new_v4();
+        let b = Uuid::new_v4();
+        let selection = make_selection(vec![(a, 0.5), (b, 0.5)]);
+        let bullets = vec![
+            make_bullet("Shipped Rust billing service to production", a, vec![]),
+            make_bullet("Shipped the Rust billing service to production", b, vec![]),
+        ];
+        let (kept, _) = dedup_draft_bullets(bullets, &selection);
+        assert_eq!(kept.len(), 1);
+        assert_eq!(kept[0].source_entry_id, a);
+    }
+}
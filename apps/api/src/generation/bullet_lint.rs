@@ -0,0 +1,394 @@
+//! Bullet lint — deterministic grammar and style checks on the final bullets.
+//!
+//! No LLM: each rule is a word-level pattern, so the same bullet always gets the same
+//! warnings and linting costs nothing. Warnings are advisory; nothing is rewritten.
+//!
+//! - passive voice: a form of "be" followed by a past participle ("was migrated")
+//! - first person: "I", "my", "we", "our", …
+//! - trailing periods: bullets that end differently from most of the resume
+//! - double spaces
+//! - tense: a role that has ended, opened with a present-tense verb ("Leads", "Managing")
+//!
+//! Education lines and the summary are not drafted bullets (see `static_sections`) and
+//! are not linted.
+
+use std::collections::HashMap;
+
+use chrono::NaiveDate;
+use uuid::Uuid;
+
+use crate::generation::static_sections::{is_static_section, parse_date};
+use crate::layout::SimulatedBullet;
+use crate::models::context::ContextEntryRow;
+
+pub use templar_types::generation::{LintRule, LintWarning};
+
+const BE_FORMS: &[&str] = &["is", "are", "was", "were", "be", "been", "being"];
+
+/// Past participles that do not end in "-ed"; prefixed forms ("rebuilt") match too.
+const IRREGULAR_PARTICIPLES: &[&str] = &[
+    "built", "brought", "chosen", "done", "driven", "given", "grown", "held", "kept", "known",
+    "led", "made", "paid", "run", "seen", "sent", "shown", "sold", "spent", "taken", "taught",
+    "thrown", "won", "written",
+];
+
+const FIRST_PERSON: &[&str] = &[
+    "i",
+    "me",
+    "my",
+    "mine",
+    "myself",
+    "we",
+    "us",
+    "our",
+    "ours",
+    "ourselves",
+];
+
+/// Resume verbs whose base, "-s" and "-es" forms read as present tense.
+const PRESENT_VERBS: &[&str] = &[
+    "analyze",
+    "architect",
+    "automate",
+    "build",
+    "collaborate",
+    "coordinate",
+    "create",
+    "define",
+    "deliver",
+    "deploy",
+    "design",
+    "develop",
+    "drive",
+    "establish",
+    "execute",
+    "grow",
+    "handle",
+    "help",
+    "implement",
+    "improve",
+    "increase",
+    "launch",
+    "lead",
+    "maintain",
+    "manage",
+    "mentor",
+    "monitor",
+    "operate",
+    "optimize",
+    "oversee",
+    "own",
+    "partner",
+    "plan",
+    "reduce",
+    "research",
+    "review",
+    "run",
+    "serve",
+    "ship",
+    "support",
+    "teach",
+    "test",
+    "train",
+    "work",
+    "write",
+];
+
+// ────────────────────────────────────────────────────────────────────────────
+// Lint
+// ────────────────────────────────────────────────────────────────────────────
+
+/// Warnings for each bullet, in the same order as `bullets`. `entries` supplies the
+/// end dates for the tense check; an entry that ends after `today` is still current.
+pub fn lint_bullets(
+    bullets: &[SimulatedBullet],
+    entries: &[ContextEntryRow],
+    today: NaiveDate,
+) -> Vec<Vec<LintWarning>> {
+    let ended: HashMap<Uuid, NaiveDate> = entries
+        .iter()
+        .filter_map(|e| Some((e.entry_id, parse_date(e.data.get("date_end"))?)))
+        .filter(|(_, end)| *end < today)
+        .collect();
+
+    let linted = |b: &SimulatedBullet| !is_static_section(&b.section);
+    let with_period = bullets
+        .iter()
+        .filter(|b| linted(b) && ends_with_period(&b.text))
+        .count();
+    let without_period = bullets.iter().filter(|b| linted(b)).count() - with_period;
+    // Ties go to no period, the more common resume style.
+    let expect_period = with_period > without_period;
+
+    bullets
+        .iter()
+        .map(|b| {
+            if !linted(b) {
+                return vec![];
+            }
+            let mut warnings = lint_text(&b.text);
+            if with_period > 0 && without_period > 0 && ends_with_period(&b.text) != expect_period {
+                warnings.push(trailing_period(&b.text, expect_period));
+            }
+            if let Some(end) = ended.get(&b.source_entry_id) {
+                warnings.extend(tense_mismatch(&b.text, *end));
+            }
+            warnings
+        })
+        .collect()
+}
+
+/// The rules that need only the bullet's own text.
+pub fn lint_text(text: &str) -> Vec<LintWarning> {
+    let mut warnings = vec![];
+    warnings.extend(passive_voice(text));
+    warnings.extend(first_person(text));
+    warnings.extend(double_space(text));
+    warnings
+}
+
+fn passive_voice(text: &str) -> Option<LintWarning> {
+    let words: Vec<&str> = text.split_whitespace().collect();
+    words.iter().enumerate().find_map(|(i, word)| {
+        if !BE_FORMS.contains(&bare(word).to_lowercase().as_str()) {
+            return None;
+        }
+        // One adverb may sit in between: "was quickly adopted".
+        let mut j = i + 1;
+        if words.get(j).is_some_and(|w| bare(w).ends_with("ly")) {
+            j += 1;
+        }
+        let participle = bare(words.get(j)?).to_lowercase();
+        let is_participle = (participle.len() > 3 && participle.ends_with("ed"))
+            || IRREGULAR_PARTICIPLES
+                .iter()
+                .any(|p| participle.ends_with(p));
+        is_participle.then(|| LintWarning {
+            rule: LintRule::PassiveVoice,
+            excerpt: words[i..=j].join(" "),
+            message: "Passive voice; lead with what you did".to_string(),
+        })
+    })
+}
+
+fn first_person(text: &str) -> Option<LintWarning> {
+    text.split_whitespace().find_map(|word| {
+        // "I'm", "we've"
+        let word = bare(word).split('\'').next().unwrap_or_default();
+        let lower = word.to_lowercase();
+        // "US" is the country; a lowercase "i" is more likely a typo than a pronoun.
+        let pronoun = match lower.as_str() {
+            "i" => word == "I",
+            "us" => word == "us",
+            _ => FIRST_PERSON.contains(&lower.as_str()),
+        };
+        pronoun.then(|| LintWarning {
+            rule: LintRule::FirstPerson,
+            excerpt: word.to_string(),
+            message: "First-person pronoun; resume bullets leave the subject implied".to_string(),
+        })
+    })
+}
+
+fn double_space(text: &str) -> Option<LintWarning> {
+    let at = text.trim().find("  ")? + (text.len() - text.trim_start().len());
+    let start = text[..at].rfind(' ').map_or(0, |i| i + 1);
+    let after = text[at..].trim_start();
+    let end = text.len() - after.len() + after.find(' ').unwrap_or(after.len());
+    Some(LintWarning {
+        rule: LintRule::DoubleSpace,
+        excerpt: text[start..end].to_string(),
+        message: "Double space".to_string(),
+    })
+}
+
+fn ends_with_period(text: &str) -> bool {
+    text.trim_end().ends_with('.')
+}
+
+fn trailing_period(text: &str, expect_period: bool) -> LintWarning {
+    let last = text.split_whitespace().next_back().unwrap_or_default();
+    LintWarning {
+        rule: LintRule::TrailingPeriod,
+        excerpt: last.to_string(),
+        message: if expect_period {
+            "Most bullets end with a period; this one does not".to_string()
+        } else {
+            "Most bullets have no trailing period; this one does".to_string()
+        },
+    }
+}
+
+fn tense_mismatch(text: &str, ended: NaiveDate) -> Option<LintWarning> {
+    let first = text.split_whitespace().next()?;
+    let verb = bare(first).to_lowercase();
+    let present = PRESENT_VERBS.iter().any(|base| {
+        verb == *base
+            || verb.strip_suffix('s') == Some(*base)
+            || verb.strip_suffix("es") == Some(*base)
+    }) || (verb.len() > 5 && verb.ends_with("ing"));
+    present.then(|| LintWarning {
+        rule: LintRule::TenseMismatch,
+        excerpt: bare(first).to_string(),
+        message: format!(
+            "Present tense for a role that ended {}; use the past tense",
+            ended.format("%b %Y")
+        ),
+    })
+}
+
+/// `word` without surrounding punctuation: "(was" → "was", "built," → "built".
+fn bare(word: &str) -> &str {
+    word.trim_matches(|c: char| !c.is_alphanumeric() && c != '\'')
+}
+
+// ────────────────────────────────────────────────────────────────────────────
+// Tests
+// ────────────────────────────────────────────────────────────────────────────
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::generation::static_sections::EDUCATION_SECTION;
+    use crate::layout::LineType;
+    use chrono::Utc;
+    use serde_json::json;
+
+    fn make_bullet(text: &str, entry_id: Uuid) -> SimulatedBullet {
+        SimulatedBullet {
+            text: text.to_string(),
+            source_entry_id: entry_id,
+            section: "experience".to_string(),
+            line_type: LineType::Bullet,
+            verified_line_count: 1,
+            jd_keywords_used: vec![],
+            was_adjusted: false,
+            flagged_for_review: false,
+            revisions: Vec::new(),
+        }
+    }
+
+    fn make_entry(entry_id: Uuid, date_end: Option<&str>) -> ContextEntryRow {
+        ContextEntryRow {
+            id: Uuid::new_v4(),
+            user_id: Uuid::new_v4(),
+            entry_id,
+            version: 1,
+            entry_type: "experience".to_string(),
+            data: json!({ "title": "Engineer", "date_end": date_end }),
+            raw_text: None,
+            recency_score: 1.0,
+            impact_score: 0.5,
+            tags: vec![],
+            flagged_evergreen: false,
+            contribution_type: "team_member".to_string(),
+            quality_score: 1.0,
+            quality_flags: vec![],
+            created_at: Utc::now(),
+            recency_policy: None,
+        }
+    }
+
+    fn rules(warnings: &[LintWarning]) -> Vec<LintRule> {
+        warnings.iter().map(|w| w.rule).collect()
+    }
+
+    fn today() -> NaiveDate {
+        NaiveDate::from_ymd_opt(2024, 6, 1).unwrap()
+    }
+
+    #[test]
+    fn test_passive_voice_is_detected() {
+        let warnings = lint_text("The billing service was quickly rebuilt in Rust");
+        assert_eq!(rules(&warnings), vec![LintRule::PassiveVoice]);
+        assert_eq!(warnings[0].excerpt, "was quickly rebuilt");
+
+        let warnings = lint_text("Dashboards were adopted by 12 teams");
+        assert_eq!(warnings[0].excerpt, "were adopted");
+
+        assert!(lint_text("Rebuilt the billing service in Rust").is_empty());
+        assert!(lint_text("Kept the API backward compatible while it was under load").is_empty());
+    }
+
+    #[test]
+    fn test_first_person_pronouns_are_detected() {
+        for (text, excerpt) in [
+            ("I migrated 40 services to Kubernetes", "I"),
+            ("Grew our NPS by 12 points", "our"),
+            ("Helped us hit 99.99% uptime", "us"),
+            ("We're now shipping weekly", "We"),
+        ] {
+            let warnings = lint_text(text);
+            assert_eq!(rules(&warnings), vec![LintRule::FirstPerson], "{text}");
+            assert_eq!(warnings[0].excerpt, excerpt, "{text}");
+        }
+        assert!(lint_text("Expanded sales across the US and EU").is_empty());
+    }
+
+    #[test]
+    fn test_double_space_is_detected() {
+        let warnings = lint_text("Cut p99 latency  by 40%");
+        assert_eq!(rules(&warnings), vec![LintRule::DoubleSpace]);
+        assert_eq!(warnings[0].excerpt, "latency  by");
+    }
+
+    #[test]
+    fn test_trailing_period_minority_is_flagged() {
+        let id = Uuid::new_v4();
+        let bullets = vec![
+            make_bullet("Cut p99 latency by 40%", id),
+            make_bullet("Shipped the billing rewrite", id),
+            make_bullet("Mentored four engineers.", id),
+        ];
+        let lint = lint_bullets(&bullets, &[], today());
+
+        assert!(lint[0].is_empty());
+        assert!(lint[1].is_empty());
+        assert_eq!(rules(&lint[2]), vec![LintRule::TrailingPeriod]);
+        assert_eq!(lint[2][0].excerpt, "engineers.");
+    }
+
+    #[test]
+    fn test_present_tense_in_past_role_is_flagged() {
+        let past = Uuid::new_v4();
+        let current = Uuid::new_v4();
+        let future = Uuid::new_v4();
+        let entries = vec![
+            make_entry(past, Some("2022-03-31")),
+            make_entry(current, None),
+            make_entry(future, Some("2025-01")),
+        ];
+        let bullets = vec![
+            make_bullet("Leads a team of 5 platform engineers", past),
+            make_bullet("Managing the on-call rotation", past),
+            make_bullet("Led a team of 5 platform engineers", past),
+            make_bullet("Leads a team of 5 platform engineers", current),
+            make_bullet("Oversees vendor integrations", future),
+        ];
+        let lint = lint_bullets(&bullets, &entries, today());
+
+        assert_eq!(rules(&lint[0]), vec![LintRule::TenseMismatch]);
+        assert_eq!(lint[0][0].excerpt, "Leads");
+        assert!(lint[0][0].message.contains("Mar 2022"));
+        assert_eq!(rules(&lint[1]), vec![LintRule::TenseMismatch]);
+        assert!(lint[2].is_empty());
+        assert!(lint[3].is_empty());
+        assert!(lint[4].is_empty());
+    }
+
+    #[test]
+    fn test_education_lines_are_not_linted() {
+        let mut line = make_bullet(
+            "B.S. in Computer Science, State College (2014).",
+            Uuid::new_v4(),
+        );
+        line.section = EDUCATION_SECTION.to_string();
+        let bullets = vec![
+            make_bullet("Shipped the billing rewrite", Uuid::new_v4()),
+            line,
+        ];
+
+        let lint = lint_bullets(&bullets, &[], today());
+        assert!(lint.iter().all(Vec::is_empty));
+    }
+}
@@ -31,11 +31,26 @@ pub struct ReframeHint {
     pub suggested_framing: String,
 }
 
+/// Why selection cut an entry, for the UI to label it by.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ExclusionKind {
+    /// Its section was full of higher-ranked entries.
+    SectionLimit,
+    /// Cut by a section limit while matching no JD keyword.
+    LowJdRelevance,
+    /// The resume was generated from hand-picked entries, and this was not one.
+    NotPicked,
+    /// Explanations recorded before kinds were, whose reason matches none of the above.
+    #[default]
+    Other,
+}
+
 /// Result of content selection — feeds directly into the generation prompt.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct SelectionResult {
     pub selected_entries: Vec<RankedEntry>,
-    pub excluded_entries: Vec<(Uuid, String)>, // (entry_id, reason)
+    pub excluded_entries: Vec<(Uuid, ExclusionKind, String)>, // (entry_id, kind, reason)
     pub section_weights: HashMap<String, f32>,
     pub reframe_hints: Vec<ReframeHint>, // populated by generator if LLM available
 }
@@ -44,6 +59,11 @@ pub struct SelectionResult {
 // Selection algorithm
 // ────────────────────────────────────────────────────────────────────────────
 
+/// Exclusion reason of entries a hand-picked generation (`select_pinned`) left out.
+pub const NOT_PICKED_REASON: &str = "Not among the hand-picked entries";
+/// Start of the exclusion reason of entries cut by a section limit.
+pub const SECTION_LIMIT_REASON: &str = "Section limit reached";
+
 /// Section-level limits for entry selection.
 const EXPERIENCE_LIMIT: usize = 8;
 const PROJECT_LIMIT: usize = 4;
@@ -57,10 +77,70 @@ const OTHER_LIMIT: usize = 3;
 /// 3. Sort descending by combined_score
 /// 4. Apply per-section selection limits
 /// 5. Adjust section_weights based on JD tone signals
-pub fn select_content(entries: Vec<ContextEntryRow>, parsed_jd: &ParsedJD) -> SelectionResult {
-    let weights = ScoringWeights::default();
+///
+/// `weights` are the user's saved scoring weights, or `ScoringWeights::default()`.
+pub fn select_content(
+    entries: Vec<ContextEntryRow>,
+    parsed_jd: &ParsedJD,
+    weights: &ScoringWeights,
+) -> SelectionResult {
+    let ranked = rank_entries(entries, parsed_jd, weights);
+
+    // Apply section-aware selection limits
+    let has_keywords = !parsed_jd.keyword_inventory.is_empty();
+    let (selected_entries, excluded_entries) = apply_section_limits(ranked, has_keywords);
+
+    // Adjust section weights per JD tone
+    let section_weights = compute_section_weights(&parsed_jd.detected_tone);
+
+    SelectionResult {
+        selected_entries,
+        excluded_entries,
+        section_weights,
+        reframe_hints: Vec::new(), // populated by generator via optional LLM call
+    }
+}
+
+/// Selection from entries the user picked by hand: exactly the entries in
+/// `entry_ids`, with no section limits, ranked the way
+/// [`select_content`] ranks them so bullet order and the explanation still follow the
+/// scores. Every other entry is excluded.
+///
+/// Callers check that each id is one of `entries`; unknown ids are ignored here.
+pub fn select_pinned(
+    entries: Vec<ContextEntryRow>,
+    entry_ids: &[Uuid],
+    parsed_jd: &ParsedJD,
+    weights: &ScoringWeights,
+) -> SelectionResult {
+    let (selected_entries, excluded_entries) = rank_entries(entries, parsed_jd, weights)
+        .into_iter()
+        .partition::<Vec<_>, _>(|r| entry_ids.contains(&r.entry.entry_id));
+    let excluded_entries = excluded_entries
+        .into_iter()
+        .map(|r| {
+            (
+                r.entry.entry_id,
+                ExclusionKind::NotPicked,
+                NOT_PICKED_REASON.to_string(),
+            )
+        })
+        .collect();
 
-    // Score and rank all entries
+    SelectionResult {
+        selected_entries,
+        excluded_entries,
+        section_weights: compute_section_weights(&parsed_jd.detected_tone),
+        reframe_hints: Vec::new(),
+    }
+}
+
+/// Scores every entry and sorts them, highest combined score first.
+fn rank_entries(
+    entries: Vec<ContextEntryRow>,
+    parsed_jd: &ParsedJD,
+    weights: &ScoringWeights,
+) -> Vec<RankedEntry> {
     let mut ranked: Vec<RankedEntry> = entries
         .into_iter()
         .map(|entry| {
@@ -69,7 +149,7 @@ pub fn select_content(entries: Vec<ContextEntryRow>, parsed_jd: &ParsedJD) -> Se
                 entry.recency_score,
                 entry.impact_score,
                 jd_relevance,
-                &weights,
+                weights,
             );
             RankedEntry {
                 entry,
@@ -86,18 +166,7 @@ pub fn select_content(entries: Vec<ContextEntryRow>, parsed_jd: &ParsedJD) -> Se
             .unwrap_or(std::cmp::Ordering::Equal)
     });
 
-    // Apply section-aware selection limits
-    let (selected_entries, excluded_entries) = apply_section_limits(ranked);
-
-    // Adjust section weights per JD tone
-    let section_weights = compute_section_weights(&parsed_jd.detected_tone);
-
-    SelectionResult {
-        selected_entries,
-        excluded_entries,
-        section_weights,
-        reframe_hints: Vec::new(), // populated by generator via optional LLM call
-    }
+    ranked
 }
 
 /// Computes JD relevance for a context entry based on keyword overlap.
@@ -121,24 +190,33 @@ pub fn compute_jd_relevance(entry: &ContextEntryRow, parsed_jd: &ParsedJD) -> f6
     let matched_weight: f32 = parsed_jd
         .keyword_inventory
         .iter()
-        .filter(|kw| {
-            let kw_lower = kw.keyword.to_lowercase();
-            let tag_hit = entry.tags.iter().any(|t| t.to_lowercase() == kw_lower);
-            let text_hit = entry
-                .raw_text
-                .as_deref()
-                .map(|t| t.to_lowercase().contains(&kw_lower))
-                .unwrap_or(false);
-            tag_hit || text_hit
-        })
+        .filter(|kw| entry_matches_keyword(entry, &kw.keyword))
         .map(|kw| kw.weighted_score)
         .sum();
 
     (matched_weight / total_weight) as f64
 }
 
-/// Applies per-section limits and separates selected from excluded entries.
-fn apply_section_limits(ranked: Vec<RankedEntry>) -> (Vec<RankedEntry>, Vec<(Uuid, String)>) {
+/// True if one of the entry's tags is `keyword` or its raw text contains it (both
+/// case-insensitive) — the match `compute_jd_relevance` counts.
+pub fn entry_matches_keyword(entry: &ContextEntryRow, keyword: &str) -> bool {
+    let kw_lower = keyword.to_lowercase();
+    let tag_hit = entry.tags.iter().any(|t| t.to_lowercase() == kw_lower);
+    let text_hit = entry
+        .raw_text
+        .as_deref()
+        .map(|t| t.to_lowercase().contains(&kw_lower))
+        .unwrap_or(false);
+    tag_hit || text_hit
+}
+
+/// Applies per-section limits and separates selected from excluded entries. A cut entry
+/// matching none of the JD's keywords (when it has any) is put down to JD relevance: it
+/// had nothing for this JD that the entries kept over it could lack.
+fn apply_section_limits(
+    ranked: Vec<RankedEntry>,
+    has_keywords: bool,
+) -> (Vec<RankedEntry>, Vec<(Uuid, ExclusionKind, String)>) {
     let mut experience_count = 0usize;
     let mut project_count = 0usize;
     let mut other_count = 0usize;
@@ -159,8 +237,13 @@ fn apply_section_limits(ranked: Vec<RankedEntry>) -> (Vec<RankedEntry>, Vec<(Uui
             *count += 1;
             selected.push(ranked_entry);
         } else {
-            let reason = format!("Section limit reached ({} max for {})", limit, section);
-            excluded.push((ranked_entry.entry.entry_id, reason));
+            let kind = if has_keywords && ranked_entry.jd_relevance == 0.0 {
+                ExclusionKind::LowJdRelevance
+            } else {
+                ExclusionKind::SectionLimit
+            };
+            let reason = format!("{SECTION_LIMIT_REASON} ({limit} max for {section})");
+            excluded.push((ranked_entry.entry.entry_id, kind, reason));
         }
     }
 
@@ -211,9 +294,9 @@ fn compute_section_weights(tone: &JDTone) -> HashMap<String, f32> {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::generation::jd_parser::{JDTone, KeywordEntry, ParsedJD, RoleSignals};
     use chrono::Utc;
     use serde_json::json;
+    use templar_types::jd::{JDTone, KeywordEntry, ParsedJD, RoleSignals};
 
     fn make_entry(
         entry_type: &str,
@@ -237,6 +320,7 @@ mod tests {
             quality_score: 1.0,
             quality_flags: vec![],
             created_at: Utc::now(),
+            recency_policy: None,
         }
     }
 
@@ -270,7 +354,7 @@ mod tests {
             make_entry("experience", vec![], 0.1, 0.1),
         ];
         let parsed_jd = make_parsed_jd(&["rust"], JDTone::AggressiveStartup);
-        let result = select_content(entries, &parsed_jd);
+        let result = select_content(entries, &parsed_jd, &ScoringWeights::default());
 
         assert!(
             result.selected_entries[0].combined_score > result.selected_entries[1].combined_score,
@@ -284,7 +368,7 @@ mod tests {
             .map(|_| make_entry("experience", vec![], 0.5, 0.5))
             .collect();
         let parsed_jd = make_parsed_jd(&[], JDTone::CollaborativeEnterprise);
-        let result = select_content(entries, &parsed_jd);
+        let result = select_content(entries, &parsed_jd, &ScoringWeights::default());
 
         let selected_exp = result
             .selected_entries
@@ -296,18 +380,40 @@ mod tests {
         let excluded_exp = result
             .excluded_entries
             .iter()
-            .filter(|(_, reason)| reason.contains("experience"))
+            .filter(|(_, _, reason)| reason.contains("experience"))
             .count();
         assert_eq!(excluded_exp, 4, "4 experience entries excluded");
     }
 
+    #[test]
+    fn test_section_limit_cuts_matching_no_keyword_are_low_relevance() {
+        let kept: Vec<_> = (0..8)
+            .map(|_| make_entry("experience", vec!["rust".to_string()], 0.9, 0.9))
+            .collect();
+        let off_topic = make_entry("experience", vec![], 0.1, 0.1);
+        let on_topic = make_entry("experience", vec!["rust".to_string()], 0.1, 0.1);
+        let parsed_jd = make_parsed_jd(&["rust"], JDTone::CollaborativeEnterprise);
+
+        let mut entries = kept.clone();
+        entries.push(off_topic.clone());
+        let result = select_content(entries, &parsed_jd, &ScoringWeights::default());
+        assert_eq!(result.excluded_entries[0].0, off_topic.entry_id);
+        assert_eq!(result.excluded_entries[0].1, ExclusionKind::LowJdRelevance);
+
+        let mut entries = kept;
+        entries.push(on_topic.clone());
+        let result = select_content(entries, &parsed_jd, &ScoringWeights::default());
+        assert_eq!(result.excluded_entries[0].0, on_topic.entry_id);
+        assert_eq!(result.excluded_entries[0].1, ExclusionKind::SectionLimit);
+    }
+
     #[test]
     fn test_project_section_capped_at_4() {
         let entries: Vec<_> = (0..7)
             .map(|_| make_entry("project", vec![], 0.5, 0.5))
             .collect();
         let parsed_jd = make_parsed_jd(&[], JDTone::CollaborativeEnterprise);
-        let result = select_content(entries, &parsed_jd);
+        let result = select_content(entries, &parsed_jd, &ScoringWeights::default());
 
         let selected = result
             .selected_entries
@@ -324,7 +430,7 @@ mod tests {
             .map(|_| make_entry("open_source", vec![], 0.5, 0.5))
             .collect();
         let parsed_jd = make_parsed_jd(&[], JDTone::AggressiveStartup);
-        let result = select_content(entries, &parsed_jd);
+        let result = select_content(entries, &parsed_jd, &ScoringWeights::default());
 
         let selected = result
             .selected_entries
@@ -337,6 +443,28 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_pinned_selection_ignores_section_limits_and_excludes_the_rest() {
+        let entries: Vec<_> = (0..10)
+            .map(|i| make_entry("experience", vec![], 0.1 * i as f64, 0.5))
+            .collect();
+        // Nine picks, past the experience limit, including the lowest-scoring entry.
+        let picked: Vec<Uuid> = entries[..9].iter().map(|e| e.entry_id).collect();
+        let left_out = entries[9].entry_id;
+        let parsed_jd = make_parsed_jd(&[], JDTone::CollaborativeEnterprise);
+        let result = select_pinned(entries, &picked, &parsed_jd, &ScoringWeights::default());
+
+        assert_eq!(result.selected_entries.len(), 9);
+        assert_eq!(result.selected_entries[8].entry.entry_id, picked[0]);
+        assert!(result
+            .selected_entries
+            .windows(2)
+            .all(|w| w[0].combined_score >= w[1].combined_score));
+        assert_eq!(result.excluded_entries.len(), 1);
+        assert_eq!(result.excluded_entries[0].0, left_out);
+        assert_eq!(result.excluded_entries[0].1, ExclusionKind::NotPicked);
+    }
+
     #[test]
     fn test_research_tone_adds_publication_weight() {
         let weights = compute_section_weights(&JDTone::ResearchOriented);
@@ -393,7 +521,11 @@ mod tests {
 
     #[test]
     fn test_reframe_hints_empty_by_default() {
-        let result = select_content(vec![], &make_parsed_jd(&[], JDTone::ProductOriented));
+        let result = select_content(
+            vec![],
+            &make_parsed_jd(&[], JDTone::ProductOriented),
+            &ScoringWeights::default(),
+        );
         assert!(result.reframe_hints.is_empty());
     }
 }
@@ -0,0 +1,483 @@
+//! Domain packs — verb sets and vague-word lists for resumes outside engineering.
+//!
+//! A pack swaps three lists: the strong verbs offered for each JD tone, the sole-owner
+//! verbs a `team_member` entry may never use, and the English vague verbs ingest
+//! flags. "Launched a campaign" is strong for a marketer; "Architected" means nothing
+//! to a finance hiring manager. Engineering is the default and holds the lists the
+//! pipeline always used.
+//!
+//! Chosen per user (`user_preferences.domain_pack`) or per persona
+//! (`personas.domain_pack`); a persona's pack wins when generating with it.
+
+use serde::{Deserialize, Serialize};
+use sqlx::PgPool;
+use uuid::Uuid;
+
+use crate::context::validation::{VagueWords, ENGLISH, FRENCH, GERMAN, SPANISH};
+use crate::errors::AppError;
+use crate::generation::jd_parser::JDTone;
+use crate::generation::prompts::{
+    DOMAIN_ACADEMIA_INSTRUCTION, DOMAIN_ENGINEERING_INSTRUCTION, DOMAIN_FINANCE_INSTRUCTION,
+    DOMAIN_MARKETING_INSTRUCTION, DOMAIN_PRODUCT_INSTRUCTION,
+};
+use crate::users::preferences;
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DomainPack {
+    #[default]
+    Engineering,
+    Product,
+    Marketing,
+    Finance,
+    Academia,
+}
+
+impl DomainPack {
+    pub const ALL: [DomainPack; 5] = [
+        DomainPack::Engineering,
+        DomainPack::Product,
+        DomainPack::Marketing,
+        DomainPack::Finance,
+        DomainPack::Academia,
+    ];
+
+    /// Value stored in `user_preferences.domain_pack` and `personas.domain_pack`.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            DomainPack::Engineering => "engineering",
+            DomainPack::Product => "product",
+            DomainPack::Marketing => "marketing",
+            DomainPack::Finance => "finance",
+            DomainPack::Academia => "academia",
+        }
+    }
+
+    pub fn parse(s: &str) -> Option<DomainPack> {
+        DomainPack::ALL.into_iter().find(|p| p.as_str() == s)
+    }
+
+    /// Verbs the generation prompt offers for a JD of `tone`.
+    pub fn strong_verbs(&self, tone: &JDTone) -> &'static [&'static str] {
+        use JDTone::*;
+        match (self, tone) {
+            (DomainPack::Engineering, AggressiveStartup) => &[
+                "Architected",
+                "Spearheaded",
+                "Owned",
+                "Drove",
+                "Built",
+                "Shipped",
+                "Launched",
+                "Led",
+            ],
+            (DomainPack::Engineering, CollaborativeEnterprise) => &[
+                "Contributed to",
+                "Partnered with",
+                "Supported",
+                "Enabled",
+                "Collaborated on",
+                "Facilitated",
+            ],
+            (DomainPack::Engineering, ResearchOriented) => &[
+                "Investigated",
+                "Designed and evaluated",
+                "Published",
+                "Proposed",
+                "Analyzed",
+                "Studied",
+            ],
+            (DomainPack::Engineering, ProductOriented) => &[
+                "Shipped",
+                "Delivered",
+                "Launched",
+                "Improved",
+                "Reduced friction for",
+                "Enabled",
+            ],
+            (DomainPack::Product, AggressiveStartup) => &[
+                "Owned",
+                "Drove",
+                "Spearheaded",
+                "Defined",
+                "Launched",
+                "Shipped",
+                "Prioritized",
+                "Led",
+            ],
+            (DomainPack::Product, CollaborativeEnterprise) => &[
+                "Partnered with",
+                "Contributed to",
+                "Collaborated on",
+                "Coordinated",
+                "Aligned",
+                "Enabled",
+            ],
+            (DomainPack::Product, ResearchOriented) => &[
+                "Interviewed",
+                "Validated",
+                "Synthesized",
+                "Analyzed",
+                "Tested",
+                "Measured",
+            ],
+            (DomainPack::Product, ProductOriented) => &[
+                "Shipped",
+                "Launched",
+                "Prioritized",
+                "Delivered",
+                "Reduced friction for",
+                "Grew",
+            ],
+            (DomainPack::Marketing, AggressiveStartup) => &[
+                "Spearheaded",
+                "Owned",
+                "Drove",
+                "Launched",
+                "Grew",
+                "Scaled",
+                "Directed",
+                "Led",
+            ],
+            (DomainPack::Marketing, CollaborativeEnterprise) => &[
+                "Partnered with",
+                "Contributed to",
+                "Collaborated on",
+                "Coordinated",
+                "Executed",
+                "Produced",
+            ],
+            (DomainPack::Marketing, ResearchOriented) => &[
+                "Analyzed",
+                "Segmented",
+                "Surveyed",
+                "Measured",
+                "A/B tested",
+                "Modeled",
+            ],
+            (DomainPack::Marketing, ProductOriented) => &[
+                "Launched",
+                "Positioned",
+                "Grew",
+                "Converted",
+                "Activated",
+                "Retained",
+            ],
+            (DomainPack::Finance, AggressiveStartup) => &[
+                "Led",
+                "Owned",
+                "Headed",
+                "Structured",
+                "Negotiated",
+                "Closed",
+                "Raised",
+                "Built",
+            ],
+            (DomainPack::Finance, CollaborativeEnterprise) => &[
+                "Partnered with",
+                "Contributed to",
+                "Collaborated on",
+                "Reconciled",
+                "Prepared",
+                "Coordinated",
+            ],
+            (DomainPack::Finance, ResearchOriented) => &[
+                "Modeled",
+                "Forecasted",
+                "Valued",
+                "Stress-tested",
+                "Analyzed",
+                "Evaluated",
+            ],
+            (DomainPack::Finance, ProductOriented) => &[
+                "Reduced",
+                "Saved",
+                "Automated",
+                "Streamlined",
+                "Priced",
+                "Delivered",
+            ],
+            (DomainPack::Academia, AggressiveStartup) => &[
+                "Led",
+                "Founded",
+                "Pioneered",
+                "Established",
+                "Secured",
+                "Directed",
+            ],
+            (DomainPack::Academia, CollaborativeEnterprise) => &[
+                "Co-authored",
+                "Collaborated on",
+                "Contributed to",
+                "Co-supervised",
+                "Taught",
+                "Coordinated",
+            ],
+            (DomainPack::Academia, ResearchOriented) => &[
+                "Published",
+                "Investigated",
+                "Proposed",
+                "Designed and evaluated",
+                "Analyzed",
+                "Presented",
+            ],
+            (DomainPack::Academia, ProductOriented) => &[
+                "Developed",
+                "Released",
+                "Presented",
+                "Taught",
+                "Delivered",
+                "Translated",
+            ],
+        }
+    }
+
+    /// Verbs that signal sole ownership in this domain — never allowed for
+    /// `team_member` entries (see `tone::filter_verbs_for_contribution`).
+    pub fn sole_owner_verbs(&self) -> &'static [&'static str] {
+        match self {
+            DomainPack::Engineering => &[
+                "Architected",
+                "Spearheaded",
+                "Owned",
+                "Drove",
+                "Led",
+                "Built",
+                "Designed",
+            ],
+            DomainPack::Product => &[
+                "Owned",
+                "Drove",
+                "Spearheaded",
+                "Defined",
+                "Led",
+                "Championed",
+            ],
+            DomainPack::Marketing => &[
+                "Spearheaded",
+                "Owned",
+                "Drove",
+                "Directed",
+                "Led",
+                "Conceived",
+            ],
+            DomainPack::Finance => &[
+                "Led",
+                "Owned",
+                "Headed",
+                "Structured",
+                "Spearheaded",
+                "Originated",
+            ],
+            DomainPack::Academia => &[
+                "Led",
+                "Founded",
+                "Pioneered",
+                "Established",
+                "Directed",
+                "Spearheaded",
+            ],
+        }
+    }
+
+    /// English vague words for this domain. Scale words are shared; verbs differ
+    /// because "managed" or "handled" say nothing in finance but "supported" is still
+    /// weak everywhere.
+    pub fn english_vague_words(&self) -> VagueWords {
+        let verbs: &'static [&'static str] = match self {
+            DomainPack::Engineering => ENGLISH.verbs,
+            DomainPack::Product => &[
+                "improved",
+                "enhanced",
+                "helped",
+                "worked on",
+                "assisted",
+                "supported",
+                "participated",
+                "involved",
+                "facilitated",
+                "aligned stakeholders",
+                "drove alignment",
+            ],
+            DomainPack::Marketing => &[
+                "improved",
+                "enhanced",
+                "helped",
+                "worked on",
+                "assisted",
+                "supported",
+                "participated",
+                "involved",
+                "managed",
+                "handled",
+                "leveraged",
+                "boosted",
+                "raised awareness",
+            ],
+            DomainPack::Finance => &[
+                "improved",
+                "enhanced",
+                "helped",
+                "worked on",
+                "assisted",
+                "supported",
+                "participated",
+                "involved",
+                "handled",
+                "oversaw",
+                "monitored",
+                "responsible for",
+            ],
+            DomainPack::Academia => &[
+                "improved",
+                "enhanced",
+                "helped",
+                "worked on",
+                "assisted",
+                "supported",
+                "participated",
+                "involved",
+                "explored",
+                "looked into",
+                "exposed to",
+                "familiarized",
+            ],
+        };
+        VagueWords { verbs, ..ENGLISH }
+    }
+
+    /// Every vague-word list ingest checks, with this pack's English list in place of
+    /// the default one.
+    pub fn vague_words(&self) -> [VagueWords; 4] {
+        [self.english_vague_words(), GERMAN, FRENCH, SPANISH]
+    }
+
+    /// Prompt fragment injected into the generation prompt as `{domain_instruction}`.
+    pub fn instruction(&self) -> &'static str {
+        match self {
+            DomainPack::Engineering => DOMAIN_ENGINEERING_INSTRUCTION,
+            DomainPack::Product => DOMAIN_PRODUCT_INSTRUCTION,
+            DomainPack::Marketing => DOMAIN_MARKETING_INSTRUCTION,
+            DomainPack::Finance => DOMAIN_FINANCE_INSTRUCTION,
+            DomainPack::Academia => DOMAIN_ACADEMIA_INSTRUCTION,
+        }
+    }
+}
+
+/// The caller's saved pack, for ingest's quality checks.
+pub async fn user_pack(db: &PgPool, user_id: Uuid) -> Result<DomainPack, AppError> {
+    Ok(preferences::load(db, user_id)
+        .await?
+        .domain_pack
+        .unwrap_or_default())
+}
+
+/// The pack a generation runs with: the persona's when it sets one, else the user's
+/// saved pack, else Engineering. A persona that is not the caller's is NotFound.
+pub async fn resolve_pack(
+    db: &PgPool,
+    user_id: Uuid,
+    persona_id: Option<Uuid>,
+    user_pack: Option<DomainPack>,
+) -> Result<DomainPack, AppError> {
+    let persona_pack = match persona_id {
+        Some(persona_id) => {
+            let row = sqlx::query!(
+                "SELECT domain_pack FROM personas WHERE id = $1 AND user_id = $2",
+                persona_id,
+                user_id
+            )
+            .fetch_optional(db)
+            .await?
+            .ok_or_else(|| AppError::NotFound(format!("Persona {persona_id} not found")))?;
+            row.domain_pack.as_deref().and_then(DomainPack::parse)
+        }
+        None => None,
+    };
+    Ok(persona_pack.or(user_pack).unwrap_or_default())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::context::validation::validate_impact_with;
+    use crate::generation::tone::filter_verbs_for_contribution;
+
+    const TONES: [JDTone; 4] = [
+        JDTone::AggressiveStartup,
+        JDTone::CollaborativeEnterprise,
+        JDTone::ResearchOriented,
+        JDTone::ProductOriented,
+    ];
+
+    #[test]
+    fn test_as_str_round_trips_and_matches_serde() {
+        for pack in DomainPack::ALL {
+            assert_eq!(DomainPack::parse(pack.as_str()), Some(pack));
+            assert_eq!(
+                serde_json::to_value(pack).unwrap(),
+                serde_json::json!(pack.as_str())
+            );
+        }
+        assert_eq!(DomainPack::parse("legal"), None);
+    }
+
+    #[test]
+    fn test_engineering_keeps_the_default_vague_words() {
+        assert_eq!(
+            DomainPack::Engineering.english_vague_words().verbs,
+            ENGLISH.verbs
+        );
+    }
+
+    #[test]
+    fn test_team_member_keeps_verbs_in_every_pack_and_tone() {
+        for pack in DomainPack::ALL {
+            for tone in &TONES {
+                let verbs = pack.strong_verbs(tone);
+                let filtered = filter_verbs_for_contribution(verbs, "team_member", pack);
+                assert!(!filtered.is_empty(), "{pack:?} {tone:?}");
+                for verb in pack.sole_owner_verbs() {
+                    assert!(!filtered.contains(verb), "{pack:?} {tone:?}: {verb}");
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_strong_verbs_are_not_flagged_vague_in_their_pack() {
+        // Engineering predates packs and offers "Supported"/"Improved" to
+        // collaborative and product JDs; the new packs must not contradict themselves.
+        for pack in &DomainPack::ALL[1..] {
+            let vague = pack.vague_words();
+            for tone in &TONES {
+                for verb in pack.strong_verbs(tone) {
+                    let q = validate_impact_with(&format!("{verb} the launch plan"), &vague);
+                    assert!(
+                        !q.flags.iter().any(|f| f.starts_with("vague_verb")),
+                        "{pack:?}: {verb} → {:?}",
+                        q.flags
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_pack_swaps_vague_verbs() {
+        let text = "Oversaw month-end close for the EMEA entities";
+        let engineering = validate_impact_with(text, &DomainPack::Engineering.vague_words());
+        let finance = validate_impact_with(text, &DomainPack::Finance.vague_words());
+        assert_eq!(engineering.flags, vec!["missing_metric"]);
+        assert_eq!(finance.flags, vec!["vague_verb:oversaw"]);
+    }
+
+    #[test]
+    fn test_each_pack_has_distinct_instruction() {
+        for (i, a) in DomainPack::ALL.iter().enumerate() {
+            for b in &DomainPack::ALL[i + 1..] {
+                assert_ne!(a.instruction(), b.instruction(), "{a:?} vs {b:?}");
+            }
+        }
+    }
+}
@@ -0,0 +1,524 @@
+//! Generation explanation — why a resume looks the way it does.
+//!
+//! Selection and tone decisions are otherwise gone once the resume is saved. The
+//! explanation keeps them on the resume row (`resumes.explanation`) for
+//! GET /resumes/:id/explanation (and GET /resumes/:id, as `resume.explanation`): which
+//! entries selection kept and which it cut (and why), the tone the bullets were written
+//! in and where it came from, and which JD keywords pulled entries in.
+
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::generation::content_selector::{
+    compute_jd_relevance, entry_matches_keyword, ExclusionKind, SelectionResult, NOT_PICKED_REASON,
+    SECTION_LIMIT_REASON,
+};
+use crate::generation::domain_pack::DomainPack;
+use crate::generation::jd_parser::{JDTone, ParsedJD};
+use crate::layout::SimulatedBullet;
+use crate::models::context::ContextEntryRow;
+
+// ────────────────────────────────────────────────────────────────────────────
+// Data models
+// ────────────────────────────────────────────────────────────────────────────
+
+/// Where the tone the bullets were written in came from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ToneSource {
+    /// Detected in the JD.
+    Jd,
+    /// The user's saved tone preference, which wins over the JD's.
+    Preference,
+}
+
+/// An entry selection kept, with the scores it was ranked by.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SelectedEntry {
+    pub entry_id: Uuid,
+    pub entry_type: String,
+    /// Company, project or institution name, when the entry has one.
+    pub label: Option<String>,
+    pub combined_score: f64,
+    pub jd_relevance: f64,
+    /// JD keywords the entry's tags or text mention.
+    pub matched_keywords: Vec<String>,
+    /// Bullets on the final resume written from this entry. 0 when the draft skipped
+    /// it or its bullets were dropped (dedup, page fill).
+    pub bullet_count: usize,
+}
+
+/// An entry selection cut.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ExcludedEntry {
+    pub entry_id: Uuid,
+    pub entry_type: String,
+    pub label: Option<String>,
+    pub jd_relevance: f64,
+    pub matched_keywords: Vec<String>,
+    pub kind: ExclusionKind,
+    /// As given by selection, e.g. "Section limit reached (8 max for experience)".
+    pub reason: String,
+}
+
+/// A JD keyword and how many selected entries it matched.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct KeywordDriver {
+    pub keyword: String,
+    pub weighted_score: f32,
+    pub selected_entries: usize,
+}
+
+/// Explanation persisted on the resume row (`resumes.explanation`).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(from = "StoredExplanation")]
+pub struct GenerationExplanation {
+    pub tone: JDTone,
+    pub tone_source: ToneSource,
+    pub domain_pack: DomainPack,
+    /// In selection order, highest combined score first.
+    pub selected: Vec<SelectedEntry>,
+    pub excluded: Vec<ExcludedEntry>,
+    /// Section weights selection derived from the tone.
+    pub section_weights: HashMap<String, f32>,
+    /// JD keywords matching at least one selected entry, highest weight first.
+    pub keyword_drivers: Vec<KeywordDriver>,
+    /// JD keywords no selected entry matches.
+    pub unmatched_keywords: Vec<String>,
+}
+
+/// `GenerationExplanation` as stored. Exclusions recorded before kinds have none, and
+/// get theirs from the reason and the JD's keywords.
+#[derive(Deserialize)]
+struct StoredExplanation {
+    tone: JDTone,
+    tone_source: ToneSource,
+    domain_pack: DomainPack,
+    selected: Vec<SelectedEntry>,
+    excluded: Vec<StoredExcludedEntry>,
+    section_weights: HashMap<String, f32>,
+    keyword_drivers: Vec<KeywordDriver>,
+    unmatched_keywords: Vec<String>,
+}
+
+#[derive(Deserialize)]
+struct StoredExcludedEntry {
+    entry_id: Uuid,
+    entry_type: String,
+    label: Option<String>,
+    jd_relevance: f64,
+    matched_keywords: Vec<String>,
+    kind: Option<ExclusionKind>,
+    reason: String,
+}
+
+impl From<StoredExplanation> for GenerationExplanation {
+    fn from(stored: StoredExplanation) -> Self {
+        let has_keywords =
+            !stored.keyword_drivers.is_empty() || !stored.unmatched_keywords.is_empty();
+        let excluded = stored
+            .excluded
+            .into_iter()
+            .map(|e| ExcludedEntry {
+                kind: e.kind.unwrap_or_else(|| {
+                    legacy_kind(&e.reason, has_keywords && e.matched_keywords.is_empty())
+                }),
+                entry_id: e.entry_id,
+                entry_type: e.entry_type,
+                label: e.label,
+                jd_relevance: e.jd_relevance,
+                matched_keywords: e.matched_keywords,
+                reason: e.reason,
+            })
+            .collect();
+        GenerationExplanation {
+            tone: stored.tone,
+            tone_source: stored.tone_source,
+            domain_pack: stored.domain_pack,
+            selected: stored.selected,
+            excluded,
+            section_weights: stored.section_weights,
+            keyword_drivers: stored.keyword_drivers,
+            unmatched_keywords: stored.unmatched_keywords,
+        }
+    }
+}
+
+// ────────────────────────────────────────────────────────────────────────────
+// Builder
+// ────────────────────────────────────────────────────────────────────────────
+
+/// Builds the explanation from the selection, all of the user's entries (excluded ones
+/// are looked up there) and the final bullets.
+pub fn build_explanation(
+    selection: &SelectionResult,
+    entries: &[ContextEntryRow],
+    parsed_jd: &ParsedJD,
+    tone_source: ToneSource,
+    domain_pack: DomainPack,
+    final_bullets: &[SimulatedBullet],
+) -> GenerationExplanation {
+    let mut keywords: Vec<(&str, f32)> = parsed_jd
+        .keyword_inventory
+        .iter()
+        .filter(|k| !k.keyword.trim().is_empty())
+        .map(|k| (k.keyword.as_str(), k.weighted_score))
+        .collect();
+    keywords.sort_by(|a, b| b.1.partial_cmp(&a.1).unwrap_or(std::cmp::Ordering::Equal));
+    let matched = |entry: &ContextEntryRow| -> Vec<String> {
+        keywords
+            .iter()
+            .filter(|(k, _)| entry_matches_keyword(entry, k))
+            .map(|(k, _)| k.to_string())
+            .collect()
+    };
+
+    let selected: Vec<SelectedEntry> = selection
+        .selected_entries
+        .iter()
+        .map(|ranked| SelectedEntry {
+            entry_id: ranked.entry.entry_id,
+            entry_type: ranked.entry.entry_type.clone(),
+            label: entry_label(&ranked.entry),
+            combined_score: ranked.combined_score,
+            jd_relevance: ranked.jd_relevance,
+            matched_keywords: matched(&ranked.entry),
+            bullet_count: final_bullets
+                .iter()
+                .filter(|b| b.source_entry_id == ranked.entry.entry_id)
+                .count(),
+        })
+        .collect();
+
+    let excluded = selection
+        .excluded_entries
+        .iter()
+        .filter_map(|(entry_id, kind, reason)| {
+            let entry = entries.iter().find(|e| e.entry_id == *entry_id)?;
+            Some(ExcludedEntry {
+                entry_id: *entry_id,
+                entry_type: entry.entry_type.clone(),
+                label: entry_label(entry),
+                jd_relevance: compute_jd_relevance(entry, parsed_jd),
+                matched_keywords: matched(entry),
+                kind: *kind,
+                reason: reason.clone(),
+            })
+        })
+        .collect();
+
+    let mut keyword_drivers = Vec::new();
+    let mut unmatched_keywords = Vec::new();
+    for (keyword, weighted_score) in &keywords {
+        let count = selected
+            .iter()
+            .filter(|s| s.matched_keywords.iter().any(|k| k == keyword))
+            .count();
+        if count == 0 {
+            unmatched_keywords.push(keyword.to_string());
+        } else {
+            keyword_drivers.push(KeywordDriver {
+                keyword: keyword.to_string(),
+                weighted_score: *weighted_score,
+                selected_entries: count,
+            });
+        }
+    }
+
+    GenerationExplanation {
+        tone: parsed_jd.detected_tone.clone(),
+        tone_source,
+        domain_pack,
+        selected,
+        excluded,
+        section_weights: selection.section_weights.clone(),
+        keyword_drivers,
+        unmatched_keywords,
+    }
+}
+
+/// The kind of a stored exclusion recorded without one, from its `reason`, the way
+/// selection now decides it. `unmatched` is set only when the JD had keywords and the
+/// entry matched none of them.
+fn legacy_kind(reason: &str, unmatched: bool) -> ExclusionKind {
+    if reason == NOT_PICKED_REASON {
+        ExclusionKind::NotPicked
+    } else if reason.starts_with(SECTION_LIMIT_REASON) {
+        if unmatched {
+            ExclusionKind::LowJdRelevance
+        } else {
+            ExclusionKind::SectionLimit
+        }
+    } else {
+        ExclusionKind::Other
+    }
+}
+
+/// The name an entry goes by on the resume, when its data has one.
+fn entry_label(entry: &ContextEntryRow) -> Option<String> {
+    [
+        "company",
+        "name",
+        "project_name",
+        "organization",
+        "institution",
+        "title",
+    ]
+    .iter()
+    .find_map(|field| entry.data.get(*field).and_then(|v| v.as_str()))
+    .map(str::to_string)
+}
+
+// ────────────────────────────────────────────────────────────────────────────
+// Tests
+// ────────────────────────────────────────────────────────────────────────────
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::generation::content_selector::RankedEntry;
+    use crate::layout::LineType;
+    use chrono::Utc;
+    use serde_json::{json, Value};
+    use templar_types::jd::{KeywordEntry, RoleSignals};
+
+    fn make_entry(entry_type: &str, data: Value, tags: &[&str]) -> ContextEntryRow {
+        ContextEntryRow {
+            id: Uuid::new_v4(),
+            user_id: Uuid::new_v4(),
+            entry_id: Uuid::new_v4(),
+            version: 1,
+            entry_type: entry_type.to_string(),
+            data,
+            raw_text: None,
+            recency_score: 0.5,
+            impact_score: 0.5,
+            tags: tags.iter().map(|t| t.to_string()).collect(),
+            flagged_evergreen: false,
+            contribution_type: "sole_author".to_string(),
+            quality_score: 1.0,
+            quality_flags: vec![],
+            created_at: Utc::now(),
+            recency_policy: None,
+        }
+    }
+
+    fn make_jd(keywords: &[(&str, f32)]) -> ParsedJD {
+        ParsedJD {
+            hard_requirements: vec![],
+            soft_signals: vec![],
+            role_signals: RoleSignals {
+                is_startup: true,
+                is_ic_focused: true,
+                is_research: false,
+                seniority: "senior".to_string(),
+            },
+            keyword_inventory: keywords
+                .iter()
+                .map(|(k, w)| KeywordEntry {
+                    keyword: k.to_string(),
+                    frequency: 1,
+                    position_weight: *w,
+                    weighted_score: *w,
+                })
+                .collect(),
+            detected_tone: JDTone::AggressiveStartup,
+        }
+    }
+
+    fn ranked(entry: ContextEntryRow, combined_score: f64) -> RankedEntry {
+        RankedEntry {
+            entry,
+            combined_score,
+            jd_relevance: 0.5,
+        }
+    }
+
+    fn bullet_from(entry_id: Uuid) -> SimulatedBullet {
+        SimulatedBullet {
+            text: "Built it".to_string(),
+            source_entry_id: entry_id,
+            section: "experience".to_string(),
+            line_type: LineType::Bullet,
+            verified_line_count: 1,
+            jd_keywords_used: vec![],
+            was_adjusted: false,
+            flagged_for_review: false,
+            revisions: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn test_selected_and_excluded_entries_carry_keywords_and_reasons() {
+        let kept = make_entry("experience", json!({ "company": "Acme" }), &["rust"]);
+        let cut = make_entry("project", json!({ "name": "Side" }), &["go"]);
+        let selection = SelectionResult {
+            selected_entries: vec![ranked(kept.clone(), 0.8)],
+            excluded_entries: vec![(
+                cut.entry_id,
+                ExclusionKind::SectionLimit,
+                "Section limit reached (4 max for project)".to_string(),
+            )],
+            section_weights: HashMap::new(),
+            reframe_hints: vec![],
+        };
+        let jd = make_jd(&[("go", 0.4), ("rust", 0.9)]);
+        let bullets = vec![bullet_from(kept.entry_id), bullet_from(kept.entry_id)];
+
+        let explanation = build_explanation(
+            &selection,
+            &[kept.clone(), cut.clone()],
+            &jd,
+            ToneSource::Jd,
+            DomainPack::Engineering,
+            &bullets,
+        );
+
+        assert_eq!(explanation.selected.len(), 1);
+        let selected = &explanation.selected[0];
+        assert_eq!(selected.label.as_deref(), Some("Acme"));
+        assert_eq!(selected.matched_keywords, vec!["rust"]);
+        assert_eq!(selected.bullet_count, 2);
+
+        assert_eq!(explanation.excluded.len(), 1);
+        let excluded = &explanation.excluded[0];
+        assert_eq!(excluded.entry_id, cut.entry_id);
+        assert_eq!(excluded.label.as_deref(), Some("Side"));
+        assert_eq!(excluded.matched_keywords, vec!["go"]);
+        assert!(excluded.reason.starts_with("Section limit"));
+        assert_eq!(excluded.kind, ExclusionKind::SectionLimit);
+        assert_eq!(explanation.tone, JDTone::AggressiveStartup);
+    }
+
+    #[test]
+    fn test_exclusion_kinds_come_from_selection() {
+        let cut = make_entry("project", json!({}), &["php"]);
+        let unpicked = make_entry("experience", json!({}), &["rust"]);
+        let selection = SelectionResult {
+            selected_entries: vec![],
+            excluded_entries: vec![
+                (
+                    cut.entry_id,
+                    ExclusionKind::SectionLimit,
+                    "Section limit reached (4 max for project)".to_string(),
+                ),
+                (
+                    unpicked.entry_id,
+                    ExclusionKind::NotPicked,
+                    NOT_PICKED_REASON.to_string(),
+                ),
+            ],
+            section_weights: HashMap::new(),
+            reframe_hints: vec![],
+        };
+        let jd = make_jd(&[("rust", 0.9)]);
+
+        let explanation = build_explanation(
+            &selection,
+            &[cut, unpicked],
+            &jd,
+            ToneSource::Jd,
+            DomainPack::Engineering,
+            &[],
+        );
+        // Taken as given, even where the reason alone would read differently.
+        let kinds: Vec<ExclusionKind> = explanation.excluded.iter().map(|e| e.kind).collect();
+        assert_eq!(
+            kinds,
+            vec![ExclusionKind::SectionLimit, ExclusionKind::NotPicked]
+        );
+    }
+
+    /// A stored explanation with one kindless exclusion per `(reason, matched)`, and
+    /// `jd_keywords` as its unmatched keywords.
+    fn stored(exclusions: &[(&str, &[&str])], jd_keywords: &[&str]) -> GenerationExplanation {
+        let excluded: Vec<Value> = exclusions
+            .iter()
+            .map(|(reason, matched)| {
+                json!({
+                    "entry_id": Uuid::new_v4(),
+                    "entry_type": "project",
+                    "label": null,
+                    "jd_relevance": 0.0,
+                    "matched_keywords": matched,
+                    "reason": reason,
+                })
+            })
+            .collect();
+        serde_json::from_value(json!({
+            "tone": "CollaborativeEnterprise",
+            "tone_source": "jd",
+            "domain_pack": "engineering",
+            "selected": [],
+            "excluded": excluded,
+            "section_weights": {},
+            "keyword_drivers": [],
+            "unmatched_keywords": jd_keywords,
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn test_stored_exclusions_without_a_kind_get_one_from_the_reason() {
+        let limit = "Section limit reached (4 max for project)";
+        let explanation = stored(
+            &[
+                (limit, &["go"]),
+                (limit, &[]),
+                (NOT_PICKED_REASON, &[]),
+                ("Archived", &[]),
+            ],
+            &["go", "rust"],
+        );
+        let kinds: Vec<ExclusionKind> = explanation.excluded.iter().map(|e| e.kind).collect();
+        assert_eq!(
+            kinds,
+            vec![
+                ExclusionKind::SectionLimit,
+                ExclusionKind::LowJdRelevance,
+                ExclusionKind::NotPicked,
+                ExclusionKind::Other,
+            ]
+        );
+    }
+
+    #[test]
+    fn test_stored_exclusions_are_not_low_relevance_without_jd_keywords() {
+        let limit = "Section limit reached (4 max for project)";
+        let explanation = stored(&[(limit, &[])], &[]);
+        assert_eq!(explanation.excluded[0].kind, ExclusionKind::SectionLimit);
+    }
+
+    #[test]
+    fn test_keywords_split_into_drivers_and_unmatched_by_weight() {
+        let a = make_entry("experience", json!({}), &["rust", "kafka"]);
+        let b = make_entry("experience", json!({}), &["rust"]);
+        let selection = SelectionResult {
+            selected_entries: vec![ranked(a, 0.9), ranked(b, 0.7)],
+            excluded_entries: vec![],
+            section_weights: HashMap::new(),
+            reframe_hints: vec![],
+        };
+        let jd = make_jd(&[("kafka", 0.5), ("rust", 0.9), ("terraform", 0.7)]);
+
+        let explanation = build_explanation(
+            &selection,
+            &[],
+            &jd,
+            ToneSource::Preference,
+            DomainPack::Engineering,
+            &[],
+        );
+
+        let drivers: Vec<(&str, usize)> = explanation
+            .keyword_drivers
+            .iter()
+            .map(|d| (d.keyword.as_str(), d.selected_entries))
+            .collect();
+        assert_eq!(drivers, vec![("rust", 2), ("kafka", 1)]);
+        assert_eq!(explanation.unmatched_keywords, vec!["terraform"]);
+        assert_eq!(explanation.selected[0].label, None);
+    }
+}
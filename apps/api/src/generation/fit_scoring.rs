@@ -8,7 +8,7 @@
 //! `AppState` holds an `Arc<dyn FitScorer>`, swapped at startup via config.
 
 use async_trait::async_trait;
-use serde::{Deserialize, Serialize};
+use serde::Deserialize;
 
 use crate::errors::AppError;
 use crate::generation::jd_parser::ParsedJD;
@@ -19,33 +19,7 @@ use crate::models::context::ContextEntryRow;
 // Output data models (shared across all scorer backends)
 // ────────────────────────────────────────────────────────────────────────────
 
-/// A single matched dimension between user context and a JD keyword/requirement.
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct FitMatch {
-    pub dimension: String,
-    pub context_evidence: String, // which entry covers it
-    pub jd_requirement: String,
-    pub strength: f32, // 0.0 – 1.0
-}
-
-/// A JD keyword or requirement not covered by any context entry.
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct Gap {
-    pub keyword: String,
-    pub jd_frequency: u32,
-    pub suggestion: Option<String>, // closest context entry_id, if any
-}
-
-/// Full fit report returned to callers.
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct FitReport {
-    pub overall_score: u32,             // 0 – 100
-    pub strong_matches: Vec<FitMatch>,  // strength ≥ 0.8
-    pub partial_matches: Vec<FitMatch>, // 0.4 – 0.79
-    pub gaps: Vec<Gap>,                 // strength < 0.4
-    pub recommendation: String,
-    pub scorer_backend: String, // "keyword" | "llm" — for transparency
-}
+pub use templar_types::fit::{FitMatch, FitReport, Gap};
 
 // ────────────────────────────────────────────────────────────────────────────
 // Trait definition
@@ -359,9 +333,9 @@ fn build_recommendation(score: u32, gaps: &[Gap]) -> String {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::generation::jd_parser::{JDTone, KeywordEntry, ParsedJD, Requirement, RoleSignals};
     use chrono::Utc;
     use serde_json::json;
+    use templar_types::jd::{JDTone, KeywordEntry, ParsedJD, Requirement, RoleSignals};
     use uuid::Uuid;
 
     fn make_entry(entry_id: Uuid, tags: Vec<String>, raw_text: Option<String>) -> ContextEntryRow {
@@ -381,6 +355,7 @@ mod tests {
             quality_score: 1.0,
             quality_flags: vec![],
             created_at: Utc::now(),
+            recency_policy: None,
         }
     }
 
@@ -1,7 +1,8 @@
 //! Resume Generation — orchestrates the full generation pipeline.
 //!
-//! Flow: parse_jd → get_current_entries → fit_score → select_content →
-//!       tone calibration → LLM generate → layout simulation → grounding → persist to DB → return response.
+//! Flow: (parse_jd ∥ get_current_entries) → select_content →
+//!       (fit_score ∥ tone calibration → LLM generate) → layout simulation → grounding →
+//!       persist to DB → return response.
 //!
 //! Phase 3 inserts a simulation loop between LLM draft generation and DB persistence.
 //! Bullets that fail the Line Coverage Contract are expanded or compressed (max 3 passes),
@@ -10,72 +11,115 @@
 //! Phase 5 inserts a grounding loop between layout simulation and DB persistence.
 //! Bullets with composite grounding score < 0.65 are regenerated once; if still failing,
 //! kept with flagged_for_review=true. grounding_score is persisted with real values.
+//!
+//! With an offline `LlmClient` (OFFLINE_MODE) the pipeline makes no LLM call: the JD
+//! goes through the deterministic extractor, bullets come from the template builder,
+//! layout relies on the local compressor, and grounding is verbatim.
 
 use std::collections::HashSet;
 
-use serde::{Deserialize, Serialize};
+use serde::Deserialize;
 use sqlx::PgPool;
 use tracing::{info, warn};
 use uuid::Uuid;
+use validator::Validate;
 
-use crate::context::versioning::get_current_entries;
+use crate::bullet_revisions;
+use crate::context::versioning::{get_context_revision, get_current_entries};
 use crate::errors::AppError;
-use crate::generation::content_selector::{select_content, SelectionResult};
-use crate::generation::fit_scoring::{FitReport, FitScorer};
-use crate::generation::jd_parser::parse_jd;
+use crate::generation::acronyms::check_acronyms;
+use crate::generation::bullet_dedup::dedup_draft_bullets;
+use crate::generation::bullet_lint::lint_bullets;
+use crate::generation::content_selector::{select_content, select_pinned, SelectionResult};
+use crate::generation::domain_pack::resolve_pack;
+use crate::generation::explanation::{build_explanation, ToneSource};
+use crate::generation::fit_scoring::FitScorer;
+use crate::generation::jd_parser::{parse_jd, ParsedJD};
+use crate::generation::keyword_coverage::{compute_keyword_coverage, keyword_spans, KeywordSpan};
+use crate::generation::keyword_stuffing::{check_keyword_stuffing, rewrite_stuffed_bullets};
+use crate::generation::layout_report::build_layout_report;
+use crate::generation::pipeline_cache;
+use crate::generation::prompt_versions::prompt_versions;
 use crate::generation::prompts::{GENERATION_PROMPT_TEMPLATE, GENERATION_SYSTEM};
-use crate::generation::tone::{get_tone_examples, ToneExamples};
+use crate::generation::static_sections::{build_static_sections, StaticSections};
+use crate::generation::template_bullets::build_template_bullets;
+use crate::generation::tone::{get_tone_examples, SeniorityLevel, ToneExamples};
 use crate::grounding::scorer::{regenerate_single_bullet, score_bullet};
 use crate::grounding::types::{GroundingResult, GroundingVerdict};
-use crate::layout::{run_simulation_loop, PageConfig, SimulatedBullet};
+use crate::jobs;
+use crate::layout::fill_remediation::run_page_fill_loop;
+use crate::layout::local_expand::{EntryFacts, SourceFacts};
+use crate::layout::page_fill::{analyze_page_fill_with_reserved, FillAction, PageFillVerdict};
+use crate::layout::simulator::SimulationResult;
+use crate::layout::{run_simulation_loop, MarginPreset, PageConfig, PaperSize, SimulatedBullet};
 use crate::llm_client::prompts::{GROUNDING_INSTRUCTION, SCOPE_INSTRUCTION};
 use crate::llm_client::LlmClient;
 use crate::models::context::ContextEntryRow;
+use crate::progress::{self, Stage};
+use crate::render::RenderJob;
+use crate::target_roles;
+use crate::users::preferences;
+use crate::validation::{FieldError, MAX_JD_TEXT_CHARS};
 
 /// Max LLM retries when bullets are missing source_entry_id.
 const MAX_GENERATION_RETRIES: u32 = 2;
 
+/// Most entries a request may hand-pick (`GenerateRequest::entry_ids`).
+pub const MAX_PINNED_ENTRIES: u64 = 40;
+
 // ────────────────────────────────────────────────────────────────────────────
 // Data models
 // ────────────────────────────────────────────────────────────────────────────
 
-/// A single draft resume bullet produced by the generation LLM call.
-///
-/// CRITICAL: every bullet MUST carry `source_entry_id` — bullets without it are rejected.
-/// `line_estimate` is the LLM's guess only — NOT trusted for layout (Phase 3 enforces).
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct DraftBullet {
-    pub text: String,
-    pub source_entry_id: Uuid,
-    pub section: String,
-    /// LLM estimate only — layout Phase 3 will re-simulate. Must be 1 or 2.
-    pub line_estimate: u8,
-    pub jd_keywords_used: Vec<String>,
-}
+pub use templar_types::generation::{DraftBullet, GenerateResponse};
 
 /// Request body for resume generation.
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Deserialize, Validate)]
 pub struct GenerateRequest {
+    /// Set from the authenticated caller (or the regenerated/batched resume's owner) —
+    /// never accepted from the request body.
+    #[serde(skip)]
     pub user_id: Uuid,
+    /// Required unless `target_role_id` is given.
+    #[serde(default)]
+    #[validate(length(max = MAX_JD_TEXT_CHARS))]
     pub jd_text: String,
-    // Reserved for Phase 7 persona-aware generation
-    #[allow(dead_code)]
+    /// One of the caller's target roles to generate for, in place of `jd_text`: its JD
+    /// text, and its stored parse when there is one, are used.
+    #[serde(default)]
+    pub target_role_id: Option<Uuid>,
+    /// A persona of the caller's whose domain pack, when set, replaces the user's
+    /// saved one. Its tags and tone preference are not applied yet.
     pub persona_id: Option<Uuid>,
     // Reserved for Phase 7 tone override
     #[allow(dead_code)]
     pub tone_override: Option<String>,
-}
-
-/// Response from the generation pipeline.
-///
-/// Phase 3: `bullets` now contains `SimulatedBullet` with `verified_line_count`,
-/// `was_adjusted`, and `flagged_for_review` fields populated by the simulation loop.
-#[derive(Debug, Clone, Serialize)]
-pub struct GenerateResponse {
-    pub resume_id: Uuid,
-    pub fit_report: FitReport,
-    pub bullets: Vec<SimulatedBullet>,
-    pub status: String,
+    /// Set internally by the regenerate endpoint — never accepted from the request body.
+    /// The new row links to this parent and inherits its template_id and target_role_id.
+    #[serde(skip)]
+    pub parent_resume_id: Option<Uuid>,
+    /// Paper to lay out and render on. None = the server's default page config
+    /// (or, on regenerate, the parent's paper).
+    #[serde(default)]
+    pub paper_size: Option<PaperSize>,
+    /// Margin preset to lay out and render with. None = the server's default page config
+    /// (or, on regenerate, the parent's preset).
+    #[serde(default)]
+    pub margin_preset: Option<MarginPreset>,
+    /// Client-chosen id to follow this generation's progress at
+    /// `GET /api/v1/events/:id` (open the stream before or right after POSTing).
+    #[serde(default)]
+    pub progress_id: Option<Uuid>,
+    /// Rewrite bullets flagged for keyword stuffing once, after layout. Off by default:
+    /// flags are always reported in `keyword_stuffing`. Ignored offline.
+    #[serde(default)]
+    pub rewrite_stuffed_bullets: bool,
+    /// Context entries to build the resume from, in place of automatic selection:
+    /// exactly these are drafted, whatever their scores or section. Each must be one of
+    /// the caller's current entries.
+    #[serde(default)]
+    #[validate(length(min = 1, max = MAX_PINNED_ENTRIES))]
+    pub entry_ids: Option<Vec<Uuid>>,
 }
 
 // ────────────────────────────────────────────────────────────────────────────
@@ -88,52 +132,258 @@ pub struct GenerateResponse {
 /// 1. parse_jd() → ParsedJD
 /// 2. get_current_entries() → Vec<ContextEntryRow>
 /// 3. fit_scorer.score() → FitReport
-/// 4. select_content() → SelectionResult
+/// 4. select_content() → SelectionResult (select_pinned() when `entry_ids` is given)
 /// 5. tone calibration → ToneExamples
 /// 6. LLM generate → Vec<DraftBullet> (retried if any bullet lacks source_entry_id)
+///
+/// 6b. Near-duplicate dedup → drops bullets that restate another bullet
+/// 6c. Acronym pass → writes out JD acronyms the bullets use in only one form
 /// 7. Layout simulation → Vec<SimulatedBullet> (Phase 3: enforces Line Coverage Contract)
 ///
+/// 7a''. Keyword-stuffing rewrite of flagged bullets, when `rewrite_stuffed_bullets` is set
 /// 7b. Grounding loop (Phase 5): score each bullet; Fail → rewrite once; still Fail → flag
+/// 7c. Deterministic education lines (replacing any LLM-written education bullets)
+/// 7d. Keyword coverage of the final bullets against the JD keyword inventory, the
+///     keyword-stuffing check, style lint of each bullet, and where in each bullet
+///     the JD keywords appear
+/// 7e. Page fill analysis, with the contact header's lines reserved
+/// 7f. Generation explanation: entries kept and cut, tone and its source, keyword drivers
 /// 8. INSERT into resumes (status='draft')
-/// 9. INSERT into resume_bullets (grounding_score now real value, not 0.0 placeholder)
+/// 9. One multi-row INSERT into resume_bullets (grounding_score now real value, not 0.0
+///    placeholder), in the same transaction as step 8
+/// 9b. Each bullet's revision chain into bullet_revisions, in the same transaction
 /// 10. Fire-and-forget render job enqueue (Phase 4; skipped when redis=None for tests)
 ///
+/// Steps 1 and 2 come from the pipeline cache when a fit score for the same JD and
+/// context revision just ran (see `pipeline_cache`); needs `redis`. Otherwise they run
+/// concurrently, as do step 3 and steps 5-6: the fit score only feeds the response and
+/// the resume row, so it is computed after selection and `FitScored` now follows
+/// `ContentSelected`.
+///
 /// `grounding_enabled` controls whether step 7b runs. Pass `true` in production,
 /// `false` in unit tests to skip LLM grounding calls.
+#[allow(clippy::too_many_arguments)]
 pub async fn generate_resume(
     pool: &PgPool,
     llm: &LlmClient,
     fit_scorer: &dyn FitScorer,
     page_config: &PageConfig,
+    adjust_concurrency: usize,
+    redis: Option<&redis::Client>,
+    grounding_enabled: bool,
+    mut request: GenerateRequest,
+) -> Result<GenerateResponse, AppError> {
+    let role_parse = match request.target_role_id {
+        Some(_) if !request.jd_text.is_empty() => {
+            return Err(AppError::Validation(
+                "Give either jd_text or target_role_id, not both".to_string(),
+            ));
+        }
+        Some(role_id) => {
+            let (jd_text, parsed_jd) =
+                target_roles::load_jd(pool, request.user_id, role_id).await?;
+            request.jd_text = jd_text;
+            parsed_jd
+        }
+        None if request.jd_text.trim().is_empty() => {
+            return Err(AppError::InvalidFields(vec![FieldError {
+                field: "jd_text".to_string(),
+                code: "blank".to_string(),
+                message: "must not be blank".to_string(),
+            }]));
+        }
+        None => None,
+    };
+
+    let cached = match redis {
+        Some(redis) => {
+            let revision = get_context_revision(pool, request.user_id)
+                .await
+                .map_err(AppError::Internal)?;
+            pipeline_cache::load(redis, request.user_id, &request.jd_text, revision).await
+        }
+        None => None,
+    };
+    let (entries, parsed_jd) = match cached {
+        Some(cached) => {
+            info!("Pipeline cache hit for user {}", request.user_id);
+            (cached.entries, Some(cached.parsed_jd))
+        }
+        None => {
+            // Steps 1 and 2 are independent: parse the JD while the entries load. A
+            // target role's stored parse stands in for step 1, and a fresh parse is
+            // stored on the role for next time.
+            let (entries, parsed_jd) = tokio::try_join!(
+                async {
+                    get_current_entries(pool, request.user_id)
+                        .await
+                        .map_err(AppError::Internal)
+                },
+                async {
+                    if let Some(parsed_jd) = role_parse {
+                        return Ok(parsed_jd);
+                    }
+                    let parsed_jd = parse_jd(&request.jd_text, llm).await?;
+                    if let Some(role_id) = request.target_role_id {
+                        if let Err(e) =
+                            target_roles::store_parse(pool, role_id, &request.jd_text, &parsed_jd)
+                                .await
+                        {
+                            warn!(%role_id, "Failed to store target role parse: {e}");
+                        }
+                    }
+                    Ok(parsed_jd)
+                },
+            )?;
+            (entries, Some(parsed_jd))
+        }
+    };
+
+    run_pipeline(
+        pool,
+        llm,
+        fit_scorer,
+        page_config,
+        adjust_concurrency,
+        redis,
+        grounding_enabled,
+        request,
+        entries,
+        parsed_jd,
+    )
+    .await
+}
+
+/// Same pipeline as [`generate_resume`], but with step 2 already done by the caller.
+///
+/// Batch generation loads the user's context once and runs every JD against the same
+/// snapshot, so all resumes in a batch are generated from identical entries.
+#[allow(clippy::too_many_arguments)]
+pub async fn generate_resume_with_entries(
+    pool: &PgPool,
+    llm: &LlmClient,
+    fit_scorer: &dyn FitScorer,
+    page_config: &PageConfig,
+    adjust_concurrency: usize,
+    redis: Option<&redis::Client>,
+    grounding_enabled: bool,
+    request: GenerateRequest,
+    entries: Vec<ContextEntryRow>,
+) -> Result<GenerateResponse, AppError> {
+    run_pipeline(
+        pool,
+        llm,
+        fit_scorer,
+        page_config,
+        adjust_concurrency,
+        redis,
+        grounding_enabled,
+        request,
+        entries,
+        None,
+    )
+    .await
+}
+
+/// The pipeline from step 1, with `parsed_jd` standing in for the parse when given.
+#[allow(clippy::too_many_arguments)]
+async fn run_pipeline(
+    pool: &PgPool,
+    llm: &LlmClient,
+    fit_scorer: &dyn FitScorer,
+    page_config: &PageConfig,
+    adjust_concurrency: usize,
     redis: Option<&redis::Client>,
     grounding_enabled: bool,
     request: GenerateRequest,
+    entries: Vec<ContextEntryRow>,
+    parsed_jd: Option<ParsedJD>,
 ) -> Result<GenerateResponse, AppError> {
-    // Step 1: Parse JD
-    info!("Parsing JD for user {}", request.user_id);
-    let parsed_jd = parse_jd(&request.jd_text, llm).await?;
+    // Saved defaults apply wherever the request leaves a field unset. Loaded while
+    // the JD is parsed (step 1), when the caller has not parsed it already.
+    let (prefs, mut parsed_jd) =
+        tokio::try_join!(preferences::load(pool, request.user_id), async {
+            match parsed_jd {
+                Some(parsed_jd) => Ok(parsed_jd),
+                None => {
+                    info!("Parsing JD for user {}", request.user_id);
+                    parse_jd(&request.jd_text, llm).await
+                }
+            }
+        },)?;
+
+    // Page geometry follows the requested (or preferred) paper, margins and starting
+    // font size; everything downstream measures against it.
+    let paper_config;
+    let page_config = match request.paper_size.or(prefs.paper_size) {
+        Some(paper) if paper != page_config.paper_size => {
+            paper_config = page_config.with_paper_size(paper);
+            &paper_config
+        }
+        _ => page_config,
+    };
+    let margin_config;
+    let page_config = match request.margin_preset.or(prefs.margin_preset) {
+        Some(preset) if preset != page_config.margin_preset => {
+            margin_config = page_config.with_margin_preset(preset);
+            &margin_config
+        }
+        _ => page_config,
+    };
+    let font_config;
+    let page_config = match prefs.font_size_pt {
+        Some(size) if size != page_config.font_size_pt => {
+            font_config = page_config.with_font_size(size);
+            &font_config
+        }
+        _ => page_config,
+    };
+
+    // Step 1: Parse JD (done above)
+    let tone_source = if let Some(tone) = prefs.tone {
+        // The user's preferred tone wins over the one read from the JD.
+        parsed_jd.detected_tone = tone;
+        ToneSource::Preference
+    } else {
+        ToneSource::Jd
+    };
     info!("JD parsed: tone={:?}", parsed_jd.detected_tone);
+    progress::emit(Stage::JdParsed).await;
 
-    // Step 2: Load current context entries
-    let entries = get_current_entries(pool, request.user_id)
-        .await
-        .map_err(AppError::Internal)?;
+    let pack = resolve_pack(pool, request.user_id, request.persona_id, prefs.domain_pack).await?;
 
+    // Step 2: Context entries (loaded by the caller)
     if entries.is_empty() {
         return Err(AppError::Validation(
             "No context entries found. Add context before generating a resume.".to_string(),
         ));
     }
 
-    // Step 3: Fit score
-    let fit_report = fit_scorer.score(&entries, &parsed_jd).await?;
-    info!(
-        "Fit score: {}/100 for user {}",
-        fit_report.overall_score, request.user_id
-    );
-
-    // Step 4: Content selection
-    let selection = select_content(entries, &parsed_jd);
+    // Education lines, the summary and header accounting are deterministic — built from
+    // the full entry set before selection, since they are always printed.
+    let static_sections = build_static_sections(&entries, page_config);
+
+    // Step 4: Content selection, or the caller's hand-picked entries. Selection takes
+    // its own copy of the entries so the fit scorer can read them while the bullets are
+    // drafted.
+    let weights = prefs.scoring_weights.unwrap_or_default();
+    let selection = match &request.entry_ids {
+        Some(entry_ids) => {
+            let unknown: Vec<String> = entry_ids
+                .iter()
+                .filter(|id| !entries.iter().any(|e| e.entry_id == **id))
+                .map(Uuid::to_string)
+                .collect();
+            if !unknown.is_empty() {
+                return Err(AppError::Validation(format!(
+                    "Unknown context entries: {}",
+                    unknown.join(", ")
+                )));
+            }
+            select_pinned(entries.clone(), entry_ids, &parsed_jd, &weights)
+        }
+        None => select_content(entries.clone(), &parsed_jd, &weights),
+    };
     info!(
         "Selected {} entries for generation",
         selection.selected_entries.len()
@@ -144,33 +394,126 @@ pub async fn generate_resume(
             "No context entries passed selection. Ensure context entries have scores above threshold.".to_string(),
         ));
     }
+    progress::emit(Stage::ContentSelected {
+        entries: selection.selected_entries.len(),
+    })
+    .await;
+
+    // Step 3 (fit score) runs alongside steps 5 and 6 (tone calibration and drafting):
+    // with the LLM scorer both are LLM round trips, and neither reads the other.
+    let (fit_report, draft_bullets) = tokio::try_join!(
+        async {
+            let fit_report = fit_scorer.score(&entries, &parsed_jd).await?;
+            info!(
+                "Fit score: {}/100 for user {}",
+                fit_report.overall_score, request.user_id
+            );
+            progress::emit(Stage::FitScored {
+                score: fit_report.overall_score,
+            })
+            .await;
+            Ok(fit_report)
+        },
+        async {
+            // Step 5: Tone calibration
+            let tone_examples = get_tone_examples(&parsed_jd.detected_tone, pack);
+
+            // Step 6: LLM generation with retry on missing source_entry_id. Offline, the
+            // template builder takes the entries' own bullets instead.
+            if llm.is_offline() {
+                Ok(build_template_bullets(&selection, &parsed_jd))
+            } else {
+                call_llm_with_retry(llm, &parsed_jd, &selection, &tone_examples).await
+            }
+        },
+    )?;
+
+    // Step 6b: Collapse near-duplicate bullets (e.g. experience + open_source entries
+    // describing the same project) before spending layout LLM calls on them.
+    let (mut draft_bullets, dedup_decisions) = dedup_draft_bullets(draft_bullets, &selection);
+    if !dedup_decisions.is_empty() {
+        info!(
+            "Dedup dropped {} near-duplicate bullets for user {}",
+            dedup_decisions.len(),
+            request.user_id
+        );
+    }
 
-    // Step 5: Tone calibration
-    let tone_examples = get_tone_examples(&parsed_jd.detected_tone);
-
-    // Step 6: LLM generation with retry on missing source_entry_id
-    let draft_bullets = call_llm_with_retry(llm, &parsed_jd, &selection, &tone_examples).await?;
+    // Step 6c: Write out JD acronyms the bullets only use in one form, before layout
+    // measures them.
+    let acronyms = check_acronyms(&mut draft_bullets, &request.jd_text);
+    if !acronyms.rewrites.is_empty() || !acronyms.flags.is_empty() {
+        info!(
+            rewrites = acronyms.rewrites.len(),
+            flags = acronyms.flags.len(),
+            "Acronym pass changed or flagged bullets"
+        );
+    }
+    progress::emit(Stage::BulletsGenerated {
+        bullets: draft_bullets.len(),
+    })
+    .await;
 
     // Step 7: Layout simulation — enforces Line Coverage Contract.
     // Replaces LLM's line_estimate with simulation-verified line counts.
     // Bullets that fail after max passes are flagged for human review (not rejected).
-    let simulation = run_simulation_loop(draft_bullets, page_config, &parsed_jd, llm).await?;
+    // Step 7a: Page fill remediation — promote, compress or drop bullets until the page fits.
+    // Bullets the LLM does not expand are lengthened with their source entry's facts.
+    let sources = EntryFacts::collect(
+        selection.selected_entries.iter().map(|r| &r.entry),
+        chrono::Utc::now().date_naive(),
+    );
+    let layout = lay_out_page(
+        draft_bullets.clone(),
+        static_sections.clone(),
+        page_config.clone(),
+        adjust_concurrency,
+        &parsed_jd,
+        &sources,
+        llm,
+    )
+    .await?;
 
-    if simulation.flagged_count > 0 {
-        warn!(
-            resume_id = %"pending",
-            flagged = simulation.flagged_count,
-            passes = simulation.total_passes,
-            llm_calls = simulation.llm_calls_made,
-            "layout simulation: bullets flagged for human review after max passes"
-        );
-    }
+    // Step 7a': Font-size fallback when remediation could not fix the page.
+    let PageLayout {
+        config: page_config,
+        static_sections,
+        mut simulation,
+        fill_actions,
+        ..
+    } = fit_font_size(
+        layout,
+        &draft_bullets,
+        &static_sections,
+        adjust_concurrency,
+        &parsed_jd,
+        &sources,
+        llm,
+    )
+    .await?;
+    let page_config = &page_config;
+
+    // Step 7a'': Opt-in rewrite of bullets stuffed with JD keywords their source lacks.
+    // Runs before grounding so the rewrites are scored like any other bullet.
+    let stuffing_rewrites = if request.rewrite_stuffed_bullets && !llm.is_offline() {
+        rewrite_stuffed_bullets(&mut simulation.bullets, &parsed_jd, &entries, llm).await
+    } else {
+        0
+    };
 
     // Step 7b: Grounding loop (Phase 5).
     // Score each simulated bullet against its source context entry.
     // Fail verdict → attempt one LLM rewrite → re-score → if still Fail, keep with flag.
     // grounding_enabled=false in unit tests skips all LLM grounding calls.
-    let grounding_pairs: Vec<(SimulatedBullet, GroundingResult)> = if grounding_enabled {
+    // Offline bullets are entry text (at most locally compressed), so they are scored
+    // as verbatim rather than sent to the LLM grader.
+    let mut grounding_pairs: Vec<(SimulatedBullet, GroundingResult)> = if llm.is_offline() {
+        simulation
+            .bullets
+            .iter()
+            .map(|b| (b.clone(), verbatim_grounding(b)))
+            .collect()
+    } else if grounding_enabled {
         run_grounding_loop(&simulation.bullets, &selection.selected_entries, llm).await?
     } else {
         // Grounding disabled (unit tests): assign placeholder score 0.0 to all bullets.
@@ -193,46 +536,187 @@ pub async fn generate_resume(
             .collect()
     };
 
+    if grounding_enabled {
+        progress::emit(Stage::GroundingChecked {
+            bullets: grounding_pairs.len(),
+        })
+        .await;
+    }
+
+    // Bullets are persisted and returned in this order; `position` records it.
+    order_by_entry_score(&mut grounding_pairs, &selection);
+
+    // Step 7c: Deterministic summary and education lines, appended after the generated
+    // bullets. They replace any such bullets the LLM wrote, which would duplicate them.
+    grounding_pairs.retain(|(b, _)| !static_sections.replaces(&b.section));
+    grounding_pairs.extend(static_sections.fixed_lines().into_iter().map(|b| {
+        let grounding = verbatim_grounding(&b);
+        (b, grounding)
+    }));
+
+    // Step 7d: Keyword coverage, keyword stuffing and style lint on the final bullet text
+    // (post-grounding rewrites).
+    let final_bullets: Vec<SimulatedBullet> =
+        grounding_pairs.iter().map(|(b, _)| b.clone()).collect();
+    let keyword_coverage = compute_keyword_coverage(&parsed_jd, &final_bullets);
+    if !keyword_coverage.missing_keywords.is_empty() {
+        info!(
+            missing = ?keyword_coverage.missing_keywords,
+            weighted_coverage = keyword_coverage.weighted_coverage,
+            "JD keywords missing from generated bullets"
+        );
+    }
+    let mut keyword_stuffing = check_keyword_stuffing(&parsed_jd, &final_bullets, &entries);
+    keyword_stuffing.rewritten = stuffing_rewrites;
+    if !keyword_stuffing.stuffed_bullets.is_empty()
+        || !keyword_stuffing.repeated_keywords.is_empty()
+    {
+        info!(
+            stuffed = keyword_stuffing.stuffed_bullets.len(),
+            repeated = ?keyword_stuffing
+                .repeated_keywords
+                .iter()
+                .map(|k| &k.keyword)
+                .collect::<Vec<_>>(),
+            "JD keyword stuffing in generated bullets"
+        );
+    }
+    let bullet_lint = lint_bullets(&final_bullets, &entries, chrono::Utc::now().date_naive());
+    let keyword_spans: Vec<_> = final_bullets
+        .iter()
+        .map(|b| keyword_spans(&parsed_jd, &b.text))
+        .collect();
+
+    // Step 7e: Page fill over everything printed — bullets, education, and the header.
+    let page_fill = analyze_page_fill_with_reserved(
+        &final_bullets,
+        static_sections.header_line_count,
+        page_config,
+    );
+    info!(
+        lines_used = page_fill.total_lines_used,
+        lines_available = page_fill.total_lines_available,
+        header_lines = static_sections.header_line_count,
+        verdict = ?page_fill.verdict,
+        "Page fill after generation"
+    );
+    let layout_report = build_layout_report(
+        &grounding_pairs,
+        &simulation,
+        &fill_actions,
+        &page_fill,
+        page_config,
+    );
+
+    // Step 7f: Why the resume looks this way — selection, tone and keyword drivers.
+    let explanation = build_explanation(
+        &selection,
+        &entries,
+        &parsed_jd,
+        tone_source,
+        pack,
+        &final_bullets,
+    );
+
     // Step 8: Persist resume row
     let resume_id = Uuid::new_v4();
     let jd_parsed_value = serde_json::to_value(&parsed_jd)
         .map_err(|e| AppError::Internal(anyhow::anyhow!("Failed to serialize ParsedJD: {e}")))?;
+    let keyword_coverage_value = serde_json::to_value(&keyword_coverage).map_err(|e| {
+        AppError::Internal(anyhow::anyhow!("Failed to serialize CoverageReport: {e}"))
+    })?;
+    let layout_report_value = serde_json::to_value(&layout_report).map_err(|e| {
+        AppError::Internal(anyhow::anyhow!("Failed to serialize LayoutReport: {e}"))
+    })?;
     let fit_score = fit_report.overall_score as f64 / 100.0;
-
-    sqlx::query(
+    let prompt_versions_value = serde_json::to_value(prompt_versions()).map_err(|e| {
+        AppError::Internal(anyhow::anyhow!("Failed to serialize prompt versions: {e}"))
+    })?;
+    let explanation_value = serde_json::to_value(&explanation).map_err(|e| {
+        AppError::Internal(anyhow::anyhow!(
+            "Failed to serialize GenerationExplanation: {e}"
+        ))
+    })?;
+
+    // Steps 8 and 9 commit together: a resume row is never visible without its bullets.
+    let mut tx = pool.begin().await?;
+    sqlx::query!(
         r#"
-        INSERT INTO resumes (id, user_id, jd_text, jd_parsed, fit_score, status)
-        VALUES ($1, $2, $3, $4, $5, 'draft')
+        INSERT INTO resumes
+            (id, user_id, jd_text, jd_parsed, fit_score, keyword_coverage, status,
+             parent_resume_id, template_id, paper_size, font_size_pt, layout_report,
+             margin_preset, font_family, margin_left_in, margin_right_in, margin_top_in,
+             margin_bottom_in, prompt_versions, target_role_id, explanation)
+        VALUES ($1, $2, $3, $4, $5, $6, 'draft',
+                $7,
+                CASE WHEN $7::uuid IS NULL THEN $12
+                     ELSE (SELECT template_id FROM resumes WHERE id = $7) END,
+                $8, $9, $10, $11, $13, $14, $15, $16, $17, $18,
+                CASE WHEN $7::uuid IS NULL THEN $19
+                     ELSE (SELECT target_role_id FROM resumes WHERE id = $7) END,
+                $20)
         "#,
+        resume_id,
+        request.user_id,
+        &request.jd_text,
+        &jd_parsed_value,
+        fit_score,
+        &keyword_coverage_value,
+        request.parent_resume_id,
+        page_config.paper_size.as_str(),
+        page_config.font_size_pt,
+        &layout_report_value,
+        page_config.margin_preset.as_str(),
+        prefs.template_id,
+        page_config.font.as_str(),
+        page_config.margin_left_in,
+        page_config.margin_right_in,
+        page_config.margin_top_in,
+        page_config.margin_bottom_in,
+        prompt_versions_value,
+        request.target_role_id,
+        explanation_value
     )
-    .bind(resume_id)
-    .bind(request.user_id)
-    .bind(&request.jd_text)
-    .bind(&jd_parsed_value)
-    .bind(fit_score)
-    .execute(pool)
+    .execute(&mut *tx)
     .await?;
 
-    // Step 9: Persist simulated bullets with real grounding scores.
+    // Step 9: Persist simulated bullets with real grounding scores, in one INSERT.
     // Uses sim_bullet.text (post-adjustment), sim_bullet.verified_line_count,
     // and the actual composite grounding score from step 7b.
-    for (sim_bullet, grounding_result) in &grounding_pairs {
-        sqlx::query(
-            r#"
-            INSERT INTO resume_bullets
-                (resume_id, section, bullet_text, source_entry_id, grounding_score, line_count)
-            VALUES ($1, $2, $3, $4, $5, $6)
-            "#,
-        )
-        .bind(resume_id)
-        .bind(&sim_bullet.section)
-        .bind(&sim_bullet.text)
-        .bind(sim_bullet.source_entry_id)
-        .bind(grounding_result.score.composite as f64)
-        .bind(sim_bullet.verified_line_count as i16)
-        .execute(pool)
-        .await?;
-    }
+    let rows = BulletRows::new(&grounding_pairs, &keyword_spans)?;
+    let mut inserted = sqlx::query!(
+        r#"
+        INSERT INTO resume_bullets
+            (resume_id, section, bullet_text, source_entry_id, grounding_score, line_count, position,
+             keyword_spans)
+        SELECT $1, b.section, b.bullet_text, b.source_entry_id, b.grounding_score, b.line_count,
+               b.position, b.keyword_spans
+        FROM UNNEST($2::text[], $3::text[], $4::uuid[], $5::float8[], $6::int2[], $7::int4[],
+                    $8::jsonb[])
+            AS b(section, bullet_text, source_entry_id, grounding_score, line_count, position,
+                 keyword_spans)
+        RETURNING id, position
+        "#,
+        resume_id,
+        &rows.sections,
+        &rows.texts,
+        &rows.source_entry_ids,
+        &rows.grounding_scores,
+        &rows.line_counts,
+        &rows.positions,
+        &rows.keyword_spans
+    )
+    .fetch_all(&mut *tx)
+    .await?;
+    // RETURNING order is not guaranteed; positions are.
+    inserted.sort_by_key(|row| row.position);
+    let bullet_ids: Vec<Uuid> = inserted.into_iter().map(|row| row.id).collect();
+
+    // Step 9b: Each bullet's revision chain — its draft and every pipeline rewrite.
+    bullet_revisions::insert_generated(&mut tx, &bullet_ids, &final_bullets).await?;
+    tx.commit().await?;
+
+    progress::emit(Stage::ResumeSaved { resume_id }).await;
 
     let grounding_pass_count = grounding_pairs
         .iter()
@@ -261,35 +745,97 @@ pub async fn generate_resume(
         let job_id = Uuid::new_v4();
 
         // Insert render_jobs row (queued)
-        sqlx::query("INSERT INTO render_jobs (id, resume_id, status) VALUES ($1, $2, 'queued')")
-            .bind(job_id)
-            .bind(resume_id)
-            .execute(pool)
-            .await?;
-
-        // Enqueue via spawn_blocking (enqueue_render_job_sync is a synchronous Redis call)
-        let redis_for_enqueue = redis_client.clone();
-        tokio::task::spawn_blocking(move || {
-            if let Err(e) =
-                crate::render::worker::enqueue_render_job_sync(&redis_for_enqueue, job_id)
-            {
-                warn!("Failed to enqueue render job {job_id} for resume {resume_id}: {e}");
-            }
-        });
+        sqlx::query!(
+            "INSERT INTO render_jobs (id, resume_id, status) VALUES ($1, $2, 'queued')",
+            job_id,
+            resume_id
+        )
+        .execute(pool)
+        .await?;
+
+        // An enqueue failure leaves the render 'queued'; the resume itself is saved.
+        if let Err(e) = jobs::enqueue(redis_client, &RenderJob { job_id }).await {
+            warn!("Failed to enqueue render job {job_id} for resume {resume_id}: {e}");
+        }
 
         info!("Enqueued render job {} for resume {}", job_id, resume_id);
+        progress::emit(Stage::RenderQueued {
+            render_job_id: job_id,
+        })
+        .await;
     }
 
-    let final_bullets: Vec<SimulatedBullet> = grounding_pairs.into_iter().map(|(b, _)| b).collect();
-
     Ok(GenerateResponse {
         resume_id,
         fit_report,
         bullets: final_bullets,
+        bullet_ids,
+        keyword_coverage,
+        keyword_spans,
+        acronyms,
+        bullet_lint,
+        keyword_stuffing,
         status: "draft".to_string(),
     })
 }
 
+/// `resume_bullets` columns for the step 9 INSERT, one array per column (UNNEST
+/// zips them back into rows). Position is the index in `pairs`; `spans` is in the same
+/// order.
+struct BulletRows {
+    sections: Vec<String>,
+    texts: Vec<String>,
+    source_entry_ids: Vec<Uuid>,
+    grounding_scores: Vec<f64>,
+    line_counts: Vec<i16>,
+    positions: Vec<i32>,
+    keyword_spans: Vec<serde_json::Value>,
+}
+
+impl BulletRows {
+    fn new(
+        pairs: &[(SimulatedBullet, GroundingResult)],
+        spans: &[Vec<KeywordSpan>],
+    ) -> Result<Self, AppError> {
+        let mut rows = BulletRows {
+            sections: Vec::with_capacity(pairs.len()),
+            texts: Vec::with_capacity(pairs.len()),
+            source_entry_ids: Vec::with_capacity(pairs.len()),
+            grounding_scores: Vec::with_capacity(pairs.len()),
+            line_counts: Vec::with_capacity(pairs.len()),
+            positions: Vec::with_capacity(pairs.len()),
+            keyword_spans: Vec::with_capacity(pairs.len()),
+        };
+        for (position, (bullet, grounding)) in pairs.iter().enumerate() {
+            rows.sections.push(bullet.section.clone());
+            rows.texts.push(bullet.text.clone());
+            rows.source_entry_ids.push(bullet.source_entry_id);
+            rows.grounding_scores.push(grounding.score.composite as f64);
+            rows.line_counts.push(bullet.verified_line_count as i16);
+            rows.positions.push(position as i32);
+        }
+        for bullet_spans in spans {
+            rows.keyword_spans
+                .push(serde_json::to_value(bullet_spans).map_err(|e| {
+                    AppError::Internal(anyhow::anyhow!("Failed to serialize keyword spans: {e}"))
+                })?);
+        }
+        Ok(rows)
+    }
+}
+
+/// Grounding result for text copied verbatim from a context entry (no LLM involved).
+fn verbatim_grounding(bullet: &SimulatedBullet) -> GroundingResult {
+    let score = crate::grounding::types::GroundingScore::compute(1.0, 1.0, 1.0, 0.0);
+    GroundingResult {
+        bullet_text: bullet.text.clone(),
+        source_entry_id: bullet.source_entry_id,
+        verdict: score.verdict(),
+        score,
+        rejection_reason: None,
+    }
+}
+
 // ────────────────────────────────────────────────────────────────────────────
 // LLM call with retry
 // ────────────────────────────────────────────────────────────────────────────
@@ -351,6 +897,137 @@ async fn call_llm_with_retry(
     )))
 }
 
+// ────────────────────────────────────────────────────────────────────────────
+// Layout (Phase 3)
+// ────────────────────────────────────────────────────────────────────────────
+
+/// One complete layout attempt at a fixed page config.
+struct PageLayout {
+    config: PageConfig,
+    /// Summary, education and header, measured and contract-checked at `config`.
+    static_sections: StaticSections,
+    simulation: SimulationResult,
+    fill_verdict: PageFillVerdict,
+    /// Remediation actions the fill loop attempted at `config`.
+    fill_actions: Vec<FillAction>,
+}
+
+/// Runs the line simulation and page-fill remediation on `drafts` at `config`, and
+/// checks the static lines against their line contracts.
+async fn lay_out_page(
+    drafts: Vec<DraftBullet>,
+    mut static_sections: StaticSections,
+    config: PageConfig,
+    adjust_concurrency: usize,
+    parsed_jd: &ParsedJD,
+    sources: &SourceFacts,
+    llm: &LlmClient,
+) -> Result<PageLayout, AppError> {
+    let mut simulation =
+        run_simulation_loop(drafts, &config, adjust_concurrency, parsed_jd, sources, llm).await?;
+    static_sections.check_contracts(&config);
+
+    if simulation.flagged_count > 0 {
+        warn!(
+            resume_id = %"pending",
+            flagged = simulation.flagged_count,
+            passes = simulation.total_passes,
+            llm_calls = simulation.llm_calls_made,
+            "layout simulation: bullets flagged for human review after max passes"
+        );
+    }
+
+    // Deterministic summary and education lines replace generated ones (step 7c), so
+    // they are measured as fixed content instead.
+    simulation
+        .bullets
+        .retain(|b| !static_sections.replaces(&b.section));
+    let fixed_lines = static_sections.fixed_lines();
+    let fill = run_page_fill_loop(
+        std::mem::take(&mut simulation.bullets),
+        &fixed_lines,
+        static_sections.header_line_count,
+        &config,
+        parsed_jd,
+        llm,
+    )
+    .await?;
+    simulation.bullets = fill.bullets;
+    simulation.llm_calls_made += fill.llm_calls_made;
+
+    Ok(PageLayout {
+        config,
+        static_sections,
+        simulation,
+        fill_verdict: fill.analysis.verdict,
+        fill_actions: fill.actions,
+    })
+}
+
+/// Steps the font size through `FONT_SIZE_STEPS_PT` when remediation left the page in
+/// MajorOverflow (smaller type) or TooMuchWhitespace (larger type), re-running the
+/// layout from the draft bullets at each size.
+///
+/// Stepping down stops once the page no longer majorly overflows. Stepping up stops
+/// once the whitespace is gone, or keeps the previous size if the larger type
+/// overflows. LLM calls of every attempt are counted in the returned simulation.
+async fn fit_font_size(
+    first: PageLayout,
+    drafts: &[DraftBullet],
+    static_sections: &StaticSections,
+    adjust_concurrency: usize,
+    parsed_jd: &ParsedJD,
+    sources: &SourceFacts,
+    llm: &LlmClient,
+) -> Result<PageLayout, AppError> {
+    let (smaller, persisting) = match first.fill_verdict {
+        PageFillVerdict::MajorOverflow => (true, PageFillVerdict::MajorOverflow),
+        PageFillVerdict::TooMuchWhitespace => (false, PageFillVerdict::TooMuchWhitespace),
+        _ => return Ok(first),
+    };
+
+    let mut best = first;
+    let mut discarded_llm_calls = 0u32;
+    while let Some(font_size_pt) = best.config.next_font_size(smaller) {
+        let config = best.config.with_font_size(font_size_pt);
+        let statics = static_sections.remeasured(&config);
+        let mut candidate = lay_out_page(
+            drafts.to_vec(),
+            statics,
+            config,
+            adjust_concurrency,
+            parsed_jd,
+            sources,
+            llm,
+        )
+        .await?;
+        info!(
+            font_size_pt,
+            verdict = ?candidate.fill_verdict,
+            "Page fill font-size fallback"
+        );
+
+        if !smaller
+            && matches!(
+                candidate.fill_verdict,
+                PageFillVerdict::MinorOverflow | PageFillVerdict::MajorOverflow
+            )
+        {
+            discarded_llm_calls += candidate.simulation.llm_calls_made;
+            break;
+        }
+
+        candidate.simulation.llm_calls_made += best.simulation.llm_calls_made;
+        best = candidate;
+        if best.fill_verdict != persisting {
+            break;
+        }
+    }
+
+    best.simulation.llm_calls_made += discarded_llm_calls;
+    Ok(best)
+}
+
 // ────────────────────────────────────────────────────────────────────────────
 // Grounding loop (Phase 5)
 // ────────────────────────────────────────────────────────────────────────────
@@ -441,6 +1118,30 @@ async fn run_grounding_loop(
     Ok(pairs)
 }
 
+/// Orders bullets by the combined_score of their source entry (highest first).
+///
+/// Stable sort: bullets from the same entry keep the LLM's order, so a two-bullet
+/// entry still reads top-down. Bullets whose entry is missing from the selection
+/// (defensive — the retry loop should prevent this) sink to the bottom.
+fn order_by_entry_score(
+    pairs: &mut [(SimulatedBullet, GroundingResult)],
+    selection: &SelectionResult,
+) {
+    let score_of = |id: &Uuid| {
+        selection
+            .selected_entries
+            .iter()
+            .find(|re| re.entry.entry_id == *id)
+            .map(|re| re.combined_score)
+            .unwrap_or(f64::MIN)
+    };
+    pairs.sort_by(|(a, _), (b, _)| {
+        score_of(&b.source_entry_id)
+            .partial_cmp(&score_of(&a.source_entry_id))
+            .unwrap_or(std::cmp::Ordering::Equal)
+    });
+}
+
 /// Builds the generation prompt by filling the template with serialized context.
 fn build_generation_prompt(
     parsed_jd: &crate::generation::jd_parser::ParsedJD,
@@ -478,6 +1179,7 @@ fn build_generation_prompt(
         "strong_verbs": tone_examples.strong_verbs,
         "ownership_prefix": tone_examples.ownership_prefix,
         "avoid_verbs": tone_examples.avoid_verbs,
+        "sole_owner_verbs": tone_examples.pack.sole_owner_verbs(),
     }))
     .map_err(|e| AppError::Internal(anyhow::anyhow!("Failed to serialize tone: {e}")))?;
 
@@ -493,10 +1195,14 @@ fn build_generation_prompt(
             .join("; ")
     );
 
+    let seniority = SeniorityLevel::from_signal(&parsed_jd.role_signals.seniority);
+
     Ok(GENERATION_PROMPT_TEMPLATE
         .replace("{grounding_instruction}", GROUNDING_INSTRUCTION)
         .replace("{scope_instruction}", SCOPE_INSTRUCTION)
         .replace("{tone_json}", &tone_json)
+        .replace("{domain_instruction}", tone_examples.pack.instruction())
+        .replace("{seniority_instruction}", seniority.instruction())
         .replace("{entries_json}", &entries_json)
         .replace("{keywords_json}", &keywords_json)
         .replace("{jd_summary}", &jd_summary))
@@ -509,6 +1215,7 @@ fn build_generation_prompt(
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::layout::LineType;
 
     #[test]
     fn test_draft_bullet_serializes_and_deserializes() {
@@ -571,6 +1278,141 @@ mod tests {
         let request: GenerateRequest = serde_json::from_value(json).unwrap();
         assert!(!request.jd_text.is_empty());
         assert!(request.persona_id.is_none());
+        assert!(request.parent_resume_id.is_none());
+    }
+
+    #[test]
+    fn test_generate_request_ignores_client_parent_resume_id() {
+        // parent_resume_id is server-controlled; a client must not be able to forge lineage
+        let json = serde_json::json!({
+            "user_id": Uuid::new_v4(),
+            "jd_text": "Rust engineer",
+            "persona_id": null,
+            "tone_override": null,
+            "parent_resume_id": Uuid::new_v4()
+        });
+        let request: GenerateRequest = serde_json::from_value(json).unwrap();
+        assert!(request.parent_resume_id.is_none());
+    }
+
+    #[test]
+    fn test_order_by_entry_score_highest_first_and_stable() {
+        use crate::generation::content_selector::RankedEntry;
+        use crate::grounding::types::GroundingScore;
+
+        let low = Uuid::new_v4();
+        let high = Uuid::new_v4();
+        let ranked = |entry_id: Uuid, combined_score: f64| RankedEntry {
+            entry: ContextEntryRow {
+                id: Uuid::new_v4(),
+                user_id: Uuid::new_v4(),
+                entry_id,
+                version: 1,
+                entry_type: "experience".to_string(),
+                data: serde_json::json!({}),
+                raw_text: None,
+                recency_score: 1.0,
+                impact_score: 0.5,
+                tags: vec![],
+                flagged_evergreen: false,
+                contribution_type: "primary_contributor".to_string(),
+                quality_score: 1.0,
+                quality_flags: vec![],
+                created_at: chrono::Utc::now(),
+                recency_policy: None,
+            },
+            combined_score,
+            jd_relevance: 0.0,
+        };
+        let selection = SelectionResult {
+            selected_entries: vec![ranked(low, 0.2), ranked(high, 0.9)],
+            excluded_entries: vec![],
+            section_weights: std::collections::HashMap::new(),
+            reframe_hints: vec![],
+        };
+        let pair = |text: &str, id: Uuid| {
+            let bullet = SimulatedBullet {
+                text: text.to_string(),
+                source_entry_id: id,
+                section: "experience".to_string(),
+                line_type: LineType::Bullet,
+                verified_line_count: 1,
+                jd_keywords_used: vec![],
+                was_adjusted: false,
+                flagged_for_review: false,
+                revisions: Vec::new(),
+            };
+            let result = GroundingResult {
+                bullet_text: text.to_string(),
+                source_entry_id: id,
+                score: GroundingScore::compute(1.0, 1.0, 1.0, 0.0),
+                verdict: GroundingVerdict::Pass,
+                rejection_reason: None,
+            };
+            (bullet, result)
+        };
+
+        let mut pairs = vec![pair("low", low), pair("high-a", high), pair("high-b", high)];
+        order_by_entry_score(&mut pairs, &selection);
+
+        let order: Vec<&str> = pairs.iter().map(|(b, _)| b.text.as_str()).collect();
+        assert_eq!(order, vec!["high-a", "high-b", "low"]);
+    }
+
+    #[test]
+    fn test_bullet_rows_are_column_arrays_in_position_order() {
+        use crate::grounding::types::GroundingScore;
+
+        let (a, b) = (Uuid::from_u128(1), Uuid::from_u128(2));
+        let pair = |text: &str, id: Uuid, section: &str, lines: u8| {
+            let bullet = SimulatedBullet {
+                text: text.to_string(),
+                source_entry_id: id,
+                section: section.to_string(),
+                line_type: LineType::Bullet,
+                verified_line_count: lines,
+                jd_keywords_used: vec![],
+                was_adjusted: false,
+                flagged_for_review: false,
+                revisions: Vec::new(),
+            };
+            let score = GroundingScore::compute(1.0, 1.0, 1.0, 0.0);
+            let result = GroundingResult {
+                bullet_text: text.to_string(),
+                source_entry_id: id,
+                verdict: score.verdict(),
+                score,
+                rejection_reason: None,
+            };
+            (bullet, result)
+        };
+
+        let pairs = vec![
+            pair("first", a, "experience", 1),
+            pair("second", b, "projects", 2),
+        ];
+        let spans = vec![
+            vec![KeywordSpan {
+                keyword: "First".to_string(),
+                start: 0,
+                end: 5,
+            }],
+            vec![],
+        ];
+        let rows = BulletRows::new(&pairs, &spans).unwrap();
+        assert_eq!(rows.texts, vec!["first", "second"]);
+        assert_eq!(rows.sections, vec!["experience", "projects"]);
+        assert_eq!(rows.source_entry_ids, vec![a, b]);
+        assert_eq!(rows.line_counts, vec![1, 2]);
+        assert_eq!(rows.positions, vec![0, 1]);
+        assert_eq!(rows.grounding_scores.len(), 2);
+        assert_eq!(
+            rows.keyword_spans,
+            vec![
+                serde_json::json!([{ "keyword": "First", "start": 0, "end": 5 }]),
+                serde_json::json!([]),
+            ]
+        );
     }
 
     #[test]
@@ -583,10 +1425,12 @@ mod tests {
             text: "Contributed to distributed caching layer".to_string(),
             source_entry_id: Uuid::new_v4(),
             section: "experience".to_string(),
+            line_type: LineType::Bullet,
             verified_line_count: 1,
             jd_keywords_used: vec!["distributed".to_string()],
             was_adjusted: false,
             flagged_for_review: false,
+            revisions: Vec::new(),
         };
         // When grounding is disabled, composite = 0.0 and verdict = FlagForReview
         let score = crate::grounding::types::GroundingScore::compute(0.0, 0.0, 0.0, 0.0);
@@ -598,4 +1442,73 @@ mod tests {
         // Bullet remains unchanged
         assert_eq!(bullet.text, "Contributed to distributed caching layer");
     }
+
+    fn make_parsed_jd(seniority: &str) -> crate::generation::jd_parser::ParsedJD {
+        use templar_types::jd::{JDTone, ParsedJD, RoleSignals};
+        ParsedJD {
+            hard_requirements: vec![],
+            soft_signals: vec![],
+            role_signals: RoleSignals {
+                is_startup: false,
+                is_ic_focused: true,
+                is_research: false,
+                seniority: seniority.to_string(),
+            },
+            keyword_inventory: vec![],
+            detected_tone: JDTone::ProductOriented,
+        }
+    }
+
+    #[test]
+    fn test_generation_prompt_changes_with_each_seniority() {
+        let selection = SelectionResult {
+            selected_entries: vec![],
+            excluded_entries: vec![],
+            section_weights: std::collections::HashMap::new(),
+            reframe_hints: vec![],
+        };
+        let tone = get_tone_examples(
+            &crate::generation::jd_parser::JDTone::ProductOriented,
+            crate::generation::domain_pack::DomainPack::Engineering,
+        );
+
+        let prompts: Vec<String> = ["junior", "mid", "senior", "staff"]
+            .iter()
+            .map(|s| build_generation_prompt(&make_parsed_jd(s), &selection, &tone).unwrap())
+            .collect();
+
+        for (i, a) in prompts.iter().enumerate() {
+            assert!(
+                !a.contains("{seniority_instruction}"),
+                "placeholder must be filled"
+            );
+            for b in &prompts[i + 1..] {
+                assert_ne!(a, b, "each seniority must produce a different prompt");
+            }
+        }
+        assert!(prompts[0].contains("JUNIOR"));
+        assert!(prompts[3].contains("STAFF / PRINCIPAL"));
+    }
+
+    #[test]
+    fn test_generation_prompt_uses_the_active_domain_pack() {
+        use crate::generation::domain_pack::DomainPack;
+
+        let selection = SelectionResult {
+            selected_entries: vec![],
+            excluded_entries: vec![],
+            section_weights: std::collections::HashMap::new(),
+            reframe_hints: vec![],
+        };
+        let tone = get_tone_examples(
+            &crate::generation::jd_parser::JDTone::ProductOriented,
+            DomainPack::Finance,
+        );
+        let prompt = build_generation_prompt(&make_parsed_jd("mid"), &selection, &tone).unwrap();
+
+        assert!(!prompt.contains("{domain_instruction}"));
+        assert!(prompt.contains("Domain: FINANCE"));
+        assert!(prompt.contains(r#""sole_owner_verbs":["Led","Owned","Headed""#));
+        assert!(prompt.contains("\"Priced\""));
+    }
 }
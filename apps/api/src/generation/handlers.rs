@@ -2,60 +2,72 @@
 
 use axum::{
     extract::{Path, State},
+    http::{HeaderMap, StatusCode},
     Json,
 };
+use std::collections::HashSet;
+
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
-use crate::context::versioning::get_current_entries;
+use crate::audit::{self, Action, AuditEvent};
+use crate::auth::{AuthUser, Resource};
+use crate::context::versioning::{get_context_revision, get_current_entries};
 use crate::errors::AppError;
-use crate::generation::fit_scoring::FitReport;
+use crate::etag::{Conditional, ETag};
+use crate::generation::batch_generation::{
+    create_generation_batch, dedup_jd_texts, get_generation_batch_status, spawn_batch_generation,
+    BatchGenerateRequest, BatchGenerateResponse, BatchGenerationStatus,
+};
+use crate::generation::explanation::GenerationExplanation;
 use crate::generation::generator::{generate_resume, GenerateRequest};
-use crate::generation::jd_parser::{parse_jd, ParsedJD};
-use crate::layout::SimulatedBullet;
+use crate::generation::html_extract::extract_jd_text;
+use crate::generation::interview_prep::{generate_interview_prep, InterviewPrepResponse};
+use crate::generation::jd_parser::parse_jd;
+use crate::generation::linkedin::{generate_linkedin_profile, LinkedInRequest, LinkedInResponse};
+use crate::generation::pipeline_cache::{self, CachedPipeline};
+use crate::layout::{MarginPreset, PaperSize};
 use crate::models::resume::{ResumeBulletRow, ResumeRow};
+use crate::progress;
+use crate::quota::{self, Meter};
 use crate::state::AppState;
+use crate::validation::ValidJson;
 
 // ────────────────────────────────────────────────────────────────────────────
 // Request / Response types
 // ────────────────────────────────────────────────────────────────────────────
 
-#[derive(Debug, Deserialize)]
-pub struct ParseJdRequest {
-    pub jd_text: String,
-}
+pub use templar_types::generation::{
+    FitScoreRequest, FitScoreResponse, GenerateResponse, ParseJdHtmlRequest, ParseJdHtmlResponse,
+    ParseJdRequest, ParseJdResponse,
+};
 
+/// Response for `POST /api/v1/resumes/:id/regenerate` — the new version plus its parent.
 #[derive(Debug, Serialize)]
-pub struct ParseJdResponse {
-    pub parsed_jd: ParsedJD,
+pub struct RegenerateResponse {
+    pub parent_resume_id: Uuid,
+    #[serde(flatten)]
+    pub generation: GenerateResponse,
 }
 
+/// Body for `PATCH /api/v1/resumes/:id/bullets/reorder`.
+/// Must list every bullet of the resume exactly once, in the desired order.
 #[derive(Debug, Deserialize)]
-pub struct FitScoreRequest {
-    pub user_id: Uuid,
-    pub jd_text: String,
+pub struct ReorderBulletsRequest {
+    pub bullet_ids: Vec<Uuid>,
 }
 
 #[derive(Debug, Serialize)]
-pub struct FitScoreResponse {
-    pub fit_report: FitReport,
-    pub parsed_jd: ParsedJD,
-}
-
-#[derive(Debug, Serialize)]
-pub struct GenerateResponse {
-    pub resume_id: Uuid,
-    pub fit_report: FitReport,
-    /// Phase 3: bullets are now `SimulatedBullet` with `verified_line_count`,
-    /// `was_adjusted`, and `flagged_for_review` populated by the simulation loop.
-    pub bullets: Vec<SimulatedBullet>,
-    pub status: String,
+pub struct ReorderBulletsResponse {
+    pub bullets: Vec<ResumeBulletRow>,
 }
 
 #[derive(Debug, Serialize)]
 pub struct ResumeDetailResponse {
     pub resume: ResumeRow,
     pub bullets: Vec<ResumeBulletRow>,
+    /// Review comment threads not yet resolved (see `crate::comments`).
+    pub unresolved_comments: i64,
 }
 
 // ────────────────────────────────────────────────────────────────────────────
@@ -68,40 +80,77 @@ pub struct ResumeDetailResponse {
 /// Useful for previewing extraction before generating.
 pub async fn handle_parse_jd(
     State(state): State<AppState>,
-    Json(request): Json<ParseJdRequest>,
+    ValidJson(request): ValidJson<ParseJdRequest>,
 ) -> Result<Json<ParseJdResponse>, AppError> {
-    if request.jd_text.trim().is_empty() {
-        return Err(AppError::Validation("jd_text cannot be empty".to_string()));
-    }
-
     let parsed_jd = parse_jd(&request.jd_text, &state.llm).await?;
 
     Ok(Json(ParseJdResponse { parsed_jd }))
 }
 
+/// POST /api/v1/resumes/parse-jd-html
+///
+/// Like parse-jd, for a whole job posting page as a browser extension captures it.
+/// The posting text is extracted from the HTML first (see `html_extract`) and
+/// returned alongside the parse, so the caller can generate from it.
+pub async fn handle_parse_jd_html(
+    State(state): State<AppState>,
+    ValidJson(request): ValidJson<ParseJdHtmlRequest>,
+) -> Result<Json<ParseJdHtmlResponse>, AppError> {
+    let jd_text = extract_jd_text(&request.html);
+    if jd_text.is_empty() {
+        return Err(AppError::UnprocessableEntity(
+            "No job posting text found in the page".to_string(),
+        ));
+    }
+    let parsed_jd = parse_jd(&jd_text, &state.llm).await?;
+
+    Ok(Json(ParseJdHtmlResponse { jd_text, parsed_jd }))
+}
+
 /// POST /api/v1/resumes/fit-score
 ///
 /// Returns a fit report for the user's current context against a JD.
 /// Surfaces gaps before generation so the user can decide to add context.
+/// The parsed JD and entries are cached for a `generate` of the same JD that follows.
 pub async fn handle_fit_score(
     State(state): State<AppState>,
-    Json(request): Json<FitScoreRequest>,
+    auth: AuthUser,
+    ValidJson(request): ValidJson<FitScoreRequest>,
 ) -> Result<Json<FitScoreResponse>, AppError> {
-    if request.jd_text.trim().is_empty() {
-        return Err(AppError::Validation("jd_text cannot be empty".to_string()));
-    }
-
-    let parsed_jd = parse_jd(&request.jd_text, &state.llm).await?;
-
-    let entries = get_current_entries(&state.db, request.user_id)
+    // Read before the entries, so a concurrent edit can only make the key older than
+    // what is cached under it, never newer.
+    let revision = get_context_revision(&state.db, auth.user_id)
         .await
         .map_err(AppError::Internal)?;
+    let cached = pipeline_cache::load(&state.redis, auth.user_id, &request.jd_text, revision).await;
+    let pipeline = match cached {
+        Some(cached) => cached,
+        None => {
+            let parsed_jd = parse_jd(&request.jd_text, &state.llm).await?;
+            let entries = get_current_entries(&state.db, auth.user_id)
+                .await
+                .map_err(AppError::Internal)?;
+            let pipeline = CachedPipeline { parsed_jd, entries };
+            pipeline_cache::store(
+                &state.redis,
+                auth.user_id,
+                &request.jd_text,
+                revision,
+                &pipeline,
+            )
+            .await;
+            pipeline
+        }
+    };
 
-    let fit_report = state.fit_scorer.score(&entries, &parsed_jd).await?;
+    let fit_report = state
+        .fit_scorer
+        .score(&pipeline.entries, &pipeline.parsed_jd)
+        .await?;
 
     Ok(Json(FitScoreResponse {
         fit_report,
-        parsed_jd,
+        parsed_jd: pipeline.parsed_jd,
     }))
 }
 
@@ -109,52 +158,510 @@ pub async fn handle_fit_score(
 ///
 /// Full generation pipeline: JD parse → fit score → content select → tone → LLM generate
 /// → layout simulation → persist. Phase 3: returns `SimulatedBullet` with layout metadata.
+/// Counts against the caller's monthly generation and LLM token quotas. With a
+/// `progress_id`, stages are streamed at `GET /api/v1/events/:progress_id`.
 pub async fn handle_generate(
     State(state): State<AppState>,
-    Json(request): Json<GenerateRequest>,
+    auth: AuthUser,
+    ValidJson(mut request): ValidJson<GenerateRequest>,
 ) -> Result<Json<GenerateResponse>, AppError> {
-    if request.jd_text.trim().is_empty() {
-        return Err(AppError::Validation("jd_text cannot be empty".to_string()));
+    request.user_id = auth.user_id;
+    let progress_id = request.progress_id;
+    if let Some(progress_id) = progress_id {
+        progress::claim(&state.redis, progress_id, auth.user_id).await?;
     }
 
-    let response = generate_resume(
+    let response = quota::metered_generation(
+        &state.db,
+        &state.redis,
+        auth.user_id,
+        progress::track(
+            &state.redis,
+            progress_id,
+            generate_resume(
+                &state.db,
+                &state.llm,
+                state.fit_scorer.as_ref(),
+                &state.page_config,
+                state.config.layout.adjust_concurrency,
+                Some(&state.redis),
+                true, // grounding_enabled: Phase 5 — real grounding scores
+                request,
+            ),
+        ),
+    )
+    .await?;
+    audit::record(
+        &state.db,
+        auth.user_id,
+        AuditEvent::new(Action::ResumeGenerate, response.resume_id),
+    )
+    .await;
+    state
+        .notifier
+        .generation_finished(&state.db, auth.user_id, response.resume_id);
+
+    Ok(Json(response))
+}
+
+/// POST /api/v1/resumes/generate-batch
+///
+/// Queues one generation job per unique JD and returns immediately with the job ids.
+/// Duplicate JDs (ignoring case/whitespace) are dropped before the quota check, so
+/// they never count against the user. Poll `GET /api/v1/resumes/batches/:id` for results.
+///
+/// The whole batch is charged against the monthly generation quota up front; jobs
+/// that fail are refunded by the worker.
+pub async fn handle_generate_batch(
+    State(state): State<AppState>,
+    auth: AuthUser,
+    ValidJson(request): ValidJson<BatchGenerateRequest>,
+) -> Result<Json<BatchGenerateResponse>, AppError> {
+    let (jd_texts, duplicates_dropped) = dedup_jd_texts(request.jd_texts)?;
+
+    quota::ensure_tokens_available(&state.db, &state.redis, auth.user_id).await?;
+    let reservation = quota::reserve(
+        &state.db,
+        &state.redis,
+        auth.user_id,
+        Meter::Generations,
+        jd_texts.len() as i64,
+    )
+    .await?;
+
+    let (batch_id, job_ids, quota) =
+        match create_generation_batch(&state.db, auth.user_id, &jd_texts).await {
+            Ok(created) => created,
+            Err(e) => {
+                quota::release(&state.redis, &reservation).await;
+                return Err(e);
+            }
+        };
+
+    audit::record(
+        &state.db,
+        auth.user_id,
+        AuditEvent::new(Action::GenerationBatchCreate, batch_id)
+            .after(serde_json::json!({ "job_ids": job_ids })),
+    )
+    .await;
+
+    spawn_batch_generation(
+        state.clone(),
+        auth.user_id,
+        request.paper_size,
+        request.margin_preset,
+        job_ids.iter().copied().zip(jd_texts).collect(),
+        reservation,
+    );
+
+    Ok(Json(BatchGenerateResponse {
+        batch_id,
+        job_ids,
+        duplicates_dropped,
+        quota,
+    }))
+}
+
+/// GET /api/v1/resumes/batches/:id
+///
+/// Progress of a batch generation request, with the resulting resume_id per job.
+pub async fn handle_generate_batch_status(
+    State(state): State<AppState>,
+    auth: AuthUser,
+    Path(batch_id): Path<Uuid>,
+) -> Result<Json<BatchGenerationStatus>, AppError> {
+    auth.authorize(&state.db, Resource::GenerationBatch, batch_id)
+        .await?;
+    let status = get_generation_batch_status(&state.db, batch_id)
+        .await?
+        .ok_or_else(|| AppError::NotFound(format!("Batch {batch_id} not found")))?;
+    Ok(Json(status))
+}
+
+/// POST /api/v1/resumes/:id/regenerate
+///
+/// Re-runs the full pipeline for an existing resume's JD against the user's CURRENT
+/// context (which may have grown since the original generation). The original row is
+/// untouched; a new resume row is created with `parent_resume_id` pointing at it and
+/// the parent's `template_id` carried over (both set in the same INSERT, so the render
+/// job enqueued by the pipeline already sees the right template).
+pub async fn handle_regenerate(
+    State(state): State<AppState>,
+    auth: AuthUser,
+    Path(resume_id): Path<Uuid>,
+) -> Result<Json<RegenerateResponse>, AppError> {
+    auth.authorize(&state.db, Resource::Resume, resume_id)
+        .await?;
+    let parent = sqlx::query_as!(
+        ResumeRow,
+        "SELECT * FROM resumes WHERE id = $1 AND deleted_at IS NULL",
+        resume_id
+    )
+    .fetch_optional(&state.db)
+    .await?
+    .ok_or_else(|| AppError::NotFound(format!("Resume {resume_id} not found")))?;
+
+    let request = GenerateRequest {
+        user_id: parent.user_id,
+        jd_text: parent.jd_text,
+        target_role_id: None,
+        persona_id: None,
+        tone_override: None,
+        parent_resume_id: Some(resume_id),
+        paper_size: PaperSize::parse(&parent.paper_size),
+        margin_preset: MarginPreset::parse(&parent.margin_preset),
+        progress_id: None,
+        rewrite_stuffed_bullets: false,
+        entry_ids: None,
+    };
+
+    let response = quota::metered_generation(
         &state.db,
-        &state.llm,
-        state.fit_scorer.as_ref(),
-        &state.page_config,
-        Some(&state.redis),
-        true, // grounding_enabled: Phase 5 — real grounding scores
-        request,
+        &state.redis,
+        auth.user_id,
+        generate_resume(
+            &state.db,
+            &state.llm,
+            state.fit_scorer.as_ref(),
+            &state.page_config,
+            state.config.layout.adjust_concurrency,
+            Some(&state.redis),
+            true,
+            request,
+        ),
     )
     .await?;
+    audit::record(
+        &state.db,
+        auth.user_id,
+        AuditEvent::new(Action::ResumeRegenerate, response.resume_id)
+            .before(serde_json::json!({ "resume_id": resume_id })),
+    )
+    .await;
+    state
+        .notifier
+        .generation_finished(&state.db, auth.user_id, response.resume_id);
 
-    Ok(Json(GenerateResponse {
-        resume_id: response.resume_id,
-        fit_report: response.fit_report,
-        bullets: response.bullets,
-        status: response.status,
+    Ok(Json(RegenerateResponse {
+        parent_resume_id: resume_id,
+        generation: response,
     }))
 }
 
+/// POST /api/v1/resumes/:id/interview-prep
+///
+/// Generates likely interview questions for each bullet of the resume, grounded in
+/// the bullet's source context entry and the JD requirements. Nothing is persisted.
+pub async fn handle_interview_prep(
+    State(state): State<AppState>,
+    auth: AuthUser,
+    Path(resume_id): Path<Uuid>,
+) -> Result<Json<InterviewPrepResponse>, AppError> {
+    auth.authorize(&state.db, Resource::Resume, resume_id)
+        .await?;
+    let response = quota::metered_llm(
+        &state.db,
+        &state.redis,
+        auth.user_id,
+        generate_interview_prep(&state.db, &state.llm, resume_id),
+    )
+    .await?;
+    Ok(Json(response))
+}
+
+/// POST /api/v1/linkedin/generate
+///
+/// Generates a LinkedIn headline (≤ 220 chars) and About section (≤ 2,600 chars)
+/// from the user's context, tuned to the target role in `jd_text`.
+pub async fn handle_generate_linkedin(
+    State(state): State<AppState>,
+    auth: AuthUser,
+    ValidJson(mut request): ValidJson<LinkedInRequest>,
+) -> Result<Json<LinkedInResponse>, AppError> {
+    request.user_id = auth.user_id;
+
+    let response = quota::metered_llm(
+        &state.db,
+        &state.redis,
+        auth.user_id,
+        generate_linkedin_profile(&state.db, &state.llm, request),
+    )
+    .await?;
+    Ok(Json(response))
+}
+
 /// GET /api/v1/resumes/:id
 ///
-/// Returns the full resume row and all associated bullets from the DB.
+/// Returns the full resume row and all associated bullets from the DB. Readable by the
+/// owner and by their coaches.
+///
+/// Conditional: the ETag follows `resumes.updated_at`, so anything that changes the
+/// resume or its bullets must bump it. Served from the read replica when it has the
+/// resume, else from the primary.
 pub async fn handle_get_resume(
     State(state): State<AppState>,
+    auth: AuthUser,
     Path(resume_id): Path<Uuid>,
-) -> Result<Json<ResumeDetailResponse>, AppError> {
-    let resume = sqlx::query_as::<_, ResumeRow>("SELECT * FROM resumes WHERE id = $1")
-        .bind(resume_id)
-        .fetch_optional(&state.db)
+    headers: HeaderMap,
+) -> Result<Conditional<Json<ResumeDetailResponse>>, AppError> {
+    auth.authorize_view(&state.db, Resource::Resume, resume_id)
+        .await?;
+    // Read replica first; `db` is whichever pool had the row, so the resume and its
+    // bullets below match the tag. Comments do not touch `updated_at`, so the open
+    // thread count is part of the tag too.
+    let (tag, db) = state
+        .reads
+        .fetch_optional(|db| {
+            sqlx::query!(
+                r#"SELECT r.updated_at,
+                          (SELECT COUNT(*) FROM resume_comments c
+                           WHERE c.resume_id = r.id
+                             AND c.parent_id IS NULL
+                             AND c.resolved_at IS NULL) AS "unresolved_comments!"
+                   FROM resumes r
+                   WHERE r.id = $1 AND r.deleted_at IS NULL"#,
+                resume_id
+            )
+            .fetch_optional(db)
+        })
         .await?
         .ok_or_else(|| AppError::NotFound(format!("Resume {resume_id} not found")))?;
+    let unresolved_comments = tag.unresolved_comments;
+    let etag = ETag::weak(&format!(
+        "resume-{}-{unresolved_comments}",
+        tag.updated_at.timestamp_micros()
+    ));
+
+    Conditional::respond(&headers, etag, || async {
+        let resume = sqlx::query_as!(
+            ResumeRow,
+            "SELECT * FROM resumes WHERE id = $1 AND deleted_at IS NULL",
+            resume_id
+        )
+        .fetch_optional(db)
+        .await?
+        .ok_or_else(|| AppError::NotFound(format!("Resume {resume_id} not found")))?;
+
+        let bullets = sqlx::query_as!(
+            ResumeBulletRow,
+            "SELECT * FROM resume_bullets WHERE resume_id = $1 ORDER BY position, id",
+            resume_id
+        )
+        .fetch_all(db)
+        .await?;
+
+        Ok(Json(ResumeDetailResponse {
+            resume,
+            bullets,
+            unresolved_comments,
+        }))
+    })
+    .await
+}
+
+/// GET /api/v1/resumes/:id/explanation
+///
+/// Why the resume looks the way it does: the entries selection kept and cut, the tone
+/// it was written in and where that came from, and the JD keywords that drove
+/// selection. Readable by the owner and by their coaches. 404 for resumes generated
+/// before explanations were recorded.
+pub async fn handle_get_explanation(
+    State(state): State<AppState>,
+    auth: AuthUser,
+    Path(resume_id): Path<Uuid>,
+) -> Result<Json<GenerationExplanation>, AppError> {
+    auth.authorize_view(&state.db, Resource::Resume, resume_id)
+        .await?;
+    let explanation = sqlx::query_scalar!(
+        "SELECT explanation FROM resumes WHERE id = $1 AND deleted_at IS NULL",
+        resume_id
+    )
+    .fetch_optional(&state.db)
+    .await?
+    .ok_or_else(|| AppError::NotFound(format!("Resume {resume_id} not found")))?
+    .ok_or_else(|| {
+        AppError::NotFound(format!("Resume {resume_id} has no generation explanation"))
+    })?;
+    let explanation = serde_json::from_value(explanation).map_err(|e| {
+        AppError::Internal(anyhow::anyhow!(
+            "Failed to deserialize GenerationExplanation: {e}"
+        ))
+    })?;
+    Ok(Json(explanation))
+}
 
-    let bullets = sqlx::query_as::<_, ResumeBulletRow>(
-        "SELECT * FROM resume_bullets WHERE resume_id = $1 ORDER BY section, id",
+/// DELETE /api/v1/resumes/:id
+///
+/// Soft delete: the resume disappears from every read at once, and `retention` purges
+/// the row and its PDFs once `deleted_at` is older than the retention window. A
+/// project pointing at the resume is detached in the same transaction.
+pub async fn handle_delete_resume(
+    State(state): State<AppState>,
+    auth: AuthUser,
+    Path(resume_id): Path<Uuid>,
+) -> Result<StatusCode, AppError> {
+    auth.authorize(&state.db, Resource::Resume, resume_id)
+        .await?;
+
+    let mut tx = state.db.begin().await?;
+    let rows = sqlx::query!(
+        "UPDATE resumes SET deleted_at = NOW() WHERE id = $1 AND deleted_at IS NULL",
+        resume_id
+    )
+    .execute(&mut *tx)
+    .await?
+    .rows_affected();
+    if rows == 0 {
+        return Err(AppError::NotFound(format!("Resume {resume_id} not found")));
+    }
+    sqlx::query!(
+        "UPDATE cv_projects SET current_resume_id = NULL, updated_at = NOW() \
+         WHERE current_resume_id = $1",
+        resume_id
     )
-    .bind(resume_id)
-    .fetch_all(&state.db)
+    .execute(&mut *tx)
     .await?;
+    tx.commit().await?;
 
-    Ok(Json(ResumeDetailResponse { resume, bullets }))
+    audit::record(
+        &state.db,
+        auth.user_id,
+        AuditEvent::new(Action::ResumeDelete, resume_id),
+    )
+    .await;
+    Ok(StatusCode::NO_CONTENT)
+}
+
+/// PATCH /api/v1/resumes/:id/bullets/reorder
+///
+/// Rewrites `position` for every bullet of the resume in a single UPDATE, in one
+/// transaction with the check below and the `updated_at` bump.
+/// The request must be a permutation of the resume's bullet IDs — partial lists
+/// would leave ambiguous positions, so they are rejected with 400.
+pub async fn handle_reorder_bullets(
+    State(state): State<AppState>,
+    auth: AuthUser,
+    Path(resume_id): Path<Uuid>,
+    Json(request): Json<ReorderBulletsRequest>,
+) -> Result<Json<ReorderBulletsResponse>, AppError> {
+    auth.authorize(&state.db, Resource::Resume, resume_id)
+        .await?;
+
+    // One transaction with the bullets locked, so none can be added, removed or
+    // rewritten between the permutation check and the UPDATE.
+    let mut tx = state.db.begin().await?;
+    let existing: Vec<Uuid> = sqlx::query_scalar!(
+        "SELECT id FROM resume_bullets WHERE resume_id = $1 FOR UPDATE",
+        resume_id
+    )
+    .fetch_all(&mut *tx)
+    .await?;
+
+    validate_reorder(&existing, &request.bullet_ids)?;
+
+    // WITH ORDINALITY is 1-based; positions are 0-based.
+    sqlx::query!(
+        r#"
+        UPDATE resume_bullets b
+        SET position = o.ord - 1
+        FROM UNNEST($2::uuid[]) WITH ORDINALITY AS o(id, ord)
+        WHERE b.id = o.id AND b.resume_id = $1
+        "#,
+        resume_id,
+        &request.bullet_ids
+    )
+    .execute(&mut *tx)
+    .await?;
+    // Bullets are part of GET /resumes/:id, whose ETag follows updated_at.
+    sqlx::query!(
+        "UPDATE resumes SET updated_at = NOW() WHERE id = $1",
+        resume_id
+    )
+    .execute(&mut *tx)
+    .await?;
+    let bullets = sqlx::query_as!(
+        ResumeBulletRow,
+        "SELECT * FROM resume_bullets WHERE resume_id = $1 ORDER BY position, id",
+        resume_id
+    )
+    .fetch_all(&mut *tx)
+    .await?;
+    tx.commit().await?;
+
+    audit::record(
+        &state.db,
+        auth.user_id,
+        AuditEvent::new(Action::ResumeReorderBullets, resume_id)
+            .after(serde_json::json!({ "bullet_ids": request.bullet_ids })),
+    )
+    .await;
+
+    Ok(Json(ReorderBulletsResponse { bullets }))
+}
+
+/// Checks that `requested` is a permutation of `existing` (same IDs, no duplicates).
+fn validate_reorder(existing: &[Uuid], requested: &[Uuid]) -> Result<(), AppError> {
+    let requested_set: HashSet<Uuid> = requested.iter().copied().collect();
+    if requested_set.len() != requested.len() {
+        return Err(AppError::Validation(
+            "bullet_ids contains duplicates".to_string(),
+        ));
+    }
+
+    let existing_set: HashSet<Uuid> = existing.iter().copied().collect();
+    if let Some(unknown) = requested_set.difference(&existing_set).next() {
+        return Err(AppError::Validation(format!(
+            "Bullet {unknown} does not belong to this resume"
+        )));
+    }
+    if requested_set.len() != existing_set.len() {
+        return Err(AppError::Validation(format!(
+            "bullet_ids must list all {} bullets of the resume (got {})",
+            existing_set.len(),
+            requested_set.len()
+        )));
+    }
+
+    Ok(())
+}
+
+// ────────────────────────────────────────────────────────────────────────────
+// Tests
+// ────────────────────────────────────────────────────────────────────────────
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_validate_reorder_accepts_permutation() {
+        let a = Uuid::new_v4();
+        let b = Uuid::new_v4();
+        let c = Uuid::new_v4();
+        assert!(validate_reorder(&[a, b, c], &[c, a, b]).is_ok());
+    }
+
+    #[test]
+    fn test_validate_reorder_rejects_duplicates() {
+        let a = Uuid::new_v4();
+        let b = Uuid::new_v4();
+        let err = validate_reorder(&[a, b], &[a, a]).unwrap_err();
+        assert!(matches!(err, AppError::Validation(_)));
+    }
+
+    #[test]
+    fn test_validate_reorder_rejects_partial_list() {
+        let a = Uuid::new_v4();
+        let b = Uuid::new_v4();
+        let err = validate_reorder(&[a, b], &[b]).unwrap_err();
+        assert!(matches!(err, AppError::Validation(m) if m.contains("all 2 bullets")));
+    }
+
+    #[test]
+    fn test_validate_reorder_rejects_foreign_bullet() {
+        let a = Uuid::new_v4();
+        let err = validate_reorder(&[a], &[Uuid::new_v4()]).unwrap_err();
+        assert!(matches!(err, AppError::Validation(m) if m.contains("does not belong")));
+    }
 }
@@ -0,0 +1,819 @@
+//! Job posting text from a page's raw HTML — the input of `POST /resumes/parse-jd-html`.
+//!
+//! Two sources, in order of trust:
+//! 1. A schema.org `JobPosting` in a `<script type="application/ld+json">` block. Most
+//!    job boards embed one for search engines, and its `description` is the posting
+//!    alone, with no page chrome.
+//! 2. The page's main content. Boilerplate is dropped first — `nav`, `header`,
+//!    `footer`, `aside`, forms, hidden elements and anything whose class or id reads
+//!    like chrome (`cookie-banner`, `sidebar`, `share`, ...). The main content is then
+//!    the deepest element holding at least `MAIN_CONTENT_SHARE` of the remaining
+//!    paragraph text, so a posting split across several section `div`s is kept whole.
+//!
+//! Either way the result is plain text with one line per block (list items as `- `
+//! lines, headings on their own line), which is the shape `parse_jd` and the offline
+//! extractor already expect from a pasted JD. The parser is a small tolerant tokenizer,
+//! not a spec-compliant HTML5 parser: stray close tags are ignored and unclosed
+//! elements end with their parent.
+
+use serde_json::Value;
+
+use crate::validation::MAX_JD_TEXT_CHARS;
+
+/// Share of all paragraph text the main content element must hold.
+const MAIN_CONTENT_SHARE: f64 = 0.75;
+/// Shorter blocks (buttons, labels, "Apply now") do not count as paragraphs.
+const MIN_PARAGRAPH_CHARS: usize = 25;
+/// Paragraphs that are mostly link text are navigation, not content.
+const MAX_PARAGRAPH_LINK_DENSITY: f64 = 0.5;
+/// A JSON-LD description shorter than this is a teaser; fall back to the page.
+const MIN_JSON_LD_CHARS: usize = 200;
+/// Elements nested deeper are attached to the deepest open one instead, which bounds
+/// the recursion over the tree whatever the page sends.
+const MAX_DEPTH: usize = 256;
+
+/// Never rendered, along with everything inside them.
+const DROPPED_TAGS: &[&str] = &[
+    "head", "script", "style", "noscript", "template", "svg", "canvas", "iframe", "nav", "header",
+    "footer", "aside", "form", "button", "select", "textarea", "dialog",
+];
+/// Elements whose own content is never parsed as markup.
+const RAW_TEXT_TAGS: &[&str] = &["script", "style", "textarea", "title"];
+const VOID_TAGS: &[&str] = &[
+    "area", "base", "br", "col", "embed", "hr", "img", "input", "link", "meta", "param", "source",
+    "track", "wbr",
+];
+/// Start a new line when they open and close.
+const BLOCK_TAGS: &[&str] = &[
+    "address",
+    "article",
+    "blockquote",
+    "body",
+    "dd",
+    "div",
+    "dl",
+    "dt",
+    "figcaption",
+    "figure",
+    "h1",
+    "h2",
+    "h3",
+    "h4",
+    "h5",
+    "h6",
+    "hr",
+    "li",
+    "main",
+    "ol",
+    "p",
+    "pre",
+    "section",
+    "table",
+    "td",
+    "th",
+    "tr",
+    "ul",
+];
+const PARAGRAPH_TAGS: &[&str] = &["p", "li", "pre", "blockquote", "dd", "td"];
+/// An open element of the same group is closed when another one starts (`<li>a<li>b`).
+const SELF_CLOSING_GROUPS: &[&[&str]] = &[&["p"], &["li"], &["dt", "dd"], &["tr"], &["td", "th"]];
+
+/// Class/id fragments marking page chrome.
+const NEGATIVE_HINTS: &[&str] = &[
+    "cookie",
+    "consent",
+    "banner",
+    "breadcrumb",
+    "sidebar",
+    "navbar",
+    "menu",
+    "footer",
+    "share",
+    "social",
+    "related",
+    "similar",
+    "recommend",
+    "newsletter",
+    "subscribe",
+    "signup",
+    "login",
+    "modal",
+    "popup",
+    "advert",
+    "promo",
+];
+/// Class/id fragments that outweigh a negative one (`job-header`, `posting-sidebar`).
+const POSITIVE_HINTS: &[&str] = &["job", "posting", "description", "vacancy", "position"];
+
+/// The posting's text, ready for `parse_jd`; empty when the page has none. Capped at
+/// `MAX_JD_TEXT_CHARS`, cut at a line boundary.
+pub fn extract_jd_text(html: &str) -> String {
+    let dom = Dom::parse(html);
+    let text = json_ld_posting(&dom).unwrap_or_else(|| main_content_text(&dom));
+    truncate_lines(&text, MAX_JD_TEXT_CHARS as usize)
+}
+
+/// Plain text of an HTML fragment such as a posting's description field, with the
+/// same block and list handling as a page.
+pub fn fragment_text(html: &str) -> String {
+    // Some boards escape the HTML twice (`&lt;p&gt;`).
+    let html = if html.contains('<') {
+        html.to_string()
+    } else {
+        decode_entities(html)
+    };
+    render_text(&Dom::parse(&html), 0)
+}
+
+// ────────────────────────────────────────────────────────────────────────────
+// Tree
+// ────────────────────────────────────────────────────────────────────────────
+
+enum Node {
+    Element {
+        tag: String,
+        attrs: Vec<(String, String)>,
+        children: Vec<usize>,
+    },
+    /// Entity-decoded text; the raw source inside raw-text elements.
+    Text(String),
+}
+
+/// Nodes in an arena; index 0 is a synthetic root holding the top-level nodes.
+struct Dom {
+    nodes: Vec<Node>,
+}
+
+impl Dom {
+    fn parse(html: &str) -> Dom {
+        let mut dom = Dom {
+            nodes: vec![Node::Element {
+                tag: String::new(),
+                attrs: vec![],
+                children: vec![],
+            }],
+        };
+        // ASCII lowercasing keeps byte offsets, so matches index `html` directly.
+        let lower = html.to_ascii_lowercase();
+        let mut open: Vec<usize> = vec![0];
+        let mut pos = 0;
+
+        while pos < html.len() {
+            let Some(lt) = html[pos..].find('<').map(|i| pos + i) else {
+                dom.push_text(*open.last().unwrap(), &decode_entities(&html[pos..]));
+                break;
+            };
+            if lt > pos {
+                dom.push_text(*open.last().unwrap(), &decode_entities(&html[pos..lt]));
+            }
+            let rest = &html[lt..];
+            pos = if rest.starts_with("<!--") {
+                find_from(&lower, lt + 4, "-->").map_or(html.len(), |i| i + 3)
+            } else if rest.starts_with("<!") || rest.starts_with("<?") {
+                find_from(&lower, lt, ">").map_or(html.len(), |i| i + 1)
+            } else if let Some(after) = rest.strip_prefix("</") {
+                let name_len = after
+                    .find(|c: char| c.is_whitespace() || c == '>')
+                    .unwrap_or(after.len());
+                let name = after[..name_len].to_ascii_lowercase();
+                if let Some(depth) = open.iter().rposition(|&n| dom.tag(n) == name) {
+                    if depth > 0 {
+                        open.truncate(depth);
+                    }
+                }
+                find_from(&lower, lt, ">").map_or(html.len(), |i| i + 1)
+            } else if rest[1..].starts_with(|c: char| c.is_ascii_alphabetic()) {
+                let (tag, attrs, self_closing, end) = parse_start_tag(html, lt);
+                let parent = dom.close_implied(&mut open, &tag);
+                let node = dom.push_element(parent, tag.clone(), attrs);
+                if RAW_TEXT_TAGS.contains(&tag.as_str()) {
+                    let close = find_from(&lower, end, &format!("</{tag}")).unwrap_or(html.len());
+                    dom.push_text(node, &html[end..close]);
+                    find_from(&lower, close, ">").map_or(html.len(), |i| i + 1)
+                } else {
+                    if !self_closing && !VOID_TAGS.contains(&tag.as_str()) && open.len() < MAX_DEPTH
+                    {
+                        open.push(node);
+                    }
+                    end
+                }
+            } else {
+                dom.push_text(*open.last().unwrap(), "<");
+                lt + 1
+            };
+        }
+        dom
+    }
+
+    /// Pops elements the new `tag` implicitly closes; returns the parent to use.
+    fn close_implied(&self, open: &mut Vec<usize>, tag: &str) -> usize {
+        if let Some(group) = SELF_CLOSING_GROUPS.iter().find(|g| g.contains(&tag)) {
+            if open.len() > 1 && group.contains(&self.tag(*open.last().unwrap())) {
+                open.pop();
+            }
+        }
+        *open.last().unwrap()
+    }
+
+    fn push_element(&mut self, parent: usize, tag: String, attrs: Vec<(String, String)>) -> usize {
+        self.push(
+            parent,
+            Node::Element {
+                tag,
+                attrs,
+                children: vec![],
+            },
+        )
+    }
+
+    fn push_text(&mut self, parent: usize, text: &str) {
+        if !text.is_empty() {
+            self.push(parent, Node::Text(text.to_string()));
+        }
+    }
+
+    fn push(&mut self, parent: usize, node: Node) -> usize {
+        let idx = self.nodes.len();
+        self.nodes.push(node);
+        if let Node::Element { children, .. } = &mut self.nodes[parent] {
+            children.push(idx);
+        }
+        idx
+    }
+
+    fn tag(&self, idx: usize) -> &str {
+        match &self.nodes[idx] {
+            Node::Element { tag, .. } => tag,
+            Node::Text(_) => "",
+        }
+    }
+
+    fn children(&self, idx: usize) -> &[usize] {
+        match &self.nodes[idx] {
+            Node::Element { children, .. } => children,
+            Node::Text(_) => &[],
+        }
+    }
+
+    fn attr(&self, idx: usize, name: &str) -> Option<&str> {
+        match &self.nodes[idx] {
+            Node::Element { attrs, .. } => attrs
+                .iter()
+                .find(|(k, _)| k == name)
+                .map(|(_, v)| v.as_str()),
+            Node::Text(_) => None,
+        }
+    }
+
+    /// Concatenated raw text of `idx`'s text children.
+    fn own_text(&self, idx: usize) -> String {
+        self.children(idx)
+            .iter()
+            .filter_map(|&c| match &self.nodes[c] {
+                Node::Text(t) => Some(t.as_str()),
+                Node::Element { .. } => None,
+            })
+            .collect()
+    }
+
+    /// Whether `idx` is page chrome or hidden, and so never rendered.
+    fn is_boilerplate(&self, idx: usize) -> bool {
+        let tag = self.tag(idx);
+        if DROPPED_TAGS.contains(&tag) {
+            return true;
+        }
+        if self.attr(idx, "hidden").is_some()
+            || self.attr(idx, "aria-hidden") == Some("true")
+            || self.attr(idx, "role") == Some("navigation")
+        {
+            return true;
+        }
+        let style = self.attr(idx, "style").unwrap_or_default().replace(' ', "");
+        if style.contains("display:none") || style.contains("visibility:hidden") {
+            return true;
+        }
+        let hints = format!(
+            "{} {}",
+            self.attr(idx, "class").unwrap_or_default(),
+            self.attr(idx, "id").unwrap_or_default()
+        )
+        .to_ascii_lowercase();
+        NEGATIVE_HINTS.iter().any(|h| hints.contains(h))
+            && !POSITIVE_HINTS.iter().any(|h| hints.contains(h))
+    }
+}
+
+fn find_from(haystack: &str, from: usize, needle: &str) -> Option<usize> {
+    haystack.get(from..)?.find(needle).map(|i| from + i)
+}
+
+/// Parses the start tag at `lt`: `(tag, attributes, self-closing, offset after '>')`.
+fn parse_start_tag(html: &str, lt: usize) -> (String, Vec<(String, String)>, bool, usize) {
+    let bytes = html.as_bytes();
+    let mut i = lt + 1;
+    while i < bytes.len() && !bytes[i].is_ascii_whitespace() && !matches!(bytes[i], b'>' | b'/') {
+        i += 1;
+    }
+    let tag = html[lt + 1..i].to_ascii_lowercase();
+    let mut attrs = vec![];
+    let mut self_closing = false;
+    loop {
+        while i < bytes.len() && (bytes[i].is_ascii_whitespace() || bytes[i] == b'/') {
+            self_closing = bytes[i] == b'/';
+            i += 1;
+        }
+        if i >= bytes.len() {
+            return (tag, attrs, self_closing, html.len());
+        }
+        if bytes[i] == b'>' {
+            return (tag, attrs, self_closing, i + 1);
+        }
+        self_closing = false;
+        let start = i;
+        while i < bytes.len() && !bytes[i].is_ascii_whitespace() && !matches!(bytes[i], b'=' | b'>')
+        {
+            i += 1;
+        }
+        let name = html[start..i].to_ascii_lowercase();
+        let mut value = String::new();
+        if i < bytes.len() && bytes[i] == b'=' {
+            i += 1;
+            let (raw, next) = match bytes.get(i) {
+                Some(&q @ (b'"' | b'\'')) => {
+                    let close = html[i + 1..]
+                        .find(q as char)
+                        .map_or(html.len(), |c| i + 1 + c);
+                    (&html[i + 1..close], (close + 1).min(html.len()))
+                }
+                _ => {
+                    let end = html[i..]
+                        .find(|c: char| c.is_whitespace() || c == '>')
+                        .map_or(html.len(), |c| i + c);
+                    (&html[i..end], end)
+                }
+            };
+            value = decode_entities(raw);
+            i = next;
+        }
+        attrs.push((name, value));
+    }
+}
+
+/// Decodes character references: numeric ones and the named ones job postings use.
+fn decode_entities(text: &str) -> String {
+    if !text.contains('&') {
+        return text.to_string();
+    }
+    let mut out = String::with_capacity(text.len());
+    let mut rest = text;
+    while let Some(amp) = rest.find('&') {
+        out.push_str(&rest[..amp]);
+        rest = &rest[amp..];
+        let decoded = rest[1..]
+            .find(';')
+            .filter(|&semi| semi <= 10)
+            .and_then(|semi| Some((decode_entity(&rest[1..1 + semi])?, semi + 2)));
+        match decoded {
+            Some((c, len)) => {
+                out.push(c);
+                rest = &rest[len..];
+            }
+            None => {
+                out.push('&');
+                rest = &rest[1..];
+            }
+        }
+    }
+    out.push_str(rest);
+    out
+}
+
+fn decode_entity(name: &str) -> Option<char> {
+    if let Some(num) = name.strip_prefix('#') {
+        let code = match num.strip_prefix(['x', 'X']) {
+            Some(hex) => u32::from_str_radix(hex, 16).ok()?,
+            None => num.parse().ok()?,
+        };
+        return char::from_u32(code);
+    }
+    Some(match name {
+        "amp" => '&',
+        "lt" => '<',
+        "gt" => '>',
+        "quot" => '"',
+        "apos" => '\'',
+        "nbsp" => ' ',
+        "ndash" => '–',
+        "mdash" => '—',
+        "lsquo" => '‘',
+        "rsquo" => '’',
+        "ldquo" => '“',
+        "rdquo" => '”',
+        "hellip" => '…',
+        "bull" => '•',
+        "middot" => '·',
+        "trade" => '™',
+        "reg" => '®',
+        "copy" => '©',
+        "euro" => '€',
+        "pound" => '£',
+        _ => return None,
+    })
+}
+
+// ────────────────────────────────────────────────────────────────────────────
+// JSON-LD
+// ────────────────────────────────────────────────────────────────────────────
+
+/// Title and description of the page's `JobPosting`, when it has a usable one.
+fn json_ld_posting(dom: &Dom) -> Option<String> {
+    let posting = (0..dom.nodes.len())
+        .filter(|&idx| {
+            dom.tag(idx) == "script"
+                && dom
+                    .attr(idx, "type")
+                    .is_some_and(|t| t.eq_ignore_ascii_case("application/ld+json"))
+        })
+        .filter_map(|idx| serde_json::from_str::<Value>(dom.own_text(idx).trim()).ok())
+        .find_map(|value| find_job_posting(&value).cloned())?;
+
+    let body = fragment_text(posting.get("description")?.as_str()?);
+    if body.chars().count() < MIN_JSON_LD_CHARS {
+        return None;
+    }
+    let title = posting.get("title").and_then(Value::as_str).map(str::trim);
+    let company = posting
+        .pointer("/hiringOrganization/name")
+        .and_then(Value::as_str)
+        .map(str::trim);
+    let heading = match (title, company) {
+        (Some(title), Some(company)) => format!("{title} at {company}\n\n"),
+        (Some(title), None) => format!("{title}\n\n"),
+        _ => String::new(),
+    };
+    Some(format!("{}{body}", decode_entities(&heading)))
+}
+
+/// The first `JobPosting` object in a JSON-LD value: the value itself, an array
+/// element or an `@graph` member.
+fn find_job_posting(value: &Value) -> Option<&Value> {
+    match value {
+        Value::Array(items) => items.iter().find_map(find_job_posting),
+        Value::Object(map) => {
+            let is_posting = match map.get("@type") {
+                Some(Value::String(t)) => t == "JobPosting",
+                Some(Value::Array(types)) => types.iter().any(|t| t == "JobPosting"),
+                _ => false,
+            };
+            if is_posting {
+                return Some(value);
+            }
+            map.get("@graph").and_then(find_job_posting)
+        }
+        _ => None,
+    }
+}
+
+// ────────────────────────────────────────────────────────────────────────────
+// Main content
+// ────────────────────────────────────────────────────────────────────────────
+
+/// Text and link-text lengths under each node, skipping boilerplate.
+struct Lengths {
+    text: Vec<usize>,
+    links: Vec<usize>,
+    /// Characters of qualifying paragraph text under each node.
+    paragraphs: Vec<usize>,
+}
+
+fn main_content_text(dom: &Dom) -> String {
+    let mut lengths = Lengths {
+        text: vec![0; dom.nodes.len()],
+        links: vec![0; dom.nodes.len()],
+        paragraphs: vec![0; dom.nodes.len()],
+    };
+    measure(dom, 0, false, &mut lengths);
+
+    // Descend while one child still holds enough of the paragraph text.
+    let total = lengths.paragraphs[0] as f64;
+    let mut node = 0;
+    if total > 0.0 {
+        while let Some(&child) = dom
+            .children(node)
+            .iter()
+            .find(|&&c| lengths.paragraphs[c] as f64 >= total * MAIN_CONTENT_SHARE)
+        {
+            node = child;
+        }
+    }
+
+    let body = render_text(dom, node);
+    // The posting title often sits in an `h1` above the description container.
+    match first_tag(dom, 0, "h1").filter(|&h1| !contains(dom, node, h1)) {
+        Some(h1) => {
+            let title = render_text(dom, h1);
+            if title.is_empty() {
+                body
+            } else {
+                format!("{title}\n\n{body}")
+            }
+        }
+        None => body,
+    }
+}
+
+fn measure(dom: &Dom, idx: usize, in_link: bool, lengths: &mut Lengths) {
+    match &dom.nodes[idx] {
+        Node::Text(text) => {
+            let len = text.split_whitespace().map(|w| w.chars().count()).sum();
+            lengths.text[idx] = len;
+            if in_link {
+                lengths.links[idx] = len;
+            }
+        }
+        Node::Element { tag, children, .. } => {
+            if idx != 0 && dom.is_boilerplate(idx) {
+                return;
+            }
+            let in_link = in_link || tag == "a";
+            for &child in children {
+                measure(dom, child, in_link, lengths);
+                lengths.text[idx] += lengths.text[child];
+                lengths.links[idx] += lengths.links[child];
+                lengths.paragraphs[idx] += lengths.paragraphs[child];
+            }
+            let is_paragraph = PARAGRAPH_TAGS.contains(&tag.as_str())
+                || (tag == "div" && !has_block_child(dom, idx));
+            let text = lengths.text[idx];
+            let link_density = lengths.links[idx] as f64 / text.max(1) as f64;
+            if is_paragraph
+                && lengths.paragraphs[idx] == 0
+                && text >= MIN_PARAGRAPH_CHARS
+                && link_density <= MAX_PARAGRAPH_LINK_DENSITY
+            {
+                lengths.paragraphs[idx] = text;
+            }
+        }
+    }
+}
+
+fn has_block_child(dom: &Dom, idx: usize) -> bool {
+    dom.children(idx)
+        .iter()
+        .any(|&c| BLOCK_TAGS.contains(&dom.tag(c)))
+}
+
+/// First element named `tag` under `idx` (inclusive), outside boilerplate.
+fn first_tag(dom: &Dom, idx: usize, tag: &str) -> Option<usize> {
+    if idx != 0 && dom.is_boilerplate(idx) {
+        return None;
+    }
+    if dom.tag(idx) == tag {
+        return Some(idx);
+    }
+    dom.children(idx)
+        .iter()
+        .find_map(|&c| first_tag(dom, c, tag))
+}
+
+fn contains(dom: &Dom, ancestor: usize, idx: usize) -> bool {
+    ancestor == idx
+        || dom
+            .children(ancestor)
+            .iter()
+            .any(|&c| contains(dom, c, idx))
+}
+
+// ────────────────────────────────────────────────────────────────────────────
+// Rendering
+// ────────────────────────────────────────────────────────────────────────────
+
+/// Builds text one line at a time; blank lines separate paragraphs.
+#[derive(Default)]
+struct TextWriter {
+    lines: Vec<String>,
+    line: String,
+}
+
+impl TextWriter {
+    fn text(&mut self, text: &str) {
+        if text.starts_with(char::is_whitespace) && !self.line.ends_with(' ') {
+            self.line.push(' ');
+        }
+        let words: Vec<&str> = text.split_whitespace().collect();
+        self.line.push_str(&words.join(" "));
+        if !words.is_empty() && text.ends_with(char::is_whitespace) {
+            self.line.push(' ');
+        }
+    }
+
+    fn break_line(&mut self) {
+        let line = self.line.trim();
+        if !line.is_empty() && line != "-" {
+            self.lines.push(line.to_string());
+        }
+        self.line.clear();
+    }
+
+    fn blank_line(&mut self) {
+        self.break_line();
+        if self.lines.last().is_some_and(|l| !l.is_empty()) {
+            self.lines.push(String::new());
+        }
+    }
+
+    fn finish(mut self) -> String {
+        self.break_line();
+        while self.lines.last().is_some_and(String::is_empty) {
+            self.lines.pop();
+        }
+        self.lines.join("\n")
+    }
+}
+
+/// Plain text of `idx` and everything under it, minus boilerplate.
+fn render_text(dom: &Dom, idx: usize) -> String {
+    let mut writer = TextWriter::default();
+    render(dom, idx, &mut writer);
+    writer.finish()
+}
+
+fn render(dom: &Dom, idx: usize, out: &mut TextWriter) {
+    let tag = match &dom.nodes[idx] {
+        Node::Text(text) => return out.text(text),
+        Node::Element { tag, .. } => tag.as_str(),
+    };
+    if idx != 0 && dom.is_boilerplate(idx) {
+        return;
+    }
+    match tag {
+        "br" => return out.break_line(),
+        "p" | "h1" | "h2" | "h3" | "h4" | "h5" | "h6" | "ul" | "ol" | "table" => out.blank_line(),
+        "li" => {
+            out.break_line();
+            out.text("- ");
+        }
+        t if BLOCK_TAGS.contains(&t) => out.break_line(),
+        _ => {}
+    }
+    for &child in dom.children(idx) {
+        render(dom, child, out);
+    }
+    match tag {
+        "p" | "h1" | "h2" | "h3" | "h4" | "h5" | "h6" | "ul" | "ol" | "table" => out.blank_line(),
+        t if BLOCK_TAGS.contains(&t) => out.break_line(),
+        _ => {}
+    }
+}
+
+/// The longest prefix of whole lines within `max_chars`.
+pub(crate) fn truncate_lines(text: &str, max_chars: usize) -> String {
+    if text.chars().count() <= max_chars {
+        return text.to_string();
+    }
+    let mut out = String::new();
+    let mut used = 0;
+    for line in text.lines() {
+        let len = line.chars().count() + 1;
+        if used + len > max_chars {
+            break;
+        }
+        out.push_str(line);
+        out.push('\n');
+        used += len;
+    }
+    out.trim_end().to_string()
+}
+
+// ────────────────────────────────────────────────────────────────────────────
+// Tests
+// ────────────────────────────────────────────────────────────────────────────
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const RESPONSIBILITIES: &str = "<li>Design and build the billing platform in Rust</li>\
+         <li>Own the Kafka pipelines that feed our ledger</li>";
+    const REQUIREMENTS: &str = "<li>5+ years of backend experience with Rust or Go</li>\
+         <li>Production experience with PostgreSQL &amp; Kafka</li>";
+
+    fn page(main: &str) -> String {
+        format!(
+            r#"<!DOCTYPE html><html><head><title>Jobs</title><style>p {{ color: red }}</style></head>
+            <body>
+              <header><nav><a href="/">Home</a> <a href="/jobs">All jobs at Acme and partners</a></nav></header>
+              <div class="cookie-banner">We use cookies to improve your experience on our site.</div>
+              <h1>Senior Backend Engineer</h1>
+              {main}
+              <aside><p>Similar jobs you might like: Staff Engineer at Globex, remote</p></aside>
+              <footer><p>© 2026 Acme Inc. All rights reserved. Privacy policy.</p></footer>
+              <script>window.track("view");</script>
+            </body></html>"#
+        )
+    }
+
+    #[test]
+    fn test_main_content_keeps_every_posting_section() {
+        let html = page(&format!(
+            r#"<div class="posting">
+                 <div class="section"><h3>What you'll do</h3><ul>{RESPONSIBILITIES}</ul></div>
+                 <div class="section"><h3>Requirements</h3><ul>{REQUIREMENTS}</ul></div>
+                 <button>Apply now</button>
+               </div>"#
+        ));
+        assert_eq!(
+            extract_jd_text(&html),
+            "Senior Backend Engineer\n\n\
+             What you'll do\n\n\
+             - Design and build the billing platform in Rust\n\
+             - Own the Kafka pipelines that feed our ledger\n\n\
+             Requirements\n\n\
+             - 5+ years of backend experience with Rust or Go\n\
+             - Production experience with PostgreSQL & Kafka"
+        );
+    }
+
+    #[test]
+    fn test_json_ld_posting_wins_over_page() {
+        let description = format!(
+            "&lt;p&gt;Acme is hiring a backend engineer to work on payments.&lt;/p&gt;\
+             &lt;ul&gt;{}{}&lt;/ul&gt;",
+            RESPONSIBILITIES.replace('<', "&lt;").replace('>', "&gt;"),
+            REQUIREMENTS.replace('<', "&lt;").replace('>', "&gt;"),
+        );
+        let ld = format!(
+            r#"<script type="application/ld+json">{{"@context": "https://schema.org",
+               "@graph": [{{"@type": "Organization", "name": "Acme"}},
+                          {{"@type": "JobPosting", "title": "Backend Engineer",
+                            "hiringOrganization": {{"name": "Acme"}},
+                            "description": "{description}"}}]}}</script>"#
+        );
+        let text = extract_jd_text(&page(&format!(
+            "{ld}<p>Apply on our careers page today, it only takes a minute.</p>"
+        )));
+        assert!(
+            text.starts_with("Backend Engineer at Acme\n\nAcme is hiring"),
+            "{text}"
+        );
+        assert!(text.contains("- Production experience with PostgreSQL & Kafka"));
+        assert!(!text.contains("careers page"));
+    }
+
+    #[test]
+    fn test_tolerates_sloppy_markup() {
+        let html = "<div id=main><P>First paragraph of the role, with a <b>bold</b> claim\n\
+                    <p>Second paragraph &mdash; unclosed &amp; trailing<br>next line &#x2713;\
+                    <ul><li>one item that is long enough<li>another item that is long enough</ul>\
+                    </span></div><!-- <p>commented out</p> -->";
+        assert_eq!(
+            extract_jd_text(html),
+            "First paragraph of the role, with a bold claim\n\n\
+             Second paragraph — unclosed & trailing\n\
+             next line ✓\n\n\
+             - one item that is long enough\n\
+             - another item that is long enough"
+        );
+        for fragment in [
+            "<",
+            "</",
+            "<a",
+            "<a href='",
+            "<!--",
+            "<script>",
+            "&",
+            "&#xZZZZ;",
+            "&#1114112;",
+            "é<é>ü</ü>",
+            "<p a=\"1\" b='2' c=3 d/>",
+        ] {
+            extract_jd_text(fragment);
+        }
+    }
+
+    #[test]
+    fn test_hidden_and_chrome_classes_dropped_unless_job_related() {
+        let html = r#"<div>
+              <div class="job-header"><p>Platform Engineer, Payments team in Berlin</p></div>
+              <div class="share-widget"><p>Share this job with a friend on social media</p></div>
+              <div style="display: none"><p>Template text that is never shown to anyone</p></div>
+              <p>Build the payment rails that move money for millions of users.</p>
+            </div>"#;
+        let text = extract_jd_text(html);
+        assert_eq!(
+            text,
+            "Platform Engineer, Payments team in Berlin\n\n\
+             Build the payment rails that move money for millions of users."
+        );
+    }
+
+    #[test]
+    fn test_truncates_at_line_boundary() {
+        assert_eq!(truncate_lines("alpha\nbeta\ngamma", 12), "alpha\nbeta");
+        assert_eq!(truncate_lines("short", 12), "short");
+        assert_eq!(
+            extract_jd_text("<html><body><nav>x</nav></body></html>"),
+            ""
+        );
+    }
+}
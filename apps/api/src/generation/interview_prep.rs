@@ -0,0 +1,369 @@
+#![allow(dead_code)]
+
+//! Interview prep — likely interview questions for each bullet of a generated resume.
+//!
+//! One LLM call per resume (not per bullet): every bullet is sent with its source
+//! context entry and the JD requirements, and the model returns questions keyed by
+//! bullet_id. The response is validated in Rust — unknown bullet_ids are dropped,
+//! question counts are capped, and every bullet appears in the output even if the
+//! model skipped it.
+
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+use sqlx::PgPool;
+use tracing::warn;
+use uuid::Uuid;
+
+use crate::context::versioning::get_current_entries;
+use crate::errors::AppError;
+use crate::generation::jd_parser::{parse_jd, ParsedJD};
+use crate::generation::prompts::{INTERVIEW_PREP_PROMPT_TEMPLATE, INTERVIEW_PREP_SYSTEM};
+use crate::llm_client::LlmClient;
+use crate::models::context::ContextEntryRow;
+use crate::models::resume::{ResumeBulletRow, ResumeRow};
+
+/// Upper bound on questions per bullet, enforced after the LLM call.
+pub const MAX_QUESTIONS_PER_BULLET: usize = 3;
+
+// ────────────────────────────────────────────────────────────────────────────
+// Data models
+// ────────────────────────────────────────────────────────────────────────────
+
+/// A single likely interview question.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct InterviewQuestion {
+    pub question: String,
+    /// "technical" | "behavioral" | "impact"
+    pub category: String,
+    /// The JD requirement this question probes, if any.
+    pub jd_requirement: Option<String>,
+}
+
+/// All questions for one resume bullet.
+#[derive(Debug, Clone, Serialize)]
+pub struct BulletQuestions {
+    pub bullet_id: Uuid,
+    pub bullet_text: String,
+    pub source_entry_id: Uuid,
+    pub questions: Vec<InterviewQuestion>,
+}
+
+/// Response for `POST /api/v1/resumes/:id/interview-prep`.
+#[derive(Debug, Clone, Serialize)]
+pub struct InterviewPrepResponse {
+    pub resume_id: Uuid,
+    pub bullets: Vec<BulletQuestions>,
+    pub total_questions: usize,
+}
+
+/// Raw per-bullet object returned by the LLM.
+#[derive(Debug, Deserialize)]
+struct LlmBulletQuestions {
+    bullet_id: Uuid,
+    questions: Vec<InterviewQuestion>,
+}
+
+const VALID_CATEGORIES: &[&str] = &["technical", "behavioral", "impact"];
+
+// ────────────────────────────────────────────────────────────────────────────
+// Pipeline
+// ────────────────────────────────────────────────────────────────────────────
+
+/// Generates interview-prep questions for every bullet of an existing resume.
+///
+/// Uses the persisted `jd_parsed` when available; re-parses the JD only for
+/// resumes that predate it being stored.
+pub async fn generate_interview_prep(
+    pool: &PgPool,
+    llm: &LlmClient,
+    resume_id: Uuid,
+) -> Result<InterviewPrepResponse, AppError> {
+    let resume = sqlx::query_as!(
+        ResumeRow,
+        "SELECT * FROM resumes WHERE id = $1 AND deleted_at IS NULL",
+        resume_id
+    )
+    .fetch_optional(pool)
+    .await?
+    .ok_or_else(|| AppError::NotFound(format!("Resume {resume_id} not found")))?;
+
+    let bullets = sqlx::query_as!(
+        ResumeBulletRow,
+        "SELECT * FROM resume_bullets WHERE resume_id = $1 ORDER BY position, id",
+        resume_id
+    )
+    .fetch_all(pool)
+    .await?;
+
+    if bullets.is_empty() {
+        return Err(AppError::UnprocessableEntity(format!(
+            "Resume {resume_id} has no bullets to prepare questions for"
+        )));
+    }
+
+    let parsed_jd = match resume
+        .jd_parsed
+        .clone()
+        .and_then(|v| serde_json::from_value::<ParsedJD>(v).ok())
+    {
+        Some(jd) => jd,
+        None => parse_jd(&resume.jd_text, llm).await?,
+    };
+
+    let entries = get_current_entries(pool, resume.user_id)
+        .await
+        .map_err(AppError::Internal)?;
+    let entries_by_id: HashMap<Uuid, &ContextEntryRow> =
+        entries.iter().map(|e| (e.entry_id, e)).collect();
+
+    let prompt = build_interview_prep_prompt(&bullets, &entries_by_id, &parsed_jd)?;
+
+    let raw: Vec<LlmBulletQuestions> = llm
+        .call_json(&prompt, INTERVIEW_PREP_SYSTEM)
+        .await
+        .map_err(|e| AppError::Llm(format!("Interview prep LLM call failed: {e}")))?;
+
+    let assembled = assemble_questions(&bullets, raw);
+    let total_questions = assembled.iter().map(|b| b.questions.len()).sum();
+
+    Ok(InterviewPrepResponse {
+        resume_id,
+        bullets: assembled,
+        total_questions,
+    })
+}
+
+/// Builds the interview-prep prompt. Bullets whose source entry no longer exists in
+/// the current context are still sent, with `source_entry: null`.
+fn build_interview_prep_prompt(
+    bullets: &[ResumeBulletRow],
+    entries_by_id: &HashMap<Uuid, &ContextEntryRow>,
+    parsed_jd: &ParsedJD,
+) -> Result<String, AppError> {
+    let bullets_json = serde_json::to_string_pretty(
+        &bullets
+            .iter()
+            .map(|b| {
+                let source = entries_by_id.get(&b.source_entry_id).map(|e| {
+                    serde_json::json!({
+                        "entry_type": e.entry_type,
+                        "contribution_type": e.contribution_type,
+                        "data": e.data,
+                    })
+                });
+                serde_json::json!({
+                    "bullet_id": b.id,
+                    "section": b.section,
+                    "text": b.bullet_text,
+                    "source_entry": source,
+                })
+            })
+            .collect::<Vec<_>>(),
+    )
+    .map_err(|e| AppError::Internal(anyhow::anyhow!("Failed to serialize bullets: {e}")))?;
+
+    let jd_requirements = if parsed_jd.hard_requirements.is_empty() {
+        "(none extracted)".to_string()
+    } else {
+        parsed_jd
+            .hard_requirements
+            .iter()
+            .map(|r| format!("- {}", r.text))
+            .collect::<Vec<_>>()
+            .join("\n")
+    };
+
+    Ok(INTERVIEW_PREP_PROMPT_TEMPLATE
+        .replace("{bullets_json}", &bullets_json)
+        .replace("{jd_requirements}", &jd_requirements)
+        .replace("{max_questions}", &MAX_QUESTIONS_PER_BULLET.to_string()))
+}
+
+/// Merges the LLM output onto the resume's bullets.
+///
+/// - Output order follows the resume's bullet order, not the LLM's.
+/// - Unknown bullet_ids are dropped with a warning.
+/// - Empty questions are dropped; unknown categories become "technical".
+/// - At most MAX_QUESTIONS_PER_BULLET questions per bullet.
+fn assemble_questions(
+    bullets: &[ResumeBulletRow],
+    raw: Vec<LlmBulletQuestions>,
+) -> Vec<BulletQuestions> {
+    let mut by_bullet: HashMap<Uuid, Vec<InterviewQuestion>> = HashMap::new();
+    for item in raw {
+        if !bullets.iter().any(|b| b.id == item.bullet_id) {
+            warn!(bullet_id = %item.bullet_id, "Interview prep: LLM returned unknown bullet_id — dropping");
+            continue;
+        }
+        by_bullet
+            .entry(item.bullet_id)
+            .or_default()
+            .extend(item.questions);
+    }
+
+    bullets
+        .iter()
+        .map(|b| {
+            let questions = by_bullet
+                .remove(&b.id)
+                .unwrap_or_default()
+                .into_iter()
+                .filter(|q| !q.question.trim().is_empty())
+                .map(|mut q| {
+                    q.category = q.category.to_lowercase();
+                    if !VALID_CATEGORIES.contains(&q.category.as_str()) {
+                        q.category = "technical".to_string();
+                    }
+                    q
+                })
+                .take(MAX_QUESTIONS_PER_BULLET)
+                .collect();
+            BulletQuestions {
+                bullet_id: b.id,
+                bullet_text: b.bullet_text.clone(),
+                source_entry_id: b.source_entry_id,
+                questions,
+            }
+        })
+        .collect()
+}
+
+// ────────────────────────────────────────────────────────────────────────────
+// Tests
+// ────────────────────────────────────────────────────────────────────────────
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Utc;
+    use serde_json::json;
+    use templar_types::jd::{JDTone, Requirement, RoleSignals};
+
+    fn make_bullet_row(text: &str, position: i32) -> ResumeBulletRow {
+        ResumeBulletRow {
+            id: Uuid::new_v4(),
+            resume_id: Uuid::new_v4(),
+            section: "experience".to_string(),
+            bullet_text: text.to_string(),
+            source_entry_id: Uuid::new_v4(),
+            grounding_score: 0.9,
+            is_user_edited: false,
+            line_count: 1,
+            position,
+            created_at: Utc::now(),
+            keyword_spans: serde_json::json!([]),
+        }
+    }
+
+    fn make_question(text: &str, category: &str) -> InterviewQuestion {
+        InterviewQuestion {
+            question: text.to_string(),
+            category: category.to_string(),
+            jd_requirement: None,
+        }
+    }
+
+    fn make_parsed_jd() -> ParsedJD {
+        ParsedJD {
+            hard_requirements: vec![Requirement {
+                text: "5+ years Rust".to_string(),
+                is_required: true,
+            }],
+            soft_signals: vec![],
+            role_signals: RoleSignals {
+                is_startup: false,
+                is_ic_focused: true,
+                is_research: false,
+                seniority: "senior".to_string(),
+            },
+            keyword_inventory: vec![],
+            detected_tone: JDTone::CollaborativeEnterprise,
+        }
+    }
+
+    #[test]
+    fn test_assemble_follows_resume_order_and_fills_missing() {
+        let b1 = make_bullet_row("First", 0);
+        let b2 = make_bullet_row("Second", 1);
+        let raw = vec![LlmBulletQuestions {
+            bullet_id: b2.id,
+            questions: vec![make_question("Why Redis?", "technical")],
+        }];
+
+        let out = assemble_questions(&[b1.clone(), b2.clone()], raw);
+        assert_eq!(out.len(), 2, "every bullet must appear in the output");
+        assert_eq!(out[0].bullet_id, b1.id);
+        assert!(out[0].questions.is_empty());
+        assert_eq!(out[1].questions.len(), 1);
+    }
+
+    #[test]
+    fn test_assemble_drops_unknown_bullet_ids() {
+        let b1 = make_bullet_row("Only bullet", 0);
+        let raw = vec![LlmBulletQuestions {
+            bullet_id: Uuid::new_v4(),
+            questions: vec![make_question("Invented?", "technical")],
+        }];
+        let out = assemble_questions(&[b1], raw);
+        assert!(out[0].questions.is_empty());
+    }
+
+    #[test]
+    fn test_assemble_caps_questions_and_normalizes_category() {
+        let b1 = make_bullet_row("Bullet", 0);
+        let raw = vec![LlmBulletQuestions {
+            bullet_id: b1.id,
+            questions: vec![
+                make_question("Q1", "Behavioral"),
+                make_question("  ", "technical"),
+                make_question("Q2", "trivia"),
+                make_question("Q3", "impact"),
+                make_question("Q4", "impact"),
+            ],
+        }];
+        let out = assemble_questions(&[b1], raw);
+        let qs = &out[0].questions;
+        assert_eq!(qs.len(), MAX_QUESTIONS_PER_BULLET);
+        assert_eq!(qs[0].category, "behavioral");
+        assert_eq!(qs[1].question, "Q2", "blank questions are dropped");
+        assert_eq!(qs[1].category, "technical", "unknown category falls back");
+    }
+
+    #[test]
+    fn test_prompt_includes_bullet_ids_requirements_and_source() {
+        let bullet = make_bullet_row("Cut p99 latency 40% with a Redis cache", 0);
+        let entry = ContextEntryRow {
+            id: Uuid::new_v4(),
+            user_id: Uuid::new_v4(),
+            entry_id: bullet.source_entry_id,
+            version: 1,
+            entry_type: "experience".to_string(),
+            data: json!({"company": "Acme"}),
+            raw_text: None,
+            recency_score: 1.0,
+            impact_score: 0.5,
+            tags: vec![],
+            flagged_evergreen: false,
+            contribution_type: "team_member".to_string(),
+            quality_score: 1.0,
+            quality_flags: vec![],
+            created_at: Utc::now(),
+            recency_policy: None,
+        };
+        let entries_by_id: HashMap<Uuid, &ContextEntryRow> =
+            [(entry.entry_id, &entry)].into_iter().collect();
+
+        let prompt = build_interview_prep_prompt(
+            std::slice::from_ref(&bullet),
+            &entries_by_id,
+            &make_parsed_jd(),
+        )
+        .unwrap();
+        assert!(prompt.contains(&bullet.id.to_string()));
+        assert!(prompt.contains("5+ years Rust"));
+        assert!(prompt.contains("Acme"));
+        assert!(prompt.contains("team_member"));
+        assert!(!prompt.contains("{max_questions}"));
+    }
+}
@@ -0,0 +1,619 @@
+//! Deterministic JD extractor — builds a `ParsedJD` without the LLM.
+//!
+//! Used by `parse_jd` in OFFLINE_MODE. It follows the rules the JD parse prompt gives
+//! the model, with fixed vocabularies standing in for judgement:
+//! - the text is cut into segments (lines, then sentences), each assigned a section by
+//!   its leading marker ("Required:", "You will", "About us", …) or, failing that, the
+//!   section of the segment before it,
+//! - keywords are the known technical terms present, weighted by the best position
+//!   they appear in (title 1.0, requirements 0.8, responsibilities 0.6, about 0.3),
+//! - hard requirements are the comma-separated items of requirement and
+//!   nice-to-have segments; nice-to-haves double as soft signals,
+//! - tone is the tone whose cue words occur most (ties → CollaborativeEnterprise),
+//!   seniority is read from the title.
+//!
+//! The output is coarser than the LLM's — unknown tools are missed unless listed as
+//! a short requirement item — but stable for identical input.
+
+use crate::generation::jd_parser::{JDTone, ParsedJD};
+use templar_types::jd::{KeywordEntry, Requirement, RoleSignals};
+
+/// Known technical terms. Matched case-insensitively as whole terms.
+const TECH_TERMS: &[&str] = &[
+    // Languages
+    "Rust",
+    "Python",
+    "Java",
+    "Kotlin",
+    "Scala",
+    "TypeScript",
+    "JavaScript",
+    "C++",
+    "C#",
+    "Ruby",
+    "PHP",
+    "Elixir",
+    "Haskell",
+    "Clojure",
+    "Erlang",
+    "SQL",
+    "Bash",
+    "Solidity",
+    // Frameworks and libraries
+    "React",
+    "Next.js",
+    "Vue",
+    "Angular",
+    "Node.js",
+    "Django",
+    "Flask",
+    "FastAPI",
+    "Rails",
+    "Spring Boot",
+    "Spring",
+    ".NET",
+    "GraphQL",
+    "gRPC",
+    "REST",
+    "Tokio",
+    "Axum",
+    "PyTorch",
+    "TensorFlow",
+    "JAX",
+    "scikit-learn",
+    "Pandas",
+    "NumPy",
+    "Hugging Face",
+    "LangChain",
+    // Data and infrastructure
+    "PostgreSQL",
+    "Postgres",
+    "MySQL",
+    "SQLite",
+    "MongoDB",
+    "Redis",
+    "Cassandra",
+    "DynamoDB",
+    "Elasticsearch",
+    "ClickHouse",
+    "Snowflake",
+    "BigQuery",
+    "Kafka",
+    "RabbitMQ",
+    "Spark",
+    "Flink",
+    "Airflow",
+    "dbt",
+    "FAISS",
+    "Kubernetes",
+    "Docker",
+    "Terraform",
+    "Ansible",
+    "Helm",
+    "AWS",
+    "GCP",
+    "Azure",
+    "Linux",
+    "Nginx",
+    "Prometheus",
+    "Grafana",
+    "Datadog",
+    "OpenTelemetry",
+    "CI/CD",
+    "GitHub Actions",
+    "Jenkins",
+    "WebAssembly",
+    // Concepts
+    "distributed systems",
+    "microservices",
+    "machine learning",
+    "deep learning",
+    "LLM",
+    "NLP",
+    "computer vision",
+    "data pipelines",
+    "observability",
+    "systems programming",
+    "embedded",
+    "security",
+    "performance",
+    "scalability",
+    "reliability",
+    "on-call",
+];
+
+/// Terms that are also everyday words; only matched with this exact capitalisation.
+const CASE_SENSITIVE_TERMS: &[&str] = &["Go", "Swift", "C"];
+
+/// Longest requirement item (in words) also taken as a keyword.
+const MAX_ITEM_KEYWORD_WORDS: usize = 2;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Section {
+    Title,
+    Requirements,
+    NiceToHave,
+    Responsibilities,
+    About,
+    Body,
+}
+
+impl Section {
+    /// Keyword position weight, per the JD parse prompt.
+    fn weight(self) -> f32 {
+        match self {
+            Section::Title => 1.0,
+            Section::Requirements => 0.8,
+            Section::NiceToHave | Section::Responsibilities | Section::Body => 0.6,
+            Section::About => 0.3,
+        }
+    }
+}
+
+/// Leading markers, checked case-insensitively against the start of a segment.
+const SECTION_MARKERS: &[(&str, Section)] = &[
+    ("required", Section::Requirements),
+    ("requirements", Section::Requirements),
+    ("must have", Section::Requirements),
+    ("you need", Section::Requirements),
+    ("you have", Section::Requirements),
+    ("qualifications", Section::Requirements),
+    ("preferred", Section::NiceToHave),
+    ("nice to have", Section::NiceToHave),
+    ("bonus", Section::NiceToHave),
+    ("responsibilities", Section::Responsibilities),
+    ("you will", Section::Responsibilities),
+    ("what you'll do", Section::Responsibilities),
+    ("about", Section::About),
+];
+
+const TONE_CUES: &[(JDTone, &[&str])] = &[
+    (
+        JDTone::AggressiveStartup,
+        &[
+            "own",
+            "drive",
+            "move fast",
+            "fast-paced",
+            "spearhead",
+            "disrupt",
+            "hustle",
+        ],
+    ),
+    (
+        JDTone::CollaborativeEnterprise,
+        &[
+            "partner",
+            "collaborate",
+            "collaborative",
+            "contribute",
+            "support",
+            "cross-functional",
+        ],
+    ),
+    (
+        JDTone::ResearchOriented,
+        &[
+            "investigate",
+            "publish",
+            "publications",
+            "evaluate",
+            "propose",
+            "research",
+            "novel",
+        ],
+    ),
+    (
+        JDTone::ProductOriented,
+        &[
+            "ship",
+            "launch",
+            "deliver",
+            "customers",
+            "user experience",
+            "product",
+        ],
+    ),
+];
+
+/// Seniority words in the title, most senior first.
+const SENIORITY_CUES: &[(&str, &[&str])] = &[
+    ("director", &["director", "head of", "vp"]),
+    ("principal", &["principal", "distinguished"]),
+    ("staff", &["staff"]),
+    ("senior", &["senior", "sr", "lead"]),
+    (
+        "junior",
+        &["junior", "jr", "entry level", "graduate", "intern"],
+    ),
+    ("mid", &["mid", "mid-level", "intermediate"]),
+];
+
+/// Extracts a `ParsedJD` from `jd_text` with fixed rules; never fails.
+pub fn extract_jd(jd_text: &str) -> ParsedJD {
+    let segments = segment(jd_text);
+    let lowered = jd_text.to_lowercase();
+
+    let mut hard_requirements = vec![];
+    let mut soft_signals = vec![];
+    for (section, text) in &segments {
+        let is_required = match section {
+            Section::Requirements => true,
+            Section::NiceToHave => false,
+            _ => continue,
+        };
+        for item in requirement_items(text) {
+            if !is_required {
+                soft_signals.push(item.clone());
+            }
+            if !hard_requirements
+                .iter()
+                .any(|r: &Requirement| r.text == item)
+            {
+                hard_requirements.push(Requirement {
+                    text: item,
+                    is_required,
+                });
+            }
+        }
+    }
+
+    let title = segments
+        .iter()
+        .find(|(section, _)| *section == Section::Title)
+        .map(|(_, text)| text.to_lowercase())
+        .unwrap_or_default();
+
+    ParsedJD {
+        keyword_inventory: keyword_inventory(&segments, &hard_requirements),
+        hard_requirements,
+        soft_signals,
+        role_signals: RoleSignals {
+            is_startup: [
+                "startup",
+                "start-up",
+                "series a",
+                "series b",
+                "series c",
+                "seed-stage",
+                "early-stage",
+            ]
+            .iter()
+            .any(|cue| lowered.contains(cue)),
+            is_ic_focused: ![
+                "direct reports",
+                "people manager",
+                "manage a team",
+                "managing a team",
+                "hiring and managing",
+            ]
+            .iter()
+            .any(|cue| lowered.contains(cue)),
+            is_research: ["research", "phd", "publication", "publish"]
+                .iter()
+                .any(|cue| lowered.contains(cue)),
+            seniority: seniority(&title).to_string(),
+        },
+        detected_tone: detect_tone(&lowered),
+    }
+}
+
+// ────────────────────────────────────────────────────────────────────────────
+// Segmentation
+// ────────────────────────────────────────────────────────────────────────────
+
+/// Non-empty lines split into sentences, each with its section. The first line is
+/// the title; a segment without a marker continues the previous section, except that
+/// a new line after a one-line marker segment ("Required: Rust") falls back to body.
+fn segment(jd_text: &str) -> Vec<(Section, String)> {
+    let mut segments = vec![];
+    let mut current = Section::Body;
+    for (line_idx, line) in jd_text
+        .lines()
+        .map(str::trim)
+        .filter(|l| !l.is_empty())
+        .enumerate()
+    {
+        if line_idx == 0 {
+            segments.push((Section::Title, line.to_string()));
+            continue;
+        }
+        let mut line_section = None;
+        for sentence in split_sentences(line) {
+            let section = match leading_marker(sentence) {
+                Some(section) => section,
+                None => line_section.unwrap_or(current),
+            };
+            line_section = Some(section);
+            segments.push((section, sentence.to_string()));
+        }
+        // A header line on its own ("Requirements:") governs the lines below it.
+        if let Some(section) = line_section {
+            current = if line.ends_with(':') || leading_marker(line).is_none() {
+                section
+            } else {
+                Section::Body
+            };
+        }
+    }
+    segments
+}
+
+/// Splits on sentence-ending periods ("SQL. Preferred: …") but not inside terms
+/// like "Node.js" or "5.5".
+fn split_sentences(line: &str) -> Vec<&str> {
+    let mut sentences = vec![];
+    let mut start = 0;
+    let bytes = line.as_bytes();
+    for (i, &b) in bytes.iter().enumerate() {
+        if matches!(b, b'.' | b'!' | b'?' | b';') && bytes.get(i + 1).is_some_and(|n| *n == b' ') {
+            sentences.push(line[start..=i].trim());
+            start = i + 1;
+        }
+    }
+    sentences.push(line[start..].trim());
+    sentences.retain(|s| !s.is_empty());
+    sentences
+}
+
+fn leading_marker(segment: &str) -> Option<Section> {
+    let lowered = segment.to_lowercase();
+    SECTION_MARKERS
+        .iter()
+        .find(|(marker, _)| {
+            lowered.starts_with(marker)
+                && !lowered[marker.len()..]
+                    .chars()
+                    .next()
+                    .is_some_and(char::is_alphanumeric)
+        })
+        .map(|(_, section)| *section)
+}
+
+/// The listed items of a requirement segment: the text after its marker, split on
+/// commas, with filler ("experience", "a plus", "required") trimmed off.
+fn requirement_items(segment: &str) -> Vec<String> {
+    let body = segment.split_once(':').map_or(segment, |(_, rest)| rest);
+    body.split(',')
+        .map(|item| {
+            let mut item = item
+                .trim()
+                .trim_end_matches(['.', ';', '!'])
+                .trim()
+                .to_string();
+            for filler in [
+                " is a plus",
+                " a plus",
+                " required",
+                " preferred",
+                " experience",
+            ] {
+                let cut = item.len().saturating_sub(filler.len());
+                if item.is_char_boundary(cut) && item[cut..].eq_ignore_ascii_case(filler) {
+                    item.truncate(cut);
+                }
+            }
+            item.trim().to_string()
+        })
+        .filter(|item| item.chars().any(char::is_alphanumeric))
+        .collect()
+}
+
+// ────────────────────────────────────────────────────────────────────────────
+// Keywords, tone, seniority
+// ────────────────────────────────────────────────────────────────────────────
+
+fn keyword_inventory(
+    segments: &[(Section, String)],
+    requirements: &[Requirement],
+) -> Vec<KeywordEntry> {
+    let mut candidates: Vec<(&str, bool)> = TECH_TERMS
+        .iter()
+        .map(|t| (*t, false))
+        .chain(CASE_SENSITIVE_TERMS.iter().map(|t| (*t, true)))
+        .collect();
+    // Short requirement items name tools the vocabulary may not know.
+    for requirement in requirements {
+        let words = requirement.text.split_whitespace().count();
+        let known = candidates
+            .iter()
+            .any(|(t, _)| t.eq_ignore_ascii_case(&requirement.text));
+        if words <= MAX_ITEM_KEYWORD_WORDS && !known {
+            candidates.push((requirement.text.as_str(), false));
+        }
+    }
+
+    let mut entries: Vec<KeywordEntry> = candidates
+        .into_iter()
+        .filter_map(|(term, case_sensitive)| {
+            let mut frequency = 0u32;
+            let mut position_weight = 0f32;
+            for (section, text) in segments {
+                let count = count_term(text, term, case_sensitive);
+                if count > 0 {
+                    frequency += count;
+                    position_weight = position_weight.max(section.weight());
+                }
+            }
+            (frequency > 0).then(|| KeywordEntry {
+                keyword: term.to_string(),
+                frequency,
+                position_weight,
+                weighted_score: frequency as f32 * position_weight,
+            })
+        })
+        .collect();
+
+    // A term inside a longer matched one ("Spring" in "Spring Boot") adds nothing.
+    let keywords: Vec<String> = entries.iter().map(|e| e.keyword.to_lowercase()).collect();
+    entries.retain(|e| {
+        let own = e.keyword.to_lowercase();
+        !keywords
+            .iter()
+            .any(|other| *other != own && count_term(other, &own, false) > 0)
+    });
+    entries.sort_by(|a, b| {
+        b.weighted_score
+            .partial_cmp(&a.weighted_score)
+            .unwrap_or(std::cmp::Ordering::Equal)
+            .then_with(|| a.keyword.cmp(&b.keyword))
+    });
+    entries
+}
+
+/// Occurrences of `term` in `text` bounded by non-alphanumeric characters (or the
+/// ends of the text).
+fn count_term(text: &str, term: &str, case_sensitive: bool) -> u32 {
+    let (haystack, needle) = if case_sensitive {
+        (text.to_string(), term.to_string())
+    } else {
+        (text.to_lowercase(), term.to_lowercase())
+    };
+    if needle.is_empty() {
+        return 0;
+    }
+    let is_word = |c: Option<char>| c.is_some_and(char::is_alphanumeric);
+    haystack
+        .match_indices(&needle)
+        .filter(|(start, _)| {
+            let before = haystack[..*start].chars().next_back();
+            let after = haystack[start + needle.len()..].chars().next();
+            // A term ending in a symbol ("C++") is bounded by it, and "C" must not
+            // match the start of "C++" or "C#".
+            !is_word(before)
+                && (!is_word(after) || !needle.ends_with(|c: char| c.is_alphanumeric()))
+                && !matches!(after, Some('+' | '#'))
+        })
+        .count() as u32
+}
+
+fn detect_tone(lowered: &str) -> JDTone {
+    let mut best = (JDTone::default(), 0u32);
+    for (tone, cues) in TONE_CUES {
+        let hits: u32 = cues.iter().map(|cue| count_term(lowered, cue, false)).sum();
+        if hits > best.1 {
+            best = (tone.clone(), hits);
+        }
+    }
+    best.0
+}
+
+fn seniority(title: &str) -> &'static str {
+    SENIORITY_CUES
+        .iter()
+        .find(|(_, cues)| cues.iter().any(|cue| count_term(title, cue, false) > 0))
+        .map_or("unknown", |(level, _)| level)
+}
+
+// ────────────────────────────────────────────────────────────────────────────
+// Tests
+// ────────────────────────────────────────────────────────────────────────────
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const STARTUP_JD: &str = "Senior Rust Engineer — Core Infrastructure
+        We move fast and own everything end-to-end. You will architect distributed systems and drive reliability.
+        Requirements: 5+ years Rust, systems programming, distributed systems expertise.
+        Nice to have: Kubernetes, Kafka experience a plus.
+        About Us: Fast-paced Series B startup disrupting fintech infrastructure.";
+
+    const ENTERPRISE_JD: &str = "Software Engineer — Platform Team
+        Join our collaborative team to contribute to our microservices platform.
+        You will partner with product managers and support reliability goals.
+        Required: Java, Spring Boot, SQL. Preferred: Kubernetes, CI/CD experience.
+        About: Global enterprise with 50,000 employees focused on financial services.";
+
+    fn keywords(parsed: &ParsedJD) -> Vec<&str> {
+        parsed
+            .keyword_inventory
+            .iter()
+            .map(|k| k.keyword.as_str())
+            .collect()
+    }
+
+    #[test]
+    fn test_startup_jd() {
+        let parsed = extract_jd(STARTUP_JD);
+        assert_eq!(parsed.detected_tone, JDTone::AggressiveStartup);
+        assert_eq!(parsed.role_signals.seniority, "senior");
+        assert!(parsed.role_signals.is_startup);
+        assert!(!parsed.role_signals.is_research);
+
+        let rust = &parsed.keyword_inventory[0];
+        assert_eq!(rust.keyword, "Rust");
+        assert_eq!(rust.position_weight, 1.0);
+        assert_eq!(rust.frequency, 2);
+        assert!(keywords(&parsed).contains(&"Kafka"));
+
+        let required: Vec<&str> = parsed
+            .hard_requirements
+            .iter()
+            .filter(|r| r.is_required)
+            .map(|r| r.text.as_str())
+            .collect();
+        assert_eq!(
+            required,
+            [
+                "5+ years Rust",
+                "systems programming",
+                "distributed systems expertise"
+            ]
+        );
+        assert_eq!(parsed.soft_signals, ["Kubernetes", "Kafka"]);
+    }
+
+    #[test]
+    fn test_inline_sections_split_on_sentences() {
+        let parsed = extract_jd(ENTERPRISE_JD);
+        assert_eq!(parsed.detected_tone, JDTone::CollaborativeEnterprise);
+        assert_eq!(parsed.role_signals.seniority, "unknown");
+
+        let requirement = |text: &str| {
+            parsed
+                .hard_requirements
+                .iter()
+                .find(|r| r.text == text)
+                .map(|r| r.is_required)
+        };
+        assert_eq!(requirement("Spring Boot"), Some(true));
+        assert_eq!(requirement("Kubernetes"), Some(false));
+        assert_eq!(requirement("CI/CD"), Some(false));
+
+        let inventory = keywords(&parsed);
+        assert!(inventory.contains(&"Spring Boot"));
+        assert!(!inventory.contains(&"Spring"), "{inventory:?}");
+        let java = parsed
+            .keyword_inventory
+            .iter()
+            .find(|k| k.keyword == "Java")
+            .unwrap();
+        assert_eq!(java.position_weight, 0.8);
+    }
+
+    #[test]
+    fn test_term_boundaries() {
+        assert_eq!(count_term("Go and Google", "Go", true), 1);
+        assert_eq!(count_term("let's go", "Go", true), 0);
+        assert_eq!(count_term("C++ and C", "C", true), 1);
+        assert_eq!(count_term("c++, C++17", "c++", false), 2);
+        assert_eq!(count_term("Node.js services", "node.js", false), 1);
+        assert_eq!(count_term("trusted", "rust", false), 0);
+    }
+
+    #[test]
+    fn test_header_lines_govern_following_lines() {
+        let parsed =
+            extract_jd("Staff Engineer\nRequirements:\nPython, Airflow\nAbout us\nWe ship.");
+        assert_eq!(parsed.role_signals.seniority, "staff");
+        assert!(parsed
+            .hard_requirements
+            .iter()
+            .any(|r| r.text == "Airflow" && r.is_required));
+        assert_eq!(
+            serde_json::to_value(extract_jd(STARTUP_JD)).unwrap(),
+            serde_json::to_value(extract_jd(STARTUP_JD)).unwrap()
+        );
+    }
+}
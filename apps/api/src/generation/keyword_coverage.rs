@@ -0,0 +1,361 @@
+#![allow(dead_code)]
+
+//! Keyword coverage — which JD keywords actually made it into the generated bullets.
+//!
+//! The fit report answers "does my context cover this JD?". Coverage answers the
+//! follow-up question after generation: "did the resume actually use it?". A keyword
+//! can be a strong fit match and still be dropped by the LLM or by layout compression.
+//!
+//! Matching is deterministic and runs on the FINAL bullet text (post-simulation,
+//! post-grounding). `jd_keywords_used` is the LLM's self-report and is not trusted.
+
+use crate::generation::jd_parser::ParsedJD;
+use crate::layout::SimulatedBullet;
+
+// ────────────────────────────────────────────────────────────────────────────
+// Data models
+// ────────────────────────────────────────────────────────────────────────────
+
+pub use templar_types::generation::{CoverageReport, KeywordCoverage, KeywordSpan};
+
+// ────────────────────────────────────────────────────────────────────────────
+// Coverage computation
+// ────────────────────────────────────────────────────────────────────────────
+
+/// Computes keyword coverage of the final bullets against the JD keyword inventory.
+///
+/// A keyword is covered when it appears in at least one bullet as a whole term
+/// (case-insensitive, bounded by non-alphanumeric characters), so "Go" does not
+/// match "Google" and "Rust" does not match "trusted".
+pub fn compute_keyword_coverage(
+    parsed_jd: &ParsedJD,
+    bullets: &[SimulatedBullet],
+) -> CoverageReport {
+    let lowered: Vec<String> = bullets.iter().map(|b| b.text.to_lowercase()).collect();
+
+    let mut keywords: Vec<KeywordCoverage> = parsed_jd
+        .keyword_inventory
+        .iter()
+        .filter(|k| !k.keyword.trim().is_empty())
+        .map(|k| {
+            let needle = k.keyword.trim().to_lowercase();
+            let bullet_count = lowered
+                .iter()
+                .filter(|text| contains_term(text, &needle))
+                .count() as u32;
+            KeywordCoverage {
+                keyword: k.keyword.clone(),
+                weighted_score: k.weighted_score,
+                bullet_count,
+                covered: bullet_count > 0,
+            }
+        })
+        .collect();
+
+    keywords.sort_by(|a, b| {
+        b.weighted_score
+            .partial_cmp(&a.weighted_score)
+            .unwrap_or(std::cmp::Ordering::Equal)
+    });
+
+    let covered_keywords: Vec<String> = keywords
+        .iter()
+        .filter(|k| k.covered)
+        .map(|k| k.keyword.clone())
+        .collect();
+    let missing_keywords: Vec<String> = keywords
+        .iter()
+        .filter(|k| !k.covered)
+        .map(|k| k.keyword.clone())
+        .collect();
+
+    let coverage_ratio = if keywords.is_empty() {
+        1.0
+    } else {
+        covered_keywords.len() as f32 / keywords.len() as f32
+    };
+
+    let total_weight: f32 = keywords.iter().map(|k| k.weighted_score.max(0.0)).sum();
+    let covered_weight: f32 = keywords
+        .iter()
+        .filter(|k| k.covered)
+        .map(|k| k.weighted_score.max(0.0))
+        .sum();
+    let weighted_coverage = if total_weight > 0.0 {
+        covered_weight / total_weight
+    } else {
+        coverage_ratio
+    };
+
+    CoverageReport {
+        keywords,
+        covered_keywords,
+        missing_keywords,
+        coverage_ratio,
+        weighted_coverage,
+    }
+}
+
+/// Where each JD keyword appears in `text`, matched as whole terms the way
+/// [`compute_keyword_coverage`] matches them, ordered by position.
+///
+/// Where keywords overlap ("machine learning" and "learning"), the longer one keeps
+/// the span; spans never overlap.
+pub fn keyword_spans(parsed_jd: &ParsedJD, text: &str) -> Vec<KeywordSpan> {
+    let chars: Vec<char> = text.chars().collect();
+    let mut keywords: Vec<(&str, Vec<char>)> = parsed_jd
+        .keyword_inventory
+        .iter()
+        .map(|k| {
+            (
+                k.keyword.as_str(),
+                k.keyword.trim().chars().collect::<Vec<_>>(),
+            )
+        })
+        .filter(|(_, needle)| !needle.is_empty())
+        .collect();
+    keywords.sort_by(|a, b| b.1.len().cmp(&a.1.len()));
+
+    // Char ranges taken so far.
+    let mut taken: Vec<(&str, usize, usize)> = Vec::new();
+    for (keyword, needle) in &keywords {
+        for begin in 0..chars.len().saturating_sub(needle.len() - 1) {
+            let end = begin + needle.len();
+            let matches = chars[begin..end]
+                .iter()
+                .zip(needle)
+                .all(|(c, n)| c.to_lowercase().eq(n.to_lowercase()));
+            let bounded = (begin == 0 || !chars[begin - 1].is_alphanumeric())
+                && chars.get(end).is_none_or(|c| !c.is_alphanumeric());
+            if matches && bounded && !taken.iter().any(|&(_, s, e)| begin < e && s < end) {
+                taken.push((keyword, begin, end));
+            }
+        }
+    }
+    taken.sort_by_key(|&(_, begin, _)| begin);
+
+    let utf16_offset = |char_index: usize| -> u32 {
+        chars[..char_index]
+            .iter()
+            .map(|c| c.len_utf16() as u32)
+            .sum()
+    };
+    taken
+        .into_iter()
+        .map(|(keyword, begin, end)| KeywordSpan {
+            keyword: keyword.to_string(),
+            start: utf16_offset(begin),
+            end: utf16_offset(end),
+        })
+        .collect()
+}
+
+/// Returns true if `needle` occurs in `haystack` with non-alphanumeric boundaries
+/// on both sides. Both arguments must already be lowercase.
+pub(crate) fn contains_term(haystack: &str, needle: &str) -> bool {
+    if needle.is_empty() {
+        return false;
+    }
+    let mut start = 0;
+    while let Some(pos) = haystack[start..].find(needle) {
+        let begin = start + pos;
+        let end = begin + needle.len();
+        let before_ok = haystack[..begin]
+            .chars()
+            .next_back()
+            .map(|c| !c.is_alphanumeric())
+            .unwrap_or(true);
+        let after_ok = haystack[end..]
+            .chars()
+            .next()
+            .map(|c| !c.is_alphanumeric())
+            .unwrap_or(true);
+        if before_ok && after_ok {
+            return true;
+        }
+        // Advance by one char past the match start to find overlapping occurrences
+        start = begin
+            + haystack[begin..]
+                .chars()
+                .next()
+                .map(|c| c.len_utf8())
+                .unwrap_or(1);
+    }
+    false
+}
+
+// ────────────────────────────────────────────────────────────────────────────
+// Tests
+// ────────────────────────────────────────────────────────────────────────────
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::layout::LineType;
+    use templar_types::jd::{JDTone, KeywordEntry, RoleSignals};
+    use uuid::Uuid;
+
+    fn make_parsed_jd(keywords: Vec<(&str, f32)>) -> ParsedJD {
+        ParsedJD {
+            hard_requirements: vec![],
+            soft_signals: vec![],
+            role_signals: RoleSignals {
+                is_startup: false,
+                is_ic_focused: true,
+                is_research: false,
+                seniority: "senior".to_string(),
+            },
+            keyword_inventory: keywords
+                .into_iter()
+                .map(|(kw, score)| KeywordEntry {
+                    keyword: kw.to_string(),
+                    frequency: 1,
+                    position_weight: score,
+                    weighted_score: score,
+                })
+                .collect(),
+            detected_tone: JDTone::CollaborativeEnterprise,
+        }
+    }
+
+    fn make_bullet(text: &str) -> SimulatedBullet {
+        SimulatedBullet {
+            text: text.to_string(),
+            source_entry_id: Uuid::new_v4(),
+            section: "experience".to_string(),
+            line_type: LineType::Bullet,
+            verified_line_count: 1,
+            jd_keywords_used: vec![],
+            was_adjusted: false,
+            flagged_for_review: false,
+            revisions: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn test_keyword_spans_locate_whole_terms() {
+        let jd = make_parsed_jd(vec![("Rust", 4.0), ("Go", 1.0)]);
+        let text = "Ported Google's Go services to rust";
+        let spans = keyword_spans(&jd, text);
+        assert_eq!(
+            spans,
+            vec![
+                KeywordSpan {
+                    keyword: "Go".to_string(),
+                    start: 16,
+                    end: 18
+                },
+                KeywordSpan {
+                    keyword: "Rust".to_string(),
+                    start: 31,
+                    end: 35
+                },
+            ]
+        );
+        assert_eq!(&text[31..35], "rust");
+    }
+
+    #[test]
+    fn test_keyword_spans_prefer_the_longer_keyword_and_count_utf16_units() {
+        let jd = make_parsed_jd(vec![("learning", 2.0), ("machine learning", 1.0)]);
+        // "🚀" is one char but two UTF-16 code units.
+        let spans = keyword_spans(&jd, "🚀 Machine learning and learning");
+        assert_eq!(
+            spans,
+            vec![
+                KeywordSpan {
+                    keyword: "machine learning".to_string(),
+                    start: 3,
+                    end: 19
+                },
+                KeywordSpan {
+                    keyword: "learning".to_string(),
+                    start: 24,
+                    end: 32
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_missing_keyword_is_reported() {
+        let jd = make_parsed_jd(vec![("Rust", 4.0), ("Kubernetes", 2.0)]);
+        let bullets = vec![make_bullet(
+            "Built a Rust ingestion service handling 10k rps",
+        )];
+
+        let report = compute_keyword_coverage(&jd, &bullets);
+        assert_eq!(report.covered_keywords, vec!["Rust".to_string()]);
+        assert_eq!(report.missing_keywords, vec!["Kubernetes".to_string()]);
+        assert!((report.coverage_ratio - 0.5).abs() < 1e-5);
+        assert!(
+            (report.weighted_coverage - 4.0 / 6.0).abs() < 1e-5,
+            "weighted_coverage={}",
+            report.weighted_coverage
+        );
+    }
+
+    #[test]
+    fn test_matching_is_case_insensitive() {
+        let jd = make_parsed_jd(vec![("kubernetes", 1.0)]);
+        let bullets = vec![make_bullet("Migrated 40 services onto Kubernetes")];
+        let report = compute_keyword_coverage(&jd, &bullets);
+        assert!(report.missing_keywords.is_empty());
+        assert_eq!(report.keywords[0].bullet_count, 1);
+    }
+
+    #[test]
+    fn test_short_keyword_does_not_match_inside_word() {
+        let jd = make_parsed_jd(vec![("Go", 1.0), ("Rust", 1.0)]);
+        let bullets = vec![make_bullet(
+            "Integrated Google APIs into a trusted billing pipeline",
+        )];
+        let report = compute_keyword_coverage(&jd, &bullets);
+        assert_eq!(
+            report.covered_keywords.len(),
+            0,
+            "Go must not match Google and Rust must not match trusted"
+        );
+    }
+
+    #[test]
+    fn test_multi_word_and_punctuated_keywords_match() {
+        let jd = make_parsed_jd(vec![("distributed systems", 2.0), ("C++", 1.0)]);
+        let bullets = vec![
+            make_bullet("Designed distributed systems for payments"),
+            make_bullet("Rewrote hot path in C++, cutting latency 30%"),
+        ];
+        let report = compute_keyword_coverage(&jd, &bullets);
+        assert_eq!(report.missing_keywords.len(), 0);
+    }
+
+    #[test]
+    fn test_bullet_count_counts_each_bullet_once() {
+        let jd = make_parsed_jd(vec![("Rust", 1.0)]);
+        let bullets = vec![
+            make_bullet("Rust service, Rust CLI"),
+            make_bullet("Ported Python tooling to Rust"),
+            make_bullet("Led hiring for the platform team"),
+        ];
+        let report = compute_keyword_coverage(&jd, &bullets);
+        assert_eq!(report.keywords[0].bullet_count, 2);
+    }
+
+    #[test]
+    fn test_keywords_sorted_by_weight_descending() {
+        let jd = make_parsed_jd(vec![("Kafka", 1.0), ("Rust", 5.0), ("gRPC", 3.0)]);
+        let report = compute_keyword_coverage(&jd, &[]);
+        let order: Vec<&str> = report.keywords.iter().map(|k| k.keyword.as_str()).collect();
+        assert_eq!(order, vec!["Rust", "gRPC", "Kafka"]);
+        assert_eq!(report.missing_keywords, vec!["Rust", "gRPC", "Kafka"]);
+    }
+
+    #[test]
+    fn test_empty_inventory_is_fully_covered() {
+        let jd = make_parsed_jd(vec![]);
+        let report = compute_keyword_coverage(&jd, &[make_bullet("Anything")]);
+        assert!(report.keywords.is_empty());
+        assert_eq!(report.coverage_ratio, 1.0);
+        assert_eq!(report.weighted_coverage, 1.0);
+    }
+}
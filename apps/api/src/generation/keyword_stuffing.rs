@@ -0,0 +1,397 @@
+//! Keyword stuffing — does the resume force JD keywords in where they don't belong?
+//!
+//! The generation prompt forbids stuffing; this verifies it on the output. Matching is
+//! deterministic and whole-term, like `keyword_coverage`:
+//! - per bullet: JD keywords the bullet uses that its source entry never mentions are
+//!   "forced". `MAX_FORCED_KEYWORDS` or more flags the bullet.
+//! - across the resume: a keyword in more than `MAX_KEYWORD_SHARE` of the bullets
+//!   reads as repetition, however well grounded each use is.
+//!
+//! Flags are advisory. With `rewrite_stuffed_bullets` on the request, flagged bullets
+//! also get one grounding-style rewrite that drops the forced keywords; the rewrite is
+//! kept only if it forces fewer of them. Education lines and the summary are not
+//! drafted bullets (see `static_sections`) and are not checked.
+
+use std::collections::HashMap;
+
+use tracing::warn;
+use uuid::Uuid;
+
+use crate::generation::jd_parser::ParsedJD;
+use crate::generation::keyword_coverage::contains_term;
+use crate::generation::static_sections::is_static_section;
+use crate::grounding::scorer::regenerate_single_bullet;
+use crate::layout::SimulatedBullet;
+use crate::llm_client::LlmClient;
+use crate::models::context::ContextEntryRow;
+
+pub use templar_types::generation::{KeywordStuffingReport, RepeatedKeyword, StuffedBullet};
+
+/// Forced keywords at which a bullet is flagged.
+pub const MAX_FORCED_KEYWORDS: usize = 3;
+
+/// Share of bullets above which one keyword counts as repeated.
+pub const MAX_KEYWORD_SHARE: f32 = 0.4;
+
+/// Fewer checked bullets than this are too few to call anything repeated.
+const MIN_BULLETS_FOR_REPETITION: usize = 5;
+
+// ────────────────────────────────────────────────────────────────────────────
+// Check
+// ────────────────────────────────────────────────────────────────────────────
+
+/// Checks `bullets` against the JD keyword inventory. `entries` are the bullets' source
+/// entries; a bullet whose entry is missing has no forced keywords.
+pub fn check_keyword_stuffing(
+    parsed_jd: &ParsedJD,
+    bullets: &[SimulatedBullet],
+    entries: &[ContextEntryRow],
+) -> KeywordStuffingReport {
+    let keywords = jd_keywords(parsed_jd);
+    let sources = source_texts(entries);
+
+    let mut matches = 0usize;
+    let mut words = 0usize;
+    let mut checked = 0usize;
+    let mut counts: HashMap<&str, u32> = HashMap::new();
+    let mut stuffed_bullets = Vec::new();
+    for (index, bullet) in bullets.iter().enumerate() {
+        if is_static_section(&bullet.section) {
+            continue;
+        }
+        checked += 1;
+        let used = keywords_in(&keywords, &bullet.text);
+        let word_count = bullet.text.split_whitespace().count();
+        matches += used.len();
+        words += word_count;
+        for keyword in &used {
+            *counts.entry(keyword).or_default() += 1;
+        }
+
+        let forced = forced_keywords(&used, sources.get(&bullet.source_entry_id));
+        if forced.len() >= MAX_FORCED_KEYWORDS {
+            stuffed_bullets.push(StuffedBullet {
+                index,
+                forced_keywords: forced.into_iter().map(str::to_string).collect(),
+                keyword_density: density(used.len(), word_count),
+            });
+        }
+    }
+
+    let mut repeated_keywords: Vec<RepeatedKeyword> = if checked >= MIN_BULLETS_FOR_REPETITION {
+        keywords
+            .iter()
+            .filter_map(|keyword| {
+                let bullet_count = *counts.get(keyword.as_str())?;
+                let share = bullet_count as f32 / checked as f32;
+                (share > MAX_KEYWORD_SHARE).then(|| RepeatedKeyword {
+                    keyword: keyword.clone(),
+                    bullet_count,
+                    share,
+                })
+            })
+            .collect()
+    } else {
+        Vec::new()
+    };
+    repeated_keywords.sort_by(|a, b| b.bullet_count.cmp(&a.bullet_count));
+
+    KeywordStuffingReport {
+        keyword_density: density(matches, words),
+        stuffed_bullets,
+        repeated_keywords,
+        rewritten: 0,
+    }
+}
+
+// ────────────────────────────────────────────────────────────────────────────
+// Rewrite
+// ────────────────────────────────────────────────────────────────────────────
+
+/// Rewrites each flagged bullet once to drop its forced keywords, in place. A rewrite
+/// that does not force fewer keywords is discarded. Returns how many were kept.
+pub async fn rewrite_stuffed_bullets(
+    bullets: &mut [SimulatedBullet],
+    parsed_jd: &ParsedJD,
+    entries: &[ContextEntryRow],
+    llm: &LlmClient,
+) -> u32 {
+    let report = check_keyword_stuffing(parsed_jd, bullets, entries);
+    let keywords = jd_keywords(parsed_jd);
+    let sources = source_texts(entries);
+
+    let mut rewritten = 0;
+    for stuffed in report.stuffed_bullets {
+        let bullet = &bullets[stuffed.index];
+        let Some(entry) = entries
+            .iter()
+            .find(|e| e.entry_id == bullet.source_entry_id)
+        else {
+            continue;
+        };
+        let reason = format!(
+            "Keyword stuffing: the bullet names {}, which the source data does not mention. \
+             Remove them and keep only what the source supports.",
+            stuffed.forced_keywords.join(", ")
+        );
+        let candidate = match regenerate_single_bullet(bullet, entry, &reason, llm).await {
+            Ok(candidate) => candidate,
+            Err(e) => {
+                warn!(error = %e, "Keyword-stuffing rewrite failed — keeping original bullet");
+                continue;
+            }
+        };
+        let still_forced = forced_keywords(
+            &keywords_in(&keywords, &candidate.text),
+            sources.get(&bullet.source_entry_id),
+        );
+        if still_forced.len() < stuffed.forced_keywords.len() {
+            bullets[stuffed.index] = candidate;
+            rewritten += 1;
+        }
+    }
+    rewritten
+}
+
+// ────────────────────────────────────────────────────────────────────────────
+// Helpers
+// ────────────────────────────────────────────────────────────────────────────
+
+/// The JD's keywords, lowercased and deduplicated, highest weight first.
+fn jd_keywords(parsed_jd: &ParsedJD) -> Vec<String> {
+    let mut inventory: Vec<_> = parsed_jd
+        .keyword_inventory
+        .iter()
+        .filter(|k| !k.keyword.trim().is_empty())
+        .collect();
+    inventory.sort_by(|a, b| {
+        b.weighted_score
+            .partial_cmp(&a.weighted_score)
+            .unwrap_or(std::cmp::Ordering::Equal)
+    });
+    let mut keywords: Vec<String> = Vec::with_capacity(inventory.len());
+    for k in inventory {
+        let keyword = k.keyword.trim().to_lowercase();
+        if !keywords.contains(&keyword) {
+            keywords.push(keyword);
+        }
+    }
+    keywords
+}
+
+/// The keywords `text` uses as whole terms, in `keywords` order.
+fn keywords_in<'k>(keywords: &'k [String], text: &str) -> Vec<&'k str> {
+    let lowered = text.to_lowercase();
+    keywords
+        .iter()
+        .filter(|k| contains_term(&lowered, k))
+        .map(String::as_str)
+        .collect()
+}
+
+/// The keywords in `used` that `source` does not contain. None when the source entry
+/// is unknown: without it nothing can be called forced.
+fn forced_keywords<'k>(used: &[&'k str], source: Option<&String>) -> Vec<&'k str> {
+    match source {
+        Some(source) => used
+            .iter()
+            .copied()
+            .filter(|k| !contains_term(source, k))
+            .collect(),
+        None => Vec::new(),
+    }
+}
+
+/// Every string in each entry's data, plus its raw text, lowercased — what a bullet
+/// may legitimately draw on.
+fn source_texts(entries: &[ContextEntryRow]) -> HashMap<Uuid, String> {
+    entries
+        .iter()
+        .map(|entry| {
+            let mut text = String::new();
+            collect_strings(&entry.data, &mut text);
+            if let Some(raw) = &entry.raw_text {
+                text.push_str(raw);
+            }
+            (entry.entry_id, text.to_lowercase())
+        })
+        .collect()
+}
+
+fn collect_strings(value: &serde_json::Value, out: &mut String) {
+    match value {
+        serde_json::Value::String(s) => {
+            out.push_str(s);
+            out.push('\n');
+        }
+        serde_json::Value::Array(items) => items.iter().for_each(|v| collect_strings(v, out)),
+        serde_json::Value::Object(map) => map.values().for_each(|v| collect_strings(v, out)),
+        _ => {}
+    }
+}
+
+fn density(matches: usize, words: usize) -> f32 {
+    if words == 0 {
+        0.0
+    } else {
+        matches as f32 / words as f32
+    }
+}
+
+// ────────────────────────────────────────────────────────────────────────────
+// Tests
+// ────────────────────────────────────────────────────────────────────────────
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::generation::static_sections::EDUCATION_SECTION;
+    use crate::layout::LineType;
+    use serde_json::json;
+    use templar_types::jd::{JDTone, KeywordEntry, RoleSignals};
+
+    fn jd(keywords: &[&str]) -> ParsedJD {
+        ParsedJD {
+            hard_requirements: vec![],
+            soft_signals: vec![],
+            role_signals: RoleSignals {
+                is_startup: false,
+                is_ic_focused: true,
+                is_research: false,
+                seniority: "senior".to_string(),
+            },
+            keyword_inventory: keywords
+                .iter()
+                .enumerate()
+                .map(|(i, k)| KeywordEntry {
+                    keyword: k.to_string(),
+                    frequency: 1,
+                    position_weight: 1.0,
+                    weighted_score: 10.0 - i as f32,
+                })
+                .collect(),
+            detected_tone: JDTone::default(),
+        }
+    }
+
+    fn entry(id: Uuid, data: serde_json::Value) -> ContextEntryRow {
+        ContextEntryRow {
+            id: Uuid::new_v4(),
+            user_id: Uuid::nil(),
+            entry_id: id,
+            version: 1,
+            entry_type: "experience".to_string(),
+            data,
+            raw_text: None,
+            recency_score: 1.0,
+            impact_score: 1.0,
+            tags: vec![],
+            flagged_evergreen: false,
+            contribution_type: "primary_contributor".to_string(),
+            quality_score: 1.0,
+            quality_flags: vec![],
+            created_at: chrono::Utc::now(),
+            recency_policy: None,
+        }
+    }
+
+    fn bullet(source_entry_id: Uuid, section: &str, text: &str) -> SimulatedBullet {
+        SimulatedBullet {
+            text: text.to_string(),
+            source_entry_id,
+            section: section.to_string(),
+            line_type: LineType::Bullet,
+            verified_line_count: 1,
+            jd_keywords_used: vec![],
+            was_adjusted: false,
+            flagged_for_review: false,
+            revisions: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn test_forced_keywords_flag_a_bullet() {
+        let id = Uuid::new_v4();
+        let entries = [entry(
+            id,
+            json!({"tech_stack": ["Rust"], "bullets": [{"text": "Built a Rust ingest service"}]}),
+        )];
+        let bullets = [
+            bullet(id, "experience", "Built a Rust ingest service"),
+            bullet(
+                id,
+                "experience",
+                "Built a Rust Kafka Kubernetes Terraform ingest service",
+            ),
+        ];
+
+        let report = check_keyword_stuffing(
+            &jd(&["Rust", "Kafka", "Kubernetes", "Terraform"]),
+            &bullets,
+            &entries,
+        );
+
+        assert_eq!(report.stuffed_bullets.len(), 1);
+        let stuffed = &report.stuffed_bullets[0];
+        assert_eq!(stuffed.index, 1);
+        assert_eq!(
+            stuffed.forced_keywords,
+            vec!["kafka", "kubernetes", "terraform"]
+        );
+        assert!((stuffed.keyword_density - 4.0 / 8.0).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_grounded_keywords_are_not_forced() {
+        let id = Uuid::new_v4();
+        let entries = [entry(
+            id,
+            json!({"description": "Ran Kafka and Kubernetes with Terraform"}),
+        )];
+        let bullets = [bullet(
+            id,
+            "experience",
+            "Ran Kafka on Kubernetes, provisioned with Terraform",
+        )];
+
+        let report = check_keyword_stuffing(
+            &jd(&["Kafka", "Kubernetes", "Terraform"]),
+            &bullets,
+            &entries,
+        );
+
+        assert!(report.stuffed_bullets.is_empty());
+    }
+
+    #[test]
+    fn test_keyword_in_most_bullets_is_repeated() {
+        let id = Uuid::new_v4();
+        let entries = [entry(id, json!({"description": "Python services"}))];
+        let mut bullets: Vec<SimulatedBullet> = (0..3)
+            .map(|i| bullet(id, "experience", &format!("Shipped Python service {i}")))
+            .collect();
+        bullets.extend((0..3).map(|i| bullet(id, "projects", &format!("Wrote parser {i}"))));
+        // Education lines neither count towards the share nor get flagged.
+        bullets.push(bullet(id, EDUCATION_SECTION, "BSc, Python University"));
+
+        let report = check_keyword_stuffing(&jd(&["Python"]), &bullets, &entries);
+
+        assert_eq!(report.repeated_keywords.len(), 1);
+        assert_eq!(report.repeated_keywords[0].bullet_count, 3);
+        assert!((report.repeated_keywords[0].share - 0.5).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_too_few_bullets_are_never_repeated() {
+        let id = Uuid::new_v4();
+        let bullets: Vec<SimulatedBullet> = (0..4)
+            .map(|i| bullet(id, "experience", &format!("Shipped Python service {i}")))
+            .collect();
+
+        let report = check_keyword_stuffing(&jd(&["Python"]), &bullets, &[]);
+
+        assert!(report.repeated_keywords.is_empty());
+        assert!(report.stuffed_bullets.is_empty());
+        assert!(report.keyword_density > 0.0);
+    }
+}
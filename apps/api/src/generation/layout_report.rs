@@ -0,0 +1,260 @@
+#![allow(dead_code)]
+
+//! Layout report — what the layout pipeline did to a resume, persisted for the UI.
+//!
+//! Simulation counters and the page-fill verdict are otherwise only logged. The report
+//! keeps them on the resume row so GET /resumes/:id can say "2 bullets flagged for
+//! review" and why: a line-contract violation the adjust loop could not fix, a
+//! demotion that left the page over the two-line cap, or a grounding rewrite that
+//! still failed.
+
+use serde::{Deserialize, Serialize};
+
+use crate::grounding::types::{GroundingResult, GroundingVerdict};
+use crate::layout::contract::{check_line_contract, LineCoverageVerdict};
+use crate::layout::font_metrics::get_metrics;
+use crate::layout::page_fill::{FillAction, PageFillAnalysis};
+use crate::layout::simulator::SimulationResult;
+use crate::layout::{PageConfig, SimulatedBullet};
+
+// ────────────────────────────────────────────────────────────────────────────
+// Data models
+// ────────────────────────────────────────────────────────────────────────────
+
+/// Why a bullet was flagged for human review.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum FlagReason {
+    /// The final text still violates the line coverage contract.
+    LineContract { verdict: LineCoverageVerdict },
+    /// A 2-line bullet the simulator could not demote while over the two-line cap.
+    TwoLineCap,
+    /// Grounding still failed after the rewrite attempt.
+    Grounding { rejection_reason: Option<String> },
+}
+
+/// One flagged bullet, addressed by its persisted `position`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FlaggedBullet {
+    pub position: usize,
+    pub text: String,
+    pub section: String,
+    pub reason: FlagReason,
+}
+
+/// Layout summary persisted on the resume row (`resumes.layout_report`).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LayoutReport {
+    /// Font size the page was laid out at.
+    pub font_size_pt: f32,
+    pub total_passes: u8,
+    /// LLM calls across simulation, fill remediation and every font-size attempt.
+    pub llm_calls_made: u32,
+    pub local_compressions: u32,
+    /// Bullets expanded from their source entry's facts (see `layout::local_expand`).
+    #[serde(default)]
+    pub local_expansions: u32,
+    /// Remediation actions of the kept layout, in the order they were attempted.
+    pub fill_actions: Vec<FillAction>,
+    /// Fill of the final printed page (header, bullets and education).
+    pub page_fill: PageFillAnalysis,
+    pub flagged_count: u32,
+    pub flagged: Vec<FlaggedBullet>,
+}
+
+// ────────────────────────────────────────────────────────────────────────────
+// Builder
+// ────────────────────────────────────────────────────────────────────────────
+
+/// Builds the report from the final `(bullet, grounding)` pairs in persisted order.
+pub fn build_layout_report(
+    pairs: &[(SimulatedBullet, GroundingResult)],
+    simulation: &SimulationResult,
+    fill_actions: &[FillAction],
+    page_fill: &PageFillAnalysis,
+    config: &PageConfig,
+) -> LayoutReport {
+    let flagged: Vec<FlaggedBullet> = pairs
+        .iter()
+        .enumerate()
+        .filter(|(_, (b, _))| b.flagged_for_review)
+        .map(|(position, (bullet, grounding))| FlaggedBullet {
+            position,
+            text: bullet.text.clone(),
+            section: bullet.section.clone(),
+            reason: flag_reason(bullet, grounding, config),
+        })
+        .collect();
+
+    LayoutReport {
+        font_size_pt: config.font_size_pt,
+        total_passes: simulation.total_passes,
+        llm_calls_made: simulation.llm_calls_made,
+        local_compressions: simulation.local_compressions,
+        local_expansions: simulation.local_expansions,
+        fill_actions: fill_actions.to_vec(),
+        page_fill: page_fill.clone(),
+        flagged_count: flagged.len() as u32,
+        flagged,
+    }
+}
+
+/// Re-derives the flag reason from the final text: a contract violation wins, then a
+/// grounding failure; a flagged bullet that satisfies both was a cap demotion.
+fn flag_reason(
+    bullet: &SimulatedBullet,
+    grounding: &GroundingResult,
+    config: &PageConfig,
+) -> FlagReason {
+    let metrics = get_metrics(&config.font);
+    let contract = check_line_contract(
+        0,
+        &bullet.text,
+        bullet.line_type,
+        metrics,
+        &config.for_section(&bullet.section),
+    );
+    if contract.verdict != LineCoverageVerdict::Satisfies {
+        return FlagReason::LineContract {
+            verdict: contract.verdict,
+        };
+    }
+    if grounding.verdict == GroundingVerdict::Fail {
+        return FlagReason::Grounding {
+            rejection_reason: grounding.rejection_reason.clone(),
+        };
+    }
+    FlagReason::TwoLineCap
+}
+
+// ────────────────────────────────────────────────────────────────────────────
+// Tests
+// ────────────────────────────────────────────────────────────────────────────
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::grounding::types::GroundingScore;
+    use crate::layout::page_fill::PageFillVerdict;
+    use crate::layout::{default_page_config, FontFamily, LineType};
+    use uuid::Uuid;
+
+    fn make_pair(
+        text: &str,
+        flagged: bool,
+        verdict: GroundingVerdict,
+    ) -> (SimulatedBullet, GroundingResult) {
+        let bullet = SimulatedBullet {
+            text: text.to_string(),
+            source_entry_id: Uuid::new_v4(),
+            section: "experience".to_string(),
+            line_type: LineType::Bullet,
+            verified_line_count: 1,
+            jd_keywords_used: vec![],
+            was_adjusted: false,
+            flagged_for_review: flagged,
+            revisions: Vec::new(),
+        };
+        let grounding = GroundingResult {
+            bullet_text: text.to_string(),
+            source_entry_id: bullet.source_entry_id,
+            score: GroundingScore::compute(0.5, 0.5, 0.5, 0.5),
+            verdict,
+            rejection_reason: Some("scope inflation".to_string()),
+        };
+        (bullet, grounding)
+    }
+
+    fn simulation() -> SimulationResult {
+        SimulationResult {
+            bullets: vec![],
+            total_passes: 2,
+            violations_remaining: 1,
+            flagged_count: 1,
+            llm_calls_made: 3,
+            local_compressions: 1,
+            local_expansions: 0,
+        }
+    }
+
+    fn page_fill() -> PageFillAnalysis {
+        PageFillAnalysis {
+            total_lines_used: 40,
+            total_lines_available: 45,
+            whitespace_fraction: 0.11,
+            overflow_fraction: 0.0,
+            verdict: PageFillVerdict::Acceptable,
+            structural_lines: 3,
+            columns: vec![],
+        }
+    }
+
+    /// A bullet that fills one line to ~90%.
+    fn full_line(config: &PageConfig) -> String {
+        let metrics = get_metrics(&config.font);
+        let mut text = "Built".to_string();
+        while metrics.measure_str(&format!("{text} infra")) < config.text_width_em * 0.9 {
+            text.push_str(" infra");
+        }
+        text
+    }
+
+    #[test]
+    fn test_only_flagged_bullets_are_listed_with_positions() {
+        let config = default_page_config(FontFamily::Inter);
+        let text = full_line(&config);
+        let pairs = vec![
+            make_pair(&text, false, GroundingVerdict::Pass),
+            make_pair("Built tools", true, GroundingVerdict::Pass),
+        ];
+        let report = build_layout_report(&pairs, &simulation(), &[], &page_fill(), &config);
+
+        assert_eq!(report.flagged_count, 1);
+        assert_eq!(report.flagged[0].position, 1);
+        assert!(matches!(
+            report.flagged[0].reason,
+            FlagReason::LineContract {
+                verdict: LineCoverageVerdict::TooShort { .. }
+            }
+        ));
+        assert_eq!(report.total_passes, 2);
+        assert_eq!(report.llm_calls_made, 3);
+        assert_eq!(report.font_size_pt, config.font_size_pt);
+    }
+
+    #[test]
+    fn test_grounding_failure_and_cap_reasons() {
+        let config = default_page_config(FontFamily::Inter);
+        let text = full_line(&config);
+        let pairs = vec![
+            make_pair(&text, true, GroundingVerdict::Fail),
+            make_pair(&text, true, GroundingVerdict::FlagForReview),
+        ];
+        let report = build_layout_report(&pairs, &simulation(), &[], &page_fill(), &config);
+
+        assert_eq!(
+            report.flagged[0].reason,
+            FlagReason::Grounding {
+                rejection_reason: Some("scope inflation".to_string())
+            }
+        );
+        assert_eq!(report.flagged[1].reason, FlagReason::TwoLineCap);
+    }
+
+    #[test]
+    fn test_report_serializes_tagged_reasons() {
+        let config = default_page_config(FontFamily::Inter);
+        let pairs = vec![make_pair("Built tools", true, GroundingVerdict::Pass)];
+        let report = build_layout_report(
+            &pairs,
+            &simulation(),
+            &[FillAction::TightenSpacing],
+            &page_fill(),
+            &config,
+        );
+        let value = serde_json::to_value(&report).unwrap();
+        assert_eq!(value["flagged"][0]["reason"]["kind"], "line_contract");
+        assert_eq!(value["page_fill"]["verdict"], "Acceptable");
+        assert_eq!(value["fill_actions"][0], "TightenSpacing");
+    }
+}
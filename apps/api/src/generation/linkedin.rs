@@ -0,0 +1,350 @@
+#![allow(dead_code)]
+
+//! LinkedIn profile copy — headline and About section tuned to a target role.
+//!
+//! Reuses the resume pipeline's front half (parse_jd → get_current_entries →
+//! select_content) so the same entries that would lead a tailored resume lead the
+//! profile copy. LinkedIn's field limits are enforced here, not trusted to the LLM:
+//! an over-length response is retried once with explicit feedback, then trimmed
+//! at a sentence boundary as a last resort.
+
+use serde::{Deserialize, Serialize};
+use sqlx::PgPool;
+use tracing::warn;
+use uuid::Uuid;
+use validator::Validate;
+
+use crate::context::versioning::get_current_entries;
+use crate::errors::AppError;
+use crate::generation::content_selector::{select_content, SelectionResult};
+use crate::generation::jd_parser::{parse_jd, ParsedJD};
+use crate::generation::prompts::{LINKEDIN_PROMPT_TEMPLATE, LINKEDIN_SYSTEM};
+use crate::llm_client::prompts::SCOPE_INSTRUCTION;
+use crate::llm_client::LlmClient;
+use crate::users::preferences;
+use crate::validation::{not_blank, MAX_JD_TEXT_CHARS};
+
+/// LinkedIn's hard limit for the headline field.
+pub const HEADLINE_MAX_CHARS: usize = 220;
+/// LinkedIn's hard limit for the About section.
+pub const ABOUT_MAX_CHARS: usize = 2_600;
+
+// ────────────────────────────────────────────────────────────────────────────
+// Data models
+// ────────────────────────────────────────────────────────────────────────────
+
+/// Request body for `POST /api/v1/linkedin/generate`.
+#[derive(Debug, Clone, Deserialize, Validate)]
+pub struct LinkedInRequest {
+    /// Set from the authenticated caller — never accepted from the request body.
+    #[serde(skip)]
+    pub user_id: Uuid,
+    /// Job description (or a short description of the target role).
+    #[validate(custom(function = "not_blank"), length(max = MAX_JD_TEXT_CHARS))]
+    pub jd_text: String,
+}
+
+/// Generated LinkedIn copy. Both fields are guaranteed to be within LinkedIn's limits.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LinkedInProfile {
+    pub headline: String,
+    pub about: String,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct LinkedInResponse {
+    pub profile: LinkedInProfile,
+    /// Entry IDs the copy was generated from, highest-ranked first.
+    pub source_entry_ids: Vec<Uuid>,
+    /// True if the final text had to be trimmed in Rust after the retry.
+    pub was_truncated: bool,
+}
+
+// ────────────────────────────────────────────────────────────────────────────
+// Pipeline
+// ────────────────────────────────────────────────────────────────────────────
+
+/// Generates a LinkedIn headline + About section for the user against a target role.
+pub async fn generate_linkedin_profile(
+    pool: &PgPool,
+    llm: &LlmClient,
+    request: LinkedInRequest,
+) -> Result<LinkedInResponse, AppError> {
+    let parsed_jd = parse_jd(&request.jd_text, llm).await?;
+
+    let entries = get_current_entries(pool, request.user_id)
+        .await
+        .map_err(AppError::Internal)?;
+    if entries.is_empty() {
+        return Err(AppError::Validation(
+            "No context entries found. Add context before generating LinkedIn copy.".to_string(),
+        ));
+    }
+
+    let weights = preferences::load(pool, request.user_id)
+        .await?
+        .scoring_weights
+        .unwrap_or_default();
+    let selection = select_content(entries, &parsed_jd, &weights);
+    let source_entry_ids = selection
+        .selected_entries
+        .iter()
+        .map(|re| re.entry.entry_id)
+        .collect();
+
+    let prompt = build_linkedin_prompt(&parsed_jd, &selection, None)?;
+    let mut profile: LinkedInProfile = llm
+        .call_json(&prompt, LINKEDIN_SYSTEM)
+        .await
+        .map_err(|e| AppError::Llm(format!("LinkedIn generation failed: {e}")))?;
+
+    if let Some(feedback) = length_violation(&profile) {
+        warn!(%feedback, "LinkedIn copy over length — retrying once");
+        let retry_prompt = build_linkedin_prompt(&parsed_jd, &selection, Some(&feedback))?;
+        match llm
+            .call_json::<LinkedInProfile>(&retry_prompt, LINKEDIN_SYSTEM)
+            .await
+        {
+            Ok(retried) => profile = retried,
+            Err(e) => warn!(error = %e, "LinkedIn retry failed — trimming first attempt"),
+        }
+    }
+
+    let was_truncated = length_violation(&profile).is_some();
+    let profile = LinkedInProfile {
+        headline: truncate_at_boundary(profile.headline.trim(), HEADLINE_MAX_CHARS),
+        about: truncate_at_boundary(profile.about.trim(), ABOUT_MAX_CHARS),
+    };
+
+    Ok(LinkedInResponse {
+        profile,
+        source_entry_ids,
+        was_truncated,
+    })
+}
+
+fn build_linkedin_prompt(
+    parsed_jd: &ParsedJD,
+    selection: &SelectionResult,
+    length_feedback: Option<&str>,
+) -> Result<String, AppError> {
+    let entries_json = serde_json::to_string_pretty(
+        &selection
+            .selected_entries
+            .iter()
+            .map(|re| {
+                serde_json::json!({
+                    "entry_type": re.entry.entry_type,
+                    "contribution_type": re.entry.contribution_type,
+                    "tags": re.entry.tags,
+                    "data": re.entry.data,
+                })
+            })
+            .collect::<Vec<_>>(),
+    )
+    .map_err(|e| AppError::Internal(anyhow::anyhow!("Failed to serialize entries: {e}")))?;
+
+    let keywords_json = serde_json::to_string(
+        &parsed_jd
+            .keyword_inventory
+            .iter()
+            .map(|k| &k.keyword)
+            .collect::<Vec<_>>(),
+    )
+    .map_err(|e| AppError::Internal(anyhow::anyhow!("Failed to serialize keywords: {e}")))?;
+
+    let jd_summary = format!(
+        "Seniority: {}. Hard requirements: {}",
+        parsed_jd.role_signals.seniority,
+        parsed_jd
+            .hard_requirements
+            .iter()
+            .take(5)
+            .map(|r| r.text.as_str())
+            .collect::<Vec<_>>()
+            .join("; ")
+    );
+
+    let feedback = length_feedback
+        .map(|f| format!("\nPREVIOUS ATTEMPT WAS TOO LONG: {f}. Shorten it."))
+        .unwrap_or_default();
+
+    Ok(LINKEDIN_PROMPT_TEMPLATE
+        .replace("{scope_instruction}", SCOPE_INSTRUCTION)
+        .replace("{entries_json}", &entries_json)
+        .replace("{keywords_json}", &keywords_json)
+        .replace("{jd_summary}", &jd_summary)
+        .replace("{headline_max_chars}", &HEADLINE_MAX_CHARS.to_string())
+        .replace("{about_max_chars}", &ABOUT_MAX_CHARS.to_string())
+        .replace("{length_feedback}", &feedback))
+}
+
+/// Returns a human-readable description of any length violation, or None if within limits.
+/// Lengths are counted in chars (LinkedIn counts characters, not bytes).
+fn length_violation(profile: &LinkedInProfile) -> Option<String> {
+    let headline_len = profile.headline.trim().chars().count();
+    let about_len = profile.about.trim().chars().count();
+
+    let mut problems = Vec::new();
+    if headline_len > HEADLINE_MAX_CHARS {
+        problems.push(format!(
+            "headline is {headline_len} chars (max {HEADLINE_MAX_CHARS})"
+        ));
+    }
+    if about_len > ABOUT_MAX_CHARS {
+        problems.push(format!(
+            "about is {about_len} chars (max {ABOUT_MAX_CHARS})"
+        ));
+    }
+
+    if problems.is_empty() {
+        None
+    } else {
+        Some(problems.join("; "))
+    }
+}
+
+/// Trims `text` to at most `max_chars` characters, preferring to cut at the last
+/// sentence end, then the last word boundary. Text already within the limit is unchanged.
+fn truncate_at_boundary(text: &str, max_chars: usize) -> String {
+    if text.chars().count() <= max_chars {
+        return text.to_string();
+    }
+
+    let cut: String = text.chars().take(max_chars).collect();
+
+    if let Some(idx) = cut.rfind(['.', '!', '?']) {
+        // Only accept a sentence cut that keeps most of the budget
+        if cut[..=idx].chars().count() >= max_chars / 2 {
+            return cut[..=idx].to_string();
+        }
+    }
+    match cut.rfind(char::is_whitespace) {
+        Some(idx) => cut[..idx].trim_end().to_string(),
+        None => cut,
+    }
+}
+
+// ────────────────────────────────────────────────────────────────────────────
+// Tests
+// ────────────────────────────────────────────────────────────────────────────
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashMap;
+    use templar_types::jd::{JDTone, KeywordEntry, Requirement, RoleSignals};
+
+    fn make_parsed_jd() -> ParsedJD {
+        ParsedJD {
+            hard_requirements: vec![Requirement {
+                text: "Production Rust".to_string(),
+                is_required: true,
+            }],
+            soft_signals: vec![],
+            role_signals: RoleSignals {
+                is_startup: true,
+                is_ic_focused: true,
+                is_research: false,
+                seniority: "staff".to_string(),
+            },
+            keyword_inventory: vec![KeywordEntry {
+                keyword: "Rust".to_string(),
+                frequency: 3,
+                position_weight: 1.0,
+                weighted_score: 3.0,
+            }],
+            detected_tone: JDTone::AggressiveStartup,
+        }
+    }
+
+    fn empty_selection() -> SelectionResult {
+        SelectionResult {
+            selected_entries: vec![],
+            excluded_entries: vec![],
+            section_weights: HashMap::new(),
+            reframe_hints: vec![],
+        }
+    }
+
+    #[test]
+    fn test_length_violation_none_within_limits() {
+        let p = LinkedInProfile {
+            headline: "Backend Engineer".to_string(),
+            about: "I build things.".to_string(),
+        };
+        assert!(length_violation(&p).is_none());
+    }
+
+    #[test]
+    fn test_length_violation_reports_about_over_2600() {
+        let p = LinkedInProfile {
+            headline: "ok".to_string(),
+            about: "a".repeat(ABOUT_MAX_CHARS + 1),
+        };
+        let v = length_violation(&p).expect("must flag");
+        assert!(v.contains("about is 2601 chars"), "{v}");
+    }
+
+    #[test]
+    fn test_length_counts_chars_not_bytes() {
+        // 220 multi-byte chars is exactly at the limit
+        let p = LinkedInProfile {
+            headline: "é".repeat(HEADLINE_MAX_CHARS),
+            about: String::new(),
+        };
+        assert!(length_violation(&p).is_none());
+    }
+
+    #[test]
+    fn test_truncate_prefers_sentence_boundary() {
+        let text = "First sentence here. Second sentence is quite a bit longer than the first.";
+        let out = truncate_at_boundary(text, 40);
+        assert_eq!(out, "First sentence here.");
+    }
+
+    #[test]
+    fn test_truncate_falls_back_to_word_boundary() {
+        let text = "one two three four five six seven";
+        let out = truncate_at_boundary(text, 12);
+        assert_eq!(out, "one two");
+        assert!(out.chars().count() <= 12);
+    }
+
+    #[test]
+    fn test_truncate_leaves_short_text_unchanged() {
+        assert_eq!(truncate_at_boundary("short", 220), "short");
+    }
+
+    #[test]
+    fn test_prompt_fills_all_placeholders() {
+        let prompt = build_linkedin_prompt(&make_parsed_jd(), &empty_selection(), None).unwrap();
+        assert!(prompt.contains("2600"));
+        assert!(prompt.contains("220"));
+        assert!(prompt.contains("staff"));
+        assert!(prompt.contains("\"Rust\""));
+        for placeholder in [
+            "{scope_instruction}",
+            "{entries_json}",
+            "{keywords_json}",
+            "{jd_summary}",
+            "{headline_max_chars}",
+            "{about_max_chars}",
+            "{length_feedback}",
+        ] {
+            assert!(!prompt.contains(placeholder), "unfilled {placeholder}");
+        }
+        assert!(!prompt.contains("TOO LONG"));
+    }
+
+    #[test]
+    fn test_prompt_includes_length_feedback_on_retry() {
+        let prompt = build_linkedin_prompt(
+            &make_parsed_jd(),
+            &empty_selection(),
+            Some("about is 3000 chars (max 2600)"),
+        )
+        .unwrap();
+        assert!(prompt.contains("TOO LONG: about is 3000 chars"));
+    }
+}
@@ -2,10 +2,27 @@
 // Implements: JD parsing, fit scoring, content selection, tone calibration, generation.
 // All LLM calls go through llm_client — no direct Anthropic SDK calls here.
 
+pub mod acronyms;
+pub mod batch_generation;
+pub mod bullet_dedup;
+pub mod bullet_lint;
 pub mod content_selector;
+pub mod domain_pack;
+pub mod explanation;
 pub mod fit_scoring;
 pub mod generator;
 pub mod handlers;
+pub mod html_extract;
+pub mod interview_prep;
+pub mod jd_extractor;
 pub mod jd_parser;
+pub mod keyword_coverage;
+pub mod keyword_stuffing;
+pub mod layout_report;
+pub mod linkedin;
+pub mod pipeline_cache;
+pub mod prompt_versions;
 pub mod prompts;
+pub mod static_sections;
+pub mod template_bullets;
 pub mod tone;
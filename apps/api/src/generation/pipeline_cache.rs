@@ -0,0 +1,169 @@
+//! Pipeline cache — the parsed JD and context entries one request computed, kept for
+//! the next step of the same flow.
+//!
+//! The common flow is `POST /resumes/fit-score` then `POST /resumes/generate` with the
+//! same JD. Fit scoring stores what it loaded under
+//! `pipeline:{user_id}:{jd_hash}:{version}-{version_sum}`; generation looks it up and,
+//! on a hit, skips the JD parse (an LLM call) and the entry load. The key carries the
+//! `ContextRevision`, so any context change since fit scoring is a miss, never a stale
+//! hit. Entries expire after `CACHE_TTL_SECS`.
+//!
+//! The cache is an optimisation only: Redis errors and undecodable values are logged
+//! and treated as a miss. Entries hold context data, so account deletion removes them
+//! (`forget_user`) rather than waiting out the TTL.
+
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use tracing::warn;
+use uuid::Uuid;
+
+use crate::context::versioning::ContextRevision;
+use crate::errors::AppError;
+use crate::generation::jd_parser::ParsedJD;
+use crate::models::context::ContextEntryRow;
+
+/// How long a fit score's work stays available to the generation that follows it.
+pub const CACHE_TTL_SECS: u64 = 30 * 60;
+
+/// What fit scoring hands on to generation. `parsed_jd` is the parse as returned by
+/// `parse_jd`, before any tone preference is applied.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CachedPipeline {
+    pub parsed_jd: ParsedJD,
+    pub entries: Vec<ContextEntryRow>,
+}
+
+fn cache_key(user_id: Uuid, jd_text: &str, revision: ContextRevision) -> String {
+    let jd_hash: String = Sha256::digest(jd_text.trim().as_bytes())
+        .iter()
+        .map(|b| format!("{b:02x}"))
+        .collect();
+    format!(
+        "pipeline:{user_id}:{jd_hash}:{}-{}",
+        revision.version, revision.version_sum
+    )
+}
+
+/// The cached parse and entries for this user, JD and context revision, if any.
+pub async fn load(
+    redis: &redis::Client,
+    user_id: Uuid,
+    jd_text: &str,
+    revision: ContextRevision,
+) -> Option<CachedPipeline> {
+    let key = cache_key(user_id, jd_text, revision);
+    let result = async {
+        let mut conn = redis.get_multiplexed_async_connection().await?;
+        redis::cmd("GET")
+            .arg(&key)
+            .query_async::<_, Option<String>>(&mut conn)
+            .await
+    }
+    .await;
+    match result {
+        Ok(Some(json)) => match serde_json::from_str(&json) {
+            Ok(cached) => Some(cached),
+            Err(e) => {
+                warn!(%user_id, "pipeline cache entry undecodable: {e}");
+                None
+            }
+        },
+        Ok(None) => None,
+        Err(e) => {
+            warn!(%user_id, "pipeline cache unavailable: {e}");
+            None
+        }
+    }
+}
+
+/// Stores `cached` for this user, JD and context revision. Best-effort.
+pub async fn store(
+    redis: &redis::Client,
+    user_id: Uuid,
+    jd_text: &str,
+    revision: ContextRevision,
+    cached: &CachedPipeline,
+) {
+    let json = match serde_json::to_string(cached) {
+        Ok(json) => json,
+        Err(e) => {
+            warn!(%user_id, "pipeline cache entry not encoded: {e}");
+            return;
+        }
+    };
+    let key = cache_key(user_id, jd_text, revision);
+    let result = async {
+        let mut conn = redis.get_multiplexed_async_connection().await?;
+        redis::cmd("SET")
+            .arg(&key)
+            .arg(json)
+            .arg("EX")
+            .arg(CACHE_TTL_SECS)
+            .query_async::<_, ()>(&mut conn)
+            .await
+    }
+    .await;
+    if let Err(e) = result {
+        warn!(%user_id, "pipeline cache not stored: {e}");
+    }
+}
+
+/// Deletes every cached pipeline of `user_id`; returns how many there were.
+pub async fn forget_user(redis: &redis::Client, user_id: Uuid) -> Result<usize, AppError> {
+    let redis_error = |e: redis::RedisError| {
+        AppError::Internal(anyhow::anyhow!("Pipeline cache unavailable: {e}"))
+    };
+    let mut conn = redis
+        .get_multiplexed_async_connection()
+        .await
+        .map_err(redis_error)?;
+    let keys = crate::quota::scan_keys(&mut conn, &format!("pipeline:{user_id}:*")).await?;
+    if !keys.is_empty() {
+        redis::cmd("DEL")
+            .arg(&keys)
+            .query_async::<_, ()>(&mut conn)
+            .await
+            .map_err(redis_error)?;
+    }
+    Ok(keys.len())
+}
+
+// ────────────────────────────────────────────────────────────────────────────
+// Tests
+// ────────────────────────────────────────────────────────────────────────────
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_key_changes_with_user_jd_and_context_revision() {
+        let user = Uuid::nil();
+        let revision = ContextRevision {
+            version: 7,
+            version_sum: 31,
+        };
+        let key = cache_key(user, "Senior Rust engineer", revision);
+        assert!(key.starts_with(&format!("pipeline:{user}:")));
+        assert!(key.ends_with(":7-31"));
+
+        // Surrounding whitespace is not part of the JD.
+        assert_eq!(key, cache_key(user, "  Senior Rust engineer\n", revision));
+        assert_ne!(key, cache_key(user, "Senior Go engineer", revision));
+        assert_ne!(
+            key,
+            cache_key(Uuid::new_v4(), "Senior Rust engineer", revision)
+        );
+        assert_ne!(
+            key,
+            cache_key(
+                user,
+                "Senior Rust engineer",
+                ContextRevision {
+                    version: 7,
+                    version_sum: 32,
+                }
+            )
+        );
+    }
+}
@@ -0,0 +1,118 @@
+//! Prompt versions — fingerprints of the prompts a resume was generated with.
+//!
+//! Each entry hashes the system prompt and template text of one pipeline step, so the
+//! version changes whenever a prompt is edited and nobody has to remember to bump a
+//! number. Stored per resume in `resumes.prompt_versions`; comparing two resumes'
+//! maps shows which steps ran on different prompts.
+
+use std::collections::BTreeMap;
+use std::sync::OnceLock;
+
+use sha2::{Digest, Sha256};
+
+use crate::generation::prompts::{
+    DOMAIN_ACADEMIA_INSTRUCTION, DOMAIN_ENGINEERING_INSTRUCTION, DOMAIN_FINANCE_INSTRUCTION,
+    DOMAIN_MARKETING_INSTRUCTION, DOMAIN_PRODUCT_INSTRUCTION, GENERATION_PROMPT_TEMPLATE,
+    GENERATION_SYSTEM, JD_PARSE_PROMPT_TEMPLATE, JD_PARSE_SYSTEM, SENIORITY_JUNIOR_INSTRUCTION,
+    SENIORITY_MID_INSTRUCTION, SENIORITY_SENIOR_INSTRUCTION, SENIORITY_STAFF_PLUS_INSTRUCTION,
+};
+use crate::grounding::prompts::{GROUNDING_SCORE_PROMPT_TEMPLATE, GROUNDING_SCORE_SYSTEM};
+use crate::layout::prompts::{
+    BATCH_ADJUST_PROMPT_TEMPLATE, BATCH_ADJUST_SYSTEM, COMPRESS_PROMPT_TEMPLATE, COMPRESS_SYSTEM,
+    EXPAND_PROMPT_TEMPLATE, EXPAND_SYSTEM,
+};
+use crate::llm_client::prompts::{GROUNDING_INSTRUCTION, SCOPE_INSTRUCTION};
+
+/// Hex characters kept from each SHA-256.
+const FINGERPRINT_LEN: usize = 12;
+
+/// `{step: fingerprint}` for every prompt generation uses. Computed once.
+pub fn prompt_versions() -> &'static BTreeMap<&'static str, String> {
+    static VERSIONS: OnceLock<BTreeMap<&'static str, String>> = OnceLock::new();
+    VERSIONS.get_or_init(|| {
+        BTreeMap::from([
+            (
+                "jd_parse",
+                fingerprint(&[JD_PARSE_SYSTEM, JD_PARSE_PROMPT_TEMPLATE]),
+            ),
+            (
+                "generation",
+                fingerprint(&[
+                    GENERATION_SYSTEM,
+                    GENERATION_PROMPT_TEMPLATE,
+                    GROUNDING_INSTRUCTION,
+                    SCOPE_INSTRUCTION,
+                    SENIORITY_JUNIOR_INSTRUCTION,
+                    SENIORITY_MID_INSTRUCTION,
+                    SENIORITY_SENIOR_INSTRUCTION,
+                    SENIORITY_STAFF_PLUS_INSTRUCTION,
+                    DOMAIN_ENGINEERING_INSTRUCTION,
+                    DOMAIN_PRODUCT_INSTRUCTION,
+                    DOMAIN_MARKETING_INSTRUCTION,
+                    DOMAIN_FINANCE_INSTRUCTION,
+                    DOMAIN_ACADEMIA_INSTRUCTION,
+                ]),
+            ),
+            (
+                "layout_adjust",
+                fingerprint(&[
+                    EXPAND_SYSTEM,
+                    EXPAND_PROMPT_TEMPLATE,
+                    COMPRESS_SYSTEM,
+                    COMPRESS_PROMPT_TEMPLATE,
+                    BATCH_ADJUST_SYSTEM,
+                    BATCH_ADJUST_PROMPT_TEMPLATE,
+                ]),
+            ),
+            (
+                "grounding",
+                fingerprint(&[GROUNDING_SCORE_SYSTEM, GROUNDING_SCORE_PROMPT_TEMPLATE]),
+            ),
+        ])
+    })
+}
+
+/// The first `FINGERPRINT_LEN` hex characters of the SHA-256 of `parts`, each
+/// followed by a NUL so moving text between parts changes the hash.
+fn fingerprint(parts: &[&str]) -> String {
+    let mut hasher = Sha256::new();
+    for part in parts {
+        hasher.update(part.as_bytes());
+        hasher.update([0]);
+    }
+    hasher
+        .finalize()
+        .iter()
+        .map(|b| format!("{b:02x}"))
+        .collect::<String>()[..FINGERPRINT_LEN]
+        .to_string()
+}
+
+// ────────────────────────────────────────────────────────────────────────────
+// Tests
+// ────────────────────────────────────────────────────────────────────────────
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fingerprint_is_stable_and_sensitive_to_part_boundaries() {
+        assert_eq!(fingerprint(&["a", "b"]), fingerprint(&["a", "b"]));
+        assert_ne!(fingerprint(&["ab", ""]), fingerprint(&["a", "b"]));
+        assert_eq!(fingerprint(&["a"]).len(), FINGERPRINT_LEN);
+    }
+
+    #[test]
+    fn test_every_step_has_a_distinct_version() {
+        let versions = prompt_versions();
+        assert_eq!(
+            versions.keys().copied().collect::<Vec<_>>(),
+            vec!["generation", "grounding", "jd_parse", "layout_adjust"]
+        );
+        let mut values: Vec<&String> = versions.values().collect();
+        values.sort();
+        values.dedup();
+        assert_eq!(values.len(), versions.len());
+    }
+}
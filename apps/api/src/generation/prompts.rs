@@ -75,7 +75,8 @@ pub const GENERATION_SYSTEM: &str = "You are an expert resume writer generating
 
 /// Resume generation prompt template.
 /// Replace: {grounding_instruction}, {scope_instruction}, {tone_json},
-///          {entries_json}, {keywords_json}, {jd_summary}
+///          {domain_instruction}, {seniority_instruction}, {entries_json},
+///          {keywords_json}, {jd_summary}
 pub const GENERATION_PROMPT_TEMPLATE: &str = r#"{grounding_instruction}
 
 {scope_instruction}
@@ -83,6 +84,12 @@ pub const GENERATION_PROMPT_TEMPLATE: &str = r#"{grounding_instruction}
 TONE CALIBRATION for this role:
 {tone_json}
 
+DOMAIN CALIBRATION for this resume:
+{domain_instruction}
+
+SENIORITY CALIBRATION for this role:
+{seniority_instruction}
+
 SELECTED CONTEXT ENTRIES (source of truth — ONLY use facts from these):
 {entries_json}
 
@@ -107,11 +114,69 @@ HARD RULES:
 1. EVERY bullet MUST have `source_entry_id` matching one of the entry_id values above — no exceptions
 2. `line_estimate` must be 1 or 2 — NEVER 3 or more
 3. Use ONLY facts from the context entries — no interpolation, no invention
-4. Match `contribution_type` to language exactly per the scope instruction above
+4. Match `contribution_type` to language exactly per the scope instruction above — the tone calibration's `sole_owner_verbs` count as solo language
 5. Pack information densely — one strong bullet per entry, two if the entry is rich enough
 6. Incorporate JD keywords naturally where they appear in the context — never force-fit
 7. Do NOT include bullets for entries with no relevant content for this role"#;
 
+// ────────────────────────────────────────────────────────────────────────────
+// Seniority calibration — one fragment per level, injected as {seniority_instruction}
+// ────────────────────────────────────────────────────────────────────────────
+//
+// These shift EMPHASIS only. Scope claims are still capped by SCOPE_INSTRUCTION and the
+// context entries; a staff-level JD does not license inventing cross-team impact.
+
+pub const SENIORITY_JUNIOR_INSTRUCTION: &str = "\
+Target level: JUNIOR / ENTRY. Lead with what the candidate built and learned: \
+ownership of a concrete component or feature, technologies picked up, and shipped results. \
+Prefer clear, specific verbs (Built, Implemented, Added, Learned). \
+Do not frame work as org-wide strategy or leadership of other engineers.";
+
+pub const SENIORITY_MID_INSTRUCTION: &str = "\
+Target level: MID-LEVEL. Lead with independent delivery: features or services owned \
+end-to-end, measurable outcomes, and sound technical decisions. \
+Mention collaboration with adjacent teams only where the context shows it.";
+
+pub const SENIORITY_SENIOR_INSTRUCTION: &str = "\
+Target level: SENIOR. Lead with technical ownership of systems, design decisions and \
+their trade-offs, reliability/performance impact, and mentoring where the context shows it. \
+De-emphasize routine task execution.";
+
+pub const SENIORITY_STAFF_PLUS_INSTRUCTION: &str = "\
+Target level: STAFF / PRINCIPAL. Lead with cross-team scope: multi-team or org-level \
+technical direction, architecture strategy, standards adopted by others, and business impact. \
+Only claim cross-team or strategic scope the context entries actually support — \
+if an entry is a single-component effort, describe it accurately rather than inflating it.";
+
+// ────────────────────────────────────────────────────────────────────────────
+// Domain calibration — one fragment per domain pack, injected as {domain_instruction}
+// ────────────────────────────────────────────────────────────────────────────
+// The pack also supplies the verbs in {tone_json}; these fragments say what a strong
+// outcome looks like in the field. Metrics are still only those in the context.
+
+pub const DOMAIN_ENGINEERING_INSTRUCTION: &str = "\
+Domain: SOFTWARE ENGINEERING. Strong outcomes are latency, throughput, reliability, cost, \
+scale (users, requests, data volume) and delivery speed.";
+
+pub const DOMAIN_PRODUCT_INSTRUCTION: &str = "\
+Domain: PRODUCT MANAGEMENT. Strong outcomes are adoption, retention, conversion, revenue, \
+and decisions made from user research or experiments. \
+Describe engineering work as what the team shipped, not as the candidate's own code.";
+
+pub const DOMAIN_MARKETING_INSTRUCTION: &str = "\
+Domain: MARKETING. Strong outcomes are pipeline, leads, conversion, CAC, reach, engagement \
+and campaign ROI. Name the channel or audience where the context gives it.";
+
+pub const DOMAIN_FINANCE_INSTRUCTION: &str = "\
+Domain: FINANCE. Strong outcomes are dollar amounts, savings, forecast accuracy, deal size, \
+close time and audit or compliance results. Prefer precise figures over percentages \
+when the context has both.";
+
+pub const DOMAIN_ACADEMIA_INSTRUCTION: &str = "\
+Domain: ACADEMIA. Strong outcomes are publications and venues, citations, grants and their \
+amounts, students supervised and courses taught. \
+Keep author order and co-authorship exactly as in the context.";
+
 // ────────────────────────────────────────────────────────────────────────────
 // Phase 7.0 — LLM-based fit scoring
 // ────────────────────────────────────────────────────────────────────────────
@@ -176,3 +241,81 @@ Return a JSON object:
 {
   "suggested_framing": "Brief framing hint — e.g. 'position as infrastructure scale story emphasizing reliability'"
 }"#;
+
+// ────────────────────────────────────────────────────────────────────────────
+// Interview prep
+// ────────────────────────────────────────────────────────────────────────────
+
+/// System prompt for interview-question generation.
+pub const INTERVIEW_PREP_SYSTEM: &str =
+    "You are a senior technical interviewer preparing questions for a candidate's interview. \
+    Every question must be answerable from the candidate's own source material — \
+    do NOT ask about technologies or outcomes that are not in the bullet or its source entry. \
+    You MUST respond with valid JSON only — no markdown fences, no explanations.";
+
+/// Interview prep prompt template.
+/// Replace: {bullets_json}, {jd_requirements}, {max_questions}
+pub const INTERVIEW_PREP_PROMPT_TEMPLATE: &str = r#"For each resume bullet below, write the interview questions a hiring panel for this role is most likely to ask about it.
+
+RESUME BULLETS (each with its source context entry — the ONLY facts you may reference):
+{bullets_json}
+
+JD REQUIREMENTS for the target role:
+{jd_requirements}
+
+Return a JSON ARRAY with one object per bullet:
+[
+  {
+    "bullet_id": "the-exact-bullet_id-from-above",
+    "questions": [
+      {"question": "Walk me through how you chose consistent hashing for the cache tier.", "category": "technical", "jd_requirement": "Distributed systems design"}
+    ]
+  }
+]
+
+Rules:
+- At most {max_questions} questions per bullet
+- `category` is exactly one of: "technical", "behavioral", "impact"
+- `jd_requirement` is the JD requirement the question probes, or null if none applies
+- Prefer questions that probe the numbers, tools, and decisions stated in the source entry
+- Match the candidate's contribution_type: do not ask a team_member how they "led" the project"#;
+
+// ────────────────────────────────────────────────────────────────────────────
+// LinkedIn profile
+// ────────────────────────────────────────────────────────────────────────────
+
+/// System prompt for LinkedIn headline + About generation.
+pub const LINKEDIN_SYSTEM: &str =
+    "You are a career writer producing a LinkedIn headline and About section. \
+    Use ONLY facts present in the candidate's context entries — no invented employers, \
+    numbers, or technologies. Write in the first person for the About section. \
+    You MUST respond with valid JSON only — no markdown fences, no explanations.";
+
+/// LinkedIn prompt template.
+/// Replace: {scope_instruction}, {entries_json}, {keywords_json}, {jd_summary},
+///          {headline_max_chars}, {about_max_chars}, {length_feedback}
+pub const LINKEDIN_PROMPT_TEMPLATE: &str = r#"{scope_instruction}
+
+Write a LinkedIn headline and About section that position this candidate for the target role below.
+
+CANDIDATE CONTEXT ENTRIES (source of truth — ONLY use facts from these):
+{entries_json}
+
+TARGET ROLE KEYWORDS (use where the context supports them — never force-fit):
+{keywords_json}
+
+TARGET ROLE SUMMARY:
+{jd_summary}
+
+Return a JSON object with this EXACT schema:
+{
+  "headline": "Backend Engineer · Rust & distributed systems · Cut p99 latency 40% at Acme",
+  "about": "I build ..."
+}
+
+HARD RULES:
+1. `headline` is at most {headline_max_chars} characters
+2. `about` is at most {about_max_chars} characters, 2–4 short paragraphs separated by blank lines
+3. No emoji, no hashtags, no buzzword lists
+4. Match contribution_type language per the scope instruction above
+{length_feedback}"#;
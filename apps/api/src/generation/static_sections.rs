@@ -0,0 +1,459 @@
+#![allow(dead_code)]
+
+//! Deterministic resume content: education lines, the summary and the contact header.
+//!
+//! None of them goes through the LLM. Education lines are formatted straight from the
+//! `education` context entries (degree, field, institution, dates, GPA, honors), so
+//! they are grounded by construction. The summary and the header come from the
+//! `profile` entry, the summary verbatim from its `summary` field.
+//!
+//! All occupy page lines, so all are measured with the same font metrics as the
+//! bullets: education lines and the summary become `SimulatedBullet`s in the
+//! "education" and "summary" sections (persisted and rendered like any other bullet),
+//! and the header's line count is reserved in the page-fill analysis. Education lines
+//! are `LineType::EntryHeader`s and the summary a `LineType::SummaryParagraph`; their
+//! text cannot be rewritten, so lines breaking their contract are flagged for review.
+
+use chrono::NaiveDate;
+use serde_json::Value;
+
+use crate::layout::contract::{check_line_contract, LineCoverageVerdict};
+use crate::layout::font_metrics::get_metrics;
+use crate::layout::{LineType, PageConfig, SimulatedBullet};
+use crate::models::context::ContextEntryRow;
+use crate::templates::ProfileData;
+
+/// Section name used for deterministic education lines.
+pub const EDUCATION_SECTION: &str = "education";
+/// Section name used for the profile summary.
+pub const SUMMARY_SECTION: &str = "summary";
+
+/// True for the sections built here rather than drafted by the LLM.
+pub fn is_static_section(section: &str) -> bool {
+    section == EDUCATION_SECTION || section == SUMMARY_SECTION
+}
+
+// ────────────────────────────────────────────────────────────────────────────
+// Data models
+// ────────────────────────────────────────────────────────────────────────────
+
+/// Everything on the page that the LLM does not write.
+#[derive(Debug, Clone)]
+pub struct StaticSections {
+    /// The profile's `summary` as one paragraph. None when the profile has none.
+    pub summary: Option<SimulatedBullet>,
+    /// One bullet per education entry, most recent first.
+    pub education: Vec<SimulatedBullet>,
+    /// Header text lines (name, contact). Empty when the user has no profile entry.
+    pub header_lines: Vec<String>,
+    /// Printed lines the header occupies at the configured font.
+    pub header_line_count: u16,
+}
+
+// ────────────────────────────────────────────────────────────────────────────
+// Builders
+// ────────────────────────────────────────────────────────────────────────────
+
+/// Builds education lines and header accounting from the user's current entries.
+pub fn build_static_sections(entries: &[ContextEntryRow], config: &PageConfig) -> StaticSections {
+    let profile = entries
+        .iter()
+        .filter(|e| e.entry_type == "profile")
+        .max_by_key(|e| e.version);
+    let header_lines = profile
+        .map(|e| ProfileData::from_entry_data(&e.data).header_lines())
+        .unwrap_or_default();
+    let header_line_count = header_lines
+        .iter()
+        .map(|l| measured_lines(l, config) as u16)
+        .sum();
+
+    StaticSections {
+        summary: profile.and_then(|e| build_summary(e, config)),
+        education: build_education_bullets(entries, config),
+        header_lines,
+        header_line_count,
+    }
+}
+
+/// The profile entry's `summary` as a paragraph, when it has a non-blank one.
+fn build_summary(profile: &ContextEntryRow, config: &PageConfig) -> Option<SimulatedBullet> {
+    let text = profile.data.get("summary")?.as_str()?.trim();
+    if text.is_empty() {
+        return None;
+    }
+    Some(SimulatedBullet {
+        verified_line_count: measured_lines(text, &config.for_section(SUMMARY_SECTION)),
+        text: text.to_string(),
+        source_entry_id: profile.entry_id,
+        section: SUMMARY_SECTION.to_string(),
+        line_type: LineType::SummaryParagraph,
+        jd_keywords_used: vec![],
+        was_adjusted: false,
+        flagged_for_review: false,
+        revisions: Vec::new(),
+    })
+}
+
+impl StaticSections {
+    /// Re-measures header and education line counts at another page config (e.g. after
+    /// the page-fill font-size fallback). Texts are unchanged.
+    pub fn remeasured(&self, config: &PageConfig) -> StaticSections {
+        let education_cfg = config.for_section(EDUCATION_SECTION);
+        StaticSections {
+            summary: self.summary.as_ref().map(|b| SimulatedBullet {
+                verified_line_count: measured_lines(&b.text, &config.for_section(SUMMARY_SECTION)),
+                ..b.clone()
+            }),
+            education: self
+                .education
+                .iter()
+                .map(|b| SimulatedBullet {
+                    verified_line_count: measured_lines(&b.text, &education_cfg),
+                    ..b.clone()
+                })
+                .collect(),
+            header_lines: self.header_lines.clone(),
+            header_line_count: self
+                .header_lines
+                .iter()
+                .map(|l| measured_lines(l, config) as u16)
+                .sum(),
+        }
+    }
+}
+
+impl StaticSections {
+    /// The summary and the education lines, in print order.
+    pub fn fixed_lines(&self) -> Vec<SimulatedBullet> {
+        self.summary
+            .iter()
+            .chain(&self.education)
+            .cloned()
+            .collect()
+    }
+
+    /// True when `section` has static lines, which replace any bullets drafted for it.
+    pub fn replaces(&self, section: &str) -> bool {
+        self.summary
+            .iter()
+            .chain(&self.education)
+            .any(|b| b.section == section)
+    }
+
+    /// Checks the summary and education lines against the contract of their line type
+    /// at `config`, flagging the ones that break it. Their text is fixed, so nothing is
+    /// rewritten.
+    pub fn check_contracts(&mut self, config: &PageConfig) {
+        let metrics = get_metrics(&config.font);
+        for (i, line) in self
+            .summary
+            .iter_mut()
+            .chain(self.education.iter_mut())
+            .enumerate()
+        {
+            let result = check_line_contract(
+                i,
+                &line.text,
+                line.line_type,
+                metrics,
+                &config.for_section(&line.section),
+            );
+            line.flagged_for_review = result.verdict != LineCoverageVerdict::Satisfies;
+        }
+    }
+}
+
+/// Formats every `education` entry into a single resume line, most recent first.
+/// Entries without an institution or degree are skipped.
+pub fn build_education_bullets(
+    entries: &[ContextEntryRow],
+    config: &PageConfig,
+) -> Vec<SimulatedBullet> {
+    let mut rows: Vec<(&ContextEntryRow, String)> = entries
+        .iter()
+        .filter(|e| e.entry_type == EDUCATION_SECTION)
+        .filter_map(|e| format_education_line(&e.data).map(|line| (e, line)))
+        .collect();
+
+    // Ongoing (no end date) first, then by end date descending
+    rows.sort_by_key(|(e, _)| {
+        std::cmp::Reverse(
+            parse_date(e.data.get("date_end"))
+                .map(|d| d.format("%Y-%m-%d").to_string())
+                .unwrap_or_else(|| "9999".to_string()),
+        )
+    });
+
+    rows.into_iter()
+        .map(|(entry, text)| SimulatedBullet {
+            // Education may sit in a two-column sidebar — measure against its column
+            verified_line_count: measured_lines(&text, &config.for_section(EDUCATION_SECTION)),
+            text,
+            source_entry_id: entry.entry_id,
+            section: EDUCATION_SECTION.to_string(),
+            line_type: LineType::EntryHeader,
+            jd_keywords_used: vec![],
+            was_adjusted: false,
+            flagged_for_review: false,
+            revisions: Vec::new(),
+        })
+        .collect()
+}
+
+/// Formats one education entry's `data` as
+/// `"{degree} in {field}, {institution} ({start} – {end}) · GPA {gpa} · {honors}"`.
+///
+/// Parsed leniently: any missing optional part is simply left out. Returns `None`
+/// when both degree and institution are missing — there is nothing to print.
+pub fn format_education_line(data: &Value) -> Option<String> {
+    let get_str = |key: &str| {
+        data.get(key)
+            .and_then(|v| v.as_str())
+            .map(str::trim)
+            .filter(|s| !s.is_empty())
+    };
+
+    let degree = get_str("degree");
+    let field = get_str("field");
+    let institution = get_str("institution");
+    if degree.is_none() && institution.is_none() {
+        return None;
+    }
+
+    let mut line = match (degree, field) {
+        (Some(d), Some(f)) => format!("{d} in {f}"),
+        (Some(d), None) => d.to_string(),
+        (None, Some(f)) => f.to_string(),
+        (None, None) => String::new(),
+    };
+    if let Some(inst) = institution {
+        if line.is_empty() {
+            line = inst.to_string();
+        } else {
+            line = format!("{line}, {inst}");
+        }
+    }
+
+    let start = parse_date(data.get("date_start")).map(|d| d.format("%Y").to_string());
+    let end = match data.get("date_end") {
+        None | Some(Value::Null) => Some("Present".to_string()),
+        v => parse_date(v).map(|d| d.format("%Y").to_string()),
+    };
+    match (start, end) {
+        (Some(s), Some(e)) if s == e => line.push_str(&format!(" ({e})")),
+        (Some(s), Some(e)) => line.push_str(&format!(" ({s} – {e})")),
+        (None, Some(e)) if e != "Present" => line.push_str(&format!(" ({e})")),
+        _ => {}
+    }
+
+    if let Some(gpa) = data.get("gpa").and_then(|v| v.as_f64()) {
+        line.push_str(&format!(" · GPA {gpa:.2}"));
+    }
+
+    let honors: Vec<&str> = data
+        .get("honors")
+        .and_then(|v| v.as_array())
+        .map(|arr| {
+            arr.iter()
+                .filter_map(|h| h.as_str())
+                .map(str::trim)
+                .filter(|h| !h.is_empty())
+                .collect()
+        })
+        .unwrap_or_default();
+    if !honors.is_empty() {
+        line.push_str(&format!(" · {}", honors.join(", ")));
+    }
+
+    Some(line)
+}
+
+/// Accepts `YYYY-MM-DD`, `YYYY-MM`, or `YYYY`.
+pub(crate) fn parse_date(value: Option<&Value>) -> Option<NaiveDate> {
+    let s = value?.as_str()?.trim();
+    NaiveDate::parse_from_str(s, "%Y-%m-%d")
+        .or_else(|_| NaiveDate::parse_from_str(&format!("{s}-01"), "%Y-%m-%d"))
+        .or_else(|_| NaiveDate::parse_from_str(&format!("{s}-01-01"), "%Y-%m-%d"))
+        .ok()
+}
+
+fn measured_lines(text: &str, config: &PageConfig) -> u8 {
+    get_metrics(&config.font).estimated_lines(text, config)
+}
+
+// ────────────────────────────────────────────────────────────────────────────
+// Tests
+// ────────────────────────────────────────────────────────────────────────────
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::layout::{default_page_config, FontFamily};
+    use chrono::Utc;
+    use serde_json::json;
+    use uuid::Uuid;
+
+    fn make_entry(entry_type: &str, data: Value) -> ContextEntryRow {
+        ContextEntryRow {
+            id: Uuid::new_v4(),
+            user_id: Uuid::new_v4(),
+            entry_id: Uuid::new_v4(),
+            version: 1,
+            entry_type: entry_type.to_string(),
+            data,
+            raw_text: None,
+            recency_score: 1.0,
+            impact_score: 0.5,
+            tags: vec![],
+            flagged_evergreen: false,
+            contribution_type: "sole_author".to_string(),
+            quality_score: 1.0,
+            quality_flags: vec![],
+            created_at: Utc::now(),
+            recency_policy: None,
+        }
+    }
+
+    #[test]
+    fn test_format_full_education_line() {
+        let line = format_education_line(&json!({
+            "institution": "Stanford University",
+            "degree": "B.S.",
+            "field": "Computer Science",
+            "date_start": "2016-09-01",
+            "date_end": "2020-06-15",
+            "gpa": 3.8,
+            "honors": ["Phi Beta Kappa", "Tau Beta Pi"],
+        }))
+        .unwrap();
+        assert_eq!(
+            line,
+            "B.S. in Computer Science, Stanford University (2016 – 2020) · GPA 3.80 · Phi Beta Kappa, Tau Beta Pi"
+        );
+    }
+
+    #[test]
+    fn test_ongoing_degree_shows_present() {
+        let line = format_education_line(&json!({
+            "institution": "MIT",
+            "degree": "Ph.D.",
+            "date_start": "2022",
+            "date_end": null,
+        }))
+        .unwrap();
+        assert_eq!(line, "Ph.D., MIT (2022 – Present)");
+    }
+
+    #[test]
+    fn test_missing_degree_and_institution_is_skipped() {
+        assert!(format_education_line(&json!({ "field": "Physics" })).is_none());
+    }
+
+    #[test]
+    fn test_education_bullets_most_recent_first_and_measured() {
+        let config = default_page_config(FontFamily::Inter);
+        let entries = vec![
+            make_entry(
+                "education",
+                json!({ "institution": "State College", "degree": "B.A.", "date_start": "2010", "date_end": "2014" }),
+            ),
+            make_entry("experience", json!({ "company": "Acme" })),
+            make_entry(
+                "education",
+                json!({ "institution": "Big University", "degree": "M.S.", "date_start": "2015", "date_end": "2017" }),
+            ),
+        ];
+        let bullets = build_education_bullets(&entries, &config);
+        assert_eq!(bullets.len(), 2, "only education entries produce lines");
+        assert!(bullets[0].text.contains("Big University"));
+        assert!(bullets.iter().all(|b| b.section == EDUCATION_SECTION));
+        assert!(bullets.iter().all(|b| b.verified_line_count == 1));
+        assert_eq!(bullets[1].source_entry_id, entries[0].entry_id);
+    }
+
+    #[test]
+    fn test_header_lines_counted_from_profile_entry() {
+        let config = default_page_config(FontFamily::Inter);
+        let entries = vec![make_entry(
+            "profile",
+            json!({ "full_name": "Ada Lovelace", "email": "ada@example.com", "phone": "" }),
+        )];
+        let statics = build_static_sections(&entries, &config);
+        assert_eq!(
+            statics.header_lines,
+            vec!["Ada Lovelace".to_string(), "ada@example.com".to_string()]
+        );
+        assert_eq!(statics.header_line_count, 2);
+    }
+
+    #[test]
+    fn test_summary_comes_from_profile_and_is_checked_as_a_paragraph() {
+        let config = default_page_config(FontFamily::Inter);
+        // Far more than the four lines a summary may take.
+        let long = "Backend engineer building data platforms. ".repeat(20);
+        let entries = vec![
+            make_entry("profile", json!({ "full_name": "Ada", "summary": long })),
+            make_entry(
+                "education",
+                json!({ "institution": "MIT", "degree": "B.S.", "date_end": "2014" }),
+            ),
+        ];
+        let mut statics = build_static_sections(&entries, &config);
+        let summary = statics.summary.clone().unwrap();
+        assert_eq!(summary.section, SUMMARY_SECTION);
+        assert_eq!(summary.line_type, LineType::SummaryParagraph);
+        assert_eq!(summary.source_entry_id, entries[0].entry_id);
+        assert!(summary.verified_line_count > 4);
+        assert_eq!(
+            statics
+                .fixed_lines()
+                .iter()
+                .map(|b| b.section.as_str())
+                .collect::<Vec<_>>(),
+            vec![SUMMARY_SECTION, EDUCATION_SECTION]
+        );
+
+        statics.check_contracts(&config);
+        assert!(statics.summary.unwrap().flagged_for_review);
+        assert!(
+            !statics.education[0].flagged_for_review,
+            "a short entry header has no fill minimum"
+        );
+    }
+
+    #[test]
+    fn test_blank_summary_is_left_out() {
+        let config = default_page_config(FontFamily::Inter);
+        let entries = vec![make_entry("profile", json!({ "summary": "  " }))];
+        assert!(build_static_sections(&entries, &config).summary.is_none());
+    }
+
+    #[test]
+    fn test_no_profile_reserves_no_lines() {
+        let config = default_page_config(FontFamily::Inter);
+        let statics = build_static_sections(&[], &config);
+        assert!(statics.header_lines.is_empty());
+        assert_eq!(statics.header_line_count, 0);
+        assert!(statics.education.is_empty());
+    }
+
+    #[test]
+    fn test_remeasured_follows_new_geometry() {
+        let config = default_page_config(FontFamily::Inter);
+        let entries = vec![make_entry(
+            "education",
+            json!({ "institution": "Big University", "degree": "M.S.", "field": "Computer Science", "date_start": "2015", "date_end": "2017" }),
+        )];
+        let statics = build_static_sections(&entries, &config);
+        assert_eq!(statics.education[0].verified_line_count, 1);
+
+        let narrow = config.with_two_columns(crate::layout::font_metrics::TwoColumnLayout {
+            sidebar_fraction: 0.1,
+            ..crate::layout::font_metrics::TwoColumnLayout::default_sidebar()
+        });
+        let remeasured = statics.remeasured(&narrow);
+        assert_eq!(remeasured.education[0].text, statics.education[0].text);
+        assert!(remeasured.education[0].verified_line_count > 1);
+    }
+}
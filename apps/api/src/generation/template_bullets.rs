@@ -0,0 +1,308 @@
+//! Template bullet builder — draft bullets assembled from the selected entries' own
+//! text, without the LLM.
+//!
+//! Replaces the generation call in OFFLINE_MODE. Entries with bullets contribute
+//! their strongest ones verbatim (most JD keywords, then most impact markers);
+//! entries without bullets get one line from a fixed template per entry type, e.g.
+//! `Languages: Rust, Go, Python` for a skill entry with the JD's keywords first.
+//! Education is skipped — the generator prints deterministic education lines anyway.
+//!
+//! Every word comes from the entry, so the output is grounded by construction; layout
+//! still measures and locally compresses it like any LLM draft.
+
+use serde_json::Value;
+
+use crate::generation::content_selector::SelectionResult;
+use crate::generation::generator::DraftBullet;
+use crate::generation::jd_parser::ParsedJD;
+use crate::generation::keyword_coverage::contains_term;
+use crate::generation::static_sections::EDUCATION_SECTION;
+
+/// Bullets taken from the top-ranked entry; later entries get one fewer, down to one.
+const MAX_BULLETS_PER_ENTRY: usize = 3;
+
+/// Resume section a bullet from an entry of `entry_type` is printed under.
+pub fn section_for(entry_type: &str) -> &str {
+    match entry_type {
+        "project" | "open_source" => "projects",
+        "skill" => "skills",
+        "publication" => "publications",
+        "award" => "awards",
+        "certification" => "certifications",
+        "extracurricular" => "activities",
+        other => other,
+    }
+}
+
+/// Draft bullets for every selected entry, in selection order.
+pub fn build_template_bullets(
+    selection: &SelectionResult,
+    parsed_jd: &ParsedJD,
+) -> Vec<DraftBullet> {
+    let mut drafts = vec![];
+    for (rank, ranked) in selection.selected_entries.iter().enumerate() {
+        let entry = &ranked.entry;
+        if entry.entry_type == EDUCATION_SECTION {
+            continue;
+        }
+        let limit = MAX_BULLETS_PER_ENTRY.saturating_sub(rank).max(1);
+        let texts = match entry_bullets(&entry.data) {
+            bullets if !bullets.is_empty() => strongest(bullets, parsed_jd, limit),
+            _ => templated_line(&entry.entry_type, &entry.data, parsed_jd)
+                .into_iter()
+                .collect(),
+        };
+        drafts.extend(texts.into_iter().map(|text| DraftBullet {
+            jd_keywords_used: keywords_in(&text, parsed_jd),
+            source_entry_id: entry.entry_id,
+            section: section_for(&entry.entry_type).to_string(),
+            line_estimate: 1,
+            text,
+        }));
+    }
+    drafts
+}
+
+/// `(text, impact marker count)` of each bullet in `data.bullets`.
+fn entry_bullets(data: &Value) -> Vec<(String, usize)> {
+    data.get("bullets")
+        .and_then(Value::as_array)
+        .into_iter()
+        .flatten()
+        .filter_map(|b| {
+            let text = b.get("text").and_then(Value::as_str)?.trim();
+            let markers = b
+                .get("impact_markers")
+                .and_then(Value::as_array)
+                .map_or(0, Vec::len);
+            (!text.is_empty()).then(|| (text.trim_end_matches('.').to_string(), markers))
+        })
+        .collect()
+}
+
+/// The `limit` best bullets, kept in their original order.
+fn strongest(bullets: Vec<(String, usize)>, parsed_jd: &ParsedJD, limit: usize) -> Vec<String> {
+    let mut ranked: Vec<(usize, usize, usize)> = bullets
+        .iter()
+        .enumerate()
+        .map(|(idx, (text, markers))| (keywords_in(text, parsed_jd).len(), *markers, idx))
+        .collect();
+    ranked.sort_by(|a, b| (b.0, b.1).cmp(&(a.0, a.1)).then(a.2.cmp(&b.2)));
+    let mut chosen: Vec<usize> = ranked.iter().take(limit).map(|r| r.2).collect();
+    chosen.sort_unstable();
+    chosen
+        .into_iter()
+        .map(|idx| bullets[idx].0.clone())
+        .collect()
+}
+
+/// One line for an entry without bullets, or None when it lacks the fields to fill
+/// its template.
+fn templated_line(entry_type: &str, data: &Value, parsed_jd: &ParsedJD) -> Option<String> {
+    let field = |key: &str| {
+        data.get(key)
+            .and_then(Value::as_str)
+            .map(str::trim)
+            .filter(|s| !s.is_empty())
+    };
+    let year = |key: &str| field(key).and_then(|d| d.get(..4));
+    let with_year = |line: String, key: &str| match year(key) {
+        Some(y) => format!("{line} ({y})"),
+        None => line,
+    };
+
+    match entry_type {
+        "skill" => {
+            let mut items: Vec<&str> = data
+                .get("items")
+                .and_then(Value::as_array)?
+                .iter()
+                .filter_map(Value::as_str)
+                .collect();
+            if items.is_empty() {
+                return None;
+            }
+            // Stable sort: JD keywords first, otherwise as the user listed them.
+            items.sort_by_key(|item| keywords_in(item, parsed_jd).is_empty());
+            Some(match field("category") {
+                Some(category) => format!("{category}: {}", items.join(", ")),
+                None => items.join(", "),
+            })
+        }
+        "project" => {
+            let name = field("name")?;
+            Some(match field("description") {
+                Some(description) => format!("{name}: {description}"),
+                None => name.to_string(),
+            })
+        }
+        "open_source" => {
+            let name = field("project_name")?;
+            Some(match field("description") {
+                Some(description) => format!("Contributor to {name}: {description}"),
+                None => format!("Contributor to {name}"),
+            })
+        }
+        "publication" => Some(with_year(
+            format!("{}, {}", field("title")?, field("venue")?),
+            "date",
+        )),
+        "award" => Some(with_year(
+            format!("{}, {}", field("title")?, field("issuer")?),
+            "date",
+        )),
+        "certification" => Some(with_year(
+            format!("{}, {}", field("name")?, field("issuer")?),
+            "date_issued",
+        )),
+        "experience" | "extracurricular" => {
+            let org = field("company").or_else(|| field("organization"))?;
+            Some(format!("{}, {org}", field("role")?))
+        }
+        _ => None,
+    }
+}
+
+/// The JD keywords `text` mentions, matched as keyword coverage matches them.
+pub(crate) fn keywords_in(text: &str, parsed_jd: &ParsedJD) -> Vec<String> {
+    let lowered = text.to_lowercase();
+    parsed_jd
+        .keyword_inventory
+        .iter()
+        .filter(|k| contains_term(&lowered, &k.keyword.trim().to_lowercase()))
+        .map(|k| k.keyword.clone())
+        .collect()
+}
+
+// ────────────────────────────────────────────────────────────────────────────
+// Tests
+// ────────────────────────────────────────────────────────────────────────────
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::generation::content_selector::RankedEntry;
+    use crate::models::context::ContextEntryRow;
+    use chrono::Utc;
+    use serde_json::json;
+    use std::collections::HashMap;
+    use templar_types::jd::{KeywordEntry, RoleSignals};
+    use uuid::Uuid;
+
+    fn make_entry(entry_type: &str, data: Value) -> ContextEntryRow {
+        ContextEntryRow {
+            id: Uuid::new_v4(),
+            user_id: Uuid::new_v4(),
+            entry_id: Uuid::new_v4(),
+            version: 1,
+            entry_type: entry_type.to_string(),
+            data,
+            raw_text: None,
+            recency_score: 1.0,
+            impact_score: 0.5,
+            tags: vec![],
+            flagged_evergreen: false,
+            contribution_type: "sole_author".to_string(),
+            quality_score: 1.0,
+            quality_flags: vec![],
+            created_at: Utc::now(),
+            recency_policy: None,
+        }
+    }
+
+    fn jd(keywords: &[&str]) -> ParsedJD {
+        ParsedJD {
+            hard_requirements: vec![],
+            soft_signals: vec![],
+            role_signals: RoleSignals {
+                is_startup: false,
+                is_ic_focused: true,
+                is_research: false,
+                seniority: "senior".into(),
+            },
+            keyword_inventory: keywords
+                .iter()
+                .map(|k| KeywordEntry {
+                    keyword: k.to_string(),
+                    frequency: 1,
+                    position_weight: 1.0,
+                    weighted_score: 1.0,
+                })
+                .collect(),
+            detected_tone: Default::default(),
+        }
+    }
+
+    fn selection(entries: Vec<(&str, Value)>) -> SelectionResult {
+        SelectionResult {
+            selected_entries: entries
+                .into_iter()
+                .map(|(entry_type, data)| RankedEntry {
+                    entry: make_entry(entry_type, data),
+                    combined_score: 1.0,
+                    jd_relevance: 1.0,
+                })
+                .collect(),
+            excluded_entries: vec![],
+            section_weights: HashMap::new(),
+            reframe_hints: vec![],
+        }
+    }
+
+    fn bullet(text: &str, markers: usize) -> Value {
+        json!({ "text": text, "impact_markers": vec!["m"; markers], "confidence_marker": null })
+    }
+
+    #[test]
+    fn test_strongest_bullets_kept_in_order() {
+        let sel = selection(vec![(
+            "experience",
+            json!({ "bullets": [
+                bullet("Wrote docs", 0),
+                bullet("Cut Kafka lag by 80%.", 1),
+                bullet("Mentored 3 engineers", 1),
+                bullet("Moved billing to Rust and Kafka", 0),
+            ]}),
+        )]);
+        let drafts = build_template_bullets(&sel, &jd(&["Rust", "Kafka"]));
+        let texts: Vec<&str> = drafts.iter().map(|d| d.text.as_str()).collect();
+        assert_eq!(
+            texts,
+            [
+                "Cut Kafka lag by 80%",
+                "Mentored 3 engineers",
+                "Moved billing to Rust and Kafka"
+            ]
+        );
+        assert_eq!(drafts[2].jd_keywords_used, ["Rust", "Kafka"]);
+        assert!(drafts.iter().all(|d| d.section == "experience"));
+    }
+
+    #[test]
+    fn test_entries_without_bullets_use_templates() {
+        let sel = selection(vec![
+            ("education", json!({ "institution": "MIT" })),
+            (
+                "skill",
+                json!({ "category": "Languages", "items": ["Python", "Go", "Rust"] }),
+            ),
+            (
+                "certification",
+                json!({ "name": "CKA", "issuer": "CNCF", "date_issued": "2023-04-15" }),
+            ),
+            ("award", json!({ "title": "Hackathon winner" })),
+        ]);
+        let drafts = build_template_bullets(&sel, &jd(&["Rust"]));
+        let lines: Vec<(&str, &str)> = drafts
+            .iter()
+            .map(|d| (d.section.as_str(), d.text.as_str()))
+            .collect();
+        assert_eq!(
+            lines,
+            [
+                ("skills", "Languages: Rust, Python, Go"),
+                ("certifications", "CKA, CNCF (2023)"),
+            ]
+        );
+    }
+}
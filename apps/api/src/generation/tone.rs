@@ -1,47 +1,45 @@
 #![allow(dead_code)]
 
-//! Tone calibration — maps detected JD tone to verb sets, filters by contribution type.
+//! Tone calibration — maps detected JD tone to verb sets (per domain pack, see
+//! domain_pack.rs), filters by contribution type, and maps the JD's target seniority
+//! to a prompt emphasis fragment.
 //!
 //! CRITICAL: Tone intersects with the SCOPE_INSTRUCTION constraint.
 //! A `team_member` entry NEVER gets AggressiveStartup sole-owner verbs,
 //! regardless of detected JD tone. This is a hard architectural rule.
 
+use serde::Serialize;
+
+use crate::generation::domain_pack::DomainPack;
 use crate::generation::jd_parser::JDTone;
+use crate::generation::prompts::{
+    SENIORITY_JUNIOR_INSTRUCTION, SENIORITY_MID_INSTRUCTION, SENIORITY_SENIOR_INSTRUCTION,
+    SENIORITY_STAFF_PLUS_INSTRUCTION,
+};
 
 /// Verb sets and phrasing calibrated to a specific JD tone.
 #[derive(Debug, Clone)]
 pub struct ToneExamples {
+    /// The domain pack the verbs came from.
+    pub pack: DomainPack,
     pub strong_verbs: Vec<&'static str>,
     pub ownership_prefix: &'static str,
     pub avoid_verbs: Vec<&'static str>,
 }
 
-/// Returns tone-calibrated verb sets for the detected JD tone.
-pub fn get_tone_examples(tone: &JDTone) -> ToneExamples {
+/// Returns tone-calibrated verb sets for the detected JD tone, drawn from `pack`.
+pub fn get_tone_examples(tone: &JDTone, pack: DomainPack) -> ToneExamples {
+    let strong_verbs = pack.strong_verbs(tone).to_vec();
     match tone {
         JDTone::AggressiveStartup => ToneExamples {
-            strong_verbs: vec![
-                "Architected",
-                "Spearheaded",
-                "Owned",
-                "Drove",
-                "Built",
-                "Shipped",
-                "Launched",
-                "Led",
-            ],
+            pack,
+            strong_verbs,
             ownership_prefix: "end-to-end ownership of",
             avoid_verbs: vec!["assisted", "helped", "supported", "participated in"],
         },
         JDTone::CollaborativeEnterprise => ToneExamples {
-            strong_verbs: vec![
-                "Contributed to",
-                "Partnered with",
-                "Supported",
-                "Enabled",
-                "Collaborated on",
-                "Facilitated",
-            ],
+            pack,
+            strong_verbs,
             ownership_prefix: "as part of a team,",
             avoid_verbs: vec![
                 "architected",
@@ -51,76 +49,109 @@ pub fn get_tone_examples(tone: &JDTone) -> ToneExamples {
             ],
         },
         JDTone::ResearchOriented => ToneExamples {
-            strong_verbs: vec![
-                "Investigated",
-                "Designed and evaluated",
-                "Published",
-                "Proposed",
-                "Analyzed",
-                "Studied",
-            ],
+            pack,
+            strong_verbs,
             ownership_prefix: "research into",
             avoid_verbs: vec!["shipped", "launched", "moved fast", "disrupted"],
         },
         JDTone::ProductOriented => ToneExamples {
-            strong_verbs: vec![
-                "Shipped",
-                "Delivered",
-                "Launched",
-                "Improved",
-                "Reduced friction for",
-                "Enabled",
-            ],
+            pack,
+            strong_verbs,
             ownership_prefix: "shipped",
             avoid_verbs: vec!["investigated", "evaluated", "researched", "proposed"],
         },
     }
 }
 
-/// Verbs that signal sole-author ownership — never allowed for team_member entries.
-const SOLE_OWNER_VERBS: &[&str] = &[
-    "Architected",
-    "Spearheaded",
-    "Owned",
-    "Drove",
-    "Led",
-    "Built",
-    "Designed",
-];
-
 /// Verbs appropriate for reviewer contribution type.
 const REVIEWER_VERBS: &[&str] = &["Reviewed", "Evaluated", "Assessed", "Audited", "Analyzed"];
 
-/// Filters a verb set based on the entry's contribution type.
+/// Filters a verb set based on the entry's contribution type. Sole-owner verbs are
+/// `pack`'s.
 ///
 /// CRITICAL: `team_member` entries cannot use sole-owner verbs even if the JD is AggressiveStartup.
 /// `reviewer` entries are restricted to reviewer-appropriate verbs regardless of tone.
 pub fn filter_verbs_for_contribution<'a>(
     verbs: &[&'a str],
     contribution_type: &str,
+    pack: DomainPack,
 ) -> Vec<&'a str> {
-    match contribution_type {
-        "sole_author" | "primary_contributor" => verbs.to_vec(),
-        "team_member" => verbs
+    let without_sole_owner = || {
+        verbs
             .iter()
             .filter(|&&v| {
-                !SOLE_OWNER_VERBS
+                !pack
+                    .sole_owner_verbs()
                     .iter()
                     .any(|&sv| sv.eq_ignore_ascii_case(v))
             })
             .copied()
-            .collect(),
+            .collect()
+    };
+    match contribution_type {
+        "sole_author" | "primary_contributor" => verbs.to_vec(),
+        "team_member" => without_sole_owner(),
         "reviewer" => REVIEWER_VERBS.to_vec(),
         // Unknown contribution type — be conservative, treat as team_member
-        _ => verbs
-            .iter()
-            .filter(|&&v| {
-                !SOLE_OWNER_VERBS
-                    .iter()
-                    .any(|&sv| sv.eq_ignore_ascii_case(v))
+        _ => without_sole_owner(),
+    }
+}
+
+/// Target seniority bucket, derived from the free-form `role_signals.seniority` string.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SeniorityLevel {
+    Junior,
+    Mid,
+    Senior,
+    StaffPlus,
+}
+
+impl SeniorityLevel {
+    /// Maps the JD parser's seniority string to a level. Matching is on whole words, so
+    /// "Senior Staff" → StaffPlus and "new grad" → Junior, while "internal tools" is not
+    /// an intern role. Unknown/empty → Mid, whose fragment is the most neutral.
+    pub fn from_signal(seniority: &str) -> Self {
+        let s = seniority.to_lowercase();
+        let words: Vec<&str> = s
+            .split(|c: char| !c.is_alphanumeric())
+            .filter(|w| !w.is_empty())
+            .collect();
+        // A needle of several words matches only as a consecutive run.
+        let has = |needles: &[&str]| {
+            needles.iter().any(|n| {
+                let phrase: Vec<&str> = n.split(' ').collect();
+                words.windows(phrase.len()).any(|w| w == phrase.as_slice())
             })
-            .copied()
-            .collect(),
+        };
+
+        if has(&["staff", "principal", "distinguished", "fellow", "architect"]) {
+            SeniorityLevel::StaffPlus
+        } else if has(&["senior", "sr", "lead"]) {
+            SeniorityLevel::Senior
+        } else if has(&[
+            "junior",
+            "jr",
+            "entry",
+            "intern",
+            "graduate",
+            "new grad",
+            "associate",
+        ]) {
+            SeniorityLevel::Junior
+        } else {
+            SeniorityLevel::Mid
+        }
+    }
+
+    /// Prompt fragment injected into the generation prompt as `{seniority_instruction}`.
+    pub fn instruction(&self) -> &'static str {
+        match self {
+            SeniorityLevel::Junior => SENIORITY_JUNIOR_INSTRUCTION,
+            SeniorityLevel::Mid => SENIORITY_MID_INSTRUCTION,
+            SeniorityLevel::Senior => SENIORITY_SENIOR_INSTRUCTION,
+            SeniorityLevel::StaffPlus => SENIORITY_STAFF_PLUS_INSTRUCTION,
+        }
     }
 }
 
@@ -130,36 +161,50 @@ mod tests {
 
     #[test]
     fn test_startup_tone_includes_architected() {
-        let t = get_tone_examples(&JDTone::AggressiveStartup);
+        let t = get_tone_examples(&JDTone::AggressiveStartup, DomainPack::Engineering);
         assert!(t.strong_verbs.contains(&"Architected"));
         assert!(t.strong_verbs.contains(&"Spearheaded"));
     }
 
     #[test]
     fn test_enterprise_tone_avoids_sole_language() {
-        let t = get_tone_examples(&JDTone::CollaborativeEnterprise);
+        let t = get_tone_examples(&JDTone::CollaborativeEnterprise, DomainPack::Engineering);
         assert!(t.avoid_verbs.contains(&"architected"));
         assert!(t.avoid_verbs.contains(&"spearheaded"));
     }
 
     #[test]
     fn test_research_tone_includes_published() {
-        let t = get_tone_examples(&JDTone::ResearchOriented);
+        let t = get_tone_examples(&JDTone::ResearchOriented, DomainPack::Engineering);
         assert!(t.strong_verbs.contains(&"Published"));
         assert!(t.strong_verbs.contains(&"Investigated"));
     }
 
     #[test]
     fn test_product_tone_includes_shipped() {
-        let t = get_tone_examples(&JDTone::ProductOriented);
+        let t = get_tone_examples(&JDTone::ProductOriented, DomainPack::Engineering);
         assert!(t.strong_verbs.contains(&"Shipped"));
         assert!(t.strong_verbs.contains(&"Launched"));
     }
 
+    #[test]
+    fn test_pack_swaps_strong_and_sole_owner_verbs() {
+        let t = get_tone_examples(&JDTone::AggressiveStartup, DomainPack::Marketing);
+        assert!(t.strong_verbs.contains(&"Grew"));
+        assert!(!t.strong_verbs.contains(&"Architected"));
+        assert_eq!(t.ownership_prefix, "end-to-end ownership of");
+
+        let filtered =
+            filter_verbs_for_contribution(&t.strong_verbs, "team_member", DomainPack::Marketing);
+        assert!(!filtered.contains(&"Directed"), "marketing sole-owner verb");
+        assert!(filtered.contains(&"Launched"));
+    }
+
     #[test]
     fn test_team_member_filters_sole_owner_verbs() {
         let verbs = vec!["Architected", "Contributed to", "Owned", "Collaborated on"];
-        let filtered = filter_verbs_for_contribution(&verbs, "team_member");
+        let filtered =
+            filter_verbs_for_contribution(&verbs, "team_member", DomainPack::Engineering);
         assert!(
             !filtered.contains(&"Architected"),
             "team_member must not get Architected"
@@ -178,21 +223,23 @@ mod tests {
     #[test]
     fn test_sole_author_keeps_all_verbs() {
         let verbs = vec!["Architected", "Contributed to", "Owned"];
-        let filtered = filter_verbs_for_contribution(&verbs, "sole_author");
+        let filtered =
+            filter_verbs_for_contribution(&verbs, "sole_author", DomainPack::Engineering);
         assert_eq!(filtered.len(), verbs.len(), "sole_author keeps all verbs");
     }
 
     #[test]
     fn test_primary_contributor_keeps_all_verbs() {
         let verbs = vec!["Architected", "Led", "Built"];
-        let filtered = filter_verbs_for_contribution(&verbs, "primary_contributor");
+        let filtered =
+            filter_verbs_for_contribution(&verbs, "primary_contributor", DomainPack::Engineering);
         assert_eq!(filtered.len(), verbs.len());
     }
 
     #[test]
     fn test_reviewer_gets_review_verbs_only() {
         let verbs = vec!["Architected", "Contributed to"];
-        let filtered = filter_verbs_for_contribution(&verbs, "reviewer");
+        let filtered = filter_verbs_for_contribution(&verbs, "reviewer", DomainPack::Engineering);
         assert!(filtered.contains(&"Reviewed"), "reviewer must get Reviewed");
         assert!(
             filtered.contains(&"Evaluated"),
@@ -205,7 +252,8 @@ mod tests {
     #[test]
     fn test_unknown_contribution_type_treated_conservatively() {
         let verbs = vec!["Architected", "Contributed to"];
-        let filtered = filter_verbs_for_contribution(&verbs, "unknown_type");
+        let filtered =
+            filter_verbs_for_contribution(&verbs, "unknown_type", DomainPack::Engineering);
         // Conservative: filters sole-owner verbs
         assert!(!filtered.contains(&"Architected"));
         assert!(filtered.contains(&"Contributed to"));
@@ -215,8 +263,12 @@ mod tests {
     /// exclude sole-owner verbs. This is the core scope inflation guard.
     #[test]
     fn test_startup_tone_team_member_never_gets_sole_owner_verbs() {
-        let startup_tone = get_tone_examples(&JDTone::AggressiveStartup);
-        let filtered = filter_verbs_for_contribution(&startup_tone.strong_verbs, "team_member");
+        let startup_tone = get_tone_examples(&JDTone::AggressiveStartup, DomainPack::Engineering);
+        let filtered = filter_verbs_for_contribution(
+            &startup_tone.strong_verbs,
+            "team_member",
+            DomainPack::Engineering,
+        );
         assert!(
             !filtered.contains(&"Architected"),
             "CRITICAL: team_member must never get Architected even in startup tone"
@@ -230,4 +282,87 @@ mod tests {
             "CRITICAL: team_member must never get Spearheaded even in startup tone"
         );
     }
+
+    #[test]
+    fn test_seniority_from_signal_buckets() {
+        assert_eq!(
+            SeniorityLevel::from_signal("junior"),
+            SeniorityLevel::Junior
+        );
+        assert_eq!(
+            SeniorityLevel::from_signal("New Grad"),
+            SeniorityLevel::Junior
+        );
+        assert_eq!(SeniorityLevel::from_signal("mid"), SeniorityLevel::Mid);
+        assert_eq!(
+            SeniorityLevel::from_signal("Senior"),
+            SeniorityLevel::Senior
+        );
+        assert_eq!(
+            SeniorityLevel::from_signal("staff"),
+            SeniorityLevel::StaffPlus
+        );
+        assert_eq!(
+            SeniorityLevel::from_signal("Senior Staff"),
+            SeniorityLevel::StaffPlus,
+            "staff outranks senior when both appear"
+        );
+        assert_eq!(
+            SeniorityLevel::from_signal("principal"),
+            SeniorityLevel::StaffPlus
+        );
+    }
+
+    #[test]
+    fn test_seniority_matches_whole_words_only() {
+        assert_eq!(
+            SeniorityLevel::from_signal("internal tools"),
+            SeniorityLevel::Mid
+        );
+        assert_eq!(
+            SeniorityLevel::from_signal("misleading"),
+            SeniorityLevel::Mid
+        );
+        assert_eq!(
+            SeniorityLevel::from_signal("Sr. Engineer"),
+            SeniorityLevel::Senior
+        );
+        assert_eq!(
+            SeniorityLevel::from_signal("Tech Lead"),
+            SeniorityLevel::Senior
+        );
+        assert_eq!(
+            SeniorityLevel::from_signal("grad-level"),
+            SeniorityLevel::Mid,
+            "\"new grad\" needs both words"
+        );
+    }
+
+    #[test]
+    fn test_unknown_seniority_defaults_to_mid() {
+        assert_eq!(SeniorityLevel::from_signal(""), SeniorityLevel::Mid);
+        assert_eq!(
+            SeniorityLevel::from_signal("unspecified"),
+            SeniorityLevel::Mid
+        );
+    }
+
+    #[test]
+    fn test_each_level_has_distinct_instruction() {
+        let levels = [
+            SeniorityLevel::Junior,
+            SeniorityLevel::Mid,
+            SeniorityLevel::Senior,
+            SeniorityLevel::StaffPlus,
+        ];
+        for (i, a) in levels.iter().enumerate() {
+            for b in &levels[i + 1..] {
+                assert_ne!(a.instruction(), b.instruction(), "{a:?} vs {b:?}");
+            }
+        }
+        assert!(SeniorityLevel::StaffPlus
+            .instruction()
+            .contains("cross-team"));
+        assert!(SeniorityLevel::Junior.instruction().contains("component"));
+    }
 }
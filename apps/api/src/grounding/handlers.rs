@@ -10,10 +10,11 @@ use axum::{
 };
 use uuid::Uuid;
 
+use crate::auth::{AuthUser, Resource};
 use crate::errors::AppError;
 use crate::grounding::manifest::manifest_from_bullet_rows;
 use crate::grounding::types::AuditManifest;
-use crate::models::resume::{ResumeBulletRow, ResumeRow};
+use crate::models::resume::ResumeBulletRow;
 use crate::state::AppState;
 
 /// GET /api/v1/resumes/:id/audit
@@ -23,23 +24,23 @@ use crate::state::AppState;
 ///
 /// Responses:
 /// - 200 OK + AuditManifest JSON
-/// - 404 Not Found if the resume_id doesn't exist
+/// - 404 Not Found if the resume_id doesn't exist or belongs to a user the caller
+///   does not coach
 pub async fn handle_get_audit_manifest(
     State(state): State<AppState>,
+    auth: AuthUser,
     Path(resume_id): Path<Uuid>,
 ) -> Result<Json<AuditManifest>, AppError> {
-    // Step 1: Verify resume exists
-    let _resume = sqlx::query_as::<_, ResumeRow>("SELECT * FROM resumes WHERE id = $1")
-        .bind(resume_id)
-        .fetch_optional(&state.db)
-        .await?
-        .ok_or_else(|| AppError::NotFound(format!("Resume {resume_id} not found")))?;
+    // Step 1: Verify the caller owns the resume (or coaches its owner)
+    auth.authorize_view(&state.db, Resource::Resume, resume_id)
+        .await?;
 
     // Step 2: Load all bullets for this resume
-    let bullets = sqlx::query_as::<_, ResumeBulletRow>(
-        "SELECT * FROM resume_bullets WHERE resume_id = $1 ORDER BY section, id",
+    let bullets = sqlx::query_as!(
+        ResumeBulletRow,
+        "SELECT * FROM resume_bullets WHERE resume_id = $1 ORDER BY position, id",
+        resume_id
     )
-    .bind(resume_id)
     .fetch_all(&state.db)
     .await?;
 
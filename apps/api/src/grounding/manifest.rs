@@ -216,7 +216,9 @@ mod tests {
                 grounding_score: 0.88, // pass
                 is_user_edited: false,
                 line_count: 1,
+                position: 0,
                 created_at: now,
+                keyword_spans: serde_json::json!([]),
             },
             ResumeBulletRow {
                 id: Uuid::new_v4(),
@@ -227,7 +229,9 @@ mod tests {
                 grounding_score: 0.70, // flag_for_review
                 is_user_edited: false,
                 line_count: 1,
+                position: 1,
                 created_at: now,
+                keyword_spans: serde_json::json!([]),
             },
             ResumeBulletRow {
                 id: Uuid::new_v4(),
@@ -238,7 +242,9 @@ mod tests {
                 grounding_score: 0.40, // fail
                 is_user_edited: false,
                 line_count: 1,
+                position: 2,
                 created_at: now,
+                keyword_spans: serde_json::json!([]),
             },
         ];
 
@@ -20,7 +20,7 @@ use crate::errors::AppError;
 use crate::grounding::prompts::{GROUNDING_SCORE_PROMPT_TEMPLATE, GROUNDING_SCORE_SYSTEM};
 use crate::grounding::scope_check::check_scope_inflation;
 use crate::grounding::types::{GroundingResult, GroundingScore, GroundingVerdict};
-use crate::layout::SimulatedBullet;
+use crate::layout::{RevisionCause, SimulatedBullet};
 use crate::llm_client::LlmClient;
 use crate::models::context::ContextEntryRow;
 
@@ -165,15 +165,10 @@ pub async fn regenerate_single_bullet(
         return Ok(bullet.clone());
     }
 
-    Ok(SimulatedBullet {
-        text: result.text,
-        source_entry_id: bullet.source_entry_id,
-        section: bullet.section.clone(),
-        verified_line_count: bullet.verified_line_count,
-        jd_keywords_used: bullet.jd_keywords_used.clone(),
-        was_adjusted: true,
-        flagged_for_review: bullet.flagged_for_review,
-    })
+    let mut rewritten = bullet.clone();
+    rewritten.revise(result.text, RevisionCause::Regenerate);
+    rewritten.was_adjusted = true;
+    Ok(rewritten)
 }
 
 // ─────────────────────────────────────────────────────────────────────────────
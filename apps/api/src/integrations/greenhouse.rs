@@ -0,0 +1,122 @@
+//! Greenhouse Job Board API (`boards-api.greenhouse.io/v1`) payloads and their mapping
+//! onto `FetchedPosting`.
+//!
+//! `content` is the posting body as HTML with its markup entity-escaped (`&lt;p&gt;`).
+
+use serde::Deserialize;
+use serde_json::json;
+
+use crate::generation::html_extract::fragment_text;
+use crate::integrations::{jd_text, non_blank, FetchedPosting, JobBoard};
+
+const API_BASE: &str = "https://boards-api.greenhouse.io/v1/boards";
+
+pub fn job_url(board: &str, job_id: &str) -> String {
+    format!("{API_BASE}/{board}/jobs/{job_id}")
+}
+
+/// The listing with each posting's `content`, which it otherwise leaves out.
+pub fn board_url(board: &str) -> String {
+    format!("{API_BASE}/{board}/jobs?content=true")
+}
+
+#[derive(Debug, Deserialize)]
+pub struct JobList {
+    pub jobs: Vec<Job>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct Job {
+    pub id: u64,
+    pub title: String,
+    pub absolute_url: String,
+    #[serde(default)]
+    pub content: Option<String>,
+    #[serde(default)]
+    pub location: Option<Named>,
+    #[serde(default)]
+    pub departments: Vec<Named>,
+    #[serde(default)]
+    pub offices: Vec<Named>,
+    #[serde(default)]
+    pub company_name: Option<String>,
+    #[serde(default)]
+    pub requisition_id: Option<String>,
+    #[serde(default)]
+    pub updated_at: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct Named {
+    #[serde(default)]
+    pub name: Option<String>,
+}
+
+pub fn normalize(board: &str, job: &Job) -> FetchedPosting {
+    let title = job.title.trim().to_string();
+    let company = non_blank(job.company_name.as_deref());
+    let body = job
+        .content
+        .as_deref()
+        .map(fragment_text)
+        .unwrap_or_default();
+    let offices: Vec<&str> = job
+        .offices
+        .iter()
+        .filter_map(|o| o.name.as_deref())
+        .collect();
+
+    FetchedPosting {
+        source: JobBoard::Greenhouse,
+        source_key: format!("{board}/{}", job.id),
+        source_url: job.absolute_url.clone(),
+        jd_text: jd_text(&title, company.as_deref(), &[body]),
+        title,
+        company,
+        location: non_blank(job.location.as_ref().and_then(|l| l.name.as_deref())),
+        department: non_blank(job.departments.first().and_then(|d| d.name.as_deref())),
+        metadata: json!({
+            "job_id": job.id,
+            "requisition_id": job.requisition_id,
+            "offices": offices,
+            "updated_at": job.updated_at,
+        }),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_normalizes_escaped_content_and_metadata() {
+        let job: Job = serde_json::from_str(
+            r#"{
+                "id": 4012345,
+                "title": "Senior Backend Engineer ",
+                "absolute_url": "https://boards.greenhouse.io/acme/jobs/4012345",
+                "company_name": "Acme",
+                "location": { "name": "Berlin, Germany" },
+                "departments": [{ "id": 7, "name": "Payments", "child_ids": [] }],
+                "offices": [{ "id": 3, "name": "Berlin" }],
+                "requisition_id": "ENG-118",
+                "updated_at": "2026-09-30T11:02:44-04:00",
+                "content": "&lt;p&gt;You will build the ledger &amp;amp; settlement services.&lt;/p&gt;&lt;ul&gt;&lt;li&gt;5+ years of Rust or Go&lt;/li&gt;&lt;li&gt;PostgreSQL at scale&lt;/li&gt;&lt;/ul&gt;"
+            }"#,
+        )
+        .unwrap();
+        let posting = normalize("acme", &job);
+
+        assert_eq!(posting.source_key, "acme/4012345");
+        assert_eq!(posting.title, "Senior Backend Engineer");
+        assert_eq!(posting.location.as_deref(), Some("Berlin, Germany"));
+        assert_eq!(posting.department.as_deref(), Some("Payments"));
+        assert!(posting.jd_text.starts_with(
+            "Senior Backend Engineer at Acme\n\nYou will build the ledger & settlement services."
+        ));
+        assert!(posting.jd_text.contains("5+ years of Rust or Go"));
+        assert!(!posting.jd_text.contains("&lt;"));
+        assert_eq!(posting.metadata["requisition_id"], "ENG-118");
+        assert_eq!(posting.metadata["offices"], json!(["Berlin"]));
+    }
+}
@@ -0,0 +1,174 @@
+//! Axum handlers for importing job postings from job boards.
+
+use axum::{extract::State, Json};
+use chrono::Utc;
+use sqlx::PgExecutor;
+
+use crate::auth::AuthUser;
+use crate::errors::AppError;
+use crate::generation::jd_parser::parse_jd;
+use crate::integrations::{
+    is_token, FetchedPosting, JobBoardImportRequest, JobBoardImportResponse,
+    JobPostingImportRequest, JobPostingImportResponse, ParsedJdRow, PostingRef,
+};
+use crate::quota;
+use crate::state::AppState;
+use crate::validation::ValidJson;
+
+/// POST /api/v1/job-postings/import
+///
+/// Fetches the Greenhouse or Lever posting at `url` from the board's API, parses it and
+/// returns both. A posting fetched within `CACHE_TTL_HOURS` (by anyone) is served from
+/// `parsed_jds` with its stored parse, so repeat imports cost no fetch and no LLM call.
+///
+/// Returns 400 for URLs that aren't a posting on either board, 404 when the board no
+/// longer lists the posting.
+pub async fn handle_import_posting(
+    State(state): State<AppState>,
+    auth: AuthUser,
+    ValidJson(request): ValidJson<JobPostingImportRequest>,
+) -> Result<Json<JobPostingImportResponse>, AppError> {
+    let posting = PostingRef::parse(&request.url)?;
+    let source = posting.source().as_str();
+    let source_key = posting.source_key();
+
+    let cached = sqlx::query_as!(
+        ParsedJdRow,
+        "SELECT * FROM parsed_jds WHERE source = $1 AND source_key = $2",
+        source,
+        source_key
+    )
+    .fetch_optional(&state.db)
+    .await?
+    .filter(|row| row.is_fresh(Utc::now()));
+    let from_cache = cached.is_some();
+    let row = match cached {
+        Some(row) => row,
+        None => {
+            let fetched = state.job_boards.posting(&posting).await?;
+            upsert_posting(&state.db, &fetched).await?
+        }
+    };
+
+    let stored = row
+        .parsed_jd
+        .clone()
+        .and_then(|value| serde_json::from_value(value).ok());
+    let (parsed_jd, cached) = match stored {
+        Some(parsed_jd) => (parsed_jd, from_cache),
+        None => {
+            let parsed_jd = quota::metered_llm(
+                &state.db,
+                &state.redis,
+                auth.user_id,
+                parse_jd(&row.jd_text, &state.llm),
+            )
+            .await?;
+            let value = serde_json::to_value(&parsed_jd)
+                .map_err(|e| AppError::Internal(anyhow::anyhow!("ParsedJD encoding: {e}")))?;
+            sqlx::query!(
+                "UPDATE parsed_jds SET parsed_jd = $2 WHERE id = $1",
+                row.id,
+                value
+            )
+            .execute(&state.db)
+            .await?;
+            (parsed_jd, false)
+        }
+    };
+
+    tracing::info!(
+        user_id = %auth.user_id,
+        source,
+        source_key = %row.source_key,
+        cached,
+        "Job posting imported"
+    );
+    Ok(Json(JobPostingImportResponse {
+        posting: row.to_posting(),
+        parsed_jd,
+        cached,
+    }))
+}
+
+/// POST /api/v1/job-postings/board
+///
+/// Fetches every open posting on a Greenhouse or Lever board and caches them in
+/// `parsed_jds`, unparsed; importing one by its `source_url` then parses it without
+/// another fetch. One board API call however many postings the board has.
+///
+/// Returns 404 when the board does not exist.
+pub async fn handle_import_board(
+    State(state): State<AppState>,
+    auth: AuthUser,
+    ValidJson(request): ValidJson<JobBoardImportRequest>,
+) -> Result<Json<JobBoardImportResponse>, AppError> {
+    let board_token = request.board_token.trim();
+    if !is_token(board_token) {
+        return Err(AppError::Validation(format!(
+            "Invalid board token: {board_token}"
+        )));
+    }
+    let fetched = state.job_boards.board(request.source, board_token).await?;
+
+    let mut tx = state.db.begin().await?;
+    let mut postings = Vec::with_capacity(fetched.len());
+    for posting in &fetched {
+        postings.push(upsert_posting(&mut *tx, posting).await?.to_summary());
+    }
+    tx.commit().await?;
+
+    tracing::info!(
+        user_id = %auth.user_id,
+        source = request.source.as_str(),
+        board_token,
+        postings = postings.len(),
+        "Job board imported"
+    );
+    Ok(Json(JobBoardImportResponse {
+        source: request.source,
+        board_token: board_token.to_string(),
+        company: fetched.iter().find_map(|p| p.company.clone()),
+        postings,
+    }))
+}
+
+/// Stores a freshly fetched posting. The stored parse survives only if the text is
+/// unchanged.
+async fn upsert_posting<'e>(
+    executor: impl PgExecutor<'e>,
+    posting: &FetchedPosting,
+) -> Result<ParsedJdRow, AppError> {
+    let row = sqlx::query_as!(
+        ParsedJdRow,
+        r#"
+        INSERT INTO parsed_jds
+            (source, source_key, source_url, title, company, location, department, jd_text, metadata)
+        VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9)
+        ON CONFLICT (source, source_key) DO UPDATE SET
+            source_url = EXCLUDED.source_url,
+            title      = EXCLUDED.title,
+            company    = EXCLUDED.company,
+            location   = EXCLUDED.location,
+            department = EXCLUDED.department,
+            jd_text    = EXCLUDED.jd_text,
+            metadata   = EXCLUDED.metadata,
+            parsed_jd  = CASE WHEN parsed_jds.jd_text = EXCLUDED.jd_text
+                              THEN parsed_jds.parsed_jd END,
+            fetched_at = NOW()
+        RETURNING *
+        "#,
+        posting.source.as_str(),
+        posting.source_key,
+        posting.source_url,
+        posting.title,
+        posting.company,
+        posting.location,
+        posting.department,
+        posting.jd_text,
+        posting.metadata
+    )
+    .fetch_one(executor)
+    .await?;
+    Ok(row)
+}
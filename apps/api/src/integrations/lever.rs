@@ -0,0 +1,152 @@
+//! Lever Postings API (`api.lever.co/v0/postings`) payloads and their mapping onto
+//! `FetchedPosting`.
+//!
+//! A Lever posting splits its body into an opening `description`, titled `lists`
+//! (requirements, responsibilities; each an HTML `<li>` run) and a closing
+//! `additional`. Postings carry no company name, only the board token.
+
+use serde::Deserialize;
+use serde_json::json;
+
+use crate::generation::html_extract::fragment_text;
+use crate::integrations::{jd_text, non_blank, FetchedPosting, JobBoard};
+
+fn api_base(eu: bool) -> &'static str {
+    if eu {
+        "https://api.eu.lever.co/v0/postings"
+    } else {
+        "https://api.lever.co/v0/postings"
+    }
+}
+
+pub fn posting_url(company: &str, posting_id: &str, eu: bool) -> String {
+    format!("{}/{company}/{posting_id}", api_base(eu))
+}
+
+pub fn board_url(company: &str, eu: bool) -> String {
+    format!("{}/{company}?mode=json", api_base(eu))
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Posting {
+    pub id: String,
+    pub text: String,
+    pub hosted_url: String,
+    #[serde(default)]
+    pub categories: Categories,
+    #[serde(default)]
+    pub description: Option<String>,
+    #[serde(default)]
+    pub lists: Vec<List>,
+    #[serde(default)]
+    pub additional: Option<String>,
+    #[serde(default)]
+    pub workplace_type: Option<String>,
+    /// Milliseconds since the epoch.
+    #[serde(default)]
+    pub created_at: Option<i64>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+pub struct Categories {
+    #[serde(default)]
+    pub location: Option<String>,
+    #[serde(default)]
+    pub team: Option<String>,
+    #[serde(default)]
+    pub department: Option<String>,
+    #[serde(default)]
+    pub commitment: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct List {
+    #[serde(default)]
+    pub text: String,
+    #[serde(default)]
+    pub content: String,
+}
+
+pub fn normalize(company: &str, posting: &Posting) -> FetchedPosting {
+    let title = posting.text.trim().to_string();
+    let mut sections = vec![posting
+        .description
+        .as_deref()
+        .map(fragment_text)
+        .unwrap_or_default()];
+    for list in &posting.lists {
+        // `content` is bare `<li>`s; wrap them so they render as a list.
+        let items = fragment_text(&format!("<ul>{}</ul>", list.content));
+        sections.push(format!("{}\n{}", list.text.trim(), items.trim()));
+    }
+    sections.push(
+        posting
+            .additional
+            .as_deref()
+            .map(fragment_text)
+            .unwrap_or_default(),
+    );
+
+    let categories = &posting.categories;
+    FetchedPosting {
+        source: JobBoard::Lever,
+        source_key: format!("{company}/{}", posting.id),
+        source_url: posting.hosted_url.clone(),
+        jd_text: jd_text(&title, None, &sections),
+        title,
+        company: None,
+        location: non_blank(categories.location.as_deref()),
+        department: non_blank(
+            categories
+                .team
+                .as_deref()
+                .or(categories.department.as_deref()),
+        ),
+        metadata: json!({
+            "posting_id": posting.id,
+            "commitment": categories.commitment,
+            "workplace_type": posting.workplace_type,
+            "created_at": posting.created_at,
+        }),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_normalizes_description_lists_and_additional_in_order() {
+        let posting: Posting = serde_json::from_str(
+            r#"{
+                "id": "5f2c8a1e-0b7d-4c1a-9e3f-2d6b7a8c9e10",
+                "text": "Data Engineer",
+                "hostedUrl": "https://jobs.lever.co/acme/5f2c8a1e-0b7d-4c1a-9e3f-2d6b7a8c9e10",
+                "categories": { "location": "Remote - US", "team": "Data Platform", "commitment": "Full-time" },
+                "description": "<div>Acme moves freight. You will own our pipelines.</div>",
+                "lists": [
+                    { "text": "What you'll need", "content": "<li>Airflow or Dagster</li><li>Strong SQL</li>" }
+                ],
+                "additional": "<div>Visa sponsorship available.</div>",
+                "workplaceType": "remote",
+                "createdAt": 1759150000000
+            }"#,
+        )
+        .unwrap();
+        let fetched = normalize("acme", &posting);
+
+        assert_eq!(
+            fetched.source_key,
+            "acme/5f2c8a1e-0b7d-4c1a-9e3f-2d6b7a8c9e10"
+        );
+        assert_eq!(fetched.department.as_deref(), Some("Data Platform"));
+        assert_eq!(fetched.company, None);
+        let text = &fetched.jd_text;
+        assert!(text.starts_with("Data Engineer\n\nAcme moves freight."));
+        let need = text.find("What you'll need").unwrap();
+        assert!(need < text.find("Strong SQL").unwrap());
+        assert!(text.find("Strong SQL").unwrap() < text.find("Visa sponsorship").unwrap());
+        assert_eq!(fetched.metadata["commitment"], "Full-time");
+    }
+}
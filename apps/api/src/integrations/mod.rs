@@ -0,0 +1,420 @@
+//! Integrations module — job postings from public job board APIs.
+//!
+//! POST /api/v1/job-postings/import — fetch one Greenhouse or Lever posting by URL and parse it
+//! POST /api/v1/job-postings/board  — fetch every open posting on a board
+//!
+//! Both boards serve their postings as JSON without authentication, so a posting comes
+//! in as title, location, department and description fields instead of a page to scrape.
+//! Each posting is normalized to `jd_text` (the input `parse_jd` and generation take)
+//! plus metadata, and cached in `parsed_jds` with its parsed form. The cache is shared by
+//! all users and refetched once older than `CACHE_TTL_HOURS`.
+//!
+//! Board payloads map in greenhouse.rs and lever.rs; the client, URL parsing and the
+//! cache row live here; handlers in handlers.rs.
+
+pub mod greenhouse;
+pub mod handlers;
+pub mod lever;
+
+use std::time::Duration;
+
+use chrono::{DateTime, Utc};
+use serde::de::DeserializeOwned;
+use serde_json::Value;
+use sqlx::FromRow;
+use uuid::Uuid;
+
+use crate::errors::AppError;
+use crate::generation::html_extract::truncate_lines;
+use crate::validation::MAX_JD_TEXT_CHARS;
+
+pub use templar_types::integrations::{
+    JobBoard, JobBoardImportRequest, JobBoardImportResponse, JobPosting, JobPostingImportRequest,
+    JobPostingImportResponse, JobPostingSummary,
+};
+
+/// How long a fetched posting is served from `parsed_jds` before it is fetched again.
+pub const CACHE_TTL_HOURS: i64 = 6;
+
+/// Leaves a board import room within the standard request budget.
+const FETCH_TIMEOUT: Duration = Duration::from_secs(10);
+
+// ────────────────────────────────────────────────────────────────────────────
+// Posting URLs
+// ────────────────────────────────────────────────────────────────────────────
+
+/// A posting on a board, as identified by its public URL.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PostingRef {
+    Greenhouse {
+        board: String,
+        job_id: String,
+    },
+    Lever {
+        company: String,
+        posting_id: String,
+        /// Hosted on `jobs.eu.lever.co`, served by the EU API.
+        eu: bool,
+    },
+}
+
+impl PostingRef {
+    /// Parses the posting URL forms the boards link and share:
+    /// - `boards.greenhouse.io/{board}/jobs/{id}` (also `job-boards.greenhouse.io` and
+    ///   `job-boards.eu.greenhouse.io`)
+    /// - `boards.greenhouse.io/embed/job_app?for={board}&token={id}`
+    /// - `jobs.lever.co/{company}/{id}` (also `jobs.eu.lever.co`, and with `/apply`)
+    pub fn parse(raw: &str) -> Result<PostingRef, AppError> {
+        let url = url::Url::parse(raw.trim())
+            .map_err(|_| AppError::Validation(format!("Not a URL: {raw}")))?;
+        let host = url.host_str().unwrap_or_default().to_ascii_lowercase();
+        let segments: Vec<&str> = url
+            .path_segments()
+            .map(|s| s.filter(|s| !s.is_empty()).collect())
+            .unwrap_or_default();
+
+        let posting = if host.ends_with(".greenhouse.io") {
+            match segments.as_slice() {
+                ["embed", "job_app"] => {
+                    let param = |name: &str| {
+                        url.query_pairs()
+                            .find(|(k, _)| k == name)
+                            .map(|(_, v)| v.into_owned())
+                    };
+                    param("for")
+                        .zip(param("token"))
+                        .map(|(board, job_id)| PostingRef::Greenhouse { board, job_id })
+                }
+                [board, "jobs", job_id, ..] => Some(PostingRef::Greenhouse {
+                    board: board.to_string(),
+                    job_id: job_id.to_string(),
+                }),
+                _ => None,
+            }
+        } else if host == "jobs.lever.co" || host == "jobs.eu.lever.co" {
+            match segments.as_slice() {
+                [company, posting_id, ..] => Some(PostingRef::Lever {
+                    company: company.to_string(),
+                    posting_id: posting_id.to_string(),
+                    eu: host == "jobs.eu.lever.co",
+                }),
+                _ => None,
+            }
+        } else {
+            return Err(AppError::Validation(
+                "Only Greenhouse and Lever posting URLs can be imported".into(),
+            ));
+        };
+
+        let posting = posting.ok_or_else(|| {
+            AppError::Validation(format!(
+                "{raw} is not a posting URL; import a board with POST /job-postings/board"
+            ))
+        })?;
+        let (board, id) = match &posting {
+            PostingRef::Greenhouse { board, job_id } => (board, job_id),
+            PostingRef::Lever {
+                company,
+                posting_id,
+                ..
+            } => (company, posting_id),
+        };
+        if !is_token(board) || !is_token(id) {
+            return Err(AppError::Validation(format!(
+                "Unrecognized posting URL: {raw}"
+            )));
+        }
+        Ok(posting)
+    }
+
+    pub fn source(&self) -> JobBoard {
+        match self {
+            PostingRef::Greenhouse { .. } => JobBoard::Greenhouse,
+            PostingRef::Lever { .. } => JobBoard::Lever,
+        }
+    }
+
+    /// `parsed_jds.source_key`.
+    pub fn source_key(&self) -> String {
+        match self {
+            PostingRef::Greenhouse { board, job_id } => format!("{board}/{job_id}"),
+            PostingRef::Lever {
+                company,
+                posting_id,
+                ..
+            } => format!("{company}/{posting_id}"),
+        }
+    }
+}
+
+/// Board tokens and posting ids go into API paths; both boards only issue these
+/// characters.
+pub fn is_token(value: &str) -> bool {
+    !value.is_empty()
+        && value
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || matches!(c, '-' | '_' | '.'))
+        && value != "."
+        && value != ".."
+}
+
+// ────────────────────────────────────────────────────────────────────────────
+// Fetching
+// ────────────────────────────────────────────────────────────────────────────
+
+/// A posting normalized from either board's payload.
+#[derive(Debug, Clone)]
+pub struct FetchedPosting {
+    pub source: JobBoard,
+    pub source_key: String,
+    pub source_url: String,
+    pub title: String,
+    pub company: Option<String>,
+    pub location: Option<String>,
+    pub department: Option<String>,
+    pub jd_text: String,
+    /// Board-specific fields worth keeping (ids, employment type, update time).
+    pub metadata: Value,
+}
+
+/// HTTP client for the boards' public APIs.
+#[derive(Clone)]
+pub struct JobBoardClient {
+    http: reqwest::Client,
+}
+
+impl Default for JobBoardClient {
+    fn default() -> Self {
+        JobBoardClient {
+            http: reqwest::Client::builder()
+                .timeout(FETCH_TIMEOUT)
+                .build()
+                .expect("Failed to build HTTP client"),
+        }
+    }
+}
+
+impl JobBoardClient {
+    /// The posting `posting` points at. 404 when the board no longer lists it.
+    pub async fn posting(&self, posting: &PostingRef) -> Result<FetchedPosting, AppError> {
+        let fetched = match posting {
+            PostingRef::Greenhouse { board, job_id } => self
+                .get_json(&greenhouse::job_url(board, job_id))
+                .await?
+                .map(|job| greenhouse::normalize(board, &job)),
+            PostingRef::Lever {
+                company,
+                posting_id,
+                eu,
+            } => self
+                .get_json(&lever::posting_url(company, posting_id, *eu))
+                .await?
+                .map(|p| lever::normalize(company, &p)),
+        };
+        fetched.ok_or_else(|| {
+            AppError::NotFound(format!(
+                "{} posting {} not found; it may have closed",
+                posting.source().as_str(),
+                posting.source_key()
+            ))
+        })
+    }
+
+    /// Every open posting on a board. 404 when the board does not exist.
+    pub async fn board(
+        &self,
+        source: JobBoard,
+        board_token: &str,
+    ) -> Result<Vec<FetchedPosting>, AppError> {
+        let postings = match source {
+            JobBoard::Greenhouse => self
+                .get_json::<greenhouse::JobList>(&greenhouse::board_url(board_token))
+                .await?
+                .map(|list| {
+                    list.jobs
+                        .iter()
+                        .map(|job| greenhouse::normalize(board_token, job))
+                        .collect()
+                }),
+            JobBoard::Lever => {
+                // A board token doesn't say which region hosts it; try global first.
+                let mut list = self
+                    .get_json::<Vec<lever::Posting>>(&lever::board_url(board_token, false))
+                    .await?;
+                if list.is_none() {
+                    list = self.get_json(&lever::board_url(board_token, true)).await?;
+                }
+                list.map(|postings| {
+                    postings
+                        .iter()
+                        .map(|p| lever::normalize(board_token, p))
+                        .collect()
+                })
+            }
+        };
+        postings.ok_or_else(|| {
+            AppError::NotFound(format!("No {} board named {board_token}", source.as_str()))
+        })
+    }
+
+    /// `None` on 404; both boards answer unknown boards and closed postings with it.
+    async fn get_json<T: DeserializeOwned>(&self, url: &str) -> Result<Option<T>, AppError> {
+        let response = self
+            .http
+            .get(url)
+            .header("Accept", "application/json")
+            .send()
+            .await
+            .map_err(|e| AppError::Internal(anyhow::anyhow!("Job board request failed: {e}")))?;
+        if response.status() == reqwest::StatusCode::NOT_FOUND {
+            return Ok(None);
+        }
+        let response = response
+            .error_for_status()
+            .map_err(|e| AppError::Internal(anyhow::anyhow!("Job board request failed: {e}")))?;
+        response
+            .json()
+            .await
+            .map(Some)
+            .map_err(|e| AppError::Internal(anyhow::anyhow!("Unexpected job board payload: {e}")))
+    }
+}
+
+/// `jd_text` for a posting: a `{title} at {company}` heading, then the body sections in
+/// order, capped like extracted page text.
+fn jd_text(title: &str, company: Option<&str>, sections: &[String]) -> String {
+    let heading = match company {
+        Some(company) => format!("{title} at {company}"),
+        None => title.to_string(),
+    };
+    let text = std::iter::once(heading)
+        .chain(sections.iter().map(|s| s.trim().to_string()))
+        .filter(|s| !s.is_empty())
+        .collect::<Vec<_>>()
+        .join("\n\n");
+    truncate_lines(&text, MAX_JD_TEXT_CHARS as usize)
+}
+
+/// `None` for absent and blank strings.
+fn non_blank(value: Option<&str>) -> Option<String> {
+    value
+        .map(str::trim)
+        .filter(|s| !s.is_empty())
+        .map(str::to_string)
+}
+
+// ────────────────────────────────────────────────────────────────────────────
+// Cache
+// ────────────────────────────────────────────────────────────────────────────
+
+#[derive(Debug, Clone, FromRow)]
+pub struct ParsedJdRow {
+    pub id: Uuid,
+    pub source: String,
+    pub source_key: String,
+    pub source_url: String,
+    pub title: String,
+    pub company: Option<String>,
+    pub location: Option<String>,
+    pub department: Option<String>,
+    pub jd_text: String,
+    pub metadata: Value,
+    pub parsed_jd: Option<Value>,
+    pub fetched_at: DateTime<Utc>,
+}
+
+impl ParsedJdRow {
+    pub fn is_fresh(&self, now: DateTime<Utc>) -> bool {
+        now - self.fetched_at < chrono::Duration::hours(CACHE_TTL_HOURS)
+    }
+
+    pub fn to_posting(&self) -> JobPosting {
+        JobPosting {
+            id: self.id,
+            source: match self.source.as_str() {
+                "lever" => JobBoard::Lever,
+                _ => JobBoard::Greenhouse,
+            },
+            source_key: self.source_key.clone(),
+            source_url: self.source_url.clone(),
+            title: self.title.clone(),
+            company: self.company.clone(),
+            location: self.location.clone(),
+            department: self.department.clone(),
+            jd_text: self.jd_text.clone(),
+            metadata: self.metadata.clone(),
+            fetched_at: self.fetched_at,
+        }
+    }
+
+    pub fn to_summary(&self) -> JobPostingSummary {
+        JobPostingSummary {
+            id: self.id,
+            source_url: self.source_url.clone(),
+            title: self.title.clone(),
+            location: self.location.clone(),
+            department: self.department.clone(),
+        }
+    }
+}
+
+// ────────────────────────────────────────────────────────────────────────────
+// Tests
+// ────────────────────────────────────────────────────────────────────────────
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parses_posting_urls() {
+        let greenhouse = |board: &str, job_id: &str| PostingRef::Greenhouse {
+            board: board.into(),
+            job_id: job_id.into(),
+        };
+        for url in [
+            "https://boards.greenhouse.io/acme/jobs/4012345",
+            "https://job-boards.greenhouse.io/acme/jobs/4012345?gh_src=abc",
+            "https://job-boards.eu.greenhouse.io/acme/jobs/4012345",
+            "https://boards.greenhouse.io/embed/job_app?for=acme&token=4012345",
+        ] {
+            assert_eq!(
+                PostingRef::parse(url).unwrap(),
+                greenhouse("acme", "4012345")
+            );
+        }
+
+        let posting = PostingRef::parse(
+            "https://jobs.eu.lever.co/acme/5f2c8a1e-0b7d-4c1a-9e3f-2d6b7a8c9e10/apply",
+        )
+        .unwrap();
+        assert_eq!(
+            posting,
+            PostingRef::Lever {
+                company: "acme".into(),
+                posting_id: "5f2c8a1e-0b7d-4c1a-9e3f-2d6b7a8c9e10".into(),
+                eu: true,
+            }
+        );
+        assert_eq!(
+            posting.source_key(),
+            "acme/5f2c8a1e-0b7d-4c1a-9e3f-2d6b7a8c9e10"
+        );
+    }
+
+    #[test]
+    fn test_rejects_boards_other_hosts_and_path_tricks() {
+        for url in [
+            "https://boards.greenhouse.io/acme",
+            "https://jobs.lever.co/acme",
+            "https://example.com/acme/jobs/1",
+            "https://boards.greenhouse.io/embed/job_app?for=../admin&token=1",
+            "not a url",
+        ] {
+            assert!(
+                matches!(PostingRef::parse(url), Err(AppError::Validation(_))),
+                "{url}"
+            );
+        }
+    }
+}
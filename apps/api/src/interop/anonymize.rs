@@ -0,0 +1,529 @@
+//! Anonymized copies of a resume, safe to share publicly or attach to a bug report.
+//!
+//! POST /api/v1/resumes/:id/anonymize builds the resume's JSON Resume document, as the
+//! export does, and masks it in two passes:
+//! 1. Deterministic: fields that name something (the author, employers, schools,
+//!    organizations, projects, award givers, publishers) get numbered placeholders such
+//!    as `[Company 1]`, and every occurrence of those names in free text is replaced
+//!    too. Contact details, links and locations are dropped; emails, URLs and phone
+//!    numbers left in free text become `[Email]`, `[URL]` and `[Phone]`.
+//! 2. An LLM check lists the proper nouns still in the free text (a client named in a
+//!    bullet, a colleague). Each one that really occurs there is masked the same way.
+//!    When the check fails the copy keeps pass 1 only and says so (`llm_checked`).
+//!
+//! The same name always gets the same placeholder. Nothing is stored.
+
+use std::collections::HashMap;
+
+use serde::Deserialize;
+use sqlx::PgPool;
+use tracing::warn;
+use uuid::Uuid;
+
+use crate::errors::AppError;
+use crate::interop::handlers::load_resume_document;
+use crate::interop::json_resume::JsonResume;
+use crate::interop::prompts::{ANONYMIZE_CHECK_PROMPT_TEMPLATE, ANONYMIZE_CHECK_SYSTEM};
+use crate::interop::{AnonymizedResume, PlaceholderKind, Redaction, RedactionSource};
+use crate::llm_client::{LlmClient, LlmError};
+
+/// Fewest digits in a run masked as a phone number; ten keeps year ranges
+/// ("2018 - 2020 (3 years)") intact.
+const MIN_PHONE_DIGITS: usize = 10;
+const MAX_PHONE_DIGITS: usize = 15;
+
+/// Builds the anonymized copy of `resume_id`. 404 for a deleted resume.
+pub async fn anonymize_resume(
+    db: &PgPool,
+    llm: &LlmClient,
+    resume_id: Uuid,
+) -> Result<AnonymizedResume, AppError> {
+    let (_, doc) = load_resume_document(db, resume_id).await?;
+    let mut masker = Masker::default();
+    let mut document = mask_document(doc, &mut masker);
+
+    let llm_checked = match llm_check(llm, &document).await {
+        Ok(names) => {
+            let text = free_text_lines(&document);
+            for name in names {
+                if masker.accepts(&name.text, &text) {
+                    let kind = serde_json::from_value(serde_json::Value::String(name.kind))
+                        .unwrap_or(PlaceholderKind::Other);
+                    masker.placeholder(&name.text, kind, RedactionSource::LlmCheck);
+                }
+            }
+            mask_free_text(&mut document, &mut masker);
+            true
+        }
+        Err(e) => {
+            warn!(%resume_id, error = %e, "Anonymize: LLM check failed, deterministic masking only");
+            false
+        }
+    };
+
+    Ok(AnonymizedResume {
+        document,
+        redactions: masker.redactions,
+        masked_contacts: masker.masked_contacts,
+        llm_checked,
+    })
+}
+
+// ────────────────────────────────────────────────────────────────────────────
+// Deterministic masking
+// ────────────────────────────────────────────────────────────────────────────
+
+/// Names found so far and the placeholders they map to.
+#[derive(Debug, Default)]
+struct Masker {
+    /// (original, placeholder); name parts of the author map to their placeholder too.
+    names: Vec<(String, String)>,
+    redactions: Vec<Redaction>,
+    counts: HashMap<PlaceholderKind, usize>,
+    masked_contacts: usize,
+}
+
+impl Masker {
+    /// The placeholder for `original`, numbering a new one per kind on first sight.
+    fn placeholder(
+        &mut self,
+        original: &str,
+        kind: PlaceholderKind,
+        found_by: RedactionSource,
+    ) -> String {
+        let original = original.trim();
+        if let Some((_, placeholder)) = self
+            .names
+            .iter()
+            .find(|(name, _)| name.eq_ignore_ascii_case(original))
+        {
+            return placeholder.clone();
+        }
+        let n = self.counts.entry(kind).or_insert(0);
+        *n += 1;
+        let placeholder = format!("[{} {n}]", kind.label());
+        self.names.push((original.to_string(), placeholder.clone()));
+        if kind == PlaceholderKind::Person {
+            // "Ada" alone in a summary is still the author.
+            for part in original
+                .split_whitespace()
+                .filter(|p| p.chars().count() >= 3)
+            {
+                self.names.push((part.to_string(), placeholder.clone()));
+            }
+        }
+        self.redactions.push(Redaction {
+            placeholder: placeholder.clone(),
+            kind,
+            found_by,
+        });
+        placeholder
+    }
+
+    /// Replaces a naming field with its placeholder.
+    fn mask_field(&mut self, field: &mut Option<String>, kind: PlaceholderKind) {
+        if let Some(value) = field.as_mut().filter(|v| !v.trim().is_empty()) {
+            *value = self.placeholder(value, kind, RedactionSource::Field);
+        }
+    }
+
+    /// Emails, URLs and phone numbers in `text` masked, then every known name
+    /// replaced by its placeholder (longest first, so a full name wins over its parts).
+    /// Contacts go first: a name inside a URL would otherwise split it.
+    fn mask_text(&mut self, text: &str) -> String {
+        let (mut masked, contacts) = mask_contacts(text);
+        self.masked_contacts += contacts;
+        let mut names: Vec<&(String, String)> = self.names.iter().collect();
+        names.sort_by_key(|(name, _)| std::cmp::Reverse(name.len()));
+        for (name, placeholder) in names {
+            masked = replace_word_ci(&masked, name, placeholder);
+        }
+        masked
+    }
+
+    /// Whether an LLM-reported name is worth masking: it occurs in the text as a whole
+    /// word and is not part of a placeholder.
+    fn accepts(&self, name: &str, text: &[String]) -> bool {
+        let name = name.trim();
+        name.chars().filter(|c| c.is_alphanumeric()).count() >= 2
+            && !name.contains(['[', ']'])
+            && !self
+                .redactions
+                .iter()
+                .any(|r| contains_word_ci(&r.placeholder, name))
+            && text.iter().any(|t| contains_word_ci(t, name))
+    }
+}
+
+/// Pass 1 over the whole document.
+fn mask_document(mut doc: JsonResume, masker: &mut Masker) -> JsonResume {
+    if let Some(basics) = doc.basics.as_mut() {
+        masker.mask_field(&mut basics.name, PlaceholderKind::Person);
+        basics.email = None;
+        basics.phone = None;
+        basics.url = None;
+        basics.location = None;
+        basics.profiles.clear();
+    }
+    for work in &mut doc.work {
+        masker.mask_field(&mut work.name, PlaceholderKind::Company);
+        work.location = None;
+        work.url = None;
+    }
+    for volunteer in &mut doc.volunteer {
+        masker.mask_field(&mut volunteer.organization, PlaceholderKind::Organization);
+        volunteer.url = None;
+    }
+    for education in &mut doc.education {
+        masker.mask_field(&mut education.institution, PlaceholderKind::Institution);
+        education.url = None;
+    }
+    for award in &mut doc.awards {
+        masker.mask_field(&mut award.awarder, PlaceholderKind::Organization);
+    }
+    for certificate in &mut doc.certificates {
+        // Issuers (AWS, CNCF) say nothing about who holds the certificate; its URL does.
+        certificate.url = None;
+    }
+    for publication in &mut doc.publications {
+        masker.mask_field(&mut publication.publisher, PlaceholderKind::Organization);
+        publication.url = None;
+    }
+    for project in &mut doc.projects {
+        masker.mask_field(&mut project.name, PlaceholderKind::Project);
+        project.url = None;
+    }
+    if let Some(meta) = doc.meta.as_mut() {
+        meta.canonical = None;
+    }
+    mask_free_text(&mut doc, masker);
+    doc
+}
+
+/// The non-empty prose of the document, one string per field.
+fn free_text_lines(doc: &JsonResume) -> Vec<String> {
+    let mut doc = doc.clone();
+    free_text(&mut doc)
+        .into_iter()
+        .filter(|t| !t.trim().is_empty())
+        .map(|t| t.to_string())
+        .collect()
+}
+
+fn mask_free_text(doc: &mut JsonResume, masker: &mut Masker) {
+    for text in free_text(doc) {
+        *text = masker.mask_text(text);
+    }
+}
+
+/// Every prose field of the document: where names can hide outside the naming fields.
+fn free_text(doc: &mut JsonResume) -> Vec<&mut String> {
+    let mut text: Vec<&mut String> = Vec::new();
+    if let Some(basics) = doc.basics.as_mut() {
+        text.extend(basics.label.as_mut());
+        text.extend(basics.summary.as_mut());
+    }
+    for work in &mut doc.work {
+        text.extend(work.position.as_mut());
+        text.extend(work.summary.as_mut());
+        text.extend(work.highlights.iter_mut());
+    }
+    for volunteer in &mut doc.volunteer {
+        text.extend(volunteer.position.as_mut());
+        text.extend(volunteer.summary.as_mut());
+        text.extend(volunteer.highlights.iter_mut());
+    }
+    for education in &mut doc.education {
+        text.extend(education.area.as_mut());
+        text.extend(education.courses.iter_mut());
+    }
+    for award in &mut doc.awards {
+        text.extend(award.title.as_mut());
+        text.extend(award.summary.as_mut());
+    }
+    for certificate in &mut doc.certificates {
+        text.extend(certificate.name.as_mut());
+    }
+    for publication in &mut doc.publications {
+        text.extend(publication.name.as_mut());
+        text.extend(publication.summary.as_mut());
+    }
+    for project in &mut doc.projects {
+        text.extend(project.description.as_mut());
+        text.extend(project.highlights.iter_mut());
+    }
+    text
+}
+
+/// `text` with each whole-word, ASCII-case-insensitive occurrence of `needle` replaced.
+fn replace_word_ci(text: &str, needle: &str, replacement: &str) -> String {
+    if needle.is_empty() {
+        return text.to_string();
+    }
+    // ASCII lowercasing keeps byte offsets, so matches index into `text` directly.
+    let haystack = text.to_ascii_lowercase();
+    let needle = needle.to_ascii_lowercase();
+    let mut out = String::with_capacity(text.len());
+    let mut last = 0;
+    for (start, _) in haystack.match_indices(&needle) {
+        let end = start + needle.len();
+        if start < last || !is_word_boundary(text, start, end) {
+            continue;
+        }
+        out.push_str(&text[last..start]);
+        out.push_str(replacement);
+        last = end;
+    }
+    out.push_str(&text[last..]);
+    out
+}
+
+fn contains_word_ci(text: &str, needle: &str) -> bool {
+    let haystack = text.to_ascii_lowercase();
+    let needle = needle.trim().to_ascii_lowercase();
+    !needle.is_empty()
+        && haystack
+            .match_indices(&needle)
+            .any(|(start, _)| is_word_boundary(text, start, start + needle.len()))
+}
+
+fn is_word_boundary(text: &str, start: usize, end: usize) -> bool {
+    let before = text[..start].chars().next_back();
+    let after = text[end..].chars().next();
+    !before.is_some_and(char::is_alphanumeric) && !after.is_some_and(char::is_alphanumeric)
+}
+
+/// `text` with emails, URLs and phone numbers masked, and how many there were.
+fn mask_contacts(text: &str) -> (String, usize) {
+    let mut count = 0;
+    let mut out = String::with_capacity(text.len());
+    for word in text.split_inclusive(char::is_whitespace) {
+        let body = word.trim_end();
+        let trailing = &word[body.len()..];
+        let start = body
+            .find(|c: char| !matches!(c, '(' | '<' | '"' | '\''))
+            .unwrap_or(body.len());
+        let end = body
+            .rfind(|c: char| !matches!(c, '.' | ',' | ';' | ':' | ')' | '>' | '"' | '\''))
+            .map_or(start, |i| {
+                i + body[i..].chars().next().map_or(1, char::len_utf8)
+            })
+            .max(start);
+        match contact_placeholder(&body[start..end]) {
+            Some(placeholder) => {
+                count += 1;
+                out.push_str(&body[..start]);
+                out.push_str(placeholder);
+                out.push_str(&body[end..]);
+                out.push_str(trailing);
+            }
+            None => out.push_str(word),
+        }
+    }
+    let (out, phones) = mask_phones(&out);
+    (out, count + phones)
+}
+
+fn contact_placeholder(token: &str) -> Option<&'static str> {
+    let lower = token.to_ascii_lowercase();
+    if lower.starts_with("http://") || lower.starts_with("https://") || lower.starts_with("www.") {
+        return Some("[URL]");
+    }
+    let (user, domain) = token.split_once('@')?;
+    let domain_ok = domain.contains('.') && !domain.starts_with('.') && !domain.ends_with('.');
+    (!user.is_empty() && domain_ok).then_some("[Email]")
+}
+
+/// Runs of digits and phone punctuation holding `MIN_PHONE_DIGITS`–`MAX_PHONE_DIGITS`
+/// digits become `[Phone]`.
+fn mask_phones(text: &str) -> (String, usize) {
+    let is_phone_char =
+        |c: char| c.is_ascii_digit() || matches!(c, '+' | '-' | '(' | ')' | '.' | ' ');
+    let mut out = String::with_capacity(text.len());
+    let mut count = 0;
+    let mut rest = text;
+    while let Some(start) = rest.find(|c: char| c.is_ascii_digit() || c == '+' || c == '(') {
+        // A digit inside a word ("p99", "S3") does not start a number.
+        if rest[..start]
+            .chars()
+            .next_back()
+            .is_some_and(char::is_alphanumeric)
+        {
+            let skip = start + rest[start..].chars().next().map_or(1, char::len_utf8);
+            out.push_str(&rest[..skip]);
+            rest = &rest[skip..];
+            continue;
+        }
+        let run_len = rest[start..]
+            .find(|c: char| !is_phone_char(c))
+            .unwrap_or(rest.len() - start);
+        let run = &rest[start..start + run_len];
+        // Ends at its last digit: trailing spaces and punctuation stay.
+        let run = &run[..run.rfind(|c: char| c.is_ascii_digit()).map_or(0, |i| i + 1)];
+        let digits = run.chars().filter(char::is_ascii_digit).count();
+        let next = rest[start + run.len()..].chars().next();
+        out.push_str(&rest[..start]);
+        if (MIN_PHONE_DIGITS..=MAX_PHONE_DIGITS).contains(&digits)
+            && !next.is_some_and(char::is_alphanumeric)
+        {
+            out.push_str("[Phone]");
+            count += 1;
+        } else {
+            out.push_str(if run.is_empty() {
+                &rest[start..start + 1]
+            } else {
+                run
+            });
+        }
+        rest = &rest[start + run.len().max(1)..];
+    }
+    out.push_str(rest);
+    (out, count)
+}
+
+// ────────────────────────────────────────────────────────────────────────────
+// LLM check
+// ────────────────────────────────────────────────────────────────────────────
+
+#[derive(Debug, Deserialize)]
+struct CheckResponse {
+    #[serde(default)]
+    names: Vec<FoundName>,
+}
+
+#[derive(Debug, Deserialize)]
+struct FoundName {
+    text: String,
+    /// A `PlaceholderKind`; anything else counts as `other`.
+    #[serde(default)]
+    kind: String,
+}
+
+/// Proper nouns the LLM still finds in the masked free text.
+async fn llm_check(llm: &LlmClient, doc: &JsonResume) -> Result<Vec<FoundName>, LlmError> {
+    let lines = free_text_lines(doc);
+    if lines.is_empty() {
+        return Ok(vec![]);
+    }
+    let prompt = ANONYMIZE_CHECK_PROMPT_TEMPLATE.replace("{lines}", &lines.join("\n"));
+    let response: CheckResponse = llm.call_json(&prompt, ANONYMIZE_CHECK_SYSTEM).await?;
+    Ok(response.names)
+}
+
+// ────────────────────────────────────────────────────────────────────────────
+// Tests
+// ────────────────────────────────────────────────────────────────────────────
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::interop::json_resume::{JrBasics, JrEducation, JrWork};
+
+    fn doc() -> JsonResume {
+        JsonResume {
+            basics: Some(JrBasics {
+                name: Some("Ada Lovelace".into()),
+                email: Some("ada@example.com".into()),
+                summary: Some("Ada builds payment systems; see https://ada.dev.".into()),
+                ..Default::default()
+            }),
+            work: vec![
+                JrWork {
+                    name: Some("Globex".into()),
+                    position: Some("Staff Engineer".into()),
+                    url: Some("https://globex.com".into()),
+                    highlights: vec![
+                        "Moved GLOBEX billing to Rust, cutting p99 latency 40%".into(),
+                        "Paged at +1 (415) 555-0100 for Globex-wide incidents".into(),
+                    ],
+                    ..Default::default()
+                },
+                JrWork {
+                    name: Some("globex".into()),
+                    ..Default::default()
+                },
+            ],
+            education: vec![JrEducation {
+                institution: Some("Big University".into()),
+                area: Some("Computer Science".into()),
+                ..Default::default()
+            }],
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_fields_and_their_mentions_get_stable_placeholders() {
+        let mut masker = Masker::default();
+        let masked = mask_document(doc(), &mut masker);
+
+        let basics = masked.basics.as_ref().unwrap();
+        assert_eq!(basics.name.as_deref(), Some("[Person 1]"));
+        assert_eq!(basics.email, None);
+        assert_eq!(
+            basics.summary.as_deref(),
+            Some("[Person 1] builds payment systems; see [URL].")
+        );
+        assert_eq!(masked.work[0].name.as_deref(), Some("[Company 1]"));
+        assert_eq!(masked.work[1].name.as_deref(), Some("[Company 1]"));
+        assert_eq!(masked.work[0].url, None);
+        assert_eq!(
+            masked.work[0].highlights,
+            vec![
+                "Moved [Company 1] billing to Rust, cutting p99 latency 40%",
+                "Paged at [Phone] for [Company 1]-wide incidents",
+            ]
+        );
+        assert_eq!(
+            masked.education[0].institution.as_deref(),
+            Some("[Institution 1]")
+        );
+        assert_eq!(
+            masked.education[0].area.as_deref(),
+            Some("Computer Science")
+        );
+
+        let placeholders: Vec<&str> = masker
+            .redactions
+            .iter()
+            .map(|r| r.placeholder.as_str())
+            .collect();
+        assert_eq!(
+            placeholders,
+            vec!["[Person 1]", "[Company 1]", "[Institution 1]"]
+        );
+        assert_eq!(masker.masked_contacts, 2);
+    }
+
+    #[test]
+    fn test_contacts_are_masked_without_touching_metrics_or_years() {
+        let (masked, count) =
+            mask_contacts("Mail me (ada@example.com), call 415.555.0100 or visit www.ada.dev.");
+        assert_eq!(masked, "Mail me ([Email]), call [Phone] or visit [URL].");
+        assert_eq!(count, 3);
+
+        let text = "Served 12,000,000 requests/day on S3 from 2018 - 2020 (3 years), p99 120ms";
+        assert_eq!(mask_contacts(text), (text.to_string(), 0));
+    }
+
+    #[test]
+    fn test_llm_names_must_occur_and_not_be_placeholders() {
+        let mut masker = Masker::default();
+        masker.placeholder("Globex", PlaceholderKind::Company, RedactionSource::Field);
+        let text = vec!["Shipped the Initech migration for [Company 1]".to_string()];
+
+        assert!(masker.accepts("Initech", &text));
+        assert!(!masker.accepts("Umbrella", &text));
+        assert!(!masker.accepts("Company", &text));
+        assert!(!masker.accepts("[Company 1]", &text));
+        assert!(!masker.accepts("I", &text));
+    }
+
+    #[test]
+    fn test_replace_respects_word_boundaries() {
+        assert_eq!(
+            replace_word_ci("Acme and ACME-wide, not Acmeology", "acme", "[Company 1]"),
+            "[Company 1] and [Company 1]-wide, not Acmeology"
+        );
+    }
+}
@@ -0,0 +1,686 @@
+//! Europass CV — XML (schema v3.4) and a PDF in the Europass layout.
+//!
+//! Both are rendered from the `JsonResume` document the `json_resume` export builds,
+//! so every format lists the same items. Europass has fewer, broader sections:
+//!
+//! | JSON Resume                  | Europass                                    |
+//! |------------------------------|---------------------------------------------|
+//! | `work`, `volunteer`          | WorkExperience                              |
+//! | `education`                  | Education                                   |
+//! | `skills`                     | Skills / Computer (digital skills)          |
+//! | `projects`, `publications`, `awards`, `certificates` | Achievement, by section code |
+//!
+//! Dates are written at the precision they were stored at; Europass months and days
+//! are XML `gMonth`/`gDay` values (`--06`, `---15`).
+
+use chrono::{DateTime, NaiveDate, Utc};
+
+use crate::interop::json_resume::{JrBasics, JrEducation, JsonResume};
+use crate::render::templates::escape_latex;
+
+const NAMESPACE: &str = "http://europass.cedefop.europa.eu/Europass";
+const SCHEMA_LOCATION: &str =
+    "http://europass.cedefop.europa.eu/Europass http://europass.cedefop.europa.eu/xml/v3.4.0/EuropassSchema.xsd";
+
+// ────────────────────────────────────────────────────────────────────────────
+// XML
+// ────────────────────────────────────────────────────────────────────────────
+
+/// The document as a Europass `SkillsPassport` CV.
+pub fn to_xml(doc: &JsonResume, last_updated: DateTime<Utc>) -> String {
+    let mut xml = XmlWriter::default();
+    xml.out
+        .push_str("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n");
+    xml.open(
+        "SkillsPassport",
+        &[
+            ("xmlns", NAMESPACE),
+            ("xmlns:xsi", "http://www.w3.org/2001/XMLSchema-instance"),
+            ("xsi:schemaLocation", SCHEMA_LOCATION),
+            ("locale", "en"),
+        ],
+    );
+
+    let updated = last_updated.format("%Y-%m-%dT%H:%M:%S%.3fZ").to_string();
+    xml.open("DocumentInfo", &[]);
+    xml.leaf("DocumentType", "ECV");
+    xml.leaf("CreationDate", &updated);
+    xml.leaf("LastUpdateDate", &updated);
+    xml.leaf("XSDVersion", "V3.4");
+    xml.leaf("Generator", "Templar");
+    xml.close("DocumentInfo");
+
+    xml.open("LearnerInfo", &[]);
+    if let Some(basics) = &doc.basics {
+        identification(&mut xml, basics);
+    }
+
+    let has_work = !doc.work.is_empty() || !doc.volunteer.is_empty();
+    if has_work {
+        xml.open("WorkExperienceList", &[]);
+        for work in &doc.work {
+            xml.open("WorkExperience", &[]);
+            period(&mut xml, &work.start_date, &work.end_date);
+            if let Some(position) = &work.position {
+                xml.open("Position", &[]);
+                xml.leaf("Label", position);
+                xml.close("Position");
+            }
+            activities(&mut xml, &work.highlights);
+            if work.name.is_some() || work.location.is_some() {
+                xml.open("Employer", &[]);
+                if let Some(name) = &work.name {
+                    xml.leaf("Name", name);
+                }
+                if let Some(location) = &work.location {
+                    xml.open("ContactInfo", &[]);
+                    xml.open("Address", &[]);
+                    xml.open("Contact", &[]);
+                    xml.leaf("Municipality", location);
+                    xml.close("Contact");
+                    xml.close("Address");
+                    xml.close("ContactInfo");
+                }
+                xml.close("Employer");
+            }
+            xml.close("WorkExperience");
+        }
+        for volunteer in &doc.volunteer {
+            xml.open("WorkExperience", &[]);
+            period(&mut xml, &volunteer.start_date, &volunteer.end_date);
+            xml.open("Position", &[]);
+            xml.leaf(
+                "Label",
+                &format!(
+                    "{} (volunteer)",
+                    volunteer.position.as_deref().unwrap_or("Volunteer")
+                ),
+            );
+            xml.close("Position");
+            activities(&mut xml, &volunteer.highlights);
+            if let Some(organization) = &volunteer.organization {
+                xml.open("Employer", &[]);
+                xml.leaf("Name", organization);
+                xml.close("Employer");
+            }
+            xml.close("WorkExperience");
+        }
+        xml.close("WorkExperienceList");
+    }
+
+    if !doc.education.is_empty() {
+        xml.open("EducationList", &[]);
+        for education in &doc.education {
+            xml.open("Education", &[]);
+            period(&mut xml, &education.start_date, &education.end_date);
+            xml.leaf("Title", &degree_title(education));
+            let mut details = education.courses.clone();
+            if let Some(score) = &education.score {
+                details.insert(0, format!("Grade: {score}"));
+            }
+            activities(&mut xml, &details);
+            if let Some(institution) = &education.institution {
+                xml.open("Organisation", &[]);
+                xml.leaf("Name", institution);
+                xml.close("Organisation");
+            }
+            xml.close("Education");
+        }
+        xml.close("EducationList");
+    }
+
+    if !doc.skills.is_empty() {
+        xml.open("Skills", &[]);
+        xml.open("Computer", &[]);
+        xml.leaf("Description", &html_list(&skill_lines(doc)));
+        xml.close("Computer");
+        xml.close("Skills");
+    }
+
+    let achievements = achievements(doc);
+    if !achievements.is_empty() {
+        xml.open("AchievementList", &[]);
+        for (code, label, lines) in achievements {
+            xml.open("Achievement", &[]);
+            xml.open("Title", &[]);
+            xml.leaf("Code", code);
+            xml.leaf("Label", label);
+            xml.close("Title");
+            xml.leaf("Description", &html_list(&lines));
+            xml.close("Achievement");
+        }
+        xml.close("AchievementList");
+    }
+
+    xml.close("LearnerInfo");
+    xml.close("SkillsPassport");
+    xml.out
+}
+
+fn identification(xml: &mut XmlWriter, basics: &JrBasics) {
+    xml.open("Identification", &[]);
+    if let Some(name) = &basics.name {
+        let (first, surname) = split_name(name);
+        xml.open("PersonName", &[]);
+        xml.leaf("FirstName", first);
+        xml.leaf("Surname", surname);
+        xml.close("PersonName");
+    }
+    xml.open("ContactInfo", &[]);
+    if let Some(address) = basics.location.as_ref().and_then(|l| l.address.as_deref()) {
+        xml.open("Address", &[]);
+        xml.open("Contact", &[]);
+        xml.leaf("AddressLine", address);
+        xml.close("Contact");
+        xml.close("Address");
+    }
+    if let Some(email) = &basics.email {
+        xml.open("Email", &[]);
+        xml.leaf("Contact", email);
+        xml.close("Email");
+    }
+    if let Some(phone) = &basics.phone {
+        xml.open("TelephoneList", &[]);
+        xml.open("Telephone", &[]);
+        xml.leaf("Contact", phone);
+        xml.close("Telephone");
+        xml.close("TelephoneList");
+    }
+    // Europass has no field for social profiles; they are listed as websites.
+    let websites: Vec<&str> = basics
+        .url
+        .as_deref()
+        .into_iter()
+        .chain(basics.profiles.iter().filter_map(|p| p.url.as_deref()))
+        .collect();
+    if !websites.is_empty() {
+        xml.open("WebsiteList", &[]);
+        for website in websites {
+            xml.open("Website", &[]);
+            xml.leaf("Contact", website);
+            xml.close("Website");
+        }
+        xml.close("WebsiteList");
+    }
+    xml.close("ContactInfo");
+    xml.close("Identification");
+}
+
+fn period(xml: &mut XmlWriter, start: &Option<String>, end: &Option<String>) {
+    let from = start.as_deref().and_then(date_attrs);
+    let to = end.as_deref().and_then(date_attrs);
+    if from.is_none() && to.is_none() {
+        return;
+    }
+    xml.open("Period", &[]);
+    if let Some(attrs) = from {
+        xml.empty("From", &attrs);
+    }
+    match to {
+        Some(attrs) => xml.empty("To", &attrs),
+        // A start with no end is the current position.
+        None => xml.leaf("Current", "true"),
+    }
+    xml.close("Period");
+}
+
+/// `year`, `month` and `day` attributes for an ISO date of any precision.
+fn date_attrs(date: &str) -> Option<Vec<(&'static str, String)>> {
+    let mut parts = date.trim().splitn(3, '-');
+    let year = parts
+        .next()
+        .filter(|y| y.len() == 4 && y.parse::<i32>().is_ok())?;
+    let mut attrs = vec![("year", year.to_string())];
+    if let Some(month) = parts.next().and_then(|m| m.parse::<u32>().ok()) {
+        attrs.push(("month", format!("--{month:02}")));
+        if let Some(day) = parts.next().and_then(|d| d.get(..2)?.parse::<u32>().ok()) {
+            attrs.push(("day", format!("---{day:02}")));
+        }
+    }
+    Some(attrs)
+}
+
+fn activities(xml: &mut XmlWriter, lines: &[String]) {
+    if !lines.is_empty() {
+        xml.leaf("Activities", &html_list(lines));
+    }
+}
+
+/// Europass rich text: an HTML list, which the XML writer then escapes.
+fn html_list(lines: &[String]) -> String {
+    let items: String = lines
+        .iter()
+        .map(|line| format!("<li>{}</li>", escape_xml(line)))
+        .collect();
+    format!("<ul>{items}</ul>")
+}
+
+#[derive(Default)]
+struct XmlWriter {
+    out: String,
+    depth: usize,
+}
+
+impl XmlWriter {
+    fn indent(&mut self) {
+        self.out.push_str(&"  ".repeat(self.depth));
+    }
+
+    fn start_tag(&mut self, tag: &str, attrs: &[(&str, String)]) {
+        self.indent();
+        self.out.push('<');
+        self.out.push_str(tag);
+        for (name, value) in attrs {
+            self.out
+                .push_str(&format!(" {name}=\"{}\"", escape_xml(value)));
+        }
+    }
+
+    fn open(&mut self, tag: &str, attrs: &[(&str, &str)]) {
+        let attrs: Vec<(&str, String)> = attrs.iter().map(|(n, v)| (*n, v.to_string())).collect();
+        self.start_tag(tag, &attrs);
+        self.out.push_str(">\n");
+        self.depth += 1;
+    }
+
+    fn close(&mut self, tag: &str) {
+        self.depth -= 1;
+        self.indent();
+        self.out.push_str(&format!("</{tag}>\n"));
+    }
+
+    fn leaf(&mut self, tag: &str, text: &str) {
+        self.indent();
+        self.out
+            .push_str(&format!("<{tag}>{}</{tag}>\n", escape_xml(text)));
+    }
+
+    fn empty(&mut self, tag: &str, attrs: &[(&str, String)]) {
+        self.start_tag(tag, attrs);
+        self.out.push_str("/>\n");
+    }
+}
+
+fn escape_xml(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&apos;"),
+            // Control characters other than tab and newlines are not allowed in XML 1.0.
+            c if c.is_control() && !matches!(c, '\t' | '\n' | '\r') => {}
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+// ────────────────────────────────────────────────────────────────────────────
+// PDF
+// ────────────────────────────────────────────────────────────────────────────
+
+/// The document as LaTeX in the Europass CV layout: section and item labels in a
+/// narrow right-aligned column, content beside them. Compiles with plain pdflatex.
+pub fn to_latex(doc: &JsonResume) -> String {
+    let mut tex = String::with_capacity(8192);
+    tex.push_str(
+        r#"\documentclass[a4paper,10pt]{article}
+\usepackage[left=0.6in,right=0.6in,top=0.6in,bottom=0.6in]{geometry}
+\usepackage{lmodern}
+\usepackage[T1]{fontenc}
+\usepackage[utf8]{inputenc}
+\usepackage{enumitem}
+\usepackage{xcolor}
+\usepackage[hidelinks]{hyperref}
+\definecolor{europassblue}{HTML}{0E4194}
+\definecolor{europassgrey}{HTML}{3F3A38}
+\setlist[itemize]{leftmargin=1.2em,itemsep=1pt,parsep=0pt,topsep=0pt}
+\setlength{\parindent}{0pt}
+\newcommand{\cvlabel}[1]{\begin{minipage}[t]{0.27\textwidth}\raggedleft\small\color{europassgrey}#1\end{minipage}\hspace{0.03\textwidth}}
+\newcommand{\cvrow}[2]{\cvlabel{#1}\begin{minipage}[t]{0.70\textwidth}#2\end{minipage}\par\medskip}
+\newcommand{\cvsection}[1]{\bigskip\cvlabel{\color{europassblue}\MakeUppercase{#1}}\begin{minipage}[t]{0.70\textwidth}\color{europassblue}\rule[0.5ex]{\linewidth}{0.6pt}\end{minipage}\par\medskip}
+\pagestyle{empty}
+\begin{document}
+"#,
+    );
+
+    if let Some(basics) = &doc.basics {
+        tex.push_str("\\cvsection{Personal information}\n");
+        if let Some(name) = &basics.name {
+            tex.push_str(&format!(
+                "\\cvrow{{}}{{{{\\Large\\color{{europassblue}} {}}}}}\n",
+                escape_latex(name)
+            ));
+        }
+        let contact: Vec<String> = [
+            basics.location.as_ref().and_then(|l| l.address.clone()),
+            basics.phone.clone(),
+            basics.email.clone(),
+            basics.url.clone(),
+        ]
+        .into_iter()
+        .flatten()
+        .chain(basics.profiles.iter().filter_map(|p| p.url.clone()))
+        .map(|c| escape_latex(&c))
+        .collect();
+        if !contact.is_empty() {
+            tex.push_str(&format!("\\cvrow{{}}{{{}}}\n", contact.join("\\\\ ")));
+        }
+    }
+
+    if !doc.work.is_empty() || !doc.volunteer.is_empty() {
+        tex.push_str("\\cvsection{Work experience}\n");
+        for work in &doc.work {
+            let employer = [work.name.as_deref(), work.location.as_deref()]
+                .into_iter()
+                .flatten()
+                .collect::<Vec<_>>()
+                .join(", ");
+            item(
+                &mut tex,
+                &work.start_date,
+                &work.end_date,
+                work.position.as_deref().unwrap_or_default(),
+                &employer,
+                &work.highlights,
+            );
+        }
+        for volunteer in &doc.volunteer {
+            item(
+                &mut tex,
+                &volunteer.start_date,
+                &volunteer.end_date,
+                &format!(
+                    "{} (volunteer)",
+                    volunteer.position.as_deref().unwrap_or("Volunteer")
+                ),
+                volunteer.organization.as_deref().unwrap_or_default(),
+                &volunteer.highlights,
+            );
+        }
+    }
+
+    if !doc.education.is_empty() {
+        tex.push_str("\\cvsection{Education and training}\n");
+        for education in &doc.education {
+            let mut details = education.courses.clone();
+            if let Some(score) = &education.score {
+                details.insert(0, format!("Grade: {score}"));
+            }
+            item(
+                &mut tex,
+                &education.start_date,
+                &education.end_date,
+                &degree_title(education),
+                education.institution.as_deref().unwrap_or_default(),
+                &details,
+            );
+        }
+    }
+
+    if !doc.skills.is_empty() {
+        tex.push_str("\\cvsection{Personal skills}\n");
+        tex.push_str(&format!(
+            "\\cvrow{{Digital skills}}{{{}}}\n",
+            latex_list(&skill_lines(doc))
+        ));
+    }
+
+    let achievements = achievements(doc);
+    if !achievements.is_empty() {
+        tex.push_str("\\cvsection{Additional information}\n");
+        for (_, label, lines) in achievements {
+            tex.push_str(&format!(
+                "\\cvrow{{{}}}{{{}}}\n",
+                escape_latex(label),
+                latex_list(&lines)
+            ));
+        }
+    }
+
+    tex.push_str("\n\\end{document}\n");
+    tex
+}
+
+/// One work or education item: dates as the label; title, organisation and details.
+fn item(
+    tex: &mut String,
+    start: &Option<String>,
+    end: &Option<String>,
+    title: &str,
+    organisation: &str,
+    details: &[String],
+) {
+    let mut lines = vec![];
+    if !title.is_empty() {
+        lines.push(format!(
+            "{{\\color{{europassblue}}\\bfseries {}}}",
+            escape_latex(title)
+        ));
+    }
+    if !organisation.is_empty() {
+        lines.push(escape_latex(organisation));
+    }
+    // `\\` goes between lines only: ending a line with no text on it is a LaTeX error.
+    let mut body = lines.join("\\\\ ");
+    if !details.is_empty() {
+        body.push_str(&latex_list(details));
+    }
+    tex.push_str(&format!(
+        "\\cvrow{{{}}}{{{body}}}\n",
+        escape_latex(&date_range(start, end))
+    ));
+}
+
+fn latex_list(lines: &[String]) -> String {
+    let items: String = lines
+        .iter()
+        .map(|line| format!("\\item {}\n", escape_latex(line)))
+        .collect();
+    format!("\\begin{{itemize}}\n{items}\\end{{itemize}}")
+}
+
+/// `06/2019 – Present`, at the precision the dates were stored at.
+fn date_range(start: &Option<String>, end: &Option<String>) -> String {
+    match (start.as_deref(), end.as_deref()) {
+        (None, None) => String::new(),
+        (Some(start), None) => format!("{} – Present", display_date(start)),
+        (None, Some(end)) => display_date(end),
+        (Some(start), Some(end)) => format!("{} – {}", display_date(start), display_date(end)),
+    }
+}
+
+/// `2019-06-01` → `01/06/2019`, `2019-06` → `06/2019`; anything else as given.
+fn display_date(date: &str) -> String {
+    if let Ok(full) = NaiveDate::parse_from_str(date, "%Y-%m-%d") {
+        return full.format("%d/%m/%Y").to_string();
+    }
+    match date.split_once('-') {
+        Some((year, month)) if year.len() == 4 && month.len() == 2 => format!("{month}/{year}"),
+        _ => date.to_string(),
+    }
+}
+
+// ────────────────────────────────────────────────────────────────────────────
+// Shared mapping
+// ────────────────────────────────────────────────────────────────────────────
+
+/// Europass "Title" of a qualification: "BSc Computer Science".
+fn degree_title(education: &JrEducation) -> String {
+    [education.study_type.as_deref(), education.area.as_deref()]
+        .into_iter()
+        .flatten()
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// Skill groups as `Languages: Rust, Go` lines.
+fn skill_lines(doc: &JsonResume) -> Vec<String> {
+    doc.skills
+        .iter()
+        .map(|skill| match &skill.name {
+            Some(name) => format!("{name}: {}", skill.keywords.join(", ")),
+            None => skill.keywords.join(", "),
+        })
+        .collect()
+}
+
+/// The Europass Achievement sections present in the document: code, label, lines.
+fn achievements(doc: &JsonResume) -> Vec<(&'static str, &'static str, Vec<String>)> {
+    let projects: Vec<String> = doc
+        .projects
+        .iter()
+        .map(|p| {
+            let mut line = p.name.clone().unwrap_or_default();
+            let detail = if p.highlights.is_empty() {
+                p.description.clone().unwrap_or_default()
+            } else {
+                p.highlights.join(" ")
+            };
+            if !detail.is_empty() {
+                line = if line.is_empty() {
+                    detail
+                } else {
+                    format!("{line}: {detail}")
+                };
+            }
+            line
+        })
+        .collect();
+    let publications: Vec<String> = doc
+        .publications
+        .iter()
+        .map(|p| joined(&[&p.name, &p.publisher, &p.release_date]))
+        .collect();
+    let awards: Vec<String> = doc
+        .awards
+        .iter()
+        .map(|a| joined(&[&a.title, &a.awarder, &a.date]))
+        .collect();
+    let certificates: Vec<String> = doc
+        .certificates
+        .iter()
+        .map(|c| joined(&[&c.name, &c.issuer, &c.date]))
+        .collect();
+    [
+        ("projects", "Projects", projects),
+        ("publications", "Publications", publications),
+        ("honors_awards", "Honours and awards", awards),
+        ("certifications", "Certifications", certificates),
+    ]
+    .into_iter()
+    .filter(|(_, _, lines)| !lines.is_empty())
+    .collect()
+}
+
+fn joined(parts: &[&Option<String>]) -> String {
+    parts
+        .iter()
+        .filter_map(|p| p.as_deref())
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+/// "Ada King Lovelace" → ("Ada King", "Lovelace"). A single word is the first name.
+fn split_name(name: &str) -> (&str, &str) {
+    let name = name.trim();
+    name.rsplit_once(char::is_whitespace)
+        .map(|(first, last)| (first.trim_end(), last))
+        .unwrap_or((name, ""))
+}
+
+// ────────────────────────────────────────────────────────────────────────────
+// Tests
+// ────────────────────────────────────────────────────────────────────────────
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::interop::json_resume::{JrAward, JrLocation, JrSkill, JrWork};
+
+    fn doc() -> JsonResume {
+        JsonResume {
+            basics: Some(JrBasics {
+                name: Some("Ada King Lovelace".into()),
+                email: Some("ada@example.com".into()),
+                location: Some(JrLocation {
+                    address: Some("London".into()),
+                    ..JrLocation::default()
+                }),
+                ..JrBasics::default()
+            }),
+            work: vec![JrWork {
+                name: Some("R&D <Labs>".into()),
+                position: Some("Engineer".into()),
+                start_date: Some("2019-06-01".into()),
+                highlights: vec!["Cut p99 latency by 40% & costs".into()],
+                ..JrWork::default()
+            }],
+            education: vec![JrEducation {
+                institution: Some("MIT".into()),
+                study_type: Some("BSc".into()),
+                area: Some("Computer Science".into()),
+                start_date: Some("2014-09".into()),
+                end_date: Some("2018".into()),
+                ..JrEducation::default()
+            }],
+            skills: vec![JrSkill {
+                name: Some("Languages".into()),
+                keywords: vec!["Rust".into(), "C#".into()],
+                ..JrSkill::default()
+            }],
+            awards: vec![JrAward {
+                title: Some("Best Paper".into()),
+                awarder: Some("ICML".into()),
+                ..JrAward::default()
+            }],
+            ..JsonResume::default()
+        }
+    }
+
+    #[test]
+    fn test_to_xml_maps_sections_and_escapes() {
+        let xml = to_xml(&doc(), DateTime::<Utc>::UNIX_EPOCH);
+        assert!(xml.starts_with("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n<SkillsPassport"));
+        assert!(xml.contains("<FirstName>Ada King</FirstName>"));
+        assert!(xml.contains("<Surname>Lovelace</Surname>"));
+        assert!(xml.contains("<AddressLine>London</AddressLine>"));
+        assert!(xml.contains("<From year=\"2019\" month=\"--06\" day=\"---01\"/>"));
+        assert!(xml.contains("<Current>true</Current>"));
+        assert!(xml.contains("<From year=\"2014\" month=\"--09\"/>"));
+        assert!(xml.contains("<To year=\"2018\"/>"));
+        assert!(xml.contains("<Name>R&amp;D &lt;Labs&gt;</Name>"));
+        // Rich text is HTML, escaped once for HTML and once for XML.
+        assert!(xml.contains(
+            "<Activities>&lt;ul&gt;&lt;li&gt;Cut p99 latency by 40% &amp;amp; costs&lt;/li&gt;&lt;/ul&gt;</Activities>"
+        ));
+        assert!(xml.contains("<Title>BSc Computer Science</Title>"));
+        assert!(xml.contains("<Code>honors_awards</Code>"));
+        assert!(xml.contains("LastUpdateDate>1970-01-01T00:00:00.000Z<"));
+        assert!(xml.ends_with("</SkillsPassport>\n"));
+    }
+
+    #[test]
+    fn test_to_latex_lays_out_europass_sections() {
+        let tex = to_latex(&doc());
+        assert!(tex.contains("\\cvsection{Work experience}"));
+        assert!(tex.contains("\\cvrow{01/06/2019 – Present}"));
+        assert!(tex.contains("\\cvrow{09/2014 – 2018}"));
+        assert!(tex.contains("R\\&D <Labs>"));
+        assert!(tex.contains("\\item Languages: Rust, C\\#"));
+        assert!(tex.contains("\\cvrow{Honours and awards}"));
+        assert!(!tex.contains("\\cvsection{Education and training}\n\\cvsection"));
+        assert!(tex.trim_end().ends_with("\\end{document}"));
+    }
+
+    #[test]
+    fn test_split_name_and_dates() {
+        assert_eq!(split_name("Ada"), ("Ada", ""));
+        assert_eq!(split_name(" Ada  Lovelace "), ("Ada", "Lovelace"));
+        assert_eq!(date_attrs("garbage"), None);
+        assert_eq!(date_range(&None, &Some("2020-01".into())), "01/2020");
+    }
+}
@@ -0,0 +1,207 @@
+//! Axum handlers for importing and exporting resume documents.
+
+use axum::{
+    body::Body,
+    extract::{Path, Query, State},
+    http::{header, Response, StatusCode},
+    Json,
+};
+use sqlx::PgPool;
+use uuid::Uuid;
+
+use crate::audit::{self, Action, AuditEvent};
+use crate::auth::{AuthUser, Resource};
+use crate::context::ingest::{confirm_ingest, IngestConfirmRequest};
+use crate::context::versioning::get_current_entries;
+use crate::errors::AppError;
+use crate::interop::anonymize::anonymize_resume;
+use crate::interop::europass;
+use crate::interop::json_resume::{self, JsonResume};
+use crate::interop::{
+    AnonymizedResume, ExportFormat, ImportedEntry, JsonResumeImportResponse, ResumeExportQuery,
+};
+use crate::models::resume::{ResumeBulletRow, ResumeRow};
+use crate::models::user::User;
+use crate::quota;
+use crate::render::pdflatex::compile_latex;
+use crate::state::AppState;
+use crate::templates::ProfileData;
+
+/// POST /api/v1/context/import/json-resume
+///
+/// Stores each mappable item of a JSON Resume document as a context entry, through the
+/// same path as a confirmed ingest (scoring, quality flags, a new context version per
+/// entry). Items identical to a current entry are reported as unchanged, so importing
+/// the same document twice stores nothing the second time. No LLM call is made.
+///
+/// Returns 400 if the document has no section that maps onto context.
+pub async fn handle_import_json_resume(
+    State(state): State<AppState>,
+    auth: AuthUser,
+    Json(doc): Json<JsonResume>,
+) -> Result<Json<JsonResumeImportResponse>, AppError> {
+    let plan = json_resume::to_entries(&doc);
+    if plan.entries.is_empty() && plan.skipped.is_empty() {
+        return Err(AppError::Validation(
+            "The document has no work, education, projects, skills or other importable section"
+                .into(),
+        ));
+    }
+
+    let existing = get_current_entries(&state.db, auth.user_id)
+        .await
+        .map_err(AppError::Internal)?;
+    let mut response = JsonResumeImportResponse {
+        imported: vec![],
+        unchanged: vec![],
+        skipped: plan.skipped,
+    };
+    for (source, entry) in plan.entries {
+        let (entry_type, data) = json_resume::entry_columns(&entry);
+        if existing
+            .iter()
+            .any(|e| e.entry_type == entry_type && e.data == data)
+        {
+            response.unchanged.push(source);
+            continue;
+        }
+        let request = IngestConfirmRequest {
+            entry: serde_json::json!({ "entry_type": entry_type, "data": data }),
+            user_id: auth.user_id,
+            acknowledged_gaps: vec![],
+        };
+        let confirmed = confirm_ingest(&state.db, &*state.blobs, &request).await?;
+        audit::record(
+            &state.db,
+            auth.user_id,
+            AuditEvent::new(Action::ContextIngest, confirmed.entry_id).after(serde_json::json!({
+                "version": confirmed.version,
+                "source": "json_resume",
+            })),
+        )
+        .await;
+        response.imported.push(ImportedEntry {
+            entry_id: confirmed.entry_id,
+            entry_type,
+            source,
+        });
+    }
+
+    tracing::info!(
+        user_id = %auth.user_id,
+        imported = response.imported.len(),
+        unchanged = response.unchanged.len(),
+        skipped = response.skipped.len(),
+        "JSON Resume import complete"
+    );
+    Ok(Json(response))
+}
+
+/// GET /api/v1/resumes/:id/export?format=json_resume|europass_xml|europass_pdf
+///
+/// The resume as a downloadable document in `format`. Entry details (employer, dates,
+/// degree) and contact details come from the owner's current context; the text is the
+/// resume's own bullets. `europass_pdf` compiles with pdflatex during the request, like
+/// template previews.
+pub async fn handle_export_resume(
+    State(state): State<AppState>,
+    auth: AuthUser,
+    Path(resume_id): Path<Uuid>,
+    Query(query): Query<ResumeExportQuery>,
+) -> Result<Response<Body>, AppError> {
+    auth.authorize_view(&state.db, Resource::Resume, resume_id)
+        .await?;
+    let (resume, doc) = load_resume_document(&state.db, resume_id).await?;
+
+    let (content_type, extension, bytes) = match query.format {
+        ExportFormat::JsonResume => {
+            let bytes = serde_json::to_vec_pretty(&doc)
+                .map_err(|e| AppError::Internal(anyhow::anyhow!("JSON Resume encoding: {e}")))?;
+            ("application/json", "json", bytes)
+        }
+        ExportFormat::EuropassXml => (
+            "application/xml",
+            "xml",
+            europass::to_xml(&doc, resume.updated_at).into_bytes(),
+        ),
+        ExportFormat::EuropassPdf => {
+            let pdf = compile_latex(&europass::to_latex(&doc), resume_id)
+                .await
+                .map_err(|e| AppError::Internal(anyhow::anyhow!("Europass PDF: {e}")))?;
+            ("application/pdf", "pdf", pdf.pdf_bytes)
+        }
+    };
+
+    Response::builder()
+        .status(StatusCode::OK)
+        .header(header::CONTENT_TYPE, content_type)
+        .header(
+            header::CONTENT_DISPOSITION,
+            format!("attachment; filename=\"resume-{resume_id}.{extension}\""),
+        )
+        .body(Body::from(bytes))
+        .map_err(|e| AppError::Internal(anyhow::anyhow!("Failed to build export response: {e}")))
+}
+
+/// POST /api/v1/resumes/:id/anonymize
+///
+/// An anonymized copy of the resume as a JSON Resume document, safe to share publicly
+/// or attach to a bug report: employers, schools, projects and the author's name are
+/// placeholders, contact details and links are gone, and an LLM check masks proper
+/// nouns left in the bullets. The response never contains the originals. Nothing is
+/// stored.
+pub async fn handle_anonymize_resume(
+    State(state): State<AppState>,
+    auth: AuthUser,
+    Path(resume_id): Path<Uuid>,
+) -> Result<Json<AnonymizedResume>, AppError> {
+    auth.authorize(&state.db, Resource::Resume, resume_id)
+        .await?;
+    let response = quota::metered_llm(
+        &state.db,
+        &state.redis,
+        auth.user_id,
+        anonymize_resume(&state.db, &state.llm, resume_id),
+    )
+    .await?;
+    Ok(Json(response))
+}
+
+/// A generated resume as a JSON Resume document: its bullets grouped under the entries
+/// they came from, with the owner's profile as `basics`. 404 for a deleted resume.
+pub(crate) async fn load_resume_document(
+    db: &PgPool,
+    resume_id: Uuid,
+) -> Result<(ResumeRow, JsonResume), AppError> {
+    let resume = sqlx::query_as!(
+        ResumeRow,
+        "SELECT * FROM resumes WHERE id = $1 AND deleted_at IS NULL",
+        resume_id
+    )
+    .fetch_optional(db)
+    .await?
+    .ok_or_else(|| AppError::NotFound(format!("Resume {resume_id} not found")))?;
+    let bullets = sqlx::query_as!(
+        ResumeBulletRow,
+        "SELECT * FROM resume_bullets WHERE resume_id = $1 ORDER BY position, id",
+        resume_id
+    )
+    .fetch_all(db)
+    .await?;
+    let owner = sqlx::query_as!(User, "SELECT * FROM users WHERE id = $1", resume.user_id)
+        .fetch_optional(db)
+        .await?
+        .ok_or_else(|| AppError::NotFound(format!("Resume {resume_id} not found")))?;
+    let entries = get_current_entries(db, resume.user_id)
+        .await
+        .map_err(AppError::Internal)?;
+
+    let profile = entries
+        .iter()
+        .find(|e| e.entry_type == "profile")
+        .map(|e| ProfileData::from_entry_data(&e.data))
+        .unwrap_or_default();
+    let basics = json_resume::basics(&profile, owner.display_name, owner.email);
+    let doc = json_resume::from_resume(basics, resume.updated_at, &bullets, &entries);
+    Ok((resume, doc))
+}
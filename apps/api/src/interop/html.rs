@@ -0,0 +1,300 @@
+//! A generated resume as a standalone, read-only HTML page — what a public share link
+//! (`crate::shares`) serves.
+//!
+//! Rendered from the same `JsonResume` document as the other exports, so the page
+//! lists the same items as a JSON Resume or Europass download. The page is one file:
+//! styles are inline, and it loads no scripts, fonts or images.
+
+use chrono::NaiveDate;
+
+use crate::interop::json_resume::JsonResume;
+
+const STYLE: &str = "body{font-family:Georgia,'Times New Roman',serif;max-width:46rem;\
+margin:2rem auto;padding:0 1rem;color:#222;line-height:1.45}\
+h1{margin:0;font-size:1.8rem}h2{font-size:1.05rem;text-transform:uppercase;\
+letter-spacing:.06em;border-bottom:1px solid #999;margin-top:1.6rem}\
+.contact,.meta{color:#555;font-size:.9rem}.item{margin:.8rem 0}\
+.item-head{display:flex;justify-content:space-between;gap:1rem}\
+ul{margin:.3rem 0;padding-left:1.2rem}";
+
+/// The document as an HTML page titled `title`.
+pub fn to_html(doc: &JsonResume, title: &str) -> String {
+    let mut html = String::with_capacity(8192);
+    html.push_str(&format!(
+        "<!DOCTYPE html>\n<html lang=\"en\">\n<head>\n<meta charset=\"utf-8\">\n\
+         <meta name=\"viewport\" content=\"width=device-width, initial-scale=1\">\n\
+         <meta name=\"robots\" content=\"noindex, nofollow\">\n\
+         <title>{}</title>\n<style>{STYLE}</style>\n</head>\n<body>\n",
+        escape_html(title)
+    ));
+
+    if let Some(basics) = &doc.basics {
+        html.push_str("<header>\n");
+        if let Some(name) = &basics.name {
+            html.push_str(&format!("<h1>{}</h1>\n", escape_html(name)));
+        }
+        if let Some(label) = &basics.label {
+            html.push_str(&format!(
+                "<div class=\"meta\">{}</div>\n",
+                escape_html(label)
+            ));
+        }
+        let contact: Vec<String> = [
+            basics.location.as_ref().and_then(|l| l.city.clone()),
+            basics.email.clone(),
+            basics.phone.clone(),
+            basics.url.clone(),
+        ]
+        .into_iter()
+        .flatten()
+        .chain(basics.profiles.iter().filter_map(|p| p.url.clone()))
+        .map(|c| escape_html(&c))
+        .collect();
+        if !contact.is_empty() {
+            html.push_str(&format!(
+                "<div class=\"contact\">{}</div>\n",
+                contact.join(" · ")
+            ));
+        }
+        html.push_str("</header>\n");
+    }
+
+    if !doc.work.is_empty() {
+        html.push_str("<h2>Experience</h2>\n");
+        for work in &doc.work {
+            item(
+                &mut html,
+                &joined(&[work.position.as_deref(), work.name.as_deref()], ", "),
+                &date_range(&work.start_date, &work.end_date),
+                &work.highlights,
+            );
+        }
+    }
+
+    if !doc.projects.is_empty() {
+        html.push_str("<h2>Projects</h2>\n");
+        for project in &doc.projects {
+            let details = if project.highlights.is_empty() {
+                project.description.iter().cloned().collect()
+            } else {
+                project.highlights.clone()
+            };
+            item(
+                &mut html,
+                project.name.as_deref().unwrap_or_default(),
+                &date_range(&project.start_date, &project.end_date),
+                &details,
+            );
+        }
+    }
+
+    if !doc.volunteer.is_empty() {
+        html.push_str("<h2>Volunteering</h2>\n");
+        for volunteer in &doc.volunteer {
+            item(
+                &mut html,
+                &joined(
+                    &[
+                        volunteer.position.as_deref(),
+                        volunteer.organization.as_deref(),
+                    ],
+                    ", ",
+                ),
+                &date_range(&volunteer.start_date, &volunteer.end_date),
+                &volunteer.highlights,
+            );
+        }
+    }
+
+    if !doc.education.is_empty() {
+        html.push_str("<h2>Education</h2>\n");
+        for education in &doc.education {
+            let degree = joined(
+                &[education.study_type.as_deref(), education.area.as_deref()],
+                " in ",
+            );
+            item(
+                &mut html,
+                &joined(
+                    &[Some(degree.as_str()), education.institution.as_deref()],
+                    ", ",
+                ),
+                &date_range(&education.start_date, &education.end_date),
+                &education.courses,
+            );
+        }
+    }
+
+    if !doc.skills.is_empty() {
+        html.push_str("<h2>Skills</h2>\n<ul>\n");
+        for skill in &doc.skills {
+            let keywords = escape_html(&skill.keywords.join(", "));
+            match &skill.name {
+                Some(name) => html.push_str(&format!(
+                    "<li><strong>{}:</strong> {keywords}</li>\n",
+                    escape_html(name)
+                )),
+                None => html.push_str(&format!("<li>{keywords}</li>\n")),
+            }
+        }
+        html.push_str("</ul>\n");
+    }
+
+    let other: Vec<String> = doc
+        .awards
+        .iter()
+        .map(|a| {
+            joined(
+                &[a.title.as_deref(), a.awarder.as_deref(), a.date.as_deref()],
+                ", ",
+            )
+        })
+        .chain(doc.certificates.iter().map(|c| {
+            joined(
+                &[c.name.as_deref(), c.issuer.as_deref(), c.date.as_deref()],
+                ", ",
+            )
+        }))
+        .chain(doc.publications.iter().map(|p| {
+            joined(
+                &[
+                    p.name.as_deref(),
+                    p.publisher.as_deref(),
+                    p.release_date.as_deref(),
+                ],
+                ", ",
+            )
+        }))
+        .filter(|line| !line.is_empty())
+        .collect();
+    if !other.is_empty() {
+        html.push_str("<h2>Awards, certifications and publications</h2>\n");
+        html.push_str(&list(&other));
+    }
+
+    html.push_str("</body>\n</html>\n");
+    html
+}
+
+/// One dated item: a heading line with the dates on the right, then its details.
+fn item(html: &mut String, title: &str, dates: &str, details: &[String]) {
+    html.push_str(&format!(
+        "<div class=\"item\">\n<div class=\"item-head\"><strong>{}</strong>\
+         <span class=\"meta\">{}</span></div>\n",
+        escape_html(title),
+        escape_html(dates)
+    ));
+    if !details.is_empty() {
+        html.push_str(&list(details));
+    }
+    html.push_str("</div>\n");
+}
+
+fn list(lines: &[String]) -> String {
+    let items: String = lines
+        .iter()
+        .map(|line| format!("<li>{}</li>\n", escape_html(line)))
+        .collect();
+    format!("<ul>\n{items}</ul>\n")
+}
+
+/// The parts that are present and non-empty, joined by `separator`.
+fn joined(parts: &[Option<&str>], separator: &str) -> String {
+    parts
+        .iter()
+        .flatten()
+        .map(|p| p.trim())
+        .filter(|p| !p.is_empty())
+        .collect::<Vec<_>>()
+        .join(separator)
+}
+
+/// `Jun 2019 – Present`, at the precision the dates were stored at.
+fn date_range(start: &Option<String>, end: &Option<String>) -> String {
+    match (start.as_deref(), end.as_deref()) {
+        (None, None) => String::new(),
+        (Some(start), None) => format!("{} – Present", display_date(start)),
+        (None, Some(end)) => display_date(end),
+        (Some(start), Some(end)) => format!("{} – {}", display_date(start), display_date(end)),
+    }
+}
+
+/// `2019-06-01` and `2019-06` → `Jun 2019`; anything else as given.
+fn display_date(date: &str) -> String {
+    NaiveDate::parse_from_str(date, "%Y-%m-%d")
+        .or_else(|_| NaiveDate::parse_from_str(&format!("{date}-01"), "%Y-%m-%d"))
+        .map(|d| d.format("%b %Y").to_string())
+        .unwrap_or_else(|_| date.to_string())
+}
+
+fn escape_html(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&#39;"),
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+// ────────────────────────────────────────────────────────────────────────────
+// Tests
+// ────────────────────────────────────────────────────────────────────────────
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::interop::json_resume::{JrBasics, JrEducation, JrWork};
+
+    fn document() -> JsonResume {
+        JsonResume {
+            basics: Some(JrBasics {
+                name: Some("Ada <Lovelace>".to_string()),
+                email: Some("ada@example.com".to_string()),
+                ..Default::default()
+            }),
+            work: vec![JrWork {
+                name: Some("R&D Labs".to_string()),
+                position: Some("Staff Engineer".to_string()),
+                start_date: Some("2019-06-01".to_string()),
+                highlights: vec!["Cut p99 latency by 40% & costs".to_string()],
+                ..Default::default()
+            }],
+            education: vec![JrEducation {
+                institution: Some("State College".to_string()),
+                study_type: Some("B.S.".to_string()),
+                area: Some("Computer Science".to_string()),
+                end_date: Some("2014".to_string()),
+                ..Default::default()
+            }],
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_page_lists_items_and_escapes_text() {
+        let html = to_html(&document(), "Resume — Ada");
+
+        assert!(html.contains("<title>Resume — Ada</title>"));
+        assert!(html.contains("<h1>Ada &lt;Lovelace&gt;</h1>"));
+        assert!(html.contains("<strong>Staff Engineer, R&amp;D Labs</strong>"));
+        assert!(html.contains("<span class=\"meta\">Jun 2019 – Present</span>"));
+        assert!(html.contains("<li>Cut p99 latency by 40% &amp; costs</li>"));
+        assert!(html.contains("<strong>B.S. in Computer Science, State College</strong>"));
+        assert!(html.contains("<meta name=\"robots\" content=\"noindex, nofollow\">"));
+        assert!(!html.contains("<h2>Skills</h2>"));
+    }
+
+    #[test]
+    fn test_page_loads_nothing_external() {
+        let html = to_html(&document(), "Resume");
+        assert!(!html.contains("<script"));
+        assert!(!html.contains("<link"));
+        assert!(!html.contains("<img"));
+    }
+}
@@ -0,0 +1,699 @@
+//! JSON Resume (<https://jsonresume.org/schema>) ↔ context entries and resumes.
+//!
+//! Import maps each schema section onto a `ContextEntryData` variant:
+//!
+//! | JSON Resume    | entry type        |
+//! |----------------|-------------------|
+//! | `work`         | `experience`      |
+//! | `volunteer`    | `extracurricular` |
+//! | `education`    | `education`       |
+//! | `projects`     | `project`         |
+//! | `skills`       | `skill`           |
+//! | `languages`    | one `skill` entry, category "Languages" |
+//! | `publications` | `publication`     |
+//! | `awards`       | `award`           |
+//! | `certificates` | `certification`   |
+//!
+//! Items missing a field the entry type requires (a company, a start date) are skipped
+//! with a reason rather than stored half-empty. Partial ISO dates (`2021`, `2021-06`)
+//! read as the first day of the period. `highlights` become bullets; a `summary` does
+//! when there are none.
+//!
+//! Export goes the other way for one generated resume: its bullets, grouped by source
+//! entry, become the `highlights` of the item built from that entry.
+
+use std::collections::HashMap;
+
+use chrono::{DateTime, NaiveDate, Utc};
+use serde_json::Value;
+use uuid::Uuid;
+
+use crate::context::models::{
+    AwardEntry, CertificationEntry, ContextEntryData, ContributionType, EducationEntry,
+    ExperienceBullet, ExperienceEntry, ExtracurricularEntry, ProjectEntry, PublicationEntry,
+    SkillEntry,
+};
+use crate::models::context::ContextEntryRow;
+use crate::models::resume::ResumeBulletRow;
+use crate::templates::ProfileData;
+
+pub use templar_types::interop::{
+    JrAward, JrBasics, JrCertificate, JrEducation, JrLocation, JrMeta, JrProfile, JrProject,
+    JrPublication, JrSkill, JrVolunteer, JrWork, JsonResume,
+};
+
+/// `$schema` of exported documents.
+pub const SCHEMA_URL: &str =
+    "https://raw.githubusercontent.com/jsonresume/resume-schema/v1.0.0/schema.json";
+/// `meta.version` of exported documents.
+const SCHEMA_VERSION: &str = "v1.0.0";
+
+// ────────────────────────────────────────────────────────────────────────────
+// Import
+// ────────────────────────────────────────────────────────────────────────────
+
+/// The document as context entries, each with its location in the document, plus the
+/// items that could not be mapped.
+pub struct ImportPlan {
+    pub entries: Vec<(String, ContextEntryData)>,
+    pub skipped: Vec<String>,
+}
+
+pub fn to_entries(doc: &JsonResume) -> ImportPlan {
+    let mut plan = ImportPlan {
+        entries: vec![],
+        skipped: vec![],
+    };
+    let mut add = |source: String, entry: Result<ContextEntryData, &str>| match entry {
+        Ok(entry) => plan.entries.push((source, entry)),
+        Err(reason) => plan.skipped.push(format!("{source}: {reason}")),
+    };
+
+    for (i, work) in doc.work.iter().enumerate() {
+        add(format!("work[{i}]"), experience(work));
+    }
+    for (i, volunteer) in doc.volunteer.iter().enumerate() {
+        add(format!("volunteer[{i}]"), extracurricular(volunteer));
+    }
+    for (i, education) in doc.education.iter().enumerate() {
+        add(format!("education[{i}]"), education_entry(education));
+    }
+    for (i, project) in doc.projects.iter().enumerate() {
+        add(format!("projects[{i}]"), project_entry(project));
+    }
+    for (i, skill) in doc.skills.iter().enumerate() {
+        add(format!("skills[{i}]"), skill_entry(skill));
+    }
+    let languages: Vec<String> = doc
+        .languages
+        .iter()
+        .filter_map(|l| {
+            let language = present(&l.language)?;
+            Some(match present(&l.fluency) {
+                Some(fluency) => format!("{language} ({fluency})"),
+                None => language,
+            })
+        })
+        .collect();
+    if !languages.is_empty() {
+        add(
+            "languages".to_string(),
+            Ok(ContextEntryData::Skill(SkillEntry {
+                category: "Languages".to_string(),
+                items: languages,
+                proficiency: None,
+            })),
+        );
+    }
+    for (i, publication) in doc.publications.iter().enumerate() {
+        add(format!("publications[{i}]"), publication_entry(publication));
+    }
+    for (i, award) in doc.awards.iter().enumerate() {
+        add(format!("awards[{i}]"), award_entry(award));
+    }
+    for (i, certificate) in doc.certificates.iter().enumerate() {
+        add(format!("certificates[{i}]"), certification(certificate));
+    }
+    plan
+}
+
+/// An entry as the `entry_type` and `data` columns store it.
+pub fn entry_columns(entry: &ContextEntryData) -> (String, Value) {
+    let mut data = serde_json::to_value(entry).unwrap_or_default();
+    if let Some(fields) = data.as_object_mut() {
+        fields.remove("entry_type");
+    }
+    (entry.entry_type_str().to_string(), data)
+}
+
+fn experience(work: &JrWork) -> Result<ContextEntryData, &'static str> {
+    Ok(ContextEntryData::Experience(ExperienceEntry {
+        company: present(&work.name).ok_or("no name (company)")?,
+        role: present(&work.position).ok_or("no position")?,
+        date_start: start_date(&work.start_date)?,
+        date_end: optional_date(&work.end_date)?,
+        team_size: None,
+        tech_stack: vec![],
+        contribution_type: ContributionType::TeamMember,
+        location: present(&work.location),
+        bullets: bullets(&work.highlights, &work.summary),
+    }))
+}
+
+fn extracurricular(volunteer: &JrVolunteer) -> Result<ContextEntryData, &'static str> {
+    Ok(ContextEntryData::Extracurricular(ExtracurricularEntry {
+        organization: present(&volunteer.organization).ok_or("no organization")?,
+        role: present(&volunteer.position).unwrap_or_else(|| "Volunteer".to_string()),
+        date_start: start_date(&volunteer.start_date)?,
+        date_end: optional_date(&volunteer.end_date)?,
+        bullets: bullets(&volunteer.highlights, &volunteer.summary),
+    }))
+}
+
+fn education_entry(education: &JrEducation) -> Result<ContextEntryData, &'static str> {
+    Ok(ContextEntryData::Education(EducationEntry {
+        institution: present(&education.institution).ok_or("no institution")?,
+        degree: present(&education.study_type).unwrap_or_default(),
+        field: present(&education.area).unwrap_or_default(),
+        date_start: start_date(&education.start_date)?,
+        date_end: optional_date(&education.end_date)?,
+        // Only numeric scores fit `gpa`; "First" and the like are dropped.
+        gpa: education
+            .score
+            .as_deref()
+            .and_then(|s| s.trim().parse::<f64>().ok()),
+        honors: vec![],
+        relevant_courses: education.courses.clone(),
+    }))
+}
+
+fn project_entry(project: &JrProject) -> Result<ContextEntryData, &'static str> {
+    Ok(ContextEntryData::Project(ProjectEntry {
+        name: present(&project.name).ok_or("no name")?,
+        description: present(&project.description).unwrap_or_default(),
+        tech_stack: project.keywords.clone(),
+        date_start: optional_date(&project.start_date)?,
+        date_end: optional_date(&project.end_date)?,
+        url: present(&project.url),
+        contribution_type: ContributionType::TeamMember,
+        bullets: bullets(&project.highlights, &None),
+    }))
+}
+
+fn skill_entry(skill: &JrSkill) -> Result<ContextEntryData, &'static str> {
+    let name = present(&skill.name);
+    let (category, items) = match (name, skill.keywords.is_empty()) {
+        (Some(name), false) => (name, skill.keywords.clone()),
+        // A bare skill ("Rust") rather than a group of them.
+        (Some(name), true) => ("Skills".to_string(), vec![name]),
+        (None, false) => ("Skills".to_string(), skill.keywords.clone()),
+        (None, true) => return Err("no name or keywords"),
+    };
+    Ok(ContextEntryData::Skill(SkillEntry {
+        category,
+        items,
+        proficiency: present(&skill.level),
+    }))
+}
+
+fn publication_entry(publication: &JrPublication) -> Result<ContextEntryData, &'static str> {
+    Ok(ContextEntryData::Publication(PublicationEntry {
+        title: present(&publication.name).ok_or("no name")?,
+        venue: present(&publication.publisher).unwrap_or_default(),
+        date: required_date(&publication.release_date, "no releaseDate")?,
+        authors: vec![],
+        url: present(&publication.url),
+        contribution_type: ContributionType::PrimaryContributor,
+    }))
+}
+
+fn award_entry(award: &JrAward) -> Result<ContextEntryData, &'static str> {
+    Ok(ContextEntryData::Award(AwardEntry {
+        title: present(&award.title).ok_or("no title")?,
+        issuer: present(&award.awarder).unwrap_or_default(),
+        date: required_date(&award.date, "no date")?,
+        description: present(&award.summary),
+    }))
+}
+
+fn certification(certificate: &JrCertificate) -> Result<ContextEntryData, &'static str> {
+    Ok(ContextEntryData::Certification(CertificationEntry {
+        name: present(&certificate.name).ok_or("no name")?,
+        issuer: present(&certificate.issuer).unwrap_or_default(),
+        date_issued: required_date(&certificate.date, "no date")?,
+        date_expires: None,
+        credential_id: None,
+    }))
+}
+
+fn bullets(highlights: &[String], summary: &Option<String>) -> Vec<ExperienceBullet> {
+    let texts: Vec<String> = if highlights.is_empty() {
+        present(summary).into_iter().collect()
+    } else {
+        highlights
+            .iter()
+            .map(|h| h.trim().to_string())
+            .filter(|h| !h.is_empty())
+            .collect()
+    };
+    texts
+        .into_iter()
+        .map(|text| ExperienceBullet {
+            text,
+            impact_markers: vec![],
+            confidence_marker: None,
+        })
+        .collect()
+}
+
+/// The trimmed value, if there is one.
+fn present(value: &Option<String>) -> Option<String> {
+    value
+        .as_deref()
+        .map(str::trim)
+        .filter(|v| !v.is_empty())
+        .map(str::to_string)
+}
+
+fn start_date(value: &Option<String>) -> Result<NaiveDate, &'static str> {
+    required_date(value, "no startDate")
+}
+
+fn required_date(value: &Option<String>, missing: &'static str) -> Result<NaiveDate, &'static str> {
+    optional_date(value)?.ok_or(missing)
+}
+
+/// An optional date; present but unreadable is an error, not `None`, so a typo does not
+/// turn a past position into a current one.
+fn optional_date(value: &Option<String>) -> Result<Option<NaiveDate>, &'static str> {
+    match present(value) {
+        None => Ok(None),
+        Some(date) => parse_partial_date(&date).map(Some).ok_or("unreadable date"),
+    }
+}
+
+/// `2021-06-15`, `2021-06` or `2021`.
+fn parse_partial_date(value: &str) -> Option<NaiveDate> {
+    let mut parts = value.splitn(3, '-');
+    let year = parts.next()?.parse().ok()?;
+    let month = parts.next().map_or(Some(1), |m| m.parse().ok())?;
+    let day = parts.next().map_or(Some(1), |d| d.parse().ok())?;
+    NaiveDate::from_ymd_opt(year, month, day)
+}
+
+// ────────────────────────────────────────────────────────────────────────────
+// Export
+// ────────────────────────────────────────────────────────────────────────────
+
+/// `basics` from the owner's `profile` entry — the contact details printed in the
+/// resume header — with the account's name and email filling any gaps.
+pub fn basics(
+    profile: &ProfileData,
+    account_name: Option<String>,
+    account_email: String,
+) -> JrBasics {
+    let field = |value: &str| Some(value.trim().to_string()).filter(|v| !v.is_empty());
+    let linkedin = field(&profile.linkedin).map(|handle| {
+        let url = if handle.starts_with("http") {
+            handle.clone()
+        } else {
+            format!(
+                "https://www.linkedin.com/{}",
+                handle.trim_start_matches('/')
+            )
+        };
+        JrProfile {
+            network: Some("LinkedIn".to_string()),
+            username: handle
+                .trim_end_matches('/')
+                .rsplit('/')
+                .next()
+                .map(str::to_string),
+            url: Some(url),
+        }
+    });
+    JrBasics {
+        name: field(&profile.full_name).or(account_name),
+        email: field(&profile.email).or(Some(account_email)),
+        phone: field(&profile.phone),
+        url: field(&profile.website),
+        location: field(&profile.location).map(|address| JrLocation {
+            address: Some(address),
+            ..JrLocation::default()
+        }),
+        profiles: linkedin.into_iter().collect(),
+        ..JrBasics::default()
+    }
+}
+
+/// A generated resume as a JSON Resume document.
+///
+/// `entries` are the owner's current context entries; bullets whose source entry is
+/// gone are placed by their section alone.
+pub fn from_resume(
+    basics: JrBasics,
+    updated_at: DateTime<Utc>,
+    bullets: &[ResumeBulletRow],
+    entries: &[ContextEntryRow],
+) -> JsonResume {
+    let entries_by_id: HashMap<Uuid, &ContextEntryRow> =
+        entries.iter().map(|e| (e.entry_id, e)).collect();
+
+    // Bullets per source entry, in the order the entries first appear.
+    let mut groups: Vec<(Uuid, &str, Vec<String>)> = vec![];
+    for bullet in bullets {
+        match groups.iter_mut().find(|g| g.0 == bullet.source_entry_id) {
+            Some(group) => group.2.push(bullet.bullet_text.clone()),
+            None => groups.push((
+                bullet.source_entry_id,
+                &bullet.section,
+                vec![bullet.bullet_text.clone()],
+            )),
+        }
+    }
+
+    let mut doc = JsonResume {
+        schema: Some(SCHEMA_URL.to_string()),
+        basics: Some(basics),
+        meta: Some(JrMeta {
+            canonical: None,
+            version: Some(SCHEMA_VERSION.to_string()),
+            last_modified: Some(updated_at.to_rfc3339()),
+        }),
+        ..JsonResume::default()
+    };
+    for (entry_id, section, highlights) in groups {
+        let Some(entry) = entries_by_id.get(&entry_id) else {
+            add_unsourced(&mut doc, section, highlights);
+            continue;
+        };
+        let data = &entry.data;
+        match entry.entry_type.as_str() {
+            // The summary paragraph (see `generation::static_sections`)
+            "profile" => {
+                if let Some(basics) = doc.basics.as_mut() {
+                    basics.summary = Some(highlights.join(" "));
+                }
+            }
+            "experience" => doc.work.push(JrWork {
+                name: text(data, "company"),
+                position: text(data, "role"),
+                location: text(data, "location"),
+                start_date: text(data, "date_start"),
+                end_date: text(data, "date_end"),
+                highlights,
+                ..JrWork::default()
+            }),
+            "project" | "open_source" => doc.projects.push(JrProject {
+                name: text(data, "name").or_else(|| text(data, "project_name")),
+                description: text(data, "description"),
+                url: text(data, "url"),
+                start_date: text(data, "date_start"),
+                end_date: text(data, "date_end"),
+                highlights,
+                keywords: texts(data, "tech_stack"),
+            }),
+            "education" => doc.education.push(JrEducation {
+                institution: text(data, "institution"),
+                area: text(data, "field"),
+                study_type: text(data, "degree"),
+                start_date: text(data, "date_start"),
+                end_date: text(data, "date_end"),
+                score: data
+                    .get("gpa")
+                    .and_then(Value::as_f64)
+                    .map(|g| g.to_string()),
+                courses: texts(data, "relevant_courses"),
+                ..JrEducation::default()
+            }),
+            "skill" => {
+                let category = text(data, "category");
+                doc.skills
+                    .extend(highlights.iter().map(|line| skill_line(line, &category)));
+            }
+            "publication" => doc.publications.push(JrPublication {
+                name: text(data, "title"),
+                publisher: text(data, "venue"),
+                release_date: text(data, "date"),
+                url: text(data, "url"),
+                summary: Some(highlights.join(" ")),
+            }),
+            "award" => doc.awards.push(JrAward {
+                title: text(data, "title"),
+                date: text(data, "date"),
+                awarder: text(data, "issuer"),
+                summary: Some(highlights.join(" ")),
+            }),
+            "certification" => doc.certificates.push(JrCertificate {
+                name: text(data, "name"),
+                date: text(data, "date_issued"),
+                issuer: text(data, "issuer"),
+                url: None,
+            }),
+            "extracurricular" => doc.volunteer.push(JrVolunteer {
+                organization: text(data, "organization"),
+                position: text(data, "role"),
+                start_date: text(data, "date_start"),
+                end_date: text(data, "date_end"),
+                highlights,
+                ..JrVolunteer::default()
+            }),
+            _ => add_unsourced(&mut doc, section, highlights),
+        }
+    }
+    doc
+}
+
+/// Bullets without a usable source entry, placed by resume section.
+fn add_unsourced(doc: &mut JsonResume, section: &str, highlights: Vec<String>) {
+    match section {
+        "skills" => doc
+            .skills
+            .extend(highlights.iter().map(|line| skill_line(line, &None))),
+        "projects" => doc.projects.push(JrProject {
+            highlights,
+            ..JrProject::default()
+        }),
+        "activities" => doc.volunteer.push(JrVolunteer {
+            highlights,
+            ..JrVolunteer::default()
+        }),
+        _ => doc.work.push(JrWork {
+            highlights,
+            ..JrWork::default()
+        }),
+    }
+}
+
+/// A skills line as printed (`Languages: Rust, Go`) as a skill group.
+fn skill_line(line: &str, category: &Option<String>) -> JrSkill {
+    let (name, list) = match line.split_once(':') {
+        Some((label, list)) => (Some(label.trim().to_string()), list),
+        None => (category.clone(), line),
+    };
+    JrSkill {
+        name,
+        level: None,
+        keywords: list
+            .split(',')
+            .map(str::trim)
+            .filter(|k| !k.is_empty())
+            .map(str::to_string)
+            .collect(),
+    }
+}
+
+fn text(data: &Value, key: &str) -> Option<String> {
+    data.get(key)
+        .and_then(Value::as_str)
+        .filter(|v| !v.trim().is_empty())
+        .map(str::to_string)
+}
+
+fn texts(data: &Value, key: &str) -> Vec<String> {
+    data.get(key)
+        .and_then(Value::as_array)
+        .map(|items| {
+            items
+                .iter()
+                .filter_map(Value::as_str)
+                .map(str::to_string)
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+// ────────────────────────────────────────────────────────────────────────────
+// Tests
+// ────────────────────────────────────────────────────────────────────────────
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn doc() -> JsonResume {
+        serde_json::from_value(json!({
+            "basics": { "name": "Ada Lovelace" },
+            "work": [
+                {
+                    "name": "Analytical Engines",
+                    "position": "Engineer",
+                    "startDate": "2019-03",
+                    "highlights": ["Cut p99 latency by 40%", " "]
+                },
+                { "name": "Acme", "position": "Intern" },
+                { "name": "Initech", "position": "SWE", "startDate": "2018", "endDate": "last year" }
+            ],
+            "education": [{
+                "institution": "MIT", "area": "CS", "studyType": "BSc",
+                "startDate": "2014-09-01", "endDate": "2018-06", "score": "3.9"
+            }],
+            "skills": [
+                { "name": "Languages", "keywords": ["Rust", "Go"] },
+                { "name": "Kubernetes" },
+                {}
+            ],
+            "languages": [{ "language": "French", "fluency": "Fluent" }, { "language": "English" }],
+            "awards": [{ "title": "Best Paper", "date": "2020", "awarder": "ICML" }]
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn test_to_entries_maps_sections_and_skips_incomplete_items() {
+        let plan = to_entries(&doc());
+        let sources: Vec<&str> = plan.entries.iter().map(|(s, _)| s.as_str()).collect();
+        assert_eq!(
+            sources,
+            [
+                "work[0]",
+                "education[0]",
+                "skills[0]",
+                "skills[1]",
+                "languages",
+                "awards[0]"
+            ]
+        );
+        assert_eq!(
+            plan.skipped,
+            [
+                "work[1]: no startDate",
+                "work[2]: unreadable date",
+                "skills[2]: no name or keywords"
+            ]
+        );
+
+        let ContextEntryData::Experience(work) = &plan.entries[0].1 else {
+            panic!("expected experience");
+        };
+        assert_eq!(
+            work.date_start,
+            NaiveDate::from_ymd_opt(2019, 3, 1).unwrap()
+        );
+        assert_eq!(work.date_end, None);
+        assert_eq!(work.bullets.len(), 1);
+
+        let ContextEntryData::Skill(bare) = &plan.entries[3].1 else {
+            panic!("expected skill");
+        };
+        assert_eq!(
+            (bare.category.as_str(), bare.items.as_slice()),
+            ("Skills", &["Kubernetes".to_string()][..])
+        );
+        let ContextEntryData::Skill(languages) = &plan.entries[4].1 else {
+            panic!("expected skill");
+        };
+        assert_eq!(languages.items, ["French (Fluent)", "English"]);
+    }
+
+    #[test]
+    fn test_entry_columns_split_type_from_data() {
+        let plan = to_entries(&doc());
+        let (entry_type, data) = entry_columns(&plan.entries[1].1);
+        assert_eq!(entry_type, "education");
+        assert!(data.get("entry_type").is_none());
+        assert_eq!(data["date_start"], "2014-09-01");
+        assert_eq!(data["date_end"], "2018-06-01");
+        assert_eq!(data["gpa"], 3.9);
+    }
+
+    fn row(entry_type: &str, data: Value) -> ContextEntryRow {
+        ContextEntryRow {
+            id: Uuid::new_v4(),
+            user_id: Uuid::nil(),
+            entry_id: Uuid::new_v4(),
+            version: 1,
+            entry_type: entry_type.to_string(),
+            data,
+            raw_text: None,
+            recency_score: 1.0,
+            impact_score: 0.5,
+            tags: vec![],
+            flagged_evergreen: false,
+            contribution_type: "team_member".to_string(),
+            quality_score: 1.0,
+            quality_flags: vec![],
+            created_at: Utc::now(),
+            recency_policy: None,
+        }
+    }
+
+    fn bullet(section: &str, source: Uuid, text: &str) -> ResumeBulletRow {
+        ResumeBulletRow {
+            id: Uuid::new_v4(),
+            resume_id: Uuid::nil(),
+            section: section.to_string(),
+            bullet_text: text.to_string(),
+            source_entry_id: source,
+            grounding_score: 1.0,
+            is_user_edited: false,
+            line_count: 1,
+            position: 0,
+            created_at: Utc::now(),
+            keyword_spans: serde_json::json!([]),
+        }
+    }
+
+    #[test]
+    fn test_basics_prefer_profile_and_fall_back_to_account() {
+        let profile = ProfileData {
+            full_name: "Ada Lovelace".into(),
+            phone: "+44 20 7946 0000".into(),
+            location: "London, UK".into(),
+            linkedin: "in/ada".into(),
+            ..ProfileData::default()
+        };
+        let basics = basics(&profile, Some("ada".into()), "ada@example.com".into());
+        assert_eq!(basics.name.as_deref(), Some("Ada Lovelace"));
+        assert_eq!(basics.email.as_deref(), Some("ada@example.com"));
+        assert_eq!(basics.url, None);
+        assert_eq!(
+            basics.location.and_then(|l| l.address).as_deref(),
+            Some("London, UK")
+        );
+        assert_eq!(
+            basics.profiles[0].url.as_deref(),
+            Some("https://www.linkedin.com/in/ada")
+        );
+        assert_eq!(basics.profiles[0].username.as_deref(), Some("ada"));
+    }
+
+    #[test]
+    fn test_from_resume_groups_bullets_under_their_entries() {
+        let job = row(
+            "experience",
+            json!({ "company": "Acme", "role": "SWE", "date_start": "2020-01-01", "date_end": null }),
+        );
+        let skills = row("skill", json!({ "category": "Tools", "items": ["Rust"] }));
+        let profile = row("profile", json!({ "summary": "Backend engineer." }));
+        let bullets = [
+            bullet("summary", profile.entry_id, "Backend engineer."),
+            bullet("experience", job.entry_id, "Shipped billing"),
+            bullet("skills", skills.entry_id, "Languages: Rust, Go"),
+            bullet("experience", job.entry_id, "Cut costs 30%"),
+            bullet("projects", Uuid::new_v4(), "Wrote a compiler"),
+        ];
+        let basics = JrBasics {
+            name: Some("Ada".into()),
+            ..JrBasics::default()
+        };
+        let doc = from_resume(basics, Utc::now(), &bullets, &[job, skills, profile]);
+
+        assert_eq!(doc.work.len(), 1);
+        assert_eq!(doc.work[0].name.as_deref(), Some("Acme"));
+        assert_eq!(doc.work[0].start_date.as_deref(), Some("2020-01-01"));
+        assert_eq!(doc.work[0].end_date, None);
+        assert_eq!(doc.work[0].highlights, ["Shipped billing", "Cut costs 30%"]);
+        assert_eq!(doc.skills[0].name.as_deref(), Some("Languages"));
+        assert_eq!(doc.skills[0].keywords, ["Rust", "Go"]);
+        assert_eq!(doc.projects[0].highlights, ["Wrote a compiler"]);
+        assert_eq!(doc.schema.as_deref(), Some(SCHEMA_URL));
+        let basics = doc.basics.as_ref().unwrap();
+        assert_eq!(basics.summary.as_deref(), Some("Backend engineer."));
+
+        // What is exported imports back.
+        let plan = to_entries(&doc);
+        assert!(matches!(plan.entries[0].1, ContextEntryData::Experience(_)));
+    }
+}
@@ -0,0 +1,24 @@
+//! Interop module — moving resume data in and out of Templar in standard formats.
+//!
+//! POST /api/v1/context/import/json-resume — store a JSON Resume document as context entries
+//! POST /api/v1/resumes/:id/anonymize — the resume as a JSON Resume document with names,
+//!   contacts and links masked, for sharing publicly (anonymize.rs)
+//! GET  /api/v1/resumes/:id/export?format= — a generated resume as a document:
+//!   - `json_resume`: JSON Resume v1.0.0
+//!   - `europass_xml`: Europass CV XML v3.4
+//!   - `europass_pdf`: a PDF in the Europass CV layout, compiled on request
+//!
+//! Format mappings live in their own modules (json_resume.rs, europass.rs, and html.rs
+//! for the page a share link serves); handlers in handlers.rs.
+
+pub mod anonymize;
+pub mod europass;
+pub mod handlers;
+pub mod html;
+pub mod json_resume;
+pub mod prompts;
+
+pub use templar_types::interop::{
+    AnonymizedResume, ExportFormat, ImportedEntry, JsonResumeImportResponse, PlaceholderKind,
+    Redaction, RedactionSource, ResumeExportQuery,
+};
@@ -0,0 +1,34 @@
+// LLM prompt constants for the Interop module.
+
+/// System prompt for the anonymization check — enforces JSON-only output.
+pub const ANONYMIZE_CHECK_SYSTEM: &str = "You are a privacy reviewer. \
+    You find the names left in text that identify a person or where they worked or studied. \
+    You MUST respond with valid JSON only. \
+    Do NOT include any text outside the JSON object. \
+    Do NOT use markdown code fences. \
+    Do NOT include explanations or apologies.";
+
+/// Anonymization check prompt template. Replace `{lines}` before sending.
+pub const ANONYMIZE_CHECK_PROMPT_TEMPLATE: &str = r#"The resume text below has already been anonymized: known names were replaced with placeholders in square brackets, like [Company 1] or [Person].
+
+List every proper noun still in the text that could identify the author, an employer, a client, a school, a colleague, a product only one company makes, or a specific place.
+
+Do NOT list:
+- placeholders in square brackets
+- technologies, languages, frameworks, cloud services or standards (Rust, Kubernetes, AWS, SOC 2)
+- job titles, degrees or generic terms
+
+Copy each name exactly as it appears in the text.
+
+Return a JSON object with this EXACT schema (no extra fields):
+{
+  "names": [
+    {"text": "Globex", "kind": "company"}
+  ]
+}
+
+"kind" is one of: person, company, institution, organization, project, place, other.
+Return {"names": []} if nothing identifying is left.
+
+TEXT:
+{lines}"#;
@@ -0,0 +1,307 @@
+//! Background job framework shared by the API's workers.
+//!
+//! A job is a serde type implementing `Job`; `enqueue` wraps it in an `Envelope` (id,
+//! attempt, payload) and LPUSHes it onto `jobs:{queue}`. Workers registered through
+//! `Workers` BRPOP envelopes and hand the decoded job to their `Handler`.
+//!
+//! Failure handling:
+//! - `JobError::Retry` schedules another attempt after an exponential backoff. The
+//!   envelope waits in the `jobs:{queue}:delayed` sorted set (scored by due time) and
+//!   workers move due entries back onto the queue before each poll.
+//! - `JobError::Fatal`, exhausting `Job::MAX_ATTEMPTS`, or an undecodable payload moves
+//!   the envelope to the `jobs:{queue}:dead` list with its last error, and calls
+//!   `Handler::on_dead` so the subsystem can record the failure on its own rows.
+//!
+//! Delivery is at-most-once per attempt: an envelope popped by a worker that then
+//! crashes is lost, as it was with the per-subsystem loops this replaces. Handlers
+//! keep their job rows authoritative, so a lost job shows up as stuck, not as done.
+//!
+//! Types, keys and enqueueing live here; the worker loop in worker.rs.
+
+pub mod worker;
+
+use std::time::Duration;
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use redis::AsyncCommands;
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use uuid::Uuid;
+
+pub use worker::Workers;
+
+/// Delay before the second attempt; doubles for each attempt after that.
+const BASE_BACKOFF: Duration = Duration::from_secs(5);
+
+/// Longest delay between attempts.
+const MAX_BACKOFF: Duration = Duration::from_secs(5 * 60);
+
+/// Most envelopes kept per dead-letter list; older ones are trimmed.
+const MAX_DEAD_LETTERS: isize = 1000;
+
+// ────────────────────────────────────────────────────────────────────────────
+// Jobs and handlers
+// ────────────────────────────────────────────────────────────────────────────
+
+/// A unit of background work. The payload should carry ids, not data — handlers load
+/// current state from the database when the job runs.
+pub trait Job: Serialize + DeserializeOwned + Send + Sync + 'static {
+    /// Queue name, used in the Redis keys and logs.
+    const QUEUE: &'static str;
+    /// Attempts before the job is dead-lettered, counting the first.
+    const MAX_ATTEMPTS: u32 = 3;
+}
+
+/// Why an attempt failed.
+#[derive(Debug)]
+pub enum JobError {
+    /// Transient (connection reset, timeout); try again after a backoff.
+    Retry(anyhow::Error),
+    /// Will fail the same way every time; dead-letter without retrying.
+    #[allow(dead_code)] // current handlers record permanent failures on their own rows
+    Fatal(anyhow::Error),
+}
+
+impl From<anyhow::Error> for JobError {
+    fn from(e: anyhow::Error) -> Self {
+        JobError::Retry(e)
+    }
+}
+
+impl std::fmt::Display for JobError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            JobError::Retry(e) | JobError::Fatal(e) => write!(f, "{e:#}"),
+        }
+    }
+}
+
+/// Runs jobs of one type. One handler value is shared by all of a queue's concurrent
+/// attempts.
+#[async_trait]
+pub trait Handler: Send + Sync + 'static {
+    type Job: Job;
+
+    async fn run(&self, job: &Self::Job) -> Result<(), JobError>;
+
+    /// Called once when `job` is dead-lettered, after its final attempt.
+    async fn on_dead(&self, _job: &Self::Job, _error: &str) {}
+}
+
+// ────────────────────────────────────────────────────────────────────────────
+// Envelopes
+// ────────────────────────────────────────────────────────────────────────────
+
+/// What is stored in Redis: the job payload plus delivery bookkeeping.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Envelope {
+    pub id: Uuid,
+    /// 1-based number of the attempt this envelope is queued for.
+    pub attempt: u32,
+    pub enqueued_at: DateTime<Utc>,
+    pub payload: serde_json::Value,
+    /// Error from the previous attempt, for retried and dead-lettered envelopes.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub last_error: Option<String>,
+}
+
+impl Envelope {
+    fn new<J: Job>(job: &J) -> Self {
+        Envelope {
+            id: Uuid::new_v4(),
+            attempt: 1,
+            enqueued_at: Utc::now(),
+            payload: serde_json::to_value(job).expect("job payloads serialize"),
+            last_error: None,
+        }
+    }
+
+    fn encode(&self) -> String {
+        serde_json::to_string(self).expect("envelopes serialize")
+    }
+}
+
+/// Delay before attempt `next_attempt` (2 or later).
+pub fn backoff(next_attempt: u32) -> Duration {
+    let doublings = next_attempt.saturating_sub(2).min(16);
+    BASE_BACKOFF.saturating_mul(1 << doublings).min(MAX_BACKOFF)
+}
+
+// ────────────────────────────────────────────────────────────────────────────
+// Keys
+// ────────────────────────────────────────────────────────────────────────────
+
+pub fn queue_key(queue: &str) -> String {
+    format!("jobs:{queue}")
+}
+
+fn delayed_key(queue: &str) -> String {
+    format!("jobs:{queue}:delayed")
+}
+
+pub fn dead_key(queue: &str) -> String {
+    format!("jobs:{queue}:dead")
+}
+
+// ────────────────────────────────────────────────────────────────────────────
+// Depth
+// ────────────────────────────────────────────────────────────────────────────
+
+/// Envelopes waiting in one queue, by where they wait.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct QueueDepth {
+    pub queue: String,
+    /// Ready for the next free worker.
+    pub ready: u64,
+    /// Waiting out a retry backoff.
+    pub delayed: u64,
+    pub dead: u64,
+}
+
+/// Current depth of `queue`, read in one round trip.
+pub async fn depth(redis: &redis::Client, queue: &str) -> redis::RedisResult<QueueDepth> {
+    let mut conn = redis.get_multiplexed_async_connection().await?;
+    let (ready, delayed, dead): (u64, u64, u64) = redis::pipe()
+        .cmd("LLEN")
+        .arg(queue_key(queue))
+        .cmd("ZCARD")
+        .arg(delayed_key(queue))
+        .cmd("LLEN")
+        .arg(dead_key(queue))
+        .query_async(&mut conn)
+        .await?;
+    Ok(QueueDepth {
+        queue: queue.to_string(),
+        ready,
+        delayed,
+        dead,
+    })
+}
+
+// ────────────────────────────────────────────────────────────────────────────
+// Enqueueing
+// ────────────────────────────────────────────────────────────────────────────
+
+/// Queues `job` for its first attempt and returns the envelope id.
+pub async fn enqueue<J: Job>(redis: &redis::Client, job: &J) -> redis::RedisResult<Uuid> {
+    let mut ids = enqueue_all(redis, std::slice::from_ref(job)).await?;
+    Ok(ids.remove(0))
+}
+
+/// Queues every job in one round trip, in order.
+pub async fn enqueue_all<J: Job>(
+    redis: &redis::Client,
+    jobs: &[J],
+) -> redis::RedisResult<Vec<Uuid>> {
+    if jobs.is_empty() {
+        return Ok(vec![]);
+    }
+    let envelopes: Vec<Envelope> = jobs.iter().map(Envelope::new).collect();
+    let encoded: Vec<String> = envelopes.iter().map(Envelope::encode).collect();
+    let mut conn = redis.get_multiplexed_async_connection().await?;
+    conn.lpush::<_, _, ()>(queue_key(J::QUEUE), encoded).await?;
+    Ok(envelopes.into_iter().map(|e| e.id).collect())
+}
+
+// ────────────────────────────────────────────────────────────────────────────
+// Periodic jobs
+// ────────────────────────────────────────────────────────────────────────────
+
+/// Queues `make()` every `interval`. Every API instance runs the loop; a Redis lock
+/// held for one interval (`jobs:{queue}:schedule`) lets only one of them queue per
+/// tick.
+pub fn schedule_every<J, F>(redis: redis::Client, interval: Duration, make: F)
+where
+    J: Job,
+    F: Fn() -> J + Send + 'static,
+{
+    tokio::spawn(async move {
+        let mut ticker = tokio::time::interval(interval);
+        ticker.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+        loop {
+            ticker.tick().await;
+            match claim_tick(&redis, J::QUEUE, interval).await {
+                Ok(true) => {
+                    if let Err(e) = enqueue(&redis, &make()).await {
+                        tracing::warn!(queue = J::QUEUE, "failed to queue periodic job: {e}");
+                    }
+                }
+                Ok(false) => {}
+                Err(e) => tracing::warn!(queue = J::QUEUE, "schedule lock failed: {e}"),
+            }
+        }
+    });
+}
+
+fn schedule_key(queue: &str) -> String {
+    format!("jobs:{queue}:schedule")
+}
+
+/// Takes the queue's schedule lock for `interval`; false if another instance holds it.
+async fn claim_tick(
+    redis: &redis::Client,
+    queue: &str,
+    interval: Duration,
+) -> redis::RedisResult<bool> {
+    let mut conn = redis.get_multiplexed_async_connection().await?;
+    let claimed: Option<String> = redis::cmd("SET")
+        .arg(schedule_key(queue))
+        .arg(Utc::now().to_rfc3339())
+        .arg("NX")
+        .arg("EX")
+        .arg(interval.as_secs().max(1))
+        .query_async(&mut conn)
+        .await?;
+    Ok(claimed.is_some())
+}
+
+// ────────────────────────────────────────────────────────────────────────────
+// Tests
+// ────────────────────────────────────────────────────────────────────────────
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Ping {
+        target: Uuid,
+    }
+
+    impl Job for Ping {
+        const QUEUE: &'static str = "ping";
+    }
+
+    #[test]
+    fn test_envelope_round_trip() {
+        let job = Ping {
+            target: Uuid::new_v4(),
+        };
+        let envelope = Envelope::new(&job);
+        assert_eq!(envelope.attempt, 1);
+
+        let decoded: Envelope = serde_json::from_str(&envelope.encode()).unwrap();
+        assert_eq!(decoded, envelope);
+        let payload: Ping = serde_json::from_value(decoded.payload).unwrap();
+        assert_eq!(payload, job);
+        assert!(!envelope.encode().contains("last_error"));
+    }
+
+    #[test]
+    fn test_backoff_doubles_then_caps() {
+        assert_eq!(backoff(2), Duration::from_secs(5));
+        assert_eq!(backoff(3), Duration::from_secs(10));
+        assert_eq!(backoff(4), Duration::from_secs(20));
+        assert_eq!(backoff(12), MAX_BACKOFF);
+        assert_eq!(backoff(u32::MAX), MAX_BACKOFF);
+    }
+
+    #[test]
+    fn test_keys_are_namespaced_per_queue() {
+        assert_eq!(queue_key(Ping::QUEUE), "jobs:ping");
+        assert_eq!(delayed_key(Ping::QUEUE), "jobs:ping:delayed");
+        assert_eq!(dead_key(Ping::QUEUE), "jobs:ping:dead");
+        assert_eq!(schedule_key(Ping::QUEUE), "jobs:ping:schedule");
+        assert_eq!(Ping::MAX_ATTEMPTS, 3);
+    }
+}
@@ -0,0 +1,282 @@
+//! Worker loop: BRPOP an envelope → decode → `Handler::run` → retry, dead-letter or done.
+//!
+//! Each registered handler gets one polling task. A semaphore caps how many of its
+//! jobs run at once; the poller blocks on it, so nothing is dequeued that can't start
+//! immediately. A worker never exits on a job failure or a Redis error — it logs,
+//! waits briefly, and keeps polling.
+
+use std::sync::Arc;
+use std::time::Duration;
+
+use chrono::Utc;
+use tokio::sync::Semaphore;
+use tracing::{error, info, warn};
+
+use super::MAX_DEAD_LETTERS;
+use super::{backoff, dead_key, delayed_key, queue_key, Envelope, Handler, Job, JobError};
+use crate::reporting;
+
+/// BRPOP timeout. Also bounds how late a retry can start past its due time, since
+/// delayed envelopes are promoted between polls.
+const POLL_TIMEOUT_SECS: f64 = 5.0;
+
+/// Most delayed envelopes promoted per poll.
+const PROMOTE_BATCH: usize = 100;
+
+/// Moves due envelopes from the delayed set back onto the queue. Atomic, so two
+/// workers polling the same queue never promote an envelope twice.
+const PROMOTE_SCRIPT: &str = r#"
+local due = redis.call('ZRANGEBYSCORE', KEYS[1], '-inf', ARGV[1], 'LIMIT', 0, ARGV[2])
+for _, envelope in ipairs(due) do
+  redis.call('ZREM', KEYS[1], envelope)
+  redis.call('LPUSH', KEYS[2], envelope)
+end
+return #due
+"#;
+
+// ────────────────────────────────────────────────────────────────────────────
+// Registration
+// ────────────────────────────────────────────────────────────────────────────
+
+/// Registry of running workers, built in main.rs.
+pub struct Workers {
+    redis: redis::Client,
+    started: Vec<(&'static str, usize)>,
+}
+
+impl Workers {
+    pub fn new(redis: redis::Client) -> Self {
+        Workers {
+            redis,
+            started: vec![],
+        }
+    }
+
+    /// Spawns a worker running up to `concurrency` of `handler`'s jobs at once.
+    pub fn register<H: Handler>(mut self, handler: H, concurrency: usize) -> Self {
+        let concurrency = concurrency.max(1);
+        let redis = self.redis.clone();
+        let handler = Arc::new(handler);
+        tokio::spawn(async move { worker_loop(redis, handler, concurrency).await });
+        self.started.push((H::Job::QUEUE, concurrency));
+        self
+    }
+
+    /// `queue×concurrency` for each registered worker, for the startup log.
+    pub fn summary(&self) -> String {
+        self.started
+            .iter()
+            .map(|(queue, n)| format!("{queue}×{n}"))
+            .collect::<Vec<_>>()
+            .join(", ")
+    }
+}
+
+// ────────────────────────────────────────────────────────────────────────────
+// Loop
+// ────────────────────────────────────────────────────────────────────────────
+
+async fn worker_loop<H: Handler>(redis: redis::Client, handler: Arc<H>, concurrency: usize) {
+    let queue = H::Job::QUEUE;
+    info!(queue, concurrency, "Job worker started");
+    let semaphore = Arc::new(Semaphore::new(concurrency));
+    let promote = redis::Script::new(PROMOTE_SCRIPT);
+
+    loop {
+        let mut conn = match redis.get_multiplexed_async_connection().await {
+            Ok(c) => c,
+            Err(e) => {
+                error!(
+                    queue,
+                    "Job worker: Redis connection failed: {e} — retrying in 5s"
+                );
+                tokio::time::sleep(Duration::from_secs(5)).await;
+                continue;
+            }
+        };
+
+        let permit = Arc::clone(&semaphore)
+            .acquire_owned()
+            .await
+            .expect("semaphore is never closed");
+
+        let promoted: redis::RedisResult<usize> = promote
+            .key(delayed_key(queue))
+            .key(queue_key(queue))
+            .arg(Utc::now().timestamp_millis())
+            .arg(PROMOTE_BATCH)
+            .invoke_async(&mut conn)
+            .await;
+        if let Err(e) = promoted {
+            warn!(queue, "Job worker: promoting delayed jobs failed: {e}");
+        }
+
+        let popped: redis::RedisResult<Option<(String, String)>> = redis::cmd("BRPOP")
+            .arg(queue_key(queue))
+            .arg(POLL_TIMEOUT_SECS)
+            .query_async(&mut conn)
+            .await;
+
+        match popped {
+            Ok(None) => drop(permit),
+            Ok(Some((_key, raw))) => {
+                let (redis, handler) = (redis.clone(), Arc::clone(&handler));
+                tokio::spawn(async move {
+                    let _permit = permit; // held for the whole attempt
+                    run_attempt(&redis, handler.as_ref(), raw).await;
+                });
+            }
+            Err(e) => {
+                drop(permit);
+                error!(queue, "Job worker: BRPOP error: {e} — reconnecting");
+                tokio::time::sleep(Duration::from_secs(1)).await;
+            }
+        }
+    }
+}
+
+/// What happens to an envelope after a failed attempt.
+#[derive(Debug, PartialEq)]
+enum Disposition {
+    RetryAfter(Duration),
+    Dead,
+}
+
+fn disposition(error: &JobError, attempt: u32, max_attempts: u32) -> Disposition {
+    match error {
+        JobError::Retry(_) if attempt < max_attempts => {
+            Disposition::RetryAfter(backoff(attempt + 1))
+        }
+        _ => Disposition::Dead,
+    }
+}
+
+async fn run_attempt<H: Handler>(redis: &redis::Client, handler: &H, raw: String) {
+    let queue = H::Job::QUEUE;
+    let mut envelope: Envelope = match serde_json::from_str(&raw) {
+        Ok(e) => e,
+        Err(e) => {
+            error!(
+                queue,
+                "Job worker: undecodable envelope — dead-lettering: {e}"
+            );
+            store_dead(redis, queue, raw).await;
+            return;
+        }
+    };
+    let job: H::Job = match serde_json::from_value(envelope.payload.clone()) {
+        Ok(job) => job,
+        Err(e) => {
+            error!(queue, job = %envelope.id, "Job worker: invalid payload — dead-lettering: {e}");
+            envelope.last_error = Some(format!("invalid payload: {e}"));
+            store_dead(redis, queue, envelope.encode()).await;
+            return;
+        }
+    };
+
+    // Errors captured while the job runs (LLM failures, the dead-letter below) carry
+    // the queue and job ID.
+    reporting::scoped(reporting::Scope::job(queue, envelope.id), async {
+        let Err(err) = handler.run(&job).await else {
+            return;
+        };
+        let message = err.to_string();
+        envelope.last_error = Some(message.clone());
+
+        match disposition(&err, envelope.attempt, H::Job::MAX_ATTEMPTS) {
+            Disposition::RetryAfter(delay) => {
+                warn!(
+                    queue,
+                    job = %envelope.id,
+                    attempt = envelope.attempt,
+                    retry_in_secs = delay.as_secs(),
+                    error = %message,
+                    "Job attempt failed — retrying"
+                );
+                envelope.attempt += 1;
+                if let Err(e) = schedule_retry(redis, queue, &envelope, delay).await {
+                    // The envelope is gone once popped; without the retry it is dead.
+                    error!(queue, job = %envelope.id, "Job worker: scheduling retry failed: {e}");
+                    handler.on_dead(&job, &message).await;
+                }
+            }
+            Disposition::Dead => {
+                error!(
+                    queue,
+                    job = %envelope.id,
+                    attempt = envelope.attempt,
+                    error = %message,
+                    "Job failed — dead-lettering"
+                );
+                reporting::capture_error("JobDead", &message);
+                store_dead(redis, queue, envelope.encode()).await;
+                handler.on_dead(&job, &message).await;
+            }
+        }
+    })
+    .await;
+}
+
+async fn schedule_retry(
+    redis: &redis::Client,
+    queue: &str,
+    envelope: &Envelope,
+    delay: Duration,
+) -> redis::RedisResult<()> {
+    let due_ms = Utc::now().timestamp_millis() + delay.as_millis() as i64;
+    let mut conn = redis.get_multiplexed_async_connection().await?;
+    redis::cmd("ZADD")
+        .arg(delayed_key(queue))
+        .arg(due_ms)
+        .arg(envelope.encode())
+        .query_async(&mut conn)
+        .await
+}
+
+/// Best-effort: a failure here is logged, since the job has already failed.
+async fn store_dead(redis: &redis::Client, queue: &str, raw: String) {
+    let result: redis::RedisResult<()> = async {
+        let mut conn = redis.get_multiplexed_async_connection().await?;
+        redis::pipe()
+            .atomic()
+            .cmd("LPUSH")
+            .arg(dead_key(queue))
+            .arg(raw)
+            .ignore()
+            .cmd("LTRIM")
+            .arg(dead_key(queue))
+            .arg(0)
+            .arg(MAX_DEAD_LETTERS - 1)
+            .ignore()
+            .query_async(&mut conn)
+            .await
+    }
+    .await;
+    if let Err(e) = result {
+        error!(queue, "Job worker: storing dead letter failed: {e}");
+    }
+}
+
+// ────────────────────────────────────────────────────────────────────────────
+// Tests
+// ────────────────────────────────────────────────────────────────────────────
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_retryable_errors_retry_until_max_attempts() {
+        let err = JobError::Retry(anyhow::anyhow!("connection reset"));
+        assert_eq!(disposition(&err, 1, 3), Disposition::RetryAfter(backoff(2)));
+        assert_eq!(disposition(&err, 2, 3), Disposition::RetryAfter(backoff(3)));
+        assert_eq!(disposition(&err, 3, 3), Disposition::Dead);
+        assert_eq!(disposition(&err, 1, 1), Disposition::Dead);
+    }
+
+    #[test]
+    fn test_fatal_errors_never_retry() {
+        let err = JobError::Fatal(anyhow::anyhow!("resume deleted"));
+        assert_eq!(disposition(&err, 1, 5), Disposition::Dead);
+    }
+}
@@ -4,18 +4,32 @@
 //! # Contract rules
 //! - 1-line bullet: fill ≥ 80% of text width
 //! - 2-line bullet: line 1 fills naturally (wraps), line 2 fill ≥ 70%
+//!
+//! The fill minimums are `PageConfig::contract` (80% / 70% unless the font's defaults
+//! or the deployment override them).
 //! - 3+ line bullets: PROHIBITED — always compress
 //!
+//! # Other line types
+//! Lines that are not bullets (`LineType`) go through the same check with their own
+//! `LineRules`:
+//! - entry header: one line, no fill minimum
+//! - summary paragraph: up to 4 lines; a wrapped paragraph's last line must fill 30%,
+//!   even when it wraps to just two (a widow, not a short bullet second line)
+//!
 //! # Promotion rules
 //! A bullet may be *promoted* to 2 lines only if it scores HIGH (≥ 0.7) on all three of:
 //! quantified outcome, technical depth, and JD relevance.
 //! Maximum 3 two-line bullets per page.
 
+use std::collections::HashMap;
+
 use serde::{Deserialize, Serialize};
 
+use crate::context::outcomes::has_quantified_outcome;
 use crate::generation::generator::DraftBullet;
 use crate::generation::jd_parser::ParsedJD;
-use crate::layout::font_metrics::{FontMetricTable, PageConfig};
+use crate::layout::font_metrics::{ContractThresholds, FontMetricTable, LineBreaking, PageConfig};
+use crate::layout::LineType;
 
 // ────────────────────────────────────────────────────────────────────────────
 // Contract result types
@@ -26,12 +40,14 @@ use crate::layout::font_metrics::{FontMetricTable, PageConfig};
 pub enum LineCoverageVerdict {
     /// Bullet satisfies all line coverage requirements.
     Satisfies,
-    /// 1-line bullet whose fill is below the minimum (80%).
+    /// 1-line bullet whose fill is below the minimum (`min_one_line_fill`).
     TooShort { fill_ratio: f32, required: f32 },
     /// Bullet wraps to 3 or more lines (prohibited).
     TooLong { actual_lines: u8 },
-    /// 2-line bullet whose second line fill is below the minimum (70%).
+    /// 2-line bullet whose second line fill is below the minimum (`min_second_line_fill`).
     SecondLineTooShort { fill_ratio: f32 },
+    /// Wrapped non-bullet line whose last line fill is below its `min_last_line_fill`.
+    LastLineTooShort { lines: u8, fill_ratio: f32 },
 }
 
 /// Full coverage result for a single bullet after simulation.
@@ -64,39 +80,177 @@ pub struct PromotionScore {
     pub eligible_for_two_lines: bool,
 }
 
+// ────────────────────────────────────────────────────────────────────────────
+// Line rules
+// ────────────────────────────────────────────────────────────────────────────
+
+/// Most printed lines a summary paragraph may take.
+const SUMMARY_MAX_LINES: u8 = 4;
+/// A wrapped summary's last line must fill this much: less is a word or two left over.
+const SUMMARY_MIN_LAST_LINE_FILL: f32 = 0.3;
+
+/// Wrapping and fill rules for one `LineType`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct LineRules {
+    pub line_type: LineType,
+    pub max_lines: u8,
+    /// Minimum fill of a line that does not wrap. `None`: any fill.
+    pub min_one_line_fill: Option<f32>,
+    /// Minimum fill of the last line of a wrapped line. `None`: any fill.
+    pub min_last_line_fill: Option<f32>,
+}
+
+/// Most printed lines a line of `line_type` may take.
+pub fn max_lines_for(line_type: LineType) -> u8 {
+    match line_type {
+        LineType::Bullet => 2,
+        LineType::EntryHeader => 1,
+        LineType::SummaryParagraph => SUMMARY_MAX_LINES,
+    }
+}
+
+impl LineRules {
+    /// The rules for `line_type`. Bullets take their fill minimums from `thresholds`.
+    pub fn for_line(line_type: LineType, thresholds: &ContractThresholds) -> Self {
+        let (min_one_line_fill, min_last_line_fill) = match line_type {
+            LineType::Bullet => (
+                Some(thresholds.min_one_line_fill),
+                Some(thresholds.min_second_line_fill),
+            ),
+            LineType::EntryHeader => (None, None),
+            LineType::SummaryParagraph => (
+                Some(thresholds.min_one_line_fill),
+                Some(SUMMARY_MIN_LAST_LINE_FILL),
+            ),
+        };
+        LineRules {
+            line_type,
+            max_lines: max_lines_for(line_type),
+            min_one_line_fill,
+            min_last_line_fill,
+        }
+    }
+}
+
 // ────────────────────────────────────────────────────────────────────────────
 // Core simulation
 // ────────────────────────────────────────────────────────────────────────────
 
-const MIN_1LINE_FILL: f32 = 0.80;
-const MIN_2LINE_L2_FILL: f32 = 0.70;
+/// Page-wide cap on 2-line bullets (promotion rules above).
+pub const MAX_TWO_LINE_BULLETS: usize = 3;
+
+/// TeX's default `\tolerance`: a line whose badness exceeds this is not a feasible break.
+const KP_TOLERANCE: f32 = 200.0;
+/// TeX's default `\linepenalty`, added to every line's badness before squaring.
+const KP_LINE_PENALTY: f32 = 10.0;
+/// Badness TeX assigns to a line that cannot be set (infinitely bad).
+const KP_INF_BADNESS: f32 = 10_000.0;
+/// Interword stretch and shrink as fractions of the space width. TeX text fonts set
+/// roughly 1/2 and 1/3 of the interword space (fontdimen 3 and 4).
+const SPACE_STRETCH: f32 = 0.5;
+const SPACE_SHRINK: f32 = 1.0 / 3.0;
+
+/// Word widths measured once and reused, for checking many texts in one go.
+///
+/// Resumes repeat most of their words across bullets, and a simulation pass re-checks
+/// every bullet, so a pass measures each distinct word once instead of character by
+/// character per occurrence. Keys borrow from the texts being checked; build one per
+/// pass, not per run, since bullet texts change between passes.
+pub struct WordWidthMemo<'a> {
+    metrics: &'a FontMetricTable,
+    widths: HashMap<&'a str, f32>,
+}
+
+impl<'a> WordWidthMemo<'a> {
+    pub fn new(metrics: &'a FontMetricTable) -> Self {
+        Self {
+            metrics,
+            widths: HashMap::new(),
+        }
+    }
+
+    /// Number of distinct words measured so far.
+    pub fn len(&self) -> usize {
+        self.widths.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.widths.is_empty()
+    }
+
+    /// Widths of `text`'s words, in order.
+    fn word_widths(&mut self, text: &'a str) -> Vec<f32> {
+        text.split_whitespace()
+            .map(|word| {
+                *self
+                    .widths
+                    .entry(word)
+                    .or_insert_with(|| self.metrics.measure_str(word))
+            })
+            .collect()
+    }
+}
+
+fn measure_words(text: &str, metrics: &FontMetricTable) -> Vec<f32> {
+    text.split_whitespace()
+        .map(|word| metrics.measure_str(word))
+        .collect()
+}
+
+/// Simulates how `text` wraps at `config.text_width_em`, using the algorithm selected
+/// by `config.line_breaking`. Returns `(line_count, per_line_fill_fractions)`.
+pub fn simulate_lines(
+    text: &str,
+    metrics: &FontMetricTable,
+    config: &PageConfig,
+) -> (u8, Vec<f32>) {
+    break_lines(&measure_words(text, metrics), metrics.space_width, config)
+}
+
+/// `simulate_lines`, taking word widths from `memo`.
+pub fn simulate_lines_memo<'a>(
+    text: &'a str,
+    memo: &mut WordWidthMemo<'a>,
+    config: &PageConfig,
+) -> (u8, Vec<f32>) {
+    let word_widths = memo.word_widths(text);
+    break_lines(&word_widths, memo.metrics.space_width, config)
+}
+
+fn break_lines(word_widths: &[f32], space: f32, config: &PageConfig) -> (u8, Vec<f32>) {
+    match config.line_breaking {
+        LineBreaking::Greedy => greedy_breaks(word_widths, space, config.text_width_em),
+        LineBreaking::KnuthPlass => knuth_plass_breaks(word_widths, space, config.text_width_em),
+    }
+}
 
 /// Greedy word-wrap simulation. Returns `(line_count, per_line_fill_fractions)`.
 ///
 /// Each fill fraction is `line_width / config.text_width_em` (may be > 1.0 for the
 /// last filled line when it wraps). An empty string returns `(0, vec![])`.
-pub fn simulate_lines(
+pub fn simulate_lines_greedy(
     text: &str,
     metrics: &FontMetricTable,
     config: &PageConfig,
 ) -> (u8, Vec<f32>) {
-    let words: Vec<&str> = text.split_whitespace().collect();
-    if words.is_empty() {
+    greedy_breaks(
+        &measure_words(text, metrics),
+        metrics.space_width,
+        config.text_width_em,
+    )
+}
+
+fn greedy_breaks(word_widths: &[f32], space: f32, max_width: f32) -> (u8, Vec<f32>) {
+    if word_widths.is_empty() {
         return (0, vec![]);
     }
 
-    let max_width = config.text_width_em;
     let mut line_fills: Vec<f32> = Vec::new();
     let mut current_width = 0.0_f32;
     let mut first_on_line = true;
 
-    for word in &words {
-        let word_w = metrics.measure_str(word);
-        let space_w = if first_on_line {
-            0.0
-        } else {
-            metrics.space_width
-        };
+    for &word_w in word_widths {
+        let space_w = if first_on_line { 0.0 } else { space };
 
         if !first_on_line && current_width + space_w + word_w > max_width {
             // Current line is full — push its fill and start a new line.
@@ -111,10 +265,123 @@ pub fn simulate_lines(
     // Push the final (possibly partial) line.
     line_fills.push(current_width / max_width);
 
-    let count = line_fills.len() as u8;
+    let count = line_fills.len().min(u8::MAX as usize) as u8;
     (count, line_fills)
 }
 
+/// Knuth-Plass (total-fit) line breaking, as TeX does it for a paragraph without
+/// hyphenation. Returns `(line_count, per_line_fill_fractions)` like the greedy version.
+///
+/// Every word gap is glue with the font's space width, stretch, and shrink. A line is
+/// feasible when it can be set with badness ≤ `KP_TOLERANCE`; shrinking lets a line hold
+/// slightly more than its natural width, which is where greedy and TeX disagree most.
+/// The last line has infinite stretch (`\parfillskip`), so it is never underfull.
+/// Among feasible break sequences, the one with the least total demerits
+/// (`(linepenalty + badness)²` per line) wins.
+///
+/// Fill fractions are natural widths, so a shrunk line reports a fill slightly above 1.0.
+/// When no feasible sequence exists (e.g. a word wider than the line), TeX would emit an
+/// overfull box; we fall back to the greedy result instead.
+pub fn simulate_lines_knuth_plass(
+    text: &str,
+    metrics: &FontMetricTable,
+    config: &PageConfig,
+) -> (u8, Vec<f32>) {
+    knuth_plass_breaks(
+        &measure_words(text, metrics),
+        metrics.space_width,
+        config.text_width_em,
+    )
+}
+
+fn knuth_plass_breaks(word_widths: &[f32], space: f32, max_width: f32) -> (u8, Vec<f32>) {
+    if word_widths.is_empty() {
+        return (0, vec![]);
+    }
+
+    let n = word_widths.len();
+
+    // best[j] = (total demerits, start of the last line) for the best way to set words[..j]
+    let mut best: Vec<Option<(f32, usize)>> = vec![None; n + 1];
+    best[0] = Some((0.0, 0));
+
+    for end in 1..=n {
+        let is_last_line = end == n;
+        let mut words_width = 0.0_f32;
+        // Walk the line start backwards: each step makes the candidate line longer.
+        for start in (0..end).rev() {
+            words_width += word_widths[start];
+            let gaps = (end - start - 1) as f32;
+            let natural = words_width + gaps * space;
+            let shrink = gaps * space * SPACE_SHRINK;
+            if natural - shrink > max_width {
+                break;
+            }
+            let Some((prev_demerits, _)) = best[start] else {
+                continue;
+            };
+
+            let badness = if is_last_line && natural <= max_width {
+                0.0
+            } else {
+                line_badness(natural, max_width, gaps * space * SPACE_STRETCH, shrink)
+            };
+            if badness > KP_TOLERANCE {
+                continue;
+            }
+
+            let demerits = prev_demerits + (KP_LINE_PENALTY + badness).powi(2);
+            if best[end].is_none_or(|(d, _)| demerits < d) {
+                best[end] = Some((demerits, start));
+            }
+        }
+    }
+
+    if best[n].is_none() {
+        return greedy_breaks(word_widths, space, max_width);
+    }
+
+    // Walk the chosen breaks back from the end of the paragraph.
+    let mut line_fills = Vec::new();
+    let mut end = n;
+    while end > 0 {
+        let (_, start) = best[end].expect("every break on the optimal path is feasible");
+        let gaps = (end - start - 1) as f32;
+        let natural = word_widths[start..end].iter().sum::<f32>() + gaps * space;
+        line_fills.push(natural / max_width);
+        end = start;
+    }
+    line_fills.reverse();
+
+    let count = line_fills.len().min(u8::MAX as usize) as u8;
+    (count, line_fills)
+}
+
+/// TeX badness of a line with the given natural width set to `target`:
+/// `100·r³` where `r` is the stretch (or shrink) ratio, capped at `KP_INF_BADNESS`.
+/// Shrinking beyond the available shrink is impossible and always infinitely bad.
+fn line_badness(natural: f32, target: f32, stretch: f32, shrink: f32) -> f32 {
+    let ratio = if natural < target {
+        if stretch <= 0.0 {
+            return KP_INF_BADNESS;
+        }
+        (target - natural) / stretch
+    } else {
+        if natural == target {
+            return 0.0;
+        }
+        if shrink <= 0.0 {
+            return KP_INF_BADNESS;
+        }
+        let r = (natural - target) / shrink;
+        if r > 1.0 {
+            return KP_INF_BADNESS;
+        }
+        r
+    };
+    (100.0 * ratio.powi(3)).min(KP_INF_BADNESS)
+}
+
 // ────────────────────────────────────────────────────────────────────────────
 // Contract check
 // ────────────────────────────────────────────────────────────────────────────
@@ -126,31 +393,82 @@ pub fn check_contract(
     metrics: &FontMetricTable,
     config: &PageConfig,
 ) -> LineCoverageResult {
+    check_line_contract(bullet_index, text, LineType::Bullet, metrics, config)
+}
+
+/// `check_contract`, taking word widths from `memo`.
+pub fn check_contract_memo<'a>(
+    bullet_index: usize,
+    text: &'a str,
+    memo: &mut WordWidthMemo<'a>,
+    config: &PageConfig,
+) -> LineCoverageResult {
+    check_line_contract_memo(bullet_index, text, LineType::Bullet, memo, config)
+}
+
+/// Checks a line of any `LineType` against its `LineRules`.
+pub fn check_line_contract(
+    bullet_index: usize,
+    text: &str,
+    line_type: LineType,
+    metrics: &FontMetricTable,
+    config: &PageConfig,
+) -> LineCoverageResult {
+    let rules = LineRules::for_line(line_type, &config.contract);
     let (line_count, fills) = simulate_lines(text, metrics, config);
+    coverage_result(bullet_index, text, line_count, &fills, &rules)
+}
 
+/// `check_line_contract`, taking word widths from `memo`.
+pub fn check_line_contract_memo<'a>(
+    bullet_index: usize,
+    text: &'a str,
+    line_type: LineType,
+    memo: &mut WordWidthMemo<'a>,
+    config: &PageConfig,
+) -> LineCoverageResult {
+    let rules = LineRules::for_line(line_type, &config.contract);
+    let (line_count, fills) = simulate_lines_memo(text, memo, config);
+    coverage_result(bullet_index, text, line_count, &fills, &rules)
+}
+
+fn coverage_result(
+    bullet_index: usize,
+    text: &str,
+    line_count: u8,
+    fills: &[f32],
+    rules: &LineRules,
+) -> LineCoverageResult {
     let line1_fill = fills.first().copied().unwrap_or(0.0);
     let line2_fill = fills.get(1).copied();
+    let last_fill = fills.last().copied().unwrap_or(0.0);
 
-    let verdict = if line_count == 0 || line_count == 1 {
-        if line1_fill < MIN_1LINE_FILL {
-            LineCoverageVerdict::TooShort {
-                fill_ratio: line1_fill,
-                required: MIN_1LINE_FILL,
-            }
-        } else {
-            LineCoverageVerdict::Satisfies
+    let verdict = if line_count > rules.max_lines {
+        LineCoverageVerdict::TooLong {
+            actual_lines: line_count,
         }
-    } else if line_count == 2 {
-        match line2_fill {
-            Some(l2) if l2 < MIN_2LINE_L2_FILL => {
-                LineCoverageVerdict::SecondLineTooShort { fill_ratio: l2 }
-            }
+    } else if line_count <= 1 {
+        match rules.min_one_line_fill {
+            Some(required) if line1_fill < required => LineCoverageVerdict::TooShort {
+                fill_ratio: line1_fill,
+                required,
+            },
             _ => LineCoverageVerdict::Satisfies,
         }
     } else {
-        // 3+ lines: always prohibited
-        LineCoverageVerdict::TooLong {
-            actual_lines: line_count,
+        // Only a 2-line bullet's second line is judged by the bullet rule; any other
+        // wrapped line's short last line is a widow.
+        match rules.min_last_line_fill {
+            Some(required) if last_fill < required && rules.line_type == LineType::Bullet => {
+                LineCoverageVerdict::SecondLineTooShort {
+                    fill_ratio: last_fill,
+                }
+            }
+            Some(required) if last_fill < required => LineCoverageVerdict::LastLineTooShort {
+                lines: line_count,
+                fill_ratio: last_fill,
+            },
+            _ => LineCoverageVerdict::Satisfies,
         }
     };
 
@@ -184,18 +502,28 @@ pub fn check_all_contracts(
 /// Scores a bullet for 2-line promotion eligibility.
 ///
 /// A bullet is eligible if it scores ≥ 0.7 on ALL three dimensions:
-/// - `quantified_outcome`: contains a number + unit pattern (%, $, x, k)
+/// - `quantified_outcome`: states an outcome with a unit, a before/after pair or a
+///   ratio (see `crate::context::outcomes`)
 /// - `technical_depth`: at least 30% of high-weighted JD keywords appear in text
 /// - `jd_relevance`: at least 30% of JD keywords from `jd_keywords_used` are high-weight
 pub fn score_promotion(bullet: &DraftBullet, parsed_jd: &ParsedJD) -> PromotionScore {
-    let quantified_outcome = if has_quantified_outcome(&bullet.text) {
+    score_promotion_text(&bullet.text, &bullet.jd_keywords_used, parsed_jd)
+}
+
+/// [`score_promotion`] over a bare text + keyword list, for bullets past the draft stage.
+pub fn score_promotion_text(
+    text: &str,
+    jd_keywords_used: &[String],
+    parsed_jd: &ParsedJD,
+) -> PromotionScore {
+    let quantified_outcome = if has_quantified_outcome(text) {
         1.0
     } else {
         0.0
     };
 
-    let technical_depth = compute_technical_depth(&bullet.text, parsed_jd);
-    let jd_relevance = compute_jd_relevance(&bullet.jd_keywords_used, parsed_jd);
+    let technical_depth = compute_technical_depth(text, parsed_jd);
+    let jd_relevance = compute_jd_relevance(jd_keywords_used, parsed_jd);
 
     let eligible_for_two_lines =
         quantified_outcome >= 0.7 && technical_depth >= 0.7 && jd_relevance >= 0.7;
@@ -208,6 +536,19 @@ pub fn score_promotion(bullet: &DraftBullet, parsed_jd: &ParsedJD) -> PromotionS
     }
 }
 
+impl PromotionScore {
+    /// Ordering key for deciding which 2-line bullets keep their second line: eligible
+    /// bullets always outrank ineligible ones, then the sum of the three dimensions.
+    pub fn rank(&self) -> f32 {
+        let eligible_bonus = if self.eligible_for_two_lines {
+            3.0
+        } else {
+            0.0
+        };
+        eligible_bonus + self.quantified_outcome + self.technical_depth + self.jd_relevance
+    }
+}
+
 /// Returns the number of 2-line bullets in a set of coverage results.
 pub fn two_line_count(results: &[LineCoverageResult]) -> usize {
     results
@@ -220,33 +561,6 @@ pub fn two_line_count(results: &[LineCoverageResult]) -> usize {
 // Internal helpers
 // ────────────────────────────────────────────────────────────────────────────
 
-/// Returns true if the text contains a quantified outcome pattern.
-///
-/// Detects: any ASCII digit immediately followed by `%`, `x`, `k`, or `m`
-/// (case-insensitive), or any ASCII digit immediately preceded by `$`.
-/// Handles integers, decimals, and multi-digit values (e.g. `3.47x`, `190.45%`,
-/// `$2.5M`, `500k`).
-fn has_quantified_outcome(text: &str) -> bool {
-    let lower = text.to_lowercase();
-    // Look for digit + unit patterns
-    let bytes = lower.as_bytes();
-    for i in 0..bytes.len() {
-        if bytes[i].is_ascii_digit() {
-            // Check following character for unit
-            if let Some(&next) = bytes.get(i + 1) {
-                if matches!(next, b'%' | b'x' | b'k' | b'm') {
-                    return true;
-                }
-            }
-            // Check preceding character for $ sign
-            if i > 0 && bytes[i - 1] == b'$' {
-                return true;
-            }
-        }
-    }
-    false
-}
-
 /// Fraction of JD keywords (with high position_weight ≥ 0.6) that appear in the bullet text.
 fn compute_technical_depth(text: &str, parsed_jd: &ParsedJD) -> f32 {
     let text_lower = text.to_lowercase();
@@ -297,8 +611,10 @@ fn compute_jd_relevance(used_keywords: &[String], parsed_jd: &ParsedJD) -> f32 {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::generation::jd_parser::{JDTone, KeywordEntry, ParsedJD, Requirement, RoleSignals};
-    use crate::layout::font_metrics::{default_page_config, get_metrics, FontFamily};
+    use crate::layout::font_metrics::{
+        default_page_config, get_metrics, ContractThresholds, FontFamily,
+    };
+    use templar_types::jd::{JDTone, KeywordEntry, ParsedJD, Requirement, RoleSignals};
     use uuid::Uuid;
 
     fn make_page_config() -> PageConfig {
@@ -373,6 +689,130 @@ mod tests {
         assert!(fills[0] < 1.0);
     }
 
+    // ── greedy vs Knuth-Plass ───────────────────────────────────────────────
+
+    fn knuth_plass_config() -> PageConfig {
+        PageConfig {
+            line_breaking: LineBreaking::KnuthPlass,
+            ..make_page_config()
+        }
+    }
+
+    #[test]
+    fn test_both_breakers_agree_on_short_text() {
+        let text = "Cut deploy time from 40 to 6 minutes with incremental Rust builds";
+        let config = make_page_config();
+        let greedy = simulate_lines_greedy(text, make_metrics(), &config);
+        let kp = simulate_lines_knuth_plass(text, make_metrics(), &config);
+        assert_eq!(greedy, kp);
+        assert_eq!(kp.0, 1);
+    }
+
+    #[test]
+    fn test_knuth_plass_fits_slightly_overfull_line_by_shrinking() {
+        // Natural width 1% over the line: greedy wraps the last word, TeX shrinks the glue.
+        let text = "word ".repeat(20);
+        let metrics = make_metrics();
+        let natural = metrics.measure_str(text.trim_end());
+        let config = PageConfig {
+            text_width_em: natural / 1.01,
+            ..make_page_config()
+        };
+
+        let (greedy_lines, _) = simulate_lines_greedy(&text, metrics, &config);
+        let (kp_lines, kp_fills) = simulate_lines_knuth_plass(&text, metrics, &config);
+        assert_eq!(greedy_lines, 2);
+        assert_eq!(kp_lines, 1);
+        assert!(kp_fills[0] > 1.0, "shrunk line reports natural fill > 1.0");
+    }
+
+    #[test]
+    fn test_knuth_plass_never_shrinks_past_glue_limit() {
+        // 10% over is beyond 1/3-space shrink per gap — both must wrap.
+        let text = "word ".repeat(20);
+        let metrics = make_metrics();
+        let config = PageConfig {
+            text_width_em: metrics.measure_str(text.trim_end()) / 1.10,
+            ..make_page_config()
+        };
+        assert_eq!(simulate_lines_knuth_plass(&text, metrics, &config).0, 2);
+        assert_eq!(simulate_lines_greedy(&text, metrics, &config).0, 2);
+    }
+
+    #[test]
+    fn test_knuth_plass_lines_never_exceed_shrinkable_width() {
+        let metrics = make_metrics();
+        let config = make_page_config();
+        let bullets = [
+            "Architected a distributed caching layer using Redis and consistent hashing \
+             to reduce p99 latency by 40% across five production services and two regions",
+            "Led migration of 120 services from a monolithic deploy pipeline to per-service \
+             canaries, cutting rollback time from hours to minutes for every on-call rotation",
+            &"word ".repeat(50),
+        ];
+        for text in bullets {
+            let (greedy_lines, _) = simulate_lines_greedy(text, metrics, &config);
+            let (kp_lines, fills) = simulate_lines_knuth_plass(text, metrics, &config);
+            assert!(
+                kp_lines.abs_diff(greedy_lines) <= 1,
+                "breakers diverged by more than a line: greedy={greedy_lines} kp={kp_lines}"
+            );
+            // Glue shrinks at most 1/3 of a space per gap, well under 5% of a line
+            assert!(fills.iter().all(|f| *f < 1.05), "{fills:?}");
+        }
+    }
+
+    #[test]
+    fn test_knuth_plass_overlong_word_falls_back_to_greedy() {
+        let text = format!("short {} tail", "x".repeat(200));
+        let config = make_page_config();
+        assert_eq!(
+            simulate_lines_knuth_plass(&text, make_metrics(), &config),
+            simulate_lines_greedy(&text, make_metrics(), &config)
+        );
+    }
+
+    #[test]
+    fn test_simulate_lines_dispatches_on_config_flag() {
+        let text = "word ".repeat(20);
+        let metrics = make_metrics();
+        let narrow = |config: PageConfig| PageConfig {
+            text_width_em: metrics.measure_str(text.trim_end()) / 1.01,
+            ..config
+        };
+        let greedy_config = narrow(make_page_config());
+        let kp_config = narrow(knuth_plass_config());
+
+        assert_eq!(simulate_lines(&text, metrics, &greedy_config).0, 2);
+        assert_eq!(simulate_lines(&text, metrics, &kp_config).0, 1);
+        assert_eq!(metrics.estimated_lines(&text, &kp_config), 1);
+    }
+
+    #[test]
+    fn test_memoised_widths_match_direct_measurement() {
+        let metrics = make_metrics();
+        let texts = [
+            "Reduced p99 latency by 40% by rewriting the Rust ingestion service",
+            "Rewrote the Rust billing service, cutting p99 latency by 25%",
+        ];
+        for config in [make_page_config(), knuth_plass_config()] {
+            let mut memo = WordWidthMemo::new(metrics);
+            for (i, text) in texts.iter().enumerate() {
+                assert_eq!(
+                    simulate_lines_memo(text, &mut memo, &config),
+                    simulate_lines(text, metrics, &config)
+                );
+                let direct = check_contract(i, text, metrics, &config);
+                let memoised = check_contract_memo(i, text, &mut memo, &config);
+                assert_eq!(memoised.verdict, direct.verdict);
+                assert_eq!(memoised.line1_fill, direct.line1_fill);
+            }
+            // Shared words ("Rust", "service", "p99", "latency", "by", ...) measured once.
+            let words: usize = texts.iter().map(|t| t.split_whitespace().count()).sum();
+            assert!(memo.len() < words);
+        }
+    }
+
     // ── check_contract verdicts ─────────────────────────────────────────────
 
     #[test]
@@ -384,7 +824,28 @@ mod tests {
             "Expected TooShort, got {:?}",
             result.verdict
         );
-        assert!(result.line1_fill < MIN_1LINE_FILL);
+        assert!(result.line1_fill < make_page_config().contract.min_one_line_fill);
+    }
+
+    #[test]
+    fn test_thresholds_come_from_page_config() {
+        let mut config = make_page_config();
+        let metrics = make_metrics();
+        let mut text = "Built".to_string();
+        while metrics.measure_str(&format!("{text} infra")) < config.text_width_em * 0.75 {
+            text.push_str(" infra");
+        }
+
+        let strict = check_contract(0, &text, metrics, &config);
+        assert!(matches!(
+            strict.verdict,
+            LineCoverageVerdict::TooShort { required, .. } if required == 0.80
+        ));
+
+        // A template that accepts this fill
+        config.contract = ContractThresholds::new(strict.line1_fill - 0.01, 0.70).unwrap();
+        let relaxed = check_contract(0, &text, metrics, &config);
+        assert_eq!(relaxed.verdict, LineCoverageVerdict::Satisfies);
     }
 
     #[test]
@@ -428,6 +889,88 @@ mod tests {
         );
     }
 
+    // ── line types ──────────────────────────────────────────────────────────
+
+    /// The shortest run of "infra" words that wraps to `lines` lines.
+    fn words_wrapping_to(lines: u8, config: &PageConfig) -> String {
+        let mut text = "infra".to_string();
+        while simulate_lines(&text, make_metrics(), config).0 < lines {
+            text.push_str(" infra");
+        }
+        text
+    }
+
+    #[test]
+    fn test_entry_headers_fit_one_line_with_no_fill_minimum() {
+        let config = make_page_config();
+        let metrics = make_metrics();
+        let short = check_line_contract(
+            0,
+            "B.S. in Computer Science, MIT",
+            LineType::EntryHeader,
+            metrics,
+            &config,
+        );
+        assert_eq!(short.verdict, LineCoverageVerdict::Satisfies);
+
+        let wrapped = words_wrapping_to(2, &config);
+        let entry = check_line_contract(0, &wrapped, LineType::EntryHeader, metrics, &config);
+        assert_eq!(
+            entry.verdict,
+            LineCoverageVerdict::TooLong { actual_lines: 2 }
+        );
+    }
+
+    #[test]
+    fn test_summary_paragraph_wraps_to_four_lines_without_a_widow() {
+        let config = make_page_config();
+        let metrics = make_metrics();
+
+        // A second line holding one word is a widow, not a short bullet second line.
+        let widowed = words_wrapping_to(2, &config);
+        let result = check_line_contract(0, &widowed, LineType::SummaryParagraph, metrics, &config);
+        assert!(matches!(
+            result.verdict,
+            LineCoverageVerdict::LastLineTooShort { lines: 2, .. }
+        ));
+        assert!(matches!(
+            check_contract(0, &widowed, metrics, &config).verdict,
+            LineCoverageVerdict::SecondLineTooShort { .. }
+        ));
+
+        // Likewise a third line, which a bullet may not have at all.
+        let widowed = words_wrapping_to(3, &config);
+        let result = check_line_contract(0, &widowed, LineType::SummaryParagraph, metrics, &config);
+        assert!(matches!(
+            result.verdict,
+            LineCoverageVerdict::LastLineTooShort { lines: 3, .. }
+        ));
+        assert!(matches!(
+            check_contract(0, &widowed, metrics, &config).verdict,
+            LineCoverageVerdict::TooLong { actual_lines: 3 }
+        ));
+
+        let four_lines = words_wrapping_to(5, &config)
+            .rsplit_once(' ')
+            .unwrap()
+            .0
+            .to_string();
+        let result =
+            check_line_contract(0, &four_lines, LineType::SummaryParagraph, metrics, &config);
+        assert_eq!(result.verdict, LineCoverageVerdict::Satisfies);
+        let result = check_line_contract(
+            0,
+            &words_wrapping_to(5, &config),
+            LineType::SummaryParagraph,
+            metrics,
+            &config,
+        );
+        assert_eq!(
+            result.verdict,
+            LineCoverageVerdict::TooLong { actual_lines: 5 }
+        );
+    }
+
     // ── check_all_contracts ─────────────────────────────────────────────────
 
     #[test]
@@ -450,6 +993,7 @@ mod tests {
     // ── promotion scoring ───────────────────────────────────────────────────
 
     #[test]
+    #[allow(clippy::overly_complex_bool_expr)]
     fn test_promotion_all_three_high_eligible() {
         // Bullet with quantified outcome, Rust + distributed keywords, high-weight matches
         let bullet = make_bullet(
@@ -514,6 +1058,16 @@ mod tests {
         ));
     }
 
+    #[test]
+    fn test_has_quantified_outcome_ranges_and_ratios() {
+        assert!(has_quantified_outcome(
+            "cut deploy time from 45 minutes to 8 minutes"
+        ));
+        assert!(has_quantified_outcome("cut costs in half"));
+        assert!(has_quantified_outcome("held sub-100ms latency"));
+        assert!(!has_quantified_outcome("led a team of 5 engineers"));
+    }
+
     // ── two_line_count ──────────────────────────────────────────────────────
 
     #[test]
@@ -0,0 +1,358 @@
+#![allow(dead_code)]
+//! Page Fill Remediation — executes `recommend_fill_action` until the page fits.
+//!
+//! Runs after the line simulation. Each pass analyzes the page, asks
+//! `recommend_fill_action_among` for one action, executes it, and re-measures:
+//! - `PromoteBullet`  → LLM expand of a 1-line bullet to two full lines; skipped once the
+//!   page already holds `MAX_TWO_LINE_BULLETS` two-line bullets
+//! - `CompressBullet` → local compressor first, then LLM, down to one line
+//! - `RemoveBullet`   → drop the bullet (no LLM call)
+//! - `TightenSpacing` / `NoAction` → nothing left to change in bullet text; the loop stops
+//!
+//! A rewrite is kept only if it satisfies the line contract at the target line count;
+//! otherwise it is discarded and that bullet is not picked again. Fixed content (the
+//! deterministic education lines) counts toward fill but is never modified.
+
+use std::collections::HashSet;
+
+use serde::{Deserialize, Serialize};
+use tracing::info;
+
+use crate::errors::AppError;
+use crate::generation::jd_parser::ParsedJD;
+use crate::layout::contract::{check_contract, LineCoverageVerdict, MAX_TWO_LINE_BULLETS};
+use crate::layout::font_metrics::{get_metrics, PageConfig};
+use crate::layout::local_compress::compress_locally;
+use crate::layout::page_fill::{
+    analyze_page_fill_with_reserved, recommend_fill_action_among, FillAction, PageFillAnalysis,
+    PageFillVerdict,
+};
+use crate::layout::simulator::{
+    adjust_bullets, demotion_request, estimate_char_budget, promotion_request, LineType,
+    RevisionCause, SimulatedBullet,
+};
+use crate::llm_client::LlmClient;
+
+/// Upper bound on executed remediation actions per resume.
+pub const MAX_FILL_PASSES: u8 = 5;
+
+/// Result of the remediation loop.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PageFillOutcome {
+    /// The editable bullets after remediation (fixed content is not included).
+    pub bullets: Vec<SimulatedBullet>,
+    /// Fill of the final page, fixed content and reserved lines included.
+    pub analysis: PageFillAnalysis,
+    /// Actions in the order they were attempted.
+    pub actions: Vec<FillAction>,
+    pub passes: u8,
+    pub llm_calls_made: u32,
+}
+
+/// Promotes, compresses or removes bullets until the page fill is Acceptable,
+/// no action applies, or `MAX_FILL_PASSES` actions have been executed.
+pub async fn run_page_fill_loop(
+    bullets: Vec<SimulatedBullet>,
+    fixed: &[SimulatedBullet],
+    reserved_lines: u16,
+    config: &PageConfig,
+    parsed_jd: &ParsedJD,
+    llm: &LlmClient,
+) -> Result<PageFillOutcome, AppError> {
+    let mut bullets = bullets;
+    let mut attempted: HashSet<usize> = HashSet::new();
+    let mut actions = Vec::new();
+    let mut passes = 0u8;
+    let mut llm_calls_made = 0u32;
+
+    while passes < MAX_FILL_PASSES {
+        let page = with_fixed(&bullets, fixed);
+        let analysis = analyze_page_fill_with_reserved(&page, reserved_lines, config);
+        if analysis.verdict == PageFillVerdict::Acceptable {
+            break;
+        }
+
+        let editable = bullets.len();
+        let action = recommend_fill_action_among(&analysis, &page, parsed_jd, |i| {
+            i < editable && !attempted.contains(&i)
+        });
+
+        match action {
+            FillAction::NoAction | FillAction::TightenSpacing => {
+                actions.push(action);
+                break;
+            }
+
+            FillAction::RemoveBullet { bullet_index } => {
+                bullets.remove(bullet_index);
+                attempted = attempted
+                    .into_iter()
+                    .filter(|&i| i != bullet_index)
+                    .map(|i| if i > bullet_index { i - 1 } else { i })
+                    .collect();
+            }
+
+            FillAction::PromoteBullet { bullet_index } => {
+                let two_line_total = bullets
+                    .iter()
+                    .filter(|b| b.line_type == LineType::Bullet && b.verified_line_count == 2)
+                    .count();
+                if two_line_total >= MAX_TWO_LINE_BULLETS {
+                    actions.push(FillAction::NoAction);
+                    break;
+                }
+                let (promoted, calls) = promote_bullet(
+                    &mut bullets[bullet_index],
+                    bullet_index,
+                    config,
+                    parsed_jd,
+                    llm,
+                )
+                .await;
+                llm_calls_made += calls;
+                if !promoted {
+                    attempted.insert(bullet_index);
+                }
+            }
+
+            FillAction::CompressBullet { bullet_index } => {
+                let (compressed, calls) = compress_to_one_line(
+                    &mut bullets[bullet_index],
+                    bullet_index,
+                    config,
+                    parsed_jd,
+                    llm,
+                )
+                .await;
+                llm_calls_made += calls;
+                if !compressed {
+                    attempted.insert(bullet_index);
+                }
+            }
+        }
+
+        actions.push(action);
+        passes += 1;
+    }
+
+    let analysis =
+        analyze_page_fill_with_reserved(&with_fixed(&bullets, fixed), reserved_lines, config);
+    if !actions.is_empty() {
+        info!(
+            passes,
+            llm_calls = llm_calls_made,
+            verdict = ?analysis.verdict,
+            actions = ?actions,
+            "Page fill remediation finished"
+        );
+    }
+
+    Ok(PageFillOutcome {
+        bullets,
+        analysis,
+        actions,
+        passes,
+        llm_calls_made,
+    })
+}
+
+// ────────────────────────────────────────────────────────────────────────────
+// Actions
+// ────────────────────────────────────────────────────────────────────────────
+
+/// Expands a 1-line bullet to two lines. Returns `(kept, llm_calls)`.
+async fn promote_bullet(
+    bullet: &mut SimulatedBullet,
+    index: usize,
+    config: &PageConfig,
+    parsed_jd: &ParsedJD,
+    llm: &LlmClient,
+) -> (bool, u32) {
+    let column_cfg = config.for_section(&bullet.section);
+    let metrics = get_metrics(&config.font);
+    let fill_ratio = check_contract(index, &bullet.text, metrics, &column_cfg).line1_fill;
+    let request = promotion_request(
+        index,
+        &bullet.text,
+        fill_ratio,
+        column_cfg.contract.min_one_line_fill,
+        estimate_char_budget(&column_cfg),
+    );
+
+    let (adjusted, calls) = adjust_bullets(&[request], parsed_jd, llm, 1).await;
+    let kept = adjusted
+        .get(&index)
+        .is_some_and(|text| accept_rewrite(bullet, text, 2, config));
+    (kept, calls)
+}
+
+/// Shrinks a multi-line bullet to one line, locally if possible. Returns `(kept, llm_calls)`.
+async fn compress_to_one_line(
+    bullet: &mut SimulatedBullet,
+    index: usize,
+    config: &PageConfig,
+    parsed_jd: &ParsedJD,
+    llm: &LlmClient,
+) -> (bool, u32) {
+    let local = compress_locally(&bullet.text);
+    if local != bullet.text && accept_rewrite(bullet, &local, 1, config) {
+        return (true, 0);
+    }
+
+    let char_budget = estimate_char_budget(&config.for_section(&bullet.section));
+    let mut request = demotion_request(index, &bullet.text, char_budget);
+    request.problem = "page overflows; compress to one printed line".to_string();
+    let (adjusted, calls) = adjust_bullets(&[request], parsed_jd, llm, 1).await;
+    let kept = adjusted
+        .get(&index)
+        .is_some_and(|text| accept_rewrite(bullet, text, 1, config));
+    (kept, calls)
+}
+
+/// Applies `text` if it satisfies the line contract at exactly `target_lines`.
+pub(crate) fn accept_rewrite(
+    bullet: &mut SimulatedBullet,
+    text: &str,
+    target_lines: u8,
+    config: &PageConfig,
+) -> bool {
+    let metrics = get_metrics(&config.font);
+    let result = check_contract(0, text, metrics, &config.for_section(&bullet.section));
+    if result.simulated_line_count != target_lines
+        || !matches!(result.verdict, LineCoverageVerdict::Satisfies)
+    {
+        return false;
+    }
+    let cause = if target_lines > bullet.verified_line_count {
+        RevisionCause::Expand
+    } else {
+        RevisionCause::Compress
+    };
+    bullet.revise(text.to_string(), cause);
+    bullet.verified_line_count = target_lines;
+    bullet.was_adjusted = true;
+    true
+}
+
+/// The printed page: editable bullets first, then fixed content.
+fn with_fixed(bullets: &[SimulatedBullet], fixed: &[SimulatedBullet]) -> Vec<SimulatedBullet> {
+    bullets.iter().chain(fixed.iter()).cloned().collect()
+}
+
+// ────────────────────────────────────────────────────────────────────────────
+// Tests
+// ────────────────────────────────────────────────────────────────────────────
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::layout::font_metrics::{default_page_config, FontFamily};
+    use uuid::Uuid;
+
+    fn make_bullet(text: &str, line_count: u8) -> SimulatedBullet {
+        SimulatedBullet {
+            text: text.to_string(),
+            source_entry_id: Uuid::new_v4(),
+            section: "experience".to_string(),
+            line_type: LineType::Bullet,
+            verified_line_count: line_count,
+            jd_keywords_used: vec![],
+            was_adjusted: false,
+            flagged_for_review: false,
+            revisions: Vec::new(),
+        }
+    }
+
+    /// A bullet that fills one line to ~90%.
+    fn one_line_text(config: &PageConfig) -> String {
+        let metrics = get_metrics(&config.font);
+        let mut text = "Built".to_string();
+        while metrics.measure_str(&format!("{text} infra")) < config.text_width_em * 0.9 {
+            text.push_str(" infra");
+        }
+        text
+    }
+
+    #[test]
+    fn test_accept_rewrite_requires_target_line_count() {
+        let config = default_page_config(FontFamily::Inter);
+        let text = one_line_text(&config);
+        let mut bullet = make_bullet("Placeholder", 2);
+
+        assert!(!accept_rewrite(&mut bullet, &text, 2, &config));
+        assert_eq!(
+            bullet.text, "Placeholder",
+            "rejected rewrite leaves the bullet alone"
+        );
+
+        assert!(accept_rewrite(&mut bullet, &text, 1, &config));
+        assert_eq!(bullet.text, text);
+        assert_eq!(bullet.verified_line_count, 1);
+        assert!(bullet.was_adjusted);
+        assert_eq!(
+            bullet.revisions.len(),
+            1,
+            "only the accepted rewrite is a revision"
+        );
+        assert_eq!(bullet.revisions[0].previous_text, "Placeholder");
+        assert_eq!(bullet.revisions[0].cause, RevisionCause::Compress);
+    }
+
+    #[test]
+    fn test_accept_rewrite_rejects_contract_violations() {
+        let config = default_page_config(FontFamily::Inter);
+        let mut bullet = make_bullet("Placeholder", 2);
+        assert!(
+            !accept_rewrite(&mut bullet, "Built tools", 1, &config),
+            "a too-short line is not an acceptable compression"
+        );
+    }
+
+    #[test]
+    fn test_with_fixed_appends_fixed_content() {
+        let bullets = vec![make_bullet("a", 1), make_bullet("b", 2)];
+        let fixed = vec![make_bullet("edu", 1)];
+        let page = with_fixed(&bullets, &fixed);
+        assert_eq!(page.len(), 3);
+        assert_eq!(page[2].text, "edu");
+    }
+
+    #[tokio::test]
+    async fn test_major_overflow_removes_bullets_without_llm_calls() {
+        let config = default_page_config(FontFamily::Inter); // 45 usable lines
+        let jd = ParsedJD {
+            hard_requirements: vec![],
+            soft_signals: vec![],
+            role_signals: templar_types::jd::RoleSignals {
+                is_startup: false,
+                is_ic_focused: true,
+                is_research: false,
+                seniority: "senior".to_string(),
+            },
+            keyword_inventory: vec![],
+            detected_tone: crate::generation::jd_parser::JDTone::CollaborativeEnterprise,
+        };
+        let text = one_line_text(&config);
+        let bullets: Vec<SimulatedBullet> = (0..46).map(|_| make_bullet(&text, 1)).collect();
+        let fixed = vec![make_bullet("B.S. Computer Science", 1)];
+
+        let outcome = run_page_fill_loop(
+            bullets,
+            &fixed,
+            0,
+            &config,
+            &jd,
+            &LlmClient::new(String::new()),
+        )
+        .await
+        .unwrap();
+
+        // 46 bullets + 1 fixed + 2 heading lines = 49 → 47: two removals reach
+        // MinorOverflow, which has no 2-line bullet to compress, so the loop ends on
+        // TightenSpacing.
+        assert_eq!(outcome.bullets.len(), 44);
+        assert_eq!(outcome.llm_calls_made, 0);
+        assert_eq!(outcome.analysis.verdict, PageFillVerdict::MinorOverflow);
+        assert_eq!(outcome.actions.last(), Some(&FillAction::TightenSpacing));
+    }
+}
@@ -1,17 +1,25 @@
-//! Static font-metric tables for 5 resume font families.
+//! Font-metric tables for 5 resume font families.
 #![allow(dead_code)]
 //!
-//! Character widths are in em units (relative to font size). This is an
-//! intentional approximation — the LaTeX Knuth-Plass algorithm (where to break lines) uses exact
-//! glyph metrics (actual shape of characters), but static tables catch real violations
-//! (3-line bullets, 30%-fill bullets) while tolerating borderline ambiguity (±1–2% of line width).
+//! Character widths are in em units (relative to font size). At startup,
+//! `ttf_metrics::load_font_metrics` parses the real font files (advance widths + kerning
+//! pairs) and installs them here; `get_metrics` prefers those tables.
 //!
-//! The simulation loop + microtype 3% safety margin absorbs the residual error.
+//! The hand-tuned static tables below are the fallback when a font file is not
+//! installed (local dev, CI). They are an approximation — they catch real violations
+//! (3-line bullets, 30%-fill bullets) while tolerating borderline ambiguity (±1–2% of
+//! line width), which the simulation loop + microtype 3% safety margin absorbs.
 //! All tables cover ASCII 0x20..=0x7E (95 printable characters).
 //! Index = (char as usize) - 32.
 
+use std::borrow::Cow;
+use std::collections::HashMap;
+use std::sync::OnceLock;
+
 use serde::{Deserialize, Serialize};
 
+use crate::errors::AppError;
+
 // ────────────────────────────────────────────────────────────────────────────
 // Font family enum
 // ────────────────────────────────────────────────────────────────────────────
@@ -31,43 +39,466 @@ pub enum FontFamily {
     ComputerModern,
 }
 
+impl FontFamily {
+    pub const ALL: [FontFamily; 5] = [
+        FontFamily::Inter,
+        FontFamily::EbGaramond,
+        FontFamily::Lato,
+        FontFamily::Oswald,
+        FontFamily::ComputerModern,
+    ];
+
+    /// Value stored in `resumes.font_family`.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            FontFamily::Inter => "inter",
+            FontFamily::EbGaramond => "eb_garamond",
+            FontFamily::Lato => "lato",
+            FontFamily::Oswald => "oswald",
+            FontFamily::ComputerModern => "computer_modern",
+        }
+    }
+
+    /// Parses a stored `resumes.font_family` value.
+    pub fn parse(s: &str) -> Option<Self> {
+        FontFamily::ALL.into_iter().find(|f| f.as_str() == s)
+    }
+}
+
 // ────────────────────────────────────────────────────────────────────────────
 // Page configuration
 // ────────────────────────────────────────────────────────────────────────────
 
+/// Line-breaking algorithm used to predict where a bullet wraps.
+///
+/// LaTeX breaks paragraphs with Knuth-Plass (total-fit), so greedy predictions can
+/// disagree with the rendered PDF on borderline bullets — mostly lines that TeX fits
+/// by shrinking interword glue. Greedy stays the default until the Knuth-Plass
+/// predictions have been checked against rendered output.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum LineBreaking {
+    /// First-fit word wrap: each line takes as many words as fit at natural width.
+    #[default]
+    Greedy,
+    /// TeX-style total-fit breaking with badness and demerits (`contract::simulate_lines_knuth_plass`).
+    KnuthPlass,
+}
+
+/// TeX points per inch.
+const PT_PER_IN: f32 = 72.27;
+
+/// Vertical pitch of one printed line as a multiple of the font size: LaTeX's
+/// baselineskip (≈1.2× at 10–12pt) plus the ~1pt itemsep between bullets.
+const LINE_PITCH_FACTOR: f32 = 1.3;
+
+/// Physical paper size. Letter for US/Canada, A4 nearly everywhere else.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum PaperSize {
+    #[default]
+    Letter,
+    A4,
+}
+
+impl PaperSize {
+    /// `(width, height)` in inches.
+    pub fn dimensions_in(&self) -> (f32, f32) {
+        match self {
+            PaperSize::Letter => (8.5, 11.0),
+            // 210mm × 297mm
+            PaperSize::A4 => (8.268, 11.693),
+        }
+    }
+
+    /// The `\documentclass` option selecting this paper size.
+    pub fn latex_option(&self) -> &'static str {
+        match self {
+            PaperSize::Letter => "letterpaper",
+            PaperSize::A4 => "a4paper",
+        }
+    }
+
+    /// Value stored in `resumes.paper_size`.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            PaperSize::Letter => "letter",
+            PaperSize::A4 => "a4",
+        }
+    }
+
+    /// Parses a stored `resumes.paper_size` value.
+    pub fn parse(s: &str) -> Option<Self> {
+        match s {
+            "letter" => Some(PaperSize::Letter),
+            "a4" => Some(PaperSize::A4),
+            _ => None,
+        }
+    }
+}
+
+/// Page margin preset, applied to all four sides.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum MarginPreset {
+    /// 0.7" — fits more content on dense resumes.
+    Compact,
+    /// 1.0" — the layout model's original assumption.
+    #[default]
+    Standard,
+    /// 1.2" — more white space around short resumes.
+    Airy,
+}
+
+impl MarginPreset {
+    /// Margin on every side, in inches.
+    pub fn inches(&self) -> f32 {
+        match self {
+            MarginPreset::Compact => 0.7,
+            MarginPreset::Standard => 1.0,
+            MarginPreset::Airy => 1.2,
+        }
+    }
+
+    /// Value stored in `resumes.margin_preset`.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            MarginPreset::Compact => "compact",
+            MarginPreset::Standard => "standard",
+            MarginPreset::Airy => "airy",
+        }
+    }
+
+    /// Parses a stored `resumes.margin_preset` value.
+    pub fn parse(s: &str) -> Option<Self> {
+        match s {
+            "compact" => Some(MarginPreset::Compact),
+            "standard" => Some(MarginPreset::Standard),
+            "airy" => Some(MarginPreset::Airy),
+            _ => None,
+        }
+    }
+}
+
+/// Which column of a two-column page a section is set in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Column {
+    /// The wide column (experience, projects, ...). Single-column pages only have this one.
+    Main,
+    /// The narrow sidebar column.
+    Sidebar,
+}
+
+/// Two-column page: a narrow sidebar (skills, education) beside the main column.
+///
+/// Column widths are derived by `PageConfig::recompute_geometry`: the text width minus
+/// the gap is split `sidebar_fraction` / `1 − sidebar_fraction`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TwoColumnLayout {
+    /// Share of the usable width (after the gap) given to the sidebar.
+    pub sidebar_fraction: f32,
+    /// Horizontal gap between the columns, in inches.
+    pub column_gap_in: f32,
+    /// Sections set in the sidebar (case-insensitive). Everything else goes in the main column.
+    pub sidebar_sections: Vec<String>,
+    /// Derived sidebar width in em units.
+    pub sidebar_width_em: f32,
+    /// Derived main column width in em units.
+    pub main_width_em: f32,
+}
+
+impl TwoColumnLayout {
+    /// A one-third sidebar holding skills and education, 0.25" gutter.
+    pub fn default_sidebar() -> Self {
+        TwoColumnLayout {
+            sidebar_fraction: 0.32,
+            column_gap_in: 0.25,
+            sidebar_sections: vec!["skills".to_string(), "education".to_string()],
+            sidebar_width_em: 0.0,
+            main_width_em: 0.0,
+        }
+    }
+}
+
 /// Layout parameters for a single resume page.
 ///
-/// `text_width_em` is the usable text width in em units at the given font size.
+/// `text_width_em` and `usable_height_lines` are derived from paper size, margins, and
+/// font size (`recompute_geometry`) — never set them by hand.
 /// Example: US letter paper, 1" margins, 11pt → 6.5" × (72.27pt/in ÷ 11pt) ≈ 42.7em.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct PageConfig {
     pub font: FontFamily,
-    pub font_size_pt: u8,
+    /// Body font size. Half-point steps (10.5, 11.5) come from the page-fill fallback.
+    pub font_size_pt: f32,
+    #[serde(default)]
+    pub paper_size: PaperSize,
+    /// The preset the four margins below were set from.
+    #[serde(default)]
+    pub margin_preset: MarginPreset,
     /// Usable text width in em units (derived from paper size, margins, and font size).
     pub text_width_em: f32,
     pub margin_left_in: f32,
     pub margin_right_in: f32,
+    pub margin_top_in: f32,
+    pub margin_bottom_in: f32,
     /// Total line slots available on a single-page resume (includes section headers, spacing).
     pub usable_height_lines: u16,
     /// LaTeX microtype expansion tolerance (typically 0.03 = 3%).
     /// Acts as a safety margin that absorbs small approximation errors in the metric tables.
     pub microtype_margin: f32,
+    /// Line-breaking algorithm for wrap prediction.
+    #[serde(default)]
+    pub line_breaking: LineBreaking,
+    /// Two-column layout. None = single column spanning `text_width_em`.
+    #[serde(default)]
+    pub columns: Option<TwoColumnLayout>,
+    /// Lines charged for section headers, entry headers and inter-section spacing.
+    #[serde(default)]
+    pub line_costs: LineCosts,
+    /// Minimum line fills of the line coverage contract.
+    #[serde(default)]
+    pub contract: ContractThresholds,
+}
+
+/// Line cost of page structure that is not bullet text, in body-text lines.
+///
+/// Defaults follow the bundled templates: a section title plus its rule and
+/// `\titlespacing` (6pt above, 4pt below) takes about 1.5 lines, sections are
+/// separated by half a line, and no template prints a per-entry heading yet.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct LineCosts {
+    /// Per section heading.
+    pub section_header: f32,
+    /// Per distinct source entry within a section (company/role line).
+    pub entry_header: f32,
+    /// Per gap between two consecutive sections.
+    pub section_gap: f32,
+}
+
+impl Default for LineCosts {
+    fn default() -> Self {
+        LineCosts {
+            section_header: 1.5,
+            entry_header: 0.0,
+            section_gap: 0.5,
+        }
+    }
+}
+
+/// Minimum fills enforced by `contract::check_contract`, as fractions of the line width.
+///
+/// How full a line must be before it stops looking ragged depends on the face:
+/// condensed Oswald sets more characters per em, so a 75% line already reads as full.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct ContractThresholds {
+    /// A 1-line bullet must fill at least this much of its line.
+    pub min_one_line_fill: f32,
+    /// The second line of a 2-line bullet must fill at least this much.
+    pub min_second_line_fill: f32,
 }
 
+/// Lowest accepted threshold. Below half a line the contract no longer means anything.
+pub const MIN_CONTRACT_THRESHOLD: f32 = 0.5;
+
+impl ContractThresholds {
+    /// Validated thresholds: both in `[MIN_CONTRACT_THRESHOLD, 1.0]`.
+    pub fn new(min_one_line_fill: f32, min_second_line_fill: f32) -> Result<Self, AppError> {
+        let thresholds = ContractThresholds {
+            min_one_line_fill,
+            min_second_line_fill,
+        };
+        thresholds.validate()?;
+        Ok(thresholds)
+    }
+
+    /// Defaults for `font`.
+    pub fn for_font(font: FontFamily) -> Self {
+        match font {
+            FontFamily::Oswald => ContractThresholds {
+                min_one_line_fill: 0.75,
+                min_second_line_fill: 0.65,
+            },
+            _ => ContractThresholds::default(),
+        }
+    }
+
+    pub fn validate(&self) -> Result<(), AppError> {
+        for (name, value) in [
+            ("min_one_line_fill", self.min_one_line_fill),
+            ("min_second_line_fill", self.min_second_line_fill),
+        ] {
+            if !(MIN_CONTRACT_THRESHOLD..=1.0).contains(&value) {
+                return Err(AppError::Validation(format!(
+                    "{name} must be between {MIN_CONTRACT_THRESHOLD} and 1.0, got {value}"
+                )));
+            }
+        }
+        Ok(())
+    }
+}
+
+impl Default for ContractThresholds {
+    fn default() -> Self {
+        ContractThresholds {
+            min_one_line_fill: 0.80,
+            min_second_line_fill: 0.70,
+        }
+    }
+}
+
+impl PageConfig {
+    /// Recomputes `text_width_em` and `usable_height_lines` from paper size, margins,
+    /// and font size. Call after changing any of them.
+    ///
+    /// text_width_em      = (paper width − left − right) × 72.27 ÷ font_size_pt
+    /// usable_height_lines = ⌊(paper height − top − bottom) × 72.27 ÷ (font_size_pt × 1.3)⌋
+    pub fn recompute_geometry(&mut self) {
+        let (width_in, height_in) = self.paper_size.dimensions_in();
+        let font_pt = self.font_size_pt.max(1.0);
+
+        let text_width_in = (width_in - self.margin_left_in - self.margin_right_in).max(0.0);
+        self.text_width_em = text_width_in * PT_PER_IN / font_pt;
+
+        let text_height_in = (height_in - self.margin_top_in - self.margin_bottom_in).max(0.0);
+        self.usable_height_lines =
+            (text_height_in * PT_PER_IN / (font_pt * LINE_PITCH_FACTOR)).floor() as u16;
+
+        if let Some(columns) = self.columns.as_mut() {
+            let split_in = (text_width_in - columns.column_gap_in).max(0.0);
+            let fraction = columns.sidebar_fraction.clamp(0.0, 1.0);
+            columns.sidebar_width_em = split_in * fraction * PT_PER_IN / font_pt;
+            columns.main_width_em = split_in * (1.0 - fraction) * PT_PER_IN / font_pt;
+        }
+    }
+
+    /// Returns a copy of this config laid out in two columns, widths derived.
+    pub fn with_two_columns(&self, columns: TwoColumnLayout) -> PageConfig {
+        let mut config = self.clone();
+        config.columns = Some(columns);
+        config.recompute_geometry();
+        config
+    }
+
+    /// The column a section is set in. Always `Main` on a single-column page.
+    pub fn column_for_section(&self, section: &str) -> Column {
+        match &self.columns {
+            Some(columns)
+                if columns
+                    .sidebar_sections
+                    .iter()
+                    .any(|s| s.eq_ignore_ascii_case(section)) =>
+            {
+                Column::Sidebar
+            }
+            _ => Column::Main,
+        }
+    }
+
+    /// Line width in em units for bullets set in `column`.
+    pub fn column_width_em(&self, column: Column) -> f32 {
+        match (&self.columns, column) {
+            (None, _) => self.text_width_em,
+            (Some(columns), Column::Main) => columns.main_width_em,
+            (Some(columns), Column::Sidebar) => columns.sidebar_width_em,
+        }
+    }
+
+    /// The config to measure a `section` bullet against: same font and height, with
+    /// `text_width_em` narrowed to the owning column. Borrowed on single-column pages.
+    pub fn for_section(&self, section: &str) -> Cow<'_, PageConfig> {
+        if self.columns.is_none() {
+            return Cow::Borrowed(self);
+        }
+        let mut config = self.clone();
+        config.text_width_em = self.column_width_em(self.column_for_section(section));
+        config.columns = None;
+        Cow::Owned(config)
+    }
+
+    /// Returns a copy of this config on a different paper size, geometry re-derived.
+    pub fn with_paper_size(&self, paper_size: PaperSize) -> PageConfig {
+        let mut config = self.clone();
+        config.paper_size = paper_size;
+        config.recompute_geometry();
+        config
+    }
+
+    /// Returns a copy of this config with all four margins set from `preset`, geometry
+    /// re-derived.
+    pub fn with_margin_preset(&self, preset: MarginPreset) -> PageConfig {
+        let mut config = self.clone();
+        let margin_in = preset.inches();
+        config.margin_preset = preset;
+        config.margin_left_in = margin_in;
+        config.margin_right_in = margin_in;
+        config.margin_top_in = margin_in;
+        config.margin_bottom_in = margin_in;
+        config.recompute_geometry();
+        config
+    }
+
+    /// Returns a copy of this config at a different font size, geometry re-derived.
+    pub fn with_font_size(&self, font_size_pt: f32) -> PageConfig {
+        let mut config = self.clone();
+        config.font_size_pt = font_size_pt;
+        config.recompute_geometry();
+        config
+    }
+
+    /// The next step on `FONT_SIZE_STEPS_PT` below (`smaller`) or above the current size.
+    /// `None` at the end of the ladder.
+    pub fn next_font_size(&self, smaller: bool) -> Option<f32> {
+        if smaller {
+            FONT_SIZE_STEPS_PT
+                .iter()
+                .rev()
+                .find(|&&pt| pt < self.font_size_pt - 0.01)
+                .copied()
+        } else {
+            FONT_SIZE_STEPS_PT
+                .iter()
+                .find(|&&pt| pt > self.font_size_pt + 0.01)
+                .copied()
+        }
+    }
+}
+
+/// Body font size of a fresh page config.
+pub const DEFAULT_FONT_SIZE_PT: f32 = 11.0;
+
+/// Font sizes the page-fill fallback may step through, smallest first.
+pub const FONT_SIZE_STEPS_PT: [f32; 5] = [10.0, 10.5, 11.0, 11.5, 12.0];
+
 /// Returns the default page config for the given font family.
 ///
 /// Assumes: US letter (8.5" × 11"), 11pt font, 1.0" margins all sides.
 /// text_width_em = 6.5" × (72.27pt/in ÷ 11pt) ≈ 42.7em.
 pub fn default_page_config(font: FontFamily) -> PageConfig {
-    PageConfig {
+    page_config_for_paper(font, PaperSize::Letter)
+}
+
+/// Page config for the given font on the given paper: 11pt, 1.0" margins all sides.
+pub fn page_config_for_paper(font: FontFamily, paper_size: PaperSize) -> PageConfig {
+    let mut config = PageConfig {
         font,
-        font_size_pt: 11,
-        text_width_em: 42.7,
+        font_size_pt: DEFAULT_FONT_SIZE_PT,
+        paper_size,
+        margin_preset: MarginPreset::Standard,
+        text_width_em: 0.0,
         margin_left_in: 1.0,
         margin_right_in: 1.0,
-        usable_height_lines: 45,
+        margin_top_in: 1.0,
+        margin_bottom_in: 1.0,
+        usable_height_lines: 0,
         microtype_margin: 0.03,
-    }
+        line_breaking: LineBreaking::Greedy,
+        columns: None,
+        line_costs: LineCosts::default(),
+        contract: ContractThresholds::for_font(font),
+    };
+    config.recompute_geometry();
+    config
 }
 
 // ────────────────────────────────────────────────────────────────────────────
@@ -96,23 +527,54 @@ pub struct FontMetricTable {
     /// Fallback width for non-ASCII characters (codepoints > 0x7E).
     pub average_char_width: f32,
     pub space_width: f32,
+    /// Kerning adjustments (em units, usually negative) for adjacent ASCII pairs.
+    /// `None` for the static tables; populated when loaded from a font file.
+    pub kerning: Option<HashMap<(char, char), f32>>,
 }
 
 impl FontMetricTable {
-    /// Measures the rendered width of a string in em units.
+    /// Builds a table from measured values. Used by the TTF loader.
+    pub(crate) fn from_parts(
+        font: FontFamily,
+        widths: [f32; 95],
+        average_char_width: f32,
+        space_width: f32,
+        kerning: HashMap<(char, char), f32>,
+    ) -> Self {
+        Self {
+            font,
+            widths,
+            average_char_width,
+            space_width,
+            kerning: (!kerning.is_empty()).then_some(kerning),
+        }
+    }
+
+    /// Width of a single character in em units (no kerning).
+    pub fn char_width(&self, c: char) -> f32 {
+        let code = c as usize;
+        if (32..=126).contains(&code) {
+            self.widths[code - 32]
+        } else {
+            self.average_char_width
+        }
+    }
+
+    /// Measures the rendered width of a string in em units, including kerning when known.
     ///
     /// Non-ASCII characters fall back to `average_char_width`.
     pub fn measure_str(&self, s: &str) -> f32 {
-        s.chars()
-            .map(|c| {
-                let code = c as usize;
-                if (32..=126).contains(&code) {
-                    self.widths[code - 32]
-                } else {
-                    self.average_char_width
-                }
-            })
-            .sum()
+        let base: f32 = s.chars().map(|c| self.char_width(c)).sum();
+        match &self.kerning {
+            None => base,
+            Some(pairs) => {
+                base + s
+                    .chars()
+                    .zip(s.chars().skip(1))
+                    .filter_map(|pair| pairs.get(&pair))
+                    .sum::<f32>()
+            }
+        }
     }
 
     /// Returns the fraction of the text width that this string occupies on a single line.
@@ -125,31 +587,9 @@ impl FontMetricTable {
 
     /// Estimates how many printed lines this string occupies when word-wrapped at `config.text_width_em`.
     ///
-    /// Uses greedy word-wrap — same algorithm as `simulate_lines` in `contract.rs`.
+    /// Delegates to `simulate_lines` in `contract.rs`, so it honours `config.line_breaking`.
     pub fn estimated_lines(&self, s: &str, config: &PageConfig) -> u8 {
-        let words: Vec<&str> = s.split_whitespace().collect();
-        if words.is_empty() {
-            return 0;
-        }
-        let max_width = config.text_width_em;
-        let mut line_count = 1u8;
-        let mut current_width = 0.0_f32;
-        let mut first = true;
-
-        for word in &words {
-            let word_w = self.measure_str(word);
-            let space_w = if first { 0.0 } else { self.space_width };
-
-            if !first && current_width + space_w + word_w > max_width {
-                line_count = line_count.saturating_add(1);
-                current_width = word_w;
-                // first stays false — next word on new line will get a space
-            } else {
-                current_width += space_w + word_w;
-                first = false;
-            }
-        }
-        line_count
+        crate::layout::contract::simulate_lines(s, self, config).0
     }
 }
 
@@ -183,6 +623,7 @@ static INTER_TABLE: FontMetricTable = FontMetricTable {
     ],
     average_char_width: 0.52,
     space_width: 0.25,
+    kerning: None,
 };
 
 /// EB Garamond — old-style serif (Researcher template). Approx. 85% of Inter.
@@ -211,6 +652,7 @@ static EB_GARAMOND_TABLE: FontMetricTable = FontMetricTable {
     ],
     average_char_width: 0.44,
     space_width: 0.21,
+    kerning: None,
 };
 
 /// Lato — geometric humanist sans-serif (Operator template). Approx. 105% of Inter.
@@ -239,6 +681,7 @@ static LATO_TABLE: FontMetricTable = FontMetricTable {
     ],
     average_char_width: 0.55,
     space_width: 0.26,
+    kerning: None,
 };
 
 /// Oswald — condensed display sans-serif (Founder template). Approx. 68% of Inter.
@@ -267,6 +710,7 @@ static OSWALD_TABLE: FontMetricTable = FontMetricTable {
     ],
     average_char_width: 0.35,
     space_width: 0.17,
+    kerning: None,
 };
 
 /// Computer Modern — traditional TeX font (Classic/ATS-safe template). Approx. 90% of Inter.
@@ -295,10 +739,28 @@ static COMPUTER_MODERN_TABLE: FontMetricTable = FontMetricTable {
     ],
     average_char_width: 0.47,
     space_width: 0.23,
+    kerning: None,
 };
 
-/// Returns the static metric table for a given font family.
+/// Metric tables parsed from real font files at startup (see `ttf_metrics`).
+static LOADED_METRICS: OnceLock<HashMap<FontFamily, FontMetricTable>> = OnceLock::new();
+
+/// Installs font-file metrics. Only the first call wins; returns false if already installed.
+pub fn install_loaded_metrics(tables: HashMap<FontFamily, FontMetricTable>) -> bool {
+    LOADED_METRICS.set(tables).is_ok()
+}
+
+/// Returns the metric table for a font family: loaded from the font file when available,
+/// otherwise the static fallback table.
 pub fn get_metrics(font: &FontFamily) -> &'static FontMetricTable {
+    LOADED_METRICS
+        .get()
+        .and_then(|loaded| loaded.get(font))
+        .unwrap_or_else(|| static_metrics(font))
+}
+
+/// Returns the hand-tuned static table for a font family.
+pub fn static_metrics(font: &FontFamily) -> &'static FontMetricTable {
     match font {
         FontFamily::Inter => &INTER_TABLE,
         FontFamily::EbGaramond => &EB_GARAMOND_TABLE,
@@ -392,6 +854,7 @@ mod tests {
     }
 
     #[test]
+    #[allow(clippy::manual_range_contains)]
     fn test_estimated_lines_long_text_wraps_to_two() {
         let metrics = get_metrics(&FontFamily::Inter);
         let config = make_config(FontFamily::Inter);
@@ -433,9 +896,203 @@ mod tests {
     fn test_default_page_config_sanity() {
         let config = default_page_config(FontFamily::Inter);
         assert_eq!(config.font, FontFamily::Inter);
-        assert_eq!(config.font_size_pt, 11);
+        assert_eq!(config.font_size_pt, 11.0);
         assert!(config.text_width_em > 40.0 && config.text_width_em < 50.0);
         assert!(config.usable_height_lines > 30);
         assert!((config.microtype_margin - 0.03).abs() < 1e-4);
     }
+
+    #[test]
+    fn test_kerning_pairs_adjust_measured_width() {
+        let base = static_metrics(&FontFamily::Inter);
+        let mut widths = [0.0_f32; 95];
+        for (i, w) in widths.iter_mut().enumerate() {
+            *w = base.char_width((32 + i as u8) as char);
+        }
+        let kerned = FontMetricTable::from_parts(
+            FontFamily::Inter,
+            widths,
+            base.average_char_width,
+            base.space_width,
+            HashMap::from([(('A', 'V'), -0.08)]),
+        );
+        let plain = base.measure_str("AVA");
+        assert!((kerned.measure_str("AVA") - (plain - 0.08)).abs() < 1e-5);
+        // Pairs split by a space are not kerned
+        assert!((kerned.measure_str("A V") - base.measure_str("A V")).abs() < 1e-5);
+    }
+
+    #[test]
+    fn test_letter_geometry_matches_documented_defaults() {
+        let config = default_page_config(FontFamily::Inter);
+        assert_eq!(config.paper_size, PaperSize::Letter);
+        assert!(
+            (config.text_width_em - 42.7).abs() < 0.05,
+            "{}",
+            config.text_width_em
+        );
+        assert_eq!(config.usable_height_lines, 45);
+    }
+
+    #[test]
+    fn test_a4_is_narrower_and_taller_than_letter() {
+        let letter = default_page_config(FontFamily::Inter);
+        let a4 = page_config_for_paper(FontFamily::Inter, PaperSize::A4);
+        // 210mm − 2" = 6.27" → 41.2em at 11pt
+        assert!(
+            (a4.text_width_em - 41.2).abs() < 0.05,
+            "{}",
+            a4.text_width_em
+        );
+        assert!(a4.text_width_em < letter.text_width_em);
+        assert!(a4.usable_height_lines > letter.usable_height_lines);
+    }
+
+    #[test]
+    fn test_geometry_tracks_font_size_and_margins() {
+        let mut config = default_page_config(FontFamily::Lato);
+        config.font_size_pt = 10.0;
+        config.margin_left_in = 0.75;
+        config.margin_right_in = 0.75;
+        config.recompute_geometry();
+        // 7.0" at 10pt
+        assert!((config.text_width_em - 50.589).abs() < 0.01);
+        assert_eq!(config.usable_height_lines, 50);
+    }
+
+    #[test]
+    fn test_with_paper_size_rederives_geometry() {
+        let letter = default_page_config(FontFamily::Inter);
+        let a4 = letter.with_paper_size(PaperSize::A4);
+        assert_eq!(a4.paper_size, PaperSize::A4);
+        let expected = page_config_for_paper(FontFamily::Inter, PaperSize::A4);
+        assert_eq!(a4.text_width_em, expected.text_width_em);
+        assert_eq!(a4.usable_height_lines, expected.usable_height_lines);
+    }
+
+    #[test]
+    fn test_margin_presets_rederive_geometry() {
+        let standard = default_page_config(FontFamily::Inter);
+        assert_eq!(
+            standard
+                .with_margin_preset(MarginPreset::Standard)
+                .text_width_em,
+            standard.text_width_em
+        );
+
+        let compact = standard.with_margin_preset(MarginPreset::Compact);
+        assert_eq!(compact.margin_top_in, 0.7);
+        // 8.5" − 1.4" = 7.1" at 11pt
+        assert!((compact.text_width_em - 46.65).abs() < 0.01);
+        assert!(compact.usable_height_lines > standard.usable_height_lines);
+
+        let airy = standard.with_margin_preset(MarginPreset::Airy);
+        assert_eq!(airy.margin_preset, MarginPreset::Airy);
+        assert!(airy.text_width_em < standard.text_width_em);
+        assert!(airy.usable_height_lines < standard.usable_height_lines);
+    }
+
+    #[test]
+    fn test_margin_preset_round_trips_through_db_value() {
+        for preset in [
+            MarginPreset::Compact,
+            MarginPreset::Standard,
+            MarginPreset::Airy,
+        ] {
+            assert_eq!(MarginPreset::parse(preset.as_str()), Some(preset));
+        }
+        assert_eq!(MarginPreset::parse("narrow"), None);
+    }
+
+    #[test]
+    fn test_paper_size_round_trips_through_db_value() {
+        for paper in [PaperSize::Letter, PaperSize::A4] {
+            assert_eq!(PaperSize::parse(paper.as_str()), Some(paper));
+        }
+        assert_eq!(PaperSize::parse("legal"), None);
+    }
+
+    #[test]
+    fn test_font_family_round_trips_through_db_value() {
+        for font in FontFamily::ALL {
+            assert_eq!(FontFamily::parse(font.as_str()), Some(font));
+        }
+        assert_eq!(FontFamily::parse("Inter"), None);
+    }
+
+    #[test]
+    fn test_two_column_widths_split_text_width_minus_gap() {
+        let config = default_page_config(FontFamily::Inter).with_two_columns(TwoColumnLayout {
+            sidebar_fraction: 0.25,
+            ..TwoColumnLayout::default_sidebar()
+        });
+        let columns = config.columns.as_ref().unwrap();
+        let gap_em = 0.25 * 72.27 / 11.0;
+        let total = columns.sidebar_width_em + columns.main_width_em + gap_em;
+        assert!((total - config.text_width_em).abs() < 1e-3);
+        assert!((columns.main_width_em - 3.0 * columns.sidebar_width_em).abs() < 1e-3);
+    }
+
+    #[test]
+    fn test_for_section_narrows_to_owning_column() {
+        let config = default_page_config(FontFamily::Inter)
+            .with_two_columns(TwoColumnLayout::default_sidebar());
+        assert_eq!(config.column_for_section("Skills"), Column::Sidebar);
+        assert_eq!(config.column_for_section("experience"), Column::Main);
+
+        let sidebar = config.for_section("education");
+        assert_eq!(
+            sidebar.text_width_em,
+            config.column_width_em(Column::Sidebar)
+        );
+        assert!(sidebar.columns.is_none());
+        assert_eq!(sidebar.usable_height_lines, config.usable_height_lines);
+    }
+
+    #[test]
+    fn test_single_column_for_section_is_unchanged() {
+        let config = default_page_config(FontFamily::Inter);
+        assert!(matches!(config.for_section("skills"), Cow::Borrowed(_)));
+        assert_eq!(config.column_for_section("skills"), Column::Main);
+        assert_eq!(
+            config.column_width_em(Column::Sidebar),
+            config.text_width_em
+        );
+    }
+
+    #[test]
+    fn test_contract_thresholds_default_per_font() {
+        let inter = default_page_config(FontFamily::Inter);
+        assert_eq!(inter.contract, ContractThresholds::default());
+        let oswald = default_page_config(FontFamily::Oswald);
+        assert_eq!(oswald.contract.min_one_line_fill, 0.75);
+        assert!(oswald.contract.min_second_line_fill < inter.contract.min_second_line_fill);
+    }
+
+    #[test]
+    fn test_contract_thresholds_are_validated() {
+        assert!(ContractThresholds::new(0.85, 0.6).is_ok());
+        assert!(ContractThresholds::new(1.2, 0.7).is_err());
+        assert!(ContractThresholds::new(0.8, 0.2).is_err());
+        assert!(ContractThresholds::new(f32::NAN, 0.7).is_err());
+    }
+
+    #[test]
+    fn test_font_size_ladder_steps_in_half_points() {
+        let config = default_page_config(FontFamily::Inter);
+        assert_eq!(config.next_font_size(true), Some(10.5));
+        assert_eq!(config.next_font_size(false), Some(11.5));
+
+        let smallest = config.with_font_size(10.0);
+        assert_eq!(smallest.next_font_size(true), None);
+        assert_eq!(config.with_font_size(12.0).next_font_size(false), None);
+    }
+
+    #[test]
+    fn test_smaller_font_fits_more_lines_and_wider_text() {
+        let config = default_page_config(FontFamily::Inter);
+        let smaller = config.with_font_size(10.5);
+        assert!(smaller.text_width_em > config.text_width_em);
+        assert!(smaller.usable_height_lines > config.usable_height_lines);
+    }
 }
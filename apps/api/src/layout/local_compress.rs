@@ -0,0 +1,217 @@
+#![allow(dead_code)]
+//! Deterministic bullet compression — tried before any LLM compress call.
+//!
+//! Applies the same removals the compress prompt asks the model for:
+//! - redundant context phrases ("in order to" → "to", "as a result of" → "from")
+//! - soft qualifiers ("various", "multiple", "significant", ...)
+//! - verbose prepositional phrases ("prior to" → "before", "in excess of" → "over")
+//!
+//! Matching is whole-word and case-insensitive. Quantities, tools and the opening verb
+//! are never touched, so the rewrite is always safe to keep. The simulator re-checks the
+//! contract afterwards and only escalates to the LLM if the bullet still violates it.
+
+// ────────────────────────────────────────────────────────────────────────────
+// Phrase lists
+// ────────────────────────────────────────────────────────────────────────────
+
+/// Redundant context phrases and their shorter replacements.
+pub const FILLER_PHRASES: &[(&str, &str)] = &[
+    ("in order to", "to"),
+    ("so as to", "to"),
+    ("in an effort to", "to"),
+    ("as a result of", "from"),
+    ("due to the fact that", "because"),
+    ("for the purpose of", "for"),
+    ("in the process of", ""),
+    ("on a daily basis", "daily"),
+    ("on a regular basis", "regularly"),
+    ("at the same time", ""),
+    ("a total of", ""),
+];
+
+/// Soft qualifiers dropped outright.
+pub const SOFT_QUALIFIERS: &[&str] = &[
+    "various",
+    "multiple",
+    "significant",
+    "significantly",
+    "successfully",
+    "effectively",
+    "very",
+    "really",
+    "several",
+    "numerous",
+];
+
+/// Verbose prepositional phrases and their one-word equivalents.
+pub const VERBOSE_PREPOSITIONS: &[(&str, &str)] = &[
+    ("prior to", "before"),
+    ("subsequent to", "after"),
+    ("in excess of", "over"),
+    ("in conjunction with", "with"),
+    ("with regard to", "on"),
+    ("with respect to", "on"),
+    ("in the context of", "in"),
+    ("by means of", "via"),
+    ("a wide range of", ""),
+    ("a variety of", ""),
+    ("a number of", ""),
+];
+
+// ────────────────────────────────────────────────────────────────────────────
+// Compressor
+// ────────────────────────────────────────────────────────────────────────────
+
+/// Trailing punctuation carried over when the word it is attached to is rewritten.
+const TRAILING_PUNCT: &[char] = &[',', ';', ':', '.'];
+
+/// Shortens a bullet by applying every phrase list. Returns the input unchanged
+/// when nothing matches.
+pub fn compress_locally(text: &str) -> String {
+    let words: Vec<&str> = text.split_whitespace().collect();
+    let mut out: Vec<String> = Vec::with_capacity(words.len());
+    let mut changed = false;
+    let mut i = 0;
+
+    while i < words.len() {
+        match match_rule(&words[i..]) {
+            Some((consumed, replacement, punct)) => {
+                if replacement.is_empty() {
+                    // Dropped phrase: keep its punctuation on the previous word.
+                    if let (Some(prev), false) = (out.last_mut(), punct.is_empty()) {
+                        prev.push_str(punct);
+                    }
+                } else {
+                    out.push(format!("{replacement}{punct}"));
+                }
+                i += consumed;
+                changed = true;
+            }
+            None => {
+                out.push(words[i].to_string());
+                i += 1;
+            }
+        }
+    }
+
+    if !changed {
+        return text.to_string();
+    }
+
+    let mut result = out.join(" ");
+    if text.trim_start().starts_with(char::is_uppercase) {
+        result = capitalize_first(&result);
+    }
+    result
+}
+
+/// Finds the first rule matching at the start of `words`.
+/// Returns `(words consumed, replacement, trailing punctuation of the last word)`.
+fn match_rule<'a>(words: &[&'a str]) -> Option<(usize, &'static str, &'a str)> {
+    let phrases = FILLER_PHRASES
+        .iter()
+        .chain(VERBOSE_PREPOSITIONS.iter())
+        .map(|&(from, to)| (from, to))
+        .chain(SOFT_QUALIFIERS.iter().map(|&q| (q, "")));
+
+    for (from, to) in phrases {
+        let pattern: Vec<&str> = from.split(' ').collect();
+        if pattern.len() > words.len() {
+            continue;
+        }
+        let last = pattern.len() - 1;
+        let mut punct = "";
+        let matched = pattern.iter().enumerate().all(|(k, p)| {
+            let word = words[k];
+            let bare = if k == last {
+                let bare = word.trim_end_matches(TRAILING_PUNCT);
+                punct = &word[bare.len()..];
+                bare
+            } else {
+                word
+            };
+            bare.eq_ignore_ascii_case(p)
+        });
+        if matched {
+            return Some((pattern.len(), to, punct));
+        }
+    }
+    None
+}
+
+fn capitalize_first(s: &str) -> String {
+    let mut chars = s.chars();
+    match chars.next() {
+        Some(first) => first.to_uppercase().chain(chars).collect(),
+        None => String::new(),
+    }
+}
+
+// ────────────────────────────────────────────────────────────────────────────
+// Tests
+// ────────────────────────────────────────────────────────────────────────────
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_filler_phrases_are_shortened() {
+        assert_eq!(
+            compress_locally("Rewrote the scheduler in order to cut p99 latency by 40%"),
+            "Rewrote the scheduler to cut p99 latency by 40%"
+        );
+        assert_eq!(
+            compress_locally("Cut costs 30% as a result of migrating to spot instances"),
+            "Cut costs 30% from migrating to spot instances"
+        );
+    }
+
+    #[test]
+    fn test_soft_qualifiers_are_removed() {
+        assert_eq!(
+            compress_locally("Led various migrations across multiple teams"),
+            "Led migrations across teams"
+        );
+    }
+
+    #[test]
+    fn test_matching_is_case_insensitive_and_keeps_capitalization() {
+        assert_eq!(
+            compress_locally("Successfully shipped Rust ingestion service"),
+            "Shipped Rust ingestion service"
+        );
+        assert_eq!(
+            compress_locally("Prior to launch, load-tested the API"),
+            "Before launch, load-tested the API"
+        );
+    }
+
+    #[test]
+    fn test_punctuation_on_dropped_phrase_moves_to_previous_word() {
+        assert_eq!(
+            compress_locally(
+                "Owned billing, payments, and fraud checks at the same time, end to end"
+            ),
+            "Owned billing, payments, and fraud checks, end to end"
+        );
+    }
+
+    #[test]
+    fn test_only_whole_words_match() {
+        let text = "Built multiplexed variousness checker";
+        assert_eq!(compress_locally(text), text);
+    }
+
+    #[test]
+    fn test_quantities_and_tools_are_untouched() {
+        let text = "Scaled Kafka pipeline to 2M events/sec on 12 brokers";
+        assert_eq!(compress_locally(text), text);
+        let spaced = "Scaled  Kafka   pipeline";
+        assert_eq!(
+            compress_locally(spaced),
+            spaced,
+            "no match leaves spacing alone"
+        );
+    }
+}
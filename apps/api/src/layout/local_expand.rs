@@ -0,0 +1,263 @@
+//! Deterministic bullet expansion — the fallback when the LLM returns no expansion.
+//!
+//! Appends facts from the bullet's source entry that the bullet does not state yet:
+//! - technologies from the entry's `tech_stack`, in the entry's order
+//! - the team size (`team_size`)
+//! - how long the work ran (`date_start` to `date_end`, or to today for a current role)
+//!
+//! Every clause is copied from the entry, so the rewrite stays grounded. Clauses are
+//! added while the bullet fits its character budget; the simulator re-checks the
+//! contract afterwards, and a bullet still short on the next pass is flagged as before.
+
+use std::collections::HashMap;
+
+use chrono::{Datelike, NaiveDate};
+use serde_json::Value;
+use uuid::Uuid;
+
+use crate::generation::keyword_coverage::contains_term;
+use crate::generation::static_sections::parse_date;
+use crate::models::context::ContextEntryRow;
+
+/// Shortest date span worth stating, in months.
+const MIN_SPAN_MONTHS: u32 = 3;
+
+/// The facts of one context entry a bullet may be expanded with.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct EntryFacts {
+    pub tech: Vec<String>,
+    pub team_size: Option<u64>,
+    /// Whole months from start to end (or `today`).
+    pub months: Option<u32>,
+}
+
+/// Expansion facts by `entry_id`, for every entry bullets may come from.
+pub type SourceFacts = HashMap<Uuid, EntryFacts>;
+
+impl EntryFacts {
+    /// Reads the facts from `entry.data`. Year-only dates give no span: "2021 – 2022"
+    /// could be a month or two years.
+    pub fn from_entry(entry: &ContextEntryRow, today: NaiveDate) -> Self {
+        let data = &entry.data;
+        let tech = data
+            .get("tech_stack")
+            .and_then(|v| v.as_array())
+            .map(|items| {
+                items
+                    .iter()
+                    .filter_map(|v| v.as_str())
+                    .map(str::trim)
+                    .filter(|t| !t.is_empty())
+                    .map(str::to_string)
+                    .collect()
+            })
+            .unwrap_or_default();
+        let team_size = data
+            .get("team_size")
+            .and_then(|v| v.as_u64())
+            .filter(|&n| n > 1);
+
+        let start = precise_date(data.get("date_start"));
+        let end = match data.get("date_end") {
+            None | Some(Value::Null) => Some(today),
+            v => precise_date(v),
+        };
+        let months = match (start, end) {
+            // A month counts once its day of the month is reached.
+            (Some(start), Some(end)) if end > start => Some(
+                ((end.year() - start.year()) * 12 + end.month() as i32
+                    - start.month() as i32
+                    - i32::from(end.day() < start.day())) as u32,
+            ),
+            _ => None,
+        }
+        .filter(|&m| m >= MIN_SPAN_MONTHS);
+
+        EntryFacts {
+            tech,
+            team_size,
+            months,
+        }
+    }
+
+    /// Facts for each of `entries`.
+    pub fn collect<'a>(
+        entries: impl IntoIterator<Item = &'a ContextEntryRow>,
+        today: NaiveDate,
+    ) -> SourceFacts {
+        entries
+            .into_iter()
+            .map(|e| (e.entry_id, EntryFacts::from_entry(e, today)))
+            .collect()
+    }
+}
+
+/// A date with at least month precision ("2022-03" or "2022-03-01").
+fn precise_date(value: Option<&Value>) -> Option<NaiveDate> {
+    value
+        .and_then(|v| v.as_str())
+        .filter(|s| s.trim().len() >= 7)
+        .and_then(|_| parse_date(value))
+}
+
+/// `text` with entry facts it does not mention appended, within `char_budget`
+/// characters. `None` when no fact is new or none fits.
+pub fn expand_locally(text: &str, facts: &EntryFacts, char_budget: usize) -> Option<String> {
+    let trimmed = text.trim_end();
+    let (body, period) = match trimmed.strip_suffix('.') {
+        Some(body) => (body, "."),
+        None => (trimmed, ""),
+    };
+    let lowered = body.to_lowercase();
+    let fits = |candidate: &str| candidate.chars().count() + period.len() <= char_budget;
+
+    let mut expanded = body.to_string();
+
+    // As many unmentioned technologies as fit, at least one.
+    let new_tech: Vec<&str> = facts
+        .tech
+        .iter()
+        .map(String::as_str)
+        .filter(|t| !contains_term(&lowered, &t.to_lowercase()))
+        .collect();
+    if let Some(clause) = (1..=new_tech.len())
+        .rev()
+        .map(|n| format!("{expanded}, using {}", join_and(&new_tech[..n])))
+        .find(|candidate| fits(candidate))
+    {
+        expanded = clause;
+    }
+
+    if let Some(size) = facts.team_size.filter(|_| !lowered.contains("team of")) {
+        let candidate = format!("{expanded} on a team of {size}");
+        if fits(&candidate) {
+            expanded = candidate;
+        }
+    }
+
+    if let Some(months) = facts
+        .months
+        .filter(|_| !lowered.contains("month") && !lowered.contains("year"))
+    {
+        let candidate = format!("{expanded} over {}", span_phrase(months));
+        if fits(&candidate) {
+            expanded = candidate;
+        }
+    }
+
+    (expanded != body).then(|| format!("{expanded}{period}"))
+}
+
+/// "Rust", "Rust and Kafka", "Rust, Kafka and PostgreSQL".
+fn join_and(items: &[&str]) -> String {
+    match items {
+        [] => String::new(),
+        [only] => only.to_string(),
+        [init @ .., last] => format!("{} and {last}", init.join(", ")),
+    }
+}
+
+/// "8 months", "18 months", "3 years". Whole years from two years on, rounded down,
+/// so the phrase never overstates the span.
+fn span_phrase(months: u32) -> String {
+    if months >= 24 {
+        format!("{} years", months / 12)
+    } else {
+        format!("{months} months")
+    }
+}
+
+// ────────────────────────────────────────────────────────────────────────────
+// Tests
+// ────────────────────────────────────────────────────────────────────────────
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Utc;
+    use serde_json::json;
+
+    fn entry(data: Value) -> ContextEntryRow {
+        ContextEntryRow {
+            id: Uuid::new_v4(),
+            user_id: Uuid::new_v4(),
+            entry_id: Uuid::new_v4(),
+            version: 1,
+            entry_type: "experience".to_string(),
+            data,
+            raw_text: None,
+            recency_score: 0.5,
+            impact_score: 0.5,
+            tags: vec![],
+            flagged_evergreen: false,
+            contribution_type: "sole_author".to_string(),
+            quality_score: 1.0,
+            quality_flags: vec![],
+            created_at: Utc::now(),
+            recency_policy: None,
+        }
+    }
+
+    fn date(s: &str) -> NaiveDate {
+        NaiveDate::parse_from_str(s, "%Y-%m-%d").unwrap()
+    }
+
+    #[test]
+    fn test_facts_read_tech_team_and_span() {
+        let facts = EntryFacts::from_entry(
+            &entry(json!({
+                "tech_stack": ["Rust", " ", "Kafka"],
+                "team_size": 7,
+                "date_start": "2022-03-01",
+                "date_end": null,
+            })),
+            date("2023-09-15"),
+        );
+        assert_eq!(facts.tech, vec!["Rust", "Kafka"]);
+        assert_eq!(facts.team_size, Some(7));
+        assert_eq!(facts.months, Some(18));
+
+        let year_only = EntryFacts::from_entry(
+            &entry(json!({ "date_start": "2020", "date_end": "2022", "team_size": 1 })),
+            date("2023-09-15"),
+        );
+        assert_eq!(year_only, EntryFacts::default());
+    }
+
+    #[test]
+    fn test_expansion_appends_only_new_facts() {
+        let facts = EntryFacts {
+            tech: vec!["Rust".to_string(), "Kafka".to_string(), "gRPC".to_string()],
+            team_size: Some(7),
+            months: Some(30),
+        };
+        assert_eq!(
+            expand_locally("Built a Rust ingestion service.", &facts, 200).as_deref(),
+            Some(
+                "Built a Rust ingestion service, using Kafka and gRPC on a team of 7 over 2 years."
+            )
+        );
+        assert_eq!(
+            expand_locally(
+                "Built Rust, Kafka and gRPC services on a team of 7 for 2 years",
+                &facts,
+                200
+            ),
+            None
+        );
+    }
+
+    #[test]
+    fn test_expansion_stays_within_budget() {
+        let facts = EntryFacts {
+            tech: vec!["Kafka".to_string(), "PostgreSQL".to_string()],
+            team_size: Some(12),
+            months: None,
+        };
+        let text = "Cut p99 latency by 40%";
+        let expanded = expand_locally(text, &facts, 37).unwrap();
+        assert_eq!(expanded, "Cut p99 latency by 40%, using Kafka");
+        assert!(expanded.chars().count() <= 37);
+        assert_eq!(expand_locally(text, &facts, 25), None);
+    }
+}
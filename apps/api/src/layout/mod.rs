@@ -3,11 +3,19 @@
 // CPU-bound simulation must run inside tokio::task::spawn_blocking.
 
 pub mod contract;
+pub mod fill_remediation;
 pub mod font_metrics;
+pub mod local_compress;
+pub mod local_expand;
 pub mod page_fill;
+pub mod pagination;
 pub mod prompts;
 pub mod simulator;
+pub mod ttf_metrics;
 
 // Re-export the public API consumed by other modules (generator, handlers).
-pub use font_metrics::{default_page_config, FontFamily, PageConfig};
-pub use simulator::{run_simulation_loop, SimulatedBullet};
+pub use font_metrics::{
+    default_page_config, page_config_for_paper, ContractThresholds, FontFamily, LineBreaking,
+    MarginPreset, PageConfig, PaperSize,
+};
+pub use simulator::{run_simulation_loop, LineType, RevisionCause, SimulatedBullet};
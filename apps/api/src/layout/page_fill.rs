@@ -6,15 +6,31 @@
 //! recommends a remediation action.
 //!
 //! # Page fill rules (from spec)
-//! - Whitespace > 8%  → add item OR promote a 1-line bullet to 2-line
+//! - Whitespace > 8%  → add item OR promote a 1-line bullet to 2-line (only bullets
+//!   whose `PromotionScore` is eligible for two lines)
 //! - Overflow < 5%    → compress bullets or tighten spacing
 //! - Overflow > 5%    → remove lowest-scoring item, re-run
+//!
+//! `fill_remediation::run_page_fill_loop` executes these recommendations.
+//!
+//! `total_lines_used` charges page structure as well as bullet lines: reserved lines
+//! (the contact header) plus `PageConfig::line_costs` for every section heading, entry
+//! heading and inter-section gap (see `structural_lines`).
+//!
+//! On a two-column page each column is filled independently against the full page
+//! height (the header spans both). The taller column sets the page length, so it
+//! drives the overall verdict and is where remediation happens.
+
+use std::collections::HashSet;
 
 use serde::{Deserialize, Serialize};
+use uuid::Uuid;
 
 use crate::generation::jd_parser::ParsedJD;
-use crate::layout::font_metrics::PageConfig;
+use crate::layout::contract::score_promotion_text;
+use crate::layout::font_metrics::{Column, LineCosts, PageConfig};
 use crate::layout::simulator::SimulatedBullet;
+use crate::layout::LineType;
 
 // ────────────────────────────────────────────────────────────────────────────
 // Types
@@ -41,6 +57,28 @@ pub struct PageFillAnalysis {
     pub whitespace_fraction: f32,
     pub overflow_fraction: f32,
     pub verdict: PageFillVerdict,
+    /// Lines of `total_lines_used` charged for section/entry headers and spacing.
+    #[serde(default)]
+    pub structural_lines: u16,
+    /// Per-column fill on a two-column page; empty for single-column pages.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub columns: Vec<ColumnFill>,
+}
+
+/// Fill of one column of a two-column page.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ColumnFill {
+    pub column: Column,
+    /// Bullet lines in this column plus the reserved (header) and structural lines.
+    pub lines_used: u16,
+    /// Lines charged for this column's section/entry headers and spacing.
+    #[serde(default)]
+    pub structural_lines: u16,
+    pub whitespace_fraction: f32,
+    pub overflow_fraction: f32,
+    pub verdict: PageFillVerdict,
+    /// Indices (into the analyzed bullet slice) of the bullets set in this column.
+    pub bullet_indices: Vec<usize>,
 }
 
 /// Recommended remediation action for a non-Acceptable page fill.
@@ -64,13 +102,115 @@ pub enum FillAction {
 
 /// Analyzes the overall page fill given the simulated bullets and page configuration.
 ///
-/// `total_lines_used` is the sum of `verified_line_count` across all bullets.
-/// `usable_height_lines` from `PageConfig` is the denominator.
+/// `total_lines_used` is the sum of `verified_line_count` across all bullets plus the
+/// structural lines of their sections. `usable_height_lines` from `PageConfig` is the
+/// denominator.
 pub fn analyze_page_fill(bullets: &[SimulatedBullet], config: &PageConfig) -> PageFillAnalysis {
-    let total_lines_used: u16 = bullets.iter().map(|b| b.verified_line_count as u16).sum();
+    analyze_page_fill_with_reserved(bullets, 0, config)
+}
 
+/// Same as [`analyze_page_fill`], plus `reserved_lines` of fixed content that is not a
+/// bullet (the contact header). Reserved lines count toward `total_lines_used`.
+///
+/// With `config.columns` set, fill is computed per column and the top-level figures
+/// are those of the taller column.
+pub fn analyze_page_fill_with_reserved(
+    bullets: &[SimulatedBullet],
+    reserved_lines: u16,
+    config: &PageConfig,
+) -> PageFillAnalysis {
     let available = config.usable_height_lines;
-    let fill_ratio = total_lines_used as f32 / available as f32;
+
+    if config.columns.is_none() {
+        let structural = structural_lines(bullets.iter(), &config.line_costs);
+        let total_lines_used = reserved_lines + structural + sum_lines(bullets.iter());
+        let (whitespace_fraction, overflow_fraction, verdict) =
+            fill_verdict(total_lines_used, available);
+        return PageFillAnalysis {
+            total_lines_used,
+            total_lines_available: available,
+            whitespace_fraction,
+            overflow_fraction,
+            verdict,
+            structural_lines: structural,
+            columns: vec![],
+        };
+    }
+
+    let columns: Vec<ColumnFill> = [Column::Main, Column::Sidebar]
+        .into_iter()
+        .map(|column| {
+            let bullet_indices: Vec<usize> = bullets
+                .iter()
+                .enumerate()
+                .filter(|(_, b)| config.column_for_section(&b.section) == column)
+                .map(|(i, _)| i)
+                .collect();
+            let column_bullets = || bullet_indices.iter().map(|&i| &bullets[i]);
+            let structural = structural_lines(column_bullets(), &config.line_costs);
+            let lines_used = reserved_lines + structural + sum_lines(column_bullets());
+            let (whitespace_fraction, overflow_fraction, verdict) =
+                fill_verdict(lines_used, available);
+            ColumnFill {
+                column,
+                lines_used,
+                whitespace_fraction,
+                overflow_fraction,
+                verdict,
+                structural_lines: structural,
+                bullet_indices,
+            }
+        })
+        .collect();
+
+    let tallest = columns
+        .iter()
+        .max_by_key(|c| c.lines_used)
+        .expect("two columns are always analyzed");
+
+    PageFillAnalysis {
+        total_lines_used: tallest.lines_used,
+        total_lines_available: available,
+        whitespace_fraction: tallest.whitespace_fraction,
+        overflow_fraction: tallest.overflow_fraction,
+        verdict: tallest.verdict.clone(),
+        structural_lines: tallest.structural_lines,
+        columns,
+    }
+}
+
+fn sum_lines<'a>(bullets: impl Iterator<Item = &'a SimulatedBullet>) -> u16 {
+    bullets.map(|b| b.verified_line_count as u16).sum()
+}
+
+/// Lines charged for the structure around `bullets`: one heading per distinct section,
+/// one entry heading per distinct (section, source entry), and a gap between each pair
+/// of consecutive sections. The fractional total is rounded up.
+pub fn structural_lines<'a>(
+    bullets: impl Iterator<Item = &'a SimulatedBullet>,
+    costs: &LineCosts,
+) -> u16 {
+    let mut sections: Vec<&str> = Vec::new();
+    let mut entries: HashSet<(&str, Uuid)> = HashSet::new();
+    for b in bullets {
+        if !sections.contains(&b.section.as_str()) {
+            sections.push(&b.section);
+        }
+        entries.insert((&b.section, b.source_entry_id));
+    }
+    if sections.is_empty() {
+        return 0;
+    }
+
+    let cost = sections.len() as f32 * costs.section_header
+        + entries.len() as f32 * costs.entry_header
+        + (sections.len() - 1) as f32 * costs.section_gap;
+    cost.max(0.0).ceil() as u16
+}
+
+/// `(whitespace_fraction, overflow_fraction, verdict)` for `used` of `available` lines.
+fn fill_verdict(used: u16, available: u16) -> (f32, f32, PageFillVerdict) {
+    let fill_ratio = used as f32 / available as f32;
 
     let whitespace_fraction = (1.0_f32 - fill_ratio).max(0.0);
     let overflow_fraction = (fill_ratio - 1.0_f32).max(0.0);
@@ -85,29 +225,45 @@ pub fn analyze_page_fill(bullets: &[SimulatedBullet], config: &PageConfig) -> Pa
         PageFillVerdict::Acceptable
     };
 
-    PageFillAnalysis {
-        total_lines_used,
-        total_lines_available: available,
-        whitespace_fraction,
-        overflow_fraction,
-        verdict,
-    }
+    (whitespace_fraction, overflow_fraction, verdict)
 }
 
 /// Recommends a single remediation action based on the page fill analysis.
 ///
 /// The caller is responsible for executing the action (expand, compress, or remove a bullet).
+/// On a two-column page only bullets in the taller column are candidates — changing the
+/// shorter column does not change the page length.
 pub fn recommend_fill_action(
     analysis: &PageFillAnalysis,
     bullets: &[SimulatedBullet],
     parsed_jd: &ParsedJD,
 ) -> FillAction {
+    recommend_fill_action_among(analysis, bullets, parsed_jd, |_| true)
+}
+
+/// Same as [`recommend_fill_action`], restricted to bullets for which `candidate` holds.
+/// The remediation loop uses this to skip fixed content and bullets it already failed on.
+pub fn recommend_fill_action_among(
+    analysis: &PageFillAnalysis,
+    bullets: &[SimulatedBullet],
+    parsed_jd: &ParsedJD,
+    candidate: impl Fn(usize) -> bool,
+) -> FillAction {
+    let in_scope: Option<&[usize]> = analysis
+        .columns
+        .iter()
+        .max_by_key(|c| c.lines_used)
+        .map(|c| c.bullet_indices.as_slice());
+    let eligible = |i: usize| candidate(i) && in_scope.is_none_or(|indices| indices.contains(&i));
+    // Compressing a 1-line bullet reclaims nothing.
+    let compressible = |i: usize| eligible(i) && bullets[i].verified_line_count > 1;
+
     match &analysis.verdict {
         PageFillVerdict::Acceptable => FillAction::NoAction,
 
         PageFillVerdict::TooMuchWhitespace => {
             // Prefer promoting a 1-line bullet to 2-line to fill space.
-            if let Some(idx) = find_best_promotion_candidate(bullets, parsed_jd) {
+            if let Some(idx) = find_best_promotion_candidate(bullets, parsed_jd, eligible) {
                 FillAction::PromoteBullet { bullet_index: idx }
             } else {
                 FillAction::NoAction
@@ -115,8 +271,8 @@ pub fn recommend_fill_action(
         }
 
         PageFillVerdict::MinorOverflow => {
-            // Compress the lowest-scoring bullet slightly.
-            if let Some(idx) = find_lowest_scoring_bullet(bullets, parsed_jd) {
+            // Compress the lowest-scoring multi-line bullet slightly.
+            if let Some(idx) = find_lowest_scoring_bullet(bullets, parsed_jd, compressible) {
                 FillAction::CompressBullet { bullet_index: idx }
             } else {
                 FillAction::TightenSpacing
@@ -125,7 +281,7 @@ pub fn recommend_fill_action(
 
         PageFillVerdict::MajorOverflow => {
             // Remove the lowest-scoring bullet.
-            if let Some(idx) = find_lowest_scoring_bullet(bullets, parsed_jd) {
+            if let Some(idx) = find_lowest_scoring_bullet(bullets, parsed_jd, eligible) {
                 FillAction::RemoveBullet { bullet_index: idx }
             } else {
                 FillAction::TightenSpacing
@@ -142,7 +298,11 @@ pub fn recommend_fill_action(
 ///
 /// Bullets that are already flagged for review are deprioritized for removal so that
 /// human-reviewed bullets are not silently discarded.
-fn find_lowest_scoring_bullet(bullets: &[SimulatedBullet], parsed_jd: &ParsedJD) -> Option<usize> {
+fn find_lowest_scoring_bullet(
+    bullets: &[SimulatedBullet],
+    parsed_jd: &ParsedJD,
+    eligible: impl Fn(usize) -> bool,
+) -> Option<usize> {
     if bullets.is_empty() {
         return None;
     }
@@ -156,6 +316,7 @@ fn find_lowest_scoring_bullet(bullets: &[SimulatedBullet], parsed_jd: &ParsedJD)
     bullets
         .iter()
         .enumerate()
+        .filter(|(i, _)| eligible(*i))
         .min_by(|(_, a), (_, b)| {
             let score_a = keyword_match_score(&a.jd_keywords_used, &jd_keyword_set);
             let score_b = keyword_match_score(&b.jd_keywords_used, &jd_keyword_set);
@@ -168,26 +329,33 @@ fn find_lowest_scoring_bullet(bullets: &[SimulatedBullet], parsed_jd: &ParsedJD)
 
 /// Finds the best 1-line bullet to promote to 2 lines (for whitespace reduction).
 ///
-/// Chooses the bullet with the most JD keyword matches that is currently 1 line.
+/// Only `LineType::Bullet`s whose `PromotionScore` is `eligible_for_two_lines` qualify —
+/// a second line is reserved for quantified, technical, JD-relevant work. Among those,
+/// the highest `PromotionScore::rank` wins.
 fn find_best_promotion_candidate(
     bullets: &[SimulatedBullet],
     parsed_jd: &ParsedJD,
+    eligible: impl Fn(usize) -> bool,
 ) -> Option<usize> {
-    let jd_keyword_set: std::collections::HashSet<String> = parsed_jd
-        .keyword_inventory
-        .iter()
-        .map(|k| k.keyword.to_lowercase())
-        .collect();
-
     bullets
         .iter()
         .enumerate()
-        .filter(|(_, b)| b.verified_line_count == 1 && !b.flagged_for_review)
+        .filter(|(i, b)| {
+            eligible(*i)
+                && b.line_type == LineType::Bullet
+                && b.verified_line_count == 1
+                && !b.flagged_for_review
+        })
+        .map(|(i, b)| {
+            (
+                i,
+                score_promotion_text(&b.text, &b.jd_keywords_used, parsed_jd),
+            )
+        })
+        .filter(|(_, score)| score.eligible_for_two_lines)
         .max_by(|(_, a), (_, b)| {
-            let score_a = keyword_match_score(&a.jd_keywords_used, &jd_keyword_set);
-            let score_b = keyword_match_score(&b.jd_keywords_used, &jd_keyword_set);
-            score_a
-                .partial_cmp(&score_b)
+            a.rank()
+                .partial_cmp(&b.rank())
                 .unwrap_or(std::cmp::Ordering::Equal)
         })
         .map(|(i, _)| i)
@@ -212,8 +380,8 @@ fn keyword_match_score(
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::generation::jd_parser::{JDTone, KeywordEntry, ParsedJD, Requirement, RoleSignals};
-    use crate::layout::font_metrics::{default_page_config, FontFamily};
+    use crate::layout::font_metrics::{default_page_config, FontFamily, TwoColumnLayout};
+    use templar_types::jd::{JDTone, KeywordEntry, ParsedJD, Requirement, RoleSignals};
     use uuid::Uuid;
 
     fn make_config() -> PageConfig {
@@ -256,10 +424,20 @@ mod tests {
             text: "Architected systems".to_string(),
             source_entry_id: Uuid::new_v4(),
             section: "experience".to_string(),
+            line_type: LineType::Bullet,
             verified_line_count: line_count,
             jd_keywords_used: keywords.into_iter().map(|s| s.to_string()).collect(),
             was_adjusted: false,
             flagged_for_review: flagged,
+            revisions: Vec::new(),
+        }
+    }
+
+    /// A 1-line bullet that names its JD keywords and a quantified outcome.
+    fn make_promotable(text: &str, keywords: Vec<&str>) -> SimulatedBullet {
+        SimulatedBullet {
+            text: text.to_string(),
+            ..make_bullet(1, keywords, false)
         }
     }
 
@@ -268,12 +446,14 @@ mod tests {
     #[test]
     fn test_acceptable_fill_verdict() {
         let config = make_config(); // 45 usable lines
-                                    // 43 lines used = 95.6% fill → Acceptable (whitespace = 4.4% < 8%)
+                                    // 41 bullet lines + 2 for the section heading = 43 = 95.6% fill
+                                    // → Acceptable (whitespace = 4.4% < 8%)
         let bullets: Vec<SimulatedBullet> =
-            (0..43).map(|_| make_bullet(1, vec![], false)).collect();
+            (0..41).map(|_| make_bullet(1, vec![], false)).collect();
         let analysis = analyze_page_fill(&bullets, &config);
         assert_eq!(analysis.verdict, PageFillVerdict::Acceptable);
         assert_eq!(analysis.total_lines_used, 43);
+        assert_eq!(analysis.structural_lines, 2);
         assert!(analysis.whitespace_fraction < 0.08);
     }
 
@@ -291,9 +471,9 @@ mod tests {
     #[test]
     fn test_minor_overflow_verdict() {
         let config = make_config(); // 45 usable lines
-                                    // 47 lines used = 104.4% fill → MinorOverflow (1–5%)
+                                    // 45 bullet lines + 2 heading = 47 = 104.4% fill → MinorOverflow (1–5%)
         let bullets: Vec<SimulatedBullet> =
-            (0..47).map(|_| make_bullet(1, vec![], false)).collect();
+            (0..45).map(|_| make_bullet(1, vec![], false)).collect();
         let analysis = analyze_page_fill(&bullets, &config);
         assert_eq!(analysis.verdict, PageFillVerdict::MinorOverflow);
         assert!(analysis.overflow_fraction > 0.0 && analysis.overflow_fraction <= 0.05);
@@ -319,6 +499,73 @@ mod tests {
         assert!((analysis.whitespace_fraction - 1.0).abs() < 1e-3);
     }
 
+    #[test]
+    fn test_reserved_lines_count_toward_fill() {
+        let config = make_config(); // 45 usable lines
+                                    // 38 bullet lines + 2 heading = 88.9% → whitespace; + 2 header lines = 93.3% → acceptable
+        let bullets: Vec<SimulatedBullet> =
+            (0..38).map(|_| make_bullet(1, vec![], false)).collect();
+        assert_eq!(
+            analyze_page_fill(&bullets, &config).verdict,
+            PageFillVerdict::TooMuchWhitespace
+        );
+        let analysis = analyze_page_fill_with_reserved(&bullets, 2, &config);
+        assert_eq!(analysis.total_lines_used, 42);
+        assert_eq!(analysis.verdict, PageFillVerdict::Acceptable);
+    }
+
+    // ── two-column fill ──────────────────────────────────────────────────────
+
+    fn in_section(mut bullet: SimulatedBullet, section: &str) -> SimulatedBullet {
+        bullet.section = section.to_string();
+        bullet
+    }
+
+    #[test]
+    fn test_two_column_fill_is_per_column() {
+        let config = make_config().with_two_columns(TwoColumnLayout::default_sidebar());
+        // 40 main-column lines + 2 heading + 2 header = 44/45 → Acceptable;
+        // sidebar 10 + 2 + 2 = 14/45
+        let mut bullets: Vec<SimulatedBullet> =
+            (0..40).map(|_| make_bullet(1, vec![], false)).collect();
+        bullets.extend((0..10).map(|_| in_section(make_bullet(1, vec![], false), "skills")));
+
+        let analysis = analyze_page_fill_with_reserved(&bullets, 2, &config);
+        assert_eq!(analysis.columns.len(), 2);
+        let main = &analysis.columns[0];
+        let sidebar = &analysis.columns[1];
+        assert_eq!((main.column, main.lines_used), (Column::Main, 44));
+        assert_eq!((sidebar.column, sidebar.lines_used), (Column::Sidebar, 14));
+        assert_eq!(sidebar.verdict, PageFillVerdict::TooMuchWhitespace);
+        assert_eq!(sidebar.bullet_indices, (40..50).collect::<Vec<_>>());
+
+        // The taller column drives the page verdict; single-column would overflow at 55
+        assert_eq!(analysis.total_lines_used, 44);
+        assert_eq!(analysis.verdict, PageFillVerdict::Acceptable);
+        assert_eq!(
+            analyze_page_fill_with_reserved(&bullets, 2, &make_config()).verdict,
+            PageFillVerdict::MajorOverflow
+        );
+    }
+
+    #[test]
+    fn test_two_column_remediation_targets_taller_column() {
+        let config = make_config().with_two_columns(TwoColumnLayout::default_sidebar());
+        // Sidebar overflows (50 lines); its keyword-less bullet must be picked, not the
+        // main-column one that scores even lower overall.
+        let mut bullets = vec![make_bullet(1, vec![], false)];
+        bullets
+            .extend((0..49).map(|_| in_section(make_bullet(1, vec!["Rust"], false), "education")));
+        bullets.push(in_section(make_bullet(1, vec![], false), "education"));
+
+        let analysis = analyze_page_fill(&bullets, &config);
+        assert_eq!(analysis.verdict, PageFillVerdict::MajorOverflow);
+        assert_eq!(
+            recommend_fill_action(&analysis, &bullets, &make_parsed_jd()),
+            FillAction::RemoveBullet { bullet_index: 50 }
+        );
+    }
+
     // ── recommend_fill_action ────────────────────────────────────────────────
 
     #[test]
@@ -334,13 +581,18 @@ mod tests {
 
     #[test]
     fn test_recommend_promote_for_whitespace() {
-        let bullets = vec![make_bullet(1, vec!["Rust"], false)];
+        let bullets = vec![make_promotable(
+            "Cut Rust distributed queue latency 40%",
+            vec!["Rust"],
+        )];
         let analysis = PageFillAnalysis {
             total_lines_used: 30,
             total_lines_available: 45,
             whitespace_fraction: 0.33,
             overflow_fraction: 0.0,
             verdict: PageFillVerdict::TooMuchWhitespace,
+            structural_lines: 0,
+            columns: vec![],
         };
         let action = recommend_fill_action(&analysis, &bullets, &make_parsed_jd());
         assert!(matches!(action, FillAction::PromoteBullet { .. }));
@@ -355,6 +607,8 @@ mod tests {
             whitespace_fraction: 0.0,
             overflow_fraction: 0.044,
             verdict: PageFillVerdict::MinorOverflow,
+            structural_lines: 0,
+            columns: vec![],
         };
         let action = recommend_fill_action(&analysis, &bullets, &make_parsed_jd());
         assert!(
@@ -375,6 +629,8 @@ mod tests {
             whitespace_fraction: 0.0,
             overflow_fraction: 0.11,
             verdict: PageFillVerdict::MajorOverflow,
+            structural_lines: 0,
+            columns: vec![],
         };
         let action = recommend_fill_action(&analysis, &bullets, &make_parsed_jd());
         match action {
@@ -396,22 +652,121 @@ mod tests {
             make_bullet(1, vec![], false), // lowest score
             make_bullet(1, vec!["distributed"], false),
         ];
-        let idx = find_lowest_scoring_bullet(&bullets, &make_parsed_jd());
+        let idx = find_lowest_scoring_bullet(&bullets, &make_parsed_jd(), |_| true);
         assert_eq!(idx, Some(1), "bullet with no JD keywords should be lowest");
     }
 
     #[test]
     fn test_find_best_promotion_candidate_prefers_1_line() {
         let bullets = vec![
-            make_bullet(2, vec!["Rust"], false), // already 2 lines, skip
-            make_bullet(1, vec!["distributed"], false),
-            make_bullet(1, vec!["Rust", "distributed"], false), // best match
+            SimulatedBullet {
+                text: "Cut Rust distributed queue latency 40%".to_string(),
+                ..make_bullet(2, vec!["Rust"], false) // already 2 lines, skip
+            },
+            make_promotable("Cut Rust distributed queue latency 40%", vec!["Rust"]),
         ];
-        let idx = find_best_promotion_candidate(&bullets, &make_parsed_jd());
-        assert_eq!(
-            idx,
-            Some(2),
-            "best 1-line candidate should have most keywords"
-        );
+        let idx = find_best_promotion_candidate(&bullets, &make_parsed_jd(), |_| true);
+        assert_eq!(idx, Some(1), "only the 1-line bullet can be promoted");
+    }
+
+    #[test]
+    fn test_promotion_requires_eligible_score() {
+        let bullets = vec![
+            // Many JD keywords but no quantified outcome → ineligible
+            make_promotable(
+                "Built Rust distributed services",
+                vec!["Rust", "distributed"],
+            ),
+            make_bullet(1, vec!["Rust", "distributed"], false),
+        ];
+        let idx = find_best_promotion_candidate(&bullets, &make_parsed_jd(), |_| true);
+        assert_eq!(idx, None, "keyword count alone does not earn a second line");
+
+        let analysis = PageFillAnalysis {
+            total_lines_used: 30,
+            total_lines_available: 45,
+            whitespace_fraction: 0.33,
+            overflow_fraction: 0.0,
+            verdict: PageFillVerdict::TooMuchWhitespace,
+            structural_lines: 0,
+            columns: vec![],
+        };
+        let action = recommend_fill_action(&analysis, &bullets, &make_parsed_jd());
+        assert_eq!(action, FillAction::NoAction);
+    }
+
+    #[test]
+    fn test_promotion_picks_highest_rank() {
+        let bullets = vec![
+            // Eligible, but one of its four keywords is not in the JD → jd_relevance 0.75
+            make_promotable(
+                "Cut Rust distributed queue latency 40%",
+                vec!["Rust", "distributed", "rust", "Kubernetes"],
+            ),
+            make_promotable("Cut Rust distributed queue latency 40%", vec!["Rust"]),
+        ];
+        let idx = find_best_promotion_candidate(&bullets, &make_parsed_jd(), |_| true);
+        assert_eq!(idx, Some(1));
+    }
+
+    #[test]
+    fn test_minor_overflow_only_compresses_multi_line_bullets() {
+        let bullets = vec![
+            make_bullet(1, vec![], false),
+            make_bullet(2, vec!["Rust"], false),
+        ];
+        let analysis = PageFillAnalysis {
+            total_lines_used: 47,
+            total_lines_available: 45,
+            whitespace_fraction: 0.0,
+            overflow_fraction: 0.044,
+            verdict: PageFillVerdict::MinorOverflow,
+            structural_lines: 0,
+            columns: vec![],
+        };
+        let action = recommend_fill_action(&analysis, &bullets, &make_parsed_jd());
+        assert_eq!(action, FillAction::CompressBullet { bullet_index: 1 });
+
+        let action =
+            recommend_fill_action_among(&analysis, &bullets, &make_parsed_jd(), |i| i != 1);
+        assert_eq!(action, FillAction::TightenSpacing);
+    }
+
+    // ── structural lines ─────────────────────────────────────────────────────
+
+    #[test]
+    fn test_structural_lines_charge_headers_entries_and_gaps() {
+        let entry = Uuid::new_v4();
+        let mut a = make_bullet(1, vec![], false);
+        a.source_entry_id = entry;
+        let mut b = make_bullet(1, vec![], false);
+        b.source_entry_id = entry;
+        let bullets = [a, b, in_section(make_bullet(1, vec![], false), "projects")];
+
+        let costs = LineCosts {
+            section_header: 1.0,
+            entry_header: 1.0,
+            section_gap: 0.5,
+        };
+        // 2 headings + 2 entries (one shared by two bullets) + 1 gap = 4.5 → 5
+        assert_eq!(structural_lines(bullets.iter(), &costs), 5);
+        assert_eq!(structural_lines([].iter(), &costs), 0);
+    }
+
+    #[test]
+    fn test_line_costs_are_configurable() {
+        let bullets: Vec<SimulatedBullet> =
+            (0..43).map(|_| make_bullet(1, vec![], false)).collect();
+        let mut config = make_config();
+        assert_eq!(analyze_page_fill(&bullets, &config).total_lines_used, 45);
+
+        config.line_costs = LineCosts {
+            section_header: 0.0,
+            entry_header: 0.0,
+            section_gap: 0.0,
+        };
+        let analysis = analyze_page_fill(&bullets, &config);
+        assert_eq!(analysis.total_lines_used, 43);
+        assert_eq!(analysis.structural_lines, 0);
     }
 }
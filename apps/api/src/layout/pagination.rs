@@ -0,0 +1,378 @@
+#![allow(dead_code)]
+//! Pagination — splits laid-out bullets across pages without widows or orphans.
+//!
+//! The page-fill loop targets a single page. When content is allowed to run onto
+//! further pages, a naive line-by-line split produces two classic defects:
+//! - a section heading printed as the last line of a page, its first bullet on the next
+//! - the last bullet of an entry stranded alone at the top of the next page
+//!
+//! `paginate` places bullets greedily in the order given, charging the same
+//! `PageConfig::line_costs` as `page_fill::structural_lines`, and keeps each section
+//! and entry heading attached to the first bullet below it. A stranded bullet is pulled
+//! back onto the previous page when tightening that page's spacing by at most
+//! `MAX_SPACING_SQUEEZE_LINES` makes room; otherwise the preceding bullet of the same
+//! entry is carried over with it (which carries the entry heading along when that
+//! bullet was the entry's first).
+//!
+//! Bullets must already be grouped by section and entry, as they are persisted. On a
+//! two-column page, paginate each column's bullets separately.
+
+use std::collections::HashSet;
+
+use serde::{Deserialize, Serialize};
+
+use crate::layout::font_metrics::PageConfig;
+use crate::layout::simulator::SimulatedBullet;
+
+/// Extra lines a page may absorb by tightening inter-item spacing to keep a stranded
+/// bullet with its entry.
+pub const MAX_SPACING_SQUEEZE_LINES: f32 = 1.0;
+
+// ────────────────────────────────────────────────────────────────────────────
+// Types
+// ────────────────────────────────────────────────────────────────────────────
+
+/// Bullets assigned to one printed page.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PageSlice {
+    /// Indices into the paginated bullet slice, in print order.
+    pub bullet_indices: Vec<usize>,
+    /// Bullet, heading and spacing lines on this page (reserved lines included), rounded up.
+    pub lines_used: u16,
+    pub lines_available: u16,
+}
+
+/// A deviation from the naive line-by-line split.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum BreakAdjustment {
+    /// A section heading that would have ended `page` was moved to the next page.
+    HeaderMovedToNextPage { section: String, page: usize },
+    /// `page` was set with tighter spacing so `bullet_index` stays with its entry.
+    SpacingTightened {
+        page: usize,
+        bullet_index: usize,
+        lines: f32,
+    },
+    /// `bullet_index` was moved to the next page so the entry's last bullet is not
+    /// printed there alone.
+    BulletCarriedOver { bullet_index: usize },
+}
+
+/// Result of [`paginate`].
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PageBreakPlan {
+    pub pages: Vec<PageSlice>,
+    pub adjustments: Vec<BreakAdjustment>,
+}
+
+// ────────────────────────────────────────────────────────────────────────────
+// Core functions
+// ────────────────────────────────────────────────────────────────────────────
+
+/// Splits `bullets` across pages of `config.usable_height_lines`. `reserved_lines`
+/// (the contact header) are charged to the first page only.
+pub fn paginate(
+    bullets: &[SimulatedBullet],
+    reserved_lines: u16,
+    config: &PageConfig,
+) -> PageBreakPlan {
+    let mut forced_breaks: HashSet<usize> = HashSet::new();
+    let mut squeezed: HashSet<usize> = HashSet::new();
+    let mut unfixable: HashSet<usize> = HashSet::new();
+    let mut adjustments = Vec::new();
+
+    // Every fix adds a forced break or a squeeze for a distinct bullet, so this terminates.
+    loop {
+        let placement = place(bullets, reserved_lines, config, &forced_breaks, &squeezed);
+        let Some(orphan) = first_orphan(bullets, &placement.pages, &unfixable) else {
+            adjustments.extend(placement.header_moves);
+            adjustments.extend(placement.squeezes);
+            return PageBreakPlan {
+                pages: placement.pages,
+                adjustments,
+            };
+        };
+
+        let previous = orphan - 1;
+        if !squeezed.contains(&orphan) && placement.fits_with_squeeze(orphan) {
+            squeezed.insert(orphan);
+        } else if is_first_on_page(previous, &placement.pages) {
+            // The entry alone is taller than a page; leave the break where it is.
+            unfixable.insert(orphan);
+        } else {
+            forced_breaks.insert(previous);
+            adjustments.push(BreakAdjustment::BulletCarriedOver {
+                bullet_index: previous,
+            });
+        }
+    }
+}
+
+// ────────────────────────────────────────────────────────────────────────────
+// Helpers
+// ────────────────────────────────────────────────────────────────────────────
+
+struct Placement {
+    pages: Vec<PageSlice>,
+    header_moves: Vec<BreakAdjustment>,
+    squeezes: Vec<BreakAdjustment>,
+    /// Lines by which each bullet that started a page missed the previous page.
+    missed_by: Vec<(usize, f32)>,
+}
+
+impl Placement {
+    /// Whether `bullet_index` started a page but would fit on the previous one with
+    /// at most `MAX_SPACING_SQUEEZE_LINES` of tightened spacing.
+    fn fits_with_squeeze(&self, bullet_index: usize) -> bool {
+        self.missed_by
+            .iter()
+            .any(|&(i, missed)| i == bullet_index && missed <= MAX_SPACING_SQUEEZE_LINES)
+    }
+}
+
+/// Greedy placement honoring `forced_breaks` (start a new page before these bullets)
+/// and `squeezed` (let these bullets overrun their page by the squeeze allowance).
+fn place(
+    bullets: &[SimulatedBullet],
+    reserved_lines: u16,
+    config: &PageConfig,
+    forced_breaks: &HashSet<usize>,
+    squeezed: &HashSet<usize>,
+) -> Placement {
+    let capacity = config.usable_height_lines as f32;
+    let costs = &config.line_costs;
+
+    let mut pages = Vec::new();
+    let mut header_moves = Vec::new();
+    let mut squeezes = Vec::new();
+    let mut missed_by = Vec::new();
+    let mut current: Vec<usize> = Vec::new();
+    let mut used = reserved_lines as f32;
+
+    for (i, bullet) in bullets.iter().enumerate() {
+        let prev = i.checked_sub(1).map(|p| &bullets[p]);
+        let new_section = prev.is_none_or(|p| p.section != bullet.section);
+        let new_entry =
+            new_section || prev.is_some_and(|p| p.source_entry_id != bullet.source_entry_id);
+
+        let lead = |page_empty: bool| {
+            let mut lead = 0.0;
+            if new_section {
+                if !page_empty {
+                    lead += costs.section_gap;
+                }
+                lead += costs.section_header;
+            }
+            if new_entry {
+                lead += costs.entry_header;
+            }
+            lead
+        };
+        let lines = bullet.verified_line_count as f32;
+
+        if !current.is_empty() {
+            let need = lead(false) + lines;
+            let allowance = if squeezed.contains(&i) {
+                MAX_SPACING_SQUEEZE_LINES
+            } else {
+                0.0
+            };
+            if forced_breaks.contains(&i) || used + need > capacity + allowance {
+                if !forced_breaks.contains(&i) {
+                    missed_by.push((i, used + need - capacity));
+                    if new_section && used + costs.section_gap + costs.section_header <= capacity {
+                        header_moves.push(BreakAdjustment::HeaderMovedToNextPage {
+                            section: bullet.section.clone(),
+                            page: pages.len(),
+                        });
+                    }
+                }
+                close_page(&mut pages, &mut current, &mut used, config);
+            } else if used + need > capacity {
+                squeezes.push(BreakAdjustment::SpacingTightened {
+                    page: pages.len(),
+                    bullet_index: i,
+                    lines: used + need - capacity,
+                });
+            }
+        }
+
+        used += lead(current.is_empty()) + lines;
+        current.push(i);
+    }
+    if !current.is_empty() || pages.is_empty() {
+        close_page(&mut pages, &mut current, &mut used, config);
+    }
+
+    Placement {
+        pages,
+        header_moves,
+        squeezes,
+        missed_by,
+    }
+}
+
+/// The first bullet that opens a page alone as the last bullet of an entry begun on
+/// the previous page.
+fn first_orphan(
+    bullets: &[SimulatedBullet],
+    pages: &[PageSlice],
+    unfixable: &HashSet<usize>,
+) -> Option<usize> {
+    let same_entry = |a: &SimulatedBullet, b: &SimulatedBullet| {
+        a.section == b.section && a.source_entry_id == b.source_entry_id
+    };
+    pages.iter().skip(1).find_map(|page| {
+        let &first = page.bullet_indices.first()?;
+        if first == 0 || unfixable.contains(&first) {
+            return None;
+        }
+        let continues_entry = same_entry(&bullets[first - 1], &bullets[first]);
+        let ends_entry = bullets
+            .get(first + 1)
+            .is_none_or(|next| !same_entry(&bullets[first], next));
+        (continues_entry && ends_entry).then_some(first)
+    })
+}
+
+fn close_page(
+    pages: &mut Vec<PageSlice>,
+    current: &mut Vec<usize>,
+    used: &mut f32,
+    config: &PageConfig,
+) {
+    pages.push(PageSlice {
+        bullet_indices: std::mem::take(current),
+        lines_used: used.max(0.0).ceil() as u16,
+        lines_available: config.usable_height_lines,
+    });
+    *used = 0.0;
+}
+
+fn is_first_on_page(bullet_index: usize, pages: &[PageSlice]) -> bool {
+    pages
+        .iter()
+        .any(|p| p.bullet_indices.first() == Some(&bullet_index))
+}
+
+// ────────────────────────────────────────────────────────────────────────────
+// Tests
+// ────────────────────────────────────────────────────────────────────────────
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::layout::font_metrics::{default_page_config, FontFamily, LineCosts};
+    use crate::layout::LineType;
+    use uuid::Uuid;
+
+    /// 10-line pages with whole-line headings and no gaps, so arithmetic stays exact.
+    fn make_config() -> PageConfig {
+        let mut config = default_page_config(FontFamily::Inter);
+        config.usable_height_lines = 10;
+        config.line_costs = LineCosts {
+            section_header: 1.0,
+            entry_header: 1.0,
+            section_gap: 0.0,
+        };
+        config
+    }
+
+    /// `count` 1-line bullets of one entry in `section`.
+    fn make_entry(section: &str, count: usize) -> Vec<SimulatedBullet> {
+        let entry = Uuid::new_v4();
+        (0..count)
+            .map(|i| SimulatedBullet {
+                text: format!("{section} bullet {i}"),
+                source_entry_id: entry,
+                section: section.to_string(),
+                line_type: LineType::Bullet,
+                verified_line_count: 1,
+                jd_keywords_used: vec![],
+                was_adjusted: false,
+                flagged_for_review: false,
+                revisions: Vec::new(),
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_fits_on_one_page() {
+        let bullets = make_entry("experience", 5);
+        let plan = paginate(&bullets, 0, &make_config());
+        assert_eq!(plan.pages.len(), 1);
+        assert_eq!(plan.pages[0].lines_used, 7);
+        assert!(plan.adjustments.is_empty());
+    }
+
+    #[test]
+    fn test_section_header_never_ends_a_page() {
+        // 9 lines of experience, then a new section whose heading would be line 10
+        let mut bullets = make_entry("experience", 7);
+        bullets.extend(make_entry("projects", 3));
+        let plan = paginate(&bullets, 0, &make_config());
+
+        assert_eq!(plan.pages[0].bullet_indices, (0..7).collect::<Vec<_>>());
+        assert_eq!(plan.pages[1].bullet_indices, vec![7, 8, 9]);
+        assert!(plan
+            .adjustments
+            .contains(&BreakAdjustment::HeaderMovedToNextPage {
+                section: "projects".to_string(),
+                page: 0,
+            }));
+    }
+
+    #[test]
+    fn test_stranded_bullet_is_kept_by_tightening_spacing() {
+        // 2 headings + 9 bullets = 11 lines: the last bullet misses by one line
+        let bullets = make_entry("experience", 9);
+        let plan = paginate(&bullets, 0, &make_config());
+
+        assert_eq!(plan.pages.len(), 1);
+        assert_eq!(
+            plan.adjustments,
+            vec![BreakAdjustment::SpacingTightened {
+                page: 0,
+                bullet_index: 8,
+                lines: 1.0,
+            }]
+        );
+    }
+
+    #[test]
+    fn test_stranded_bullet_pulls_previous_bullet_along() {
+        // 3 reserved + 2 headings + 6 bullets = 11: the 6th bullet would open page 2
+        // alone, and a 2-line squeeze is more than spacing can absorb.
+        let mut bullets = make_entry("experience", 6);
+        bullets[5].verified_line_count = 2;
+        let plan = paginate(&bullets, 3, &make_config());
+
+        assert_eq!(plan.pages[0].bullet_indices, vec![0, 1, 2, 3]);
+        assert_eq!(plan.pages[1].bullet_indices, vec![4, 5]);
+        assert_eq!(
+            plan.adjustments,
+            vec![BreakAdjustment::BulletCarriedOver { bullet_index: 4 }]
+        );
+    }
+
+    #[test]
+    fn test_single_bullet_entry_may_open_a_page() {
+        // A one-bullet entry carries its own heading; it is not stranded.
+        let mut bullets = make_entry("experience", 8);
+        bullets.extend(make_entry("experience", 1));
+        let plan = paginate(&bullets, 0, &make_config());
+
+        assert_eq!(plan.pages.len(), 2);
+        assert_eq!(plan.pages[1].bullet_indices, vec![8]);
+        assert!(plan.adjustments.is_empty());
+    }
+
+    #[test]
+    fn test_entry_taller_than_a_page_is_left_as_is() {
+        let bullets = make_entry("experience", 20);
+        let plan = paginate(&bullets, 0, &make_config());
+        let placed: usize = plan.pages.iter().map(|p| p.bullet_indices.len()).sum();
+        assert_eq!(placed, 20);
+        assert!(plan.pages.len() >= 2);
+    }
+}
@@ -1,7 +1,8 @@
 //! LLM prompt constants for layout bullet adjustment.
 //!
-//! Both prompts instruct the model to return `{"text": "..."}` JSON only.
-//! Callers deserialize via `llm.call_json::<AdjustedBullet>()`.
+//! The single-bullet prompts instruct the model to return `{"text": "..."}` JSON only;
+//! callers deserialize via `llm.call_json::<AdjustedBullet>()`. The batch prompt returns
+//! `{"bullets": [{"index": N, "text": "..."}]}` for every violator in a pass.
 
 // ────────────────────────────────────────────────────────────────────────────
 // Expand prompt (bullet too short — 1-line fill < 80%)
@@ -34,22 +35,22 @@ EXPANSION RULES:\n\
 Return JSON only: {\"text\": \"expanded bullet text here\"}";
 
 // ────────────────────────────────────────────────────────────────────────────
-// Compress prompt (bullet too long — 3+ lines)
+// Compress prompt (bullet too long — 3+ lines, or a 2-line bullet over the page cap)
 // ────────────────────────────────────────────────────────────────────────────
 
 pub const COMPRESS_SYSTEM: &str = "\
 You are a resume bullet editor. Your task is to compress a resume bullet that exceeds \
-2 printed lines. Remove redundant words and soft qualifiers — preserve quantified \
+its allowed number of printed lines. Remove redundant words and soft qualifiers — preserve quantified \
 outcomes above all else.\n\
 \n\
 Respond with valid JSON only: {\"text\": \"...\"}\n\
 Do NOT use markdown code fences. Do NOT add any explanation outside the JSON object.";
 
 pub const COMPRESS_PROMPT_TEMPLATE: &str = "\
-A resume bullet is too long and must be compressed to fit within 2 printed lines.\n\
+A resume bullet is too long and must be compressed to fit within {max_lines} printed line(s).\n\
 \n\
 CURRENT BULLET: {bullet_text}\n\
-CURRENT LINES: {actual_lines} printed lines (maximum allowed: 2)\n\
+CURRENT LINES: {actual_lines} printed lines (maximum allowed: {max_lines})\n\
 CHARACTER BUDGET: approximately {char_budget} characters for the compressed bullet\n\
 JD KEYWORDS TO PRESERVE: {jd_keywords}\n\
 \n\
@@ -61,4 +62,45 @@ PRIORITY ORDER (keep > remove):\n\
 5. REMOVE: Soft qualifiers (\"various\", \"multiple\", \"significant\")\n\
 6. REMOVE: Verbose prepositions and filler clauses\n\
 \n\
-The result MUST fit within 2 printed lines. Return JSON only: {\"text\": \"compressed bullet text here\"}";
+The result MUST fit within {max_lines} printed line(s). Return JSON only: {\"text\": \"compressed bullet text here\"}";
+
+// ────────────────────────────────────────────────────────────────────────────
+// Batch adjust prompt (every violator of one simulation pass in a single call)
+// ────────────────────────────────────────────────────────────────────────────
+
+pub const BATCH_ADJUST_SYSTEM: &str = "\
+You are a resume bullet editor. You receive several resume bullets that violate the page \
+layout contract. Each one must be either expanded to fill more of its line or compressed \
+to fit fewer printed lines. Fix every bullet independently — without inventing facts.\n\
+\n\
+Respond with valid JSON only: {\"bullets\": [{\"index\": 0, \"text\": \"...\"}]}\n\
+Do NOT use markdown code fences. Do NOT add any explanation outside the JSON object.";
+
+pub const BATCH_ADJUST_PROMPT_TEMPLATE: &str = "\
+The following resume bullets violate the page layout contract and must each be rewritten.\n\
+\n\
+BULLETS (JSON array):\n\
+{bullets_json}\n\
+\n\
+Each entry has:\n\
+- index: identifier to echo back unchanged\n\
+- line_type: \"bullet\", \"entry_header\" or \"summary_paragraph\" \
+— keep the rewrite in the same form\n\
+- action: \"expand\" or \"compress\"\n\
+- problem: what is wrong with the current layout\n\
+- max_lines: maximum printed lines allowed for the rewritten bullet\n\
+- char_budget: approximate character count to aim for\n\
+\n\
+JD KEYWORDS TO PRIORITIZE: {jd_keywords}\n\
+\n\
+EXPAND RULES:\n\
+1. Add technical specificity or quantified context implied by the original bullet\n\
+2. DO NOT invent metrics or outcomes\n\
+3. DO NOT change the contribution type or the opening action verb\n\
+\n\
+COMPRESS RULES:\n\
+1. KEEP quantified outcomes, the primary technical claim, the action verb and JD keywords\n\
+2. REMOVE redundant context phrases, soft qualifiers and filler clauses\n\
+\n\
+Every rewritten bullet MUST fit within its max_lines. Return exactly one entry per input index.\n\
+Return JSON only: {\"bullets\": [{\"index\": 0, \"text\": \"rewritten bullet text here\"}]}";
@@ -4,50 +4,58 @@
 //! # Architecture
 //! - `run_simulation_loop` is the public async entry point. Max 3 passes.
 //! - `run_single_pass_sync` is the CPU-bound inner pass, run via `tokio::task::spawn_blocking`.
-//! - Between passes, async LLM calls fix violations (expand or compress).
+//!   Each line is checked against the `LineRules` of its `LineType`.
+//! - Between passes, TooLong violators first get a deterministic local compression
+//!   (`local_compress`); only bullets still violating go to the LLM.
+//! - Then one batched LLM call fixes every violation of the pass (expand or
+//!   compress); if the batch call fails, the violators are retried with per-bullet calls
+//!   run concurrently, at most `adjust_concurrency` (`LAYOUT_ADJUST_CONCURRENCY`) in
+//!   flight.
+//! - Expand requests the LLM left unanswered (failed calls, offline mode) fall back to
+//!   `expand_locally`, which appends facts from the bullet's source entry.
 //! - After 3 passes, remaining violators are flagged for human review.
+//! - Then the page-wide cap of 3 two-line bullets is enforced: the lowest
+//!   promotion-score 2-line bullets are compressed back to 1 line.
 //!
 //! # spawn_blocking pattern
 //! Width summation over all bullets is CPU-bound but fast. `spawn_blocking` keeps the
-//! tokio scheduler unblocked. `run_single_pass_sync` accepts owned data (required for
-//! 'static closure bounds) and returns only the violating indices + results.
+//! tokio scheduler unblocked. Its closures need `'static` data, so the loop keeps the
+//! bullets and the page config behind `Arc`s and hands each blocking task a pointer
+//! copy; tasks return only deltas (violating indices, rewrites, line counts). Once a
+//! task has been joined the loop holds the only reference again, so `Arc::make_mut`
+//! updates the bullets in place rather than copying them.
 
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use futures::stream::{self, StreamExt};
 use serde::{Deserialize, Serialize};
-use tracing::warn;
-use uuid::Uuid;
+use tracing::{info, warn};
 
 use crate::errors::AppError;
 use crate::generation::generator::DraftBullet;
 use crate::generation::jd_parser::ParsedJD;
-use crate::layout::contract::{check_contract, LineCoverageResult, LineCoverageVerdict};
+use crate::layout::contract::{
+    check_contract, check_line_contract, check_line_contract_memo, max_lines_for,
+    score_promotion_text, simulate_lines, simulate_lines_memo, LineCoverageResult,
+    LineCoverageVerdict, WordWidthMemo, MAX_TWO_LINE_BULLETS,
+};
 use crate::layout::font_metrics::{get_metrics, FontMetricTable, PageConfig};
+use crate::layout::local_compress::compress_locally;
+use crate::layout::local_expand::{expand_locally, SourceFacts};
 use crate::layout::prompts::{
-    COMPRESS_PROMPT_TEMPLATE, COMPRESS_SYSTEM, EXPAND_PROMPT_TEMPLATE, EXPAND_SYSTEM,
+    BATCH_ADJUST_PROMPT_TEMPLATE, BATCH_ADJUST_SYSTEM, COMPRESS_PROMPT_TEMPLATE, COMPRESS_SYSTEM,
+    EXPAND_PROMPT_TEMPLATE, EXPAND_SYSTEM,
 };
 use crate::llm_client::LlmClient;
+use crate::metrics::LAYOUT;
+use crate::progress::{self, Stage};
 
 // ────────────────────────────────────────────────────────────────────────────
 // Output types
 // ────────────────────────────────────────────────────────────────────────────
 
-/// A bullet after layout simulation. Replaces `DraftBullet` for all downstream consumers.
-///
-/// `verified_line_count` is set by the simulation — NOT the LLM's `line_estimate`.
-/// `was_adjusted` indicates at least one LLM expand/compress call modified the text.
-/// `flagged_for_review` indicates the bullet still violated the contract after max passes.
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct SimulatedBullet {
-    pub text: String,
-    pub source_entry_id: Uuid,
-    pub section: String,
-    /// Line count as measured by the simulator (1 or 2 for passing bullets).
-    pub verified_line_count: u8,
-    pub jd_keywords_used: Vec<String>,
-    /// True if the simulator called the LLM at least once to adjust this bullet.
-    pub was_adjusted: bool,
-    /// True if the bullet still violates the contract after all simulation passes.
-    pub flagged_for_review: bool,
-}
+pub use templar_types::generation::{LineType, RevisionCause, SimulatedBullet};
 
 /// Summary of a complete simulation run.
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -57,6 +65,12 @@ pub struct SimulationResult {
     pub violations_remaining: u32,
     pub flagged_count: u32,
     pub llm_calls_made: u32,
+    /// Bullets rewritten by the local compressor instead of (or before) an LLM call.
+    #[serde(default)]
+    pub local_compressions: u32,
+    /// Bullets lengthened from their source entry's facts after the LLM gave no expansion.
+    #[serde(default)]
+    pub local_expansions: u32,
 }
 
 /// Intermediate type for deserializing the LLM's adjust response.
@@ -65,101 +79,196 @@ struct AdjustedBullet {
     text: String,
 }
 
+/// Whether a violator needs more text or less.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub(crate) enum AdjustAction {
+    Expand,
+    Compress,
+}
+
+/// One violating bullet in a batched adjust call. Serialized into the prompt as-is.
+#[derive(Debug, Clone, Serialize)]
+pub(crate) struct AdjustRequest {
+    /// Index into the simulated bullet list; echoed back by the LLM.
+    pub index: usize,
+    pub text: String,
+    pub line_type: LineType,
+    pub action: AdjustAction,
+    /// Human-readable description of the violation.
+    pub problem: String,
+    pub max_lines: u8,
+    pub char_budget: usize,
+    /// Original verdict, kept for the single-bullet fallback prompts.
+    #[serde(skip)]
+    pub verdict: LineCoverageVerdict,
+}
+
+/// Intermediate type for deserializing the LLM's batch adjust response.
+#[derive(Debug, Deserialize)]
+struct BatchAdjustResponse {
+    bullets: Vec<BatchAdjustedBullet>,
+}
+
+#[derive(Debug, Deserialize)]
+struct BatchAdjustedBullet {
+    index: usize,
+    text: String,
+}
+
 // ────────────────────────────────────────────────────────────────────────────
 // Public entry point
 // ────────────────────────────────────────────────────────────────────────────
 
 const MAX_PASSES: u8 = 3;
 
+/// Default cap on concurrent per-bullet adjust calls.
+pub const DEFAULT_ADJUST_CONCURRENCY: usize = 4;
+
 /// Runs the layout simulation loop on a set of draft bullets.
 ///
 /// Steps per pass:
-/// 1. `spawn_blocking` → `run_single_pass_sync` (CPU-bound width check)
-/// 2. For each violation: async LLM call to expand or compress
+/// 1. `spawn_blocking` → `run_single_pass_sync` (CPU-bound width check), then local
+///    compression of TooLong violators
+/// 2. One batched async LLM call to expand or compress every violation, then local
+///    expansion from `sources` of the expand requests it left unanswered
 /// 3. Update bullet text in place
 ///
 /// After MAX_PASSES, remaining violations are flagged for human review.
+/// `adjust_concurrency` caps the per-bullet calls in flight when a batch call fails.
 pub async fn run_simulation_loop(
     bullets: Vec<DraftBullet>,
     config: &PageConfig,
+    adjust_concurrency: usize,
     parsed_jd: &ParsedJD,
+    sources: &SourceFacts,
     llm: &LlmClient,
 ) -> Result<SimulationResult, AppError> {
-    let mut sim_bullets = init_simulated(bullets);
-    let config_clone = config.clone();
+    let mut sim_bullets = Arc::new(init_simulated(bullets));
+    let shared_config = Arc::new(config.clone());
     let mut total_passes = 0u8;
     let mut llm_calls_made = 0u32;
+    let mut local_compressions = 0u32;
+    let mut local_expansions = 0u32;
+    let mut violations_per_pass: Vec<usize> = Vec::new();
 
-    for _pass in 0..MAX_PASSES {
+    for pass in 1..=MAX_PASSES {
         total_passes += 1;
 
         // CPU-bound pass — spawn_blocking to avoid blocking the async executor.
-        let bullets_snapshot = sim_bullets.clone();
-        let cfg = config_clone.clone();
-        let violations: Vec<(usize, LineCoverageResult)> = tokio::task::spawn_blocking(move || {
+        let bullets_snapshot = Arc::clone(&sim_bullets);
+        let cfg = Arc::clone(&shared_config);
+        let (rewrites, violations) = tokio::task::spawn_blocking(move || {
             let metrics = get_metrics(&cfg.font);
-            run_single_pass_sync(&bullets_snapshot, metrics, &cfg)
+            let violations = run_single_pass_sync(&bullets_snapshot, metrics, &cfg);
+            compress_violations_locally(&bullets_snapshot, violations, metrics, &cfg)
         })
         .await
         .map_err(|e| {
             AppError::Internal(anyhow::anyhow!("spawn_blocking failed in simulation: {e}"))
         })?;
 
+        let found = rewrites.len() + violations.len();
+        violations_per_pass.push(found);
+        LAYOUT.violations_per_pass.observe(found as u64);
+        local_compressions += rewrites.len() as u32;
+        apply_adjustments(
+            bullets_mut(&mut sim_bullets),
+            rewrites.into_iter().collect(),
+            |_| RevisionCause::Compress,
+        );
+        progress::emit(Stage::LayoutPass {
+            pass,
+            violations: violations.len(),
+        })
+        .await;
+
         if violations.is_empty() {
             break;
         }
 
-        // Fix violations with LLM calls (async, not blocking).
-        for (idx, coverage_result) in &violations {
-            let bullet = &mut sim_bullets[*idx];
-            let char_budget = estimate_char_budget(config);
-
-            let adjusted_text = match &coverage_result.verdict {
-                LineCoverageVerdict::TooShort { fill_ratio, .. } => {
-                    llm_calls_made += 1;
-                    expand_bullet(&bullet.text, *fill_ratio, char_budget, parsed_jd, llm)
-                        .await
-                        .unwrap_or_else(|_| bullet.text.clone())
-                }
-
-                LineCoverageVerdict::TooLong { actual_lines } => {
-                    llm_calls_made += 1;
-                    compress_bullet(&bullet.text, *actual_lines, char_budget, parsed_jd, llm)
-                        .await
-                        .unwrap_or_else(|_| bullet.text.clone())
-                }
-
-                LineCoverageVerdict::SecondLineTooShort { fill_ratio } => {
-                    // Line 2 is too short — try expanding to fill it more.
-                    llm_calls_made += 1;
-                    expand_bullet(
-                        &bullet.text,
-                        *fill_ratio,
-                        char_budget * 2, // 2-line budget
-                        parsed_jd,
-                        llm,
-                    )
-                    .await
-                    .unwrap_or_else(|_| bullet.text.clone())
-                }
-
-                LineCoverageVerdict::Satisfies => bullet.text.clone(),
-            };
-
-            if adjusted_text != bullet.text {
-                bullet.text = adjusted_text;
-                bullet.was_adjusted = true;
-            }
-        }
+        // Fix all violations of this pass with one batched LLM call (async, not blocking).
+        let requests: Vec<AdjustRequest> = violations
+            .iter()
+            .filter_map(|(idx, coverage_result)| {
+                let bullet = &sim_bullets[*idx];
+                let char_budget = estimate_char_budget(&config.for_section(&bullet.section));
+                adjust_request_for(
+                    *idx,
+                    &bullet.text,
+                    bullet.line_type,
+                    &coverage_result.verdict,
+                    char_budget,
+                )
+            })
+            .collect();
+
+        let (mut adjusted, calls) =
+            adjust_bullets(&requests, parsed_jd, llm, adjust_concurrency).await;
+        llm_calls_made += calls;
+        let expanded = expand_unanswered_locally(&requests, &adjusted, &sim_bullets, sources);
+        local_expansions += expanded.len() as u32;
+        adjusted.extend(expanded);
+        apply_adjustments(bullets_mut(&mut sim_bullets), adjusted, |idx| {
+            request_cause(&requests, idx)
+        });
     }
 
-    // Final pass: determine verified_line_count and flag remaining violators.
-    let bullets_final = sim_bullets.clone();
-    let cfg_final = config_clone.clone();
-    let final_violations: Vec<(usize, LineCoverageResult)> =
+    // Two-line cap: demote the weakest 2-line bullets beyond MAX_TWO_LINE_BULLETS.
+    let bullets_snapshot = Arc::clone(&sim_bullets);
+    let cfg = Arc::clone(&shared_config);
+    let jd = parsed_jd.clone();
+    let (demotions, rewrites, escalated) = tokio::task::spawn_blocking(move || {
+        let metrics = get_metrics(&cfg.font);
+        let demotions = select_two_line_excess(&bullets_snapshot, metrics, &cfg, &jd);
+        let (rewrites, escalated) =
+            compress_demotions_locally(&bullets_snapshot, &demotions, metrics, &cfg);
+        (demotions, rewrites, escalated)
+    })
+    .await
+    .map_err(|e| {
+        AppError::Internal(anyhow::anyhow!(
+            "spawn_blocking failed selecting two-line demotions: {e}"
+        ))
+    })?;
+
+    local_compressions += rewrites.len() as u32;
+    apply_adjustments(
+        bullets_mut(&mut sim_bullets),
+        rewrites.into_iter().collect(),
+        |_| RevisionCause::Compress,
+    );
+
+    let requests: Vec<AdjustRequest> = escalated
+        .iter()
+        .map(|&idx| {
+            let bullet = &sim_bullets[idx];
+            let char_budget = estimate_char_budget(&config.for_section(&bullet.section));
+            demotion_request(idx, &bullet.text, char_budget)
+        })
+        .collect();
+    let (adjusted, calls) = adjust_bullets(&requests, parsed_jd, llm, adjust_concurrency).await;
+    llm_calls_made += calls;
+    apply_adjustments(bullets_mut(&mut sim_bullets), adjusted, |idx| {
+        request_cause(&requests, idx)
+    });
+
+    // Final pass: measure every bullet's line count and find the remaining violators.
+    let bullets_final = Arc::clone(&sim_bullets);
+    let cfg_final = Arc::clone(&shared_config);
+    let (final_violations, line_counts): (Vec<(usize, LineCoverageResult)>, Vec<u8>) =
         tokio::task::spawn_blocking(move || {
             let metrics = get_metrics(&cfg_final.font);
-            run_single_pass_sync(&bullets_final, metrics, &cfg_final)
+            let violations = run_single_pass_sync(&bullets_final, metrics, &cfg_final);
+            let line_counts = bullets_final
+                .iter()
+                .map(|b| {
+                    let column_cfg = cfg_final.for_section(&b.section);
+                    let (count, _) = simulate_lines(&b.text, metrics, &column_cfg);
+                    count.max(1) // treat empty string as 1 line
+                })
+                .collect();
+            (violations, line_counts)
         })
         .await
         .map_err(|e| {
@@ -168,6 +277,7 @@ pub async fn run_simulation_loop(
             ))
         })?;
 
+    let mut sim_bullets = Arc::unwrap_or_clone(sim_bullets);
     let violations_remaining = final_violations.len() as u32;
     let mut flagged_count = 0u32;
 
@@ -184,37 +294,62 @@ pub async fn run_simulation_loop(
         );
     }
 
-    // Update verified_line_count for all bullets via a final blocking measurement.
-    let bullets_snapshot = sim_bullets.clone();
-    let cfg_measure = config_clone.clone();
-    let line_counts: Vec<u8> = tokio::task::spawn_blocking(move || {
-        let metrics = get_metrics(&cfg_measure.font);
-        bullets_snapshot
-            .iter()
-            .map(|b| {
-                let (count, _) =
-                    crate::layout::contract::simulate_lines(&b.text, metrics, &cfg_measure);
-                count.max(1) // treat empty string as 1 line
-            })
-            .collect()
-    })
-    .await
-    .map_err(|e| {
-        AppError::Internal(anyhow::anyhow!(
-            "spawn_blocking failed updating line counts: {e}"
-        ))
-    })?;
-
     for (bullet, &count) in sim_bullets.iter_mut().zip(line_counts.iter()) {
         bullet.verified_line_count = count;
     }
 
+    // Demotions the LLM could not get down to 1 line: flag them while still over the cap.
+    let mut two_line_total = sim_bullets
+        .iter()
+        .filter(|b| b.line_type == LineType::Bullet && b.verified_line_count == 2)
+        .count();
+    for &idx in &demotions {
+        if two_line_total <= MAX_TWO_LINE_BULLETS {
+            break;
+        }
+        let bullet = &mut sim_bullets[idx];
+        if bullet.verified_line_count == 2 && !bullet.flagged_for_review {
+            bullet.flagged_for_review = true;
+            flagged_count += 1;
+            two_line_total -= 1;
+        }
+    }
+    if two_line_total > MAX_TWO_LINE_BULLETS {
+        warn!(
+            two_line_bullets = two_line_total,
+            max = MAX_TWO_LINE_BULLETS,
+            "Layout simulation: two-line cap still exceeded after demotion"
+        );
+    }
+
+    LAYOUT.simulation_runs.inc();
+    LAYOUT.passes_per_run.observe(total_passes as u64);
+    LAYOUT.bullets_simulated.inc_by(sim_bullets.len() as u64);
+    LAYOUT.bullets_flagged.inc_by(flagged_count as u64);
+    LAYOUT.local_compressions.inc_by(local_compressions as u64);
+    LAYOUT.local_expansions.inc_by(local_expansions as u64);
+    info!(
+        target: "layout_metrics",
+        font = ?config.font,
+        font_size_pt = config.font_size_pt,
+        bullets = sim_bullets.len(),
+        passes = total_passes,
+        violations_per_pass = ?violations_per_pass,
+        local_compressions,
+        local_expansions,
+        llm_calls = llm_calls_made,
+        flagged = flagged_count,
+        "Layout simulation metrics"
+    );
+
     Ok(SimulationResult {
         bullets: sim_bullets,
         total_passes,
         violations_remaining,
         flagged_count,
         llm_calls_made,
+        local_compressions,
+        local_expansions,
     })
 }
 
@@ -225,18 +360,27 @@ pub async fn run_simulation_loop(
 /// Runs one simulation pass over all bullets.
 ///
 /// Returns `(bullet_index, LineCoverageResult)` for each violating bullet only.
-/// Takes owned/borrowed data compatible with `spawn_blocking`'s `'static` requirement
-/// (callers pass cloned `Vec<SimulatedBullet>` and `PageConfig`).
-pub(crate) fn run_single_pass_sync(
+/// Each bullet is checked against the rules of its `line_type`; on a two-column page,
+/// against its own column's width.
+/// Called inside `spawn_blocking` on the loop's shared `Arc` snapshots. Word widths
+/// are measured once per pass (`WordWidthMemo`).
+pub fn run_single_pass_sync(
     bullets: &[SimulatedBullet],
     metrics: &FontMetricTable,
     config: &PageConfig,
 ) -> Vec<(usize, LineCoverageResult)> {
+    let mut memo = WordWidthMemo::new(metrics);
     bullets
         .iter()
         .enumerate()
         .filter_map(|(i, b)| {
-            let result = check_contract(i, &b.text, metrics, config);
+            let result = check_line_contract_memo(
+                i,
+                &b.text,
+                b.line_type,
+                &mut memo,
+                &config.for_section(&b.section),
+            );
             if matches!(result.verdict, LineCoverageVerdict::Satisfies) {
                 None
             } else {
@@ -246,10 +390,262 @@ pub(crate) fn run_single_pass_sync(
         .collect()
 }
 
+/// Compressed texts produced by the local compressor, keyed by bullet index.
+type LocalRewrites = Vec<(usize, String)>;
+
+/// Tries the local compressor on every TooLong violation.
+///
+/// Returns `(rewrites, remaining)`: `rewrites` are compressed texts to apply, and
+/// `remaining` are the violations still needing an LLM call, re-checked against the
+/// compressed text where one was produced.
+pub(crate) fn compress_violations_locally(
+    bullets: &[SimulatedBullet],
+    violations: Vec<(usize, LineCoverageResult)>,
+    metrics: &FontMetricTable,
+    config: &PageConfig,
+) -> (LocalRewrites, Vec<(usize, LineCoverageResult)>) {
+    let mut rewrites = Vec::new();
+    let mut remaining = Vec::new();
+
+    for (idx, result) in violations {
+        if !matches!(result.verdict, LineCoverageVerdict::TooLong { .. }) {
+            remaining.push((idx, result));
+            continue;
+        }
+        let bullet = &bullets[idx];
+        let compressed = compress_locally(&bullet.text);
+        if compressed == bullet.text {
+            remaining.push((idx, result));
+            continue;
+        }
+        let recheck = check_line_contract(
+            idx,
+            &compressed,
+            bullet.line_type,
+            metrics,
+            &config.for_section(&bullet.section),
+        );
+        rewrites.push((idx, compressed));
+        if !matches!(recheck.verdict, LineCoverageVerdict::Satisfies) {
+            remaining.push((idx, recheck));
+        }
+    }
+
+    (rewrites, remaining)
+}
+
+/// Tries the local compressor on each two-line demotion.
+///
+/// A rewrite is kept only if it lands on one line and satisfies the contract; the
+/// other demotions are returned unchanged for the LLM.
+pub(crate) fn compress_demotions_locally(
+    bullets: &[SimulatedBullet],
+    demotions: &[usize],
+    metrics: &FontMetricTable,
+    config: &PageConfig,
+) -> (LocalRewrites, Vec<usize>) {
+    let mut rewrites = Vec::new();
+    let mut escalated = Vec::new();
+
+    for &idx in demotions {
+        let bullet = &bullets[idx];
+        let column_cfg = config.for_section(&bullet.section);
+        let compressed = compress_locally(&bullet.text);
+        let result = check_contract(idx, &compressed, metrics, &column_cfg);
+        if compressed != bullet.text
+            && result.simulated_line_count == 1
+            && matches!(result.verdict, LineCoverageVerdict::Satisfies)
+        {
+            rewrites.push((idx, compressed));
+        } else {
+            escalated.push(idx);
+        }
+    }
+
+    (rewrites, escalated)
+}
+
+/// Picks the 2-line bullets to demote so at most `MAX_TWO_LINE_BULLETS` remain.
+///
+/// Only `LineType::Bullet`s count. They are ranked by promotion score
+/// (`PromotionScore::rank`); the lowest-ranked excess is returned, weakest first. Empty
+/// when the page is within the cap.
+pub(crate) fn select_two_line_excess(
+    bullets: &[SimulatedBullet],
+    metrics: &FontMetricTable,
+    config: &PageConfig,
+    parsed_jd: &ParsedJD,
+) -> Vec<usize> {
+    let mut memo = WordWidthMemo::new(metrics);
+    let mut two_liners: Vec<(usize, f32)> = bullets
+        .iter()
+        .enumerate()
+        .filter(|(_, b)| {
+            b.line_type == LineType::Bullet
+                && simulate_lines_memo(&b.text, &mut memo, &config.for_section(&b.section)).0 == 2
+        })
+        .map(|(i, b)| {
+            let score = score_promotion_text(&b.text, &b.jd_keywords_used, parsed_jd);
+            (i, score.rank())
+        })
+        .collect();
+
+    if two_liners.len() <= MAX_TWO_LINE_BULLETS {
+        return vec![];
+    }
+
+    two_liners.sort_by(|a, b| a.1.partial_cmp(&b.1).unwrap_or(std::cmp::Ordering::Equal));
+    let excess = two_liners.len() - MAX_TWO_LINE_BULLETS;
+    two_liners
+        .into_iter()
+        .take(excess)
+        .map(|(i, _)| i)
+        .collect()
+}
+
 // ────────────────────────────────────────────────────────────────────────────
 // LLM adjust calls
 // ────────────────────────────────────────────────────────────────────────────
 
+/// Adjusts every request with a single batched LLM call.
+///
+/// Returns the adjusted texts keyed by bullet index plus the number of LLM calls made.
+/// Indices the model drops or answers with empty text are left out and get retried on
+/// the next pass. If the batch call itself fails, each request falls back to its own
+/// expand/compress call, with up to `concurrency` calls in flight.
+///
+/// An offline client adjusts nothing: the local compressor's rewrites are all the
+/// layout gets, and remaining violators end up flagged for review.
+pub(crate) async fn adjust_bullets(
+    requests: &[AdjustRequest],
+    parsed_jd: &ParsedJD,
+    llm: &LlmClient,
+    concurrency: usize,
+) -> (HashMap<usize, String>, u32) {
+    if requests.is_empty() || llm.is_offline() {
+        return (HashMap::new(), 0);
+    }
+    record_adjust_requests(requests);
+
+    let prompt = build_batch_adjust_prompt(requests, parsed_jd);
+    match llm
+        .call_json::<BatchAdjustResponse>(&prompt, BATCH_ADJUST_SYSTEM)
+        .await
+    {
+        Ok(response) => {
+            LAYOUT.llm_calls.inc();
+            (collect_batch_response(requests, response), 1)
+        }
+        Err(e) => {
+            warn!(
+                violators = requests.len(),
+                "Batch adjust LLM call failed, falling back to per-bullet calls: {e}"
+            );
+            let adjusted = adjust_concurrently(requests, parsed_jd, llm, concurrency).await;
+            LAYOUT.llm_calls.inc_by(1 + requests.len() as u64);
+            (adjusted, 1 + requests.len() as u32)
+        }
+    }
+}
+
+/// Local expansions for the expand requests missing from `adjusted`, from the facts of
+/// each bullet's source entry. Requests whose bullet has nothing new that fits are left
+/// out, so the bullet keeps its text for the next pass.
+pub(crate) fn expand_unanswered_locally(
+    requests: &[AdjustRequest],
+    adjusted: &HashMap<usize, String>,
+    bullets: &[SimulatedBullet],
+    sources: &SourceFacts,
+) -> HashMap<usize, String> {
+    requests
+        .iter()
+        .filter(|r| r.action == AdjustAction::Expand && !adjusted.contains_key(&r.index))
+        .filter_map(|r| {
+            let facts = sources.get(&bullets.get(r.index)?.source_entry_id)?;
+            expand_locally(&r.text, facts, r.char_budget).map(|text| (r.index, text))
+        })
+        .collect()
+}
+
+/// Counts expand vs compress requests sent to the LLM.
+fn record_adjust_requests(requests: &[AdjustRequest]) {
+    let expands = requests
+        .iter()
+        .filter(|r| r.action == AdjustAction::Expand)
+        .count();
+    LAYOUT.expand_requests.inc_by(expands as u64);
+    LAYOUT
+        .compress_requests
+        .inc_by((requests.len() - expands) as u64);
+}
+
+/// Runs one expand/compress call per request, at most `concurrency` at a time.
+/// Failed calls are dropped so the bullet keeps its text for the next pass.
+async fn adjust_concurrently(
+    requests: &[AdjustRequest],
+    parsed_jd: &ParsedJD,
+    llm: &LlmClient,
+    concurrency: usize,
+) -> HashMap<usize, String> {
+    // Owned requests: a stream over borrowed items trips the `Send` check in axum handlers.
+    let results: Vec<(usize, Result<String, AppError>)> = stream::iter(requests.to_vec())
+        .map(|request| async move {
+            let result = adjust_single(&request, parsed_jd, llm).await;
+            (request.index, result)
+        })
+        .buffer_unordered(concurrency.max(1))
+        .collect()
+        .await;
+
+    results
+        .into_iter()
+        .filter_map(|(index, result)| result.ok().map(|text| (index, text)))
+        .collect()
+}
+
+/// Adjusts one request with the dedicated expand or compress prompt.
+async fn adjust_single(
+    request: &AdjustRequest,
+    parsed_jd: &ParsedJD,
+    llm: &LlmClient,
+) -> Result<String, AppError> {
+    match (&request.action, &request.verdict) {
+        (
+            AdjustAction::Expand,
+            LineCoverageVerdict::TooShort { fill_ratio, .. }
+            | LineCoverageVerdict::SecondLineTooShort { fill_ratio },
+        ) => {
+            expand_bullet(
+                &request.text,
+                *fill_ratio,
+                request.char_budget,
+                parsed_jd,
+                llm,
+            )
+            .await
+        }
+        (
+            AdjustAction::Compress,
+            LineCoverageVerdict::TooLong { actual_lines }
+            | LineCoverageVerdict::LastLineTooShort {
+                lines: actual_lines,
+                ..
+            },
+        ) => {
+            compress_bullet(
+                &request.text,
+                *actual_lines,
+                request.max_lines,
+                request.char_budget,
+                parsed_jd,
+                llm,
+            )
+            .await
+        }
+        _ => Ok(request.text.clone()),
+    }
+}
+
 /// Calls the LLM to expand a bullet that doesn't fill enough horizontal space.
 async fn expand_bullet(
     text: &str,
@@ -266,15 +662,16 @@ async fn expand_bullet(
     Ok(result.text)
 }
 
-/// Calls the LLM to compress a bullet that wraps to 3+ lines.
+/// Calls the LLM to compress a bullet down to at most `max_lines` printed lines.
 async fn compress_bullet(
     text: &str,
     actual_lines: u8,
+    max_lines: u8,
     char_budget: usize,
     parsed_jd: &ParsedJD,
     llm: &LlmClient,
 ) -> Result<String, AppError> {
-    let prompt = build_compress_prompt(text, actual_lines, char_budget, parsed_jd);
+    let prompt = build_compress_prompt(text, actual_lines, max_lines, char_budget, parsed_jd);
     let result: AdjustedBullet = llm
         .call_json(&prompt, COMPRESS_SYSTEM)
         .await
@@ -304,6 +701,7 @@ pub(crate) fn build_expand_prompt(
 pub(crate) fn build_compress_prompt(
     text: &str,
     actual_lines: u8,
+    max_lines: u8,
     char_budget: usize,
     parsed_jd: &ParsedJD,
 ) -> String {
@@ -311,14 +709,182 @@ pub(crate) fn build_compress_prompt(
     COMPRESS_PROMPT_TEMPLATE
         .replace("{bullet_text}", text)
         .replace("{actual_lines}", &actual_lines.to_string())
+        .replace("{max_lines}", &max_lines.to_string())
         .replace("{char_budget}", &char_budget.to_string())
         .replace("{jd_keywords}", &jd_keywords)
 }
 
+pub(crate) fn build_batch_adjust_prompt(
+    requests: &[AdjustRequest],
+    parsed_jd: &ParsedJD,
+) -> String {
+    let jd_keywords = top_jd_keywords(parsed_jd, 5);
+    let bullets_json = serde_json::to_string_pretty(requests).unwrap_or_else(|_| "[]".to_string());
+    BATCH_ADJUST_PROMPT_TEMPLATE
+        .replace("{bullets_json}", &bullets_json)
+        .replace("{jd_keywords}", &jd_keywords)
+}
+
 // ────────────────────────────────────────────────────────────────────────────
 // Internal helpers
 // ────────────────────────────────────────────────────────────────────────────
 
+/// Builds the batch request for a contract violation. `None` for satisfied bullets.
+///
+/// Budgets match the single-bullet prompts: a second line that is too short gets a
+/// two-line character budget. A paragraph whose last line is too short is tightened to
+/// one line fewer.
+pub(crate) fn adjust_request_for(
+    index: usize,
+    text: &str,
+    line_type: LineType,
+    verdict: &LineCoverageVerdict,
+    char_budget: usize,
+) -> Option<AdjustRequest> {
+    let mut max_lines = max_lines_for(line_type);
+    let (action, problem, budget) = match verdict {
+        LineCoverageVerdict::Satisfies => return None,
+        LineCoverageVerdict::TooShort {
+            fill_ratio,
+            required,
+        } => (
+            AdjustAction::Expand,
+            format!(
+                "fills {:.0}% of one line (minimum {:.0}%)",
+                fill_ratio * 100.0,
+                required * 100.0
+            ),
+            char_budget,
+        ),
+        LineCoverageVerdict::TooLong { actual_lines } => (
+            AdjustAction::Compress,
+            format!("wraps to {actual_lines} printed lines (maximum {max_lines})"),
+            // Aim a line under the maximum, as a 2-line bullet aims for one line.
+            char_budget * (max_lines as usize - 1).max(1),
+        ),
+        LineCoverageVerdict::SecondLineTooShort { fill_ratio } => (
+            AdjustAction::Expand,
+            format!(
+                "second line fills only {:.0}% of the line",
+                fill_ratio * 100.0
+            ),
+            char_budget * 2,
+        ),
+        LineCoverageVerdict::LastLineTooShort { lines, fill_ratio } => {
+            max_lines = lines - 1;
+            (
+                AdjustAction::Compress,
+                format!(
+                    "last of {lines} lines fills only {:.0}% of the line",
+                    fill_ratio * 100.0
+                ),
+                char_budget * max_lines as usize,
+            )
+        }
+    };
+
+    Some(AdjustRequest {
+        index,
+        text: text.to_string(),
+        line_type,
+        action,
+        problem,
+        max_lines,
+        char_budget: budget,
+        verdict: verdict.clone(),
+    })
+}
+
+/// Builds the batch request compressing a 2-line bullet over the page-wide cap to 1 line.
+pub(crate) fn demotion_request(index: usize, text: &str, char_budget: usize) -> AdjustRequest {
+    AdjustRequest {
+        index,
+        text: text.to_string(),
+        line_type: LineType::Bullet,
+        action: AdjustAction::Compress,
+        problem: format!(
+            "2 printed lines, but the page allows only {MAX_TWO_LINE_BULLETS} two-line bullets"
+        ),
+        max_lines: 1,
+        char_budget,
+        verdict: LineCoverageVerdict::TooLong { actual_lines: 2 },
+    }
+}
+
+/// Builds the batch request expanding a 1-line bullet into two full lines, used by
+/// page-fill remediation when the page has too much whitespace.
+pub(crate) fn promotion_request(
+    index: usize,
+    text: &str,
+    fill_ratio: f32,
+    required: f32,
+    char_budget: usize,
+) -> AdjustRequest {
+    AdjustRequest {
+        index,
+        text: text.to_string(),
+        line_type: LineType::Bullet,
+        action: AdjustAction::Expand,
+        problem: "page has too much whitespace; rewrite to fill two full lines".to_string(),
+        max_lines: 2,
+        char_budget: char_budget * 2,
+        verdict: LineCoverageVerdict::TooShort {
+            fill_ratio,
+            required,
+        },
+    }
+}
+
+/// Keeps only batch answers for requested indices with non-empty text.
+fn collect_batch_response(
+    requests: &[AdjustRequest],
+    response: BatchAdjustResponse,
+) -> HashMap<usize, String> {
+    let mut adjusted = HashMap::new();
+    for bullet in response.bullets {
+        let text = bullet.text.trim();
+        if text.is_empty() || !requests.iter().any(|r| r.index == bullet.index) {
+            continue;
+        }
+        adjusted
+            .entry(bullet.index)
+            .or_insert_with(|| text.to_string());
+    }
+    adjusted
+}
+
+/// The loop's bullets for writing. Copies them only if a blocking task still holds a
+/// snapshot, which none does once joined.
+fn bullets_mut(bullets: &mut Arc<Vec<SimulatedBullet>>) -> &mut [SimulatedBullet] {
+    Arc::make_mut(bullets).as_mut_slice()
+}
+
+/// Writes adjusted texts back, marking changed bullets as adjusted and recording each
+/// change as a revision with `cause(index)`.
+fn apply_adjustments(
+    bullets: &mut [SimulatedBullet],
+    adjusted: HashMap<usize, String>,
+    cause: impl Fn(usize) -> RevisionCause,
+) {
+    for (idx, text) in adjusted {
+        let Some(bullet) = bullets.get_mut(idx) else {
+            continue;
+        };
+        if text != bullet.text {
+            bullet.revise(text, cause(idx));
+            bullet.was_adjusted = true;
+        }
+    }
+}
+
+/// The revision cause of an LLM rewrite of bullet `index`: what its request asked for.
+fn request_cause(requests: &[AdjustRequest], index: usize) -> RevisionCause {
+    match requests.iter().find(|r| r.index == index).map(|r| r.action) {
+        Some(AdjustAction::Expand) => RevisionCause::Expand,
+        _ => RevisionCause::Compress,
+    }
+}
+
 /// Converts a `Vec<DraftBullet>` into the initial `Vec<SimulatedBullet>` for simulation.
 pub(crate) fn init_simulated(bullets: Vec<DraftBullet>) -> Vec<SimulatedBullet> {
     bullets
@@ -327,16 +893,19 @@ pub(crate) fn init_simulated(bullets: Vec<DraftBullet>) -> Vec<SimulatedBullet>
             text: b.text,
             source_entry_id: b.source_entry_id,
             section: b.section,
+            line_type: LineType::Bullet,
             verified_line_count: b.line_estimate, // will be overwritten by simulation
             jd_keywords_used: b.jd_keywords_used,
             was_adjusted: false,
             flagged_for_review: false,
+            revisions: Vec::new(),
         })
         .collect()
 }
 
 /// Estimates the maximum character count for a 1-line bullet at the current config.
-fn estimate_char_budget(config: &PageConfig) -> usize {
+/// Pass the column config (`PageConfig::for_section`) on two-column pages.
+pub(crate) fn estimate_char_budget(config: &PageConfig) -> usize {
     let metrics = get_metrics(&config.font);
     // text_width_em / average_char_width gives approximate chars per line
     (config.text_width_em / metrics.average_char_width).round() as usize
@@ -365,9 +934,10 @@ fn top_jd_keywords(parsed_jd: &ParsedJD, n: usize) -> String {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::generation::jd_parser::{JDTone, KeywordEntry, ParsedJD, Requirement, RoleSignals};
     use crate::layout::contract::LineCoverageVerdict;
     use crate::layout::font_metrics::{default_page_config, get_metrics, FontFamily};
+    use templar_types::generation::TextRevision;
+    use templar_types::jd::{JDTone, KeywordEntry, ParsedJD, Requirement, RoleSignals};
     use uuid::Uuid;
 
     fn make_page_config() -> PageConfig {
@@ -462,10 +1032,12 @@ mod tests {
             text: "Built it.".to_string(),
             source_entry_id: Uuid::new_v4(),
             section: "experience".to_string(),
+            line_type: LineType::Bullet,
             verified_line_count: 1,
             jd_keywords_used: vec![],
             was_adjusted: false,
             flagged_for_review: false,
+            revisions: Vec::new(),
         };
 
         let violations = run_single_pass_sync(&[bullet], metrics, &config);
@@ -486,10 +1058,12 @@ mod tests {
             text: long_text,
             source_entry_id: Uuid::new_v4(),
             section: "experience".to_string(),
+            line_type: LineType::Bullet,
             verified_line_count: 1,
             jd_keywords_used: vec![],
             was_adjusted: false,
             flagged_for_review: false,
+            revisions: Vec::new(),
         };
 
         let violations = run_single_pass_sync(&[bullet], metrics, &config);
@@ -500,6 +1074,109 @@ mod tests {
         ));
     }
 
+    #[test]
+    fn test_single_pass_checks_sidebar_bullets_against_sidebar_width() {
+        use crate::layout::font_metrics::TwoColumnLayout;
+
+        // Sidebar is a third of the main column's width
+        let config = make_page_config().with_two_columns(TwoColumnLayout {
+            sidebar_fraction: 0.25,
+            ..TwoColumnLayout::default_sidebar()
+        });
+        let metrics = get_metrics(&config.font);
+        // Fills ~90% of the main column: fine there, 3 lines in the sidebar
+        let main_width = config.for_section("experience").text_width_em;
+        let mut text = String::new();
+        while metrics.measure_str(&format!("{text} word")) < main_width * 0.9 {
+            text.push_str(" word");
+        }
+        let make = |section: &str| SimulatedBullet {
+            text: text.trim().to_string(),
+            source_entry_id: Uuid::new_v4(),
+            section: section.to_string(),
+            line_type: LineType::Bullet,
+            verified_line_count: 1,
+            jd_keywords_used: vec![],
+            was_adjusted: false,
+            flagged_for_review: false,
+            revisions: Vec::new(),
+        };
+
+        let violations =
+            run_single_pass_sync(&[make("experience"), make("skills")], metrics, &config);
+        assert_eq!(violations.len(), 1, "only the sidebar bullet violates");
+        assert_eq!(violations[0].0, 1);
+        assert!(matches!(
+            violations[0].1.verdict,
+            LineCoverageVerdict::TooLong { .. }
+        ));
+    }
+
+    // ── two-line cap ────────────────────────────────────────────────────────
+
+    /// A bullet padded with filler until it wraps to ~1.5 lines at `config`.
+    fn two_line_bullet(prefix: &str, keywords: &[&str], config: &PageConfig) -> SimulatedBullet {
+        let metrics = get_metrics(&config.font);
+        let mut text = prefix.to_string();
+        while metrics.measure_str(&text) < config.text_width_em * 1.5 {
+            text.push_str(" across the platform");
+        }
+        SimulatedBullet {
+            text,
+            source_entry_id: Uuid::new_v4(),
+            section: "experience".to_string(),
+            line_type: LineType::Bullet,
+            verified_line_count: 2,
+            jd_keywords_used: keywords.iter().map(|k| k.to_string()).collect(),
+            was_adjusted: false,
+            flagged_for_review: false,
+            revisions: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn test_two_line_cap_within_limit_selects_nothing() {
+        let config = make_page_config();
+        let bullets: Vec<SimulatedBullet> = (0..MAX_TWO_LINE_BULLETS)
+            .map(|_| two_line_bullet("Shipped Rust services", &["Rust"], &config))
+            .collect();
+        let picked = select_two_line_excess(
+            &bullets,
+            get_metrics(&config.font),
+            &config,
+            &make_parsed_jd(),
+        );
+        assert!(picked.is_empty());
+    }
+
+    #[test]
+    fn test_two_line_cap_demotes_lowest_promotion_scores() {
+        let config = make_page_config();
+        let strong = "Cut p99 latency 40% in distributed Rust services";
+        let bullets = vec![
+            two_line_bullet(strong, &["Rust", "distributed"], &config),
+            two_line_bullet("Helped with various internal tooling", &[], &config),
+            two_line_bullet(strong, &["Rust", "distributed"], &config),
+            two_line_bullet("Reduced costs by 20% with Rust", &["Rust"], &config),
+            two_line_bullet(strong, &["Rust", "distributed"], &config),
+        ];
+        let picked = select_two_line_excess(
+            &bullets,
+            get_metrics(&config.font),
+            &config,
+            &make_parsed_jd(),
+        );
+        // 5 two-liners, cap 3 → the two weakest, weakest first
+        assert_eq!(picked, vec![1, 3]);
+    }
+
+    #[test]
+    fn test_build_compress_prompt_targets_requested_line_count() {
+        let prompt = build_compress_prompt("Two-line bullet", 2, 1, 82, &make_parsed_jd());
+        assert!(prompt.contains("maximum allowed: 1"));
+        assert!(!prompt.contains("{max_lines}"));
+    }
+
     // ── prompt builders ─────────────────────────────────────────────────────
 
     #[test]
@@ -520,7 +1197,8 @@ mod tests {
     #[test]
     fn test_build_compress_prompt_contains_line_count() {
         let jd = make_parsed_jd();
-        let prompt = build_compress_prompt("A very long bullet that goes on and on", 4, 164, &jd);
+        let prompt =
+            build_compress_prompt("A very long bullet that goes on and on", 4, 2, 164, &jd);
         assert!(
             prompt.contains("4"),
             "prompt should contain actual line count"
@@ -546,4 +1224,215 @@ mod tests {
         let sim = init_simulated(vec![bullet]);
         assert!(!sim[0].flagged_for_review);
     }
+
+    #[tokio::test]
+    async fn test_offline_client_leaves_violators_flagged() {
+        let drafts = vec![make_draft_bullet("Built Rust services")];
+        let result = run_simulation_loop(
+            drafts,
+            &make_page_config(),
+            DEFAULT_ADJUST_CONCURRENCY,
+            &make_parsed_jd(),
+            &SourceFacts::new(),
+            &LlmClient::offline(),
+        )
+        .await
+        .unwrap();
+        assert_eq!(result.llm_calls_made, 0);
+        assert_eq!(result.bullets[0].text, "Built Rust services");
+        assert!(result.bullets[0].flagged_for_review);
+    }
+
+    // ── batch adjust ────────────────────────────────────────────────────────
+
+    #[test]
+    fn test_adjust_request_for_maps_verdicts() {
+        let short = LineCoverageVerdict::TooShort {
+            fill_ratio: 0.5,
+            required: 0.8,
+        };
+        let req = adjust_request_for(3, "Built X", LineType::Bullet, &short, 90).unwrap();
+        assert_eq!(req.index, 3);
+        assert_eq!(req.action, AdjustAction::Expand);
+        assert_eq!(req.char_budget, 90);
+        assert!(req.problem.contains("50%"), "problem: {}", req.problem);
+
+        let long = LineCoverageVerdict::TooLong { actual_lines: 3 };
+        let req = adjust_request_for(0, "Built X", LineType::Bullet, &long, 90).unwrap();
+        assert_eq!(req.action, AdjustAction::Compress);
+        assert_eq!(req.max_lines, 2);
+
+        let second = LineCoverageVerdict::SecondLineTooShort { fill_ratio: 0.2 };
+        let req = adjust_request_for(0, "Built X", LineType::Bullet, &second, 90).unwrap();
+        assert_eq!(req.action, AdjustAction::Expand);
+        assert_eq!(
+            req.char_budget, 180,
+            "second-line expansion gets a 2-line budget"
+        );
+
+        assert!(adjust_request_for(
+            0,
+            "Built X",
+            LineType::Bullet,
+            &LineCoverageVerdict::Satisfies,
+            90
+        )
+        .is_none());
+    }
+
+    #[test]
+    fn test_adjust_request_for_follows_line_type_rules() {
+        let long = LineCoverageVerdict::TooLong { actual_lines: 2 };
+        let req = adjust_request_for(0, "Education", LineType::EntryHeader, &long, 90).unwrap();
+        assert_eq!(req.max_lines, 1);
+        assert_eq!(req.char_budget, 90);
+
+        let long = LineCoverageVerdict::TooLong { actual_lines: 5 };
+        let req = adjust_request_for(0, "Summary", LineType::SummaryParagraph, &long, 90).unwrap();
+        assert_eq!(req.max_lines, 4);
+        assert!(
+            req.problem.contains("maximum 4"),
+            "problem: {}",
+            req.problem
+        );
+
+        let widow = LineCoverageVerdict::LastLineTooShort {
+            lines: 3,
+            fill_ratio: 0.1,
+        };
+        let req = adjust_request_for(0, "Summary", LineType::SummaryParagraph, &widow, 90).unwrap();
+        assert_eq!(req.action, AdjustAction::Compress);
+        assert_eq!(req.max_lines, 2);
+        assert_eq!(req.char_budget, 180);
+    }
+
+    #[test]
+    fn test_build_batch_adjust_prompt_lists_every_violator() {
+        let jd = make_parsed_jd();
+        let requests = vec![
+            adjust_request_for(
+                1,
+                "Built a cache",
+                LineType::Bullet,
+                &LineCoverageVerdict::TooShort {
+                    fill_ratio: 0.4,
+                    required: 0.8,
+                },
+                90,
+            )
+            .unwrap(),
+            demotion_request(4, "Led the migration of twelve services", 90),
+        ];
+        let prompt = build_batch_adjust_prompt(&requests, &jd);
+        assert!(prompt.contains("Built a cache"));
+        assert!(prompt.contains("Led the migration of twelve services"));
+        assert!(prompt.contains("\"index\": 4"));
+        assert!(prompt.contains("\"action\": \"compress\""));
+        assert!(prompt.contains("\"max_lines\": 1"));
+        assert!(prompt.contains("\"line_type\": \"bullet\""));
+        assert!(prompt.contains("Rust"));
+        assert!(!prompt.contains("{bullets_json}"));
+        assert!(!prompt.contains("verdict"), "verdict is internal only");
+    }
+
+    #[test]
+    fn test_collect_batch_response_drops_unknown_and_empty_entries() {
+        let requests = vec![
+            demotion_request(0, "a", 90),
+            demotion_request(2, "b", 90),
+            demotion_request(5, "c", 90),
+        ];
+        let response: BatchAdjustResponse = serde_json::from_str(
+            r#"{"bullets": [
+                {"index": 0, "text": "  Shorter a  "},
+                {"index": 2, "text": ""},
+                {"index": 9, "text": "Not requested"},
+                {"index": 0, "text": "Duplicate a"}
+            ]}"#,
+        )
+        .unwrap();
+        let adjusted = collect_batch_response(&requests, response);
+        assert_eq!(adjusted.len(), 1);
+        assert_eq!(adjusted[&0], "Shorter a");
+    }
+
+    #[test]
+    fn test_apply_adjustments_marks_only_changed_bullets() {
+        let mut bullets = init_simulated(vec![
+            make_draft_bullet("Same text"),
+            make_draft_bullet("Old text"),
+        ]);
+        let adjusted = HashMap::from([
+            (0, "Same text".to_string()),
+            (1, "New text".to_string()),
+            (7, "Out of range".to_string()),
+        ]);
+        apply_adjustments(&mut bullets, adjusted, |_| RevisionCause::Expand);
+        assert!(!bullets[0].was_adjusted);
+        assert!(bullets[0].revisions.is_empty());
+        assert!(bullets[1].was_adjusted);
+        assert_eq!(bullets[1].text, "New text");
+        assert_eq!(
+            bullets[1].revisions,
+            vec![TextRevision {
+                previous_text: "Old text".to_string(),
+                cause: RevisionCause::Expand,
+            }]
+        );
+    }
+
+    // ── local compression ───────────────────────────────────────────────────
+
+    #[test]
+    fn test_local_compression_resolves_filler_only_overflow() {
+        let config = make_page_config();
+        let metrics = get_metrics(&config.font);
+        // A two-line bullet pushed onto a third line purely by soft qualifiers.
+        let core = two_line_bullet("Led", &[], &config).text;
+        let mut padded = core.clone();
+        while simulate_lines(&padded, metrics, &config).0 < 3 {
+            padded.push_str(" various significant");
+        }
+        let bullets = init_simulated(vec![make_draft_bullet(&padded)]);
+        let violations = run_single_pass_sync(&bullets, metrics, &config);
+        assert!(matches!(
+            violations[0].1.verdict,
+            LineCoverageVerdict::TooLong { .. }
+        ));
+
+        let (rewrites, remaining) =
+            compress_violations_locally(&bullets, violations, metrics, &config);
+        assert_eq!(rewrites.len(), 1);
+        assert_eq!(rewrites[0].1, core);
+        assert!(
+            remaining
+                .iter()
+                .all(|(_, r)| !matches!(r.verdict, LineCoverageVerdict::TooLong { .. })),
+            "compressed text no longer wraps to 3 lines"
+        );
+    }
+
+    #[test]
+    fn test_local_compression_passes_through_other_violations() {
+        let config = make_page_config();
+        let metrics = get_metrics(&config.font);
+        let bullets = init_simulated(vec![make_draft_bullet("Built various tools")]);
+        let violations = run_single_pass_sync(&bullets, metrics, &config);
+        let (rewrites, remaining) =
+            compress_violations_locally(&bullets, violations, metrics, &config);
+        assert!(rewrites.is_empty(), "TooShort bullets are never compressed");
+        assert_eq!(remaining.len(), 1);
+    }
+
+    #[test]
+    fn test_demotion_without_filler_escalates_to_llm() {
+        let config = make_page_config();
+        let metrics = get_metrics(&config.font);
+        let bullet = two_line_bullet("Led", &[], &config);
+        let text = bullet.text.clone();
+        let bullets = vec![bullet];
+        let (rewrites, escalated) = compress_demotions_locally(&bullets, &[0], metrics, &config);
+        assert!(rewrites.is_empty(), "nothing to strip in {text}");
+        assert_eq!(escalated, vec![0]);
+    }
 }
@@ -0,0 +1,47 @@
+//! Templar API server. The modules live in this library so that benches can link
+//! against them; main.rs is the `api` binary that wires them together.
+
+pub mod admin;
+pub mod api_version;
+pub mod applications;
+pub mod audit;
+pub mod auth;
+pub mod bullet_revisions;
+pub mod comments;
+pub mod config;
+pub mod context;
+pub mod cors;
+pub mod db;
+pub mod errors;
+pub mod etag;
+pub mod export;
+pub mod generation;
+pub mod grounding;
+pub mod integrations;
+pub mod interop;
+pub mod jobs;
+pub mod layout;
+pub mod llm_client;
+pub mod metrics;
+pub mod models;
+pub mod notifications;
+pub mod orgs;
+pub mod personas;
+pub mod progress;
+pub mod projects;
+pub mod quota;
+pub mod rate_limit;
+pub mod render;
+pub mod reporting;
+pub mod request_id;
+pub mod retention;
+pub mod routes;
+pub mod seed;
+pub mod shares;
+pub mod state;
+pub mod storage;
+pub mod target_roles;
+pub mod templates;
+pub mod timeout;
+pub mod users;
+pub mod validation;
@@ -0,0 +1,158 @@
+//! Pulls a JSON value out of LLM output that is not only JSON.
+//!
+//! The prompts ask for bare JSON, but models sometimes prepend a sentence ("Here are
+//! the bullets:"), stop before the closing code fence, or leave a trailing comma after
+//! the last array element. Rather than fail the call and pay for a retry, `call_json`
+//! falls back to `extract_json`, which scans for balanced `{...}` / `[...]` spans and
+//! returns the first one that parses, trailing commas removed.
+//!
+//! Output cut off mid-value is not completed: the missing elements are unknown, and a
+//! silently shortened bullet list is worse than a retry.
+
+use std::borrow::Cow;
+
+/// The first balanced object or array in `text` that is valid JSON once trailing
+/// commas are removed. Later candidates are tried when an earlier balanced span is
+/// prose (e.g. "[see below]"), but not once one runs off the end of the text: every
+/// later candidate is nested inside it, and returning the first element of a cut-off
+/// array would hide the truncation.
+pub fn extract_json(text: &str) -> Option<Cow<'_, str>> {
+    let bytes = text.as_bytes();
+    for start in (0..bytes.len()).filter(|&i| matches!(bytes[i], b'{' | b'[')) {
+        match balanced_end(bytes, start) {
+            Span::Closed(end) => {
+                let candidate = remove_trailing_commas(&text[start..end]);
+                if serde_json::from_str::<serde_json::Value>(&candidate).is_ok() {
+                    return Some(candidate);
+                }
+            }
+            Span::Mismatched => {}
+            Span::Truncated => return None,
+        }
+    }
+    None
+}
+
+enum Span {
+    /// End (exclusive) of the value.
+    Closed(usize),
+    /// A closer does not match its opener: not JSON.
+    Mismatched,
+    /// The text ends before the value closes.
+    Truncated,
+}
+
+/// Where the bracketed value opening at `start` ends, skipping brackets inside strings.
+fn balanced_end(bytes: &[u8], start: usize) -> Span {
+    let mut stack = vec![];
+    let mut in_string = false;
+    let mut escaped = false;
+    for (i, &b) in bytes.iter().enumerate().skip(start) {
+        if in_string {
+            match b {
+                _ if escaped => escaped = false,
+                b'\\' => escaped = true,
+                b'"' => in_string = false,
+                _ => {}
+            }
+            continue;
+        }
+        match b {
+            b'"' => in_string = true,
+            b'{' => stack.push(b'}'),
+            b'[' => stack.push(b']'),
+            b'}' | b']' => {
+                if stack.pop() != Some(b) {
+                    return Span::Mismatched;
+                }
+                if stack.is_empty() {
+                    return Span::Closed(i + 1);
+                }
+            }
+            _ => {}
+        }
+    }
+    Span::Truncated
+}
+
+/// Drops commas (outside strings) that are followed only by whitespace and a closing
+/// bracket: `[1, 2,]` → `[1, 2]`. Borrows when there is none.
+fn remove_trailing_commas(json: &str) -> Cow<'_, str> {
+    let bytes = json.as_bytes();
+    let mut dangling = vec![];
+    let mut in_string = false;
+    let mut escaped = false;
+    for (i, &b) in bytes.iter().enumerate() {
+        if in_string {
+            match b {
+                _ if escaped => escaped = false,
+                b'\\' => escaped = true,
+                b'"' => in_string = false,
+                _ => {}
+            }
+            continue;
+        }
+        match b {
+            b'"' => in_string = true,
+            b',' => {
+                let next = bytes[i + 1..].iter().find(|c| !c.is_ascii_whitespace());
+                if matches!(next, Some(b'}' | b']')) {
+                    dangling.push(i);
+                }
+            }
+            _ => {}
+        }
+    }
+    if dangling.is_empty() {
+        return Cow::Borrowed(json);
+    }
+    let mut repaired = String::with_capacity(json.len());
+    let mut from = 0;
+    for i in dangling {
+        repaired.push_str(&json[from..i]);
+        from = i + 1;
+    }
+    repaired.push_str(&json[from..]);
+    Cow::Owned(repaired)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_extracts_json_after_prose_and_unclosed_fence() {
+        let text = "Here are the bullets you asked for:\n```json\n[{\"text\": \"a\"}]\n";
+        assert_eq!(extract_json(text).unwrap(), "[{\"text\": \"a\"}]");
+    }
+
+    #[test]
+    fn test_skips_balanced_prose_before_the_value() {
+        let text = "Result [see notes]: {\"score\": 80, \"notes\": [\"x\"]} Hope this helps!";
+        assert_eq!(
+            extract_json(text).unwrap(),
+            "{\"score\": 80, \"notes\": [\"x\"]}"
+        );
+    }
+
+    #[test]
+    fn test_brackets_inside_strings_do_not_count() {
+        let text = "{\"text\": \"Cut costs [by 30%] }\", \"q\": \"say \\\"hi\\\"\"}";
+        assert_eq!(extract_json(text).unwrap(), text);
+    }
+
+    #[test]
+    fn test_trailing_commas_are_removed() {
+        let text = "[{\"a\": 1, \"b\": \"x,]\",}, {\"a\": 2},\n]";
+        assert_eq!(
+            extract_json(text).unwrap(),
+            "[{\"a\": 1, \"b\": \"x,]\"}, {\"a\": 2}\n]"
+        );
+    }
+
+    #[test]
+    fn test_truncated_output_is_not_completed() {
+        assert!(extract_json("[{\"text\": \"a\"}, {\"text\": \"b").is_none());
+        assert!(extract_json("no json here").is_none());
+    }
+}
@@ -4,12 +4,17 @@
 /// All LLM interactions MUST go through this module.
 ///
 /// Model: claude-sonnet-4-5 (hardcoded — do not make configurable to prevent drift)
+use std::future::Future;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Arc;
+
 use anyhow::Result;
 use reqwest::Client;
 use serde::{de::DeserializeOwned, Deserialize, Serialize};
 use thiserror::Error;
 use tracing::{debug, warn};
 
+pub mod json_extract;
 pub mod prompts;
 
 const ANTHROPIC_API_URL: &str = "https://api.anthropic.com/v1/messages";
@@ -20,6 +25,21 @@ pub const MODEL: &str = "claude-sonnet-4-5";
 const MAX_TOKENS: u32 = 4096;
 const MAX_RETRIES: u32 = 3;
 
+tokio::task_local! {
+    /// Token counter for the enclosing `metered` scope, if any.
+    static TOKEN_METER: Arc<AtomicU64>;
+}
+
+/// Runs `fut` and returns its output together with the input + output tokens of every
+/// successful LLM call it made. Used to charge token usage to the requesting user.
+///
+/// Calls made from tasks spawned inside `fut` are not counted.
+pub async fn metered<F: Future>(fut: F) -> (F::Output, u64) {
+    let meter = Arc::new(AtomicU64::new(0));
+    let output = TOKEN_METER.scope(meter.clone(), fut).await;
+    (output, meter.load(Ordering::Relaxed))
+}
+
 #[derive(Debug, Error)]
 pub enum LlmError {
     #[error("HTTP error: {0}")]
@@ -31,11 +51,22 @@ pub enum LlmError {
     #[error("JSON parse error: {0}")]
     Parse(#[from] serde_json::Error),
 
+    /// A `call_json` response with no usable JSON in it; `raw` is the start of the
+    /// model's text, for diagnosing the prompt.
+    #[error("JSON parse error: {source} (raw response: {raw:?})")]
+    MalformedJson {
+        source: serde_json::Error,
+        raw: String,
+    },
+
     #[error("Rate limited after {retries} retries")]
     RateLimited { retries: u32 },
 
     #[error("LLM returned empty content")]
     EmptyContent,
+
+    #[error("LLM calls are disabled (OFFLINE_MODE)")]
+    Offline,
 }
 
 #[derive(Debug, Serialize)]
@@ -97,6 +128,9 @@ struct AnthropicErrorBody {
 pub struct LlmClient {
     client: Client,
     api_key: String,
+    /// Set by `offline()`: every call fails with `LlmError::Offline` without touching
+    /// the network, and pipeline stages with a deterministic path take it instead.
+    offline: bool,
 }
 
 impl LlmClient {
@@ -107,12 +141,37 @@ impl LlmClient {
                 .build()
                 .expect("Failed to build HTTP client"),
             api_key,
+            offline: false,
         }
     }
 
+    /// A client that never calls the API, for `OFFLINE_MODE`.
+    pub fn offline() -> Self {
+        Self {
+            offline: true,
+            ..Self::new(String::new())
+        }
+    }
+
+    pub fn is_offline(&self) -> bool {
+        self.offline
+    }
+
     /// Makes a raw call to the Claude API, returning the full response object.
-    /// Retries on 429 (rate limit) and 5xx errors with exponential backoff.
+    /// Retries on 429 (rate limit) and 5xx errors with exponential backoff; a call
+    /// that still fails is reported to error reporting.
     pub async fn call(&self, prompt: &str, system: &str) -> Result<LlmResponse, LlmError> {
+        if self.offline {
+            return Err(LlmError::Offline);
+        }
+        let result = self.call_with_retries(prompt, system).await;
+        if let Err(e) = &result {
+            crate::reporting::capture_error("LlmError", &e.to_string());
+        }
+        result
+    }
+
+    async fn call_with_retries(&self, prompt: &str, system: &str) -> Result<LlmResponse, LlmError> {
         let request_body = AnthropicRequest {
             model: MODEL,
             max_tokens: MAX_TOKENS,
@@ -185,6 +244,13 @@ impl LlmClient {
                 "LLM call succeeded: input_tokens={}, output_tokens={}",
                 llm_response.usage.input_tokens, llm_response.usage.output_tokens
             );
+            let _ = TOKEN_METER.try_with(|meter| {
+                meter.fetch_add(
+                    u64::from(llm_response.usage.input_tokens)
+                        + u64::from(llm_response.usage.output_tokens),
+                    Ordering::Relaxed,
+                )
+            });
 
             return Ok(llm_response);
         }
@@ -196,6 +262,9 @@ impl LlmClient {
 
     /// Convenience method that calls the LLM and deserializes the text response as JSON.
     /// The prompt must instruct the model to return valid JSON.
+    ///
+    /// A response that is not only JSON (prose around it, an unclosed fence, trailing
+    /// commas) goes through `json_extract::extract_json` before it counts as a failure.
     pub async fn call_json<T: DeserializeOwned>(
         &self,
         prompt: &str,
@@ -204,21 +273,43 @@ impl LlmClient {
         let response = self.call(prompt, system).await?;
 
         let text = response.text().ok_or(LlmError::EmptyContent)?;
-
-        // Strip markdown code fences if the model wraps JSON in them
-        let text = strip_json_fences(text);
-
-        serde_json::from_str(text).map_err(|e| {
-            tracing::error!(
-                parse_error = %e,
-                raw_response = %&text[..text.len().min(500)],
-                "LLM response JSON parse failed"
-            );
-            LlmError::Parse(e)
-        })
+        parse_json_response(text)
     }
 }
 
+/// Characters of the raw response kept in `LlmError::MalformedJson` and the log.
+const RAW_RESPONSE_EXCERPT_CHARS: usize = 500;
+
+/// Deserializes a `call_json` response: as-is once code fences are stripped, else the
+/// JSON value `extract_json` finds in it.
+fn parse_json_response<T: DeserializeOwned>(text: &str) -> Result<T, LlmError> {
+    // Strip markdown code fences if the model wraps JSON in them
+    let stripped = strip_json_fences(text);
+    let error = match serde_json::from_str(stripped) {
+        Ok(value) => return Ok(value),
+        Err(e) => e,
+    };
+    let error = match json_extract::extract_json(stripped) {
+        Some(json) => match serde_json::from_str(&json) {
+            Ok(value) => {
+                warn!(parse_error = %error, "Recovered JSON from a malformed LLM response");
+                return Ok(value);
+            }
+            Err(e) => e,
+        },
+        None => error,
+    };
+
+    let raw: String = text.chars().take(RAW_RESPONSE_EXCERPT_CHARS).collect();
+    tracing::error!(
+        parse_error = %error,
+        raw_response = %raw,
+        "LLM response JSON parse failed"
+    );
+    crate::reporting::capture_error("LlmParseError", &error.to_string());
+    Err(LlmError::MalformedJson { source: error, raw })
+}
+
 /// Strips ```json ... ``` or ``` ... ``` code fences from LLM output.
 fn strip_json_fences(text: &str) -> &str {
     let text = text.trim();
@@ -260,4 +351,20 @@ mod tests {
         let input = "{\"key\": \"value\"}";
         assert_eq!(strip_json_fences(input), "{\"key\": \"value\"}");
     }
+
+    #[test]
+    fn test_parse_json_response_recovers_prose_wrapped_json() {
+        let text = "Sure! Here is the list:\n```json\n[\"a\", \"b\",]\n";
+        let parsed: Vec<String> = parse_json_response(text).unwrap();
+        assert_eq!(parsed, vec!["a", "b"]);
+    }
+
+    #[test]
+    fn test_parse_json_response_error_carries_raw_text() {
+        let err = parse_json_response::<Vec<String>>("I cannot help with that.").unwrap_err();
+        match err {
+            LlmError::MalformedJson { raw, .. } => assert_eq!(raw, "I cannot help with that."),
+            other => panic!("expected MalformedJson, got {other:?}"),
+        }
+    }
 }
@@ -1,98 +1,142 @@
-mod config;
-mod context;
-mod db;
-mod errors;
-mod generation;
-mod grounding;
-mod layout;
-mod llm_client;
-mod models;
-mod projects;
-mod render;
-mod routes;
-mod state;
-mod templates;
-
 use anyhow::Result;
-use aws_config::Region;
-use aws_sdk_s3::config::Credentials;
+use chrono::Utc;
 use std::net::SocketAddr;
-use std::path::PathBuf;
-use tower_http::{cors::CorsLayer, trace::TraceLayer};
+use std::time::Duration;
+use tower_http::trace::TraceLayer;
 use tracing::info;
 use tracing_subscriber::{layer::SubscriberExt, util::SubscriberInitExt, EnvFilter};
 
 use std::sync::Arc;
 
-use crate::config::Config;
-use crate::context::worker::spawn_context_ingest_worker;
-use crate::db::create_pool;
-use crate::generation::fit_scoring::{KeywordFitScorer, LlmFitScorer};
-use crate::layout::{default_page_config, FontFamily};
-use crate::llm_client::LlmClient;
-use crate::render::pdflatex::check_pdflatex_available;
-use crate::render::worker::spawn_render_worker;
-use crate::routes::build_router;
-use crate::state::AppState;
-use crate::templates::{load_templates_from_dir, precompute_thumbnails, TemplateCache};
+use api::auth::JwtKeys;
+use api::config::{Config, FitScorerBackend};
+use api::context::compaction::{CompactContext, ContextCompactor};
+use api::context::freshness::{CheckContextFreshness, FreshnessChecker};
+use api::context::worker::IngestWorker;
+use api::db::{create_pool, ReadRouter};
+use api::export::worker::{ExportWorker, WORKER_CONCURRENCY as EXPORT_CONCURRENCY};
+use api::generation::fit_scoring::{KeywordFitScorer, LlmFitScorer};
+use api::integrations::JobBoardClient;
+use api::jobs::Workers;
+use api::layout::{default_page_config, ContractThresholds, FontFamily};
+use api::llm_client::LlmClient;
+use api::notifications::Notifier;
+use api::render::pdflatex::check_pdflatex_available;
+use api::render::worker::{RenderWorker, WORKER_CONCURRENCY as RENDER_CONCURRENCY};
+use api::retention::{RetentionSweep, RetentionSweeper};
+use api::routes::build_router;
+use api::state::AppState;
+use api::storage::BlobStore;
+use api::templates::{load_templates_from_dir, precompute_thumbnails, TemplateCache};
+use api::users::deletion::ObjectPurger;
+use api::{cors, jobs, llm_client, notifications, reporting, request_id, storage};
 
 #[tokio::main]
 async fn main() -> Result<()> {
-    // Load configuration first (panics on missing required env vars)
-    let config = Config::from_env()?;
+    // Load and validate configuration first; any problem stops startup.
+    let config = Config::load()?;
 
     // Initialize structured logging
     tracing_subscriber::registry()
         .with(EnvFilter::try_from_default_env().unwrap_or_else(|_| {
-            EnvFilter::new(format!("{}={}", env!("CARGO_PKG_NAME"), &config.rust_log))
+            EnvFilter::new(format!(
+                "{}={}",
+                env!("CARGO_PKG_NAME"),
+                &config.server.rust_log
+            ))
         }))
         .with(tracing_subscriber::fmt::layer())
         .init();
 
     info!("Starting Templar API v{}", env!("CARGO_PKG_VERSION"));
+    // Secrets and URL passwords are redacted by the config types' Debug impls.
+    info!("Configuration (profile {}): {:?}", config.profile, config);
+
+    // Error reporting (Sentry-compatible; off unless SENTRY_DSN is set)
+    reporting::init(&config.reporting)?;
 
     // Initialize PostgreSQL
-    let db = create_pool(&config.database_url).await?;
+    let db = create_pool(
+        config.database.url.expose(),
+        "primary",
+        &config.database.pool,
+    )
+    .await?;
+    let replica = match &config.database.replica_url {
+        Some(url) => Some(create_pool(url.expose(), "replica", &config.database.pool).await?),
+        None => None,
+    };
+    let reads = ReadRouter::new(db.clone(), replica);
 
     // Initialize Redis
-    let redis = redis::Client::open(config.redis_url.clone())?;
+    let redis = redis::Client::open(config.redis.url.expose())?;
     info!("Redis client initialized");
 
-    // Initialize S3 / MinIO
-    let s3 = build_s3_client(&config).await;
-    info!("S3 client initialized");
+    // Initialize blob storage (S3 / MinIO, or local disk with STORAGE_BACKEND=local)
+    let blobs = storage::build(&config).await?;
+    check_storage(&*blobs, &config).await?;
+    info!("Blob storage initialized ({})", blobs.describe());
 
-    // Initialize LLM client
-    let llm = LlmClient::new(config.anthropic_api_key.clone());
-    info!("LLM client initialized (model: {})", llm_client::MODEL);
+    // Initialize job notification emails (EMAIL_BACKEND; logged only by default)
+    let notifier = Notifier::new(notifications::build(&config)?, &config.email.app_url);
+    info!("Email notifications: {}", notifier.mailer.describe());
+
+    // Initialize LLM client (OFFLINE_MODE: a client that never calls the API)
+    let llm = if config.llm.offline_mode {
+        info!("OFFLINE_MODE: LLM calls disabled, deterministic pipeline only");
+        LlmClient::offline()
+    } else {
+        let llm = LlmClient::new(config.llm.anthropic_api_key.expose().to_string());
+        info!("LLM client initialized (model: {})", llm_client::MODEL);
+        llm
+    };
 
     // Initialize fit scorer.
-    // Set FIT_SCORER_BACKEND=llm to use semantic Claude-based scoring (Phase 7.0).
+    // FIT_SCORER_BACKEND=llm uses semantic Claude-based scoring (Phase 7.0).
     // Default: KeywordFitScorer (fast, deterministic, no LLM call).
-    let fit_scorer_backend =
-        std::env::var("FIT_SCORER_BACKEND").unwrap_or_else(|_| "keyword".to_string());
-    let fit_scorer: Arc<dyn crate::generation::fit_scoring::FitScorer> =
-        if fit_scorer_backend == "llm" {
-            info!("Fit scorer: LlmFitScorer (semantic, Claude-backed)");
-            Arc::new(LlmFitScorer(llm.clone()))
-        } else {
-            info!("Fit scorer: KeywordFitScorer (default)");
-            Arc::new(KeywordFitScorer)
+    let fit_scorer: Arc<dyn api::generation::fit_scoring::FitScorer> =
+        match config.llm.fit_scorer_backend {
+            FitScorerBackend::Llm => {
+                info!("Fit scorer: LlmFitScorer (semantic, Claude-backed)");
+                Arc::new(LlmFitScorer(llm.clone()))
+            }
+            FitScorerBackend::Keyword => {
+                info!("Fit scorer: KeywordFitScorer (default)");
+                Arc::new(KeywordFitScorer)
+            }
         };
 
-    // Initialize layout page config (Phase 3: Inter 11pt on US letter, 1" margins)
-    let page_config = default_page_config(FontFamily::Inter);
+    // Load real font metrics (advance widths + kerning) from FONT_DIRS, colon-separated.
+    // Families without a font file keep their static tables.
+    let loaded_fonts = api::layout::ttf_metrics::load_font_metrics(&config.layout.font_dirs);
     info!(
-        "Layout page config: {:?} {}pt",
-        page_config.font, page_config.font_size_pt
+        "Font metrics: {} loaded from files {:?}, others use static tables",
+        loaded_fonts.len(),
+        loaded_fonts
+    );
+
+    // Initialize layout page config (Phase 3: Inter 11pt on US letter, 1" margins).
+    // LINE_BREAKING=knuth_plass switches wrap prediction to TeX-style total-fit breaking.
+    let mut page_config = default_page_config(FontFamily::Inter);
+    page_config.line_breaking = config.layout.line_breaking;
+    // LAYOUT_MIN_1LINE_FILL / LAYOUT_MIN_2LINE_FILL override the font's contract thresholds.
+    let one_line = config.layout.min_one_line_fill;
+    let second_line = config.layout.min_second_line_fill;
+    if one_line.is_some() || second_line.is_some() {
+        page_config.contract = ContractThresholds::new(
+            one_line.unwrap_or(page_config.contract.min_one_line_fill),
+            second_line.unwrap_or(page_config.contract.min_second_line_fill),
+        )?;
+    }
+    info!(
+        "Layout page config: {:?} {}pt, line breaking {:?}, contract {:?}",
+        page_config.font, page_config.font_size_pt, page_config.line_breaking, page_config.contract
     );
 
     // Load file-based templates from TEMPLATES_DIR (default: ./templates).
     // This is synchronous and cheap — just reads a handful of small text files.
     // A missing directory is not fatal (see load_templates_from_dir docstring).
-    let templates_dir: PathBuf = std::env::var("TEMPLATES_DIR")
-        .unwrap_or_else(|_| "./templates".to_string())
-        .into();
+    let templates_dir = config.server.templates_dir.clone();
     let loaded = load_templates_from_dir(&templates_dir)
         .map_err(|e| anyhow::anyhow!("Failed to load templates: {e}"))?;
     let template_count = loaded.len();
@@ -106,10 +150,17 @@ async fn main() -> Result<()> {
     // Build app state
     let state = AppState {
         db,
+        reads,
         redis,
-        s3,
+        blobs,
         llm,
+        notifier,
+        job_boards: JobBoardClient::default(),
         config: config.clone(),
+        jwt: JwtKeys::new(
+            config.auth.jwt_secret.expose().as_bytes(),
+            config.auth.jwt_ttl_secs,
+        ),
         fit_scorer,
         page_config,
         template_cache: template_cache.clone(),
@@ -132,75 +183,123 @@ async fn main() -> Result<()> {
     // in dev), it logs warnings but the server continues normally.
     {
         let cache_clone = template_cache.clone();
-        let s3_clone = state.s3.clone();
-        let bucket_clone = state.config.s3_bucket.clone();
+        let blobs_clone = state.blobs.clone();
         tokio::spawn(async move {
-            precompute_thumbnails(cache_clone, s3_clone, bucket_clone).await;
+            precompute_thumbnails(cache_clone, blobs_clone).await;
         });
     }
     info!("Thumbnail pre-computation: task spawned");
 
-    // Spawn background render worker (clones before state is moved into router)
-    spawn_render_worker(
+    // Background job workers (clones before state is moved into router).
+    // Render jobs use file-based templates from the shared template cache; ingest
+    // concurrency is INGEST_WORKER_COUNT. The purger retries S3 deletes left over from
+    // account deletion and retention sweeps. Retention, context compaction and the
+    // stale context check run on a timer.
+    let workers = Workers::new(state.redis.clone())
+        .register(
+            RenderWorker {
+                redis: state.redis.clone(),
+                db: state.db.clone(),
+                blobs: state.blobs.clone(),
+                template_cache,
+                notifier: state.notifier.clone(),
+            },
+            RENDER_CONCURRENCY,
+        )
+        .register(
+            IngestWorker::new(state.db.clone(), state.llm.clone(), state.blobs.clone()),
+            config.workers.ingest_workers,
+        )
+        .register(
+            ExportWorker {
+                redis: state.redis.clone(),
+                db: state.db.clone(),
+                blobs: state.blobs.clone(),
+            },
+            EXPORT_CONCURRENCY,
+        )
+        .register(
+            ObjectPurger {
+                blobs: state.blobs.clone(),
+            },
+            1,
+        )
+        .register(
+            ContextCompactor {
+                db: state.db.clone(),
+                keep_versions: config.compaction.keep_versions,
+            },
+            1,
+        )
+        .register(
+            RetentionSweeper {
+                db: state.db.clone(),
+                redis: state.redis.clone(),
+                blobs: state.blobs.clone(),
+                days: config.retention.days,
+            },
+            1,
+        )
+        .register(
+            FreshnessChecker {
+                db: state.db.clone(),
+                notifier: state.notifier.clone(),
+            },
+            1,
+        );
+    info!("Job workers: {}", workers.summary());
+    jobs::schedule_every(
         state.redis.clone(),
-        state.db.clone(),
-        state.s3.clone(),
-        state.config.s3_bucket.clone(),
-        // Pass template cache so worker can use file-based templates
-        template_cache,
+        Duration::from_secs(config.retention.sweep_interval_secs),
+        || RetentionSweep {
+            scheduled_at: Utc::now(),
+        },
+    );
+    jobs::schedule_every(
+        state.redis.clone(),
+        Duration::from_secs(config.compaction.interval_secs),
+        || CompactContext {
+            scheduled_at: Utc::now(),
+        },
+    );
+    jobs::schedule_every(
+        state.redis.clone(),
+        Duration::from_secs(config.freshness.interval_secs),
+        || CheckContextFreshness {
+            scheduled_at: Utc::now(),
+        },
     );
-    info!("Render worker: spawned");
-
-    // Spawn N background context ingest workers (configurable via INGEST_WORKER_COUNT)
-    let ingest_worker_count: usize = std::env::var("INGEST_WORKER_COUNT")
-        .ok()
-        .and_then(|s| s.parse().ok())
-        .unwrap_or(4);
-    for _ in 0..ingest_worker_count {
-        spawn_context_ingest_worker(
-            state.redis.clone(),
-            state.db.clone(),
-            state.llm.clone(),
-            state.s3.clone(),
-            state.config.s3_bucket.clone(),
-        );
-    }
-    info!("Context ingest workers: spawned {ingest_worker_count}");
 
     // Build router
     let app = build_router(state)
-        .layer(TraceLayer::new_for_http())
-        .layer(CorsLayer::permissive()); // TODO: tighten CORS in production
+        .layer(TraceLayer::new_for_http().make_span_with(request_id::make_request_span))
+        // Outside the trace layer so the span sees the assigned id.
+        .layer(axum::middleware::from_fn(request_id::assign_request_id))
+        .layer(cors::build_cors_layer(&config)?);
 
-    let addr: SocketAddr = format!("0.0.0.0:{}", config.api_port).parse()?;
+    let addr: SocketAddr = format!("0.0.0.0:{}", config.server.port).parse()?;
     info!("Listening on {addr}");
 
     let listener = tokio::net::TcpListener::bind(addr).await?;
-    axum::serve(listener, app).await?;
+    // Peer addresses feed the per-IP rate limit.
+    axum::serve(
+        listener,
+        app.into_make_service_with_connect_info::<SocketAddr>(),
+    )
+    .await?;
 
     Ok(())
 }
 
-/// Constructs an S3 client configured for MinIO (local) or AWS (production).
-async fn build_s3_client(config: &Config) -> aws_sdk_s3::Client {
-    let credentials = Credentials::new(
-        &config.aws_access_key_id,
-        &config.aws_secret_access_key,
-        None,
-        None,
-        "templar-static",
-    );
-
-    let s3_config = aws_config::defaults(aws_config::BehaviorVersion::latest())
-        .region(Region::new("us-east-1"))
-        .credentials_provider(credentials)
-        .endpoint_url(&config.s3_endpoint)
-        .load()
-        .await;
-
-    let s3_client_config = aws_sdk_s3::config::Builder::from(&s3_config)
-        .force_path_style(true)
-        .build();
-
-    aws_sdk_s3::Client::from_conf(s3_client_config)
+/// Fails startup if blob storage is missing or unreachable. In the dev profile this
+/// only warns, since MinIO may still be creating the bucket.
+async fn check_storage(blobs: &dyn BlobStore, config: &Config) -> Result<()> {
+    match blobs.check().await {
+        Ok(()) => Ok(()),
+        Err(e) if config.profile.is_dev() => {
+            tracing::warn!("{e}");
+            Ok(())
+        }
+        Err(e) => Err(e),
+    }
 }
@@ -0,0 +1,360 @@
+//! Process-wide metrics, exposed in Prometheus text format at `GET /metrics`.
+//!
+//! Plain atomics in statics — no registry, no labels beyond what a metric's name says.
+//! Counters only ever go up; Prometheus derives rates and ratios (e.g. flagged rate =
+//! `layout_bullets_flagged_total / layout_bullets_simulated_total`).
+//!
+//! Layout metrics exist to catch the static font tables drifting from what LaTeX
+//! actually sets. Per-bullet rendered line counts are not extracted from the PDF, so
+//! the rendered page count is the calibration signal: the simulator always targets
+//! one page, and a render that spills onto a second page is a misprediction.
+//!
+//! Database pool occupancy is read from the pools at scrape time rather than kept in
+//! statics; only acquire timeouts, which happen inside request handlers, are counted.
+
+use std::fmt::Write as _;
+use std::sync::atomic::{AtomicU64, Ordering};
+
+use crate::db::PoolStatus;
+
+// ────────────────────────────────────────────────────────────────────────────
+// Primitives
+// ────────────────────────────────────────────────────────────────────────────
+
+/// Monotonic counter.
+pub struct Counter(AtomicU64);
+
+impl Default for Counter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Counter {
+    pub const fn new() -> Self {
+        Counter(AtomicU64::new(0))
+    }
+
+    pub fn inc(&self) {
+        self.inc_by(1);
+    }
+
+    pub fn inc_by(&self, n: u64) {
+        self.0.fetch_add(n, Ordering::Relaxed);
+    }
+
+    pub fn get(&self) -> u64 {
+        self.0.load(Ordering::Relaxed)
+    }
+}
+
+/// Histogram of small non-negative integer observations (counts per pass, passes per
+/// run). `bounds` are inclusive upper bucket bounds; `+Inf` is implicit.
+pub struct Histogram<const N: usize> {
+    bounds: [u64; N],
+    buckets: [AtomicU64; N],
+    count: AtomicU64,
+    sum: AtomicU64,
+}
+
+impl<const N: usize> Histogram<N> {
+    pub const fn new(bounds: [u64; N]) -> Self {
+        Histogram {
+            bounds,
+            buckets: [const { AtomicU64::new(0) }; N],
+            count: AtomicU64::new(0),
+            sum: AtomicU64::new(0),
+        }
+    }
+
+    pub fn observe(&self, value: u64) {
+        if let Some(i) = self.bounds.iter().position(|&b| value <= b) {
+            self.buckets[i].fetch_add(1, Ordering::Relaxed);
+        }
+        self.count.fetch_add(1, Ordering::Relaxed);
+        self.sum.fetch_add(value, Ordering::Relaxed);
+    }
+
+    pub fn count(&self) -> u64 {
+        self.count.load(Ordering::Relaxed)
+    }
+
+    /// Cumulative `(upper bound, count)` pairs as Prometheus expects them.
+    fn cumulative(&self) -> Vec<(u64, u64)> {
+        let mut running = 0;
+        self.bounds
+            .iter()
+            .zip(self.buckets.iter())
+            .map(|(&bound, bucket)| {
+                running += bucket.load(Ordering::Relaxed);
+                (bound, running)
+            })
+            .collect()
+    }
+}
+
+// ────────────────────────────────────────────────────────────────────────────
+// Layout metrics
+// ────────────────────────────────────────────────────────────────────────────
+
+/// Everything the layout simulator and render worker report.
+pub struct LayoutMetrics {
+    pub simulation_runs: Counter,
+    /// Simulation passes per run (the final re-measure pass is not counted).
+    pub passes_per_run: Histogram<5>,
+    /// Contract violations found by each pass.
+    pub violations_per_pass: Histogram<7>,
+    pub expand_requests: Counter,
+    pub compress_requests: Counter,
+    pub local_compressions: Counter,
+    pub local_expansions: Counter,
+    pub llm_calls: Counter,
+    pub bullets_simulated: Counter,
+    pub bullets_flagged: Counter,
+    /// Rendered PDFs whose page count was known.
+    pub renders_checked: Counter,
+    /// Of those, renders that spilled past the single page the simulation predicted.
+    pub render_page_overflows: Counter,
+    /// Bullets located in a rendered PDF by the line map.
+    pub bullets_placed: Counter,
+    /// Of those, bullets whose rendered line count differs from the simulation's.
+    pub bullet_line_mispredictions: Counter,
+}
+
+pub static LAYOUT: LayoutMetrics = LayoutMetrics::new();
+
+impl Default for LayoutMetrics {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl LayoutMetrics {
+    pub const fn new() -> Self {
+        LayoutMetrics {
+            simulation_runs: Counter::new(),
+            passes_per_run: Histogram::new([0, 1, 2, 3, 5]),
+            violations_per_pass: Histogram::new([0, 1, 2, 4, 8, 16, 32]),
+            expand_requests: Counter::new(),
+            compress_requests: Counter::new(),
+            local_compressions: Counter::new(),
+            local_expansions: Counter::new(),
+            llm_calls: Counter::new(),
+            bullets_simulated: Counter::new(),
+            bullets_flagged: Counter::new(),
+            renders_checked: Counter::new(),
+            render_page_overflows: Counter::new(),
+            bullets_placed: Counter::new(),
+            bullet_line_mispredictions: Counter::new(),
+        }
+    }
+
+    /// Records the page count of a rendered resume against the one-page prediction.
+    pub fn record_rendered_pages(&self, pages: u32) {
+        self.renders_checked.inc();
+        if pages > 1 {
+            self.render_page_overflows.inc();
+        }
+    }
+
+    /// Records how many of a render's placed bullets missed their predicted line count.
+    pub fn record_rendered_lines(&self, placed: usize, mispredicted: usize) {
+        self.bullets_placed.inc_by(placed as u64);
+        self.bullet_line_mispredictions.inc_by(mispredicted as u64);
+    }
+}
+
+// ────────────────────────────────────────────────────────────────────────────
+// Database pool metrics
+// ────────────────────────────────────────────────────────────────────────────
+
+/// Queries that gave up waiting for a pooled connection (`sqlx::Error::PoolTimedOut`)
+/// and surfaced as an API error.
+pub static DB_POOL_ACQUIRE_TIMEOUTS: Counter = Counter::new();
+
+// ────────────────────────────────────────────────────────────────────────────
+// Exposition
+// ────────────────────────────────────────────────────────────────────────────
+
+/// All metrics in Prometheus text exposition format (version 0.0.4), plus gauges for
+/// each pool in `pools`.
+pub fn render_prometheus(pools: &[PoolStatus]) -> String {
+    let m = &LAYOUT;
+    let mut out = String::new();
+
+    let counters: [(&str, &str, &Counter); 12] = [
+        (
+            "layout_simulation_runs_total",
+            "Layout simulation runs.",
+            &m.simulation_runs,
+        ),
+        (
+            "layout_expand_requests_total",
+            "Bullets sent to the LLM to expand.",
+            &m.expand_requests,
+        ),
+        (
+            "layout_compress_requests_total",
+            "Bullets sent to the LLM to compress.",
+            &m.compress_requests,
+        ),
+        (
+            "layout_local_compressions_total",
+            "Bullets fixed by the local compressor.",
+            &m.local_compressions,
+        ),
+        (
+            "layout_local_expansions_total",
+            "Bullets expanded from their source entry after the LLM gave no expansion.",
+            &m.local_expansions,
+        ),
+        (
+            "layout_llm_calls_total",
+            "LLM adjust calls (simulation and page fill).",
+            &m.llm_calls,
+        ),
+        (
+            "layout_bullets_simulated_total",
+            "Bullets measured by the simulator.",
+            &m.bullets_simulated,
+        ),
+        (
+            "layout_bullets_flagged_total",
+            "Bullets flagged for review after simulation.",
+            &m.bullets_flagged,
+        ),
+        (
+            "layout_renders_checked_total",
+            "Rendered PDFs with a known page count.",
+            &m.renders_checked,
+        ),
+        (
+            "layout_render_page_overflows_total",
+            "Rendered PDFs longer than the predicted single page.",
+            &m.render_page_overflows,
+        ),
+        (
+            "layout_bullets_placed_total",
+            "Bullets located in rendered PDFs by the line map.",
+            &m.bullets_placed,
+        ),
+        (
+            "layout_bullet_line_mispredictions_total",
+            "Rendered bullets whose line count differs from the simulation.",
+            &m.bullet_line_mispredictions,
+        ),
+    ];
+    for (name, help, counter) in counters {
+        let _ = writeln!(out, "# HELP {name} {help}");
+        let _ = writeln!(out, "# TYPE {name} counter");
+        let _ = writeln!(out, "{name} {}", counter.get());
+    }
+
+    write_histogram(
+        &mut out,
+        "layout_passes_per_run",
+        "Simulation passes per run.",
+        &m.passes_per_run,
+    );
+    write_histogram(
+        &mut out,
+        "layout_violations_per_pass",
+        "Line contract violations found per simulation pass.",
+        &m.violations_per_pass,
+    );
+
+    let name = "db_pool_acquire_timeouts_total";
+    let _ = writeln!(
+        out,
+        "# HELP {name} Queries that timed out waiting for a pooled connection."
+    );
+    let _ = writeln!(out, "# TYPE {name} counter");
+    let _ = writeln!(out, "{name} {}", DB_POOL_ACQUIRE_TIMEOUTS.get());
+    for pool in pools {
+        let role = pool.role;
+        let gauges = [
+            ("connections", "Open connections", pool.size),
+            ("idle_connections", "Idle connections", pool.idle),
+            ("max_connections", "Connection limit", pool.max),
+        ];
+        for (suffix, help, value) in gauges {
+            let name = format!("db_pool_{role}_{suffix}");
+            let _ = writeln!(out, "# HELP {name} {help} in the {role} pool.");
+            let _ = writeln!(out, "# TYPE {name} gauge");
+            let _ = writeln!(out, "{name} {value}");
+        }
+    }
+    out
+}
+
+fn write_histogram<const N: usize>(out: &mut String, name: &str, help: &str, h: &Histogram<N>) {
+    let _ = writeln!(out, "# HELP {name} {help}");
+    let _ = writeln!(out, "# TYPE {name} histogram");
+    for (bound, count) in h.cumulative() {
+        let _ = writeln!(out, "{name}_bucket{{le=\"{bound}\"}} {count}");
+    }
+    let _ = writeln!(out, "{name}_bucket{{le=\"+Inf\"}} {}", h.count());
+    let _ = writeln!(out, "{name}_sum {}", h.sum.load(Ordering::Relaxed));
+    let _ = writeln!(out, "{name}_count {}", h.count());
+}
+
+// ────────────────────────────────────────────────────────────────────────────
+// Tests
+// ────────────────────────────────────────────────────────────────────────────
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_histogram_buckets_are_cumulative() {
+        let h = Histogram::new([0, 2, 8]);
+        for v in [0, 1, 2, 5, 40] {
+            h.observe(v);
+        }
+        assert_eq!(h.cumulative(), vec![(0, 1), (2, 3), (8, 4)]);
+        assert_eq!(h.count(), 5);
+        assert_eq!(h.sum.load(Ordering::Relaxed), 48);
+    }
+
+    #[test]
+    fn test_rendered_pages_count_overflows_only() {
+        let m = LayoutMetrics::new();
+        m.record_rendered_pages(1);
+        m.record_rendered_pages(2);
+        assert_eq!(m.renders_checked.get(), 2);
+        assert_eq!(m.render_page_overflows.get(), 1);
+    }
+
+    #[test]
+    fn test_exposition_lists_every_metric() {
+        let text = render_prometheus(&[]);
+        assert!(text.contains("# TYPE layout_simulation_runs_total counter"));
+        assert!(text.contains("layout_violations_per_pass_bucket{le=\"+Inf\"}"));
+        assert!(text.contains("layout_passes_per_run_count"));
+        assert!(text.contains("# TYPE db_pool_acquire_timeouts_total counter"));
+    }
+
+    #[test]
+    fn test_exposition_has_gauges_per_pool() {
+        let pools = [
+            PoolStatus {
+                role: "primary",
+                size: 7,
+                idle: 2,
+                max: 10,
+            },
+            PoolStatus {
+                role: "replica",
+                size: 1,
+                idle: 1,
+                max: 10,
+            },
+        ];
+        let text = render_prometheus(&pools);
+        assert!(text.contains("# TYPE db_pool_primary_connections gauge"));
+        assert!(text.contains("db_pool_primary_connections 7\n"));
+        assert!(text.contains("db_pool_primary_idle_connections 2\n"));
+        assert!(text.contains("db_pool_replica_max_connections 10\n"));
+    }
+}
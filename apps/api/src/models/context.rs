@@ -23,6 +23,9 @@ pub struct ContextEntryRow {
     /// Phase 5.5: machine-readable quality flags (e.g. ["missing_metric"]).
     pub quality_flags: Vec<String>,
     pub created_at: DateTime<Utc>,
+    /// The `DecayCurve` `recency_score` was computed with, as of `created_at`. NULL on
+    /// rows written before migration 028, which used the default curve.
+    pub recency_policy: Option<Value>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
@@ -32,4 +35,6 @@ pub struct ContextSnapshotRow {
     pub version: i32,
     pub s3_key: String,
     pub created_at: DateTime<Utc>,
+    /// Added in migration 019: set by `DELETE /context/version/:v`; purged by `retention`.
+    pub deleted_at: Option<DateTime<Utc>>,
 }
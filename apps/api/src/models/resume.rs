@@ -19,8 +19,40 @@ pub struct ResumeRow {
     /// Added in migration 004: which file-based template was used (None = legacy font template).
     /// TEXT column referencing the templates directory name, not a FK.
     pub template_id: Option<String>,
+    /// Added in migration 005: serialized `CoverageReport` computed at generation time.
+    pub keyword_coverage: Option<Value>,
+    /// Added in migration 006: the resume this one was regenerated from (None = original).
+    pub parent_resume_id: Option<Uuid>,
+    /// Added in migration 009: `letter` or `a4` (see `layout::PaperSize`).
+    pub paper_size: String,
+    /// Added in migration 010: body font size the layout was simulated at (default 11).
+    pub font_size_pt: f32,
+    /// Added in migration 011: serialized `LayoutReport` (passes, page fill, flagged bullets).
+    pub layout_report: Option<Value>,
+    /// Added in migration 012: `compact`, `standard` or `airy` (see `layout::MarginPreset`).
+    pub margin_preset: String,
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
+    /// Added in migration 019: set by `DELETE /resumes/:id`; purged by `retention`.
+    pub deleted_at: Option<DateTime<Utc>>,
+    /// Added in migration 023: the metric table the layout was simulated with (see
+    /// `layout::FontFamily::as_str`).
+    pub font_family: String,
+    /// Added in migration 023: exact margins in inches. None on older rows until their
+    /// next render derives them from `margin_preset`.
+    pub margin_left_in: Option<f32>,
+    pub margin_right_in: Option<f32>,
+    pub margin_top_in: Option<f32>,
+    pub margin_bottom_in: Option<f32>,
+    /// Added in migration 023: `{prompt name: fingerprint}` of the prompts used at
+    /// generation (see `generation::prompt_versions`).
+    pub prompt_versions: Option<Value>,
+    /// Added in migration 032: the target role generated for (see `crate::target_roles`).
+    /// Regenerations inherit it; SET NULL when the role is deleted.
+    pub target_role_id: Option<Uuid>,
+    /// Added in migration 035: serialized `GenerationExplanation` (see
+    /// `generation::explanation`).
+    pub explanation: Option<Value>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
@@ -33,7 +65,12 @@ pub struct ResumeBulletRow {
     pub grounding_score: f64,
     pub is_user_edited: bool,
     pub line_count: i16,
+    /// Added in migration 007: 0-based display order within the resume.
+    pub position: i32,
     pub created_at: DateTime<Utc>,
+    /// Added in migration 037: serialized `Vec<KeywordSpan>` — where JD keywords appear
+    /// in `bullet_text`. Empty on bullets written before it.
+    pub keyword_spans: Value,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
@@ -44,6 +81,25 @@ pub struct RenderJobRow {
     pub error_message: Option<String>,
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
+    /// Added in migration 033: serialized `Vec<BulletPlacement>`, set when the job is done.
+    pub line_map: Option<Value>,
+}
+
+/// One JD within a batch generation request (migration 008).
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
+pub struct GenerationJobRow {
+    pub id: Uuid,
+    pub batch_id: Uuid,
+    pub user_id: Uuid,
+    pub jd_index: i32,
+    pub jd_text: String,
+    /// queued | running | succeeded | failed
+    pub status: String,
+    /// Set when the job succeeds.
+    pub resume_id: Option<Uuid>,
+    pub error_msg: Option<String>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
@@ -56,4 +112,5 @@ pub struct PersonaRow {
     pub tone_preference: Option<String>,
     pub section_order: Option<Value>,
     pub created_at: DateTime<Utc>,
+    pub domain_pack: Option<String>,
 }
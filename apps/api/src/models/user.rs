@@ -8,8 +8,19 @@ use uuid::Uuid;
 #[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
 pub struct User {
     pub id: Uuid,
+    /// Identity-provider user id; `local:<id>` for accounts created by password signup.
     pub external_id: String,
     pub email: String,
     pub tier: String,
     pub created_at: DateTime<Utc>,
+    /// Added in migration 013: PBKDF2 hash for password accounts (None = IdP-provisioned).
+    /// Never serialized.
+    #[serde(skip)]
+    pub password_hash: Option<String>,
+    /// Added in migration 013: name shown in the app.
+    pub display_name: Option<String>,
+    /// Added in migration 013.
+    pub updated_at: DateTime<Utc>,
+    /// Added in migration 018: may read the stats under /api/v1/admin.
+    pub is_admin: bool,
 }
@@ -0,0 +1,204 @@
+//! Axum handlers for the caller's Slack and Discord webhooks.
+
+use axum::{
+    extract::{Path, State},
+    http::StatusCode,
+    response::IntoResponse,
+    Json,
+};
+use uuid::Uuid;
+
+use crate::audit::{self, Action, AuditEvent};
+use crate::auth::{AuthUser, Resource};
+use crate::errors::AppError;
+use crate::notifications::webhooks::{
+    self, event_names, validate_url, CreateWebhookRequest, Level, Message, UpdateWebhookRequest,
+    WebhookKind, WebhookRow, WebhookTestResponse, WebhookView, MAX_WEBHOOKS_PER_USER,
+};
+use crate::state::AppState;
+use crate::validation::ValidJson;
+
+// ────────────────────────────────────────────────────────────────────────────
+// GET /api/v1/users/me/webhooks
+// ────────────────────────────────────────────────────────────────────────────
+
+/// The caller's webhooks, oldest first.
+pub async fn handle_list_webhooks(
+    State(state): State<AppState>,
+    auth: AuthUser,
+) -> Result<Json<Vec<WebhookView>>, AppError> {
+    let rows = sqlx::query_as!(
+        WebhookRow,
+        r#"SELECT id, kind, url, events, created_at, last_delivery_at, last_error, disabled_at
+           FROM user_webhooks WHERE user_id = $1
+           ORDER BY created_at, id"#,
+        auth.user_id
+    )
+    .fetch_all(&state.db)
+    .await?;
+
+    Ok(Json(rows.into_iter().map(WebhookView::from).collect()))
+}
+
+// ────────────────────────────────────────────────────────────────────────────
+// POST /api/v1/users/me/webhooks
+// ────────────────────────────────────────────────────────────────────────────
+
+/// Registers a webhook for the listed events. 400 for a URL that isn't `kind`'s own
+/// webhook endpoint, 409 past `MAX_WEBHOOKS_PER_USER`.
+pub async fn handle_create_webhook(
+    State(state): State<AppState>,
+    auth: AuthUser,
+    ValidJson(body): ValidJson<CreateWebhookRequest>,
+) -> Result<impl IntoResponse, AppError> {
+    validate_url(body.kind, &body.url)?;
+    let count = sqlx::query_scalar!(
+        r#"SELECT COUNT(*) AS "count!" FROM user_webhooks WHERE user_id = $1"#,
+        auth.user_id
+    )
+    .fetch_one(&state.db)
+    .await?;
+    if count >= MAX_WEBHOOKS_PER_USER {
+        return Err(AppError::Conflict(format!(
+            "At most {MAX_WEBHOOKS_PER_USER} webhooks per account; delete one first"
+        )));
+    }
+
+    let events = event_names(&body.events);
+    let row = sqlx::query_as!(
+        WebhookRow,
+        r#"INSERT INTO user_webhooks (user_id, kind, url, events)
+           VALUES ($1, $2, $3, $4)
+           RETURNING id, kind, url, events, created_at, last_delivery_at, last_error, disabled_at"#,
+        auth.user_id,
+        body.kind.as_str(),
+        body.url.trim(),
+        &events
+    )
+    .fetch_one(&state.db)
+    .await?;
+    audit::record(
+        &state.db,
+        auth.user_id,
+        AuditEvent::new(Action::WebhookCreate, row.id)
+            .after(serde_json::json!({ "kind": row.kind, "events": events })),
+    )
+    .await;
+
+    Ok((StatusCode::CREATED, Json(WebhookView::from(row))))
+}
+
+// ────────────────────────────────────────────────────────────────────────────
+// PATCH /api/v1/users/me/webhooks/:id
+// ────────────────────────────────────────────────────────────────────────────
+
+/// Replaces the webhook's events and/or URL. A new URL re-enables it.
+pub async fn handle_update_webhook(
+    Path(id): Path<Uuid>,
+    State(state): State<AppState>,
+    auth: AuthUser,
+    ValidJson(body): ValidJson<UpdateWebhookRequest>,
+) -> Result<Json<WebhookView>, AppError> {
+    auth.authorize(&state.db, Resource::Webhook, id).await?;
+    if let Some(url) = &body.url {
+        let kind = sqlx::query_scalar!("SELECT kind FROM user_webhooks WHERE id = $1", id)
+            .fetch_one(&state.db)
+            .await?;
+        validate_url(WebhookKind::parse(&kind).unwrap_or(WebhookKind::Slack), url)?;
+    }
+    let events = body.events.as_deref().map(event_names);
+
+    let row = sqlx::query_as!(
+        WebhookRow,
+        r#"UPDATE user_webhooks
+           SET url         = COALESCE($2, url),
+               events      = COALESCE($3, events),
+               disabled_at = CASE WHEN $2::TEXT IS NULL THEN disabled_at END,
+               last_error  = CASE WHEN $2::TEXT IS NULL THEN last_error END
+           WHERE id = $1
+           RETURNING id, kind, url, events, created_at, last_delivery_at, last_error, disabled_at"#,
+        id,
+        body.url.as_deref().map(str::trim),
+        events.as_deref()
+    )
+    .fetch_optional(&state.db)
+    .await?
+    .ok_or(AppError::NotFound(format!("Webhook {id} not found")))?;
+    audit::record(
+        &state.db,
+        auth.user_id,
+        AuditEvent::new(Action::WebhookUpdate, id).after(serde_json::json!({
+            "events": row.events,
+            "url_changed": body.url.is_some(),
+        })),
+    )
+    .await;
+
+    Ok(Json(WebhookView::from(row)))
+}
+
+// ────────────────────────────────────────────────────────────────────────────
+// DELETE /api/v1/users/me/webhooks/:id
+// ────────────────────────────────────────────────────────────────────────────
+
+pub async fn handle_delete_webhook(
+    Path(id): Path<Uuid>,
+    State(state): State<AppState>,
+    auth: AuthUser,
+) -> Result<impl IntoResponse, AppError> {
+    auth.authorize(&state.db, Resource::Webhook, id).await?;
+
+    let rows = sqlx::query!("DELETE FROM user_webhooks WHERE id = $1", id)
+        .execute(&state.db)
+        .await?
+        .rows_affected();
+    if rows == 0 {
+        return Err(AppError::NotFound(format!("Webhook {id} not found")));
+    }
+    audit::record(
+        &state.db,
+        auth.user_id,
+        AuditEvent::new(Action::WebhookDelete, id),
+    )
+    .await;
+
+    Ok(StatusCode::NO_CONTENT)
+}
+
+// ────────────────────────────────────────────────────────────────────────────
+// POST /api/v1/users/me/webhooks/:id/test
+// ────────────────────────────────────────────────────────────────────────────
+
+/// Posts a test message to the webhook now, disabled or not, and reports how it went.
+/// The outcome is recorded like any delivery, so a deleted webhook gets disabled.
+pub async fn handle_test_webhook(
+    Path(id): Path<Uuid>,
+    State(state): State<AppState>,
+    auth: AuthUser,
+) -> Result<Json<WebhookTestResponse>, AppError> {
+    auth.authorize(&state.db, Resource::Webhook, id).await?;
+    let row = sqlx::query_as!(
+        WebhookRow,
+        r#"SELECT id, kind, url, events, created_at, last_delivery_at, last_error, disabled_at
+           FROM user_webhooks WHERE id = $1"#,
+        id
+    )
+    .fetch_optional(&state.db)
+    .await?
+    .ok_or(AppError::NotFound(format!("Webhook {id} not found")))?;
+    let kind = WebhookKind::parse(&row.kind).unwrap_or(WebhookKind::Slack);
+
+    let message = Message {
+        title: "Templar webhook test".to_string(),
+        lines: vec!["This channel will receive the Templar events you subscribed it to.".into()],
+        link: None,
+        level: Level::Success,
+    };
+    let delivery = webhooks::deliver(kind, &row.url, &message).await;
+    webhooks::record_delivery(&state.db, id, &delivery).await;
+
+    Ok(Json(WebhookTestResponse {
+        delivered: delivery.error().is_none(),
+        error: delivery.error().map(str::to_string),
+    }))
+}
@@ -0,0 +1,592 @@
+//! Email and chat notifications when pipeline work finishes.
+//!
+//! A render job or a batch generation that ends — in success or failure — emails its
+//! owner, if they opted in through `NotificationSettings` (`render_complete`,
+//! `batch_complete`). Render emails link to the resume in the web app and, when the
+//! render succeeded, to a presigned PDF download; batch emails list every JD's
+//! outcome with a link to each resume it produced.
+//!
+//! Delivery goes through `Mailer`, carried by `Notifier` in `AppState` and the render
+//! worker. `EMAIL_BACKEND` chooses the implementation:
+//! - `none` (default): `LogMailer`, which logs each email instead of sending it;
+//! - `smtp`: `SmtpMailer`, the relay at `SMTP_URL`;
+//! - `ses`: `SesMailer`, the Amazon SES v2 API.
+//!
+//! The same events also go to the user's Slack and Discord webhooks subscribed to
+//! them (webhooks.rs), independently of the email preferences; so does a finished
+//! single generation, and a quota nearing its limit (from `quota`).
+//!
+//! Stale context nudges (from `context::freshness`) go out the same two ways: by email
+//! with `context_nudges` on, and to webhooks subscribed to `context_stale`.
+//!
+//! GET    /api/v1/users/me/webhooks          — the caller's webhooks
+//! POST   /api/v1/users/me/webhooks          — register one
+//! PATCH  /api/v1/users/me/webhooks/:id      — change its events or URL
+//! DELETE /api/v1/users/me/webhooks/:id
+//! POST   /api/v1/users/me/webhooks/:id/test — post a test message now
+//!
+//! A notification never changes a job's outcome: preference lookups, presigning and
+//! delivery failures are logged and dropped.
+
+pub mod handlers;
+pub mod ses;
+pub mod smtp;
+pub mod webhooks;
+
+use std::sync::Arc;
+use std::time::Duration;
+
+use anyhow::Result;
+use async_trait::async_trait;
+use sqlx::PgPool;
+use tracing::{info, warn};
+use uuid::Uuid;
+
+use crate::config::{Config, EmailBackend};
+use crate::context::freshness::ContextNudge;
+use crate::storage::BlobStore;
+use crate::users::preferences;
+
+pub use ses::SesMailer;
+pub use smtp::SmtpMailer;
+use webhooks::{Level, Message, WebhookEvent};
+
+/// Lifetime of the PDF link in a render email — the longest S3 allows, since the
+/// email may sit unread for a while.
+pub const PDF_LINK_TTL: Duration = Duration::from_secs(7 * 24 * 3600);
+
+/// A plain-text email to one recipient.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Email {
+    pub to: String,
+    pub subject: String,
+    pub body: String,
+}
+
+/// Delivers emails from the configured sender.
+#[async_trait]
+pub trait Mailer: Send + Sync {
+    /// Where emails go, for logs (`smtp://smtp.example.com`, `ses (eu-west-1)`).
+    fn describe(&self) -> String;
+
+    async fn send(&self, email: &Email) -> Result<()>;
+}
+
+/// `EMAIL_BACKEND=none`: emails are logged and dropped.
+pub struct LogMailer;
+
+#[async_trait]
+impl Mailer for LogMailer {
+    fn describe(&self) -> String {
+        "none (emails are logged, not sent)".to_string()
+    }
+
+    async fn send(&self, email: &Email) -> Result<()> {
+        info!(to = %email.to, subject = %email.subject, "email not sent (EMAIL_BACKEND=none)");
+        Ok(())
+    }
+}
+
+/// Builds the backend selected by `config.email`.
+pub fn build(config: &Config) -> Result<Arc<dyn Mailer>> {
+    Ok(match config.email.backend {
+        EmailBackend::None => Arc::new(LogMailer),
+        EmailBackend::Smtp => {
+            let url = config.email.smtp_url.as_ref().map(|u| u.expose());
+            Arc::new(SmtpMailer::new(
+                url.unwrap_or_default(),
+                &config.email.from,
+            )?)
+        }
+        EmailBackend::Ses => Arc::new(SesMailer::new(config)),
+    })
+}
+
+// ────────────────────────────────────────────────────────────────────────────
+// Notifier
+// ────────────────────────────────────────────────────────────────────────────
+
+/// How a render job ended.
+pub enum RenderOutcome<'a> {
+    /// The PDF is stored under `pdf_key`.
+    Rendered {
+        pdf_key: &'a str,
+    },
+    Failed {
+        error: &'a str,
+    },
+}
+
+/// Sends the job notifications users asked for.
+#[derive(Clone)]
+pub struct Notifier {
+    pub mailer: Arc<dyn Mailer>,
+    /// Web app base URL (`APP_URL`), without a trailing slash.
+    app_url: String,
+}
+
+impl Notifier {
+    pub fn new(mailer: Arc<dyn Mailer>, app_url: &str) -> Self {
+        Notifier {
+            mailer,
+            app_url: app_url.trim_end_matches('/').to_string(),
+        }
+    }
+
+    /// Tells the resume's owner a render finished, if `render_complete` is on.
+    pub async fn render_finished(
+        &self,
+        db: &PgPool,
+        blobs: &dyn BlobStore,
+        resume_id: Uuid,
+        outcome: RenderOutcome<'_>,
+    ) {
+        if let Err(e) = self
+            .try_render_finished(db, blobs, resume_id, outcome)
+            .await
+        {
+            warn!(%resume_id, "render notification not sent: {e:#}");
+        }
+    }
+
+    /// Posts a finished single generation to the user's webhooks. Its result went
+    /// back in the response, so there is no email.
+    pub fn generation_finished(&self, db: &PgPool, user_id: Uuid, resume_id: Uuid) {
+        webhooks::notify(
+            db,
+            user_id,
+            WebhookEvent::GenerationComplete,
+            generation_message(&self.resume_url(resume_id)),
+        );
+    }
+
+    /// Tells the user a batch finished, if `batch_complete` is on. `jobs` holds each
+    /// JD's resume id or error message, in batch order.
+    pub async fn batch_finished(&self, db: &PgPool, user_id: Uuid, jobs: &[Result<Uuid, String>]) {
+        if let Err(e) = self.try_batch_finished(db, user_id, jobs).await {
+            warn!(%user_id, "batch notification not sent: {e:#}");
+        }
+    }
+
+    async fn try_render_finished(
+        &self,
+        db: &PgPool,
+        blobs: &dyn BlobStore,
+        resume_id: Uuid,
+        outcome: RenderOutcome<'_>,
+    ) -> Result<()> {
+        let owner = sqlx::query!(
+            "SELECT u.id, u.email FROM resumes r JOIN users u ON u.id = r.user_id WHERE r.id = $1",
+            resume_id
+        )
+        .fetch_optional(db)
+        .await?;
+        // Deleted along with its account while rendering.
+        let Some(owner) = owner else {
+            return Ok(());
+        };
+        let event = match outcome {
+            RenderOutcome::Rendered { .. } => WebhookEvent::RenderComplete,
+            RenderOutcome::Failed { .. } => WebhookEvent::RenderFailed,
+        };
+        webhooks::notify(
+            db,
+            owner.id,
+            event,
+            render_message(&self.resume_url(resume_id), &outcome),
+        );
+        if !preferences::load(db, owner.id)
+            .await?
+            .notifications
+            .render_complete
+        {
+            return Ok(());
+        }
+        let pdf_url = match outcome {
+            RenderOutcome::Rendered { pdf_key } => Some(
+                blobs
+                    .presigned_get(pdf_key, PDF_LINK_TTL, &format!("resume-{resume_id}.pdf"))
+                    .await?,
+            ),
+            RenderOutcome::Failed { .. } => None,
+        };
+        let email = render_email(
+            owner.email,
+            &self.resume_url(resume_id),
+            &outcome,
+            pdf_url.as_deref(),
+        );
+        self.mailer.send(&email).await
+    }
+
+    async fn try_batch_finished(
+        &self,
+        db: &PgPool,
+        user_id: Uuid,
+        jobs: &[Result<Uuid, String>],
+    ) -> Result<()> {
+        webhooks::notify(
+            db,
+            user_id,
+            WebhookEvent::GenerationComplete,
+            batch_message(jobs, |id| self.resume_url(id)),
+        );
+        if !preferences::load(db, user_id)
+            .await?
+            .notifications
+            .batch_complete
+        {
+            return Ok(());
+        }
+        let to = sqlx::query_scalar!("SELECT email FROM users WHERE id = $1", user_id)
+            .fetch_optional(db)
+            .await?;
+        let Some(to) = to else {
+            return Ok(());
+        };
+        let email = batch_email(to, jobs, |id| self.resume_url(id));
+        self.mailer.send(&email).await
+    }
+
+    /// Tells the user about stale context: webhooks subscribed to `context_stale`, and
+    /// email if `context_nudges` is on.
+    pub async fn context_stale(&self, db: &PgPool, user_id: Uuid, nudges: &[ContextNudge]) {
+        if let Err(e) = self.try_context_stale(db, user_id, nudges).await {
+            warn!(%user_id, "context nudge email not sent: {e:#}");
+        }
+    }
+
+    async fn try_context_stale(
+        &self,
+        db: &PgPool,
+        user_id: Uuid,
+        nudges: &[ContextNudge],
+    ) -> Result<()> {
+        let context_url = self.context_url();
+        webhooks::notify(
+            db,
+            user_id,
+            WebhookEvent::ContextStale,
+            nudge_message(nudges, &context_url),
+        );
+        if !preferences::load(db, user_id)
+            .await?
+            .notifications
+            .context_nudges
+        {
+            return Ok(());
+        }
+        let to = sqlx::query_scalar!("SELECT email FROM users WHERE id = $1", user_id)
+            .fetch_optional(db)
+            .await?;
+        let Some(to) = to else {
+            return Ok(());
+        };
+        self.mailer
+            .send(&nudge_email(to, nudges, &context_url))
+            .await
+    }
+
+    fn resume_url(&self, resume_id: Uuid) -> String {
+        format!("{}/resumes/{resume_id}", self.app_url)
+    }
+
+    fn context_url(&self) -> String {
+        format!("{}/context", self.app_url)
+    }
+}
+
+// ────────────────────────────────────────────────────────────────────────────
+// Messages
+// ────────────────────────────────────────────────────────────────────────────
+
+const FOOTER: &str =
+    "You are receiving this because job notifications are on in your Templar preferences.";
+
+fn render_email(
+    to: String,
+    resume_url: &str,
+    outcome: &RenderOutcome<'_>,
+    pdf_url: Option<&str>,
+) -> Email {
+    let days = PDF_LINK_TTL.as_secs() / 86_400;
+    let (subject, body) = match (outcome, pdf_url) {
+        (RenderOutcome::Rendered { .. }, Some(pdf_url)) => (
+            "Your resume PDF is ready",
+            format!(
+                "Your resume has finished rendering.\n\n\
+                 View it: {resume_url}\n\
+                 Download the PDF (link valid for {days} days): {pdf_url}"
+            ),
+        ),
+        (RenderOutcome::Rendered { .. }, None) => (
+            "Your resume PDF is ready",
+            format!("Your resume has finished rendering.\n\nView it: {resume_url}"),
+        ),
+        (RenderOutcome::Failed { error }, _) => (
+            "Your resume could not be rendered",
+            format!(
+                "Rendering your resume failed: {error}\n\n\
+                 Open it to try again: {resume_url}"
+            ),
+        ),
+    };
+    Email {
+        to,
+        subject: subject.to_string(),
+        body: format!("{body}\n\n{FOOTER}\n"),
+    }
+}
+
+fn batch_email(
+    to: String,
+    jobs: &[Result<Uuid, String>],
+    resume_url: impl Fn(Uuid) -> String,
+) -> Email {
+    Email {
+        to,
+        subject: batch_subject(jobs),
+        body: format!(
+            "Your batch generation has finished.\n\n{}\n\n{FOOTER}\n",
+            batch_lines(jobs, resume_url).join("\n")
+        ),
+    }
+}
+
+fn batch_subject(jobs: &[Result<Uuid, String>]) -> String {
+    match jobs.iter().filter(|job| job.is_ok()).count() {
+        0 => "Your batch generation failed".to_string(),
+        n if n == jobs.len() => format!("Your {n} resumes are ready"),
+        n => format!("{n} of {} resumes generated", jobs.len()),
+    }
+}
+
+fn batch_lines(jobs: &[Result<Uuid, String>], resume_url: impl Fn(Uuid) -> String) -> Vec<String> {
+    jobs.iter()
+        .enumerate()
+        .map(|(i, job)| match job {
+            Ok(resume_id) => format!("{}. Generated: {}", i + 1, resume_url(*resume_id)),
+            Err(error) => format!("{}. Failed: {error}", i + 1),
+        })
+        .collect()
+}
+
+const NUDGE_FOOTER: &str =
+    "You are receiving this because context nudges are on in your Templar preferences.";
+
+fn nudge_subject(nudges: &[ContextNudge]) -> String {
+    match nudges.len() {
+        1 => "Your Templar context needs an update".to_string(),
+        n => format!("{n} parts of your Templar context need an update"),
+    }
+}
+
+fn nudge_email(to: String, nudges: &[ContextNudge], context_url: &str) -> Email {
+    let lines: Vec<String> = nudges.iter().map(|n| format!("- {}", n.message)).collect();
+    Email {
+        to,
+        subject: nudge_subject(nudges),
+        body: format!(
+            "Resumes are only as current as your context.\n\n{}\n\n\
+             Update it: {context_url}\n\n{NUDGE_FOOTER}\n",
+            lines.join("\n")
+        ),
+    }
+}
+
+fn nudge_message(nudges: &[ContextNudge], context_url: &str) -> Message {
+    Message {
+        title: nudge_subject(nudges),
+        lines: nudges.iter().map(|n| n.message.clone()).collect(),
+        link: Some(context_url.to_string()),
+        level: Level::Warning,
+    }
+}
+
+/// Most batch outcomes listed in a chat message; the rest are counted.
+const MAX_LISTED_JOBS: usize = 10;
+
+fn render_message(resume_url: &str, outcome: &RenderOutcome<'_>) -> Message {
+    let (title, line, level) = match outcome {
+        RenderOutcome::Rendered { .. } => (
+            "Your resume PDF is ready",
+            "Your resume has finished rendering.".to_string(),
+            Level::Success,
+        ),
+        RenderOutcome::Failed { error } => (
+            "Your resume could not be rendered",
+            format!("Rendering failed: {error}"),
+            Level::Failure,
+        ),
+    };
+    Message {
+        title: title.to_string(),
+        lines: vec![line],
+        link: Some(resume_url.to_string()),
+        level,
+    }
+}
+
+fn generation_message(resume_url: &str) -> Message {
+    Message {
+        title: "Your resume is ready".to_string(),
+        lines: vec!["A new resume has been generated.".to_string()],
+        link: Some(resume_url.to_string()),
+        level: Level::Success,
+    }
+}
+
+fn batch_message(jobs: &[Result<Uuid, String>], resume_url: impl Fn(Uuid) -> String) -> Message {
+    let mut lines = batch_lines(jobs, resume_url);
+    if lines.len() > MAX_LISTED_JOBS {
+        let more = lines.len() - MAX_LISTED_JOBS;
+        lines.truncate(MAX_LISTED_JOBS);
+        lines.push(format!("…and {more} more"));
+    }
+    let level = match jobs.iter().filter(|job| job.is_ok()).count() {
+        0 => Level::Failure,
+        n if n == jobs.len() => Level::Success,
+        _ => Level::Warning,
+    };
+    Message {
+        title: batch_subject(jobs),
+        lines,
+        link: None,
+        level,
+    }
+}
+
+// ────────────────────────────────────────────────────────────────────────────
+// Tests
+// ────────────────────────────────────────────────────────────────────────────
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_render_email_links_resume_and_pdf() {
+        let email = render_email(
+            "ada@example.com".into(),
+            "https://app.templar.dev/resumes/r1",
+            &RenderOutcome::Rendered {
+                pdf_key: "pdfs/r1.pdf",
+            },
+            Some("https://s3/pdfs/r1.pdf?sig"),
+        );
+        assert_eq!(email.subject, "Your resume PDF is ready");
+        assert!(email
+            .body
+            .contains("View it: https://app.templar.dev/resumes/r1"));
+        assert!(email
+            .body
+            .contains("(link valid for 7 days): https://s3/pdfs/r1.pdf?sig"));
+
+        let failed = render_email(
+            "ada@example.com".into(),
+            "https://app.templar.dev/resumes/r1",
+            &RenderOutcome::Failed {
+                error: "pdflatex exited with 1",
+            },
+            None,
+        );
+        assert_eq!(failed.subject, "Your resume could not be rendered");
+        assert!(failed.body.contains("failed: pdflatex exited with 1"));
+        assert!(failed.body.ends_with(&format!("{FOOTER}\n")));
+    }
+
+    #[test]
+    fn test_batch_email_lists_each_outcome() {
+        let (a, b) = (Uuid::new_v4(), Uuid::new_v4());
+        let url = |id: Uuid| format!("http://app/resumes/{id}");
+
+        let mixed = batch_email(
+            "ada@example.com".into(),
+            &[Ok(a), Err("LLM timed out".into()), Ok(b)],
+            url,
+        );
+        assert_eq!(mixed.subject, "2 of 3 resumes generated");
+        assert!(mixed
+            .body
+            .contains(&format!("1. Generated: http://app/resumes/{a}")));
+        assert!(mixed.body.contains("2. Failed: LLM timed out"));
+        assert!(mixed
+            .body
+            .contains(&format!("3. Generated: http://app/resumes/{b}")));
+
+        assert_eq!(
+            batch_email("ada@example.com".into(), &[Ok(a), Ok(b)], url).subject,
+            "Your 2 resumes are ready"
+        );
+        assert_eq!(
+            batch_email("ada@example.com".into(), &[Err("x".into())], url).subject,
+            "Your batch generation failed"
+        );
+    }
+
+    #[test]
+    fn test_batch_message_caps_listed_jobs() {
+        let mut jobs: Vec<Result<Uuid, String>> = (0..12).map(|_| Ok(Uuid::new_v4())).collect();
+        jobs.push(Err("LLM timed out".into()));
+        let message = batch_message(&jobs, |id| format!("http://app/resumes/{id}"));
+
+        assert_eq!(message.title, "12 of 13 resumes generated");
+        assert_eq!(message.level, Level::Warning);
+        assert_eq!(message.lines.len(), MAX_LISTED_JOBS + 1);
+        assert_eq!(message.lines.last().unwrap(), "…and 3 more");
+
+        let failed = render_message(
+            "http://app/resumes/r1",
+            &RenderOutcome::Failed {
+                error: "pdflatex exited with 1",
+            },
+        );
+        assert_eq!(failed.level, Level::Failure);
+        assert_eq!(
+            failed.lines,
+            vec!["Rendering failed: pdflatex exited with 1"]
+        );
+    }
+
+    #[test]
+    fn test_nudge_email_lists_each_nudge() {
+        use crate::context::freshness::NudgeKind;
+
+        let nudge = |message: &str| ContextNudge {
+            kind: NudgeKind::CurrentRoleStale,
+            entry_id: None,
+            label: None,
+            last_updated_at: chrono::Utc::now(),
+            message: message.to_string(),
+        };
+        let nudges = [
+            nudge("Acme was last updated 10 months ago."),
+            nudge("Your skills have not changed in 14 months."),
+        ];
+        let email = nudge_email("ada@example.com".into(), &nudges, "http://app/context");
+
+        assert_eq!(
+            email.subject,
+            "2 parts of your Templar context need an update"
+        );
+        assert!(email
+            .body
+            .contains("- Acme was last updated 10 months ago.\n- Your skills"));
+        assert!(email.body.contains("Update it: http://app/context"));
+        assert!(email.body.ends_with(&format!("{NUDGE_FOOTER}\n")));
+
+        let message = nudge_message(&nudges[..1], "http://app/context");
+        assert_eq!(message.title, "Your Templar context needs an update");
+        assert_eq!(message.level, Level::Warning);
+    }
+
+    #[test]
+    fn test_resume_url_trims_trailing_slash() {
+        let notifier = Notifier::new(Arc::new(LogMailer), "https://app.templar.dev/");
+        let id = Uuid::nil();
+        assert_eq!(
+            notifier.resume_url(id),
+            format!("https://app.templar.dev/resumes/{id}")
+        );
+    }
+}
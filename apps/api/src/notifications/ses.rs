@@ -0,0 +1,106 @@
+//! `Mailer` on Amazon SES — one SigV4-signed `SendEmail` call to the v2 API per email.
+
+use std::time::SystemTime;
+
+use anyhow::{bail, Result};
+use async_trait::async_trait;
+use aws_credential_types::Credentials;
+use aws_sigv4::http_request::{sign, SignableBody, SignableRequest, SigningSettings};
+use aws_sigv4::sign::v4;
+use serde_json::{json, Value};
+
+use crate::config::Config;
+use crate::notifications::{Email, Mailer};
+
+pub struct SesMailer {
+    http: reqwest::Client,
+    credentials: Credentials,
+    region: String,
+    from: String,
+}
+
+impl SesMailer {
+    /// Signs with the same static AWS credentials the S3 store uses.
+    pub fn new(config: &Config) -> Self {
+        SesMailer {
+            http: reqwest::Client::new(),
+            credentials: Credentials::new(
+                &config.s3.access_key_id,
+                config.s3.secret_access_key.expose(),
+                None,
+                None,
+                "templar-static",
+            ),
+            region: config.email.ses_region.clone(),
+            from: config.email.from.clone(),
+        }
+    }
+
+    fn endpoint(&self) -> String {
+        format!(
+            "https://email.{}.amazonaws.com/v2/email/outbound-emails",
+            self.region
+        )
+    }
+}
+
+#[async_trait]
+impl Mailer for SesMailer {
+    fn describe(&self) -> String {
+        format!("ses ({})", self.region)
+    }
+
+    async fn send(&self, email: &Email) -> Result<()> {
+        let url = self.endpoint();
+        let body = serde_json::to_vec(&send_email_body(&self.from, email))?;
+
+        let identity = self.credentials.clone().into();
+        let params = v4::SigningParams::builder()
+            .identity(&identity)
+            .region(&self.region)
+            .name("ses")
+            .time(SystemTime::now())
+            .settings(SigningSettings::default())
+            .build()?
+            .into();
+        let signable = SignableRequest::new(
+            "POST",
+            url.as_str(),
+            [("content-type", "application/json")].into_iter(),
+            SignableBody::Bytes(&body),
+        )?;
+        let (instructions, _) = sign(signable, &params)?.into_parts();
+
+        let mut request = self
+            .http
+            .post(&url)
+            .header("content-type", "application/json");
+        for (name, value) in instructions.headers() {
+            request = request.header(name, value);
+        }
+        let response = request.body(body).send().await?;
+        if !response.status().is_success() {
+            let status = response.status();
+            let detail: Value = response.json().await.unwrap_or(Value::Null);
+            bail!(
+                "SES SendEmail failed with HTTP {status}: {}",
+                detail["message"].as_str().unwrap_or("no message")
+            );
+        }
+        Ok(())
+    }
+}
+
+/// The `SendEmail` request body for a plain-text email.
+fn send_email_body(from: &str, email: &Email) -> Value {
+    json!({
+        "FromEmailAddress": from,
+        "Destination": { "ToAddresses": [email.to] },
+        "Content": {
+            "Simple": {
+                "Subject": { "Data": email.subject, "Charset": "UTF-8" },
+                "Body": { "Text": { "Data": email.body, "Charset": "UTF-8" } },
+            }
+        }
+    })
+}
@@ -0,0 +1,52 @@
+//! `Mailer` over SMTP — any relay that accepts authenticated submission.
+
+use anyhow::{anyhow, Result};
+use async_trait::async_trait;
+use lettre::message::header::ContentType;
+use lettre::message::Mailbox;
+use lettre::{AsyncSmtpTransport, AsyncTransport, Message, Tokio1Executor};
+
+use crate::notifications::{Email, Mailer};
+
+pub struct SmtpMailer {
+    transport: AsyncSmtpTransport<Tokio1Executor>,
+    from: Mailbox,
+    host: String,
+}
+
+impl SmtpMailer {
+    /// A pooled transport for `url` (`smtp://` upgrades with STARTTLS, `smtps://` is
+    /// TLS from the start). Nothing is sent or connected until the first email.
+    pub fn new(url: &str, from: &str) -> Result<Self> {
+        let host = url::Url::parse(url)?
+            .host_str()
+            .unwrap_or_default()
+            .to_string();
+        Ok(SmtpMailer {
+            transport: AsyncSmtpTransport::<Tokio1Executor>::from_url(url)?.build(),
+            from: from.parse()?,
+            host,
+        })
+    }
+}
+
+#[async_trait]
+impl Mailer for SmtpMailer {
+    fn describe(&self) -> String {
+        format!("smtp://{}", self.host)
+    }
+
+    async fn send(&self, email: &Email) -> Result<()> {
+        let message = Message::builder()
+            .from(self.from.clone())
+            .to(email.to.parse()?)
+            .subject(&email.subject)
+            .header(ContentType::TEXT_PLAIN)
+            .body(email.body.clone())?;
+        self.transport
+            .send(message)
+            .await
+            .map_err(|e| anyhow!("SMTP delivery via {} failed: {e}", self.host))?;
+        Ok(())
+    }
+}
@@ -0,0 +1,515 @@
+//! Slack and Discord webhooks — pipeline events posted to a chat channel.
+//!
+//! A user registers incoming-webhook URLs at `/api/v1/users/me/webhooks` (handlers.rs),
+//! each subscribed to some `WebhookEvent`s. `notify` posts a `Message` to every enabled
+//! webhook of theirs subscribed to the event, formatted for its platform, from a
+//! background task: like an email, a webhook never delays or fails the work it reports.
+//!
+//! Only the platforms' own webhook hosts are accepted, so a registered URL cannot point
+//! the server at anything else.
+
+use std::sync::OnceLock;
+use std::time::Duration;
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+use sqlx::{FromRow, PgPool};
+use tracing::warn;
+use uuid::Uuid;
+use validator::Validate;
+
+use crate::errors::AppError;
+use crate::validation::MAX_URL_CHARS;
+
+/// Most webhooks one user may register.
+pub const MAX_WEBHOOKS_PER_USER: i64 = 10;
+
+/// Slack rejects section text over 3000 characters; Discord embeds allow 4096.
+const MAX_MESSAGE_CHARS: usize = 2_900;
+
+const DELIVERY_TIMEOUT: Duration = Duration::from_secs(5);
+
+// ────────────────────────────────────────────────────────────────────────────
+// Types
+// ────────────────────────────────────────────────────────────────────────────
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum WebhookKind {
+    /// A Slack incoming webhook (`hooks.slack.com/services/...`).
+    Slack,
+    /// A Discord channel webhook (`discord.com/api/webhooks/...`).
+    Discord,
+}
+
+impl WebhookKind {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            WebhookKind::Slack => "slack",
+            WebhookKind::Discord => "discord",
+        }
+    }
+
+    pub fn parse(s: &str) -> Option<WebhookKind> {
+        match s {
+            "slack" => Some(WebhookKind::Slack),
+            "discord" => Some(WebhookKind::Discord),
+            _ => None,
+        }
+    }
+}
+
+/// A pipeline event a webhook can subscribe to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum WebhookEvent {
+    /// A resume generated, regenerated, or a generation batch finished.
+    GenerationComplete,
+    RenderComplete,
+    RenderFailed,
+    /// A monthly allowance crossed `quota::WARNING_FRACTION` of its limit.
+    QuotaWarning,
+    /// Context has gone stale (see `context::freshness`).
+    ContextStale,
+}
+
+impl WebhookEvent {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            WebhookEvent::GenerationComplete => "generation_complete",
+            WebhookEvent::RenderComplete => "render_complete",
+            WebhookEvent::RenderFailed => "render_failed",
+            WebhookEvent::QuotaWarning => "quota_warning",
+            WebhookEvent::ContextStale => "context_stale",
+        }
+    }
+
+    pub fn parse(s: &str) -> Option<WebhookEvent> {
+        match s {
+            "generation_complete" => Some(WebhookEvent::GenerationComplete),
+            "render_complete" => Some(WebhookEvent::RenderComplete),
+            "render_failed" => Some(WebhookEvent::RenderFailed),
+            "quota_warning" => Some(WebhookEvent::QuotaWarning),
+            "context_stale" => Some(WebhookEvent::ContextStale),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, FromRow)]
+pub struct WebhookRow {
+    pub id: Uuid,
+    pub kind: String,
+    pub url: String,
+    pub events: Vec<String>,
+    pub created_at: DateTime<Utc>,
+    pub last_delivery_at: Option<DateTime<Utc>>,
+    pub last_error: Option<String>,
+    pub disabled_at: Option<DateTime<Utc>>,
+}
+
+/// A webhook as the API returns it: the URL is a credential, so only its tail is shown.
+#[derive(Debug, Clone, Serialize)]
+pub struct WebhookView {
+    pub id: Uuid,
+    pub kind: WebhookKind,
+    /// `https://hooks.slack.com/…Xy7Q`
+    pub url_hint: String,
+    pub events: Vec<WebhookEvent>,
+    pub created_at: DateTime<Utc>,
+    pub last_delivery_at: Option<DateTime<Utc>>,
+    /// Why the last delivery failed; `None` after a successful one.
+    pub last_error: Option<String>,
+    /// Set when the platform reported the webhook deleted; nothing is posted to it
+    /// until its URL is replaced.
+    pub disabled_at: Option<DateTime<Utc>>,
+}
+
+impl From<WebhookRow> for WebhookView {
+    fn from(row: WebhookRow) -> Self {
+        WebhookView {
+            id: row.id,
+            kind: WebhookKind::parse(&row.kind).unwrap_or(WebhookKind::Slack),
+            url_hint: url_hint(&row.url),
+            events: row
+                .events
+                .iter()
+                .filter_map(|e| WebhookEvent::parse(e))
+                .collect(),
+            created_at: row.created_at,
+            last_delivery_at: row.last_delivery_at,
+            last_error: row.last_error,
+            disabled_at: row.disabled_at,
+        }
+    }
+}
+
+/// Body for `POST /api/v1/users/me/webhooks`.
+#[derive(Debug, Deserialize, Validate)]
+pub struct CreateWebhookRequest {
+    pub kind: WebhookKind,
+    #[validate(length(max = MAX_URL_CHARS))]
+    pub url: String,
+    #[validate(length(min = 1))]
+    pub events: Vec<WebhookEvent>,
+}
+
+/// Body for `PATCH /api/v1/users/me/webhooks/:id` — absent fields are left unchanged.
+/// A new `url` re-enables a disabled webhook.
+#[derive(Debug, Deserialize, Validate)]
+pub struct UpdateWebhookRequest {
+    #[validate(length(max = MAX_URL_CHARS))]
+    pub url: Option<String>,
+    #[validate(length(min = 1))]
+    pub events: Option<Vec<WebhookEvent>>,
+}
+
+/// Response of `POST /api/v1/users/me/webhooks/:id/test`.
+#[derive(Debug, Clone, Serialize)]
+pub struct WebhookTestResponse {
+    pub delivered: bool,
+    pub error: Option<String>,
+}
+
+/// Event names as stored: deduplicated, in declaration order.
+pub fn event_names(events: &[WebhookEvent]) -> Vec<String> {
+    [
+        WebhookEvent::GenerationComplete,
+        WebhookEvent::RenderComplete,
+        WebhookEvent::RenderFailed,
+        WebhookEvent::QuotaWarning,
+        WebhookEvent::ContextStale,
+    ]
+    .into_iter()
+    .filter(|e| events.contains(e))
+    .map(|e| e.as_str().to_string())
+    .collect()
+}
+
+/// Accepts only `kind`'s own webhook endpoints, over HTTPS.
+pub fn validate_url(kind: WebhookKind, raw: &str) -> Result<(), AppError> {
+    let invalid = || {
+        AppError::Validation(match kind {
+            WebhookKind::Slack => {
+                "url must be a Slack incoming webhook (https://hooks.slack.com/services/...)"
+                    .to_string()
+            }
+            WebhookKind::Discord => {
+                "url must be a Discord webhook (https://discord.com/api/webhooks/...)".to_string()
+            }
+        })
+    };
+    let url = url::Url::parse(raw.trim()).map_err(|_| invalid())?;
+    let host = url.host_str().unwrap_or_default();
+    let path = url.path();
+    let valid = url.scheme() == "https"
+        && url.port().is_none()
+        && url.username().is_empty()
+        && match kind {
+            WebhookKind::Slack => host == "hooks.slack.com" && path.starts_with("/services/"),
+            WebhookKind::Discord => {
+                matches!(
+                    host,
+                    "discord.com" | "discordapp.com" | "ptb.discord.com" | "canary.discord.com"
+                ) && path.starts_with("/api/webhooks/")
+            }
+        };
+    if valid {
+        Ok(())
+    } else {
+        Err(invalid())
+    }
+}
+
+/// Scheme, host and the last four characters of `url`.
+pub fn url_hint(url: &str) -> String {
+    let host = url::Url::parse(url)
+        .ok()
+        .and_then(|u| u.host_str().map(str::to_string))
+        .unwrap_or_default();
+    let tail: String = url
+        .chars()
+        .rev()
+        .take(4)
+        .collect::<Vec<_>>()
+        .into_iter()
+        .rev()
+        .collect();
+    format!("https://{host}/…{tail}")
+}
+
+// ────────────────────────────────────────────────────────────────────────────
+// Messages
+// ────────────────────────────────────────────────────────────────────────────
+
+/// Shown as the color of the message's side bar.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Level {
+    Success,
+    Warning,
+    Failure,
+}
+
+impl Level {
+    fn rgb(self) -> u32 {
+        match self {
+            Level::Success => 0x2EB67D,
+            Level::Warning => 0xECB22E,
+            Level::Failure => 0xE01E5A,
+        }
+    }
+}
+
+/// A platform-neutral notification: a title, body lines and an optional link.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Message {
+    pub title: String,
+    pub lines: Vec<String>,
+    pub link: Option<String>,
+    pub level: Level,
+}
+
+fn body_text(lines: &[String]) -> String {
+    let body = lines.join("\n");
+    if body.chars().count() <= MAX_MESSAGE_CHARS {
+        return body;
+    }
+    let mut cut: String = body.chars().take(MAX_MESSAGE_CHARS - 1).collect();
+    cut.push('…');
+    cut
+}
+
+/// Slack treats `&`, `<` and `>` as markup in message text.
+fn escape_slack(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+}
+
+fn slack_payload(message: &Message) -> Value {
+    let mut text = format!(
+        "*{}*\n{}",
+        escape_slack(&message.title),
+        escape_slack(&body_text(&message.lines))
+    );
+    if let Some(link) = &message.link {
+        text.push_str(&format!("\n<{link}|Open in Templar>"));
+    }
+    json!({
+        "text": message.title,
+        "attachments": [{
+            "color": format!("#{:06x}", message.level.rgb()),
+            "blocks": [{ "type": "section", "text": { "type": "mrkdwn", "text": text } }],
+        }],
+    })
+}
+
+fn discord_payload(message: &Message) -> Value {
+    let mut embed = json!({
+        "title": message.title,
+        "description": body_text(&message.lines),
+        "color": message.level.rgb(),
+    });
+    if let Some(link) = &message.link {
+        embed["url"] = json!(link);
+    }
+    json!({
+        "username": "Templar",
+        "embeds": [embed],
+        // Resume text may contain `@everyone` or role mentions; never ping anyone.
+        "allowed_mentions": { "parse": [] },
+    })
+}
+
+// ────────────────────────────────────────────────────────────────────────────
+// Delivery
+// ────────────────────────────────────────────────────────────────────────────
+
+fn http() -> &'static reqwest::Client {
+    static HTTP: OnceLock<reqwest::Client> = OnceLock::new();
+    HTTP.get_or_init(|| {
+        reqwest::Client::builder()
+            .timeout(DELIVERY_TIMEOUT)
+            .build()
+            .expect("Failed to build HTTP client")
+    })
+}
+
+/// How one post went.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Delivery {
+    Sent,
+    /// The platform no longer knows the webhook (404/410).
+    Gone(String),
+    Failed(String),
+}
+
+impl Delivery {
+    pub fn error(&self) -> Option<&str> {
+        match self {
+            Delivery::Sent => None,
+            Delivery::Gone(e) | Delivery::Failed(e) => Some(e),
+        }
+    }
+}
+
+/// Posts `message` to one webhook.
+pub async fn deliver(kind: WebhookKind, url: &str, message: &Message) -> Delivery {
+    let payload = match kind {
+        WebhookKind::Slack => slack_payload(message),
+        WebhookKind::Discord => discord_payload(message),
+    };
+    match http().post(url).json(&payload).send().await {
+        Ok(response) if response.status().is_success() => Delivery::Sent,
+        Ok(response) => {
+            let status = response.status();
+            let body = response.text().await.unwrap_or_default();
+            let error = format!("{status}: {}", body.chars().take(200).collect::<String>());
+            if matches!(status.as_u16(), 404 | 410) {
+                Delivery::Gone(error)
+            } else {
+                Delivery::Failed(error)
+            }
+        }
+        Err(e) => Delivery::Failed(e.to_string()),
+    }
+}
+
+/// Stores the outcome on the webhook row; `Gone` disables it.
+pub async fn record_delivery(db: &PgPool, webhook_id: Uuid, delivery: &Delivery) {
+    let result = sqlx::query!(
+        "UPDATE user_webhooks
+         SET last_delivery_at = NOW(), last_error = $2,
+             disabled_at = CASE WHEN $3 THEN NOW() ELSE disabled_at END
+         WHERE id = $1",
+        webhook_id,
+        delivery.error(),
+        matches!(delivery, Delivery::Gone(_))
+    )
+    .execute(db)
+    .await;
+    if let Err(e) = result {
+        warn!(%webhook_id, "webhook delivery not recorded: {e}");
+    }
+}
+
+/// Posts `message` to the user's enabled webhooks subscribed to `event`, in the
+/// background. Failures are logged and recorded on the webhook.
+pub fn notify(db: &PgPool, user_id: Uuid, event: WebhookEvent, message: Message) {
+    let db = db.clone();
+    tokio::spawn(async move {
+        let hooks = sqlx::query!(
+            "SELECT id, kind, url FROM user_webhooks
+             WHERE user_id = $1 AND $2 = ANY(events) AND disabled_at IS NULL",
+            user_id,
+            event.as_str()
+        )
+        .fetch_all(&db)
+        .await;
+        let hooks = match hooks {
+            Ok(hooks) => hooks,
+            Err(e) => {
+                warn!(%user_id, event = event.as_str(), "webhooks not loaded: {e}");
+                return;
+            }
+        };
+        for hook in hooks {
+            let Some(kind) = WebhookKind::parse(&hook.kind) else {
+                continue;
+            };
+            let delivery = deliver(kind, &hook.url, &message).await;
+            if let Some(error) = delivery.error() {
+                warn!(webhook_id = %hook.id, event = event.as_str(), "webhook not delivered: {error}");
+            }
+            record_delivery(&db, hook.id, &delivery).await;
+        }
+    });
+}
+
+// ────────────────────────────────────────────────────────────────────────────
+// Tests
+// ────────────────────────────────────────────────────────────────────────────
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn message() -> Message {
+        Message {
+            title: "Your resume PDF is ready".into(),
+            lines: vec!["Staff Engineer <Payments> at A&B".into()],
+            link: Some("https://app.templar.dev/resumes/r1".into()),
+            level: Level::Success,
+        }
+    }
+
+    #[test]
+    fn test_only_platform_webhook_urls_accepted() {
+        let slack = "https://hooks.slack.com/services/T000/B000/XXXXXXXX";
+        let discord = "https://discord.com/api/webhooks/123/abc-DEF";
+        assert!(validate_url(WebhookKind::Slack, slack).is_ok());
+        assert!(validate_url(WebhookKind::Discord, discord).is_ok());
+        assert!(validate_url(WebhookKind::Discord, slack).is_err());
+
+        for url in [
+            "http://hooks.slack.com/services/T000/B000/X",
+            "https://hooks.slack.com.evil.io/services/T000/B000/X",
+            "https://hooks.slack.com:8443/services/T000/B000/X",
+            "https://user@hooks.slack.com/services/T000/B000/X",
+            "https://hooks.slack.com/other",
+        ] {
+            assert!(validate_url(WebhookKind::Slack, url).is_err(), "{url}");
+        }
+    }
+
+    #[test]
+    fn test_url_hint_hides_the_token() {
+        assert_eq!(
+            url_hint("https://hooks.slack.com/services/T000/B000/abcdXy7Q"),
+            "https://hooks.slack.com/…Xy7Q"
+        );
+    }
+
+    #[test]
+    fn test_slack_payload_escapes_text_and_links() {
+        let payload = slack_payload(&message());
+        let text = payload["attachments"][0]["blocks"][0]["text"]["text"]
+            .as_str()
+            .unwrap();
+        assert!(text.starts_with("*Your resume PDF is ready*\n"));
+        assert!(text.contains("Staff Engineer &lt;Payments&gt; at A&amp;B"));
+        assert!(text.ends_with("<https://app.templar.dev/resumes/r1|Open in Templar>"));
+        assert_eq!(payload["attachments"][0]["color"], "#2eb67d");
+    }
+
+    #[test]
+    fn test_discord_payload_uses_an_embed_and_mentions_nobody() {
+        let payload = discord_payload(&message());
+        let embed = &payload["embeds"][0];
+        assert_eq!(embed["title"], "Your resume PDF is ready");
+        assert_eq!(embed["description"], "Staff Engineer <Payments> at A&B");
+        assert_eq!(embed["url"], "https://app.templar.dev/resumes/r1");
+        assert_eq!(payload["allowed_mentions"]["parse"], json!([]));
+    }
+
+    #[test]
+    fn test_long_bodies_truncated() {
+        let lines = vec!["x".repeat(5_000)];
+        let body = body_text(&lines);
+        assert_eq!(body.chars().count(), MAX_MESSAGE_CHARS);
+        assert!(body.ends_with('…'));
+    }
+
+    #[test]
+    fn test_event_names_dedupe_in_declaration_order() {
+        assert_eq!(
+            event_names(&[
+                WebhookEvent::QuotaWarning,
+                WebhookEvent::GenerationComplete,
+                WebhookEvent::QuotaWarning,
+            ]),
+            vec!["generation_complete", "quota_warning"]
+        );
+    }
+}
@@ -0,0 +1,377 @@
+//! Axum handlers for organizations, membership and resume annotations.
+
+use axum::{
+    extract::{Path, State},
+    http::StatusCode,
+    Json,
+};
+use uuid::Uuid;
+
+use crate::audit::{self, Action, AuditEvent};
+use crate::auth::{AuthUser, Resource};
+use crate::errors::AppError;
+use crate::models::resume::ResumeRow;
+use crate::orgs::{
+    ensure_other_owner, member_role, require_role, AddMemberRequest, AnnotationRow,
+    CreateAnnotationRequest, CreateOrgRequest, MemberMeterUsage, MemberRow, MemberUsage, MyOrgRow,
+    OrgUsageResponse, OrganizationRow, Role,
+};
+use crate::quota::{self, Meter};
+use crate::state::AppState;
+use crate::users::normalize_email;
+use crate::validation::ValidJson;
+
+// ────────────────────────────────────────────────────────────────────────────
+// Organizations
+// ────────────────────────────────────────────────────────────────────────────
+
+/// POST /api/v1/orgs
+///
+/// Creates an organization on the starter pool with the caller as its only owner.
+pub async fn handle_create_org(
+    State(state): State<AppState>,
+    auth: AuthUser,
+    ValidJson(body): ValidJson<CreateOrgRequest>,
+) -> Result<(StatusCode, Json<OrganizationRow>), AppError> {
+    let name = body.name.trim();
+
+    let mut tx = state.db.begin().await?;
+    let org = sqlx::query_as!(
+        OrganizationRow,
+        "INSERT INTO organizations (id, name) VALUES ($1, $2) RETURNING *",
+        Uuid::new_v4(),
+        name
+    )
+    .fetch_one(&mut *tx)
+    .await?;
+    sqlx::query!(
+        "INSERT INTO org_memberships (org_id, user_id, role) VALUES ($1, $2, 'owner')",
+        org.id,
+        auth.user_id
+    )
+    .execute(&mut *tx)
+    .await?;
+    tx.commit().await?;
+
+    audit::record(
+        &state.db,
+        auth.user_id,
+        AuditEvent::new(Action::OrgCreate, org.id),
+    )
+    .await;
+    Ok((StatusCode::CREATED, Json(org)))
+}
+
+/// GET /api/v1/orgs
+///
+/// Orgs the caller belongs to, with the caller's role in each.
+pub async fn handle_list_orgs(
+    State(state): State<AppState>,
+    auth: AuthUser,
+) -> Result<Json<Vec<MyOrgRow>>, AppError> {
+    let orgs = sqlx::query_as!(
+        MyOrgRow,
+        r#"SELECT o.id, o.name, o.tier, m.role, o.created_at
+           FROM org_memberships m
+           JOIN organizations o ON o.id = m.org_id
+           WHERE m.user_id = $1
+           ORDER BY o.name"#,
+        auth.user_id
+    )
+    .fetch_all(&state.db)
+    .await?;
+    Ok(Json(orgs))
+}
+
+// ────────────────────────────────────────────────────────────────────────────
+// Members
+// ────────────────────────────────────────────────────────────────────────────
+
+/// GET /api/v1/orgs/:id/members
+pub async fn handle_list_members(
+    State(state): State<AppState>,
+    auth: AuthUser,
+    Path(org_id): Path<Uuid>,
+) -> Result<Json<Vec<MemberRow>>, AppError> {
+    require_role(&state.db, org_id, auth.user_id, |_| true).await?;
+    Ok(Json(list_members(&state, org_id).await?))
+}
+
+/// POST /api/v1/orgs/:id/members
+///
+/// Adds an existing account to the org, or changes the role of a member. Owner only.
+/// 404 if no account has that email; 409 if the user is already a candidate in
+/// another org, or if the change would leave the org without an owner.
+pub async fn handle_add_member(
+    State(state): State<AppState>,
+    auth: AuthUser,
+    Path(org_id): Path<Uuid>,
+    Json(body): Json<AddMemberRequest>,
+) -> Result<Json<Vec<MemberRow>>, AppError> {
+    require_role(&state.db, org_id, auth.user_id, |r| r == Role::Owner).await?;
+    let email = normalize_email(&body.email)?;
+    let user_id: Uuid = sqlx::query_scalar!("SELECT id FROM users WHERE email = $1", &email)
+        .fetch_optional(&state.db)
+        .await?
+        .ok_or_else(|| AppError::NotFound(format!("No account for {email}")))?;
+    if body.role != Role::Owner {
+        ensure_other_owner(&state.db, org_id, user_id).await?;
+    }
+
+    sqlx::query!(
+        r#"INSERT INTO org_memberships (org_id, user_id, role)
+           VALUES ($1, $2, $3)
+           ON CONFLICT (org_id, user_id) DO UPDATE SET role = EXCLUDED.role"#,
+        org_id,
+        user_id,
+        body.role.as_str()
+    )
+    .execute(&state.db)
+    .await
+    .map_err(|e| match &e {
+        sqlx::Error::Database(db) if db.is_unique_violation() => AppError::Conflict(format!(
+            "{email} is already a candidate in another organization"
+        )),
+        _ => AppError::Database(e),
+    })?;
+
+    audit::record(
+        &state.db,
+        auth.user_id,
+        AuditEvent::new(Action::OrgMemberUpdate, org_id)
+            .after(serde_json::json!({ "user_id": user_id, "role": body.role })),
+    )
+    .await;
+    Ok(Json(list_members(&state, org_id).await?))
+}
+
+/// DELETE /api/v1/orgs/:id/members/:user_id
+///
+/// Removes a member. Owners can remove anyone; any member can remove themselves.
+/// The last owner cannot leave (409).
+pub async fn handle_remove_member(
+    State(state): State<AppState>,
+    auth: AuthUser,
+    Path((org_id, user_id)): Path<(Uuid, Uuid)>,
+) -> Result<StatusCode, AppError> {
+    require_role(&state.db, org_id, auth.user_id, |r| {
+        r == Role::Owner || user_id == auth.user_id
+    })
+    .await?;
+    if member_role(&state.db, org_id, user_id).await?.is_none() {
+        return Err(AppError::NotFound(format!(
+            "User {user_id} is not a member"
+        )));
+    }
+    ensure_other_owner(&state.db, org_id, user_id).await?;
+
+    sqlx::query!(
+        "DELETE FROM org_memberships WHERE org_id = $1 AND user_id = $2",
+        org_id,
+        user_id
+    )
+    .execute(&state.db)
+    .await?;
+    audit::record(
+        &state.db,
+        auth.user_id,
+        AuditEvent::new(Action::OrgMemberRemove, org_id)
+            .before(serde_json::json!({ "user_id": user_id })),
+    )
+    .await;
+    Ok(StatusCode::NO_CONTENT)
+}
+
+// ────────────────────────────────────────────────────────────────────────────
+// Coaching
+// ────────────────────────────────────────────────────────────────────────────
+
+/// GET /api/v1/orgs/:id/candidates/:user_id/resumes
+///
+/// A candidate's resumes, newest first. Owners and coaches only; open one with
+/// `GET /api/v1/resumes/:id`.
+pub async fn handle_candidate_resumes(
+    State(state): State<AppState>,
+    auth: AuthUser,
+    Path((org_id, candidate_id)): Path<(Uuid, Uuid)>,
+) -> Result<Json<Vec<ResumeRow>>, AppError> {
+    require_role(&state.db, org_id, auth.user_id, Role::can_coach).await?;
+    if member_role(&state.db, org_id, candidate_id).await? != Some(Role::Candidate) {
+        return Err(AppError::NotFound(format!(
+            "Candidate {candidate_id} not found"
+        )));
+    }
+    let resumes = sqlx::query_as!(
+        ResumeRow,
+        "SELECT * FROM resumes WHERE user_id = $1 AND deleted_at IS NULL ORDER BY created_at DESC",
+        candidate_id
+    )
+    .fetch_all(&state.db)
+    .await?;
+    Ok(Json(resumes))
+}
+
+/// GET /api/v1/orgs/:id/usage
+///
+/// This month's usage of the org's shared pool, plus every member's own counters.
+/// Owners and coaches only.
+pub async fn handle_org_usage(
+    State(state): State<AppState>,
+    auth: AuthUser,
+    Path(org_id): Path<Uuid>,
+) -> Result<Json<OrgUsageResponse>, AppError> {
+    require_role(&state.db, org_id, auth.user_id, Role::can_coach).await?;
+    let tier: String = sqlx::query_scalar!("SELECT tier FROM organizations WHERE id = $1", org_id)
+        .fetch_one(&state.db)
+        .await?;
+    let pool = quota::org_pool_usage(&state.redis, org_id, &tier).await?;
+
+    let members = list_members(&state, org_id).await?;
+    let user_ids: Vec<Uuid> = members.iter().map(|m| m.user_id).collect();
+    let mut per_meter = Vec::with_capacity(Meter::ALL.len());
+    for meter in Meter::ALL {
+        per_meter.push((
+            meter,
+            quota::user_usage(&state.redis, &user_ids, meter).await?,
+        ));
+    }
+    let members = members
+        .into_iter()
+        .enumerate()
+        .map(|(i, m)| MemberUsage {
+            user_id: m.user_id,
+            email: m.email,
+            role: m.role,
+            meters: per_meter
+                .iter()
+                .map(|(meter, used)| MemberMeterUsage {
+                    meter: *meter,
+                    used: used[i],
+                })
+                .collect(),
+        })
+        .collect();
+
+    Ok(Json(OrgUsageResponse { pool, members }))
+}
+
+// ────────────────────────────────────────────────────────────────────────────
+// Annotations
+// ────────────────────────────────────────────────────────────────────────────
+
+/// GET /api/v1/resumes/:id/annotations
+///
+/// Notes on a resume, oldest first. Readable by the owner and their coaches.
+pub async fn handle_list_annotations(
+    State(state): State<AppState>,
+    auth: AuthUser,
+    Path(resume_id): Path<Uuid>,
+) -> Result<Json<Vec<AnnotationRow>>, AppError> {
+    auth.authorize_view(&state.db, Resource::Resume, resume_id)
+        .await?;
+    let notes = sqlx::query_as!(
+        AnnotationRow,
+        "SELECT * FROM resume_annotations WHERE resume_id = $1 ORDER BY created_at",
+        resume_id
+    )
+    .fetch_all(&state.db)
+    .await?;
+    Ok(Json(notes))
+}
+
+/// POST /api/v1/resumes/:id/annotations
+///
+/// Adds a note to a resume, optionally pinned to one of its bullets. The owner and
+/// their coaches can annotate; the resume itself is never changed.
+pub async fn handle_create_annotation(
+    State(state): State<AppState>,
+    auth: AuthUser,
+    Path(resume_id): Path<Uuid>,
+    ValidJson(body): ValidJson<CreateAnnotationRequest>,
+) -> Result<(StatusCode, Json<AnnotationRow>), AppError> {
+    auth.authorize_view(&state.db, Resource::Resume, resume_id)
+        .await?;
+    let text = body.body.trim();
+    if let Some(bullet_id) = body.bullet_id {
+        let on_resume: bool = sqlx::query_scalar!(
+            r#"SELECT EXISTS (
+                   SELECT 1 FROM resume_bullets WHERE id = $1 AND resume_id = $2
+               ) AS "exists!""#,
+            bullet_id,
+            resume_id
+        )
+        .fetch_one(&state.db)
+        .await?;
+        if !on_resume {
+            return Err(AppError::Validation(format!(
+                "Bullet {bullet_id} is not part of resume {resume_id}"
+            )));
+        }
+    }
+
+    let note = sqlx::query_as!(
+        AnnotationRow,
+        r#"INSERT INTO resume_annotations (id, resume_id, bullet_id, author_id, body)
+           VALUES ($1, $2, $3, $4, $5)
+           RETURNING *"#,
+        Uuid::new_v4(),
+        resume_id,
+        body.bullet_id,
+        auth.user_id,
+        text
+    )
+    .fetch_one(&state.db)
+    .await?;
+    audit::record(
+        &state.db,
+        auth.user_id,
+        AuditEvent::new(Action::ResumeAnnotate, resume_id)
+            .after(serde_json::json!({ "annotation_id": note.id, "bullet_id": note.bullet_id })),
+    )
+    .await;
+    Ok((StatusCode::CREATED, Json(note)))
+}
+
+/// DELETE /api/v1/resumes/:id/annotations/:note_id
+///
+/// Removes a note. Only its author can; anyone else gets 404.
+pub async fn handle_delete_annotation(
+    State(state): State<AppState>,
+    auth: AuthUser,
+    Path((resume_id, note_id)): Path<(Uuid, Uuid)>,
+) -> Result<StatusCode, AppError> {
+    let deleted = sqlx::query!(
+        "DELETE FROM resume_annotations WHERE id = $1 AND resume_id = $2 AND author_id = $3",
+        note_id,
+        resume_id,
+        auth.user_id
+    )
+    .execute(&state.db)
+    .await?
+    .rows_affected();
+    if deleted == 0 {
+        return Err(AppError::NotFound(format!(
+            "Annotation {note_id} not found"
+        )));
+    }
+    Ok(StatusCode::NO_CONTENT)
+}
+
+// ────────────────────────────────────────────────────────────────────────────
+// Helpers
+// ────────────────────────────────────────────────────────────────────────────
+
+async fn list_members(state: &AppState, org_id: Uuid) -> Result<Vec<MemberRow>, AppError> {
+    let members = sqlx::query_as!(
+        MemberRow,
+        r#"SELECT u.id AS user_id, u.email, u.display_name, m.role, m.created_at AS joined_at
+           FROM org_memberships m
+           JOIN users u ON u.id = m.user_id
+           WHERE m.org_id = $1
+           ORDER BY m.role, u.email"#,
+        org_id
+    )
+    .fetch_all(&state.db)
+    .await?;
+    Ok(members)
+}
@@ -0,0 +1,315 @@
+//! Organizations — career coaches and bootcamps managing a group of candidates.
+//!
+//! POST   /api/v1/orgs                                — create an org; the caller becomes its owner
+//! GET    /api/v1/orgs                                — orgs the caller belongs to, with their role
+//! GET    /api/v1/orgs/:id/members                    — members (any member)
+//! POST   /api/v1/orgs/:id/members                    — add a user by email or change their role (owner)
+//! DELETE /api/v1/orgs/:id/members/:user_id           — remove a member (owner, or the member themselves)
+//! GET    /api/v1/orgs/:id/candidates/:user_id/resumes — a candidate's resumes (owner, coach)
+//! GET    /api/v1/orgs/:id/usage                      — shared pool and per-member usage (owner, coach)
+//! GET    /api/v1/resumes/:id/annotations             — notes on a resume (owner, their coaches)
+//! POST   /api/v1/resumes/:id/annotations             — add a note (owner, their coaches)
+//! DELETE /api/v1/resumes/:id/annotations/:note_id    — remove a note (its author)
+//!
+//! Roles:
+//! - `owner` manages membership and has every coach permission.
+//! - `coach` can read the resumes of the org's candidates (`AuthUser::authorize_view`)
+//!   and annotate them. Coaches never edit a candidate's resumes or context.
+//! - `candidate` is coached. A user is a candidate in at most one org; that org's
+//!   shared pool replaces their own tier limits (see `quota`).
+//!
+//! Types and membership checks live here; handlers in handlers.rs.
+
+pub mod handlers;
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::{FromRow, PgPool};
+use uuid::Uuid;
+use validator::Validate;
+
+use crate::errors::AppError;
+use crate::quota::{Meter, UsageReport};
+use crate::validation::{not_blank, MAX_NAME_CHARS};
+
+/// Longest accepted annotation, in characters.
+pub const MAX_ANNOTATION_CHARS: u64 = 2_000;
+
+// ────────────────────────────────────────────────────────────────────────────
+// Roles
+// ────────────────────────────────────────────────────────────────────────────
+
+/// A member's role, stored in `org_memberships.role`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Role {
+    Owner,
+    Coach,
+    Candidate,
+}
+
+impl Role {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Role::Owner => "owner",
+            Role::Coach => "coach",
+            Role::Candidate => "candidate",
+        }
+    }
+
+    pub fn parse(s: &str) -> Option<Role> {
+        [Role::Owner, Role::Coach, Role::Candidate]
+            .into_iter()
+            .find(|r| r.as_str() == s)
+    }
+
+    /// Owners and coaches can read and annotate candidates' resumes.
+    pub fn can_coach(self) -> bool {
+        matches!(self, Role::Owner | Role::Coach)
+    }
+}
+
+// ────────────────────────────────────────────────────────────────────────────
+// Rows
+// ────────────────────────────────────────────────────────────────────────────
+
+/// A row from `organizations`. `tier` selects the shared pool (`quota::org_tier_limits`).
+#[derive(Debug, Clone, Serialize, FromRow)]
+pub struct OrganizationRow {
+    pub id: Uuid,
+    pub name: String,
+    pub tier: String,
+    pub created_at: DateTime<Utc>,
+}
+
+/// An org as listed for one of its members.
+#[derive(Debug, Clone, Serialize, FromRow)]
+pub struct MyOrgRow {
+    pub id: Uuid,
+    pub name: String,
+    pub tier: String,
+    /// The caller's role.
+    pub role: String,
+    pub created_at: DateTime<Utc>,
+}
+
+/// A member with the profile fields other members may see.
+#[derive(Debug, Clone, Serialize, FromRow)]
+pub struct MemberRow {
+    pub user_id: Uuid,
+    pub email: String,
+    pub display_name: Option<String>,
+    pub role: String,
+    pub joined_at: DateTime<Utc>,
+}
+
+/// A row from `resume_annotations`: a coach's (or the owner's) note on a resume,
+/// optionally pinned to one bullet.
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
+pub struct AnnotationRow {
+    pub id: Uuid,
+    pub resume_id: Uuid,
+    pub bullet_id: Option<Uuid>,
+    pub author_id: Uuid,
+    pub body: String,
+    pub created_at: DateTime<Utc>,
+}
+
+// ────────────────────────────────────────────────────────────────────────────
+// Request / response types
+// ────────────────────────────────────────────────────────────────────────────
+
+/// Body for `POST /api/v1/orgs`.
+#[derive(Debug, Deserialize, Validate)]
+pub struct CreateOrgRequest {
+    #[validate(custom(function = "not_blank"), length(max = MAX_NAME_CHARS))]
+    pub name: String,
+}
+
+/// Body for `POST /api/v1/orgs/:id/members`.
+#[derive(Debug, Deserialize)]
+pub struct AddMemberRequest {
+    /// Email of an existing account.
+    pub email: String,
+    pub role: Role,
+}
+
+/// Body for `POST /api/v1/resumes/:id/annotations`.
+#[derive(Debug, Deserialize, Validate)]
+pub struct CreateAnnotationRequest {
+    /// Pin the note to one bullet of the resume; absent for a note on the whole resume.
+    #[serde(default)]
+    pub bullet_id: Option<Uuid>,
+    #[validate(custom(function = "not_blank"), length(max = MAX_ANNOTATION_CHARS))]
+    pub body: String,
+}
+
+/// One member's usage of one meter this month.
+#[derive(Debug, Clone, Serialize)]
+pub struct MemberMeterUsage {
+    pub meter: Meter,
+    pub used: i64,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct MemberUsage {
+    pub user_id: Uuid,
+    pub email: String,
+    pub role: String,
+    pub meters: Vec<MemberMeterUsage>,
+}
+
+/// Response for `GET /api/v1/orgs/:id/usage`.
+#[derive(Debug, Clone, Serialize)]
+pub struct OrgUsageResponse {
+    /// The shared pool candidates draw from.
+    pub pool: UsageReport,
+    pub members: Vec<MemberUsage>,
+}
+
+// ────────────────────────────────────────────────────────────────────────────
+// Membership checks
+// ────────────────────────────────────────────────────────────────────────────
+
+/// `user_id`'s role in `org_id`, if they are a member.
+pub async fn member_role(
+    db: &PgPool,
+    org_id: Uuid,
+    user_id: Uuid,
+) -> Result<Option<Role>, AppError> {
+    let role: Option<String> = sqlx::query_scalar!(
+        "SELECT role FROM org_memberships WHERE org_id = $1 AND user_id = $2",
+        org_id,
+        user_id
+    )
+    .fetch_optional(db)
+    .await?;
+    Ok(role.as_deref().and_then(Role::parse))
+}
+
+/// The caller's role, if `allowed` accepts it. Non-members get 404 (as if the org did
+/// not exist); members without the needed role get 403.
+pub async fn require_role(
+    db: &PgPool,
+    org_id: Uuid,
+    user_id: Uuid,
+    allowed: impl Fn(Role) -> bool,
+) -> Result<Role, AppError> {
+    check_role(org_id, member_role(db, org_id, user_id).await?, allowed)
+}
+
+fn check_role(
+    org_id: Uuid,
+    role: Option<Role>,
+    allowed: impl Fn(Role) -> bool,
+) -> Result<Role, AppError> {
+    match role {
+        Some(role) if allowed(role) => Ok(role),
+        Some(_) => Err(AppError::Forbidden),
+        None => Err(AppError::NotFound(format!(
+            "Organization {org_id} not found"
+        ))),
+    }
+}
+
+/// Whether `coach_id` is an owner or coach of an org `candidate_id` is a candidate in.
+pub async fn coaches(db: &PgPool, coach_id: Uuid, candidate_id: Uuid) -> Result<bool, AppError> {
+    let coaches: bool = sqlx::query_scalar!(
+        r#"SELECT EXISTS (
+               SELECT 1
+               FROM org_memberships c
+               JOIN org_memberships m ON m.org_id = c.org_id
+               WHERE c.user_id = $2 AND c.role = 'candidate'
+                 AND m.user_id = $1 AND m.role IN ('owner', 'coach')
+           ) AS "coaches!""#,
+        coach_id,
+        candidate_id
+    )
+    .fetch_one(db)
+    .await?;
+    Ok(coaches)
+}
+
+/// Owners of `org_id` other than `except`.
+async fn other_owners(db: &PgPool, org_id: Uuid, except: Uuid) -> Result<i64, AppError> {
+    let count = sqlx::query_scalar!(
+        r#"SELECT COUNT(*) AS "count!" FROM org_memberships
+           WHERE org_id = $1 AND role = 'owner' AND user_id <> $2"#,
+        org_id,
+        except
+    )
+    .fetch_one(db)
+    .await?;
+    Ok(count)
+}
+
+/// Errors if removing or demoting `user_id` would leave `org_id` without an owner.
+pub async fn ensure_other_owner(db: &PgPool, org_id: Uuid, user_id: Uuid) -> Result<(), AppError> {
+    if member_role(db, org_id, user_id).await? == Some(Role::Owner)
+        && other_owners(db, org_id, user_id).await? == 0
+    {
+        return Err(AppError::Conflict(
+            "An organization must keep at least one owner".to_string(),
+        ));
+    }
+    Ok(())
+}
+
+// ────────────────────────────────────────────────────────────────────────────
+// Tests
+// ────────────────────────────────────────────────────────────────────────────
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_role_names_round_trip() {
+        for role in [Role::Owner, Role::Coach, Role::Candidate] {
+            assert_eq!(Role::parse(role.as_str()), Some(role));
+            assert_eq!(serde_json::to_value(role).unwrap(), role.as_str());
+        }
+        assert_eq!(Role::parse("admin"), None);
+        assert!(Role::Coach.can_coach());
+        assert!(!Role::Candidate.can_coach());
+    }
+
+    #[test]
+    fn test_check_role_hides_orgs_from_non_members() {
+        let org = Uuid::new_v4();
+        assert!(matches!(
+            check_role(org, None, Role::can_coach),
+            Err(AppError::NotFound(_))
+        ));
+        assert!(matches!(
+            check_role(org, Some(Role::Candidate), Role::can_coach),
+            Err(AppError::Forbidden)
+        ));
+        assert_eq!(
+            check_role(org, Some(Role::Coach), Role::can_coach).unwrap(),
+            Role::Coach
+        );
+    }
+
+    #[test]
+    fn test_org_name_and_annotation_validation() {
+        let org = |name: &str| CreateOrgRequest {
+            name: name.to_string(),
+        };
+        assert!(org("  Acme Bootcamp ").validate().is_ok());
+        assert!(org("   ").validate().is_err());
+        assert!(org(&"x".repeat(MAX_NAME_CHARS as usize + 1))
+            .validate()
+            .is_err());
+
+        let note = |body: &str| CreateAnnotationRequest {
+            bullet_id: None,
+            body: body.to_string(),
+        };
+        assert!(note(" Quantify this ").validate().is_ok());
+        assert!(note("").validate().is_err());
+        assert!(note(&"x".repeat(MAX_ANNOTATION_CHARS as usize + 1))
+            .validate()
+            .is_err());
+    }
+}
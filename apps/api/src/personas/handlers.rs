@@ -0,0 +1,195 @@
+//! Axum handlers for the personas API.
+//!
+//! GET    /api/v1/personas          — list the caller's personas
+//! POST   /api/v1/personas          — create a persona
+//! POST   /api/v1/personas/suggest  — propose personas from the caller's context (not saved)
+//! GET    /api/v1/personas/:id      — fetch one persona
+//! PATCH  /api/v1/personas/:id      — partial update
+//! DELETE /api/v1/personas/:id      — hard delete
+
+use axum::{
+    extract::{Path, State},
+    http::StatusCode,
+    response::IntoResponse,
+    Json,
+};
+use uuid::Uuid;
+
+use crate::audit::{self, Action, AuditEvent};
+use crate::auth::{AuthUser, Resource};
+use crate::context::versioning::get_current_entries;
+use crate::errors::AppError;
+use crate::generation::domain_pack::DomainPack;
+use crate::generation::jd_parser::JDTone;
+use crate::models::resume::PersonaRow;
+use crate::personas::suggest::suggest_personas;
+use crate::personas::{normalize_tags, CreatePersonaRequest, UpdatePersonaRequest};
+use crate::state::AppState;
+use crate::validation::ValidJson;
+
+// ────────────────────────────────────────────────────────────────────────────
+// GET /api/v1/personas
+// ────────────────────────────────────────────────────────────────────────────
+
+/// Returns the caller's personas, oldest first.
+pub async fn handle_list_personas(
+    State(state): State<AppState>,
+    auth: AuthUser,
+) -> Result<impl IntoResponse, AppError> {
+    let personas = sqlx::query_as!(
+        PersonaRow,
+        "SELECT * FROM personas WHERE user_id = $1 ORDER BY created_at",
+        auth.user_id
+    )
+    .fetch_all(&state.db)
+    .await?;
+
+    Ok(Json(serde_json::json!({ "personas": personas })))
+}
+
+// ────────────────────────────────────────────────────────────────────────────
+// POST /api/v1/personas
+// ────────────────────────────────────────────────────────────────────────────
+
+/// Creates a persona. A suggestion from `/personas/suggest` can be posted back as-is.
+pub async fn handle_create_persona(
+    State(state): State<AppState>,
+    auth: AuthUser,
+    ValidJson(body): ValidJson<CreatePersonaRequest>,
+) -> Result<impl IntoResponse, AppError> {
+    let persona = sqlx::query_as!(
+        PersonaRow,
+        r#"INSERT INTO personas
+               (user_id, name, emphasized_tags, suppressed_tags, tone_preference, domain_pack)
+           VALUES ($1, $2, $3, $4, $5, $6)
+           RETURNING *"#,
+        auth.user_id,
+        body.name.trim(),
+        &normalize_tags(&body.emphasized_tags),
+        &normalize_tags(&body.suppressed_tags),
+        body.tone_preference.as_ref().map(JDTone::as_str),
+        body.domain_pack.as_ref().map(DomainPack::as_str)
+    )
+    .fetch_one(&state.db)
+    .await?;
+    audit::record(
+        &state.db,
+        auth.user_id,
+        AuditEvent::new(Action::PersonaCreate, persona.id),
+    )
+    .await;
+
+    Ok((StatusCode::CREATED, Json(persona)))
+}
+
+// ────────────────────────────────────────────────────────────────────────────
+// POST /api/v1/personas/suggest
+// ────────────────────────────────────────────────────────────────────────────
+
+/// Proposes 2–3 personas by clustering the caller's current context entries. Nothing
+/// is stored; an empty list means the context has too few tagged entries to cluster.
+pub async fn handle_suggest_personas(
+    State(state): State<AppState>,
+    auth: AuthUser,
+) -> Result<impl IntoResponse, AppError> {
+    let entries = get_current_entries(state.reads.pool(), auth.user_id)
+        .await
+        .map_err(AppError::Internal)?;
+    let suggestions = suggest_personas(&entries);
+
+    Ok(Json(serde_json::json!({ "suggestions": suggestions })))
+}
+
+// ────────────────────────────────────────────────────────────────────────────
+// GET /api/v1/personas/:id
+// ────────────────────────────────────────────────────────────────────────────
+
+/// Returns a single persona by ID.
+pub async fn handle_get_persona(
+    Path(id): Path<Uuid>,
+    State(state): State<AppState>,
+    auth: AuthUser,
+) -> Result<impl IntoResponse, AppError> {
+    auth.authorize(&state.db, Resource::Persona, id).await?;
+
+    let persona = sqlx::query_as!(PersonaRow, "SELECT * FROM personas WHERE id = $1", id)
+        .fetch_optional(&state.db)
+        .await?
+        .ok_or(AppError::NotFound(format!("Persona {id} not found")))?;
+
+    Ok(Json(persona))
+}
+
+// ────────────────────────────────────────────────────────────────────────────
+// PATCH /api/v1/personas/:id
+// ────────────────────────────────────────────────────────────────────────────
+
+/// Partially updates a persona; a tag list in the body replaces the stored one.
+pub async fn handle_update_persona(
+    Path(id): Path<Uuid>,
+    State(state): State<AppState>,
+    auth: AuthUser,
+    ValidJson(body): ValidJson<UpdatePersonaRequest>,
+) -> Result<impl IntoResponse, AppError> {
+    auth.authorize(&state.db, Resource::Persona, id).await?;
+    let emphasized_tags = body.emphasized_tags.as_deref().map(normalize_tags);
+    let suppressed_tags = body.suppressed_tags.as_deref().map(normalize_tags);
+
+    let persona = sqlx::query_as!(
+        PersonaRow,
+        r#"UPDATE personas
+           SET name            = COALESCE($2, name),
+               emphasized_tags = COALESCE($3, emphasized_tags),
+               suppressed_tags = COALESCE($4, suppressed_tags),
+               tone_preference = COALESCE($5, tone_preference),
+               domain_pack     = COALESCE($6, domain_pack)
+           WHERE id = $1
+           RETURNING *"#,
+        id,
+        body.name.as_deref().map(str::trim),
+        emphasized_tags.as_deref(),
+        suppressed_tags.as_deref(),
+        body.tone_preference.as_ref().map(JDTone::as_str),
+        body.domain_pack.as_ref().map(DomainPack::as_str)
+    )
+    .fetch_optional(&state.db)
+    .await?
+    .ok_or(AppError::NotFound(format!("Persona {id} not found")))?;
+    audit::record(
+        &state.db,
+        auth.user_id,
+        AuditEvent::new(Action::PersonaUpdate, id),
+    )
+    .await;
+
+    Ok(Json(persona))
+}
+
+// ────────────────────────────────────────────────────────────────────────────
+// DELETE /api/v1/personas/:id
+// ────────────────────────────────────────────────────────────────────────────
+
+/// Hard-deletes a persona.
+pub async fn handle_delete_persona(
+    Path(id): Path<Uuid>,
+    State(state): State<AppState>,
+    auth: AuthUser,
+) -> Result<impl IntoResponse, AppError> {
+    auth.authorize(&state.db, Resource::Persona, id).await?;
+
+    let rows = sqlx::query!("DELETE FROM personas WHERE id = $1", id)
+        .execute(&state.db)
+        .await?
+        .rows_affected();
+    if rows == 0 {
+        return Err(AppError::NotFound(format!("Persona {id} not found")));
+    }
+    audit::record(
+        &state.db,
+        auth.user_id,
+        AuditEvent::new(Action::PersonaDelete, id),
+    )
+    .await;
+
+    Ok(StatusCode::NO_CONTENT)
+}
@@ -0,0 +1,99 @@
+//! Personas module — named resume "angles" over one context library.
+//!
+//! A persona emphasizes some tags, suppresses others and may prefer a tone and a
+//! domain pack: a "Backend/Infra" persona and an "ML Research" persona draw different
+//! resumes from the same entries.
+//!
+//! POST /api/v1/personas/suggest proposes personas by clustering the caller's
+//! context (suggest.rs); nothing is saved until the user POSTs one back.
+//!
+//! Types live here; handlers in handlers.rs; suggestions in suggest.rs.
+
+pub mod handlers;
+pub mod suggest;
+
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+use validator::Validate;
+
+use crate::generation::domain_pack::DomainPack;
+use crate::generation::jd_parser::JDTone;
+use crate::validation::{not_blank, MAX_NAME_CHARS};
+
+/// Most tags a persona may emphasize or suppress, each.
+pub const MAX_PERSONA_TAGS: u64 = 50;
+
+// ────────────────────────────────────────────────────────────────────────────
+// Request / response types
+// ────────────────────────────────────────────────────────────────────────────
+
+/// Body for `POST /api/v1/personas` — also the shape of a suggestion's fields, so a
+/// suggestion can be saved as-is.
+#[derive(Debug, Deserialize, Validate)]
+pub struct CreatePersonaRequest {
+    #[validate(custom(function = "not_blank"), length(max = MAX_NAME_CHARS))]
+    pub name: String,
+    #[serde(default)]
+    #[validate(length(max = MAX_PERSONA_TAGS))]
+    pub emphasized_tags: Vec<String>,
+    #[serde(default)]
+    #[validate(length(max = MAX_PERSONA_TAGS))]
+    pub suppressed_tags: Vec<String>,
+    pub tone_preference: Option<JDTone>,
+    /// Replaces the user's saved pack when generating with this persona.
+    pub domain_pack: Option<DomainPack>,
+}
+
+/// Body for `PATCH /api/v1/personas/:id` — absent fields are left unchanged; a tag
+/// list, when present, replaces the stored one.
+#[derive(Debug, Deserialize, Validate)]
+pub struct UpdatePersonaRequest {
+    #[validate(custom(function = "not_blank"), length(max = MAX_NAME_CHARS))]
+    pub name: Option<String>,
+    #[validate(length(max = MAX_PERSONA_TAGS))]
+    pub emphasized_tags: Option<Vec<String>>,
+    #[validate(length(max = MAX_PERSONA_TAGS))]
+    pub suppressed_tags: Option<Vec<String>>,
+    pub tone_preference: Option<JDTone>,
+    pub domain_pack: Option<DomainPack>,
+}
+
+/// One proposed persona from `POST /api/v1/personas/suggest`. Not stored.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct PersonaSuggestion {
+    pub name: String,
+    /// Most frequent tags in the cluster, most frequent first.
+    pub emphasized_tags: Vec<String>,
+    /// Tags that lead other suggested clusters but never appear in this one.
+    pub suppressed_tags: Vec<String>,
+    pub tone_preference: Option<JDTone>,
+    /// Stable ids of the context entries the cluster was built from.
+    pub entry_ids: Vec<Uuid>,
+}
+
+/// Tags as stored: trimmed, lowercased (like ingest's), deduplicated, blanks dropped.
+pub fn normalize_tags(tags: &[String]) -> Vec<String> {
+    let mut tags: Vec<String> = tags
+        .iter()
+        .map(|t| t.trim().to_lowercase())
+        .filter(|t| !t.is_empty())
+        .collect();
+    tags.sort();
+    tags.dedup();
+    tags
+}
+
+// ────────────────────────────────────────────────────────────────────────────
+// Tests
+// ────────────────────────────────────────────────────────────────────────────
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_normalize_tags() {
+        let tags = ["Rust", " rust ", "", "Kafka"].map(String::from);
+        assert_eq!(normalize_tags(&tags), vec!["kafka", "rust"]);
+    }
+}
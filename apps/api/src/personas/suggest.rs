@@ -0,0 +1,379 @@
+//! Persona suggestions — clusters context entries into 2–3 proposed personas.
+//!
+//! Deterministic, no LLM call. Each entry's tags (tech stack, skills; the entry type
+//! and company/institution names that ingest also files as tags are ignored) are
+//! matched against a small catalogue of role archetypes, and the entry joins the
+//! archetype it matches best. Archetypes with at least `MIN_CLUSTER_ENTRIES` entries
+//! become suggestions, largest first.
+//!
+//! Skill entries are left out of clustering: one "Languages: Rust, Python" entry
+//! spans every persona.
+
+use std::collections::{HashMap, HashSet};
+
+use crate::generation::jd_parser::JDTone;
+use crate::models::context::ContextEntryRow;
+use crate::personas::PersonaSuggestion;
+
+/// At most this many suggestions.
+const MAX_SUGGESTIONS: usize = 3;
+/// A cluster needs this many entries to be worth a persona.
+const MIN_CLUSTER_ENTRIES: usize = 2;
+/// Emphasized tags per suggestion.
+const MAX_EMPHASIZED: usize = 8;
+/// Suppressed tags per suggestion.
+const MAX_SUPPRESSED: usize = 5;
+
+/// Entries of one cluster, each with its terms.
+type Members<'a> = Vec<(&'a ContextEntryRow, Vec<String>)>;
+
+struct Archetype {
+    name: &'static str,
+    /// Lowercase tags that vote for this archetype.
+    keywords: &'static [&'static str],
+    /// Entry types that vote for it regardless of tags.
+    entry_types: &'static [&'static str],
+    tone: Option<JDTone>,
+}
+
+/// Order breaks ties: an entry matching two archetypes equally joins the earlier one.
+const ARCHETYPES: &[Archetype] = &[
+    Archetype {
+        name: "Backend/Infra",
+        keywords: &[
+            "rust",
+            "go",
+            "golang",
+            "java",
+            "c++",
+            "kubernetes",
+            "k8s",
+            "docker",
+            "postgres",
+            "postgresql",
+            "mysql",
+            "redis",
+            "kafka",
+            "grpc",
+            "aws",
+            "gcp",
+            "azure",
+            "terraform",
+            "linux",
+            "distributed systems",
+            "microservices",
+            "backend",
+            "infrastructure",
+            "devops",
+            "sre",
+            "observability",
+            "sql",
+            "nginx",
+            "tokio",
+            "spring",
+        ],
+        entry_types: &[],
+        tone: None,
+    },
+    Archetype {
+        name: "ML Research",
+        keywords: &[
+            "pytorch",
+            "tensorflow",
+            "jax",
+            "machine learning",
+            "ml",
+            "deep learning",
+            "nlp",
+            "computer vision",
+            "llm",
+            "transformers",
+            "scikit-learn",
+            "numpy",
+            "research",
+            "statistics",
+            "reinforcement learning",
+            "cuda",
+            "hugging face",
+        ],
+        entry_types: &["publication"],
+        tone: Some(JDTone::ResearchOriented),
+    },
+    Archetype {
+        name: "Frontend/Product",
+        keywords: &[
+            "react",
+            "typescript",
+            "javascript",
+            "next.js",
+            "nextjs",
+            "vue",
+            "angular",
+            "svelte",
+            "css",
+            "html",
+            "tailwind",
+            "frontend",
+            "ui",
+            "ux",
+            "figma",
+            "graphql",
+        ],
+        entry_types: &[],
+        tone: Some(JDTone::ProductOriented),
+    },
+    Archetype {
+        name: "Data Engineering",
+        keywords: &[
+            "spark",
+            "airflow",
+            "dbt",
+            "etl",
+            "snowflake",
+            "bigquery",
+            "pandas",
+            "hadoop",
+            "data pipelines",
+            "data engineering",
+            "flink",
+            "databricks",
+            "redshift",
+        ],
+        entry_types: &[],
+        tone: None,
+    },
+    Archetype {
+        name: "Protocol/Blockchain",
+        keywords: &[
+            "solidity",
+            "ethereum",
+            "blockchain",
+            "consensus",
+            "cryptography",
+            "zk",
+            "zero-knowledge",
+            "smart contracts",
+            "web3",
+            "evm",
+            "substrate",
+            "bitcoin",
+        ],
+        entry_types: &[],
+        tone: Some(JDTone::ResearchOriented),
+    },
+    Archetype {
+        name: "Mobile",
+        keywords: &[
+            "swift",
+            "kotlin",
+            "ios",
+            "android",
+            "react native",
+            "flutter",
+            "swiftui",
+            "jetpack compose",
+        ],
+        entry_types: &[],
+        tone: Some(JDTone::ProductOriented),
+    },
+];
+
+/// Proposes up to `MAX_SUGGESTIONS` personas from the user's current entries.
+/// Empty when no archetype gathers `MIN_CLUSTER_ENTRIES` entries.
+pub fn suggest_personas(entries: &[ContextEntryRow]) -> Vec<PersonaSuggestion> {
+    // Archetype index → (entry, its terms), in input order.
+    let mut clusters: HashMap<usize, Members> = HashMap::new();
+    for entry in entries.iter().filter(|e| e.entry_type != "skill") {
+        let terms = entry_terms(entry);
+        if let Some(archetype) = best_archetype(entry, &terms) {
+            clusters.entry(archetype).or_default().push((entry, terms));
+        }
+    }
+
+    let mut ranked: Vec<(usize, Members)> = clusters
+        .into_iter()
+        .filter(|(_, members)| members.len() >= MIN_CLUSTER_ENTRIES)
+        .collect();
+    ranked.sort_by(|(a, a_members), (b, b_members)| {
+        b_members.len().cmp(&a_members.len()).then(a.cmp(b))
+    });
+    ranked.truncate(MAX_SUGGESTIONS);
+
+    let emphasized: Vec<Vec<String>> = ranked
+        .iter()
+        .map(|(_, members)| top_terms(members))
+        .collect();
+    ranked
+        .iter()
+        .enumerate()
+        .map(|(i, (archetype, members))| {
+            let own: HashSet<&str> = members
+                .iter()
+                .flat_map(|(_, terms)| terms.iter().map(String::as_str))
+                .collect();
+            let mut suppressed: Vec<String> = vec![];
+            for tag in emphasized
+                .iter()
+                .enumerate()
+                .filter(|(j, _)| *j != i)
+                .flat_map(|(_, tags)| tags)
+            {
+                if !own.contains(tag.as_str()) && !suppressed.contains(tag) {
+                    suppressed.push(tag.clone());
+                }
+            }
+            suppressed.truncate(MAX_SUPPRESSED);
+
+            let archetype = &ARCHETYPES[*archetype];
+            PersonaSuggestion {
+                name: archetype.name.to_string(),
+                emphasized_tags: emphasized[i].clone(),
+                suppressed_tags: suppressed,
+                tone_preference: archetype.tone.clone(),
+                entry_ids: members.iter().map(|(e, _)| e.entry_id).collect(),
+            }
+        })
+        .collect()
+}
+
+/// The entry's tags minus the ones ingest adds for bookkeeping: the entry type and
+/// the company/institution/project/organization name.
+fn entry_terms(entry: &ContextEntryRow) -> Vec<String> {
+    let names: Vec<String> = ["company", "institution", "project_name", "organization"]
+        .iter()
+        .filter_map(|field| entry.data.get(field).and_then(|v| v.as_str()))
+        .map(str::to_lowercase)
+        .collect();
+    let mut terms: Vec<String> = entry
+        .tags
+        .iter()
+        .map(|t| t.trim().to_lowercase())
+        .filter(|t| !t.is_empty() && *t != entry.entry_type && !names.contains(t))
+        .collect();
+    terms.sort();
+    terms.dedup();
+    terms
+}
+
+/// Index of the archetype with the most votes from `terms` and the entry type, or
+/// `None` when nothing votes.
+fn best_archetype(entry: &ContextEntryRow, terms: &[String]) -> Option<usize> {
+    let mut best: Option<(usize, usize)> = None;
+    for (i, archetype) in ARCHETYPES.iter().enumerate() {
+        let votes = terms
+            .iter()
+            .filter(|t| archetype.keywords.contains(&t.as_str()))
+            .count()
+            + usize::from(archetype.entry_types.contains(&entry.entry_type.as_str()));
+        if votes > 0 && best.is_none_or(|(_, most)| votes > most) {
+            best = Some((i, votes));
+        }
+    }
+    best.map(|(i, _)| i)
+}
+
+/// The cluster's most frequent terms, ties alphabetical.
+fn top_terms(members: &[(&ContextEntryRow, Vec<String>)]) -> Vec<String> {
+    let mut counts: HashMap<&str, usize> = HashMap::new();
+    for (_, terms) in members {
+        for term in terms {
+            *counts.entry(term.as_str()).or_default() += 1;
+        }
+    }
+    let mut terms: Vec<(&str, usize)> = counts.into_iter().collect();
+    terms.sort_by(|(a, a_count), (b, b_count)| b_count.cmp(a_count).then(a.cmp(b)));
+    terms
+        .into_iter()
+        .take(MAX_EMPHASIZED)
+        .map(|(term, _)| term.to_string())
+        .collect()
+}
+
+// ────────────────────────────────────────────────────────────────────────────
+// Tests
+// ────────────────────────────────────────────────────────────────────────────
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Utc;
+    use serde_json::json;
+    use uuid::Uuid;
+
+    fn entry(entry_type: &str, tags: &[&str]) -> ContextEntryRow {
+        ContextEntryRow {
+            id: Uuid::new_v4(),
+            user_id: Uuid::nil(),
+            entry_id: Uuid::new_v4(),
+            version: 1,
+            entry_type: entry_type.to_string(),
+            data: json!({ "company": "Acme" }),
+            raw_text: None,
+            recency_score: 0.5,
+            impact_score: 0.5,
+            tags: tags.iter().map(|t| t.to_string()).collect(),
+            flagged_evergreen: false,
+            contribution_type: "team_member".to_string(),
+            quality_score: 1.0,
+            quality_flags: vec![],
+            created_at: Utc::now(),
+            recency_policy: None,
+        }
+    }
+
+    #[test]
+    fn test_two_clusters_become_two_personas() {
+        let entries = vec![
+            entry(
+                "experience",
+                &["experience", "acme", "rust", "kafka", "postgres"],
+            ),
+            entry("project", &["project", "rust", "kubernetes"]),
+            entry("experience", &["experience", "pytorch", "nlp"]),
+            entry("publication", &["publication", "transformers"]),
+            // One frontend entry is not enough for a persona.
+            entry("project", &["project", "react", "typescript"]),
+            entry("skill", &["skill", "rust", "pytorch", "react"]),
+        ];
+        let suggestions = suggest_personas(&entries);
+
+        let names: Vec<&str> = suggestions.iter().map(|s| s.name.as_str()).collect();
+        assert_eq!(names, vec!["Backend/Infra", "ML Research"]);
+
+        let backend = &suggestions[0];
+        assert_eq!(backend.emphasized_tags[0], "rust");
+        assert!(!backend.emphasized_tags.contains(&"acme".to_string()));
+        assert!(!backend.emphasized_tags.contains(&"experience".to_string()));
+        assert!(backend.suppressed_tags.contains(&"pytorch".to_string()));
+        assert_eq!(backend.entry_ids.len(), 2);
+
+        let research = &suggestions[1];
+        assert_eq!(research.tone_preference, Some(JDTone::ResearchOriented));
+        assert!(research.suppressed_tags.contains(&"rust".to_string()));
+    }
+
+    #[test]
+    fn test_untagged_or_sparse_context_suggests_nothing() {
+        assert!(suggest_personas(&[]).is_empty());
+        let entries = vec![
+            entry("experience", &["experience", "excel"]),
+            entry("project", &["project", "rust"]),
+        ];
+        assert!(suggest_personas(&entries).is_empty());
+    }
+
+    #[test]
+    fn test_at_most_three_suggestions() {
+        let mut entries = vec![];
+        for tags in [
+            ["rust", "kafka"],
+            ["pytorch", "nlp"],
+            ["react", "css"],
+            ["spark", "airflow"],
+        ] {
+            entries.push(entry("project", &tags));
+            entries.push(entry("project", &tags));
+        }
+        assert_eq!(suggest_personas(&entries).len(), MAX_SUGGESTIONS);
+    }
+}
@@ -0,0 +1,98 @@
+//! Axum handler for the progress event stream.
+
+use std::time::Duration;
+
+use axum::{
+    extract::{Path, State},
+    response::sse::{Event, KeepAlive, Sse},
+};
+use futures::{stream, Stream, StreamExt};
+use uuid::Uuid;
+
+use crate::auth::{AuthUser, Resource};
+use crate::errors::AppError;
+use crate::progress::{channel, owner, replay, ProgressEvent};
+use crate::state::AppState;
+
+/// Streams are closed after this long even if the job never reports a terminal stage.
+const MAX_STREAM_DURATION: Duration = Duration::from_secs(15 * 60);
+
+/// GET /api/v1/events/:job_id
+///
+/// Server-Sent Events for a generation job, a render job, a data export, or a
+/// `progress_id` passed to `POST /api/v1/resumes/generate`. Events already published are replayed first; the
+/// stream ends after the `completed` or `failed` event. Each SSE event is named after
+/// its stage and carries the `ProgressEvent` JSON, with `seq` as the event id.
+pub async fn handle_events(
+    State(state): State<AppState>,
+    auth: AuthUser,
+    Path(job_id): Path<Uuid>,
+) -> Result<Sse<impl Stream<Item = Result<Event, axum::Error>>>, AppError> {
+    authorize_job(&state, &auth, job_id).await?;
+
+    // Subscribe before reading the replay log so nothing published in between is lost;
+    // `seq` drops the overlap.
+    let redis_err = |e: redis::RedisError| AppError::Internal(anyhow::anyhow!("Redis pubsub: {e}"));
+    let mut pubsub = state.redis.get_async_pubsub().await.map_err(redis_err)?;
+    pubsub.subscribe(channel(job_id)).await.map_err(redis_err)?;
+
+    let backlog = replay(&state.redis, job_id).await?;
+    let last_seq = backlog.last().map(|e| e.seq).unwrap_or(0);
+    let finished = backlog.iter().any(|e| e.stage.is_terminal());
+
+    let live = pubsub
+        .into_on_message()
+        .filter_map(|msg| async move {
+            let payload: String = msg.get_payload().ok()?;
+            serde_json::from_str::<ProgressEvent>(&payload).ok()
+        })
+        .filter(move |event| std::future::ready(event.seq > last_seq));
+    let live = if finished {
+        live.take(0).left_stream()
+    } else {
+        live.right_stream()
+    };
+
+    let events = stream::iter(backlog)
+        .chain(live)
+        .scan(false, |done, event| {
+            if *done {
+                return std::future::ready(None);
+            }
+            *done = event.stage.is_terminal();
+            std::future::ready(Some(event))
+        })
+        .take_until(tokio::time::sleep(MAX_STREAM_DURATION))
+        .map(|event| {
+            Event::default()
+                .event(event.stage.name())
+                .id(event.seq.to_string())
+                .json_data(&event)
+        });
+
+    Ok(Sse::new(events).keep_alive(KeepAlive::default()))
+}
+
+/// Client-chosen progress ids are owned through their Redis claim; generation and
+/// render jobs through their rows. All failures are the same 404.
+async fn authorize_job(state: &AppState, auth: &AuthUser, job_id: Uuid) -> Result<(), AppError> {
+    match owner(&state.redis, job_id).await? {
+        Some(owner) if owner == auth.user_id => return Ok(()),
+        Some(_) => return Err(not_found(job_id)),
+        None => {}
+    }
+    for resource in [
+        Resource::GenerationJob,
+        Resource::RenderJob,
+        Resource::Export,
+    ] {
+        if auth.authorize(&state.db, resource, job_id).await.is_ok() {
+            return Ok(());
+        }
+    }
+    Err(not_found(job_id))
+}
+
+fn not_found(job_id: Uuid) -> AppError {
+    AppError::NotFound(format!("Job {job_id} not found"))
+}
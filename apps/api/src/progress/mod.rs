@@ -0,0 +1,380 @@
+//! Progress events for long-running jobs (resume generation, PDF render, data export).
+//!
+//! GET /api/v1/events/:job_id — Server-Sent Events stream of a job's progress
+//!
+//! A job's code runs inside `scoped(redis, job_id, ..)`; anything it calls — the
+//! generation pipeline, the layout simulator, the render worker — reports with
+//! `emit(Stage::..)` without threading a publisher through every signature. Outside a
+//! scope `emit` is a no-op, so tests and un-tracked callers are unaffected.
+//!
+//! Each event is published on the Redis channel `progress:{job_id}` and appended to a
+//! short replay log, so a client that subscribes after the job started still sees
+//! every stage. Events carry a per-job `seq` for de-duplication across the two.
+//! Keys expire an hour after the last event.
+//!
+//! Types and publishing live here; the SSE handler in handlers.rs.
+
+pub mod handlers;
+
+use std::future::Future;
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::errors::AppError;
+
+/// Replay log, sequence counter and ownership keys live this long after the last write.
+const EVENT_TTL_SECS: u64 = 60 * 60;
+
+/// Most events kept in a job's replay log.
+const MAX_LOGGED_EVENTS: isize = 200;
+
+// ────────────────────────────────────────────────────────────────────────────
+// Events
+// ────────────────────────────────────────────────────────────────────────────
+
+/// A pipeline milestone. Serialized with its name in `stage`, e.g.
+/// `{"stage": "layout_pass", "pass": 1, "violations": 3}`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "stage", rename_all = "snake_case")]
+pub enum Stage {
+    JdParsed,
+    FitScored {
+        score: u32,
+    },
+    ContentSelected {
+        entries: usize,
+    },
+    BulletsGenerated {
+        bullets: usize,
+    },
+    /// One layout simulation pass; `violations` still to fix after it.
+    LayoutPass {
+        pass: u8,
+        violations: usize,
+    },
+    GroundingChecked {
+        bullets: usize,
+    },
+    ResumeSaved {
+        resume_id: Uuid,
+    },
+    /// The PDF render for the new resume; follow it at `/events/{render_job_id}`.
+    RenderQueued {
+        render_job_id: Uuid,
+    },
+    RenderStarted,
+    /// A data export has gathered everything; `files` will go into the archive.
+    ExportCollected {
+        files: usize,
+    },
+    /// The export archive is in S3.
+    ExportUploaded {
+        bytes: u64,
+    },
+    /// Terminal: the job finished successfully.
+    Completed,
+    /// Terminal: the job failed.
+    Failed {
+        message: String,
+    },
+}
+
+impl Stage {
+    /// The `stage` tag, also used as the SSE event name.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Stage::JdParsed => "jd_parsed",
+            Stage::FitScored { .. } => "fit_scored",
+            Stage::ContentSelected { .. } => "content_selected",
+            Stage::BulletsGenerated { .. } => "bullets_generated",
+            Stage::LayoutPass { .. } => "layout_pass",
+            Stage::GroundingChecked { .. } => "grounding_checked",
+            Stage::ResumeSaved { .. } => "resume_saved",
+            Stage::RenderQueued { .. } => "render_queued",
+            Stage::RenderStarted => "render_started",
+            Stage::ExportCollected { .. } => "export_collected",
+            Stage::ExportUploaded { .. } => "export_uploaded",
+            Stage::Completed => "completed",
+            Stage::Failed { .. } => "failed",
+        }
+    }
+
+    pub fn is_terminal(&self) -> bool {
+        matches!(self, Stage::Completed | Stage::Failed { .. })
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ProgressEvent {
+    pub job_id: Uuid,
+    /// 1-based, increasing per job.
+    pub seq: u64,
+    pub at: DateTime<Utc>,
+    #[serde(flatten)]
+    pub stage: Stage,
+}
+
+/// The message a failed job reports to its subscribers. Client errors keep their
+/// message; anything else is summarised, since internal details belong in the logs.
+pub fn failure_message(err: &AppError) -> String {
+    match err {
+        AppError::Validation(msg)
+        | AppError::UnprocessableEntity(msg)
+        | AppError::NotFound(msg)
+        | AppError::QuotaExceeded { message: msg, .. } => msg.clone(),
+        _ => "The job failed due to an internal error".to_string(),
+    }
+}
+
+// ────────────────────────────────────────────────────────────────────────────
+// Keys
+// ────────────────────────────────────────────────────────────────────────────
+
+pub fn channel(job_id: Uuid) -> String {
+    format!("progress:{job_id}")
+}
+
+fn log_key(job_id: Uuid) -> String {
+    format!("progress:{job_id}:log")
+}
+
+fn seq_key(job_id: Uuid) -> String {
+    format!("progress:{job_id}:seq")
+}
+
+fn owner_key(job_id: Uuid) -> String {
+    format!("progress:{job_id}:owner")
+}
+
+// ────────────────────────────────────────────────────────────────────────────
+// Publishing
+// ────────────────────────────────────────────────────────────────────────────
+
+struct Scope {
+    job_id: Uuid,
+    redis: redis::Client,
+}
+
+tokio::task_local! {
+    static SCOPE: Scope;
+}
+
+/// Runs `fut` with `emit` reporting to `job_id`. Tasks spawned inside do not inherit
+/// the scope.
+pub async fn scoped<F: Future>(redis: &redis::Client, job_id: Uuid, fut: F) -> F::Output {
+    let scope = Scope {
+        job_id,
+        redis: redis.clone(),
+    };
+    SCOPE.scope(scope, fut).await
+}
+
+/// Runs a job to completion inside `scoped` and reports `Completed` or `Failed` from
+/// its result. With no `job_id` the work simply runs untracked.
+pub async fn track<T, F>(
+    redis: &redis::Client,
+    job_id: Option<Uuid>,
+    work: F,
+) -> Result<T, AppError>
+where
+    F: Future<Output = Result<T, AppError>>,
+{
+    let Some(job_id) = job_id else {
+        return work.await;
+    };
+    scoped(redis, job_id, async {
+        let result = work.await;
+        match &result {
+            Ok(_) => emit(Stage::Completed).await,
+            Err(e) => {
+                emit(Stage::Failed {
+                    message: failure_message(e),
+                })
+                .await
+            }
+        }
+        result
+    })
+    .await
+}
+
+/// Reports `stage` for the job in scope. Best-effort: progress is advisory, so a
+/// Redis failure is logged and never fails the job.
+pub async fn emit(stage: Stage) {
+    let Ok((job_id, redis)) = SCOPE.try_with(|s| (s.job_id, s.redis.clone())) else {
+        return;
+    };
+    if let Err(e) = publish(&redis, job_id, stage).await {
+        tracing::warn!(%job_id, error = %e, "failed to publish progress event");
+    }
+}
+
+async fn publish(redis: &redis::Client, job_id: Uuid, stage: Stage) -> redis::RedisResult<()> {
+    let mut conn = redis.get_multiplexed_async_connection().await?;
+    let seq: u64 = redis::cmd("INCR")
+        .arg(seq_key(job_id))
+        .query_async(&mut conn)
+        .await?;
+    let event = ProgressEvent {
+        job_id,
+        seq,
+        at: Utc::now(),
+        stage,
+    };
+    let payload = serde_json::to_string(&event).expect("progress events serialize");
+
+    redis::pipe()
+        .atomic()
+        .cmd("RPUSH")
+        .arg(log_key(job_id))
+        .arg(&payload)
+        .ignore()
+        .cmd("LTRIM")
+        .arg(log_key(job_id))
+        .arg(-MAX_LOGGED_EVENTS)
+        .arg(-1)
+        .ignore()
+        .cmd("EXPIRE")
+        .arg(log_key(job_id))
+        .arg(EVENT_TTL_SECS)
+        .ignore()
+        .cmd("EXPIRE")
+        .arg(seq_key(job_id))
+        .arg(EVENT_TTL_SECS)
+        .ignore()
+        .cmd("PUBLISH")
+        .arg(channel(job_id))
+        .arg(&payload)
+        .ignore()
+        .query_async(&mut conn)
+        .await
+}
+
+// ────────────────────────────────────────────────────────────────────────────
+// Ownership of client-chosen ids
+// ────────────────────────────────────────────────────────────────────────────
+
+/// Claims a client-chosen progress id for `user_id`. Generation and render jobs are
+/// owned through their database rows; ids without a row are owned through this key.
+/// Returns 409 if another user already holds the id.
+pub async fn claim(redis: &redis::Client, job_id: Uuid, user_id: Uuid) -> Result<(), AppError> {
+    let mut conn = redis
+        .get_multiplexed_async_connection()
+        .await
+        .map_err(|e| AppError::Internal(anyhow::anyhow!("Redis connection failed: {e}")))?;
+    let claimed: Option<String> = redis::cmd("SET")
+        .arg(owner_key(job_id))
+        .arg(user_id.to_string())
+        .arg("NX")
+        .arg("EX")
+        .arg(EVENT_TTL_SECS)
+        .query_async(&mut conn)
+        .await
+        .map_err(|e| AppError::Internal(anyhow::anyhow!("Redis SET failed: {e}")))?;
+    if claimed.is_some() || owner(redis, job_id).await? == Some(user_id) {
+        Ok(())
+    } else {
+        Err(AppError::Conflict(format!(
+            "progress_id {job_id} is already in use"
+        )))
+    }
+}
+
+/// The user that claimed `job_id` with `claim`, if any.
+pub async fn owner(redis: &redis::Client, job_id: Uuid) -> Result<Option<Uuid>, AppError> {
+    let mut conn = redis
+        .get_multiplexed_async_connection()
+        .await
+        .map_err(|e| AppError::Internal(anyhow::anyhow!("Redis connection failed: {e}")))?;
+    let owner: Option<String> = redis::cmd("GET")
+        .arg(owner_key(job_id))
+        .query_async(&mut conn)
+        .await
+        .map_err(|e| AppError::Internal(anyhow::anyhow!("Redis GET failed: {e}")))?;
+    Ok(owner.and_then(|o| Uuid::parse_str(&o).ok()))
+}
+
+/// Every event still in `job_id`'s replay log, oldest first.
+pub async fn replay(redis: &redis::Client, job_id: Uuid) -> Result<Vec<ProgressEvent>, AppError> {
+    let mut conn = redis
+        .get_multiplexed_async_connection()
+        .await
+        .map_err(|e| AppError::Internal(anyhow::anyhow!("Redis connection failed: {e}")))?;
+    let payloads: Vec<String> = redis::cmd("LRANGE")
+        .arg(log_key(job_id))
+        .arg(0)
+        .arg(-1)
+        .query_async(&mut conn)
+        .await
+        .map_err(|e| AppError::Internal(anyhow::anyhow!("Redis LRANGE failed: {e}")))?;
+    Ok(payloads
+        .iter()
+        .filter_map(|p| serde_json::from_str(p).ok())
+        .collect())
+}
+
+// ────────────────────────────────────────────────────────────────────────────
+// Tests
+// ────────────────────────────────────────────────────────────────────────────
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_event_wire_format() {
+        let event = ProgressEvent {
+            job_id: Uuid::nil(),
+            seq: 3,
+            at: Utc::now(),
+            stage: Stage::LayoutPass {
+                pass: 1,
+                violations: 4,
+            },
+        };
+        let value = serde_json::to_value(&event).unwrap();
+        assert_eq!(value["stage"], "layout_pass");
+        assert_eq!(value["pass"], 1);
+        assert_eq!(value["seq"], 3);
+
+        let back: ProgressEvent = serde_json::from_value(value).unwrap();
+        assert_eq!(back, event);
+        assert_eq!(back.stage.name(), "layout_pass");
+    }
+
+    #[test]
+    fn test_stage_names_match_serde_tags() {
+        let stages = [
+            Stage::JdParsed,
+            Stage::RenderQueued {
+                render_job_id: Uuid::nil(),
+            },
+            Stage::ExportUploaded { bytes: 2048 },
+            Stage::Failed {
+                message: "x".to_string(),
+            },
+        ];
+        for stage in stages {
+            let value = serde_json::to_value(&stage).unwrap();
+            assert_eq!(value["stage"], stage.name());
+        }
+        assert!(Stage::Completed.is_terminal());
+        assert!(!Stage::JdParsed.is_terminal());
+    }
+
+    #[test]
+    fn test_failure_message_hides_internal_errors() {
+        let internal = AppError::Internal(anyhow::anyhow!("connection reset by peer"));
+        assert!(!failure_message(&internal).contains("connection"));
+        let validation = AppError::Validation("No context entries found".to_string());
+        assert_eq!(failure_message(&validation), "No context entries found");
+    }
+
+    #[tokio::test]
+    async fn test_emit_outside_scope_is_a_noop() {
+        // Must not panic or try to reach Redis.
+        emit(Stage::JdParsed).await;
+    }
+}
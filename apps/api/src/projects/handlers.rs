@@ -1,45 +1,43 @@
 //! Axum handlers for the cv_projects API.
 //!
-//! GET    /api/v1/projects?user_id={uuid}  — list user's projects
-//! POST   /api/v1/projects                 — create a new project
-//! GET    /api/v1/projects/:id             — fetch one project
-//! PATCH  /api/v1/projects/:id             — partial update
-//! DELETE /api/v1/projects/:id             — hard delete
+//! GET    /api/v1/projects      — list the caller's projects
+//! POST   /api/v1/projects      — create a new project
+//! GET    /api/v1/projects/:id  — fetch one project
+//! PATCH  /api/v1/projects/:id  — partial update
+//! DELETE /api/v1/projects/:id  — hard delete
 
 use axum::{
-    extract::{Path, Query, State},
+    extract::{Path, State},
     http::StatusCode,
     response::IntoResponse,
     Json,
 };
-use serde::Deserialize;
 use uuid::Uuid;
 
+use crate::audit::{self, Action, AuditEvent};
+use crate::auth::{AuthUser, Resource};
 use crate::errors::AppError;
 use crate::projects::{CreateProjectRequest, CvProjectRow, UpdateProjectRequest};
 use crate::state::AppState;
+use crate::validation::ValidJson;
 
 // ────────────────────────────────────────────────────────────────────────────
-// GET /api/v1/projects?user_id={uuid}
+// GET /api/v1/projects
 // ────────────────────────────────────────────────────────────────────────────
 
-#[derive(Debug, Deserialize)]
-pub struct ListProjectsQuery {
-    pub user_id: Uuid,
-}
-
-/// Returns all projects for a user, ordered by `updated_at DESC`.
+/// Returns all projects of the authenticated user, ordered by `updated_at DESC`.
 ///
 /// Performance: single indexed query — `idx_cv_projects_user_id` covers
 /// (user_id, updated_at DESC), so no sort happens at runtime.
 pub async fn handle_list_projects(
-    Query(q): Query<ListProjectsQuery>,
     State(state): State<AppState>,
+    auth: AuthUser,
 ) -> Result<impl IntoResponse, AppError> {
-    let projects = sqlx::query_as::<_, CvProjectRow>(
+    let projects = sqlx::query_as!(
+        CvProjectRow,
         "SELECT * FROM cv_projects WHERE user_id = $1 ORDER BY updated_at DESC",
+        auth.user_id
     )
-    .bind(q.user_id)
     .fetch_all(&state.db)
     .await?;
 
@@ -50,14 +48,15 @@ pub async fn handle_list_projects(
 // POST /api/v1/projects
 // ────────────────────────────────────────────────────────────────────────────
 
-/// Creates a new project for the given user.
+/// Creates a new project for the authenticated user.
 ///
 /// Validates `template_id` against the in-memory TemplateCache (no DB query).
 /// Returns 400 if the template_id is unknown — we do NOT create projects for
 /// non-existent templates because the render worker would have no LaTeX to use.
 pub async fn handle_create_project(
     State(state): State<AppState>,
-    Json(body): Json<CreateProjectRequest>,
+    auth: AuthUser,
+    ValidJson(body): ValidJson<CreateProjectRequest>,
 ) -> Result<impl IntoResponse, AppError> {
     // Validate template_id exists in our loaded cache.
     // This is a read lock on the cache — lightweight, no DB involved.
@@ -72,22 +71,24 @@ pub async fn handle_create_project(
         }
     }
 
-    if body.name.trim().is_empty() {
-        return Err(AppError::Validation(
-            "Project name must not be empty".to_string(),
-        ));
-    }
-
-    let project = sqlx::query_as::<_, CvProjectRow>(
+    let project = sqlx::query_as!(
+        CvProjectRow,
         r#"INSERT INTO cv_projects (user_id, name, template_id)
            VALUES ($1, $2, $3)
            RETURNING *"#,
+        auth.user_id,
+        body.name.trim(),
+        &body.template_id
     )
-    .bind(body.user_id)
-    .bind(body.name.trim())
-    .bind(&body.template_id)
     .fetch_one(&state.db)
     .await?;
+    audit::record(
+        &state.db,
+        auth.user_id,
+        AuditEvent::new(Action::ProjectCreate, project.id)
+            .after(serde_json::json!({ "template_id": project.template_id })),
+    )
+    .await;
 
     Ok((StatusCode::CREATED, Json(project)))
 }
@@ -100,9 +101,11 @@ pub async fn handle_create_project(
 pub async fn handle_get_project(
     Path(id): Path<Uuid>,
     State(state): State<AppState>,
+    auth: AuthUser,
 ) -> Result<impl IntoResponse, AppError> {
-    let project = sqlx::query_as::<_, CvProjectRow>("SELECT * FROM cv_projects WHERE id = $1")
-        .bind(id)
+    auth.authorize(&state.db, Resource::Project, id).await?;
+
+    let project = sqlx::query_as!(CvProjectRow, "SELECT * FROM cv_projects WHERE id = $1", id)
         .fetch_optional(&state.db)
         .await?
         .ok_or(AppError::NotFound(format!("Project {} not found", id)))?;
@@ -116,14 +119,26 @@ pub async fn handle_get_project(
 
 /// Partially updates a project: name, template_id, and/or current_resume_id.
 ///
-/// Only provided fields are updated — absent fields are left unchanged.
+/// Only provided fields are updated — absent fields are left unchanged. A new
+/// `current_resume_id` must belong to the caller too.
 /// Uses a SQL COALESCE pattern to handle partial updates without multiple
 /// round-trips or conditional query building.
 pub async fn handle_update_project(
     Path(id): Path<Uuid>,
     State(state): State<AppState>,
-    Json(body): Json<UpdateProjectRequest>,
+    auth: AuthUser,
+    ValidJson(body): ValidJson<UpdateProjectRequest>,
 ) -> Result<impl IntoResponse, AppError> {
+    auth.authorize(&state.db, Resource::Project, id).await?;
+    let before = sqlx::query_as!(CvProjectRow, "SELECT * FROM cv_projects WHERE id = $1", id)
+        .fetch_optional(&state.db)
+        .await?
+        .ok_or(AppError::NotFound(format!("Project {} not found", id)))?;
+    if let Some(resume_id) = body.current_resume_id {
+        auth.authorize(&state.db, Resource::Resume, resume_id)
+            .await?;
+    }
+
     // Validate new template_id if provided
     if let Some(ref tid) = body.template_id {
         let cache = state.template_cache.read().await;
@@ -135,7 +150,8 @@ pub async fn handle_update_project(
     // COALESCE($2, name): if $2 IS NULL (not provided), keep existing value.
     // This is simpler than building a dynamic SET clause and avoids multiple
     // DB round-trips for a partial update.
-    let project = sqlx::query_as::<_, CvProjectRow>(
+    let project = sqlx::query_as!(
+        CvProjectRow,
         r#"UPDATE cv_projects
            SET name              = COALESCE($2, name),
                template_id       = COALESCE($3, template_id),
@@ -143,18 +159,34 @@ pub async fn handle_update_project(
                updated_at        = NOW()
            WHERE id = $1
            RETURNING *"#,
+        id,
+        body.name.as_deref().map(str::trim),
+        body.template_id.as_deref(),
+        body.current_resume_id
     )
-    .bind(id)
-    .bind(body.name.as_deref())
-    .bind(body.template_id.as_deref())
-    .bind(body.current_resume_id)
     .fetch_optional(&state.db)
     .await?
     .ok_or(AppError::NotFound(format!("Project {} not found", id)))?;
+    audit::record(
+        &state.db,
+        auth.user_id,
+        AuditEvent::new(Action::ProjectUpdate, id)
+            .before(project_ids(&before))
+            .after(project_ids(&project)),
+    )
+    .await;
 
     Ok(Json(project))
 }
 
+/// The identifiers a project update can change, for the audit log.
+fn project_ids(project: &CvProjectRow) -> serde_json::Value {
+    serde_json::json!({
+        "template_id": project.template_id,
+        "current_resume_id": project.current_resume_id,
+    })
+}
+
 // ────────────────────────────────────────────────────────────────────────────
 // DELETE /api/v1/projects/:id
 // ────────────────────────────────────────────────────────────────────────────
@@ -165,9 +197,11 @@ pub async fn handle_update_project(
 pub async fn handle_delete_project(
     Path(id): Path<Uuid>,
     State(state): State<AppState>,
+    auth: AuthUser,
 ) -> Result<impl IntoResponse, AppError> {
-    let rows = sqlx::query("DELETE FROM cv_projects WHERE id = $1")
-        .bind(id)
+    auth.authorize(&state.db, Resource::Project, id).await?;
+
+    let rows = sqlx::query!("DELETE FROM cv_projects WHERE id = $1", id)
         .execute(&state.db)
         .await?
         .rows_affected();
@@ -175,6 +209,12 @@ pub async fn handle_delete_project(
     if rows == 0 {
         return Err(AppError::NotFound(format!("Project {} not found", id)));
     }
+    audit::record(
+        &state.db,
+        auth.user_id,
+        AuditEvent::new(Action::ProjectDelete, id),
+    )
+    .await;
 
     Ok(StatusCode::NO_CONTENT)
 }
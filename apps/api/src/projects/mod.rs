@@ -14,15 +14,15 @@ use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use sqlx::FromRow;
 use uuid::Uuid;
+use validator::Validate;
+
+use crate::validation::{not_blank, MAX_NAME_CHARS};
 
 // ────────────────────────────────────────────────────────────────────────────
 // DB row type
 // ────────────────────────────────────────────────────────────────────────────
 
 /// A row from the `cv_projects` table.
-///
-/// Using runtime `query_as::<_, CvProjectRow>()` (not the macro `query_as!`)
-/// so this type does not need an entry in the `.sqlx/` offline cache.
 #[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
 pub struct CvProjectRow {
     pub id: Uuid,
@@ -39,17 +39,18 @@ pub struct CvProjectRow {
 // ────────────────────────────────────────────────────────────────────────────
 
 /// Body for `POST /api/v1/projects`.
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, Validate)]
 pub struct CreateProjectRequest {
-    pub user_id: Uuid,
+    #[validate(custom(function = "not_blank"), length(max = MAX_NAME_CHARS))]
     pub name: String,
     /// Must be a key in the loaded TemplateCache. Validated against cache (no DB query).
     pub template_id: String,
 }
 
 /// Body for `PATCH /api/v1/projects/:id` — all fields are optional.
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, Validate)]
 pub struct UpdateProjectRequest {
+    #[validate(custom(function = "not_blank"), length(max = MAX_NAME_CHARS))]
     pub name: Option<String>,
     pub template_id: Option<String>,
     pub current_resume_id: Option<Uuid>,
@@ -0,0 +1,20 @@
+//! Axum handler for the caller's quota usage.
+
+use axum::{extract::State, Json};
+
+use crate::auth::AuthUser;
+use crate::errors::AppError;
+use crate::quota::{usage_report, UsageReport};
+use crate::state::AppState;
+
+/// GET /api/v1/users/me/usage
+///
+/// Current-month usage and limits for generations, renders and LLM tokens.
+pub async fn handle_get_usage(
+    State(state): State<AppState>,
+    auth: AuthUser,
+) -> Result<Json<UsageReport>, AppError> {
+    Ok(Json(
+        usage_report(&state.db, &state.redis, auth.user_id).await?,
+    ))
+}
@@ -0,0 +1,809 @@
+//! Tier-based monthly usage quotas.
+//!
+//! `users.tier` maps to monthly allowances for resume generations, explicit render
+//! requests and LLM tokens. Usage is counted in Redis under
+//! `quota:{user_id}:{meter}:{YYYY-MM}`; each key expires a day after its calendar
+//! month (UTC) ends, so a new month starts from zero without a reset job.
+//!
+//! These limits sit on top of the rolling 24h throttle in
+//! `generation::batch_generation`, which still applies to batches.
+//!
+//! A candidate sponsored by an organization (see `orgs`) is limited by the org's
+//! shared pool instead of their own tier: usage is still counted per user, and also
+//! under `quota:org:{org_id}:{meter}:{YYYY-MM}`, which is what the limit applies to.
+//!
+//! The charge that takes a meter past `WARNING_FRACTION` of its limit posts a
+//! `quota_warning` to the user's webhooks (see `notifications::webhooks`); for an org
+//! pool, to the candidate whose charge crossed it.
+//!
+//! GET /api/v1/users/me/usage — usage against every limit for the current month
+//!
+//! Types and counters live here; the handler in handlers.rs.
+
+pub mod handlers;
+
+use std::future::Future;
+
+use chrono::{DateTime, Datelike, Duration, TimeZone, Utc};
+use serde::Serialize;
+use sqlx::PgPool;
+use uuid::Uuid;
+
+use crate::errors::AppError;
+use crate::llm_client;
+use crate::notifications::webhooks::{self, Level, Message, WebhookEvent};
+
+/// Share of a monthly limit at which the user is warned.
+pub const WARNING_FRACTION: f64 = 0.8;
+
+// ────────────────────────────────────────────────────────────────────────────
+// Limits
+// ────────────────────────────────────────────────────────────────────────────
+
+/// A counted resource.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Meter {
+    /// Resumes generated or regenerated, including batch jobs.
+    Generations,
+    /// `POST /api/v1/render` requests that queue a new job. The render a generation
+    /// enqueues by itself is part of the generation and not counted here.
+    Renders,
+    /// Input + output tokens across all metered LLM calls.
+    LlmTokens,
+}
+
+impl Meter {
+    pub const ALL: [Meter; 3] = [Meter::Generations, Meter::Renders, Meter::LlmTokens];
+
+    fn key_name(self) -> &'static str {
+        match self {
+            Meter::Generations => "generations",
+            Meter::Renders => "renders",
+            Meter::LlmTokens => "llm_tokens",
+        }
+    }
+
+    fn label(self) -> &'static str {
+        match self {
+            Meter::Generations => "resume generation",
+            Meter::Renders => "PDF render",
+            Meter::LlmTokens => "AI token",
+        }
+    }
+}
+
+/// Monthly allowances for a tier. `None` means unlimited.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TierLimits {
+    pub generations: Option<i64>,
+    pub renders: Option<i64>,
+    pub llm_tokens: Option<i64>,
+}
+
+impl TierLimits {
+    pub fn limit(&self, meter: Meter) -> Option<i64> {
+        match meter {
+            Meter::Generations => self.generations,
+            Meter::Renders => self.renders,
+            Meter::LlmTokens => self.llm_tokens,
+        }
+    }
+}
+
+/// Monthly allowances by `users.tier`. Unknown tiers get the free limits rather
+/// than an error, matching `daily_generation_limit`.
+pub fn tier_limits(tier: &str) -> TierLimits {
+    match tier {
+        "pro" => TierLimits {
+            generations: None,
+            renders: Some(500),
+            llm_tokens: Some(5_000_000),
+        },
+        "team" => TierLimits {
+            generations: None,
+            renders: None,
+            llm_tokens: Some(20_000_000),
+        },
+        "api" => TierLimits {
+            generations: None,
+            renders: None,
+            llm_tokens: Some(100_000_000),
+        },
+        _ => TierLimits {
+            generations: Some(3),
+            renders: Some(10),
+            llm_tokens: Some(250_000),
+        },
+    }
+}
+
+/// Monthly pool shared by an organization's candidates, by `organizations.tier`.
+/// Unknown tiers get the starter pool.
+pub fn org_tier_limits(tier: &str) -> TierLimits {
+    match tier {
+        "growth" => TierLimits {
+            generations: Some(1_000),
+            renders: Some(3_000),
+            llm_tokens: Some(50_000_000),
+        },
+        "enterprise" => TierLimits {
+            generations: None,
+            renders: None,
+            llm_tokens: Some(250_000_000),
+        },
+        _ => TierLimits {
+            generations: Some(200),
+            renders: Some(500),
+            llm_tokens: Some(10_000_000),
+        },
+    }
+}
+
+/// Whose limits a user's usage is checked against.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Billing {
+    /// The user's tier, or the sponsoring org's tier.
+    pub tier: String,
+    /// The org sponsoring the user as a candidate, if any.
+    pub org_id: Option<Uuid>,
+}
+
+impl Billing {
+    pub fn limits(&self) -> TierLimits {
+        match self.org_id {
+            Some(_) => org_tier_limits(&self.tier),
+            None => tier_limits(&self.tier),
+        }
+    }
+
+    /// Counters charged for `user_id`'s usage. The last one is what the limit applies
+    /// to: the org pool when sponsored, otherwise the user's own counter.
+    fn counter_keys(&self, user_id: Uuid, meter: Meter, period: &Period) -> Vec<String> {
+        let mut keys = vec![counter_key(user_id, meter, period)];
+        keys.extend(self.org_id.map(|org| org_counter_key(org, meter, period)));
+        keys
+    }
+}
+
+// ────────────────────────────────────────────────────────────────────────────
+// Periods
+// ────────────────────────────────────────────────────────────────────────────
+
+/// The calendar month (UTC) usage is counted against.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Period {
+    /// `YYYY-MM`, used in the Redis key.
+    pub key: String,
+    /// First instant of the next month.
+    pub resets_at: DateTime<Utc>,
+}
+
+pub fn current_period(now: DateTime<Utc>) -> Period {
+    let (year, month) = if now.month() == 12 {
+        (now.year() + 1, 1)
+    } else {
+        (now.year(), now.month() + 1)
+    };
+    Period {
+        key: format!("{:04}-{:02}", now.year(), now.month()),
+        resets_at: Utc
+            .with_ymd_and_hms(year, month, 1, 0, 0, 0)
+            .single()
+            .expect("midnight on the 1st is unambiguous in UTC"),
+    }
+}
+
+fn counter_key(user_id: Uuid, meter: Meter, period: &Period) -> String {
+    format!("quota:{user_id}:{}:{}", meter.key_name(), period.key)
+}
+
+fn org_counter_key(org_id: Uuid, meter: Meter, period: &Period) -> String {
+    format!("quota:org:{org_id}:{}:{}", meter.key_name(), period.key)
+}
+
+// ────────────────────────────────────────────────────────────────────────────
+// Usage report
+// ────────────────────────────────────────────────────────────────────────────
+
+#[derive(Debug, Clone, Serialize)]
+pub struct MeterUsage {
+    pub meter: Meter,
+    pub used: i64,
+    /// `None` when the tier is unlimited for this meter.
+    pub limit: Option<i64>,
+    pub remaining: Option<i64>,
+}
+
+/// Response for `GET /api/v1/users/me/usage`.
+#[derive(Debug, Clone, Serialize)]
+pub struct UsageReport {
+    pub tier: String,
+    /// Set when the limits are the sponsoring organization's shared pool; `tier` and
+    /// each meter's `used` are then the org's.
+    pub org_id: Option<Uuid>,
+    /// `YYYY-MM`
+    pub period: String,
+    pub resets_at: DateTime<Utc>,
+    pub meters: Vec<MeterUsage>,
+}
+
+pub fn meter_usage(meter: Meter, used: i64, limits: &TierLimits) -> MeterUsage {
+    let limit = limits.limit(meter);
+    MeterUsage {
+        meter,
+        used,
+        limit,
+        remaining: limit.map(|l| (l - used).max(0)),
+    }
+}
+
+// ────────────────────────────────────────────────────────────────────────────
+// Enforcement
+// ────────────────────────────────────────────────────────────────────────────
+
+/// Usage charged by `reserve`, refundable with `release` if the work fails.
+#[derive(Debug, Clone)]
+pub struct Reservation {
+    user_id: Uuid,
+    keys: Vec<String>,
+    amount: i64,
+}
+
+impl Reservation {
+    /// One unit of this reservation — refunds a single failed job of a batch.
+    pub fn unit(&self) -> Reservation {
+        Reservation {
+            amount: 1,
+            ..self.clone()
+        }
+    }
+}
+
+/// Errors if `used` — the counter value including this request — exceeds `limit`.
+fn check_within_limit(
+    meter: Meter,
+    tier: &str,
+    limit: Option<i64>,
+    used: i64,
+    period: &Period,
+) -> Result<(), AppError> {
+    match limit {
+        Some(limit) if used > limit => Err(AppError::QuotaExceeded {
+            message: format!(
+                "Monthly {} limit reached ({limit} on the '{tier}' tier). Resets at {}.",
+                meter.label(),
+                period.resets_at.to_rfc3339()
+            ),
+            resets_at: Some(period.resets_at),
+        }),
+        _ => Ok(()),
+    }
+}
+
+/// The user's tier, or their sponsoring org's pool.
+pub async fn load_billing(db: &PgPool, user_id: Uuid) -> Result<Billing, AppError> {
+    let row = sqlx::query!(
+        r#"SELECT u.tier, o.id AS "org_id?", o.tier AS "org_tier?"
+           FROM users u
+           LEFT JOIN org_memberships m ON m.user_id = u.id AND m.role = 'candidate'
+           LEFT JOIN organizations o ON o.id = m.org_id
+           WHERE u.id = $1"#,
+        user_id
+    )
+    .fetch_optional(db)
+    .await?
+    .ok_or(AppError::Unauthorized)?;
+    match (row.org_id, row.org_tier) {
+        (Some(org_id), Some(org_tier)) => Ok(Billing {
+            tier: org_tier,
+            org_id: Some(org_id),
+        }),
+        _ => Ok(Billing {
+            tier: row.tier,
+            org_id: None,
+        }),
+    }
+}
+
+fn redis_error(e: redis::RedisError) -> AppError {
+    AppError::Internal(anyhow::anyhow!("Quota counter unavailable: {e}"))
+}
+
+/// Adds `amount` to the counter and returns the new value. The key expires a day
+/// after the period ends.
+async fn incr(
+    redis: &redis::Client,
+    key: &str,
+    amount: i64,
+    period: &Period,
+) -> Result<i64, AppError> {
+    let mut conn = redis
+        .get_multiplexed_async_connection()
+        .await
+        .map_err(redis_error)?;
+    let (used,): (i64,) = redis::pipe()
+        .atomic()
+        .cmd("INCRBY")
+        .arg(key)
+        .arg(amount)
+        .cmd("EXPIREAT")
+        .arg(key)
+        .arg((period.resets_at + Duration::days(1)).timestamp())
+        .ignore()
+        .query_async(&mut conn)
+        .await
+        .map_err(redis_error)?;
+    Ok(used)
+}
+
+/// Charges `amount` of `meter` to the user, or fails with `QuotaExceeded` (429,
+/// with the reset time) if that would go over their tier's monthly limit. A
+/// rejected request is not charged.
+pub async fn reserve(
+    db: &PgPool,
+    redis: &redis::Client,
+    user_id: Uuid,
+    meter: Meter,
+    amount: i64,
+) -> Result<Reservation, AppError> {
+    let billing = load_billing(db, user_id).await?;
+    let period = current_period(Utc::now());
+    let keys = billing.counter_keys(user_id, meter, &period);
+
+    let mut used = 0;
+    for key in &keys {
+        used = incr(redis, key, amount, &period).await?;
+    }
+    let reservation = Reservation {
+        user_id,
+        keys,
+        amount,
+    };
+    let limit = billing.limits().limit(meter);
+    if let Err(e) = check_within_limit(meter, &billing.tier, limit, used, &period) {
+        release(redis, &reservation).await;
+        return Err(e);
+    }
+    warn_on_crossing(db, user_id, meter, limit, used - amount, used, &period);
+    Ok(reservation)
+}
+
+/// Whether going from `before` to `after` crossed `WARNING_FRACTION` of `limit`.
+fn crosses_warning(before: i64, after: i64, limit: i64) -> bool {
+    let threshold = (limit as f64 * WARNING_FRACTION).ceil() as i64;
+    limit > 0 && before < threshold && after >= threshold
+}
+
+fn warn_on_crossing(
+    db: &PgPool,
+    user_id: Uuid,
+    meter: Meter,
+    limit: Option<i64>,
+    before: i64,
+    after: i64,
+    period: &Period,
+) {
+    let Some(limit) = limit.filter(|&limit| crosses_warning(before, after, limit)) else {
+        return;
+    };
+    webhooks::notify(
+        db,
+        user_id,
+        WebhookEvent::QuotaWarning,
+        warning_message(meter, after, limit, period),
+    );
+}
+
+fn warning_message(meter: Meter, used: i64, limit: i64, period: &Period) -> Message {
+    Message {
+        title: format!(
+            "{:.0}% of your monthly {} allowance used",
+            WARNING_FRACTION * 100.0,
+            meter.label()
+        ),
+        lines: vec![
+            format!("{used} of {limit} used this month."),
+            format!("Resets {}.", period.resets_at.format("%Y-%m-%d")),
+        ],
+        link: None,
+        level: Level::Warning,
+    }
+}
+
+/// Refunds a reservation whose work failed. Best-effort: errors are logged.
+pub async fn release(redis: &redis::Client, reservation: &Reservation) {
+    let result = async {
+        let mut conn = redis.get_multiplexed_async_connection().await?;
+        let mut pipe = redis::pipe();
+        for key in &reservation.keys {
+            pipe.cmd("DECRBY").arg(key).arg(reservation.amount).ignore();
+        }
+        pipe.query_async::<_, ()>(&mut conn).await
+    }
+    .await;
+    if let Err(e) = result {
+        tracing::warn!(user_id = %reservation.user_id, error = %e, "failed to release quota reservation");
+    }
+}
+
+/// Fails with `QuotaExceeded` if the user has already used up their monthly LLM
+/// tokens. Token cost is only known afterwards, so this gates on what is already
+/// spent and `record_tokens` charges the actual usage.
+pub async fn ensure_tokens_available(
+    db: &PgPool,
+    redis: &redis::Client,
+    user_id: Uuid,
+) -> Result<(), AppError> {
+    let billing = load_billing(db, user_id).await?;
+    let Some(limit) = billing.limits().llm_tokens else {
+        return Ok(());
+    };
+    let period = current_period(Utc::now());
+    let keys = billing.counter_keys(user_id, Meter::LlmTokens, &period);
+    let used = get_counter(redis, keys.last().expect("at least the user's key")).await?;
+    // `used + 1`: the next call needs at least one token.
+    check_within_limit(
+        Meter::LlmTokens,
+        &billing.tier,
+        Some(limit),
+        used + 1,
+        &period,
+    )
+}
+
+/// Charges tokens already spent. Best-effort: the work is done, so a counter
+/// failure is logged rather than failing the request.
+pub async fn record_tokens(db: &PgPool, redis: &redis::Client, user_id: Uuid, tokens: u64) {
+    if tokens == 0 {
+        return;
+    }
+    let period = current_period(Utc::now());
+    let result = async {
+        let billing = load_billing(db, user_id).await?;
+        let mut used = 0;
+        for key in billing.counter_keys(user_id, Meter::LlmTokens, &period) {
+            used = incr(redis, &key, tokens as i64, &period).await?;
+        }
+        Ok::<_, AppError>((billing.limits().llm_tokens, used))
+    }
+    .await;
+    match result {
+        Ok((limit, used)) => warn_on_crossing(
+            db,
+            user_id,
+            Meter::LlmTokens,
+            limit,
+            used - tokens as i64,
+            used,
+            &period,
+        ),
+        Err(e) => {
+            tracing::warn!(%user_id, tokens, error = %e, "failed to record LLM token usage")
+        }
+    }
+}
+
+/// Runs LLM-backed work for `user_id` under their token ceiling: refuses to start
+/// once the ceiling is reached, and charges the tokens the work used.
+pub async fn metered_llm<T, F>(
+    db: &PgPool,
+    redis: &redis::Client,
+    user_id: Uuid,
+    work: F,
+) -> Result<T, AppError>
+where
+    F: Future<Output = Result<T, AppError>>,
+{
+    ensure_tokens_available(db, redis, user_id).await?;
+    let (result, tokens) = llm_client::metered(work).await;
+    record_tokens(db, redis, user_id, tokens).await;
+    result
+}
+
+/// Runs one resume generation under the user's quotas: one generation is charged up
+/// front (and refunded if the pipeline fails), plus the LLM tokens it used.
+pub async fn metered_generation<T, F>(
+    db: &PgPool,
+    redis: &redis::Client,
+    user_id: Uuid,
+    work: F,
+) -> Result<T, AppError>
+where
+    F: Future<Output = Result<T, AppError>>,
+{
+    ensure_tokens_available(db, redis, user_id).await?;
+    let reservation = reserve(db, redis, user_id, Meter::Generations, 1).await?;
+    let (result, tokens) = llm_client::metered(work).await;
+    record_tokens(db, redis, user_id, tokens).await;
+    if result.is_err() {
+        release(redis, &reservation).await;
+    }
+    result
+}
+
+async fn get_counter(redis: &redis::Client, key: &str) -> Result<i64, AppError> {
+    let mut conn = redis
+        .get_multiplexed_async_connection()
+        .await
+        .map_err(redis_error)?;
+    let used: Option<i64> = redis::cmd("GET")
+        .arg(key)
+        .query_async(&mut conn)
+        .await
+        .map_err(redis_error)?;
+    Ok(used.unwrap_or(0).max(0))
+}
+
+/// Current-month usage against every limit of the user's tier, or of their
+/// sponsoring org's pool.
+pub async fn usage_report(
+    db: &PgPool,
+    redis: &redis::Client,
+    user_id: Uuid,
+) -> Result<UsageReport, AppError> {
+    let billing = load_billing(db, user_id).await?;
+    let limits = billing.limits();
+    let period = current_period(Utc::now());
+
+    let mut meters = Vec::with_capacity(Meter::ALL.len());
+    for meter in Meter::ALL {
+        let keys = billing.counter_keys(user_id, meter, &period);
+        let used = get_counter(redis, keys.last().expect("at least the user's key")).await?;
+        meters.push(meter_usage(meter, used, &limits));
+    }
+
+    Ok(UsageReport {
+        tier: billing.tier,
+        org_id: billing.org_id,
+        period: period.key,
+        resets_at: period.resets_at,
+        meters,
+    })
+}
+
+/// Current-month usage of `meter` for each of `user_ids`, in order.
+pub async fn user_usage(
+    redis: &redis::Client,
+    user_ids: &[Uuid],
+    meter: Meter,
+) -> Result<Vec<i64>, AppError> {
+    let period = current_period(Utc::now());
+    let mut used = Vec::with_capacity(user_ids.len());
+    for user_id in user_ids {
+        used.push(get_counter(redis, &counter_key(*user_id, meter, &period)).await?);
+    }
+    Ok(used)
+}
+
+/// Current-month usage of `org_id`'s shared pool against its tier's limits.
+pub async fn org_pool_usage(
+    redis: &redis::Client,
+    org_id: Uuid,
+    tier: &str,
+) -> Result<UsageReport, AppError> {
+    let limits = org_tier_limits(tier);
+    let period = current_period(Utc::now());
+    let mut meters = Vec::with_capacity(Meter::ALL.len());
+    for meter in Meter::ALL {
+        let used = get_counter(redis, &org_counter_key(org_id, meter, &period)).await?;
+        meters.push(meter_usage(meter, used, &limits));
+    }
+    Ok(UsageReport {
+        tier: tier.to_string(),
+        org_id: Some(org_id),
+        period: period.key,
+        resets_at: period.resets_at,
+        meters,
+    })
+}
+
+/// Deletes every usage counter of `user_id`, for account deletion. Returns how many
+/// keys were removed.
+pub async fn forget_user(redis: &redis::Client, user_id: Uuid) -> Result<usize, AppError> {
+    let mut conn = redis
+        .get_multiplexed_async_connection()
+        .await
+        .map_err(redis_error)?;
+    let keys = scan_keys(&mut conn, &format!("quota:{user_id}:*")).await?;
+    if !keys.is_empty() {
+        redis::cmd("DEL")
+            .arg(&keys)
+            .query_async::<_, ()>(&mut conn)
+            .await
+            .map_err(redis_error)?;
+    }
+    Ok(keys.len())
+}
+
+/// Current-month usage of `meter` by every user with a non-zero counter, highest
+/// first. Org pool counters are not included.
+pub async fn usage_by_user(
+    redis: &redis::Client,
+    meter: Meter,
+) -> Result<Vec<(Uuid, i64)>, AppError> {
+    let period = current_period(Utc::now());
+    let mut conn = redis
+        .get_multiplexed_async_connection()
+        .await
+        .map_err(redis_error)?;
+    let pattern = format!("quota:*:{}:{}", meter.key_name(), period.key);
+    let keys: Vec<String> = scan_keys(&mut conn, &pattern)
+        .await?
+        .into_iter()
+        .filter(|key| counter_key_user(key).is_some())
+        .collect();
+    if keys.is_empty() {
+        return Ok(vec![]);
+    }
+    let values: Vec<Option<i64>> = redis::cmd("MGET")
+        .arg(&keys)
+        .query_async(&mut conn)
+        .await
+        .map_err(redis_error)?;
+
+    let mut usage: Vec<(Uuid, i64)> = keys
+        .iter()
+        .zip(values)
+        .filter_map(|(key, used)| Some((counter_key_user(key)?, used.unwrap_or(0))))
+        .filter(|(_, used)| *used > 0)
+        .collect();
+    usage.sort_by(|a, b| b.1.cmp(&a.1).then(a.0.cmp(&b.0)));
+    Ok(usage)
+}
+
+/// The user a `quota:{user_id}:...` key counts for; `None` for org pool keys.
+fn counter_key_user(key: &str) -> Option<Uuid> {
+    key.strip_prefix("quota:")?.split(':').next()?.parse().ok()
+}
+
+pub(crate) async fn scan_keys(
+    conn: &mut redis::aio::MultiplexedConnection,
+    pattern: &str,
+) -> Result<Vec<String>, AppError> {
+    let mut keys: Vec<String> = vec![];
+    let mut cursor: u64 = 0;
+    loop {
+        let (next, batch): (u64, Vec<String>) = redis::cmd("SCAN")
+            .arg(cursor)
+            .arg("MATCH")
+            .arg(pattern)
+            .arg("COUNT")
+            .arg(500)
+            .query_async(conn)
+            .await
+            .map_err(redis_error)?;
+        keys.extend(batch);
+        if next == 0 {
+            return Ok(keys);
+        }
+        cursor = next;
+    }
+}
+
+// ────────────────────────────────────────────────────────────────────────────
+// Tests
+// ────────────────────────────────────────────────────────────────────────────
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn at(year: i32, month: u32, day: u32) -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(year, month, day, 12, 30, 0).unwrap()
+    }
+
+    #[test]
+    fn test_period_rolls_over_at_month_and_year_end() {
+        let period = current_period(at(2026, 10, 16));
+        assert_eq!(period.key, "2026-10");
+        assert_eq!(
+            period.resets_at,
+            Utc.with_ymd_and_hms(2026, 11, 1, 0, 0, 0).unwrap()
+        );
+
+        let december = current_period(at(2026, 12, 31));
+        assert_eq!(december.key, "2026-12");
+        assert_eq!(
+            december.resets_at,
+            Utc.with_ymd_and_hms(2027, 1, 1, 0, 0, 0).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_free_tier_is_limited_and_pro_generations_are_not() {
+        assert_eq!(tier_limits("free").generations, Some(3));
+        assert_eq!(tier_limits("unknown"), tier_limits("free"));
+        assert_eq!(tier_limits("pro").generations, None);
+        assert!(tier_limits("pro").llm_tokens.is_some());
+    }
+
+    #[test]
+    fn test_warning_fires_once_when_crossing_the_threshold() {
+        assert!(!crosses_warning(0, 79, 100));
+        assert!(crosses_warning(79, 80, 100));
+        assert!(crosses_warning(10, 95, 100));
+        assert!(!crosses_warning(80, 81, 100));
+        // Small limits round the threshold up: the 3rd of 3 free generations.
+        assert!(!crosses_warning(1, 2, 3));
+        assert!(crosses_warning(2, 3, 3));
+        assert!(!crosses_warning(0, 1, 0));
+
+        let message = warning_message(Meter::Generations, 3, 3, &current_period(at(2026, 10, 16)));
+        assert_eq!(
+            message.title,
+            "80% of your monthly resume generation allowance used"
+        );
+        assert_eq!(
+            message.lines,
+            vec!["3 of 3 used this month.", "Resets 2026-11-01."]
+        );
+    }
+
+    #[test]
+    fn test_limit_check_reports_reset_time() {
+        let period = current_period(at(2026, 10, 16));
+        assert!(check_within_limit(Meter::Generations, "free", Some(3), 3, &period).is_ok());
+        assert!(check_within_limit(Meter::Generations, "pro", None, 10_000, &period).is_ok());
+
+        match check_within_limit(Meter::Generations, "free", Some(3), 4, &period) {
+            Err(AppError::QuotaExceeded { message, resets_at }) => {
+                assert_eq!(resets_at, Some(period.resets_at));
+                assert!(message.contains("resume generation"));
+                assert!(message.contains("2026-11-01"));
+            }
+            other => panic!("expected QuotaExceeded, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn test_sponsored_usage_is_limited_by_the_org_pool() {
+        let period = current_period(at(2026, 10, 16));
+        let (user, org) = (Uuid::new_v4(), Uuid::new_v4());
+        let personal = Billing {
+            tier: "free".to_string(),
+            org_id: None,
+        };
+        assert_eq!(
+            personal.counter_keys(user, Meter::Renders, &period),
+            vec![format!("quota:{user}:renders:2026-10")]
+        );
+        assert_eq!(personal.limits(), tier_limits("free"));
+
+        let sponsored = Billing {
+            tier: "starter".to_string(),
+            org_id: Some(org),
+        };
+        let keys = sponsored.counter_keys(user, Meter::Renders, &period);
+        assert_eq!(keys[0], format!("quota:{user}:renders:2026-10"));
+        assert_eq!(keys[1], format!("quota:org:{org}:renders:2026-10"));
+        assert_eq!(sponsored.limits(), org_tier_limits("starter"));
+        // The org pool key is not matched by the per-user cleanup pattern.
+        assert!(!keys[1].starts_with(&format!("quota:{user}:")));
+    }
+
+    #[test]
+    fn test_meter_usage_clamps_remaining() {
+        let limits = tier_limits("free");
+        let usage = meter_usage(Meter::Renders, 12, &limits);
+        assert_eq!(usage.remaining, Some(0));
+        let unlimited = meter_usage(Meter::Generations, 50, &tier_limits("team"));
+        assert_eq!(unlimited.limit, None);
+        assert_eq!(unlimited.remaining, None);
+    }
+
+    #[test]
+    fn test_counter_key_user_skips_org_pool_keys() {
+        let period = current_period(Utc::now());
+        let (user, org) = (Uuid::new_v4(), Uuid::new_v4());
+        assert_eq!(
+            counter_key_user(&counter_key(user, Meter::LlmTokens, &period)),
+            Some(user)
+        );
+        assert_eq!(
+            counter_key_user(&org_counter_key(org, Meter::LlmTokens, &period)),
+            None
+        );
+        assert_eq!(counter_key_user("ratelimit:standard:user:1"), None);
+    }
+}
@@ -0,0 +1,322 @@
+//! Per-client request rate limiting, shared across API instances through Redis.
+//!
+//! Every routed request is classified into a budget (auth, expensive, standard) and
+//! counted against its caller in a fixed window:
+//! `ratelimit:{budget}:{caller}:{window_start}`. The caller is the authenticated user
+//! when the request carries a valid bearer token and the client IP otherwise; the
+//! auth budget is always keyed by IP so credential guessing cannot hop accounts.
+//!
+//! Over budget → 429 `RATE_LIMITED` with `Retry-After`. Allowed responses carry
+//! `X-RateLimit-Limit` / `X-RateLimit-Remaining`. If Redis is unreachable requests
+//! are let through: rate limiting is protection, not correctness.
+//!
+//! Monthly tier allowances are a separate concern — see `crate::quota`.
+
+use std::net::{IpAddr, SocketAddr};
+
+use axum::{
+    extract::{ConnectInfo, MatchedPath, Request, State},
+    http::{HeaderMap, HeaderName, HeaderValue, Method},
+    middleware::Next,
+    response::{IntoResponse, Response},
+};
+use chrono::Utc;
+
+use crate::api_version::{V1_PREFIX, V2_PREFIX};
+use crate::auth::bearer_token;
+use crate::errors::AppError;
+use crate::state::AppState;
+
+pub const LIMIT_HEADER: HeaderName = HeaderName::from_static("x-ratelimit-limit");
+pub const REMAINING_HEADER: HeaderName = HeaderName::from_static("x-ratelimit-remaining");
+
+// ────────────────────────────────────────────────────────────────────────────
+// Budgets
+// ────────────────────────────────────────────────────────────────────────────
+
+/// Requests allowed per window, by endpoint cost.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Budget {
+    /// Signup and login — small, to slow down credential stuffing.
+    Auth,
+    /// Endpoints that call the LLM, compile LaTeX or parse uploads.
+    Expensive,
+    /// Everything else (reads, small edits).
+    Standard,
+}
+
+impl Budget {
+    fn name(self) -> &'static str {
+        match self {
+            Budget::Auth => "auth",
+            Budget::Expensive => "expensive",
+            Budget::Standard => "standard",
+        }
+    }
+
+    /// `(requests, window_secs)`
+    pub fn limit(self) -> (u64, u64) {
+        match self {
+            Budget::Auth => (10, 60),
+            Budget::Expensive => (20, 60),
+            Budget::Standard => (300, 60),
+        }
+    }
+}
+
+/// Routes (method + matched path below the `/api/vN` prefix) charged to the expensive
+/// budget. Both API versions share a budget.
+const EXPENSIVE_ROUTES: &[(Method, &str)] = &[
+    (Method::POST, "/resumes/parse-jd"),
+    (Method::POST, "/resumes/parse-jd-html"),
+    (Method::POST, "/job-postings/import"),
+    (Method::POST, "/job-postings/board"),
+    (Method::POST, "/target-roles/:id/parse"),
+    (Method::POST, "/resumes/fit-score"),
+    (Method::POST, "/resumes/generate"),
+    (Method::POST, "/resumes/generate-batch"),
+    (Method::POST, "/resumes/:id/regenerate"),
+    (Method::POST, "/resumes/:id/interview-prep"),
+    (Method::POST, "/linkedin/generate"),
+    (Method::POST, "/context/ingest"),
+    (Method::POST, "/context/ingest/batch"),
+    (Method::POST, "/context/ingest/upload"),
+    (Method::POST, "/context/retag/preview"),
+    (Method::POST, "/render"),
+    (Method::GET, "/resumes/:id/ats-check"),
+    (Method::GET, "/resumes/:id/export"),
+    (Method::POST, "/resumes/:id/anonymize"),
+    (Method::GET, "/templates/:id/render-pdf"),
+    (Method::GET, "/templates/:id/thumbnail-pdf"),
+];
+
+/// The budget a request is charged to, or `None` for unlimited probes.
+pub fn classify(method: &Method, path: &str) -> Option<Budget> {
+    let route = path
+        .strip_prefix(V1_PREFIX)
+        .or_else(|| path.strip_prefix(V2_PREFIX))
+        .unwrap_or(path);
+    match route {
+        "/health" | "/metrics" => None,
+        "/auth/signup" | "/auth/login" => Some(Budget::Auth),
+        _ if EXPENSIVE_ROUTES
+            .iter()
+            .any(|(m, p)| m == method && *p == route) =>
+        {
+            Some(Budget::Expensive)
+        }
+        _ => Some(Budget::Standard),
+    }
+}
+
+// ────────────────────────────────────────────────────────────────────────────
+// Callers and windows
+// ────────────────────────────────────────────────────────────────────────────
+
+/// Client IP: the rightmost `X-Forwarded-For` hop when the API sits behind a trusted
+/// proxy, the socket peer otherwise. Earlier hops come from the client and can be
+/// forged; the last one is the address the proxy itself saw.
+fn client_ip(
+    headers: &HeaderMap,
+    peer: Option<IpAddr>,
+    trust_forwarded_for: bool,
+) -> Option<IpAddr> {
+    if trust_forwarded_for {
+        let forwarded = headers
+            .get_all("x-forwarded-for")
+            .iter()
+            .next_back()
+            .and_then(|v| v.to_str().ok())
+            .and_then(|v| v.rsplit(',').next())
+            .and_then(|ip| ip.trim().parse().ok());
+        if forwarded.is_some() {
+            return forwarded;
+        }
+    }
+    peer
+}
+
+/// Counter key for `caller` in the window containing `now_secs`, plus the seconds
+/// until that window closes.
+fn window_key(budget: Budget, caller: &str, now_secs: u64) -> (String, u64) {
+    let (_, window_secs) = budget.limit();
+    let start = now_secs - now_secs % window_secs;
+    (
+        format!("ratelimit:{}:{caller}:{start}", budget.name()),
+        start + window_secs - now_secs,
+    )
+}
+
+async fn hit(redis: &redis::Client, key: &str, window_secs: u64) -> redis::RedisResult<u64> {
+    let mut conn = redis.get_multiplexed_async_connection().await?;
+    let (count,): (u64,) = redis::pipe()
+        .atomic()
+        .cmd("INCR")
+        .arg(key)
+        .cmd("EXPIRE")
+        .arg(key)
+        .arg(window_secs)
+        .ignore()
+        .query_async(&mut conn)
+        .await?;
+    Ok(count)
+}
+
+// ────────────────────────────────────────────────────────────────────────────
+// Middleware
+// ────────────────────────────────────────────────────────────────────────────
+
+/// `route_layer` middleware; runs after routing so `MatchedPath` is available.
+pub async fn limit_requests(State(state): State<AppState>, req: Request, next: Next) -> Response {
+    if !state.config.rate_limit.enabled {
+        return next.run(req).await;
+    }
+
+    let path = req
+        .extensions()
+        .get::<MatchedPath>()
+        .map(|p| p.as_str().to_string())
+        .unwrap_or_else(|| req.uri().path().to_string());
+    let Some(budget) = classify(req.method(), &path) else {
+        return next.run(req).await;
+    };
+
+    let user = match budget {
+        Budget::Auth => None,
+        _ => bearer_token(req.headers()).and_then(|t| state.jwt.verify(t).ok()),
+    };
+    let caller = match user {
+        Some(claims) => format!("user:{}", claims.sub),
+        None => {
+            let peer = req
+                .extensions()
+                .get::<ConnectInfo<SocketAddr>>()
+                .map(|ConnectInfo(addr)| addr.ip());
+            match client_ip(
+                req.headers(),
+                peer,
+                state.config.rate_limit.trust_forwarded_for,
+            ) {
+                Some(ip) => format!("ip:{ip}"),
+                None => return next.run(req).await,
+            }
+        }
+    };
+
+    let (limit, window_secs) = budget.limit();
+    let (key, retry_after_secs) = window_key(budget, &caller, Utc::now().timestamp() as u64);
+    let count = match hit(&state.redis, &key, window_secs).await {
+        Ok(count) => count,
+        Err(e) => {
+            tracing::warn!(error = %e, "rate limiter unavailable; allowing request");
+            return next.run(req).await;
+        }
+    };
+
+    if count > limit {
+        tracing::info!(%caller, budget = budget.name(), "rate limited");
+        return AppError::RateLimited { retry_after_secs }.into_response();
+    }
+
+    let mut response = next.run(req).await;
+    let headers = response.headers_mut();
+    headers.insert(LIMIT_HEADER, HeaderValue::from(limit));
+    headers.insert(REMAINING_HEADER, HeaderValue::from(limit - count));
+    response
+}
+
+// ────────────────────────────────────────────────────────────────────────────
+// Tests
+// ────────────────────────────────────────────────────────────────────────────
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_classify_budgets() {
+        assert_eq!(classify(&Method::GET, "/health"), None);
+        assert_eq!(
+            classify(&Method::POST, "/api/v1/auth/login"),
+            Some(Budget::Auth)
+        );
+        assert_eq!(
+            classify(&Method::POST, "/api/v1/resumes/generate"),
+            Some(Budget::Expensive)
+        );
+        assert_eq!(
+            classify(&Method::POST, "/api/v1/resumes/:id/regenerate"),
+            Some(Budget::Expensive)
+        );
+        assert_eq!(
+            classify(&Method::POST, "/api/v1/target-roles/:id/parse"),
+            Some(Budget::Expensive)
+        );
+        assert_eq!(
+            classify(&Method::GET, "/api/v1/context"),
+            Some(Budget::Standard)
+        );
+        // Same path, cheap method.
+        assert_eq!(
+            classify(&Method::GET, "/api/v1/render/:job_id"),
+            Some(Budget::Standard)
+        ); // v2 routes share v1's budgets.
+        assert_eq!(
+            classify(&Method::POST, "/api/v2/resumes/generate"),
+            Some(Budget::Expensive)
+        );
+        assert_eq!(
+            classify(&Method::POST, "/api/v2/auth/signup"),
+            Some(Budget::Auth)
+        );
+    }
+
+    #[test]
+    fn test_window_key_and_retry_after() {
+        let (key, retry) = window_key(Budget::Expensive, "user:abc", 1_000_030);
+        assert_eq!(key, "ratelimit:expensive:user:abc:1000020");
+        assert_eq!(retry, 50);
+
+        // Last second of a window still has one second to wait.
+        let (_, retry) = window_key(Budget::Standard, "ip:1.2.3.4", 1_000_019);
+        assert_eq!(retry, 1);
+    }
+
+    #[test]
+    fn test_forwarded_for_only_when_trusted() {
+        let mut headers = HeaderMap::new();
+        headers.insert(
+            "x-forwarded-for",
+            HeaderValue::from_static("198.51.100.1, 203.0.113.7"),
+        );
+        let peer: IpAddr = "10.0.0.1".parse().unwrap();
+
+        assert_eq!(
+            client_ip(&headers, Some(peer), true),
+            Some("203.0.113.7".parse().unwrap())
+        );
+        assert_eq!(client_ip(&headers, Some(peer), false), Some(peer));
+        assert_eq!(client_ip(&HeaderMap::new(), Some(peer), true), Some(peer));
+    }
+
+    #[test]
+    fn test_forwarded_for_ignores_client_supplied_hops() {
+        let peer: IpAddr = "10.0.0.1".parse().unwrap();
+        let mut headers = HeaderMap::new();
+        // The client sends its own header; the proxy appends a second one.
+        headers.append("x-forwarded-for", HeaderValue::from_static("1.1.1.1"));
+        headers.append("x-forwarded-for", HeaderValue::from_static("203.0.113.7"));
+        assert_eq!(
+            client_ip(&headers, Some(peer), true),
+            Some("203.0.113.7".parse().unwrap())
+        );
+
+        // A forged rightmost hop that isn't an address falls back to the peer.
+        let mut headers = HeaderMap::new();
+        headers.insert(
+            "x-forwarded-for",
+            HeaderValue::from_static("203.0.113.7, unknown"),
+        );
+        assert_eq!(client_ip(&headers, Some(peer), true), Some(peer));
+    }
+}
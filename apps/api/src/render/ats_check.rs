@@ -0,0 +1,259 @@
+//! ATS round-trip check — does a rendered PDF still say what the resume says?
+//!
+//! Templates are meant to be ATS-safe: one column, real text, standard headings. This
+//! verifies it per resume by reading the PDF's text back the way a naive ATS parser
+//! does — `pdf_extract` output, lowercased, no cleanup — and comparing it with the
+//! resume's bullets:
+//! - keywords: every JD keyword the bullets contain must still match as a whole term.
+//!   A lost keyword is attributed to ligature glyphs or to line-break hyphenation when
+//!   spelling those out brings it back, and reported as missing otherwise;
+//! - sections: every section heading must appear as a line of its own;
+//! - bullets: every bullet must read back as one run of words. Two-column layouts and
+//!   floats interleave lines and break this even when every word is present.
+
+use std::collections::BTreeSet;
+
+use uuid::Uuid;
+
+use crate::generation::jd_parser::ParsedJD;
+use crate::generation::keyword_coverage::contains_term;
+use crate::models::resume::ResumeBulletRow;
+
+pub use templar_types::render::{AtsCheckResponse, KeywordLossCause, LostKeyword};
+
+/// Unicode presentation-form ligatures and their spelled-out letters.
+const LIGATURES: &[(char, &str)] = &[
+    ('\u{FB00}', "ff"),
+    ('\u{FB01}', "fi"),
+    ('\u{FB02}', "fl"),
+    ('\u{FB03}', "ffi"),
+    ('\u{FB04}', "ffl"),
+    ('\u{FB05}', "st"),
+    ('\u{FB06}', "st"),
+];
+
+/// Compares `extracted_text` (from the resume's PDF) with its bullets and JD keywords.
+pub fn check(
+    resume_id: Uuid,
+    extracted_text: String,
+    parsed_jd: Option<&ParsedJD>,
+    bullets: &[ResumeBulletRow],
+) -> AtsCheckResponse {
+    let naive = extracted_text.to_lowercase();
+    let spelled_out = spell_out_ligatures(&naive);
+    let rejoined = rejoin_line_breaks(&spelled_out);
+
+    let resume_text = bullets
+        .iter()
+        .map(|b| b.bullet_text.to_lowercase())
+        .collect::<Vec<_>>()
+        .join("\n");
+    let lost_keywords: Vec<LostKeyword> = parsed_jd
+        .map(|jd| jd.keyword_inventory.as_slice())
+        .unwrap_or_default()
+        .iter()
+        .filter_map(|k| {
+            let needle = k.keyword.trim().to_lowercase();
+            if needle.is_empty()
+                || !contains_term(&resume_text, &needle)
+                || contains_term(&naive, &needle)
+            {
+                return None;
+            }
+            let cause = if contains_term(&spelled_out, &needle) {
+                KeywordLossCause::Ligature
+            } else if contains_term(&rejoined, &needle) {
+                KeywordLossCause::LineBreak
+            } else {
+                KeywordLossCause::Missing
+            };
+            Some(LostKeyword {
+                keyword: k.keyword.clone(),
+                cause,
+            })
+        })
+        .collect();
+
+    let heading_lines: BTreeSet<String> = naive.lines().map(words).collect();
+    let mut lost_sections: Vec<String> = vec![];
+    for bullet in bullets {
+        let heading = words(&bullet.section.to_lowercase());
+        if !heading_lines.contains(&heading) && !lost_sections.contains(&bullet.section) {
+            lost_sections.push(bullet.section.clone());
+        }
+    }
+
+    let flowing = words(&rejoined);
+    let broken_bullets: Vec<String> = bullets
+        .iter()
+        .filter(|b| {
+            let bullet = words(&b.bullet_text.to_lowercase());
+            !bullet.is_empty() && !format!(" {flowing} ").contains(&format!(" {bullet} "))
+        })
+        .map(|b| b.bullet_text.clone())
+        .collect();
+
+    let ligatures: Vec<String> = LIGATURES
+        .iter()
+        .filter(|(glyph, _)| naive.contains(*glyph))
+        .map(|(glyph, _)| glyph.to_string())
+        .collect();
+
+    AtsCheckResponse {
+        resume_id,
+        ats_safe: lost_keywords.is_empty() && lost_sections.is_empty() && broken_bullets.is_empty(),
+        lost_keywords,
+        lost_sections,
+        broken_bullets,
+        ligatures,
+        extracted_text,
+    }
+}
+
+fn spell_out_ligatures(text: &str) -> String {
+    LIGATURES
+        .iter()
+        .fold(text.to_string(), |acc, (glyph, letters)| {
+            acc.replace(*glyph, letters)
+        })
+}
+
+/// Joins words LaTeX hyphenated across lines (`infra-\nstructure`).
+fn rejoin_line_breaks(text: &str) -> String {
+    text.replace("-\n", "")
+}
+
+/// `text` as its alphanumeric words separated by single spaces, so punctuation the
+/// typesetter changed (quotes, dashes) and line wrapping do not count as differences.
+fn words(text: &str) -> String {
+    text.split(|c: char| !c.is_alphanumeric())
+        .filter(|w| !w.is_empty())
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+// ────────────────────────────────────────────────────────────────────────────
+// Tests
+// ────────────────────────────────────────────────────────────────────────────
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Utc;
+    use templar_types::jd::{KeywordEntry, RoleSignals};
+
+    fn make_bullet(section: &str, text: &str) -> ResumeBulletRow {
+        ResumeBulletRow {
+            id: Uuid::new_v4(),
+            resume_id: Uuid::nil(),
+            section: section.to_string(),
+            bullet_text: text.to_string(),
+            source_entry_id: Uuid::nil(),
+            grounding_score: 1.0,
+            is_user_edited: false,
+            line_count: 1,
+            position: 0,
+            created_at: Utc::now(),
+            keyword_spans: serde_json::json!([]),
+        }
+    }
+
+    fn jd(keywords: &[&str]) -> ParsedJD {
+        ParsedJD {
+            hard_requirements: vec![],
+            soft_signals: vec![],
+            role_signals: RoleSignals {
+                is_startup: false,
+                is_ic_focused: true,
+                is_research: false,
+                seniority: "senior".into(),
+            },
+            keyword_inventory: keywords
+                .iter()
+                .map(|k| KeywordEntry {
+                    keyword: k.to_string(),
+                    frequency: 1,
+                    position_weight: 1.0,
+                    weighted_score: 1.0,
+                })
+                .collect(),
+            detected_tone: Default::default(),
+        }
+    }
+
+    fn bullets() -> Vec<ResumeBulletRow> {
+        vec![
+            make_bullet(
+                "experience",
+                "Built the \"ledger\" service in Rust -- 99.99% uptime",
+            ),
+            make_bullet(
+                "experience",
+                "Cut Kubernetes costs by 30% for the finance team",
+            ),
+            make_bullet("skills", "Languages: Rust, Go, Python"),
+        ]
+    }
+
+    #[test]
+    fn test_clean_pdf_text_is_ats_safe() {
+        let text = "EXPERIENCE\nBuilt the “ledger” service in Rust – 99.99% up-\ntime\n\
+                    Cut Kubernetes costs by 30% for the finance team\n\
+                    Skills\nLanguages: Rust, Go, Python\n";
+        let report = check(
+            Uuid::nil(),
+            text.to_string(),
+            Some(&jd(&["Rust", "Kubernetes", "Kafka"])),
+            &bullets(),
+        );
+        assert!(report.ats_safe, "{report:?}");
+        assert!(report.ligatures.is_empty());
+    }
+
+    #[test]
+    fn test_reports_ligatures_line_breaks_and_lost_headings() {
+        // The skills heading was an image; the finance line came out with a ligature
+        // and Kubernetes was hyphenated at a line end.
+        let text = "Experience\nBuilt the ledger service in Rust 99.99% uptime\n\
+                    Cut Kuber-\nnetes costs by 30% for the \u{FB01}nance team\n\
+                    Languages: Rust, Go, Python\n";
+        let report = check(
+            Uuid::nil(),
+            text.to_string(),
+            Some(&jd(&["Kubernetes", "finance", "Python"])),
+            &bullets(),
+        );
+        assert!(!report.ats_safe);
+        assert_eq!(
+            report.lost_keywords,
+            [
+                LostKeyword {
+                    keyword: "Kubernetes".into(),
+                    cause: KeywordLossCause::LineBreak,
+                },
+                LostKeyword {
+                    keyword: "finance".into(),
+                    cause: KeywordLossCause::Ligature,
+                },
+            ]
+        );
+        assert_eq!(report.lost_sections, ["skills"]);
+        assert_eq!(report.ligatures, ["\u{FB01}"]);
+        // Rejoined and spelled out, every bullet still reads back in order.
+        assert!(
+            report.broken_bullets.is_empty(),
+            "{:?}",
+            report.broken_bullets
+        );
+    }
+
+    #[test]
+    fn test_interleaved_columns_break_bullets() {
+        let text = "Experience\nBuilt the ledger service Cut Kubernetes costs by 30%\n\
+                    in Rust 99.99% uptime for the finance team\nSkills\nLanguages: Rust, Go, Python";
+        let report = check(Uuid::nil(), text.to_string(), None, &bullets());
+        assert!(report.lost_keywords.is_empty());
+        assert_eq!(report.broken_bullets.len(), 2);
+        assert!(!report.ats_safe);
+    }
+}
@@ -4,6 +4,8 @@
 //! POST /api/v1/render            → handle_trigger_render
 //! GET  /api/v1/render/:job_id/status → handle_render_status
 //! GET  /api/v1/render/:job_id    → handle_get_pdf
+//! GET  /api/v1/render/:job_id/line-map → handle_render_line_map
+//! GET  /api/v1/resumes/:id/ats-check → handle_ats_check
 
 use axum::{
     body::Body,
@@ -12,39 +14,28 @@ use axum::{
     Json,
 };
 use chrono::{DateTime, Utc};
-use redis::AsyncCommands;
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
+use crate::audit::{self, Action, AuditEvent};
+use crate::auth::{AuthUser, Resource};
 use crate::errors::AppError;
-use crate::models::resume::RenderJobRow;
-use crate::render::worker::RENDER_QUEUE_KEY;
+use crate::generation::jd_parser::ParsedJD;
+use crate::jobs;
+use crate::models::resume::{RenderJobRow, ResumeBulletRow, ResumeRow};
+use crate::quota::{self, Meter};
+use crate::render::ats_check;
+use crate::render::worker::RenderJob;
 use crate::state::AppState;
 
 // ────────────────────────────────────────────────────────────────────────────
 // Request / Response types
 // ────────────────────────────────────────────────────────────────────────────
 
-#[derive(Debug, Deserialize)]
-pub struct TriggerRenderRequest {
-    pub resume_id: Uuid,
-}
-
-#[derive(Debug, Serialize)]
-pub struct TriggerRenderResponse {
-    pub job_id: Uuid,
-    pub status: String,
-}
-
-#[derive(Debug, Serialize)]
-pub struct RenderStatusResponse {
-    pub job_id: Uuid,
-    pub resume_id: Uuid,
-    pub status: String,
-    pub error_message: Option<String>,
-    pub created_at: DateTime<Utc>,
-    pub updated_at: DateTime<Utc>,
-}
+pub use templar_types::render::{
+    AtsCheckResponse, BulletPlacement, KeywordLossCause, LostKeyword, RenderLineMapResponse,
+    RenderStatusResponse, TriggerRenderRequest, TriggerRenderResponse,
+};
 
 // ────────────────────────────────────────────────────────────────────────────
 // Handlers
@@ -54,36 +45,29 @@ pub struct RenderStatusResponse {
 ///
 /// Triggers a render job for an existing resume.
 /// Idempotent: if a queued or processing job already exists for the resume,
-/// returns the existing job_id instead of creating a duplicate.
+/// returns the existing job_id instead of creating a duplicate. Only a newly queued
+/// job counts against the monthly render quota.
 pub async fn handle_trigger_render(
     State(state): State<AppState>,
+    auth: AuthUser,
     Json(req): Json<TriggerRenderRequest>,
 ) -> Result<Json<TriggerRenderResponse>, AppError> {
-    // Validate resume exists
-    let resume_exists: bool =
-        sqlx::query_scalar("SELECT EXISTS(SELECT 1 FROM resumes WHERE id = $1)")
-            .bind(req.resume_id)
-            .fetch_one(&state.db)
-            .await?;
-
-    if !resume_exists {
-        return Err(AppError::NotFound(format!(
-            "Resume {} not found",
-            req.resume_id
-        )));
-    }
+    // Validate the resume exists and belongs to the caller
+    auth.authorize(&state.db, Resource::Resume, req.resume_id)
+        .await?;
 
     // Idempotency: return existing active job if one exists.
     // The `updated_at > NOW() - INTERVAL '5 minutes'` guard prevents stale
     // 'processing' jobs (e.g. a worker that crashed mid-job and never transitioned
     // to 'failed') from blocking new render requests indefinitely.
-    let existing = sqlx::query_as::<_, RenderJobRow>(
+    let existing = sqlx::query_as!(
+        RenderJobRow,
         "SELECT * FROM render_jobs \
          WHERE resume_id = $1 AND status IN ('queued', 'processing') \
          AND updated_at > NOW() - INTERVAL '5 minutes' \
          ORDER BY created_at DESC LIMIT 1",
+        req.resume_id
     )
-    .bind(req.resume_id)
     .fetch_optional(&state.db)
     .await?;
 
@@ -94,30 +78,27 @@ pub async fn handle_trigger_render(
         }));
     }
 
-    // Insert new render job row
-    let job_id = Uuid::new_v4();
-    sqlx::query("INSERT INTO render_jobs (id, resume_id, status) VALUES ($1, $2, 'queued')")
-        .bind(job_id)
-        .bind(req.resume_id)
-        .execute(&state.db)
-        .await?;
-
-    // Push job_id to Redis queue (async)
-    let mut conn = state
-        .redis
-        .get_multiplexed_async_connection()
-        .await
-        .map_err(|e| AppError::Internal(anyhow::anyhow!("Redis connection failed: {e}")))?;
+    let reservation =
+        quota::reserve(&state.db, &state.redis, auth.user_id, Meter::Renders, 1).await?;
 
-    conn.lpush::<_, _, ()>(RENDER_QUEUE_KEY, job_id.to_string())
-        .await
-        .map_err(|e| AppError::Internal(anyhow::anyhow!("Redis enqueue failed: {e}")))?;
+    let job_id = Uuid::new_v4();
+    if let Err(e) = queue_render_job(&state, job_id, req.resume_id).await {
+        quota::release(&state.redis, &reservation).await;
+        return Err(e);
+    }
 
     tracing::info!(
         "Triggered render job {} for resume {}",
         job_id,
         req.resume_id
     );
+    audit::record(
+        &state.db,
+        auth.user_id,
+        AuditEvent::new(Action::RenderJobCreate, job_id)
+            .after(serde_json::json!({ "resume_id": req.resume_id })),
+    )
+    .await;
 
     Ok(Json(TriggerRenderResponse {
         job_id,
@@ -130,13 +111,19 @@ pub async fn handle_trigger_render(
 /// Returns the current status of a render job.
 pub async fn handle_render_status(
     State(state): State<AppState>,
+    auth: AuthUser,
     Path(job_id): Path<Uuid>,
 ) -> Result<Json<RenderStatusResponse>, AppError> {
-    let job = sqlx::query_as::<_, RenderJobRow>("SELECT * FROM render_jobs WHERE id = $1")
-        .bind(job_id)
-        .fetch_optional(&state.db)
-        .await?
-        .ok_or_else(|| AppError::NotFound(format!("Render job {job_id} not found")))?;
+    auth.authorize_view(&state.db, Resource::RenderJob, job_id)
+        .await?;
+    let job = sqlx::query_as!(
+        RenderJobRow,
+        "SELECT * FROM render_jobs WHERE id = $1",
+        job_id
+    )
+    .fetch_optional(&state.db)
+    .await?
+    .ok_or_else(|| AppError::NotFound(format!("Render job {job_id} not found")))?;
 
     Ok(Json(RenderStatusResponse {
         job_id: job.id,
@@ -156,14 +143,21 @@ pub async fn handle_render_status(
 /// Returns the PDF as an `application/pdf` response body.
 pub async fn handle_get_pdf(
     State(state): State<AppState>,
+    auth: AuthUser,
     Path(job_id): Path<Uuid>,
 ) -> Result<Response<Body>, AppError> {
+    auth.authorize_view(&state.db, Resource::RenderJob, job_id)
+        .await?;
+
     // Fetch job record
-    let job = sqlx::query_as::<_, RenderJobRow>("SELECT * FROM render_jobs WHERE id = $1")
-        .bind(job_id)
-        .fetch_optional(&state.db)
-        .await?
-        .ok_or_else(|| AppError::NotFound(format!("Render job {job_id} not found")))?;
+    let job = sqlx::query_as!(
+        RenderJobRow,
+        "SELECT * FROM render_jobs WHERE id = $1",
+        job_id
+    )
+    .fetch_optional(&state.db)
+    .await?
+    .ok_or_else(|| AppError::NotFound(format!("Render job {job_id} not found")))?;
 
     // Only serve PDF if job is done
     if job.status != "done" {
@@ -184,12 +178,13 @@ pub async fn handle_get_pdf(
     }
 
     // Fetch S3 key from resume
-    let s3_pdf_key: Option<String> =
-        sqlx::query_scalar("SELECT s3_pdf_key FROM resumes WHERE id = $1")
-            .bind(job.resume_id)
-            .fetch_optional(&state.db)
-            .await?
-            .flatten();
+    let s3_pdf_key: Option<String> = sqlx::query_scalar!(
+        "SELECT s3_pdf_key FROM resumes WHERE id = $1 AND deleted_at IS NULL",
+        job.resume_id
+    )
+    .fetch_optional(&state.db)
+    .await?
+    .flatten();
 
     let s3_key = s3_pdf_key.ok_or_else(|| {
         AppError::Internal(anyhow::anyhow!(
@@ -199,22 +194,12 @@ pub async fn handle_get_pdf(
         ))
     })?;
 
-    // Fetch PDF from S3
-    let s3_response = state
-        .s3
-        .get_object()
-        .bucket(&state.config.s3_bucket)
-        .key(&s3_key)
-        .send()
-        .await
-        .map_err(|e| AppError::S3(format!("Failed to fetch PDF from S3: {e}")))?;
-
-    let bytes = s3_response
-        .body
-        .collect()
+    // Fetch PDF from blob storage
+    let bytes = state
+        .blobs
+        .get(&s3_key)
         .await
-        .map_err(|e| AppError::S3(format!("Failed to read PDF stream from S3: {e}")))?
-        .into_bytes();
+        .map_err(|e| AppError::S3(format!("Failed to fetch PDF: {e}")))?;
 
     let content_length = bytes.len().to_string();
 
@@ -226,10 +211,128 @@ pub async fn handle_get_pdf(
             "Content-Disposition",
             format!("attachment; filename=\"resume-{}.pdf\"", job.resume_id),
         )
-        .body(Body::from(bytes.to_vec()))
+        .body(Body::from(bytes))
         .map_err(|e| AppError::Internal(anyhow::anyhow!("Failed to build PDF response: {e}")))
 }
 
+/// GET /api/v1/render/:job_id/line-map
+///
+/// Returns where each bullet landed in the job's PDF (see `render::line_map`).
+/// Returns 409 Conflict if the job is not yet done.
+pub async fn handle_render_line_map(
+    State(state): State<AppState>,
+    auth: AuthUser,
+    Path(job_id): Path<Uuid>,
+) -> Result<Json<RenderLineMapResponse>, AppError> {
+    auth.authorize_view(&state.db, Resource::RenderJob, job_id)
+        .await?;
+    let job = sqlx::query_as!(
+        RenderJobRow,
+        "SELECT * FROM render_jobs WHERE id = $1",
+        job_id
+    )
+    .fetch_optional(&state.db)
+    .await?
+    .ok_or_else(|| AppError::NotFound(format!("Render job {job_id} not found")))?;
+
+    if job.status != "done" {
+        return Err(AppError::Conflict(format!(
+            "Render job {job_id} is not complete (status={})",
+            job.status
+        )));
+    }
+    let bullets: Vec<BulletPlacement> = job
+        .line_map
+        .map(serde_json::from_value)
+        .transpose()
+        .map_err(|e| AppError::Internal(anyhow::anyhow!("Stored line map is invalid: {e}")))?
+        .unwrap_or_default();
+
+    Ok(Json(RenderLineMapResponse {
+        job_id,
+        resume_id: job.resume_id,
+        bullets,
+    }))
+}
+
+/// GET /api/v1/resumes/:id/ats-check
+///
+/// Reads the resume's latest rendered PDF back as plain text and reports the JD
+/// keywords, section headings and bullets that did not survive (see `ats_check`).
+/// Keywords come from the JD parse stored at generation time; resumes without one
+/// are checked for sections and bullets only.
+///
+/// Returns 409 Conflict if the resume has not been rendered, and 422 if no text can
+/// be extracted from its PDF.
+pub async fn handle_ats_check(
+    State(state): State<AppState>,
+    auth: AuthUser,
+    Path(resume_id): Path<Uuid>,
+) -> Result<Json<AtsCheckResponse>, AppError> {
+    auth.authorize_view(&state.db, Resource::Resume, resume_id)
+        .await?;
+    let resume = sqlx::query_as!(
+        ResumeRow,
+        "SELECT * FROM resumes WHERE id = $1 AND deleted_at IS NULL",
+        resume_id
+    )
+    .fetch_optional(&state.db)
+    .await?
+    .ok_or_else(|| AppError::NotFound(format!("Resume {resume_id} not found")))?;
+
+    let s3_key = resume.s3_pdf_key.ok_or_else(|| {
+        AppError::Conflict(format!("Resume {resume_id} has not been rendered yet"))
+    })?;
+    let bytes = state
+        .blobs
+        .get(&s3_key)
+        .await
+        .map_err(|e| AppError::S3(format!("Failed to fetch PDF: {e}")))?;
+
+    // pdf_extract is CPU-bound and can take a while on large documents.
+    let extracted_text =
+        tokio::task::spawn_blocking(move || pdf_extract::extract_text_from_mem(&bytes))
+            .await
+            .map_err(|e| AppError::Internal(anyhow::anyhow!("PDF extraction task failed: {e}")))?
+            .map_err(|e| {
+                AppError::UnprocessableEntity(format!("PDF text extraction failed: {e}"))
+            })?;
+
+    let bullets = sqlx::query_as!(
+        ResumeBulletRow,
+        "SELECT * FROM resume_bullets WHERE resume_id = $1 ORDER BY position, id",
+        resume_id
+    )
+    .fetch_all(&state.db)
+    .await?;
+    let parsed_jd = resume
+        .jd_parsed
+        .and_then(|v| serde_json::from_value::<ParsedJD>(v).ok());
+
+    Ok(Json(ats_check::check(
+        resume_id,
+        extracted_text,
+        parsed_jd.as_ref(),
+        &bullets,
+    )))
+}
+
+/// Inserts the job row and queues a `RenderJob` for it.
+async fn queue_render_job(state: &AppState, job_id: Uuid, resume_id: Uuid) -> Result<(), AppError> {
+    sqlx::query!(
+        "INSERT INTO render_jobs (id, resume_id, status) VALUES ($1, $2, 'queued')",
+        job_id,
+        resume_id
+    )
+    .execute(&state.db)
+    .await?;
+
+    jobs::enqueue(&state.redis, &RenderJob { job_id })
+        .await
+        .map_err(|e| AppError::Internal(anyhow::anyhow!("Redis enqueue failed: {e}")))?;
+    Ok(())
+}
+
 // ────────────────────────────────────────────────────────────────────────────
 // Tests
 // ────────────────────────────────────────────────────────────────────────────
@@ -0,0 +1,277 @@
+//! Bullet line map — where each bullet landed in the rendered PDF.
+//!
+//! Before compiling, `mark_bullets` puts a `\templarmark` at the start and end of every
+//! resume bullet in the LaTeX. Each mark is a pdfTeX `\pdfsavepos` whose position is
+//! written to `document.lines` at shipout, when the page and baseline are final:
+//!
+//!   `<bullet index> <s|e> <page> <baseline y, sp from page bottom> <baselineskip> <page height>`
+//!
+//! `parse_line_marks` turns those into a `BulletPlacement` per bullet: page, first line
+//! and line count. The front end uses it to map a click on the PDF back to a bullet;
+//! the worker compares each line count with the simulator's prediction.
+//!
+//! Bullets are found by their escaped text on an `\item` line, which is how both
+//! `templates::build_sections_latex` and the minimal document print them. A template
+//! that does not print the resume's bullets gets no marks and an empty map. The marks
+//! are only in the compiled source — `resumes.latex_source` stores the clean document.
+
+use std::collections::HashMap;
+
+use crate::models::resume::ResumeBulletRow;
+use crate::render::templates::escape_latex;
+use crate::render::types::RenderParams;
+
+pub use templar_types::render::BulletPlacement;
+
+/// File the marks are written to, next to `document.tex`.
+pub const LINE_MARKS_FILE: &str = "document.lines";
+
+/// Points per inch in TeX (`geometry` margins are TeX inches).
+const PT_PER_IN: f32 = 72.27;
+
+/// Scaled points per point.
+const SP_PER_PT: f32 = 65536.0;
+
+/// Defines `\templarmark{<index>}{<s|e>}`. Inserted just before `\begin{document}`.
+/// `\leavevmode` matters: after `\item` TeX is still in vertical mode, and a position
+/// saved there is above the first line's baseline.
+const MARK_PREAMBLE: &str = r"% Bullet position marks for the render line map.
+\newwrite\templarlines
+\immediate\openout\templarlines=\jobname.lines
+\newcommand{\templarmark}[2]{\leavevmode\pdfsavepos\write\templarlines{#1 #2 \arabic{page} \the\pdflastypos\space\the\dimexpr\baselineskip\relax\space\the\pdfpageheight}}
+";
+
+// ────────────────────────────────────────────────────────────────────────────
+// Marking
+// ────────────────────────────────────────────────────────────────────────────
+
+/// Returns `latex` with a start and end mark around each of `bullets`, identified by
+/// its index in `bullets`. Bullets not found on an `\item` line are left unmarked;
+/// a document without `\begin{document}` is returned unchanged.
+pub fn mark_bullets(latex: &str, bullets: &[ResumeBulletRow]) -> String {
+    let Some(body_start) = latex.find(r"\begin{document}") else {
+        return latex.to_string();
+    };
+    let (preamble, body) = latex.split_at(body_start);
+    let mut body = body.to_string();
+
+    for (index, bullet) in bullets.iter().enumerate() {
+        let item = format!("\\item {}\n", escape_latex(&bullet.bullet_text));
+        // A marked item no longer matches, so equal texts mark successive items.
+        let Some(at) = body.find(&item) else {
+            continue;
+        };
+        let text_start = at + r"\item ".len();
+        let text_end = at + item.len() - 1;
+        body.insert_str(text_end, &format!("\\templarmark{{{index}}}{{e}}"));
+        body.insert_str(text_start, &format!("\\templarmark{{{index}}}{{s}}"));
+    }
+
+    format!("{preamble}{MARK_PREAMBLE}{body}")
+}
+
+// ────────────────────────────────────────────────────────────────────────────
+// Reading back
+// ────────────────────────────────────────────────────────────────────────────
+
+/// One line of the marks file.
+#[derive(Debug, Clone, Copy)]
+struct Mark {
+    page: u32,
+    /// Baseline, in points from the bottom of the page.
+    y_pt: f32,
+    baselineskip_pt: f32,
+    page_height_pt: f32,
+}
+
+/// Placements of the marked `bullets`, in `bullets` order, from the contents of
+/// `LINE_MARKS_FILE`. Bullets missing either mark are left out; malformed lines
+/// are skipped.
+pub fn parse_line_marks(
+    marks: &str,
+    bullets: &[ResumeBulletRow],
+    params: &RenderParams,
+) -> Vec<BulletPlacement> {
+    let mut starts: HashMap<usize, Mark> = HashMap::new();
+    let mut ends: HashMap<usize, Mark> = HashMap::new();
+    for line in marks.lines() {
+        let Some((index, kind, mark)) = parse_mark(line) else {
+            continue;
+        };
+        match kind {
+            "s" => starts.entry(index).or_insert(mark),
+            "e" => ends.entry(index).or_insert(mark),
+            _ => continue,
+        };
+    }
+
+    bullets
+        .iter()
+        .enumerate()
+        .filter_map(|(index, bullet)| {
+            let start = starts.get(&index)?;
+            let end = ends.get(&index)?;
+            let pitch = start.baselineskip_pt.max(1.0);
+            let text_top = start.page_height_pt - params.margin_top_in * PT_PER_IN;
+            let text_bottom = params.margin_bottom_in * PT_PER_IN;
+
+            let line_count = if end.page == start.page {
+                ((start.y_pt - end.y_pt) / pitch).round() as u32 + 1
+            } else {
+                let on_first = ((start.y_pt - text_bottom) / pitch).floor().max(0.0) as u32 + 1;
+                let on_last = ((text_top - end.y_pt) / pitch).floor().max(0.0) as u32 + 1;
+                on_first + on_last
+            };
+            Some(BulletPlacement {
+                bullet_id: bullet.id,
+                page: start.page,
+                first_line: ((text_top - start.y_pt) / pitch).floor().max(0.0) as u32 + 1,
+                line_count,
+                predicted_line_count: bullet.line_count.max(0) as u32,
+                top_pt: start.page_height_pt - start.y_pt,
+                end_page: end.page,
+                bottom_pt: end.page_height_pt - end.y_pt,
+            })
+        })
+        .collect()
+}
+
+fn parse_mark(line: &str) -> Option<(usize, &str, Mark)> {
+    let mut fields = line.split_whitespace();
+    let index = fields.next()?.parse().ok()?;
+    let kind = fields.next()?;
+    let page = fields.next()?.parse().ok()?;
+    let y_sp: f32 = fields.next()?.parse().ok()?;
+    let baselineskip_pt = parse_pt(fields.next()?)?;
+    let page_height_pt = parse_pt(fields.next()?)?;
+    Some((
+        index,
+        kind,
+        Mark {
+            page,
+            y_pt: y_sp / SP_PER_PT,
+            baselineskip_pt,
+            page_height_pt,
+        },
+    ))
+}
+
+/// Parses a TeX dimension as `\the` prints it, e.g. `13.6pt`.
+fn parse_pt(value: &str) -> Option<f32> {
+    value.strip_suffix("pt")?.parse().ok()
+}
+
+// ────────────────────────────────────────────────────────────────────────────
+// Tests
+// ────────────────────────────────────────────────────────────────────────────
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::layout::{FontFamily, PaperSize};
+    use uuid::Uuid;
+
+    fn bullet(text: &str, line_count: i16) -> ResumeBulletRow {
+        ResumeBulletRow {
+            id: Uuid::new_v4(),
+            resume_id: Uuid::nil(),
+            section: "experience".to_string(),
+            bullet_text: text.to_string(),
+            source_entry_id: Uuid::nil(),
+            grounding_score: 1.0,
+            is_user_edited: false,
+            line_count,
+            position: 0,
+            created_at: chrono::Utc::now(),
+            keyword_spans: serde_json::json!([]),
+        }
+    }
+
+    fn params() -> RenderParams {
+        RenderParams {
+            resume_id: Uuid::nil(),
+            font: FontFamily::Inter,
+            font_size_pt: 11.0,
+            paper_size: PaperSize::Letter,
+            margin_left_in: 1.0,
+            margin_right_in: 1.0,
+            margin_top_in: 1.0,
+            margin_bottom_in: 1.0,
+            sections: vec![],
+        }
+    }
+
+    /// A mark line as pdfTeX writes it, with `y_pt` measured from the page bottom.
+    fn mark(index: usize, kind: &str, page: u32, y_pt: f32) -> String {
+        format!(
+            "{index} {kind} {page} {} 13.6pt 795.0pt",
+            (y_pt * SP_PER_PT) as i64
+        )
+    }
+
+    #[test]
+    fn test_mark_bullets_wraps_items_and_adds_preamble() {
+        let latex = "\\documentclass{article}\n\\begin{document}\n\\begin{itemize}\n  \\item Cut costs 50\\%\n  \\item Cut costs 50\\%\n  \\item Static line\n\\end{itemize}\n\\end{document}\n";
+        let bullets = [bullet("Cut costs 50%", 1), bullet("Cut costs 50%", 1)];
+
+        let marked = mark_bullets(latex, &bullets);
+
+        assert!(marked.contains("\\newwrite\\templarlines\n"));
+        assert!(marked.find("\\newwrite").unwrap() < marked.find("\\begin{document}").unwrap());
+        assert!(marked.contains("\\item \\templarmark{0}{s}Cut costs 50\\%\\templarmark{0}{e}\n"));
+        assert!(marked.contains("\\item \\templarmark{1}{s}Cut costs 50\\%\\templarmark{1}{e}\n"));
+        assert!(marked.contains("  \\item Static line\n"));
+    }
+
+    #[test]
+    fn test_mark_bullets_leaves_documents_without_body_alone() {
+        let latex = "\\item Cut costs\n";
+        assert_eq!(mark_bullets(latex, &[bullet("Cut costs", 1)]), latex);
+    }
+
+    #[test]
+    fn test_parse_line_marks_on_one_page() {
+        let bullets = [bullet("First", 1), bullet("Second", 1)];
+        // Text area top is 795 - 72.27 = 722.73pt from the bottom.
+        let marks = [
+            mark(0, "s", 1, 712.73),
+            mark(0, "e", 1, 712.73),
+            mark(1, "s", 1, 699.13),
+            mark(1, "e", 1, 685.53),
+            "garbage line".to_string(),
+        ]
+        .join("\n");
+
+        let placements = parse_line_marks(&marks, &bullets, &params());
+
+        assert_eq!(placements.len(), 2);
+        assert_eq!(placements[0].bullet_id, bullets[0].id);
+        assert_eq!(placements[0].first_line, 1);
+        assert_eq!(placements[0].line_count, 1);
+        assert_eq!(placements[1].first_line, 2);
+        assert_eq!(placements[1].line_count, 2);
+        assert_eq!(placements[1].predicted_line_count, 1);
+        assert!((placements[1].top_pt - 95.87).abs() < 0.01);
+        assert_eq!(placements[1].end_page, 1);
+    }
+
+    #[test]
+    fn test_parse_line_marks_across_a_page_break() {
+        let bullets = [bullet("Long", 2)];
+        // Last line of page 1 (just above the bottom margin), first line of page 2.
+        let marks = [mark(0, "s", 1, 80.0), mark(0, "e", 2, 712.73)].join("\n");
+
+        let placements = parse_line_marks(&marks, &bullets, &params());
+
+        assert_eq!(placements[0].page, 1);
+        assert_eq!(placements[0].end_page, 2);
+        assert_eq!(placements[0].line_count, 2);
+    }
+
+    #[test]
+    fn test_parse_line_marks_skips_unmarked_bullets() {
+        let bullets = [bullet("Only start", 1)];
+        let placements = parse_line_marks(&mark(0, "s", 1, 700.0), &bullets, &params());
+        assert!(placements.is_empty());
+    }
+}
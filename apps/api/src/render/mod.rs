@@ -1,9 +1,11 @@
 // Phase 4: Render Service
-// Implements: pdflatex (TeX Live) LaTeX → PDF pipeline via Redis async job queue.
+// Implements: pdflatex (TeX Live) LaTeX → PDF pipeline via the Redis job queue (crate::jobs).
 // NEVER block the request thread on pdflatex compilation.
 #![allow(unused_imports)]
 
+pub mod ats_check;
 pub mod handlers;
+pub mod line_map;
 pub mod pdflatex;
 pub mod templates;
 pub mod types;
@@ -11,4 +13,4 @@ pub mod worker;
 
 // Re-exports for Phase 5 (grounding) and Phase 6 (frontend) consumers.
 pub use types::{PdflatexResult, RenderError, RenderParams, RenderStatus, ResumeSection};
-pub use worker::RENDER_QUEUE_KEY;
+pub use worker::RenderJob;
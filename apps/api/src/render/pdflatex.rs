@@ -16,6 +16,7 @@ use tokio::process::Command;
 use tokio::time::{timeout, Duration};
 use uuid::Uuid;
 
+use crate::render::line_map::LINE_MARKS_FILE;
 use crate::render::types::{PdflatexResult, RenderError};
 
 /// Compiles a LaTeX source string to PDF bytes using pdflatex.
@@ -125,6 +126,11 @@ pub async fn compile_latex(
         let _ = handle.read_to_end(&mut stderr_bytes).await;
     }
 
+    // Bullet position marks written by documents instrumented with `line_map`.
+    let line_marks = tokio::fs::read_to_string(tmp_dir.path().join(LINE_MARKS_FILE))
+        .await
+        .unwrap_or_default();
+
     // Read the .log file BEFORE TempDir drops — it contains the full LaTeX transcript,
     // including package errors, missing font messages, and undefined control sequences
     // that are the root cause of most pdflatex failures.
@@ -223,11 +229,20 @@ pub async fn compile_latex(
     // TempDir dropped here → all .tex, .pdf, .log, .aux files cleaned up automatically
     Ok(PdflatexResult {
         pdf_bytes,
+        page_count: parse_page_count(&stdout_text),
+        line_marks,
         stderr: warnings,
         duration_ms,
     })
 }
 
+/// Extracts N from pdflatex's `Output written on document.pdf (N pages, M bytes).`
+fn parse_page_count(stdout: &str) -> Option<u32> {
+    let line = stdout.lines().find(|l| l.contains("Output written on"))?;
+    let open = line.rfind('(')?;
+    line[open + 1..].split_whitespace().next()?.parse().ok()
+}
+
 /// Checks that the `pdflatex` binary is available on PATH.
 ///
 /// Called at server startup — fails fast so the operator knows to install TeX Live.
@@ -312,4 +327,11 @@ Hello, Templar.
             "output must be a valid PDF"
         );
     }
+
+    #[test]
+    fn test_parse_page_count_from_pdflatex_stdout() {
+        let stdout = "This is pdfTeX\n(./document.tex)\nOutput written on /tmp/x/document.pdf (2 pages, 31337 bytes).\nTranscript written on document.log.";
+        assert_eq!(parse_page_count(stdout), Some(2));
+        assert_eq!(parse_page_count("No pages of output."), None);
+    }
 }
@@ -10,7 +10,10 @@
 //! `escape_latex` is a single-pass character scanner — never use chained
 //! `.replace()` which would double-escape backslashes.
 
-use crate::layout::FontFamily;
+use serde::Serialize;
+
+use crate::layout::font_metrics::{DEFAULT_FONT_SIZE_PT, FONT_SIZE_STEPS_PT};
+use crate::layout::{FontFamily, MarginPreset, PaperSize};
 use crate::render::types::{RenderParams, ResumeSection};
 
 // ────────────────────────────────────────────────────────────────────────────
@@ -65,16 +68,123 @@ pub fn setmainfont_declaration(font: &FontFamily) -> String {
     }
 }
 
+// ────────────────────────────────────────────────────────────────────────────
+// Style catalog (template gallery)
+// ────────────────────────────────────────────────────────────────────────────
+
+/// How reliably a naive ATS parser reads a style's PDF back (see `render::ats_check`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum AtsSafety {
+    /// Plain text extraction returns the resume as written.
+    High,
+    /// Reads back, but some glyphs or headings need the ATS check to confirm.
+    Good,
+}
+
+/// Page settings a style can be rendered with.
+#[derive(Debug, Clone, Serialize)]
+pub struct PageOptions {
+    pub paper_sizes: &'static [PaperSize],
+    pub margin_presets: &'static [MarginPreset],
+    /// The sizes the page-fill fallback may step through.
+    pub font_sizes_pt: &'static [f32],
+    pub default_font_size_pt: f32,
+    /// Whether the renderer can set the style in two columns.
+    pub two_column: bool,
+}
+
+/// One of the five render styles, as `GET /api/v1/templates` lists it.
+#[derive(Debug, Clone, Serialize)]
+pub struct TemplateStyle {
+    /// Value stored in `resumes.font_family`.
+    pub font_family: &'static str,
+    pub name: &'static str,
+    pub font_name: &'static str,
+    pub description: &'static str,
+    /// Who the style is written for.
+    pub target_persona: &'static str,
+    /// Blob-store key of the sample preview PNG.
+    pub preview_key: String,
+    pub ats_safety: AtsSafety,
+    pub ats_notes: &'static str,
+    pub page_options: PageOptions,
+}
+
+const SINGLE_COLUMN_PAGES: PageOptions = PageOptions {
+    paper_sizes: &[PaperSize::Letter, PaperSize::A4],
+    margin_presets: &[
+        MarginPreset::Compact,
+        MarginPreset::Standard,
+        MarginPreset::Airy,
+    ],
+    font_sizes_pt: &FONT_SIZE_STEPS_PT,
+    default_font_size_pt: DEFAULT_FONT_SIZE_PT,
+    two_column: false,
+};
+
+/// Every render style, in `FontFamily::ALL` order.
+pub fn style_catalog() -> Vec<TemplateStyle> {
+    FontFamily::ALL.iter().map(template_style).collect()
+}
+
+fn template_style(font: &FontFamily) -> TemplateStyle {
+    let (description, target_persona, ats_safety, ats_notes) = match font {
+        FontFamily::Inter => (
+            "Clean and dense: sans-serif headers over a full-width rule, tight bullet spacing.",
+            "Software engineers with more to fit than space to fit it in",
+            AtsSafety::High,
+            "Sans-serif text with standard headings reads back unchanged.",
+        ),
+        FontFamily::EbGaramond => (
+            "Formal and publication-ready: small-caps headers in an old-style serif.",
+            "Researchers and academics with publications to list",
+            AtsSafety::Good,
+            "Old-style ligatures (fi, fl, ff) can merge letters in naive text extraction.",
+        ),
+        FontFamily::Lato => (
+            "Spacious and achievement-forward: generous spacing and a gray rule under headers.",
+            "Operators and managers leading with outcomes",
+            AtsSafety::High,
+            "Sans-serif text with standard headings reads back unchanged.",
+        ),
+        FontFamily::Oswald => (
+            "Bold and condensed: large display headers without rules.",
+            "Founders and startup generalists",
+            AtsSafety::Good,
+            "The condensed display face is embedded from a variable font; check the ATS read-back before sending.",
+        ),
+        FontFamily::ComputerModern => (
+            "Classic and plain: the traditional TeX face with ruled headers.",
+            "Applicants to ATS-heavy employers and conservative industries",
+            AtsSafety::High,
+            "Built to be parsed: plain headings and the standard TeX font.",
+        ),
+    };
+    TemplateStyle {
+        font_family: font.as_str(),
+        name: template_name(font),
+        font_name: fontspec_name(font),
+        description,
+        target_persona,
+        preview_key: format!("thumbnails/styles/{}.png", font.as_str()),
+        ats_safety,
+        ats_notes,
+        page_options: SINGLE_COLUMN_PAGES,
+    }
+}
+
 // ────────────────────────────────────────────────────────────────────────────
 // Section ordering
 // ────────────────────────────────────────────────────────────────────────────
 
-/// Canonical section order: Experience → Education → Projects → Skills →
+/// Canonical section order: Summary → Experience → Education → Projects → Skills →
 /// Publications → Other.
 ///
 /// Sections not in the priority list are appended in their original order.
 pub fn order_sections(sections: &[ResumeSection]) -> Vec<&ResumeSection> {
     const PRIORITY: &[&str] = &[
+        "summary",
         "experience",
         "education",
         "projects",
@@ -211,7 +321,7 @@ pub fn escape_latex(text: &str) -> String {
 ///
 /// Structure:
 /// ```text
-/// \documentclass[<pt>]{article}
+/// \documentclass[<paper>,<pt>]{article}
 /// \usepackage[<margins>]{geometry}
 /// \usepackage{fontspec}
 /// \setmainfont{<fontspec_name>}
@@ -243,27 +353,172 @@ pub fn build_latex_document(params: &RenderParams) -> String {
     }
 
     format!(
-        r#"\documentclass[{font_size_pt}pt]{{article}}
-\usepackage[left={margin_left:.2}in, right={margin_right:.2}in, top=0.75in, bottom=0.75in]{{geometry}}
+        r#"\documentclass[{paper},{font_size_pt}pt]{{article}}
+\usepackage[{geometry}]{{geometry}}
 \usepackage{{fontspec}}
 \setmainfont{font_decl}
 \usepackage{{microtype,enumitem,titlesec,xcolor,tabularx,parskip}}
 \usepackage[hidelinks]{{hyperref}}
 {preamble}
 \begin{{document}}
-\pagestyle{{empty}}
+{font_size_command}\pagestyle{{empty}}
 % Header placeholder (Phase 6 will wire real user data)
 {body}\end{{document}}
 "#,
-        font_size_pt = params.font_size_pt,
-        margin_left = params.margin_left_in,
-        margin_right = params.margin_right_in,
+        paper = params.paper_size.latex_option(),
+        font_size_pt = latex_class_size(params.font_size_pt),
+        font_size_command = font_size_command(params.font_size_pt)
+            .map(|c| format!("{c}\n"))
+            .unwrap_or_default(),
+        geometry = geometry_options(params),
         font_decl = font_decl,
         preamble = preamble,
         body = body,
     )
 }
 
+/// Forces the paper size in a LaTeX document's `\documentclass` options.
+///
+/// File-based templates hard-code their paper option; this swaps any existing
+/// `letterpaper`/`a4paper` for the requested one, or adds it when the template names
+/// none. Documents without a `\documentclass[...]` line are returned unchanged.
+pub fn set_document_paper_size(latex: &str, paper: PaperSize) -> String {
+    map_class_options(latex, |options| {
+        let mut options: Vec<String> = options
+            .into_iter()
+            .filter(|o| !matches!(o.as_str(), "letterpaper" | "a4paper"))
+            .collect();
+        options.insert(0, paper.latex_option().to_string());
+        options
+    })
+}
+
+/// Forces the body font size of a LaTeX document.
+///
+/// The `\documentclass` size option becomes `latex_class_size(font_size_pt)`; a
+/// half-point size additionally gets `\fontsize{..}{..}\selectfont` right after
+/// `\begin{document}`. Documents without a `\documentclass[...]` line are returned unchanged.
+pub fn set_document_font_size(latex: &str, font_size_pt: f32) -> String {
+    let class_size = format!("{}pt", latex_class_size(font_size_pt));
+    let mut replaced = false;
+    let latex = map_class_options(latex, |options| {
+        let mut options: Vec<String> = options.into_iter().filter(|o| !is_size_option(o)).collect();
+        let at = options
+            .iter()
+            .position(|o| matches!(o.as_str(), "letterpaper" | "a4paper"))
+            .map_or(0, |i| i + 1);
+        options.insert(at, class_size.clone());
+        replaced = true;
+        options
+    });
+    if !replaced {
+        return latex;
+    }
+    match font_size_command(font_size_pt) {
+        Some(command) => latex.replacen(
+            r"\begin{document}",
+            &format!("\\begin{{document}}\n{command}"),
+            1,
+        ),
+        None => latex,
+    }
+}
+
+/// Forces the page margins of a LaTeX document to the ones in `params`.
+///
+/// File-based templates load `geometry` with their own margins; this replaces that
+/// option list (or adds one to a bare `\usepackage{geometry}`). A template that does
+/// not load `geometry` gets it right after `\documentclass`. Documents with neither
+/// are returned unchanged.
+pub fn set_document_margins(latex: &str, params: &RenderParams) -> String {
+    let package = format!("\\usepackage[{}]{{geometry}}", geometry_options(params));
+
+    if let Some(end) = latex.find("]{geometry}") {
+        if let Some(start) = latex[..end].rfind(r"\usepackage[") {
+            let end = end + "]{geometry}".len();
+            return format!("{}{package}{}", &latex[..start], &latex[end..]);
+        }
+    }
+    if latex.contains(r"\usepackage{geometry}") {
+        return latex.replacen(r"\usepackage{geometry}", &package, 1);
+    }
+
+    let Some(class_start) = latex.find(r"\documentclass") else {
+        return latex.to_string();
+    };
+    let line_end = latex[class_start..]
+        .find('\n')
+        .map_or(latex.len(), |i| class_start + i);
+    format!("{}\n{package}{}", &latex[..line_end], &latex[line_end..])
+}
+
+/// `geometry` options for the four margins in `params`.
+fn geometry_options(params: &RenderParams) -> String {
+    format!(
+        "left={:.2}in, right={:.2}in, top={:.2}in, bottom={:.2}in",
+        params.margin_left_in,
+        params.margin_right_in,
+        params.margin_top_in,
+        params.margin_bottom_in
+    )
+}
+
+/// The `article` class size option (10, 11 or 12pt) a body size is built on.
+/// Half-point sizes round down and are finished by [`font_size_command`].
+pub fn latex_class_size(font_size_pt: f32) -> u8 {
+    if font_size_pt < 11.0 {
+        10
+    } else if font_size_pt < 12.0 {
+        11
+    } else {
+        12
+    }
+}
+
+/// `\fontsize{pt}{baselineskip}\selectfont` for sizes the class option cannot express.
+/// The baseline skip matches the layout model's 1.3 line pitch.
+pub fn font_size_command(font_size_pt: f32) -> Option<String> {
+    if (font_size_pt - latex_class_size(font_size_pt) as f32).abs() < 0.01 {
+        return None;
+    }
+    Some(format!(
+        "\\fontsize{{{font_size_pt}}}{{{:.2}}}\\selectfont",
+        font_size_pt * 1.3
+    ))
+}
+
+fn is_size_option(option: &str) -> bool {
+    option
+        .strip_suffix("pt")
+        .is_some_and(|n| !n.is_empty() && n.chars().all(|c| c.is_ascii_digit()))
+}
+
+/// Rewrites the option list of the first `\documentclass[...]`. Unchanged if absent.
+fn map_class_options(latex: &str, f: impl FnOnce(Vec<String>) -> Vec<String>) -> String {
+    let Some(start) = latex.find(r"\documentclass[") else {
+        return latex.to_string();
+    };
+    let opts_start = start + r"\documentclass[".len();
+    let Some(opts_len) = latex[opts_start..].find(']') else {
+        return latex.to_string();
+    };
+    let opts_end = opts_start + opts_len;
+
+    let options: Vec<String> = latex[opts_start..opts_end]
+        .split(',')
+        .map(str::trim)
+        .filter(|o| !o.is_empty())
+        .map(str::to_string)
+        .collect();
+
+    format!(
+        "{}{}{}",
+        &latex[..opts_start],
+        f(options).join(","),
+        &latex[opts_end..]
+    )
+}
+
 // ────────────────────────────────────────────────────────────────────────────
 // Tests
 // ────────────────────────────────────────────────────────────────────────────
@@ -277,9 +532,12 @@ mod tests {
         RenderParams {
             resume_id: uuid::Uuid::new_v4(),
             font,
-            font_size_pt: 11,
+            font_size_pt: 11.0,
+            paper_size: PaperSize::Letter,
             margin_left_in: 1.0,
             margin_right_in: 1.0,
+            margin_top_in: 1.0,
+            margin_bottom_in: 1.0,
             sections: vec![ResumeSection {
                 name: "Experience".to_string(),
                 bullets: vec![
@@ -429,6 +687,27 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_style_catalog_lists_every_font_once() {
+        let styles = style_catalog();
+
+        assert_eq!(styles.len(), FontFamily::ALL.len());
+        for (style, font) in styles.iter().zip(FontFamily::ALL) {
+            assert_eq!(FontFamily::parse(style.font_family), Some(font));
+            assert_eq!(style.name, template_name(&font));
+        }
+        let classic = serde_json::to_value(&styles[4]).unwrap();
+        assert_eq!(classic["ats_safety"], "high");
+        assert_eq!(
+            classic["preview_key"],
+            "thumbnails/styles/computer_modern.png"
+        );
+        assert_eq!(
+            classic["page_options"]["paper_sizes"],
+            serde_json::json!(["letter", "a4"])
+        );
+    }
+
     #[test]
     fn test_template_name_and_fontspec_name_distinct_for_all_five() {
         for font in [
@@ -515,4 +794,99 @@ mod tests {
             "Oswald document must use explicit font path for xdvipdfmx compatibility"
         );
     }
+
+    #[test]
+    fn test_build_latex_uses_params_paper_size_and_margins() {
+        let mut params = make_params(FontFamily::Inter);
+        let doc = build_latex_document(&params);
+        assert!(doc.contains(r"\documentclass[letterpaper,11pt]{article}"));
+        assert!(doc.contains("top=1.00in, bottom=1.00in"));
+
+        params.paper_size = PaperSize::A4;
+        let doc = build_latex_document(&params);
+        assert!(doc.contains(r"\documentclass[a4paper,11pt]{article}"));
+    }
+
+    #[test]
+    fn test_set_document_paper_size_replaces_existing_option() {
+        let src = "\\documentclass[11pt,a4paper]{article}\n\\begin{document}";
+        assert_eq!(
+            set_document_paper_size(src, PaperSize::Letter),
+            "\\documentclass[letterpaper,11pt]{article}\n\\begin{document}"
+        );
+    }
+
+    #[test]
+    fn test_set_document_paper_size_adds_missing_option() {
+        let src = r"\documentclass[11pt]{article}";
+        assert_eq!(
+            set_document_paper_size(src, PaperSize::A4),
+            r"\documentclass[a4paper,11pt]{article}"
+        );
+        assert_eq!(
+            set_document_paper_size(r"\documentclass{article}", PaperSize::A4),
+            r"\documentclass{article}"
+        );
+    }
+
+    #[test]
+    fn test_set_document_margins_replaces_template_geometry() {
+        let mut params = make_params(FontFamily::Inter);
+        params.margin_left_in = 0.7;
+        params.margin_right_in = 0.7;
+        params.margin_top_in = 0.7;
+        params.margin_bottom_in = 0.7;
+        let src = "\\documentclass[11pt,a4paper]{article}\n\\usepackage[margin=0.5in]{geometry}\n\\usepackage{enumitem}";
+        assert_eq!(
+            set_document_margins(src, &params),
+            "\\documentclass[11pt,a4paper]{article}\n\\usepackage[left=0.70in, right=0.70in, top=0.70in, bottom=0.70in]{geometry}\n\\usepackage{enumitem}"
+        );
+    }
+
+    #[test]
+    fn test_set_document_margins_adds_missing_geometry() {
+        let params = make_params(FontFamily::Inter);
+        let bare = "\\documentclass{article}\n\\usepackage{geometry}\n";
+        assert!(set_document_margins(bare, &params).contains(
+            r"\usepackage[left=1.00in, right=1.00in, top=1.00in, bottom=1.00in]{geometry}"
+        ));
+
+        let without = "\\documentclass[11pt]{article}\n\\begin{document}";
+        assert_eq!(
+            set_document_margins(without, &params),
+            "\\documentclass[11pt]{article}\n\\usepackage[left=1.00in, right=1.00in, top=1.00in, bottom=1.00in]{geometry}\n\\begin{document}"
+        );
+        assert_eq!(set_document_margins("plain text", &params), "plain text");
+    }
+
+    #[test]
+    fn test_half_point_font_size_uses_class_size_plus_fontsize() {
+        let mut params = make_params(FontFamily::Inter);
+        params.font_size_pt = 10.5;
+        let doc = build_latex_document(&params);
+        assert!(doc.contains(r"\documentclass[letterpaper,10pt]{article}"));
+        assert!(doc.contains(r"\fontsize{10.5}{13.65}\selectfont"));
+
+        params.font_size_pt = 12.0;
+        let doc = build_latex_document(&params);
+        assert!(doc.contains(r"\documentclass[letterpaper,12pt]{article}"));
+        assert!(!doc.contains(r"\fontsize"));
+    }
+
+    #[test]
+    fn test_set_document_font_size_rewrites_class_option() {
+        let src = "\\documentclass[letterpaper,11pt]{article}\n\\begin{document}\nBody";
+        assert_eq!(
+            set_document_font_size(src, 10.0),
+            "\\documentclass[letterpaper,10pt]{article}\n\\begin{document}\nBody"
+        );
+        assert_eq!(
+            set_document_font_size(src, 11.5),
+            "\\documentclass[letterpaper,11pt]{article}\n\\begin{document}\n\\fontsize{11.5}{14.95}\\selectfont\nBody"
+        );
+        assert_eq!(
+            set_document_font_size(r"\documentclass{article}", 10.0),
+            r"\documentclass{article}"
+        );
+    }
 }
@@ -11,7 +11,7 @@ use serde::{Deserialize, Serialize};
 use thiserror::Error;
 use uuid::Uuid;
 
-use crate::layout::FontFamily;
+use crate::layout::{FontFamily, PaperSize};
 
 // ────────────────────────────────────────────────────────────────────────────
 // Render job lifecycle
@@ -66,9 +66,12 @@ pub struct RenderJobPayload {
 pub struct RenderParams {
     pub resume_id: Uuid,
     pub font: FontFamily,
-    pub font_size_pt: u8,
+    pub font_size_pt: f32,
+    pub paper_size: PaperSize,
     pub margin_left_in: f32,
     pub margin_right_in: f32,
+    pub margin_top_in: f32,
+    pub margin_bottom_in: f32,
     pub sections: Vec<ResumeSection>,
 }
 
@@ -92,6 +95,11 @@ pub struct PdflatexResult {
     pub stderr: String,
     /// Wall-clock time for the compilation.
     pub duration_ms: u64,
+    /// Page count from pdflatex's "Output written on ..." line, when present.
+    pub page_count: Option<u32>,
+    /// Contents of the `.lines` file the line-map marks write (see `line_map`); empty
+    /// when the document carries no marks.
+    pub line_marks: String,
 }
 
 // ────────────────────────────────────────────────────────────────────────────
@@ -1,156 +1,97 @@
 #![allow(dead_code)]
-//! Background render worker: Redis BRPOP → DB fetch → LaTeX → pdflatex → S3 upload.
+//! Background render worker: `RenderJob` → DB fetch → LaTeX → pdflatex → S3 upload.
 //!
-//! Queue pattern: LPUSH on enqueue, BRPOP with 5-second timeout on dequeue.
-//! Redis key stores `job_id` as a UUID string; worker fetches all data from DB.
+//! Runs on the shared job queue (`crate::jobs`, queue `render`). The payload is the
+//! `render_jobs` row id; the worker fetches all data from DB.
 //!
-//! The worker NEVER panics on individual job failure — it logs the error and
-//! continues processing the next job.
+//! A failed render NEVER escapes the job — it is recorded on the job row and the
+//! worker continues processing the next job.
 
 use std::collections::HashMap;
 use std::sync::Arc;
 
-use aws_sdk_s3::primitives::ByteStream;
-use aws_sdk_s3::Client as S3Client;
-use redis::AsyncCommands;
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
 use sqlx::PgPool;
-use tokio::sync::Semaphore;
 use tracing::{error, info, warn};
 use uuid::Uuid;
 
-use crate::layout::{default_page_config, FontFamily};
+use crate::jobs::{Handler, Job, JobError};
+use crate::layout::font_metrics::DEFAULT_FONT_SIZE_PT;
+use crate::layout::{page_config_for_paper, FontFamily, MarginPreset, PaperSize};
+use crate::metrics::LAYOUT;
 use crate::models::resume::{ResumeBulletRow, ResumeRow};
+use crate::notifications::{Notifier, RenderOutcome};
+use crate::progress::{self, Stage};
+use crate::render::line_map::{mark_bullets, parse_line_marks};
 use crate::render::pdflatex::compile_latex;
-use crate::render::templates::build_latex_document;
+use crate::render::templates::{
+    build_latex_document, font_size_command, latex_class_size, set_document_font_size,
+    set_document_margins, set_document_paper_size,
+};
 use crate::render::types::{RenderError, RenderParams, ResumeSection};
+use crate::storage::BlobStore;
 use crate::templates::{ProfileData, SampleSection, TemplateCache};
 
-/// Redis list key used for the render job queue.
-pub const RENDER_QUEUE_KEY: &str = "render:jobs";
-
 // ────────────────────────────────────────────────────────────────────────────
-// Worker spawn
+// Job
 // ────────────────────────────────────────────────────────────────────────────
 
-/// Spawns the background render worker as a detached tokio task.
-///
-/// The worker processes render jobs from the Redis queue indefinitely.
-/// It receives clones of all needed resources (Redis, DB, S3) before
-/// `AppState` is moved into the Axum router.
-///
-/// `template_cache` is passed so the worker can look up file-based templates
-/// when `resume.template_id` is set. The Arc ensures the cache is shared without
-/// copying the HashMap — the worker holds a read lock only during template lookup.
-pub fn spawn_render_worker(
-    redis: redis::Client,
-    db: PgPool,
-    s3: S3Client,
-    s3_bucket: String,
-    template_cache: Arc<TemplateCache>,
-) {
-    tokio::spawn(async move {
-        worker_loop(redis, db, s3, s3_bucket, template_cache).await;
-    });
+/// Queue payload: the `render_jobs` row to process. Everything else is fetched from
+/// the DB when the job runs.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RenderJob {
+    pub job_id: Uuid,
 }
 
-// ────────────────────────────────────────────────────────────────────────────
-// Worker loop
-// ────────────────────────────────────────────────────────────────────────────
+impl Job for RenderJob {
+    const QUEUE: &'static str = "render";
+    /// pdflatex failures are deterministic, and `process_render_job` records every
+    /// failure on the job row itself, so a render is never retried.
+    const MAX_ATTEMPTS: u32 = 1;
+}
 
 /// How many render jobs can run in parallel.
 ///
 /// pdflatex is CPU + I/O bound (compile + S3 upload). On a typical container with
 /// 2 vCPUs, 4 concurrent jobs keeps both cores busy without starving the HTTP server.
 /// Increase this if containers have more CPU; decrease if you see OOM.
-const WORKER_CONCURRENCY: usize = 4;
+pub const WORKER_CONCURRENCY: usize = 4;
 
-/// Main worker loop — runs indefinitely, BRPOP-ing jobs from Redis.
-///
-/// Concurrency model: we allow up to WORKER_CONCURRENCY jobs to run in parallel.
-/// Each dequeued job is spawned as an independent tokio task. A semaphore provides
-/// back-pressure: the BRPOP loop blocks when all slots are occupied, so Redis doesn't
-/// accumulate jobs faster than we can process them.
+/// Runs render jobs. Holds clones of everything a job needs (Redis, DB, S3) taken
+/// before `AppState` is moved into the Axum router.
 ///
-/// Why a semaphore instead of a thread pool?
-/// pdflatex compilation is `async` (tokio::process::Command), so it fits naturally
-/// on the tokio runtime. spawn() lets jobs overlap their I/O-wait phases (S3 upload,
-/// DB queries) without blocking each other. The semaphore caps peak concurrency so
-/// we never overload the container.
-async fn worker_loop(
-    redis: redis::Client,
-    db: PgPool,
-    s3: S3Client,
-    s3_bucket: String,
-    template_cache: Arc<TemplateCache>,
-) {
-    info!("Render worker loop started (concurrency: {WORKER_CONCURRENCY})");
-
-    // Semaphore that limits concurrent render jobs. Arc so it can be cloned into
-    // each spawned task — the task holds the permit for its entire lifetime, and
-    // the permit is released (slot freed) when the task completes or panics.
-    let semaphore = Arc::new(Semaphore::new(WORKER_CONCURRENCY));
-
-    loop {
-        let mut conn = match redis.get_multiplexed_async_connection().await {
-            Ok(c) => c,
-            Err(e) => {
-                error!("Render worker: Redis connection failed: {e} — retrying in 5s");
-                tokio::time::sleep(tokio::time::Duration::from_secs(5)).await;
-                continue;
-            }
-        };
-
-        // Block here if all WORKER_CONCURRENCY slots are in use.
-        // This prevents dequeuing more work than we can handle — if 4 jobs are
-        // already compiling, we wait until one finishes before pulling the next.
-        // acquire_owned() gives us an OwnedSemaphorePermit that can be moved into the task.
-        let permit = Arc::clone(&semaphore)
-            .acquire_owned()
-            .await
-            .expect("semaphore closed — this never happens");
-
-        // BRPOP with 5.0-second timeout — returns None on timeout, Some((key, value)) on job
-        let result: Result<Option<(String, String)>, redis::RedisError> =
-            conn.brpop(RENDER_QUEUE_KEY, 5.0).await;
-
-        match result {
-            Ok(None) => {
-                // No job arrived within 5 seconds — drop the permit immediately so we
-                // don't hold a slot while the queue is empty, then loop.
-                drop(permit);
-            }
-            Ok(Some((_key, job_id_str))) => match Uuid::parse_str(&job_id_str) {
-                Ok(job_id) => {
-                    info!("Render worker: dequeued job {}", job_id);
-
-                    // Clone the shared resources so the spawned task owns its own handles.
-                    // These are all cheap reference-counted clones (Arc / connection pool).
-                    let (db2, s3_2, bucket2, tc2) = (
-                        db.clone(),
-                        s3.clone(),
-                        s3_bucket.clone(),
-                        Arc::clone(&template_cache),
-                    );
+/// `template_cache` is passed so the worker can look up file-based templates
+/// when `resume.template_id` is set. The Arc ensures the cache is shared without
+/// copying the HashMap — the worker holds a read lock only during template lookup.
+pub struct RenderWorker {
+    pub redis: redis::Client,
+    pub db: PgPool,
+    pub blobs: Arc<dyn BlobStore>,
+    pub template_cache: Arc<TemplateCache>,
+    /// Emails the owner once the job is done or failed.
+    pub notifier: Notifier,
+}
 
-                    // Spawn the job as an independent async task so it runs concurrently
-                    // with the next BRPOP. The permit is moved into the task and dropped
-                    // when process_render_job() returns, freeing the semaphore slot.
-                    tokio::spawn(async move {
-                        let _permit = permit; // Holds the slot for the lifetime of this job
-                        process_render_job(job_id, &db2, &s3_2, &bucket2, &tc2).await;
-                    });
-                }
-                Err(e) => {
-                    drop(permit); // Release slot — we're not starting a job
-                    error!("Render worker: invalid UUID in queue '{}': {e}", job_id_str);
-                }
-            },
-            Err(e) => {
-                drop(permit); // Release slot on error — no job to process
-                error!("Render worker: BRPOP error: {e} — reconnecting");
-                tokio::time::sleep(tokio::time::Duration::from_secs(1)).await;
-            }
-        }
+#[async_trait]
+impl Handler for RenderWorker {
+    type Job = RenderJob;
+
+    async fn run(&self, job: &RenderJob) -> Result<(), JobError> {
+        info!("Render worker: dequeued job {}", job.job_id);
+        progress::scoped(
+            &self.redis,
+            job.job_id,
+            process_render_job(
+                job.job_id,
+                &self.db,
+                &*self.blobs,
+                &self.template_cache,
+                &self.notifier,
+            ),
+        )
+        .await;
+        Ok(())
     }
 }
 
@@ -165,29 +106,32 @@ async fn worker_loop(
 /// 2. Fetch resume_id from render_jobs
 /// 3. Fetch resume row + bullets from DB (grouped by section)
 /// 4. Build LaTeX — file-based template OR legacy font-based path
-/// 5. Compile LaTeX via pdflatex (-halt-on-error; packages are on-disk TeX Live files)
+/// 5. Compile LaTeX via pdflatex (-halt-on-error; packages are on-disk TeX Live files),
+///    with each bullet's position marked for the line map
 /// 6. Upload PDF to S3 (key: pdfs/{resume_id}.pdf)
 /// 7. UPDATE resumes: s3_pdf_key + latex_source + status='rendered'
-/// 8. Mark job 'done'
+/// 8. Mark job 'done', storing the bullet line map
+/// 9. Notify the owner by email, if they asked to be (success or failure alike)
 ///
 /// This function is an outer wrapper that catches ALL errors (including those
 /// from the early resume_id lookup) and ensures the job is always marked with
 /// a terminal status ('done' or 'failed'). A job MUST NEVER be left at
 /// 'processing' — that would cause the frontend to poll forever.
 ///
-/// Semaphore note: the caller holds an OwnedSemaphorePermit for the lifetime
-/// of this task. If this function panics, tokio drops the future and the permit
+/// Semaphore note: the job worker (`crate::jobs::worker`) holds an
+/// OwnedSemaphorePermit for the lifetime of this task. If this function panics, tokio drops the future and the permit
 /// is released automatically (OwnedSemaphorePermit implements Drop). This is
 /// safe and intentional — no explicit guard is needed, but the permit drop is
 /// documented here so future engineers understand why there is no manual release.
 async fn process_render_job(
     job_id: Uuid,
     db: &PgPool,
-    s3: &S3Client,
-    s3_bucket: &str,
+    blobs: &dyn BlobStore,
     template_cache: &Arc<TemplateCache>,
+    notifier: &Notifier,
 ) {
     info!(job_id = %job_id, "Render job dequeued — starting processing");
+    progress::emit(Stage::RenderStarted).await;
 
     // Step 1: Mark job as processing so the status endpoint shows progress.
     // If this fails, we still try the inner work — but log the failure.
@@ -201,10 +145,10 @@ async fn process_render_job(
 
     // Step 2: Fetch resume_id for this job. If not found, mark failed immediately.
     // This was previously a silent `return` which left the job at 'processing' forever.
-    let resume_id = match sqlx::query_scalar::<_, Uuid>(
+    let resume_id = match sqlx::query_scalar!(
         "SELECT resume_id FROM render_jobs WHERE id = $1",
+        job_id
     )
-    .bind(job_id)
     .fetch_optional(db)
     .await
     {
@@ -221,6 +165,10 @@ async fn process_render_job(
                 Some("Render job row not found in database"),
             )
             .await;
+            progress::emit(Stage::Failed {
+                message: "Render job not found".to_string(),
+            })
+            .await;
             return;
         }
         Err(e) => {
@@ -232,16 +180,20 @@ async fn process_render_job(
                 Some(&format!("DB error fetching job: {e}")),
             )
             .await;
+            progress::emit(Stage::Failed {
+                message: "The job failed due to an internal error".to_string(),
+            })
+            .await;
             return;
         }
     };
 
     // Inner async block returns Result so all errors bubble up to the single
     // error handler below, which marks the job failed with the error message.
-    let result: Result<(), RenderError> = async {
+    let result: Result<String, RenderError> = async {
         // Step 3: Fetch render data from DB (resume row + bullets grouped by section)
         info!(job_id = %job_id, resume_id = %resume_id, "Render job: fetching render data from DB");
-        let (params, resume_template_id) = fetch_render_data(db, resume_id).await?;
+        let (params, resume_template_id, bullets) = fetch_render_data(db, resume_id).await?;
         info!(
             job_id = %job_id,
             resume_id = %resume_id,
@@ -281,12 +233,14 @@ async fn process_render_job(
         // Step 5: Compile LaTeX → PDF via pdflatex.
         // TeX Live packages are installed on disk via apt — no cache hash, no network.
         // compile_latex() runs pdflatex with -halt-on-error so failures are fast and clear.
+        // The compiled copy carries the line-map marks; `latex` stays clean for storage.
         info!(
             job_id = %job_id,
             resume_id = %resume_id,
             "Render job: spawning pdflatex"
         );
-        let pdflatex_result = compile_latex(&latex, job_id).await.map_err(|e| {
+        let marked_latex = mark_bullets(&latex, &bullets);
+        let pdflatex_result = compile_latex(&marked_latex, job_id).await.map_err(|e| {
             // Log pdflatex failure with FULL stderr before propagating the error.
             // This is the key diagnostic log — it contains the LaTeX error message
             // that explains why compilation failed (e.g. missing font, bad package).
@@ -321,6 +275,36 @@ async fn process_render_job(
             stderr_bytes = pdflatex_result.stderr.len(),
             "pdflatex compilation succeeded"
         );
+        // Calibration: the layout simulation fit this resume on one page.
+        if let Some(pages) = pdflatex_result.page_count {
+            LAYOUT.record_rendered_pages(pages);
+            if pages > 1 {
+                warn!(
+                    target: "layout_metrics",
+                    job_id = %job_id,
+                    resume_id = %resume_id,
+                    pages,
+                    "Rendered resume spilled past the simulated single page"
+                );
+            }
+        }
+        // Calibration: rendered line counts against the simulator's predictions.
+        let line_map = parse_line_marks(&pdflatex_result.line_marks, &bullets, &params);
+        let mispredicted = line_map
+            .iter()
+            .filter(|p| p.line_count != p.predicted_line_count)
+            .count();
+        LAYOUT.record_rendered_lines(line_map.len(), mispredicted);
+        if mispredicted > 0 {
+            warn!(
+                target: "layout_metrics",
+                job_id = %job_id,
+                resume_id = %resume_id,
+                mispredicted,
+                placed = line_map.len(),
+                "Rendered bullet line counts differ from the simulation"
+            );
+        }
         // pdflatex writes warnings (e.g. overfull hbox, underfull hbox) to stderr
         // even on success. Log them at DEBUG (not WARN — they are routine and not
         // actionable unless the operator is actively tuning LaTeX spacing).
@@ -342,7 +326,7 @@ async fn process_render_job(
             byte_count = pdflatex_result.pdf_bytes.len(),
             "Render job: uploading PDF to S3"
         );
-        let s3_key = upload_pdf_to_s3(s3, s3_bucket, resume_id, pdflatex_result.pdf_bytes)
+        let s3_key = upload_pdf(blobs, resume_id, pdflatex_result.pdf_bytes)
             .await
             .map_err(|e| {
                 error!(
@@ -365,19 +349,28 @@ async fn process_render_job(
         // compile_latex() means a failed compile would leave stale/broken LaTeX in the
         // DB. Combining it with the rendered-status update keeps things consistent —
         // latex_source is only ever set when we know the compiled PDF is valid.
-        sqlx::query(
+        sqlx::query!(
             "UPDATE resumes \
              SET s3_pdf_key = $1, latex_source = $2, status = 'rendered', updated_at = NOW() \
              WHERE id = $3",
+            &s3_key,
+            &latex,
+            resume_id
         )
-        .bind(&s3_key)
-        .bind(&latex)
-        .bind(resume_id)
         .execute(db)
         .await?;
 
         // Step 8: Mark job as done — frontend polling will see this and fetch the PDF
-        update_job_status(db, job_id, "done", None).await?;
+        let line_map = serde_json::to_value(&line_map).unwrap_or_default();
+        sqlx::query!(
+            "UPDATE render_jobs \
+             SET status = 'done', error_message = NULL, line_map = $2, updated_at = NOW() \
+             WHERE id = $1",
+            job_id,
+            line_map
+        )
+        .execute(db)
+        .await?;
 
         info!(
             job_id = %job_id,
@@ -386,18 +379,37 @@ async fn process_render_job(
             "Render job DONE"
         );
 
-        Ok(())
+        Ok(s3_key)
     }
     .await;
 
-    if let Err(e) = result {
-        error!(
-            job_id = %job_id,
-            resume_id = %resume_id,
-            error = %e,
-            "Render job FAILED — marking as failed in DB"
-        );
-        let _ = update_job_status(db, job_id, "failed", Some(&e.to_string())).await;
+    match result {
+        Ok(s3_key) => {
+            progress::emit(Stage::Completed).await;
+            let outcome = RenderOutcome::Rendered { pdf_key: &s3_key };
+            notifier
+                .render_finished(db, blobs, resume_id, outcome)
+                .await;
+        }
+        Err(e) => {
+            error!(
+                job_id = %job_id,
+                resume_id = %resume_id,
+                error = %e,
+                "Render job FAILED — marking as failed in DB"
+            );
+            let _ = update_job_status(db, job_id, "failed", Some(&e.to_string())).await;
+            // Same message the status endpoint reports for this job.
+            progress::emit(Stage::Failed {
+                message: e.to_string(),
+            })
+            .await;
+            let error = e.to_string();
+            let outcome = RenderOutcome::Failed { error: &error };
+            notifier
+                .render_finished(db, blobs, resume_id, outcome)
+                .await;
+        }
     }
 }
 
@@ -407,29 +419,35 @@ async fn process_render_job(
 
 /// Fetches resume and bullets from DB and constructs RenderParams.
 ///
-/// Returns `(RenderParams, Option<template_id>)`. The template_id is passed
-/// to `build_latex_for_job` to decide which LaTeX code path to use.
+/// Returns `(RenderParams, Option<template_id>, bullets)`. The template_id is passed
+/// to `build_latex_for_job` to decide which LaTeX code path to use; the bullets, in
+/// display order, are what the line map marks.
 ///
-/// Uses default page config (Inter 11pt, 1" margins) — per-user font/margins
-/// can be wired later without changing this interface.
+/// Page geometry comes from the resume's stored `paper_size`, `font_family`,
+/// `font_size_pt` and margins so the PDF is set on the same page the layout simulation
+/// measured against, whatever the defaults are today.
 async fn fetch_render_data(
     db: &PgPool,
     resume_id: Uuid,
-) -> Result<(RenderParams, Option<String>), RenderError> {
+) -> Result<(RenderParams, Option<String>, Vec<ResumeBulletRow>), RenderError> {
     // Fetch resume row — includes template_id (added in migration 004)
-    let resume = sqlx::query_as::<_, ResumeRow>("SELECT * FROM resumes WHERE id = $1")
-        .bind(resume_id)
-        .fetch_optional(db)
-        .await?
-        .ok_or(RenderError::ResumeNotFound(resume_id))?;
+    let resume = sqlx::query_as!(
+        ResumeRow,
+        "SELECT * FROM resumes WHERE id = $1 AND deleted_at IS NULL",
+        resume_id
+    )
+    .fetch_optional(db)
+    .await?
+    .ok_or(RenderError::ResumeNotFound(resume_id))?;
 
     let resume_template_id = resume.template_id.clone();
 
-    // Fetch all bullets ordered by section + insertion order
-    let bullets = sqlx::query_as::<_, ResumeBulletRow>(
-        "SELECT * FROM resume_bullets WHERE resume_id = $1 ORDER BY section, id",
+    // Fetch all bullets in display order (position set at generation / via reorder API)
+    let bullets = sqlx::query_as!(
+        ResumeBulletRow,
+        "SELECT * FROM resume_bullets WHERE resume_id = $1 ORDER BY position, id",
+        resume_id
     )
-    .bind(resume_id)
     .fetch_all(db)
     .await?;
 
@@ -437,14 +455,14 @@ async fn fetch_render_data(
     let mut section_order: Vec<String> = Vec::new();
     let mut section_map: HashMap<String, Vec<String>> = HashMap::new();
 
-    for bullet in bullets {
+    for bullet in &bullets {
         if !section_map.contains_key(&bullet.section) {
             section_order.push(bullet.section.clone());
         }
         section_map
-            .entry(bullet.section)
+            .entry(bullet.section.clone())
             .or_default()
-            .push(bullet.bullet_text);
+            .push(bullet.bullet_text.clone());
     }
 
     let sections = section_order
@@ -458,22 +476,91 @@ async fn fetch_render_data(
         })
         .collect();
 
-    // Use default page config — per-user font/margins can be wired in later
-    let page_config = default_page_config(FontFamily::Inter);
+    let paper_size = PaperSize::parse(&resume.paper_size).unwrap_or_else(|| {
+        warn!(
+            "Resume {} has unknown paper_size '{}' — using letter",
+            resume_id, resume.paper_size
+        );
+        PaperSize::Letter
+    });
+    let margin_preset = MarginPreset::parse(&resume.margin_preset).unwrap_or_else(|| {
+        warn!(
+            "Resume {} has unknown margin_preset '{}' — using standard",
+            resume_id, resume.margin_preset
+        );
+        MarginPreset::Standard
+    });
+    let font = FontFamily::parse(&resume.font_family).unwrap_or_else(|| {
+        warn!(
+            "Resume {} has unknown font_family '{}' — using inter",
+            resume_id, resume.font_family
+        );
+        FontFamily::Inter
+    });
+    let page_config = page_config_for_paper(font, paper_size)
+        .with_margin_preset(margin_preset)
+        .with_font_size(resume.font_size_pt);
+
+    // Stored margins win over the preset's current inches. Rows from before they were
+    // stored get the derived margins pinned now, so later renders match this one.
+    let margins = match (
+        resume.margin_left_in,
+        resume.margin_right_in,
+        resume.margin_top_in,
+        resume.margin_bottom_in,
+    ) {
+        (Some(left), Some(right), Some(top), Some(bottom)) => (left, right, top, bottom),
+        _ => {
+            let margins = (
+                page_config.margin_left_in,
+                page_config.margin_right_in,
+                page_config.margin_top_in,
+                page_config.margin_bottom_in,
+            );
+            pin_margins(db, resume_id, margins).await?;
+            margins
+        }
+    };
 
     Ok((
         RenderParams {
             resume_id,
             font: page_config.font,
             font_size_pt: page_config.font_size_pt,
-            margin_left_in: page_config.margin_left_in,
-            margin_right_in: page_config.margin_right_in,
+            paper_size: page_config.paper_size,
+            margin_left_in: margins.0,
+            margin_right_in: margins.1,
+            margin_top_in: margins.2,
+            margin_bottom_in: margins.3,
             sections,
         },
         resume_template_id,
+        bullets,
     ))
 }
 
+/// Stores the margins a resume is being rendered with, if none are stored yet.
+async fn pin_margins(
+    db: &PgPool,
+    resume_id: Uuid,
+    (left, right, top, bottom): (f32, f32, f32, f32),
+) -> Result<(), sqlx::Error> {
+    sqlx::query!(
+        r#"UPDATE resumes
+           SET margin_left_in = $2, margin_right_in = $3, margin_top_in = $4,
+               margin_bottom_in = $5
+           WHERE id = $1 AND margin_left_in IS NULL"#,
+        resume_id,
+        left,
+        right,
+        top,
+        bottom
+    )
+    .execute(db)
+    .await?;
+    Ok(())
+}
+
 /// Builds the LaTeX document string for a render job.
 ///
 /// Routing logic:
@@ -518,7 +605,8 @@ async fn build_latex_for_job(
                 })
                 .collect();
 
-            return crate::templates::render_file_template(template, &profile, &sections);
+            let latex = crate::templates::render_file_template(template, &profile, &sections);
+            return apply_page_settings(&latex, params);
         } else {
             // template_id set but not in cache — log and fall through to legacy path
             warn!(
@@ -554,12 +642,28 @@ async fn build_latex_for_job(
                 })
                 .collect();
 
-            return crate::templates::render_file_template(template, &profile, &sections);
+            let latex = crate::templates::render_file_template(template, &profile, &sections);
+            return apply_page_settings(&latex, params);
         }
     }
 
     // Final fallback: minimal pdflatex document — no extra fonts required.
-    build_minimal_pdflatex_document(params)
+    let profile = fetch_user_profile(db, params.resume_id)
+        .await
+        .unwrap_or_default();
+    build_minimal_pdflatex_document(params, &profile)
+}
+
+/// Forces the resume's paper size and margins onto file-template output, and its font
+/// size when the generator stepped away from the default — templates otherwise keep
+/// their own size.
+fn apply_page_settings(latex: &str, params: &RenderParams) -> String {
+    let latex = set_document_margins(&set_document_paper_size(latex, params.paper_size), params);
+    if (params.font_size_pt - DEFAULT_FONT_SIZE_PT).abs() < 0.01 {
+        latex
+    } else {
+        set_document_font_size(&latex, params.font_size_pt)
+    }
 }
 
 /// Builds a minimal pdflatex-compatible document from RenderParams.
@@ -569,17 +673,28 @@ async fn build_latex_for_job(
 /// No fontspec, no XeLaTeX-only packages — this compiles cleanly with plain pdflatex.
 ///
 /// Called when: template_id is None AND generic-cv is not in the template cache.
-fn build_minimal_pdflatex_document(params: &RenderParams) -> String {
+/// The header (name + contact line) is omitted when the profile is empty.
+fn build_minimal_pdflatex_document(params: &RenderParams, profile: &ProfileData) -> String {
     use crate::render::templates::escape_latex;
 
     let mut doc = String::with_capacity(4096);
 
+    doc.push_str(&format!(
+        "\\documentclass[{paper},{pt}pt]{{article}}\n",
+        paper = params.paper_size.latex_option(),
+        pt = latex_class_size(params.font_size_pt),
+    ));
+    doc.push_str(&format!(
+        "\\usepackage[left={:.2}in,right={:.2}in,top={:.2}in,bottom={:.2}in]{{geometry}}\n",
+        params.margin_left_in,
+        params.margin_right_in,
+        params.margin_top_in,
+        params.margin_bottom_in,
+    ));
     doc.push_str(
-        r#"\documentclass[letterpaper,11pt]{article}
-\usepackage{lmodern}
+        r#"\usepackage{lmodern}
 \usepackage[T1]{fontenc}
 \usepackage[utf8]{inputenc}
-\usepackage[margin=0.75in,top=0.6in,bottom=0.6in]{geometry}
 \usepackage{titlesec}
 \usepackage{enumitem}
 \usepackage[dvipsnames]{xcolor}
@@ -592,6 +707,26 @@ fn build_minimal_pdflatex_document(params: &RenderParams) -> String {
 \begin{document}
 "#,
     );
+    if let Some(command) = font_size_command(params.font_size_pt) {
+        doc.push_str(&command);
+        doc.push('\n');
+    }
+
+    let header = profile.header_lines();
+    if !header.is_empty() {
+        doc.push_str("\\begin{center}\n");
+        for (i, line) in header.iter().enumerate() {
+            if i == 0 {
+                doc.push_str(&format!(
+                    "  {{\\LARGE\\bfseries {}}}\\\\\n",
+                    escape_latex(line)
+                ));
+            } else {
+                doc.push_str(&format!("  {}\\\\\n", escape_latex(line)));
+            }
+        }
+        doc.push_str("\\end{center}\n");
+    }
 
     for section in &params.sections {
         doc.push_str(&format!(
@@ -617,41 +752,25 @@ fn build_minimal_pdflatex_document(params: &RenderParams) -> String {
 /// If the user has no profile entry, returns an error (caller uses ProfileData::default()).
 async fn fetch_user_profile(db: &PgPool, resume_id: Uuid) -> anyhow::Result<ProfileData> {
     // First get user_id from the resume
-    let user_id = sqlx::query_scalar::<_, Uuid>("SELECT user_id FROM resumes WHERE id = $1")
-        .bind(resume_id)
+    let user_id = sqlx::query_scalar!("SELECT user_id FROM resumes WHERE id = $1", resume_id)
         .fetch_optional(db)
         .await?
         .ok_or_else(|| anyhow::anyhow!("resume not found"))?;
 
     // Get the most recent profile context entry
-    let data: Option<serde_json::Value> = sqlx::query_scalar::<_, serde_json::Value>(
+    let data: Option<serde_json::Value> = sqlx::query_scalar!(
         r#"SELECT data FROM context_entries
            WHERE user_id = $1 AND entry_type = 'profile'
            ORDER BY version DESC
            LIMIT 1"#,
+        user_id
     )
-    .bind(user_id)
     .fetch_optional(db)
     .await?;
 
     let data = data.ok_or_else(|| anyhow::anyhow!("no profile entry for user {}", user_id))?;
 
-    // Extract fields leniently — missing keys become empty strings
-    let get_str = |key: &str| -> String {
-        data.get(key)
-            .and_then(|v| v.as_str())
-            .unwrap_or("")
-            .to_string()
-    };
-
-    Ok(ProfileData {
-        full_name: get_str("full_name"),
-        email: get_str("email"),
-        phone: get_str("phone"),
-        location: get_str("location"),
-        linkedin: get_str("linkedin"),
-        website: get_str("website"),
-    })
+    Ok(ProfileData::from_entry_data(&data))
 }
 
 /// Updates `render_jobs.status` (and optionally `error_message`) for a given job.
@@ -661,59 +780,39 @@ async fn update_job_status(
     status: &str,
     error_msg: Option<&str>,
 ) -> Result<(), sqlx::Error> {
-    sqlx::query(
+    sqlx::query!(
         "UPDATE render_jobs SET status = $1, error_message = $2, updated_at = NOW() WHERE id = $3",
+        status,
+        error_msg,
+        job_id
     )
-    .bind(status)
-    .bind(error_msg)
-    .bind(job_id)
     .execute(db)
     .await?;
     Ok(())
 }
 
 // ────────────────────────────────────────────────────────────────────────────
-// S3 upload
+// PDF upload
 // ────────────────────────────────────────────────────────────────────────────
 
-/// Uploads PDF bytes to S3 and returns the object key.
+/// Uploads PDF bytes to blob storage and returns the object key.
 ///
 /// Key pattern: `pdfs/{resume_id}.pdf`
-async fn upload_pdf_to_s3(
-    s3: &S3Client,
-    s3_bucket: &str,
+async fn upload_pdf(
+    blobs: &dyn BlobStore,
     resume_id: Uuid,
     pdf_bytes: Vec<u8>,
 ) -> Result<String, RenderError> {
     let key = format!("pdfs/{resume_id}.pdf");
 
-    s3.put_object()
-        .bucket(s3_bucket)
-        .key(&key)
-        .body(ByteStream::from(pdf_bytes))
-        .content_type("application/pdf")
-        .send()
+    blobs
+        .put(&key, pdf_bytes, "application/pdf")
         .await
         .map_err(|e| RenderError::S3Upload(e.to_string()))?;
 
     Ok(key)
 }
 
-// ────────────────────────────────────────────────────────────────────────────
-// Sync enqueue (called from generation/generator.rs via spawn_blocking)
-// ────────────────────────────────────────────────────────────────────────────
-
-/// Enqueues a render job by pushing the job_id UUID string to the Redis list.
-///
-/// This is a synchronous function, intended to be called via
-/// `tokio::task::spawn_blocking` from async contexts.
-pub fn enqueue_render_job_sync(redis: &redis::Client, job_id: Uuid) -> Result<(), RenderError> {
-    use redis::Commands;
-    let mut conn = redis.get_connection()?;
-    conn.lpush::<_, _, ()>(RENDER_QUEUE_KEY, job_id.to_string())?;
-    Ok(())
-}
-
 // ────────────────────────────────────────────────────────────────────────────
 // Tests
 // ────────────────────────────────────────────────────────────────────────────
@@ -721,26 +820,33 @@ pub fn enqueue_render_job_sync(redis: &redis::Client, job_id: Uuid) -> Result<()
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::jobs;
+    use redis::AsyncCommands;
 
     /// Integration test — requires a live Redis instance.
     #[tokio::test]
     #[ignore]
-    async fn test_enqueue_render_job_sync_pushes_to_list() {
+    async fn test_enqueue_render_job_pushes_envelope() {
         let redis_url =
             std::env::var("REDIS_URL").unwrap_or_else(|_| "redis://127.0.0.1:6379".to_string());
         let client = redis::Client::open(redis_url).expect("Redis client");
         let job_id = Uuid::new_v4();
 
-        enqueue_render_job_sync(&client, job_id).expect("enqueue should succeed");
+        jobs::enqueue(&client, &RenderJob { job_id })
+            .await
+            .expect("enqueue should succeed");
 
-        // Verify the job_id was pushed to the queue
+        // Verify the job was pushed to the queue
         let mut conn = client
             .get_multiplexed_async_connection()
             .await
             .expect("async conn");
-        let popped: Option<(String, String)> = conn.brpop(RENDER_QUEUE_KEY, 1.0).await.unwrap();
+        let popped: Option<(String, String)> = conn
+            .brpop(jobs::queue_key(RenderJob::QUEUE), 1.0)
+            .await
+            .unwrap();
         let (_key, value) = popped.expect("job should be in queue");
-        assert_eq!(value, job_id.to_string());
+        assert!(value.contains(&job_id.to_string()));
     }
 
     /// Integration test — requires a live PostgreSQL instance.
@@ -0,0 +1,113 @@
+//! Request IDs — one per HTTP request, quoted back to the client.
+//!
+//! The outermost middleware honours a well-formed incoming `X-Request-Id` (so a
+//! proxy's or client's id carries through) and otherwise assigns a UUID. The id is:
+//! - written back to the request headers, where the trace layer puts it on the
+//!   request span (and so on every log line inside it),
+//! - echoed in the `X-Request-Id` response header,
+//! - included as `request_id` in every `AppError` JSON body, via `current()`.
+
+use axum::{
+    extract::Request,
+    http::{HeaderName, HeaderValue},
+    middleware::Next,
+    response::Response,
+};
+use uuid::Uuid;
+
+pub const REQUEST_ID_HEADER: HeaderName = HeaderName::from_static("x-request-id");
+
+/// Longest client-supplied id accepted; longer ones are replaced.
+const MAX_LEN: usize = 128;
+
+tokio::task_local! {
+    static REQUEST_ID: String;
+}
+
+/// The id of the request being handled, if called from inside the middleware's scope.
+/// Background tasks spawned by a handler do not inherit it.
+pub fn current() -> Option<String> {
+    REQUEST_ID.try_with(|id| id.clone()).ok()
+}
+
+/// Accepts ids made of ASCII letters, digits and `-_.:`, at most `MAX_LEN` long.
+fn accept_client_id(value: &HeaderValue) -> Option<String> {
+    let id = value.to_str().ok()?.trim();
+    let valid = !id.is_empty()
+        && id.len() <= MAX_LEN
+        && id
+            .bytes()
+            .all(|b| b.is_ascii_alphanumeric() || matches!(b, b'-' | b'_' | b'.' | b':'));
+    valid.then(|| id.to_string())
+}
+
+/// Middleware: assigns the request id and scopes the rest of the request to it.
+pub async fn assign_request_id(mut req: Request, next: Next) -> Response {
+    let id = req
+        .headers()
+        .get(&REQUEST_ID_HEADER)
+        .and_then(accept_client_id)
+        .unwrap_or_else(|| Uuid::new_v4().to_string());
+    let header = HeaderValue::from_str(&id).expect("request ids are visible ASCII");
+    req.headers_mut().insert(REQUEST_ID_HEADER, header.clone());
+
+    let mut response = REQUEST_ID.scope(id, next.run(req)).await;
+    response.headers_mut().insert(REQUEST_ID_HEADER, header);
+    response
+}
+
+/// Request span for `TraceLayer::make_span_with`, carrying the assigned id.
+pub fn make_request_span(req: &Request) -> tracing::Span {
+    let request_id = req
+        .headers()
+        .get(&REQUEST_ID_HEADER)
+        .and_then(|v| v.to_str().ok())
+        .unwrap_or("-");
+    tracing::info_span!(
+        "request",
+        method = %req.method(),
+        uri = %req.uri(),
+        request_id,
+    )
+}
+
+// ────────────────────────────────────────────────────────────────────────────
+// Tests
+// ────────────────────────────────────────────────────────────────────────────
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::errors::AppError;
+    use axum::response::IntoResponse;
+
+    #[test]
+    fn test_client_ids_are_validated() {
+        let ok = HeaderValue::from_static("req-2026.10:abc_1");
+        assert_eq!(accept_client_id(&ok).as_deref(), Some("req-2026.10:abc_1"));
+        assert!(accept_client_id(&HeaderValue::from_static("has space")).is_none());
+        assert!(accept_client_id(&HeaderValue::from_static("")).is_none());
+        let long = HeaderValue::from_str(&"a".repeat(MAX_LEN + 1)).unwrap();
+        assert!(accept_client_id(&long).is_none());
+    }
+
+    #[tokio::test]
+    async fn test_error_body_carries_request_id() {
+        let response = REQUEST_ID
+            .scope("req-42".to_string(), async {
+                AppError::NotFound("Resume x not found".to_string()).into_response()
+            })
+            .await;
+        let bytes = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        let body: serde_json::Value = serde_json::from_slice(&bytes).unwrap();
+        assert_eq!(body["error"]["request_id"], "req-42");
+        assert_eq!(body["error"]["code"], "NOT_FOUND");
+    }
+
+    #[test]
+    fn test_no_request_id_outside_scope() {
+        assert_eq!(current(), None);
+    }
+}
@@ -0,0 +1,146 @@
+//! Retention — purges soft-deleted resumes and context snapshots.
+//!
+//! `DELETE /resumes/:id` and `DELETE /context/version/:v` only stamp `deleted_at`;
+//! every read filters those rows out. A `RetentionSweep` job, queued every
+//! `RETENTION_SWEEP_INTERVAL_SECS` (see `jobs::schedule_every`), hard-deletes rows whose
+//! `deleted_at` is older than `RETENTION_DAYS`, then their S3 objects. A resume's
+//! bullets, render jobs and annotations go with it (`ON DELETE CASCADE`).
+//!
+//! As with account deletion, rows go first and objects second: an object whose
+//! delete fails is handed to `PurgeObjectsJob` rather than keeping the row alive.
+
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::PgPool;
+use uuid::Uuid;
+
+use crate::jobs::{self, Handler, Job, JobError};
+use crate::storage::BlobStore;
+use crate::users::deletion::{delete_objects, s3_keys, PurgeObjectsJob};
+
+/// Rows deleted per statement, so a large backlog is purged in short transactions.
+const BATCH_SIZE: i64 = 200;
+
+// ────────────────────────────────────────────────────────────────────────────
+// Job
+// ────────────────────────────────────────────────────────────────────────────
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct RetentionSweep {
+    pub scheduled_at: DateTime<Utc>,
+}
+
+impl Job for RetentionSweep {
+    const QUEUE: &'static str = "retention";
+}
+
+pub struct RetentionSweeper {
+    pub db: PgPool,
+    pub redis: redis::Client,
+    pub blobs: Arc<dyn BlobStore>,
+    /// `RETENTION_DAYS`.
+    pub days: i32,
+}
+
+#[async_trait]
+impl Handler for RetentionSweeper {
+    type Job = RetentionSweep;
+
+    async fn run(&self, job: &RetentionSweep) -> Result<(), JobError> {
+        let mut resumes = 0;
+        let mut snapshots = 0;
+        loop {
+            let purged_resumes = self.purge_resumes().await?;
+            let purged_snapshots = self.purge_snapshots().await?;
+            resumes += purged_resumes;
+            snapshots += purged_snapshots;
+            if purged_resumes < BATCH_SIZE as usize && purged_snapshots < BATCH_SIZE as usize {
+                break;
+            }
+        }
+        if resumes + snapshots > 0 {
+            tracing::info!(
+                scheduled_at = %job.scheduled_at,
+                resumes,
+                snapshots,
+                days = self.days,
+                "retention sweep purged soft-deleted rows"
+            );
+        }
+        Ok(())
+    }
+}
+
+impl RetentionSweeper {
+    /// Purges one batch of expired resumes; returns how many rows went.
+    async fn purge_resumes(&self) -> Result<usize, JobError> {
+        let rows: Vec<(Uuid, Option<String>)> = sqlx::query!(
+            r#"DELETE FROM resumes
+               WHERE id IN (
+                   SELECT id FROM resumes
+                   WHERE deleted_at < NOW() - make_interval(days => $1)
+                   ORDER BY deleted_at
+                   LIMIT $2
+                   FOR UPDATE SKIP LOCKED
+               )
+               RETURNING id, s3_pdf_key"#,
+            self.days,
+            BATCH_SIZE
+        )
+        .fetch_all(&self.db)
+        .await
+        .map_err(retry)?
+        .into_iter()
+        .map(|row| (row.id, row.s3_pdf_key))
+        .collect();
+
+        self.purge_objects(s3_keys(vec![], &rows)).await;
+        Ok(rows.len())
+    }
+
+    /// Purges one batch of expired context snapshots; returns how many rows went.
+    async fn purge_snapshots(&self) -> Result<usize, JobError> {
+        let keys: Vec<String> = sqlx::query_scalar!(
+            r#"DELETE FROM context_snapshots
+               WHERE id IN (
+                   SELECT id FROM context_snapshots
+                   WHERE deleted_at < NOW() - make_interval(days => $1)
+                   ORDER BY deleted_at
+                   LIMIT $2
+                   FOR UPDATE SKIP LOCKED
+               )
+               RETURNING s3_key"#,
+            self.days,
+            BATCH_SIZE
+        )
+        .fetch_all(&self.db)
+        .await
+        .map_err(retry)?;
+
+        let count = keys.len();
+        self.purge_objects(s3_keys(keys, &[])).await;
+        Ok(count)
+    }
+
+    async fn purge_objects(&self, keys: Vec<String>) {
+        let failed = delete_objects(&*self.blobs, &keys).await;
+        if failed.is_empty() {
+            return;
+        }
+        tracing::warn!(count = failed.len(), "S3 deletes failed — queued for retry");
+        let job = PurgeObjectsJob {
+            keys: failed.clone(),
+        };
+        if let Err(e) = jobs::enqueue(&self.redis, &job).await {
+            // The rows are already gone; log the keys so they can be removed by hand.
+            tracing::error!(keys = ?failed, "failed to queue S3 purge: {e}");
+        }
+    }
+}
+
+fn retry(e: sqlx::Error) -> JobError {
+    JobError::Retry(e.into())
+}
@@ -1,6 +1,11 @@
+use axum::extract::State;
+use axum::http::StatusCode;
 use axum::Json;
 use serde_json::{json, Value};
 
+use crate::db::PoolStatus;
+use crate::state::AppState;
+
 /// GET /health
 /// Returns a simple status object with service version.
 pub async fn health_handler() -> Json<Value> {
@@ -10,3 +15,35 @@ pub async fn health_handler() -> Json<Value> {
         "service": "templar-api"
     }))
 }
+
+/// GET /ready
+/// 200 when every database pool can serve a query now; 503 while a pool is saturated
+/// (every connection checked out) or its database does not answer. A saturated pool is
+/// not pinged: the ping would only queue behind the requests already waiting.
+pub async fn ready_handler(State(state): State<AppState>) -> (StatusCode, Json<Value>) {
+    let mut ready = true;
+    let mut pools = vec![];
+    for (role, pool) in state.reads.pools() {
+        let status = PoolStatus::of(pool, role);
+        let saturated = status.is_saturated();
+        let reachable = !saturated && sqlx::query("SELECT 1").execute(pool).await.is_ok();
+        ready &= reachable;
+        pools.push(json!({
+            "pool": status,
+            "saturated": saturated,
+            "reachable": reachable,
+        }));
+    }
+    let code = if ready {
+        StatusCode::OK
+    } else {
+        StatusCode::SERVICE_UNAVAILABLE
+    };
+    (
+        code,
+        Json(json!({
+            "status": if ready { "ready" } else { "unavailable" },
+            "pools": pools,
+        })),
+    )
+}
@@ -0,0 +1,16 @@
+use axum::extract::State;
+use axum::http::header;
+use axum::response::IntoResponse;
+
+use crate::metrics::render_prometheus;
+use crate::state::AppState;
+
+/// GET /metrics
+/// Prometheus text exposition of the process-wide metrics (see `crate::metrics`) and
+/// the database pools' occupancy.
+pub async fn metrics_handler(State(state): State<AppState>) -> impl IntoResponse {
+    (
+        [(header::CONTENT_TYPE, "text/plain; version=0.0.4")],
+        render_prometheus(&state.reads.statuses()),
+    )
+}
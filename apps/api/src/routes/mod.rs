@@ -1,100 +1,345 @@
 pub mod health;
+pub mod metrics;
 
 use axum::{
     extract::DefaultBodyLimit,
-    routing::{get, patch, post},
+    middleware,
+    routing::{delete, get, patch, post},
     Router,
 };
 
+use crate::admin::{self, handlers as admin_stats};
+use crate::api_version::{self, V1_PREFIX, V2_PREFIX};
+use crate::applications::handlers as applications;
+use crate::audit::handlers as audit;
+use crate::bullet_revisions::handlers as bullet_revisions;
+use crate::comments::handlers as comments;
 use crate::context::handlers as ctx;
+use crate::export::handlers as export;
 use crate::generation::handlers as gen;
 use crate::grounding::handlers as grounding;
+use crate::integrations::handlers as integrations;
+use crate::interop::handlers as interop;
+use crate::notifications::handlers as notifications;
+use crate::orgs::handlers as orgs;
+use crate::personas::handlers as personas;
+use crate::progress::handlers as progress;
 use crate::projects::handlers as projects;
+use crate::quota::handlers as quota;
+use crate::rate_limit;
 use crate::render::handlers as render;
+use crate::reporting;
+use crate::shares::handlers as shares;
 use crate::state::AppState;
+use crate::storage::handlers as storage;
+use crate::target_roles::handlers as target_roles;
 use crate::templates::handlers as templates;
+use crate::timeout;
+use crate::users::handlers as users;
+use crate::validation::{MAX_JSON_BODY_BYTES, MAX_UPLOAD_BODY_BYTES};
 
 pub fn build_router(state: AppState) -> Router {
     Router::new()
         .route("/health", get(health::health_handler))
+        .route("/ready", get(health::ready_handler))
+        .route("/metrics", get(metrics::metrics_handler))
+        // v1 is deprecated; v2 wraps bodies in a versioned envelope (see api_version.rs)
+        .nest(
+            V1_PREFIX,
+            api_routes(&state).layer(middleware::from_fn_with_state(
+                state.clone(),
+                api_version::deprecate_v1,
+            )),
+        )
+        .nest(
+            V2_PREFIX,
+            api_routes(&state).layer(middleware::from_fn(api_version::envelope_v2)),
+        )
+        .with_state(state)
+        // Global body size limit; the upload route raises its own (see validation.rs)
+        .layer(DefaultBodyLimit::max(MAX_JSON_BODY_BYTES))
+}
+
+/// Every versioned route, relative to its `/api/vN` prefix.
+fn api_routes(state: &AppState) -> Router<AppState> {
+    // Admin-only stats; every route requires `users.is_admin` (see admin/mod.rs)
+    let admin = Router::new()
+        .route(
+            "/stats/generations",
+            get(admin_stats::handle_generation_stats),
+        )
+        .route("/stats/llm-tokens", get(admin_stats::handle_token_spend))
+        .route("/stats/renders", get(admin_stats::handle_render_stats))
+        .route(
+            "/stats/flagged-bullets",
+            get(admin_stats::handle_flag_stats),
+        )
+        .route("/queues", get(admin_stats::handle_queue_depths))
+        .route("/seed", post(admin_stats::handle_seed))
+        .route_layer(middleware::from_fn_with_state(
+            state.clone(),
+            admin::require_admin,
+        ));
+
+    Router::new()
+        // ── Auth & users ───────────────────────────────────────────────────
+        .route("/auth/signup", post(users::handle_signup))
+        .route("/auth/login", post(users::handle_login))
+        .route(
+            "/users/me",
+            get(users::handle_get_me)
+                .patch(users::handle_update_me)
+                .delete(users::handle_delete_me),
+        )
+        .route(
+            "/users/me/preferences",
+            get(users::handle_get_preferences).put(users::handle_put_preferences),
+        )
+        .route(
+            "/users/me/webhooks",
+            get(notifications::handle_list_webhooks).post(notifications::handle_create_webhook),
+        )
+        .route(
+            "/users/me/webhooks/:id",
+            patch(notifications::handle_update_webhook)
+                .delete(notifications::handle_delete_webhook),
+        )
+        .route(
+            "/users/me/webhooks/:id/test",
+            post(notifications::handle_test_webhook),
+        )
+        .route("/users/me/usage", get(quota::handle_get_usage))
+        .route("/users/me/audit", get(audit::handle_get_audit_log))
+        .route("/users/me/export", post(export::handle_start_export))
+        .route("/users/me/export/:id", get(export::handle_export_status))
+        // ── Organizations ──────────────────────────────────────────────────
+        .route(
+            "/orgs",
+            get(orgs::handle_list_orgs).post(orgs::handle_create_org),
+        )
+        .route(
+            "/orgs/:id/members",
+            get(orgs::handle_list_members).post(orgs::handle_add_member),
+        )
+        .route(
+            "/orgs/:id/members/:user_id",
+            delete(orgs::handle_remove_member),
+        )
+        .route(
+            "/orgs/:id/candidates/:user_id/resumes",
+            get(orgs::handle_candidate_resumes),
+        )
+        .route("/orgs/:id/usage", get(orgs::handle_org_usage))
         // ── Context API (Phase 1) ──────────────────────────────────────────
-        .route("/api/v1/context", get(ctx::handle_get_context))
-        .route("/api/v1/context/health", get(ctx::handle_context_health))
-        .route("/api/v1/context/history", get(ctx::handle_context_history))
-        .route("/api/v1/context/version/:v", get(ctx::handle_get_version))
-        .route("/api/v1/context/ingest", post(ctx::handle_ingest))
+        .route("/context", get(ctx::handle_get_context))
+        .route("/context/health", get(ctx::handle_context_health))
+        .route("/context/nudges", get(ctx::handle_context_nudges))
+        .route("/context/retag/preview", post(ctx::handle_retag_preview))
+        .route("/context/retag/apply", post(ctx::handle_retag_apply))
+        .route("/context/history", get(ctx::handle_context_history))
         .route(
-            "/api/v1/context/ingest/confirm",
-            post(ctx::handle_ingest_confirm),
+            "/context/version/:v",
+            get(ctx::handle_get_version).delete(ctx::handle_delete_version),
         )
+        .route("/context/ingest", post(ctx::handle_ingest))
+        .route("/context/ingest/confirm", post(ctx::handle_ingest_confirm))
         .route(
-            "/api/v1/context/entries/:id/evergreen",
+            "/context/entries/:id/evergreen",
             patch(ctx::handle_toggle_evergreen),
         )
         .route(
-            "/api/v1/context/entries/:id",
-            patch(ctx::handle_patch_entry),
+            "/context/entries/:id/quality",
+            get(ctx::handle_entry_quality),
         )
+        .route("/context/entries/:id", patch(ctx::handle_patch_entry))
         // ── Batch ingestion API (async pipeline) ──────────────────────────
         // Note: specific literal paths before the :id param route (Axum priority)
+        .route("/context/ingest/batch", post(ctx::handle_ingest_batch))
         .route(
-            "/api/v1/context/ingest/batch",
-            post(ctx::handle_ingest_batch),
+            "/context/ingest/upload",
+            // The only route allowed a body past MAX_JSON_BODY_BYTES
+            post(ctx::handle_ingest_upload).layer(DefaultBodyLimit::max(MAX_UPLOAD_BODY_BYTES)),
         )
+        .route("/context/ingest/batch/:id", get(ctx::handle_batch_status))
+        // ── Interop: standard document formats ───────────────────────────
         .route(
-            "/api/v1/context/ingest/upload",
-            post(ctx::handle_ingest_upload),
-        )
-        .route(
-            "/api/v1/context/ingest/batch/:id",
-            get(ctx::handle_batch_status),
+            "/context/import/json-resume",
+            post(interop::handle_import_json_resume),
         )
         // ── Resume / Generation API (Phase 2) ─────────────────────────────
         // Note: specific routes before the :id param route (Axum priority)
-        .route("/api/v1/resumes/parse-jd", post(gen::handle_parse_jd))
-        .route("/api/v1/resumes/fit-score", post(gen::handle_fit_score))
-        .route("/api/v1/resumes/generate", post(gen::handle_generate))
-        .route("/api/v1/resumes/:id", get(gen::handle_get_resume))
+        .route("/resumes/parse-jd", post(gen::handle_parse_jd))
+        .route("/resumes/parse-jd-html", post(gen::handle_parse_jd_html))
+        .route("/resumes/fit-score", post(gen::handle_fit_score))
+        .route("/resumes/generate", post(gen::handle_generate))
+        .route("/resumes/generate-batch", post(gen::handle_generate_batch))
+        .route(
+            "/resumes/batches/:id",
+            get(gen::handle_generate_batch_status),
+        )
+        .route(
+            "/resumes/:id",
+            get(gen::handle_get_resume).delete(gen::handle_delete_resume),
+        )
+        .route("/resumes/:id/explanation", get(gen::handle_get_explanation))
+        .route("/resumes/:id/regenerate", post(gen::handle_regenerate))
+        .route(
+            "/resumes/:id/interview-prep",
+            post(gen::handle_interview_prep),
+        )
+        .route(
+            "/resumes/:id/bullets/reorder",
+            patch(gen::handle_reorder_bullets),
+        )
         .route(
-            "/api/v1/resumes/:id/audit",
+            "/resumes/:id/bullets/:bullet_id/revisions",
+            get(bullet_revisions::handle_list_revisions),
+        )
+        .route(
+            "/resumes/:id/bullets/:bullet_id/revisions/:revision/restore",
+            post(bullet_revisions::handle_restore_revision),
+        )
+        .route(
+            "/resumes/:id/annotations",
+            get(orgs::handle_list_annotations).post(orgs::handle_create_annotation),
+        )
+        .route(
+            "/resumes/:id/annotations/:note_id",
+            delete(orgs::handle_delete_annotation),
+        )
+        .route(
+            "/resumes/:id/comments",
+            get(comments::handle_list_comments).post(comments::handle_create_comment),
+        )
+        .route(
+            "/resumes/:id/comments/:comment_id",
+            patch(comments::handle_resolve_comment).delete(comments::handle_delete_comment),
+        )
+        .route(
+            "/resumes/:id/audit",
             get(grounding::handle_get_audit_manifest),
         )
+        .route("/resumes/:id/ats-check", get(render::handle_ats_check))
+        .route("/resumes/:id/export", get(interop::handle_export_resume))
+        .route(
+            "/resumes/:id/anonymize",
+            post(interop::handle_anonymize_resume),
+        )
+        .route(
+            "/resumes/:id/share",
+            get(shares::handle_list_shares).post(shares::handle_create_share),
+        )
+        .route("/resumes/:id/share/stats", get(shares::handle_share_stats))
+        .route(
+            "/resumes/:id/share/:share_id",
+            delete(shares::handle_revoke_share),
+        )
+        // ── Job board integrations ────────────────────────────────────────
+        .route(
+            "/job-postings/import",
+            post(integrations::handle_import_posting),
+        )
+        .route(
+            "/job-postings/board",
+            post(integrations::handle_import_board),
+        )
+        // ── LinkedIn profile copy ─────────────────────────────────────────
+        .route("/linkedin/generate", post(gen::handle_generate_linkedin))
+        // ── Progress events (SSE) ─────────────────────────────────────────
+        .route("/events/:job_id", get(progress::handle_events))
         // ── Render API (Phase 4) ───────────────────────────────────────────
-        .route("/api/v1/render", post(render::handle_trigger_render))
-        .route("/api/v1/render/:job_id", get(render::handle_get_pdf))
+        .route("/render", post(render::handle_trigger_render))
+        .route("/render/:job_id", get(render::handle_get_pdf))
+        .route("/render/:job_id/status", get(render::handle_render_status))
         .route(
-            "/api/v1/render/:job_id/status",
-            get(render::handle_render_status),
+            "/render/:job_id/line-map",
+            get(render::handle_render_line_map),
         )
         // ── Templates API (Phase 8) ────────────────────────────────────────
         // Note: literal path suffixes (/preview, /render-pdf) must come BEFORE any
         // single-param route /templates/:id — Axum matches literal segments first.
-        .route("/api/v1/templates", get(templates::handle_list_templates))
+        .route("/templates", get(templates::handle_list_templates))
         .route(
-            "/api/v1/templates/:id/preview",
+            "/templates/:id/preview",
             get(templates::handle_template_preview),
         )
         .route(
-            "/api/v1/templates/:id/render-pdf",
+            "/templates/:id/render-pdf",
             get(templates::handle_template_render_pdf),
         )
         .route(
-            "/api/v1/templates/:id/thumbnail-pdf",
+            "/templates/:id/thumbnail-pdf",
             get(templates::handle_template_thumbnail_pdf),
         )
+        // ── Signed downloads (local storage backend; see storage/mod.rs) ──
+        .route("/blobs/*key", get(storage::handle_download))
+        // ── Public share links (no auth; the token is the credential) ─────
+        .route("/shared/:token", get(shares::handle_view_shared))
+        .route("/shared/:token/pdf", get(shares::handle_shared_pdf))
+        .route(
+            "/shared/:token/comments",
+            get(comments::handle_shared_review).post(comments::handle_create_shared_comment),
+        )
         // ── Projects API (Phase 8) ─────────────────────────────────────────
         .route(
-            "/api/v1/projects",
+            "/projects",
             get(projects::handle_list_projects).post(projects::handle_create_project),
         )
         .route(
-            "/api/v1/projects/:id",
+            "/projects/:id",
             get(projects::handle_get_project)
                 .patch(projects::handle_update_project)
                 .delete(projects::handle_delete_project),
         )
-        .with_state(state)
-        // 10 MB global body size limit — protects all endpoints, covers the
-        // upload endpoint which does its own per-file check in extractor.rs
-        .layer(DefaultBodyLimit::max(10 * 1024 * 1024))
+        // ── Personas ───────────────────────────────────────────────────────
+        .route(
+            "/personas",
+            get(personas::handle_list_personas).post(personas::handle_create_persona),
+        )
+        .route("/personas/suggest", post(personas::handle_suggest_personas))
+        .route(
+            "/personas/:id",
+            get(personas::handle_get_persona)
+                .patch(personas::handle_update_persona)
+                .delete(personas::handle_delete_persona),
+        )
+        // ── Application tracker ────────────────────────────────────────────
+        .route(
+            "/applications",
+            get(applications::handle_list_applications)
+                .post(applications::handle_create_application),
+        )
+        .route(
+            "/applications/:id",
+            get(applications::handle_get_application)
+                .patch(applications::handle_update_application)
+                .delete(applications::handle_delete_application),
+        )
+        // ── Target roles (saved JDs) ───────────────────────────────────────
+        .route(
+            "/target-roles",
+            get(target_roles::handle_list_target_roles)
+                .post(target_roles::handle_create_target_role),
+        )
+        .route(
+            "/target-roles/:id",
+            get(target_roles::handle_get_target_role)
+                .patch(target_roles::handle_update_target_role)
+                .delete(target_roles::handle_delete_target_role),
+        )
+        .route(
+            "/target-roles/:id/parse",
+            post(target_roles::handle_parse_target_role),
+        )
+        // ── Admin ──────────────────────────────────────────────────────────
+        .nest("/admin", admin)
+        // Per-caller request budgets (Redis-backed); see rate_limit.rs
+        .route_layer(middleware::from_fn_with_state(
+            state.clone(),
+            rate_limit::limit_requests,
+        ))
+        // Per-class response deadlines → 504 TIMEOUT; see timeout.rs
+        .route_layer(middleware::from_fn(timeout::enforce_timeouts))
+        // Request context for error reports; see reporting.rs
+        .route_layer(middleware::from_fn(reporting::capture_context))
 }
@@ -0,0 +1,308 @@
+//! Axum handlers for resume share links.
+//!
+//! POST   /api/v1/resumes/:id/share            — mint a link
+//! GET    /api/v1/resumes/:id/share            — list the resume's links
+//! GET    /api/v1/resumes/:id/share/stats      — opens of the resume's links
+//! DELETE /api/v1/resumes/:id/share/:share_id  — revoke a link
+//! GET    /api/v1/shared/:token                — read-only HTML view, no auth
+//! GET    /api/v1/shared/:token/pdf            — the rendered PDF, no auth
+
+use axum::{
+    body::Body,
+    extract::{Path, State},
+    http::{header, HeaderMap, HeaderName, StatusCode},
+    response::{IntoResponse, Response},
+    Json,
+};
+use chrono::{DateTime, Duration, Utc};
+use uuid::Uuid;
+
+use crate::audit::{self, Action, AuditEvent};
+use crate::auth::{AuthUser, Resource};
+use crate::errors::AppError;
+use crate::interop::handlers::load_resume_document;
+use crate::interop::html;
+use crate::shares::views::{self, ShareStats, ShareViewRow, ViewFormat, Viewer};
+use crate::shares::{
+    CreateShareRequest, OpenShare, ShareResponse, ShareRow, ShareToken, DEFAULT_SHARE_DAYS,
+};
+use crate::state::AppState;
+use crate::validation::ValidJson;
+
+/// Only inline styles: a shared page loads nothing and runs nothing.
+const SHARED_CSP: &str = "default-src 'none'; style-src 'unsafe-inline'";
+
+// ────────────────────────────────────────────────────────────────────────────
+// POST /api/v1/resumes/:id/share
+// ────────────────────────────────────────────────────────────────────────────
+
+/// Mints a share link for one of the caller's resumes, valid for `expires_in_days`
+/// (default 7, at most 30). Returns 201 with the link's URL.
+pub async fn handle_create_share(
+    State(state): State<AppState>,
+    auth: AuthUser,
+    Path(resume_id): Path<Uuid>,
+    ValidJson(body): ValidJson<CreateShareRequest>,
+) -> Result<impl IntoResponse, AppError> {
+    auth.authorize(&state.db, Resource::Resume, resume_id)
+        .await?;
+
+    let days = body.expires_in_days.unwrap_or(DEFAULT_SHARE_DAYS);
+    // Whole seconds, as the token carries them.
+    let expires = (Utc::now() + Duration::days(days.into())).timestamp();
+    let expires_at = DateTime::from_timestamp(expires, 0)
+        .ok_or_else(|| AppError::Internal(anyhow::anyhow!("share expiry out of range")))?;
+
+    let share = sqlx::query_as!(
+        ShareRow,
+        r#"INSERT INTO resume_shares (resume_id, user_id, expires_at, can_comment)
+           VALUES ($1, $2, $3, $4)
+           RETURNING *"#,
+        resume_id,
+        auth.user_id,
+        expires_at,
+        body.allow_comments
+    )
+    .fetch_one(&state.db)
+    .await?;
+    audit::record(
+        &state.db,
+        auth.user_id,
+        AuditEvent::new(Action::ResumeShareCreate, share.id).after(serde_json::json!({
+            "resume_id": resume_id,
+            "expires_at": expires_at,
+            "can_comment": share.can_comment,
+        })),
+    )
+    .await;
+
+    Ok((StatusCode::CREATED, Json(share_response(&state, share))))
+}
+
+// ────────────────────────────────────────────────────────────────────────────
+// GET /api/v1/resumes/:id/share
+// ────────────────────────────────────────────────────────────────────────────
+
+/// The resume's share links, newest first, including expired and revoked ones.
+pub async fn handle_list_shares(
+    State(state): State<AppState>,
+    auth: AuthUser,
+    Path(resume_id): Path<Uuid>,
+) -> Result<impl IntoResponse, AppError> {
+    auth.authorize(&state.db, Resource::Resume, resume_id)
+        .await?;
+
+    let shares: Vec<ShareResponse> = sqlx::query_as!(
+        ShareRow,
+        "SELECT * FROM resume_shares WHERE resume_id = $1 ORDER BY created_at DESC",
+        resume_id
+    )
+    .fetch_all(&state.db)
+    .await?
+    .into_iter()
+    .map(|share| share_response(&state, share))
+    .collect();
+
+    Ok(Json(serde_json::json!({ "shares": shares })))
+}
+
+// ────────────────────────────────────────────────────────────────────────────
+// GET /api/v1/resumes/:id/share/stats
+// ────────────────────────────────────────────────────────────────────────────
+
+/// Opens of the resume's share links: totals, per link, by country, device and browser,
+/// and the latest views. Opens by the owner's own browser count like anyone else's.
+pub async fn handle_share_stats(
+    State(state): State<AppState>,
+    auth: AuthUser,
+    Path(resume_id): Path<Uuid>,
+) -> Result<Json<ShareStats>, AppError> {
+    auth.authorize(&state.db, Resource::Resume, resume_id)
+        .await?;
+
+    let shares = sqlx::query_as!(
+        ShareRow,
+        "SELECT * FROM resume_shares WHERE resume_id = $1 ORDER BY created_at DESC",
+        resume_id
+    )
+    .fetch_all(&state.db)
+    .await?;
+    let views = sqlx::query_as!(
+        ShareViewRow,
+        r#"SELECT v.share_id, v.format, v.country, v.browser, v.os, v.device, v.viewed_at
+           FROM resume_share_views v
+           JOIN resume_shares s ON s.id = v.share_id
+           WHERE s.resume_id = $1
+           ORDER BY v.viewed_at DESC"#,
+        resume_id
+    )
+    .fetch_all(&state.db)
+    .await?;
+
+    Ok(Json(views::summarize(resume_id, &shares, views)))
+}
+
+// ────────────────────────────────────────────────────────────────────────────
+// DELETE /api/v1/resumes/:id/share/:share_id
+// ────────────────────────────────────────────────────────────────────────────
+
+/// Revokes a link; its URL stops working immediately. Revoking twice is a no-op.
+pub async fn handle_revoke_share(
+    State(state): State<AppState>,
+    auth: AuthUser,
+    Path((resume_id, share_id)): Path<(Uuid, Uuid)>,
+) -> Result<impl IntoResponse, AppError> {
+    auth.authorize(&state.db, Resource::Resume, resume_id)
+        .await?;
+
+    let revoked = sqlx::query!(
+        r#"UPDATE resume_shares SET revoked_at = COALESCE(revoked_at, NOW())
+           WHERE id = $1 AND resume_id = $2"#,
+        share_id,
+        resume_id
+    )
+    .execute(&state.db)
+    .await?;
+    if revoked.rows_affected() == 0 {
+        return Err(AppError::NotFound(format!("Share {share_id} not found")));
+    }
+    audit::record(
+        &state.db,
+        auth.user_id,
+        AuditEvent::new(Action::ResumeShareRevoke, share_id)
+            .before(serde_json::json!({ "resume_id": resume_id })),
+    )
+    .await;
+
+    Ok(StatusCode::NO_CONTENT)
+}
+
+// ────────────────────────────────────────────────────────────────────────────
+// GET /api/v1/shared/:token
+// ────────────────────────────────────────────────────────────────────────────
+
+/// The shared resume as a standalone HTML page.
+///
+/// No `AuthUser`: the token is the credential. The page is built from the resume's
+/// current bullets on every view, so edits made after sharing show up. Each view is
+/// recorded for the share stats.
+pub async fn handle_view_shared(
+    State(state): State<AppState>,
+    Path(token): Path<String>,
+    headers: HeaderMap,
+) -> Result<Response, AppError> {
+    let share = resolve_share(&state, &token).await?;
+    let (_, doc) = load_resume_document(&state.db, share.resume_id)
+        .await
+        .map_err(|_| link_not_found())?;
+    record_view(&state, share.share_id, ViewFormat::Html, &headers).await;
+    let title = match doc.basics.as_ref().and_then(|b| b.name.as_deref()) {
+        Some(name) => format!("{name} — Resume"),
+        None => "Resume".to_string(),
+    };
+
+    shared_response(
+        "text/html; charset=utf-8",
+        None,
+        html::to_html(&doc, &title).into_bytes(),
+    )
+}
+
+// ────────────────────────────────────────────────────────────────────────────
+// GET /api/v1/shared/:token/pdf
+// ────────────────────────────────────────────────────────────────────────────
+
+/// The shared resume's latest rendered PDF, shown inline. 404 until it has been
+/// rendered.
+pub async fn handle_shared_pdf(
+    State(state): State<AppState>,
+    Path(token): Path<String>,
+    headers: HeaderMap,
+) -> Result<Response, AppError> {
+    let share = resolve_share(&state, &token).await?;
+    let s3_pdf_key = sqlx::query_scalar!(
+        "SELECT s3_pdf_key FROM resumes WHERE id = $1 AND deleted_at IS NULL",
+        share.resume_id
+    )
+    .fetch_optional(&state.db)
+    .await?
+    .flatten()
+    .ok_or_else(|| AppError::NotFound("This resume has no PDF yet".to_string()))?;
+    let bytes =
+        state.blobs.get(&s3_pdf_key).await.map_err(|e| {
+            AppError::Internal(anyhow::anyhow!("Failed to read PDF {s3_pdf_key}: {e}"))
+        })?;
+    record_view(&state, share.share_id, ViewFormat::Pdf, &headers).await;
+
+    shared_response(
+        "application/pdf",
+        Some("inline; filename=\"resume.pdf\""),
+        bytes,
+    )
+}
+
+// ────────────────────────────────────────────────────────────────────────────
+// Helpers
+// ────────────────────────────────────────────────────────────────────────────
+
+fn share_response(state: &AppState, share: ShareRow) -> ShareResponse {
+    ShareResponse::new(
+        share,
+        state.config.auth.jwt_secret.expose().as_bytes(),
+        &state.config.storage.public_url,
+    )
+}
+
+/// The share a token opens: the signature must verify and be unexpired, and the share
+/// unrevoked on a resume that still exists.
+pub(crate) async fn resolve_share(state: &AppState, token: &str) -> Result<OpenShare, AppError> {
+    let secret = state.config.auth.jwt_secret.expose().as_bytes();
+    let token =
+        ShareToken::verify(token, secret, Utc::now().timestamp()).ok_or_else(link_not_found)?;
+    sqlx::query_as!(
+        OpenShare,
+        r#"SELECT s.id AS share_id, s.resume_id, s.can_comment FROM resume_shares s
+           JOIN resumes r ON r.id = s.resume_id
+           WHERE s.id = $1
+             AND s.revoked_at IS NULL
+             AND s.expires_at > NOW()
+             AND r.deleted_at IS NULL"#,
+        token.share_id
+    )
+    .fetch_optional(&state.db)
+    .await?
+    .ok_or_else(link_not_found)
+}
+
+async fn record_view(state: &AppState, share_id: Uuid, format: ViewFormat, headers: &HeaderMap) {
+    let viewer = Viewer::from_headers(headers, state.config.rate_limit.trust_forwarded_for);
+    views::record(&state.db, share_id, format, &viewer).await;
+}
+
+/// One error for every link that does not open, so the route reveals nothing about
+/// which shares exist.
+pub(crate) fn link_not_found() -> AppError {
+    AppError::NotFound("Share link is invalid, expired or revoked".to_string())
+}
+
+/// A response for a public viewer: never cached, indexed or sent on as a referrer.
+fn shared_response(
+    content_type: &str,
+    disposition: Option<&str>,
+    bytes: Vec<u8>,
+) -> Result<Response, AppError> {
+    let mut builder = Response::builder()
+        .status(StatusCode::OK)
+        .header(header::CONTENT_TYPE, content_type)
+        .header(header::CONTENT_LENGTH, bytes.len())
+        .header(header::CACHE_CONTROL, "private, no-store")
+        .header(header::REFERRER_POLICY, "no-referrer")
+        .header(header::CONTENT_SECURITY_POLICY, SHARED_CSP)
+        .header(HeaderName::from_static("x-robots-tag"), "noindex, nofollow");
+    if let Some(disposition) = disposition {
+        builder = builder.header(header::CONTENT_DISPOSITION, disposition);
+    }
+    builder
+        .body(Body::from(bytes))
+        .map_err(|e| AppError::Internal(anyhow::anyhow!("Failed to build share response: {e}")))
+}
@@ -0,0 +1,250 @@
+//! Shares module — public, read-only links to a resume, for sending drafts to mentors.
+//!
+//! POST   /api/v1/resumes/:id/share            — mint a link (7 days by default, at most 30)
+//! GET    /api/v1/resumes/:id/share            — the resume's links, newest first
+//! GET    /api/v1/resumes/:id/share/stats      — how often the links were opened
+//! DELETE /api/v1/resumes/:id/share/:share_id  — revoke a link
+//! GET    /api/v1/shared/:token                — the resume as an HTML page, no auth
+//! GET    /api/v1/shared/:token/pdf            — its latest rendered PDF, no auth
+//!
+//! A link minted with `allow_comments` also lets its holders comment on bullets, under
+//! `/shared/:token/comments` (see `crate::comments`).
+//!
+//! The token is the credential: `base64url(share_id ‖ expires ‖ HMAC-SHA256)`, keyed
+//! by a value derived from `JWT_SECRET` the way the local blob store signs downloads.
+//! A forged or expired token is turned away without a query; a valid one still needs
+//! its `resume_shares` row unrevoked, so revoking takes effect at once. Bad, expired,
+//! revoked and deleted-resume links all get the same 404.
+//!
+//! Types and token signing live here; view recording and stats in views.rs; handlers in
+//! handlers.rs.
+
+pub mod handlers;
+pub mod views;
+
+use base64::{engine::general_purpose::URL_SAFE_NO_PAD, Engine};
+use chrono::{DateTime, Utc};
+use hmac::{Hmac, Mac};
+use serde::{Deserialize, Serialize};
+use sha2::Sha256;
+use sqlx::FromRow;
+use uuid::Uuid;
+use validator::Validate;
+
+use crate::api_version::V2_PREFIX;
+
+type HmacSha256 = Hmac<Sha256>;
+
+/// Mixed into the signing key so share tokens can never pass as JWTs or download
+/// signatures.
+const SIGNING_CONTEXT: &[u8] = b"templar-resume-share-v1";
+
+/// Lifetime of a link when the request does not say.
+pub const DEFAULT_SHARE_DAYS: u32 = 7;
+/// Longest lifetime a link may be given.
+pub const MAX_SHARE_DAYS: u32 = 30;
+
+// ────────────────────────────────────────────────────────────────────────────
+// Tokens
+// ────────────────────────────────────────────────────────────────────────────
+
+/// What a share token carries.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ShareToken {
+    pub share_id: Uuid,
+    /// Unix seconds.
+    pub expires: i64,
+}
+
+impl ShareToken {
+    /// The token for the URL, signed with a key derived from `secret`.
+    pub fn sign(&self, secret: &[u8]) -> String {
+        let mut bytes = self.payload().to_vec();
+        bytes.extend_from_slice(&self.mac(secret).finalize().into_bytes());
+        URL_SAFE_NO_PAD.encode(bytes)
+    }
+
+    /// The token's contents, if it was signed with `secret` and has not expired at
+    /// `now` (unix seconds).
+    pub fn verify(token: &str, secret: &[u8], now: i64) -> Option<ShareToken> {
+        let bytes = URL_SAFE_NO_PAD.decode(token).ok()?;
+        if bytes.len() != 24 + 32 {
+            return None;
+        }
+        let (payload, signature) = bytes.split_at(24);
+        let share = ShareToken {
+            share_id: Uuid::from_slice(&payload[..16]).ok()?,
+            expires: i64::from_be_bytes(payload[16..].try_into().ok()?),
+        };
+        share.mac(secret).verify_slice(signature).ok()?;
+        (share.expires > now).then_some(share)
+    }
+
+    fn payload(&self) -> [u8; 24] {
+        let mut payload = [0; 24];
+        payload[..16].copy_from_slice(self.share_id.as_bytes());
+        payload[16..].copy_from_slice(&self.expires.to_be_bytes());
+        payload
+    }
+
+    fn mac(&self, secret: &[u8]) -> HmacSha256 {
+        let mut key = HmacSha256::new_from_slice(secret).expect("HMAC accepts keys of any length");
+        key.update(SIGNING_CONTEXT);
+        let mut mac = HmacSha256::new_from_slice(&key.finalize().into_bytes())
+            .expect("HMAC accepts keys of any length");
+        mac.update(&self.payload());
+        mac
+    }
+}
+
+/// The public URL of a share, under `public_url` (`STORAGE_PUBLIC_URL`).
+pub fn share_url(public_url: &str, token: &str) -> String {
+    format!(
+        "{}{V2_PREFIX}/shared/{token}",
+        public_url.trim_end_matches('/')
+    )
+}
+
+// ────────────────────────────────────────────────────────────────────────────
+// DB row type
+// ────────────────────────────────────────────────────────────────────────────
+
+/// A row from the `resume_shares` table.
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
+pub struct ShareRow {
+    pub id: Uuid,
+    pub resume_id: Uuid,
+    pub user_id: Uuid,
+    pub expires_at: DateTime<Utc>,
+    pub revoked_at: Option<DateTime<Utc>>,
+    pub created_at: DateTime<Utc>,
+    /// Holders of the link may comment on bullets (see `crate::comments`).
+    pub can_comment: bool,
+}
+
+/// A share link that currently opens its resume.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct OpenShare {
+    pub share_id: Uuid,
+    pub resume_id: Uuid,
+    pub can_comment: bool,
+}
+
+// ────────────────────────────────────────────────────────────────────────────
+// Request / response types
+// ────────────────────────────────────────────────────────────────────────────
+
+/// Body for `POST /api/v1/resumes/:id/share`.
+#[derive(Debug, Default, Deserialize, Validate)]
+pub struct CreateShareRequest {
+    /// Defaults to `DEFAULT_SHARE_DAYS`.
+    #[validate(range(min = 1, max = MAX_SHARE_DAYS))]
+    pub expires_in_days: Option<u32>,
+    /// Let holders of the link comment on bullets. Defaults to false: view only.
+    #[serde(default)]
+    pub allow_comments: bool,
+}
+
+/// A share link as its owner sees it.
+#[derive(Debug, Clone, Serialize)]
+pub struct ShareResponse {
+    pub id: Uuid,
+    pub resume_id: Uuid,
+    pub url: String,
+    pub expires_at: DateTime<Utc>,
+    pub revoked_at: Option<DateTime<Utc>>,
+    pub created_at: DateTime<Utc>,
+    pub can_comment: bool,
+}
+
+impl ShareResponse {
+    /// The row with its URL, re-signed from the stored id and expiry.
+    pub fn new(row: ShareRow, secret: &[u8], public_url: &str) -> Self {
+        let token = ShareToken {
+            share_id: row.id,
+            expires: row.expires_at.timestamp(),
+        }
+        .sign(secret);
+        ShareResponse {
+            id: row.id,
+            resume_id: row.resume_id,
+            url: share_url(public_url, &token),
+            expires_at: row.expires_at,
+            revoked_at: row.revoked_at,
+            created_at: row.created_at,
+            can_comment: row.can_comment,
+        }
+    }
+}
+
+// ────────────────────────────────────────────────────────────────────────────
+// Tests
+// ────────────────────────────────────────────────────────────────────────────
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SECRET: &[u8] = b"a-test-secret-that-is-long-enough!!";
+
+    fn token() -> ShareToken {
+        ShareToken {
+            share_id: Uuid::new_v4(),
+            expires: 1_700_000_000,
+        }
+    }
+
+    #[test]
+    fn test_token_round_trips_until_expiry() {
+        let share = token();
+        let signed = share.sign(SECRET);
+
+        assert_eq!(
+            ShareToken::verify(&signed, SECRET, share.expires - 1),
+            Some(share)
+        );
+        assert_eq!(ShareToken::verify(&signed, SECRET, share.expires), None);
+    }
+
+    #[test]
+    fn test_tampered_or_foreign_tokens_are_rejected() {
+        let share = token();
+        let signed = share.sign(SECRET);
+        let now = share.expires - 60;
+
+        assert_eq!(ShareToken::verify(&signed, b"another-secret", now), None);
+
+        // A later expiry with the old signature.
+        let mut bytes = URL_SAFE_NO_PAD.decode(&signed).unwrap();
+        bytes[23] ^= 1;
+        assert_eq!(
+            ShareToken::verify(&URL_SAFE_NO_PAD.encode(bytes), SECRET, now),
+            None
+        );
+
+        for junk in ["", "not-a-token", &signed[..signed.len() - 2]] {
+            assert_eq!(ShareToken::verify(junk, SECRET, now), None, "{junk:?}");
+        }
+    }
+
+    #[test]
+    fn test_share_url_uses_the_public_base() {
+        assert_eq!(
+            share_url("https://api.example.com/", "abc"),
+            "https://api.example.com/api/v2/shared/abc"
+        );
+    }
+
+    #[test]
+    fn test_create_request_bounds_the_lifetime() {
+        let body: CreateShareRequest = serde_json::from_str("{}").unwrap();
+        assert!(body.validate().is_ok() && body.expires_in_days.is_none());
+        for days in [0, MAX_SHARE_DAYS + 1] {
+            let body = CreateShareRequest {
+                expires_in_days: Some(days),
+                allow_comments: false,
+            };
+            assert!(body.validate().is_err(), "{days}");
+        }
+    }
+}
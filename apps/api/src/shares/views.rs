@@ -0,0 +1,494 @@
+//! Opens of share links: what is recorded per view, and the per-resume summary served by
+//! `GET /api/v1/resumes/:id/share/stats`.
+//!
+//! A view keeps only coarse details: the browser family, OS and device class parsed from
+//! the User-Agent, and the country a trusted CDN reports. Link unfurlers (Slack,
+//! LinkedIn, WhatsApp, …) fetch a link as soon as it is pasted, so their fetches are
+//! stored as `bot` views and kept out of the open counts.
+
+use std::collections::HashMap;
+
+use axum::http::HeaderMap;
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use sqlx::{FromRow, PgPool};
+use uuid::Uuid;
+
+use crate::shares::ShareRow;
+
+/// Most recent views listed in the stats, bot fetches included.
+pub const RECENT_VIEWS: usize = 50;
+
+/// Country headers set by CDNs in front of the API, read only when the proxy is trusted.
+const COUNTRY_HEADERS: &[&str] = &["cf-ipcountry", "cloudfront-viewer-country"];
+
+/// User-Agent fragments (lowercase) of crawlers, link unfurlers and HTTP libraries.
+const BOT_MARKERS: &[&str] = &[
+    "bot",
+    "crawler",
+    "spider",
+    "slurp",
+    "facebookexternalhit",
+    "whatsapp",
+    "preview",
+    "headlesschrome",
+    "curl/",
+    "wget/",
+    "python-requests",
+    "go-http-client",
+];
+
+// ────────────────────────────────────────────────────────────────────────────
+// Recording
+// ────────────────────────────────────────────────────────────────────────────
+
+/// What was opened.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ViewFormat {
+    Html,
+    Pdf,
+}
+
+impl ViewFormat {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            ViewFormat::Html => "html",
+            ViewFormat::Pdf => "pdf",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Device {
+    Desktop,
+    Mobile,
+    Tablet,
+    Bot,
+    Unknown,
+}
+
+impl Device {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Device::Desktop => "desktop",
+            Device::Mobile => "mobile",
+            Device::Tablet => "tablet",
+            Device::Bot => "bot",
+            Device::Unknown => "unknown",
+        }
+    }
+}
+
+/// The coarse description of a viewer that gets stored.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Viewer {
+    pub country: Option<String>,
+    pub browser: Option<&'static str>,
+    pub os: Option<&'static str>,
+    pub device: Device,
+}
+
+impl Viewer {
+    /// Reads the viewer from request headers. The country is taken only when
+    /// `trust_proxy` is set (`TRUST_FORWARDED_FOR`): otherwise any client could claim one.
+    /// When a header repeats, the last value wins, since that is the one the proxy set.
+    pub fn from_headers(headers: &HeaderMap, trust_proxy: bool) -> Viewer {
+        let user_agent = headers
+            .get(axum::http::header::USER_AGENT)
+            .and_then(|v| v.to_str().ok())
+            .unwrap_or_default();
+        let country = if trust_proxy {
+            COUNTRY_HEADERS
+                .iter()
+                .filter_map(|name| headers.get_all(*name).iter().next_back()?.to_str().ok())
+                .find_map(country_code)
+        } else {
+            None
+        };
+        Viewer {
+            country,
+            ..parse_user_agent(user_agent)
+        }
+    }
+}
+
+/// Records one open of `share_id`. A failed insert is logged, never surfaced: the viewer
+/// still gets the resume.
+pub async fn record(db: &PgPool, share_id: Uuid, format: ViewFormat, viewer: &Viewer) {
+    let result = sqlx::query!(
+        r#"INSERT INTO resume_share_views (share_id, format, country, browser, os, device)
+           VALUES ($1, $2, $3, $4, $5, $6)"#,
+        share_id,
+        format.as_str(),
+        viewer.country.as_deref(),
+        viewer.browser,
+        viewer.os,
+        viewer.device.as_str()
+    )
+    .execute(db)
+    .await;
+
+    if let Err(e) = result {
+        tracing::warn!(%share_id, error = %e, "failed to record share view");
+    }
+}
+
+/// A two-letter country code, uppercased; CDN placeholders (`XX`, Tor's `T1`) are dropped.
+fn country_code(value: &str) -> Option<String> {
+    let code = value.trim().to_ascii_uppercase();
+    (code.len() == 2 && code.bytes().all(|b| b.is_ascii_uppercase()) && code != "XX")
+        .then_some(code)
+}
+
+/// Browser family, OS and device class from a User-Agent string. Order matters: Edge and
+/// Opera also claim to be Chrome, and Chrome also claims to be Safari.
+fn parse_user_agent(user_agent: &str) -> Viewer {
+    let lower = user_agent.to_ascii_lowercase();
+    let unknown = Viewer {
+        country: None,
+        browser: None,
+        os: None,
+        device: Device::Unknown,
+    };
+    if lower.trim().is_empty() {
+        return unknown;
+    }
+    if BOT_MARKERS.iter().any(|m| lower.contains(m)) {
+        return Viewer {
+            device: Device::Bot,
+            ..unknown
+        };
+    }
+
+    let has = |needle: &str| user_agent.contains(needle);
+    let browser = if has("Edg/") || has("EdgA/") || has("EdgiOS/") {
+        Some("Edge")
+    } else if has("OPR/") || has("Opera") {
+        Some("Opera")
+    } else if has("SamsungBrowser/") {
+        Some("Samsung Internet")
+    } else if has("Firefox/") || has("FxiOS/") {
+        Some("Firefox")
+    } else if has("Chrome/") || has("CriOS/") || has("Chromium/") {
+        Some("Chrome")
+    } else if has("Safari/") {
+        Some("Safari")
+    } else {
+        None
+    };
+    let os = if has("Windows") {
+        Some("Windows")
+    } else if has("iPhone") || has("iPad") || has("iPod") {
+        Some("iOS")
+    } else if has("Android") {
+        Some("Android")
+    } else if has("CrOS") {
+        Some("ChromeOS")
+    } else if has("Macintosh") || has("Mac OS X") {
+        Some("macOS")
+    } else if has("Linux") {
+        Some("Linux")
+    } else {
+        None
+    };
+    let device = if has("iPad") || has("Tablet") || (has("Android") && !has("Mobile")) {
+        Device::Tablet
+    } else if has("Mobi") || has("iPhone") || has("iPod") {
+        Device::Mobile
+    } else if os.is_some() {
+        Device::Desktop
+    } else {
+        Device::Unknown
+    };
+
+    Viewer {
+        country: None,
+        browser,
+        os,
+        device,
+    }
+}
+
+// ────────────────────────────────────────────────────────────────────────────
+// Stats
+// ────────────────────────────────────────────────────────────────────────────
+
+/// A row from `resume_share_views`, as listed in the stats.
+#[derive(Debug, Clone, Serialize, FromRow)]
+pub struct ShareViewRow {
+    pub share_id: Uuid,
+    pub format: String,
+    pub country: Option<String>,
+    pub browser: Option<String>,
+    pub os: Option<String>,
+    pub device: String,
+    pub viewed_at: DateTime<Utc>,
+}
+
+/// Opens of one link.
+#[derive(Debug, Clone, Serialize)]
+pub struct ShareOpens {
+    pub share_id: Uuid,
+    pub created_at: DateTime<Utc>,
+    pub expires_at: DateTime<Utc>,
+    pub revoked_at: Option<DateTime<Utc>>,
+    pub opens: i64,
+    pub bot_opens: i64,
+    pub last_opened_at: Option<DateTime<Utc>>,
+}
+
+/// Opens with one value of a dimension (a country, a device class, a browser).
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct OpenCount {
+    /// `unknown` when the detail was not available.
+    pub value: String,
+    pub opens: i64,
+}
+
+/// Response body for `GET /api/v1/resumes/:id/share/stats`.
+#[derive(Debug, Clone, Serialize)]
+pub struct ShareStats {
+    pub resume_id: Uuid,
+    /// Opens across all of the resume's links, bot fetches excluded.
+    pub opens: i64,
+    /// Fetches by link unfurlers and crawlers.
+    pub bot_opens: i64,
+    pub first_opened_at: Option<DateTime<Utc>>,
+    pub last_opened_at: Option<DateTime<Utc>>,
+    /// Every link, newest first, including ones never opened.
+    pub shares: Vec<ShareOpens>,
+    /// Most opens first; bot fetches excluded.
+    pub countries: Vec<OpenCount>,
+    pub devices: Vec<OpenCount>,
+    pub browsers: Vec<OpenCount>,
+    /// The latest `RECENT_VIEWS` views, newest first, bot fetches included.
+    pub recent: Vec<ShareViewRow>,
+}
+
+/// Summarizes `views` (newest first) of the resume's `shares` (newest first).
+pub fn summarize(resume_id: Uuid, shares: &[ShareRow], views: Vec<ShareViewRow>) -> ShareStats {
+    let (bots, people): (Vec<&ShareViewRow>, Vec<&ShareViewRow>) =
+        views.iter().partition(|v| v.device == Device::Bot.as_str());
+
+    let shares = shares
+        .iter()
+        .map(|share| {
+            let opened: Vec<&&ShareViewRow> =
+                people.iter().filter(|v| v.share_id == share.id).collect();
+            ShareOpens {
+                share_id: share.id,
+                created_at: share.created_at,
+                expires_at: share.expires_at,
+                revoked_at: share.revoked_at,
+                opens: opened.len() as i64,
+                bot_opens: bots.iter().filter(|v| v.share_id == share.id).count() as i64,
+                last_opened_at: opened.first().map(|v| v.viewed_at),
+            }
+        })
+        .collect();
+
+    ShareStats {
+        resume_id,
+        opens: people.len() as i64,
+        bot_opens: bots.len() as i64,
+        first_opened_at: people.last().map(|v| v.viewed_at),
+        last_opened_at: people.first().map(|v| v.viewed_at),
+        shares,
+        countries: tally(people.iter().map(|v| v.country.as_deref())),
+        devices: tally(people.iter().map(|v| Some(v.device.as_str()))),
+        browsers: tally(people.iter().map(|v| v.browser.as_deref())),
+        recent: views.into_iter().take(RECENT_VIEWS).collect(),
+    }
+}
+
+/// Counts of each value, most first, ties alphabetical.
+fn tally<'a>(values: impl Iterator<Item = Option<&'a str>>) -> Vec<OpenCount> {
+    let mut counts: HashMap<&str, i64> = HashMap::new();
+    for value in values {
+        *counts.entry(value.unwrap_or("unknown")).or_default() += 1;
+    }
+    let mut counts: Vec<OpenCount> = counts
+        .into_iter()
+        .map(|(value, opens)| OpenCount {
+            value: value.to_string(),
+            opens,
+        })
+        .collect();
+    counts.sort_by(|a, b| b.opens.cmp(&a.opens).then_with(|| a.value.cmp(&b.value)));
+    counts
+}
+
+// ────────────────────────────────────────────────────────────────────────────
+// Tests
+// ────────────────────────────────────────────────────────────────────────────
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use axum::http::HeaderValue;
+    use chrono::Duration;
+
+    const MAC_CHROME: &str = "Mozilla/5.0 (Macintosh; Intel Mac OS X 10_15_7) \
+        AppleWebKit/537.36 (KHTML, like Gecko) Chrome/120.0.0.0 Safari/537.36";
+    const WINDOWS_EDGE: &str = "Mozilla/5.0 (Windows NT 10.0; Win64; x64) AppleWebKit/537.36 \
+        (KHTML, like Gecko) Chrome/120.0.0.0 Safari/537.36 Edg/120.0.0.0";
+    const IPHONE_SAFARI: &str = "Mozilla/5.0 (iPhone; CPU iPhone OS 17_1 like Mac OS X) \
+        AppleWebKit/605.1.15 (KHTML, like Gecko) Version/17.1 Mobile/15E148 Safari/604.1";
+    const ANDROID_TABLET: &str = "Mozilla/5.0 (Linux; Android 13; SM-X700) AppleWebKit/537.36 \
+        (KHTML, like Gecko) Chrome/120.0.0.0 Safari/537.36";
+
+    #[test]
+    fn test_user_agents_are_reduced_to_coarse_details() {
+        let cases = [
+            (MAC_CHROME, Some("Chrome"), Some("macOS"), Device::Desktop),
+            (WINDOWS_EDGE, Some("Edge"), Some("Windows"), Device::Desktop),
+            (IPHONE_SAFARI, Some("Safari"), Some("iOS"), Device::Mobile),
+            (
+                ANDROID_TABLET,
+                Some("Chrome"),
+                Some("Android"),
+                Device::Tablet,
+            ),
+            ("", None, None, Device::Unknown),
+        ];
+        for (user_agent, browser, os, device) in cases {
+            let viewer = parse_user_agent(user_agent);
+            assert_eq!(
+                (viewer.browser, viewer.os, viewer.device),
+                (browser, os, device),
+                "{user_agent}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_link_unfurlers_are_bots() {
+        for user_agent in [
+            "Slackbot-LinkExpanding 1.0 (+https://api.slack.com/robots)",
+            "LinkedInBot/1.0 (compatible; Mozilla/5.0; Apache-HttpClient +http://www.linkedin.com)",
+            "facebookexternalhit/1.1",
+            "WhatsApp/2.23.20.0",
+            "curl/8.4.0",
+        ] {
+            assert_eq!(
+                parse_user_agent(user_agent).device,
+                Device::Bot,
+                "{user_agent}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_country_is_read_only_behind_a_trusted_proxy() {
+        let mut headers = HeaderMap::new();
+        headers.insert("user-agent", HeaderValue::from_static(MAC_CHROME));
+        headers.insert("cf-ipcountry", HeaderValue::from_static("de"));
+
+        assert_eq!(Viewer::from_headers(&headers, false).country, None);
+        assert_eq!(
+            Viewer::from_headers(&headers, true).country.as_deref(),
+            Some("DE")
+        );
+
+        headers.insert("cf-ipcountry", HeaderValue::from_static("XX"));
+        assert_eq!(Viewer::from_headers(&headers, true).country, None);
+    }
+
+    #[test]
+    fn test_country_takes_the_proxy_value_over_a_client_one() {
+        let mut headers = HeaderMap::new();
+        headers.append("cf-ipcountry", HeaderValue::from_static("US"));
+        headers.append("cf-ipcountry", HeaderValue::from_static("de"));
+        assert_eq!(
+            Viewer::from_headers(&headers, true).country.as_deref(),
+            Some("DE")
+        );
+    }
+
+    fn share(created_at: DateTime<Utc>) -> ShareRow {
+        ShareRow {
+            id: Uuid::new_v4(),
+            resume_id: Uuid::nil(),
+            user_id: Uuid::nil(),
+            expires_at: created_at + Duration::days(7),
+            revoked_at: None,
+            created_at,
+            can_comment: false,
+        }
+    }
+
+    fn view(
+        share_id: Uuid,
+        device: Device,
+        country: Option<&str>,
+        at: DateTime<Utc>,
+    ) -> ShareViewRow {
+        ShareViewRow {
+            share_id,
+            format: "html".to_string(),
+            country: country.map(str::to_string),
+            browser: None,
+            os: None,
+            device: device.as_str().to_string(),
+            viewed_at: at,
+        }
+    }
+
+    #[test]
+    fn test_summary_counts_people_and_bots_apart() {
+        let now = Utc::now();
+        let newer = share(now - Duration::days(1));
+        let older = share(now - Duration::days(3));
+        let views = vec![
+            view(newer.id, Device::Desktop, Some("US"), now),
+            view(
+                newer.id,
+                Device::Mobile,
+                Some("US"),
+                now - Duration::hours(2),
+            ),
+            view(newer.id, Device::Bot, None, now - Duration::hours(20)),
+            view(older.id, Device::Desktop, None, now - Duration::days(2)),
+        ];
+
+        let stats = summarize(Uuid::nil(), &[newer.clone(), older.clone()], views);
+
+        assert_eq!((stats.opens, stats.bot_opens), (3, 1));
+        assert_eq!(stats.last_opened_at, Some(now));
+        assert_eq!(stats.first_opened_at, Some(now - Duration::days(2)));
+        assert_eq!(
+            stats
+                .shares
+                .iter()
+                .map(|s| (s.share_id, s.opens, s.bot_opens))
+                .collect::<Vec<_>>(),
+            vec![(newer.id, 2, 1), (older.id, 1, 0)]
+        );
+        assert_eq!(
+            stats.countries,
+            vec![
+                OpenCount {
+                    value: "US".to_string(),
+                    opens: 2
+                },
+                OpenCount {
+                    value: "unknown".to_string(),
+                    opens: 1
+                },
+            ]
+        );
+        assert_eq!(stats.devices[0].value, "desktop");
+        assert_eq!(stats.recent.len(), 4);
+    }
+
+    #[test]
+    fn test_summary_of_unopened_links() {
+        let link = share(Utc::now());
+        let stats = summarize(Uuid::nil(), std::slice::from_ref(&link), Vec::new());
+
+        assert_eq!(stats.opens, 0);
+        assert_eq!(stats.last_opened_at, None);
+        assert_eq!(stats.shares[0].opens, 0);
+        assert!(stats.countries.is_empty());
+    }
+}
@@ -2,26 +2,41 @@ use std::collections::HashMap;
 use std::path::PathBuf;
 use std::sync::Arc;
 
-use aws_sdk_s3::Client as S3Client;
 use bytes::Bytes;
 use redis::Client as RedisClient;
 use sqlx::PgPool;
 
+use crate::auth::JwtKeys;
 use crate::config::Config;
+use crate::db::ReadRouter;
 use crate::generation::fit_scoring::FitScorer;
+use crate::integrations::JobBoardClient;
 use crate::layout::PageConfig;
 use crate::llm_client::LlmClient;
+use crate::notifications::Notifier;
+use crate::storage::BlobStore;
 use crate::templates::TemplateCache;
 
 /// Shared application state injected into all route handlers via Axum extractors.
 #[derive(Clone)]
 pub struct AppState {
+    /// The primary: every write, and every read that must see one.
     pub db: PgPool,
+    /// Lag-tolerant reads; the replica when `DATABASE_REPLICA_URL` is set.
+    pub reads: ReadRouter,
     /// Redis client for the async render job queue (Phase 4).
     pub redis: RedisClient,
-    pub s3: S3Client,
+    /// Object storage for snapshots, PDFs, thumbnails and exports; S3 or local disk
+    /// per `STORAGE_BACKEND`.
+    pub blobs: Arc<dyn BlobStore>,
     pub llm: LlmClient,
+    /// Emails users when their batch generations and renders finish.
+    pub notifier: Notifier,
+    /// Fetches postings from the Greenhouse and Lever board APIs.
+    pub job_boards: JobBoardClient,
     pub config: Config,
+    /// Issues and validates the bearer tokens `AuthUser` is extracted from.
+    pub jwt: JwtKeys,
     /// Pluggable fit scorer. Default: KeywordFitScorer. Swap via ENABLE_LLM_FIT_SCORING env.
     pub fit_scorer: Arc<dyn FitScorer>,
     /// Layout page config — font metrics and page dimensions for the simulation loop.
@@ -0,0 +1,47 @@
+//! Download route for URLs presigned by the local backend.
+
+use axum::{
+    body::Body,
+    extract::{Path, Query, State},
+    http::{header, StatusCode},
+    response::Response,
+};
+
+use crate::errors::AppError;
+use crate::state::AppState;
+use crate::storage::{content_type_for, SignedUrlParams};
+
+/// GET /api/v2/blobs/*key?expires=&filename=&signature=
+///
+/// No `AuthUser`: the signature is the credential. A bad or expired signature is a
+/// 404 rather than a 403, so the route reveals nothing about which keys exist. With
+/// the S3 backend no URL verifies and every request is a 404.
+pub async fn handle_download(
+    State(state): State<AppState>,
+    Path(key): Path<String>,
+    Query(params): Query<SignedUrlParams>,
+) -> Result<Response, AppError> {
+    if !state.blobs.verify_signature(&key, &params) {
+        return Err(AppError::NotFound(
+            "Download link is invalid or expired".to_string(),
+        ));
+    }
+    let bytes = state
+        .blobs
+        .get(&key)
+        .await
+        .map_err(|_| AppError::NotFound("Download link is invalid or expired".to_string()))?;
+    let filename = params.filename.replace(['"', '\\', '\r', '\n'], "_");
+
+    Response::builder()
+        .status(StatusCode::OK)
+        .header(header::CONTENT_TYPE, content_type_for(&key))
+        .header(header::CONTENT_LENGTH, bytes.len())
+        .header(
+            header::CONTENT_DISPOSITION,
+            format!("attachment; filename=\"{filename}\""),
+        )
+        .header(header::CACHE_CONTROL, "private, no-store")
+        .body(Body::from(bytes))
+        .map_err(|e| AppError::Internal(anyhow::anyhow!("Failed to build download response: {e}")))
+}
@@ -0,0 +1,312 @@
+//! `BlobStore` on the local filesystem — for development and CI without MinIO.
+//!
+//! An object is the file `{root}/{key}`; directories are created as needed. Writes
+//! go to a temporary sibling that is renamed into place, so a reader never sees a
+//! half-written object. Presigned URLs point at this API's download route and carry
+//! an HMAC-SHA256 over the key, expiry and filename, keyed by a value derived from
+//! `JWT_SECRET`.
+
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+use anyhow::{anyhow, Context, Result};
+use async_trait::async_trait;
+use base64::{engine::general_purpose::URL_SAFE_NO_PAD, Engine};
+use chrono::Utc;
+use hmac::{Hmac, Mac};
+use sha2::Sha256;
+use uuid::Uuid;
+
+use crate::api_version::V2_PREFIX;
+use crate::storage::{validate_key, BlobStore, SignedUrlParams};
+
+type HmacSha256 = Hmac<Sha256>;
+
+/// Mixed into the signing key so download signatures can never pass as JWTs.
+const SIGNING_CONTEXT: &[u8] = b"templar-blob-download-v1";
+
+pub struct LocalBlobStore {
+    root: PathBuf,
+    /// Base URL of this API as browsers reach it, without a trailing slash.
+    public_url: String,
+    signing_key: Vec<u8>,
+}
+
+impl LocalBlobStore {
+    pub fn new(root: PathBuf, public_url: &str, secret: &[u8]) -> Result<Self> {
+        std::fs::create_dir_all(&root)
+            .with_context(|| format!("creating storage directory {}", root.display()))?;
+        let mut mac = HmacSha256::new_from_slice(secret).expect("HMAC accepts keys of any length");
+        mac.update(SIGNING_CONTEXT);
+        Ok(LocalBlobStore {
+            root,
+            public_url: public_url.trim_end_matches('/').to_string(),
+            signing_key: mac.finalize().into_bytes().to_vec(),
+        })
+    }
+
+    fn path_for(&self, key: &str) -> Result<PathBuf> {
+        validate_key(key)?;
+        Ok(self.root.join(key))
+    }
+
+    fn mac(&self, key: &str, expires: i64, filename: &str) -> HmacSha256 {
+        let mut mac =
+            HmacSha256::new_from_slice(&self.signing_key).expect("HMAC accepts keys of any length");
+        mac.update(format!("{key}\n{expires}\n{filename}").as_bytes());
+        mac
+    }
+
+    fn signed_url(&self, key: &str, expires: i64, filename: &str) -> Result<String> {
+        let signature =
+            URL_SAFE_NO_PAD.encode(self.mac(key, expires, filename).finalize().into_bytes());
+        let mut url = url::Url::parse(&format!("{}{V2_PREFIX}/blobs/", self.public_url))
+            .with_context(|| format!("invalid STORAGE_PUBLIC_URL '{}'", self.public_url))?;
+        url.path_segments_mut()
+            .map_err(|_| anyhow!("STORAGE_PUBLIC_URL cannot be a base URL"))?
+            .pop_if_empty()
+            .extend(key.split('/'));
+        url.query_pairs_mut()
+            .append_pair("expires", &expires.to_string())
+            .append_pair("filename", filename)
+            .append_pair("signature", &signature);
+        Ok(url.into())
+    }
+}
+
+#[async_trait]
+impl BlobStore for LocalBlobStore {
+    fn describe(&self) -> String {
+        format!("file://{}", self.root.display())
+    }
+
+    async fn check(&self) -> Result<()> {
+        let meta = tokio::fs::metadata(&self.root).await.with_context(|| {
+            format!("storage directory {} is not readable", self.root.display())
+        })?;
+        if meta.permissions().readonly() {
+            return Err(anyhow!(
+                "storage directory {} is read-only",
+                self.root.display()
+            ));
+        }
+        Ok(())
+    }
+
+    /// `content_type` is not stored; downloads derive it from the key's extension.
+    async fn put(&self, key: &str, body: Vec<u8>, _content_type: &str) -> Result<()> {
+        let path = self.path_for(key)?;
+        if let Some(dir) = path.parent() {
+            tokio::fs::create_dir_all(dir)
+                .await
+                .with_context(|| format!("creating {}", dir.display()))?;
+        }
+        let tmp = path.with_file_name(format!(".tmp-{}", Uuid::new_v4()));
+        tokio::fs::write(&tmp, body)
+            .await
+            .with_context(|| format!("writing {}", tmp.display()))?;
+        if let Err(e) = tokio::fs::rename(&tmp, &path).await {
+            let _ = tokio::fs::remove_file(&tmp).await;
+            return Err(e).with_context(|| format!("moving {} into place", path.display()));
+        }
+        Ok(())
+    }
+
+    async fn get(&self, key: &str) -> Result<Vec<u8>> {
+        let path = self.path_for(key)?;
+        tokio::fs::read(&path)
+            .await
+            .with_context(|| format!("reading blob {key}"))
+    }
+
+    async fn exists(&self, key: &str) -> Result<bool> {
+        let path = self.path_for(key)?;
+        Ok(tokio::fs::try_exists(&path).await? && path.is_file())
+    }
+
+    async fn list(&self, prefix: &str) -> Result<Vec<String>> {
+        let root = self.root.clone();
+        let prefix = prefix.to_string();
+        tokio::task::spawn_blocking(move || list_files(&root, &prefix)).await?
+    }
+
+    async fn delete(&self, key: &str) -> Result<()> {
+        let path = self.path_for(key)?;
+        match tokio::fs::remove_file(&path).await {
+            Ok(()) => Ok(()),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(()),
+            Err(e) => Err(e).with_context(|| format!("deleting blob {key}")),
+        }
+    }
+
+    async fn presigned_get(&self, key: &str, ttl: Duration, filename: &str) -> Result<String> {
+        validate_key(key)?;
+        let expires = Utc::now().timestamp() + ttl.as_secs() as i64;
+        self.signed_url(key, expires, filename)
+    }
+
+    fn verify_signature(&self, key: &str, params: &SignedUrlParams) -> bool {
+        if params.expires < Utc::now().timestamp() {
+            return false;
+        }
+        let Ok(signature) = URL_SAFE_NO_PAD.decode(&params.signature) else {
+            return false;
+        };
+        self.mac(key, params.expires, &params.filename)
+            .verify_slice(&signature)
+            .is_ok()
+    }
+}
+
+/// Keys of the regular files under `root` that start with `prefix`. Only the
+/// directory named by the prefix (up to its last `/`) is walked.
+fn list_files(root: &Path, prefix: &str) -> Result<Vec<String>> {
+    let dir_part = prefix.rsplit_once('/').map_or("", |(dir, _)| dir);
+    let start = if dir_part.is_empty() {
+        root.to_path_buf()
+    } else {
+        validate_key(dir_part)?;
+        root.join(dir_part)
+    };
+
+    let mut keys = vec![];
+    let mut pending = vec![start];
+    while let Some(dir) = pending.pop() {
+        let entries = match std::fs::read_dir(&dir) {
+            Ok(entries) => entries,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => continue,
+            Err(e) => return Err(e).with_context(|| format!("listing {}", dir.display())),
+        };
+        for entry in entries {
+            let entry = entry?;
+            let file_type = entry.file_type()?;
+            let path = entry.path();
+            if file_type.is_dir() {
+                pending.push(path);
+                continue;
+            }
+            let is_tmp = entry.file_name().to_string_lossy().starts_with(".tmp-");
+            if !file_type.is_file() || is_tmp {
+                continue;
+            }
+            let Ok(relative) = path.strip_prefix(root) else {
+                continue;
+            };
+            let key = relative
+                .components()
+                .map(|c| c.as_os_str().to_string_lossy())
+                .collect::<Vec<_>>()
+                .join("/");
+            if key.starts_with(prefix) {
+                keys.push(key);
+            }
+        }
+    }
+    Ok(keys)
+}
+
+// ────────────────────────────────────────────────────────────────────────────
+// Tests
+// ────────────────────────────────────────────────────────────────────────────
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn store() -> (tempfile::TempDir, LocalBlobStore) {
+        let dir = tempfile::tempdir().unwrap();
+        let store = LocalBlobStore::new(
+            dir.path().join("blobs"),
+            "http://localhost:8080/",
+            b"0123456789abcdef0123456789abcdef",
+        )
+        .unwrap();
+        (dir, store)
+    }
+
+    #[tokio::test]
+    async fn test_put_get_list_delete_round_trip() {
+        let (_dir, store) = store();
+        store
+            .put("contexts/u1/v1.md", b"# one".to_vec(), "text/markdown")
+            .await
+            .unwrap();
+        store
+            .put("contexts/u1/v2.md", b"# two".to_vec(), "text/markdown")
+            .await
+            .unwrap();
+        store
+            .put("contexts/u2/v1.md", b"# other".to_vec(), "text/markdown")
+            .await
+            .unwrap();
+
+        assert_eq!(store.get("contexts/u1/v2.md").await.unwrap(), b"# two");
+        assert!(store.exists("contexts/u1/v1.md").await.unwrap());
+        assert!(!store.exists("contexts/u1/v9.md").await.unwrap());
+
+        let mut keys = store.list("contexts/u1/").await.unwrap();
+        keys.sort();
+        assert_eq!(keys, ["contexts/u1/v1.md", "contexts/u1/v2.md"]);
+        assert!(store.list("exports/u1/").await.unwrap().is_empty());
+
+        store.delete("contexts/u1/v1.md").await.unwrap();
+        store.delete("contexts/u1/v1.md").await.unwrap();
+        assert!(store.get("contexts/u1/v1.md").await.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_keys_cannot_escape_the_root() {
+        let (_dir, store) = store();
+        assert!(store.put("../outside", vec![1], "x").await.is_err());
+        assert!(store.get("/etc/passwd").await.is_err());
+        assert!(store.list("../").await.is_err());
+    }
+
+    fn params(url: &str) -> SignedUrlParams {
+        let url = url::Url::parse(url).unwrap();
+        let query = |name: &str| {
+            url.query_pairs()
+                .find(|(k, _)| k == name)
+                .map(|(_, v)| v.into_owned())
+                .unwrap()
+        };
+        SignedUrlParams {
+            expires: query("expires").parse().unwrap(),
+            filename: query("filename"),
+            signature: query("signature"),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_presigned_url_is_bound_to_key_and_filename() {
+        let (_dir, store) = store();
+        let url = store
+            .presigned_get(
+                "exports/u1/e1.zip",
+                Duration::from_secs(60),
+                "my export.zip",
+            )
+            .await
+            .unwrap();
+        assert!(url.starts_with("http://localhost:8080/api/v2/blobs/exports/u1/e1.zip?"));
+
+        let signed = params(&url);
+        assert_eq!(signed.filename, "my export.zip");
+        assert!(store.verify_signature("exports/u1/e1.zip", &signed));
+        assert!(!store.verify_signature("exports/u2/e1.zip", &signed));
+
+        let renamed = SignedUrlParams {
+            filename: "other.zip".to_string(),
+            ..signed
+        };
+        assert!(!store.verify_signature("exports/u1/e1.zip", &renamed));
+    }
+
+    #[test]
+    fn test_expired_signature_is_rejected() {
+        let (_dir, store) = store();
+        let past = Utc::now().timestamp() - 1;
+        let url = store.signed_url("pdfs/r1.pdf", past, "r.pdf").unwrap();
+        assert!(!store.verify_signature("pdfs/r1.pdf", &params(&url)));
+    }
+}
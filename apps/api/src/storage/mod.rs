@@ -0,0 +1,148 @@
+//! Blob storage — context snapshots, résumé PDFs, template thumbnails and data
+//! exports.
+//!
+//! Everything that reads or writes an object goes through `BlobStore`, carried in
+//! `AppState` (and in each worker) as `Arc<dyn BlobStore>`. `STORAGE_BACKEND`
+//! chooses the implementation:
+//! - `s3` (default): `S3BlobStore`, the configured bucket on S3 or MinIO;
+//! - `local`: `LocalBlobStore`, files under `STORAGE_LOCAL_DIR`, so development and
+//!   CI can run the whole pipeline without MinIO. Not allowed in the prod profile.
+//!
+//! Keys are the same on every backend (`contexts/{user_id}/v{n}.md`,
+//! `pdfs/{resume_id}.pdf`, ...) and are what the `*s3_key` columns store.
+//!
+//! Presigned downloads: S3 signs its own URLs. The local backend signs
+//! `GET /api/v2/blobs/*key` with an HMAC instead, served by `handlers::handle_download`
+//! without authentication — the signature is the credential, as with S3.
+
+pub mod handlers;
+pub mod local;
+pub mod s3;
+
+use std::sync::Arc;
+use std::time::Duration;
+
+use anyhow::{bail, Result};
+use async_trait::async_trait;
+use serde::Deserialize;
+
+use crate::config::{Config, StorageBackend};
+
+pub use local::LocalBlobStore;
+pub use s3::S3BlobStore;
+
+/// An object store addressed by `/`-separated keys.
+#[async_trait]
+pub trait BlobStore: Send + Sync {
+    /// Where objects go, for logs (`s3://templar-pdfs`, `file:///var/lib/templar`).
+    fn describe(&self) -> String;
+
+    /// Fails if the store cannot be reached; called once at startup.
+    async fn check(&self) -> Result<()>;
+
+    async fn put(&self, key: &str, body: Vec<u8>, content_type: &str) -> Result<()>;
+
+    /// The object's bytes. A missing key is an error.
+    async fn get(&self, key: &str) -> Result<Vec<u8>>;
+
+    async fn exists(&self, key: &str) -> Result<bool>;
+
+    /// Every key starting with `prefix`, in no particular order.
+    async fn list(&self, prefix: &str) -> Result<Vec<String>>;
+
+    /// Removes the object. Deleting a missing key succeeds, so retries are safe.
+    async fn delete(&self, key: &str) -> Result<()>;
+
+    /// A URL anyone holding it can download `key` from until `ttl` passes, saved as
+    /// `filename`.
+    async fn presigned_get(&self, key: &str, ttl: Duration, filename: &str) -> Result<String>;
+
+    /// Whether `params` is a valid, unexpired signature this store issued for `key`.
+    /// Only backends whose URLs point back at the API issue such signatures.
+    fn verify_signature(&self, _key: &str, _params: &SignedUrlParams) -> bool {
+        false
+    }
+}
+
+/// Query string of a download URL signed by the API itself.
+#[derive(Debug, Clone, Deserialize)]
+pub struct SignedUrlParams {
+    /// Unix seconds.
+    pub expires: i64,
+    pub filename: String,
+    pub signature: String,
+}
+
+/// Builds the backend selected by `config.storage`.
+pub async fn build(config: &Config) -> Result<Arc<dyn BlobStore>> {
+    Ok(match config.storage.backend {
+        StorageBackend::S3 => Arc::new(S3BlobStore::new(config).await),
+        StorageBackend::Local => Arc::new(LocalBlobStore::new(
+            config.storage.local_dir.clone(),
+            &config.storage.public_url,
+            config.auth.jwt_secret.expose().as_bytes(),
+        )?),
+    })
+}
+
+/// Rejects keys that could escape a directory or do not name an object: empty,
+/// absolute, `.`/`..` or empty segments, backslashes and control characters.
+pub fn validate_key(key: &str) -> Result<()> {
+    if key.is_empty() || key.starts_with('/') {
+        bail!("invalid blob key '{key}'");
+    }
+    let bad_segment = key
+        .split('/')
+        .any(|s| s.is_empty() || s == "." || s == "..");
+    if bad_segment || key.contains('\\') || key.chars().any(char::is_control) {
+        bail!("invalid blob key '{key}'");
+    }
+    Ok(())
+}
+
+/// `Content-Type` for a stored key, from its extension.
+pub fn content_type_for(key: &str) -> &'static str {
+    match key.rsplit_once('.').map(|(_, ext)| ext) {
+        Some("pdf") => "application/pdf",
+        Some("png") => "image/png",
+        Some("zip") => "application/zip",
+        Some("md") => "text/markdown; charset=utf-8",
+        Some("json") => "application/json",
+        _ => "application/octet-stream",
+    }
+}
+
+// ────────────────────────────────────────────────────────────────────────────
+// Tests
+// ────────────────────────────────────────────────────────────────────────────
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_validate_key_rejects_escapes() {
+        assert!(validate_key("contexts/0b6e/v3.md").is_ok());
+        assert!(validate_key("templates/classic/thumbnail.png").is_ok());
+        for bad in [
+            "",
+            "/etc/passwd",
+            "../secrets",
+            "pdfs/../../x",
+            "pdfs//x.pdf",
+            "pdfs/./x.pdf",
+            "pdfs\\x.pdf",
+            "pdfs/x\n.pdf",
+            "pdfs/",
+        ] {
+            assert!(validate_key(bad).is_err(), "{bad:?} accepted");
+        }
+    }
+
+    #[test]
+    fn test_content_type_follows_the_extension() {
+        assert_eq!(content_type_for("pdfs/r.pdf"), "application/pdf");
+        assert_eq!(content_type_for("exports/u/e.zip"), "application/zip");
+        assert_eq!(content_type_for("blob"), "application/octet-stream");
+    }
+}
@@ -0,0 +1,161 @@
+//! `BlobStore` on an S3 bucket — AWS in production, MinIO in docker-compose.
+
+use std::time::Duration;
+
+use anyhow::{anyhow, Result};
+use async_trait::async_trait;
+use aws_config::Region;
+use aws_sdk_s3::config::Credentials;
+use aws_sdk_s3::presigning::PresigningConfig;
+use aws_sdk_s3::primitives::ByteStream;
+use aws_sdk_s3::Client;
+
+use crate::config::Config;
+use crate::storage::BlobStore;
+
+pub struct S3BlobStore {
+    client: Client,
+    bucket: String,
+}
+
+impl S3BlobStore {
+    /// Client for `config.s3`, with path-style addressing so MinIO works.
+    pub async fn new(config: &Config) -> Self {
+        let credentials = Credentials::new(
+            &config.s3.access_key_id,
+            config.s3.secret_access_key.expose(),
+            None,
+            None,
+            "templar-static",
+        );
+
+        let s3_config = aws_config::defaults(aws_config::BehaviorVersion::latest())
+            .region(Region::new("us-east-1"))
+            .credentials_provider(credentials)
+            .endpoint_url(&config.s3.endpoint)
+            .load()
+            .await;
+
+        let s3_client_config = aws_sdk_s3::config::Builder::from(&s3_config)
+            .force_path_style(true)
+            .build();
+
+        S3BlobStore {
+            client: Client::from_conf(s3_client_config),
+            bucket: config.s3.bucket.clone(),
+        }
+    }
+}
+
+#[async_trait]
+impl BlobStore for S3BlobStore {
+    fn describe(&self) -> String {
+        format!("s3://{}", self.bucket)
+    }
+
+    async fn check(&self) -> Result<()> {
+        self.client
+            .head_bucket()
+            .bucket(&self.bucket)
+            .send()
+            .await
+            .map_err(|e| anyhow!("S3 bucket '{}' is not reachable: {e}", self.bucket))?;
+        Ok(())
+    }
+
+    async fn put(&self, key: &str, body: Vec<u8>, content_type: &str) -> Result<()> {
+        self.client
+            .put_object()
+            .bucket(&self.bucket)
+            .key(key)
+            .body(ByteStream::from(body))
+            .content_type(content_type)
+            .send()
+            .await
+            .map_err(|e| anyhow!("S3 upload of {key} failed: {e}"))?;
+        Ok(())
+    }
+
+    async fn get(&self, key: &str) -> Result<Vec<u8>> {
+        let output = self
+            .client
+            .get_object()
+            .bucket(&self.bucket)
+            .key(key)
+            .send()
+            .await
+            .map_err(|e| anyhow!("S3 get of {key} failed: {e}"))?;
+        let bytes = output
+            .body
+            .collect()
+            .await
+            .map_err(|e| anyhow!("S3 body read of {key} failed: {e}"))?
+            .into_bytes();
+        Ok(bytes.to_vec())
+    }
+
+    /// HEAD only — the body is not downloaded. Any error counts as absent.
+    async fn exists(&self, key: &str) -> Result<bool> {
+        let head = self
+            .client
+            .head_object()
+            .bucket(&self.bucket)
+            .key(key)
+            .send()
+            .await;
+        Ok(head.is_ok())
+    }
+
+    async fn list(&self, prefix: &str) -> Result<Vec<String>> {
+        let mut keys = vec![];
+        let mut continuation: Option<String> = None;
+        loop {
+            let page = self
+                .client
+                .list_objects_v2()
+                .bucket(&self.bucket)
+                .prefix(prefix)
+                .set_continuation_token(continuation.take())
+                .send()
+                .await
+                .map_err(|e| anyhow!("S3 list of {prefix} failed: {e}"))?;
+            keys.extend(
+                page.contents()
+                    .iter()
+                    .filter_map(|o| o.key().map(str::to_string)),
+            );
+            match page.next_continuation_token() {
+                Some(token) if page.is_truncated().unwrap_or(false) => {
+                    continuation = Some(token.to_string())
+                }
+                _ => return Ok(keys),
+            }
+        }
+    }
+
+    async fn delete(&self, key: &str) -> Result<()> {
+        self.client
+            .delete_object()
+            .bucket(&self.bucket)
+            .key(key)
+            .send()
+            .await
+            .map_err(|e| anyhow!("S3 delete of {key} failed: {e}"))?;
+        Ok(())
+    }
+
+    async fn presigned_get(&self, key: &str, ttl: Duration, filename: &str) -> Result<String> {
+        let config =
+            PresigningConfig::expires_in(ttl).map_err(|e| anyhow!("presigning config: {e}"))?;
+        let request = self
+            .client
+            .get_object()
+            .bucket(&self.bucket)
+            .key(key)
+            .response_content_disposition(format!("attachment; filename=\"{filename}\""))
+            .presigned(config)
+            .await
+            .map_err(|e| anyhow!("S3 presign failed: {e}"))?;
+        Ok(request.uri().to_string())
+    }
+}
@@ -0,0 +1,210 @@
+//! Axum handlers for target roles.
+//!
+//! GET    /api/v1/target-roles            — list the caller's roles, most recently edited first
+//! POST   /api/v1/target-roles            — start a role
+//! GET    /api/v1/target-roles/:id        — fetch one role
+//! PATCH  /api/v1/target-roles/:id        — autosave: partial update
+//! DELETE /api/v1/target-roles/:id        — hard delete; its resumes are kept
+//! POST   /api/v1/target-roles/:id/parse  — parse the JD text, caching the result
+
+use axum::{
+    extract::{Path, State},
+    http::StatusCode,
+    response::IntoResponse,
+    Json,
+};
+use uuid::Uuid;
+
+use crate::audit::{self, Action, AuditEvent};
+use crate::auth::{AuthUser, Resource};
+use crate::errors::AppError;
+use crate::generation::jd_parser::parse_jd;
+use crate::quota;
+use crate::state::AppState;
+use crate::target_roles::{
+    load_jd, store_parse, CreateTargetRoleRequest, ParseTargetRoleResponse, TargetRoleRow,
+    UpdateTargetRoleRequest,
+};
+use crate::validation::ValidJson;
+
+// ────────────────────────────────────────────────────────────────────────────
+// GET /api/v1/target-roles
+// ────────────────────────────────────────────────────────────────────────────
+
+/// Returns the caller's target roles, most recently edited first.
+pub async fn handle_list_target_roles(
+    State(state): State<AppState>,
+    auth: AuthUser,
+) -> Result<impl IntoResponse, AppError> {
+    let roles = sqlx::query_as!(
+        TargetRoleRow,
+        "SELECT * FROM target_roles WHERE user_id = $1 ORDER BY updated_at DESC",
+        auth.user_id
+    )
+    .fetch_all(state.reads.pool())
+    .await?;
+
+    Ok(Json(serde_json::json!({ "target_roles": roles })))
+}
+
+// ────────────────────────────────────────────────────────────────────────────
+// POST /api/v1/target-roles
+// ────────────────────────────────────────────────────────────────────────────
+
+/// Starts a target role. Only the title is required; the JD can follow by autosave.
+pub async fn handle_create_target_role(
+    State(state): State<AppState>,
+    auth: AuthUser,
+    ValidJson(body): ValidJson<CreateTargetRoleRequest>,
+) -> Result<impl IntoResponse, AppError> {
+    let role = sqlx::query_as!(
+        TargetRoleRow,
+        r#"INSERT INTO target_roles (user_id, title, company, jd_text, notes)
+           VALUES ($1, $2, $3, COALESCE($4, ''), $5)
+           RETURNING *"#,
+        auth.user_id,
+        body.title.trim(),
+        body.company.as_deref().map(str::trim),
+        body.jd_text.as_deref(),
+        body.notes.as_deref()
+    )
+    .fetch_one(&state.db)
+    .await?;
+    audit::record(
+        &state.db,
+        auth.user_id,
+        AuditEvent::new(Action::TargetRoleCreate, role.id),
+    )
+    .await;
+
+    Ok((StatusCode::CREATED, Json(role)))
+}
+
+// ────────────────────────────────────────────────────────────────────────────
+// GET /api/v1/target-roles/:id
+// ────────────────────────────────────────────────────────────────────────────
+
+/// Returns a single target role by ID.
+pub async fn handle_get_target_role(
+    Path(id): Path<Uuid>,
+    State(state): State<AppState>,
+    auth: AuthUser,
+) -> Result<impl IntoResponse, AppError> {
+    auth.authorize(&state.db, Resource::TargetRole, id).await?;
+    let role = sqlx::query_as!(
+        TargetRoleRow,
+        "SELECT * FROM target_roles WHERE id = $1",
+        id
+    )
+    .fetch_optional(&state.db)
+    .await?
+    .ok_or(AppError::NotFound(format!("Target role {id} not found")))?;
+
+    Ok(Json(role))
+}
+
+// ────────────────────────────────────────────────────────────────────────────
+// PATCH /api/v1/target-roles/:id
+// ────────────────────────────────────────────────────────────────────────────
+
+/// Autosaves a target role. Editing `jd_text` drops the cached parse. Not audited:
+/// clients call this on every pause in typing.
+pub async fn handle_update_target_role(
+    Path(id): Path<Uuid>,
+    State(state): State<AppState>,
+    auth: AuthUser,
+    ValidJson(body): ValidJson<UpdateTargetRoleRequest>,
+) -> Result<impl IntoResponse, AppError> {
+    auth.authorize(&state.db, Resource::TargetRole, id).await?;
+
+    let role = sqlx::query_as!(
+        TargetRoleRow,
+        r#"UPDATE target_roles
+           SET title      = COALESCE($2, title),
+               company    = CASE WHEN $3::text IS NULL THEN company
+                                 ELSE NULLIF($3, '') END,
+               parsed_jd  = CASE WHEN $4::text IS NOT NULL AND $4 <> jd_text
+                                 THEN NULL ELSE parsed_jd END,
+               jd_text    = COALESCE($4, jd_text),
+               notes      = CASE WHEN $5::text IS NULL THEN notes
+                                 ELSE NULLIF($5, '') END,
+               updated_at = NOW()
+           WHERE id = $1
+           RETURNING *"#,
+        id,
+        body.title.as_deref().map(str::trim),
+        body.company.as_deref().map(str::trim),
+        body.jd_text.as_deref(),
+        body.notes.as_deref()
+    )
+    .fetch_optional(&state.db)
+    .await?
+    .ok_or(AppError::NotFound(format!("Target role {id} not found")))?;
+
+    Ok(Json(role))
+}
+
+// ────────────────────────────────────────────────────────────────────────────
+// DELETE /api/v1/target-roles/:id
+// ────────────────────────────────────────────────────────────────────────────
+
+/// Hard-deletes a target role. Resumes generated for it keep their copy of the JD.
+pub async fn handle_delete_target_role(
+    Path(id): Path<Uuid>,
+    State(state): State<AppState>,
+    auth: AuthUser,
+) -> Result<impl IntoResponse, AppError> {
+    auth.authorize(&state.db, Resource::TargetRole, id).await?;
+
+    let rows = sqlx::query!("DELETE FROM target_roles WHERE id = $1", id)
+        .execute(&state.db)
+        .await?
+        .rows_affected();
+    if rows == 0 {
+        return Err(AppError::NotFound(format!("Target role {id} not found")));
+    }
+    audit::record(
+        &state.db,
+        auth.user_id,
+        AuditEvent::new(Action::TargetRoleDelete, id),
+    )
+    .await;
+
+    Ok(StatusCode::NO_CONTENT)
+}
+
+// ────────────────────────────────────────────────────────────────────────────
+// POST /api/v1/target-roles/:id/parse
+// ────────────────────────────────────────────────────────────────────────────
+
+/// Parses the role's JD text, or returns the stored parse when the text has not
+/// changed since. 400 while the role has no JD text.
+pub async fn handle_parse_target_role(
+    Path(id): Path<Uuid>,
+    State(state): State<AppState>,
+    auth: AuthUser,
+) -> Result<Json<ParseTargetRoleResponse>, AppError> {
+    auth.authorize(&state.db, Resource::TargetRole, id).await?;
+    let (jd_text, stored) = load_jd(&state.db, auth.user_id, id).await?;
+    let response = match stored {
+        Some(parsed_jd) => ParseTargetRoleResponse {
+            parsed_jd,
+            cached: true,
+        },
+        None => {
+            let parsed_jd = quota::metered_llm(
+                &state.db,
+                &state.redis,
+                auth.user_id,
+                parse_jd(&jd_text, &state.llm),
+            )
+            .await?;
+            store_parse(&state.db, id, &jd_text, &parsed_jd).await?;
+            ParseTargetRoleResponse {
+                parsed_jd,
+                cached: false,
+            }
+        }
+    };
+    Ok(Json(response))
+}
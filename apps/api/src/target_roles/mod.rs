@@ -0,0 +1,171 @@
+//! Target roles module — job descriptions saved before generation.
+//!
+//! A target role is a job the user is considering: title, company, the JD text and
+//! their notes. Clients autosave the draft with PATCH as the user types, so a pipeline
+//! of roles builds up and can be picked up later. Generation takes a `target_role_id`
+//! in place of `jd_text`; the resume links back to the role.
+//!
+//! `parsed_jd` caches `parse_jd`'s output for `jd_text` (filled by
+//! `POST /target-roles/:id/parse` or the first generation). Changing `jd_text` clears
+//! it. Concurrent autosaves are last-write-wins.
+//!
+//! Types and storage live here; handlers in handlers.rs.
+
+pub mod handlers;
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use sqlx::{FromRow, PgPool};
+use uuid::Uuid;
+use validator::Validate;
+
+use crate::applications::MAX_NOTES_CHARS;
+use crate::errors::AppError;
+use crate::generation::jd_parser::ParsedJD;
+use crate::validation::{not_blank, MAX_JD_TEXT_CHARS, MAX_NAME_CHARS};
+
+// ────────────────────────────────────────────────────────────────────────────
+// DB row type
+// ────────────────────────────────────────────────────────────────────────────
+
+/// A row from the `target_roles` table.
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
+pub struct TargetRoleRow {
+    pub id: Uuid,
+    pub user_id: Uuid,
+    pub title: String,
+    pub company: Option<String>,
+    /// Empty until the user pastes the posting.
+    pub jd_text: String,
+    /// Serialized `ParsedJD` of `jd_text`; None until parsed.
+    pub parsed_jd: Option<Value>,
+    pub notes: Option<String>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+// ────────────────────────────────────────────────────────────────────────────
+// Request / response types
+// ────────────────────────────────────────────────────────────────────────────
+
+/// Body for `POST /api/v1/target-roles`.
+#[derive(Debug, Deserialize, Validate)]
+pub struct CreateTargetRoleRequest {
+    #[validate(custom(function = "not_blank"), length(max = MAX_NAME_CHARS))]
+    pub title: String,
+    #[validate(length(max = MAX_NAME_CHARS))]
+    pub company: Option<String>,
+    #[validate(length(max = MAX_JD_TEXT_CHARS))]
+    pub jd_text: Option<String>,
+    #[validate(length(max = MAX_NOTES_CHARS))]
+    pub notes: Option<String>,
+}
+
+/// Body for `PATCH /api/v1/target-roles/:id` — the autosave. Absent fields are left
+/// unchanged; an empty string clears `company`, `jd_text` or `notes`.
+#[derive(Debug, Deserialize, Validate)]
+pub struct UpdateTargetRoleRequest {
+    #[validate(custom(function = "not_blank"), length(max = MAX_NAME_CHARS))]
+    pub title: Option<String>,
+    #[validate(length(max = MAX_NAME_CHARS))]
+    pub company: Option<String>,
+    #[validate(length(max = MAX_JD_TEXT_CHARS))]
+    pub jd_text: Option<String>,
+    #[validate(length(max = MAX_NOTES_CHARS))]
+    pub notes: Option<String>,
+}
+
+/// Response body for `POST /api/v1/target-roles/:id/parse`.
+#[derive(Debug, Serialize)]
+pub struct ParseTargetRoleResponse {
+    pub parsed_jd: ParsedJD,
+    /// True when served from the role's stored parse, without an LLM call.
+    pub cached: bool,
+}
+
+// ────────────────────────────────────────────────────────────────────────────
+// Generation input
+// ────────────────────────────────────────────────────────────────────────────
+
+/// The role's JD text, and its stored parse if there is one. 404 unless the role is
+/// `user_id`'s; 400 while it has no JD text.
+pub async fn load_jd(
+    db: &PgPool,
+    user_id: Uuid,
+    role_id: Uuid,
+) -> Result<(String, Option<ParsedJD>), AppError> {
+    let row = sqlx::query!(
+        "SELECT jd_text, parsed_jd FROM target_roles WHERE id = $1 AND user_id = $2",
+        role_id,
+        user_id
+    )
+    .fetch_optional(db)
+    .await?
+    .ok_or_else(|| AppError::NotFound(format!("Target role {role_id} not found")))?;
+    if row.jd_text.trim().is_empty() {
+        return Err(AppError::Validation(format!(
+            "Target role {role_id} has no job description yet"
+        )));
+    }
+    // A parse stored by an older ParsedJD shape is re-parsed rather than failing.
+    let parsed_jd = row
+        .parsed_jd
+        .and_then(|value| serde_json::from_value(value).ok());
+    Ok((row.jd_text, parsed_jd))
+}
+
+/// Stores `parsed_jd` as the role's parse of `jd_text` — unless the text was edited
+/// since it was read, in which case the parse is stale and dropped.
+pub async fn store_parse(
+    db: &PgPool,
+    role_id: Uuid,
+    jd_text: &str,
+    parsed_jd: &ParsedJD,
+) -> Result<(), AppError> {
+    let value = serde_json::to_value(parsed_jd)
+        .map_err(|e| AppError::Internal(anyhow::anyhow!("ParsedJD encoding: {e}")))?;
+    sqlx::query!(
+        "UPDATE target_roles SET parsed_jd = $3 WHERE id = $1 AND jd_text = $2",
+        role_id,
+        jd_text,
+        value
+    )
+    .execute(db)
+    .await?;
+    Ok(())
+}
+
+// ────────────────────────────────────────────────────────────────────────────
+// Tests
+// ────────────────────────────────────────────────────────────────────────────
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_create_request_needs_only_a_title() {
+        let body: CreateTargetRoleRequest =
+            serde_json::from_str(r#"{"title": "Staff Engineer"}"#).unwrap();
+        assert!(body.validate().is_ok());
+        assert!(body.jd_text.is_none() && body.company.is_none());
+
+        let blank: CreateTargetRoleRequest = serde_json::from_str(r#"{"title": " "}"#).unwrap();
+        assert!(blank
+            .validate()
+            .unwrap_err()
+            .field_errors()
+            .contains_key("title"));
+    }
+
+    #[test]
+    fn test_autosave_accepts_empty_text_but_not_a_blank_title() {
+        let body: UpdateTargetRoleRequest =
+            serde_json::from_str(r#"{"jd_text": "", "notes": ""}"#).unwrap();
+        assert!(body.validate().is_ok());
+
+        let body: UpdateTargetRoleRequest = serde_json::from_str(r#"{"title": ""}"#).unwrap();
+        assert!(body.validate().is_err());
+    }
+}
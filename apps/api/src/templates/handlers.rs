@@ -1,10 +1,9 @@
 //! Axum handlers for the template API.
 //!
-//! GET /api/v1/templates              — list all available templates (no DB, no alloc in hot path)
+//! GET /api/v1/templates              — list all available templates and render styles (no DB)
 //! GET /api/v1/templates/:id/preview  — proxy thumbnail PNG from S3 (404 if unknown template)
 //! GET /api/v1/templates/:id/render-pdf — compile template LaTeX → PDF; response cached in-memory
 
-use aws_sdk_s3::Client as S3Client;
 use axum::{
     body::{Body, Bytes},
     extract::{Path, State},
@@ -15,6 +14,7 @@ use axum::{
 use tracing::{debug, error, info, warn};
 use uuid::Uuid;
 
+use crate::render::templates::style_catalog;
 use crate::state::AppState;
 use crate::templates::TemplateSummary;
 
@@ -22,7 +22,9 @@ use crate::templates::TemplateSummary;
 // GET /api/v1/templates
 // ────────────────────────────────────────────────────────────────────────────
 
-/// Lists all available resume templates.
+/// Lists all available resume templates, and the five render styles with their
+/// descriptions, ATS-safety rating and supported page settings (`styles`), so clients
+/// need not hardcode them.
 ///
 /// Performance: reads from the in-memory TemplateCache (RwLock read lock).
 /// No DB query, no network call — expected < 1ms even with many templates.
@@ -60,7 +62,7 @@ pub async fn handle_list_templates(State(state): State<AppState>) -> impl IntoRe
             (header::CACHE_CONTROL, "public, max-age=300"),
             (header::CONTENT_TYPE, "application/json"),
         ],
-        Json(serde_json::json!({ "templates": summaries })),
+        Json(serde_json::json!({ "templates": summaries, "styles": style_catalog() })),
     )
 }
 
@@ -102,8 +104,8 @@ pub async fn handle_template_preview(
         }
     };
 
-    // Fetch thumbnail from S3
-    match fetch_s3_png(&state.s3, &state.config.s3_bucket, &thumbnail_key).await {
+    // Fetch thumbnail from blob storage
+    match state.blobs.get(&thumbnail_key).await {
         Ok(bytes) => {
             // Return PNG bytes with appropriate headers
             Response::builder()
@@ -416,23 +418,3 @@ pub async fn handle_template_thumbnail_pdf(
         }
     }
 }
-
-/// Fetches an S3 object and returns its bytes.
-async fn fetch_s3_png(s3: &S3Client, bucket: &str, key: &str) -> anyhow::Result<Vec<u8>> {
-    let output = s3
-        .get_object()
-        .bucket(bucket)
-        .key(key)
-        .send()
-        .await
-        .map_err(|e| anyhow::anyhow!("S3 get_object failed: {e}"))?;
-
-    let bytes = output
-        .body
-        .collect()
-        .await
-        .map_err(|e| anyhow::anyhow!("S3 body read failed: {e}"))?
-        .into_bytes();
-
-    Ok(bytes.to_vec())
-}
@@ -24,6 +24,7 @@ use serde::{Deserialize, Serialize};
 use tracing::{info, warn};
 
 use crate::render::templates::escape_latex;
+use crate::storage::BlobStore;
 
 // ────────────────────────────────────────────────────────────────────────────
 // Public types
@@ -56,6 +57,56 @@ pub struct ProfileData {
     pub website: String,
 }
 
+impl ProfileData {
+    /// Builds profile data from a `profile` context entry's JSONB `data`.
+    ///
+    /// Extraction is lenient — missing or non-string keys become empty strings.
+    pub fn from_entry_data(data: &serde_json::Value) -> Self {
+        let get_str = |key: &str| -> String {
+            data.get(key)
+                .and_then(|v| v.as_str())
+                .unwrap_or("")
+                .to_string()
+        };
+
+        Self {
+            full_name: get_str("full_name"),
+            email: get_str("email"),
+            phone: get_str("phone"),
+            location: get_str("location"),
+            linkedin: get_str("linkedin"),
+            website: get_str("website"),
+        }
+    }
+
+    /// Plain-text header lines: the name, then one contact line joined with " | ".
+    ///
+    /// Empty fields are skipped, and an entirely empty profile yields no lines.
+    /// Layout accounting and the minimal render path both use this, so the header
+    /// that is counted is the header that is printed.
+    pub fn header_lines(&self) -> Vec<String> {
+        let mut lines = Vec::new();
+        if !self.full_name.trim().is_empty() {
+            lines.push(self.full_name.trim().to_string());
+        }
+        let contact: Vec<&str> = [
+            &self.email,
+            &self.phone,
+            &self.location,
+            &self.linkedin,
+            &self.website,
+        ]
+        .into_iter()
+        .map(|f| f.trim())
+        .filter(|f| !f.is_empty())
+        .collect();
+        if !contact.is_empty() {
+            lines.push(contact.join(" | "));
+        }
+        lines
+    }
+}
+
 /// A single section of sample content used to render template thumbnails.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct SampleSection {
@@ -323,14 +374,10 @@ fn build_sections_latex(sections: &[SampleSection]) -> String {
 /// Design decisions:
 /// - Runs as a fire-and-forget background task — does NOT block server readiness.
 ///   Server can answer requests while thumbnails are being computed.
-/// - Idempotent: checks S3 before computing. Restarting the server is cheap.
+/// - Idempotent: checks blob storage before computing. Restarting the server is cheap.
 /// - If pdftoppm is unavailable (local dev without poppler-utils), it logs a
 ///   warning and skips — the frontend falls back gracefully to a placeholder.
-pub async fn precompute_thumbnails(
-    cache: Arc<TemplateCache>,
-    s3: aws_sdk_s3::Client,
-    s3_bucket: String,
-) {
+pub async fn precompute_thumbnails(cache: Arc<TemplateCache>, blobs: Arc<dyn BlobStore>) {
     // Snapshot the template IDs we need to process (quick read lock, released immediately)
     let templates: Vec<(String, Arc<LoadedTemplate>)> = {
         let guard = cache.read().await;
@@ -343,10 +390,10 @@ pub async fn precompute_thumbnails(
     for (template_id, template) in templates {
         let thumbnail_key = template.metadata.thumbnail_s3_key.clone();
 
-        // IMPORTANT: S3 HEAD check before running pdflatex. Without this guard,
+        // IMPORTANT: existence check before running pdflatex. Without this guard,
         // every API restart would re-render and re-upload all thumbnails — expensive.
-        // The HEAD check is cheap (<10ms) and makes thumbnail generation idempotent.
-        if s3_object_exists(&s3, &s3_bucket, &thumbnail_key).await {
+        // The check is cheap (a HEAD on S3) and makes thumbnail generation idempotent.
+        if blobs.exists(&thumbnail_key).await.unwrap_or(false) {
             info!(
                 template_id = %template_id,
                 key = %thumbnail_key,
@@ -417,7 +464,7 @@ pub async fn precompute_thumbnails(
         };
 
         // Upload PNG to S3
-        match upload_thumbnail(&s3, &s3_bucket, &thumbnail_key, png_bytes).await {
+        match blobs.put(&thumbnail_key, png_bytes, "image/png").await {
             Ok(_) => info!(
                 "Thumbnail for '{}' uploaded to S3: '{}'",
                 template_id, thumbnail_key
@@ -427,17 +474,6 @@ pub async fn precompute_thumbnails(
     }
 }
 
-/// Returns true if an object exists in S3 at the given key.
-/// Uses HEAD (metadata only) — does not download the object body.
-async fn s3_object_exists(s3: &aws_sdk_s3::Client, bucket: &str, key: &str) -> bool {
-    s3.head_object()
-        .bucket(bucket)
-        .key(key)
-        .send()
-        .await
-        .is_ok()
-}
-
 /// Converts PDF bytes to a PNG thumbnail using `pdftoppm` from poppler-utils.
 ///
 /// `pdftoppm -png -r 96 -singlefile` renders only page 1 at 96 DPI.
@@ -481,29 +517,6 @@ async fn pdf_to_png(pdf_bytes: Vec<u8>) -> Result<Vec<u8>> {
     Ok(bytes)
 }
 
-/// Uploads a PNG to S3 at the given key.
-async fn upload_thumbnail(
-    s3: &aws_sdk_s3::Client,
-    bucket: &str,
-    key: &str,
-    png_bytes: Vec<u8>,
-) -> Result<()> {
-    use aws_sdk_s3::primitives::ByteStream;
-
-    s3.put_object()
-        .bucket(bucket)
-        .key(key)
-        .body(ByteStream::from(png_bytes))
-        .content_type("image/png")
-        // Public-read caching hint — the thumbnail PNG is static content
-        .cache_control("public, max-age=86400")
-        .send()
-        .await
-        .map_err(|e| anyhow::anyhow!("S3 upload error: {e}"))?;
-
-    Ok(())
-}
-
 // ────────────────────────────────────────────────────────────────────────────
 // Tests
 // ────────────────────────────────────────────────────────────────────────────
@@ -572,6 +585,19 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_header_lines_skip_empty_fields() {
+        let profile = sample_profile();
+        let lines = profile.header_lines();
+        assert_eq!(lines.len(), 2, "name line + contact line");
+        assert_eq!(lines[0], profile.full_name);
+        assert!(
+            !lines[1].ends_with(" | "),
+            "no orphaned separator for empty website"
+        );
+        assert!(ProfileData::default().header_lines().is_empty());
+    }
+
     #[test]
     fn test_build_sections_latex_skips_empty() {
         let sections = sample_sections();
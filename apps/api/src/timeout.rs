@@ -0,0 +1,184 @@
+//! Per-route request timeouts.
+//!
+//! Every routed request is classified by cost and must produce its response within
+//! that class's budget:
+//! - `Pipeline` (LLM-backed generation and ingestion): 5 minutes, enough for several
+//!   Anthropic calls with their retries;
+//! - `Render` (synchronous LaTeX compiles and PDF/blob downloads): 60s;
+//! - `Standard` (everything else — CRUD, status polls, enqueueing jobs): 15s.
+//!
+//! Past the budget the handler is dropped (cancelling its pending DB, S3 or LLM
+//! calls) and the client gets 504 `TIMEOUT`, so a hung dependency cannot hold a
+//! connection open indefinitely. The budget covers producing the response head only:
+//! server-sent event streams keep running after their handler returns.
+
+use std::time::Duration;
+
+use axum::{
+    extract::{MatchedPath, Request},
+    http::Method,
+    middleware::Next,
+    response::{IntoResponse, Response},
+};
+
+use crate::api_version::{V1_PREFIX, V2_PREFIX};
+use crate::errors::AppError;
+
+/// How long a request may take to produce its response, by endpoint cost.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RouteClass {
+    /// Endpoints that call the LLM in the request.
+    Pipeline,
+    /// Endpoints that compile LaTeX or stream a stored PDF or archive.
+    Render,
+    /// Everything else.
+    Standard,
+}
+
+impl RouteClass {
+    pub fn budget(self) -> Duration {
+        Duration::from_secs(match self {
+            RouteClass::Pipeline => 300,
+            RouteClass::Render => 60,
+            RouteClass::Standard => 15,
+        })
+    }
+}
+
+/// Routes (method + matched path below the `/api/vN` prefix) that call the LLM before
+/// responding. Batch generation and batch ingestion are absent: they only enqueue.
+const PIPELINE_ROUTES: &[(Method, &str)] = &[
+    (Method::POST, "/resumes/parse-jd"),
+    (Method::POST, "/resumes/parse-jd-html"),
+    (Method::POST, "/job-postings/import"),
+    (Method::POST, "/target-roles/:id/parse"),
+    (Method::POST, "/resumes/fit-score"),
+    (Method::POST, "/resumes/generate"),
+    (Method::POST, "/resumes/:id/regenerate"),
+    (Method::POST, "/resumes/:id/interview-prep"),
+    (Method::POST, "/resumes/:id/anonymize"),
+    (Method::POST, "/linkedin/generate"),
+    (Method::POST, "/context/ingest"),
+    (Method::POST, "/context/ingest/upload"),
+    (Method::POST, "/context/retag/preview"),
+];
+
+const RENDER_ROUTES: &[(Method, &str)] = &[
+    (Method::GET, "/render/:job_id"),
+    (Method::GET, "/resumes/:id/ats-check"),
+    (Method::GET, "/resumes/:id/export"),
+    (Method::GET, "/templates/:id/preview"),
+    (Method::GET, "/templates/:id/render-pdf"),
+    (Method::GET, "/templates/:id/thumbnail-pdf"),
+    (Method::GET, "/blobs/*key"),
+    (Method::GET, "/shared/:token/pdf"),
+];
+
+/// The class of a request, from its method and matched path.
+pub fn classify(method: &Method, path: &str) -> RouteClass {
+    let route = path
+        .strip_prefix(V1_PREFIX)
+        .or_else(|| path.strip_prefix(V2_PREFIX))
+        .unwrap_or(path);
+    let listed = |routes: &[(Method, &str)]| routes.iter().any(|(m, p)| m == method && *p == route);
+    if listed(PIPELINE_ROUTES) {
+        RouteClass::Pipeline
+    } else if listed(RENDER_ROUTES) {
+        RouteClass::Render
+    } else {
+        RouteClass::Standard
+    }
+}
+
+/// `route_layer` middleware; runs after routing so `MatchedPath` is available.
+pub async fn enforce_timeouts(req: Request, next: Next) -> Response {
+    let path = req
+        .extensions()
+        .get::<MatchedPath>()
+        .map(|p| p.as_str().to_string())
+        .unwrap_or_else(|| req.uri().path().to_string());
+    let budget = classify(req.method(), &path).budget();
+
+    match tokio::time::timeout(budget, next.run(req)).await {
+        Ok(response) => response,
+        Err(_) => {
+            tracing::error!(route = %path, budget_secs = budget.as_secs(), "Request timed out");
+            AppError::Timeout {
+                budget_secs: budget.as_secs(),
+            }
+            .into_response()
+        }
+    }
+}
+
+// ────────────────────────────────────────────────────────────────────────────
+// Tests
+// ────────────────────────────────────────────────────────────────────────────
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use axum::{body::Body, http::StatusCode, middleware, routing::get, Router};
+    use tower::ServiceExt;
+
+    #[test]
+    fn test_classify_route_classes() {
+        assert_eq!(
+            classify(&Method::POST, "/api/v1/resumes/generate"),
+            RouteClass::Pipeline
+        );
+        assert_eq!(
+            classify(&Method::POST, "/api/v2/context/ingest/upload"),
+            RouteClass::Pipeline
+        );
+        assert_eq!(
+            classify(&Method::POST, "/api/v1/target-roles/:id/parse"),
+            RouteClass::Pipeline
+        );
+        assert_eq!(
+            classify(&Method::GET, "/api/v2/templates/:id/render-pdf"),
+            RouteClass::Render
+        );
+        assert_eq!(
+            classify(&Method::GET, "/api/v2/blobs/*key"),
+            RouteClass::Render
+        );
+        // Enqueue-only and plain reads get the short budget.
+        assert_eq!(
+            classify(&Method::POST, "/api/v2/resumes/generate-batch"),
+            RouteClass::Standard
+        );
+        assert_eq!(
+            classify(&Method::POST, "/api/v1/render"),
+            RouteClass::Standard
+        );
+        assert_eq!(
+            classify(&Method::GET, "/api/v1/resumes/generate"),
+            RouteClass::Standard
+        );
+        assert!(RouteClass::Pipeline.budget() > RouteClass::Render.budget());
+        assert!(RouteClass::Render.budget() > RouteClass::Standard.budget());
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_slow_handler_gets_a_504() {
+        let app = Router::new()
+            .route(
+                "/context",
+                get(|| async {
+                    tokio::time::sleep(Duration::from_secs(3600)).await;
+                    "late"
+                }),
+            )
+            .route("/health", get(|| async { "ok" }))
+            .route_layer(middleware::from_fn(enforce_timeouts));
+
+        let request = |uri: &str| Request::builder().uri(uri).body(Body::empty()).unwrap();
+        let response = app.clone().oneshot(request("/context")).await.unwrap();
+        assert_eq!(response.status(), StatusCode::GATEWAY_TIMEOUT);
+
+        let response = app.oneshot(request("/health")).await.unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+    }
+}
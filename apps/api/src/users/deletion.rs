@@ -0,0 +1,353 @@
+//! Account deletion — purge every row and object belonging to a user.
+//!
+//! Order matters:
+//! 1. Collect the user's S3 keys (context snapshots under `contexts/{user_id}/`, data
+//!    exports under `exports/{user_id}/`, résumé PDFs) while the rows that name them
+//!    still exist.
+//! 2. In one transaction, count the rows about to go and delete the `users` row. Every
+//!    user-owned table cascades from it, so nothing is left half-deleted.
+//! 3. Delete the S3 objects. Keys that fail are handed to `PurgeObjectsJob`, which
+//!    keeps retrying after the response has been sent.
+//! 4. Drop the usage counters in Redis.
+//! 5. Write a receipt to `account_deletion_receipts`. It holds counts and ids only —
+//!    proof the deletion happened, not a copy of what was deleted.
+
+use std::collections::BTreeSet;
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use futures::stream::{self, StreamExt};
+use serde::{Deserialize, Serialize};
+use sqlx::{FromRow, PgPool};
+use uuid::Uuid;
+
+use crate::errors::AppError;
+use crate::jobs::{self, Handler, Job, JobError};
+use crate::storage::BlobStore;
+
+/// S3 deletes in flight at once.
+const S3_DELETE_CONCURRENCY: usize = 8;
+
+// ────────────────────────────────────────────────────────────────────────────
+// Receipts
+// ────────────────────────────────────────────────────────────────────────────
+
+/// Rows removed per table.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize, FromRow)]
+pub struct RowCounts {
+    /// Archived versions included.
+    pub context_entries: i64,
+    pub context_snapshots: i64,
+    pub context_ingest_batches: i64,
+    pub context_ingest_items: i64,
+    pub resumes: i64,
+    pub resume_bullets: i64,
+    pub render_jobs: i64,
+    pub generation_batches: i64,
+    pub generation_jobs: i64,
+    pub cv_projects: i64,
+    pub personas: i64,
+    pub audit_log: i64,
+    pub user_exports: i64,
+    pub org_memberships: i64,
+    /// Notes written by the user, on any resume.
+    pub resume_annotations: i64,
+    pub applications: i64,
+    pub user_webhooks: i64,
+    pub resume_shares: i64,
+    pub resume_share_views: i64,
+    /// Comments written by the user, on any resume.
+    pub resume_comments: i64,
+    pub target_roles: i64,
+    pub context_nudge_deliveries: i64,
+}
+
+/// A row from `account_deletion_receipts`, returned by `DELETE /api/v1/users/me`.
+#[derive(Debug, Clone, Serialize, FromRow)]
+pub struct DeletionReceipt {
+    pub id: Uuid,
+    /// Id of the deleted account. The account itself no longer exists.
+    pub user_id: Uuid,
+    pub deleted_at: DateTime<Utc>,
+    pub rows_deleted: sqlx::types::Json<RowCounts>,
+    pub s3_objects_deleted: i32,
+    /// Objects whose delete failed and was queued for retry.
+    pub s3_objects_retrying: i32,
+    pub usage_keys_deleted: i32,
+    pub request_id: Option<String>,
+}
+
+// ────────────────────────────────────────────────────────────────────────────
+// Deletion
+// ────────────────────────────────────────────────────────────────────────────
+
+/// Deletes `user_id` and everything they own, and returns the receipt.
+pub async fn delete_account(
+    db: &PgPool,
+    redis: &redis::Client,
+    blobs: &dyn BlobStore,
+    user_id: Uuid,
+) -> Result<DeletionReceipt, AppError> {
+    let keys = collect_s3_keys(db, blobs, user_id).await?;
+
+    let mut tx = db.begin().await?;
+    let rows_deleted = count_rows(&mut tx, user_id).await?;
+    sqlx::query!("DELETE FROM users WHERE id = $1", user_id)
+        .execute(&mut *tx)
+        .await?;
+    tx.commit().await?;
+    tracing::info!(%user_id, "account rows deleted");
+
+    let failed = delete_objects(blobs, &keys).await;
+    if !failed.is_empty() {
+        tracing::warn!(%user_id, count = failed.len(), "S3 deletes failed — queued for retry");
+        let job = PurgeObjectsJob {
+            keys: failed.clone(),
+        };
+        if let Err(e) = jobs::enqueue(redis, &job).await {
+            // The rows are already gone; log the keys so they can be removed by hand.
+            tracing::error!(%user_id, keys = ?failed, "failed to queue S3 purge: {e}");
+        }
+    }
+
+    let usage_keys_deleted = match crate::quota::forget_user(redis, user_id).await {
+        Ok(n) => n,
+        Err(e) => {
+            tracing::warn!(%user_id, "failed to clear usage counters: {e}");
+            0
+        }
+    };
+    if let Err(e) = crate::generation::pipeline_cache::forget_user(redis, user_id).await {
+        // Expires within `pipeline_cache::CACHE_TTL_SECS` regardless.
+        tracing::warn!(%user_id, "failed to clear pipeline cache: {e}");
+    }
+
+    let receipt = sqlx::query_as!(
+        DeletionReceipt,
+        r#"INSERT INTO account_deletion_receipts
+               (user_id, rows_deleted, s3_objects_deleted, s3_objects_retrying,
+                usage_keys_deleted, request_id)
+           VALUES ($1, $2, $3, $4, $5, $6)
+           RETURNING id, user_id, deleted_at,
+                     rows_deleted AS "rows_deleted: sqlx::types::Json<RowCounts>",
+                     s3_objects_deleted, s3_objects_retrying, usage_keys_deleted, request_id"#,
+        user_id,
+        sqlx::types::Json(&rows_deleted) as _,
+        (keys.len() - failed.len()) as i32,
+        failed.len() as i32,
+        usage_keys_deleted as i32,
+        crate::request_id::current()
+    )
+    .fetch_one(db)
+    .await?;
+    Ok(receipt)
+}
+
+/// Every S3 key holding `user_id`'s data: everything under the user's prefixes plus
+/// each résumé's PDF.
+async fn collect_s3_keys(
+    db: &PgPool,
+    blobs: &dyn BlobStore,
+    user_id: Uuid,
+) -> Result<Vec<String>, AppError> {
+    let resumes: Vec<(Uuid, Option<String>)> = sqlx::query!(
+        "SELECT id, s3_pdf_key FROM resumes WHERE user_id = $1",
+        user_id
+    )
+    .fetch_all(db)
+    .await?
+    .into_iter()
+    .map(|row| (row.id, row.s3_pdf_key))
+    .collect();
+
+    let mut listed = vec![];
+    for prefix in user_prefixes(user_id) {
+        let keys = blobs
+            .list(&prefix)
+            .await
+            .map_err(|e| AppError::Internal(e.context("listing the user's objects")))?;
+        listed.extend(keys);
+    }
+    Ok(s3_keys(listed, &resumes))
+}
+
+/// Key prefixes that only ever hold `user_id`'s objects: context snapshots and data
+/// exports.
+fn user_prefixes(user_id: Uuid) -> [String; 2] {
+    [
+        format!("contexts/{user_id}/"),
+        crate::export::user_prefix(user_id),
+    ]
+}
+
+/// Listed keys plus `pdfs/{resume_id}.pdf` and any stored `s3_pdf_key`, sorted and
+/// deduplicated. The conventional PDF key is included even when `s3_pdf_key` is unset,
+/// since a render can upload before the row is updated.
+pub(crate) fn s3_keys(listed: Vec<String>, resumes: &[(Uuid, Option<String>)]) -> Vec<String> {
+    let mut keys: BTreeSet<String> = listed.into_iter().collect();
+    for (resume_id, stored) in resumes {
+        keys.insert(format!("pdfs/{resume_id}.pdf"));
+        keys.extend(stored.iter().filter(|k| !k.is_empty()).cloned());
+    }
+    keys.into_iter().collect()
+}
+
+async fn count_rows(
+    tx: &mut sqlx::Transaction<'_, sqlx::Postgres>,
+    user_id: Uuid,
+) -> Result<RowCounts, AppError> {
+    let counts = sqlx::query_as!(
+        RowCounts,
+        r#"SELECT
+             (SELECT COUNT(*) FROM context_entries WHERE user_id = $1)
+           + (SELECT COUNT(*) FROM context_entries_archive WHERE user_id = $1) AS "context_entries!",
+             (SELECT COUNT(*) FROM context_snapshots WHERE user_id = $1)      AS "context_snapshots!",
+             (SELECT COUNT(*) FROM context_ingest_batches WHERE user_id = $1) AS "context_ingest_batches!",
+             (SELECT COUNT(*) FROM context_ingest_items WHERE user_id = $1)   AS "context_ingest_items!",
+             (SELECT COUNT(*) FROM resumes WHERE user_id = $1)                AS "resumes!",
+             (SELECT COUNT(*) FROM resume_bullets b JOIN resumes r ON r.id = b.resume_id
+               WHERE r.user_id = $1)                                          AS "resume_bullets!",
+             (SELECT COUNT(*) FROM render_jobs j JOIN resumes r ON r.id = j.resume_id
+               WHERE r.user_id = $1)                                          AS "render_jobs!",
+             (SELECT COUNT(*) FROM generation_batches WHERE user_id = $1)     AS "generation_batches!",
+             (SELECT COUNT(*) FROM generation_jobs WHERE user_id = $1)        AS "generation_jobs!",
+             (SELECT COUNT(*) FROM cv_projects WHERE user_id = $1)            AS "cv_projects!",
+             (SELECT COUNT(*) FROM personas WHERE user_id = $1)               AS "personas!",
+             (SELECT COUNT(*) FROM audit_log WHERE user_id = $1)              AS "audit_log!",
+             (SELECT COUNT(*) FROM user_exports WHERE user_id = $1)           AS "user_exports!",
+             (SELECT COUNT(*) FROM org_memberships WHERE user_id = $1)        AS "org_memberships!",
+             (SELECT COUNT(*) FROM resume_annotations WHERE author_id = $1)   AS "resume_annotations!",
+             (SELECT COUNT(*) FROM applications WHERE user_id = $1)           AS "applications!",
+             (SELECT COUNT(*) FROM user_webhooks WHERE user_id = $1)          AS "user_webhooks!",
+             (SELECT COUNT(*) FROM resume_shares WHERE user_id = $1)          AS "resume_shares!",
+             (SELECT COUNT(*) FROM resume_share_views v JOIN resume_shares s ON s.id = v.share_id
+               WHERE s.user_id = $1)                                          AS "resume_share_views!",
+             (SELECT COUNT(*) FROM resume_comments WHERE author_id = $1)      AS "resume_comments!",
+             (SELECT COUNT(*) FROM target_roles WHERE user_id = $1)           AS "target_roles!",
+             (SELECT COUNT(*) FROM context_nudge_deliveries WHERE user_id = $1) AS "context_nudge_deliveries!""#,
+        user_id
+    )
+    .fetch_one(&mut **tx)
+    .await?;
+    Ok(counts)
+}
+
+/// Deletes `keys` concurrently; returns the keys whose delete failed.
+pub(crate) async fn delete_objects(blobs: &dyn BlobStore, keys: &[String]) -> Vec<String> {
+    let results: Vec<Option<String>> = stream::iter(keys.iter().cloned())
+        .map(|key| async move {
+            let result = blobs.delete(&key).await;
+            result.err().map(|e| {
+                tracing::warn!(key, "object delete failed: {e}");
+                key
+            })
+        })
+        .buffer_unordered(S3_DELETE_CONCURRENCY)
+        .collect()
+        .await;
+    results.into_iter().flatten().collect()
+}
+
+// ────────────────────────────────────────────────────────────────────────────
+// Retry job
+// ────────────────────────────────────────────────────────────────────────────
+
+/// S3 objects whose first delete failed, from an account deletion or a retention
+/// sweep.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct PurgeObjectsJob {
+    pub keys: Vec<String>,
+}
+
+impl Job for PurgeObjectsJob {
+    const QUEUE: &'static str = "purge_objects";
+    const MAX_ATTEMPTS: u32 = 8;
+}
+
+pub struct ObjectPurger {
+    pub blobs: Arc<dyn BlobStore>,
+}
+
+#[async_trait]
+impl Handler for ObjectPurger {
+    type Job = PurgeObjectsJob;
+
+    async fn run(&self, job: &PurgeObjectsJob) -> Result<(), JobError> {
+        // S3 deletes are idempotent, so retrying the whole list is safe.
+        let failed = delete_objects(&*self.blobs, &job.keys).await;
+        if failed.is_empty() {
+            return Ok(());
+        }
+        Err(JobError::Retry(anyhow::anyhow!(
+            "{} of {} deletes failed",
+            failed.len(),
+            job.keys.len()
+        )))
+    }
+
+    async fn on_dead(&self, job: &PurgeObjectsJob, error: &str) {
+        tracing::error!(keys = ?job.keys, error, "S3 purge gave up — remove these keys by hand");
+    }
+}
+
+// ────────────────────────────────────────────────────────────────────────────
+// Tests
+// ────────────────────────────────────────────────────────────────────────────
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_s3_keys_include_conventional_pdf_and_dedupe() {
+        let user_id = Uuid::new_v4();
+        let (rendered, unrendered) = (Uuid::new_v4(), Uuid::new_v4());
+        let [contexts, _] = user_prefixes(user_id);
+        let snapshots = vec![format!("{contexts}v1.md"), format!("{contexts}v2.md")];
+        let resumes = vec![
+            (rendered, Some(format!("pdfs/{rendered}.pdf"))),
+            (unrendered, None),
+        ];
+
+        let keys = s3_keys(snapshots, &resumes);
+        assert_eq!(keys.len(), 4);
+        assert!(keys.contains(&format!("pdfs/{unrendered}.pdf")));
+        assert!(keys.contains(&format!("contexts/{user_id}/v2.md")));
+    }
+
+    #[test]
+    fn test_s3_keys_keep_non_conventional_stored_key() {
+        let resume_id = Uuid::new_v4();
+        let keys = s3_keys(
+            vec![],
+            &[(resume_id, Some("legacy/resume.pdf".to_string()))],
+        );
+        assert_eq!(
+            keys,
+            vec![
+                "legacy/resume.pdf".to_string(),
+                format!("pdfs/{resume_id}.pdf")
+            ]
+        );
+    }
+
+    #[test]
+    fn test_user_prefixes_cover_exports() {
+        let (user_id, export_id) = (Uuid::new_v4(), Uuid::new_v4());
+        let [_, exports] = user_prefixes(user_id);
+        assert!(crate::export::s3_key(user_id, export_id).starts_with(&exports));
+    }
+
+    #[test]
+    fn test_receipt_counts_serialize_by_table() {
+        let counts = RowCounts {
+            resumes: 2,
+            resume_bullets: 14,
+            ..Default::default()
+        };
+        let json = serde_json::to_value(&counts).unwrap();
+        assert_eq!(json["resume_bullets"], 14);
+        assert_eq!(json["audit_log"], 0);
+    }
+}
@@ -0,0 +1,259 @@
+//! Axum handlers for signup, login and the caller's profile.
+
+use axum::{extract::State, http::StatusCode, response::IntoResponse, Json};
+use uuid::Uuid;
+
+use crate::audit::{self, Action, AuditEvent};
+use crate::auth::password::{hash_password, validate_password, verify_password};
+use crate::auth::AuthUser;
+use crate::errors::AppError;
+use crate::models::user::User;
+use crate::state::AppState;
+use crate::users::deletion::{delete_account, DeletionReceipt};
+use crate::users::preferences::{self, Preferences};
+use crate::users::{
+    confirms_email, normalize_display_name, normalize_email, AuthResponse, DeleteMeRequest,
+    LoginRequest, SignupRequest, UpdateMeRequest,
+};
+
+// ────────────────────────────────────────────────────────────────────────────
+// POST /api/v1/auth/signup
+// ────────────────────────────────────────────────────────────────────────────
+
+/// Creates a password account on the free tier and signs it in.
+/// Returns 409 if the email is already registered.
+pub async fn handle_signup(
+    State(state): State<AppState>,
+    Json(body): Json<SignupRequest>,
+) -> Result<impl IntoResponse, AppError> {
+    let email = normalize_email(&body.email)?;
+    let display_name = normalize_display_name(body.display_name.as_deref())?;
+    validate_password(&body.password)?;
+
+    let password_hash = hash_off_thread(body.password).await?;
+    let id = Uuid::new_v4();
+
+    let user = sqlx::query_as!(
+        User,
+        r#"INSERT INTO users (id, external_id, email, password_hash, display_name)
+           VALUES ($1, $2, $3, $4, $5)
+           RETURNING *"#,
+        id,
+        format!("local:{id}"),
+        &email,
+        &password_hash,
+        display_name.as_deref()
+    )
+    .fetch_one(&state.db)
+    .await
+    .map_err(|e| email_conflict(e, &email))?;
+
+    tracing::info!(user_id = %user.id, "user signed up");
+    audit::record(
+        &state.db,
+        user.id,
+        AuditEvent::new(Action::UserSignup, user.id),
+    )
+    .await;
+    Ok((StatusCode::CREATED, Json(auth_response(&state, user))))
+}
+
+// ────────────────────────────────────────────────────────────────────────────
+// POST /api/v1/auth/login
+// ────────────────────────────────────────────────────────────────────────────
+
+/// Exchanges email + password for a token. Unknown email, wrong password and
+/// IdP-provisioned accounts (no password) all return the same 401.
+pub async fn handle_login(
+    State(state): State<AppState>,
+    Json(body): Json<LoginRequest>,
+) -> Result<Json<AuthResponse>, AppError> {
+    let email = normalize_email(&body.email).map_err(|_| AppError::Unauthorized)?;
+
+    let user = sqlx::query_as!(User, "SELECT * FROM users WHERE email = $1", &email)
+        .fetch_optional(&state.db)
+        .await?
+        .ok_or(AppError::Unauthorized)?;
+    let stored = user.password_hash.clone().ok_or(AppError::Unauthorized)?;
+
+    let password = body.password;
+    let matches = tokio::task::spawn_blocking(move || verify_password(&password, &stored))
+        .await
+        .map_err(|e| AppError::Internal(e.into()))?;
+    if !matches {
+        return Err(AppError::Unauthorized);
+    }
+
+    Ok(Json(auth_response(&state, user)))
+}
+
+// ────────────────────────────────────────────────────────────────────────────
+// GET /api/v1/users/me
+// ────────────────────────────────────────────────────────────────────────────
+
+/// The authenticated user. 401 if the account no longer exists.
+pub async fn handle_get_me(
+    State(state): State<AppState>,
+    auth: AuthUser,
+) -> Result<Json<User>, AppError> {
+    Ok(Json(load_user(&state, auth.user_id).await?))
+}
+
+// ────────────────────────────────────────────────────────────────────────────
+// PATCH /api/v1/users/me
+// ────────────────────────────────────────────────────────────────────────────
+
+/// Updates email and/or display name. An empty `display_name` clears it.
+/// Tier and identity-provider id are not user-editable.
+pub async fn handle_update_me(
+    State(state): State<AppState>,
+    auth: AuthUser,
+    Json(body): Json<UpdateMeRequest>,
+) -> Result<Json<User>, AppError> {
+    let mut user = load_user(&state, auth.user_id).await?;
+    // Field names only — the audit log does not keep personal data.
+    let mut changed_fields = vec![];
+    if let Some(email) = body.email.as_deref() {
+        let email = normalize_email(email)?;
+        if email != user.email {
+            changed_fields.push("email");
+        }
+        user.email = email;
+    }
+    if body.display_name.is_some() {
+        let display_name = normalize_display_name(body.display_name.as_deref())?;
+        if display_name != user.display_name {
+            changed_fields.push("display_name");
+        }
+        user.display_name = display_name;
+    }
+
+    let user = sqlx::query_as!(
+        User,
+        r#"UPDATE users
+           SET email = $2, display_name = $3, updated_at = NOW()
+           WHERE id = $1
+           RETURNING *"#,
+        user.id,
+        &user.email,
+        user.display_name.as_deref()
+    )
+    .fetch_one(&state.db)
+    .await
+    .map_err(|e| email_conflict(e, &user.email))?;
+    audit::record(
+        &state.db,
+        user.id,
+        AuditEvent::new(Action::UserUpdate, user.id)
+            .after(serde_json::json!({ "fields": changed_fields })),
+    )
+    .await;
+
+    Ok(Json(user))
+}
+
+// ────────────────────────────────────────────────────────────────────────────
+// DELETE /api/v1/users/me
+// ────────────────────────────────────────────────────────────────────────────
+
+/// Permanently deletes the account: every context entry, snapshot, résumé, bullet,
+/// render job, batch, project and audit entry, the S3 snapshots and PDFs, and the
+/// usage counters. `confirm_email` must match the account email (400 otherwise).
+/// Returns the deletion receipt.
+pub async fn handle_delete_me(
+    State(state): State<AppState>,
+    auth: AuthUser,
+    Json(body): Json<DeleteMeRequest>,
+) -> Result<Json<DeletionReceipt>, AppError> {
+    let user = load_user(&state, auth.user_id).await?;
+    if !confirms_email(&body.confirm_email, &user.email) {
+        return Err(AppError::Validation(
+            "confirm_email does not match the account email".to_string(),
+        ));
+    }
+
+    let receipt = delete_account(&state.db, &state.redis, &*state.blobs, user.id).await?;
+    tracing::info!(user_id = %user.id, receipt = %receipt.id, "account deleted");
+    Ok(Json(receipt))
+}
+
+// ────────────────────────────────────────────────────────────────────────────
+// GET /api/v1/users/me/preferences
+// ────────────────────────────────────────────────────────────────────────────
+
+/// The caller's generation defaults; every field unset if they never saved any.
+pub async fn handle_get_preferences(
+    State(state): State<AppState>,
+    auth: AuthUser,
+) -> Result<Json<Preferences>, AppError> {
+    Ok(Json(preferences::load(&state.db, auth.user_id).await?))
+}
+
+// ────────────────────────────────────────────────────────────────────────────
+// PUT /api/v1/users/me/preferences
+// ────────────────────────────────────────────────────────────────────────────
+
+/// Replaces the caller's generation defaults. Omitted fields are cleared. 400 for a
+/// font size off the step list, weights that do not sum to 1, or an unknown template.
+pub async fn handle_put_preferences(
+    State(state): State<AppState>,
+    auth: AuthUser,
+    Json(body): Json<Preferences>,
+) -> Result<Json<Preferences>, AppError> {
+    body.validate()?;
+    if let Some(template_id) = &body.template_id {
+        if !state.template_cache.read().await.contains_key(template_id) {
+            return Err(AppError::Validation(format!(
+                "unknown template_id '{template_id}'"
+            )));
+        }
+    }
+
+    let current = preferences::load(&state.db, auth.user_id).await?;
+    preferences::save(&state.db, auth.user_id, &body).await?;
+    audit::record(
+        &state.db,
+        auth.user_id,
+        AuditEvent::new(Action::UserPreferencesUpdate, auth.user_id)
+            .after(serde_json::json!({ "fields": current.changed_fields(&body) })),
+    )
+    .await;
+
+    Ok(Json(body))
+}
+
+// ────────────────────────────────────────────────────────────────────────────
+// Helpers
+// ────────────────────────────────────────────────────────────────────────────
+
+async fn load_user(state: &AppState, user_id: Uuid) -> Result<User, AppError> {
+    sqlx::query_as!(User, "SELECT * FROM users WHERE id = $1", user_id)
+        .fetch_optional(&state.db)
+        .await?
+        .ok_or(AppError::Unauthorized)
+}
+
+fn auth_response(state: &AppState, user: User) -> AuthResponse {
+    AuthResponse {
+        token: state.jwt.issue(user.id),
+        expires_in: state.jwt.ttl_secs(),
+        user,
+    }
+}
+
+/// PBKDF2 is deliberately slow — keep it off the async executor.
+async fn hash_off_thread(password: String) -> Result<String, AppError> {
+    tokio::task::spawn_blocking(move || hash_password(&password))
+        .await
+        .map_err(|e| AppError::Internal(e.into()))?
+}
+
+/// Maps the `users.email` unique violation to 409; other errors pass through.
+fn email_conflict(err: sqlx::Error, email: &str) -> AppError {
+    match &err {
+        sqlx::Error::Database(db) if db.is_unique_violation() => {
+            AppError::Conflict(format!("An account for {email} already exists"))
+        }
+        _ => AppError::Database(err),
+    }
+}
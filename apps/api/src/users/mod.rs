@@ -0,0 +1,157 @@
+//! Users module — password signup/login and the caller's own profile.
+//!
+//! POST  /api/v1/auth/signup  — create a password account, returns a token
+//! POST  /api/v1/auth/login   — exchange email + password for a token
+//! GET   /api/v1/users/me     — the authenticated user
+//! PATCH /api/v1/users/me     — update email and/or display name
+//! DELETE /api/v1/users/me    — delete the account and all its data, returns a receipt
+//! GET   /api/v1/users/me/preferences — saved generation defaults
+//! PUT   /api/v1/users/me/preferences — replace them
+//!
+//! Types live here; handlers in handlers.rs; account deletion in deletion.rs;
+//! generation defaults in preferences.rs.
+
+pub mod deletion;
+pub mod handlers;
+pub mod preferences;
+
+use serde::{Deserialize, Serialize};
+
+use crate::errors::AppError;
+use crate::models::user::User;
+
+/// Longest accepted display name, in characters.
+pub const MAX_DISPLAY_NAME_LEN: usize = 100;
+
+// ────────────────────────────────────────────────────────────────────────────
+// Request / response types
+// ────────────────────────────────────────────────────────────────────────────
+
+/// Body for `POST /api/v1/auth/signup`.
+#[derive(Debug, Deserialize)]
+pub struct SignupRequest {
+    pub email: String,
+    pub password: String,
+    #[serde(default)]
+    pub display_name: Option<String>,
+}
+
+/// Body for `POST /api/v1/auth/login`.
+#[derive(Debug, Deserialize)]
+pub struct LoginRequest {
+    pub email: String,
+    pub password: String,
+}
+
+/// Returned by signup and login.
+#[derive(Debug, Serialize)]
+pub struct AuthResponse {
+    /// Bearer token for the `Authorization` header.
+    pub token: String,
+    /// Token lifetime in seconds.
+    pub expires_in: i64,
+    pub user: User,
+}
+
+/// Body for `PATCH /api/v1/users/me` — absent fields are left unchanged.
+#[derive(Debug, Deserialize)]
+pub struct UpdateMeRequest {
+    pub email: Option<String>,
+    pub display_name: Option<String>,
+}
+
+/// Body for `DELETE /api/v1/users/me`. The account's email, typed again, so a stray
+/// request with a valid token can't wipe an account.
+#[derive(Debug, Deserialize)]
+pub struct DeleteMeRequest {
+    pub confirm_email: String,
+}
+
+// ────────────────────────────────────────────────────────────────────────────
+// Validation
+// ────────────────────────────────────────────────────────────────────────────
+
+/// Trims and lowercases `email`; rejects anything without a local part and a dotted
+/// domain. Deliverability is not checked.
+pub fn normalize_email(email: &str) -> Result<String, AppError> {
+    let email = email.trim().to_lowercase();
+    let valid = email.len() <= 254
+        && !email.contains(char::is_whitespace)
+        && email.split_once('@').is_some_and(|(local, domain)| {
+            !local.is_empty()
+                && !domain.contains('@')
+                && domain
+                    .split_once('.')
+                    .is_some_and(|(host, tld)| !host.is_empty() && !tld.is_empty())
+        });
+    if !valid {
+        return Err(AppError::Validation(
+            "email is not a valid address".to_string(),
+        ));
+    }
+    Ok(email)
+}
+
+/// Trims `name`; empty becomes None.
+pub fn normalize_display_name(name: Option<&str>) -> Result<Option<String>, AppError> {
+    let Some(name) = name.map(str::trim).filter(|n| !n.is_empty()) else {
+        return Ok(None);
+    };
+    if name.chars().count() > MAX_DISPLAY_NAME_LEN {
+        return Err(AppError::Validation(format!(
+            "display_name must be at most {MAX_DISPLAY_NAME_LEN} characters"
+        )));
+    }
+    Ok(Some(name.to_string()))
+}
+
+/// Whether `confirm` names the account `email`, ignoring case and surrounding space.
+pub fn confirms_email(confirm: &str, email: &str) -> bool {
+    confirm.trim().eq_ignore_ascii_case(email.trim())
+}
+
+// ────────────────────────────────────────────────────────────────────────────
+// Tests
+// ────────────────────────────────────────────────────────────────────────────
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_normalize_email() {
+        assert_eq!(
+            normalize_email("  Ada@Example.COM ").unwrap(),
+            "ada@example.com"
+        );
+        for bad in [
+            "",
+            "ada",
+            "@example.com",
+            "ada@example",
+            "ada@.com",
+            "a b@x.io",
+            "a@b@c.io",
+        ] {
+            assert!(normalize_email(bad).is_err(), "{bad:?} should be rejected");
+        }
+    }
+
+    #[test]
+    fn test_normalize_display_name() {
+        assert_eq!(normalize_display_name(None).unwrap(), None);
+        assert_eq!(normalize_display_name(Some("   ")).unwrap(), None);
+        assert_eq!(
+            normalize_display_name(Some(" Ada ")).unwrap(),
+            Some("Ada".to_string())
+        );
+        assert!(normalize_display_name(Some(&"x".repeat(MAX_DISPLAY_NAME_LEN + 1))).is_err());
+    }
+
+    #[test]
+    fn test_confirms_email() {
+        assert!(confirms_email(" Ada@Example.com ", "ada@example.com"));
+        assert!(!confirms_email("", "ada@example.com"));
+        assert!(!confirms_email("ada@example.co", "ada@example.com"));
+    }
+}
@@ -0,0 +1,304 @@
+//! Saved defaults for the generation pipeline (`user_preferences`).
+//!
+//! GET /api/v1/users/me/preferences — the caller's preferences (all unset if never saved)
+//! PUT /api/v1/users/me/preferences — replace them; `null` clears a field
+//!
+//! Generation loads these once per request and applies them beneath the request's
+//! own fields: an explicit `paper_size` in the body still wins over the saved one.
+//! `tone` replaces the tone detected from the JD, `scoring_weights` the default
+//! content-selection weights, and `domain_pack` the engineering verb and vague-word
+//! lists (see `crate::generation::domain_pack`). `recency_policy` applies at ingest:
+//! it sets how fast each entry type's recency score decays.
+
+use serde::{Deserialize, Serialize};
+use sqlx::PgPool;
+use uuid::Uuid;
+
+use crate::context::scoring::{RecencyPolicy, ScoringWeights};
+use crate::errors::AppError;
+use crate::generation::domain_pack::DomainPack;
+use crate::generation::jd_parser::JDTone;
+use crate::layout::font_metrics::FONT_SIZE_STEPS_PT;
+use crate::layout::{MarginPreset, PaperSize};
+
+/// How far the scoring weights may sum from 1.
+const WEIGHT_SUM_TOLERANCE: f64 = 0.01;
+
+// ────────────────────────────────────────────────────────────────────────────
+// Types
+// ────────────────────────────────────────────────────────────────────────────
+
+/// Body and response of the preferences endpoints. Every field is optional; unset
+/// leaves the server default in place.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct Preferences {
+    /// A template id from `GET /api/v1/templates`; used for new (not regenerated)
+    /// resumes.
+    #[serde(default)]
+    pub template_id: Option<String>,
+    /// Starting body font size, one of `FONT_SIZE_STEPS_PT`. Layout may still step it
+    /// to fill the page.
+    #[serde(default)]
+    pub font_size_pt: Option<f32>,
+    #[serde(default)]
+    pub paper_size: Option<PaperSize>,
+    #[serde(default)]
+    pub margin_preset: Option<MarginPreset>,
+    #[serde(default)]
+    pub tone: Option<JDTone>,
+    #[serde(default)]
+    pub scoring_weights: Option<ScoringWeights>,
+    /// Verb sets for generation and vague words for ingest. A persona's pack wins.
+    #[serde(default)]
+    pub domain_pack: Option<DomainPack>,
+    /// Recency decay per entry type, for entries ingested from now on. Unset = an
+    /// 18-month half-life, with skills and certifications never decaying.
+    #[serde(default)]
+    pub recency_policy: Option<RecencyPolicy>,
+    #[serde(default)]
+    pub notifications: NotificationSettings,
+}
+
+/// Which events the user wants to hear about by email (see `crate::notifications`);
+/// all off by default. `export_ready` is stored but not yet sent. `context_nudges`
+/// covers stale context (see `context::freshness`).
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct NotificationSettings {
+    #[serde(default)]
+    pub render_complete: bool,
+    #[serde(default)]
+    pub batch_complete: bool,
+    #[serde(default)]
+    pub export_ready: bool,
+    #[serde(default)]
+    pub context_nudges: bool,
+}
+
+// ────────────────────────────────────────────────────────────────────────────
+// Validation
+// ────────────────────────────────────────────────────────────────────────────
+
+impl Preferences {
+    /// Checks the fields that serde cannot: font size steps, scoring weights and
+    /// decay curves.
+    /// `template_id` is checked by the handler against the template cache.
+    pub fn validate(&self) -> Result<(), AppError> {
+        if let Some(size) = self.font_size_pt {
+            if !FONT_SIZE_STEPS_PT
+                .iter()
+                .any(|step| (step - size).abs() < 0.01)
+            {
+                return Err(AppError::Validation(format!(
+                    "font_size_pt must be one of {FONT_SIZE_STEPS_PT:?}"
+                )));
+            }
+        }
+        if let Some(w) = &self.scoring_weights {
+            let weights = [w.recency, w.impact, w.jd_relevance];
+            if weights.iter().any(|w| !(0.0..=1.0).contains(w)) {
+                return Err(AppError::Validation(
+                    "scoring_weights must each be between 0 and 1".to_string(),
+                ));
+            }
+            let sum: f64 = weights.iter().sum();
+            if (sum - 1.0).abs() > WEIGHT_SUM_TOLERANCE {
+                return Err(AppError::Validation(format!(
+                    "scoring_weights must sum to 1 (got {sum:.2})"
+                )));
+            }
+        }
+        if let Some(policy) = &self.recency_policy {
+            policy.validate().map_err(AppError::Validation)?;
+        }
+        Ok(())
+    }
+
+    /// Names of the fields that differ between `self` and `new`, for the audit log.
+    pub fn changed_fields(&self, new: &Preferences) -> Vec<&'static str> {
+        let mut fields = vec![];
+        if self.template_id != new.template_id {
+            fields.push("template_id");
+        }
+        if self.font_size_pt != new.font_size_pt {
+            fields.push("font_size_pt");
+        }
+        if self.paper_size != new.paper_size {
+            fields.push("paper_size");
+        }
+        if self.margin_preset != new.margin_preset {
+            fields.push("margin_preset");
+        }
+        if self.tone != new.tone {
+            fields.push("tone");
+        }
+        if self.scoring_weights != new.scoring_weights {
+            fields.push("scoring_weights");
+        }
+        if self.domain_pack != new.domain_pack {
+            fields.push("domain_pack");
+        }
+        if self.recency_policy != new.recency_policy {
+            fields.push("recency_policy");
+        }
+        if self.notifications != new.notifications {
+            fields.push("notifications");
+        }
+        fields
+    }
+}
+
+// ────────────────────────────────────────────────────────────────────────────
+// Persistence
+// ────────────────────────────────────────────────────────────────────────────
+
+/// The user's saved preferences; all unset if they never saved any.
+pub async fn load(db: &PgPool, user_id: Uuid) -> Result<Preferences, AppError> {
+    let row = sqlx::query!(
+        r#"SELECT template_id, font_size_pt, paper_size, margin_preset, tone,
+                  scoring_weights, domain_pack, recency_policy, notifications
+           FROM user_preferences WHERE user_id = $1"#,
+        user_id
+    )
+    .fetch_optional(db)
+    .await?;
+    let Some(row) = row else {
+        return Ok(Preferences::default());
+    };
+    // Stored values were validated on the way in; anything unreadable (a variant
+    // since removed, say) falls back to the default rather than failing generation.
+    Ok(Preferences {
+        template_id: row.template_id,
+        font_size_pt: row.font_size_pt,
+        paper_size: row.paper_size.as_deref().and_then(PaperSize::parse),
+        margin_preset: row.margin_preset.as_deref().and_then(MarginPreset::parse),
+        tone: row.tone.as_deref().and_then(JDTone::parse),
+        scoring_weights: row
+            .scoring_weights
+            .and_then(|w| serde_json::from_value(w).ok()),
+        domain_pack: row.domain_pack.as_deref().and_then(DomainPack::parse),
+        recency_policy: row
+            .recency_policy
+            .and_then(|p| serde_json::from_value(p).ok()),
+        notifications: serde_json::from_value(row.notifications).unwrap_or_default(),
+    })
+}
+
+/// Inserts or replaces the user's preferences.
+pub async fn save(db: &PgPool, user_id: Uuid, prefs: &Preferences) -> Result<(), AppError> {
+    let weights = prefs
+        .scoring_weights
+        .as_ref()
+        .map(serde_json::to_value)
+        .transpose()
+        .map_err(|e| AppError::Internal(e.into()))?;
+    let recency_policy = prefs
+        .recency_policy
+        .as_ref()
+        .map(serde_json::to_value)
+        .transpose()
+        .map_err(|e| AppError::Internal(e.into()))?;
+    let notifications =
+        serde_json::to_value(&prefs.notifications).map_err(|e| AppError::Internal(e.into()))?;
+    sqlx::query!(
+        r#"INSERT INTO user_preferences
+               (user_id, template_id, font_size_pt, paper_size, margin_preset, tone,
+                scoring_weights, domain_pack, recency_policy, notifications, updated_at)
+           VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, NOW())
+           ON CONFLICT (user_id) DO UPDATE SET
+               template_id = EXCLUDED.template_id,
+               font_size_pt = EXCLUDED.font_size_pt,
+               paper_size = EXCLUDED.paper_size,
+               margin_preset = EXCLUDED.margin_preset,
+               tone = EXCLUDED.tone,
+               scoring_weights = EXCLUDED.scoring_weights,
+               domain_pack = EXCLUDED.domain_pack,
+               recency_policy = EXCLUDED.recency_policy,
+               notifications = EXCLUDED.notifications,
+               updated_at = NOW()"#,
+        user_id,
+        prefs.template_id.as_deref(),
+        prefs.font_size_pt,
+        prefs.paper_size.map(|p| p.as_str()),
+        prefs.margin_preset.map(|m| m.as_str()),
+        prefs.tone.as_ref().map(JDTone::as_str),
+        weights,
+        prefs.domain_pack.map(|p| p.as_str()),
+        recency_policy,
+        notifications
+    )
+    .execute(db)
+    .await?;
+    Ok(())
+}
+
+// ────────────────────────────────────────────────────────────────────────────
+// Tests
+// ────────────────────────────────────────────────────────────────────────────
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn weights(recency: f64, impact: f64, jd_relevance: f64) -> Option<ScoringWeights> {
+        Some(ScoringWeights {
+            recency,
+            impact,
+            jd_relevance,
+        })
+    }
+
+    #[test]
+    fn test_validate_font_size_and_weights() {
+        assert!(Preferences::default().validate().is_ok());
+        let ok = Preferences {
+            font_size_pt: Some(10.5),
+            scoring_weights: weights(0.2, 0.3, 0.5),
+            ..Default::default()
+        };
+        assert!(ok.validate().is_ok());
+
+        for bad in [
+            Preferences {
+                font_size_pt: Some(10.25),
+                ..Default::default()
+            },
+            Preferences {
+                scoring_weights: weights(0.5, 0.5, 0.5),
+                ..Default::default()
+            },
+            Preferences {
+                scoring_weights: weights(1.5, -0.5, 0.0),
+                ..Default::default()
+            },
+            serde_json::from_str(
+                r#"{"recency_policy": {"default": {"curve": "exponential", "half_life_months": -6}}}"#,
+            )
+            .unwrap(),
+        ] {
+            assert!(
+                matches!(bad.validate(), Err(AppError::Validation(_))),
+                "{bad:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_partial_body_and_changed_fields() {
+        let prefs: Preferences =
+            serde_json::from_str(r#"{"paper_size": "a4", "tone": "ResearchOriented"}"#).unwrap();
+        assert_eq!(prefs.paper_size, Some(PaperSize::A4));
+        assert_eq!(prefs.domain_pack, None);
+        assert_eq!(prefs.tone, Some(JDTone::ResearchOriented));
+        assert_eq!(prefs.notifications, NotificationSettings::default());
+
+        let mut next = prefs.clone();
+        assert!(prefs.changed_fields(&next).is_empty());
+        next.tone = None;
+        next.domain_pack = Some(DomainPack::Academia);
+        next.notifications.render_complete = true;
+        assert_eq!(
+            prefs.changed_fields(&next),
+            vec!["tone", "domain_pack", "notifications"]
+        );
+    }
+}
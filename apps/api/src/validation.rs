@@ -0,0 +1,225 @@
+//! Request body limits and validation.
+//!
+//! Size limits are enforced in two places:
+//! - bytes, by `DefaultBodyLimit` in `routes` — `MAX_JSON_BODY_BYTES` for every route,
+//!   raised to `MAX_UPLOAD_BODY_BYTES` for the file upload route only;
+//! - characters, per field, by `#[validate(...)]` on the request structs, using the
+//!   `MAX_*_CHARS` constants below.
+//!
+//! Handlers take `ValidJson<T>` instead of `Json<T>`. It deserializes the body, runs
+//! `T::validate()` and rejects with 400 `VALIDATION_ERROR` listing every failing
+//! field:
+//!
+//! ```json
+//! { "error": { "code": "VALIDATION_ERROR", "message": "jd_text: must not be blank",
+//!              "fields": [{ "field": "jd_text", "code": "blank", "message": "must not be blank" }] } }
+//! ```
+//!
+//! Malformed JSON keeps axum's status (400/415/422) and an oversized body is 413,
+//! both in the same error envelope as every other `AppError`.
+
+use axum::{
+    async_trait,
+    extract::{rejection::JsonRejection, FromRequest, Request},
+    http::StatusCode,
+    Json,
+};
+use serde::{de::DeserializeOwned, Serialize};
+use validator::{Validate, ValidationError, ValidationErrors};
+
+use crate::errors::AppError;
+
+/// Largest JSON request body, in bytes.
+pub const MAX_JSON_BODY_BYTES: usize = 2 * 1024 * 1024;
+
+/// Largest file accepted by `POST /api/v1/context/ingest/upload`.
+pub const MAX_UPLOAD_BYTES: usize = 10 * 1024 * 1024;
+
+/// Body limit for the upload route: the file plus room for the multipart framing.
+pub const MAX_UPLOAD_BODY_BYTES: usize = MAX_UPLOAD_BYTES + 64 * 1024;
+
+// Text limits and `not_blank` are shared with clients through templar-types.
+pub use templar_types::validation::{
+    not_blank, MAX_JD_TEXT_CHARS, MAX_RAW_TEXT_CHARS, MAX_URL_CHARS,
+};
+
+/// Longest project or organization name, in characters.
+pub const MAX_NAME_CHARS: u64 = 100;
+
+// ────────────────────────────────────────────────────────────────────────────
+// Field errors
+// ────────────────────────────────────────────────────────────────────────────
+
+/// One failing field, as reported in the error body.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct FieldError {
+    pub field: String,
+    /// Machine-readable reason: `blank`, `length`, ...
+    pub code: String,
+    pub message: String,
+}
+
+/// Flattens `errors` into one entry per failure, sorted by field name.
+pub fn field_errors(errors: &ValidationErrors) -> Vec<FieldError> {
+    let mut fields: Vec<FieldError> = errors
+        .field_errors()
+        .into_iter()
+        .flat_map(|(field, errors)| {
+            errors.iter().map(move |e| FieldError {
+                field: field.to_string(),
+                code: e.code.to_string(),
+                message: describe(e),
+            })
+        })
+        .collect();
+    fields.sort_by(|a, b| a.field.cmp(&b.field));
+    fields
+}
+
+/// The error's own message, or one built from the `length` bounds.
+fn describe(error: &ValidationError) -> String {
+    if let Some(message) = &error.message {
+        return message.to_string();
+    }
+    let bound = |name: &str| error.params.get(name).and_then(|v| v.as_u64());
+    match (error.code.as_ref(), bound("min"), bound("max")) {
+        ("length", Some(min), Some(max)) => format!("must be {min}–{max} characters long"),
+        ("length", Some(min), None) => format!("must have at least {min} item(s) or characters"),
+        ("length", None, Some(max)) => format!("must be at most {max} characters"),
+        (code, _, _) => format!("is invalid ({code})"),
+    }
+}
+
+// ────────────────────────────────────────────────────────────────────────────
+// Custom validators
+// ────────────────────────────────────────────────────────────────────────────
+
+/// Every job description in a batch: not blank and at most `MAX_JD_TEXT_CHARS`.
+pub fn jd_texts(values: &[String]) -> Result<(), ValidationError> {
+    for (idx, text) in values.iter().enumerate() {
+        if text.trim().is_empty() {
+            return Err(ValidationError::new("blank")
+                .with_message(format!("jd_texts[{idx}] must not be blank").into()));
+        }
+        if text.chars().count() as u64 > MAX_JD_TEXT_CHARS {
+            return Err(ValidationError::new("length").with_message(
+                format!("jd_texts[{idx}] must be at most {MAX_JD_TEXT_CHARS} characters").into(),
+            ));
+        }
+    }
+    Ok(())
+}
+
+/// Applies the raw-text cap to text that did not arrive in a JSON field (an upload's
+/// extracted text), reporting it against `field`.
+pub fn check_raw_text(field: &str, text: &str) -> Result<(), AppError> {
+    if text.chars().count() as u64 > MAX_RAW_TEXT_CHARS {
+        return Err(AppError::InvalidFields(vec![FieldError {
+            field: field.to_string(),
+            code: "length".to_string(),
+            message: format!("extracted text must be at most {MAX_RAW_TEXT_CHARS} characters"),
+        }]));
+    }
+    Ok(())
+}
+
+// ────────────────────────────────────────────────────────────────────────────
+// Extractor
+// ────────────────────────────────────────────────────────────────────────────
+
+/// `Json<T>` that also runs `T::validate()`.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct ValidJson<T>(pub T);
+
+#[async_trait]
+impl<T, S> FromRequest<S> for ValidJson<T>
+where
+    T: DeserializeOwned + Validate,
+    S: Send + Sync,
+{
+    type Rejection = AppError;
+
+    async fn from_request(req: Request, state: &S) -> Result<Self, AppError> {
+        let Json(value) = Json::<T>::from_request(req, state)
+            .await
+            .map_err(rejection_error)?;
+        value
+            .validate()
+            .map_err(|e| AppError::InvalidFields(field_errors(&e)))?;
+        Ok(ValidJson(value))
+    }
+}
+
+fn rejection_error(rejection: JsonRejection) -> AppError {
+    let message = rejection.body_text();
+    match rejection.status() {
+        StatusCode::PAYLOAD_TOO_LARGE => AppError::PayloadTooLarge(format!(
+            "Request body exceeds {} bytes",
+            MAX_JSON_BODY_BYTES
+        )),
+        StatusCode::UNPROCESSABLE_ENTITY => AppError::UnprocessableEntity(message),
+        _ => AppError::Validation(message),
+    }
+}
+
+// ────────────────────────────────────────────────────────────────────────────
+// Tests
+// ────────────────────────────────────────────────────────────────────────────
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde::Deserialize;
+
+    #[derive(Debug, Deserialize, Validate)]
+    struct Probe {
+        #[validate(custom(function = "not_blank"), length(max = 5))]
+        text: String,
+        #[validate(length(min = 1), custom(function = "jd_texts"))]
+        items: Vec<String>,
+    }
+
+    #[test]
+    fn test_field_errors_report_every_field() {
+        let probe = Probe {
+            text: "   ".to_string(),
+            items: vec![],
+        };
+        let fields = field_errors(&probe.validate().unwrap_err());
+        assert_eq!(fields.len(), 2);
+        assert_eq!(fields[0].field, "items");
+        assert_eq!(fields[0].code, "length");
+        assert_eq!(fields[1].field, "text");
+        assert_eq!(fields[1].message, "must not be blank");
+    }
+
+    #[test]
+    fn test_length_messages_use_the_bounds() {
+        let probe = Probe {
+            text: "too long".to_string(),
+            items: vec!["ok".to_string(), " ".to_string()],
+        };
+        let fields = field_errors(&probe.validate().unwrap_err());
+        assert_eq!(fields[0].message, "jd_texts[1] must not be blank");
+        assert_eq!(fields[1].message, "must be at most 5 characters");
+    }
+
+    #[test]
+    fn test_lengths_count_characters_not_bytes() {
+        let probe = Probe {
+            text: "héllo".to_string(),
+            items: vec!["日本語".to_string()],
+        };
+        assert!(probe.validate().is_ok());
+    }
+
+    #[test]
+    fn test_check_raw_text_caps_extracted_text() {
+        assert!(check_raw_text("file", "short").is_ok());
+        let long = "x".repeat(MAX_RAW_TEXT_CHARS as usize + 1);
+        assert!(matches!(
+            check_raw_text("file", &long),
+            Err(AppError::InvalidFields(f)) if f[0].field == "file"
+        ));
+    }
+}
@@ -0,0 +1,54 @@
+//! Job description sources: a local file, or an http(s) URL fetched as-is.
+
+use anyhow::{bail, Context, Result};
+
+/// Reads the JD text from `source` — a URL when it starts with `http://` or
+/// `https://`, otherwise a file path.
+pub async fn load(source: &str) -> Result<String> {
+    let text = if is_url(source) {
+        let response = reqwest::get(source)
+            .await
+            .with_context(|| format!("failed to fetch {source}"))?;
+        if !response.status().is_success() {
+            bail!("fetching {source} returned HTTP {}", response.status());
+        }
+        response.text().await?
+    } else {
+        tokio::fs::read_to_string(source)
+            .await
+            .with_context(|| format!("failed to read {source}"))?
+    };
+    if text.trim().is_empty() {
+        bail!("job description from {source} is empty");
+    }
+    Ok(text)
+}
+
+fn is_url(source: &str) -> bool {
+    source.starts_with("http://") || source.starts_with("https://")
+}
+
+// ────────────────────────────────────────────────────────────────────────────
+// Tests
+// ────────────────────────────────────────────────────────────────────────────
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_is_url() {
+        assert!(is_url("https://jobs.example.com/123"));
+        assert!(is_url("http://localhost/jd.txt"));
+        assert!(!is_url("jd.txt"));
+        assert!(!is_url("./https-notes.txt"));
+    }
+
+    #[tokio::test]
+    async fn test_empty_file_is_rejected() {
+        let path = std::env::temp_dir().join("templar-cli-empty-jd.txt");
+        std::fs::write(&path, "  \n").unwrap();
+        let err = load(path.to_str().unwrap()).await.unwrap_err();
+        assert!(err.to_string().contains("is empty"));
+    }
+}
@@ -0,0 +1,154 @@
+//! `templar` — command-line client for a running Templar API.
+//!
+//! templar login --email you@example.com         — print a token (password from TEMPLAR_PASSWORD)
+//! templar ingest context.md                     — batch-ingest a context file and wait for it
+//! templar parse-jd <file|url>                   — print the parsed JD
+//! templar fit-score <file|url>                  — print the fit report
+//! templar generate <file|url> [--pdf out.pdf]   — generate a resume, optionally render it
+//! templar pdf <resume_id> -o out.pdf            — render an existing resume and download it
+//!
+//! Every command but `login` needs `--token` / TEMPLAR_TOKEN. Results are printed as
+//! JSON on stdout; a non-zero exit status means the step failed, which is what CI
+//! smoke tests key on.
+
+mod jd;
+
+use std::path::{Path, PathBuf};
+
+use anyhow::{bail, Context, Result};
+use clap::{Parser, Subcommand};
+use serde::Serialize;
+use templar_client::{GenerateOptions, TemplarClient};
+use uuid::Uuid;
+
+#[derive(Parser)]
+#[command(
+    name = "templar",
+    about = "Drive the Templar resume pipeline from the shell"
+)]
+struct Cli {
+    /// Base URL of the API.
+    #[arg(long, env = "TEMPLAR_API_URL", default_value = "http://localhost:8080")]
+    api_url: String,
+    /// Bearer token, as printed by `templar login`.
+    #[arg(long, env = "TEMPLAR_TOKEN", hide_env_values = true)]
+    token: Option<String>,
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Log in and print the bearer token.
+    Login {
+        #[arg(long)]
+        email: String,
+        #[arg(long, env = "TEMPLAR_PASSWORD", hide_env_values = true)]
+        password: String,
+    },
+    /// Ingest a context file; separate entries with a line of `---`.
+    Ingest { file: PathBuf },
+    /// Parse a job description from a file or http(s) URL.
+    ParseJd { jd: String },
+    /// Score the stored context against a job description.
+    FitScore { jd: String },
+    /// Generate a resume for a job description.
+    Generate {
+        jd: String,
+        /// `letter` or `a4`.
+        #[arg(long)]
+        paper_size: Option<String>,
+        /// `compact`, `standard` or `airy`.
+        #[arg(long)]
+        margin_preset: Option<String>,
+        /// Also render the resume and write the PDF here.
+        #[arg(long)]
+        pdf: Option<PathBuf>,
+    },
+    /// Render an existing resume and download the PDF.
+    Pdf {
+        resume_id: Uuid,
+        #[arg(short, long)]
+        output: PathBuf,
+    },
+}
+
+#[tokio::main]
+async fn main() -> Result<()> {
+    let cli = Cli::parse();
+    let needs_token = !matches!(cli.command, Command::Login { .. });
+    if needs_token && cli.token.is_none() {
+        bail!("no token: pass --token or set TEMPLAR_TOKEN (see `templar login`)");
+    }
+    let mut client = TemplarClient::new(&cli.api_url)?;
+    if let Some(token) = cli.token {
+        client = client.with_token(token);
+    }
+
+    match cli.command {
+        Command::Login { email, password } => {
+            println!("{}", client.login(&email, &password).await?);
+        }
+        Command::Ingest { file } => {
+            let raw_text = tokio::fs::read_to_string(&file)
+                .await
+                .with_context(|| format!("failed to read {}", file.display()))?;
+            let batch = client.ingest_batch(&raw_text).await?;
+            let status = client.wait_for_batch(batch.batch_id).await?;
+            print_json(&status)?;
+            if status.failed > 0 {
+                bail!(
+                    "{} of {} entries failed to ingest",
+                    status.failed,
+                    status.total
+                );
+            }
+        }
+        Command::ParseJd { jd } => {
+            let jd_text = jd::load(&jd).await?;
+            print_json(&client.parse_jd(&jd_text).await?)?;
+        }
+        Command::FitScore { jd } => {
+            let jd_text = jd::load(&jd).await?;
+            print_json(&client.fit_score(&jd_text).await?)?;
+        }
+        Command::Generate {
+            jd,
+            paper_size,
+            margin_preset,
+            pdf,
+        } => {
+            let jd_text = jd::load(&jd).await?;
+            let options = GenerateOptions {
+                paper_size,
+                margin_preset,
+                ..GenerateOptions::new(jd_text)
+            };
+            let generated = client.generate(&options).await?;
+            print_json(&generated)?;
+            if let Some(path) = pdf {
+                download_pdf(&client, generated.resume_id, &path).await?;
+            }
+        }
+        Command::Pdf { resume_id, output } => {
+            download_pdf(&client, resume_id, &output).await?;
+        }
+    }
+    Ok(())
+}
+
+async fn download_pdf(client: &TemplarClient, resume_id: Uuid, path: &Path) -> Result<()> {
+    let job = client.trigger_render(resume_id).await?;
+    client.wait_for_render(job.job_id).await?;
+    let bytes = client.download_pdf(job.job_id).await?;
+    tokio::fs::write(path, &bytes)
+        .await
+        .with_context(|| format!("failed to write {}", path.display()))?;
+    eprintln!("wrote {} ({} bytes)", path.display(), bytes.len());
+    Ok(())
+}
+
+fn print_json(value: &impl Serialize) -> Result<()> {
+    println!("{}", serde_json::to_string_pretty(value)?);
+    Ok(())
+}
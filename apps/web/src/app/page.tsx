@@ -1,7 +1,7 @@
 "use client";
 
 // Root page — client component that loads the user's project list from the store.
-// Auth is not yet wired (Phase 9), so we use the MVP_USER_ID constant.
+// The API resolves the user from the bearer token set via `setAuthToken`.
 // Using a client component here avoids Next.js 16 server-component fetch issues
 // when the API is not running in dev (shows graceful loading + empty state).
 
@@ -10,9 +10,6 @@ import Link from "next/link";
 import { useRouter } from "next/navigation";
 import { useProjectStore } from "@/store/projectStore";
 
-// Hardcoded MVP user — replaced by Clerk auth in Phase 9
-const MVP_USER_ID = "00000000-0000-0000-0000-000000000001";
-
 function formatDate(iso: string) {
   return new Date(iso).toLocaleDateString("en-US", {
     month: "short",
@@ -49,7 +46,7 @@ export default function HomePage() {
   const { projects, isLoadingProjects, loadProjects, deleteProject } = useProjectStore();
 
   useEffect(() => {
-    loadProjects(MVP_USER_ID);
+    loadProjects();
   }, [loadProjects]);
 
   return (
@@ -14,8 +14,6 @@ import { TemplateThumbnailPdf } from "@/components/pdf/TemplateThumbnailPdf";
 import type { TemplateSummary } from "@templar/types";
 import { Eye, X } from "lucide-react";
 
-const MVP_USER_ID = "00000000-0000-0000-0000-000000000001";
-
 function TemplatePickerCard({
   template,
   selected,
@@ -186,7 +184,7 @@ export default function NewProjectPage() {
 
     try {
       const { createProject } = useProjectStore.getState();
-      const project = await createProject(MVP_USER_ID, projectName.trim(), selectedTemplateId);
+      const project = await createProject(projectName.trim(), selectedTemplateId);
       // Redirect directly to the editor for this project
       router.push(`/editor/${project.id}`);
     } catch (e) {
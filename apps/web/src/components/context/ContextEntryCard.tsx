@@ -24,8 +24,6 @@ function normalizeBullets(raw: unknown[]): BulletItem[] {
 
 // ─── Constants ───────────────────────────────────────────────────────────────
 
-const MVP_USER_ID = '00000000-0000-0000-0000-000000000001'
-
 /** Max tags shown in collapsed view before truncating. */
 const MAX_COLLAPSED_TAGS = 4
 
@@ -109,7 +107,6 @@ interface EditableFieldProps {
   value: string | null | undefined
   fieldKey: string
   entryId: string
-  userId: string
   onSave: () => void
 }
 
@@ -118,7 +115,6 @@ function EditableField({
   value,
   fieldKey,
   entryId,
-  userId,
   onSave,
 }: EditableFieldProps) {
   const [editing, setEditing] = useState(false)
@@ -127,7 +123,7 @@ function EditableField({
   const handleSave = async () => {
     if (draft !== (value ?? '')) {
       try {
-        await api.patchEntry(entryId, userId, { [fieldKey]: draft || null })
+        await api.patchEntry(entryId, { [fieldKey]: draft || null })
         onSave()
       } catch {
         // silently revert — field will show old value on next render
@@ -179,7 +175,6 @@ interface EditableDateFieldProps {
   value: string | null | undefined
   fieldKey: string
   entryId: string
-  userId: string
   /** true for date_end only — shows "Currently ongoing" checkbox */
   allowPresent: boolean
   onSave: () => void
@@ -190,7 +185,6 @@ function EditableDateField({
   value,
   fieldKey,
   entryId,
-  userId,
   allowPresent,
   onSave,
 }: EditableDateFieldProps) {
@@ -201,7 +195,7 @@ function EditableDateField({
   const handleSave = async () => {
     const patchVal = isPresent ? null : (draft || null)
     try {
-      await api.patchEntry(entryId, userId, { [fieldKey]: patchVal })
+      await api.patchEntry(entryId, { [fieldKey]: patchVal })
       onSave()
     } catch {
       // silently revert
@@ -422,7 +416,7 @@ export default function ContextEntryCard({
     setEvergreen(newValue) // optimistic
     setEvergreenLoading(true)
     try {
-      await api.toggleEvergreen(entry.entry_id, MVP_USER_ID, newValue)
+      await api.toggleEvergreen(entry.entry_id, newValue)
     } catch {
       setEvergreen(!newValue) // rollback
     } finally {
@@ -594,7 +588,6 @@ export default function ContextEntryCard({
                         value={fieldValue}
                         fieldKey={key}
                         entryId={entry.entry_id}
-                        userId={MVP_USER_ID}
                         allowPresent={key === 'date_end'}
                         onSave={handleFieldSave}
                       />
@@ -604,7 +597,6 @@ export default function ContextEntryCard({
                         value={fieldValue}
                         fieldKey={key}
                         entryId={entry.entry_id}
-                        userId={MVP_USER_ID}
                         onSave={handleFieldSave}
                       />
                     )}
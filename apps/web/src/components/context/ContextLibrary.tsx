@@ -8,8 +8,6 @@ import ContextEntryCard from './ContextEntryCard'
 
 // ─── Constants ───────────────────────────────────────────────────────────────
 
-const MVP_USER_ID = '00000000-0000-0000-0000-000000000001'
-
 /** Canonical display order for entry_type groups. */
 const SECTION_ORDER = [
   'experience',
@@ -132,7 +130,7 @@ export default function ContextLibrary({ refreshKey }: ContextLibraryProps) {
     setIsLoading(true)
     setError(null)
     try {
-      const resp = await api.getContextEntries(MVP_USER_ID)
+      const resp = await api.getContextEntries()
       setEntries(dedupEntries(resp.entries))
       setCompleteness(resp.completeness)
     } catch (e) {
@@ -4,9 +4,9 @@ import { useCallback, useEffect, useRef, useState } from 'react'
 import { Button } from '@/components/ui/button'
 import { Textarea } from '@/components/ui/textarea'
 import { Tabs, TabsContent, TabsList, TabsTrigger } from '@/components/ui/tabs'
+import { authHeaders } from '@/lib/api'
 
 const API_BASE = process.env.NEXT_PUBLIC_API_URL ?? 'http://localhost:8080'
-const MVP_USER_ID = '00000000-0000-0000-0000-000000000001'
 
 // ─── Types ─────────────────────────────────────────────────────────────────
 
@@ -157,7 +157,9 @@ export function ContextPanel({ onContextUpdated }: { onContextUpdated?: () => vo
 
     const poll = async () => {
       try {
-        const res = await fetch(`${API_BASE}/api/v1/context/ingest/batch/${textBatchId}`)
+        const res = await fetch(`${API_BASE}/api/v1/context/ingest/batch/${textBatchId}`, {
+          headers: authHeaders(),
+        })
         if (!res.ok) return
         const data: BatchStatusResponse = await res.json()
         setTextBatchStatus(data)
@@ -187,7 +189,9 @@ export function ContextPanel({ onContextUpdated }: { onContextUpdated?: () => vo
       for (const entry of active) {
         if (!entry.batchId) continue
         try {
-          const res = await fetch(`${API_BASE}/api/v1/context/ingest/batch/${entry.batchId}`)
+          const res = await fetch(`${API_BASE}/api/v1/context/ingest/batch/${entry.batchId}`, {
+            headers: authHeaders(),
+          })
           if (!res.ok) continue
           const data: BatchStatusResponse = await res.json()
           setUploadEntries((prev) =>
@@ -217,8 +221,8 @@ export function ContextPanel({ onContextUpdated }: { onContextUpdated?: () => vo
     try {
       const res = await fetch(`${API_BASE}/api/v1/context/ingest/batch`, {
         method: 'POST',
-        headers: { 'Content-Type': 'application/json' },
-        body: JSON.stringify({ user_id: MVP_USER_ID, raw_text: rawText }),
+        headers: { 'Content-Type': 'application/json', ...authHeaders() },
+        body: JSON.stringify({ raw_text: rawText }),
       })
       if (!res.ok) {
         const errData = await res.json().catch(() => ({}))
@@ -271,10 +275,10 @@ export function ContextPanel({ onContextUpdated }: { onContextUpdated?: () => vo
       )
       try {
         const fd = new FormData()
-        fd.append('user_id', MVP_USER_ID)
         fd.append('file', entries[i].file)
         const res = await fetch(`${API_BASE}/api/v1/context/ingest/upload`, {
           method: 'POST',
+          headers: authHeaders(),
           body: fd,
         })
         if (!res.ok) {
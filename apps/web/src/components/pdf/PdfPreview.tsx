@@ -2,7 +2,7 @@
 
 import { useEffect, useRef, useState, useCallback } from "react";
 import { useResumeStore } from "@/store/resumeStore";
-import { api } from "@/lib/api";
+import { api, authHeaders } from "@/lib/api";
 
 // PDF.js is lazy-loaded client-side only to avoid SSR issues.
 // The global is cached after first load to avoid redundant dynamic imports.
@@ -41,7 +41,7 @@ export function PdfPreview() {
 
       const pdfUrl = api.getPdfUrl(jobId);
       console.log("[PdfPreview] PDF.js ready, fetching:", pdfUrl);
-      const loadingTask = pdfjsLib.getDocument(pdfUrl);
+      const loadingTask = pdfjsLib.getDocument({ url: pdfUrl, httpHeaders: authHeaders() });
       const pdf = await loadingTask.promise;
       console.log("[PdfPreview] Document loaded, numPages:", pdf.numPages);
       const page = await pdf.getPage(1);
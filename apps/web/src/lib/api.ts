@@ -7,7 +7,43 @@ import type {
   ProjectListResponse,
   CreateProjectRequest,
   UpdateProjectRequest,
+  Application,
+  ApplicationListResponse,
+  ApplicationStatus,
+  CreateApplicationRequest,
+  UpdateApplicationRequest,
+  Persona,
+  PersonaListResponse,
+  PersonaSuggestResponse,
+  CreatePersonaRequest,
+  UpdatePersonaRequest,
   ContextEntriesResponse,
+  PaperSize,
+  MarginPreset,
+  AuthResponse,
+  User,
+  UpdateMeRequest,
+  UserPreferences,
+  UsageReport,
+  AuditAction,
+  AuditLogResponse,
+  DeletionReceipt,
+  ExportStatusResponse,
+  StartExportResponse,
+  Organization,
+  MyOrganization,
+  OrgMember,
+  AddOrgMemberRequest,
+  OrgUsageResponse,
+  ResumeAnnotation,
+  GenerationStats,
+  TokenSpendStats,
+  RenderStats,
+  FlagStats,
+  QueueDepth,
+  SeedReport,
+  ResumeRow,
+  ProgressEvent,
 } from "@templar/types";
 
 export type {
@@ -20,32 +56,228 @@ export type {
 const API_BASE =
   process.env.NEXT_PUBLIC_API_URL ?? "http://localhost:8080";
 
+const TOKEN_STORAGE_KEY = "templar.apiToken";
+
+/**
+ * Stores (or clears, with null) the API bearer token. The API resolves the user
+ * from this token — request bodies never carry a user_id.
+ */
+export function setAuthToken(token: string | null): void {
+  if (typeof window === "undefined") return;
+  if (token) {
+    window.localStorage.setItem(TOKEN_STORAGE_KEY, token);
+  } else {
+    window.localStorage.removeItem(TOKEN_STORAGE_KEY);
+  }
+}
+
+/** `Authorization` header for the stored token, or no headers when signed out. */
+export function authHeaders(): Record<string, string> {
+  if (typeof window === "undefined") return {};
+  const token = window.localStorage.getItem(TOKEN_STORAGE_KEY);
+  return token ? { Authorization: `Bearer ${token}` } : {};
+}
+
 async function apiFetch<T>(path: string, init?: RequestInit): Promise<T> {
   const res = await fetch(`${API_BASE}${path}`, {
-    headers: { "Content-Type": "application/json" },
     ...init,
+    headers: { "Content-Type": "application/json", ...authHeaders(), ...init?.headers },
   });
   if (!res.ok) {
     const err = await res.json().catch(() => ({})) as Record<string, unknown>;
-    const errorMsg =
-      (err?.error as Record<string, unknown>)?.message ??
-      `HTTP ${res.status}`;
-    throw new Error(String(errorMsg));
+    const body = err?.error as Record<string, unknown> | undefined;
+    const errorMsg = body?.message ?? `HTTP ${res.status}`;
+    // Quote-able id for support reports; also in the X-Request-Id header.
+    const requestId = body?.request_id ?? res.headers.get("X-Request-Id");
+    throw new Error(
+      requestId ? `${String(errorMsg)} (request id: ${String(requestId)})` : String(errorMsg),
+    );
   }
   return res.json() as Promise<T>;
 }
 
 export const api = {
+  // ── Auth & users API ───────────────────────────────────────────────────────
+
+  /**
+   * POST /api/v1/auth/signup
+   * Creates a password account and stores the returned token.
+   */
+  signup: async (email: string, password: string, displayName?: string) => {
+    const res = await apiFetch<AuthResponse>("/api/v1/auth/signup", {
+      method: "POST",
+      body: JSON.stringify({ email, password, display_name: displayName }),
+    });
+    setAuthToken(res.token);
+    return res;
+  },
+
+  /**
+   * POST /api/v1/auth/login
+   * Exchanges email + password for a token and stores it.
+   */
+  login: async (email: string, password: string) => {
+    const res = await apiFetch<AuthResponse>("/api/v1/auth/login", {
+      method: "POST",
+      body: JSON.stringify({ email, password }),
+    });
+    setAuthToken(res.token);
+    return res;
+  },
+
+  /** Forgets the stored token. */
+  logout: () => setAuthToken(null),
+
+  /**
+   * GET /api/v1/users/me
+   * The signed-in user.
+   */
+  getMe: () => apiFetch<User>("/api/v1/users/me"),
+
+  /**
+   * PATCH /api/v1/users/me
+   * Updates email and/or display name.
+   */
+  updateMe: (body: UpdateMeRequest) =>
+    apiFetch<User>("/api/v1/users/me", {
+      method: "PATCH",
+      body: JSON.stringify(body),
+    }),
+
+  /**
+   * DELETE /api/v1/users/me
+   * Permanently deletes the account and all its data. `confirmEmail` must match
+   * the account email. Forgets the stored token on success.
+   */
+  deleteMe: async (confirmEmail: string) => {
+    const receipt = await apiFetch<DeletionReceipt>("/api/v1/users/me", {
+      method: "DELETE",
+      body: JSON.stringify({ confirm_email: confirmEmail }),
+    });
+    setAuthToken(null);
+    return receipt;
+  },
+
+  /**
+   * GET /api/v1/users/me/preferences
+   * Saved generation defaults; every field null if never saved.
+   */
+  getPreferences: () => apiFetch<UserPreferences>("/api/v1/users/me/preferences"),
+
+  /**
+   * PUT /api/v1/users/me/preferences
+   * Replaces the saved defaults; omitted fields are cleared.
+   */
+  updatePreferences: (body: UserPreferences) =>
+    apiFetch<UserPreferences>("/api/v1/users/me/preferences", {
+      method: "PUT",
+      body: JSON.stringify(body),
+    }),
+
+  /**
+   * GET /api/v1/users/me/usage
+   * Monthly usage against the caller's tier limits.
+   */
+  getUsage: () => apiFetch<UsageReport>("/api/v1/users/me/usage"),
+
+  /**
+   * GET /api/v1/users/me/audit
+   * The caller's audit log, newest first. Page with `before` = previous `next_before`.
+   */
+  getAuditLog: (
+    params: {
+      action?: AuditAction;
+      resourceId?: string;
+      before?: string;
+      limit?: number;
+    } = {}
+  ) => {
+    const query = new URLSearchParams();
+    if (params.action) query.set("action", params.action);
+    if (params.resourceId) query.set("resource_id", params.resourceId);
+    if (params.before) query.set("before", params.before);
+    if (params.limit) query.set("limit", String(params.limit));
+    const qs = query.toString();
+    return apiFetch<AuditLogResponse>(
+      `/api/v1/users/me/audit${qs ? `?${qs}` : ""}`
+    );
+  },
+
+  /**
+   * POST /api/v1/users/me/export
+   * Starts a full data export; follow `events_url`, then poll getExport.
+   */
+  startExport: () =>
+    apiFetch<StartExportResponse>("/api/v1/users/me/export", {
+      method: "POST",
+    }),
+
+  /**
+   * GET /api/v1/users/me/export/:id
+   * Export status, with a short-lived download URL once completed.
+   */
+  getExport: (exportId: string) =>
+    apiFetch<ExportStatusResponse>(`/api/v1/users/me/export/${exportId}`),
+
   /**
    * POST /api/v1/resumes/generate
    * Generates a resume from context entries + job description.
+   * `paperSize` and `marginPreset` default to the server's page config
+   * (US letter, standard 1" margins) when omitted.
    */
-  generateResume: (userId: string, jdText: string) =>
+  generateResume: (
+    jdText: string,
+    paperSize?: PaperSize,
+    marginPreset?: MarginPreset,
+    progressId?: string,
+  ) =>
     apiFetch<GenerateResponse>("/api/v1/resumes/generate", {
       method: "POST",
-      body: JSON.stringify({ user_id: userId, jd_text: jdText }),
+      body: JSON.stringify({
+        jd_text: jdText,
+        paper_size: paperSize,
+        margin_preset: marginPreset,
+        progress_id: progressId,
+      }),
     }),
 
+  /**
+   * GET /api/v1/events/:job_id (Server-Sent Events)
+   * Calls `onEvent` for each progress event of a generation job, render job or
+   * `progressId`, until a terminal event or `signal` aborts. Uses fetch rather than
+   * EventSource, which cannot send the Authorization header.
+   */
+  streamEvents: async (
+    jobId: string,
+    onEvent: (event: ProgressEvent) => void,
+    signal?: AbortSignal,
+  ) => {
+    const res = await fetch(`${API_BASE}/api/v1/events/${jobId}`, {
+      headers: { Accept: "text/event-stream", ...authHeaders() },
+      signal,
+    });
+    if (!res.ok || !res.body) throw new Error(`HTTP ${res.status}`);
+
+    const reader = res.body.pipeThrough(new TextDecoderStream()).getReader();
+    let buffer = "";
+    for (;;) {
+      const { value, done } = await reader.read();
+      if (done) return;
+      buffer += value;
+      let end: number;
+      while ((end = buffer.indexOf("\n\n")) >= 0) {
+        const frame = buffer.slice(0, end);
+        buffer = buffer.slice(end + 2);
+        const data = frame
+          .split("\n")
+          .filter((line) => line.startsWith("data:"))
+          .map((line) => line.slice(5).trimStart())
+          .join("\n");
+        if (data) onEvent(JSON.parse(data) as ProgressEvent);
+      }
+    }
+  },
+
   /**
    * GET /api/v1/resumes/:id
    * Fetches a resume and its bullets from the database.
@@ -53,6 +285,16 @@ export const api = {
   getResume: (id: string) =>
     apiFetch<ResumeDetailResponse>(`/api/v1/resumes/${id}`),
 
+  /**
+   * DELETE /api/v1/resumes/:id
+   * Soft-deletes a resume; it is purged for good after the retention window.
+   */
+  deleteResume: (id: string) =>
+    fetch(`${API_BASE}/api/v1/resumes/${id}`, {
+      method: "DELETE",
+      headers: authHeaders(),
+    }),
+
   /**
    * GET /api/v1/resumes/:id/audit
    * Fetches the grounding audit manifest for a resume.
@@ -83,7 +325,8 @@ export const api = {
 
   /**
    * Returns the URL to stream the rendered PDF.
-   * GET /api/v1/render/:job_id
+   * GET /api/v1/render/:job_id — requires `authHeaders()`, so fetch it rather than
+   * using it as a plain link.
    */
   getPdfUrl: (jobId: string) => `${API_BASE}/api/v1/render/${jobId}`,
 
@@ -126,11 +369,11 @@ export const api = {
   // ── Projects API ───────────────────────────────────────────────────────────
 
   /**
-   * GET /api/v1/projects?user_id={uuid}
-   * Lists all projects for a user, ordered by updated_at DESC.
+   * GET /api/v1/projects
+   * Lists the signed-in user's projects, ordered by updated_at DESC.
    */
-  listProjects: (userId: string) =>
-    apiFetch<ProjectListResponse>(`/api/v1/projects?user_id=${userId}`),
+  listProjects: () =>
+    apiFetch<ProjectListResponse>("/api/v1/projects"),
 
   /**
    * POST /api/v1/projects
@@ -163,27 +406,253 @@ export const api = {
    * Hard-deletes the project. Resume rows are NOT deleted (FK is SET NULL).
    */
   deleteProject: (id: string) =>
-    fetch(`${API_BASE}/api/v1/projects/${id}`, { method: "DELETE" }),
+    fetch(`${API_BASE}/api/v1/projects/${id}`, {
+      method: "DELETE",
+      headers: authHeaders(),
+    }),
+
+  // ── Personas API ───────────────────────────────────────────────────────────
+
+  /**
+   * GET /api/v1/personas
+   */
+  listPersonas: () => apiFetch<PersonaListResponse>("/api/v1/personas"),
+
+  /**
+   * POST /api/v1/personas
+   * A suggestion from suggestPersonas can be passed straight in.
+   */
+  createPersona: (body: CreatePersonaRequest) =>
+    apiFetch<Persona>("/api/v1/personas", {
+      method: "POST",
+      body: JSON.stringify(body),
+    }),
+
+  /**
+   * POST /api/v1/personas/suggest
+   * Proposes personas by clustering the user's context. Nothing is saved.
+   */
+  suggestPersonas: () =>
+    apiFetch<PersonaSuggestResponse>("/api/v1/personas/suggest", {
+      method: "POST",
+    }),
+
+  /**
+   * GET /api/v1/personas/:id
+   */
+  getPersona: (id: string) => apiFetch<Persona>(`/api/v1/personas/${id}`),
+
+  /**
+   * PATCH /api/v1/personas/:id
+   * Partial update; a tag list replaces the stored one.
+   */
+  updatePersona: (id: string, body: UpdatePersonaRequest) =>
+    apiFetch<Persona>(`/api/v1/personas/${id}`, {
+      method: "PATCH",
+      body: JSON.stringify(body),
+    }),
+
+  /**
+   * DELETE /api/v1/personas/:id
+   */
+  deletePersona: (id: string) =>
+    fetch(`${API_BASE}/api/v1/personas/${id}`, {
+      method: "DELETE",
+      headers: authHeaders(),
+    }),
+
+  // ── Application tracker ────────────────────────────────────────────────────
+
+  /**
+   * GET /api/v1/applications
+   * The signed-in user's applications, most recently updated first.
+   */
+  listApplications: (
+    params: { status?: ApplicationStatus; resumeId?: string } = {}
+  ) => {
+    const query = new URLSearchParams();
+    if (params.status) query.set("status", params.status);
+    if (params.resumeId) query.set("resume_id", params.resumeId);
+    const qs = query.toString();
+    return apiFetch<ApplicationListResponse>(
+      `/api/v1/applications${qs ? `?${qs}` : ""}`
+    );
+  },
+
+  /**
+   * POST /api/v1/applications
+   * Records an application; `resume_id` must be one of the user's resumes.
+   */
+  createApplication: (body: CreateApplicationRequest) =>
+    apiFetch<Application>("/api/v1/applications", {
+      method: "POST",
+      body: JSON.stringify(body),
+    }),
+
+  /**
+   * GET /api/v1/applications/:id
+   */
+  getApplication: (id: string) =>
+    apiFetch<Application>(`/api/v1/applications/${id}`),
+
+  /**
+   * PATCH /api/v1/applications/:id
+   * Partial update — e.g. `{ status: "interview" }` to move it along.
+   */
+  updateApplication: (id: string, body: UpdateApplicationRequest) =>
+    apiFetch<Application>(`/api/v1/applications/${id}`, {
+      method: "PATCH",
+      body: JSON.stringify(body),
+    }),
+
+  /**
+   * DELETE /api/v1/applications/:id
+   */
+  deleteApplication: (id: string) =>
+    fetch(`${API_BASE}/api/v1/applications/${id}`, {
+      method: "DELETE",
+      headers: authHeaders(),
+    }),
+
+  // ── Organizations API ──────────────────────────────────────────────────────
+
+  /**
+   * GET /api/v1/orgs
+   * Orgs the signed-in user belongs to, with their role in each.
+   */
+  listOrgs: () => apiFetch<MyOrganization[]>("/api/v1/orgs"),
+
+  /**
+   * POST /api/v1/orgs
+   * Creates an org with the caller as its owner.
+   */
+  createOrg: (name: string) =>
+    apiFetch<Organization>("/api/v1/orgs", {
+      method: "POST",
+      body: JSON.stringify({ name }),
+    }),
+
+  /** GET /api/v1/orgs/:id/members */
+  listOrgMembers: (orgId: string) =>
+    apiFetch<OrgMember[]>(`/api/v1/orgs/${orgId}/members`),
+
+  /**
+   * POST /api/v1/orgs/:id/members
+   * Adds an existing account, or changes a member's role. Owner only.
+   * Returns the updated member list.
+   */
+  addOrgMember: (orgId: string, body: AddOrgMemberRequest) =>
+    apiFetch<OrgMember[]>(`/api/v1/orgs/${orgId}/members`, {
+      method: "POST",
+      body: JSON.stringify(body),
+    }),
+
+  /**
+   * DELETE /api/v1/orgs/:id/members/:userId
+   * Owners can remove anyone; members can remove themselves.
+   */
+  removeOrgMember: (orgId: string, userId: string) =>
+    fetch(`${API_BASE}/api/v1/orgs/${orgId}/members/${userId}`, {
+      method: "DELETE",
+      headers: authHeaders(),
+    }),
+
+  /**
+   * GET /api/v1/orgs/:id/candidates/:userId/resumes
+   * A candidate's resumes, newest first. Owners and coaches only.
+   */
+  listCandidateResumes: (orgId: string, userId: string) =>
+    apiFetch<ResumeRow[]>(`/api/v1/orgs/${orgId}/candidates/${userId}/resumes`),
+
+  /**
+   * GET /api/v1/orgs/:id/usage
+   * The org's shared pool plus each member's usage this month.
+   */
+  getOrgUsage: (orgId: string) =>
+    apiFetch<OrgUsageResponse>(`/api/v1/orgs/${orgId}/usage`),
+
+  /** GET /api/v1/resumes/:id/annotations */
+  listAnnotations: (resumeId: string) =>
+    apiFetch<ResumeAnnotation[]>(`/api/v1/resumes/${resumeId}/annotations`),
+
+  /**
+   * POST /api/v1/resumes/:id/annotations
+   * Adds a note, optionally pinned to one bullet.
+   */
+  createAnnotation: (resumeId: string, body: string, bulletId?: string) =>
+    apiFetch<ResumeAnnotation>(`/api/v1/resumes/${resumeId}/annotations`, {
+      method: "POST",
+      body: JSON.stringify({ body, bullet_id: bulletId }),
+    }),
+
+  /** DELETE /api/v1/resumes/:id/annotations/:noteId — author only */
+  deleteAnnotation: (resumeId: string, noteId: string) =>
+    fetch(`${API_BASE}/api/v1/resumes/${resumeId}/annotations/${noteId}`, {
+      method: "DELETE",
+      headers: authHeaders(),
+    }),
+
+  // ── Admin API (requires is_admin) ─────────────────────────────────────────
+
+  /** GET /api/v1/admin/stats/generations — resumes generated per UTC day */
+  getGenerationStats: (days?: number) =>
+    apiFetch<GenerationStats>(
+      `/api/v1/admin/stats/generations${days ? `?days=${days}` : ""}`
+    ),
+
+  /** GET /api/v1/admin/stats/llm-tokens — this month's token spend, top users first */
+  getTokenSpend: (limit?: number) =>
+    apiFetch<TokenSpendStats>(
+      `/api/v1/admin/stats/llm-tokens${limit ? `?limit=${limit}` : ""}`
+    ),
+
+  /** GET /api/v1/admin/stats/renders */
+  getRenderStats: (days?: number) =>
+    apiFetch<RenderStats>(
+      `/api/v1/admin/stats/renders${days ? `?days=${days}` : ""}`
+    ),
+
+  /** GET /api/v1/admin/stats/flagged-bullets */
+  getFlagStats: (days?: number) =>
+    apiFetch<FlagStats>(
+      `/api/v1/admin/stats/flagged-bullets${days ? `?days=${days}` : ""}`
+    ),
+
+  /** GET /api/v1/admin/queues */
+  getQueueDepths: () => apiFetch<QueueDepth[]>("/api/v1/admin/queues"),
+
+  /** POST /api/v1/admin/seed — deletes and recreates the demo account */
+  seedDemoUser: () =>
+    apiFetch<SeedReport>("/api/v1/admin/seed", {
+      method: "POST",
+    }),
 
   // ── Context Library API ────────────────────────────────────────────────────
 
   /**
-   * GET /api/v1/context?user_id={userId}
+   * GET /api/v1/context
    * Returns all current context entries (latest version per entry_id) and a
    * completeness health report.
    */
-  getContextEntries: (userId: string) =>
-    apiFetch<ContextEntriesResponse>(`/api/v1/context?user_id=${userId}`),
+  getContextEntries: () =>
+    apiFetch<ContextEntriesResponse>("/api/v1/context"),
+
+  /** DELETE /api/v1/context/version/:v — soft-deletes one context snapshot */
+  deleteContextVersion: (version: number) =>
+    fetch(`${API_BASE}/api/v1/context/version/${version}`, {
+      method: "DELETE",
+      headers: authHeaders(),
+    }),
 
   /**
    * PATCH /api/v1/context/entries/:entryId/evergreen
    * Flips the flagged_evergreen flag on an entry (append-only — creates new version).
    * Only meaningful for skill / certification entries, but the API accepts any type.
    */
-  toggleEvergreen: (entryId: string, userId: string, value: boolean) =>
+  toggleEvergreen: (entryId: string, value: boolean) =>
     apiFetch<void>(`/api/v1/context/entries/${entryId}/evergreen`, {
       method: "PATCH",
-      body: JSON.stringify({ flagged_evergreen: value, user_id: userId }),
+      body: JSON.stringify({ flagged_evergreen: value }),
     }),
 
   /**
@@ -191,9 +660,9 @@ export const api = {
    * Partial update of whitelisted data fields (append-only — creates new version).
    * Only fields in the server-side EDITABLE_FIELDS whitelist are applied.
    */
-  patchEntry: (entryId: string, userId: string, patch: Record<string, unknown>) =>
+  patchEntry: (entryId: string, patch: Record<string, unknown>) =>
     apiFetch<void>(`/api/v1/context/entries/${entryId}`, {
       method: "PATCH",
-      body: JSON.stringify({ user_id: userId, patch }),
+      body: JSON.stringify({ patch }),
     }),
 };
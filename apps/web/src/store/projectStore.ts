@@ -19,10 +19,10 @@ interface ProjectStore {
   error: string | null;
 
   // ─── Actions ───────────────────────────────────────────────────────────────
-  loadProjects: (userId: string) => Promise<void>;
+  loadProjects: () => Promise<void>;
   loadProject: (id: string) => Promise<void>;
   loadTemplates: () => Promise<void>;
-  createProject: (userId: string, name: string, templateId: string) => Promise<CvProject>;
+  createProject: (name: string, templateId: string) => Promise<CvProject>;
   /** Hard-deletes a project. Clears currentProject if it matches. */
   deleteProject: (id: string) => Promise<void>;
   /** Links a generated resume to the current project (fire-and-forget). */
@@ -42,10 +42,10 @@ export const useProjectStore = create<ProjectStore>((set, get) => ({
   setCurrentProject: (project) => set({ currentProject: project }),
   clearError: () => set({ error: null }),
 
-  loadProjects: async (userId) => {
+  loadProjects: async () => {
     set({ isLoadingProjects: true, error: null });
     try {
-      const { projects } = await api.listProjects(userId);
+      const { projects } = await api.listProjects();
       set({ projects });
     } catch (e) {
       set({ error: e instanceof Error ? e.message : "Failed to load projects" });
@@ -80,8 +80,8 @@ export const useProjectStore = create<ProjectStore>((set, get) => ({
     }
   },
 
-  createProject: async (userId, name, templateId) => {
-    const project = await api.createProject({ user_id: userId, name, template_id: templateId });
+  createProject: async (name, templateId) => {
+    const project = await api.createProject({ name, template_id: templateId });
     // Prepend to list so it appears at the top (projects are ordered by updated_at DESC)
     set((s) => ({ projects: [project, ...s.projects] }));
     return project;
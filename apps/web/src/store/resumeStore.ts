@@ -4,12 +4,6 @@ import { create } from "zustand";
 import { api } from "@/lib/api";
 import type { SimulatedBullet, FitReport, AuditManifest } from "@templar/types";
 
-/**
- * Hardcoded user ID for MVP development.
- * Auth integration (Clerk) will replace this in Phase 9.
- */
-export const MVP_USER_ID = "00000000-0000-0000-0000-000000000001";
-
 type RenderStatus = "idle" | "queued" | "rendering" | "done" | "failed";
 
 interface ResumeStore {
@@ -80,7 +74,7 @@ export const useResumeStore = create<ResumeStore>((set, get) => ({
 
     try {
       // Step 1: Generate resume
-      const generated = await api.generateResume(MVP_USER_ID, jdText);
+      const generated = await api.generateResume(jdText);
       console.log("[store] Step 1 done — resume generated", { resume_id: generated.resume_id, bulletCount: generated.bullets.length });
       set({
         resumeId: generated.resume_id,
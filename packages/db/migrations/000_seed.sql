@@ -1,10 +1,10 @@
--- Seed: dev user for pre-auth MVP
+-- Seed: dev user (00000000-0000-0000-0000-000000000001)
 --
--- This user is hardcoded in API handlers (00000000-0000-0000-0000-000000000001)
--- until Clerk auth is wired in Phase 9. This file must run AFTER 001_initial.sql
--- so that the users table already exists.
+-- Owns the dev fixtures. It has no password; sign up a fresh account through
+-- POST /api/v1/auth/signup to use the API locally. This file must run AFTER
+-- 001_initial.sql so that the users table already exists.
 --
--- Production: real users are created via Clerk webhook; this seed row is dev-only.
+-- Production: real users sign up through the API; this seed row is dev-only.
 
 INSERT INTO users (id, external_id, email, tier)
 VALUES (
@@ -0,0 +1,7 @@
+-- Migration 005: keyword coverage report on resumes
+--
+-- Stores which JD keywords made it into the generated bullets (and which did not).
+-- JSONB shape mirrors generation::keyword_coverage::CoverageReport.
+-- Nullable: resumes generated before this migration have no coverage report.
+
+ALTER TABLE resumes ADD COLUMN IF NOT EXISTS keyword_coverage JSONB;
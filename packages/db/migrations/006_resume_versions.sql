@@ -0,0 +1,14 @@
+-- Migration 006: resume version lineage
+--
+-- POST /api/v1/resumes/:id/regenerate creates a NEW resume row rather than
+-- overwriting the old one. parent_resume_id links the new row to the one it was
+-- regenerated from, so the full history of a JD's resumes is a simple chain walk.
+--
+-- ON DELETE SET NULL: deleting an old version must not cascade into its children.
+
+ALTER TABLE resumes
+    ADD COLUMN IF NOT EXISTS parent_resume_id UUID REFERENCES resumes(id) ON DELETE SET NULL;
+
+-- Partial index: most resumes are originals (NULL parent)
+CREATE INDEX IF NOT EXISTS idx_resumes_parent ON resumes(parent_resume_id)
+    WHERE parent_resume_id IS NOT NULL;
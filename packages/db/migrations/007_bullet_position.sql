@@ -0,0 +1,19 @@
+-- Migration 007: explicit bullet ordering
+--
+-- Bullets were previously read back ORDER BY section, id — id is a random UUID,
+-- so order within a section was arbitrary. `position` is a 0-based display order
+-- within the resume: set by generation (highest combined_score first) and
+-- rewritten by PATCH /api/v1/resumes/:id/bullets/reorder.
+
+ALTER TABLE resume_bullets ADD COLUMN IF NOT EXISTS position INTEGER NOT NULL DEFAULT 0;
+
+-- Backfill existing rows with the order they were previously displayed in.
+UPDATE resume_bullets b
+SET position = ranked.rn - 1
+FROM (
+    SELECT id, ROW_NUMBER() OVER (PARTITION BY resume_id ORDER BY section, id) AS rn
+    FROM resume_bullets
+) ranked
+WHERE b.id = ranked.id;
+
+CREATE INDEX IF NOT EXISTS idx_resume_bullets_position ON resume_bullets(resume_id, position);
@@ -0,0 +1,41 @@
+-- Migration 008: batch resume generation
+--
+-- POST /api/v1/resumes/generate-batch accepts several JDs at once. Each JD becomes
+-- a generation_jobs row processed in the background; the batch row groups them so
+-- the client can poll one id. Counts are derived from job rows (no counters to drift).
+--
+-- resume_id is populated on success. ON DELETE SET NULL: deleting a resume keeps the
+-- job history intact.
+
+-- ============================================================
+-- generation_batches
+-- ============================================================
+CREATE TABLE IF NOT EXISTS generation_batches (
+    id          UUID        PRIMARY KEY DEFAULT gen_random_uuid(),
+    user_id     UUID        NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+    total       INT         NOT NULL,
+    created_at  TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX IF NOT EXISTS idx_generation_batches_user ON generation_batches(user_id, created_at DESC);
+
+-- ============================================================
+-- generation_jobs
+-- ============================================================
+CREATE TABLE IF NOT EXISTS generation_jobs (
+    id          UUID        PRIMARY KEY DEFAULT gen_random_uuid(),
+    batch_id    UUID        NOT NULL REFERENCES generation_batches(id) ON DELETE CASCADE,
+    user_id     UUID        NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+    jd_index    INT         NOT NULL,
+    jd_text     TEXT        NOT NULL,
+    status      TEXT        NOT NULL DEFAULT 'queued',  -- queued | running | succeeded | failed
+    resume_id   UUID        REFERENCES resumes(id) ON DELETE SET NULL,
+    error_msg   TEXT,
+    created_at  TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at  TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX IF NOT EXISTS idx_generation_jobs_batch ON generation_jobs(batch_id, jd_index);
+-- Quota check counts a user's in-flight jobs
+CREATE INDEX IF NOT EXISTS idx_generation_jobs_user_active ON generation_jobs(user_id)
+    WHERE status IN ('queued', 'running');
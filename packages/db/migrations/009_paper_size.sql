@@ -0,0 +1,9 @@
+-- Migration 009: per-resume paper size
+--
+-- Layout geometry (text width, usable lines) is derived from the paper size, so the
+-- render worker must set the PDF on the same paper the generator simulated against.
+-- Existing rows were all laid out for US letter.
+
+ALTER TABLE resumes
+    ADD COLUMN IF NOT EXISTS paper_size TEXT NOT NULL DEFAULT 'letter'
+        CHECK (paper_size IN ('letter', 'a4'));
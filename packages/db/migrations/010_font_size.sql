@@ -0,0 +1,9 @@
+-- Migration 010: per-resume body font size
+--
+-- The generator may step the font down (to fit an overflowing page) or up (to fill a
+-- sparse one) in half-point steps. The render worker must typeset at the size the
+-- layout was simulated at. Existing rows were all laid out at 11pt.
+
+ALTER TABLE resumes
+    ADD COLUMN IF NOT EXISTS font_size_pt REAL NOT NULL DEFAULT 11
+        CHECK (font_size_pt BETWEEN 8 AND 14);
@@ -0,0 +1,8 @@
+-- Migration 011: layout report on resumes
+--
+-- Stores what the layout pipeline did: simulation passes, LLM calls, fill remediation
+-- actions, the final page fill, and every bullet flagged for review with its reason.
+-- JSONB shape mirrors generation::layout_report::LayoutReport.
+-- Nullable: resumes generated before this migration have no layout report.
+
+ALTER TABLE resumes ADD COLUMN IF NOT EXISTS layout_report JSONB;
@@ -0,0 +1,10 @@
+-- Migration 012: per-resume margin preset
+--
+-- compact (0.7"), standard (1.0") or airy (1.2") on all four sides. The layout
+-- simulation derives text width and page height from it, and the render worker
+-- forces the same margins onto the template so the PDF matches the simulation.
+-- Existing rows were laid out with 1" margins.
+
+ALTER TABLE resumes
+    ADD COLUMN IF NOT EXISTS margin_preset TEXT NOT NULL DEFAULT 'standard'
+        CHECK (margin_preset IN ('compact', 'standard', 'airy'));
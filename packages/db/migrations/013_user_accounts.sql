@@ -0,0 +1,10 @@
+-- Migration 013: password accounts and editable profile fields
+--
+-- Users can now sign up with email + password (POST /api/v1/auth/signup) instead of
+-- only being provisioned from the identity provider. password_hash is NULL for
+-- IdP-provisioned users; local accounts get external_id 'local:<id>'.
+
+ALTER TABLE users
+    ADD COLUMN IF NOT EXISTS password_hash TEXT,
+    ADD COLUMN IF NOT EXISTS display_name  TEXT,
+    ADD COLUMN IF NOT EXISTS updated_at    TIMESTAMPTZ NOT NULL DEFAULT NOW();
@@ -0,0 +1,23 @@
+-- Migration 014: audit log of mutating API operations
+--
+-- One row per successful mutation (context ingest/edit/evergreen, resume generation,
+-- renders, projects, profile changes), readable by its user at
+-- GET /api/v1/users/me/audit. before/after hold identifiers and versions, never
+-- résumé content. request_id ties an entry to the request's logs.
+
+CREATE TABLE IF NOT EXISTS audit_log (
+    id            UUID        PRIMARY KEY DEFAULT gen_random_uuid(),
+    user_id       UUID        NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+    action        TEXT        NOT NULL,  -- resource.verb, e.g. context_entry.edit
+    resource_type TEXT        NOT NULL,
+    resource_id   UUID,
+    before        JSONB,
+    after         JSONB,
+    request_id    TEXT,
+    created_at    TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX IF NOT EXISTS idx_audit_log_user ON audit_log(user_id, created_at DESC);
+-- "Who changed this entry": history of one resource
+CREATE INDEX IF NOT EXISTS idx_audit_log_resource ON audit_log(resource_id, created_at DESC)
+    WHERE resource_id IS NOT NULL;
@@ -0,0 +1,19 @@
+-- Migration 015: receipts for deleted accounts
+--
+-- DELETE /api/v1/users/me removes the users row and everything cascading from it, then
+-- writes one receipt here. user_id deliberately has no foreign key: the receipt must
+-- outlive the account it records. Rows hold counts only, never user content.
+
+CREATE TABLE IF NOT EXISTS account_deletion_receipts (
+    id                  UUID        PRIMARY KEY DEFAULT gen_random_uuid(),
+    user_id             UUID        NOT NULL,
+    deleted_at          TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    rows_deleted        JSONB       NOT NULL,  -- {"resumes": 3, "resume_bullets": 41, ...}
+    s3_objects_deleted  INT         NOT NULL DEFAULT 0,
+    s3_objects_retrying INT         NOT NULL DEFAULT 0,
+    usage_keys_deleted  INT         NOT NULL DEFAULT 0,
+    request_id          TEXT
+);
+
+CREATE INDEX IF NOT EXISTS idx_account_deletion_receipts_user
+    ON account_deletion_receipts(user_id);
@@ -0,0 +1,18 @@
+-- Migration 016: full data exports
+--
+-- POST /api/v1/users/me/export inserts a pending row and queues an ExportJob; the
+-- worker moves it to running, then completed (with the ZIP's S3 key) or failed.
+-- Archives live at exports/{user_id}/{id}.zip and are removed with the account.
+
+CREATE TABLE IF NOT EXISTS user_exports (
+    id            UUID        PRIMARY KEY DEFAULT gen_random_uuid(),
+    user_id       UUID        NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+    status        TEXT        NOT NULL DEFAULT 'pending',  -- pending | running | completed | failed
+    s3_key        TEXT,
+    size_bytes    BIGINT,
+    error_message TEXT,
+    created_at    TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    completed_at  TIMESTAMPTZ
+);
+
+CREATE INDEX IF NOT EXISTS idx_user_exports_user ON user_exports(user_id, created_at DESC);
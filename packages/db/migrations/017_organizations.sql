@@ -0,0 +1,38 @@
+-- Migration 017: organizations, membership and resume annotations
+--
+-- Career coaches and bootcamps group candidates into an organization. Roles:
+--   owner     — manages membership; everything a coach can do
+--   coach     — reads and annotates the org's candidates' resumes
+--   candidate — coached; usage counts against the org's shared pool (organizations.tier)
+-- A user is a candidate in at most one org, so their pool is unambiguous.
+
+CREATE TABLE IF NOT EXISTS organizations (
+    id         UUID        PRIMARY KEY DEFAULT gen_random_uuid(),
+    name       TEXT        NOT NULL,
+    tier       TEXT        NOT NULL DEFAULT 'starter',  -- starter | growth | enterprise
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE TABLE IF NOT EXISTS org_memberships (
+    org_id     UUID        NOT NULL REFERENCES organizations(id) ON DELETE CASCADE,
+    user_id    UUID        NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+    role       TEXT        NOT NULL CHECK (role IN ('owner', 'coach', 'candidate')),
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    PRIMARY KEY (org_id, user_id)
+);
+
+CREATE INDEX IF NOT EXISTS idx_org_memberships_user ON org_memberships(user_id);
+CREATE UNIQUE INDEX IF NOT EXISTS idx_org_memberships_one_sponsor
+    ON org_memberships(user_id) WHERE role = 'candidate';
+
+-- Notes on a resume by its owner or their coaches; bullet_id pins a note to one bullet.
+CREATE TABLE IF NOT EXISTS resume_annotations (
+    id         UUID        PRIMARY KEY DEFAULT gen_random_uuid(),
+    resume_id  UUID        NOT NULL REFERENCES resumes(id) ON DELETE CASCADE,
+    bullet_id  UUID        REFERENCES resume_bullets(id) ON DELETE CASCADE,
+    author_id  UUID        NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+    body       TEXT        NOT NULL,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX IF NOT EXISTS idx_resume_annotations_resume ON resume_annotations(resume_id, created_at);
@@ -0,0 +1,8 @@
+-- Migration 018: admin role
+--
+-- Admins can read the aggregate stats under /api/v1/admin (generation volume, token
+-- spend, render failures, flag rates, queue depths). There is no endpoint that grants
+-- the role; set it directly:
+--   UPDATE users SET is_admin = TRUE WHERE email = '...';
+
+ALTER TABLE users ADD COLUMN IF NOT EXISTS is_admin BOOLEAN NOT NULL DEFAULT FALSE;
@@ -0,0 +1,14 @@
+-- Migration 019: soft delete for resumes and context snapshots
+--
+-- Deleting a resume or snapshot only stamps deleted_at; every read filters those
+-- rows out. The retention sweep (see apps/api/src/retention.rs) hard-deletes rows,
+-- and their S3 objects, once deleted_at is older than RETENTION_DAYS.
+
+ALTER TABLE resumes ADD COLUMN IF NOT EXISTS deleted_at TIMESTAMPTZ;
+ALTER TABLE context_snapshots ADD COLUMN IF NOT EXISTS deleted_at TIMESTAMPTZ;
+
+-- The sweep only ever scans the deleted rows.
+CREATE INDEX IF NOT EXISTS idx_resumes_deleted_at
+    ON resumes (deleted_at) WHERE deleted_at IS NOT NULL;
+CREATE INDEX IF NOT EXISTS idx_context_snapshots_deleted_at
+    ON context_snapshots (deleted_at) WHERE deleted_at IS NOT NULL;
@@ -0,0 +1,68 @@
+-- Migration 020: context entry compaction
+--
+-- context_entries is append-only, so "current entries" used to be a DISTINCT ON scan
+-- over every version a user ever wrote. Two changes keep that cheap:
+--
+-- 1. context_current_entries points at the latest version of each entry. A trigger
+--    maintains it on every INSERT into context_entries, in the same transaction, so
+--    no write path can forget it.
+-- 2. context_entries_archive takes versions beyond the newest COMPACTION_KEEP_VERSIONS
+--    of each entry (moved by the compaction job, see apps/api/src/context/compaction.rs).
+--    Archived rows still answer GET /context/version/:v and data exports.
+
+CREATE TABLE IF NOT EXISTS context_current_entries (
+    user_id      UUID NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+    entry_id     UUID NOT NULL,
+    -- context_entries.id of the latest version; never archived.
+    entry_row_id UUID NOT NULL REFERENCES context_entries(id) ON DELETE CASCADE,
+    version      INT  NOT NULL,
+
+    PRIMARY KEY (user_id, entry_id)
+);
+
+-- Ownership checks look entries up by entry_id alone.
+CREATE INDEX IF NOT EXISTS idx_context_current_entries_entry_id
+    ON context_current_entries (entry_id);
+
+CREATE OR REPLACE FUNCTION sync_context_current_entry() RETURNS trigger AS $$
+BEGIN
+    INSERT INTO context_current_entries (user_id, entry_id, entry_row_id, version)
+    VALUES (NEW.user_id, NEW.entry_id, NEW.id, NEW.version)
+    ON CONFLICT (user_id, entry_id) DO UPDATE
+        SET entry_row_id = EXCLUDED.entry_row_id, version = EXCLUDED.version
+        WHERE context_current_entries.version < EXCLUDED.version;
+    RETURN NULL;
+END;
+$$ LANGUAGE plpgsql;
+
+DROP TRIGGER IF EXISTS context_entries_sync_current ON context_entries;
+CREATE TRIGGER context_entries_sync_current
+    AFTER INSERT ON context_entries
+    FOR EACH ROW EXECUTE FUNCTION sync_context_current_entry();
+
+INSERT INTO context_current_entries (user_id, entry_id, entry_row_id, version)
+SELECT DISTINCT ON (user_id, entry_id) user_id, entry_id, id, version
+FROM context_entries
+ORDER BY user_id, entry_id, version DESC
+ON CONFLICT (user_id, entry_id) DO NOTHING;
+
+CREATE TABLE IF NOT EXISTS context_entries_archive (
+    id                UUID        PRIMARY KEY,
+    user_id           UUID        NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+    entry_id          UUID        NOT NULL,
+    version           INT         NOT NULL,
+    entry_type        TEXT        NOT NULL,
+    data              JSONB       NOT NULL,
+    raw_text          TEXT,
+    recency_score     FLOAT8      NOT NULL,
+    impact_score      FLOAT8      NOT NULL,
+    tags              TEXT[]      NOT NULL,
+    flagged_evergreen BOOLEAN     NOT NULL,
+    contribution_type TEXT        NOT NULL,
+    quality_score     FLOAT8      NOT NULL,
+    quality_flags     TEXT[]      NOT NULL,
+    created_at        TIMESTAMPTZ NOT NULL,
+    archived_at       TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+
+    UNIQUE (user_id, entry_id, version)
+);
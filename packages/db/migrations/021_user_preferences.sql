@@ -0,0 +1,17 @@
+-- Migration 021: user preferences
+--
+-- Defaults the generation pipeline applies when a request leaves them out. NULL means
+-- "no preference" (the server default applies). One row per user, created on first
+-- PUT /api/v1/users/me/preferences.
+
+CREATE TABLE IF NOT EXISTS user_preferences (
+    user_id         UUID        PRIMARY KEY REFERENCES users(id) ON DELETE CASCADE,
+    template_id     TEXT,                 -- templates directory name, not a FK
+    font_size_pt    REAL,                 -- 10-12 in half points
+    paper_size      TEXT,                 -- letter | a4
+    margin_preset   TEXT,                 -- compact | standard | airy
+    tone            TEXT,                 -- a JDTone variant; replaces the detected tone
+    scoring_weights JSONB,                -- {"recency", "impact", "jd_relevance"}, sums to 1
+    notifications   JSONB       NOT NULL DEFAULT '{}',
+    updated_at      TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
@@ -0,0 +1,30 @@
+-- Migration 022: job application tracker
+--
+-- One row per job the user applied to, optionally linked to the resume they sent.
+-- Connects generated resumes to outcomes: which resumes got screens, interviews and
+-- offers.
+
+CREATE TABLE IF NOT EXISTS applications (
+    id                UUID        PRIMARY KEY DEFAULT gen_random_uuid(),
+    user_id           UUID        NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+    company           TEXT        NOT NULL,
+    role              TEXT        NOT NULL,
+    -- Posting URL or requisition id; free text
+    jd_reference      TEXT,
+    -- SET NULL when the resume is purged, so the outcome survives the resume
+    resume_id         UUID        REFERENCES resumes(id) ON DELETE SET NULL,
+    status            TEXT        NOT NULL DEFAULT 'applied'
+                      CHECK (status IN ('applied', 'screen', 'interview', 'offer', 'rejected')),
+    applied_on        DATE        NOT NULL DEFAULT CURRENT_DATE,
+    status_changed_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    notes             TEXT,
+    created_at        TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at        TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+-- The tracker list, newest activity first
+CREATE INDEX IF NOT EXISTS idx_applications_user_id ON applications(user_id, updated_at DESC);
+
+-- Outcomes per resume, for conversion analytics
+CREATE INDEX IF NOT EXISTS idx_applications_resume ON applications(resume_id)
+    WHERE resume_id IS NOT NULL;
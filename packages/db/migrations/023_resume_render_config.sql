@@ -0,0 +1,20 @@
+-- Migration 023: the full page config and prompt versions behind each resume
+--
+-- template_id, paper_size, font_size_pt and margin_preset are already stored. This
+-- adds what a faithful re-render still had to re-derive from current defaults:
+--   font_family     — the metric table the layout was simulated with
+--   margin_*_in     — the exact margins, not just the preset they came from
+--   prompt_versions — fingerprints of the prompts that wrote the bullets
+--
+-- Rows from before this migration were all laid out in Inter. Their margins stay NULL
+-- until the next render, which derives them from margin_preset and stores them.
+
+ALTER TABLE resumes
+    ADD COLUMN IF NOT EXISTS font_family TEXT NOT NULL DEFAULT 'inter'
+        CHECK (font_family IN ('inter', 'eb_garamond', 'lato', 'oswald', 'computer_modern'));
+ALTER TABLE resumes
+    ADD COLUMN IF NOT EXISTS margin_left_in   REAL,
+    ADD COLUMN IF NOT EXISTS margin_right_in  REAL,
+    ADD COLUMN IF NOT EXISTS margin_top_in    REAL,
+    ADD COLUMN IF NOT EXISTS margin_bottom_in REAL,
+    ADD COLUMN IF NOT EXISTS prompt_versions  JSONB;
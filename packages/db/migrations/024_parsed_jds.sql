@@ -0,0 +1,25 @@
+-- Migration 024: job postings pulled from public job board APIs
+--
+-- POST /api/v1/job-postings/import and /job-postings/board fetch postings from the
+-- Greenhouse and Lever board APIs and keep one row per posting, shared by every user:
+-- the postings are public. source_key identifies the posting on its board
+-- ("{board_token}/{job_id}"); jd_text is the normalized posting text generation takes.
+--
+-- parsed_jd caches parse_jd's output for jd_text. Refetching a posting whose text
+-- changed clears it, so it is re-parsed on next use.
+
+CREATE TABLE IF NOT EXISTS parsed_jds (
+    id          UUID        PRIMARY KEY DEFAULT gen_random_uuid(),
+    source      TEXT        NOT NULL CHECK (source IN ('greenhouse', 'lever')),
+    source_key  TEXT        NOT NULL,
+    source_url  TEXT        NOT NULL,
+    title       TEXT        NOT NULL,
+    company     TEXT,
+    location    TEXT,
+    department  TEXT,
+    jd_text     TEXT        NOT NULL,
+    metadata    JSONB       NOT NULL DEFAULT '{}',
+    parsed_jd   JSONB,
+    fetched_at  TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    UNIQUE (source, source_key)
+);
@@ -0,0 +1,22 @@
+-- Migration 025: Slack and Discord webhooks for pipeline events
+--
+-- Each row posts the events listed in `events` (generation_complete, render_complete,
+-- render_failed, quota_warning) to a Slack incoming webhook or a Discord channel
+-- webhook. The URL is the credential, so the API never returns it in full.
+--
+-- Delivery records its last attempt. A webhook the platform reports as gone (404/410:
+-- deleted in Slack or Discord) gets disabled_at and is skipped until re-created.
+
+CREATE TABLE IF NOT EXISTS user_webhooks (
+    id               UUID        PRIMARY KEY DEFAULT gen_random_uuid(),
+    user_id          UUID        NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+    kind             TEXT        NOT NULL CHECK (kind IN ('slack', 'discord')),
+    url              TEXT        NOT NULL,
+    events           TEXT[]      NOT NULL,
+    created_at       TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    last_delivery_at TIMESTAMPTZ,
+    last_error       TEXT,
+    disabled_at      TIMESTAMPTZ
+);
+
+CREATE INDEX IF NOT EXISTS idx_user_webhooks_user ON user_webhooks(user_id);
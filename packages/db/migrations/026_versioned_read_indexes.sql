@@ -0,0 +1,28 @@
+-- Migration 026: indexes for versioned context reads
+--
+-- The versioned reads in apps/api/src/context/versioning.rs filter on user_id and
+-- sort by (entry_id, version DESC). The UNIQUE (user_id, entry_id, version) indexes
+-- sort version ascending, so Postgres could not use them for DISTINCT ON and fell
+-- back to a sort of every version the user ever wrote. The expected plans are pinned
+-- in versioning.rs tests (`test_versioned_read_indexes_*`).
+
+-- get_entries_at_version: DISTINCT ON (entry_id) ... ORDER BY entry_id, version DESC
+-- becomes an Index Scan + Unique, with no Sort node.
+CREATE INDEX IF NOT EXISTS idx_context_entries_user_entry_version
+    ON context_entries (user_id, entry_id, version DESC);
+CREATE INDEX IF NOT EXISTS idx_context_entries_archive_user_entry_version
+    ON context_entries_archive (user_id, entry_id, version DESC);
+
+-- commit_context_update / get_context_revision: MAX(version) per user is answered
+-- from the top of this index (Index Only Scan Backward + Limit).
+CREATE INDEX IF NOT EXISTS idx_context_current_entries_user_version
+    ON context_current_entries (user_id, version);
+
+-- get_current_entries walks the pointers in entry_id order; carrying entry_row_id and
+-- version makes that an Index Only Scan that never visits the heap.
+CREATE INDEX IF NOT EXISTS idx_context_current_entries_latest_versions
+    ON context_current_entries (user_id, entry_id) INCLUDE (entry_row_id, version);
+
+-- Superseded: every query that filtered on user_id alone is served by the composite
+-- index above, which has user_id as its leading column.
+DROP INDEX IF EXISTS idx_context_entries_user_id;
@@ -0,0 +1,9 @@
+-- Migration 027: domain packs
+--
+-- A domain pack (engineering | product | marketing | finance | academia) swaps the
+-- verb sets generation offers and the vague verbs ingest flags; see
+-- apps/api/src/generation/domain_pack.rs. NULL means engineering, the old behaviour.
+-- A persona's pack wins over the user's when generating with that persona.
+
+ALTER TABLE user_preferences ADD COLUMN IF NOT EXISTS domain_pack TEXT;
+ALTER TABLE personas ADD COLUMN IF NOT EXISTS domain_pack TEXT;
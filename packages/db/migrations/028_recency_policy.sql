@@ -0,0 +1,14 @@
+-- Migration 028: configurable recency decay
+--
+-- user_preferences.recency_policy holds a RecencyPolicy (apps/api/src/context/
+-- scoring.rs): a decay curve per entry type plus a default. NULL keeps the old
+-- behaviour: an 18-month half-life, with skills and certifications never decaying.
+--
+-- Each entry version records the curve its recency_score was computed with, so a
+-- recomputation can reproduce the score from the curve, the entry's end date and
+-- created_at. NULL on rows written before this migration (18-month exponential).
+-- The archive mirrors context_entries column for column.
+
+ALTER TABLE user_preferences ADD COLUMN IF NOT EXISTS recency_policy JSONB;
+ALTER TABLE context_entries ADD COLUMN IF NOT EXISTS recency_policy JSONB;
+ALTER TABLE context_entries_archive ADD COLUMN IF NOT EXISTS recency_policy JSONB;
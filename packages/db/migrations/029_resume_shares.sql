@@ -0,0 +1,18 @@
+-- Migration 029: public read-only share links for resumes
+--
+-- One row per link minted with POST /resumes/:id/share. The URL carries a signed
+-- token (see apps/api/src/shares/mod.rs); the row is what makes it revocable: a
+-- token only opens the resume while its row is unrevoked and unexpired.
+
+CREATE TABLE IF NOT EXISTS resume_shares (
+    id          UUID        PRIMARY KEY DEFAULT gen_random_uuid(),
+    resume_id   UUID        NOT NULL REFERENCES resumes(id) ON DELETE CASCADE,
+    user_id     UUID        NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+    -- Signed into the token too; stored to the second, as the token carries it
+    expires_at  TIMESTAMPTZ NOT NULL,
+    revoked_at  TIMESTAMPTZ,
+    created_at  TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+-- A resume's links, newest first
+CREATE INDEX IF NOT EXISTS idx_resume_shares_resume ON resume_shares(resume_id, created_at DESC);
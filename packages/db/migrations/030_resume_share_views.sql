@@ -0,0 +1,22 @@
+-- Migration 030: opens of public share links
+--
+-- One row each time a share link's HTML page or PDF is fetched, so the owner can see
+-- whether the resume they sent was opened. Only coarse details are kept: the country
+-- a trusted CDN reports and the browser family, OS and device class parsed from the
+-- User-Agent. Neither the IP address nor the raw User-Agent is stored.
+
+CREATE TABLE IF NOT EXISTS resume_share_views (
+    id         UUID        PRIMARY KEY DEFAULT gen_random_uuid(),
+    share_id   UUID        NOT NULL REFERENCES resume_shares(id) ON DELETE CASCADE,
+    format     TEXT        NOT NULL CHECK (format IN ('html', 'pdf')),
+    -- ISO 3166-1 alpha-2; NULL unless the API sits behind a proxy that sets it
+    country    TEXT,
+    browser    TEXT,
+    os         TEXT,
+    -- 'bot' covers link unfurlers (Slack, LinkedIn, …) and crawlers
+    device     TEXT        NOT NULL CHECK (device IN ('desktop', 'mobile', 'tablet', 'bot', 'unknown')),
+    viewed_at  TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+-- A link's opens, newest first
+CREATE INDEX IF NOT EXISTS idx_resume_share_views_share ON resume_share_views(share_id, viewed_at DESC);
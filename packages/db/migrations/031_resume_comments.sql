@@ -0,0 +1,34 @@
+-- Migration 031: threaded review comments on resume bullets
+--
+-- Reviewers comment on one bullet at a time. A comment with no parent_id starts a
+-- thread; replies point at that first comment, so threads are one level deep. Only
+-- the first comment carries resolved_at: resolving or reopening applies to the thread.
+--
+-- Two kinds of reviewer:
+--   account — the resume's owner or a coach of theirs (author_id set)
+--   link    — anyone holding a share link minted with can_comment (share_id set);
+--             they have no account, so author_name is whatever name they gave
+-- author_name is filled in for account authors too, so a thread reads the same to both.
+
+ALTER TABLE resume_shares ADD COLUMN IF NOT EXISTS can_comment BOOLEAN NOT NULL DEFAULT false;
+
+CREATE TABLE IF NOT EXISTS resume_comments (
+    id           UUID        PRIMARY KEY DEFAULT gen_random_uuid(),
+    resume_id    UUID        NOT NULL REFERENCES resumes(id) ON DELETE CASCADE,
+    bullet_id    UUID        NOT NULL REFERENCES resume_bullets(id) ON DELETE CASCADE,
+    parent_id    UUID        REFERENCES resume_comments(id) ON DELETE CASCADE,
+    author_id    UUID        REFERENCES users(id) ON DELETE CASCADE,
+    share_id     UUID        REFERENCES resume_shares(id) ON DELETE CASCADE,
+    author_name  TEXT        NOT NULL,
+    body         TEXT        NOT NULL,
+    resolved_at  TIMESTAMPTZ,
+    resolved_by  UUID        REFERENCES users(id) ON DELETE SET NULL,
+    created_at   TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    CHECK ((author_id IS NULL) <> (share_id IS NULL))
+);
+
+-- A resume's comments, oldest first
+CREATE INDEX IF NOT EXISTS idx_resume_comments_resume ON resume_comments(resume_id, created_at);
+-- Open threads, counted on GET /resumes/:id
+CREATE INDEX IF NOT EXISTS idx_resume_comments_open ON resume_comments(resume_id)
+    WHERE parent_id IS NULL AND resolved_at IS NULL;
@@ -0,0 +1,31 @@
+-- Migration 032: target roles — job descriptions saved before generation
+--
+-- A target role is a job the user is considering: its title and company, the JD
+-- text as they paste and edit it (autosaved), and their own notes. Generation can
+-- run against a role instead of raw JD text, and the resumes it produces link back.
+--
+-- parsed_jd caches parse_jd's output for jd_text; editing jd_text clears it, so it
+-- is re-parsed on next use.
+
+CREATE TABLE IF NOT EXISTS target_roles (
+    id          UUID        PRIMARY KEY DEFAULT gen_random_uuid(),
+    user_id     UUID        NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+    title       TEXT        NOT NULL,
+    company     TEXT,
+    -- Empty until the user pastes the posting
+    jd_text     TEXT        NOT NULL DEFAULT '',
+    parsed_jd   JSONB,
+    notes       TEXT,
+    created_at  TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at  TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+-- The pipeline list, most recently edited first
+CREATE INDEX IF NOT EXISTS idx_target_roles_user_id ON target_roles(user_id, updated_at DESC);
+
+-- SET NULL when the role is deleted; the resume keeps its own copy of the JD
+ALTER TABLE resumes
+    ADD COLUMN IF NOT EXISTS target_role_id UUID REFERENCES target_roles(id) ON DELETE SET NULL;
+
+CREATE INDEX IF NOT EXISTS idx_resumes_target_role ON resumes(target_role_id)
+    WHERE target_role_id IS NOT NULL;
@@ -0,0 +1,8 @@
+-- Migration 033: bullet line map on render jobs
+--
+-- Where each bullet landed in the job's PDF: page, first line and line count, read
+-- back from position marks pdflatex writes while compiling. JSONB shape is an array
+-- of render::BulletPlacement. Nullable: jobs rendered before this migration, and
+-- failed jobs, have none.
+
+ALTER TABLE render_jobs ADD COLUMN IF NOT EXISTS line_map JSONB;
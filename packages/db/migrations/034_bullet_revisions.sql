@@ -0,0 +1,20 @@
+-- Migration 034: per-bullet revision history
+--
+-- Every text a bullet has had, oldest first. Generation writes the first draft
+-- (cause 'generate') plus one row per change the pipeline made before saving:
+-- 'expand' / 'compress' from layout simulation and page fill, 'regenerate' from a
+-- grounding or keyword-stuffing rewrite. Restoring an earlier revision adds a 'user'
+-- row whose restored_from names it. The newest row matches resume_bullets.bullet_text.
+-- Bullets saved before this migration have no history.
+
+CREATE TABLE IF NOT EXISTS bullet_revisions (
+    id            UUID        PRIMARY KEY DEFAULT gen_random_uuid(),
+    bullet_id     UUID        NOT NULL REFERENCES resume_bullets(id) ON DELETE CASCADE,
+    revision      INTEGER     NOT NULL,  -- 1-based within the bullet
+    bullet_text   TEXT        NOT NULL,
+    cause         TEXT        NOT NULL
+                  CHECK (cause IN ('generate', 'expand', 'compress', 'regenerate', 'user')),
+    restored_from INTEGER,
+    created_at    TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    UNIQUE (bullet_id, revision)
+);
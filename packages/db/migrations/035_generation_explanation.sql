@@ -0,0 +1,9 @@
+-- Migration 035: generation explanation on resumes
+--
+-- Why the resume looks the way it does: the entries selection kept and cut (with
+-- reasons), the tone applied and whether it came from the JD or the user's
+-- preference, and the JD keywords that drove selection. JSONB shape is
+-- generation::explanation::GenerationExplanation. Nullable: resumes generated before
+-- this migration have none.
+
+ALTER TABLE resumes ADD COLUMN IF NOT EXISTS explanation JSONB;
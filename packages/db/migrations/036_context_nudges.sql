@@ -0,0 +1,14 @@
+-- Migration 036: stale context nudge deliveries
+--
+-- One row per nudge the freshness check has sent a user (see context::freshness):
+-- nudge_key is the nudge kind, the stale entry (or '-' for the skills section) and
+-- when it was last updated, so a nudge goes out once, and again only after the entry
+-- is updated and goes stale anew. Webhook subscriptions gain the 'context_stale'
+-- event (user_webhooks.events is unconstrained TEXT[]).
+
+CREATE TABLE IF NOT EXISTS context_nudge_deliveries (
+    user_id   UUID        NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+    nudge_key TEXT        NOT NULL,
+    sent_at   TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    PRIMARY KEY (user_id, nudge_key)
+);
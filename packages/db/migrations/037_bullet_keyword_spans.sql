@@ -0,0 +1,9 @@
+-- Migration 037: where JD keywords appear in each bullet
+--
+-- Serialized Vec<KeywordSpan> (keyword, start, end in UTF-16 code units), computed
+-- on the final bullet text at generation and again when a revision is restored, so
+-- clients can highlight keyword coverage inline without matching keywords themselves.
+-- Rows written before this migration have no spans.
+
+ALTER TABLE resume_bullets
+    ADD COLUMN IF NOT EXISTS keyword_spans JSONB NOT NULL DEFAULT '[]'::jsonb;
@@ -0,0 +1,413 @@
+//! Async client for the Templar API, typed with `templar-types`.
+//!
+//! One method per endpoint, plus `wait_for_batch` / `wait_for_render` to poll the two
+//! async pipelines to a terminal state:
+//!
+//! ```no_run
+//! # async fn run() -> Result<(), templar_client::ClientError> {
+//! let mut client = templar_client::TemplarClient::new("http://localhost:8080")?;
+//! client.login("me@example.com", "secret").await?;
+//! let generated = client
+//!     .generate(&templar_client::GenerateOptions::new("Senior Rust engineer…"))
+//!     .await?;
+//! let job = client.trigger_render(generated.resume_id).await?;
+//! client.wait_for_render(job.job_id).await?;
+//! let pdf = client.download_pdf(job.job_id).await?;
+//! # Ok(()) }
+//! ```
+
+use std::time::Duration;
+
+use reqwest::{Client, RequestBuilder, Response};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use thiserror::Error;
+use uuid::Uuid;
+
+use templar_types::context::{BatchStartResponse, BatchStatusResponse, IngestBatchRequest};
+use templar_types::generation::{
+    FitScoreRequest, FitScoreResponse, GenerateResponse, ParseJdHtmlRequest, ParseJdHtmlResponse,
+    ParseJdRequest, ParseJdResponse,
+};
+use templar_types::integrations::{
+    JobBoard, JobBoardImportRequest, JobBoardImportResponse, JobPostingImportRequest,
+    JobPostingImportResponse,
+};
+use templar_types::interop::{
+    AnonymizedResume, ExportFormat, JsonResume, JsonResumeImportResponse, ResumeExportQuery,
+};
+use templar_types::render::{
+    AtsCheckResponse, RenderLineMapResponse, RenderStatusResponse, TriggerRenderRequest,
+    TriggerRenderResponse,
+};
+
+/// How often `wait_for_*` polls.
+const POLL_INTERVAL: Duration = Duration::from_secs(2);
+/// Generation runs several LLM calls; leave it well past the server's own timeout.
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(300);
+
+#[derive(Debug, Error)]
+pub enum ClientError {
+    #[error("HTTP error: {0}")]
+    Http(#[from] reqwest::Error),
+
+    /// A non-2xx response. `request_id` matches the API's logs.
+    #[error("{}", api_message(.status, .message, .request_id.as_deref()))]
+    Api {
+        status: u16,
+        message: String,
+        request_id: Option<String>,
+    },
+
+    #[error("render job {job_id} failed: {message}")]
+    RenderFailed { job_id: Uuid, message: String },
+}
+
+fn api_message(status: &u16, message: &str, request_id: Option<&str>) -> String {
+    let message = if message.is_empty() {
+        format!("HTTP {status}")
+    } else {
+        message.to_string()
+    };
+    match request_id {
+        Some(id) => format!("{message} (request id: {id})"),
+        None => message,
+    }
+}
+
+/// The client-settable fields of `POST /api/v1/resumes/generate`. Unset page options
+/// fall back to the caller's saved preferences, then the server defaults.
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct GenerateOptions {
+    pub jd_text: String,
+    /// `letter` or `a4`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub paper_size: Option<String>,
+    /// `compact`, `standard` or `airy`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub margin_preset: Option<String>,
+    /// Streams stages to `GET /api/v1/events/:progress_id`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub progress_id: Option<Uuid>,
+}
+
+impl GenerateOptions {
+    pub fn new(jd_text: impl Into<String>) -> Self {
+        Self {
+            jd_text: jd_text.into(),
+            ..Self::default()
+        }
+    }
+}
+
+#[derive(Deserialize)]
+struct TokenResponse {
+    token: String,
+}
+
+pub struct TemplarClient {
+    http: Client,
+    base_url: String,
+    token: Option<String>,
+}
+
+impl TemplarClient {
+    /// A signed-out client for the API at `base_url` (e.g. `http://localhost:8080`).
+    pub fn new(base_url: &str) -> Result<Self, ClientError> {
+        let http = Client::builder().timeout(REQUEST_TIMEOUT).build()?;
+        Ok(Self {
+            http,
+            base_url: base_url.trim_end_matches('/').to_string(),
+            token: None,
+        })
+    }
+
+    /// Uses an existing bearer token instead of logging in.
+    pub fn with_token(mut self, token: impl Into<String>) -> Self {
+        self.token = Some(token.into());
+        self
+    }
+
+    // ── Auth ───────────────────────────────────────────────────────────────
+
+    /// POST /api/v1/auth/login — stores the token for later calls and returns it.
+    pub async fn login(&mut self, email: &str, password: &str) -> Result<String, ClientError> {
+        let body = serde_json::json!({ "email": email, "password": password });
+        let response: TokenResponse = self.send_json(self.post("/auth/login").json(&body)).await?;
+        self.token = Some(response.token.clone());
+        Ok(response.token)
+    }
+
+    // ── Context ────────────────────────────────────────────────────────────
+
+    /// POST /api/v1/context/ingest/batch
+    pub async fn ingest_batch(&self, raw_text: &str) -> Result<BatchStartResponse, ClientError> {
+        let body = IngestBatchRequest {
+            raw_text: raw_text.to_string(),
+        };
+        self.send_json(self.post("/context/ingest/batch").json(&body))
+            .await
+    }
+
+    /// GET /api/v1/context/ingest/batch/:id
+    pub async fn batch_status(&self, batch_id: Uuid) -> Result<BatchStatusResponse, ClientError> {
+        self.send_json(self.get(&format!("/context/ingest/batch/{batch_id}")))
+            .await
+    }
+
+    /// Polls a batch until every entry is processed. Failed entries are counted in
+    /// the returned status, not reported as an error.
+    pub async fn wait_for_batch(&self, batch_id: Uuid) -> Result<BatchStatusResponse, ClientError> {
+        loop {
+            let status = self.batch_status(batch_id).await?;
+            if status.status == "done" {
+                return Ok(status);
+            }
+            tokio::time::sleep(POLL_INTERVAL).await;
+        }
+    }
+
+    /// POST /api/v1/context/import/json-resume
+    pub async fn import_json_resume(
+        &self,
+        doc: &JsonResume,
+    ) -> Result<JsonResumeImportResponse, ClientError> {
+        self.send_json(self.post("/context/import/json-resume").json(doc))
+            .await
+    }
+
+    // ── Generation ─────────────────────────────────────────────────────────
+
+    /// POST /api/v1/resumes/parse-jd
+    pub async fn parse_jd(&self, jd_text: &str) -> Result<ParseJdResponse, ClientError> {
+        let body = ParseJdRequest {
+            jd_text: jd_text.to_string(),
+        };
+        self.send_json(self.post("/resumes/parse-jd").json(&body))
+            .await
+    }
+
+    /// POST /api/v1/resumes/parse-jd-html — `jd_text` in the response is the posting
+    /// text the server extracted from the page.
+    pub async fn parse_jd_html(&self, html: &str) -> Result<ParseJdHtmlResponse, ClientError> {
+        let body = ParseJdHtmlRequest {
+            html: html.to_string(),
+        };
+        self.send_json(self.post("/resumes/parse-jd-html").json(&body))
+            .await
+    }
+
+    /// POST /api/v1/resumes/fit-score
+    pub async fn fit_score(&self, jd_text: &str) -> Result<FitScoreResponse, ClientError> {
+        let body = FitScoreRequest {
+            jd_text: jd_text.to_string(),
+        };
+        self.send_json(self.post("/resumes/fit-score").json(&body))
+            .await
+    }
+
+    /// POST /api/v1/resumes/generate
+    pub async fn generate(
+        &self,
+        options: &GenerateOptions,
+    ) -> Result<GenerateResponse, ClientError> {
+        self.send_json(self.post("/resumes/generate").json(options))
+            .await
+    }
+
+    // ── Render ─────────────────────────────────────────────────────────────
+
+    /// POST /api/v1/render — returns the already-queued job if there is one.
+    pub async fn trigger_render(
+        &self,
+        resume_id: Uuid,
+    ) -> Result<TriggerRenderResponse, ClientError> {
+        let body = TriggerRenderRequest { resume_id };
+        self.send_json(self.post("/render").json(&body)).await
+    }
+
+    /// GET /api/v1/render/:job_id/status
+    pub async fn render_status(&self, job_id: Uuid) -> Result<RenderStatusResponse, ClientError> {
+        self.send_json(self.get(&format!("/render/{job_id}/status")))
+            .await
+    }
+
+    /// Polls a render job until it is done; a failed job is `ClientError::RenderFailed`.
+    pub async fn wait_for_render(&self, job_id: Uuid) -> Result<RenderStatusResponse, ClientError> {
+        loop {
+            let status = self.render_status(job_id).await?;
+            match status.status.as_str() {
+                "done" => return Ok(status),
+                "failed" => {
+                    return Err(ClientError::RenderFailed {
+                        job_id,
+                        message: status.error_message.unwrap_or_default(),
+                    })
+                }
+                _ => tokio::time::sleep(POLL_INTERVAL).await,
+            }
+        }
+    }
+
+    /// GET /api/v1/render/:job_id — the PDF of a finished job.
+    pub async fn download_pdf(&self, job_id: Uuid) -> Result<Vec<u8>, ClientError> {
+        let response = self.send(self.get(&format!("/render/{job_id}"))).await?;
+        Ok(response.bytes().await?.to_vec())
+    }
+
+    /// GET /api/v1/render/:job_id/line-map — each bullet's page and lines in the PDF.
+    pub async fn render_line_map(
+        &self,
+        job_id: Uuid,
+    ) -> Result<RenderLineMapResponse, ClientError> {
+        self.send_json(self.get(&format!("/render/{job_id}/line-map")))
+            .await
+    }
+
+    /// GET /api/v1/resumes/:id/ats-check — what survives of the resume in its PDF's text.
+    pub async fn ats_check(&self, resume_id: Uuid) -> Result<AtsCheckResponse, ClientError> {
+        self.send_json(self.get(&format!("/resumes/{resume_id}/ats-check")))
+            .await
+    }
+
+    /// GET /api/v1/resumes/:id/export — the resume as a document in `format`.
+    pub async fn export_resume(
+        &self,
+        resume_id: Uuid,
+        format: ExportFormat,
+    ) -> Result<Vec<u8>, ClientError> {
+        let request = self
+            .get(&format!("/resumes/{resume_id}/export"))
+            .query(&ResumeExportQuery { format });
+        let response = self.send(request).await?;
+        Ok(response.bytes().await?.to_vec())
+    }
+
+    /// POST /api/v1/resumes/:id/anonymize — a copy of the resume safe to share publicly.
+    pub async fn anonymize_resume(&self, resume_id: Uuid) -> Result<AnonymizedResume, ClientError> {
+        self.send_json(self.post(&format!("/resumes/{resume_id}/anonymize")))
+            .await
+    }
+
+    /// POST /api/v1/job-postings/import — a Greenhouse or Lever posting by URL, parsed.
+    pub async fn import_job_posting(
+        &self,
+        url: &str,
+    ) -> Result<JobPostingImportResponse, ClientError> {
+        let body = JobPostingImportRequest {
+            url: url.to_string(),
+        };
+        self.send_json(self.post("/job-postings/import").json(&body))
+            .await
+    }
+
+    /// POST /api/v1/job-postings/board — every open posting on a board, unparsed.
+    pub async fn import_job_board(
+        &self,
+        source: JobBoard,
+        board_token: &str,
+    ) -> Result<JobBoardImportResponse, ClientError> {
+        let body = JobBoardImportRequest {
+            source,
+            board_token: board_token.to_string(),
+        };
+        self.send_json(self.post("/job-postings/board").json(&body))
+            .await
+    }
+
+    // ── Plumbing ───────────────────────────────────────────────────────────
+
+    fn get(&self, path: &str) -> RequestBuilder {
+        self.authorized(self.http.get(self.url(path)))
+    }
+
+    fn post(&self, path: &str) -> RequestBuilder {
+        self.authorized(self.http.post(self.url(path)))
+    }
+
+    fn url(&self, path: &str) -> String {
+        format!("{}/api/v1{path}", self.base_url)
+    }
+
+    fn authorized(&self, request: RequestBuilder) -> RequestBuilder {
+        match &self.token {
+            Some(token) => request.bearer_auth(token),
+            None => request,
+        }
+    }
+
+    async fn send_json<T: serde::de::DeserializeOwned>(
+        &self,
+        request: RequestBuilder,
+    ) -> Result<T, ClientError> {
+        Ok(self.send(request).await?.json().await?)
+    }
+
+    async fn send(&self, request: RequestBuilder) -> Result<Response, ClientError> {
+        let response = request.send().await?;
+        let status = response.status();
+        if status.is_success() {
+            return Ok(response);
+        }
+        let header_id = response
+            .headers()
+            .get("x-request-id")
+            .and_then(|v| v.to_str().ok())
+            .map(str::to_string);
+        let body: Value = response.json().await.unwrap_or(Value::Null);
+        Err(api_error(status.as_u16(), &body, header_id))
+    }
+}
+
+/// `ClientError::Api` from an error body `{"error": {"message", "request_id"}}`,
+/// falling back to the `X-Request-Id` header.
+fn api_error(status: u16, body: &Value, header_id: Option<String>) -> ClientError {
+    let error = &body["error"];
+    ClientError::Api {
+        status,
+        message: error["message"].as_str().unwrap_or_default().to_string(),
+        request_id: error["request_id"]
+            .as_str()
+            .map(str::to_string)
+            .or(header_id),
+    }
+}
+
+// ────────────────────────────────────────────────────────────────────────────
+// Tests
+// ────────────────────────────────────────────────────────────────────────────
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_api_error_display() {
+        let body = json!({ "error": { "message": "JD text is empty", "request_id": "abc" } });
+        assert_eq!(
+            api_error(422, &body, Some("header".into())).to_string(),
+            "JD text is empty (request id: abc)"
+        );
+        assert_eq!(
+            api_error(502, &Value::Null, Some("xyz".into())).to_string(),
+            "HTTP 502 (request id: xyz)"
+        );
+        assert_eq!(api_error(500, &Value::Null, None).to_string(), "HTTP 500");
+    }
+
+    #[test]
+    fn test_url_joins_base_and_version_prefix() {
+        let client = TemplarClient::new("http://localhost:8080/").unwrap();
+        assert_eq!(
+            client.url("/resumes/generate"),
+            "http://localhost:8080/api/v1/resumes/generate"
+        );
+    }
+
+    #[test]
+    fn test_generate_options_omit_unset_fields() {
+        let body = serde_json::to_value(GenerateOptions::new("jd")).unwrap();
+        assert_eq!(body, json!({ "jd_text": "jd" }));
+    }
+}
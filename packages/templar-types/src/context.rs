@@ -0,0 +1,68 @@
+//! Context ingestion: single-entry and batch request bodies, and batch progress.
+
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+#[cfg(feature = "validate")]
+use validator::Validate;
+
+#[cfg(feature = "validate")]
+use crate::validation::{not_blank, MAX_ENTRY_TEXT_CHARS, MAX_RAW_TEXT_CHARS};
+
+/// Body for `POST /api/v1/context/ingest` — one entry, previewed before it is stored.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "validate", derive(Validate))]
+pub struct IngestRequest {
+    #[cfg_attr(
+        feature = "validate",
+        validate(custom(function = "not_blank"), length(max = MAX_ENTRY_TEXT_CHARS))
+    )]
+    pub raw_text: String,
+}
+
+/// Body for `POST /api/v1/context/ingest/batch` — a document that may hold several
+/// entries separated by `\n---`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "validate", derive(Validate))]
+pub struct IngestBatchRequest {
+    #[cfg_attr(
+        feature = "validate",
+        validate(custom(function = "not_blank"), length(max = MAX_RAW_TEXT_CHARS))
+    )]
+    pub raw_text: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BatchStartResponse {
+    pub batch_id: Uuid,
+    pub entry_count: usize,
+    pub status: String,
+}
+
+/// Per-item status as returned by the polling endpoint.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BatchItemStatus {
+    pub id: Uuid,
+    pub entry_index: i32,
+    pub status: String,
+    pub error_msg: Option<String>,
+    pub entry_id: Option<Uuid>,
+    /// Phase 5.5.4: non-null when this item was merged into an existing entry.
+    pub merged_with_entry_id: Option<Uuid>,
+}
+
+/// Full batch status response for the polling endpoint.
+///
+/// `status` becomes `done` once no item is pending; `failed` counts the items that
+/// did not make it.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BatchStatusResponse {
+    pub batch_id: Uuid,
+    pub source_type: String,
+    pub filename: Option<String>,
+    pub total: i32,
+    pub pending: i32,
+    pub succeeded: i32,
+    pub failed: i32,
+    pub status: String,
+    pub items: Vec<BatchItemStatus>,
+}
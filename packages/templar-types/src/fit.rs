@@ -0,0 +1,32 @@
+//! Fit report — how well the user's context covers a parsed JD. The same shape comes
+//! back from every scorer backend.
+
+use serde::{Deserialize, Serialize};
+
+/// A single matched dimension between user context and a JD keyword/requirement.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FitMatch {
+    pub dimension: String,
+    pub context_evidence: String, // which entry covers it
+    pub jd_requirement: String,
+    pub strength: f32, // 0.0 – 1.0
+}
+
+/// A JD keyword or requirement not covered by any context entry.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Gap {
+    pub keyword: String,
+    pub jd_frequency: u32,
+    pub suggestion: Option<String>, // closest context entry_id, if any
+}
+
+/// Full fit report returned to callers.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FitReport {
+    pub overall_score: u32,             // 0 – 100
+    pub strong_matches: Vec<FitMatch>,  // strength ≥ 0.8
+    pub partial_matches: Vec<FitMatch>, // 0.4 – 0.79
+    pub gaps: Vec<Gap>,                 // strength < 0.4
+    pub recommendation: String,
+    pub scorer_backend: String, // "keyword" | "llm" — for transparency
+}
@@ -0,0 +1,347 @@
+//! Resume generation: JD parsing and fit-score bodies, draft and simulated bullets, and
+//! the generate response.
+
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+#[cfg(feature = "validate")]
+use validator::Validate;
+
+use crate::fit::FitReport;
+use crate::jd::ParsedJD;
+#[cfg(feature = "validate")]
+use crate::validation::{not_blank, MAX_JD_HTML_CHARS, MAX_JD_TEXT_CHARS};
+
+// ────────────────────────────────────────────────────────────────────────────
+// POST /api/v1/resumes/parse-jd, POST /api/v1/resumes/fit-score
+// ────────────────────────────────────────────────────────────────────────────
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "validate", derive(Validate))]
+pub struct ParseJdRequest {
+    #[cfg_attr(
+        feature = "validate",
+        validate(custom(function = "not_blank"), length(max = MAX_JD_TEXT_CHARS))
+    )]
+    pub jd_text: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ParseJdResponse {
+    pub parsed_jd: ParsedJD,
+}
+
+// ────────────────────────────────────────────────────────────────────────────
+// POST /api/v1/resumes/parse-jd-html
+// ────────────────────────────────────────────────────────────────────────────
+
+/// A job posting page as a browser extension captures it.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "validate", derive(Validate))]
+pub struct ParseJdHtmlRequest {
+    /// The page's full HTML (`document.documentElement.outerHTML`).
+    #[cfg_attr(
+        feature = "validate",
+        validate(custom(function = "not_blank"), length(max = MAX_JD_HTML_CHARS))
+    )]
+    pub html: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ParseJdHtmlResponse {
+    /// The posting text extracted from the page; send it as `jd_text` to generate.
+    pub jd_text: String,
+    pub parsed_jd: ParsedJD,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "validate", derive(Validate))]
+pub struct FitScoreRequest {
+    #[cfg_attr(
+        feature = "validate",
+        validate(custom(function = "not_blank"), length(max = MAX_JD_TEXT_CHARS))
+    )]
+    pub jd_text: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FitScoreResponse {
+    pub fit_report: FitReport,
+    pub parsed_jd: ParsedJD,
+}
+
+// ────────────────────────────────────────────────────────────────────────────
+// Bullets
+// ────────────────────────────────────────────────────────────────────────────
+
+/// A single draft resume bullet produced by the generation LLM call.
+///
+/// CRITICAL: every bullet MUST carry `source_entry_id` — bullets without it are rejected.
+/// `line_estimate` is the LLM's guess only — NOT trusted for layout (Phase 3 enforces).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DraftBullet {
+    pub text: String,
+    pub source_entry_id: Uuid,
+    pub section: String,
+    /// LLM estimate only — layout Phase 3 will re-simulate. Must be 1 or 2.
+    pub line_estimate: u8,
+    pub jd_keywords_used: Vec<String>,
+}
+
+/// A bullet after layout simulation. Replaces `DraftBullet` for all downstream consumers.
+///
+/// `verified_line_count` is set by the simulation — NOT the LLM's `line_estimate`.
+/// `was_adjusted` indicates at least one LLM expand/compress call modified the text.
+/// `flagged_for_review` indicates the bullet still violated the contract after max passes.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SimulatedBullet {
+    pub text: String,
+    pub source_entry_id: Uuid,
+    pub section: String,
+    /// What kind of printed line this is; decides its wrapping and fill rules.
+    #[serde(default)]
+    pub line_type: LineType,
+    /// Line count as measured by the simulator (1 or 2 for passing bullets).
+    pub verified_line_count: u8,
+    pub jd_keywords_used: Vec<String>,
+    /// True if the simulator called the LLM at least once to adjust this bullet.
+    pub was_adjusted: bool,
+    /// True if the bullet still violates the contract after all simulation passes.
+    pub flagged_for_review: bool,
+    /// Texts this bullet had earlier in the pipeline, oldest first (see `revise`).
+    /// Persisted to `bullet_revisions` with the bullet; never sent to clients.
+    #[serde(skip)]
+    pub revisions: Vec<TextRevision>,
+}
+
+impl SimulatedBullet {
+    /// Replaces `text`, keeping the old text and what replaced it.
+    pub fn revise(&mut self, text: String, cause: RevisionCause) {
+        let previous_text = std::mem::replace(&mut self.text, text);
+        self.revisions.push(TextRevision {
+            previous_text,
+            cause,
+        });
+    }
+}
+
+/// The kind of printed line a `SimulatedBullet` is. Each kind has its own wrapping and
+/// fill rules in the line coverage contract; only `Bullet`s count toward the two-line cap.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum LineType {
+    /// An itemized resume bullet.
+    #[default]
+    Bullet,
+    /// A one-line entry heading, e.g. an education line.
+    EntryHeader,
+    /// A multi-line prose paragraph, e.g. a summary.
+    SummaryParagraph,
+}
+
+/// What produced a revision of a bullet's text. Stored in `bullet_revisions.cause`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum RevisionCause {
+    /// The first draft (LLM or template builder).
+    Generate,
+    /// Lengthened: a simulator expand or a page-fill promotion.
+    Expand,
+    /// Shortened: local or LLM compression in the simulator or page fill.
+    Compress,
+    /// Rewritten after failing grounding or the keyword-stuffing check.
+    Regenerate,
+    /// Changed by the user.
+    User,
+}
+
+impl RevisionCause {
+    const ALL: [RevisionCause; 5] = [
+        RevisionCause::Generate,
+        RevisionCause::Expand,
+        RevisionCause::Compress,
+        RevisionCause::Regenerate,
+        RevisionCause::User,
+    ];
+
+    /// The serialized name — the form stored in `bullet_revisions.cause`.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            RevisionCause::Generate => "generate",
+            RevisionCause::Expand => "expand",
+            RevisionCause::Compress => "compress",
+            RevisionCause::Regenerate => "regenerate",
+            RevisionCause::User => "user",
+        }
+    }
+
+    pub fn parse(s: &str) -> Option<RevisionCause> {
+        RevisionCause::ALL.into_iter().find(|c| c.as_str() == s)
+    }
+}
+
+/// A text a `SimulatedBullet` no longer has, and why it was replaced.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TextRevision {
+    pub previous_text: String,
+    pub cause: RevisionCause,
+}
+
+// ────────────────────────────────────────────────────────────────────────────
+// Keyword coverage
+// ────────────────────────────────────────────────────────────────────────────
+
+/// Coverage status for a single JD keyword.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct KeywordCoverage {
+    pub keyword: String,
+    pub weighted_score: f32,
+    /// Number of final bullets whose text contains the keyword.
+    pub bullet_count: u32,
+    pub covered: bool,
+}
+
+/// Coverage summary attached to the generate response and persisted on the resume row.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CoverageReport {
+    /// Every JD keyword, ordered by weighted_score descending.
+    pub keywords: Vec<KeywordCoverage>,
+    pub covered_keywords: Vec<String>,
+    /// JD keywords that never appear in any bullet — highest weight first.
+    pub missing_keywords: Vec<String>,
+    /// covered / total (unweighted). 1.0 when the JD has no keywords.
+    pub coverage_ratio: f32,
+    /// Σ weighted_score of covered / Σ weighted_score of all. 1.0 when the JD has no keywords.
+    pub weighted_coverage: f32,
+}
+
+/// Where a JD keyword appears in a bullet's text, for inline highlighting.
+///
+/// Offsets are in UTF-16 code units, the unit JavaScript strings index by, so
+/// `text.slice(start, end)` is the matched text. Spans in one bullet never overlap.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct KeywordSpan {
+    /// The keyword as it appears in the JD keyword inventory.
+    pub keyword: String,
+    pub start: u32,
+    pub end: u32,
+}
+
+/// An acronym written out in a bullet so ATS matching finds both forms.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AcronymRewrite {
+    pub acronym: String,
+    pub expansion: String,
+    pub source_entry_id: Uuid,
+    /// Bullet text after the rewrite.
+    pub text: String,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum AcronymIssue {
+    /// The resume spells the acronym more than one way ("CI/CD" and "CICD").
+    MixedSpellings,
+    /// The resume and the JD define the acronym differently.
+    ConflictingExpansion,
+}
+
+/// An acronym inconsistency left for the user to resolve.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AcronymFlag {
+    pub acronym: String,
+    pub issue: AcronymIssue,
+    pub detail: String,
+}
+
+/// Result of the post-generation acronym pass, attached to the generate response.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct AcronymReport {
+    pub rewrites: Vec<AcronymRewrite>,
+    pub flags: Vec<AcronymFlag>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum LintRule {
+    PassiveVoice,
+    FirstPerson,
+    /// The bullet ends with a period when most bullets do not, or the other way round.
+    TrailingPeriod,
+    DoubleSpace,
+    /// A role that has ended, described in the present tense.
+    TenseMismatch,
+}
+
+/// One grammar or style problem in a bullet.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LintWarning {
+    pub rule: LintRule,
+    /// The words that triggered the warning, as written in the bullet.
+    pub excerpt: String,
+    pub message: String,
+}
+
+/// A bullet carrying JD keywords its source entry never mentions.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StuffedBullet {
+    /// Index into the response's `bullets`.
+    pub index: usize,
+    /// JD keywords in the bullet that are not in its source entry, in JD weight order.
+    pub forced_keywords: Vec<String>,
+    /// JD keyword matches per word of the bullet.
+    pub keyword_density: f32,
+}
+
+/// A JD keyword repeated across too many bullets to read naturally.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RepeatedKeyword {
+    pub keyword: String,
+    pub bullet_count: u32,
+    /// Share of the checked bullets containing the keyword.
+    pub share: f32,
+}
+
+/// Result of the post-generation keyword-stuffing check, attached to the generate
+/// response.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct KeywordStuffingReport {
+    /// JD keyword matches per word, across every checked bullet.
+    pub keyword_density: f32,
+    pub stuffed_bullets: Vec<StuffedBullet>,
+    pub repeated_keywords: Vec<RepeatedKeyword>,
+    /// Bullets rewritten to drop forced keywords (only when the request asked for it).
+    pub rewritten: u32,
+}
+
+// ────────────────────────────────────────────────────────────────────────────
+// POST /api/v1/resumes/generate
+// ────────────────────────────────────────────────────────────────────────────
+
+/// Response from the generation pipeline, also the body of `POST /resumes/generate`.
+///
+/// Phase 3: `bullets` now contains `SimulatedBullet` with `verified_line_count`,
+/// `was_adjusted`, and `flagged_for_review` fields populated by the simulation loop.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GenerateResponse {
+    pub resume_id: Uuid,
+    pub fit_report: FitReport,
+    pub bullets: Vec<SimulatedBullet>,
+    /// Ids of the persisted `resume_bullets` rows, in the same order as `bullets`.
+    #[serde(default)]
+    pub bullet_ids: Vec<Uuid>,
+    /// Which JD keywords made it into the final bullet text.
+    pub keyword_coverage: CoverageReport,
+    /// Where those keywords appear in each bullet, in the same order as `bullets`.
+    #[serde(default)]
+    pub keyword_spans: Vec<Vec<KeywordSpan>>,
+    /// Acronyms expanded in the bullets, and inconsistencies left as they were.
+    #[serde(default)]
+    pub acronyms: AcronymReport,
+    /// Style warnings for each bullet, in the same order as `bullets`.
+    #[serde(default)]
+    pub bullet_lint: Vec<Vec<LintWarning>>,
+    /// Bullets with forced JD keywords, and keywords repeated across the resume.
+    #[serde(default)]
+    pub keyword_stuffing: KeywordStuffingReport,
+    pub status: String,
+}
@@ -0,0 +1,111 @@
+//! Job postings pulled from public job board APIs: `POST /api/v1/job-postings/import`
+//! for one posting by URL, `POST /api/v1/job-postings/board` for a whole board.
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+#[cfg(feature = "validate")]
+use validator::Validate;
+
+use crate::jd::ParsedJD;
+#[cfg(feature = "validate")]
+use crate::validation::{not_blank, MAX_URL_CHARS};
+
+/// A job board whose public posting API Templar reads.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum JobBoard {
+    /// `boards.greenhouse.io` / `job-boards.greenhouse.io`.
+    Greenhouse,
+    /// `jobs.lever.co` / `jobs.eu.lever.co`.
+    Lever,
+}
+
+impl JobBoard {
+    /// The name stored in `parsed_jds.source`.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            JobBoard::Greenhouse => "greenhouse",
+            JobBoard::Lever => "lever",
+        }
+    }
+}
+
+/// A posting as fetched from its board, normalized.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct JobPosting {
+    pub id: Uuid,
+    pub source: JobBoard,
+    /// `{board_token}/{job_id}`.
+    pub source_key: String,
+    /// The posting's public page.
+    pub source_url: String,
+    pub title: String,
+    pub company: Option<String>,
+    pub location: Option<String>,
+    pub department: Option<String>,
+    /// The posting as plain text, headed by title and company; send it as `jd_text` to
+    /// generate.
+    pub jd_text: String,
+    /// Board-specific fields: ids, `updated_at`, Lever's `commitment` and
+    /// `workplace_type`, Greenhouse's `offices`.
+    pub metadata: serde_json::Value,
+    pub fetched_at: DateTime<Utc>,
+}
+
+// ────────────────────────────────────────────────────────────────────────────
+// POST /api/v1/job-postings/import
+// ────────────────────────────────────────────────────────────────────────────
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "validate", derive(Validate))]
+pub struct JobPostingImportRequest {
+    /// A Greenhouse or Lever posting URL, as shared or as the board links it.
+    #[cfg_attr(
+        feature = "validate",
+        validate(custom(function = "not_blank"), length(max = MAX_URL_CHARS))
+    )]
+    pub url: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct JobPostingImportResponse {
+    pub posting: JobPosting,
+    pub parsed_jd: ParsedJD,
+    /// True when the posting came from the cache rather than a fresh fetch.
+    pub cached: bool,
+}
+
+// ────────────────────────────────────────────────────────────────────────────
+// POST /api/v1/job-postings/board
+// ────────────────────────────────────────────────────────────────────────────
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "validate", derive(Validate))]
+pub struct JobBoardImportRequest {
+    pub source: JobBoard,
+    /// The board's token: `acme` in `boards.greenhouse.io/acme` or `jobs.lever.co/acme`.
+    #[cfg_attr(
+        feature = "validate",
+        validate(custom(function = "not_blank"), length(max = 100))
+    )]
+    pub board_token: String,
+}
+
+/// A board posting without its text; import it by `source_url` to parse it.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct JobPostingSummary {
+    pub id: Uuid,
+    pub source_url: String,
+    pub title: String,
+    pub location: Option<String>,
+    pub department: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct JobBoardImportResponse {
+    pub source: JobBoard,
+    pub board_token: String,
+    pub company: Option<String>,
+    pub postings: Vec<JobPostingSummary>,
+}
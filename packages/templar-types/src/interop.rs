@@ -0,0 +1,412 @@
+//! Interchange with other resume tooling: the JSON Resume schema (import into context,
+//! export of a generated resume) and the formats `GET /api/v1/resumes/:id/export`
+//! serves, JSON Resume and Europass.
+
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+// ────────────────────────────────────────────────────────────────────────────
+// GET /api/v1/resumes/:id/export
+// ────────────────────────────────────────────────────────────────────────────
+
+/// Document format of a resume export.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ExportFormat {
+    /// A `JsonResume` document (<https://jsonresume.org/schema>).
+    JsonResume,
+    /// Europass CV XML (schema v3.4), importable into the Europass editor.
+    EuropassXml,
+    /// A PDF laid out like the Europass CV.
+    EuropassPdf,
+}
+
+/// Query string of `GET /api/v1/resumes/:id/export`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ResumeExportQuery {
+    pub format: ExportFormat,
+}
+
+// ────────────────────────────────────────────────────────────────────────────
+// POST /api/v1/context/import/json-resume
+// ────────────────────────────────────────────────────────────────────────────
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ImportedEntry {
+    pub entry_id: Uuid,
+    pub entry_type: String,
+    /// Where in the document it came from, e.g. `work[0]`.
+    pub source: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct JsonResumeImportResponse {
+    /// Entries stored, in document order.
+    pub imported: Vec<ImportedEntry>,
+    /// Items identical to an entry already in context, so not stored again.
+    pub unchanged: Vec<String>,
+    /// Items that could not be mapped, with the reason (`work[2]: no startDate`).
+    pub skipped: Vec<String>,
+}
+
+// ────────────────────────────────────────────────────────────────────────────
+// POST /api/v1/resumes/:id/anonymize
+// ────────────────────────────────────────────────────────────────────────────
+
+/// What a placeholder stands for.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum PlaceholderKind {
+    Person,
+    Company,
+    Institution,
+    Organization,
+    Project,
+    Place,
+    Other,
+}
+
+impl PlaceholderKind {
+    /// The word in the placeholder, e.g. `Company` in `[Company 2]`.
+    pub fn label(self) -> &'static str {
+        match self {
+            PlaceholderKind::Person => "Person",
+            PlaceholderKind::Company => "Company",
+            PlaceholderKind::Institution => "Institution",
+            PlaceholderKind::Organization => "Organization",
+            PlaceholderKind::Project => "Project",
+            PlaceholderKind::Place => "Place",
+            PlaceholderKind::Other => "Name",
+        }
+    }
+}
+
+/// How a redacted name was found.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum RedactionSource {
+    /// A field of the resume that names something: employer, institution, project.
+    Field,
+    /// The LLM check found it in free text.
+    LlmCheck,
+}
+
+/// One name the copy no longer contains. The original is not returned, so the
+/// response itself is safe to share.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Redaction {
+    /// `[Company 1]`
+    pub placeholder: String,
+    pub kind: PlaceholderKind,
+    pub found_by: RedactionSource,
+}
+
+/// Response of `POST /api/v1/resumes/:id/anonymize`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AnonymizedResume {
+    /// The resume as a JSON Resume document, with names replaced by placeholders and
+    /// contact details, links and locations removed.
+    pub document: JsonResume,
+    pub redactions: Vec<Redaction>,
+    /// Emails, URLs and phone numbers masked in free text.
+    pub masked_contacts: usize,
+    /// False when the LLM check could not run; only deterministic masking applied.
+    pub llm_checked: bool,
+}
+
+// ────────────────────────────────────────────────────────────────────────────
+// JSON Resume schema (v1.0.0)
+// ────────────────────────────────────────────────────────────────────────────
+//
+// Every field is optional in the schema. Absent fields are omitted on output rather
+// than written as `null`, which the schema's validators reject. `interests` and
+// `references` have no counterpart in context and are not modelled.
+
+/// A JSON Resume document.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct JsonResume {
+    /// `$schema` URL, when the document carries one.
+    #[serde(rename = "$schema", skip_serializing_if = "Option::is_none")]
+    pub schema: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub basics: Option<JrBasics>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub work: Vec<JrWork>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub volunteer: Vec<JrVolunteer>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub education: Vec<JrEducation>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub awards: Vec<JrAward>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub certificates: Vec<JrCertificate>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub publications: Vec<JrPublication>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub skills: Vec<JrSkill>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub languages: Vec<JrLanguage>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub projects: Vec<JrProject>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub meta: Option<JrMeta>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct JrBasics {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    /// Headline, e.g. "Backend Engineer".
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub label: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub email: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub phone: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub summary: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub location: Option<JrLocation>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub profiles: Vec<JrProfile>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct JrLocation {
+    /// Free-form, e.g. "Unter den Linden 1, Berlin".
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub address: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub city: Option<String>,
+    /// ISO 3166-1 alpha-2.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub country_code: Option<String>,
+}
+
+/// An online profile.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct JrProfile {
+    /// e.g. "LinkedIn".
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub network: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub username: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct JrWork {
+    /// Company name.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub position: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub location: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
+    /// ISO 8601 date: `2021`, `2021-06` or `2021-06-01`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub start_date: Option<String>,
+    /// Absent while the position is current.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub end_date: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub summary: Option<String>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub highlights: Vec<String>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct JrVolunteer {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub organization: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub position: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub start_date: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub end_date: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub summary: Option<String>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub highlights: Vec<String>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct JrEducation {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub institution: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
+    /// Field of study, e.g. "Computer Science".
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub area: Option<String>,
+    /// Degree, e.g. "Bachelor".
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub study_type: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub start_date: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub end_date: Option<String>,
+    /// Grade, free-form ("3.8", "First").
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub score: Option<String>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub courses: Vec<String>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct JrAward {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub date: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub awarder: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub summary: Option<String>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct JrCertificate {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub date: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub issuer: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct JrPublication {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub publisher: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub release_date: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub summary: Option<String>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct JrSkill {
+    /// Skill group, e.g. "Languages".
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub level: Option<String>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub keywords: Vec<String>,
+}
+
+/// A spoken language.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct JrLanguage {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub language: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub fluency: Option<String>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct JrProject {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub start_date: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub end_date: Option<String>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub highlights: Vec<String>,
+    /// Technologies used.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub keywords: Vec<String>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct JrMeta {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub canonical: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub version: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub last_modified: Option<String>,
+}
+
+// ────────────────────────────────────────────────────────────────────────────
+// Tests
+// ────────────────────────────────────────────────────────────────────────────
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_json_resume_reads_camel_case_and_omits_absent_fields() {
+        let doc: JsonResume = serde_json::from_str(
+            r#"{
+                "$schema": "https://raw.githubusercontent.com/jsonresume/resume-schema/v1.0.0/schema.json",
+                "basics": { "name": "Ada Lovelace", "location": { "city": "London" } },
+                "work": [{ "name": "Analytical Engines", "startDate": "1842-09", "highlights": ["Wrote the first program"] }],
+                "interests": [{ "name": "Poetry" }]
+            }"#,
+        )
+        .unwrap();
+        assert_eq!(doc.work[0].start_date.as_deref(), Some("1842-09"));
+        assert_eq!(
+            doc.basics.as_ref().unwrap().name.as_deref(),
+            Some("Ada Lovelace")
+        );
+
+        let out = serde_json::to_value(&doc).unwrap();
+        assert_eq!(
+            out["work"][0],
+            serde_json::json!({
+                "name": "Analytical Engines",
+                "startDate": "1842-09",
+                "highlights": ["Wrote the first program"],
+            })
+        );
+        assert!(out.get("education").is_none());
+        assert!(out.get("meta").is_none());
+    }
+
+    #[test]
+    fn test_export_format_is_snake_case() {
+        let query: ResumeExportQuery =
+            serde_json::from_value(serde_json::json!({ "format": "json_resume" })).unwrap();
+        assert_eq!(query.format, ExportFormat::JsonResume);
+        let query: ResumeExportQuery =
+            serde_json::from_value(serde_json::json!({ "format": "europass_pdf" })).unwrap();
+        assert_eq!(query.format, ExportFormat::EuropassPdf);
+    }
+}
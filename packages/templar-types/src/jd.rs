@@ -0,0 +1,91 @@
+//! Structured job description — the output of JD parsing.
+
+use serde::{Deserialize, Serialize};
+
+/// Detected tone of a job description. Drives verb selection in generation.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub enum JDTone {
+    AggressiveStartup,
+    #[default]
+    CollaborativeEnterprise,
+    ResearchOriented,
+    ProductOriented,
+}
+
+impl JDTone {
+    const ALL: [JDTone; 4] = [
+        JDTone::AggressiveStartup,
+        JDTone::CollaborativeEnterprise,
+        JDTone::ResearchOriented,
+        JDTone::ProductOriented,
+    ];
+
+    /// The variant name, as serialized — the form stored in TEXT columns.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            JDTone::AggressiveStartup => "AggressiveStartup",
+            JDTone::CollaborativeEnterprise => "CollaborativeEnterprise",
+            JDTone::ResearchOriented => "ResearchOriented",
+            JDTone::ProductOriented => "ProductOriented",
+        }
+    }
+
+    pub fn parse(s: &str) -> Option<JDTone> {
+        JDTone::ALL.into_iter().find(|t| t.as_str() == s)
+    }
+}
+
+/// A single requirement extracted from the JD.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Requirement {
+    pub text: String,
+    pub is_required: bool,
+}
+
+/// High-level signals about the role shape.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RoleSignals {
+    pub is_startup: bool,
+    pub is_ic_focused: bool,
+    pub is_research: bool,
+    pub seniority: String,
+}
+
+/// A single keyword from the JD, weighted by position and frequency.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct KeywordEntry {
+    pub keyword: String,
+    pub frequency: u32,
+    /// title=1.0, requirements=0.8, responsibilities=0.6, about=0.3
+    pub position_weight: f32,
+    /// frequency * position_weight
+    pub weighted_score: f32,
+}
+
+/// Full structured output of JD parsing.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ParsedJD {
+    pub hard_requirements: Vec<Requirement>,
+    pub soft_signals: Vec<String>,
+    pub role_signals: RoleSignals,
+    pub keyword_inventory: Vec<KeywordEntry>,
+    pub detected_tone: JDTone,
+}
+
+// ────────────────────────────────────────────────────────────────────────────
+// Tests
+// ────────────────────────────────────────────────────────────────────────────
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_jd_tone_as_str_matches_serde() {
+        for tone in JDTone::ALL {
+            assert_eq!(serde_json::to_value(&tone).unwrap(), tone.as_str());
+            assert_eq!(JDTone::parse(tone.as_str()), Some(tone));
+        }
+        assert_eq!(JDTone::parse("aggressive_startup"), None);
+    }
+}
@@ -0,0 +1,16 @@
+//! Wire types of the Templar API, shared by the server (`apps/api`) and its Rust
+//! clients (`templar-client`, the `templar` CLI).
+//!
+//! The TypeScript mirrors live in `packages/types/index.ts`; keep both in step.
+//!
+//! Request bodies derive `validator::Validate` with the `validate` feature, which the
+//! API enables. Clients leave it off and skip the dependency.
+
+pub mod context;
+pub mod fit;
+pub mod generation;
+pub mod integrations;
+pub mod interop;
+pub mod jd;
+pub mod render;
+pub mod validation;
@@ -0,0 +1,102 @@
+//! Render jobs: LaTeX → PDF for an existing resume, and the ATS read-back check on
+//! the result.
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+/// Body for `POST /api/v1/render`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TriggerRenderRequest {
+    pub resume_id: Uuid,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TriggerRenderResponse {
+    pub job_id: Uuid,
+    pub status: String,
+}
+
+/// `GET /api/v1/render/:job_id/status`. `status` is one of 'queued' | 'processing' |
+/// 'done' | 'failed'; `error_message` is only set when it is 'failed'.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RenderStatusResponse {
+    pub job_id: Uuid,
+    pub resume_id: Uuid,
+    pub status: String,
+    pub error_message: Option<String>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+/// `GET /api/v1/render/:job_id/line-map` — where each bullet landed in the job's PDF.
+/// Empty for jobs rendered before line maps were recorded, and for templates that do
+/// not print the resume's own bullets.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RenderLineMapResponse {
+    pub job_id: Uuid,
+    pub resume_id: Uuid,
+    /// In display order.
+    pub bullets: Vec<BulletPlacement>,
+}
+
+/// One bullet's position in a rendered PDF, read back from pdflatex.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct BulletPlacement {
+    pub bullet_id: Uuid,
+    /// 1-based page of the bullet's first line.
+    pub page: u32,
+    /// 1-based line on `page`, counted in body-text line pitches from the top margin.
+    pub first_line: u32,
+    /// Lines the bullet takes in the PDF.
+    pub line_count: u32,
+    /// Lines the layout simulation predicted for it.
+    pub predicted_line_count: u32,
+    /// Baseline of the first line, in points from the top of `page`.
+    pub top_pt: f32,
+    /// 1-based page of the last line; only differs from `page` when the bullet breaks
+    /// across pages.
+    pub end_page: u32,
+    /// Baseline of the last line, in points from the top of `end_page`.
+    pub bottom_pt: f32,
+}
+
+/// `GET /api/v1/resumes/:id/ats-check` — the resume's rendered PDF read back as a
+/// plain-text ATS parser reads it, compared with the resume's own text.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AtsCheckResponse {
+    pub resume_id: Uuid,
+    /// Nothing below was lost: every keyword, heading and bullet reads back intact.
+    pub ats_safe: bool,
+    /// JD keywords the resume text contains but the PDF text does not match. Empty
+    /// when the resume has no stored JD parse.
+    pub lost_keywords: Vec<LostKeyword>,
+    /// Section headings with no line of their own in the PDF text.
+    pub lost_sections: Vec<String>,
+    /// Bullets that do not read back as one run of text — interleaved by columns,
+    /// reordered, or partly missing.
+    pub broken_bullets: Vec<String>,
+    /// Ligature glyphs (`ﬁ`, `ﬂ`, ...) in the PDF text, which break matching of the
+    /// words they sit in.
+    pub ligatures: Vec<String>,
+    /// Everything extracted from the PDF.
+    pub extracted_text: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct LostKeyword {
+    pub keyword: String,
+    pub cause: KeywordLossCause,
+}
+
+/// Why a keyword did not survive rendering.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum KeywordLossCause {
+    /// Matches once ligature glyphs are spelled out.
+    Ligature,
+    /// Matches once words hyphenated across lines are rejoined.
+    LineBreak,
+    /// Not in the text at all, e.g. set in an image or an unextractable font.
+    Missing,
+}
@@ -0,0 +1,30 @@
+//! Size limits on request text fields, and the custom validators the request types use.
+//!
+//! The limits are plain constants so clients can check input before sending it.
+
+/// Longest job description, in characters. Real postings run 2–10k.
+pub const MAX_JD_TEXT_CHARS: u64 = 30_000;
+
+/// Longest page sent to `POST /api/v1/resumes/parse-jd-html`, in characters. Job board
+/// pages run 100k–800k with their inline scripts and styles.
+pub const MAX_JD_HTML_CHARS: u64 = 1_000_000;
+
+/// Longest URL accepted in a request body, in characters.
+pub const MAX_URL_CHARS: u64 = 2_048;
+
+/// Longest single context entry sent to `POST /api/v1/context/ingest`, in characters.
+pub const MAX_ENTRY_TEXT_CHARS: u64 = 20_000;
+
+/// Longest raw context document (pasted or extracted from an upload), in characters.
+pub const MAX_RAW_TEXT_CHARS: u64 = 200_000;
+
+/// Rejects empty and whitespace-only strings.
+#[cfg(feature = "validate")]
+pub fn not_blank(value: &str) -> Result<(), validator::ValidationError> {
+    if value.trim().is_empty() {
+        return Err(
+            validator::ValidationError::new("blank").with_message("must not be blank".into())
+        );
+    }
+    Ok(())
+}
@@ -1,19 +1,46 @@
 // Shared TypeScript types for the Templar platform.
 // These types mirror the Rust backend data models exactly.
-// DO NOT change these independently of the Rust structs in apps/api/src/.
+// DO NOT change these independently of the Rust structs in apps/api/src/ and
+// packages/templar-types/src/.
 
 // ─────────────────────────────────────────────────────────────────────────────
 // Layout / Generation types
 // ─────────────────────────────────────────────────────────────────────────────
 
+/**
+ * Physical paper a resume is laid out and rendered on.
+ * Mirrors: apps/api/src/layout/font_metrics.rs — PaperSize
+ */
+export type PaperSize = 'letter' | 'a4'
+
+/**
+ * Margins on all four sides: compact 0.7", standard 1.0", airy 1.2".
+ * Mirrors: apps/api/src/layout/font_metrics.rs — MarginPreset
+ */
+export type MarginPreset = 'compact' | 'standard' | 'airy'
+
+/**
+ * Body font whose metric table the layout simulation used.
+ * Mirrors: apps/api/src/layout/font_metrics.rs — FontFamily::as_str
+ */
+export type FontFamily = 'inter' | 'eb_garamond' | 'lato' | 'oswald' | 'computer_modern'
+
+/**
+ * The kind of printed line a simulated bullet is.
+ * Mirrors: packages/templar-types/src/generation.rs — LineType
+ */
+export type LineType = 'bullet' | 'entry_header' | 'summary_paragraph'
+
 /**
  * A resume bullet after layout simulation.
- * Mirrors: apps/api/src/layout/simulator.rs — SimulatedBullet
+ * Mirrors: packages/templar-types/src/generation.rs — SimulatedBullet
  */
 export interface SimulatedBullet {
   text: string
   source_entry_id: string
   section: string
+  /** Decides the line's wrapping and fill rules */
+  line_type: LineType
   /** Line count as measured by the simulator (1 or 2 for passing bullets). */
   verified_line_count: number
   jd_keywords_used: string[]
@@ -40,7 +67,11 @@ export interface ResumeBulletRow {
   grounding_score: number
   is_user_edited: boolean
   line_count: number
+  /** 0-based display order within the resume */
+  position: number
   created_at: string
+  /** Where JD keywords appear in bullet_text; empty on bullets from before migration 037 */
+  keyword_spans: KeywordSpan[]
 }
 
 /**
@@ -56,8 +87,36 @@ export interface ResumeRow {
   latex_source: string | null
   s3_pdf_key: string | null
   status: string
+  template_id: string | null
+  /** Added in migration 005 — null for resumes generated before it. */
+  keyword_coverage: CoverageReport | null
+  /** Set when this resume was produced by POST /resumes/:id/regenerate. */
+  parent_resume_id: string | null
+  /** Added in migration 009 — existing rows are 'letter'. */
+  paper_size: PaperSize
+  /** Added in migration 010 — body font size in points (10–12, half-point steps). */
+  font_size_pt: number
+  /** Added in migration 011 — null for resumes generated before it. */
+  layout_report: LayoutReport | null
+  /** Added in migration 012 — existing rows are 'standard'. */
+  margin_preset: MarginPreset
   created_at: string
   updated_at: string
+  /** Added in migration 019 — always null in responses; deleted resumes are hidden. */
+  deleted_at: string | null
+  /** Added in migration 023 — existing rows are 'inter'. */
+  font_family: FontFamily
+  /** Added in migration 023 — exact margins in inches; null until an older resume's next render. */
+  margin_left_in: number | null
+  margin_right_in: number | null
+  margin_top_in: number | null
+  margin_bottom_in: number | null
+  /** Added in migration 023 — prompt name → fingerprint; null for resumes generated before it. */
+  prompt_versions: Record<string, string> | null
+  /** Added in migration 032 — the target role generated for; null if none or deleted. */
+  target_role_id: string | null
+  /** Added in migration 035 — see GenerationExplanation; null for resumes generated before it. */
+  explanation: GenerationExplanation | null
 }
 
 // ─────────────────────────────────────────────────────────────────────────────
@@ -66,7 +125,7 @@ export interface ResumeRow {
 
 /**
  * A keyword extracted from the job description.
- * Mirrors: apps/api/src/generation/jd_parser.rs — KeywordEntry
+ * Mirrors: packages/templar-types/src/jd.rs — KeywordEntry
  */
 export interface KeywordEntry {
   keyword: string
@@ -77,7 +136,7 @@ export interface KeywordEntry {
 
 /**
  * A single matched dimension between user context and a JD keyword/requirement.
- * Mirrors: apps/api/src/generation/fit_scoring.rs — FitMatch
+ * Mirrors: packages/templar-types/src/fit.rs — FitMatch
  */
 export interface FitMatch {
   dimension: string
@@ -89,7 +148,7 @@ export interface FitMatch {
 
 /**
  * A JD keyword or requirement not covered by any context entry.
- * Mirrors: apps/api/src/generation/fit_scoring.rs — Gap
+ * Mirrors: packages/templar-types/src/fit.rs — Gap
  */
 export interface Gap {
   keyword: string
@@ -99,7 +158,7 @@ export interface Gap {
 
 /**
  * Full fit report returned by the fit scorer.
- * Mirrors: apps/api/src/generation/fit_scoring.rs — FitReport
+ * Mirrors: packages/templar-types/src/fit.rs — FitReport
  *
  * NOTE: This is NOT the same as the spec document describes.
  * The actual backend returns strong_matches/partial_matches/gaps, not
@@ -119,6 +178,193 @@ export interface FitReport {
   scorer_backend: string
 }
 
+/**
+ * Coverage status for one JD keyword in the final bullets.
+ * Mirrors: packages/templar-types/src/generation.rs — KeywordCoverage
+ */
+export interface KeywordCoverage {
+  keyword: string
+  weighted_score: number
+  bullet_count: number
+  covered: boolean
+}
+
+/**
+ * Which JD keywords made it into the generated resume.
+ * Mirrors: packages/templar-types/src/generation.rs — CoverageReport
+ */
+export interface CoverageReport {
+  /** Ordered by weighted_score descending */
+  keywords: KeywordCoverage[]
+  covered_keywords: string[]
+  missing_keywords: string[]
+  /** 0.0 – 1.0 */
+  coverage_ratio: number
+  /** 0.0 – 1.0, weighted by keyword weighted_score */
+  weighted_coverage: number
+}
+
+/**
+ * Where a JD keyword appears in a bullet. Offsets are UTF-16 code units, so
+ * `text.slice(start, end)` is the matched text. Spans in one bullet never overlap.
+ * Mirrors: packages/templar-types/src/generation.rs — KeywordSpan
+ */
+export interface KeywordSpan {
+  keyword: string
+  start: number
+  end: number
+}
+
+/**
+ * An acronym written out in a bullet so ATS matching finds both forms.
+ * Mirrors: packages/templar-types/src/generation.rs — AcronymRewrite
+ */
+export interface AcronymRewrite {
+  acronym: string
+  expansion: string
+  source_entry_id: string
+  /** Bullet text after the rewrite */
+  text: string
+}
+
+/** Mirrors: packages/templar-types/src/generation.rs — AcronymIssue */
+export type AcronymIssue = 'mixed_spellings' | 'conflicting_expansion'
+
+/** Mirrors: packages/templar-types/src/generation.rs — AcronymFlag */
+export interface AcronymFlag {
+  acronym: string
+  issue: AcronymIssue
+  detail: string
+}
+
+/**
+ * Post-generation acronym pass: rewrites made and inconsistencies left as written.
+ * Mirrors: packages/templar-types/src/generation.rs — AcronymReport
+ */
+export interface AcronymReport {
+  rewrites: AcronymRewrite[]
+  flags: AcronymFlag[]
+}
+
+/** Mirrors: packages/templar-types/src/generation.rs — LintRule */
+export type LintRule =
+  | 'passive_voice'
+  | 'first_person'
+  | 'trailing_period'
+  | 'double_space'
+  | 'tense_mismatch'
+
+/**
+ * One grammar or style problem in a bullet.
+ * Mirrors: packages/templar-types/src/generation.rs — LintWarning
+ */
+export interface LintWarning {
+  rule: LintRule
+  /** The words that triggered the warning, as written in the bullet */
+  excerpt: string
+  message: string
+}
+
+/**
+ * A bullet carrying JD keywords its source entry never mentions.
+ * Mirrors: packages/templar-types/src/generation.rs — StuffedBullet
+ */
+export interface StuffedBullet {
+  /** Index into the response's `bullets` */
+  index: number
+  forced_keywords: string[]
+  /** JD keyword matches per word of the bullet */
+  keyword_density: number
+}
+
+/**
+ * A JD keyword repeated across too many bullets.
+ * Mirrors: packages/templar-types/src/generation.rs — RepeatedKeyword
+ */
+export interface RepeatedKeyword {
+  keyword: string
+  bullet_count: number
+  share: number
+}
+
+/**
+ * Post-generation keyword-stuffing check.
+ * Mirrors: packages/templar-types/src/generation.rs — KeywordStuffingReport
+ */
+export interface KeywordStuffingReport {
+  keyword_density: number
+  stuffed_bullets: StuffedBullet[]
+  repeated_keywords: RepeatedKeyword[]
+  /** Bullets rewritten to drop forced keywords (only when requested) */
+  rewritten: number
+}
+
+/**
+ * Line coverage verdict for one bullet (serde external tagging).
+ * Mirrors: apps/api/src/layout/contract.rs — LineCoverageVerdict
+ */
+export type LineCoverageVerdict =
+  | 'Satisfies'
+  | { TooShort: { fill_ratio: number; required: number } }
+  | { TooLong: { actual_lines: number } }
+  | { SecondLineTooShort: { fill_ratio: number } }
+  | { LastLineTooShort: { lines: number; fill_ratio: number } }
+
+export type PageFillVerdict =
+  | 'Acceptable'
+  | 'TooMuchWhitespace'
+  | 'MinorOverflow'
+  | 'MajorOverflow'
+
+/**
+ * Page fill of the final printed page.
+ * Mirrors: apps/api/src/layout/page_fill.rs — PageFillAnalysis (columns omitted)
+ */
+export interface PageFillAnalysis {
+  total_lines_used: number
+  total_lines_available: number
+  whitespace_fraction: number
+  overflow_fraction: number
+  verdict: PageFillVerdict
+  /** Lines charged for section/entry headers and spacing */
+  structural_lines: number
+}
+
+/**
+ * Why a bullet was flagged for review.
+ * Mirrors: apps/api/src/generation/layout_report.rs — FlagReason
+ */
+export type FlagReason =
+  | { kind: 'line_contract'; verdict: LineCoverageVerdict }
+  | { kind: 'two_line_cap' }
+  | { kind: 'grounding'; rejection_reason: string | null }
+
+export interface FlaggedBullet {
+  /** Matches ResumeBulletRow.position */
+  position: number
+  text: string
+  section: string
+  reason: FlagReason
+}
+
+/**
+ * What the layout pipeline did to a resume.
+ * Mirrors: apps/api/src/generation/layout_report.rs — LayoutReport
+ */
+export interface LayoutReport {
+  font_size_pt: number
+  total_passes: number
+  llm_calls_made: number
+  local_compressions: number
+  /** Bullets expanded from their source entry's facts when the LLM gave no expansion */
+  local_expansions: number
+  /** e.g. { RemoveBullet: { bullet_index: 3 } } or "TightenSpacing" */
+  fill_actions: unknown[]
+  page_fill: PageFillAnalysis
+  flagged_count: number
+  flagged: FlaggedBullet[]
+}
+
 // ─────────────────────────────────────────────────────────────────────────────
 // Grounding / Audit types
 // ─────────────────────────────────────────────────────────────────────────────
@@ -157,17 +403,112 @@ export interface AuditManifest {
 // API response types
 // ─────────────────────────────────────────────────────────────────────────────
 
+/** The `error` object of every non-2xx JSON response. */
+export interface ApiErrorBody {
+  code: string
+  message: string
+  request_id?: string
+  /** Present on 400 VALIDATION_ERROR from body validation */
+  fields?: FieldError[]
+  /** Present on 429 QUOTA_EXCEEDED for monthly quotas */
+  resets_at?: string
+}
+
+/**
+ * Body of every JSON response under /api/v2 (v1 returns `T` or `{ error }` bare).
+ * Mirrors: apps/api/src/api_version.rs — envelope
+ */
+export type ApiV2Envelope<T> =
+  | { api_version: 'v2'; data: T }
+  | { api_version: 'v2'; error: ApiErrorBody }
+
+/**
+ * One failing request field, listed under `error.fields` of a 400 VALIDATION_ERROR.
+ * Mirrors: apps/api/src/validation.rs — FieldError
+ */
+export interface FieldError {
+  field: string
+  /** blank | length | ... */
+  code: string
+  message: string
+}
+
 /**
  * Response from POST /api/v1/resumes/generate.
- * Mirrors: apps/api/src/generation/handlers.rs — GenerateResponse
+ * Mirrors: packages/templar-types/src/generation.rs — GenerateResponse
  */
 export interface GenerateResponse {
   resume_id: string
   fit_report: FitReport
   bullets: SimulatedBullet[]
+  keyword_coverage: CoverageReport
+  /** Keyword spans for each bullet, in the same order as bullets */
+  keyword_spans: KeywordSpan[][]
+  acronyms: AcronymReport
+  /** Style warnings for each bullet, in the same order as bullets */
+  bullet_lint: LintWarning[][]
+  keyword_stuffing: KeywordStuffingReport
   status: string
 }
 
+/**
+ * Response from POST /api/v1/resumes/:id/regenerate.
+ * Mirrors: apps/api/src/generation/handlers.rs — RegenerateResponse
+ */
+export interface RegenerateResponse extends GenerateResponse {
+  parent_resume_id: string
+}
+
+/** Mirrors: apps/api/src/generation/batch_generation.rs — QuotaStatus */
+export interface QuotaStatus {
+  tier: string
+  daily_limit: number
+  used: number
+  remaining: number
+}
+
+/**
+ * Response from POST /api/v1/resumes/generate-batch.
+ * Mirrors: apps/api/src/generation/batch_generation.rs — BatchGenerateResponse
+ */
+export interface BatchGenerateResponse {
+  batch_id: string
+  job_ids: string[]
+  duplicates_dropped: number
+  quota: QuotaStatus
+}
+
+export type GenerationJobStatus = 'queued' | 'running' | 'succeeded' | 'failed'
+
+/** Mirrors: apps/api/src/models/resume.rs — GenerationJobRow */
+export interface GenerationJob {
+  id: string
+  batch_id: string
+  user_id: string
+  jd_index: number
+  jd_text: string
+  status: GenerationJobStatus
+  resume_id: string | null
+  error_msg: string | null
+  created_at: string
+  updated_at: string
+}
+
+/**
+ * Response from GET /api/v1/resumes/batches/:id.
+ * Mirrors: apps/api/src/generation/batch_generation.rs — BatchGenerationStatus
+ */
+export interface BatchGenerationStatus {
+  batch_id: string
+  total: number
+  queued: number
+  running: number
+  succeeded: number
+  failed: number
+  status: 'processing' | 'done'
+  jobs: GenerationJob[]
+}
+
 /**
  * Response from GET /api/v1/resumes/:id.
  * Mirrors: apps/api/src/generation/handlers.rs — ResumeDetailResponse
@@ -175,6 +516,8 @@ export interface GenerateResponse {
 export interface ResumeDetailResponse {
   resume: ResumeRow
   bullets: ResumeBulletRow[]
+  /** Review comment threads not yet resolved */
+  unresolved_comments: number
 }
 
 // ─────────────────────────────────────────────────────────────────────────────
@@ -194,8 +537,64 @@ export interface TemplateSummary {
   thumbnail_url: string
 }
 
+/**
+ * One of the five render styles listed by GET /api/v1/templates.
+ * Mirrors: apps/api/src/render/templates.rs — TemplateStyle
+ */
+export interface TemplateStyle {
+  /** Value stored in `resumes.font_family`, e.g. "eb_garamond". */
+  font_family: string
+  /** "Hacker" | "Researcher" | "Operator" | "Founder" | "Classic" */
+  name: string
+  font_name: string
+  description: string
+  target_persona: string
+  /** Blob-store key of the sample preview PNG. */
+  preview_key: string
+  ats_safety: 'high' | 'good'
+  ats_notes: string
+  page_options: {
+    paper_sizes: PaperSize[]
+    margin_presets: MarginPreset[]
+    font_sizes_pt: number[]
+    default_font_size_pt: number
+    two_column: boolean
+  }
+}
+
+/**
+ * One bullet's position in a rendered PDF.
+ * Mirrors: packages/templar-types/src/render.rs — BulletPlacement
+ */
+export interface BulletPlacement {
+  bullet_id: string
+  /** 1-based page of the first line */
+  page: number
+  /** 1-based line on `page`, in body-text line pitches from the top margin */
+  first_line: number
+  line_count: number
+  /** Lines the layout simulation predicted */
+  predicted_line_count: number
+  /** Baseline of the first line, in points from the top of `page` */
+  top_pt: number
+  end_page: number
+  /** Baseline of the last line, in points from the top of `end_page` */
+  bottom_pt: number
+}
+
+/**
+ * GET /api/v1/render/:job_id/line-map
+ * Mirrors: packages/templar-types/src/render.rs — RenderLineMapResponse
+ */
+export interface RenderLineMapResponse {
+  job_id: string
+  resume_id: string
+  bullets: BulletPlacement[]
+}
+
 export interface TemplateListResponse {
   templates: TemplateSummary[]
+  styles: TemplateStyle[]
 }
 
 // ─────────────────────────────────────────────────────────────────────────────
@@ -222,7 +621,6 @@ export interface ProjectListResponse {
 }
 
 export interface CreateProjectRequest {
-  user_id: string
   name: string
   template_id: string
 }
@@ -233,6 +631,153 @@ export interface UpdateProjectRequest {
   current_resume_id?: string
 }
 
+// ─────────────────────────────────────────────────────────────────────────────
+// Persona types
+// ─────────────────────────────────────────────────────────────────────────────
+
+/**
+ * A saved persona — one angle on the context library.
+ * Mirrors: apps/api/src/models/resume.rs — PersonaRow
+ */
+export interface Persona {
+  id: string
+  user_id: string
+  name: string
+  /** Lowercased */
+  emphasized_tags: string[]
+  suppressed_tags: string[]
+  tone_preference: JdTone | null
+  section_order: unknown | null
+  created_at: string
+  domain_pack: DomainPack | null
+}
+
+export interface PersonaListResponse {
+  personas: Persona[]
+}
+
+/** Mirrors: apps/api/src/personas/mod.rs — CreatePersonaRequest */
+export interface CreatePersonaRequest {
+  name: string
+  emphasized_tags?: string[]
+  suppressed_tags?: string[]
+  tone_preference?: JdTone
+  /** Replaces the user's saved pack when generating with this persona. */
+  domain_pack?: DomainPack
+}
+
+/** Mirrors: apps/api/src/personas/mod.rs — UpdatePersonaRequest */
+export type UpdatePersonaRequest = Partial<CreatePersonaRequest>
+
+/**
+ * A proposed persona from POST /api/v1/personas/suggest; save it with createPersona.
+ * Mirrors: apps/api/src/personas/mod.rs — PersonaSuggestion
+ */
+export interface PersonaSuggestion {
+  name: string
+  emphasized_tags: string[]
+  suppressed_tags: string[]
+  tone_preference: JdTone | null
+  /** Context entries the cluster was built from */
+  entry_ids: string[]
+}
+
+export interface PersonaSuggestResponse {
+  /** 0–3 suggestions; empty when the context has too few tagged entries */
+  suggestions: PersonaSuggestion[]
+}
+
+// ─────────────────────────────────────────────────────────────────────────────
+// Target role types
+// ─────────────────────────────────────────────────────────────────────────────
+
+/**
+ * A job description saved before generation, with the user's notes.
+ * Mirrors: apps/api/src/target_roles/mod.rs — TargetRoleRow
+ */
+export interface TargetRole {
+  id: string
+  user_id: string
+  title: string
+  company: string | null
+  /** Empty until the posting is pasted */
+  jd_text: string
+  /** Cached parse of jd_text; null until parsed, and again after jd_text changes */
+  parsed_jd: unknown
+  notes: string | null
+  created_at: string
+  updated_at: string
+}
+
+export interface TargetRoleListResponse {
+  target_roles: TargetRole[]
+}
+
+export interface CreateTargetRoleRequest {
+  title: string
+  company?: string
+  jd_text?: string
+  notes?: string
+}
+
+/** PATCH /api/v1/target-roles/:id (autosave). An empty string clears company, jd_text or notes. */
+export type UpdateTargetRoleRequest = Partial<CreateTargetRoleRequest>
+
+/** Mirrors: apps/api/src/target_roles/mod.rs — ParseTargetRoleResponse */
+export interface ParseTargetRoleResponse {
+  parsed_jd: unknown
+  /** True when served from the stored parse */
+  cached: boolean
+}
+
+// ─────────────────────────────────────────────────────────────────────────────
+// Application tracker types
+// ─────────────────────────────────────────────────────────────────────────────
+
+/** Mirrors: apps/api/src/applications/mod.rs — ApplicationStatus */
+export type ApplicationStatus = 'applied' | 'screen' | 'interview' | 'offer' | 'rejected'
+
+/**
+ * A tracked job application.
+ * Mirrors: apps/api/src/applications/mod.rs — ApplicationRow
+ */
+export interface Application {
+  id: string
+  user_id: string
+  company: string
+  role: string
+  /** Posting URL or requisition id */
+  jd_reference: string | null
+  /** The resume sent; SET NULL if the resume is purged */
+  resume_id: string | null
+  status: ApplicationStatus
+  /** YYYY-MM-DD */
+  applied_on: string
+  /** When the status last changed */
+  status_changed_at: string
+  notes: string | null
+  created_at: string
+  updated_at: string
+}
+
+export interface ApplicationListResponse {
+  applications: Application[]
+}
+
+export interface CreateApplicationRequest {
+  company: string
+  role: string
+  jd_reference?: string
+  resume_id?: string
+  /** Defaults to 'applied' */
+  status?: ApplicationStatus
+  /** YYYY-MM-DD; defaults to today (UTC) */
+  applied_on?: string
+  notes?: string
+}
+
+export type UpdateApplicationRequest = Partial<CreateApplicationRequest>
+
 // ─────────────────────────────────────────────────────────────────────────────
 // Context Library types
 // ─────────────────────────────────────────────────────────────────────────────
@@ -252,7 +797,7 @@ export interface ContextEntryRow {
   /** Structured JSON data for the entry (company, role, bullets, etc.). May be null for raw-text-only entries. */
   data: Record<string, unknown> | null
   raw_text: string | null
-  /** 0.0–1.0 recency score (18-month half-life decay). */
+  /** 0.0–1.0 recency score, as of created_at, under recency_policy. */
   recency_score: number
   /** 0.0–1.0 impact score from validation pass. */
   impact_score: number
@@ -265,6 +810,8 @@ export interface ContextEntryRow {
   /** Machine-readable quality flags, e.g. ["missing_metric"]. */
   quality_flags: string[]
   created_at: string
+  /** Curve recency_score was computed with; null = 18-month half-life (older rows). */
+  recency_policy: DecayCurve | null
 }
 
 /**
@@ -295,10 +842,842 @@ export interface CompletenessReport {
 }
 
 /**
- * Response from GET /api/v1/context?user_id=...
+ * Response from GET /api/v1/context
  * Mirrors: apps/api/src/context/handlers.rs — ContextListResponse
  */
 export interface ContextEntriesResponse {
   entries: ContextEntryRow[]
   completeness: CompletenessReport
 }
+
+/** Mirrors: apps/api/src/context/freshness.rs — NudgeKind */
+export type NudgeKind = 'current_role_stale' | 'skills_stale'
+
+/**
+ * A stale part of the user's context. Returned (as an array) by
+ * GET /api/v1/context/nudges.
+ * Mirrors: apps/api/src/context/freshness.rs — ContextNudge
+ */
+export interface ContextNudge {
+  kind: NudgeKind
+  /** The stale entry; null for 'skills_stale', which covers the whole section */
+  entry_id: string | null
+  /** Company or title of the stale job */
+  label: string | null
+  last_updated_at: string
+  message: string
+}
+
+/** Mirrors: apps/api/src/context/quality.rs — QualityComponent */
+export type QualityComponent = 'impact' | 'recency' | 'tags' | 'grounding'
+
+/**
+ * One change that would raise an entry's quality score.
+ * Mirrors: apps/api/src/context/quality.rs — QualitySuggestion
+ */
+export interface QualitySuggestion {
+  component: QualityComponent
+  /** 0-based index into the entry's bullets, when the fix is to one bullet. */
+  bullet_index?: number
+  message: string
+}
+
+/**
+ * Response from GET /api/v1/context/entries/:id/quality
+ * Mirrors: apps/api/src/context/quality.rs — EntryQualityReport
+ */
+export interface EntryQualityReport {
+  entry_id: string
+  version: number
+  entry_type: string
+  /** 0.0–1.0, the weighted mean of the components that apply. */
+  score: number
+  /** Null for entry types that have no bullets. */
+  impact: {
+    score: number
+    bullets: { text: string; quality_score: number; flags: string[] }[]
+  } | null
+  /** Scored as of today; `date_end` is an ISO date. */
+  recency: { score: number; date_end: string | null; evergreen: boolean }
+  tags: { score: number; tags: string[] }
+  grounding: { score: number; contribution_type: string | null; issues: string[] }
+  suggestions: QualitySuggestion[]
+}
+
+/**
+ * An entry whose tags the LLM would change.
+ * Mirrors: apps/api/src/context/retag.rs — TagProposal
+ */
+export interface TagProposal {
+  entry_id: string
+  /** Send back in AcceptedTags; apply skips the entry if it has been edited since */
+  version: number
+  entry_type: string
+  /** Company, project or institution name, when the entry has one */
+  label: string | null
+  current_tags: string[]
+  proposed_tags: string[]
+  added: string[]
+  removed: string[]
+}
+
+/**
+ * Response from POST /api/v1/context/retag/preview
+ * Mirrors: apps/api/src/context/retag.rs — RetagPreview
+ */
+export interface RetagPreview {
+  proposals: TagProposal[]
+  /** Entries whose proposed tags match their current ones */
+  unchanged: number
+  /** Entries the LLM failed on; preview again to retry them */
+  failed: number
+}
+
+/**
+ * Tags accepted for one entry, as proposed or edited (1–20 tags).
+ * Mirrors: apps/api/src/context/retag.rs — AcceptedTags
+ */
+export interface AcceptedTags {
+  entry_id: string
+  version: number
+  tags: string[]
+}
+
+/**
+ * Body for POST /api/v1/context/retag/apply (at most 500 entries)
+ * Mirrors: apps/api/src/context/retag.rs — RetagApplyRequest
+ */
+export interface RetagApplyRequest {
+  accepted: AcceptedTags[]
+}
+
+/**
+ * Response from POST /api/v1/context/retag/apply
+ * Mirrors: apps/api/src/context/retag.rs — RetagApplyResponse
+ */
+export interface RetagApplyResponse {
+  /** `version` is the new version carrying `tags` */
+  applied: { entry_id: string; version: number; previous_tags: string[]; tags: string[] }[]
+  /** Entries edited or deleted since the preview; nothing was written for them */
+  stale: string[]
+}
+
+// ─────────────────────────────────────────────────────────────────────────────
+// User / auth types
+// ─────────────────────────────────────────────────────────────────────────────
+
+/**
+ * The signed-in user (password hash is never sent).
+ * Mirrors: apps/api/src/models/user.rs — User
+ */
+export interface User {
+  id: string
+  external_id: string
+  email: string
+  /** free | pro | team | api */
+  tier: string
+  display_name: string | null
+  /** May read the stats under /api/v1/admin */
+  is_admin: boolean
+  created_at: string
+  updated_at: string
+}
+
+/**
+ * Response from POST /api/v1/auth/signup and /api/v1/auth/login.
+ * Mirrors: apps/api/src/users/mod.rs — AuthResponse
+ */
+export interface AuthResponse {
+  /** Bearer token for the Authorization header. */
+  token: string
+  /** Token lifetime in seconds. */
+  expires_in: number
+  user: User
+}
+
+/**
+ * Body for PATCH /api/v1/users/me — absent fields are unchanged,
+ * an empty display_name clears it.
+ */
+export interface UpdateMeRequest {
+  email?: string
+  display_name?: string
+}
+
+/**
+ * Tone a generation writes in.
+ * Mirrors: packages/templar-types/src/jd.rs — JDTone
+ */
+export type JdTone =
+  | 'AggressiveStartup'
+  | 'CollaborativeEnterprise'
+  | 'ResearchOriented'
+  | 'ProductOriented'
+
+/** Mirrors: apps/api/src/generation/domain_pack.rs — DomainPack */
+export type DomainPack = 'engineering' | 'product' | 'marketing' | 'finance' | 'academia'
+
+/**
+ * Content-selection weights; each in [0, 1], summing to 1.
+ * Mirrors: apps/api/src/context/scoring.rs — ScoringWeights
+ */
+export interface ScoringWeights {
+  recency: number
+  impact: number
+  jd_relevance: number
+}
+
+/** Mirrors: apps/api/src/context/scoring.rs — DecayCurve */
+export type DecayCurve =
+  | { curve: 'exponential'; half_life_months: number }
+  /** 1.0 until the first step; steps ascend in after_months and never raise the score. */
+  | { curve: 'step'; steps: { after_months: number; score: number }[] }
+  | { curve: 'never' }
+
+/**
+ * Recency decay per entry type, plus a default for the rest.
+ * Mirrors: apps/api/src/context/scoring.rs — RecencyPolicy
+ */
+export interface RecencyPolicy {
+  default?: DecayCurve
+  by_entry_type?: Record<string, DecayCurve>
+}
+
+/** Mirrors: apps/api/src/users/preferences.rs — NotificationSettings */
+export interface NotificationSettings {
+  render_complete: boolean
+  batch_complete: boolean
+  export_ready: boolean
+  /** Email when context goes stale (see ContextNudge) */
+  context_nudges: boolean
+}
+
+/**
+ * Saved generation defaults — body and response of /api/v1/users/me/preferences.
+ * A request's own paper_size / margin_preset win over these.
+ * Mirrors: apps/api/src/users/preferences.rs — Preferences
+ */
+export interface UserPreferences {
+  template_id: string | null
+  /** One of 10, 10.5, 11, 11.5, 12 — the starting size; layout may still step it. */
+  font_size_pt: number | null
+  paper_size: PaperSize | null
+  margin_preset: MarginPreset | null
+  /** Replaces the tone detected from the JD. */
+  tone: JdTone | null
+  scoring_weights: ScoringWeights | null
+  /** Verb sets for generation and vague words for ingest; null = engineering. */
+  domain_pack: DomainPack | null
+  /** Applies to entries ingested afterwards; null = 18-month half-life, skills never decay. */
+  recency_policy: RecencyPolicy | null
+  notifications: NotificationSettings
+}
+
+/** Body for DELETE /api/v1/users/me — the account email, typed again to confirm. */
+export interface DeleteMeRequest {
+  confirm_email: string
+}
+
+/**
+ * Rows removed per table by an account deletion.
+ * Mirrors: apps/api/src/users/deletion.rs — RowCounts
+ */
+export interface DeletedRowCounts {
+  context_entries: number
+  context_snapshots: number
+  context_ingest_batches: number
+  context_ingest_items: number
+  resumes: number
+  resume_bullets: number
+  render_jobs: number
+  generation_batches: number
+  generation_jobs: number
+  cv_projects: number
+  personas: number
+  audit_log: number
+  user_exports: number
+  org_memberships: number
+  /** Notes written by the user, on any resume */
+  resume_annotations: number
+  applications: number
+  user_webhooks: number
+  resume_shares: number
+  resume_share_views: number
+  /** Comments written by the user, on any resume */
+  resume_comments: number
+  target_roles: number
+  context_nudge_deliveries: number
+}
+
+/**
+ * Returned by DELETE /api/v1/users/me.
+ * Mirrors: apps/api/src/users/deletion.rs — DeletionReceipt
+ */
+export interface DeletionReceipt {
+  id: string
+  /** The deleted account's id */
+  user_id: string
+  deleted_at: string
+  rows_deleted: DeletedRowCounts
+  s3_objects_deleted: number
+  /** Objects whose delete failed and is being retried in the background */
+  s3_objects_retrying: number
+  usage_keys_deleted: number
+  request_id: string | null
+}
+
+/** Mirrors: apps/api/src/quota/mod.rs — Meter */
+export type QuotaMeter = 'generations' | 'renders' | 'llm_tokens'
+
+/** Mirrors: apps/api/src/quota/mod.rs — MeterUsage */
+export interface MeterUsage {
+  meter: QuotaMeter
+  used: number
+  /** null when the tier is unlimited for this meter */
+  limit: number | null
+  remaining: number | null
+}
+
+/**
+ * Response from GET /api/v1/users/me/usage — current calendar month (UTC).
+ * Mirrors: apps/api/src/quota/mod.rs — UsageReport
+ */
+export interface UsageReport {
+  tier: string
+  /** Set when the limits are a sponsoring organization's shared pool */
+  org_id: string | null
+  /** YYYY-MM */
+  period: string
+  resets_at: string
+  meters: MeterUsage[]
+}
+
+// ─────────────────────────────────────────────────────────────────────────────
+// Organizations
+// ─────────────────────────────────────────────────────────────────────────────
+
+/** Mirrors: apps/api/src/orgs/mod.rs — Role */
+export type OrgRole = 'owner' | 'coach' | 'candidate'
+
+/** Mirrors: apps/api/src/orgs/mod.rs — OrganizationRow */
+export interface Organization {
+  id: string
+  name: string
+  /** Shared pool for the org's candidates: starter | growth | enterprise */
+  tier: string
+  created_at: string
+}
+
+/**
+ * An org as listed for one of its members.
+ * Mirrors: apps/api/src/orgs/mod.rs — MyOrgRow
+ */
+export interface MyOrganization extends Organization {
+  /** The caller's role */
+  role: OrgRole
+}
+
+/** Mirrors: apps/api/src/orgs/mod.rs — MemberRow */
+export interface OrgMember {
+  user_id: string
+  email: string
+  display_name: string | null
+  role: OrgRole
+  joined_at: string
+}
+
+/** Body for POST /api/v1/orgs/:id/members — adds an account or changes its role */
+export interface AddOrgMemberRequest {
+  email: string
+  role: OrgRole
+}
+
+/** Mirrors: apps/api/src/orgs/mod.rs — MemberUsage */
+export interface OrgMemberUsage {
+  user_id: string
+  email: string
+  role: OrgRole
+  meters: { meter: QuotaMeter; used: number }[]
+}
+
+/**
+ * Returned by GET /api/v1/orgs/:id/usage.
+ * Mirrors: apps/api/src/orgs/mod.rs — OrgUsageResponse
+ */
+export interface OrgUsageResponse {
+  /** The shared pool the org's candidates draw from */
+  pool: UsageReport
+  members: OrgMemberUsage[]
+}
+
+/**
+ * A note on a resume by its owner or one of their coaches.
+ * Mirrors: apps/api/src/orgs/mod.rs — AnnotationRow
+ */
+export interface ResumeAnnotation {
+  id: string
+  resume_id: string
+  /** Set when the note is pinned to one bullet */
+  bullet_id: string | null
+  author_id: string
+  body: string
+  created_at: string
+}
+
+// ─────────────────────────────────────────────────────────────────────────────
+// Share links
+// ─────────────────────────────────────────────────────────────────────────────
+
+/**
+ * Body for POST /api/v1/resumes/:id/share.
+ * Mirrors: apps/api/src/shares/mod.rs — CreateShareRequest
+ */
+export interface CreateShareRequest {
+  /** 1–30; defaults to 7 */
+  expires_in_days?: number
+  /** Let holders of the link comment on bullets; defaults to false */
+  allow_comments?: boolean
+}
+
+/**
+ * A public, read-only link to a resume. GET /api/v1/resumes/:id/share returns
+ * `{ shares: ResumeShare[] }`, newest first.
+ * Mirrors: apps/api/src/shares/mod.rs — ShareResponse
+ */
+export interface ResumeShare {
+  id: string
+  resume_id: string
+  /** Opens the resume as HTML without signing in; append `/pdf` for the PDF */
+  url: string
+  expires_at: string
+  revoked_at: string | null
+  created_at: string
+  can_comment: boolean
+}
+
+/**
+ * One fetch of a share link. No IP address or raw User-Agent is kept.
+ * Mirrors: apps/api/src/shares/views.rs — ShareViewRow
+ */
+export interface ShareView {
+  share_id: string
+  format: 'html' | 'pdf'
+  /** ISO 3166-1 alpha-2, only behind a trusted CDN */
+  country: string | null
+  browser: string | null
+  os: string | null
+  /** 'bot' is a link unfurler or crawler, not a person */
+  device: 'desktop' | 'mobile' | 'tablet' | 'bot' | 'unknown'
+  viewed_at: string
+}
+
+/** Mirrors: apps/api/src/shares/views.rs — ShareOpens */
+export interface ShareOpens {
+  share_id: string
+  created_at: string
+  expires_at: string
+  revoked_at: string | null
+  opens: number
+  bot_opens: number
+  last_opened_at: string | null
+}
+
+/** Mirrors: apps/api/src/shares/views.rs — OpenCount */
+export interface OpenCount {
+  /** 'unknown' when the detail was not available */
+  value: string
+  opens: number
+}
+
+/**
+ * Returned by GET /api/v1/resumes/:id/share/stats. Counts exclude bot fetches.
+ * Mirrors: apps/api/src/shares/views.rs — ShareStats
+ */
+export interface ShareStats {
+  resume_id: string
+  opens: number
+  bot_opens: number
+  first_opened_at: string | null
+  last_opened_at: string | null
+  /** Every link, newest first */
+  shares: ShareOpens[]
+  countries: OpenCount[]
+  devices: OpenCount[]
+  browsers: OpenCount[]
+  /** The latest 50 views, newest first, bots included */
+  recent: ShareView[]
+}
+
+// ─────────────────────────────────────────────────────────────────────────────
+// Review comments
+// ─────────────────────────────────────────────────────────────────────────────
+
+/**
+ * A comment on one bullet. Exactly one of `author_id` (the owner or a coach) and
+ * `share_id` (a reviewer holding a share link) is set.
+ * Mirrors: apps/api/src/comments/mod.rs — CommentRow
+ */
+export interface ResumeComment {
+  id: string
+  resume_id: string
+  bullet_id: string
+  /** The thread's first comment; null on that first comment */
+  parent_id: string | null
+  author_id: string | null
+  share_id: string | null
+  author_name: string
+  body: string
+  /** Set on a thread's first comment once the thread is resolved */
+  resolved_at: string | null
+  resolved_by: string | null
+  created_at: string
+}
+
+/**
+ * Returned (as an array, oldest first) by GET /api/v1/resumes/:id/comments.
+ * Mirrors: apps/api/src/comments/mod.rs — CommentThread
+ */
+export interface CommentThread extends ResumeComment {
+  replies: ResumeComment[]
+}
+
+/**
+ * Body for POST /api/v1/resumes/:id/comments.
+ * Mirrors: apps/api/src/comments/mod.rs — CreateCommentRequest
+ */
+export interface CreateCommentRequest {
+  bullet_id: string
+  /** Reply to this comment's thread; omit to start a thread */
+  parent_id?: string
+  body: string
+}
+
+/**
+ * Body for POST /api/v1/shared/:token/comments.
+ * Mirrors: apps/api/src/comments/mod.rs — SharedCommentRequest
+ */
+export interface SharedCommentRequest extends CreateCommentRequest {
+  author_name: string
+}
+
+/**
+ * Returned by GET /api/v1/shared/:token/comments.
+ * Mirrors: apps/api/src/comments/mod.rs — SharedReviewResponse
+ */
+export interface SharedReviewResponse {
+  bullets: { id: string; section: string; bullet_text: string }[]
+  threads: CommentThread[]
+}
+
+// ─────────────────────────────────────────────────────────────────────────────
+// Bullet revisions
+// ─────────────────────────────────────────────────────────────────────────────
+
+/** Mirrors: packages/templar-types/src/generation.rs — RevisionCause */
+export type RevisionCause = 'generate' | 'expand' | 'compress' | 'regenerate' | 'user'
+
+/**
+ * One text a bullet has had. Returned (as an array, oldest first) by
+ * GET /api/v1/resumes/:id/bullets/:bullet_id/revisions.
+ * Mirrors: apps/api/src/bullet_revisions/mod.rs — BulletRevisionRow
+ */
+export interface BulletRevision {
+  id: string
+  bullet_id: string
+  /** 1-based; the highest is the bullet's current text */
+  revision: number
+  bullet_text: string
+  cause: RevisionCause
+  /** The revision a 'user' restore copied */
+  restored_from: number | null
+  created_at: string
+}
+
+/**
+ * Returned by POST /api/v1/resumes/:id/bullets/:bullet_id/revisions/:revision/restore.
+ * Mirrors: apps/api/src/bullet_revisions/mod.rs — RestoreRevisionResponse
+ */
+export interface RestoreRevisionResponse {
+  bullet: ResumeBulletRow
+  /** The 'user' revision the restore added */
+  revision: BulletRevision
+}
+
+// ─────────────────────────────────────────────────────────────────────────────
+// Generation explanation
+// ─────────────────────────────────────────────────────────────────────────────
+
+/** Mirrors: apps/api/src/generation/explanation.rs — ToneSource */
+export type ToneSource = 'jd' | 'preference'
+
+/** Mirrors: apps/api/src/generation/explanation.rs — SelectedEntry */
+export interface ExplainedSelectedEntry {
+  entry_id: string
+  entry_type: string
+  /** Company, project or institution name, when the entry has one */
+  label: string | null
+  combined_score: number
+  jd_relevance: number
+  /** JD keywords the entry's tags or text mention */
+  matched_keywords: string[]
+  /** Bullets on the final resume written from this entry */
+  bullet_count: number
+}
+
+/**
+ * Why selection cut an entry; 'other' for older explanations whose reason names none.
+ * Mirrors: apps/api/src/generation/content_selector.rs — ExclusionKind
+ */
+export type ExclusionKind = 'section_limit' | 'low_jd_relevance' | 'not_picked' | 'other'
+
+/** Mirrors: apps/api/src/generation/explanation.rs — ExcludedEntry */
+export interface ExplainedExcludedEntry {
+  entry_id: string
+  entry_type: string
+  label: string | null
+  jd_relevance: number
+  matched_keywords: string[]
+  kind: ExclusionKind
+  reason: string
+}
+
+/** Mirrors: apps/api/src/generation/explanation.rs — KeywordDriver */
+export interface KeywordDriver {
+  keyword: string
+  weighted_score: number
+  /** Selected entries matching the keyword */
+  selected_entries: number
+}
+
+/**
+ * Returned by GET /api/v1/resumes/:id/explanation, and as ResumeRow.explanation by
+ * GET /api/v1/resumes/:id.
+ * Mirrors: apps/api/src/generation/explanation.rs — GenerationExplanation
+ */
+export interface GenerationExplanation {
+  tone: JdTone
+  tone_source: ToneSource
+  domain_pack: DomainPack
+  /** Highest combined score first */
+  selected: ExplainedSelectedEntry[]
+  excluded: ExplainedExcludedEntry[]
+  section_weights: Record<string, number>
+  /** JD keywords matching at least one selected entry, highest weight first */
+  keyword_drivers: KeywordDriver[]
+  /** JD keywords no selected entry matches */
+  unmatched_keywords: string[]
+}
+
+// ─────────────────────────────────────────────────────────────────────────────
+// Audit log
+// ─────────────────────────────────────────────────────────────────────────────
+
+/** Mirrors: apps/api/src/audit/mod.rs — Action */
+export type AuditAction =
+  | 'context_entry.ingest'
+  | 'context_entry.edit'
+  | 'context_entry.evergreen'
+  | 'context_entry.retag'
+  | 'ingest_batch.create'
+  | 'resume.generate'
+  | 'resume.regenerate'
+  | 'resume.reorder_bullets'
+  | 'generation_batch.create'
+  | 'render_job.create'
+  | 'project.create'
+  | 'project.update'
+  | 'project.delete'
+  | 'user.signup'
+  | 'user.update'
+  | 'user.preferences_update'
+  | 'org.create'
+  | 'org.member_update'
+  | 'org.member_remove'
+  | 'resume.annotate'
+  | 'resume.delete'
+  | 'context_snapshot.delete'
+  | 'application.create'
+  | 'application.update'
+  | 'application.delete'
+  | 'persona.create'
+  | 'persona.update'
+  | 'persona.delete'
+  | 'webhook.create'
+  | 'webhook.update'
+  | 'webhook.delete'
+  | 'resume_share.create'
+  | 'resume_share.revoke'
+  | 'resume_comment.create'
+  | 'resume_comment.resolve'
+  | 'target_role.create'
+  | 'target_role.delete'
+  | 'resume_bullet.restore'
+
+/**
+ * One recorded mutation. before/after hold identifiers and versions, not content.
+ * Mirrors: apps/api/src/audit/mod.rs — AuditLogRow
+ */
+export interface AuditLogEntry {
+  id: string
+  user_id: string
+  action: AuditAction
+  resource_type: string
+  resource_id: string | null
+  before: Record<string, unknown> | null
+  after: Record<string, unknown> | null
+  request_id: string | null
+  created_at: string
+}
+
+/**
+ * Response from GET /api/v1/users/me/audit — newest first.
+ * Mirrors: apps/api/src/audit/handlers.rs — AuditLogResponse
+ */
+export interface AuditLogResponse {
+  entries: AuditLogEntry[]
+  /** Pass as `before` for the next page; null on the last page */
+  next_before: string | null
+}
+
+// ─────────────────────────────────────────────────────────────────────────────
+// Data export
+// ─────────────────────────────────────────────────────────────────────────────
+
+export type ExportStatus = 'pending' | 'running' | 'completed' | 'failed'
+
+/**
+ * Returned by POST /api/v1/users/me/export.
+ * Mirrors: apps/api/src/export/mod.rs — StartExportResponse
+ */
+export interface StartExportResponse {
+  export_id: string
+  status: ExportStatus
+  /** SSE stream of the export's progress */
+  events_url: string
+}
+
+/**
+ * Returned by GET /api/v1/users/me/export/:id.
+ * Mirrors: apps/api/src/export/mod.rs — ExportStatusResponse
+ */
+export interface ExportStatusResponse {
+  id: string
+  user_id: string
+  status: ExportStatus
+  s3_key: string | null
+  size_bytes: number | null
+  error_message: string | null
+  created_at: string
+  completed_at: string | null
+  /** Presigned ZIP download; present once completed, valid for 15 minutes */
+  download_url: string | null
+  download_url_expires_at: string | null
+}
+
+// ─────────────────────────────────────────────────────────────────────────────
+// Admin stats
+// ─────────────────────────────────────────────────────────────────────────────
+
+/** Mirrors: apps/api/src/admin/mod.rs — GenerationDay */
+export interface GenerationDay {
+  /** YYYY-MM-DD (UTC) */
+  day: string
+  generated: number
+  regenerated: number
+  /** Failed batch generation jobs */
+  failed: number
+}
+
+/** Mirrors: apps/api/src/admin/mod.rs — GenerationStats */
+export interface GenerationStats {
+  days: number
+  per_day: GenerationDay[]
+}
+
+/** Mirrors: apps/api/src/admin/mod.rs — UserTokenSpend */
+export interface UserTokenSpend {
+  user_id: string
+  email: string | null
+  tokens: number
+}
+
+/** Mirrors: apps/api/src/admin/mod.rs — TokenSpendStats */
+export interface TokenSpendStats {
+  /** YYYY-MM */
+  period: string
+  total_tokens: number
+  users_with_spend: number
+  users: UserTokenSpend[]
+}
+
+/** Mirrors: apps/api/src/admin/mod.rs — RenderStats */
+export interface RenderStats {
+  days: number
+  queued: number
+  processing: number
+  done: number
+  failed: number
+  /** failed / (done + failed); null when nothing finished */
+  failure_rate: number | null
+}
+
+/** Mirrors: apps/api/src/admin/mod.rs — FlagStats */
+export interface FlagStats {
+  days: number
+  resumes: number
+  resumes_with_flags: number
+  bullets: number
+  flagged_bullets: number
+  flagged_rate: number | null
+}
+
+/** Mirrors: apps/api/src/jobs/mod.rs — QueueDepth */
+export interface QueueDepth {
+  queue: string
+  ready: number
+  delayed: number
+  dead: number
+}
+
+/** Mirrors: apps/api/src/seed/mod.rs — SeedReport */
+export interface SeedReport {
+  user_id: string
+  email: string
+  password: string
+  entry_count: number
+  resume_ids: string[]
+}
+
+// ─────────────────────────────────────────────────────────────────────────────
+// Progress events
+// ─────────────────────────────────────────────────────────────────────────────
+
+/**
+ * A pipeline stage, tagged by `stage`. `completed` and `failed` are terminal.
+ * Mirrors: apps/api/src/progress/mod.rs — Stage
+ */
+export type ProgressStage =
+  | { stage: 'jd_parsed' }
+  | { stage: 'fit_scored'; score: number }
+  | { stage: 'content_selected'; entries: number }
+  | { stage: 'bullets_generated'; bullets: number }
+  | { stage: 'layout_pass'; pass: number; violations: number }
+  | { stage: 'grounding_checked'; bullets: number }
+  | { stage: 'resume_saved'; resume_id: string }
+  | { stage: 'render_queued'; render_job_id: string }
+  | { stage: 'render_started' }
+  | { stage: 'export_collected'; files: number }
+  | { stage: 'export_uploaded'; bytes: number }
+  | { stage: 'completed' }
+  | { stage: 'failed'; message: string }
+
+/**
+ * One event from GET /api/v1/events/:job_id.
+ * Mirrors: apps/api/src/progress/mod.rs — ProgressEvent
+ */
+export type ProgressEvent = {
+  job_id: string
+  seq: number
+  at: string
+} & ProgressStage
+
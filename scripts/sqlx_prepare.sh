@@ -45,10 +45,16 @@ if ! cargo sqlx --version > /dev/null 2>&1; then
     cargo install sqlx-cli --no-default-features --features postgres
 fi
 
-# Run sqlx prepare from the api crate directory
+# Run sqlx prepare from the api crate directory. Extra arguments (e.g. --check)
+# are passed through.
 echo "==> [sqlx-prepare] Generating .sqlx query cache..."
 cd "$API_DIR"
-DATABASE_URL="$DATABASE_URL" cargo sqlx prepare
+DATABASE_URL="$DATABASE_URL" cargo sqlx prepare "$@"
+if [ "${1:-}" = "--check" ]; then
+    echo ""
+    echo "✓  .sqlx cache matches the queries and the migrated schema."
+    exit 0
+fi
 
 echo ""
 echo "✓  .sqlx cache updated at apps/api/.sqlx/"